@@ -0,0 +1,463 @@
+//! Benchmarks ที่พิสูจน์คำกล่าวอ้างของบท Performance
+//!
+//! แต่ละ group วัดคู่เปรียบเทียบจาก `rust_concepts::performance::microbench`
+//! ซึ่งเป็นโค้ดชุดเดียวกับที่ `cargo run -- --benchmark` ใช้ในห้องเรียน
+//!
+//! รันด้วย: `cargo bench --bench chapter_claims`
+
+use std::collections::hash_map::RandomState;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::BuildHasher;
+use std::path::PathBuf;
+use std::sync::RwLock;
+
+use criterion::{BenchmarkId, Criterion, Throughput, black_box, criterion_group, criterion_main};
+use rust_concepts::advanced_topics::allocation_tracking::measure_allocations;
+use rust_concepts::collections::iterators::{
+    even_square_sum_index, even_square_sum_iter, rising_total_index, rising_total_iter,
+    sample_series,
+};
+use rust_concepts::collections::{BTree, SkipList, shuffled_keys};
+use rust_concepts::concurrency::rcu::{
+    ArcSwap, SeqLock, SharedRead, Snapshot, read_heavy_workload,
+};
+use rust_concepts::concurrency::thread_pool::ThreadPool;
+use rust_concepts::io_filesystem::csv::{parse_naive, parse_rfc4180, sample_csv};
+use rust_concepts::performance::arena::{Arena, build_arena_expr, build_boxed_expr};
+use rust_concepts::performance::cache_optimization::{
+    Matrix, PaddedCounters, ParticlesSoA, SharedLineCounters, checkerboard, hammer_counters,
+    make_particles, total_mass_aos, total_mass_soa,
+};
+use rust_concepts::performance::microbench::{
+    hashmap_lookup, lock_free_queue_round_trip, lookup_fixture, mutex_queue_round_trip,
+    parse_cloning, parse_zero_copy, sample_text, scalar_sum, simd_sum, vec_lookup,
+};
+use rust_concepts::performance::parallel_processing::{
+    parallel_collatz_total, sequential_collatz_total,
+};
+use rust_concepts::performance::simd_vectorization::{
+    SimdBackend, simd_dot_product_with, simd_find_byte_with, simd_rgb_to_grayscale_with,
+};
+use rust_concepts::performance::zero_copy::{
+    sample_log, summarize_log_owned, summarize_log_zero_copy,
+};
+use rust_concepts::security::checksums::{Crc32, FnvBuildHasher, SipBuildHasher};
+use rust_concepts::task_search::{self, Query};
+use rust_concepts::traits::trait_objects::{
+    ManualDynSpeaker, Speaker, sample_dogs, total_volume_dyn, total_volume_manual,
+    total_volume_static,
+};
+use rust_concepts::{Priority, TaskManager};
+
+/// SIMD-style sum เทียบกับ scalar loop
+fn benchmark_simd_sum(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sum_f32");
+    for size in [256_u16, 4_096, 65_535] {
+        let data: Vec<f32> = (0..size).map(f32::from).collect();
+        group.throughput(Throughput::Elements(u64::from(size)));
+        group.bench_with_input(BenchmarkId::new("scalar", size), &data, |b, data| {
+            b.iter(|| scalar_sum(black_box(data)));
+        });
+        group.bench_with_input(BenchmarkId::new("simd", size), &data, |b, data| {
+            b.iter(|| simd_sum(black_box(data)));
+        });
+    }
+    group.finish();
+}
+
+/// `HashMap` lookup เทียบกับการไล่หาใน `Vec`
+fn benchmark_lookup(c: &mut Criterion) {
+    let mut group = c.benchmark_group("lookup");
+    for size in [8_u32, 64, 512] {
+        let (map, pairs, keys) = lookup_fixture(size);
+        group.throughput(Throughput::Elements(u64::from(size)));
+        group.bench_function(BenchmarkId::new("vec", size), |b| {
+            b.iter(|| vec_lookup(black_box(&pairs), black_box(&keys)));
+        });
+        group.bench_function(BenchmarkId::new("hashmap", size), |b| {
+            b.iter(|| hashmap_lookup(black_box(&map), black_box(&keys)));
+        });
+    }
+    group.finish();
+}
+
+/// Zero-copy parsing เทียบกับการ clone ทุกคำเป็น `String`
+fn benchmark_parsing(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse_words");
+    for words in [100_usize, 10_000] {
+        let text = sample_text(words);
+        group.throughput(Throughput::Bytes(text.len() as u64));
+        group.bench_with_input(BenchmarkId::new("cloning", words), &text, |b, text| {
+            b.iter(|| parse_cloning(black_box(text)));
+        });
+        group.bench_with_input(BenchmarkId::new("zero_copy", words), &text, |b, text| {
+            b.iter(|| parse_zero_copy(black_box(text)));
+        });
+    }
+    group.finish();
+}
+
+/// Lock-free ring buffer เทียบกับ `Mutex<VecDeque>` ภายใต้หลาย thread
+fn benchmark_queues(c: &mut Criterion) {
+    let mut group = c.benchmark_group("concurrent_queue");
+    group.sample_size(20);
+    for threads in [1_usize, 4] {
+        group.bench_function(BenchmarkId::new("mutex_vecdeque", threads), |b| {
+            b.iter(|| mutex_queue_round_trip(black_box(threads), 1_000));
+        });
+        group.bench_function(BenchmarkId::new("lock_free", threads), |b| {
+            b.iter(|| lock_free_queue_round_trip(black_box(threads), 1_000));
+        });
+    }
+    group.finish();
+}
+
+/// `RwLock` เทียบกับ `ArcSwap` (RCU) และ `SeqLock` เมื่ออ่านมากกว่าเขียนหลายร้อยเท่า
+fn benchmark_shared_reads(c: &mut Criterion) {
+    fn bench<S: SharedRead<Snapshot>>(
+        group: &mut criterion::BenchmarkGroup<'_, criterion::measurement::WallTime>,
+        readers: usize,
+        shared: &S,
+    ) {
+        group.bench_function(BenchmarkId::new(S::NAME, readers), |b| {
+            b.iter(|| read_heavy_workload(shared, black_box(readers), 10_000, 20));
+        });
+    }
+
+    let mut group = c.benchmark_group("read_mostly");
+    group.sample_size(20);
+    for readers in [1_usize, 4, 8] {
+        group.throughput(Throughput::Elements(readers as u64 * 10_000));
+        bench(&mut group, readers, &RwLock::new(Snapshot::new(0)));
+        bench(&mut group, readers, &ArcSwap::new(Snapshot::new(0)));
+        bench(&mut group, readers, &SeqLock::new(Snapshot::new(0)));
+    }
+    group.finish();
+}
+
+/// Arena (bump) allocation เทียบกับ `Box` ทีละ node
+fn benchmark_arena(c: &mut Criterion) {
+    let mut group = c.benchmark_group("expression_tree");
+    for depth in [6_u32, 12] {
+        group.throughput(Throughput::Elements((1_u64 << (depth + 1)) - 1));
+        group.bench_function(BenchmarkId::new("boxed", depth), |b| {
+            b.iter(|| build_boxed_expr(black_box(depth)).eval());
+        });
+        group.bench_function(BenchmarkId::new("arena", depth), |b| {
+            b.iter(|| {
+                let arena = Arena::new();
+                build_arena_expr(&arena, black_box(depth)).eval()
+            });
+        });
+    }
+    group.finish();
+}
+
+/// กราฟ speedup ของ `par_map` ตามจำนวน worker เทียบกับ sequential
+fn benchmark_parallel(c: &mut Criterion) {
+    const LIMIT: u64 = 50_000;
+    let mut group = c.benchmark_group("parallel_collatz");
+    group.sample_size(20);
+    group.throughput(Throughput::Elements(LIMIT));
+    group.bench_function("sequential", |b| {
+        b.iter(|| sequential_collatz_total(black_box(LIMIT)));
+    });
+    for workers in [1_usize, 2, 4, 8] {
+        let pool = ThreadPool::new(workers);
+        group.bench_function(BenchmarkId::new("par_map", workers), |b| {
+            b.iter(|| parallel_collatz_total(&pool, black_box(LIMIT)));
+        });
+    }
+    group.finish();
+}
+
+/// Layout ที่เป็นมิตรกับ cache เทียบกับแบบที่ไม่เป็นมิตร
+fn benchmark_cache(c: &mut Criterion) {
+    let mut group = c.benchmark_group("cache_layout");
+
+    let particles = make_particles(u16::MAX);
+    let soa = ParticlesSoA::from_aos(&particles);
+    group.bench_function("particles_aos", |b| {
+        b.iter(|| total_mass_aos(black_box(&particles)));
+    });
+    group.bench_function("particles_soa", |b| {
+        b.iter(|| total_mass_soa(black_box(&soa)));
+    });
+
+    let matrix = Matrix::from_fn(512, 512, checkerboard);
+    group.bench_function("matrix_column_major", |b| {
+        b.iter(|| black_box(&matrix).sum_column_major());
+    });
+    group.bench_function("matrix_row_major", |b| {
+        b.iter(|| black_box(&matrix).sum_row_major());
+    });
+
+    let shared = SharedLineCounters::default();
+    let padded = PaddedCounters::default();
+    group.bench_function("counters_same_line", |b| {
+        b.iter(|| hammer_counters(&shared.left, &shared.right, 10_000));
+    });
+    group.bench_function("counters_padded", |b| {
+        b.iter(|| hammer_counters(&padded.left, &padded.right, 10_000));
+    });
+    group.finish();
+}
+
+/// Log parser แบบยืม field (zero-copy) เทียบกับแบบ `String` ทุก field
+///
+/// พิมพ์จำนวน allocation ของแต่ละแบบก่อนจับเวลา (ต้องเปิด feature `tracking-allocator`)
+fn benchmark_log_parsing(c: &mut Criterion) {
+    let mut group = c.benchmark_group("log_parsing");
+    for lines in [100_usize, 10_000] {
+        let text = sample_log(lines);
+
+        let (_, zero_copy) = measure_allocations(|| summarize_log_zero_copy(text.as_bytes()));
+        let (_, owned) = measure_allocations(|| summarize_log_owned(&text));
+        println!(
+            "log_parsing/{lines}: owned {} allocations ({} bytes), zero_copy {} allocations ({} bytes)",
+            owned.allocations,
+            owned.bytes_allocated,
+            zero_copy.allocations,
+            zero_copy.bytes_allocated
+        );
+
+        group.throughput(Throughput::Bytes(text.len() as u64));
+        group.bench_with_input(BenchmarkId::new("owned", lines), &text, |b, text| {
+            b.iter(|| summarize_log_owned(black_box(text)));
+        });
+        group.bench_with_input(BenchmarkId::new("zero_copy", lines), &text, |b, text| {
+            b.iter(|| summarize_log_zero_copy(black_box(text.as_bytes())));
+        });
+    }
+    group.finish();
+}
+
+/// kernel SIMD จริงทุก backend ที่ CPU นี้รองรับ (Scalar คือ fallback)
+fn benchmark_simd_kernels(c: &mut Criterion) {
+    let a: Vec<f32> = (0..4_096_u16).map(|i| f32::from(i % 100)).collect();
+    let b: Vec<f32> = (0..4_096_u16).map(|i| f32::from(i % 7)).collect();
+    let mut haystack = vec![b'a'; 64 * 1024];
+    haystack[60_000] = b'!';
+    let rgb: Vec<u8> = (0..=u8::MAX).cycle().take(256 * 256 * 3).collect();
+    let mut gray = vec![0_u8; 256 * 256];
+
+    let mut group = c.benchmark_group("simd_kernels");
+    for backend in SimdBackend::available() {
+        let name = format!("{backend:?}");
+        group.bench_function(BenchmarkId::new("dot_product", &name), |bench| {
+            bench.iter(|| simd_dot_product_with(backend, black_box(&a), black_box(&b)));
+        });
+        group.bench_function(BenchmarkId::new("find_byte", &name), |bench| {
+            bench.iter(|| simd_find_byte_with(backend, black_box(&haystack), b'!'));
+        });
+        group.bench_function(BenchmarkId::new("grayscale", &name), |bench| {
+            bench.iter(|| simd_rgb_to_grayscale_with(backend, black_box(&rgb), &mut gray));
+        });
+    }
+    group.finish();
+}
+
+/// Inverted index ของ `TaskManager::search` เทียบกับ linear scan
+fn benchmark_task_search(c: &mut Criterion) {
+    const WORDS: [&str; 8] = [
+        "write", "review", "deploy", "docs", "release", "fix", "report", "plan",
+    ];
+    let mut group = c.benchmark_group("task_search");
+    let query: Query = "docs #work p:high".parse().expect("valid query");
+    for size in [100_usize, 10_000] {
+        let mut manager = TaskManager::new(PathBuf::from("unused.json"));
+        for i in 0..size {
+            let title = format!("{} {} #{i}", WORDS[i % 8], WORDS[(i / 8) % 8]);
+            let priority = [Priority::Low, Priority::Medium, Priority::High][i % 3];
+            let id = manager.add_task(title, priority);
+            if i % 5 == 0 {
+                manager
+                    .update_task(&id, |task| task.tags.push("work".to_string()))
+                    .expect("task exists");
+            }
+        }
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_function(BenchmarkId::new("scan", size), |b| {
+            b.iter(|| task_search::scan(black_box(manager.list_tasks()), black_box(&query)).len());
+        });
+        group.bench_function(BenchmarkId::new("index", size), |b| {
+            b.iter(|| manager.search(black_box(&query)).len());
+        });
+    }
+    group.finish();
+}
+
+/// CSV parser ตาม RFC 4180 เทียบกับ `split(',')` ที่เร็วกว่าแต่ตัด field ที่มี quote ผิด
+fn benchmark_csv(c: &mut Criterion) {
+    let mut group = c.benchmark_group("csv");
+    for rows in [100_usize, 10_000] {
+        let text = sample_csv(rows);
+        group.throughput(Throughput::Bytes(text.len() as u64));
+        group.bench_with_input(BenchmarkId::new("naive_split", rows), &text, |b, text| {
+            b.iter(|| parse_naive(black_box(text)).len());
+        });
+        group.bench_with_input(BenchmarkId::new("rfc4180", rows), &text, |b, text| {
+            b.iter(|| parse_rfc4180(black_box(text)).expect("valid CSV").len());
+        });
+    }
+    group.finish();
+}
+
+/// `HashMap` กับ hasher ต่างๆ: FNV-1a เร็วกับ key สั้น, `SipHash` แลกความเร็วกับการกัน `HashDoS`
+fn benchmark_hashers(c: &mut Criterion) {
+    fn fill<S: BuildHasher>(build: S, keys: &[String]) -> usize {
+        let mut map = HashMap::with_capacity_and_hasher(keys.len(), build);
+        for (value, key) in keys.iter().enumerate() {
+            map.insert(key.as_str(), value);
+        }
+        keys.iter()
+            .filter(|key| map.contains_key(key.as_str()))
+            .count()
+    }
+
+    let keys: Vec<String> = (0..10_000).map(|i| format!("user-{i}")).collect();
+    let mut group = c.benchmark_group("hashers");
+    group.throughput(Throughput::Elements(keys.len() as u64));
+    group.bench_function("std_random_state", |b| {
+        b.iter(|| fill(RandomState::new(), black_box(&keys)));
+    });
+    group.bench_function("fnv1a", |b| {
+        b.iter(|| fill(FnvBuildHasher::default(), black_box(&keys)));
+    });
+    group.bench_function("siphash_1_3", |b| {
+        b.iter(|| fill(SipBuildHasher::random(), black_box(&keys)));
+    });
+
+    let payload = vec![0xA5_u8; 64 * 1024];
+    group.throughput(Throughput::Bytes(payload.len() as u64));
+    group.bench_function("crc32_table", |b| {
+        b.iter(|| Crc32::checksum(black_box(&payload)));
+    });
+    group.finish();
+}
+
+/// iterator chain / adapter ที่เขียนเอง เทียบกับ index loop - zero-cost จริงไหม?
+fn benchmark_iterators(c: &mut Criterion) {
+    let mut group = c.benchmark_group("iterators");
+    for len in [1_000_u64, 100_000] {
+        let data = sample_series(len);
+        group.throughput(Throughput::Elements(len));
+        group.bench_with_input(
+            BenchmarkId::new("filter_map_index", len),
+            &data,
+            |b, data| {
+                b.iter(|| even_square_sum_index(black_box(data)));
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("filter_map_chain", len),
+            &data,
+            |b, data| {
+                b.iter(|| even_square_sum_iter(black_box(data)));
+            },
+        );
+        group.bench_with_input(BenchmarkId::new("pairs_index", len), &data, |b, data| {
+            b.iter(|| rising_total_index(black_box(data)));
+        });
+        group.bench_with_input(BenchmarkId::new("pairs_windows2", len), &data, |b, data| {
+            b.iter(|| rising_total_iter(black_box(data)));
+        });
+    }
+    group.finish();
+}
+
+fn benchmark_ordered_maps(c: &mut Criterion) {
+    // debug_assert ตรวจกฎทุกครั้งที่แก้ - bench รันใน release จึงวัดเฉพาะตัวโครงสร้างจริง
+    let mut group = c.benchmark_group("ordered_maps");
+    for len in [1_000_u64, 50_000] {
+        let keys = shuffled_keys(len);
+        group.throughput(Throughput::Elements(len));
+        group.bench_with_input(BenchmarkId::new("skip_list", len), &keys, |b, keys| {
+            b.iter(|| {
+                let mut map = SkipList::new();
+                for &key in keys {
+                    map.insert(key, key);
+                }
+                keys.iter().filter_map(|key| map.get(key)).count()
+            });
+        });
+        group.bench_with_input(BenchmarkId::new("btree", len), &keys, |b, keys| {
+            b.iter(|| {
+                let mut map = BTree::new();
+                for &key in keys {
+                    map.insert(key, key);
+                }
+                keys.iter().filter_map(|key| map.get(key)).count()
+            });
+        });
+        group.bench_with_input(BenchmarkId::new("std_btree_map", len), &keys, |b, keys| {
+            b.iter(|| {
+                let mut map = BTreeMap::new();
+                for &key in keys {
+                    map.insert(key, key);
+                }
+                keys.iter().filter_map(|key| map.get(key)).count()
+            });
+        });
+        group.bench_with_input(BenchmarkId::new("std_hash_map", len), &keys, |b, keys| {
+            b.iter(|| {
+                let mut map = HashMap::new();
+                for &key in keys {
+                    map.insert(key, key);
+                }
+                keys.iter().filter_map(|key| map.get(key)).count()
+            });
+        });
+    }
+    group.finish();
+}
+
+/// static dispatch (generic) เทียบกับ `dyn Trait` และ vtable ที่ประกอบเอง
+fn benchmark_dispatch(c: &mut Criterion) {
+    let mut group = c.benchmark_group("dispatch");
+    for count in [1_000_u32, 100_000] {
+        let dogs = sample_dogs(count);
+        let boxed: Vec<Box<dyn Speaker>> = dogs
+            .iter()
+            .map(|dog| Box::new(dog.clone()) as Box<dyn Speaker>)
+            .collect();
+        let manual: Vec<ManualDynSpeaker> =
+            dogs.iter().cloned().map(ManualDynSpeaker::new).collect();
+        group.throughput(Throughput::Elements(u64::from(count)));
+        group.bench_with_input(BenchmarkId::new("static", count), &dogs, |b, dogs| {
+            b.iter(|| total_volume_static(black_box(dogs)));
+        });
+        group.bench_with_input(BenchmarkId::new("dyn", count), &boxed, |b, boxed| {
+            b.iter(|| total_volume_dyn(black_box(boxed)));
+        });
+        group.bench_with_input(
+            BenchmarkId::new("manual_vtable", count),
+            &manual,
+            |b, manual| {
+                b.iter(|| total_volume_manual(black_box(manual)));
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    benchmark_simd_sum,
+    benchmark_lookup,
+    benchmark_parsing,
+    benchmark_queues,
+    benchmark_shared_reads,
+    benchmark_arena,
+    benchmark_parallel,
+    benchmark_cache,
+    benchmark_log_parsing,
+    benchmark_simd_kernels,
+    benchmark_task_search,
+    benchmark_csv,
+    benchmark_hashers,
+    benchmark_iterators,
+    benchmark_ordered_maps,
+    benchmark_dispatch
+);
+criterion_main!(benches);
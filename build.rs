@@ -0,0 +1,18 @@
+//! Build script - คอมไพล์โค้ด C ใน `csrc/` เป็น static library ให้บทที่ 14 (FFI) เรียกใช้ 🇨🔧
+
+fn main() {
+    println!("cargo:rerun-if-changed=csrc/crc32.c");
+    println!("cargo:rerun-if-changed=csrc/crc32.h");
+
+    // wasm32 ไม่มี C toolchain ให้ลิงก์ - บทที่ 14 gate ส่วน FFI ออกบน target นี้
+    if std::env::var("CARGO_CFG_TARGET_ARCH").as_deref() == Ok("wasm32") {
+        return;
+    }
+
+    // cc เลือก compiler ตาม target เอง (gcc/clang/MSVC) และสั่งให้ cargo ลิงก์ libcrc32.a
+    cc::Build::new()
+        .file("csrc/crc32.c")
+        .include("csrc")
+        .warnings(true)
+        .compile("crc32");
+}
@@ -0,0 +1,28 @@
+/*
+ * crc32.c - โค้ด C จิ๋วที่ build.rs คอมไพล์แล้วลิงก์เข้ากับ crate (บทที่ 14: FFI) 🇨🤝🦀
+ *
+ * - rc_crc32:    Rust เรียก C (CRC-32 แบบ IEEE 802.3 เหมือน zlib)
+ * - rc_fold_i32: C เรียกกลับเข้า Rust ผ่าน function pointer
+ */
+
+#include "crc32.h"
+
+uint32_t rc_crc32(const uint8_t *data, size_t len) {
+    uint32_t crc = 0xFFFFFFFFu;
+    for (size_t i = 0; i < len; i++) {
+        crc ^= data[i];
+        for (int bit = 0; bit < 8; bit++) {
+            /* ถ้าบิตล่างเป็น 1 ให้ XOR ด้วย polynomial (แบบไม่มี branch) */
+            crc = (crc >> 1) ^ (0xEDB88320u & (0u - (crc & 1u)));
+        }
+    }
+    return ~crc;
+}
+
+int32_t rc_fold_i32(const int32_t *values, size_t len, int32_t init, rc_binary_op op) {
+    int32_t acc = init;
+    for (size_t i = 0; i < len; i++) {
+        acc = op(acc, values[i]);
+    }
+    return acc;
+}
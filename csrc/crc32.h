@@ -0,0 +1,20 @@
+/*
+ * crc32.h - ฟังก์ชัน C ที่ Rust เรียกใช้ (คู่กับ `unsafe extern "C"` ใน src/14_unsafe_rust/ffi.rs)
+ */
+
+#ifndef RUST_CONCEPTS_CRC32_H
+#define RUST_CONCEPTS_CRC32_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+/* ฟังก์ชันรับเลขสองตัวคืนหนึ่งตัว - Rust ส่ง `extern "C" fn` มาเป็นค่านี้ได้ */
+typedef int32_t (*rc_binary_op)(int32_t acc, int32_t value);
+
+/* CRC-32 (IEEE) ของ `len` ไบต์ที่ `data` - `data` เป็น NULL ได้ถ้า `len` เป็น 0 */
+uint32_t rc_crc32(const uint8_t *data, size_t len);
+
+/* พับ `values` จากซ้ายไปขวาด้วย `op` เริ่มจาก `init` */
+int32_t rc_fold_i32(const int32_t *values, size_t len, int32_t init, rc_binary_op op);
+
+#endif /* RUST_CONCEPTS_CRC32_H */
@@ -0,0 +1,14 @@
+//! 🔥 บทที่ 1: พื้นฐาน Rust
+
+/// 01-1 🌡️ แปลงองศาเซลเซียสเป็นฟาเรนไฮต์: `F = C × 9/5 + 32`
+#[must_use]
+pub fn celsius_to_fahrenheit(celsius: f64) -> f64 {
+    todo!("01-1: แปลง {celsius}°C เป็น °F")
+}
+
+/// 01-2 🥤 `FizzBuzz`: หาร 3 ลงตัว → `"Fizz"`, หาร 5 ลงตัว → `"Buzz"`, ลงตัวทั้งคู่ → `"FizzBuzz"`,
+/// นอกนั้นคืนตัวเลขเป็น string
+#[must_use]
+pub fn fizzbuzz(n: u32) -> String {
+    todo!("01-2: FizzBuzz ของ {n}")
+}
@@ -0,0 +1,9 @@
+//! 🔒 บทที่ 2: Ownership และ Borrowing
+
+/// 02-1 ✂️ คืนคำแรกของประโยคเป็น slice ของ `text` (ไม่ต้อง allocate ใหม่)
+///
+/// ข้ามช่องว่างข้างหน้า และถ้าไม่มีคำเลยให้คืน `""`
+#[must_use]
+pub fn first_word(text: &str) -> &str {
+    todo!("02-1: คำแรกของ {text:?}")
+}
@@ -0,0 +1,9 @@
+//! 📚 บทที่ 6: Collections
+
+/// 06-1 📊 คำที่พบบ่อยที่สุด (ไม่สนตัวพิมพ์ใหญ่เล็ก คืนเป็นตัวพิมพ์เล็ก)
+///
+/// ถ้าเสมอกันให้เลือกคำที่มาก่อนตามลำดับตัวอักษร, ไม่มีคำเลยคืน `None`
+#[must_use]
+pub fn most_common_word(text: &str) -> Option<String> {
+    todo!("06-1: คำที่พบบ่อยที่สุดใน {text:?}")
+}
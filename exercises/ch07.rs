@@ -0,0 +1,23 @@
+//! ⚠️ บทที่ 7: Error Handling
+
+use std::num::ParseIntError;
+
+/// 07-1 💯 อ่านเปอร์เซ็นต์ เช่น `"42%"` หรือ `"42"` ได้ `Ok(42)`
+///
+/// ค่านอกช่วง 0-100 หรืออ่านไม่ออกให้คืน `Err` พร้อมข้อความอธิบาย
+///
+/// # Errors
+/// เมื่อไม่ใช่ตัวเลข หรือมากกว่า 100
+pub fn parse_percentage(text: &str) -> Result<u8, String> {
+    todo!("07-1: อ่าน {text:?} เป็นเปอร์เซ็นต์")
+}
+
+/// 07-2 ➕ รวมตัวเลขที่คั่นด้วย `,` เช่น `"1, 2, 3"` ได้ `Ok(6)` - string ว่างได้ `Ok(0)`
+///
+/// ตัวไหนอ่านไม่ออกให้ส่ง `ParseIntError` ต่อด้วย `?`
+///
+/// # Errors
+/// เมื่อมีตัวที่ไม่ใช่จำนวนเต็ม
+pub fn sum_csv(text: &str) -> Result<i64, ParseIntError> {
+    todo!("07-2: รวมตัวเลขใน {text:?}")
+}
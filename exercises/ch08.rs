@@ -0,0 +1,7 @@
+//! 🔧 บทที่ 8: Generics
+
+/// 08-1 🏔️ ค่าที่มากที่สุดใน slice ของอะไรก็ได้ที่เทียบกันได้ - slice ว่างคืน `None`
+#[must_use]
+pub fn largest<T: PartialOrd + Copy>(items: &[T]) -> Option<T> {
+    todo!("08-1: หาค่ามากสุดจาก {} ตัว", items.len())
+}
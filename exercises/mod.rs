@@ -0,0 +1,16 @@
+//! ✍️ พื้นที่ทำแบบฝึกหัด - แก้ไฟล์ในโฟลเดอร์นี้ได้เต็มที่!
+//!
+//! แต่ละฟังก์ชันเริ่มต้นด้วย `todo!()` ให้เขียนคำตอบแทนที่ แล้วตรวจด้วย
+//!
+//! ```text
+//! cargo run -- exercise 07-2      # ตรวจข้อเดียว
+//! cargo run -- exercise --list    # ดูทุกข้อและข้อที่ผ่านแล้ว
+//! ```
+//!
+//! test case ที่ใช้ตรวจอยู่ใน `src/exercises/catalog.rs` - ลองทำเองก่อนแอบดูนะ 😉
+
+pub mod ch01;
+pub mod ch02;
+pub mod ch06;
+pub mod ch07;
+pub mod ch08;
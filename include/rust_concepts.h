@@ -0,0 +1,20 @@
+#ifndef RUST_CONCEPTS_H
+#define RUST_CONCEPTS_H
+
+/* สร้างด้วย cbindgen: cbindgen --config cbindgen.toml --output include/rust_concepts.h - อย่าแก้ด้วยมือ */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+int32_t rust_add(int32_t a, int32_t b);
+
+uint32_t rust_crc32(const uint8_t *data, size_t len);
+
+int32_t rust_function(int32_t x);
+
+size_t rust_string_length(const char *s);
+
+#endif  /* RUST_CONCEPTS_H */
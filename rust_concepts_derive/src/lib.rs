@@ -0,0 +1,417 @@
+//! # `rust_concepts_derive` - Procedural Macros ของบทที่ 12 🧙‍♂️🔧
+//!
+//! `macro_rules!` จับคู่ pattern ของ token เท่านั้น แต่ procedural macro คือฟังก์ชัน Rust จริงๆ
+//! ที่รับ `TokenStream` เข้ามาแล้วคืน `TokenStream` ใหม่ออกไป
+//! - 🔍 อ่านโค้ดเป็น AST ด้วย `syn`
+//! - 🏗️ ประกอบโค้ดใหม่ด้วย `quote!`
+//! - 🚨 รายงาน error ชี้ตรงจุดด้วย `syn::Error` (กลายเป็น `compile_error!` ที่ตำแหน่งนั้น)
+//!
+//! Macros ในนี้:
+//! - 🏗️ `#[derive(Builder)]` - สร้าง `<Name>Builder` พร้อม setter ทุก field และ `build()`
+//! - 🖨️ `#[derive(SimpleDisplay)]` - สร้าง `impl Display` จากชื่อ type/variant หรือ `#[display("...")]`
+//! - ⏱️ `#[timed]` - attribute macro ที่ห่อฟังก์ชัน (sync/async) ให้พิมพ์ชื่อและเวลาที่ใช้
+//!
+//! 📦 proc-macro crate export ได้แค่ macros จึงต้องแยกเป็น crate ของตัวเอง
+//! `rust_concepts::macros` re-export ไว้ให้แล้ว ดูตัวอย่างใน `12_macros/procedural_macros.rs`
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::ext::IdentExt;
+use syn::{
+    Attribute, Data, DataEnum, DeriveInput, Fields, GenericArgument, ItemFn, LitStr, PathArguments,
+    Type, parse_macro_input,
+};
+
+/// 🏗️ สร้าง builder ให้ struct ที่มี field แบบมีชื่อ
+///
+/// - field ชนิด `Option<T>` ไม่ต้องตั้งค่าก็ได้ (ได้ `None`) และ setter รับ `T` ตรงๆ
+/// - field ที่ติด `#[builder(default)]` ได้ `Default::default()` ถ้าไม่ได้ตั้งค่า
+/// - field อื่นต้องตั้งค่าก่อน `build()` ไม่งั้นได้ `Err("missing field `ชื่อ`")`
+///
+/// setter ทุกตัวรับ `impl Into<T>` จึงส่ง `&str` ให้ field `String` ได้เลย
+///
+/// ```
+/// use rust_concepts_derive::Builder;
+///
+/// #[derive(Builder, Debug)]
+/// struct Server {
+///     host: String,
+///     #[builder(default)]
+///     port: u16,
+///     tls: Option<bool>,
+/// }
+///
+/// let server = Server::builder().host("localhost").build().unwrap();
+/// assert_eq!((server.host.as_str(), server.port, server.tls), ("localhost", 0, None));
+/// assert_eq!(Server::builder().build().unwrap_err(), "missing field `host`");
+/// ```
+#[proc_macro_derive(Builder, attributes(builder))]
+pub fn derive_builder(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_builder(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// 🖨️ สร้าง `impl Display` แบบง่ายๆ
+///
+/// - struct ที่มี field: `Name { a: 1, b: 2 }` / tuple struct: `Name(1, 2)` / unit struct: `Name`
+/// - enum: ชื่อ variant (รองรับเฉพาะ unit variants)
+/// - `#[display("...")]` บน struct หรือ variant ใช้ format string ของเราเองแทน
+///   struct ที่มี field อ้างชื่อ field ใน format string ได้ตรงๆ เช่น `"{name} ({age})"`
+///   ส่วน tuple struct ใช้ตำแหน่ง `"{0}/{1}"`
+///
+/// ```
+/// use rust_concepts_derive::SimpleDisplay;
+///
+/// #[derive(SimpleDisplay)]
+/// #[display("{name} ({age})")]
+/// struct Person {
+///     name: String,
+///     age: u8,
+/// }
+///
+/// #[derive(SimpleDisplay)]
+/// enum Light {
+///     Red,
+///     #[display("🟢 go")]
+///     Green,
+/// }
+///
+/// let person = Person { name: "Ferris".into(), age: 9 };
+/// assert_eq!(person.to_string(), "Ferris (9)");
+/// assert_eq!(Light::Red.to_string(), "Red");
+/// assert_eq!(Light::Green.to_string(), "🟢 go");
+/// ```
+#[proc_macro_derive(SimpleDisplay, attributes(display))]
+pub fn derive_simple_display(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_simple_display(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// ⏱️ พิมพ์ชื่อฟังก์ชันและเวลาที่ใช้ทุกครั้งที่ฟังก์ชันจบ
+///
+/// ใช้ได้ทั้ง `fn` และ `async fn` (รวม generic) - ตัวจับเวลาเป็น guard ที่พิมพ์ตอนถูก drop
+/// จึงนับครบแม้ออกจากฟังก์ชันด้วย `return`, `?` หรือ panic
+/// สำหรับ `async fn` เวลาเริ่มนับตอน future ถูก poll ครั้งแรก และรวมเวลาที่รอ `.await` ด้วย
+/// ข้อความพิมพ์ด้วย `println!` ที่มองเห็นตรงจุดที่ใช้ macro (ใน `rust_concepts` คือตัวที่ผ่าน `output::Printer`)
+///
+/// ```
+/// use rust_concepts_derive::timed;
+///
+/// #[timed]
+/// fn largest<T: PartialOrd + Copy>(items: &[T]) -> Option<T> {
+///     let mut best = *items.first()?;
+///     for &item in items {
+///         if item > best {
+///             best = item;
+///         }
+///     }
+///     Some(best)
+/// }
+///
+/// assert_eq!(largest(&[3, 9, 4]), Some(9)); // พิมพ์ "⏱️ largest ใช้เวลา ..."
+/// assert_eq!(largest::<u8>(&[]), None);
+/// ```
+#[proc_macro_attribute]
+pub fn timed(args: TokenStream, item: TokenStream) -> TokenStream {
+    let args = TokenStream2::from(args);
+    let function = parse_macro_input!(item as ItemFn);
+    expand_timed(&args, function)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// field หนึ่งตัวของ builder แยกตามวิธีจัดการตอน `build()`
+enum FieldKind<'a> {
+    /// ต้องตั้งค่าก่อน build
+    Required,
+    /// ไม่ได้ตั้งค่าก็ใช้ `Default::default()`
+    Defaulted,
+    /// field เป็น `Option<T>` อยู่แล้ว - เก็บ `T` ที่อยู่ข้างใน
+    Optional(&'a Type),
+}
+
+fn expand_builder(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "Builder can only be derived for structs",
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "Builder needs a struct with named fields",
+        ));
+    };
+
+    let name = &input.ident;
+    let vis = &input.vis;
+    let builder = format_ident!("{}Builder", name);
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let mut storage = Vec::new();
+    let mut setters = Vec::new();
+    let mut build_fields = Vec::new();
+    let mut field_names = Vec::new();
+
+    for field in &fields.named {
+        let ident = field.ident.as_ref().expect("named fields have identifiers");
+        let ty = &field.ty;
+        let kind = if let Some(inner) = option_inner(ty) {
+            FieldKind::Optional(inner)
+        } else if builder_default(&field.attrs)? {
+            FieldKind::Defaulted
+        } else {
+            FieldKind::Required
+        };
+
+        let setter_ty = match kind {
+            FieldKind::Optional(inner) => inner,
+            FieldKind::Required | FieldKind::Defaulted => ty,
+        };
+        storage.push(quote! { #ident: ::core::option::Option<#setter_ty> });
+        setters.push(quote! {
+            #[must_use]
+            pub fn #ident(mut self, value: impl ::core::convert::Into<#setter_ty>) -> Self {
+                self.#ident = ::core::option::Option::Some(value.into());
+                self
+            }
+        });
+
+        let missing = format!("missing field `{}`", ident.unraw());
+        build_fields.push(match kind {
+            FieldKind::Required => quote! {
+                #ident: self.#ident.ok_or_else(|| ::std::string::String::from(#missing))?
+            },
+            FieldKind::Defaulted => quote! { #ident: self.#ident.unwrap_or_default() },
+            FieldKind::Optional(_) => quote! { #ident: self.#ident },
+        });
+        field_names.push(ident);
+    }
+
+    let builder_doc = format!("Builder ของ [`{name}`] ที่สร้างโดย `#[derive(Builder)]`");
+
+    Ok(quote! {
+        #[doc = #builder_doc]
+        #vis struct #builder #impl_generics #where_clause {
+            #(#storage,)*
+        }
+
+        impl #impl_generics ::core::default::Default for #builder #ty_generics #where_clause {
+            fn default() -> Self {
+                Self {
+                    #(#field_names: ::core::option::Option::None,)*
+                }
+            }
+        }
+
+        impl #impl_generics #builder #ty_generics #where_clause {
+            #(#setters)*
+
+            /// ประกอบค่าจริง - คืน `Err` ถ้ามี field ที่จำเป็นยังไม่ได้ตั้งค่า
+            pub fn build(self) -> ::core::result::Result<#name #ty_generics, ::std::string::String> {
+                ::core::result::Result::Ok(#name {
+                    #(#build_fields,)*
+                })
+            }
+        }
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// เริ่มสร้างค่าด้วย builder
+            #[must_use]
+            pub fn builder() -> #builder #ty_generics {
+                ::core::default::Default::default()
+            }
+        }
+    })
+}
+
+/// ถ้า `ty` เป็น `Option<T>` คืน `T`
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return None;
+    };
+    match arguments.args.first()? {
+        GenericArgument::Type(inner) if arguments.args.len() == 1 => Some(inner),
+        _ => None,
+    }
+}
+
+/// อ่าน `#[builder(default)]` - attribute อื่นใน `builder(...)` เป็น error
+fn builder_default(attrs: &[Attribute]) -> syn::Result<bool> {
+    let mut default = false;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("builder")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("default") {
+                default = true;
+                Ok(())
+            } else {
+                Err(meta.error("unknown builder attribute, expected `default`"))
+            }
+        })?;
+    }
+    Ok(default)
+}
+
+/// อ่าน `#[display("...")]` ถ้ามี
+fn display_format(attrs: &[Attribute]) -> syn::Result<Option<LitStr>> {
+    attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("display"))
+        .map(Attribute::parse_args::<LitStr>)
+        .transpose()
+}
+
+fn expand_simple_display(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let body = match &input.data {
+        Data::Struct(data) => struct_display(name, &data.fields, display_format(&input.attrs)?),
+        Data::Enum(data) => {
+            if let Some(attr) = input
+                .attrs
+                .iter()
+                .find(|attr| attr.path().is_ident("display"))
+            {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    "put #[display(\"...\")] on each variant, not on the enum",
+                ));
+            }
+            enum_display(data)?
+        }
+        Data::Union(data) => {
+            return Err(syn::Error::new_spanned(
+                data.union_token,
+                "SimpleDisplay cannot be derived for unions",
+            ));
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::core::fmt::Display for #name #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                #body
+            }
+        }
+    })
+}
+
+fn struct_display(name: &syn::Ident, fields: &Fields, format: Option<LitStr>) -> TokenStream2 {
+    match (fields, format) {
+        (Fields::Named(fields), Some(format)) => {
+            // ผูกทุก field เป็นตัวแปรท้องถิ่น format string จึงอ้างชื่อ field ได้ตรงๆ
+            let idents = fields.named.iter().map(|field| &field.ident);
+            quote! {
+                #[allow(unused_variables)]
+                let Self { #(#idents),* } = self;
+                ::core::write!(f, #format)
+            }
+        }
+        (Fields::Named(fields), None) => {
+            let idents: Vec<_> = fields
+                .named
+                .iter()
+                .filter_map(|field| field.ident.as_ref())
+                .collect();
+            let labels = idents
+                .iter()
+                .map(|ident| format!("{}: {{}}", ident.unraw()))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let format = format!("{name} {{{{ {labels} }}}}");
+            quote! { ::core::write!(f, #format, #(self.#idents),*) }
+        }
+        (Fields::Unnamed(fields), format) => {
+            let indices = (0..fields.unnamed.len()).map(syn::Index::from);
+            let format = format.map_or_else(
+                || {
+                    let holes = vec!["{}"; fields.unnamed.len()].join(", ");
+                    format!("{name}({holes})")
+                },
+                |format| format.value(),
+            );
+            quote! { ::core::write!(f, #format, #(self.#indices),*) }
+        }
+        (Fields::Unit, Some(format)) => quote! { ::core::write!(f, #format) },
+        (Fields::Unit, None) => {
+            let text = name.to_string();
+            quote! { f.write_str(#text) }
+        }
+    }
+}
+
+fn enum_display(data: &DataEnum) -> syn::Result<TokenStream2> {
+    let mut arms = Vec::new();
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new_spanned(
+                &variant.fields,
+                "SimpleDisplay only supports unit variants",
+            ));
+        }
+        let ident = &variant.ident;
+        let arm = display_format(&variant.attrs)?.map_or_else(
+            || {
+                let text = ident.to_string();
+                quote! { Self::#ident => f.write_str(#text) }
+            },
+            |format| quote! { Self::#ident => ::core::write!(f, #format) },
+        );
+        arms.push(arm);
+    }
+    Ok(quote! {
+        match *self {
+            #(#arms,)*
+        }
+    })
+}
+
+fn expand_timed(args: &TokenStream2, mut function: ItemFn) -> syn::Result<TokenStream2> {
+    if !args.is_empty() {
+        return Err(syn::Error::new_spanned(args, "#[timed] takes no arguments"));
+    }
+    if let Some(constness) = &function.sig.constness {
+        return Err(syn::Error::new_spanned(
+            constness,
+            "#[timed] cannot be used on `const fn` (Instant::now is not const)",
+        ));
+    }
+
+    let name = function.sig.ident.unraw().to_string();
+    // mixed_site: ชื่อ guard ชนกับตัวแปรของผู้ใช้ไม่ได้ (hygiene แบบเดียวกับ macro_rules!)
+    let guard = syn::Ident::new("timed_guard", Span::mixed_site());
+    let stmts = &function.block.stmts;
+    let block = quote! {
+        {
+            struct TimedGuard(&'static str, ::std::time::Instant);
+
+            impl ::core::ops::Drop for TimedGuard {
+                fn drop(&mut self) {
+                    // ไม่ระบุ path: ใน rust_concepts จะได้ `println!` ของ crate ที่ส่งต่อให้ Printer
+                    // (จึงเงียบตอน `--quiet`) ส่วน crate อื่นได้ของ std ตามปกติ
+                    println!("⏱️ {} ใช้เวลา {:?}", self.0, self.1.elapsed());
+                }
+            }
+
+            let #guard = TimedGuard(#name, ::std::time::Instant::now());
+            #(#stmts)*
+        }
+    };
+    *function.block = syn::parse2(block)?;
+    Ok(quote! { #function })
+}
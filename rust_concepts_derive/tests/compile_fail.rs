@@ -0,0 +1,10 @@
+//! 🚨 Compile-fail tests - ใช้ derive ผิดวิธีต้องได้ error ที่อ่านรู้เรื่องและชี้ตรงจุด
+//!
+//! แต่ละไฟล์ใน `tests/ui/` ต้องคอมไพล์ไม่ผ่าน และข้อความต้องตรงกับ `.stderr` คู่กัน
+//! ถ้าตั้งใจเปลี่ยนข้อความ error ให้รัน `TRYBUILD=overwrite cargo test -p rust_concepts_derive`
+
+#[test]
+fn ui() {
+    let tests = trybuild::TestCases::new();
+    tests.compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,91 @@
+//! ⏱️ Expansion tests ของ `#[timed]` - โค้ดที่ถูกห่อต้องคอมไพล์ได้และทำงานเหมือนเดิมทุกแบบ
+//! (generic, where clause, method, `?`, `return` กลางทาง, async)
+
+use std::fmt::Display;
+use std::future::Future;
+use std::pin::pin;
+use std::task::{Context, Poll, Waker};
+
+use rust_concepts_derive::timed;
+
+#[timed]
+fn describe<T: Display>(value: T) -> String {
+    format!("<{value}>")
+}
+
+#[timed]
+fn sum_all<I>(items: I) -> i64
+where
+    I: IntoIterator,
+    I::Item: Into<i64>,
+{
+    items.into_iter().map(Into::into).sum()
+}
+
+#[timed]
+fn parse_pair(text: &str) -> Result<(i32, i32), std::num::ParseIntError> {
+    let Some((left, right)) = text.split_once(',') else {
+        return Ok((0, 0));
+    };
+    Ok((left.trim().parse()?, right.trim().parse()?))
+}
+
+#[timed]
+fn first_word<'a>(text: &'a str) -> &'a str {
+    text.split_whitespace().next().unwrap_or_default()
+}
+
+struct Counter {
+    hits: u32,
+}
+
+impl Counter {
+    #[timed]
+    fn hit(&mut self) -> u32 {
+        self.hits += 1;
+        self.hits
+    }
+}
+
+#[timed]
+async fn add_async<T: std::ops::Add<Output = T>>(left: T, right: T) -> T {
+    std::future::ready(()).await;
+    left + right
+}
+
+/// executor จิ๋วพอสำหรับ future ที่ไม่เคยรอจริง
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut context = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+    }
+}
+
+#[test]
+fn test_timed_generic_functions_keep_behaviour() {
+    assert_eq!(describe(42), "<42>");
+    assert_eq!(describe("hi"), "<hi>");
+    assert_eq!(sum_all([1_i32, 2, 3]), 6);
+    assert_eq!(sum_all(vec![10_u8, 20]), 30);
+    assert_eq!(first_word("hello timed world"), "hello");
+}
+
+#[test]
+fn test_timed_preserves_early_return_and_question_mark() {
+    assert_eq!(parse_pair("3, 4"), Ok((3, 4)));
+    assert_eq!(parse_pair("no comma"), Ok((0, 0)));
+    assert!(parse_pair("x, 1").is_err());
+}
+
+#[test]
+fn test_timed_methods_and_async_functions() {
+    let mut counter = Counter { hits: 0 };
+    counter.hit();
+    assert_eq!(counter.hit(), 2);
+
+    assert_eq!(block_on(add_async(2, 3)), 5);
+    assert!((block_on(add_async(0.5_f64, 0.25)) - 0.75).abs() < f64::EPSILON);
+}
@@ -0,0 +1,9 @@
+use rust_concepts_derive::Builder;
+
+#[derive(Builder)]
+enum Shape {
+    Circle,
+    Square,
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use rust_concepts_derive::Builder;
+
+#[derive(Builder)]
+struct Point(i32, i32);
+
+fn main() {}
@@ -0,0 +1,9 @@
+use rust_concepts_derive::Builder;
+
+#[derive(Builder)]
+struct Config {
+    #[builder(optional)]
+    name: String,
+}
+
+fn main() {}
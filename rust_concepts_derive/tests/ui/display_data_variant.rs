@@ -0,0 +1,9 @@
+use rust_concepts_derive::SimpleDisplay;
+
+#[derive(SimpleDisplay)]
+enum Message {
+    Quit,
+    Move { x: i32, y: i32 },
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+use rust_concepts_derive::SimpleDisplay;
+
+#[derive(SimpleDisplay)]
+#[display(42)]
+struct Answer;
+
+fn main() {}
@@ -0,0 +1,9 @@
+use rust_concepts_derive::SimpleDisplay;
+
+#[derive(SimpleDisplay)]
+#[display("{name} is {age}")]
+struct Person {
+    name: String,
+}
+
+fn main() {}
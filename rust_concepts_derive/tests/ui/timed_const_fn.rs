@@ -0,0 +1,10 @@
+use rust_concepts_derive::timed;
+
+#[timed]
+const fn answer() -> u32 {
+    42
+}
+
+fn main() {
+    let _ = answer();
+}
@@ -0,0 +1,8 @@
+use rust_concepts_derive::timed;
+
+#[timed(verbose)]
+fn work() {}
+
+fn main() {
+    work();
+}
@@ -3,6 +3,8 @@
 //! โมดูลนี้รวบรวมการเรียนรู้พื้นฐานของ Rust
 //! แบ่งออกเป็นหมวดหมู่ตามประเภทของเนื้อหา
 
+use crate::i18n::t;
+
 // Module declarations
 mod comments;
 mod compound_types;
@@ -36,28 +38,28 @@ pub use variables::learn_variables;
 
 /// ฟังก์ชันสำหรับรันตัวอย่างพื้นฐาน (เรียกจาก main.rs)
 pub fn run_basics_examples() {
-    println!("   📝 Variables และ Mutability");
+    println!("   📝 {}", t!("basics.variables"));
     learn_variables();
 
-    println!("\n   🔢 Data Types");
+    println!("\n   🔢 {}", t!("basics.data_types"));
     learn_data_types();
 
-    println!("\n   📦 Compound Types (Array, Tuple)");
+    println!("\n   📦 {}", t!("basics.compound_types"));
     learn_compound_types();
 
-    println!("\n   🔒 Constants และ Shadowing");
+    println!("\n   🔒 {}", t!("basics.constants_shadowing"));
     learn_constants_and_shadowing();
 
-    println!("\n   🔄 Type Conversion");
+    println!("\n   🔄 {}", t!("basics.type_conversion"));
     learn_type_conversion();
 
-    println!("\n   ⚡ Operators");
+    println!("\n   ⚡ {}", t!("basics.operators"));
     learn_operators();
 
-    println!("\n   💬 Comments และ Documentation");
+    println!("\n   💬 {}", t!("basics.comments"));
     learn_comments_and_documentation();
 
-    println!("\n   💪 แบบฝึกหัดพื้นฐาน");
+    println!("\n   💪 {}", t!("basics.practice"));
     practice_basics();
 }
 
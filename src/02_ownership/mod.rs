@@ -3,26 +3,33 @@
 //! โมดูลนี้สอนเกี่ยวกับระบบ Ownership และ Borrowing ใน Rust
 //! ซึ่งเป็นหัวใจสำคัญของภาษา Rust
 
+use crate::i18n::t;
+
 mod borrowing;
 mod ownership_basics;
 mod practice_ownership;
+pub mod visualizer;
 
 pub use ownership_basics::learn_ownership;
 pub use borrowing::learn_borrowing;
 pub use practice_ownership::practice_ownership_and_borrowing;
+pub use visualizer::{BorrowTracker, Traced, visualize_ownership};
 
 /// ฟังก์ชันสำหรับรันตัวอย่าง ownership (เรียกจาก main.rs)
 pub fn run_ownership_examples() {
-    println!("   🏠 Ownership Basics");
+    println!("   🏠 {}", t!("ownership.basics"));
     learn_ownership();
 
-    println!("\n   🤝 Borrowing และ References");
+    println!("\n   🤝 {}", t!("ownership.borrowing"));
     learn_borrowing();
 
-    println!("\n   ✂️ Slices");
+    println!("\n   ✂️ {}", t!("ownership.slices"));
     // เพิ่มการเรียกใช้ slice examples ถ้ามี
-    println!("      - String slices และ array slices");
+    println!("      - {}", t!("ownership.slices_note"));
+
+    println!("\n   🔬 {}", t!("ownership.visualizer"));
+    visualize_ownership();
 
-    println!("\n   🎯 Practice Examples");
+    println!("\n   🎯 {}", t!("ownership.practice"));
     practice_ownership_and_borrowing();
 }
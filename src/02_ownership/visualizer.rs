@@ -0,0 +1,481 @@
+//! 🔬 Ownership Visualizer - ทำให้ move/clone/borrow/drop "มองเห็นได้"
+//!
+//! ปกติ compiler จัดการ ownership เงียบๆ ตอน compile - ตอนรันเราไม่เห็นว่าค่าย้ายไปไหน
+//! `Traced<T>` ห่อค่าไว้แล้วจด event ทุกครั้งที่ค่าถูกสร้าง, ย้าย, ทำสำเนา, ถูกยืม หรือถูก drop
+//! ลงใน `BorrowTracker` จากนั้น `timeline()` วาดออกมาเป็นแผนภาพทีละขั้น
+//!
+//! - 📦 move ในภาษาจริงไม่มี hook ให้ดัก จึงต้องเขียนให้ชัดด้วย `move_to("s2")`
+//! - 📍 event จำบรรทัดที่เรียกไว้ด้วย `#[track_caller]` (ยกเว้น drop ที่ compiler เรียกเอง)
+
+use std::cell::RefCell;
+use std::fmt::{self, Write as _};
+use std::ops::{Deref, DerefMut};
+use std::panic::Location;
+use std::rc::Rc;
+
+/// 🏷️ ชนิดของเหตุการณ์
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EventKind {
+    /// ค่าใหม่ถูกสร้างและผูกกับชื่อ
+    Created,
+    /// ownership ย้ายไปที่ `to` - ชื่อเดิมใช้ต่อไม่ได้แล้ว
+    Moved { to: String },
+    /// ทำสำเนาเป็นค่าใหม่ชื่อ `into`
+    Cloned { into: String },
+    /// `by` ยืมแบบอ่านอย่างเดียว (`&`)
+    Borrowed { by: String },
+    /// `by` ยืมแบบแก้ไขได้ (`&mut`)
+    BorrowedMut { by: String },
+    /// `by` เลิกยืม (reference ออกจาก scope)
+    Returned { by: String },
+    /// owner ออกจาก scope - หน่วยความจำถูกคืน
+    Dropped,
+}
+
+/// 📝 เหตุการณ์หนึ่งครั้งของค่าชื่อ `value`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Event {
+    pub value: String,
+    pub kind: EventKind,
+    /// บรรทัดในโค้ดที่ทำให้เกิดเหตุการณ์ - `None` สำหรับ drop/คืนการยืม
+    pub location: Option<&'static Location<'static>>,
+}
+
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = &self.value;
+        match &self.kind {
+            EventKind::Created => write!(f, "🆕 สร้าง {value}"),
+            EventKind::Moved { to } => write!(f, "📦 move {value} → {to}"),
+            EventKind::Cloned { into } => write!(f, "🧬 clone {value} → {into}"),
+            EventKind::Borrowed { by } => write!(f, "👀 {by} = &{value}"),
+            EventKind::BorrowedMut { by } => write!(f, "✏️ {by} = &mut {value}"),
+            EventKind::Returned { by } => write!(f, "↩️ {by} คืน {value}"),
+            EventKind::Dropped => write!(f, "🗑️ drop {value}"),
+        }
+    }
+}
+
+/// 📒 สมุดจด event ที่ `Traced` ทุกตัวจากตัวติดตามเดียวกันเขียนร่วมกัน
+///
+/// clone แล้วได้สมุดเล่มเดิม (`Rc`) - ใช้ได้ใน thread เดียว เหมือนตัวอย่างในบทนี้
+#[derive(Debug, Clone, Default)]
+pub struct BorrowTracker {
+    events: Rc<RefCell<Vec<Event>>>,
+}
+
+impl BorrowTracker {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// เริ่มติดตามค่า `value` ในชื่อ `label`
+    #[must_use]
+    #[track_caller]
+    pub fn track<T>(&self, label: &str, value: T) -> Traced<T> {
+        self.record(label, EventKind::Created, Some(Location::caller()));
+        Traced {
+            value: Some(value),
+            label: label.to_string(),
+            tracker: self.clone(),
+        }
+    }
+
+    /// event ทั้งหมดตามลำดับ
+    #[must_use]
+    pub fn events(&self) -> Vec<Event> {
+        self.events.borrow().clone()
+    }
+
+    /// ลบ event ที่จดไว้ (ใช้ตัวติดตามเดิมกับตัวอย่างถัดไป)
+    pub fn clear(&self) {
+        self.events.borrow_mut().clear();
+    }
+
+    fn record(&self, value: &str, kind: EventKind, location: Option<&'static Location<'static>>) {
+        self.events.borrow_mut().push(Event {
+            value: value.to_string(),
+            kind,
+            location,
+        });
+    }
+
+    /// 🧭 แผนภาพ timeline - หนึ่งคอลัมน์ต่อหนึ่งชื่อ อ่านจากบนลงล่าง
+    ///
+    /// ```text
+    ///   #  s1     s2
+    ///   1  ●             🆕 สร้าง s1  (main.rs:12)
+    ///   2  ○ ──▶  ●      📦 move s1 → s2  (main.rs:13)
+    ///   3         ✗      🗑️ drop s2
+    /// ```
+    ///
+    /// `●` เป็นเจ้าของ, `│` ยังมีชีวิต, `&`/`&mut` กำลังถูกยืม, `○` ถูก move ออก, `✗` ถูก drop
+    #[must_use]
+    pub fn timeline(&self) -> String {
+        let events = self.events.borrow();
+        let mut lanes: Vec<&str> = Vec::new();
+        for event in events.iter() {
+            let new_lane = match &event.kind {
+                EventKind::Moved { to } => Some(to.as_str()),
+                EventKind::Cloned { into } => Some(into.as_str()),
+                _ => None,
+            };
+            for name in std::iter::once(event.value.as_str()).chain(new_lane) {
+                if !lanes.contains(&name) {
+                    lanes.push(name);
+                }
+            }
+        }
+        // กว้างพอสำหรับชื่อและ "○ ──▶" (5 ตัวอักษร) บวกช่องว่างอีกหนึ่ง
+        let widths: Vec<usize> = lanes
+            .iter()
+            .map(|lane| lane.chars().count().max(5) + 1)
+            .collect();
+        let index = |name: &str| lanes.iter().position(|lane| *lane == name);
+
+        let mut out = String::from("🧭 Ownership timeline\n");
+        let _ = write!(out, "{:>3} ", "#");
+        for (lane, width) in lanes.iter().zip(&widths) {
+            let _ = write!(out, " {lane:<width$}");
+        }
+        out.push('\n');
+
+        // สถานะของแต่ละคอลัมน์: มีชีวิตอยู่ไหม และตอนนี้ถูกยืมกี่ตัว
+        let mut alive = vec![false; lanes.len()];
+        let mut borrows = vec![0_usize; lanes.len()];
+        for (step, event) in events.iter().enumerate() {
+            let Some(owner) = index(&event.value) else {
+                continue;
+            };
+            let mut cells: Vec<String> = (0..lanes.len())
+                .map(|lane| {
+                    if !alive[lane] {
+                        String::new()
+                    } else if borrows[lane] > 0 {
+                        "&".to_string()
+                    } else {
+                        "│".to_string()
+                    }
+                })
+                .collect();
+            match &event.kind {
+                EventKind::Created => {
+                    alive[owner] = true;
+                    cells[owner] = "●".to_string();
+                }
+                EventKind::Moved { to } => {
+                    alive[owner] = false;
+                    cells[owner] = "○ ──▶".to_string();
+                    if let Some(target) = index(to) {
+                        alive[target] = true;
+                        cells[target] = "●".to_string();
+                    }
+                }
+                EventKind::Cloned { into } => {
+                    cells[owner] = "◐ ──▶".to_string();
+                    if let Some(target) = index(into) {
+                        alive[target] = true;
+                        cells[target] = "●".to_string();
+                    }
+                }
+                EventKind::Borrowed { .. } => {
+                    borrows[owner] += 1;
+                    cells[owner] = "&".to_string();
+                }
+                EventKind::BorrowedMut { .. } => {
+                    borrows[owner] += 1;
+                    cells[owner] = "&mut".to_string();
+                }
+                EventKind::Returned { .. } => {
+                    borrows[owner] = borrows[owner].saturating_sub(1);
+                    cells[owner] = "↩".to_string();
+                }
+                EventKind::Dropped => {
+                    alive[owner] = false;
+                    cells[owner] = "✗".to_string();
+                }
+            }
+
+            let _ = write!(out, "{:>3} ", step + 1);
+            for (cell, width) in cells.iter().zip(&widths) {
+                // นับความกว้างเป็นตัวอักษร ไม่ใช่ byte (`●` ยาว 3 byte)
+                let padding = width.saturating_sub(cell.chars().count());
+                let _ = write!(out, " {cell}{}", " ".repeat(padding));
+            }
+            let _ = write!(out, " {event}");
+            if let Some(location) = event.location {
+                let file = location
+                    .file()
+                    .rsplit(['/', '\\'])
+                    .next()
+                    .unwrap_or_default();
+                let _ = write!(out, "  ({file}:{})", location.line());
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+/// 📦 ค่าที่ถูกติดตาม - ใช้ได้เหมือน `T` ผ่าน `Deref`
+#[derive(Debug)]
+pub struct Traced<T> {
+    /// `None` หลังค่าถูก move ออกไปแล้ว (กัน `Drop` จดซ้ำ)
+    value: Option<T>,
+    label: String,
+    tracker: BorrowTracker,
+}
+
+impl<T> Traced<T> {
+    /// ชื่อที่เป็นเจ้าของค่านี้อยู่
+    #[must_use]
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// ย้าย ownership ไปชื่อ `to` - เหมือน `let s2 = s1;` หรือส่งค่าเข้า function
+    #[must_use]
+    #[track_caller]
+    pub fn move_to(mut self, to: &str) -> Self {
+        let moved = EventKind::Moved { to: to.to_string() };
+        self.tracker
+            .record(&self.label, moved, Some(Location::caller()));
+        Self {
+            value: self.value.take(),
+            label: to.to_string(),
+            tracker: self.tracker.clone(),
+        }
+    }
+
+    /// ยืมแบบอ่านอย่างเดียวในชื่อ `by` - คืนเมื่อ reference ถูก drop
+    #[must_use]
+    #[track_caller]
+    pub fn borrow_as(&self, by: &str) -> TracedRef<'_, T> {
+        let borrowed = EventKind::Borrowed { by: by.to_string() };
+        self.tracker
+            .record(&self.label, borrowed, Some(Location::caller()));
+        TracedRef {
+            owner: self,
+            by: by.to_string(),
+        }
+    }
+
+    /// ยืมแบบแก้ไขได้ในชื่อ `by` - borrow checker ยังทำงานจริง ยืมซ้อน `&mut` ไม่ได้เหมือนเดิม
+    #[must_use]
+    #[track_caller]
+    pub fn borrow_mut_as(&mut self, by: &str) -> TracedMut<'_, T> {
+        let borrowed = EventKind::BorrowedMut { by: by.to_string() };
+        self.tracker
+            .record(&self.label, borrowed, Some(Location::caller()));
+        TracedMut {
+            owner: self,
+            by: by.to_string(),
+        }
+    }
+}
+
+impl<T: Clone> Traced<T> {
+    /// ทำสำเนาเป็นค่าใหม่ชื่อ `into` - ค่าเดิมยังใช้ต่อได้
+    #[must_use]
+    #[track_caller]
+    pub fn clone_as(&self, into: &str) -> Self {
+        let cloned = EventKind::Cloned {
+            into: into.to_string(),
+        };
+        self.tracker
+            .record(&self.label, cloned, Some(Location::caller()));
+        Self {
+            value: self.value.clone(),
+            label: into.to_string(),
+            tracker: self.tracker.clone(),
+        }
+    }
+}
+
+impl<T> Deref for Traced<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.value.as_ref().expect("Traced ที่ยังเข้าถึงได้ต้องมีค่าเสมอ")
+    }
+}
+
+impl<T> DerefMut for Traced<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        self.value.as_mut().expect("Traced ที่ยังเข้าถึงได้ต้องมีค่าเสมอ")
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Traced<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+impl<T> Drop for Traced<T> {
+    fn drop(&mut self) {
+        if self.value.is_some() {
+            self.tracker.record(&self.label, EventKind::Dropped, None);
+        }
+    }
+}
+
+/// 👀 reference แบบ `&` ที่จดตอนคืน
+#[derive(Debug)]
+pub struct TracedRef<'a, T> {
+    owner: &'a Traced<T>,
+    by: String,
+}
+
+impl<T> Deref for TracedRef<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.owner
+    }
+}
+
+impl<T> Drop for TracedRef<'_, T> {
+    fn drop(&mut self) {
+        let returned = EventKind::Returned {
+            by: std::mem::take(&mut self.by),
+        };
+        self.owner.tracker.record(&self.owner.label, returned, None);
+    }
+}
+
+/// ✏️ reference แบบ `&mut` ที่จดตอนคืน
+#[derive(Debug)]
+pub struct TracedMut<'a, T> {
+    owner: &'a mut Traced<T>,
+    by: String,
+}
+
+impl<T> Deref for TracedMut<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.owner
+    }
+}
+
+impl<T> DerefMut for TracedMut<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        self.owner
+    }
+}
+
+impl<T> Drop for TracedMut<'_, T> {
+    fn drop(&mut self) {
+        let returned = EventKind::Returned {
+            by: std::mem::take(&mut self.by),
+        };
+        self.owner.tracker.record(&self.owner.label, returned, None);
+    }
+}
+
+/// ฟังก์ชันสำหรับสอนด้วยภาพ: รันตัวอย่างคลาสสิกของบทนี้แล้วพิมพ์ timeline
+pub fn visualize_ownership() {
+    println!("🔬 === Ownership Visualizer: ดูค่าเดินทางทีละขั้น! === 🔬");
+    let tracker = BorrowTracker::new();
+    {
+        let s1 = tracker.track("s1", String::from("สวัสดี"));
+        let s2 = s1.move_to("s2"); // let s2 = s1;
+        let mut s3 = s2.clone_as("s3"); // let s3 = s2.clone();
+        {
+            let r1 = s2.borrow_as("r1");
+            let r2 = s2.borrow_as("r2");
+            println!("👀 r1: {}, r2: {} (ยืมพร้อมกันได้หลายตัว)", *r1, *r2);
+        }
+        let mut r3 = s3.borrow_mut_as("r3");
+        r3.push_str(" ชาวโลก");
+        drop(r3);
+        println!("✅ s2: {s2}, s3: {s3}");
+        let _some_string = s3.move_to("some_string"); // take_ownership(s3)
+    } // ทุกค่าออกจาก scope ตรงนี้ - สังเกตลำดับ drop ที่กลับด้านกับลำดับประกาศ
+    println!("{}", tracker.timeline());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `(ชื่อ, event)` แบบสั้นสำหรับเทียบ
+    fn kinds(tracker: &BorrowTracker) -> Vec<String> {
+        tracker.events().iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn test_move_then_drop_only_new_owner() {
+        let tracker = BorrowTracker::new();
+        {
+            let s1 = tracker.track("s1", String::from("hello"));
+            let s2 = s1.move_to("s2");
+            assert_eq!(s2.len(), 5);
+        }
+        assert_eq!(
+            kinds(&tracker),
+            ["🆕 สร้าง s1", "📦 move s1 → s2", "🗑️ drop s2"]
+        );
+        let events = tracker.events();
+        assert!(
+            events[0]
+                .location
+                .is_some_and(|at| at.file().ends_with("visualizer.rs"))
+        );
+        assert_eq!(events[2].location, None);
+    }
+
+    #[test]
+    fn test_clone_and_borrows() {
+        let tracker = BorrowTracker::new();
+        {
+            let mut s = tracker.track("s", vec![1, 2]);
+            let copy = s.clone_as("copy");
+            {
+                let r1 = s.borrow_as("r1");
+                let r2 = s.borrow_as("r2");
+                assert_eq!(r1.len() + r2.len(), 4);
+            }
+            s.borrow_mut_as("r3").push(3);
+            assert_eq!((s.len(), copy.len()), (3, 2));
+        }
+        assert_eq!(
+            kinds(&tracker),
+            [
+                "🆕 สร้าง s",
+                "🧬 clone s → copy",
+                "👀 r1 = &s",
+                "👀 r2 = &s",
+                "↩️ r2 คืน s",
+                "↩️ r1 คืน s",
+                "✏️ r3 = &mut s",
+                "↩️ r3 คืน s",
+                "🗑️ drop copy",
+                "🗑️ drop s",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_timeline_lanes() {
+        let tracker = BorrowTracker::new();
+        {
+            let a = tracker.track("a", 1);
+            let b = a.move_to("b");
+            let _r = b.borrow_as("r");
+        }
+        let timeline = tracker.timeline();
+        let rows: Vec<&str> = timeline.lines().collect();
+        assert_eq!(rows[1], "  #  a      b     ");
+        assert!(rows[2].starts_with("  1  ●             🆕 สร้าง a  (visualizer.rs:"));
+        assert!(rows[3].starts_with("  2  ○ ──▶  ●      📦 move a → b"));
+        assert!(rows[4].starts_with("  3         &      👀 r = &b"));
+        assert_eq!(rows[5], "  4         ↩      ↩️ r คืน b");
+        assert_eq!(rows[6], "  5         ✗      🗑️ drop b");
+
+        tracker.clear();
+        assert!(tracker.events().is_empty());
+    }
+}
@@ -0,0 +1,94 @@
+//! Memory Layout ของ Structs และ Enums - struct ของเราใหญ่แค่ไหนในหน่วยความจำ? 📐
+//!
+//! ใช้ `crate::layout` ส่องดู size, align, padding และ niche ของชนิดที่เราสร้างเอง
+
+use crate::layout::{TypeLayout, inspect, layout_of, niche_table};
+
+/// ฟิลด์เรียงตามที่เขียน (แบบภาษา C) - ฟิลด์เล็กสลับกับฟิลด์ใหญ่ทำให้เกิด padding 🧱
+#[repr(C)]
+struct CStudent {
+    grade: u8,
+    id: u32,
+    active: bool,
+}
+
+/// ฟิลด์เดียวกัน แต่ให้ Rust เรียงเอง - compiler จัดฟิลด์ใหญ่ไว้ก่อน 🧩
+struct RustStudent {
+    grade: u8,
+    id: u32,
+    active: bool,
+}
+
+/// enum ที่มีข้อมูล - ขนาดเท่า variant ที่ใหญ่ที่สุด + tag 🏷️
+#[allow(dead_code)] // reason: ใช้ดูขนาดเท่านั้น ไม่ได้สร้างค่า
+enum Shape {
+    Circle(f32),
+    Rectangle { width: f32, height: f32 },
+    Empty,
+}
+
+fn c_student_layout() -> TypeLayout {
+    layout_of!(CStudent {
+        grade: u8,
+        id: u32,
+        active: bool
+    })
+}
+
+fn rust_student_layout() -> TypeLayout {
+    layout_of!(RustStudent {
+        grade: u8,
+        id: u32,
+        active: bool
+    })
+}
+
+/// ฟังก์ชันสำหรับสอนเรื่อง memory layout ของ struct และ enum
+pub fn learn_memory_layout() {
+    println!("📐 === Memory Layout: struct ของเรากินที่เท่าไร? === 📐");
+
+    println!("\n🧱 repr(C) - เรียงตามที่เขียน (ตัวอักษรคือฟิลด์, ░ คือ padding):");
+    print!("{}", c_student_layout());
+
+    println!("\n🧩 repr(Rust) - compiler เรียงใหม่ให้ประหยัดที่:");
+    print!("{}", rust_student_layout());
+
+    println!("\n🏷️ enum Shape - ขนาด = variant ที่ใหญ่ที่สุด + tag:");
+    print!("{}", inspect::<Shape>());
+
+    println!(
+        "\n🔍 Niche: Option<T> ฟรีเมื่อ T มีค่าที่เป็นไปไม่ได้ (null, false/true เกิน, char เกิน U+10FFFF)"
+    );
+    print!(
+        "{}",
+        niche_table(&[
+            inspect::<&CStudent>(),
+            inspect::<Box<RustStudent>>(),
+            inspect::<bool>(),
+            inspect::<char>(),
+            inspect::<u32>(),
+            inspect::<Shape>(),
+        ])
+    );
+    println!("💡 เรียงฟิลด์จากใหญ่ไปเล็กเองก็ได้ผลเหมือน repr(Rust) ถ้าจำเป็นต้องใช้ repr(C)");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_student_layouts() {
+        let c = c_student_layout();
+        assert_eq!((c.size, c.padding_bytes()), (12, Some(6)));
+        assert_eq!(c.byte_map(), "A░░░|BBBB|C░░░");
+
+        let rust = rust_student_layout();
+        assert_eq!((rust.size, rust.padding_bytes()), (8, Some(2)));
+
+        assert!(inspect::<&CStudent>().has_niche());
+        assert!(!inspect::<u32>().has_niche());
+        // Shape มี tag ที่ใช้ไม่ครบทุกค่า - Option<Shape> ใช้ค่าที่เหลือแทน None ได้
+        assert!(inspect::<Shape>().has_niche());
+    }
+}
@@ -6,11 +6,13 @@
 
 // Module declarations (ประกาศโมดูลสุดเท่! 📦)
 mod enums;                    // โลกของ Enums! 🌍
+mod memory_layout;            // ส่องหน่วยความจำ! 📐
 mod practice_structs_enums;   // สนามฝึกซ้อม! 🏟️
 mod structs;                  // อาณาจักร Structs! 🏰
 
 // Re-exports (ส่งออกความรู้! 📤)
 pub use enums::*;                    // ส่งออก Enums! 📋
+pub use memory_layout::*;            // ส่งออก Memory Layout! 📐
 pub use practice_structs_enums::*;   // ส่งออกแบบฝึกหัด! 🎯
 pub use structs::*;                  // ส่งออก Structs! 🏗️
 
@@ -31,6 +33,9 @@ pub fn run_structs_enums_examples() {
     // เรียกใช้ฟังก์ชันที่เกี่ยวข้องกับ pattern matching ถ้ามี
     println!("      - match expressions และ if let (นักสืบแห่งโค้ด! 🕵️‍♂️)");
 
+    println!("\n   📐 Memory Layout: struct และ enum ใหญ่แค่ไหน?");
+    learn_memory_layout();  // ส่องหน่วยความจำ! 🔬
+
     println!("\n   🎮 Practice Examples: สนามเด็กเล่นโปรแกรมเมอร์!");
     practice_structs_and_enums();  // ฝึกฝนกันเถอะ! 💪
 }
@@ -0,0 +1,496 @@
+//! Graph - node กับ edge แบบ adjacency list พร้อม algorithm พื้นฐานที่ใช้กันบ่อยที่สุด 🕸️🧭
+//!
+//! `Graph<N, E>` เก็บข้อมูล `N` ไว้ที่ node และ `E` ไว้ที่ edge (ระยะทาง, น้ำหนัก, ป้ายชื่อ ...)
+//! node อ้างถึงด้วย `NodeId` (index ใน `Vec`) จึงไม่ต้องใช้ `Rc<RefCell<_>>` ชี้กันไปมา
+//! - 🌊 `bfs` / 🏊 `dfs` - iterator เดินทั่ว graph จาก node เริ่มต้น
+//! - 🌀 `find_cycle` - หาวงจรและคืนเส้นทางของวงจรนั้น
+//! - 📋 `topological_sort` - เรียงให้ทุก edge ชี้ไปข้างหน้า (ลำดับ build, dependency ของ resource)
+//! - 🧭 `shortest_path` - Dijkstra สำหรับ edge ที่น้ำหนักไม่ติดลบ
+//!
+//! edge มีทิศเสมอ - graph ไม่มีทิศใช้ `add_undirected_edge` ใส่ทั้งไปและกลับ
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+use std::fmt;
+
+/// ตำแหน่งของ node ใน graph - ได้จาก `add_node`
+pub type NodeId = usize;
+
+/// 🌀 graph มีวงจร - เก็บเส้นทางตามทิศของ edge โดย node แรกซ้ำที่ท้าย เช่น `[a, b, a]`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CycleError(pub Vec<NodeId>);
+
+impl fmt::Display for CycleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let path: Vec<String> = self.0.iter().map(ToString::to_string).collect();
+        write!(f, "graph has a cycle: {}", path.join(" → "))
+    }
+}
+
+impl std::error::Error for CycleError {}
+
+/// 🕸️ directed graph แบบ adjacency list - แต่ละ node จำ edge ที่ออกจากตัวเองตามลำดับที่ใส่
+#[derive(Debug, Clone)]
+pub struct Graph<N, E> {
+    nodes: Vec<N>,
+    edges: Vec<Vec<(NodeId, E)>>,
+}
+
+impl<N, E> Default for Graph<N, E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N, E> Graph<N, E> {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            edges: Vec::new(),
+        }
+    }
+
+    pub fn add_node(&mut self, value: N) -> NodeId {
+        self.nodes.push(value);
+        self.edges.push(Vec::new());
+        self.nodes.len() - 1
+    }
+
+    /// เพิ่ม edge `from → to` (ใส่ซ้ำได้ - เป็น multigraph)
+    ///
+    /// # Panics
+    ///
+    /// ถ้า `from` หรือ `to` ไม่ใช่ node ใน graph นี้
+    pub fn add_edge(&mut self, from: NodeId, to: NodeId, edge: E) {
+        assert!(to < self.nodes.len(), "unknown node {to}");
+        self.edges[from].push((to, edge));
+    }
+
+    /// เพิ่ม edge ทั้งสองทิศ - ถนนสองทาง, เพื่อนกันบน social network
+    ///
+    /// # Panics
+    ///
+    /// ถ้า `a` หรือ `b` ไม่ใช่ node ใน graph นี้
+    pub fn add_undirected_edge(&mut self, a: NodeId, b: NodeId, edge: E)
+    where
+        E: Clone,
+    {
+        self.add_edge(a, b, edge.clone());
+        self.add_edge(b, a, edge);
+    }
+
+    #[must_use]
+    pub fn node(&self, id: NodeId) -> Option<&N> {
+        self.nodes.get(id)
+    }
+
+    /// node แรกที่ตรงเงื่อนไข
+    pub fn find(&self, predicate: impl Fn(&N) -> bool) -> Option<NodeId> {
+        self.nodes.iter().position(predicate)
+    }
+
+    #[must_use]
+    pub const fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    #[must_use]
+    pub fn edge_count(&self) -> usize {
+        self.edges.iter().map(Vec::len).sum()
+    }
+
+    /// edge ที่ออกจาก `id` เป็น `(ปลายทาง, &edge)` ตามลำดับที่ใส่
+    pub fn neighbors(&self, id: NodeId) -> impl Iterator<Item = (NodeId, &E)> {
+        self.edges
+            .get(id)
+            .into_iter()
+            .flatten()
+            .map(|(to, edge)| (*to, edge))
+    }
+
+    /// 🌊 เดินแบบกว้างก่อน - ได้ node เรียงตามจำนวน edge ที่ห่างจาก `start`
+    #[must_use]
+    pub fn bfs(&self, start: NodeId) -> Bfs<'_, N, E> {
+        let mut visited = vec![false; self.nodes.len()];
+        let mut queue = VecDeque::new();
+        if let Some(seen) = visited.get_mut(start) {
+            *seen = true;
+            queue.push_back(start);
+        }
+        Bfs {
+            graph: self,
+            queue,
+            visited,
+        }
+    }
+
+    /// 🏊 เดินแบบลึกก่อน (pre-order) - ลงไปตาม edge แรกให้สุดก่อนย้อนกลับมาลองเส้นถัดไป
+    #[must_use]
+    pub fn dfs(&self, start: NodeId) -> Dfs<'_, N, E> {
+        Dfs {
+            graph: self,
+            stack: if start < self.nodes.len() {
+                vec![start]
+            } else {
+                Vec::new()
+            },
+            visited: vec![false; self.nodes.len()],
+        }
+    }
+
+    /// 🌀 วงจรแรกที่ DFS เจอ (ไล่ node เริ่มต้นตามลำดับ `NodeId`) - `None` ถ้าไม่มีวงจร
+    ///
+    /// ใช้สามสี: ยังไม่เยี่ยม / อยู่บนเส้นทางปัจจุบัน / เสร็จแล้ว
+    /// edge ที่ชี้กลับไปหา node "บนเส้นทางปัจจุบัน" คือวงจร
+    #[must_use]
+    pub fn find_cycle(&self) -> Option<Vec<NodeId>> {
+        #[derive(Clone, Copy, PartialEq, Eq)]
+        enum Color {
+            White,
+            Gray,
+            Black,
+        }
+
+        let mut color = vec![Color::White; self.nodes.len()];
+        for root in 0..self.nodes.len() {
+            if color[root] != Color::White {
+                continue;
+            }
+            // path[i] คือ node บนเส้นทาง และ cursor[i] คือ edge ถัดไปที่จะลอง
+            let mut path = vec![root];
+            let mut cursor = vec![0];
+            color[root] = Color::Gray;
+            while let (Some(&node), Some(next)) = (path.last(), cursor.last_mut()) {
+                let Some(&(to, _)) = self.edges[node].get(*next) else {
+                    color[node] = Color::Black;
+                    path.pop();
+                    cursor.pop();
+                    continue;
+                };
+                *next += 1;
+                match color[to] {
+                    Color::White => {
+                        color[to] = Color::Gray;
+                        path.push(to);
+                        cursor.push(0);
+                    }
+                    Color::Gray => {
+                        let start = path.iter().position(|&seen| seen == to)?;
+                        let mut cycle = path.split_off(start);
+                        cycle.push(to);
+                        return Some(cycle);
+                    }
+                    Color::Black => {}
+                }
+            }
+        }
+        None
+    }
+
+    #[must_use]
+    pub fn has_cycle(&self) -> bool {
+        self.find_cycle().is_some()
+    }
+
+    /// 📋 เรียง node ให้ทุก edge `a → b` มี `a` มาก่อน `b` (Kahn's algorithm)
+    ///
+    /// เมื่อมีหลาย node พร้อมพร้อมกัน จะเลือก `NodeId` ที่น้อยที่สุดก่อน
+    /// node ที่ไม่เกี่ยวข้องกันจึงคงลำดับตามที่ใส่ และผลเหมือนเดิมทุกครั้ง
+    ///
+    /// # Errors
+    ///
+    /// คืน `CycleError` พร้อมเส้นทางของวงจรถ้าเรียงไม่ได้
+    pub fn topological_sort(&self) -> Result<Vec<NodeId>, CycleError> {
+        let mut incoming = vec![0_usize; self.nodes.len()];
+        for (to, _) in self.edges.iter().flatten() {
+            incoming[*to] += 1;
+        }
+        let mut ready: BinaryHeap<Reverse<NodeId>> = incoming
+            .iter()
+            .enumerate()
+            .filter(|(_, count)| **count == 0)
+            .map(|(id, _)| Reverse(id))
+            .collect();
+
+        let mut order = Vec::with_capacity(self.nodes.len());
+        while let Some(Reverse(id)) = ready.pop() {
+            order.push(id);
+            for (to, _) in &self.edges[id] {
+                incoming[*to] -= 1;
+                if incoming[*to] == 0 {
+                    ready.push(Reverse(*to));
+                }
+            }
+        }
+        if order.len() == self.nodes.len() {
+            Ok(order)
+        } else {
+            // node ที่เหลือทุกตัวยังมี edge ขาเข้าค้าง → ต้องมีวงจรอย่างน้อยหนึ่งวง
+            Err(CycleError(self.find_cycle().unwrap_or_default()))
+        }
+    }
+
+    /// 🧭 เส้นทางที่ผลรวมน้ำหนักน้อยที่สุดจาก `from` ไป `to` (Dijkstra) - `None` ถ้าไปไม่ถึง
+    ///
+    /// `weight` แปลง edge เป็นน้ำหนัก (ต้องไม่ติดลบ - ใช้ `u64` จึงรับประกันได้)
+    pub fn shortest_path(
+        &self,
+        from: NodeId,
+        to: NodeId,
+        weight: impl Fn(&E) -> u64,
+    ) -> Option<(u64, Vec<NodeId>)> {
+        if from >= self.nodes.len() || to >= self.nodes.len() {
+            return None;
+        }
+        let mut distance = vec![u64::MAX; self.nodes.len()];
+        let mut previous: Vec<Option<NodeId>> = vec![None; self.nodes.len()];
+        let mut frontier = BinaryHeap::new();
+        distance[from] = 0;
+        frontier.push(Reverse((0, from)));
+
+        while let Some(Reverse((cost, node))) = frontier.pop() {
+            if node == to {
+                break;
+            }
+            if cost > distance[node] {
+                // ค่าเก่าที่ค้างใน heap - เจอทางที่สั้นกว่าไปแล้ว
+                continue;
+            }
+            for (next, edge) in &self.edges[node] {
+                let candidate = cost.saturating_add(weight(edge));
+                if candidate < distance[*next] {
+                    distance[*next] = candidate;
+                    previous[*next] = Some(node);
+                    frontier.push(Reverse((candidate, *next)));
+                }
+            }
+        }
+
+        if distance[to] == u64::MAX {
+            return None;
+        }
+        let mut path: Vec<NodeId> =
+            std::iter::successors(Some(to), |&node| previous[node]).collect();
+        path.reverse();
+        Some((distance[to], path))
+    }
+}
+
+/// iterator ของ `Graph::bfs`
+pub struct Bfs<'a, N, E> {
+    graph: &'a Graph<N, E>,
+    queue: VecDeque<NodeId>,
+    visited: Vec<bool>,
+}
+
+impl<N, E> Iterator for Bfs<'_, N, E> {
+    type Item = NodeId;
+
+    fn next(&mut self) -> Option<NodeId> {
+        let node = self.queue.pop_front()?;
+        for (to, _) in &self.graph.edges[node] {
+            if !self.visited[*to] {
+                self.visited[*to] = true;
+                self.queue.push_back(*to);
+            }
+        }
+        Some(node)
+    }
+}
+
+/// iterator ของ `Graph::dfs`
+pub struct Dfs<'a, N, E> {
+    graph: &'a Graph<N, E>,
+    stack: Vec<NodeId>,
+    visited: Vec<bool>,
+}
+
+impl<N, E> Iterator for Dfs<'_, N, E> {
+    type Item = NodeId;
+
+    fn next(&mut self) -> Option<NodeId> {
+        loop {
+            let node = self.stack.pop()?;
+            if self.visited[node] {
+                continue;
+            }
+            self.visited[node] = true;
+            // ใส่กลับด้าน edge แรกจะได้ถูกหยิบก่อน
+            let unvisited = self.graph.edges[node]
+                .iter()
+                .rev()
+                .map(|(to, _)| *to)
+                .filter(|to| !self.visited[*to]);
+            self.stack.extend(unvisited);
+            return Some(node);
+        }
+    }
+}
+
+fn names<N: fmt::Display, E>(graph: &Graph<N, E>, ids: &[NodeId], separator: &str) -> String {
+    ids.iter()
+        .filter_map(|&id| graph.node(id))
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+/// ฟังก์ชันสำหรับสอนเรื่อง graph - แผนที่รถไฟฟ้ากับตารางวิชาบังคับก่อน! 🚆📚
+pub fn learn_graphs() {
+    println!("🕸️ === Graph: node + edge แบบ adjacency list! === 🕸️");
+
+    // สถานีรถไฟฟ้า (สมมติ) - edge คือเวลาเดินทางเป็นนาที
+    let mut metro: Graph<&str, u64> = Graph::new();
+    let mochit = metro.add_node("หมอชิต");
+    let phaya_thai = metro.add_node("พญาไท");
+    let siam = metro.add_node("สยาม");
+    let asok = metro.add_node("อโศก");
+    let silom = metro.add_node("สีลม");
+    let chatuchak = metro.add_node("จตุจักร");
+    metro.add_undirected_edge(mochit, phaya_thai, 9);
+    metro.add_undirected_edge(phaya_thai, siam, 4);
+    metro.add_undirected_edge(siam, asok, 6);
+    metro.add_undirected_edge(siam, silom, 5);
+    metro.add_undirected_edge(asok, silom, 8);
+    metro.add_undirected_edge(mochit, chatuchak, 2);
+    metro.add_undirected_edge(chatuchak, asok, 14);
+    println!(
+        "🚆 {} สถานี {} edge (ไป-กลับนับแยกกัน)",
+        metro.node_count(),
+        metro.edge_count()
+    );
+
+    let breadth_first: Vec<NodeId> = metro.bfs(mochit).collect();
+    println!(
+        "🌊 BFS จากหมอชิต (ใกล้สุดตามจำนวนป้าย): {}",
+        names(&metro, &breadth_first, ", ")
+    );
+    let depth_first: Vec<NodeId> = metro.dfs(mochit).collect();
+    println!(
+        "🏊 DFS จากหมอชิต (ลงลึกตามเส้นแรก): {}",
+        names(&metro, &depth_first, ", ")
+    );
+
+    if let Some((minutes, path)) = metro.shortest_path(mochit, silom, |minutes| *minutes) {
+        println!(
+            "🧭 หมอชิต → สีลม เร็วสุด {minutes} นาที: {}",
+            names(&metro, &path, " → ")
+        );
+    }
+
+    // วิชาบังคับก่อน - edge `a → b` แปลว่าต้องเรียน a ก่อน b
+    let mut courses: Graph<&str, ()> = Graph::new();
+    let basics = courses.add_node("พื้นฐาน");
+    let ownership = courses.add_node("ownership");
+    let traits = courses.add_node("traits");
+    let lifetimes = courses.add_node("lifetimes");
+    let async_await = courses.add_node("async");
+    courses.add_edge(basics, ownership, ());
+    courses.add_edge(basics, traits, ());
+    courses.add_edge(ownership, lifetimes, ());
+    courses.add_edge(traits, async_await, ());
+    courses.add_edge(lifetimes, async_await, ());
+    match courses.topological_sort() {
+        Ok(order) => println!("📋 ลำดับการเรียน: {}", names(&courses, &order, " → ")),
+        Err(error) => println!("❌ {error}"),
+    }
+
+    courses.add_edge(async_await, basics, ());
+    if let Err(CycleError(cycle)) = courses.topological_sort() {
+        println!(
+            "🌀 เพิ่ม async → พื้นฐาน แล้วเรียงไม่ได้: {}",
+            names(&courses, &cycle, " → ")
+        );
+    }
+    println!(
+        "💡 ใช้ topological sort เรียงลำดับสร้าง resource ในบท DevOps (Infrastructure as Code) ด้วย"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// a → b → d, a → c → d, c → e
+    fn diamond() -> Graph<char, u64> {
+        let mut graph = Graph::new();
+        for label in ['a', 'b', 'c', 'd', 'e'] {
+            graph.add_node(label);
+        }
+        graph.add_edge(0, 1, 1);
+        graph.add_edge(0, 2, 4);
+        graph.add_edge(1, 3, 5);
+        graph.add_edge(2, 3, 1);
+        graph.add_edge(2, 4, 2);
+        graph
+    }
+
+    fn labels(graph: &Graph<char, u64>, ids: impl IntoIterator<Item = NodeId>) -> String {
+        ids.into_iter().filter_map(|id| graph.node(id)).collect()
+    }
+
+    #[test]
+    fn test_traversals() {
+        let graph = diamond();
+        assert_eq!(graph.node_count(), 5);
+        assert_eq!(graph.edge_count(), 5);
+        assert_eq!(graph.find(|&label| label == 'c'), Some(2));
+        assert_eq!(labels(&graph, graph.bfs(0)), "abcde");
+        assert_eq!(labels(&graph, graph.dfs(0)), "abdce");
+        assert_eq!(labels(&graph, graph.bfs(2)), "cde");
+        assert_eq!(graph.bfs(99).count(), 0);
+        assert_eq!(graph.dfs(99).count(), 0);
+
+        let neighbors: Vec<_> = graph.neighbors(2).collect();
+        assert_eq!(neighbors, [(3, &1), (4, &2)]);
+        assert_eq!(graph.neighbors(99).count(), 0);
+    }
+
+    #[test]
+    fn test_topological_sort_and_cycles() {
+        let mut graph = diamond();
+        assert!(!graph.has_cycle());
+        assert_eq!(labels(&graph, graph.topological_sort().unwrap()), "abcde");
+
+        // d → c ทำให้เกิดวงจร - DFS มาถึง d ก่อน (a → b → d) จึงรายงานเป็น d → c → d
+        graph.add_edge(3, 2, 1);
+        assert_eq!(graph.find_cycle(), Some(vec![3, 2, 3]));
+        assert_eq!(graph.topological_sort(), Err(CycleError(vec![3, 2, 3])));
+        assert_eq!(
+            CycleError(vec![2, 3, 2]).to_string(),
+            "graph has a cycle: 2 → 3 → 2"
+        );
+
+        let mut self_loop: Graph<(), ()> = Graph::new();
+        let only = self_loop.add_node(());
+        self_loop.add_edge(only, only, ());
+        assert_eq!(self_loop.find_cycle(), Some(vec![0, 0]));
+
+        // ใส่ node ตามลำดับใด ผลต้องคงที่: node ที่ไม่ขึ้นต่อกันเรียงตาม id
+        let mut loose: Graph<char, ()> = Graph::new();
+        for label in ['x', 'y', 'z'] {
+            loose.add_node(label);
+        }
+        loose.add_edge(2, 0, ());
+        assert_eq!(loose.topological_sort(), Ok(vec![1, 2, 0]));
+    }
+
+    #[test]
+    fn test_shortest_path() {
+        let mut graph = diamond();
+        assert_eq!(graph.shortest_path(0, 3, |w| *w), Some((5, vec![0, 2, 3])));
+        // นับจำนวน edge แทนน้ำหนัก - ทางผ่าน b ก็ยาว 2 เท่ากัน
+        assert_eq!(
+            graph.shortest_path(0, 3, |_| 1).map(|(cost, _)| cost),
+            Some(2)
+        );
+        assert_eq!(graph.shortest_path(0, 0, |w| *w), Some((0, vec![0])));
+        assert_eq!(graph.shortest_path(3, 0, |w| *w), None);
+        assert_eq!(graph.shortest_path(0, 99, |w| *w), None);
+
+        graph.add_undirected_edge(3, 4, 1);
+        assert_eq!(graph.shortest_path(4, 1, |w| *w), None);
+        assert_eq!(graph.shortest_path(4, 3, |w| *w), Some((1, vec![4, 3])));
+    }
+}
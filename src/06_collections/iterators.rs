@@ -0,0 +1,558 @@
+//! Iterators - เขียน adapter เองและดูว่า "lazy" กับ "fusion" ทำงานอย่างไร 🔄⚙️
+//!
+//! `map`/`filter`/`zip` ใน std ก็เป็นแค่ struct ที่ห่อ iterator อีกตัวแล้ว `impl Iterator`
+//! ไฟล์นี้เขียน adapter แบบเดียวกันเอง 3 ตัว:
+//! - 📦 `ChunksExactOwned<I, N>` - รวบทีละ `N` ตัวเป็น `[T; N]` ที่เป็นเจ้าของค่าเอง เศษเก็บไว้ใน `remainder`
+//! - 🧹 `Dedup<I>` - ตัดค่าที่ซ้ำติดกัน (แบบ `Vec::dedup` แต่ไม่ต้องมี `Vec`)
+//! - 🪟 `Windows2<I>` - คู่ `(ก่อนหน้า, ปัจจุบัน)` ที่ติดกัน (แบบ `slice::windows(2)` จาก iterator ใดก็ได้)
+//!
+//! และ `Pipeline` ที่รันขั้นตอนเดียวกันได้สองแบบ: lazy (ทีละตัวผ่านทุกขั้น) กับ eager (ทีละขั้นผ่านทุกตัว)
+
+use std::cell::RefCell;
+use std::fmt::Debug;
+use std::hint::black_box;
+use std::iter::FusedIterator;
+use std::rc::Rc;
+
+/// 🔌 เพิ่ม adapter ให้ทุก iterator - แบบเดียวกับที่ crate `itertools` ทำ
+pub trait IteratorExt: Iterator + Sized {
+    /// รวบทีละ `N` ตัว - ตัวที่เหลือไม่ครบ `N` ดูได้จาก `remainder()`
+    fn chunks_exact_owned<const N: usize>(self) -> ChunksExactOwned<Self, N> {
+        ChunksExactOwned::new(self)
+    }
+
+    /// ตัดค่าที่ซ้ำติดกัน: `1 1 2 1` → `1 2 1`
+    fn dedup(self) -> Dedup<Self>
+    where
+        Self::Item: PartialEq,
+    {
+        Dedup {
+            iter: self,
+            pending: None,
+        }
+    }
+
+    /// คู่ที่ติดกัน: `a b c` → `(a, b) (b, c)`
+    fn windows2(self) -> Windows2<Self>
+    where
+        Self::Item: Clone,
+    {
+        Windows2 {
+            iter: self,
+            previous: None,
+        }
+    }
+}
+
+impl<I: Iterator> IteratorExt for I {}
+
+/// 📦 `[T; N]` ทีละก้อน - ต่างจาก `slice::chunks_exact` ตรงที่รับ iterator ใดก็ได้และได้ค่ามาเป็นเจ้าของ
+#[derive(Debug, Clone)]
+pub struct ChunksExactOwned<I: Iterator, const N: usize> {
+    iter: I,
+    remainder: Vec<I::Item>,
+    done: bool,
+}
+
+impl<I: Iterator, const N: usize> ChunksExactOwned<I, N> {
+    const fn new(iter: I) -> Self {
+        const { assert!(N > 0, "chunk size must be non-zero") };
+        Self {
+            iter,
+            remainder: Vec::new(),
+            done: false,
+        }
+    }
+
+    /// ตัวที่เหลือไม่ครบก้อน - มีค่าหลังจาก iterator คืน `None` แล้วเท่านั้น
+    #[must_use]
+    pub fn remainder(&self) -> &[I::Item] {
+        &self.remainder
+    }
+}
+
+impl<I: Iterator, const N: usize> Iterator for ChunksExactOwned<I, N> {
+    type Item = [I::Item; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let mut chunk = Vec::with_capacity(N);
+        while chunk.len() < N {
+            let Some(item) = self.iter.next() else {
+                // iterator ข้างในอาจไม่ fused - จำไว้ว่าจบแล้ว จะได้ไม่ถามซ้ำ
+                self.done = true;
+                self.remainder = chunk;
+                return None;
+            };
+            chunk.push(item);
+        }
+        chunk.try_into().ok()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+        let (low, high) = self.iter.size_hint();
+        (low / N, high.map(|high| high / N))
+    }
+}
+
+impl<I: Iterator, const N: usize> FusedIterator for ChunksExactOwned<I, N> {}
+
+/// 🧹 ตัดค่าที่ซ้ำติดกัน - ถือค่า "ตัวถัดไปที่ต่างออกไป" ไว้หนึ่งตัว ไม่ต้อง `Clone`
+#[derive(Debug, Clone)]
+pub struct Dedup<I: Iterator> {
+    iter: I,
+    pending: Option<I::Item>,
+}
+
+impl<I: Iterator> Iterator for Dedup<I>
+where
+    I::Item: PartialEq,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.pending.take().or_else(|| self.iter.next())?;
+        for item in self.iter.by_ref() {
+            if item != current {
+                self.pending = Some(item);
+                break;
+            }
+        }
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = usize::from(self.pending.is_some());
+        let (low, high) = self.iter.size_hint();
+        // ซ้ำกันหมดก็ยังเหลืออย่างน้อยหนึ่งตัว ถ้ามีของเหลือ
+        let low = usize::from(pending + low > 0);
+        (low, high.and_then(|high| high.checked_add(pending)))
+    }
+}
+
+impl<I: FusedIterator> FusedIterator for Dedup<I> where I::Item: PartialEq {}
+
+/// 🪟 คู่ที่ติดกัน - `clone` ตัวปัจจุบันเก็บไว้เป็น "ตัวก่อนหน้า" ของรอบถัดไป
+#[derive(Debug, Clone)]
+pub struct Windows2<I: Iterator> {
+    iter: I,
+    previous: Option<I::Item>,
+}
+
+impl<I: Iterator> Iterator for Windows2<I>
+where
+    I::Item: Clone,
+{
+    type Item = (I::Item, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let previous = match self.previous.take() {
+            Some(previous) => previous,
+            None => self.iter.next()?,
+        };
+        let current = self.iter.next()?;
+        self.previous = Some(current.clone());
+        Some((previous, current))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (low, high) = self.iter.size_hint();
+        if self.previous.is_some() {
+            (low, high)
+        } else {
+            (
+                low.saturating_sub(1),
+                high.map(|high| high.saturating_sub(1)),
+            )
+        }
+    }
+}
+
+impl<I: FusedIterator> FusedIterator for Windows2<I> where I::Item: Clone {}
+
+// ===== Pipeline: lazy vs eager =====
+
+/// บันทึกลำดับการเรียกแต่ละขั้น เช่น `"double(3)"`
+pub type Trace = Rc<RefCell<Vec<String>>>;
+
+enum Stage<T> {
+    Map(&'static str, Box<dyn Fn(T) -> T>),
+    Filter(&'static str, Box<dyn Fn(&T) -> bool>),
+}
+
+/// 🏭 ขั้นตอนประมวลผลที่ประกอบด้วย builder แล้วรันได้ทั้งแบบ lazy และ eager
+///
+/// - `run` - lazy และ "fused": ค่าแต่ละตัววิ่งผ่านทุกขั้นก่อนดึงตัวถัดไป ไม่มี `Vec` ระหว่างทาง
+///   และไม่ทำอะไรเลยจนกว่าจะมีคนเรียก `next()`
+/// - `run_eager` - ทำทีละขั้นกับทุกตัว เก็บผลเป็น `Vec` ใหม่ทุกขั้น (แบบ `list.map().filter()` ในภาษาอื่น)
+pub struct Pipeline<T> {
+    stages: Vec<Stage<T>>,
+    limit: Option<usize>,
+    trace: Option<Trace>,
+}
+
+impl<T: Debug + 'static> Default for Pipeline<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Debug + 'static> Pipeline<T> {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            stages: Vec::new(),
+            limit: None,
+            trace: None,
+        }
+    }
+
+    #[must_use]
+    pub fn map(mut self, name: &'static str, f: impl Fn(T) -> T + 'static) -> Self {
+        self.stages.push(Stage::Map(name, Box::new(f)));
+        self
+    }
+
+    #[must_use]
+    pub fn filter(mut self, name: &'static str, f: impl Fn(&T) -> bool + 'static) -> Self {
+        self.stages.push(Stage::Filter(name, Box::new(f)));
+        self
+    }
+
+    /// เอาผลลัพธ์แค่ `n` ตัวแรก (ใช้กับผลสุดท้ายเสมอ ไม่ว่าจะเรียกตอนไหน)
+    #[must_use]
+    pub const fn take(mut self, n: usize) -> Self {
+        self.limit = Some(n);
+        self
+    }
+
+    /// บันทึกทุกการเรียกลง `trace` - ใช้ดูลำดับการทำงาน
+    #[must_use]
+    pub fn traced(mut self, trace: &Trace) -> Self {
+        self.trace = Some(Rc::clone(trace));
+        self
+    }
+
+    fn record(&self, stage: &str, value: &T) {
+        if let Some(trace) = &self.trace {
+            trace.borrow_mut().push(format!("{stage}({value:?})"));
+        }
+    }
+
+    /// ส่งค่าหนึ่งตัวผ่านทุกขั้น - `None` ถ้าโดน filter ทิ้ง
+    fn process(&self, mut value: T) -> Option<T> {
+        for stage in &self.stages {
+            match stage {
+                Stage::Map(name, f) => {
+                    self.record(name, &value);
+                    value = f(value);
+                }
+                Stage::Filter(name, f) => {
+                    self.record(name, &value);
+                    if !f(&value) {
+                        return None;
+                    }
+                }
+            }
+        }
+        Some(value)
+    }
+
+    /// 💤 lazy: ได้ iterator กลับไปทันทีโดยยังไม่แตะ `source` เลย
+    pub fn run<'a>(
+        &'a self,
+        source: impl IntoIterator<Item = T> + 'a,
+    ) -> impl Iterator<Item = T> + 'a {
+        let mut source = source.into_iter();
+        let mut remaining = self.limit.unwrap_or(usize::MAX);
+        std::iter::from_fn(move || {
+            if remaining == 0 {
+                return None;
+            }
+            loop {
+                let value = source.next()?;
+                self.record("source", &value);
+                if let Some(value) = self.process(value) {
+                    remaining -= 1;
+                    return Some(value);
+                }
+            }
+        })
+        .fuse()
+    }
+
+    /// 🏃 eager: แต่ละขั้นทำกับทุกตัวให้เสร็จก่อนไปขั้นถัดไป
+    pub fn run_eager(&self, source: impl IntoIterator<Item = T>) -> Vec<T> {
+        let mut values: Vec<T> = source.into_iter().collect();
+        for value in &values {
+            self.record("source", value);
+        }
+        for stage in &self.stages {
+            values = match stage {
+                Stage::Map(name, f) => values
+                    .into_iter()
+                    .map(|value| {
+                        self.record(name, &value);
+                        f(value)
+                    })
+                    .collect(),
+                Stage::Filter(name, f) => values
+                    .into_iter()
+                    .filter(|value| {
+                        self.record(name, value);
+                        f(value)
+                    })
+                    .collect(),
+            };
+        }
+        values.truncate(self.limit.unwrap_or(usize::MAX));
+        values
+    }
+}
+
+// ===== Workloads สำหรับ benchmark: iterator chain เทียบกับ index loop =====
+
+/// ➕ ผลรวมกำลังสองของเลขคู่ - แบบ iterator chain
+#[must_use]
+pub fn even_square_sum_iter(data: &[u64]) -> u64 {
+    data.iter()
+        .filter(|&&x| x.is_multiple_of(2))
+        .map(|&x| x * x)
+        .sum()
+}
+
+/// ➕ ผลรวมกำลังสองของเลขคู่ - แบบ index loop (มี bounds check ทุกรอบถ้า compiler พิสูจน์ไม่ได้)
+#[must_use]
+pub fn even_square_sum_index(data: &[u64]) -> u64 {
+    let mut total = 0;
+    let mut i = 0;
+    while i < data.len() {
+        if data[i].is_multiple_of(2) {
+            total += data[i] * data[i];
+        }
+        i += 1;
+    }
+    total
+}
+
+/// 📈 ผลรวมของผลต่างที่เป็นบวกระหว่างค่าที่ติดกัน - แบบ `windows2`
+#[must_use]
+pub fn rising_total_iter(data: &[u64]) -> u64 {
+    data.iter()
+        .windows2()
+        .map(|(a, b)| b.saturating_sub(*a))
+        .sum()
+}
+
+/// 📈 ผลรวมของผลต่างที่เป็นบวกระหว่างค่าที่ติดกัน - แบบ index loop
+#[must_use]
+pub fn rising_total_index(data: &[u64]) -> u64 {
+    let mut total = 0;
+    for i in 1..data.len() {
+        total += data[i].saturating_sub(data[i - 1]);
+    }
+    total
+}
+
+/// ข้อมูลทดสอบที่ขึ้นลงสลับกัน (ไม่สุ่ม - ผลเท่ากันทุกครั้ง)
+#[must_use]
+pub fn sample_series(len: u64) -> Vec<u64> {
+    (0..len).map(|i| (i * 7919) % 1000).collect()
+}
+
+/// ฟังก์ชันสำหรับสอนเรื่อง iterator adapters และ lazy pipeline - โรงงานสายพานแบบ Toyota! 🏭🔄
+pub fn learn_iterators() {
+    println!("🔄 === Iterators: เขียน adapter เองและดูความขี้เกียจของ iterator! === 🔄");
+
+    println!("\n📦 ChunksExactOwned::<3> - แพ็คของลงกล่องละ 3 ชิ้น:");
+    let mut boxes = "ABCDEFGH".chars().chunks_exact_owned::<3>();
+    for chunk in boxes.by_ref() {
+        println!("   📦 {chunk:?}");
+    }
+    println!("   🧺 เศษที่ไม่ครบกล่อง: {:?}", boxes.remainder());
+
+    println!("\n🧹 Dedup - ตัดค่าที่ซ้ำติดกัน (ค่าซ้ำที่ไม่ติดกันยังอยู่):");
+    let readings = [20, 20, 21, 21, 21, 20, 22, 22];
+    let changes: Vec<_> = readings.iter().dedup().collect();
+    println!("   🌡️ {readings:?} → {changes:?}");
+
+    println!("\n🪟 Windows2 - เทียบค่ากับตัวก่อนหน้า:");
+    let prices = [100, 104, 101, 110];
+    for (before, after) in prices.iter().windows2() {
+        let arrow = if after > before { "📈" } else { "📉" };
+        println!("   {arrow} {before} → {after}");
+    }
+
+    println!("\n🏭 Pipeline: filter(even) → map(square) → take(2) กับข้อมูล 1..=6");
+    let build = |trace: &Trace| {
+        Pipeline::new()
+            .filter("even", |x: &u64| x.is_multiple_of(2))
+            .map("square", |x| x * x)
+            .take(2)
+            .traced(trace)
+    };
+
+    let lazy_trace = Trace::default();
+    let lazy = build(&lazy_trace);
+    let results = lazy.run(1..=6);
+    println!(
+        "   💤 สร้าง iterator แล้วแต่ยังไม่ดึง: เรียกไป {} ครั้ง",
+        lazy_trace.borrow().len()
+    );
+    let results: Vec<_> = results.collect();
+    println!("   ✅ lazy  {results:?}: {}", lazy_trace.borrow().join(" "));
+
+    let eager_trace = Trace::default();
+    let results = build(&eager_trace).run_eager(1..=6);
+    println!(
+        "   🏃 eager {results:?}: {}",
+        eager_trace.borrow().join(" ")
+    );
+    println!(
+        "   💡 lazy เรียก {} ครั้ง (หยุดเมื่อได้ครบ 2 ตัว) eager เรียก {} ครั้ง + สร้าง Vec ทุกขั้น",
+        lazy_trace.borrow().len(),
+        eager_trace.borrow().len()
+    );
+
+    println!("\n⏱️ iterator chain เทียบกับ index loop (median ต่อการเรียก):");
+    let data = sample_series(10_000);
+    let pairs = [
+        (
+            measure("index loop", || even_square_sum_index(black_box(&data))),
+            measure("iterator chain", || even_square_sum_iter(black_box(&data))),
+        ),
+        (
+            measure("index windows", || rising_total_index(black_box(&data))),
+            measure("windows2", || rising_total_iter(black_box(&data))),
+        ),
+    ];
+    for (index_loop, chain) in &pairs {
+        println!(
+            "   {:<16} {:>10?} | {:<16} {:>10?}",
+            index_loop.name, index_loop.median, chain.name, chain.median
+        );
+    }
+    println!(
+        "   💡 ใน --release สองแบบมักเร็วพอกัน (zero-cost abstraction) - debug build จะเห็น iterator ช้ากว่า"
+    );
+}
+
+fn measure<R>(
+    name: &str,
+    f: impl FnMut() -> R,
+) -> crate::performance::microbench::MicroBenchResult {
+    crate::performance::microbench::measure(name, 9, f)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// iterator ที่ "ฟื้น" หลังคืน `None` - adapter ที่ดีต้องไม่ดึงต่อ
+    struct Flaky {
+        calls: usize,
+    }
+
+    impl Iterator for Flaky {
+        type Item = usize;
+
+        fn next(&mut self) -> Option<usize> {
+            self.calls += 1;
+            (self.calls != 3).then_some(self.calls)
+        }
+    }
+
+    #[test]
+    fn test_chunks_exact_owned_edges() {
+        let mut chunks = (1..=7).chunks_exact_owned::<3>();
+        assert_eq!(chunks.size_hint(), (2, Some(2)));
+        assert_eq!(chunks.next(), Some([1, 2, 3]));
+        assert_eq!(chunks.remainder(), &[] as &[i32]);
+        assert_eq!(chunks.next(), Some([4, 5, 6]));
+        assert_eq!(chunks.next(), None);
+        assert_eq!(chunks.remainder(), &[7]);
+
+        // ไม่เป็นเจ้าของ String ตัวที่ครบก้อนซ้ำ - ย้ายเข้า array เลย
+        let words = ["a", "b"].map(String::from);
+        let pairs: Vec<[String; 2]> = words.into_iter().chunks_exact_owned().collect();
+        assert_eq!(pairs, [["a", "b"].map(String::from)]);
+
+        let mut empty = std::iter::empty::<u8>().chunks_exact_owned::<4>();
+        assert_eq!(empty.next(), None);
+        assert!(empty.remainder().is_empty());
+
+        // Flaky คืน 1, 2, None, 4, ... - ต้องหยุดที่ None แรกและไม่ถามอีก
+        let mut flaky = Flaky { calls: 0 }.chunks_exact_owned::<2>();
+        assert_eq!(flaky.next(), Some([1, 2]));
+        assert_eq!(flaky.next(), None);
+        assert_eq!(flaky.next(), None);
+        assert_eq!(flaky.iter.calls, 3);
+    }
+
+    #[test]
+    fn test_dedup_and_windows2_edges() {
+        assert_eq!(
+            [1, 1, 2, 2, 2, 1].into_iter().dedup().collect::<Vec<_>>(),
+            [1, 2, 1]
+        );
+        assert_eq!([7, 7, 7].into_iter().dedup().collect::<Vec<_>>(), [7]);
+        assert_eq!(std::iter::empty::<u8>().dedup().count(), 0);
+        let mut dedup = [1, 1, 2].into_iter().dedup();
+        assert_eq!(dedup.size_hint(), (1, Some(3)));
+        dedup.next();
+        assert_eq!(dedup.size_hint(), (1, Some(1)));
+
+        assert_eq!(
+            (1..=4).windows2().collect::<Vec<_>>(),
+            [(1, 2), (2, 3), (3, 4)]
+        );
+        assert_eq!(std::iter::once(1).windows2().next(), None);
+        assert_eq!(std::iter::empty::<u8>().windows2().next(), None);
+        let mut windows = (1..=4).windows2();
+        assert_eq!(windows.size_hint(), (3, Some(3)));
+        windows.next();
+        assert_eq!(windows.size_hint(), (2, Some(2)));
+        assert_eq!(windows.count(), 2);
+
+        let data = sample_series(500);
+        assert_eq!(even_square_sum_iter(&data), even_square_sum_index(&data));
+        assert_eq!(rising_total_iter(&data), rising_total_index(&data));
+        assert_eq!(rising_total_iter(&[]), 0);
+    }
+
+    #[test]
+    fn test_pipeline_laziness_and_fusion() {
+        let trace = Trace::default();
+        let pipeline = Pipeline::new()
+            .filter("odd", |x: &i32| x % 2 == 1)
+            .map("triple", |x| x * 3)
+            .take(2)
+            .traced(&trace);
+
+        let lazy = pipeline.run(1..);
+        assert!(trace.borrow().is_empty(), "ยังไม่ดึงต้องยังไม่ทำงาน");
+        // แหล่งข้อมูลไม่มีที่สิ้นสุด - lazy จบได้เพราะ take(2)
+        assert_eq!(lazy.collect::<Vec<_>>(), [3, 9]);
+        assert_eq!(
+            trace.borrow().join(" "),
+            "source(1) odd(1) triple(1) source(2) odd(2) source(3) odd(3) triple(3)"
+        );
+
+        trace.borrow_mut().clear();
+        assert_eq!(pipeline.run_eager(1..=4), [3, 9]);
+        assert_eq!(
+            trace.borrow().join(" "),
+            "source(1) source(2) source(3) source(4) odd(1) odd(2) odd(3) odd(4) triple(1) triple(3)"
+        );
+
+        let identity = Pipeline::new();
+        let mut exhausted = identity.run([1, 2]);
+        assert_eq!(exhausted.by_ref().count(), 2);
+        assert_eq!(exhausted.next(), None);
+    }
+}
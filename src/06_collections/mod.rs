@@ -4,10 +4,14 @@
 //! Collections ต่างๆ ใน Rust แบ่งออกเป็นหมวดหมู่ตามประเภทอย่างเป็นระบบแบบ Marie Kondo! 📚✨
 
 // Module declarations
+pub mod graph; // Graph<N, E> + BFS/DFS/topological sort/Dijkstra - บท DevOps ใช้ต่อ
 mod hashmaps;
+pub mod iterators; // ไม่ re-export ทั้งหมด - `Pipeline` ชนกับบท DevOps และ `IteratorExt` ควร import เมื่อใช้เท่านั้น
 mod other_collections;
+pub mod persistent; // List/PVec แบบ immutable ที่แชร์ node ด้วย Rc
 mod practice_collections;
 mod vectors;
+pub mod serialization; // ไม่ re-export ทั้งหมด - ชื่ออย่าง `Format`/`Stats` ชนกับบทอื่นง่าย
 
 // Re-exports
 pub use hashmaps::*;
@@ -26,6 +30,18 @@ pub fn run_collections_examples() {
     println!("\n   📦 Other Collections (คอลเลกชันอื่นๆ: ตู้เก็บของพิเศษแบบ limited edition!)");
     learn_other_collections();
 
+    println!("\n   💾 Serialization (เก็บข้อมูลลงไฟล์แล้วโหลดกลับ: JSON, TOML, bincode!)");
+    serialization::learn_serialization();
+
+    println!("\n   🔄 Iterators (เขียน adapter เอง + lazy pipeline: สายพานโรงงานที่ขยับเมื่อมีคนรอ!)");
+    iterators::learn_iterators();
+
+    println!("\n   🕰️ Persistent Collections (แก้แล้วได้เวอร์ชันใหม่ เวอร์ชันเก่ายังอยู่: ไทม์แมชชีนของข้อมูล!)");
+    persistent::learn_persistent_collections();
+
+    println!("\n   🕸️ Graphs (node + edge: แผนที่รถไฟฟ้าและลำดับวิชาบังคับก่อน!)");
+    graph::learn_graphs();
+
     println!("\n   💪 แบบฝึกหัด Collections (ยิมฝึกจัดการคอลเลกชันแบบ CrossFit!)");
     practice_collections();
 }
@@ -2,9 +2,13 @@
 //!
 //! ไฟล์นี้สอนเรื่องการใช้งาน `HashSet`, `BTreeMap` และ `VecDeque`
 //! รวมถึงการเลือกใช้ Collection ที่เหมาะสมกับงาน
+//! และลองเขียน `SkipList` กับ `BTree` เองตั้งแต่ศูนย์ เพื่อดูว่า ordered map ข้างในทำงานอย่างไร
 //! เหมือนมีกล่องเครื่องมือพิเศษสำหรับทุกสถานการณ์แบบ Swiss Army knife! 🧰🔧⚡
 
-use std::collections::{BTreeMap, HashSet, VecDeque};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+
+use crate::performance::microbench::{self, MicroBenchResult};
+use crate::random::{Rng, SeedableRng, Xorshift64};
 
 /// ฟังก์ชันสำหรับสอนเรื่อง Collections อื่นๆ
 /// มาเรียนรู้คลังสมบัติพิเศษกันเถอะ! เป็น treasure hunter! 🧰🎁🗺️
@@ -90,5 +94,804 @@ pub fn learn_other_collections() {
         println!("➡️ เอาจากหลัง: {back}, เหลือ: {deque:?} (เอาท้ายคิวแบบ last out!)");
     }
 
+    // เขียน ordered map เองสองแบบ แล้ววัดกับของ std 🛠️
+    demonstrate_handmade_maps();
+
     println!("\n🎉 จบการเรียนรู้ Collections อื่นๆ! (เป็นนักสะสมคลังสมบัติมืออาชีพแล้วแบบ master collector! 🏆💎🎯)");
 }
+// ===== เขียนเองเพื่อดูว่าข้างในทำงานอย่างไร: SkipList และ B-tree =====
+
+/// ชั้นสูงสุดของ skip list - พอสำหรับราว 2^16 ค่าโดยยังค้นได้ O(log n)
+const SKIP_MAX_LEVEL: usize = 16;
+
+struct SkipNode<K, V> {
+    key: K,
+    value: V,
+    /// ตัวถัดไปในแต่ละชั้น (index ใน `nodes`) - ชั้น 0 มีทุกตัว ชั้นบนมีน้อยลงทีละครึ่ง
+    forward: Vec<Option<usize>>,
+}
+
+/// 🪜 Skip list - linked list หลายชั้นที่ "กระโดดข้าม" ได้ ค้นหาเฉลี่ย O(log n) โดยไม่ต้องหมุนต้นไม้
+///
+/// ความสูงของแต่ละ node มาจากการโยนเหรียญ (`Xorshift64` seed ตายตัว ทำให้ทุกครั้งได้โครงสร้างเดิม)
+/// node เก็บใน `Vec` แล้วชี้กันด้วย index แทน pointer - ไม่ต้องใช้ `unsafe` หรือ `Rc<RefCell<_>>`
+pub struct SkipList<K, V> {
+    nodes: Vec<Option<SkipNode<K, V>>>,
+    /// ช่องว่างใน `nodes` จากการลบ - ใช้ซ้ำก่อนต่อท้าย
+    free: Vec<usize>,
+    head: [Option<usize>; SKIP_MAX_LEVEL],
+    level: usize,
+    len: usize,
+    rng: Xorshift64,
+}
+
+impl<K: Ord, V> Default for SkipList<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord, V> SkipList<K, V> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            free: Vec::new(),
+            head: [None; SKIP_MAX_LEVEL],
+            level: 1,
+            len: 0,
+            rng: Xorshift64::seed_from_u64(0x5EED),
+        }
+    }
+
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn node(&self, index: usize) -> &SkipNode<K, V> {
+        self.nodes[index]
+            .as_ref()
+            .expect("skip list links point at live nodes")
+    }
+
+    /// ตัวถัดจาก `at` ในชั้น `level` (`None` = เริ่มจาก head)
+    fn next(&self, at: Option<usize>, level: usize) -> Option<usize> {
+        at.map_or(self.head[level], |index| self.node(index).forward[level])
+    }
+
+    fn set_next(&mut self, at: Option<usize>, level: usize, target: Option<usize>) {
+        match at {
+            None => self.head[level] = target,
+            Some(index) => {
+                self.nodes[index]
+                    .as_mut()
+                    .expect("skip list links point at live nodes")
+                    .forward[level] = target;
+            }
+        }
+    }
+
+    /// ตัวสุดท้ายที่ key น้อยกว่า `key` ในแต่ละชั้น - เดินขวาจนเกินแล้วลงชั้น
+    fn predecessors(&self, key: &K) -> [Option<usize>; SKIP_MAX_LEVEL] {
+        let mut path = [None; SKIP_MAX_LEVEL];
+        let mut at = None;
+        for level in (0..self.level).rev() {
+            while let Some(next) = self.next(at, level) {
+                if self.node(next).key >= *key {
+                    break;
+                }
+                at = Some(next);
+            }
+            path[level] = at;
+        }
+        path
+    }
+
+    /// โยนเหรียญจนออกก้อย - สูง 1 ชั้นครึ่งหนึ่ง, 2 ชั้นหนึ่งในสี่, ...
+    fn random_level(&mut self) -> usize {
+        let heads = self.rng.next_u64().trailing_ones() as usize;
+        (heads + 1).min(SKIP_MAX_LEVEL)
+    }
+
+    #[must_use]
+    pub fn get(&self, key: &K) -> Option<&V> {
+        let path = self.predecessors(key);
+        let found = self.next(path[0], 0)?;
+        let node = self.node(found);
+        (node.key == *key).then_some(&node.value)
+    }
+
+    /// ใส่หรือแทนที่ - คืนค่าเดิมถ้ามี key นี้อยู่แล้ว
+    ///
+    /// # Panics
+    ///
+    /// เฉพาะเมื่อโครงสร้างภายในเสีย (bug) - ดู `check_invariants`
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let path = self.predecessors(&key);
+        if let Some(found) = self.next(path[0], 0)
+            && self.node(found).key == key
+        {
+            let node = self.nodes[found].as_mut().expect("live node");
+            return Some(std::mem::replace(&mut node.value, value));
+        }
+
+        let height = self.random_level();
+        let forward = (0..height)
+            .map(|level| {
+                if level < self.level {
+                    self.next(path[level], level)
+                } else {
+                    None
+                }
+            })
+            .collect();
+        let node = Some(SkipNode {
+            key,
+            value,
+            forward,
+        });
+        let index = if let Some(slot) = self.free.pop() {
+            self.nodes[slot] = node;
+            slot
+        } else {
+            self.nodes.push(node);
+            self.nodes.len() - 1
+        };
+        // ชั้นที่สูงกว่าที่เคยมี - predecessor คือ head (path เป็น None อยู่แล้ว)
+        for (level, &before) in path.iter().enumerate().take(height) {
+            self.set_next(before, level, Some(index));
+        }
+        self.level = self.level.max(height);
+        self.len += 1;
+        debug_assert_eq!(self.check_invariants(), Ok(()));
+        None
+    }
+
+    /// ลบแล้วคืนค่าของ key นั้น - ช่องใน `nodes` เก็บไว้ใช้ซ้ำ
+    ///
+    /// # Panics
+    ///
+    /// เฉพาะเมื่อโครงสร้างภายในเสีย (bug) - ดู `check_invariants`
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let path = self.predecessors(key);
+        let found = self.next(path[0], 0)?;
+        if self.node(found).key != *key {
+            return None;
+        }
+        let node = self.nodes[found].take().expect("live node");
+        for (level, &target) in node.forward.iter().enumerate() {
+            self.set_next(path[level], level, target);
+        }
+        while self.level > 1 && self.head[self.level - 1].is_none() {
+            self.level -= 1;
+        }
+        self.free.push(found);
+        self.len -= 1;
+        debug_assert_eq!(self.check_invariants(), Ok(()));
+        Some(node.value)
+    }
+
+    /// เรียงตาม key (เดินชั้น 0)
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        std::iter::successors(self.head[0], |&index| self.node(index).forward[0]).map(|index| {
+            let node = self.node(index);
+            (&node.key, &node.value)
+        })
+    }
+
+    /// จำนวน node ในแต่ละชั้น (ชั้น 0 ก่อน) - ดูว่าชั้นบนบางลงราวครึ่งหนึ่งจริงไหม
+    #[must_use]
+    pub fn level_sizes(&self) -> Vec<usize> {
+        (0..self.level)
+            .map(|level| {
+                std::iter::successors(self.head[level], |&index| self.node(index).forward[level])
+                    .count()
+            })
+            .collect()
+    }
+
+    /// ตรวจ: ทุกชั้นเรียง key จากน้อยไปมากไม่ซ้ำ, ชั้น 0 มีครบ `len` ตัว, node สูงพอกับชั้นที่อยู่
+    ///
+    /// # Errors
+    ///
+    /// คืนคำอธิบายของกฎข้อแรกที่ผิด
+    pub fn check_invariants(&self) -> Result<(), String> {
+        for level in 0..self.level {
+            let mut previous: Option<&K> = None;
+            let mut count = 0;
+            let mut at = self.head[level];
+            while let Some(index) = at {
+                let node = self.node(index);
+                if node.forward.len() <= level {
+                    return Err(format!(
+                        "node {index} linked at level {level} but too short"
+                    ));
+                }
+                if previous.is_some_and(|previous| *previous >= node.key) {
+                    return Err(format!("level {level} is not strictly increasing"));
+                }
+                previous = Some(&node.key);
+                count += 1;
+                at = node.forward[level];
+            }
+            if level == 0 && count != self.len {
+                return Err(format!("level 0 has {count} nodes, len is {}", self.len));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// degree ขั้นต่ำของ B-tree (ไม่ใช่ root): มี key ได้ `T-1..=2T-1` ตัว → ลูก 3-6 ตัวต่อ node
+/// std `BTreeMap` ใช้ B = 6 (key สูงสุด 11 ตัว) ที่นี่ใช้เล็กเพื่อให้เห็นการแยก/รวม node บ่อยๆ
+const BTREE_T: usize = 3;
+
+struct BTreeNode<K, V> {
+    keys: Vec<K>,
+    values: Vec<V>,
+    /// ว่างถ้าเป็น leaf ไม่อย่างนั้นมี `keys.len() + 1` ตัว
+    children: Vec<Self>,
+}
+
+impl<K: Ord, V> BTreeNode<K, V> {
+    const fn leaf() -> Self {
+        Self {
+            keys: Vec::new(),
+            values: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    const fn is_leaf(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    const fn is_full(&self) -> bool {
+        self.keys.len() == 2 * BTREE_T - 1
+    }
+
+    /// แยกลูกตัวที่ `index` ที่เต็มเป็นสองซีก ยก key ตรงกลางขึ้นมาไว้ที่ node นี้
+    fn split_child(&mut self, index: usize) {
+        let child = &mut self.children[index];
+        let right = Self {
+            keys: child.keys.split_off(BTREE_T),
+            values: child.values.split_off(BTREE_T),
+            children: if child.is_leaf() {
+                Vec::new()
+            } else {
+                child.children.split_off(BTREE_T)
+            },
+        };
+        let key = child.keys.pop().expect("full node has a median");
+        let value = child.values.pop().expect("full node has a median");
+        self.keys.insert(index, key);
+        self.values.insert(index, value);
+        self.children.insert(index + 1, right);
+    }
+
+    /// ใส่ลงใน node ที่ไม่เต็ม - แยกลูกที่เต็มก่อนลงไป จึงไม่ต้องย้อนกลับขึ้นมาแยกทีหลัง
+    fn insert_non_full(&mut self, key: K, value: V) {
+        let Err(mut index) = self.keys.binary_search(&key) else {
+            unreachable!("existing keys are replaced before descending");
+        };
+        if self.is_leaf() {
+            self.keys.insert(index, key);
+            self.values.insert(index, value);
+            return;
+        }
+        if self.children[index].is_full() {
+            self.split_child(index);
+            if key > self.keys[index] {
+                index += 1;
+            }
+        }
+        self.children[index].insert_non_full(key, value);
+    }
+
+    /// ทำให้ลูกตัวที่ `index` มี key อย่างน้อย `T` ตัวก่อนลงไปลบ - ยืมจากพี่น้องหรือรวมร่าง
+    /// คืน index ใหม่ของลูกตัวนั้น (เลื่อนไปทางซ้ายถ้ารวมกับพี่น้องฝั่งซ้าย)
+    fn fill_child(&mut self, index: usize) -> usize {
+        if self.children[index].keys.len() >= BTREE_T {
+            return index;
+        }
+        if index > 0 && self.children[index - 1].keys.len() >= BTREE_T {
+            // หมุนขวา: key ของพ่อลงมาที่ลูก, key ตัวท้ายของพี่ซ้ายขึ้นไปแทน
+            let (left, right) = self.children.split_at_mut(index);
+            let (sibling, child) = (&mut left[index - 1], &mut right[0]);
+            let key = sibling.keys.pop().expect("sibling has spare keys");
+            let value = sibling.values.pop().expect("sibling has spare keys");
+            child
+                .keys
+                .insert(0, std::mem::replace(&mut self.keys[index - 1], key));
+            child
+                .values
+                .insert(0, std::mem::replace(&mut self.values[index - 1], value));
+            if let Some(grandchild) = sibling.children.pop() {
+                child.children.insert(0, grandchild);
+            }
+            return index;
+        }
+        if index + 1 < self.children.len() && self.children[index + 1].keys.len() >= BTREE_T {
+            // หมุนซ้าย: กลับด้านของข้างบน
+            let (left, right) = self.children.split_at_mut(index + 1);
+            let (child, sibling) = (&mut left[index], &mut right[0]);
+            let key = sibling.keys.remove(0);
+            let value = sibling.values.remove(0);
+            child
+                .keys
+                .push(std::mem::replace(&mut self.keys[index], key));
+            child
+                .values
+                .push(std::mem::replace(&mut self.values[index], value));
+            if !sibling.is_leaf() {
+                child.children.push(sibling.children.remove(0));
+            }
+            return index;
+        }
+        if index + 1 < self.children.len() {
+            self.merge_children(index);
+            index
+        } else {
+            self.merge_children(index - 1);
+            index - 1
+        }
+    }
+
+    /// รวมลูก `index` + key ของพ่อ + ลูก `index + 1` เป็น node เดียว (key รวม 2T-1 ตัวพอดี)
+    fn merge_children(&mut self, index: usize) {
+        let right = self.children.remove(index + 1);
+        let key = self.keys.remove(index);
+        let value = self.values.remove(index);
+        let left = &mut self.children[index];
+        left.keys.push(key);
+        left.values.push(value);
+        left.keys.extend(right.keys);
+        left.values.extend(right.values);
+        left.children.extend(right.children);
+    }
+
+    fn remove_max(&mut self) -> (K, V) {
+        if self.is_leaf() {
+            let key = self.keys.pop().expect("node on the removal path has keys");
+            let value = self
+                .values
+                .pop()
+                .expect("node on the removal path has keys");
+            return (key, value);
+        }
+        let index = self.fill_child(self.children.len() - 1);
+        self.children[index].remove_max()
+    }
+
+    fn remove_min(&mut self) -> (K, V) {
+        if self.is_leaf() {
+            return (self.keys.remove(0), self.values.remove(0));
+        }
+        let index = self.fill_child(0);
+        self.children[index].remove_min()
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        match self.keys.binary_search(key) {
+            Ok(index) if self.is_leaf() => {
+                self.keys.remove(index);
+                Some(self.values.remove(index))
+            }
+            Ok(index) => {
+                // แทนที่ด้วยตัวก่อนหน้า/ถัดไปจากลูกที่ key เหลือพอ หรือรวมลูกสองข้างแล้วลบในนั้น
+                let replacement = if self.children[index].keys.len() >= BTREE_T {
+                    self.children[index].remove_max()
+                } else if self.children[index + 1].keys.len() >= BTREE_T {
+                    self.children[index + 1].remove_min()
+                } else {
+                    self.merge_children(index);
+                    return self.children[index].remove(key);
+                };
+                self.keys[index] = replacement.0;
+                Some(std::mem::replace(&mut self.values[index], replacement.1))
+            }
+            Err(_) if self.is_leaf() => None,
+            Err(index) => {
+                let index = self.fill_child(index);
+                self.children[index].remove(key)
+            }
+        }
+    }
+
+    /// ความสูงของ subtree ถ้าถูกต้อง - ตรวจจำนวน key, ลำดับ, ขอบเขตเทียบกับพ่อ และ leaf ลึกเท่ากัน
+    fn check(&self, is_root: bool, lower: Option<&K>, upper: Option<&K>) -> Result<usize, String> {
+        let count = self.keys.len();
+        if count > 2 * BTREE_T - 1 || (!is_root && count < BTREE_T - 1) {
+            return Err(format!("node has {count} keys"));
+        }
+        if self.keys.len() != self.values.len() {
+            return Err("keys and values differ in length".to_string());
+        }
+        if self.keys.windows(2).any(|pair| pair[0] >= pair[1]) {
+            return Err("keys are not sorted".to_string());
+        }
+        let (Some(first), Some(last)) = (self.keys.first(), self.keys.last()) else {
+            return if is_root && self.is_leaf() {
+                Ok(1)
+            } else {
+                Err("empty non-root node".to_string())
+            };
+        };
+        if lower.is_some_and(|lower| first <= lower) || upper.is_some_and(|upper| last >= upper) {
+            return Err("key outside the range given by its parent".to_string());
+        }
+        if self.is_leaf() {
+            return Ok(1);
+        }
+        if self.children.len() != count + 1 {
+            return Err(format!("{count} keys but {} children", self.children.len()));
+        }
+        let mut height = None;
+        for (index, child) in self.children.iter().enumerate() {
+            let low = if index == 0 {
+                lower
+            } else {
+                self.keys.get(index - 1)
+            };
+            let high = self.keys.get(index).or(upper);
+            let child_height = child.check(false, low, high)?;
+            if *height.get_or_insert(child_height) != child_height {
+                return Err("leaves are at different depths".to_string());
+            }
+        }
+        Ok(height.unwrap_or(0) + 1)
+    }
+}
+
+/// 🌳 B-tree - แต่ละ node เก็บหลาย key ติดกันในหน่วยความจำ ต้นไม้จึงเตี้ยและเป็นมิตรกับ cache
+///
+/// เหมือน `std::collections::BTreeMap` แต่เขียนแบบตรงไปตรงมาตามตำรา (CLRS):
+/// แยก node ที่เต็มก่อนลงไป insert และเติม node ที่บางก่อนลงไป remove - ไม่ต้องย้อนขึ้นมาแก้
+pub struct BTree<K, V> {
+    root: BTreeNode<K, V>,
+    len: usize,
+}
+
+impl<K: Ord, V> Default for BTree<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord, V> BTree<K, V> {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            root: BTreeNode::leaf(),
+            len: 0,
+        }
+    }
+
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[must_use]
+    pub fn get(&self, key: &K) -> Option<&V> {
+        let mut node = &self.root;
+        loop {
+            match node.keys.binary_search(key) {
+                Ok(index) => return Some(&node.values[index]),
+                Err(_) if node.is_leaf() => return None,
+                Err(index) => node = &node.children[index],
+            }
+        }
+    }
+
+    fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        let mut node = &mut self.root;
+        loop {
+            match node.keys.binary_search(key) {
+                Ok(index) => return Some(&mut node.values[index]),
+                Err(_) if node.is_leaf() => return None,
+                Err(index) => node = &mut node.children[index],
+            }
+        }
+    }
+
+    /// ใส่หรือแทนที่ - คืนค่าเดิมถ้ามี key นี้อยู่แล้ว
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        if let Some(slot) = self.get_mut(&key) {
+            return Some(std::mem::replace(slot, value));
+        }
+        if self.root.is_full() {
+            // root เต็ม - ต้นไม้สูงขึ้นทางเดียวคือแยก root (leaf ทุกตัวจึงลึกเท่ากันเสมอ)
+            let old_root = std::mem::replace(&mut self.root, BTreeNode::leaf());
+            self.root.children.push(old_root);
+            self.root.split_child(0);
+        }
+        self.root.insert_non_full(key, value);
+        self.len += 1;
+        debug_assert_eq!(self.check_invariants(), Ok(()));
+        None
+    }
+
+    /// ลบแล้วคืนค่าของ key นั้น
+    ///
+    /// # Panics
+    ///
+    /// เฉพาะเมื่อโครงสร้างภายในเสีย (bug) - ดู `check_invariants`
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let removed = self.root.remove(key)?;
+        if self.root.keys.is_empty() && !self.root.is_leaf() {
+            // root ถูกรวมจนว่าง - ลูกคนเดียวขึ้นเป็น root ต้นไม้เตี้ยลงหนึ่งชั้น
+            self.root = self
+                .root
+                .children
+                .pop()
+                .expect("empty internal root has one child");
+        }
+        self.len -= 1;
+        debug_assert_eq!(self.check_invariants(), Ok(()));
+        Some(removed)
+    }
+
+    /// จำนวนชั้น (leaf อย่างเดียว = 1)
+    #[must_use]
+    pub fn height(&self) -> usize {
+        let mut height = 1;
+        let mut node = &self.root;
+        while let Some(child) = node.children.first() {
+            node = child;
+            height += 1;
+        }
+        height
+    }
+
+    /// เรียงตาม key - เดินแบบ in-order ด้วย stack แทน recursion
+    #[must_use]
+    pub fn iter(&self) -> BTreeIter<'_, K, V> {
+        let mut iter = BTreeIter { stack: Vec::new() };
+        iter.push_left(&self.root);
+        iter
+    }
+
+    /// ตรวจกฎของ B-tree ทั้งต้น
+    ///
+    /// # Errors
+    ///
+    /// คืนคำอธิบายของกฎข้อแรกที่ผิด
+    pub fn check_invariants(&self) -> Result<(), String> {
+        self.root.check(true, None, None)?;
+        let count = self.iter().count();
+        if count == self.len {
+            Ok(())
+        } else {
+            Err(format!("tree holds {count} keys, len is {}", self.len))
+        }
+    }
+}
+
+impl<'a, K: Ord, V> IntoIterator for &'a BTree<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = BTreeIter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// iterator ของ `BTree` - แต่ละช่องใน stack คือ (node, key ตัวถัดไปที่จะคืน)
+pub struct BTreeIter<'a, K, V> {
+    stack: Vec<(&'a BTreeNode<K, V>, usize)>,
+}
+
+impl<'a, K, V> BTreeIter<'a, K, V> {
+    fn push_left(&mut self, mut node: &'a BTreeNode<K, V>) {
+        loop {
+            self.stack.push((node, 0));
+            match node.children.first() {
+                Some(child) => node = child,
+                None => break,
+            }
+        }
+    }
+}
+
+impl<'a, K, V> Iterator for BTreeIter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (node, index) = self.stack.pop()?;
+            if index < node.keys.len() {
+                self.stack.push((node, index + 1));
+                if let Some(child) = node.children.get(index + 1) {
+                    self.push_left(child);
+                }
+                return Some((&node.keys[index], &node.values[index]));
+            }
+        }
+    }
+}
+
+/// key `0..n` แบบสลับลำดับ (seed ตายตัว) - ใส่เรียงลำดับจะได้ต้นไม้ที่ไม่เหมือนการใช้งานจริง
+#[must_use]
+pub fn shuffled_keys(n: u64) -> Vec<u64> {
+    let mut keys: Vec<u64> = (0..n).collect();
+    Xorshift64::seed_from_u64(n).shuffle(&mut keys);
+    keys
+}
+
+fn demonstrate_handmade_maps() {
+    println!("\n🪜 === SkipList และ 🌳 BTree: เขียนเองเทียบกับ std === 🪜");
+
+    let mut ladder = SkipList::new();
+    let mut tree = BTree::new();
+    for key in shuffled_keys(64) {
+        ladder.insert(key, key * key);
+        tree.insert(key, key * key);
+    }
+    println!(
+        "🪜 SkipList 64 ตัว - จำนวน node ต่อชั้น (ล่างขึ้นบน): {:?}",
+        ladder.level_sizes()
+    );
+    println!(
+        "🌳 BTree 64 ตัว (key 2-5 ตัวต่อ node) สูงแค่ {} ชั้น",
+        tree.height()
+    );
+    println!(
+        "🔍 get(&7): SkipList = {:?}, BTree = {:?}",
+        ladder.get(&7),
+        tree.get(&7)
+    );
+
+    for key in (0..64).step_by(2) {
+        ladder.remove(&key);
+        tree.remove(&key);
+    }
+    let first: Vec<_> = tree.iter().take(5).map(|(key, _)| *key).collect();
+    println!(
+        "🗑️ ลบเลขคู่ออกแล้ว เหลือ {} ตัว เริ่มที่ {first:?} สูง {} ชั้น",
+        tree.len(),
+        tree.height()
+    );
+    let same = ladder
+        .iter()
+        .map(|(key, _)| key)
+        .eq(tree.iter().map(|(key, _)| key));
+    println!("🤝 ลำดับ key ของทั้งสองตรงกัน: {same}");
+
+    println!("⏱️ ใส่ + ค้นหา 500 key (median ต่อรอบ):");
+    let keys = shuffled_keys(500);
+    let results = [
+        measure("SkipList", || {
+            let mut map = SkipList::new();
+            for &key in &keys {
+                map.insert(key, key);
+            }
+            keys.iter().filter_map(|key| map.get(key)).count()
+        }),
+        measure("BTree", || {
+            let mut map = BTree::new();
+            for &key in &keys {
+                map.insert(key, key);
+            }
+            keys.iter().filter_map(|key| map.get(key)).count()
+        }),
+        measure("std BTreeMap", || {
+            let mut map = BTreeMap::new();
+            for &key in &keys {
+                map.insert(key, key);
+            }
+            keys.iter().filter_map(|key| map.get(key)).count()
+        }),
+        measure("std HashMap", || {
+            let mut map = HashMap::new();
+            for &key in &keys {
+                map.insert(key, key);
+            }
+            keys.iter().filter_map(|key| map.get(key)).count()
+        }),
+    ];
+    for result in &results {
+        println!("   {:<14} {:>12?}", result.name, result.median);
+    }
+    println!(
+        "💡 debug build เปิด debug_assert ตรวจกฎทุกครั้งที่แก้ - วัดจริงด้วย cargo bench --bench chapter_claims"
+    );
+}
+
+fn measure<R>(name: &str, f: impl FnMut() -> R) -> MicroBenchResult {
+    microbench::measure(name, 5, f)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_skip_list_operations() {
+        let mut list = SkipList::new();
+        assert_eq!(list.get(&1), None);
+        assert_eq!(list.remove(&1), None);
+        for key in shuffled_keys(500) {
+            assert_eq!(list.insert(key, key * 10), None);
+        }
+        assert_eq!(list.insert(42, 0), Some(420));
+        assert_eq!(list.len(), 500);
+        assert_eq!(list.get(&42), Some(&0));
+        assert!(list.iter().map(|(key, _)| *key).eq(0..500));
+
+        // ชั้นบนบางลงเรื่อยๆ
+        let sizes = list.level_sizes();
+        assert_eq!(sizes[0], 500);
+        assert!(sizes.windows(2).all(|pair| pair[0] >= pair[1]));
+
+        for key in (0..500).filter(|key| key % 3 != 0) {
+            assert_eq!(list.remove(&key), Some(key * 10), "key {key}");
+        }
+        assert_eq!(list.remove(&1), None);
+        assert_eq!(list.len(), 167);
+        assert!(list.iter().map(|(key, _)| *key).eq((0..500).step_by(3)));
+        // ช่องที่ลบถูกใช้ซ้ำ
+        list.insert(1, 1);
+        assert_eq!(list.nodes.len(), 500);
+        assert_eq!(list.check_invariants(), Ok(()));
+    }
+
+    #[test]
+    fn test_btree_operations() {
+        let mut tree = BTree::new();
+        assert_eq!(tree.height(), 1);
+        assert_eq!(tree.remove(&1), None);
+        for key in shuffled_keys(1_000) {
+            assert_eq!(tree.insert(key, key.to_string()), None);
+        }
+        assert_eq!(tree.insert(7, "seven".to_string()), Some("7".to_string()));
+        assert_eq!(tree.len(), 1_000);
+        assert_eq!(tree.get(&7).map(String::as_str), Some("seven"));
+        assert_eq!(tree.get(&1_000), None);
+        assert!(tree.iter().map(|(key, _)| *key).eq(0..1_000));
+        // node มีลูกอย่างน้อย 3 ตัว: 1,000 key สูงไม่เกิน log₃(1000) + 1 ชั้น
+        assert!((4..=7).contains(&tree.height()), "height {}", tree.height());
+
+        // ลบแบบสลับลำดับจนหมด - ผ่านทุกกรณีของการยืม/รวม node
+        for key in shuffled_keys(1_000).into_iter().rev() {
+            assert!(tree.remove(&key).is_some(), "key {key}");
+            assert_eq!(tree.get(&key), None);
+        }
+        assert!(tree.is_empty());
+        assert_eq!(tree.height(), 1);
+        assert_eq!(tree.check_invariants(), Ok(()));
+    }
+
+    #[test]
+    fn test_handmade_maps_match_std() {
+        let mut model = BTreeMap::new();
+        let mut skip = SkipList::new();
+        let mut tree = BTree::new();
+        let mut rng = Xorshift64::seed_from_u64(7);
+        for step in 0..3_000_u64 {
+            let key = rng.gen_range(0..200);
+            if rng.gen_range(0..3) == 0 {
+                let expected = model.remove(&key);
+                assert_eq!(skip.remove(&key), expected, "step {step}");
+                assert_eq!(tree.remove(&key), expected, "step {step}");
+            } else {
+                let expected = model.insert(key, step);
+                assert_eq!(skip.insert(key, step), expected, "step {step}");
+                assert_eq!(tree.insert(key, step), expected, "step {step}");
+            }
+        }
+        assert!(skip.iter().eq(model.iter()));
+        assert!(tree.iter().eq(model.iter()));
+    }
+}
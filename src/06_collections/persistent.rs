@@ -0,0 +1,514 @@
+//! Persistent Collections - แก้ไขแล้วได้ "เวอร์ชันใหม่" โดยเวอร์ชันเก่ายังอยู่ครบ 🕰️🌳
+//!
+//! แทนที่จะ copy ทั้งก้อน เวอร์ชันใหม่ใช้ node เดิมร่วมกับเวอร์ชันเก่าผ่าน `Rc` (structural sharing)
+//! และ copy เฉพาะ node บนเส้นทางที่เปลี่ยน (path copying)
+//! - 🔗 `List<T>` - linked list ทางเดียว: `push_front` ได้ list ใหม่ที่ชี้ไปยัง list เดิมทั้งเส้น
+//! - 🌲 `PVec<T>` - vector แบบ trie แบ่งบิต (ทีละ 2 บิต = ลูก 4 ตัวต่อ node ให้เห็นโครงสร้างง่าย)
+//!   `set`/`push` copy แค่ node ตามความลึก O(log₄ n) แทนที่จะ copy ทั้ง n ตัวแบบ `Vec::clone`
+//!
+//! ใช้ใน undo/redo, snapshot ของ state ใน game loop หรือแชร์ข้อมูลให้หลายเวอร์ชันอ่านพร้อมกัน
+
+use std::fmt;
+use std::hint::black_box;
+use std::rc::Rc;
+
+// ===== List =====
+
+struct ListNode<T> {
+    value: T,
+    next: Option<Rc<Self>>,
+}
+
+/// 🔗 linked list ที่ไม่มีวันเปลี่ยน - `clone` แค่เพิ่ม reference count
+pub struct List<T> {
+    head: Option<Rc<ListNode<T>>>,
+    len: usize,
+}
+
+impl<T> List<T> {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { head: None, len: 0 }
+    }
+
+    /// list ใหม่ที่มี `value` นำหน้า - list เดิมกลายเป็นหางของ list ใหม่ทั้งเส้น
+    #[must_use]
+    pub fn push_front(&self, value: T) -> Self {
+        Self {
+            head: Some(Rc::new(ListNode {
+                value,
+                next: self.head.clone(),
+            })),
+            len: self.len + 1,
+        }
+    }
+
+    /// list ที่ไม่มีตัวแรก - ไม่ copy อะไรเลย แค่ชี้ไปยัง node ที่สอง
+    #[must_use]
+    pub fn tail(&self) -> Self {
+        self.head.as_ref().map_or_else(Self::new, |node| Self {
+            head: node.next.clone(),
+            len: self.len - 1,
+        })
+    }
+
+    #[must_use]
+    pub fn head(&self) -> Option<&T> {
+        self.head.as_ref().map(|node| &node.value)
+    }
+
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        std::iter::successors(self.head.as_deref(), |node| node.next.as_deref())
+            .map(|node| &node.value)
+    }
+
+    /// จำนวน node ท้าย list ที่ใช้ร่วมกับ `other` (เป็น node เดียวกันในหน่วยความจำ)
+    #[must_use]
+    pub fn shared_with(&self, other: &Self) -> usize {
+        let mut mine = self.head.clone();
+        // ตัดให้ยาวเท่ากันก่อน ส่วนที่แชร์ต้องอยู่ท้ายทั้งสองฝั่ง
+        for _ in other.len..self.len {
+            mine = mine.and_then(|node| node.next.clone());
+        }
+        let mut theirs = other.head.clone();
+        for _ in self.len..other.len {
+            theirs = theirs.and_then(|node| node.next.clone());
+        }
+        let mut remaining = self.len.min(other.len);
+        while let (Some(a), Some(b)) = (&mine, &theirs) {
+            if Rc::ptr_eq(a, b) {
+                return remaining;
+            }
+            remaining -= 1;
+            (mine, theirs) = (a.next.clone(), b.next.clone());
+        }
+        0
+    }
+}
+
+impl<T> Clone for List<T> {
+    fn clone(&self) -> Self {
+        Self {
+            head: self.head.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<T> Default for List<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for List<T> {
+    /// drop แบบวนลูป - drop ตามปกติจะ recursive ทีละ node และ stack ล้นเมื่อ list ยาวมาก
+    /// หยุดทันทีที่เจอ node ที่ยังมีคนอื่นถืออยู่ (ส่วนที่แชร์ต้องอยู่ต่อ)
+    fn drop(&mut self) {
+        let mut next = self.head.take();
+        while let Some(node) = next {
+            next = match Rc::try_unwrap(node) {
+                Ok(mut node) => node.next.take(),
+                Err(_) => break,
+            };
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for List<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> FromIterator<T> for List<T> {
+    /// ลำดับเหมือน iterator ต้นทาง (ต่อจากท้ายไปหน้า)
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut items: Vec<T> = iter.into_iter().collect();
+        let mut list = Self::new();
+        while let Some(item) = items.pop() {
+            list = list.push_front(item);
+        }
+        list
+    }
+}
+
+// ===== PVec =====
+
+/// บิตต่อชั้นของ trie - ลูก `1 << BITS` ตัวต่อ node (Clojure ใช้ 5 บิต = 32 ตัว)
+const BITS: usize = 2;
+const WIDTH: usize = 1 << BITS;
+const MASK: usize = WIDTH - 1;
+
+enum Node<T> {
+    Branch(Vec<Rc<Self>>),
+    Leaf(Vec<T>),
+}
+
+impl<T> Node<T> {
+    fn count(&self) -> usize {
+        match self {
+            Self::Branch(children) => 1 + children.iter().map(|child| child.count()).sum::<usize>(),
+            Self::Leaf(_) => 1,
+        }
+    }
+}
+
+/// 🌲 vector ที่ไม่มีวันเปลี่ยน - index `i` เดินลง trie ทีละ `BITS` บิตจากบิตสูงไปต่ำ
+///
+/// ```text
+/// len 10, shift 2:      [root]
+///                  /      |      \
+///             [0..4]   [4..8]   [8..10]
+/// ```
+pub struct PVec<T> {
+    root: Rc<Node<T>>,
+    len: usize,
+    /// จำนวนบิตที่ชั้นบนสุดใช้ - ความลึกของ trie คือ `shift / BITS + 1`
+    shift: usize,
+}
+
+impl<T: Clone> PVec<T> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            root: Rc::new(Node::Leaf(Vec::new())),
+            len: 0,
+            shift: 0,
+        }
+    }
+
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// จำนวนชั้นของ trie
+    #[must_use]
+    pub const fn depth(&self) -> usize {
+        self.shift / BITS + 1
+    }
+
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len {
+            return None;
+        }
+        let mut node = &self.root;
+        let mut shift = self.shift;
+        loop {
+            match node.as_ref() {
+                Node::Branch(children) => {
+                    node = &children[(index >> shift) & MASK];
+                    shift -= BITS;
+                }
+                Node::Leaf(items) => return items.get(index & MASK),
+            }
+        }
+    }
+
+    /// เวอร์ชันใหม่ที่ต่อ `value` ท้าย - trie เต็มเมื่อไหร่ก็เพิ่มชั้นใหม่ครอบ root เดิม
+    #[must_use]
+    pub fn push(&self, value: T) -> Self {
+        let capacity = WIDTH << self.shift;
+        if self.len == capacity {
+            let root = Node::Branch(vec![Rc::clone(&self.root), new_path(self.shift, value)]);
+            return Self {
+                root: Rc::new(root),
+                len: self.len + 1,
+                shift: self.shift + BITS,
+            };
+        }
+        Self {
+            root: push_into(&self.root, self.shift, self.len, value),
+            len: self.len + 1,
+            shift: self.shift,
+        }
+    }
+
+    /// เวอร์ชันใหม่ที่ตำแหน่ง `index` เป็น `value` - `None` ถ้าเกินขนาด
+    #[must_use]
+    pub fn set(&self, index: usize, value: T) -> Option<Self> {
+        (index < self.len).then(|| Self {
+            root: set_in(&self.root, self.shift, index, value),
+            len: self.len,
+            shift: self.shift,
+        })
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        (0..self.len).filter_map(|index| self.get(index))
+    }
+
+    /// จำนวน node ทั้งหมดของเวอร์ชันนี้ และจำนวนที่ใช้ร่วมกับ `other` (node เดียวกันในหน่วยความจำ)
+    #[must_use]
+    pub fn sharing_with(&self, other: &Self) -> (usize, usize) {
+        let shared = if self.shift == other.shift {
+            shared_nodes(&self.root, &other.root)
+        } else {
+            // trie สูงไม่เท่ากัน - root เก่าอาจเป็นลูกคนแรกของ root ใหม่ (หลัง push ที่เพิ่มชั้น)
+            let (tall, short) = if self.shift > other.shift {
+                (self, other)
+            } else {
+                (other, self)
+            };
+            let mut node = &tall.root;
+            for _ in 0..(tall.shift - short.shift) / BITS {
+                match node.as_ref() {
+                    Node::Branch(children) => node = &children[0],
+                    Node::Leaf(_) => break,
+                }
+            }
+            shared_nodes(node, &short.root)
+        };
+        (self.root.count(), shared)
+    }
+}
+
+fn new_path<T>(shift: usize, value: T) -> Rc<Node<T>> {
+    if shift == 0 {
+        Rc::new(Node::Leaf(vec![value]))
+    } else {
+        Rc::new(Node::Branch(vec![new_path(shift - BITS, value)]))
+    }
+}
+
+/// copy node บนเส้นทางจาก root ถึง leaf ที่ต้องเพิ่มค่า - node ข้างเคียงใช้ `Rc` เดิม
+fn push_into<T: Clone>(node: &Rc<Node<T>>, shift: usize, index: usize, value: T) -> Rc<Node<T>> {
+    match node.as_ref() {
+        Node::Leaf(items) => {
+            let mut items = items.clone();
+            items.push(value);
+            Rc::new(Node::Leaf(items))
+        }
+        Node::Branch(children) => {
+            let slot = (index >> shift) & MASK;
+            let mut children = children.clone();
+            if let Some(child) = children.get(slot) {
+                children[slot] = push_into(child, shift - BITS, index, value);
+            } else {
+                children.push(new_path(shift - BITS, value));
+            }
+            Rc::new(Node::Branch(children))
+        }
+    }
+}
+
+fn set_in<T: Clone>(node: &Rc<Node<T>>, shift: usize, index: usize, value: T) -> Rc<Node<T>> {
+    match node.as_ref() {
+        Node::Leaf(items) => {
+            let mut items = items.clone();
+            items[index & MASK] = value;
+            Rc::new(Node::Leaf(items))
+        }
+        Node::Branch(children) => {
+            let slot = (index >> shift) & MASK;
+            let mut children = children.clone();
+            children[slot] = set_in(&children[slot], shift - BITS, index, value);
+            Rc::new(Node::Branch(children))
+        }
+    }
+}
+
+fn shared_nodes<T>(a: &Rc<Node<T>>, b: &Rc<Node<T>>) -> usize {
+    if Rc::ptr_eq(a, b) {
+        return a.count();
+    }
+    match (a.as_ref(), b.as_ref()) {
+        (Node::Branch(left), Node::Branch(right)) => left
+            .iter()
+            .zip(right)
+            .map(|(a, b)| shared_nodes(a, b))
+            .sum(),
+        _ => 0,
+    }
+}
+
+impl<T> Clone for PVec<T> {
+    fn clone(&self) -> Self {
+        Self {
+            root: Rc::clone(&self.root),
+            len: self.len,
+            shift: self.shift,
+        }
+    }
+}
+
+impl<T: Clone> Default for PVec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone + fmt::Debug> fmt::Debug for PVec<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Clone> FromIterator<T> for PVec<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        iter.into_iter()
+            .fold(Self::new(), |vector, item| vector.push(item))
+    }
+}
+
+/// ฟังก์ชันสำหรับสอนเรื่อง persistent collections - ไทม์แมชชีนของข้อมูล! 🕰️
+pub fn learn_persistent_collections() {
+    println!("🕰️ === Persistent Collections: แก้แล้วของเก่ายังอยู่! === 🕰️");
+
+    println!("\n🔗 List - เวอร์ชันใหม่ต่อหัวบนหางเดิม:");
+    let base: List<&str> = ["ข้าว", "ไข่"].into_iter().collect();
+    let breakfast = base.push_front("กาแฟ");
+    let lunch = base.push_front("ส้มตำ").push_front("ไก่ย่าง");
+    let show = |list: &List<&str>| list.iter().copied().collect::<Vec<_>>().join(" → ");
+    println!("   📜 base      = {}", show(&base));
+    println!(
+        "   ☕ breakfast = {} (แชร์ {} node กับ base)",
+        show(&breakfast),
+        breakfast.shared_with(&base)
+    );
+    println!(
+        "   🍗 lunch     = {} (แชร์ {} node กับ breakfast)",
+        show(&lunch),
+        lunch.shared_with(&breakfast)
+    );
+
+    println!("\n🌲 PVec - แก้ตำแหน่งเดียว copy แค่เส้นทางจาก root:");
+    let scores: PVec<u32> = (0..16).collect();
+    let Some(updated) = scores.set(5, 99) else {
+        return;
+    };
+    let (total, shared) = updated.sharing_with(&scores);
+    println!(
+        "   📊 scores[5] = {:?} → updated[5] = {:?}",
+        scores.get(5),
+        updated.get(5)
+    );
+    println!(
+        "   🌳 depth {} มี {total} node ใช้ร่วมกัน {shared} node สร้างใหม่แค่ {}",
+        updated.depth(),
+        total - shared
+    );
+    let grown = scores.push(16);
+    let (total, shared) = grown.sharing_with(&scores);
+    println!(
+        "   ➕ push ตัวที่ 17 trie เต็ม → เพิ่มชั้น: depth {} มี {total} node แชร์ {shared}",
+        grown.depth()
+    );
+
+    println!("\n⏱️ ราคาของการได้เวอร์ชันใหม่ที่แก้ 1 ตัว (n = 10,000, median ต่อครั้ง):");
+    let vector: Vec<u64> = (0..10_000).collect();
+    let persistent: PVec<u64> = (0..10_000).collect();
+    let results = [
+        measure("Vec::clone + แก้", || {
+            let mut copy = black_box(&vector).clone();
+            copy[5_000] = 0;
+            copy
+        }),
+        measure("PVec::set", || black_box(&persistent).set(5_000, 0)),
+    ];
+    for result in &results {
+        println!("   {:<18} {:>12?}", result.name, result.median);
+    }
+    println!(
+        "   💡 Vec copy ทั้ง 10,000 ตัว ส่วน PVec copy แค่ {} node ({} ค่าต่อ node) - แต่ get ของ PVec ช้ากว่าเพราะต้องเดินลง trie",
+        persistent.depth(),
+        WIDTH
+    );
+}
+
+fn measure<R>(
+    name: &str,
+    f: impl FnMut() -> R,
+) -> crate::performance::microbench::MicroBenchResult {
+    crate::performance::microbench::measure(name, 9, f)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_list_versions_are_independent() {
+        let empty = List::new();
+        let one = empty.push_front(1);
+        let two = one.push_front(2);
+        let other = one.push_front(20);
+
+        assert!(empty.is_empty());
+        assert_eq!(one.iter().copied().collect::<Vec<_>>(), [1]);
+        assert_eq!(two.iter().copied().collect::<Vec<_>>(), [2, 1]);
+        assert_eq!(other.iter().copied().collect::<Vec<_>>(), [20, 1]);
+        assert_eq!(two.shared_with(&other), 1);
+        assert_eq!(two.tail().shared_with(&one), 1);
+        assert_eq!(two.tail().head(), Some(&1));
+        assert_eq!(empty.tail().len(), 0);
+
+        // drop เวอร์ชันใหม่แล้วเวอร์ชันเก่ายังอยู่ครบ
+        drop(two);
+        drop(other);
+        assert_eq!(format!("{one:?}"), "[1]");
+
+        // list ยาวมาก drop ได้โดยไม่ stack overflow
+        let long: List<u32> = (0..200_000).collect();
+        assert_eq!(long.head(), Some(&0));
+        drop(long);
+    }
+
+    #[test]
+    fn test_pvec_old_versions_unchanged() {
+        let mut versions = vec![PVec::new()];
+        for value in 0..100_u32 {
+            let next = versions.last().unwrap().push(value);
+            versions.push(next);
+        }
+        for (len, version) in versions.iter().enumerate() {
+            assert_eq!(version.len(), len);
+            assert!(version.iter().copied().eq(0..u32::try_from(len).unwrap()));
+        }
+        assert_eq!(versions[100].depth(), 4);
+        assert_eq!(versions[100].get(100), None);
+
+        let original = &versions[100];
+        let edited = original.set(63, 999).unwrap();
+        assert_eq!(edited.get(63), Some(&999));
+        assert_eq!(original.get(63), Some(&63));
+        assert!(original.set(100, 0).is_none());
+        assert_eq!(original.iter().filter(|&&value| value == 999).count(), 0);
+    }
+
+    #[test]
+    fn test_pvec_structural_sharing() {
+        let full: PVec<u8> = (0..16).collect();
+        assert_eq!(full.depth(), 2);
+
+        // set copy เฉพาะ root + leaf หนึ่งตัว: 5 node เดิม แชร์ได้ 3 leaf
+        let edited = full.set(5, 0).unwrap();
+        assert_eq!(edited.sharing_with(&full), (5, 3));
+
+        // push ตอนเต็ม - root ใหม่ + เส้นทางใหม่ 2 node, trie เดิมทั้ง 5 node กลายเป็นลูกคนแรก
+        let grown = full.push(16);
+        assert_eq!(grown.depth(), 3);
+        assert_eq!(grown.sharing_with(&full), (8, 5));
+        assert_eq!(full.sharing_with(&grown), (5, 5));
+
+        let copy = full.clone();
+        assert_eq!(copy.sharing_with(&full), (5, 5));
+    }
+}
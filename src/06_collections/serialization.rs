@@ -0,0 +1,410 @@
+//! Serialization - เก็บ collections ลงไฟล์แล้วโหลดกลับด้วย serde 💾🔁
+//!
+//! serde แยก "โครงสร้างข้อมูล" ออกจาก "รูปแบบไฟล์": เขียน `Serialize`/`Deserialize` ครั้งเดียว
+//! แล้วใช้ได้กับ JSON, TOML, bincode และอีกหลายสิบ format
+//!
+//! - ✍️ `PlayerSave` เขียน `Serialize`/`Deserialize` เองเพื่อรองรับไฟล์ save เวอร์ชันเก่า
+//! - 🫓 `#[serde(flatten)]` แผ่ field ของ struct ลูกขึ้นมาอยู่ชั้นเดียวกับแม่
+//! - 🏷️ `#[serde(tag, content)]` (adjacently tagged) เก็บชื่อ variant กับข้อมูลแยกกัน
+//!
+//! ```
+//! use rust_concepts::collections::serialization::PlayerSave;
+//!
+//! // save เวอร์ชัน 1 เก็บแค่ xp - โหลดแล้วแปลงเป็น level ให้อัตโนมัติ
+//! let old: PlayerSave = serde_json::from_str(r#"{"version":1,"name":"Ann","xp":250}"#).unwrap();
+//! assert_eq!(old.level, 3);
+//! assert!(old.inventory.is_empty());
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use serde::de::{self, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// เวอร์ชันปัจจุบันของ `PlayerSave`
+pub const SAVE_VERSION: u32 = 2;
+
+/// xp ต่อ 1 level ในไฟล์ save เวอร์ชัน 1
+const XP_PER_LEVEL: u64 = 100;
+
+/// 💾 ไฟล์ save ของผู้เล่น
+///
+/// - v1: `{ version: 1, name, xp }`
+/// - v2: `{ version: 2, name, level, inventory }` (ไม่มี `version` ถือเป็น v1)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlayerSave {
+    pub name: String,
+    pub level: u32,
+    pub inventory: Vec<String>,
+}
+
+impl Serialize for PlayerSave {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // ลำดับ field สำคัญกับ format ที่ไม่มีชื่อ field อย่าง bincode: version ต้องมาก่อนเสมอ
+        let mut state = serializer.serialize_struct("PlayerSave", 4)?;
+        state.serialize_field("version", &SAVE_VERSION)?;
+        state.serialize_field("name", &self.name)?;
+        state.serialize_field("level", &self.level)?;
+        state.serialize_field("inventory", &self.inventory)?;
+        state.end()
+    }
+}
+
+fn level_from_xp(xp: u64) -> u32 {
+    u32::try_from(xp / XP_PER_LEVEL).map_or(u32::MAX, |level| level.saturating_add(1))
+}
+
+struct PlayerSaveVisitor;
+
+impl<'de> Visitor<'de> for PlayerSaveVisitor {
+    type Value = PlayerSave;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a player save (version 1 or 2)")
+    }
+
+    /// format ที่มีชื่อ field (JSON/TOML) - field มาลำดับไหนก็ได้
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<PlayerSave, A::Error> {
+        let mut version = None;
+        let mut name = None;
+        let mut level = None;
+        let mut xp = None;
+        let mut inventory = None;
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "version" => version = Some(map.next_value::<u32>()?),
+                "name" => name = Some(map.next_value::<String>()?),
+                "level" => level = Some(map.next_value::<u32>()?),
+                "xp" => xp = Some(map.next_value::<u64>()?),
+                "inventory" => inventory = Some(map.next_value::<Vec<String>>()?),
+                _ => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+
+        let name = name.ok_or_else(|| de::Error::missing_field("name"))?;
+        match version.unwrap_or(1) {
+            1 => Ok(PlayerSave {
+                name,
+                level: level_from_xp(xp.ok_or_else(|| de::Error::missing_field("xp"))?),
+                inventory: Vec::new(),
+            }),
+            SAVE_VERSION => Ok(PlayerSave {
+                name,
+                level: level.ok_or_else(|| de::Error::missing_field("level"))?,
+                inventory: inventory.unwrap_or_default(),
+            }),
+            other => Err(unsupported_version(other)),
+        }
+    }
+
+    /// format ที่เรียงตามตำแหน่ง (bincode) - อ่าน version ก่อนแล้วค่อยรู้ว่า field ถัดไปคืออะไร
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<PlayerSave, A::Error> {
+        let version: u32 = seq_element(&mut seq, 0)?;
+        let name = seq_element(&mut seq, 1)?;
+        match version {
+            1 => {
+                let xp: u64 = seq_element(&mut seq, 2)?;
+                Ok(PlayerSave {
+                    name,
+                    level: level_from_xp(xp),
+                    inventory: Vec::new(),
+                })
+            }
+            SAVE_VERSION => {
+                let level = seq_element(&mut seq, 2)?;
+                let inventory = seq_element(&mut seq, 3)?;
+                Ok(PlayerSave {
+                    name,
+                    level,
+                    inventory,
+                })
+            }
+            other => Err(unsupported_version(other)),
+        }
+    }
+}
+
+fn seq_element<'de, A, T>(seq: &mut A, index: usize) -> Result<T, A::Error>
+where
+    A: SeqAccess<'de>,
+    T: Deserialize<'de>,
+{
+    seq.next_element()?
+        .ok_or_else(|| de::Error::invalid_length(index, &"a complete player save"))
+}
+
+fn unsupported_version<E: de::Error>(version: u32) -> E {
+    E::custom(format_args!(
+        "unsupported save version {version} (newest is {SAVE_VERSION})"
+    ))
+}
+
+impl<'de> Deserialize<'de> for PlayerSave {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_struct(
+            "PlayerSave",
+            &["version", "name", "level", "inventory"],
+            PlayerSaveVisitor,
+        )
+    }
+}
+
+/// 📊 ค่าสถานะที่ถูกแผ่ออกมาใน `Character`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    pub hp: u32,
+    pub mp: u32,
+}
+
+/// 🧙 ตัวละคร - JSON จะเป็น `{"name":..,"hp":..,"mp":..}` ไม่มีชั้น `stats`
+///
+/// field ที่ไม่รู้จักทั้งหมดตกไปอยู่ใน `extra` (flatten กับ map เก็บของที่เหลือ)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Character {
+    pub name: String,
+    #[serde(flatten)]
+    pub stats: Stats,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, String>,
+}
+
+/// 📨 ข้อความในเกม - JSON จะเป็น `{"type":"Move","data":{"x":1,"y":2}}`
+///
+/// ใช้ได้กับ format ที่บอกชื่อ field (JSON/TOML) เท่านั้น - bincode เขียนได้แต่อ่านกลับไม่ได้
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "data")]
+pub enum GameMessage {
+    Chat { from: String, text: String },
+    Move { x: i32, y: i32 },
+    Ping,
+}
+
+/// 🗂️ format ที่ใช้เปรียบเทียบ
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Json,
+    JsonPretty,
+    Toml,
+    Bincode,
+}
+
+impl Format {
+    pub const ALL: [Self; 4] = [Self::Json, Self::JsonPretty, Self::Toml, Self::Bincode];
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Json => "JSON",
+            Self::JsonPretty => "JSON (pretty)",
+            Self::Toml => "TOML",
+            Self::Bincode => "bincode",
+        })
+    }
+}
+
+/// ❌ แปลงด้วย format หนึ่งไม่สำเร็จ
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatError {
+    pub format: Format,
+    pub message: String,
+}
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} failed: {}", self.format, self.message)
+    }
+}
+
+impl std::error::Error for FormatError {}
+
+/// แปลง `value` เป็น bytes ตาม `format`
+///
+/// # Errors
+///
+/// คืน `FormatError` ถ้า format นั้นแทนข้อมูลนี้ไม่ได้ (เช่น TOML ไม่มี `null`, bincode ไม่รองรับ flatten)
+pub fn encode<T: Serialize>(value: &T, format: Format) -> Result<Vec<u8>, FormatError> {
+    let error = |message: String| FormatError { format, message };
+    match format {
+        Format::Json => serde_json::to_vec(value).map_err(|e| error(e.to_string())),
+        Format::JsonPretty => serde_json::to_vec_pretty(value).map_err(|e| error(e.to_string())),
+        Format::Toml => toml::to_string(value)
+            .map(String::into_bytes)
+            .map_err(|e| error(e.to_string())),
+        Format::Bincode => bincode::serialize(value).map_err(|e| error(e.to_string())),
+    }
+}
+
+/// อ่าน bytes ที่ได้จาก `encode` กลับเป็น `T`
+///
+/// # Errors
+///
+/// คืน `FormatError` ถ้าข้อมูลเสียหรือไม่ตรงกับ `T`
+pub fn decode<T: for<'de> Deserialize<'de>>(
+    bytes: &[u8],
+    format: Format,
+) -> Result<T, FormatError> {
+    let error = |message: String| FormatError { format, message };
+    match format {
+        Format::Json | Format::JsonPretty => {
+            serde_json::from_slice(bytes).map_err(|e| error(e.to_string()))
+        }
+        Format::Toml => {
+            let text = std::str::from_utf8(bytes).map_err(|e| error(e.to_string()))?;
+            toml::from_str(text).map_err(|e| error(e.to_string()))
+        }
+        Format::Bincode => bincode::deserialize(bytes).map_err(|e| error(e.to_string())),
+    }
+}
+
+/// ขนาด (bytes) ของ `value` ในแต่ละ format - format ที่แทนข้อมูลไม่ได้คืน `Err`
+pub fn format_sizes<T: Serialize>(value: &T) -> Vec<(Format, Result<usize, FormatError>)> {
+    Format::ALL
+        .into_iter()
+        .map(|format| (format, encode(value, format).map(|bytes| bytes.len())))
+        .collect()
+}
+
+/// ตัวอย่าง serialization ด้วย serde
+pub fn learn_serialization() {
+    println!("💾 === Serialization ด้วย serde ===");
+
+    let save = PlayerSave {
+        name: "Somchai".to_string(),
+        level: 7,
+        inventory: vec!["ดาบไม้".to_string(), "ยาแดง".to_string()],
+    };
+    println!("📏 ขนาดของ PlayerSave ในแต่ละ format:");
+    for (format, size) in format_sizes(&save) {
+        match size {
+            Ok(bytes) => println!("   • {format:<14} {bytes:>4} bytes"),
+            Err(error) => println!("   • {error}"),
+        }
+    }
+
+    match serde_json::from_str::<PlayerSave>(r#"{"name":"Old Timer","xp":420}"#) {
+        Ok(upgraded) => println!("⬆️ save v1 (ไม่มี version) → level {}", upgraded.level),
+        Err(error) => println!("❌ {error}"),
+    }
+
+    let hero = Character {
+        name: "Mali".to_string(),
+        stats: Stats { hp: 120, mp: 40 },
+        extra: BTreeMap::from([("guild".to_string(), "Naga".to_string())]),
+    };
+    if let Ok(json) = serde_json::to_string(&hero) {
+        println!("🫓 flatten: {json}");
+    }
+    if let Err(error) = encode(&hero, Format::Bincode) {
+        println!("🫓 แต่ bincode ต้องรู้จำนวน field ล่วงหน้า: {error}");
+    }
+
+    let message = GameMessage::Move { x: 3, y: -1 };
+    if let Ok(json) = serde_json::to_string(&message) {
+        println!("🏷️ adjacently tagged: {json}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_save() -> PlayerSave {
+        PlayerSave {
+            name: "Ann".to_string(),
+            level: 12,
+            inventory: vec!["bow".to_string(), "rope".to_string()],
+        }
+    }
+
+    #[test]
+    fn test_player_save_round_trips_through_every_format() {
+        let save = sample_save();
+        for format in Format::ALL {
+            let bytes = encode(&save, format).unwrap();
+            assert_eq!(
+                decode::<PlayerSave>(&bytes, format).unwrap(),
+                save,
+                "{format}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_version_one_saves_are_upgraded() {
+        #[derive(Serialize)]
+        struct SaveV1 {
+            version: u32,
+            name: String,
+            xp: u64,
+        }
+        let v1 = SaveV1 {
+            version: 1,
+            name: "Ann".to_string(),
+            xp: 1_150,
+        };
+        let expected = PlayerSave {
+            name: "Ann".to_string(),
+            level: 12,
+            inventory: Vec::new(),
+        };
+
+        for format in [Format::Json, Format::Toml, Format::Bincode] {
+            let bytes = encode(&v1, format).unwrap();
+            assert_eq!(
+                decode::<PlayerSave>(&bytes, format).unwrap(),
+                expected,
+                "{format}"
+            );
+        }
+        let future = r#"{"version":9,"name":"Ann"}"#;
+        let error = serde_json::from_str::<PlayerSave>(future).unwrap_err();
+        assert!(error.to_string().contains("unsupported save version 9"));
+    }
+
+    #[test]
+    fn test_flatten_and_adjacent_tagging_shapes() {
+        let json = r#"{"name":"Mali","hp":120,"mp":40,"guild":"Naga"}"#;
+        let hero: Character = serde_json::from_str(json).unwrap();
+        assert_eq!(hero.stats, Stats { hp: 120, mp: 40 });
+        assert_eq!(hero.extra["guild"], "Naga");
+        assert_eq!(serde_json::to_string(&hero).unwrap(), json);
+        assert!(encode(&hero, Format::Bincode).is_err());
+
+        let chat = GameMessage::Chat {
+            from: "a".to_string(),
+            text: "hi".to_string(),
+        };
+        assert_eq!(
+            serde_json::to_value(&chat).unwrap(),
+            serde_json::json!({"type": "Chat", "data": {"from": "a", "text": "hi"}})
+        );
+        assert_eq!(
+            serde_json::to_string(&GameMessage::Ping).unwrap(),
+            r#"{"type":"Ping"}"#
+        );
+        for message in [chat, GameMessage::Move { x: -4, y: 9 }, GameMessage::Ping] {
+            for format in [Format::Json, Format::Toml] {
+                let bytes = encode(&message, format).unwrap();
+                assert_eq!(decode::<GameMessage>(&bytes, format).unwrap(), message);
+            }
+            // เขียนเป็น bincode ได้ แต่อ่านกลับไม่ได้: ต้องอ่านชื่อ variant จาก tag ซึ่ง bincode ไม่มี
+            let bytes = encode(&message, Format::Bincode).unwrap();
+            assert!(decode::<GameMessage>(&bytes, Format::Bincode).is_err());
+        }
+    }
+
+    #[test]
+    fn test_bincode_is_the_most_compact() {
+        let sizes: BTreeMap<_, _> = format_sizes(&sample_save())
+            .into_iter()
+            .map(|(format, size)| (format.to_string(), size.unwrap()))
+            .collect();
+        assert!(sizes["bincode"] < sizes["JSON"]);
+        assert!(sizes["JSON"] < sizes["JSON (pretty)"]);
+    }
+}
@@ -0,0 +1,367 @@
+//! Advanced Errors - error หลายชั้นที่เล่าเรื่องได้ตั้งแต่ต้นจนจบ! 🧅🔗
+//!
+//! `String` บอกได้แค่ "พังแล้ว" แต่ error จริงในแอปมีหลายชั้น:
+//! API เรียก database → database อ่านแถวข้อมูล → แปลงตัวเลขไม่ได้
+//! แต่ละชั้นห่อ error ของชั้นล่างไว้ใน `source()` กลายเป็น "โซ่" ที่ไล่ดูได้จนถึงต้นเหตุ
+//!
+//! - 🏭 `ConfigError`, `ApiError` - ใช้ `#[derive(thiserror::Error)]` ให้เขียน Display/source/From ให้
+//! - 🔧 `DbError` - เขียน `Display` + `Error::source` + `From` เองทั้งหมด (สิ่งที่ derive สร้างให้หน้าตาแบบนี้)
+//! - 📜 `Report` - เดินตามโซ่แล้วพิมพ์เป็นต้นไม้ หรือบรรทัดเดียวด้วย `{:#}`
+//! - 🚪 `ApiError::exit_code` - แปลงชนิดของ error เป็น exit code ตามแบบ `sysexits.h`
+//!
+//! ⚠️ กฎสำคัญ: ข้อความของชั้นบนไม่ควรซ้ำข้อความของ `source` (ไม่ใส่ `{source}` ใน `#[error]`)
+//! ไม่อย่างนั้นตอนพิมพ์ทั้งโซ่จะเห็นข้อความเดียวกันสองรอบ
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::num::ParseIntError;
+use std::process::ExitCode;
+
+use thiserror::Error;
+
+/// ⚙️ อ่าน config ไม่ได้
+#[derive(Debug, Error)]
+pub enum ConfigError {
+    #[error("ไม่พบค่า `{key}` ใน config")]
+    Missing { key: String },
+    #[error("ค่า `{key}` = \"{value}\" ไม่ใช่ตัวเลข")]
+    Invalid {
+        key: String,
+        value: String,
+        #[source]
+        source: ParseIntError,
+    },
+}
+
+/// 🗄️ database ทำงานไม่สำเร็จ - เขียนเองทั้งหมดเพื่อดูว่า derive ทำอะไรให้บ้าง
+#[derive(Debug)]
+pub enum DbError {
+    /// ต่อ database ไม่ได้
+    Connect { url: String, source: io::Error },
+    /// แถวข้อมูลเสีย
+    CorruptRow { line: usize, source: ParseIntError },
+    /// ไม่มีแถวที่ต้องการ
+    NotFound { table: &'static str, id: u32 },
+}
+
+impl fmt::Display for DbError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Connect { url, .. } => write!(f, "เชื่อมต่อ {url} ไม่ได้"),
+            Self::CorruptRow { line, .. } => write!(f, "ข้อมูลแถวที่ {line} เสีย"),
+            Self::NotFound { table, id } => write!(f, "ไม่มี id {id} ในตาราง {table}"),
+        }
+    }
+}
+
+impl Error for DbError {
+    // ชี้ไปยัง error ชั้นล่าง - นี่คือ "ข้อต่อ" ของโซ่
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Connect { source, .. } => Some(source),
+            Self::CorruptRow { source, .. } => Some(source),
+            Self::NotFound { .. } => None,
+        }
+    }
+}
+
+/// 🌐 error ชั้นบนสุดที่ CLI/handler เห็น
+#[derive(Debug, Error)]
+pub enum ApiError {
+    /// `#[from]` สร้าง `impl From<ConfigError> for ApiError` ให้ - ใช้ `?` ได้ทันที
+    #[error("เริ่มระบบไม่ได้")]
+    Config(#[from] ConfigError),
+    #[error("โหลดผู้ใช้ {id} ไม่สำเร็จ")]
+    Db {
+        id: u32,
+        #[source]
+        source: DbError,
+    },
+    #[error("ไม่พบผู้ใช้ {0}")]
+    NotFound(u32),
+    #[error("คำขอไม่ถูกต้อง: {0}")]
+    BadRequest(String),
+}
+
+impl ApiError {
+    /// exit code สำหรับ CLI ตามแบบ `sysexits.h` - script ที่เรียกเราแยกสาเหตุได้โดยไม่ต้องอ่านข้อความ
+    #[must_use]
+    pub const fn exit_code(&self) -> u8 {
+        match self {
+            Self::BadRequest(_) => 64, // EX_USAGE
+            Self::NotFound(_) => 1,    // ทำงานปกติแต่ไม่เจอ
+            Self::Db {
+                source: DbError::CorruptRow { .. },
+                ..
+            } => 65, // EX_DATAERR
+            Self::Db { .. } => 69,     // EX_UNAVAILABLE
+            Self::Config(_) => 78,     // EX_CONFIG
+        }
+    }
+}
+
+/// 🔗 error ตัวนี้และทุกตัวใน `source()` ต่อกันไป
+pub fn chain<'a>(
+    error: &'a (dyn Error + 'static),
+) -> impl Iterator<Item = &'a (dyn Error + 'static)> {
+    std::iter::successors(Some(error), |&current| current.source())
+}
+
+/// 📜 พิมพ์ error พร้อมสาเหตุทั้งโซ่
+///
+/// - `{}` เป็นต้นไม้หลายบรรทัด เหมาะกับคนอ่าน
+/// - `{:#}` เป็นบรรทัดเดียว `ชั้นบน: ชั้นกลาง: ต้นเหตุ` เหมาะกับ log
+pub struct Report<'a>(pub &'a (dyn Error + 'static));
+
+impl fmt::Display for Report<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut errors = chain(self.0);
+        let Some(top) = errors.next() else {
+            return Ok(());
+        };
+        if f.alternate() {
+            write!(f, "{top}")?;
+            for cause in errors {
+                write!(f, ": {cause}")?;
+            }
+            return Ok(());
+        }
+        write!(f, "❌ {top}")?;
+        let causes: Vec<_> = errors.collect();
+        for (index, cause) in causes.iter().enumerate() {
+            let branch = if index + 1 == causes.len() {
+                "└─"
+            } else {
+                "├─"
+            };
+            write!(f, "\n   {branch} สาเหตุ: {cause}")?;
+        }
+        Ok(())
+    }
+}
+
+/// 🗃️ database จำลอง - เก็บแถวเป็นข้อความ `"id,ชื่อ,อายุ"` แบบไฟล์ CSV
+pub struct UserDb {
+    rows: Vec<String>,
+}
+
+impl UserDb {
+    /// host ชื่อ `offline` จำลองว่าต่อไม่ได้
+    ///
+    /// # Errors
+    ///
+    /// คืน `DbError::Connect` เมื่อ host เป็น `offline`
+    pub fn connect(url: &str, rows: &[&str]) -> Result<Self, DbError> {
+        if url.contains("offline") {
+            return Err(DbError::Connect {
+                url: url.to_string(),
+                source: io::Error::new(io::ErrorKind::ConnectionRefused, "connection refused"),
+            });
+        }
+        Ok(Self {
+            rows: rows.iter().map(ToString::to_string).collect(),
+        })
+    }
+
+    /// หาผู้ใช้ตาม id คืน `(ชื่อ, อายุ)`
+    ///
+    /// # Errors
+    ///
+    /// คืน `DbError::CorruptRow` เมื่อเจอแถวที่อ่านไม่ได้ระหว่างค้นหา หรือ `NotFound` ถ้าไม่มี
+    pub fn find_user(&self, id: u32) -> Result<(String, u32), DbError> {
+        for (index, row) in self.rows.iter().enumerate() {
+            let line = index + 1;
+            let mut columns = row.split(',');
+            let row_id: u32 = parse_column(columns.next(), line)?;
+            if row_id != id {
+                continue;
+            }
+            let name = columns.next().unwrap_or_default().trim().to_string();
+            let age: u32 = parse_column(columns.next(), line)?;
+            return Ok((name, age));
+        }
+        Err(DbError::NotFound { table: "users", id })
+    }
+}
+
+fn parse_column(column: Option<&str>, line: usize) -> Result<u32, DbError> {
+    column
+        .unwrap_or_default()
+        .trim()
+        .parse()
+        .map_err(|source| DbError::CorruptRow { line, source })
+}
+
+/// อ่านค่าตัวเลขจาก config แบบ `key=value`
+///
+/// # Errors
+///
+/// คืน `ConfigError` เมื่อไม่มี key หรือค่าไม่ใช่ตัวเลข
+pub fn config_number(config: &HashMap<&str, &str>, key: &str) -> Result<u32, ConfigError> {
+    let value = config.get(key).ok_or_else(|| ConfigError::Missing {
+        key: key.to_string(),
+    })?;
+    value.parse().map_err(|source| ConfigError::Invalid {
+        key: key.to_string(),
+        value: (*value).to_string(),
+        source,
+    })
+}
+
+/// 🌐 handler ของ API: อ่าน config → ต่อ database → หาผู้ใช้ แต่ละชั้นเติมบริบทของตัวเอง
+///
+/// # Errors
+///
+/// คืน `ApiError` ที่มีโซ่สาเหตุครบทุกชั้น
+pub fn get_user(
+    config: &HashMap<&str, &str>,
+    rows: &[&str],
+    request: &str,
+) -> Result<String, ApiError> {
+    let id: u32 = request
+        .parse()
+        .map_err(|_| ApiError::BadRequest(format!("id {request:?} ต้องเป็นตัวเลข")))?;
+    let max_id = config_number(config, "max_id")?; // ConfigError → ApiError ด้วย #[from]
+    if id > max_id {
+        return Err(ApiError::BadRequest(format!("id ต้องไม่เกิน {max_id}")));
+    }
+
+    let url = config
+        .get("db_url")
+        .copied()
+        .unwrap_or("postgres://localhost/app");
+    let db = UserDb::connect(url, rows).map_err(|source| ApiError::Db { id, source })?;
+    match db.find_user(id) {
+        Ok((name, age)) => Ok(format!("{name} ({age} ปี)")),
+        Err(DbError::NotFound { id, .. }) => Err(ApiError::NotFound(id)),
+        Err(source) => Err(ApiError::Db { id, source }),
+    }
+}
+
+/// จำลอง `main` ของ CLI - พิมพ์ report แล้วคืน exit code ให้ระบบปฏิบัติการ
+#[must_use]
+pub fn run_cli(config: &HashMap<&str, &str>, rows: &[&str], request: &str) -> ExitCode {
+    match get_user(config, rows, request) {
+        Ok(user) => {
+            println!("✅ {user}");
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            println!("{}", Report(&error));
+            println!("   🚪 exit code {}", error.exit_code());
+            ExitCode::from(error.exit_code())
+        }
+    }
+}
+
+/// ฟังก์ชันสำหรับสอน error หลายชั้น - สืบหาต้นเหตุแบบนักสืบ! 🕵️
+pub fn learn_advanced_errors() {
+    println!("\n🧅 === Advanced Errors: error หลายชั้นพร้อมโซ่สาเหตุ! === 🧅");
+
+    let rows = ["1, สมชาย, 30", "2, สมหญิง, สิบแปด", "3, มานี, 25"];
+    let config: HashMap<&str, &str> =
+        [("max_id", "100"), ("db_url", "postgres://db.local/app")].into();
+
+    println!("\n✅ กรณีปกติ:");
+    let _ = run_cli(&config, &rows, "1");
+
+    println!("\n🔍 ไม่พบผู้ใช้ (error ชั้นเดียว):");
+    let _ = run_cli(&config, &rows, "9");
+
+    println!("\n💥 ข้อมูลเสีย (API → DB → ParseIntError):");
+    let _ = run_cli(&config, &rows, "2");
+
+    println!("\n🔌 database ล่ม (API → DB → io::Error):");
+    let offline: HashMap<&str, &str> =
+        [("max_id", "100"), ("db_url", "postgres://offline/app")].into();
+    let _ = run_cli(&offline, &rows, "1");
+
+    println!("\n⚙️ config ผิด (API → Config → ParseIntError) แบบบรรทัดเดียวด้วยแฟล็ก `#`:");
+    let broken: HashMap<&str, &str> = [("max_id", "ร้อย")].into();
+    if let Err(error) = get_user(&broken, &rows, "1") {
+        println!("   {:#}", Report(&error));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROWS: [&str; 3] = ["1, สมชาย, 30", "2, สมหญิง, สิบแปด", "3, มานี, 25"];
+
+    fn config(pairs: &[(&'static str, &'static str)]) -> HashMap<&'static str, &'static str> {
+        pairs.iter().copied().collect()
+    }
+
+    fn messages(error: &ApiError) -> Vec<String> {
+        chain(error).map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn test_chain_reaches_root_cause() {
+        let ok = config(&[("max_id", "10")]);
+        assert_eq!(get_user(&ok, &ROWS, "1").unwrap(), "สมชาย (30 ปี)");
+
+        // อายุของแถวที่ 2 เขียนเป็นตัวหนังสือ
+        let error = get_user(&ok, &ROWS, "2").unwrap_err();
+        assert_eq!(
+            messages(&error),
+            [
+                "โหลดผู้ใช้ 2 ไม่สำเร็จ",
+                "ข้อมูลแถวที่ 2 เสีย",
+                "invalid digit found in string",
+            ]
+        );
+        assert!(chain(&error).last().unwrap().is::<ParseIntError>());
+        assert_eq!(error.exit_code(), 65);
+
+        let offline = config(&[("max_id", "10"), ("db_url", "postgres://offline/app")]);
+        let error = get_user(&offline, &ROWS, "1").unwrap_err();
+        let root = chain(&error)
+            .last()
+            .unwrap()
+            .downcast_ref::<io::Error>()
+            .unwrap();
+        assert_eq!(root.kind(), io::ErrorKind::ConnectionRefused);
+        assert_eq!(error.exit_code(), 69);
+    }
+
+    #[test]
+    fn test_from_conversion_and_exit_codes() {
+        let error = get_user(&config(&[]), &ROWS, "1").unwrap_err();
+        assert!(matches!(
+            error,
+            ApiError::Config(ConfigError::Missing { .. })
+        ));
+        assert_eq!(
+            messages(&error),
+            ["เริ่มระบบไม่ได้", "ไม่พบค่า `max_id` ใน config"]
+        );
+        assert_eq!(error.exit_code(), 78);
+
+        let ok = config(&[("max_id", "10")]);
+        assert_eq!(get_user(&ok, &ROWS, "7").unwrap_err().exit_code(), 1);
+        assert_eq!(get_user(&ok, &ROWS, "abc").unwrap_err().exit_code(), 64);
+        assert_eq!(get_user(&ok, &ROWS, "11").unwrap_err().exit_code(), 64);
+        assert_eq!(run_cli(&ok, &ROWS, "1"), ExitCode::SUCCESS);
+    }
+
+    #[test]
+    fn test_report_formats() {
+        let error = get_user(&config(&[("max_id", "ร้อย")]), &ROWS, "1").unwrap_err();
+        assert_eq!(
+            Report(&error).to_string(),
+            "❌ เริ่มระบบไม่ได้\n\
+             \x20  ├─ สาเหตุ: ค่า `max_id` = \"ร้อย\" ไม่ใช่ตัวเลข\n\
+             \x20  └─ สาเหตุ: invalid digit found in string"
+        );
+        assert_eq!(
+            format!("{:#}", Report(&error)),
+            "เริ่มระบบไม่ได้: ค่า `max_id` = \"ร้อย\" ไม่ใช่ตัวเลข: invalid digit found in string"
+        );
+        let single = ApiError::NotFound(7);
+        assert_eq!(Report(&single).to_string(), "❌ ไม่พบผู้ใช้ 7");
+    }
+}
@@ -0,0 +1,398 @@
+//! INI Parser - ไฟล์ config แบบเก่าแก่ `[section]` + `key = value` ที่ parse ทีละบรรทัด 🗒️
+//!
+//! INI ไม่มีมาตรฐานกลาง parser นี้จึงเลือกกติกาที่เดาง่ายและบอก error ชัดเจน:
+//! - 🏷️ key ก่อน `[section]` แรกอยู่ใน section ชื่อ `""` (global)
+//! - ➗ คั่นด้วย `=` หรือ `:` ตัวแรกที่เจอ ช่องว่างรอบ key/value ถูกตัดทิ้ง
+//! - 💬 comment ต้องขึ้นต้นบรรทัดด้วย `;` หรือ `#` - ไม่มี comment ท้ายบรรทัด
+//!   (`url = http://a/#top` จึงได้ค่าครบ)
+//! - 🔤 value ใน `"..."` เก็บช่องว่างหัวท้ายได้และรองรับ `\"`, `\\`, `\n`, `\t`
+//! - 🧱 section ซ้ำ / key ซ้ำใน section เดียวกันเป็น error ไม่ใช่ทับเงียบๆ
+//!
+//! ค่าหลายบรรทัด (บรรทัดย่อหน้าต่อจาก key แบบ Python `configparser`) ตั้งใจไม่รองรับ
+//! และได้ `IniErrorKind::MultilineValue` แทน - ทุกค่าเป็น `String` ให้ผู้ใช้แปลงชนิดเอง
+//! (`config` ใช้ `toml_parser::parse_value` แปลง `8080` → integer)
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// 🏷️ key → value ของ section หนึ่ง
+pub type IniSection = BTreeMap<String, String>;
+
+/// 🗒️ เอกสาร INI ทั้งไฟล์ - section เรียงตามชื่อ, `""` คือ global
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Ini {
+    sections: BTreeMap<String, IniSection>,
+}
+
+impl Ini {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// ค่าของ `key` ใน `section` (`""` = global)
+    #[must_use]
+    pub fn get(&self, section: &str, key: &str) -> Option<&str> {
+        self.sections.get(section)?.get(key).map(String::as_str)
+    }
+
+    #[must_use]
+    pub fn section(&self, name: &str) -> Option<&IniSection> {
+        self.sections.get(name)
+    }
+
+    /// ทุก section ตามลำดับชื่อ (global มาก่อนเสมอเพราะ `""` น้อยที่สุด)
+    pub fn sections(&self) -> impl Iterator<Item = (&str, &IniSection)> {
+        self.sections
+            .iter()
+            .map(|(name, section)| (name.as_str(), section))
+    }
+
+    /// ตั้งค่า (ทับของเดิม) - key ต้องไม่มี `=`/`:`/ขึ้นบรรทัด และไม่ขึ้นต้นด้วย `[`, `;`, `#`
+    /// ถึงจะเขียนออกแล้วอ่านกลับได้
+    pub fn set(&mut self, section: &str, key: &str, value: &str) {
+        self.sections
+            .entry(section.to_string())
+            .or_default()
+            .insert(key.to_string(), value.to_string());
+    }
+}
+
+impl fmt::Display for Ini {
+    /// เขียนกลับเป็น INI - value ที่ตัดช่องว่างแล้วจะเพี้ยนถูกใส่ `"..."` ให้
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut first = true;
+        for (name, section) in &self.sections {
+            if !name.is_empty() {
+                if !first {
+                    writeln!(f)?;
+                }
+                writeln!(f, "[{name}]")?;
+            }
+            for (key, value) in section {
+                writeln!(f, "{key} = {}", format_value(value))?;
+            }
+            first = false;
+        }
+        Ok(())
+    }
+}
+
+fn format_value(value: &str) -> String {
+    let needs_quotes =
+        value.trim() != value || value.starts_with('"') || value.contains(['\n', '\r']);
+    if !needs_quotes {
+        return value.to_string();
+    }
+    let mut out = String::from("\"");
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            '\r' => out.push_str("\\r"),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// ❌ ผิดอะไร
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IniErrorKind {
+    /// บรรทัดที่ไม่ใช่ comment, section หรือ `key = value`
+    MissingSeparator,
+    /// `= value` ที่ไม่มีชื่อ key
+    EmptyKey,
+    /// `[]`, `[a]b` หรือ `[a` - ชื่อ section ว่างหรือวงเล็บไม่ครบ
+    InvalidSection,
+    DuplicateSection(String),
+    /// key ซ้ำใน section เดียวกัน (`section.key`, global เป็นแค่ `key`)
+    DuplicateKey(String),
+    /// `"...` ที่ไม่มี `"` ปิด
+    UnterminatedString,
+    /// มีข้อความต่อหลัง `"..."` ที่ปิดแล้ว
+    TrailingCharacters,
+    /// escape ที่ไม่รู้จักใน value แบบ `"..."`
+    InvalidEscape(char),
+    /// บรรทัดย่อหน้าต่อจาก key - ค่าหลายบรรทัดไม่รองรับ
+    MultilineValue,
+}
+
+/// 📍 error พร้อมเลขบรรทัด (เริ่มที่ 1) - INI ผิดได้ทีละบรรทัด จึงไม่ต้องมีคอลัมน์
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IniError {
+    pub kind: IniErrorKind,
+    pub line: usize,
+}
+
+impl fmt::Display for IniError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            IniErrorKind::MissingSeparator => {
+                f.write_str("คาดว่าจะเจอ `key = value` หรือ `[section]`")
+            }
+            IniErrorKind::EmptyKey => f.write_str("key ว่าง"),
+            IniErrorKind::InvalidSection => f.write_str("ชื่อ section ต้องไม่ว่างและอยู่ใน [ ] ครบคู่"),
+            IniErrorKind::DuplicateSection(name) => write!(f, "section [{name}] ซ้ำ"),
+            IniErrorKind::DuplicateKey(key) => write!(f, "key `{key}` ถูกกำหนดไปแล้ว"),
+            IniErrorKind::UnterminatedString => f.write_str("value ใน \" ไม่มี \" ปิด"),
+            IniErrorKind::TrailingCharacters => f.write_str("มีข้อความเกินหลัง \" ปิด"),
+            IniErrorKind::InvalidEscape(c) => write!(f, "ไม่รู้จัก escape \\{c}"),
+            IniErrorKind::MultilineValue => {
+                f.write_str("ไม่รองรับค่าหลายบรรทัด (ตั้งใจตัดออก) - ใช้ \"...\\n...\" แทน")
+            }
+        }?;
+        write!(f, " (บรรทัด {})", self.line)
+    }
+}
+
+impl std::error::Error for IniError {}
+
+/// parse INI ทั้งไฟล์
+///
+/// # Errors
+///
+/// คืน `IniError` ของบรรทัดแรกที่ผิด
+pub fn parse(source: &str) -> Result<Ini, IniError> {
+    let mut ini = Ini::new();
+    let mut current = String::new();
+    let mut after_key = false;
+    for (index, raw) in source.lines().enumerate() {
+        let error = |kind| IniError {
+            kind,
+            line: index + 1,
+        };
+        let line = raw.trim();
+        if line.is_empty() || line.starts_with([';', '#']) {
+            after_key = false;
+            continue;
+        }
+
+        if let Some(rest) = line.strip_prefix('[') {
+            let name = rest
+                .strip_suffix(']')
+                .map(str::trim)
+                .filter(|name| !name.is_empty() && !name.contains(['[', ']']))
+                .ok_or_else(|| error(IniErrorKind::InvalidSection))?;
+            if ini.sections.contains_key(name) {
+                return Err(error(IniErrorKind::DuplicateSection(name.to_string())));
+            }
+            ini.sections.insert(name.to_string(), IniSection::new());
+            current = name.to_string();
+            after_key = false;
+            continue;
+        }
+
+        let Some(split) = line.find(['=', ':']) else {
+            let indented = raw.starts_with([' ', '\t']);
+            return Err(error(if indented && after_key {
+                IniErrorKind::MultilineValue
+            } else {
+                IniErrorKind::MissingSeparator
+            }));
+        };
+        let key = line[..split].trim_end();
+        if key.is_empty() {
+            return Err(error(IniErrorKind::EmptyKey));
+        }
+        let value = unquote(line[split + 1..].trim_start()).map_err(error)?;
+        let section = ini.sections.entry(current.clone()).or_default();
+        if section.contains_key(key) {
+            let full = if current.is_empty() {
+                key.to_string()
+            } else {
+                format!("{current}.{key}")
+            };
+            return Err(error(IniErrorKind::DuplicateKey(full)));
+        }
+        section.insert(key.to_string(), value);
+        after_key = true;
+    }
+    Ok(ini)
+}
+
+/// value ที่ขึ้นต้นด้วย `"` ต้องปิดด้วย `"` ตัวสุดท้ายของบรรทัดพอดี
+fn unquote(value: &str) -> Result<String, IniErrorKind> {
+    let Some(body) = value.strip_prefix('"') else {
+        return Ok(value.to_string());
+    };
+    let mut text = String::new();
+    let mut chars = body.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if chars.as_str().is_empty() => return Ok(text),
+            '"' => return Err(IniErrorKind::TrailingCharacters),
+            '\\' => text.push(match chars.next() {
+                Some('"') => '"',
+                Some('\\') => '\\',
+                Some('n') => '\n',
+                Some('t') => '\t',
+                Some('r') => '\r',
+                Some(other) => return Err(IniErrorKind::InvalidEscape(other)),
+                None => return Err(IniErrorKind::UnterminatedString),
+            }),
+            c => text.push(c),
+        }
+    }
+    Err(IniErrorKind::UnterminatedString)
+}
+
+/// 🗒️ สาธิต INI: อ่าน, เข้าถึงค่า, เขียนกลับ และ error ที่บอกบรรทัด
+pub fn learn_ini_parser() {
+    println!("🗒️ === INI Parser ที่เขียนเอง: section, quoted value และ round-trip === 🗒️");
+
+    let source = "\
+; ⚙️ ตั้งค่าร้านค้า
+name = ร้านปู 🦀
+
+[server]
+host = 0.0.0.0
+port: 8080
+url = http://shop.local/#home
+
+[messages]
+greeting = \"  สวัสดี  \"
+footer = \"บรรทัดแรก\\nบรรทัดสอง\"
+";
+    match parse(source) {
+        Ok(ini) => {
+            println!("\n✅ parse สำเร็จ:");
+            for (name, section) in ini.sections() {
+                let title = if name.is_empty() { "(global)" } else { name };
+                for (key, value) in section {
+                    println!("   🏷️ {title:<10} {key:<9} = {}", format_value(value));
+                }
+            }
+            println!(
+                "   🔑 server.port = {} (ทุกค่าเป็น string)",
+                ini.get("server", "port").unwrap_or("(ไม่มี)")
+            );
+
+            let written = ini.to_string();
+            println!("\n🖨️ เขียนกลับ:");
+            for line in written.lines() {
+                println!("   {line}");
+            }
+            println!(
+                "   🔁 round-trip ตรงกัน: {}",
+                parse(&written).as_ref() == Ok(&ini)
+            );
+        }
+        Err(error) => println!("❌ {error}"),
+    }
+
+    println!("\n📍 error บอกบรรทัด:");
+    for broken in [
+        "[server]\nport = 80\nport = 81",
+        "[a]\n[a]",
+        "just some words",
+        "motd = first line\n  second line",
+        "name = \"unterminated",
+    ] {
+        if let Err(error) = parse(broken) {
+            println!("   ❌ {error}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sections_keys_and_quoting() {
+        let ini = parse(
+            "top = 1\n\n; comment\n# another\n[server]\n  host = 0.0.0.0  \nport:8080\nurl = http://a/#x ; not a comment\nempty =\n\n[ spaced name ]\nquoted = \"  keep  \"\nescaped = \"say \\\"hi\\\"\\n\\tbye\"\nratio = a=b:c\n",
+        )
+        .unwrap();
+        assert_eq!(ini.get("", "top"), Some("1"));
+        assert_eq!(ini.get("server", "host"), Some("0.0.0.0"));
+        assert_eq!(ini.get("server", "port"), Some("8080"));
+        assert_eq!(
+            ini.get("server", "url"),
+            Some("http://a/#x ; not a comment")
+        );
+        assert_eq!(ini.get("server", "empty"), Some(""));
+        assert_eq!(ini.get("spaced name", "quoted"), Some("  keep  "));
+        assert_eq!(ini.get("spaced name", "escaped"), Some("say \"hi\"\n\tbye"));
+        assert_eq!(ini.get("spaced name", "ratio"), Some("a=b:c"));
+        assert_eq!(ini.get("server", "missing"), None);
+        assert_eq!(
+            ini.sections().map(|(name, _)| name).collect::<Vec<_>>(),
+            ["", "server", "spaced name"]
+        );
+
+        let empty = parse("[only]\n").unwrap();
+        assert_eq!(empty.section("only"), Some(&IniSection::new()));
+    }
+
+    #[test]
+    fn test_errors_point_to_lines() {
+        let error = |source: &str| {
+            let error = parse(source).unwrap_err();
+            (error.kind, error.line)
+        };
+        assert_eq!(error("words only"), (IniErrorKind::MissingSeparator, 1));
+        assert_eq!(error("[a]\n = 1"), (IniErrorKind::EmptyKey, 2));
+        for broken in ["[]", "[  ]", "[a", "[a]b", "[a[b]]"] {
+            assert_eq!(error(broken), (IniErrorKind::InvalidSection, 1), "{broken}");
+        }
+        assert_eq!(
+            error("[a]\nx = 1\n[b]\n[a]"),
+            (IniErrorKind::DuplicateSection("a".into()), 4)
+        );
+        assert_eq!(
+            error("[db]\nhost = a\nhost = b"),
+            (IniErrorKind::DuplicateKey("db.host".into()), 3)
+        );
+        assert_eq!(
+            error("x = 1\nx = 2"),
+            (IniErrorKind::DuplicateKey("x".into()), 2)
+        );
+        assert_eq!(error("s = \"open"), (IniErrorKind::UnterminatedString, 1));
+        assert_eq!(
+            error("s = \"ends with \\"),
+            (IniErrorKind::UnterminatedString, 1)
+        );
+        assert_eq!(error("s = \"a\" b"), (IniErrorKind::TrailingCharacters, 1));
+        assert_eq!(error("s = \"\\q\""), (IniErrorKind::InvalidEscape('q'), 1));
+
+        // บรรทัดย่อหน้าต่อจาก key คือค่าหลายบรรทัด - บอกชัดว่าไม่รองรับ
+        let multiline = parse("[motd]\ntext = first\n    second").unwrap_err();
+        assert_eq!(
+            (multiline.kind.clone(), multiline.line),
+            (IniErrorKind::MultilineValue, 3)
+        );
+        assert!(multiline.to_string().contains("ตั้งใจตัดออก"), "{multiline}");
+        // แต่ key ที่ย่อหน้าเฉยๆ ยังเป็น key ปกติ
+        assert_eq!(
+            parse("[s]\n    a = 1\n    b = 2").unwrap().get("s", "b"),
+            Some("2")
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut ini = Ini::new();
+        ini.set("", "name", "ร้านปู 🦀");
+        ini.set("paths", "home", "  padded  ");
+        ini.set("paths", "quote", "\"starts with quote");
+        ini.set("paths", "lines", "a\nb\r\n\tc \\ d");
+        ini.set("paths", "plain", "C:\\Users\\ferris");
+        ini.sections.insert("empty".to_string(), IniSection::new());
+
+        let written = ini.to_string();
+        assert_eq!(
+            written,
+            "name = ร้านปู 🦀\n\n[empty]\n\n[paths]\nhome = \"  padded  \"\nlines = \"a\\nb\\r\\n\\tc \\\\ d\"\nplain = C:\\Users\\ferris\nquote = \"\\\"starts with quote\"\n"
+        );
+        assert_eq!(parse(&written), Ok(ini));
+
+        let source = "[b]\nz=1\n[a]\ny = \" x \"\n";
+        let reparsed = parse(&parse(source).unwrap().to_string()).unwrap();
+        assert_eq!(reparsed, parse(source).unwrap());
+    }
+}
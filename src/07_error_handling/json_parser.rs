@@ -0,0 +1,759 @@
+//! JSON Parser - เขียน parser เองทั้งตัวเพื่อดูว่า enum + Result ทำงานร่วมกันยังไง 🧾🔍
+//!
+//! โปรเจคนี้ใช้ `serde_json` อยู่แล้ว แต่ parser ที่เขียนเองสอนได้หลายเรื่องในไฟล์เดียว:
+//! - 🌳 `JsonValue` - enum ที่แต่ละ variant เก็บข้อมูลคนละชนิด (recursive ผ่าน `Vec`/`BTreeMap`)
+//! - 🧭 recursive descent - หนึ่งฟังก์ชันต่อหนึ่งกฎไวยากรณ์ ดูแค่ byte ถัดไปก็รู้ว่าต้องเรียกกฎไหน
+//! - 📍 `JsonError` - error ที่บอกได้ว่าผิดอะไร (`JsonErrorKind`) และผิดตรงไหน (บรรทัด/คอลัมน์)
+//! - 🔣 `\uXXXX` รวม surrogate pair (`\ud83e\udd80` = 🦀) และปฏิเสธ surrogate ที่ไม่มีคู่
+//! - 🎨 pretty-printer ที่ escape กลับได้ถูกต้อง - parse ผลลัพธ์ซ้ำแล้วต้องได้ค่าเดิม
+//!
+//! tests เทียบผลกับ `serde_json` บน corpus เดียวกัน (differential testing)
+
+use std::collections::BTreeMap;
+use std::fmt::{self, Write as _};
+
+/// ซ้อน array/object ได้ลึกสุดเท่านี้ - ค่าเดียวกับ `serde_json` กัน stack ล้นจาก `[[[[...`
+pub const MAX_DEPTH: usize = 128;
+
+/// 🌳 ค่า JSON หนึ่งค่า - object เรียง key ตามตัวอักษรเหมือน `serde_json::Map` ค่าเริ่มต้น
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonValue {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Self>),
+    Object(BTreeMap<String, Self>),
+}
+
+impl JsonValue {
+    /// ดึงค่าตาม path แบบ `"users.0.name"` - ตัวเลขใช้เป็น index ของ array
+    #[must_use]
+    pub fn get_path(&self, path: &str) -> Option<&Self> {
+        path.split('.').try_fold(self, |value, key| match value {
+            Self::Object(fields) => fields.get(key),
+            Self::Array(items) => items.get(key.parse::<usize>().ok()?),
+            _ => None,
+        })
+    }
+
+    #[must_use]
+    pub const fn type_name(&self) -> &'static str {
+        match self {
+            Self::Null => "null",
+            Self::Bool(_) => "boolean",
+            Self::Number(_) => "number",
+            Self::String(_) => "string",
+            Self::Array(_) => "array",
+            Self::Object(_) => "object",
+        }
+    }
+
+    /// เขียนแบบมีย่อหน้า `indent` ช่องต่อชั้น - array/object ว่างอยู่บรรทัดเดียว
+    #[must_use]
+    pub fn pretty(&self, indent: usize) -> String {
+        let mut out = String::new();
+        self.write_pretty(&mut out, indent, 0);
+        out
+    }
+
+    fn write_pretty(&self, out: &mut String, indent: usize, depth: usize) {
+        let pad = |out: &mut String, depth: usize| out.push_str(&" ".repeat(indent * depth));
+        match self {
+            Self::Array(items) if !items.is_empty() => {
+                out.push_str("[\n");
+                for (index, item) in items.iter().enumerate() {
+                    pad(out, depth + 1);
+                    item.write_pretty(out, indent, depth + 1);
+                    out.push_str(if index + 1 < items.len() { ",\n" } else { "\n" });
+                }
+                pad(out, depth);
+                out.push(']');
+            }
+            Self::Object(fields) if !fields.is_empty() => {
+                out.push_str("{\n");
+                for (index, (key, value)) in fields.iter().enumerate() {
+                    pad(out, depth + 1);
+                    write_string(out, key);
+                    out.push_str(": ");
+                    value.write_pretty(out, indent, depth + 1);
+                    out.push_str(if index + 1 < fields.len() {
+                        ",\n"
+                    } else {
+                        "\n"
+                    });
+                }
+                pad(out, depth);
+                out.push('}');
+            }
+            scalar => {
+                let _ = write!(out, "{scalar}");
+            }
+        }
+    }
+}
+
+impl fmt::Display for JsonValue {
+    /// JSON แบบบรรทัดเดียวไม่มีช่องว่าง
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Null => f.write_str("null"),
+            Self::Bool(value) => write!(f, "{value}"),
+            // f64 ของ Rust พิมพ์ 1.0 เป็น "1" และไม่มี NaN/inf เพราะ parser ไม่สร้างค่าเหล่านั้น
+            Self::Number(value) => write!(f, "{value}"),
+            Self::String(text) => {
+                let mut out = String::new();
+                write_string(&mut out, text);
+                f.write_str(&out)
+            }
+            Self::Array(items) => {
+                f.write_str("[")?;
+                for (index, item) in items.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str(",")?;
+                    }
+                    write!(f, "{item}")?;
+                }
+                f.write_str("]")
+            }
+            Self::Object(fields) => {
+                f.write_str("{")?;
+                for (index, (key, value)) in fields.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str(",")?;
+                    }
+                    let mut quoted = String::new();
+                    write_string(&mut quoted, key);
+                    write!(f, "{quoted}:{value}")?;
+                }
+                f.write_str("}")
+            }
+        }
+    }
+}
+
+/// เขียน string พร้อม escape - ตัวอักษรควบคุมที่ไม่มีชื่อย่อใช้ `\u00XX`
+fn write_string(out: &mut String, text: &str) {
+    out.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\u{8}' => out.push_str("\\b"),
+            '\u{c}' => out.push_str("\\f"),
+            c if c < ' ' => {
+                let _ = write!(out, "\\u{:04x}", u32::from(c));
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// ❌ ผิดอะไร - แยกจากตำแหน่งเพื่อให้ test เทียบชนิดของ error ได้ตรงๆ
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JsonErrorKind {
+    /// input หมดก่อนค่าจะครบ
+    UnexpectedEnd,
+    /// เจอตัวอักษรที่ไม่ควรอยู่ตรงนี้
+    UnexpectedChar { found: char, expected: &'static str },
+    /// ตัวเลขผิดรูปแบบ เช่น `01`, `1.`, `-`, `1e`
+    InvalidNumber,
+    /// escape ที่ JSON ไม่รู้จัก เช่น `\x`
+    InvalidEscape(char),
+    /// `\u` ที่ตามด้วยเลขฐาน 16 ไม่ครบ 4 หลัก
+    InvalidUnicodeEscape,
+    /// surrogate ครึ่งเดียว เช่น `\ud83e` ที่ไม่มีคู่
+    LoneSurrogate(u16),
+    /// ตัวอักษรควบคุม (ต่ำกว่า U+0020) ใน string ที่ไม่ได้ escape
+    ControlCharacter(char),
+    /// ค่าจบแล้วแต่ยังมีอย่างอื่นตามมา
+    TrailingCharacters,
+    /// ซ้อนลึกเกิน `MAX_DEPTH`
+    TooDeep,
+}
+
+/// 📍 error พร้อมตำแหน่ง - `offset` เป็น byte, `line`/`column` เริ่มที่ 1 และนับเป็นตัวอักษร
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonError {
+    pub kind: JsonErrorKind,
+    pub offset: usize,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl JsonError {
+    fn at(kind: JsonErrorKind, source: &str, offset: usize) -> Self {
+        let before = &source[..offset];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+        Self {
+            kind,
+            offset,
+            line,
+            column: before[line_start..].chars().count() + 1,
+        }
+    }
+
+    /// บรรทัดที่ผิดพร้อม `^` ใต้ตำแหน่ง
+    #[must_use]
+    pub fn render(&self, source: &str) -> String {
+        let code = source.lines().nth(self.line - 1).unwrap_or_default();
+        format!(
+            "{self}\n  {code}\n  {}^",
+            " ".repeat(self.column.saturating_sub(1))
+        )
+    }
+}
+
+impl fmt::Display for JsonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            JsonErrorKind::UnexpectedEnd => f.write_str("JSON จบกลางคัน"),
+            JsonErrorKind::UnexpectedChar { found, expected } => {
+                write!(f, "คาดว่าจะเจอ {expected} แต่เจอ {found:?}")
+            }
+            JsonErrorKind::InvalidNumber => f.write_str("ตัวเลขผิดรูปแบบ"),
+            JsonErrorKind::InvalidEscape(c) => write!(f, "ไม่รู้จัก escape \\{c}"),
+            JsonErrorKind::InvalidUnicodeEscape => f.write_str("\\u ต้องตามด้วยเลขฐาน 16 สี่หลัก"),
+            JsonErrorKind::LoneSurrogate(unit) => {
+                write!(f, "surrogate \\u{unit:04x} ไม่มีคู่")
+            }
+            JsonErrorKind::ControlCharacter(c) => {
+                write!(f, "ตัวอักษรควบคุม {c:?} ใน string ต้อง escape")
+            }
+            JsonErrorKind::TrailingCharacters => f.write_str("มีข้อมูลเกินหลังจบค่า JSON"),
+            JsonErrorKind::TooDeep => write!(f, "ซ้อนลึกเกิน {MAX_DEPTH} ชั้น"),
+        }?;
+        write!(f, " (บรรทัด {} คอลัมน์ {})", self.line, self.column)
+    }
+}
+
+impl std::error::Error for JsonError {}
+
+/// parse JSON ทั้งเอกสาร - ต้องมีค่าเดียวพอดี (ช่องว่างรอบๆ ได้)
+///
+/// # Errors
+///
+/// คืน `JsonError` ที่ชี้ตำแหน่งแรกที่ผิดไวยากรณ์
+pub fn parse(source: &str) -> Result<JsonValue, JsonError> {
+    let mut parser = Parser {
+        source,
+        bytes: source.as_bytes(),
+        at: 0,
+        depth: 0,
+    };
+    let value = parser.value()?;
+    parser.skip_whitespace();
+    if parser.at < parser.bytes.len() {
+        return Err(parser.error(JsonErrorKind::TrailingCharacters));
+    }
+    Ok(value)
+}
+
+/// 🧭 recursive descent บน byte - JSON มีแต่ตัวอักษร ASCII เป็นโครงสร้าง
+/// UTF-8 หลาย byte จึงโผล่ได้แค่ใน string ซึ่งเราคัดลอกผ่านไปทั้งก้อน
+struct Parser<'a> {
+    source: &'a str,
+    bytes: &'a [u8],
+    at: usize,
+    depth: usize,
+}
+
+impl Parser<'_> {
+    fn error(&self, kind: JsonErrorKind) -> JsonError {
+        JsonError::at(kind, self.source, self.at)
+    }
+
+    /// error "เจออะไรที่ไม่คาด" ณ ตำแหน่งปัจจุบัน - ถ้าหมด input แล้วเป็น `UnexpectedEnd`
+    fn unexpected(&self, expected: &'static str) -> JsonError {
+        self.source[self.at..].chars().next().map_or_else(
+            || self.error(JsonErrorKind::UnexpectedEnd),
+            |found| self.error(JsonErrorKind::UnexpectedChar { found, expected }),
+        )
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.at).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.peek() {
+            self.at += 1;
+        }
+    }
+
+    fn expect_literal(
+        &mut self,
+        literal: &'static str,
+        value: JsonValue,
+    ) -> Result<JsonValue, JsonError> {
+        for expected in literal.bytes() {
+            if self.peek() != Some(expected) {
+                return Err(self.unexpected(literal));
+            }
+            self.at += 1;
+        }
+        Ok(value)
+    }
+
+    fn value(&mut self) -> Result<JsonValue, JsonError> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(b'n') => self.expect_literal("null", JsonValue::Null),
+            Some(b't') => self.expect_literal("true", JsonValue::Bool(true)),
+            Some(b'f') => self.expect_literal("false", JsonValue::Bool(false)),
+            Some(b'"') => self.string().map(JsonValue::String),
+            Some(b'-' | b'0'..=b'9') => self.number(),
+            Some(b'[') => self.nested(Self::array),
+            Some(b'{') => self.nested(Self::object),
+            _ => Err(self.unexpected("ค่า JSON")),
+        }
+    }
+
+    /// นับความลึกรอบการ parse array/object
+    fn nested(
+        &mut self,
+        parse: fn(&mut Self) -> Result<JsonValue, JsonError>,
+    ) -> Result<JsonValue, JsonError> {
+        if self.depth == MAX_DEPTH {
+            return Err(self.error(JsonErrorKind::TooDeep));
+        }
+        self.depth += 1;
+        let value = parse(self);
+        self.depth -= 1;
+        value
+    }
+
+    fn array(&mut self) -> Result<JsonValue, JsonError> {
+        self.at += 1; // '['
+        let mut items = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(b']') {
+            self.at += 1;
+            return Ok(JsonValue::Array(items));
+        }
+        loop {
+            items.push(self.value()?);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.at += 1,
+                Some(b']') => {
+                    self.at += 1;
+                    return Ok(JsonValue::Array(items));
+                }
+                _ => return Err(self.unexpected("',' หรือ ']'")),
+            }
+        }
+    }
+
+    fn object(&mut self) -> Result<JsonValue, JsonError> {
+        self.at += 1; // '{'
+        let mut fields = BTreeMap::new();
+        self.skip_whitespace();
+        if self.peek() == Some(b'}') {
+            self.at += 1;
+            return Ok(JsonValue::Object(fields));
+        }
+        loop {
+            self.skip_whitespace();
+            if self.peek() != Some(b'"') {
+                return Err(self.unexpected("key แบบ string"));
+            }
+            let key = self.string()?;
+            self.skip_whitespace();
+            if self.peek() != Some(b':') {
+                return Err(self.unexpected("':'"));
+            }
+            self.at += 1;
+            // key ซ้ำ: ตัวหลังชนะ เหมือน serde_json
+            fields.insert(key, self.value()?);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.at += 1,
+                Some(b'}') => {
+                    self.at += 1;
+                    return Ok(JsonValue::Object(fields));
+                }
+                _ => return Err(self.unexpected("',' หรือ '}'")),
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<String, JsonError> {
+        self.at += 1; // '"'
+        let mut text = String::new();
+        loop {
+            // คัดลอกช่วงที่ไม่มี escape ทีเดียว - ได้ UTF-8 หลาย byte มาครบโดยไม่ต้องถอดรหัสเอง
+            let start = self.at;
+            while let Some(byte) = self.peek() {
+                if byte == b'"' || byte == b'\\' || byte < 0x20 {
+                    break;
+                }
+                self.at += 1;
+            }
+            text.push_str(&self.source[start..self.at]);
+
+            match self.peek() {
+                None => return Err(self.error(JsonErrorKind::UnexpectedEnd)),
+                Some(b'"') => {
+                    self.at += 1;
+                    return Ok(text);
+                }
+                Some(b'\\') => {
+                    self.at += 1;
+                    text.push(self.escape()?);
+                }
+                Some(control) => {
+                    return Err(self.error(JsonErrorKind::ControlCharacter(char::from(control))));
+                }
+            }
+        }
+    }
+
+    fn escape(&mut self) -> Result<char, JsonError> {
+        let Some(c) = self.source[self.at..].chars().next() else {
+            return Err(self.error(JsonErrorKind::UnexpectedEnd));
+        };
+        let decoded = match c {
+            '"' => '"',
+            '\\' => '\\',
+            '/' => '/',
+            'b' => '\u{8}',
+            'f' => '\u{c}',
+            'n' => '\n',
+            'r' => '\r',
+            't' => '\t',
+            'u' => return self.unicode_escape(),
+            other => return Err(self.error(JsonErrorKind::InvalidEscape(other))),
+        };
+        self.at += 1;
+        Ok(decoded)
+    }
+
+    /// `\uXXXX` - ตัวอักษรนอก BMP มาเป็นคู่ high (D800-DBFF) + low (DC00-DFFF)
+    fn unicode_escape(&mut self) -> Result<char, JsonError> {
+        let escape_start = self.at - 1;
+        let high = self.hex4()?;
+        let code = match high {
+            0xD800..=0xDBFF => {
+                if !self.bytes[self.at..].starts_with(b"\\u") {
+                    return Err(JsonError::at(
+                        JsonErrorKind::LoneSurrogate(high),
+                        self.source,
+                        escape_start,
+                    ));
+                }
+                self.at += 1;
+                let low = self.hex4()?;
+                if !(0xDC00..=0xDFFF).contains(&low) {
+                    return Err(JsonError::at(
+                        JsonErrorKind::LoneSurrogate(high),
+                        self.source,
+                        escape_start,
+                    ));
+                }
+                0x10000 + ((u32::from(high) - 0xD800) << 10) + (u32::from(low) - 0xDC00)
+            }
+            0xDC00..=0xDFFF => {
+                return Err(JsonError::at(
+                    JsonErrorKind::LoneSurrogate(high),
+                    self.source,
+                    escape_start,
+                ));
+            }
+            _ => u32::from(high),
+        };
+        // ช่วง surrogate ถูกกันไว้หมดแล้ว ค่าที่เหลือเป็น scalar value ที่ถูกต้องเสมอ
+        Ok(char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER))
+    }
+
+    /// อ่าน `u` + เลขฐาน 16 สี่หลัก (ตำแหน่งปัจจุบันชี้ที่ `u`)
+    fn hex4(&mut self) -> Result<u16, JsonError> {
+        self.at += 1; // 'u'
+        let digits = self
+            .bytes
+            .get(self.at..self.at + 4)
+            .filter(|digits| digits.iter().all(u8::is_ascii_hexdigit))
+            .ok_or_else(|| self.error(JsonErrorKind::InvalidUnicodeEscape))?;
+        let value = digits
+            .iter()
+            .fold(0_u16, |value, &digit| value * 16 + hex_value(digit));
+        self.at += 4;
+        Ok(value)
+    }
+
+    /// ตัวเลขตามไวยากรณ์ JSON: `-? (0 | [1-9][0-9]*) (. [0-9]+)? ([eE] [+-]? [0-9]+)?`
+    fn number(&mut self) -> Result<JsonValue, JsonError> {
+        let start = self.at;
+        let invalid =
+            |parser: &Self| JsonError::at(JsonErrorKind::InvalidNumber, parser.source, start);
+        if self.peek() == Some(b'-') {
+            self.at += 1;
+        }
+        match self.peek() {
+            Some(b'0') => {
+                self.at += 1;
+                if matches!(self.peek(), Some(b'0'..=b'9')) {
+                    return Err(invalid(self));
+                }
+            }
+            Some(b'1'..=b'9') => self.digits(),
+            _ => return Err(invalid(self)),
+        }
+        if self.peek() == Some(b'.') {
+            self.at += 1;
+            if !matches!(self.peek(), Some(b'0'..=b'9')) {
+                return Err(invalid(self));
+            }
+            self.digits();
+        }
+        if let Some(b'e' | b'E') = self.peek() {
+            self.at += 1;
+            if let Some(b'+' | b'-') = self.peek() {
+                self.at += 1;
+            }
+            if !matches!(self.peek(), Some(b'0'..=b'9')) {
+                return Err(invalid(self));
+            }
+            self.digits();
+        }
+        // ผ่านไวยากรณ์แล้ว str::parse รับได้แน่ - ค่าใหญ่เกิน f64 ถือว่าผิด (serde_json ก็ปฏิเสธ)
+        match self.source[start..self.at].parse::<f64>() {
+            Ok(value) if value.is_finite() => Ok(JsonValue::Number(value)),
+            _ => Err(invalid(self)),
+        }
+    }
+
+    fn digits(&mut self) {
+        while let Some(b'0'..=b'9') = self.peek() {
+            self.at += 1;
+        }
+    }
+}
+
+fn hex_value(digit: u8) -> u16 {
+    u16::from(match digit {
+        b'0'..=b'9' => digit - b'0',
+        b'a'..=b'f' => digit - b'a' + 10,
+        _ => digit - b'A' + 10,
+    })
+}
+
+/// 🧾 สาธิต parser: ค่าที่ได้, pretty-print และ error ที่ชี้ตำแหน่ง
+pub fn learn_json_parser() {
+    println!("🧾 === JSON Parser ที่เขียนเอง: enum + Result + ตำแหน่งของ error === 🧾");
+
+    let source = r#"{"name": "Ferris", "emoji": "\ud83e\udd80", "langs": ["th", "en"], "age": 9, "admin": false}"#;
+    match parse(source) {
+        Ok(value) => {
+            println!("\n✅ parse สำเร็จ → {}", value.type_name());
+            for path in ["name", "emoji", "langs.1", "age", "missing"] {
+                let found = value
+                    .get_path(path)
+                    .map_or_else(|| "(ไม่มี)".to_string(), ToString::to_string);
+                println!("   🔑 {path:<8} = {found}");
+            }
+            println!("\n🎨 pretty-print:");
+            for line in value.pretty(2).lines() {
+                println!("   {line}");
+            }
+        }
+        Err(error) => println!("❌ {error}"),
+    }
+
+    println!("\n📍 error บอกทั้งชนิดและตำแหน่ง:");
+    for broken in [
+        "{\"a\": 1,\n \"b\": tru}",
+        "[1, 2,, 3]",
+        "{\"price\": 01}",
+        "\"\\ud83e alone\"",
+        "{\"open\": [1, 2",
+    ] {
+        if let Err(error) = parse(broken) {
+            for line in error.render(broken).lines() {
+                println!("   {line}");
+            }
+        }
+    }
+    println!("   💡 JsonErrorKind เป็น enum - โค้ดที่เรียกใช้ match ชนิดของ error ได้ ไม่ต้องเทียบข้อความ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// เทียบค่าของเรากับ `serde_json` - ตัวเลขเทียบเป็น f64 เพราะ `serde_json` แยก u64/i64/f64
+    fn same(ours: &JsonValue, theirs: &serde_json::Value) -> bool {
+        match (ours, theirs) {
+            (JsonValue::Null, serde_json::Value::Null) => true,
+            (JsonValue::Bool(a), serde_json::Value::Bool(b)) => a == b,
+            (JsonValue::Number(a), serde_json::Value::Number(b)) => Some(*a) == b.as_f64(),
+            (JsonValue::String(a), serde_json::Value::String(b)) => a == b,
+            (JsonValue::Array(a), serde_json::Value::Array(b)) => {
+                a.len() == b.len() && a.iter().zip(b).all(|(a, b)| same(a, b))
+            }
+            (JsonValue::Object(a), serde_json::Value::Object(b)) => {
+                a.len() == b.len()
+                    && a.iter()
+                        .all(|(key, value)| b.get(key).is_some_and(|other| same(value, other)))
+            }
+            _ => false,
+        }
+    }
+
+    const VALID: &[&str] = &[
+        "null",
+        " true ",
+        "false",
+        "0",
+        "-0",
+        "42",
+        "-17",
+        "3.25",
+        "1e3",
+        "-2.5E-3",
+        "6.02e+23",
+        "9007199254740993",
+        r#""""#,
+        r#""สวัสดี 🦀""#,
+        r#""quote \" backslash \\ slash \/ tab \t nl \n""#,
+        r#""\b\f\r\u0000\u001f""#,
+        r#""\u0e01\u0E02""#,
+        r#""\ud83e\udd80 \uD834\uDD1E""#,
+        "[]",
+        "{}",
+        "[1, [2, [3, []]], {}]",
+        r#"{"a": {"b": {"c": [true, null]}}}"#,
+        r#"{"dup": 1, "dup": 2}"#,
+        "\n\t{ \"spaced\" :\r\n [ 1 , 2 ] }\n",
+        r#"[{"id": 1, "tags": ["x", "y"]}, {"id": 2, "tags": []}]"#,
+    ];
+
+    const INVALID: &[&str] = &[
+        "",
+        "   ",
+        "nul",
+        "True",
+        "01",
+        "-",
+        "1.",
+        ".5",
+        "1e",
+        "+1",
+        "1e999",
+        "[1, 2",
+        "[1, 2,]",
+        "[1 2]",
+        "{\"a\" 1}",
+        "{\"a\": 1,}",
+        "{a: 1}",
+        "\"unterminated",
+        "\"bad \\x escape\"",
+        "\"\\u12\"",
+        "\"\\ud83e\"",
+        "\"\\udd80\"",
+        "\"raw\ttab\"",
+        "[] []",
+        "{} x",
+    ];
+
+    #[test]
+    fn test_differential_against_serde_json() {
+        for source in VALID {
+            let ours = parse(source).unwrap_or_else(|error| panic!("{source:?}: {error}"));
+            let theirs: serde_json::Value = serde_json::from_str(source).unwrap();
+            assert!(same(&ours, &theirs), "{source:?}: {ours:?} != {theirs:?}");
+
+            // สิ่งที่เราเขียนออก serde_json ต้องอ่านได้ค่าเดิม ทั้งแบบ compact และ pretty
+            for written in [ours.to_string(), ours.pretty(4)] {
+                let reparsed: serde_json::Value = serde_json::from_str(&written).unwrap();
+                assert!(same(&ours, &reparsed), "{written}");
+                assert_eq!(parse(&written).unwrap(), ours);
+            }
+        }
+        for source in INVALID {
+            assert!(parse(source).is_err(), "{source:?} ควร error");
+            assert!(
+                serde_json::from_str::<serde_json::Value>(source).is_err(),
+                "corpus ผิด: serde_json รับ {source:?}"
+            );
+        }
+
+        let deep_ok = format!("{}{}", "[".repeat(MAX_DEPTH), "]".repeat(MAX_DEPTH));
+        assert!(parse(&deep_ok).is_ok());
+        let too_deep = format!("{}{}", "[".repeat(MAX_DEPTH + 1), "]".repeat(MAX_DEPTH + 1));
+        assert_eq!(parse(&too_deep).unwrap_err().kind, JsonErrorKind::TooDeep);
+        assert!(serde_json::from_str::<serde_json::Value>(&too_deep).is_err());
+    }
+
+    #[test]
+    fn test_error_kinds_and_positions() {
+        let error = |source: &str| {
+            let error = parse(source).unwrap_err();
+            (error.kind, error.line, error.column)
+        };
+        assert_eq!(error(""), (JsonErrorKind::UnexpectedEnd, 1, 1));
+        assert_eq!(
+            error("{\"a\": 1,\n \"b\": tru}"),
+            (
+                JsonErrorKind::UnexpectedChar {
+                    found: '}',
+                    expected: "true"
+                },
+                2,
+                10
+            )
+        );
+        assert_eq!(error("[1, 2"), (JsonErrorKind::UnexpectedEnd, 1, 6));
+        assert_eq!(
+            error("{\"price\": 01}"),
+            (JsonErrorKind::InvalidNumber, 1, 11)
+        );
+        assert_eq!(
+            error("\"bad \\x\""),
+            (JsonErrorKind::InvalidEscape('x'), 1, 7)
+        );
+        assert_eq!(
+            error("[\"สวัสดี\", \"\\ud83e!\"]"),
+            (JsonErrorKind::LoneSurrogate(0xD83E), 1, 13)
+        );
+        assert_eq!(
+            error("\"tab\there\""),
+            (JsonErrorKind::ControlCharacter('\t'), 1, 5)
+        );
+        assert_eq!(error("[1] 2"), (JsonErrorKind::TrailingCharacters, 1, 5));
+
+        let source = "{\n  \"ok\": true,\n  \"bad\": [1,, 2]\n}";
+        let rendered = parse(source).unwrap_err().render(source);
+        assert_eq!(
+            rendered,
+            "คาดว่าจะเจอ ค่า JSON แต่เจอ ',' (บรรทัด 3 คอลัมน์ 13)\n    \"bad\": [1,, 2]\n              ^"
+        );
+    }
+
+    #[test]
+    fn test_values_pretty_and_paths() {
+        let value =
+            parse(r#"{"b": [1, 2.5, "x\n"], "a": {"empty": [], "none": {}}, "c": null}"#).unwrap();
+        assert_eq!(
+            value.to_string(),
+            r#"{"a":{"empty":[],"none":{}},"b":[1,2.5,"x\n"],"c":null}"#
+        );
+        assert_eq!(
+            value.pretty(2),
+            "{\n  \"a\": {\n    \"empty\": [],\n    \"none\": {}\n  },\n  \"b\": [\n    1,\n    2.5,\n    \"x\\n\"\n  ],\n  \"c\": null\n}"
+        );
+        assert_eq!(value.get_path("b.1"), Some(&JsonValue::Number(2.5)));
+        assert_eq!(value.get_path("a.empty.0"), None);
+        assert_eq!(value.get_path("c").map(JsonValue::type_name), Some("null"));
+        assert_eq!(
+            parse(r#""\ud83e\udd80\u0041""#).unwrap(),
+            JsonValue::String("🦀A".to_string())
+        );
+        assert_eq!(JsonValue::String("\u{1}".into()).to_string(), r#""\u0001""#);
+    }
+}
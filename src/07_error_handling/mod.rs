@@ -3,10 +3,15 @@
 //! โมดูลนี้เป็นโรงเรียนนักดับเพลิงข้อผิดพลาด! 🚒 สอนเกี่ยวกับการจัดการข้อผิดพลาดใน Rust
 //! รวมถึง Result (ผลลัพธ์), Option (ตัวเลือก), Panic (ตื่นตระหนก), และ Error Propagation (การส่งต่อข้อผิดพลาด)! 🎯
 
+mod advanced_errors;
+pub mod ini_parser;
+pub mod json_parser;
 mod panic_and_propagation;
 mod practice_error_handling;
 mod result_and_option;
+pub mod toml_parser;
 
+pub use advanced_errors::{ApiError, ConfigError, DbError, Report, learn_advanced_errors};
 pub use panic_and_propagation::learn_panic_and_error_propagation;
 pub use practice_error_handling::practice_error_handling;
 pub use result_and_option::learn_result_and_option;
@@ -21,6 +26,16 @@ pub fn run_error_handling_examples() {
 
     println!("\n   💪 แบบฝึกหัด Error Handling (ยิมฝึกจัดการข้อผิดพลาด!)");
     practice_error_handling();
+
+    println!("\n   🧅 Advanced Errors (error หลายชั้น, โซ่สาเหตุ และ exit code: สืบหาต้นเหตุ!)");
+    learn_advanced_errors();
+
+    println!("\n   🧾 JSON Parser ที่เขียนเอง (enum ซ้อน enum + error ที่บอกตำแหน่ง: อ่านเอกสารทีละตัวอักษร!)");
+    json_parser::learn_json_parser();
+
+    println!("\n   📄 TOML/INI Parser ที่เขียนเอง (ไฟล์ config โดยไม่พึ่ง crate: บอกตรงๆ ว่าอะไรไม่รองรับ!)");
+    toml_parser::learn_toml_parser();
+    ini_parser::learn_ini_parser();
 }
 
 #[cfg(test)]
@@ -0,0 +1,1111 @@
+//! TOML Parser (subset) - อ่านไฟล์ config ได้เองโดยไม่ต้องพึ่ง crate `toml` 📄🔧
+//!
+//! ใช้กับ `Config::builder().builtin_parsers()` ในโหมดสอน และเป็นตัวอย่างต่อจาก `json_parser`:
+//! - 🔑 key แบบ bare (`name`), quoted (`"a.b"`, `'raw'`) และ dotted (`server.port = 80`)
+//! - 🗂️ ตาราง `[server]`, ตารางซ้อน `[a.b]`, array of tables `[[users]]`, inline table `{ x = 1 }` (บรรทัดเดียวตาม TOML 1.0)
+//! - 🔢 integer (`1_000`, `0xff`, `0o7`, `0b1`), float (`6.5e-3`, `inf`, `nan`), boolean, array หลายบรรทัด
+//! - 🧱 key ซ้ำหรือกำหนดตารางซ้ำเป็น error พร้อมบรรทัด/คอลัมน์ (`TomlErrorKind::DuplicateKey`)
+//! - 🪜 array/inline table/dotted key ซ้อนเกิน `MAX_DEPTH` เป็น `TomlErrorKind::TooDeep` แทน stack ล้น
+//!
+//! ตั้งใจ **ไม่** รองรับ string หลายบรรทัด (`"""`/`'''`) และวันที่/เวลา - เจอแล้วได้
+//! `TomlErrorKind::Unsupported` ที่บอกตรงๆ ว่าตัดออก แทนที่จะเดาผิดเงียบๆ 🚧
+//!
+//! `to_string` เขียนตารางกลับเป็น TOML ที่ parse ซ้ำแล้วได้ค่าเดิม (round-trip)
+
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::{self, Write as _};
+
+/// ซ้อน array/inline table (รวมส่วนของ dotted key) ได้ลึกสุดเท่านี้ - ค่าเดียวกับ `json_parser`
+/// กัน stack ล้นจาก `a={a={a={...` หรือ `[[[[...`
+pub const MAX_DEPTH: usize = 128;
+
+/// 🗂️ ตาราง TOML - key เรียงตามตัวอักษรเหมือน `toml::Table`
+pub type TomlTable = BTreeMap<String, TomlValue>;
+
+/// 🌳 ค่า TOML หนึ่งค่า
+#[derive(Debug, Clone, PartialEq)]
+pub enum TomlValue {
+    String(String),
+    Integer(i64),
+    Float(f64),
+    Boolean(bool),
+    Array(Vec<Self>),
+    Table(TomlTable),
+}
+
+impl TomlValue {
+    #[must_use]
+    pub const fn type_name(&self) -> &'static str {
+        match self {
+            Self::String(_) => "string",
+            Self::Integer(_) => "integer",
+            Self::Float(_) => "float",
+            Self::Boolean(_) => "boolean",
+            Self::Array(_) => "array",
+            Self::Table(_) => "table",
+        }
+    }
+
+    /// array ที่ไม่ว่างและมีแต่ตาราง - เขียนออกเป็น `[[...]]`
+    fn is_array_of_tables(&self) -> bool {
+        matches!(self, Self::Array(items)
+            if !items.is_empty() && items.iter().all(|item| matches!(item, Self::Table(_))))
+    }
+}
+
+impl fmt::Display for TomlValue {
+    /// ค่าแบบ inline (ใช้หลัง `key = `) - ตารางเป็น `{ ... }`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::String(text) => f.write_str(&quote(text)),
+            Self::Integer(value) => write!(f, "{value}"),
+            Self::Float(value) if value.is_nan() => f.write_str("nan"),
+            Self::Float(value) if value.is_infinite() => {
+                f.write_str(if *value > 0.0 { "inf" } else { "-inf" })
+            }
+            // Debug ของ f64 มีจุดทศนิยมเสมอ (1.0) จึงอ่านกลับเป็น float ไม่ใช่ integer
+            Self::Float(value) => write!(f, "{value:?}"),
+            Self::Boolean(value) => write!(f, "{value}"),
+            Self::Array(items) => {
+                f.write_str("[")?;
+                for (index, item) in items.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{item}")?;
+                }
+                f.write_str("]")
+            }
+            Self::Table(fields) if fields.is_empty() => f.write_str("{}"),
+            Self::Table(fields) => {
+                f.write_str("{ ")?;
+                for (index, (key, value)) in fields.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{} = {value}", format_key(key))?;
+                }
+                f.write_str(" }")
+            }
+        }
+    }
+}
+
+/// 🖨️ เขียนทั้งเอกสาร: ค่าธรรมดาของแต่ละตารางก่อน ตามด้วย `[ตารางย่อย]` และ `[[array of tables]]`
+#[must_use]
+pub fn to_string(table: &TomlTable) -> String {
+    let mut out = String::new();
+    write_table(&mut out, table, &[]);
+    out
+}
+
+fn write_table(out: &mut String, table: &TomlTable, path: &[String]) {
+    for (key, value) in table {
+        if !matches!(value, TomlValue::Table(_)) && !value.is_array_of_tables() {
+            let _ = writeln!(out, "{} = {value}", format_key(key));
+        }
+    }
+    for (key, value) in table {
+        let child: Vec<String> = path.iter().cloned().chain([key.clone()]).collect();
+        match value {
+            TomlValue::Table(inner) => {
+                let _ = writeln!(out, "\n[{}]", format_path(&child));
+                write_table(out, inner, &child);
+            }
+            TomlValue::Array(items) if value.is_array_of_tables() => {
+                for item in items {
+                    if let TomlValue::Table(inner) = item {
+                        let _ = writeln!(out, "\n[[{}]]", format_path(&child));
+                        write_table(out, inner, &child);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn format_path(path: &[String]) -> String {
+    path.iter()
+        .map(|key| format_key(key))
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+/// key ที่เป็น bare ได้ก็เขียนตรงๆ นอกนั้นใส่ `"..."`
+fn format_key(key: &str) -> String {
+    if !key.is_empty() && key.bytes().all(is_bare_key_byte) {
+        key.to_string()
+    } else {
+        quote(key)
+    }
+}
+
+fn quote(text: &str) -> String {
+    let mut out = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\u{8}' => out.push_str("\\b"),
+            '\u{c}' => out.push_str("\\f"),
+            c if c < ' ' || c == '\u{7f}' => {
+                let _ = write!(out, "\\u{:04X}", u32::from(c));
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+const fn is_bare_key_byte(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || byte == b'_' || byte == b'-'
+}
+
+/// ❌ ผิดอะไร
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TomlErrorKind {
+    /// เจอตัวอักษรที่ไม่ควรอยู่ตรงนี้ (หรือไฟล์หมดก่อน ถ้า `found` เป็น `None`)
+    Unexpected {
+        found: Option<char>,
+        expected: &'static str,
+    },
+    /// string ที่ไม่มี `"`/`'` ปิดก่อนจบบรรทัด
+    UnterminatedString,
+    /// escape ที่ TOML ไม่รู้จัก เช่น `\x`
+    InvalidEscape(char),
+    /// `\u`/`\U` ที่เลขฐาน 16 ไม่ครบหรือไม่ใช่ตัวอักษรที่ถูกต้อง
+    InvalidUnicodeEscape,
+    /// ตัวอักษรควบคุมใน string ที่ไม่ได้ escape
+    ControlCharacter(char),
+    /// ตัวเลขผิดรูปแบบ เช่น `01`, `1__0`, `1.`, ค่าเกิน `i64`
+    InvalidNumber,
+    /// key หรือตารางที่ถูกกำหนดไปแล้ว (เก็บ path แบบ `a.b`)
+    DuplicateKey(String),
+    /// ไวยากรณ์ TOML ที่ subset นี้ตั้งใจไม่รองรับ
+    Unsupported(&'static str),
+    /// ซ้อนลึกเกิน `MAX_DEPTH`
+    TooDeep,
+}
+
+/// 📍 error พร้อมตำแหน่ง - `line`/`column` เริ่มที่ 1 และนับเป็นตัวอักษร
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TomlError {
+    pub kind: TomlErrorKind,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl TomlError {
+    fn at(kind: TomlErrorKind, source: &str, offset: usize) -> Self {
+        let before = &source[..offset];
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+        Self {
+            kind,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+        }
+    }
+
+    /// บรรทัดที่ผิดพร้อม `^` ใต้ตำแหน่ง
+    #[must_use]
+    pub fn render(&self, source: &str) -> String {
+        let code = source.lines().nth(self.line - 1).unwrap_or_default();
+        format!(
+            "{self}\n  {code}\n  {}^",
+            " ".repeat(self.column.saturating_sub(1))
+        )
+    }
+}
+
+impl fmt::Display for TomlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            TomlErrorKind::Unexpected {
+                found: Some(found),
+                expected,
+            } => write!(f, "คาดว่าจะเจอ {expected} แต่เจอ {found:?}"),
+            TomlErrorKind::Unexpected {
+                found: None,
+                expected,
+            } => write!(f, "คาดว่าจะเจอ {expected} แต่ไฟล์จบก่อน"),
+            TomlErrorKind::UnterminatedString => f.write_str("string ไม่มีเครื่องหมายปิดในบรรทัดเดียวกัน"),
+            TomlErrorKind::InvalidEscape(c) => write!(f, "ไม่รู้จัก escape \\{c}"),
+            TomlErrorKind::InvalidUnicodeEscape => {
+                f.write_str("\\u ต้องตามด้วยเลขฐาน 16 สี่หลัก (\\U แปดหลัก) ที่เป็นตัวอักษรได้")
+            }
+            TomlErrorKind::ControlCharacter(c) => {
+                write!(f, "ตัวอักษรควบคุม {c:?} ใน string ต้อง escape")
+            }
+            TomlErrorKind::InvalidNumber => f.write_str("ตัวเลขผิดรูปแบบ"),
+            TomlErrorKind::DuplicateKey(key) => write!(f, "key `{key}` ถูกกำหนดไปแล้ว"),
+            TomlErrorKind::Unsupported(what) => {
+                write!(f, "TOML subset นี้ไม่รองรับ {what} (ตั้งใจตัดออก)")
+            }
+            TomlErrorKind::TooDeep => write!(f, "ซ้อนลึกเกิน {MAX_DEPTH} ชั้น"),
+        }?;
+        write!(f, " (บรรทัด {} คอลัมน์ {})", self.line, self.column)
+    }
+}
+
+impl std::error::Error for TomlError {}
+
+const MULTILINE_STRING: &str = "string หลายบรรทัด (\"\"\" หรือ ''')";
+const DATE_TIME: &str = "วันที่/เวลา - ใช้ string แทน";
+
+/// parse เอกสาร TOML ทั้งไฟล์เป็นตารางราก
+///
+/// # Errors
+///
+/// คืน `TomlError` ที่ชี้ตำแหน่งแรกที่ผิดไวยากรณ์, key ซ้ำ หรือใช้ไวยากรณ์ที่ไม่รองรับ
+pub fn parse(source: &str) -> Result<TomlTable, TomlError> {
+    let mut parser = Parser::new(source);
+    let mut document = Document::default();
+    loop {
+        parser.skip_blank_lines();
+        match parser.peek() {
+            None => return Ok(document.root),
+            Some(b'[') => parser.header(&mut document)?,
+            Some(_) => {
+                let start = parser.at;
+                let (keys, value) = parser.key_value()?;
+                let table = descend(&mut document.root, &document.current)
+                    .map_err(|key| parser.error_at(TomlErrorKind::DuplicateKey(key), start))?;
+                insert(table, &keys, value)
+                    .map_err(|key| parser.error_at(TomlErrorKind::DuplicateKey(key), start))?;
+            }
+        }
+        parser.end_of_line()?;
+    }
+}
+
+/// parse ค่าเดี่ยวแบบที่อยู่หลัง `key = ` เช่น `8080`, `"text"`, `[1, 2]`
+///
+/// # Errors
+///
+/// คืน `TomlError` ถ้าไม่ใช่ค่า TOML ที่ถูกต้องทั้งข้อความ
+pub fn parse_value(text: &str) -> Result<TomlValue, TomlError> {
+    let mut parser = Parser::new(text);
+    parser.skip_spaces();
+    let value = parser.value()?;
+    parser.skip_spaces();
+    match parser.peek() {
+        None => Ok(value),
+        Some(_) => Err(parser.unexpected("จบค่า")),
+    }
+}
+
+/// สถานะระหว่าง parse ทั้งไฟล์: ตารางที่กำลังเติม และตารางที่ประกาศด้วย `[...]` แล้ว
+#[derive(Default)]
+struct Document {
+    root: TomlTable,
+    current: Vec<String>,
+    declared: HashSet<Vec<String>>,
+}
+
+/// เดินลงตามตาราง (สร้างตารางที่ยังไม่มี) - เจอ array of tables ก็ลงไปที่ตัวสุดท้าย
+/// ถ้าทางเดินชนค่าที่ไม่ใช่ตาราง คืน path ที่ชน
+fn descend<'t>(mut table: &'t mut TomlTable, path: &[String]) -> Result<&'t mut TomlTable, String> {
+    for (depth, key) in path.iter().enumerate() {
+        let entry = table
+            .entry(key.clone())
+            .or_insert_with(|| TomlValue::Table(TomlTable::new()));
+        table = match entry {
+            TomlValue::Table(inner) => inner,
+            TomlValue::Array(items) => match items.last_mut() {
+                Some(TomlValue::Table(inner)) => inner,
+                _ => return Err(path[..=depth].join(".")),
+            },
+            _ => return Err(path[..=depth].join(".")),
+        };
+    }
+    Ok(table)
+}
+
+/// วาง `value` ที่ dotted key `keys` - key ซ้ำเป็น error
+fn insert(table: &mut TomlTable, keys: &[String], value: TomlValue) -> Result<(), String> {
+    let (last, parents) = keys.split_last().expect("key มีอย่างน้อยหนึ่งส่วน");
+    let table = descend(table, parents)?;
+    if table.contains_key(last) {
+        return Err(keys.join("."));
+    }
+    table.insert(last.clone(), value);
+    Ok(())
+}
+
+/// 🧭 recursive descent บน byte เหมือน `json_parser` - โครงสร้างของ TOML เป็น ASCII ทั้งหมด
+struct Parser<'a> {
+    source: &'a str,
+    bytes: &'a [u8],
+    at: usize,
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    const fn new(source: &'a str) -> Self {
+        Self {
+            source,
+            bytes: source.as_bytes(),
+            at: 0,
+            depth: 0,
+        }
+    }
+
+    fn error_at(&self, kind: TomlErrorKind, offset: usize) -> TomlError {
+        TomlError::at(kind, self.source, offset)
+    }
+
+    fn error(&self, kind: TomlErrorKind) -> TomlError {
+        self.error_at(kind, self.at)
+    }
+
+    fn unexpected(&self, expected: &'static str) -> TomlError {
+        let found = self.source[self.at..].chars().next();
+        self.error(TomlErrorKind::Unexpected { found, expected })
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.at).copied()
+    }
+
+    fn rest_starts_with(&self, prefix: &[u8]) -> bool {
+        self.bytes[self.at..].starts_with(prefix)
+    }
+
+    fn skip_spaces(&mut self) {
+        while let Some(b' ' | b'\t') = self.peek() {
+            self.at += 1;
+        }
+    }
+
+    fn skip_comment(&mut self) {
+        if self.peek() == Some(b'#') {
+            while !matches!(self.peek(), None | Some(b'\n' | b'\r')) {
+                self.at += 1;
+            }
+        }
+    }
+
+    /// ข้ามช่องว่าง, comment และบรรทัดว่าง (ใช้ระหว่างบรรทัดและใน array)
+    fn skip_blank_lines(&mut self) {
+        loop {
+            self.skip_spaces();
+            self.skip_comment();
+            match self.peek() {
+                Some(b'\n') => self.at += 1,
+                Some(b'\r') if self.rest_starts_with(b"\r\n") => self.at += 2,
+                _ => return,
+            }
+        }
+    }
+
+    /// หลัง key/value หรือ header ต้องจบบรรทัด (comment ต่อท้ายได้)
+    fn end_of_line(&mut self) -> Result<(), TomlError> {
+        self.skip_spaces();
+        self.skip_comment();
+        match self.peek() {
+            None | Some(b'\n') => Ok(()),
+            Some(b'\r') if self.rest_starts_with(b"\r\n") => Ok(()),
+            Some(_) => Err(self.unexpected("ขึ้นบรรทัดใหม่")),
+        }
+    }
+
+    fn header(&mut self, document: &mut Document) -> Result<(), TomlError> {
+        let start = self.at;
+        self.at += 1; // '['
+        let array = self.peek() == Some(b'[');
+        if array {
+            self.at += 1;
+        }
+        let path = self.key()?;
+        for _ in 0..=usize::from(array) {
+            if self.peek() != Some(b']') {
+                return Err(self.unexpected(if array { "']]'" } else { "']'" }));
+            }
+            self.at += 1;
+        }
+
+        let duplicate =
+            |parser: &Self| parser.error_at(TomlErrorKind::DuplicateKey(path.join(".")), start);
+        let (last, parents) = path.split_last().expect("key มีอย่างน้อยหนึ่งส่วน");
+        let parent = descend(&mut document.root, parents)
+            .map_err(|key| self.error_at(TomlErrorKind::DuplicateKey(key), start))?;
+        if array {
+            let entry = parent
+                .entry(last.clone())
+                .or_insert_with(|| TomlValue::Array(Vec::new()));
+            match entry {
+                TomlValue::Array(items)
+                    if items.iter().all(|item| matches!(item, TomlValue::Table(_))) =>
+                {
+                    items.push(TomlValue::Table(TomlTable::new()));
+                }
+                _ => return Err(duplicate(self)),
+            }
+            // สมาชิกใหม่ของ array เริ่มนับตารางย่อยที่ประกาศแล้วใหม่หมด
+            document
+                .declared
+                .retain(|declared| !declared.starts_with(&path));
+        } else {
+            let entry = parent
+                .entry(last.clone())
+                .or_insert_with(|| TomlValue::Table(TomlTable::new()));
+            if !matches!(entry, TomlValue::Table(_)) || !document.declared.insert(path.clone()) {
+                return Err(duplicate(self));
+            }
+        }
+        document.current = path;
+        Ok(())
+    }
+
+    fn key_value(&mut self) -> Result<(Vec<String>, TomlValue), TomlError> {
+        let keys = self.key()?;
+        if self.peek() != Some(b'=') {
+            return Err(self.unexpected("'='"));
+        }
+        self.at += 1;
+        self.skip_spaces();
+        Ok((keys, self.value()?))
+    }
+
+    /// key หนึ่งตัว อาจเป็น dotted (`a."b.c".d`) - ช่องว่างรอบ `.` ได้
+    ///
+    /// แต่ละส่วนของ dotted key สร้างตารางซ้อนอีกชั้น จึงนับรวมกับ `depth` ด้วย
+    fn key(&mut self) -> Result<Vec<String>, TomlError> {
+        let mut parts = Vec::new();
+        loop {
+            self.skip_spaces();
+            if self.depth + parts.len() == MAX_DEPTH {
+                return Err(self.error(TomlErrorKind::TooDeep));
+            }
+            parts.push(match self.peek() {
+                Some(b'"') => self.basic_string()?,
+                Some(b'\'') => self.literal_string()?,
+                Some(byte) if is_bare_key_byte(byte) => {
+                    let start = self.at;
+                    while self.peek().is_some_and(is_bare_key_byte) {
+                        self.at += 1;
+                    }
+                    self.source[start..self.at].to_string()
+                }
+                _ => return Err(self.unexpected("key")),
+            });
+            self.skip_spaces();
+            if self.peek() != Some(b'.') {
+                return Ok(parts);
+            }
+            self.at += 1;
+        }
+    }
+
+    fn value(&mut self) -> Result<TomlValue, TomlError> {
+        match self.peek() {
+            Some(b'"') => self.basic_string().map(TomlValue::String),
+            Some(b'\'') => self.literal_string().map(TomlValue::String),
+            Some(b'[') => self.nested(Self::array),
+            Some(b'{') => self.nested(Self::inline_table),
+            Some(b't') if self.rest_starts_with(b"true") => {
+                self.at += 4;
+                Ok(TomlValue::Boolean(true))
+            }
+            Some(b'f') if self.rest_starts_with(b"false") => {
+                self.at += 5;
+                Ok(TomlValue::Boolean(false))
+            }
+            Some(b'+' | b'-' | b'0'..=b'9' | b'i' | b'n') => self.number(),
+            _ => Err(self.unexpected("ค่า TOML")),
+        }
+    }
+
+    /// นับความลึกรอบการ parse array/inline table
+    fn nested(
+        &mut self,
+        parse: fn(&mut Self) -> Result<TomlValue, TomlError>,
+    ) -> Result<TomlValue, TomlError> {
+        if self.depth == MAX_DEPTH {
+            return Err(self.error(TomlErrorKind::TooDeep));
+        }
+        self.depth += 1;
+        let value = parse(self);
+        self.depth -= 1;
+        value
+    }
+
+    fn array(&mut self) -> Result<TomlValue, TomlError> {
+        self.at += 1; // '['
+        let mut items = Vec::new();
+        loop {
+            self.skip_blank_lines();
+            if self.peek() == Some(b']') {
+                self.at += 1;
+                return Ok(TomlValue::Array(items));
+            }
+            items.push(self.value()?);
+            self.skip_blank_lines();
+            match self.peek() {
+                Some(b',') => self.at += 1,
+                Some(b']') => {
+                    self.at += 1;
+                    return Ok(TomlValue::Array(items));
+                }
+                _ => return Err(self.unexpected("',' หรือ ']'")),
+            }
+        }
+    }
+
+    /// `{ a = 1, b.c = 2 }` - ต้องอยู่ในบรรทัดเดียวและไม่มี comma ท้าย
+    fn inline_table(&mut self) -> Result<TomlValue, TomlError> {
+        self.at += 1; // '{'
+        let mut table = TomlTable::new();
+        self.skip_spaces();
+        if self.peek() == Some(b'}') {
+            self.at += 1;
+            return Ok(TomlValue::Table(table));
+        }
+        loop {
+            let start = self.at;
+            let (keys, value) = self.key_value()?;
+            insert(&mut table, &keys, value)
+                .map_err(|key| self.error_at(TomlErrorKind::DuplicateKey(key), start))?;
+            self.skip_spaces();
+            match self.peek() {
+                Some(b',') => self.at += 1,
+                Some(b'}') => {
+                    self.at += 1;
+                    return Ok(TomlValue::Table(table));
+                }
+                _ => return Err(self.unexpected("',' หรือ '}'")),
+            }
+        }
+    }
+
+    fn basic_string(&mut self) -> Result<String, TomlError> {
+        if self.rest_starts_with(b"\"\"\"") {
+            return Err(self.error(TomlErrorKind::Unsupported(MULTILINE_STRING)));
+        }
+        self.at += 1; // '"'
+        let mut text = String::new();
+        loop {
+            let start = self.at;
+            while let Some(byte) = self.peek() {
+                if byte == b'"' || byte == b'\\' || is_control(byte) {
+                    break;
+                }
+                self.at += 1;
+            }
+            text.push_str(&self.source[start..self.at]);
+            match self.peek() {
+                Some(b'"') => {
+                    self.at += 1;
+                    return Ok(text);
+                }
+                Some(b'\\') => {
+                    self.at += 1;
+                    text.push(self.escape()?);
+                }
+                None | Some(b'\n' | b'\r') => {
+                    return Err(self.error(TomlErrorKind::UnterminatedString));
+                }
+                Some(control) => {
+                    return Err(self.error(TomlErrorKind::ControlCharacter(char::from(control))));
+                }
+            }
+        }
+    }
+
+    /// `'...'` - ไม่มี escape ได้ข้อความตรงตัว
+    fn literal_string(&mut self) -> Result<String, TomlError> {
+        if self.rest_starts_with(b"'''") {
+            return Err(self.error(TomlErrorKind::Unsupported(MULTILINE_STRING)));
+        }
+        self.at += 1; // '\''
+        let start = self.at;
+        loop {
+            match self.peek() {
+                Some(b'\'') => {
+                    self.at += 1;
+                    return Ok(self.source[start..self.at - 1].to_string());
+                }
+                None | Some(b'\n' | b'\r') => {
+                    return Err(self.error(TomlErrorKind::UnterminatedString));
+                }
+                Some(control) if is_control(control) => {
+                    return Err(self.error(TomlErrorKind::ControlCharacter(char::from(control))));
+                }
+                Some(_) => self.at += 1,
+            }
+        }
+    }
+
+    fn escape(&mut self) -> Result<char, TomlError> {
+        let Some(c) = self.source[self.at..].chars().next() else {
+            return Err(self.error(TomlErrorKind::UnterminatedString));
+        };
+        let decoded = match c {
+            '"' => '"',
+            '\\' => '\\',
+            'b' => '\u{8}',
+            'f' => '\u{c}',
+            'n' => '\n',
+            'r' => '\r',
+            't' => '\t',
+            'u' => return self.unicode_escape(4),
+            'U' => return self.unicode_escape(8),
+            other => return Err(self.error(TomlErrorKind::InvalidEscape(other))),
+        };
+        self.at += 1;
+        Ok(decoded)
+    }
+
+    /// `\uXXXX` / `\UXXXXXXXX` - ต่างจาก JSON ตรงที่ใส่ scalar value ตรงๆ ไม่มี surrogate pair
+    fn unicode_escape(&mut self, digits: usize) -> Result<char, TomlError> {
+        let escape_start = self.at - 1;
+        let hex = self
+            .source
+            .get(self.at + 1..self.at + 1 + digits)
+            .filter(|hex| hex.bytes().all(|byte| byte.is_ascii_hexdigit()))
+            .ok_or_else(|| self.error_at(TomlErrorKind::InvalidUnicodeEscape, escape_start))?;
+        let c = u32::from_str_radix(hex, 16)
+            .ok()
+            .and_then(char::from_u32)
+            .ok_or_else(|| self.error_at(TomlErrorKind::InvalidUnicodeEscape, escape_start))?;
+        self.at += 1 + digits;
+        Ok(c)
+    }
+
+    /// กวาดตัวอักษรที่เป็นส่วนของตัวเลขได้ทั้งก้อน แล้วค่อยตัดสินว่าเป็นชนิดไหน
+    fn number(&mut self) -> Result<TomlValue, TomlError> {
+        let start = self.at;
+        while let Some(b'0'..=b'9' | b'a'..=b'z' | b'A'..=b'Z' | b'_' | b'+' | b'-' | b'.' | b':') =
+            self.peek()
+        {
+            self.at += 1;
+        }
+        let text = &self.source[start..self.at];
+        let invalid = || self.error_at(TomlErrorKind::InvalidNumber, start);
+
+        let looks_like_date = text.len() > 4
+            && text.as_bytes()[..4].iter().all(u8::is_ascii_digit)
+            && text.as_bytes()[4] == b'-';
+        if looks_like_date || text.contains(':') {
+            return Err(self.error_at(TomlErrorKind::Unsupported(DATE_TIME), start));
+        }
+
+        let unsigned = text.trim_start_matches(['+', '-']);
+        if matches!(unsigned, "inf" | "nan") && text.len() - unsigned.len() <= 1 {
+            let value = if unsigned == "inf" {
+                f64::INFINITY
+            } else {
+                f64::NAN
+            };
+            return Ok(TomlValue::Float(if text.starts_with('-') {
+                -value
+            } else {
+                value
+            }));
+        }
+
+        for (prefix, radix) in [("0x", 16), ("0o", 8), ("0b", 2)] {
+            if let Some(digits) = text.strip_prefix(prefix) {
+                if !underscores_ok(digits, |byte| char::from(byte).is_digit(radix)) {
+                    return Err(invalid());
+                }
+                return i64::from_str_radix(&digits.replace('_', ""), radix)
+                    .map(TomlValue::Integer)
+                    .map_err(|_| invalid());
+            }
+        }
+
+        if !is_decimal(text) {
+            return Err(invalid());
+        }
+        let cleaned = text.replace('_', "");
+        if text.contains(['.', 'e', 'E']) {
+            match cleaned.parse::<f64>() {
+                Ok(value) if value.is_finite() => Ok(TomlValue::Float(value)),
+                _ => Err(invalid()),
+            }
+        } else {
+            cleaned
+                .parse::<i64>()
+                .map(TomlValue::Integer)
+                .map_err(|_| invalid())
+        }
+    }
+}
+
+/// ตัวอักษรควบคุมที่ต้อง escape (tab อยู่ใน string ได้)
+const fn is_control(byte: u8) -> bool {
+    (byte < 0x20 && byte != b'\t') || byte == 0x7f
+}
+
+/// มีแต่หลักที่ถูกต้อง และ `_` ต้องอยู่ระหว่างหลักเท่านั้น (`1_000` ได้, `_1`, `1__0`, `1_` ไม่ได้)
+fn underscores_ok(digits: &str, is_digit: impl Fn(u8) -> bool) -> bool {
+    !digits.is_empty()
+        && digits
+            .split('_')
+            .all(|group| !group.is_empty() && group.bytes().all(&is_digit))
+}
+
+/// `[+-]? int (. digits)? ([eE] [+-]? digits)?` - int ห้ามขึ้นต้นด้วย 0 ถ้ามีหลายหลัก
+fn is_decimal(text: &str) -> bool {
+    let digit = |byte: u8| byte.is_ascii_digit();
+    let body = text.strip_prefix(['+', '-']).unwrap_or(text);
+    let (mantissa, exponent) = body.find(['e', 'E']).map_or((body, None), |index| {
+        (&body[..index], Some(&body[index + 1..]))
+    });
+    let (integer, fraction) = mantissa
+        .split_once('.')
+        .map_or((mantissa, None), |(integer, fraction)| {
+            (integer, Some(fraction))
+        });
+    underscores_ok(integer, digit)
+        && !(integer.len() > 1 && integer.starts_with('0'))
+        && fraction.is_none_or(|fraction| underscores_ok(fraction, digit))
+        && exponent.is_none_or(|exponent| {
+            underscores_ok(exponent.strip_prefix(['+', '-']).unwrap_or(exponent), digit)
+        })
+}
+
+/// 📄 สาธิต TOML subset: parse, เขียนกลับ และ error ที่ตั้งใจให้ชัด
+pub fn learn_toml_parser() {
+    println!("📄 === TOML Parser (subset) ที่เขียนเอง: ตาราง, dotted key และ round-trip === 📄");
+
+    let source = r#"
+# ⚙️ config ของร้านค้า
+name = "ร้านปู 🦀"
+"quoted.key" = 'C:\path'
+server.port = 8_080
+limits = { burst = 20, rate = 0.5 }
+
+[database]
+hosts = [
+    "db1",  # ตัวหลัก
+    "db2",
+]
+
+[[users]]
+name = "ferris"
+admin = true
+
+[[users]]
+name = "corro"
+"#;
+    match parse(source) {
+        Ok(table) => {
+            println!("\n✅ parse สำเร็จ {} key ที่ราก:", table.len());
+            for (key, value) in &table {
+                println!("   🔑 {key:<12} = {value} ({})", value.type_name());
+            }
+            println!("\n🖨️ เขียนกลับเป็น TOML (parse ซ้ำแล้วได้ค่าเดิม):");
+            let written = to_string(&table);
+            for line in written.lines().filter(|line| !line.is_empty()) {
+                println!("   {line}");
+            }
+            println!(
+                "   🔁 round-trip ตรงกัน: {}",
+                parse(&written).as_ref() == Ok(&table)
+            );
+        }
+        Err(error) => println!("❌ {error}"),
+    }
+
+    println!("\n📍 error บอกตำแหน่ง และบอกตรงๆ เมื่อเจอสิ่งที่ตั้งใจไม่รองรับ:");
+    for broken in [
+        "port = 80\nport = 81",
+        "[server]\nhost = \"a\"\n[server]",
+        "bio = \"\"\"\nยาวหลายบรรทัด\n\"\"\"",
+        "released = 2024-05-27",
+        "count = 0_1",
+    ] {
+        if let Err(error) = parse(broken) {
+            for line in error.render(broken).lines() {
+                println!("   {line}");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// เทียบกับ crate `toml` - float เทียบแบบ NaN == NaN
+    fn same(ours: &TomlValue, theirs: &toml::Value) -> bool {
+        match (ours, theirs) {
+            (TomlValue::String(a), toml::Value::String(b)) => a == b,
+            (TomlValue::Integer(a), toml::Value::Integer(b)) => a == b,
+            (TomlValue::Float(a), toml::Value::Float(b)) => {
+                a.total_cmp(b).is_eq() || (a.is_nan() && b.is_nan())
+            }
+            (TomlValue::Boolean(a), toml::Value::Boolean(b)) => a == b,
+            (TomlValue::Array(a), toml::Value::Array(b)) => {
+                a.len() == b.len() && a.iter().zip(b).all(|(a, b)| same(a, b))
+            }
+            (TomlValue::Table(a), toml::Value::Table(b)) => same_table(a, b),
+            _ => false,
+        }
+    }
+
+    fn same_table(ours: &TomlTable, theirs: &toml::Table) -> bool {
+        ours.len() == theirs.len()
+            && ours
+                .iter()
+                .all(|(key, value)| theirs.get(key).is_some_and(|other| same(value, other)))
+    }
+
+    const VALID: &[&str] = &[
+        "",
+        "# แค่ comment\n\n",
+        "title = \"TOML\"",
+        "bare_key-1 = 1\n\"quoted key\" = 2\n'literal \"key\"' = 3\n\"\" = 4",
+        "a.b.c = 1\na.d = 2\n\"x.y\".z = 3\nsite . \"google.com\" = true",
+        "esc = \"tab\\t nl\\n quote\\\" back\\\\ \\u0e01 \\U0001F980\"",
+        "path = 'C:\\Users\\nodejs'\nthai = \"สวัสดี 🦀\"",
+        "int = [+99, 42, 0, -17, 1_000, 5_349_221]\nhex = [0xDEADBEEF, 0xdead_beef, 0o755, 0b1101]",
+        "floats = [+1.0, 3.1415, -0.01, 5e+22, 1e06, -2E-2, 6.626e-34, 224_617.445_991]",
+        "special = [inf, +inf, -inf, nan, +nan, -nan]",
+        "bools = [true, false]\nempty = []\nnested = [[1, 2], [\"a\"], []]",
+        "multi = [\n  1, # หนึ่ง\n  2,\n\n  3,\n]",
+        "point = { x = 1, y = 2 }\nempty = {}\ndeep = { a.b = 1, c = { d = [1] } }",
+        "[table]\nkey = 1\n\n[table.child]\nkey = 2\n\n[other]",
+        "[a.b.c]\nx = 1\n[a]\ny = 2",
+        "[ spaced . \"header\" ]\nk = 1 # ท้ายบรรทัด",
+        "[[fruits]]\nname = \"apple\"\n[fruits.physical]\ncolor = \"red\"\n\n[[fruits]]\nname = \"banana\"\n[fruits.physical]\ncolor = \"yellow\"",
+        "[[empty]]\n[[empty]]",
+        "list = [{ a = 1 }, { a = 2 }]",
+        "windows = 1\r\nline = 2\r\n",
+    ];
+
+    const INVALID: &[&str] = &[
+        "key",
+        "key =",
+        "= 1",
+        "a = 1 b = 2",
+        "a = 1\na = 2",
+        "a.b = 1\na.b = 2",
+        "a = 1\na.b = 2",
+        "[t]\n[t]",
+        "a = 1\n[a]",
+        "[t\nx = 1",
+        "[[t]\nx = 1",
+        "s = \"unterminated",
+        "s = \"line\nbreak\"",
+        "s = 'unterminated",
+        "s = \"\\x\"",
+        "s = \"\\u12\"",
+        "s = \"\\uD800\"",
+        "n = 01",
+        "n = 1__0",
+        "n = _1",
+        "n = 1_",
+        "n = 1.",
+        "n = .5",
+        "n = 1e",
+        "n = 9223372036854775808",
+        "n = 0x",
+        "n = +0x1",
+        "b = True",
+        "arr = [1 2]",
+        "arr = [1,,]",
+        "t = { a = 1, a = 2 }",
+        "a = [1]\n[[a]]",
+    ];
+
+    #[test]
+    fn test_differential_against_toml_crate() {
+        for source in VALID {
+            let ours = parse(source).unwrap_or_else(|error| panic!("{source:?}: {error}"));
+            let theirs: toml::Table = source.parse().unwrap();
+            assert!(
+                same_table(&ours, &theirs),
+                "{source:?}: {ours:?} != {theirs:?}"
+            );
+
+            // สิ่งที่เราเขียนออกต้องอ่านกลับได้เหมือนเดิม ทั้งโดยตัวเราและโดย crate `toml`
+            let written = to_string(&ours);
+            let reparsed = parse(&written).unwrap_or_else(|error| panic!("{written}\n{error}"));
+            assert!(
+                same_table(&reparsed, &written.parse::<toml::Table>().unwrap()),
+                "{written}"
+            );
+            assert!(same_table(&reparsed, &theirs), "{written}");
+        }
+        for source in INVALID {
+            assert!(parse(source).is_err(), "{source:?} ควร error");
+            assert!(
+                source.parse::<toml::Table>().is_err(),
+                "corpus ผิด: crate toml รับ {source:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_unsupported_syntax_has_clear_errors() {
+        for (source, what, column) in [
+            ("bio = \"\"\"\nline\n\"\"\"", MULTILINE_STRING, 7),
+            ("raw = '''x'''", MULTILINE_STRING, 7),
+            ("date = 1979-05-27", DATE_TIME, 8),
+            ("at = 1979-05-27T07:32:00Z", DATE_TIME, 6),
+            ("time = 07:32:00", DATE_TIME, 8),
+        ] {
+            let error = parse(source).unwrap_err();
+            assert_eq!(error.kind, TomlErrorKind::Unsupported(what), "{source}");
+            assert_eq!((error.line, error.column), (1, column), "{source}");
+            // crate `toml` รับทั้งหมดนี้ - เป็นการตัดออกโดยตั้งใจ ไม่ใช่ input เสีย
+            assert!(source.parse::<toml::Table>().is_ok(), "{source}");
+        }
+        assert!(
+            parse("bio = \"\"\"x\"\"\"")
+                .unwrap_err()
+                .to_string()
+                .contains("ตั้งใจตัดออก")
+        );
+    }
+
+    #[test]
+    fn test_nesting_depth_is_limited() {
+        let arrays = |depth: usize| format!("a = {}1{}", "[".repeat(depth), "]".repeat(depth));
+        assert!(parse(&arrays(MAX_DEPTH)).is_ok());
+        let error = parse(&arrays(MAX_DEPTH + 1)).unwrap_err();
+        assert_eq!(
+            (error.kind, error.line, error.column),
+            (TomlErrorKind::TooDeep, 1, MAX_DEPTH + 5)
+        );
+
+        // ~20 KB ที่เดิมทำ stack ล้น - ได้ error ที่มีตำแหน่งแทน
+        let tables = format!("x = 1\na = {}1{}", "{a = ".repeat(4_000), "}".repeat(4_000));
+        let error = parse(&tables).unwrap_err();
+        assert_eq!((error.kind, error.line), (TomlErrorKind::TooDeep, 2));
+        assert_eq!(
+            parse(&arrays(20_000)).unwrap_err().kind,
+            TomlErrorKind::TooDeep
+        );
+        assert_eq!(
+            parse_value(&"[".repeat(20_000)).unwrap_err().kind,
+            TomlErrorKind::TooDeep
+        );
+
+        // dotted key ก็สร้างตารางซ้อน จึงนับด้วย
+        let dotted = format!("{} = 1", vec!["a"; MAX_DEPTH + 1].join("."));
+        assert_eq!(parse(&dotted).unwrap_err().kind, TomlErrorKind::TooDeep);
+        assert!(parse(&format!("[{}]", vec!["a"; 20_000].join("."))).is_err());
+    }
+
+    #[test]
+    fn test_error_kinds_and_positions() {
+        let error = |source: &str| {
+            let error = parse(source).unwrap_err();
+            (error.kind, error.line, error.column)
+        };
+        assert_eq!(
+            error("name = \"x\"\nname = \"y\""),
+            (TomlErrorKind::DuplicateKey("name".into()), 2, 1)
+        );
+        assert_eq!(
+            error("[server]\nport = 1\n\n[server]"),
+            (TomlErrorKind::DuplicateKey("server".into()), 4, 1)
+        );
+        assert_eq!(
+            error("a = 1\n[a.b]"),
+            (TomlErrorKind::DuplicateKey("a".into()), 2, 1)
+        );
+        assert_eq!(
+            error("key = value"),
+            (
+                TomlErrorKind::Unexpected {
+                    found: Some('v'),
+                    expected: "ค่า TOML"
+                },
+                1,
+                7
+            )
+        );
+        assert_eq!(
+            error("[t]\nk = 1 2"),
+            (
+                TomlErrorKind::Unexpected {
+                    found: Some('2'),
+                    expected: "ขึ้นบรรทัดใหม่"
+                },
+                2,
+                7
+            )
+        );
+        assert_eq!(
+            error("arr = [1, 2"),
+            (
+                TomlErrorKind::Unexpected {
+                    found: None,
+                    expected: "',' หรือ ']'"
+                },
+                1,
+                12
+            )
+        );
+        assert_eq!(
+            error("ชื่อ = 1"),
+            (
+                TomlErrorKind::Unexpected {
+                    found: Some('ช'),
+                    expected: "key"
+                },
+                1,
+                1
+            )
+        );
+        assert_eq!(
+            error("s = \"a\\qb\""),
+            (TomlErrorKind::InvalidEscape('q'), 1, 8)
+        );
+        assert_eq!(error("n = 007"), (TomlErrorKind::InvalidNumber, 1, 5));
+
+        // inline table บรรทัดเดียวไม่มี comma ท้ายตาม TOML 1.0 (crate `toml` ใช้ 1.1 ที่ผ่อนกฎนี้แล้ว)
+        assert_eq!(
+            error("t = { a = 1, }"),
+            (
+                TomlErrorKind::Unexpected {
+                    found: Some('}'),
+                    expected: "key"
+                },
+                1,
+                14
+            )
+        );
+        assert!(parse("t = { a = 1\n}").is_err());
+
+        let source = "[server]\nhost = \"localhost\nport = 80";
+        assert_eq!(
+            parse(source).unwrap_err().render(source),
+            "string ไม่มีเครื่องหมายปิดในบรรทัดเดียวกัน (บรรทัด 2 คอลัมน์ 18)\n  host = \"localhost\n                   ^"
+        );
+    }
+
+    #[test]
+    fn test_serializer_and_parse_value() {
+        let table = parse(
+            "z = 1.0\n\"needs quote\" = \"a\\\"b\"\n[a]\nlist = [{ x = 1 }]\n[[a.items]]\nn = 1\n[a.items.meta]\nok = true",
+        )
+        .unwrap();
+        assert_eq!(
+            to_string(&table),
+            "\"needs quote\" = \"a\\\"b\"\nz = 1.0\n\n[a]\n\n[[a.items]]\nn = 1\n\n[a.items.meta]\nok = true\n\n[[a.list]]\nx = 1\n"
+        );
+        assert_eq!(parse(&to_string(&table)).unwrap(), table);
+
+        assert_eq!(parse_value(" 8080 "), Ok(TomlValue::Integer(8080)));
+        assert_eq!(
+            parse_value("[\"a\", 1.5]"),
+            Ok(TomlValue::Array(vec![
+                TomlValue::String("a".into()),
+                TomlValue::Float(1.5)
+            ]))
+        );
+        assert!(parse_value("localhost:80").is_err());
+        assert!(parse_value("1 2").is_err());
+    }
+}
@@ -9,7 +9,7 @@ mod practice_generics;
 pub use generic_enums::learn_generic_enums;
 pub use generic_functions::learn_generic_functions;
 pub use generic_structs::learn_generic_structs;
-pub use practice_generics::practice_generics;
+pub use practice_generics::{practice_generics, Stack};
 
 /// ฟังก์ชันสำหรับรันตัวอย่าง generics (เรียกจาก main.rs) - ทัวร์ห้องแล็บ! 🧬🎫
 pub fn run_generics_examples() {
@@ -4,46 +4,51 @@
 //! ฝึกฝนจนเป็นมืออาชีพ - นี่คือการเรียนรู้แบบ hands-on! 🔧🎪
 //! เหมือนเรียนขับรถที่ขับได้ทุกคัน หรือเรียนทำอาหารที่ทำได้ทุกเมนู! 🚗👨‍🍳
 
-/// ฟังก์ชันสำหรับฝึกฝน Generics
-/// มาฝึกใช้ Generics กับตัวอย่างจริงกันเถอะ! เหมือนเล่นเลโก้ที่ต่อได้ทุกชิ้น! 🎯
-#[allow(clippy::too_many_lines)]
-pub fn practice_generics() {
-    println!("🎯 === ฝึกฝน Generics: เวลาฝึกหัดใช้ของเล่นใหม่! === 🎯");
-    println!("🎪 เหมือนเข้าค่ายฝึกซ้อมกีฬา หรือเรียนเต้นรำที่ต้องฝึกจนชำนาญ! 💃🕺");
+/// 📚 Generic Stack - กองซ้อนที่ใส่อะไรก็ได้!
+/// เหมือนกองหนังสือที่เรียงซ้อนกัน หรือจานที่ล้างแล้วเรียงไว้! 📚🍽️
+#[derive(Debug, Default)]
+pub struct Stack<T> {
+    items: Vec<T>,  // กองของที่เรียงซ้อนกัน
+}
 
-    // 1. Generic Stack - กองซ้อนที่ใส่อะไรก็ได้!
-    // เหมือนกองหนังสือที่เรียงซ้อนกัน หรือจานที่ล้างแล้วเรียงไว้! 📚🍽️
-    #[derive(Debug)]
-    struct Stack<T> {
-        items: Vec<T>,  // กองของที่เรียงซ้อนกัน
+impl<T> Stack<T> {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { items: Vec::new() }
     }
 
-    impl<T> Stack<T> {
-        const fn new() -> Self {
-            Self { items: Vec::new() }
-        }
-
-        fn push(&mut self, item: T) {
-            self.items.push(item);  // ใส่ของเข้าไปในกอง
-        }
+    pub fn push(&mut self, item: T) {
+        self.items.push(item);  // ใส่ของเข้าไปในกอง
+    }
 
-        fn pop(&mut self) -> Option<T> {
-            self.items.pop()  // เอาของออกจากด้านบน
-        }
+    pub fn pop(&mut self) -> Option<T> {
+        self.items.pop()  // เอาของออกจากด้านบน
+    }
 
-        fn peek(&self) -> Option<&T> {
-            self.items.last()  // แอบดูของด้านบนโดยไม่เอาออก
-        }
+    #[must_use]
+    pub fn peek(&self) -> Option<&T> {
+        self.items.last()  // แอบดูของด้านบนโดยไม่เอาออก
+    }
 
-        const fn is_empty(&self) -> bool {
-            self.items.is_empty()
-        }
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
 
-        const fn size(&self) -> usize {
-            self.items.len()
-        }
+    #[must_use]
+    pub const fn size(&self) -> usize {
+        self.items.len()
     }
+}
+
+/// ฟังก์ชันสำหรับฝึกฝน Generics
+/// มาฝึกใช้ Generics กับตัวอย่างจริงกันเถอะ! เหมือนเล่นเลโก้ที่ต่อได้ทุกชิ้น! 🎯
+#[allow(clippy::too_many_lines)]
+pub fn practice_generics() {
+    println!("🎯 === ฝึกฝน Generics: เวลาฝึกหัดใช้ของเล่นใหม่! === 🎯");
+    println!("🎪 เหมือนเข้าค่ายฝึกซ้อมกีฬา หรือเรียนเต้นรำที่ต้องฝึกจนชำนาญ! 💃🕺");
 
+    // 1. Generic Stack - กองซ้อนที่ใส่อะไรก็ได้! (ดู `Stack<T>` ด้านล่าง)
     println!("\n📚 === Generic Stack: กองซ้อนที่ไม่เลือกของ! === 📚");
     println!("🥞 เหมือนแพนเค้กที่ซ้อนกันสูง หรือกองเหรียญที่เก็บไว้ในกระปุก! 🪙💰");
 
@@ -0,0 +1,787 @@
+//! Generic Associated Types (GATs) - associated type ที่มี lifetime เป็นของตัวเอง! 🧬🔑
+//!
+//! associated type ธรรมดา (`type Item;`) ผูกกับ type เดียวตลอดชีวิตของ trait
+//! GAT (`type Item<'a> where Self: 'a;`) ให้แต่ละ *การเรียก* เลือก lifetime เองได้
+//! จึงคืนของที่ "ยืม `self` มา" ได้ ซึ่ง `Iterator` ธรรมดาทำไม่ได้ 🤯
+//!
+//! 🛠️ **สิ่งที่จะได้เรียนรู้:**
+//! - 🔁 `LendingIterator` - iterator ที่ให้ยืมของทีละชิ้น (`windows_mut`, `ReusedLines`)
+//! - 🗄️ `Repository` ที่คืน transaction handle แบบ `Self::Tx<'_>` - ยืม repo ไว้จน commit/rollback
+//! - ⚡ async trait สองแบบบน storage backend: future เป็น GAT (`GatStorageBackend`, สไตล์ก่อน 1.75)
+//!   กับ `impl Future` ใน trait (`StorageBackend`, Rust 1.75+)
+//!
+//! ```
+//! use rust_concepts::traits::gats::{LendingIterator, windows_mut};
+//!
+//! let mut data = [1, 2, 3, 4];
+//! let mut windows = windows_mut(&mut data, 2);
+//! while let Some(window) = windows.next() {
+//!     window[1] += window[0]; // แต่ละหน้าต่างเห็นผลจากหน้าต่างก่อนหน้า
+//! }
+//! assert_eq!(data, [1, 3, 6, 10]);
+//! ```
+//!
+//! ```compile_fail
+//! use rust_concepts::traits::gats::{LendingIterator, windows_mut};
+//!
+//! let mut data = [1, 2, 3];
+//! let mut windows = windows_mut(&mut data, 2);
+//! let first = windows.next().unwrap();
+//! let second = windows.next().unwrap(); // ❌ `first` ยังยืม `windows` อยู่ - ถือสองชิ้นพร้อมกันไม่ได้
+//! first[1] = second[0];
+//! ```
+//!
+//! ```
+//! use rust_concepts::traits::gats::{MemoryRepo, Repository, Transaction};
+//!
+//! let mut repo = MemoryRepo::new();
+//! let mut tx = repo.begin();
+//! let id = tx.insert("ร่าง");
+//! drop(tx); // ไม่ commit = rollback
+//! assert!(repo.get(id).is_none());
+//! ```
+//!
+//! ```compile_fail
+//! use rust_concepts::traits::gats::{MemoryRepo, Repository, Transaction};
+//!
+//! let mut repo = MemoryRepo::new();
+//! let mut tx = repo.begin();
+//! tx.insert("ร่าง");
+//! println!("{}", repo.len()); // ❌ transaction ยืม repo แบบ `&mut` อยู่ - อ่านค่าครึ่งๆ กลางๆ ไม่ได้
+//! tx.commit().unwrap();
+//! ```
+//!
+//! ```
+//! use rust_concepts::traits::gats::{MemoryBackend, StorageBackend};
+//!
+//! # tokio::runtime::Runtime::new().unwrap().block_on(async {
+//! let backend = MemoryBackend::new();
+//! backend.put("greeting", b"hi".to_vec()).await.unwrap();
+//! assert_eq!(backend.get("greeting").await.unwrap(), Some(b"hi".to_vec()));
+//! # });
+//! ```
+//!
+//! ```compile_fail
+//! // ❌ ลืม `+ Send` ที่ future ใน trait: โค้ด generic จะ `tokio::spawn` ไม่ได้เลย
+//! // เพราะ compiler ไม่รู้ว่า future ของ backend ทุกตัวส่งข้าม thread ได้
+//! use std::future::Future;
+//! use std::sync::Arc;
+//!
+//! trait Backend: Send + Sync + 'static {
+//!     fn get(&self) -> impl Future<Output = u8>;
+//! }
+//!
+//! fn spawn_get<B: Backend>(backend: Arc<B>) {
+//!     tokio::spawn(async move { backend.get().await });
+//! }
+//! ```
+
+use std::collections::{BTreeMap, HashMap};
+use std::error::Error;
+use std::fmt;
+use std::future::{self, Future, Ready};
+use std::io::{self, BufRead};
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::PathBuf;
+#[cfg(not(target_arch = "wasm32"))]
+use std::pin::Pin;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use crate::security::sanitization::PathViolation;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::security::sanitization::safe_relative_path;
+
+/// 🔁 iterator ที่ของแต่ละชิ้นยืม iterator เองอยู่ - ต้องคืนชิ้นเก่าก่อนขอชิ้นใหม่
+///
+/// ⚠️ ยังไม่มี adapter อย่าง `for_each(impl FnMut(Self::Item<'_>))` ให้ใช้สบายๆ:
+/// closure แบบ higher-ranked บังคับ `for<'a> Self: 'a` ซึ่ง compiler ปัจจุบันตีความเป็น `Self: 'static`
+/// จึงวนด้วย `while let Some(item) = iter.next()` แทน
+pub trait LendingIterator {
+    /// ของที่ให้ยืม - lifetime `'a` มาจาก `&'a mut self` ของการเรียก `next` แต่ละครั้ง
+    type Item<'a>
+    where
+        Self: 'a;
+
+    fn next(&mut self) -> Option<Self::Item<'_>>;
+}
+
+/// 🪟 หน้าต่างแบบ `&mut` ที่ซ้อนทับกัน - `Iterator` ธรรมดาทำไม่ได้เพราะจะมี `&mut` ซ้อนกันสองอัน
+#[derive(Debug)]
+pub struct WindowsMut<'s, T> {
+    slice: &'s mut [T],
+    size: usize,
+    start: usize,
+}
+
+/// หน้าต่างขนาด `size` ที่เลื่อนทีละช่อง (แบบ `slice::windows` แต่แก้ค่าได้)
+///
+/// # Panics
+///
+/// เมื่อ `size` เป็น 0
+pub fn windows_mut<T>(slice: &mut [T], size: usize) -> WindowsMut<'_, T> {
+    assert!(size > 0, "window size must be positive");
+    WindowsMut {
+        slice,
+        size,
+        start: 0,
+    }
+}
+
+impl<T> LendingIterator for WindowsMut<'_, T> {
+    type Item<'a>
+        = &'a mut [T]
+    where
+        Self: 'a;
+
+    fn next(&mut self) -> Option<&mut [T]> {
+        let window = self.slice.get_mut(self.start..self.start + self.size)?;
+        self.start += 1;
+        Some(window)
+    }
+}
+
+/// 📜 อ่านทีละบรรทัดโดยใช้ `String` ตัวเดียวซ้ำ - ไม่ alloc ใหม่ทุกบรรทัดแบบ `BufRead::lines`
+#[derive(Debug)]
+pub struct ReusedLines<R> {
+    reader: R,
+    buffer: String,
+}
+
+impl<R: BufRead> ReusedLines<R> {
+    pub const fn new(reader: R) -> Self {
+        Self {
+            reader,
+            buffer: String::new(),
+        }
+    }
+
+    /// ความจุของ buffer ที่ใช้ซ้ำ (โตตามบรรทัดที่ยาวที่สุดที่เคยเจอ)
+    #[must_use]
+    pub const fn buffer_capacity(&self) -> usize {
+        self.buffer.capacity()
+    }
+}
+
+impl<R: BufRead> LendingIterator for ReusedLines<R> {
+    type Item<'a>
+        = io::Result<&'a str>
+    where
+        Self: 'a;
+
+    fn next(&mut self) -> Option<io::Result<&str>> {
+        self.buffer.clear();
+        match self.reader.read_line(&mut self.buffer) {
+            Ok(0) => None,
+            Ok(_) => Some(Ok(self.buffer.trim_end_matches(['\n', '\r']))),
+            Err(error) => Some(Err(error)),
+        }
+    }
+}
+
+/// ❌ ข้อผิดพลาดของ `MemoryRepo`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RepoError {
+    NotFound(u64),
+}
+
+impl fmt::Display for RepoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotFound(id) => write!(f, "entity {id} not found"),
+        }
+    }
+}
+
+impl Error for RepoError {}
+
+/// ✍️ การแก้ไขที่ยังไม่ถูกบันทึก - drop โดยไม่ `commit` = rollback
+pub trait Transaction {
+    type Entity;
+    type Error;
+
+    /// อ่านค่า โดยเห็นการแก้ไขของ transaction นี้เองด้วย (read-your-writes)
+    fn get(&self, id: u64) -> Option<&Self::Entity>;
+
+    fn insert(&mut self, entity: Self::Entity) -> u64;
+
+    /// # Errors
+    ///
+    /// คืน error เมื่อไม่มี `id` นี้
+    fn update(&mut self, id: u64, entity: Self::Entity) -> Result<(), Self::Error>;
+
+    /// # Errors
+    ///
+    /// คืน error เมื่อไม่มี `id` นี้
+    fn delete(&mut self, id: u64) -> Result<(), Self::Error>;
+
+    /// # Errors
+    ///
+    /// คืน error เมื่อบันทึกไม่สำเร็จ (ไม่มีอะไรถูกบันทึกเลย)
+    fn commit(self) -> Result<(), Self::Error>;
+}
+
+/// 🗄️ repository ที่แต่ละ backend เลือก type ของ transaction handle ได้เอง
+///
+/// `Tx<'a>` ยืม repository ไว้ `'a` - ระหว่างที่ transaction เปิดอยู่
+/// compiler จึงไม่ยอมให้ใครอ่านหรือเขียน repo ตรงๆ (isolation ตรวจตอน compile!)
+pub trait Repository {
+    type Entity;
+    type Error;
+    type Tx<'a>: Transaction<Entity = Self::Entity, Error = Self::Error>
+    where
+        Self: 'a;
+
+    fn get(&self, id: u64) -> Option<&Self::Entity>;
+
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn begin(&mut self) -> Self::Tx<'_>;
+}
+
+/// 🧠 repository ใน memory
+#[derive(Debug)]
+pub struct MemoryRepo<T> {
+    rows: BTreeMap<u64, T>,
+    next_id: u64,
+}
+
+impl<T> Default for MemoryRepo<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> MemoryRepo<T> {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            rows: BTreeMap::new(),
+            next_id: 1,
+        }
+    }
+}
+
+/// transaction ของ `MemoryRepo` - เก็บการแก้ไขไว้ใน overlay (`None` = ถูกลบ) จนกว่าจะ commit
+#[derive(Debug)]
+#[must_use = "dropping a transaction rolls it back"]
+pub struct MemoryTx<'a, T> {
+    repo: &'a mut MemoryRepo<T>,
+    overlay: BTreeMap<u64, Option<T>>,
+    next_id: u64,
+}
+
+impl<T> Repository for MemoryRepo<T> {
+    type Entity = T;
+    type Error = RepoError;
+    type Tx<'a>
+        = MemoryTx<'a, T>
+    where
+        Self: 'a;
+
+    fn get(&self, id: u64) -> Option<&T> {
+        self.rows.get(&id)
+    }
+
+    fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    fn begin(&mut self) -> MemoryTx<'_, T> {
+        let next_id = self.next_id;
+        MemoryTx {
+            repo: self,
+            overlay: BTreeMap::new(),
+            next_id,
+        }
+    }
+}
+
+impl<T> Transaction for MemoryTx<'_, T> {
+    type Entity = T;
+    type Error = RepoError;
+
+    fn get(&self, id: u64) -> Option<&T> {
+        self.overlay
+            .get(&id)
+            .map_or_else(|| self.repo.rows.get(&id), Option::as_ref)
+    }
+
+    fn insert(&mut self, entity: T) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.overlay.insert(id, Some(entity));
+        id
+    }
+
+    fn update(&mut self, id: u64, entity: T) -> Result<(), RepoError> {
+        if self.get(id).is_none() {
+            return Err(RepoError::NotFound(id));
+        }
+        self.overlay.insert(id, Some(entity));
+        Ok(())
+    }
+
+    fn delete(&mut self, id: u64) -> Result<(), RepoError> {
+        if self.get(id).is_none() {
+            return Err(RepoError::NotFound(id));
+        }
+        self.overlay.insert(id, None);
+        Ok(())
+    }
+
+    fn commit(self) -> Result<(), RepoError> {
+        for (id, staged) in self.overlay {
+            match staged {
+                Some(entity) => self.repo.rows.insert(id, entity),
+                None => self.repo.rows.remove(&id),
+            };
+        }
+        self.repo.next_id = self.next_id;
+        Ok(())
+    }
+}
+
+/// แทนค่าหลายแถวแบบ all-or-nothing ได้กับทุก `Repository` - ถ้ามีแถวไหนไม่เจอ ไม่มีอะไรเปลี่ยนเลย
+///
+/// # Errors
+///
+/// คืน error แรกจาก transaction (แถวที่ไม่มีอยู่ หรือ commit ไม่สำเร็จ)
+pub fn replace_all<R: Repository>(
+    repo: &mut R,
+    updates: impl IntoIterator<Item = (u64, R::Entity)>,
+) -> Result<(), R::Error> {
+    let mut tx = repo.begin();
+    for (id, entity) in updates {
+        // `?` คืน error ออกไปพร้อม drop `tx` = rollback อัตโนมัติ
+        tx.update(id, entity)?;
+    }
+    tx.commit()
+}
+
+/// ❌ ข้อผิดพลาดของ storage backend
+#[derive(Debug)]
+pub enum BackendError {
+    /// key ที่อาจพาออกนอก root directory
+    InvalidKey {
+        key: String,
+        reason: PathViolation,
+    },
+    Io {
+        key: String,
+        source: io::Error,
+    },
+}
+
+impl fmt::Display for BackendError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidKey { key, reason } => write!(f, "invalid key {key:?}: {reason}"),
+            Self::Io { key, source } => write!(f, "I/O error on key {key:?}: {source}"),
+        }
+    }
+}
+
+impl Error for BackendError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::InvalidKey { reason, .. } => Some(reason),
+            Self::Io { source, .. } => Some(source),
+        }
+    }
+}
+
+type GetResult = Result<Option<Vec<u8>>, BackendError>;
+
+/// ⚡ async trait แบบ GAT: type ของ future เป็น associated type ที่ยืม `self` และ `key`
+///
+/// นี่คือวิธีเดียวก่อน Rust 1.75 ที่ไม่ต้อง alloc - backend ที่ไม่ต้องรออะไรเลย
+/// ตั้ง `GetFuture` เป็น `Ready` ได้ ส่วน backend ที่ต้องรอ I/O มักต้อง `Box::pin`
+/// (ซึ่งคือสิ่งที่ `#[async_trait]` generate ให้ทุก method)
+pub trait GatStorageBackend: Send + Sync {
+    type GetFuture<'a>: Future<Output = GetResult> + Send + 'a
+    where
+        Self: 'a;
+    type PutFuture<'a>: Future<Output = Result<(), BackendError>> + Send + 'a
+    where
+        Self: 'a;
+
+    fn get<'a>(&'a self, key: &'a str) -> Self::GetFuture<'a>;
+
+    fn put<'a>(&'a self, key: &'a str, value: Vec<u8>) -> Self::PutFuture<'a>;
+}
+
+/// ⚡ async trait แบบ Rust 1.75+: `impl Future` ใน trait (ฝั่ง impl เขียน `async fn` ได้เลย)
+///
+/// ใส่ `+ Send` ไว้ใน trait เพื่อให้โค้ด generic `tokio::spawn` future ของ backend ใดก็ได้
+/// (ถ้าเขียน `async fn` ใน trait ตรงๆ จะกำหนด `Send` ให้ future ไม่ได้)
+pub trait StorageBackend: Send + Sync {
+    fn get(&self, key: &str) -> impl Future<Output = GetResult> + Send;
+
+    fn put(
+        &self,
+        key: &str,
+        value: Vec<u8>,
+    ) -> impl Future<Output = Result<(), BackendError>> + Send;
+
+    /// คืน `true` ถ้ามี key นี้อยู่และถูกลบ
+    fn delete(&self, key: &str) -> impl Future<Output = Result<bool, BackendError>> + Send;
+}
+
+/// 🧠 backend ใน memory - ทุก operation เสร็จทันที
+#[derive(Debug, Default)]
+pub struct MemoryBackend {
+    entries: Mutex<HashMap<String, Vec<u8>>>,
+}
+
+impl MemoryBackend {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn entries(&self) -> MutexGuard<'_, HashMap<String, Vec<u8>>> {
+        self.entries.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl GatStorageBackend for MemoryBackend {
+    // ไม่มีอะไรต้องรอ - future สำเร็จรูปจาก std ไม่ต้อง alloc
+    type GetFuture<'a> = Ready<GetResult>;
+    type PutFuture<'a> = Ready<Result<(), BackendError>>;
+
+    fn get<'a>(&'a self, key: &'a str) -> Ready<GetResult> {
+        future::ready(Ok(self.entries().get(key).cloned()))
+    }
+
+    fn put<'a>(&'a self, key: &'a str, value: Vec<u8>) -> Ready<Result<(), BackendError>> {
+        self.entries().insert(key.to_string(), value);
+        future::ready(Ok(()))
+    }
+}
+
+impl StorageBackend for MemoryBackend {
+    async fn get(&self, key: &str) -> GetResult {
+        Ok(self.entries().get(key).cloned())
+    }
+
+    async fn put(&self, key: &str, value: Vec<u8>) -> Result<(), BackendError> {
+        self.entries().insert(key.to_string(), value);
+        Ok(())
+    }
+
+    async fn delete(&self, key: &str) -> Result<bool, BackendError> {
+        Ok(self.entries().remove(key).is_some())
+    }
+}
+
+/// 📁 backend ที่เก็บแต่ละ key เป็นไฟล์ใต้ `root` (key `a/b` → `<root>/a/b`)
+///
+/// ใช้ `tokio::fs` จึงมีเฉพาะบน native (wasm32 ไม่มีระบบไฟล์)
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug, Clone)]
+pub struct DirBackend {
+    root: PathBuf,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl DirBackend {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// ตรวจ key ด้วยกฎเดียวกับ static file server - ห้าม `..`, ไฟล์ซ่อน, path แบบ absolute
+    fn path_for(&self, key: &str) -> Result<PathBuf, BackendError> {
+        let invalid = |reason| BackendError::InvalidKey {
+            key: key.to_string(),
+            reason,
+        };
+        let relative = safe_relative_path(key).map_err(invalid)?;
+        if relative.as_os_str().is_empty() {
+            return Err(invalid(PathViolation::ForbiddenCharacter('/')));
+        }
+        Ok(self.root.join(relative))
+    }
+
+    async fn read(&self, key: &str) -> GetResult {
+        let path = self.path_for(key)?;
+        match tokio::fs::read(&path).await {
+            Ok(bytes) => Ok(Some(bytes)),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(source) => Err(io_error(key, source)),
+        }
+    }
+
+    async fn write(&self, key: &str, value: Vec<u8>) -> Result<(), BackendError> {
+        let path = self.path_for(key)?;
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(|source| io_error(key, source))?;
+        }
+        tokio::fs::write(&path, value)
+            .await
+            .map_err(|source| io_error(key, source))
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn io_error(key: &str, source: io::Error) -> BackendError {
+    BackendError::Io {
+        key: key.to_string(),
+        source,
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl GatStorageBackend for DirBackend {
+    // ต้องรอ I/O และ type ของ `async` block ตั้งชื่อไม่ได้ - จึงต้อง box (แบบ `#[async_trait]`)
+    type GetFuture<'a> = Pin<Box<dyn Future<Output = GetResult> + Send + 'a>>;
+    type PutFuture<'a> = Pin<Box<dyn Future<Output = Result<(), BackendError>> + Send + 'a>>;
+
+    fn get<'a>(&'a self, key: &'a str) -> Self::GetFuture<'a> {
+        Box::pin(self.read(key))
+    }
+
+    fn put<'a>(&'a self, key: &'a str, value: Vec<u8>) -> Self::PutFuture<'a> {
+        Box::pin(self.write(key, value))
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl StorageBackend for DirBackend {
+    async fn get(&self, key: &str) -> GetResult {
+        self.read(key).await
+    }
+
+    async fn put(&self, key: &str, value: Vec<u8>) -> Result<(), BackendError> {
+        self.write(key, value).await
+    }
+
+    async fn delete(&self, key: &str) -> Result<bool, BackendError> {
+        let path = self.path_for(key)?;
+        match tokio::fs::remove_file(&path).await {
+            Ok(()) => Ok(true),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(false),
+            Err(source) => Err(io_error(key, source)),
+        }
+    }
+}
+
+/// คัดลอก key ระหว่าง backend ใดก็ได้ - คืน `false` ถ้าต้นทางไม่มี key นี้
+///
+/// # Errors
+///
+/// คืน `BackendError` จาก backend ต้นทางหรือปลายทาง
+pub async fn copy_key<A: StorageBackend, B: StorageBackend>(
+    from: &A,
+    to: &B,
+    key: &str,
+) -> Result<bool, BackendError> {
+    let Some(value) = from.get(key).await? else {
+        return Ok(false);
+    };
+    to.put(key, value).await?;
+    Ok(true)
+}
+
+/// อ่าน key จาก backend แบบ GAT หรือคืน `default` ถ้าไม่มี
+///
+/// # Errors
+///
+/// คืน `BackendError` จาก backend
+pub async fn get_or_default<B: GatStorageBackend>(
+    backend: &B,
+    key: &str,
+    default: &[u8],
+) -> Result<Vec<u8>, BackendError> {
+    Ok(backend.get(key).await?.unwrap_or_else(|| default.to_vec()))
+}
+
+/// ฟังก์ชันสอนเรื่อง GATs - associated type ที่ยืดหยุ่นตาม lifetime! 🧬
+pub fn learn_gats() {
+    println!("\n🧬 === Generic Associated Types: type ที่มี lifetime ของตัวเอง! === 🧬");
+
+    let mut prices = [100, 120, 90, 130];
+    let mut windows = windows_mut(&mut prices, 2);
+    while let Some(window) = windows.next() {
+        window[1] = window[1].max(window[0]);
+    }
+    println!("🪟 windows_mut (ราคาสูงสุดสะสม): {prices:?}");
+
+    let text = "บรรทัดแรก\nบรรทัดที่สองที่ยาวกว่า\nสั้น\n";
+    let mut lines = ReusedLines::new(text.as_bytes());
+    let mut count = 0;
+    while let Some(Ok(line)) = lines.next() {
+        count += 1;
+        println!("📜 บรรทัด {count}: {line}");
+    }
+    println!(
+        "♻️ อ่าน {count} บรรทัดด้วย buffer เดียว (ความจุ {} bytes)",
+        lines.buffer_capacity()
+    );
+
+    let mut repo = MemoryRepo::new();
+    let mut tx = repo.begin();
+    let alice = tx.insert("alice");
+    let bob = tx.insert("bob");
+    let _ = tx.commit();
+    let result = replace_all(&mut repo, [(alice, "alice ✨"), (99, "ghost")]);
+    println!(
+        "🗄️ replace_all กับ id ที่ไม่มี: {result:?} → alice ยังเป็น {:?} (rollback ทั้งก้อน)",
+        repo.get(alice)
+    );
+    let mut tx = repo.begin();
+    let _ = tx.delete(bob);
+    drop(tx);
+    println!(
+        "↩️ drop transaction โดยไม่ commit: bob ยังอยู่? {}",
+        repo.get(bob).is_some()
+    );
+
+    let backend = MemoryBackend::new();
+    let (before, after) = futures::executor::block_on(async {
+        let before = get_or_default(&backend, "theme", b"light").await;
+        let _ = StorageBackend::put(&backend, "theme", b"dark".to_vec()).await;
+        let after = get_or_default(&backend, "theme", b"light").await;
+        (before, after)
+    });
+    println!(
+        "⚡ async trait: ก่อน {:?} → หลัง {:?}",
+        before.map(String::from_utf8),
+        after.map(String::from_utf8)
+    );
+    println!("💡 MemoryBackend ใช้ future แบบ Ready (ไม่ alloc) ส่วน DirBackend ต้อง Box::pin");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_lending_iterators_hand_out_borrowed_items() {
+        let mut data = [1, 2, 3, 4];
+        let mut windows = windows_mut(&mut data, 3);
+        while let Some(window) = windows.next() {
+            window[2] += window[0] + window[1];
+        }
+        assert_eq!(data, [1, 2, 6, 12]);
+
+        let mut lines = ReusedLines::new(&b"short\r\na much longer line\nend"[..]);
+        let mut seen = Vec::new();
+        while let Some(line) = lines.next() {
+            seen.push(line.unwrap().to_string());
+        }
+        assert_eq!(seen, ["short", "a much longer line", "end"]);
+        assert!(lines.buffer_capacity() >= "a much longer line\n".len());
+    }
+
+    #[test]
+    fn test_transaction_commits_or_rolls_back() {
+        let mut repo = MemoryRepo::new();
+        let mut tx = repo.begin();
+        let first = tx.insert("first".to_string());
+        let second = tx.insert("second".to_string());
+        tx.update(first, "first v2".to_string()).unwrap();
+        assert_eq!(tx.get(first).map(String::as_str), Some("first v2"));
+        tx.commit().unwrap();
+        assert_eq!(repo.len(), 2);
+
+        let mut tx = repo.begin();
+        tx.delete(second).unwrap();
+        assert_eq!(tx.get(second), None);
+        assert_eq!(tx.delete(second), Err(RepoError::NotFound(second)));
+        drop(tx);
+        assert_eq!(repo.get(second).map(String::as_str), Some("second"));
+
+        let mut tx = repo.begin();
+        let third = tx.insert("third".to_string());
+        tx.commit().unwrap();
+        assert_eq!(third, 3, "ids are never reused after commit");
+    }
+
+    #[test]
+    fn test_replace_all_is_all_or_nothing() {
+        let mut repo = MemoryRepo::new();
+        let mut tx = repo.begin();
+        let (a, b) = (tx.insert(1), tx.insert(2));
+        tx.commit().unwrap();
+
+        assert_eq!(
+            replace_all(&mut repo, [(a, 10), (42, 0), (b, 20)]),
+            Err(RepoError::NotFound(42))
+        );
+        assert_eq!((repo.get(a), repo.get(b)), (Some(&1), Some(&2)));
+
+        replace_all(&mut repo, [(a, 10), (b, 20)]).unwrap();
+        assert_eq!((repo.get(a), repo.get(b)), (Some(&10), Some(&20)));
+    }
+
+    #[tokio::test]
+    async fn test_both_async_styles_agree_on_every_backend() {
+        let dir = tempfile::tempdir().unwrap();
+        let disk = DirBackend::new(dir.path());
+        let memory = MemoryBackend::new();
+
+        GatStorageBackend::put(&disk, "users/1", b"alice".to_vec())
+            .await
+            .unwrap();
+        assert!(copy_key(&disk, &memory, "users/1").await.unwrap());
+        assert!(!copy_key(&disk, &memory, "users/2").await.unwrap());
+        assert_eq!(
+            StorageBackend::get(&memory, "users/1").await.unwrap(),
+            Some(b"alice".to_vec())
+        );
+        assert_eq!(
+            get_or_default(&memory, "users/2", b"?").await.unwrap(),
+            b"?"
+        );
+
+        assert!(StorageBackend::delete(&disk, "users/1").await.unwrap());
+        assert_eq!(
+            GatStorageBackend::get(&disk, "users/1").await.unwrap(),
+            None
+        );
+
+        let error = StorageBackend::put(&disk, "../escape", Vec::new())
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            BackendError::InvalidKey {
+                reason: PathViolation::ParentDirectory,
+                ..
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_send_bound_lets_generic_code_spawn() {
+        async fn spawn_copy<A, B>(from: Arc<A>, to: Arc<B>, key: &'static str) -> bool
+        where
+            A: StorageBackend + 'static,
+            B: StorageBackend + 'static,
+        {
+            tokio::spawn(async move { copy_key(&*from, &*to, key).await.unwrap() })
+                .await
+                .unwrap()
+        }
+
+        let from = Arc::new(MemoryBackend::new());
+        StorageBackend::put(&*from, "k", vec![1, 2, 3])
+            .await
+            .unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let to = Arc::new(DirBackend::new(dir.path()));
+
+        assert!(spawn_copy(from, Arc::clone(&to), "k").await);
+        assert_eq!(
+            StorageBackend::get(&*to, "k").await.unwrap(),
+            Some(vec![1, 2, 3])
+        );
+    }
+}
@@ -8,16 +8,20 @@
 //! หรือเหมือนใบประกาศนียบัตรที่รับรองว่าคุณทำอะไรได้บ้าง! 📜🏆
 
 mod basic_traits;
+pub mod gats;
+pub mod operators;
 mod practice_traits;
 mod standard_traits;
 mod trait_bounds;
-mod trait_objects;
+pub mod trait_objects;
 
 pub use basic_traits::learn_basic_traits;
+pub use gats::learn_gats;
+pub use operators::learn_operator_overloading;
 pub use practice_traits::practice_traits;
 pub use standard_traits::learn_standard_traits;
 pub use trait_bounds::learn_trait_bounds;
-pub use trait_objects::learn_trait_objects;
+pub use trait_objects::{learn_advanced_trait_objects, learn_trait_objects};
 
 /// ฟังก์ชันสำหรับรันตัวอย่าง traits (เรียกจาก main.rs) - ทัวร์โรงละคร Traits! 🎭🎫
 /// เหมือนการดูโชว์ที่มีหลายรอบ แต่ละรอบมีเสน่ห์ต่างกัน! 🎪🌟
@@ -37,6 +41,15 @@ pub fn run_traits_examples() {
     println!("\n   🎭 Trait Objects (วัตถุ Traits: การแสดงแบบไดนามิก!)");
     println!("   🎪 เหมือนนักแสดงที่เปลี่ยนบทได้ตลอดเวลา หรือหุ่นยนต์ที่ทำงานได้หลายอย่าง! 🤖🎨");
     learn_trait_objects();
+    learn_advanced_trait_objects();
+
+    println!("\n   🧬 GATs (Generic Associated Types: type ที่ยืม self ได้!)");
+    println!("   🔑 เหมือนห้องสมุดที่ให้ยืมหนังสือทีละเล่ม ต้องคืนก่อนถึงยืมเล่มใหม่ได้! 📚🔁");
+    learn_gats();
+
+    println!("\n   ➕ Operator Overloading (เครื่องหมายคณิตศาสตร์กับ type ของเราเอง!)");
+    println!("   🧮 เหมือนสอนเครื่องคิดเลขให้รู้จักเวกเตอร์และเมทริกซ์! 📐🚀");
+    learn_operator_overloading();
 
     println!("\n   💪 แบบฝึกหัด Traits (ยิมฝึก Traits!)");
     println!("   🏋️‍♂️ เหมือนเข้าฟิตเนสเพื่อฝึกกล้ามเนื้อ หรือเรียนพิเศษเพื่อเก่งขึ้น! 🎯📈");
@@ -0,0 +1,255 @@
+//! Operator Overloading - ให้ `+ - * []` ทำงานกับ type ของเราเอง! ➕✖️
+//!
+//! operator ใน Rust คือ trait ใน `std::ops` ธรรมดา: `a + b` คือ `Add::add(a, b)`
+//! และ `m[(r, c)]` คือ `*Index::index(&m, (r, c))`
+//!
+//! บทนี้ไม่สร้าง type ใหม่ แต่ใช้ operator ที่ impl ไว้บน type จริงของ repo:
+//! [`Vec2`] ของ physics engine (`Copy` จึงใช้ `a + b` ได้เลย) และ [`Matrix`] ของบท ML
+//! (ไม่ใช่ `Copy` จึง impl บน reference `&a + &b`)
+//!
+//! 🛠️ **สิ่งที่จะได้เรียนรู้:**
+//! - ➕ `Add`/`Sub`/`Mul`/`Neg` บน type ที่ `Copy` กับบน reference เพื่อไม่ต้อง move หรือ clone
+//! - 🎯 `Index`/`IndexMut` ด้วย key แบบ `usize` และแบบ tuple `(row, col)`
+//! - 🪞 `Deref` ที่ผิดที่ (จำลอง inheritance)
+//! - 🖨️ `Display` ที่รองรับ `{:.2}`
+//!
+//! ```
+//! use rust_concepts::game_development::physics_engine::Vec2;
+//! use rust_concepts::machine_learning::Matrix;
+//!
+//! let east = Vec2::new(1.0, 0.0);
+//! let north = east.rotate(std::f32::consts::FRAC_PI_2);
+//! assert!((north[1] - 1.0).abs() < 1e-6);
+//! assert_eq!(format!("{:.1}", north + east), "(1.0, 1.0)");
+//!
+//! let swap = Matrix::new(vec![vec![0.0, 1.0], vec![1.0, 0.0]]);
+//! assert_eq!(&swap * &swap, Matrix::identity(2));
+//! ```
+//!
+//! ```compile_fail,E0382
+//! use rust_concepts::machine_learning::Matrix;
+//!
+//! let a = Matrix::identity(2);
+//! let b = Matrix::identity(2);
+//! let sum = a + b; // `Add` แบบ owned กิน `a` ไปแล้ว
+//! println!("{sum} {}", a[(0, 0)]); // ❌ ใช้ `a` หลัง move - ถ้ายังต้องใช้ ให้เขียน `&a + &b`
+//! ```
+//!
+//! ⚠️ **Deref ไม่ใช่ inheritance:** `Deref` มีไว้ให้ smart pointer/wrapper ที่ "เป็น" ของข้างในจริงๆ
+//! ถ้าใช้ต่อ struct เพื่อยืม method ของ field มาใช้ จะเจอความประหลาดแบบนี้
+//!
+//! ```
+//! use std::ops::Deref;
+//!
+//! #[derive(Debug, Clone, PartialEq)]
+//! struct User {
+//!     name: String,
+//! }
+//!
+//! struct Admin {
+//!     user: User,
+//!     level: u8,
+//! }
+//!
+//! impl Deref for Admin {
+//!     type Target = User;
+//!     fn deref(&self) -> &User {
+//!         &self.user
+//!     }
+//! }
+//!
+//! let admin = Admin { user: User { name: "พลอย".into() }, level: 9 };
+//! let copy = admin.clone(); // 😱 `Admin` ไม่มี `Clone` จึง auto-deref ไป clone `User` แทน
+//! assert_eq!(copy, User { name: "พลอย".into() }); // `level` หายไปเงียบๆ
+//! # assert_eq!(admin.level, 9);
+//! ```
+
+use crate::game_development::physics_engine::Vec2;
+use crate::machine_learning::Matrix;
+
+/// ความเร่งโน้มถ่วงของโลก (m/s²)
+pub const GRAVITY: f32 = 9.81;
+
+/// 🎯 ความเร็วต้นของวัตถุที่ยิงด้วย `speed` ทำมุม `angle` radians กับพื้น
+#[must_use]
+pub fn launch_velocity(speed: f32, angle: f32) -> Vec2 {
+    Vec2::new(speed, 0.0).rotate(angle)
+}
+
+/// 🚀 จำลองวิถีวัตถุภายใต้แรงโน้มถ่วงและแรงต้านอากาศ (semi-implicit Euler)
+/// จนตกถึงพื้นหรือครบ `max_steps` - คืนตำแหน่งหลังทุก step
+#[must_use]
+pub fn simulate_projectile(
+    mut position: Vec2,
+    mut velocity: Vec2,
+    drag: f32,
+    dt: f32,
+    max_steps: usize,
+) -> Vec<Vec2> {
+    let gravity = Vec2::new(0.0, -GRAVITY);
+    let mut trajectory = Vec::with_capacity(max_steps);
+    for _ in 0..max_steps {
+        let acceleration = gravity - drag * velocity;
+        velocity += acceleration * dt;
+        position += velocity * dt;
+        trajectory.push(position);
+        if position.y <= 0.0 {
+            break;
+        }
+    }
+    trajectory
+}
+
+/// matrix หมุน 2 มิติทวนเข็มนาฬิกา `angle` radians
+fn rotation(angle: f64) -> Matrix {
+    let (sin, cos) = angle.sin_cos();
+    Matrix::new(vec![vec![cos, -sin], vec![sin, cos]])
+}
+
+/// ฟังก์ชันสอน operator overloading - `+ - * []` กับ type ของเราเอง! ➕
+pub fn learn_operator_overloading() {
+    println!("\n➕ === Operator Overloading: ให้ + - * [] รู้จัก type ของเรา! === ➕");
+
+    let a = Vec2::new(1.0, 2.0);
+    let b = Vec2::new(0.5, -1.0);
+    println!("📐 a = {a}, b = {b}");
+    println!(
+        "➕ a + b = {}, a - b = {}, -a = {}, 2.0 * a = {}",
+        a + b,
+        a - b,
+        -a,
+        2.0 * a
+    );
+    println!("🎯 Index: a[0] = {}, a[1] = {}", a[0], a[1]);
+
+    let shear = Matrix::new(vec![vec![1.0, 1.0], vec![0.0, 1.0]]);
+    let quarter_turn = rotation(std::f64::consts::FRAC_PI_2);
+    println!("🧮 shear * turn =\n{:.1}", &shear * &quarter_turn);
+    println!("🧮 turn * shear =\n{:.1}", &quarter_turn * &shear);
+    println!("⚠️ ผลคูณ matrix สลับที่ไม่ได้!");
+
+    let mut grid = Matrix::zeros(2, 3);
+    grid[(1, 2)] = 7.0;
+    println!(
+        "🎯 IndexMut: grid[(1, 2)] = {}, transpose =\n{}",
+        grid[(1, 2)],
+        grid.transpose()
+    );
+
+    let velocity = launch_velocity(20.0, std::f32::consts::FRAC_PI_4);
+    for (label, drag) in [("สุญญากาศ", 0.0), ("มีแรงต้านอากาศ", 0.1)]
+    {
+        let trajectory = simulate_projectile(Vec2::ZERO, velocity, drag, 0.01, 10_000);
+        let peak = trajectory.iter().map(|p| p.y).fold(0.0, f32::max);
+        if let Some(landing) = trajectory.last() {
+            println!(
+                "🚀 {label}: ตกที่ x = {:.1} m หลัง {} steps (สูงสุด {peak:.1} m)",
+                landing.x,
+                trajectory.len()
+            );
+        }
+    }
+    println!(
+        "💡 ทฤษฎี (ไม่มีแรงต้าน): ระยะ = v²/g = {:.1} m",
+        20.0_f32.powi(2) / GRAVITY
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::FRAC_PI_4;
+
+    #[test]
+    fn test_vec2_operator_semantics() {
+        let a = Vec2::new(1.0, 2.0);
+        let b = Vec2::new(4.0, 5.0);
+
+        assert_eq!(a + b, Vec2::new(5.0, 7.0));
+        assert_eq!(a + b, b + a);
+        assert_eq!(a - b + b, a);
+        assert_eq!(-a, a * -1.0);
+        assert_eq!(2.0 * a, a * 2.0);
+        assert_eq!((a * 2.0) / 2.0, a);
+
+        let mut acc = Vec2::ZERO;
+        acc += a;
+        acc += a;
+        acc -= b;
+        assert_eq!(acc, Vec2::new(-2.0, -1.0));
+
+        acc[0] = 10.0;
+        assert_eq!((acc[0], acc[1]), (10.0, -1.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "index 2 out of bounds for Vec2")]
+    fn test_vec2_index_out_of_bounds_panics() {
+        let _ = Vec2::ONE[2];
+    }
+
+    #[test]
+    fn test_matrix_operator_semantics() {
+        let a = Matrix::new(vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+        let b = Matrix::new(vec![vec![0.0, 1.0], vec![1.0, 0.0]]);
+
+        assert_eq!(&a * &Matrix::identity(2), a);
+        assert_eq!(&a * &b, Matrix::new(vec![vec![2.0, 1.0], vec![4.0, 3.0]]));
+        assert_eq!(&b * &a, Matrix::new(vec![vec![3.0, 4.0], vec![1.0, 2.0]]));
+        assert_eq!(&(&a + &b) - &b, a);
+        assert_eq!(a.clone() + b.clone(), &a + &b);
+        assert_eq!(-&a, &a * -1.0);
+        assert_eq!((&a * &b).transpose(), &b.transpose() * &a.transpose());
+
+        let turned =
+            &rotation(std::f64::consts::FRAC_PI_2) * &Matrix::new(vec![vec![1.0], vec![0.0]]);
+        assert!(turned[(0, 0)].abs() < 1e-12);
+        assert!((turned[(1, 0)] - 1.0).abs() < 1e-12);
+
+        let mut m = Matrix::zeros(2, 3);
+        m[(1, 0)] = 5.0;
+        assert_eq!((m.rows(), m.cols()), (2, 3));
+        assert!((m.transpose()[(0, 1)] - 5.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    #[should_panic(expected = "Matrix index out of bounds: (2, 0) for 2x3 matrix")]
+    fn test_matrix_index_out_of_bounds_panics() {
+        let _ = Matrix::zeros(2, 3)[(2, 0)];
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot multiply 2x3 by 2x3 matrix")]
+    fn test_matrix_dimension_mismatch_panics() {
+        let _ = &Matrix::zeros(2, 3) * &Matrix::zeros(2, 3);
+    }
+
+    #[test]
+    fn test_display_honours_precision() {
+        let v = Vec2::new(1.0, -0.25);
+        assert_eq!(v.to_string(), "(1, -0.25)");
+        assert_eq!(format!("{v:.2}"), "(1.00, -0.25)");
+
+        let m = Matrix::new(vec![vec![1.0, 2.5], vec![0.0, -1.0]]);
+        assert_eq!(format!("{m:.1}"), "[1.0 2.5]\n[0.0 -1.0]");
+    }
+
+    #[test]
+    fn test_projectile_matches_physics() {
+        let dt = 0.001;
+        let velocity = launch_velocity(20.0, FRAC_PI_4);
+        assert!((velocity.magnitude() - 20.0).abs() < 1e-4);
+
+        let vacuum = simulate_projectile(Vec2::ZERO, velocity, 0.0, dt, 100_000);
+        let range = vacuum.last().unwrap().x;
+        let expected = 20.0_f32.powi(2) / GRAVITY;
+        assert!((range - expected).abs() / expected < 0.01, "range {range}");
+
+        // ไม่มีแรงต้าน: ความเร็วแนวนอนคงที่ → x เพิ่มเท่ากันทุก step
+        let step = vacuum[1].x - vacuum[0].x;
+        assert!(velocity.x.mul_add(-dt, step).abs() < 1e-5);
+
+        let with_drag = simulate_projectile(Vec2::ZERO, velocity, 0.2, dt, 100_000);
+        assert!(with_drag.last().unwrap().x < range);
+    }
+}
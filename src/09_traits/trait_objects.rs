@@ -1,3 +1,77 @@
+//! Trait Objects - `dyn Trait` ตั้งแต่ใช้งานจนถึงเปิดดู vtable ข้างใน! 🎭🔬
+//!
+//! ไม่ใช่ทุก trait จะกลายเป็น `dyn Trait` ได้ (dyn compatibility / object safety):
+//! method ที่ compiler สร้าง vtable ให้ไม่ได้ จะทำให้ทั้ง trait ใช้แบบ dynamic ไม่ได้เลย
+//!
+//! ```compile_fail,E0038
+//! trait Serializer {
+//!     // ❌ method generic: vtable ต้องมีหนึ่งช่องต่อหนึ่ง `T` ซึ่งนับไม่ถ้วน
+//!     fn write<T: std::fmt::Display>(&self, value: T) -> String;
+//! }
+//!
+//! fn pick(serializers: Vec<Box<dyn Serializer>>) {}
+//! ```
+//!
+//! ```compile_fail,E0038
+//! trait Shape {
+//!     // ❌ คืน `Self`: ผู้เรียกผ่าน `dyn Shape` ไม่รู้ขนาดของค่าที่จะได้กลับมา
+//!     fn duplicate(&self) -> Self;
+//! }
+//!
+//! fn show(shape: &dyn Shape) {}
+//! ```
+//!
+//! ```compile_fail,E0038
+//! trait Plugin {
+//!     // ❌ associated function ไม่มี `self` ให้หา vtable
+//!     fn create() -> Box<Self>;
+//!     fn run(&self);
+//! }
+//!
+//! fn load(plugin: Box<dyn Plugin>) {}
+//! ```
+//!
+//! ✅ ทางแก้: ตัด method ที่มีปัญหาออกจาก vtable ด้วย `where Self: Sized`
+//! (เรียกผ่าน type จริงได้ แต่เรียกผ่าน `dyn` ไม่ได้)
+//!
+//! ```
+//! trait Shape {
+//!     fn area(&self) -> f64;
+//!     fn duplicate(&self) -> Self
+//!     where
+//!         Self: Sized;
+//! }
+//!
+//! #[derive(Clone)]
+//! struct Square(f64);
+//!
+//! impl Shape for Square {
+//!     fn area(&self) -> f64 {
+//!         self.0 * self.0
+//!     }
+//!     fn duplicate(&self) -> Self {
+//!         self.clone()
+//!     }
+//! }
+//!
+//! let shapes: Vec<Box<dyn Shape>> = vec![Box::new(Square(2.0)), Box::new(Square(3.0).duplicate())];
+//! assert_eq!(shapes.iter().map(|shape| shape.area()).sum::<f64>(), 13.0);
+//! ```
+//!
+//! ```
+//! use rust_concepts::traits::trait_objects::{Cat, ManualDynSpeaker};
+//!
+//! let speaker = ManualDynSpeaker::new(Cat { owner: "ฝน".to_string(), volume: 5 });
+//! assert_eq!(speaker.name(), "แมวของฝน");
+//! assert_eq!(std::mem::size_of_val(&speaker), 2 * std::mem::size_of::<usize>());
+//! ```
+
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::mem;
+use std::ptr::NonNull;
+
 /// ฟังก์ชันสำหรับสอนเรื่อง Trait Objects
 /// มาเรียนรู้การใช้ Trait Objects กันเถอะ! เหมือนนักแสดงที่เปลี่ยนบทได้ตลอดเวลาแบบ shape-shifting performer! 🎭
 pub fn learn_trait_objects() {
@@ -141,3 +215,475 @@ pub fn learn_trait_objects() {
     println!("\n🎉 จบบทเรียน Trait Objects! ตอนนี้คุณเป็นผู้กำกับที่ควบคุมนักแสดงได้หลายประเภทแล้ว! 🎉");
     println!("🎬 ยินดีด้วย! คุณเป็นผู้กำกับมืออาชีพที่ทำงานกับนักแสดงหลากหลายได้แล้ว! 🎭🏆");
 }
+
+// ===== 🔬 ภาคขั้นสูง: เปิดฝากระโปรง `dyn Trait` =====
+
+/// 🔊 trait ตัวอย่างสำหรับวัด dispatch และประกอบ vtable เอง
+pub trait Speaker {
+    fn name(&self) -> String;
+    fn volume(&self) -> u32;
+}
+
+/// 🐶 สุนัขเสียงดังตาม `volume`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dog {
+    pub volume: u32,
+}
+
+/// 🐱 แมวที่มีเจ้าของ
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cat {
+    pub owner: String,
+    pub volume: u32,
+}
+
+impl Speaker for Dog {
+    fn name(&self) -> String {
+        "สุนัข".to_string()
+    }
+
+    fn volume(&self) -> u32 {
+        self.volume
+    }
+}
+
+impl Speaker for Cat {
+    fn name(&self) -> String {
+        format!("แมวของ{}", self.owner)
+    }
+
+    fn volume(&self) -> u32 {
+        self.volume
+    }
+}
+
+/// 📋 สิ่งที่ compiler ใส่ไว้ใน vtable ของ `dyn Speaker` - เขียนเองให้เห็นว่าข้างในมีอะไร
+///
+/// ของจริงมี drop, size, align แล้วตามด้วย method ตามลำดับที่ประกาศใน trait
+/// (ลำดับ/layout ของจริงไม่ได้ stable - ห้ามพึ่งพา)
+#[derive(Debug)]
+pub struct SpeakerVTable {
+    drop_box: unsafe fn(*mut ()),
+    size: usize,
+    align: usize,
+    name: unsafe fn(*const ()) -> String,
+    volume: unsafe fn(*const ()) -> u32,
+}
+
+/// โรงงาน vtable: หนึ่ง `T` ต่อหนึ่ง vtable แบบ `'static` (เหมือนที่ compiler monomorphize ให้)
+struct VTableFor<T>(PhantomData<T>);
+
+impl<T: Speaker> VTableFor<T> {
+    const VTABLE: SpeakerVTable = SpeakerVTable {
+        drop_box: Self::drop_box,
+        size: mem::size_of::<T>(),
+        align: mem::align_of::<T>(),
+        name: Self::name,
+        volume: Self::volume,
+    };
+
+    /// # Safety
+    ///
+    /// `data` ต้องมาจาก `Box::<T>::into_raw` และยังไม่ถูกคืน
+    unsafe fn drop_box(data: *mut ()) {
+        // SAFETY: ผู้เรียกรับประกันว่า `data` คือ `Box<T>` ที่ยังมีชีวิต
+        drop(unsafe { Box::from_raw(data.cast::<T>()) });
+    }
+
+    /// # Safety
+    ///
+    /// `data` ต้องชี้ไปที่ `T` ที่ยังมีชีวิต
+    unsafe fn name(data: *const ()) -> String {
+        // SAFETY: ผู้เรียกรับประกันว่า `data` ชี้ไปที่ `T`
+        unsafe { &*data.cast::<T>() }.name()
+    }
+
+    /// # Safety
+    ///
+    /// `data` ต้องชี้ไปที่ `T` ที่ยังมีชีวิต
+    unsafe fn volume(data: *const ()) -> u32 {
+        // SAFETY: ผู้เรียกรับประกันว่า `data` ชี้ไปที่ `T`
+        unsafe { &*data.cast::<T>() }.volume()
+    }
+}
+
+/// 🧰 `Box<dyn Speaker>` ที่ประกอบเองด้วยมือ: pointer ไปที่ข้อมูล + pointer ไปที่ vtable
+///
+/// ขนาดเท่ากับ fat pointer ของจริง (`2 * usize`) และ dispatch ผ่าน function pointer เหมือนกันทุกประการ
+#[derive(Debug)]
+pub struct ManualDynSpeaker {
+    data: NonNull<()>,
+    vtable: &'static SpeakerVTable,
+}
+
+impl ManualDynSpeaker {
+    /// ลบ type ของ `value` ทิ้ง เหลือแค่ pointer สองตัว
+    pub fn new<T: Speaker + 'static>(value: T) -> Self {
+        Self {
+            data: NonNull::from(Box::leak(Box::new(value))).cast(),
+            vtable: &VTableFor::<T>::VTABLE,
+        }
+    }
+
+    #[must_use]
+    pub fn name(&self) -> String {
+        // SAFETY: `data` คือ `Box<T>` ที่เราเป็นเจ้าของ และ `vtable` สร้างมาจาก `T` ตัวเดียวกัน
+        unsafe { (self.vtable.name)(self.data.as_ptr()) }
+    }
+
+    #[must_use]
+    pub fn volume(&self) -> u32 {
+        // SAFETY: เหมือน `name`
+        unsafe { (self.vtable.volume)(self.data.as_ptr()) }
+    }
+
+    /// `(size, align)` ของ type จริง - ตรงกับ `size_of_val`/`align_of_val` ของ `dyn` ของจริง
+    #[must_use]
+    pub const fn layout(&self) -> (usize, usize) {
+        (self.vtable.size, self.vtable.align)
+    }
+
+    /// vtable ที่ใช้อยู่ - ทุก object ที่เป็น type เดียวกันใช้ vtable ชุดเดียวกัน
+    #[must_use]
+    pub const fn vtable(&self) -> &'static SpeakerVTable {
+        self.vtable
+    }
+}
+
+impl Drop for ManualDynSpeaker {
+    fn drop(&mut self) {
+        // SAFETY: `data` มาจาก `Box::leak` ใน `new` และถูกคืนที่นี่ครั้งเดียว
+        unsafe { (self.vtable.drop_box)(self.data.as_ptr()) }
+    }
+}
+
+/// สุนัข `count` ตัวที่เสียงดังไม่เท่ากัน - ข้อมูลชุดเดียวกันสำหรับวัด dispatch ทั้งสามแบบ
+#[must_use]
+pub fn sample_dogs(count: u32) -> Vec<Dog> {
+    (0..count).map(|i| Dog { volume: i % 10 }).collect()
+}
+
+/// ⚡ static dispatch: compiler สร้างโค้ดเฉพาะ `S` และ inline `volume` ได้
+pub fn total_volume_static<S: Speaker>(speakers: &[S]) -> u64 {
+    speakers.iter().map(|s| u64::from(s.volume())).sum()
+}
+
+/// 🎭 dynamic dispatch: ทุกครั้งต้องอ่าน vtable แล้วกระโดดผ่าน function pointer
+#[must_use]
+pub fn total_volume_dyn(speakers: &[Box<dyn Speaker>]) -> u64 {
+    speakers.iter().map(|s| u64::from(s.volume())).sum()
+}
+
+/// 🧰 dynamic dispatch ผ่าน vtable ที่ประกอบเอง - ต้นทุนระดับเดียวกับ `total_volume_dyn`
+/// (compiler devirtualize vtable ของเราไม่ได้ จึงอาจช้ากว่าเล็กน้อย)
+#[must_use]
+pub fn total_volume_manual(speakers: &[ManualDynSpeaker]) -> u64 {
+    speakers.iter().map(|s| u64::from(s.volume())).sum()
+}
+
+/// 🐾 subtrait ที่มีทั้ง `Speaker` และ `Any` เป็น supertrait
+///
+/// ตั้งแต่ Rust 1.86 `&dyn Pet` upcast เป็น `&dyn Speaker` หรือ `&dyn Any` ได้ตรงๆ
+/// (ก่อนหน้านั้นต้องเขียน `fn as_any(&self) -> &dyn Any` ทุก impl)
+pub trait Pet: Speaker + Any {
+    fn favourite_toy(&self) -> &'static str;
+}
+
+impl Pet for Dog {
+    fn favourite_toy(&self) -> &'static str {
+        "ลูกบอล"
+    }
+}
+
+impl Pet for Cat {
+    fn favourite_toy(&self) -> &'static str {
+        "กล่องกระดาษ"
+    }
+}
+
+/// ⬆️ upcast `dyn Pet` → `dyn Speaker` (เปลี่ยน vtable เป็นของ supertrait)
+#[must_use]
+pub fn as_speaker(pet: &dyn Pet) -> &dyn Speaker {
+    pet
+}
+
+/// ⬆️ upcast เป็น `dyn Any` แล้ว downcast กลับเป็น `Dog` ทุกตัว
+///
+/// ⚠️ ต้อง deref `Box` ก่อน (`&**pet`) - `&Box<dyn Pet>` เองก็เป็น `Any` แต่ `TypeId` เป็นของ `Box`
+#[must_use]
+pub fn find_dogs(pets: &[Box<dyn Pet>]) -> Vec<&Dog> {
+    pets.iter()
+        .filter_map(|pet| {
+            let any: &dyn Any = &**pet;
+            any.downcast_ref::<Dog>()
+        })
+        .collect()
+}
+
+/// 💥 ข้อความจาก panic payload - `panic!("...")` ให้ `&str`, `panic!("{x}")` ให้ `String`
+#[must_use]
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|message| (*message).to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "non-string panic payload".to_string())
+}
+
+/// 📦 `Box<dyn Any>::downcast` คืนกล่องเดิมเมื่อ type ไม่ตรง จึงลองต่อเป็นทอดๆ ได้
+#[must_use]
+pub fn describe_any(value: Box<dyn Any>) -> String {
+    let value = match value.downcast::<i32>() {
+        Ok(number) => return format!("i32: {number}"),
+        Err(value) => value,
+    };
+    value.downcast::<String>().map_or_else(
+        |_| "ไม่รู้จัก type".to_string(),
+        |text| format!("String: {text}"),
+    )
+}
+
+/// 🗂️ type map: เก็บค่าได้หนึ่งค่าต่อหนึ่ง type (แบบ `http::Extensions`)
+#[derive(Debug, Default)]
+pub struct TypeMap {
+    values: HashMap<TypeId, Box<dyn Any>>,
+}
+
+impl TypeMap {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// ใส่ค่า คืนค่าเก่าของ type เดียวกัน (ถ้ามี)
+    pub fn insert<T: Any>(&mut self, value: T) -> Option<T> {
+        self.values
+            .insert(TypeId::of::<T>(), Box::new(value))
+            .and_then(|old| old.downcast().ok())
+            .map(|old| *old)
+    }
+
+    #[must_use]
+    pub fn get<T: Any>(&self) -> Option<&T> {
+        self.values.get(&TypeId::of::<T>())?.downcast_ref()
+    }
+
+    pub fn get_mut<T: Any>(&mut self) -> Option<&mut T> {
+        self.values.get_mut(&TypeId::of::<T>())?.downcast_mut()
+    }
+
+    pub fn remove<T: Any>(&mut self) -> Option<T> {
+        self.values
+            .remove(&TypeId::of::<T>())
+            .and_then(|value| value.downcast().ok())
+            .map(|value| *value)
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+/// ฟังก์ชันสอน trait objects ขั้นสูง - vtable, upcasting และ `Any`! 🔬
+pub fn learn_advanced_trait_objects() {
+    println!("\n🔬 === เปิดฝากระโปรง dyn Trait: vtable, upcasting และ Any! === 🔬");
+
+    println!(
+        "📏 &Dog = {} bytes แต่ &dyn Speaker = {} bytes (pointer ข้อมูล + pointer vtable)",
+        mem::size_of::<&Dog>(),
+        mem::size_of::<&dyn Speaker>()
+    );
+    println!(
+        "🧰 ManualDynSpeaker = {} bytes - ขนาดเท่า fat pointer ของจริง",
+        mem::size_of::<ManualDynSpeaker>()
+    );
+
+    let manual = [
+        ManualDynSpeaker::new(Dog { volume: 7 }),
+        ManualDynSpeaker::new(Cat {
+            owner: "มะลิ".to_string(),
+            volume: 3,
+        }),
+    ];
+    for speaker in &manual {
+        println!(
+            "🔊 {} ดัง {} (size/align จาก vtable: {:?})",
+            speaker.name(),
+            speaker.volume(),
+            speaker.layout()
+        );
+    }
+
+    let dogs = sample_dogs(1_000);
+    let boxed: Vec<Box<dyn Speaker>> = dogs
+        .iter()
+        .map(|dog| Box::new(dog.clone()) as Box<dyn Speaker>)
+        .collect();
+    println!(
+        "⚖️ เสียงรวม static = {}, dyn = {} - ผลเท่ากัน ต่างกันที่ความเร็ว (cargo bench --bench chapter_claims -- dispatch)",
+        total_volume_static(&dogs),
+        total_volume_dyn(&boxed)
+    );
+
+    let pets: Vec<Box<dyn Pet>> = vec![
+        Box::new(Dog { volume: 9 }),
+        Box::new(Cat {
+            owner: "ต้นกล้า".to_string(),
+            volume: 2,
+        }),
+        Box::new(Dog { volume: 4 }),
+    ];
+    for pet in &pets {
+        let speaker = as_speaker(pet.as_ref());
+        println!(
+            "⬆️ {} (ของเล่นโปรด: {}) upcast เป็น dyn Speaker แล้วยังดัง {}",
+            speaker.name(),
+            pet.favourite_toy(),
+            speaker.volume()
+        );
+    }
+    println!("⬇️ downcast กลับเป็น Dog ได้ {} ตัว", find_dogs(&pets).len());
+
+    // payload แบบเดียวกับที่ `catch_unwind` คืนมาจาก `panic!("ระเบิดตอนรันครั้งที่ {}", 3)`
+    let payload: Box<dyn Any + Send> = Box::new(format!("ระเบิดตอนรันครั้งที่ {}", 3));
+    println!("💥 panic payload: {}", panic_message(payload.as_ref()));
+    println!("📦 {}", describe_any(Box::new(42_i32)));
+    println!("📦 {}", describe_any(Box::new("สวัสดี".to_string())));
+
+    let mut extensions = TypeMap::new();
+    extensions.insert(42_u32);
+    extensions.insert("request-id-7".to_string());
+    println!(
+        "🗂️ TypeMap มี {} ค่า: u32 = {:?}, String = {:?}",
+        extensions.len(),
+        extensions.get::<u32>(),
+        extensions.get::<String>()
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::ptr;
+    use std::rc::Rc;
+
+    struct Counted(Rc<Cell<u32>>);
+
+    impl Speaker for Counted {
+        fn name(&self) -> String {
+            "counted".to_string()
+        }
+
+        fn volume(&self) -> u32 {
+            self.0.get()
+        }
+    }
+
+    impl Drop for Counted {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 100);
+        }
+    }
+
+    #[test]
+    fn test_manual_vtable_matches_compiler_vtable() {
+        let cat = Cat {
+            owner: "ฝน".to_string(),
+            volume: 5,
+        };
+        let real: Box<dyn Speaker> = Box::new(cat.clone());
+        let manual = ManualDynSpeaker::new(cat);
+
+        assert_eq!(manual.name(), real.name());
+        assert_eq!(manual.volume(), real.volume());
+        assert_eq!(
+            manual.layout(),
+            (mem::size_of_val(&*real), mem::align_of_val(&*real))
+        );
+        assert_eq!(
+            mem::size_of::<ManualDynSpeaker>(),
+            mem::size_of::<Box<dyn Speaker>>()
+        );
+
+        let (a, b) = (
+            ManualDynSpeaker::new(Dog { volume: 1 }),
+            ManualDynSpeaker::new(Dog { volume: 2 }),
+        );
+        assert!(ptr::eq(a.vtable(), b.vtable()));
+        assert!(!ptr::eq(a.vtable(), manual.vtable()));
+    }
+
+    #[test]
+    fn test_manual_dyn_drops_value_exactly_once() {
+        let drops = Rc::new(Cell::new(1));
+        let speaker = ManualDynSpeaker::new(Counted(Rc::clone(&drops)));
+        assert_eq!(speaker.volume(), 1);
+        drop(speaker);
+        assert_eq!(drops.get(), 101);
+    }
+
+    #[test]
+    fn test_all_dispatch_styles_agree() {
+        let dogs = sample_dogs(250);
+        let boxed: Vec<Box<dyn Speaker>> = dogs
+            .iter()
+            .map(|dog| Box::new(dog.clone()) as Box<dyn Speaker>)
+            .collect();
+        let manual: Vec<_> = dogs.iter().cloned().map(ManualDynSpeaker::new).collect();
+
+        let expected = total_volume_static(&dogs);
+        assert_eq!(expected, 25 * 45);
+        assert_eq!(total_volume_dyn(&boxed), expected);
+        assert_eq!(total_volume_manual(&manual), expected);
+    }
+
+    #[test]
+    fn test_upcast_and_downcast_pets() {
+        let pets: Vec<Box<dyn Pet>> = vec![
+            Box::new(Cat {
+                owner: "นก".to_string(),
+                volume: 1,
+            }),
+            Box::new(Dog { volume: 8 }),
+        ];
+
+        assert_eq!(as_speaker(pets[0].as_ref()).name(), "แมวของนก");
+        assert_eq!(find_dogs(&pets), [&Dog { volume: 8 }]);
+
+        // หลุมพราง: `&Box<dyn Pet>` ก็เป็น `dyn Any` ได้ แต่เป็น Any ของตัว `Box`
+        let wrong: &dyn Any = &pets[1];
+        assert!(wrong.downcast_ref::<Dog>().is_none());
+        assert!(wrong.is::<Box<dyn Pet>>());
+    }
+
+    #[test]
+    fn test_any_patterns() {
+        let literal = std::panic::catch_unwind(|| panic!("boom")).unwrap_err();
+        let formatted = std::panic::catch_unwind(|| panic!("boom {}", 2)).unwrap_err();
+        let custom = std::panic::catch_unwind(|| std::panic::panic_any(7_u8)).unwrap_err();
+        assert_eq!(panic_message(literal.as_ref()), "boom");
+        assert_eq!(panic_message(formatted.as_ref()), "boom 2");
+        assert_eq!(panic_message(custom.as_ref()), "non-string panic payload");
+
+        assert_eq!(describe_any(Box::new(-3_i32)), "i32: -3");
+        assert_eq!(describe_any(Box::new("hi".to_string())), "String: hi");
+        assert_eq!(describe_any(Box::new(1.5_f64)), "ไม่รู้จัก type");
+
+        let mut map = TypeMap::new();
+        assert_eq!(map.insert(1_u32), None);
+        assert_eq!(map.insert(2_u32), Some(1));
+        map.insert(Dog { volume: 3 });
+        *map.get_mut::<u32>().unwrap() += 10;
+        assert_eq!(map.get::<u32>(), Some(&12));
+        assert_eq!(map.get::<u64>(), None);
+        assert_eq!(map.remove::<Dog>(), Some(Dog { volume: 3 }));
+        assert_eq!(map.len(), 1);
+    }
+}
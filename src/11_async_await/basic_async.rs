@@ -8,9 +8,12 @@
 use std::time::Duration;
 use tokio::time::sleep;
 
+use crate::macros::timed;
+
 /// ตัวอย่างการใช้ async/await พื้นฐาน - โรงเรียนสอนการรอคอย! 🎓⏰
 /// เรียนรู้ศิลปะการทำงานแบบไม่ซิงค์ เหมือนเป็นนักแสดงที่เล่นหลายบทพร้อมกัน! 🎭
 #[allow(clippy::items_after_statements)]
+#[timed]
 pub async fn basic_async_example() {
     println!("\n🚀 === ตัวอย่าง Async/Await พื้นฐาน: โรงเรียนสอนการรอคอย! === 🚀");
 
@@ -0,0 +1,374 @@
+//! Future Combinators - สร้าง join, select, map และ timeout เองด้วยมือ! 🧩⚙️
+//!
+//! 🎯 **เป้าหมายการเรียนรู้:**
+//! `tokio::join!`, `tokio::select!` และ `tokio::time::timeout` ไม่ใช่เวทมนตร์
+//! มันคือ struct ธรรมดาที่ถือ future ลูกไว้ แล้ว `poll` ต่อให้ลูกอีกที 🔁
+//!
+//! 🛠️ **สิ่งที่จะได้เรียนรู้:**
+//! - 📌 Pin projection ด้วยมือ: จาก `Pin<&mut Self>` ไปเป็น `Pin<&mut Field>` อย่างปลอดภัย
+//! - 🤝 `Join2` - poll ทั้งสองตัวจนครบ จำผลของตัวที่เสร็จก่อนไว้ (ไม่ poll ซ้ำ)
+//! - 🏁 `Select2` - ตัวแรกที่เสร็จชนะ ตัวที่แพ้ถูก drop (= ถูกยกเลิก)
+//! - 🗺️ `Map` - แปลงผลลัพธ์เมื่อ future เสร็จ
+//! - ⏰ `Timeout` - `Select2` ระหว่างงานจริงกับ `Delay`
+//!
+//! 📌 **กฎของ pin projection** (สิ่งที่ crate `pin-project` ตรวจให้อัตโนมัติ):
+//! - field ที่ถูก "pin ตามโครงสร้าง" (future ลูก) ห้ามถูกย้ายออก และห้ามมี `Drop` ที่ย้ายมัน
+//! - field อื่น (closure, ผลลัพธ์) ไม่ถูก pin จึง `take()` ออกมาได้ตามปกติ
+
+use std::fmt;
+use std::future::Future;
+use std::mem;
+use std::pin::Pin;
+use std::task::{Context, Poll, ready};
+use std::time::Duration;
+
+use super::mini_executor::{Delay, block_on, yield_now};
+
+/// 🗺️ future ที่แปลงผลลัพธ์ของ `future` ด้วย `f`
+#[must_use = "futures do nothing unless polled"]
+pub struct Map<Fut, F> {
+    future: Fut,
+    f: Option<F>,
+}
+
+/// แปลงผลลัพธ์ของ future - แบบเดียวกับ `FutureExt::map` ของ crate `futures`
+pub const fn map<Fut, F, T>(future: Fut, f: F) -> Map<Fut, F>
+where
+    Fut: Future,
+    F: FnOnce(Fut::Output) -> T,
+{
+    Map { future, f: Some(f) }
+}
+
+impl<Fut, F, T> Future for Map<Fut, F>
+where
+    Fut: Future,
+    F: FnOnce(Fut::Output) -> T,
+{
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        // SAFETY: ไม่ย้ายอะไรออกจาก `this` - `future` ถูกใช้ผ่าน Pin เท่านั้น
+        // ส่วน `f` ไม่ถูก pin ตามโครงสร้าง จึง take() ได้
+        let this = unsafe { self.get_unchecked_mut() };
+        // SAFETY: `future` อยู่ในที่เดิมตลอดชีวิตของ Map (Map ไม่มี Drop และไม่เคยย้าย field นี้)
+        let future = unsafe { Pin::new_unchecked(&mut this.future) };
+        let output = ready!(future.poll(cx));
+        let f = this.f.take().expect("Map polled after completion");
+        Poll::Ready(f(output))
+    }
+}
+
+/// future ลูกของ `Join2` - ยังรันอยู่ / เสร็จแล้วเก็บผลไว้ / ผลถูกส่งออกไปแล้ว
+enum MaybeDone<F: Future> {
+    Running(F),
+    Done(F::Output),
+    Taken,
+}
+
+impl<F: Future> MaybeDone<F> {
+    /// poll ถ้ายังไม่เสร็จ - คืน `true` เมื่อมีผลลัพธ์รออยู่
+    fn poll_done(self: Pin<&mut Self>, cx: &mut Context<'_>) -> bool {
+        // SAFETY: ไม่ย้าย future ออก - ตอนเสร็จเขียนทับทั้ง enum ในที่เดิม (future ถูก drop ในที่
+        // แบบเดียวกับ `Pin::set`)
+        let this = unsafe { self.get_unchecked_mut() };
+        match this {
+            Self::Running(future) => {
+                // SAFETY: future อยู่ใน enum ที่ถูก pin และไม่เคยถูกย้าย
+                let future = unsafe { Pin::new_unchecked(future) };
+                if let Poll::Ready(output) = future.poll(cx) {
+                    *this = Self::Done(output);
+                    true
+                } else {
+                    false
+                }
+            }
+            Self::Done(_) => true,
+            Self::Taken => panic!("Join2 polled after completion"),
+        }
+    }
+
+    fn take_output(self: Pin<&mut Self>) -> F::Output {
+        // SAFETY: ย้ายออกเฉพาะตอนเป็น `Done` ซึ่งไม่มี future ที่ถูก pin อยู่ข้างใน
+        let this = unsafe { self.get_unchecked_mut() };
+        assert!(matches!(this, Self::Done(_)), "output is not ready");
+        match mem::replace(this, Self::Taken) {
+            Self::Done(output) => output,
+            Self::Running(_) | Self::Taken => unreachable!("checked above"),
+        }
+    }
+}
+
+/// 🤝 รอ future สองตัวพร้อมกัน แล้วคืนผลทั้งคู่
+#[must_use = "futures do nothing unless polled"]
+pub struct Join2<A: Future, B: Future> {
+    a: MaybeDone<A>,
+    b: MaybeDone<B>,
+}
+
+/// รอ `a` และ `b` พร้อมกันบน task เดียว - แบบเดียวกับ `tokio::join!(a, b)`
+pub const fn join2<A: Future, B: Future>(a: A, b: B) -> Join2<A, B> {
+    Join2 {
+        a: MaybeDone::Running(a),
+        b: MaybeDone::Running(b),
+    }
+}
+
+impl<A: Future, B: Future> Future for Join2<A, B> {
+    type Output = (A::Output, B::Output);
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // SAFETY: `a` และ `b` ถูก pin ตามโครงสร้าง - ใช้ผ่าน Pin เท่านั้น
+        let this = unsafe { self.get_unchecked_mut() };
+        // SAFETY: ทั้งสอง field ไม่เคยถูกย้ายออกจาก Join2
+        let (mut a, mut b) = unsafe {
+            (
+                Pin::new_unchecked(&mut this.a),
+                Pin::new_unchecked(&mut this.b),
+            )
+        };
+        // poll ทั้งคู่ทุกครั้ง (ไม่หยุดที่ตัวแรก) - ทั้งสองได้ลงทะเบียน waker
+        let a_done = a.as_mut().poll_done(cx);
+        let b_done = b.as_mut().poll_done(cx);
+        if a_done && b_done {
+            Poll::Ready((a.take_output(), b.take_output()))
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+/// ผลของ `Select2` - ฝั่งไหนเสร็จก่อน
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+/// 🏁 แข่งกันระหว่าง future สองตัว
+#[must_use = "futures do nothing unless polled"]
+pub struct Select2<A, B> {
+    a: A,
+    b: B,
+}
+
+/// คืนผลของตัวที่เสร็จก่อน ตัวที่แพ้ถูก drop พร้อม `Select2` - แบบเดียวกับ `tokio::select!`
+///
+/// ⚖️ poll `a` ก่อนเสมอ (เหมือน `select!` ที่ใส่ `biased;`) ถ้าเสร็จพร้อมกัน `a` ชนะ
+/// - `tokio::select!` ปกติสุ่มลำดับเพื่อไม่ให้ฝั่งหนึ่งชนะตลอด
+pub const fn select2<A: Future, B: Future>(a: A, b: B) -> Select2<A, B> {
+    Select2 { a, b }
+}
+
+impl<A: Future, B: Future> Future for Select2<A, B> {
+    type Output = Either<A::Output, B::Output>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // SAFETY: `a` และ `b` ถูก pin ตามโครงสร้าง และไม่เคยถูกย้ายออก
+        let this = unsafe { self.get_unchecked_mut() };
+        // SAFETY: เหตุผลเดียวกับด้านบน
+        if let Poll::Ready(output) = unsafe { Pin::new_unchecked(&mut this.a) }.poll(cx) {
+            return Poll::Ready(Either::Left(output));
+        }
+        // SAFETY: เหตุผลเดียวกับด้านบน
+        if let Poll::Ready(output) = unsafe { Pin::new_unchecked(&mut this.b) }.poll(cx) {
+            return Poll::Ready(Either::Right(output));
+        }
+        Poll::Pending
+    }
+}
+
+/// ❌ future ทำไม่เสร็จภายในเวลาที่กำหนด
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Elapsed;
+
+impl fmt::Display for Elapsed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("deadline has elapsed")
+    }
+}
+
+impl std::error::Error for Elapsed {}
+
+/// ⏰ future ที่มีเส้นตาย
+#[must_use = "futures do nothing unless polled"]
+pub struct Timeout<F> {
+    future: F,
+    delay: Delay,
+}
+
+/// ให้ `future` ทำงานไม่เกิน `duration` - แบบเดียวกับ `tokio::time::timeout`
+///
+/// ใช้ `Delay` ของ `mini_executor` ที่ปลุกจาก thread ของตัวเอง จึงรันได้ทุก executor
+/// (ของ tokio ต้องมี timer driver ของ tokio runtime)
+pub const fn timeout<F: Future>(duration: Duration, future: F) -> Timeout<F> {
+    Timeout {
+        future,
+        delay: Delay::new(duration),
+    }
+}
+
+impl<F: Future> Future for Timeout<F> {
+    type Output = Result<F::Output, Elapsed>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // SAFETY: `future` ถูก pin ตามโครงสร้าง ส่วน `delay` เป็น Unpin
+        let this = unsafe { self.get_unchecked_mut() };
+        // SAFETY: `future` ไม่เคยถูกย้ายออกจาก Timeout
+        let future = unsafe { Pin::new_unchecked(&mut this.future) };
+        // งานที่เสร็จพอดีเส้นตายถือว่าทัน
+        if let Poll::Ready(output) = future.poll(cx) {
+            return Poll::Ready(Ok(output));
+        }
+        Pin::new(&mut this.delay).poll(cx).map(|()| Err(Elapsed))
+    }
+}
+
+/// งานจำลองที่ต้องถูก poll `steps` รอบ (yield ระหว่างรอบ) แล้วคืน `value`
+async fn work<T>(steps: usize, value: T) -> T {
+    for _ in 0..steps {
+        yield_now().await;
+    }
+    value
+}
+
+/// ตัวอย่าง combinators ที่เขียนเอง รันบน mini executor 🧩⚙️
+pub fn combinators_example() {
+    println!("\n🧩⚙️ === Future Combinators: join, select, map, timeout ทำเอง! === ⚙️🧩");
+
+    let (left, right) = block_on(join2(work(3, "🐢 ช้า"), work(1, "🐇 เร็ว")));
+    println!("🤝 join2 ได้ทั้งคู่: ({left}, {right})");
+
+    let winner = block_on(select2(work(3, "🐢"), work(1, "🐇")));
+    println!("🏁 select2 ผู้ชนะ: {winner:?} (อีกตัวถูก drop ทิ้ง)");
+
+    let doubled = block_on(map(work(2, 21), |value| value * 2));
+    println!("🗺️ map(21 → ×2) = {doubled}");
+
+    let fast = block_on(timeout(Duration::from_millis(50), work(1, 200)));
+    let slow = block_on(timeout(
+        Duration::from_millis(10),
+        Delay::new(Duration::from_secs(1)),
+    ));
+    println!("⏰ timeout: {fast:?} | {slow:?}");
+    println!("💡 tokio::join!/select! ก็เป็น future แบบนี้ - จึงรันบน executor ของเราได้ด้วย!");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::async_await::mini_executor::MiniExecutor;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use std::time::Instant;
+
+    type Log = Rc<RefCell<Vec<String>>>;
+
+    /// งานที่จด log ทุกครั้งที่ถูก poll
+    #[allow(clippy::future_not_send)] // รันบน MiniExecutor ที่ poll บน thread เดียว
+    async fn logged(log: Log, name: &'static str, steps: usize) -> &'static str {
+        for step in 0..steps {
+            log.borrow_mut().push(format!("{name}{step}"));
+            yield_now().await;
+        }
+        log.borrow_mut().push(format!("{name} done"));
+        name
+    }
+
+    /// บันทึกเมื่อถูก drop - ใช้ดูว่าตัวที่แพ้ใน select ถูกยกเลิกจริง
+    struct DropFlag(Log, &'static str);
+
+    impl Drop for DropFlag {
+        fn drop(&mut self) {
+            self.0.borrow_mut().push(format!("{} dropped", self.1));
+        }
+    }
+
+    #[test]
+    fn test_join2_interleaves_and_keeps_first_result() {
+        let log = Log::default();
+        let mut executor = MiniExecutor::new();
+        let output = executor.block_on(join2(
+            logged(Rc::clone(&log), "a", 2),
+            map(logged(Rc::clone(&log), "b", 1), str::len),
+        ));
+        assert_eq!(output, ("a", 1));
+        // สลับกันทำงานบน task เดียว และ b ที่เสร็จแล้วไม่ถูก poll ซ้ำ
+        assert_eq!(*log.borrow(), ["a0", "b0", "a1", "b done", "a done"]);
+        // join2 ถูก poll 3 รอบ: ครั้งแรก + หลัง yield 2 ครั้ง
+        assert_eq!(executor.poll_count(), 3);
+    }
+
+    #[test]
+    fn test_select2_returns_first_and_drops_loser() {
+        let log = Log::default();
+        let slow = {
+            let flag = DropFlag(Rc::clone(&log), "slow");
+            let log = Rc::clone(&log);
+            async move {
+                let _flag = flag;
+                logged(log, "slow", 5).await
+            }
+        };
+        let winner = block_on(select2(slow, logged(Rc::clone(&log), "fast", 1)));
+        assert_eq!(winner, Either::Right("fast"));
+        assert_eq!(
+            *log.borrow(),
+            ["slow0", "fast0", "slow1", "fast done", "slow dropped"]
+        );
+
+        // เสร็จพร้อมกัน - ฝั่งซ้ายชนะเพราะถูก poll ก่อน
+        assert_eq!(block_on(select2(work(0, 1), work(0, 2))), Either::Left(1));
+    }
+
+    #[test]
+    fn test_timeout_passes_result_or_elapses() {
+        assert_eq!(
+            block_on(timeout(Duration::from_secs(5), work(3, "ok"))),
+            Ok("ok")
+        );
+
+        let start = Instant::now();
+        let never = std::future::pending::<()>();
+        assert_eq!(
+            block_on(timeout(Duration::from_millis(20), never)),
+            Err(Elapsed)
+        );
+        assert!(start.elapsed() >= Duration::from_millis(20));
+        assert_eq!(Elapsed.to_string(), "deadline has elapsed");
+    }
+
+    #[test]
+    fn test_tokio_join_and_select_run_on_mini_executor() {
+        // macro ของ tokio เป็นแค่ poll combinator - ไม่ต้องใช้ tokio runtime
+        let output = block_on(async { tokio::join!(work(2, 'a'), work(1, 'b')) });
+        assert_eq!(output, block_on(join2(work(2, 'a'), work(1, 'b'))));
+
+        let winner = block_on(async {
+            tokio::select! {
+                biased;
+                value = work(3, 'a') => Either::Left(value),
+                value = work(1, 'b') => Either::Right(value),
+            }
+        });
+        assert_eq!(winner, block_on(select2(work(3, 'a'), work(1, 'b'))));
+    }
+
+    #[tokio::test]
+    async fn test_matches_tokio_timeout() {
+        let ours = timeout(
+            Duration::from_millis(10),
+            tokio::time::sleep(Duration::from_secs(1)),
+        );
+        let theirs = tokio::time::timeout(
+            Duration::from_millis(10),
+            tokio::time::sleep(Duration::from_secs(1)),
+        );
+        assert_eq!(ours.await.is_err(), theirs.await.is_err());
+
+        let ours = timeout(Duration::from_secs(1), async { 7 }).await;
+        let theirs = tokio::time::timeout(Duration::from_secs(1), async { 7 }).await;
+        assert_eq!(ours, Ok(7));
+        assert_eq!(theirs.ok(), Some(7));
+    }
+}
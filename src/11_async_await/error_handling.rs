@@ -9,6 +9,8 @@
 //! - ✅ การใช้ Result กับ async functions - ระบบรายงานผลที่แม่นยำ!
 //! - 📡 Error propagation ใน async code - ส่งสัญญาณขอความช่วยเหลือ!
 //! - 🔄 การใช้ `try_join`! สำหรับ error handling - ทีมงานที่ช่วยเหลือกัน!
+//! - 🔁 Retry พร้อม backoff (fixed/exponential/jitter) - ล้มแล้วลุกใหม่อย่างมีจังหวะ!
+//! - 🔌 Circuit breaker - ตัดไฟก่อนระบบพังทั้งหลัง!
 //!
 //! ⚠️ **หมายเหตุสำคัญ (ข้อมูลเทคนิค):**
 //! - 📦 `use tokio::time::sleep;` และ `use std::time::Duration;` อาจแสดง warning "unused import"
@@ -16,8 +18,12 @@
 //! - ⚡ การลบ imports เหล่านี้จะทำให้โค้ดไม่สามารถ compile ได้
 //! - 🤖 Warnings เหล่านี้เกิดจากการที่ Rust analyzer ไม่สามารถตรวจสอบการใช้งานใน async context ได้อย่างสมบูรณ์
 
+use rand::Rng;
+use std::fmt;
+use std::future::Future;
+use std::sync::Mutex;
 use std::time::Duration;
-use tokio::time::sleep;
+use tokio::time::{Instant, sleep};
 
 /// ตัวอย่างการจัดการ error ใน async context - ทีมกู้ภัยในภารกิจ! 🚨🚀
 #[allow(clippy::items_after_statements)]
@@ -164,6 +170,321 @@ pub async fn timeout_example() {
     println!("🎯 นี่คือพลังของ Timeout! ไม่ให้ใครมาทำให้เราช้า! 🌈✨");
 }
 
+/// รูปแบบการรอก่อน retry ครั้งถัดไป ⏳
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BackoffPolicy {
+    /// รอเท่าเดิมทุกครั้ง
+    Fixed(Duration),
+    /// รอนานขึ้นเป็นทวีคูณ: `initial * factor^retry` แต่ไม่เกิน `max`
+    Exponential {
+        initial: Duration,
+        factor: u32,
+        max: Duration,
+    },
+    /// exponential แบบ "full jitter": สุ่มระหว่าง 0 ถึงค่า exponential
+    /// เพื่อไม่ให้ client จำนวนมาก retry พร้อมกันเป๊ะ (thundering herd)
+    Jittered {
+        initial: Duration,
+        factor: u32,
+        max: Duration,
+    },
+}
+
+impl BackoffPolicy {
+    /// เวลาที่ต้องรอก่อน retry ครั้งที่ `retry` (เริ่มนับจาก 0)
+    #[must_use]
+    pub fn delay_for(&self, retry: u32) -> Duration {
+        match *self {
+            Self::Fixed(delay) => delay,
+            Self::Exponential {
+                initial,
+                factor,
+                max,
+            } => exponential_delay(initial, factor, max, retry),
+            Self::Jittered {
+                initial,
+                factor,
+                max,
+            } => {
+                let ceiling = exponential_delay(initial, factor, max, retry);
+                let nanos = u64::try_from(ceiling.as_nanos()).unwrap_or(u64::MAX);
+                Duration::from_nanos(rand::thread_rng().gen_range(0..=nanos))
+            }
+        }
+    }
+}
+
+fn exponential_delay(initial: Duration, factor: u32, max: Duration, retry: u32) -> Duration {
+    factor
+        .checked_pow(retry)
+        .and_then(|multiplier| initial.checked_mul(multiplier))
+        .map_or(max, |delay| delay.min(max))
+}
+
+/// นโยบาย retry: ลองได้กี่ครั้ง และรอแบบไหน 🔁
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// จำนวนครั้งที่เรียกได้ทั้งหมด (รวมครั้งแรก)
+    pub max_attempts: u32,
+    pub backoff: BackoffPolicy,
+}
+
+impl RetryPolicy {
+    #[must_use]
+    pub const fn new(max_attempts: u32, backoff: BackoffPolicy) -> Self {
+        Self {
+            max_attempts,
+            backoff,
+        }
+    }
+}
+
+/// เรียก async operation ซ้ำจนสำเร็จหรือครบจำนวนครั้ง - ไม่ยอมแพ้ง่ายๆ! 💪🔁
+///
+/// # Errors
+///
+/// คืน error จากครั้งสุดท้ายถ้าทุกครั้งล้มเหลว
+pub async fn retry_with_backoff<F, Fut, T, E>(mut operation: F, policy: &RetryPolicy) -> Result<T, E>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+{
+    let mut retry = 0;
+    loop {
+        match operation().await {
+            Ok(value) => return Ok(value),
+            Err(error) if retry + 1 >= policy.max_attempts => return Err(error),
+            Err(_) => {
+                sleep(policy.backoff.delay_for(retry)).await;
+                retry += 1;
+            }
+        }
+    }
+}
+
+/// สถานะของ circuit breaker 🔌
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// ปกติ - ปล่อยทุก request ผ่าน และนับ failure ที่เกิดติดกัน
+    Closed { consecutive_failures: u32 },
+    /// ตัดวงจร - ปฏิเสธทันทีจนกว่าจะถึงเวลาลองใหม่
+    Open { until: Instant },
+    /// ปล่อย request ทดลอง (probe) ผ่านทีละตัว ถ้าสำเร็จจะกลับไป Closed
+    /// ระหว่างที่ probe ยังไม่จบ request อื่นถูกปฏิเสธเหมือนตอน Open
+    HalfOpen,
+}
+
+/// Error จาก circuit breaker ⚡
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CircuitBreakerError<E> {
+    /// วงจรเปิดอยู่ - ไม่ได้เรียก operation เลย
+    Open,
+    /// operation ถูกเรียกแล้วแต่ล้มเหลว
+    Inner(E),
+}
+
+impl<E: fmt::Display> fmt::Display for CircuitBreakerError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Open => write!(f, "circuit breaker is open"),
+            Self::Inner(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl<E: fmt::Debug + fmt::Display> std::error::Error for CircuitBreakerError<E> {}
+
+/// Circuit breaker - เบรกเกอร์ไฟฟ้าสำหรับ service ที่กำลังป่วย! 🔌⚡
+///
+/// `Closed` → (failure ติดกันครบ threshold) → `Open` → (ครบ `reset_timeout`) → `HalfOpen`
+/// → probe สำเร็จ: `Closed` / ล้มเหลว: `Open` อีกรอบ
+#[derive(Debug)]
+pub struct CircuitBreaker {
+    failure_threshold: u32,
+    reset_timeout: Duration,
+    state: Mutex<BreakerState>,
+}
+
+#[derive(Debug)]
+struct BreakerState {
+    circuit: CircuitState,
+    /// มี probe ของ half-open กำลังทำงานอยู่ - ถ้าปล่อยทุกคนผ่าน service ที่เพิ่งฟื้นจะโดนถล่มซ้ำ
+    probe_in_flight: bool,
+}
+
+impl BreakerState {
+    /// สถานะปัจจุบัน โดยเลื่อน Open ที่หมดเวลาแล้วเป็น `HalfOpen`
+    fn refresh(&mut self) -> CircuitState {
+        if let CircuitState::Open { until } = self.circuit
+            && Instant::now() >= until
+        {
+            self.circuit = CircuitState::HalfOpen;
+            self.probe_in_flight = false;
+        }
+        self.circuit
+    }
+}
+
+/// คืนสิทธิ์ probe เมื่อ probe จบ - รวมถึงตอนที่ future ของ `call` ถูก drop กลางทาง
+struct ProbeGuard<'a> {
+    breaker: &'a CircuitBreaker,
+}
+
+impl Drop for ProbeGuard<'_> {
+    fn drop(&mut self) {
+        self.breaker.lock_state().probe_in_flight = false;
+    }
+}
+
+impl CircuitBreaker {
+    #[must_use]
+    pub const fn new(failure_threshold: u32, reset_timeout: Duration) -> Self {
+        Self {
+            failure_threshold,
+            reset_timeout,
+            state: Mutex::new(BreakerState {
+                circuit: CircuitState::Closed {
+                    consecutive_failures: 0,
+                },
+                probe_in_flight: false,
+            }),
+        }
+    }
+
+    /// สถานะปัจจุบัน (Open ที่หมดเวลาแล้วจะแสดงเป็น `HalfOpen`)
+    #[must_use]
+    pub fn state(&self) -> CircuitState {
+        self.lock_state().refresh()
+    }
+
+    /// เรียก operation ผ่าน circuit breaker
+    ///
+    /// # Errors
+    ///
+    /// คืน `CircuitBreakerError::Open` ถ้าวงจรเปิดอยู่หรือมี probe ของ half-open ค้างอยู่
+    /// หรือ `CircuitBreakerError::Inner` ถ้า operation ล้มเหลว
+    pub async fn call<F, Fut, T, E>(&self, operation: F) -> Result<T, CircuitBreakerError<E>>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        let Some(probe) = self.admit() else {
+            return Err(CircuitBreakerError::Open);
+        };
+        let _probe = probe.then_some(ProbeGuard { breaker: self });
+
+        match operation().await {
+            Ok(value) => {
+                self.record_success(probe);
+                Ok(value)
+            }
+            Err(error) => {
+                self.record_failure();
+                Err(CircuitBreakerError::Inner(error))
+            }
+        }
+    }
+
+    /// `None` = ปฏิเสธ, `Some(true)` = caller นี้ได้เป็น probe ของ half-open
+    fn admit(&self) -> Option<bool> {
+        let mut state = self.lock_state();
+        let admission = match state.refresh() {
+            CircuitState::Closed { .. } => Some(false),
+            CircuitState::HalfOpen if !state.probe_in_flight => {
+                state.probe_in_flight = true;
+                Some(true)
+            }
+            CircuitState::HalfOpen | CircuitState::Open { .. } => None,
+        };
+        drop(state);
+        admission
+    }
+
+    /// ปิดวงจรเมื่อ probe ผ่าน หรือล้างตัวนับถ้ายัง Closed อยู่
+    ///
+    /// request ที่เข้ามาตอน Closed แต่จบช้าหลังวงจรเปิดไปแล้ว ต้องไม่ข้าม `reset_timeout`
+    /// หรือแซง probe ของ half-open
+    fn record_success(&self, probe: bool) {
+        let mut state = self.lock_state();
+        if probe || matches!(state.circuit, CircuitState::Closed { .. }) {
+            state.circuit = CircuitState::Closed {
+                consecutive_failures: 0,
+            };
+        }
+    }
+
+    fn record_failure(&self) {
+        let mut state = self.lock_state();
+        let failures = match state.circuit {
+            CircuitState::Closed {
+                consecutive_failures,
+            } => consecutive_failures + 1,
+            // ลองใน half-open แล้วยังพัง หรือมี request ค้างจากก่อนเปิดวงจร
+            CircuitState::HalfOpen | CircuitState::Open { .. } => self.failure_threshold,
+        };
+
+        state.circuit = if failures >= self.failure_threshold {
+            CircuitState::Open {
+                until: Instant::now() + self.reset_timeout,
+            }
+        } else {
+            CircuitState::Closed {
+                consecutive_failures: failures,
+            }
+        };
+    }
+
+    fn lock_state(&self) -> std::sync::MutexGuard<'_, BreakerState> {
+        self.state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+/// ตัวอย่าง retry และ circuit breaker - ทีมกู้ภัยที่รู้จักพักเหนื่อย! 🔁🔌
+pub async fn resilience_example() {
+    println!("\n🔁🔌 === ตัวอย่าง Retry/Backoff และ Circuit Breaker === 🔌🔁");
+
+    let policy = RetryPolicy::new(
+        5,
+        BackoffPolicy::Exponential {
+            initial: Duration::from_millis(10),
+            factor: 2,
+            max: Duration::from_millis(100),
+        },
+    );
+    let mut attempts = 0;
+    let result = retry_with_backoff(
+        || {
+            attempts += 1;
+            let attempt = attempts;
+            async move {
+                println!("  📡 ลองครั้งที่ {attempt}...");
+                if attempt < 3 {
+                    Err(format!("timeout ครั้งที่ {attempt}"))
+                } else {
+                    Ok("เชื่อมต่อสำเร็จ!")
+                }
+            }
+        },
+        &policy,
+    )
+    .await;
+    println!("  🎯 ผลลัพธ์: {result:?}");
+
+    let breaker = CircuitBreaker::new(2, Duration::from_millis(50));
+    for call in 1..=4 {
+        let outcome = breaker
+            .call(|| async { Err::<(), _>("service ล่ม") })
+            .await;
+        println!("  🔌 เรียกครั้งที่ {call}: {outcome:?} → {:?}", breaker.state());
+    }
+    sleep(Duration::from_millis(60)).await;
+    println!("  ⏰ ผ่านไป 60ms → {:?}", breaker.state());
+    let outcome = breaker.call(|| async { Ok::<_, &str>("service กลับมาแล้ว") }).await;
+    println!("  ✅ ลองใหม่: {outcome:?} → {:?}", breaker.state());
+}
+
 /// รันตัวอย่างทั้งหมดของ error handling - เปิดศูนย์กู้ภัยแห่งอนาคต! 🚑⚡
 pub async fn run_error_handling_examples() {
     println!("🚀🚑 === เปิดศูนย์กู้ภัยแห่งอนาคต! === 🚑🚀");
@@ -173,6 +494,7 @@ pub async fn run_error_handling_examples() {
     try_join_error_handling().await;
     error_propagation_example().await;
     timeout_example().await;
+    resilience_example().await;
 
     println!("\n🎉🏆 === ศูนย์กู้ภัยแห่งอนาคตปิดการฝึกอบรม! === 🏆🎉");
     println!("🌈 ขอบคุณที่เข้าร่วมการฝึกอบรม! ตอนนี้คุณพร้อมเป็นทีมกู้ภัยมืออาชีพแล้ว! 🚀✨");
@@ -260,4 +582,170 @@ mod tests {
         let result = tokio::time::timeout(Duration::from_millis(50), slow_task()).await;
         assert!(result.is_err()); // timeout error
     }
-}
\ No newline at end of file
+
+    #[tokio::test(start_paused = true)]
+    async fn test_retry_exponential_backoff_timing() {
+        let policy = RetryPolicy::new(
+            4,
+            BackoffPolicy::Exponential {
+                initial: Duration::from_millis(100),
+                factor: 2,
+                max: Duration::from_millis(300),
+            },
+        );
+        let mut calls = 0;
+        let start = Instant::now();
+        let result: Result<(), u32> = retry_with_backoff(
+            || {
+                calls += 1;
+                let call = calls;
+                async move { Err(call) }
+            },
+            &policy,
+        )
+        .await;
+
+        assert_eq!(result, Err(4));
+        // รอ 100 + 200 + 300 (ถูกจำกัดที่ max)
+        assert_eq!(start.elapsed(), Duration::from_millis(600));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_retry_stops_on_success() {
+        let policy = RetryPolicy::new(5, BackoffPolicy::Fixed(Duration::from_secs(1)));
+        let mut calls = 0;
+        let start = Instant::now();
+        let result = retry_with_backoff(
+            || {
+                calls += 1;
+                let call = calls;
+                async move { if call < 3 { Err("not yet") } else { Ok(call) } }
+            },
+            &policy,
+        )
+        .await;
+
+        assert_eq!(result, Ok(3));
+        assert_eq!(start.elapsed(), Duration::from_secs(2));
+    }
+
+    #[test]
+    async fn test_jittered_delay_within_bounds() {
+        let backoff = BackoffPolicy::Jittered {
+            initial: Duration::from_millis(10),
+            factor: 3,
+            max: Duration::from_millis(50),
+        };
+        for retry in 0..10 {
+            let ceiling = exponential_delay(
+                Duration::from_millis(10),
+                3,
+                Duration::from_millis(50),
+                retry,
+            );
+            assert!(backoff.delay_for(retry) <= ceiling);
+        }
+        assert_eq!(
+            exponential_delay(Duration::from_millis(10), 3, Duration::from_secs(1), 40),
+            Duration::from_secs(1)
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_circuit_breaker_transitions() {
+        let breaker = CircuitBreaker::new(2, Duration::from_secs(5));
+
+        for _ in 0..2 {
+            let result = breaker.call(|| async { Err::<(), _>("down") }).await;
+            assert_eq!(result, Err(CircuitBreakerError::Inner("down")));
+        }
+        assert!(matches!(breaker.state(), CircuitState::Open { .. }));
+
+        // ระหว่างเปิดวงจร operation จะไม่ถูกเรียกเลย
+        let mut called = false;
+        let result = breaker
+            .call(|| {
+                called = true;
+                async { Ok::<_, &str>(()) }
+            })
+            .await;
+        assert_eq!(result, Err(CircuitBreakerError::Open));
+        assert!(!called);
+
+        tokio::time::advance(Duration::from_secs(5)).await;
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+
+        // half-open แล้วพังอีก → กลับไป Open
+        let _ = breaker.call(|| async { Err::<(), _>("still down") }).await;
+        assert!(matches!(breaker.state(), CircuitState::Open { .. }));
+
+        tokio::time::advance(Duration::from_secs(5)).await;
+        let result = breaker.call(|| async { Ok::<_, &str>(42) }).await;
+        assert_eq!(result, Ok(42));
+        assert_eq!(
+            breaker.state(),
+            CircuitState::Closed {
+                consecutive_failures: 0
+            }
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_late_success_does_not_close_an_open_breaker() {
+        let breaker = CircuitBreaker::new(2, Duration::from_secs(5));
+
+        // request ช้าที่เข้ามาตอน Closed จบหลังจากวงจรเปิดไปแล้ว
+        let slow = breaker.call(|| async {
+            sleep(Duration::from_secs(1)).await;
+            Ok::<_, &str>("late")
+        });
+        let failures = async {
+            for _ in 0..2 {
+                let _ = breaker.call(|| async { Err::<(), _>("down") }).await;
+            }
+        };
+        let (late, ()) = tokio::join!(slow, failures);
+        assert_eq!(late, Ok("late"));
+        assert!(matches!(breaker.state(), CircuitState::Open { .. }));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_half_open_admits_a_single_probe() {
+        let breaker = CircuitBreaker::new(1, Duration::from_secs(5));
+        let _ = breaker.call(|| async { Err::<(), _>("down") }).await;
+        tokio::time::advance(Duration::from_secs(5)).await;
+
+        // probe ที่ยังไม่จบทำให้คนอื่นถูกปฏิเสธโดยไม่ได้เรียก operation
+        let slow_probe = breaker.call(|| async {
+            sleep(Duration::from_secs(1)).await;
+            Ok::<_, &str>("probe")
+        });
+        let mut called = false;
+        let (probe, rejected) = tokio::join!(slow_probe, async {
+            breaker
+                .call(|| {
+                    called = true;
+                    async { Ok::<_, &str>("second") }
+                })
+                .await
+        });
+        assert_eq!(probe, Ok("probe"));
+        assert_eq!(rejected, Err(CircuitBreakerError::Open));
+        assert!(!called);
+
+        // probe ที่ถูก drop กลางทางต้องคืนสิทธิ์ให้ probe ตัวถัดไป
+        let _ = breaker.call(|| async { Err::<(), _>("down") }).await;
+        tokio::time::advance(Duration::from_secs(5)).await;
+        let abandoned = tokio::time::timeout(
+            Duration::from_millis(10),
+            breaker.call(|| async {
+                sleep(Duration::from_secs(60)).await;
+                Ok::<_, &str>(())
+            }),
+        )
+        .await;
+        assert!(abandoned.is_err());
+        let result = breaker.call(|| async { Ok::<_, &str>(7) }).await;
+        assert_eq!(result, Ok(7));
+    }
+}
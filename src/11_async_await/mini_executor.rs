@@ -0,0 +1,455 @@
+//! Mini Executor - สร้าง async runtime เองตั้งแต่ศูนย์! 🏗️⚙️
+//!
+//! 🎯 **เป้าหมายการเรียนรู้:**
+//! tokio ดูเหมือนเวทมนตร์ แต่จริงๆ แล้วหัวใจของมันคือ loop ง่ายๆ:
+//! "poll future → ถ้ายังไม่เสร็จก็รอจนกว่าจะมีคนปลุก → poll ใหม่" 🔁
+//!
+//! 🛠️ **สิ่งที่จะได้เรียนรู้:**
+//! - 🔔 การสร้าง `Waker` ด้วยมือผ่าน `RawWaker` และ vtable
+//! - 📋 Task queue ที่เก็บเฉพาะ task ที่พร้อมจะถูก poll
+//! - 🧱 `block_on` - สะพานเชื่อมโลก sync กับโลก async
+//! - ⏰ Timer future ที่ถูกปลุกจาก background thread (เหมือน reactor ของ tokio)
+//! - 🎡 Timer wheel ในตัว executor (`timer_wheel::wheel_sleep`) - รอได้โดยไม่ต้องมี thread เพิ่ม
+
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::sync::{Arc, Condvar, Mutex};
+use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use super::timer_wheel::{self, DEFAULT_SLOTS, DEFAULT_TICK, TimerWheel};
+
+/// id พิเศษสำหรับ future หลักที่ส่งเข้า `block_on`
+const MAIN_TASK_ID: usize = usize::MAX;
+
+type LocalTask = Pin<Box<dyn Future<Output = ()>>>;
+
+/// คิวของ task id ที่พร้อมถูก poll - แชร์กับ `Waker` ได้ข้าม thread 📋
+#[derive(Default)]
+struct ReadyQueue {
+    ids: Mutex<VecDeque<usize>>,
+    available: Condvar,
+}
+
+impl ReadyQueue {
+    fn push(&self, id: usize) {
+        self.ids
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .push_back(id);
+        self.available.notify_one();
+    }
+
+    /// รอจนกว่าจะมี task ถูกปลุก (หรือจนถึง `deadline`) แล้วคืน id ของมัน
+    fn wait_pop(&self, deadline: Option<Instant>) -> Option<usize> {
+        let mut ids = self
+            .ids
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        loop {
+            if let Some(id) = ids.pop_front() {
+                return Some(id);
+            }
+            ids = match deadline {
+                None => self
+                    .available
+                    .wait(ids)
+                    .unwrap_or_else(std::sync::PoisonError::into_inner),
+                Some(deadline) => {
+                    let timeout = deadline.saturating_duration_since(Instant::now());
+                    if timeout.is_zero() {
+                        return None;
+                    }
+                    self.available
+                        .wait_timeout(ids, timeout)
+                        .unwrap_or_else(std::sync::PoisonError::into_inner)
+                        .0
+                }
+            };
+        }
+    }
+}
+
+/// ข้อมูลที่ `Waker` ถือไว้ - รู้แค่ว่าต้องปลุก task ไหนในคิวไหน 🔔
+struct WakeHandle {
+    task_id: usize,
+    queue: Arc<ReadyQueue>,
+}
+
+impl WakeHandle {
+    fn wake(&self) {
+        self.queue.push(self.task_id);
+    }
+}
+
+/// vtable ของ waker - ฟังก์ชัน 4 ตัวที่ `Waker` เรียกใช้แทนเรา
+static VTABLE: RawWakerVTable = RawWakerVTable::new(clone_raw, wake_raw, wake_by_ref_raw, drop_raw);
+
+unsafe fn clone_raw(data: *const ()) -> RawWaker {
+    // SAFETY: data มาจาก Arc::into_raw ใน `waker_for` และยังมีชีวิตอยู่
+    unsafe { Arc::increment_strong_count(data.cast::<WakeHandle>()) };
+    RawWaker::new(data, &VTABLE)
+}
+
+unsafe fn wake_raw(data: *const ()) {
+    // SAFETY: wake กิน waker ไป จึงรับ ownership ของ reference count นี้คืน
+    let handle = unsafe { Arc::from_raw(data.cast::<WakeHandle>()) };
+    handle.wake();
+}
+
+unsafe fn wake_by_ref_raw(data: *const ()) {
+    // SAFETY: ยืมดูเฉยๆ ไม่เปลี่ยน reference count
+    let handle = unsafe { &*data.cast::<WakeHandle>() };
+    handle.wake();
+}
+
+unsafe fn drop_raw(data: *const ()) {
+    // SAFETY: คืน reference count ที่ waker ตัวนี้ถืออยู่
+    drop(unsafe { Arc::from_raw(data.cast::<WakeHandle>()) });
+}
+
+/// สร้าง `Waker` จาก `RawWaker` ด้วยมือ - ไม่พึ่ง `std::task::Wake` เลย!
+fn waker_for(task_id: usize, queue: &Arc<ReadyQueue>) -> Waker {
+    let handle = Arc::new(WakeHandle {
+        task_id,
+        queue: Arc::clone(queue),
+    });
+    let raw = RawWaker::new(Arc::into_raw(handle).cast::<()>(), &VTABLE);
+    // SAFETY: ฟังก์ชันใน VTABLE จัดการ reference count ของ Arc<WakeHandle> อย่างถูกต้อง
+    // และ WakeHandle เป็น Send + Sync จึงส่งข้าม thread ได้
+    unsafe { Waker::from_raw(raw) }
+}
+
+/// Executor แบบ single-thread - tokio ฉบับจิ๋ว! 🏗️
+///
+/// task ไม่จำเป็นต้องเป็น `Send` เพราะถูก poll บน thread เดียวเสมอ
+/// มีแค่ `Waker` เท่านั้นที่ข้าม thread ได้ (เช่นจาก timer thread)
+pub struct MiniExecutor {
+    tasks: HashMap<usize, LocalTask>,
+    next_id: usize,
+    queue: Arc<ReadyQueue>,
+    timers: Rc<TimerWheel>,
+    polls: usize,
+}
+
+impl Default for MiniExecutor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MiniExecutor {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            tasks: HashMap::new(),
+            next_id: 0,
+            queue: Arc::new(ReadyQueue::default()),
+            timers: Rc::new(TimerWheel::new(Instant::now(), DEFAULT_TICK, DEFAULT_SLOTS)),
+            polls: 0,
+        }
+    }
+
+    /// เพิ่ม task ใหม่และใส่ลงคิวเพื่อให้ถูก poll ครั้งแรก
+    pub fn spawn(&mut self, future: impl Future<Output = ()> + 'static) {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.tasks.insert(id, Box::pin(future));
+        self.queue.push(id);
+    }
+
+    /// จำนวน task ที่ยังไม่เสร็จ
+    #[must_use]
+    pub fn pending_tasks(&self) -> usize {
+        self.tasks.len()
+    }
+
+    /// จำนวนครั้งที่ executor poll future ไปทั้งหมด
+    #[must_use]
+    pub const fn poll_count(&self) -> usize {
+        self.polls
+    }
+
+    /// วงล้อ timer ของ executor นี้ (ที่ `wheel_sleep` ลงทะเบียนไว้)
+    #[must_use]
+    pub fn timers(&self) -> &TimerWheel {
+        &self.timers
+    }
+
+    /// รอ task ตัวถัดไปที่พร้อม - ระหว่างรอก็หมุนวงล้อปลุก timer ที่ถึงกำหนด
+    fn next_ready(&self) -> usize {
+        loop {
+            self.timers.advance(Instant::now());
+            if let Some(id) = self.queue.wait_pop(self.timers.next_wakeup()) {
+                return id;
+            }
+        }
+    }
+
+    /// รันจนกว่า task ทั้งหมดจะเสร็จ
+    ///
+    /// ถ้ามี task ที่คืน `Pending` โดยไม่มีใครเก็บ waker ไว้ปลุก จะรอตลอดไป
+    /// (เหมือน runtime จริงที่ task ค้างเพราะลืม wake)
+    pub fn run(&mut self) {
+        let _context = timer_wheel::enter(&self.timers);
+        while !self.tasks.is_empty() {
+            let id = self.next_ready();
+            self.poll_task(id);
+        }
+    }
+
+    /// รัน future หลักจนเสร็จ พร้อมกับ poll task ที่ spawn ไว้ไปด้วย
+    pub fn block_on<F: Future>(&mut self, future: F) -> F::Output {
+        let mut future = std::pin::pin!(future);
+        let waker = waker_for(MAIN_TASK_ID, &self.queue);
+        self.queue.push(MAIN_TASK_ID);
+        let _context = timer_wheel::enter(&self.timers);
+
+        loop {
+            let id = self.next_ready();
+            if id == MAIN_TASK_ID {
+                self.polls += 1;
+                let mut cx = Context::from_waker(&waker);
+                if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                    return output;
+                }
+            } else {
+                self.poll_task(id);
+            }
+        }
+    }
+
+    fn poll_task(&mut self, id: usize) {
+        // task อาจถูกปลุกซ้ำหลังจากเสร็จไปแล้ว - ข้ามไปได้เลย
+        let Some(task) = self.tasks.get_mut(&id) else {
+            return;
+        };
+
+        self.polls += 1;
+        let waker = waker_for(id, &self.queue);
+        let mut cx = Context::from_waker(&waker);
+        if task.as_mut().poll(&mut cx).is_ready() {
+            self.tasks.remove(&id);
+        }
+    }
+}
+
+/// รัน future หนึ่งตัวจนเสร็จบน executor ใหม่ - `block_on` ฉบับพกพา 🧱
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    MiniExecutor::new().block_on(future)
+}
+
+/// สถานะที่แชร์ระหว่าง `Delay` และ timer thread
+#[derive(Default)]
+struct DelayState {
+    completed: bool,
+    waker: Option<Waker>,
+}
+
+/// Timer future ที่ถูกปลุกโดย background thread ⏰🧵
+///
+/// ต่างจาก `TimerFuture` ใน `custom_futures` ที่ปลุกตัวเองทันที (busy polling)
+/// ตัวนี้จะ `Pending` เงียบๆ จนกว่า thread ของ timer จะเรียก `wake()`
+pub struct Delay {
+    duration: Duration,
+    state: Option<Arc<Mutex<DelayState>>>,
+}
+
+impl Delay {
+    #[must_use]
+    pub const fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            state: None,
+        }
+    }
+}
+
+impl Future for Delay {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let duration = self.duration;
+        let state = self.state.get_or_insert_with(|| {
+            // poll ครั้งแรก: เริ่ม timer thread
+            let state = Arc::new(Mutex::new(DelayState::default()));
+            let timer_state = Arc::clone(&state);
+            thread::spawn(move || {
+                thread::sleep(duration);
+                let mut state = timer_state
+                    .lock()
+                    .unwrap_or_else(std::sync::PoisonError::into_inner);
+                state.completed = true;
+                if let Some(waker) = state.waker.take() {
+                    waker.wake();
+                }
+            });
+            state
+        });
+
+        let mut state = state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        if state.completed {
+            Poll::Ready(())
+        } else {
+            // เก็บ waker ล่าสุดไว้เสมอ เพราะ task อาจย้ายไป executor อื่นได้
+            state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+/// future ที่ยอมสละคิวหนึ่งรอบ ให้ task อื่นได้ทำงานบ้าง 🤝
+pub struct YieldNow {
+    yielded: bool,
+}
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.yielded {
+            Poll::Ready(())
+        } else {
+            self.yielded = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+/// สละคิวให้ task อื่นหนึ่งรอบ
+#[must_use]
+pub const fn yield_now() -> YieldNow {
+    YieldNow { yielded: false }
+}
+
+/// ตัวอย่างการใช้ mini executor - เปิดฝาดูเครื่องยนต์ของ tokio! 🔧🏎️
+pub fn mini_executor_example() {
+    println!("\n🏗️⚙️ === Mini Executor: สร้าง runtime เอง! === ⚙️🏗️");
+
+    let mut executor = MiniExecutor::new();
+    for worker in 1..=3u64 {
+        executor.spawn(async move {
+            for step in 1..=2 {
+                println!("  👷 Worker {worker} ทำขั้นตอนที่ {step}");
+                Delay::new(Duration::from_millis(10 * worker)).await;
+            }
+            println!("  ✅ Worker {worker} เสร็จงาน!");
+        });
+    }
+    executor.run();
+    println!(
+        "📊 poll ไปทั้งหมด {} ครั้ง (ไม่มี busy loop เพราะ timer thread เป็นคนปลุก!)",
+        executor.poll_count()
+    );
+
+    let answer = block_on(async {
+        Delay::new(Duration::from_millis(5)).await;
+        42
+    });
+    println!("🧱 block_on ได้คำตอบ: {answer}");
+
+    let mut executor = MiniExecutor::new();
+    for id in 0..1_000u64 {
+        executor.spawn(async move {
+            timer_wheel::wheel_sleep(Duration::from_millis(1 + id % 20)).await;
+        });
+    }
+    executor.run();
+    println!(
+        "🎡 timer 1,000 ตัวบน timer wheel: ไม่มี thread เพิ่มสักตัว poll ไป {} ครั้ง",
+        executor.poll_count()
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use std::time::Instant;
+
+    #[test]
+    fn test_block_on_returns_output() {
+        assert_eq!(block_on(async { 1 + 2 }), 3);
+    }
+
+    #[test]
+    fn test_interleaved_tasks_with_yield() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let mut executor = MiniExecutor::new();
+
+        for name in ["a", "b"] {
+            let log = Rc::clone(&log);
+            executor.spawn(async move {
+                for step in 0..3 {
+                    log.borrow_mut().push(format!("{name}{step}"));
+                    yield_now().await;
+                }
+            });
+        }
+
+        executor.run();
+        assert_eq!(executor.pending_tasks(), 0);
+        assert_eq!(*log.borrow(), ["a0", "b0", "a1", "b1", "a2", "b2"]);
+    }
+
+    #[test]
+    fn test_delays_complete_in_duration_order() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let mut executor = MiniExecutor::new();
+
+        for (name, millis) in [("slow", 60), ("fast", 10), ("medium", 30)] {
+            let log = Rc::clone(&log);
+            executor.spawn(async move {
+                Delay::new(Duration::from_millis(millis)).await;
+                log.borrow_mut().push(name);
+            });
+        }
+
+        let start = Instant::now();
+        executor.run();
+
+        assert_eq!(*log.borrow(), ["fast", "medium", "slow"]);
+        // รันพร้อมกัน ไม่ใช่ต่อกัน: ใช้เวลาประมาณ task ที่นานที่สุด
+        assert!(start.elapsed() < Duration::from_millis(100 + 60));
+    }
+
+    #[test]
+    fn test_delay_does_not_busy_poll() {
+        let mut executor = MiniExecutor::new();
+        executor.spawn(async {
+            Delay::new(Duration::from_millis(20)).await;
+        });
+        executor.run();
+        // poll ครั้งแรก (เริ่ม timer) + poll หลังถูกปลุก
+        assert_eq!(executor.poll_count(), 2);
+    }
+
+    #[test]
+    fn test_block_on_drives_spawned_tasks() {
+        let done = Rc::new(RefCell::new(false));
+        let mut executor = MiniExecutor::new();
+        {
+            let done = Rc::clone(&done);
+            executor.spawn(async move {
+                Delay::new(Duration::from_millis(5)).await;
+                *done.borrow_mut() = true;
+            });
+        }
+
+        let observed = executor.block_on({
+            let done = Rc::clone(&done);
+            async move {
+                Delay::new(Duration::from_millis(30)).await;
+                *done.borrow()
+            }
+        });
+        assert!(observed);
+    }
+}
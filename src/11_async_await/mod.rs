@@ -9,6 +9,7 @@
 //! - 🏎️ **Tokio Runtime**: เครื่องยนต์ที่เร็วกว่า Formula 1! (รถแข่งแห่งโค้ด!)
 //! - 🎬 **Concurrent Tasks**: ดิเรกเตอร์หนังแอ็คชั่นระดับฮอลลีวูด! (ผู้กำกับมืออาชีพ!)
 //! - 🚒 **Error Handling**: ทีมกู้ภัยที่ไม่เคยหลับ! (ฮีโร่กู้ภัย!)
+//! - 🌊 **Streams**: ข้อมูลที่ไหลมาทีละชิ้นพร้อม backpressure! (สายพานโรงงาน!)
 //! - 🧙‍♂️ **Advanced Patterns**: เทคนิคขั้นเทพที่แม้แต่ Gandalf ยังต้องอิจฉา! (เวทมนตร์ขั้นเทพ!)
 //!
 //! 🎯 **เป้าหมาย**: ทำให้คุณเป็นนินจา async ที่เก่งที่สุดในจักรวาล! 🥷✨
@@ -19,6 +20,11 @@ pub mod channels;           // ช่องทางสื่อสาร - โ
 pub mod custom_futures;     // Future ที่สร้างเอง - DIY แห่งอนาคต! 🔧 (ช่างฝีมือ!)
 pub mod error_handling;     // จัดการ error - ทีมกู้ภัย! 🚑 (หน่วยกู้ภัยมืออาชีพ!)
 pub mod advanced_async;     // เทคนิคขั้นสูง - โรงเรียนนินจา! 🥷 (มหาวิทยาลัยเทพ!)
+pub mod mini_executor;      // executor ทำเอง - เปิดฝาดูเครื่องยนต์! ⚙️ (โรงงานสร้าง runtime!)
+pub mod timer_wheel;        // วงล้อ timer ของ mini executor - นาฬิกาวงล้อ! 🎡 (หอนาฬิกา!)
+pub mod combinators;        // join/select/map/timeout ทำเอง - ต่อเลโก้ future! 🧩 (ช่างประกอบ!)
+pub mod streams;            // Stream และ backpressure - สายพานข้อมูล! 🌊 (โรงงานสายพาน!)
+pub mod task_group;         // structured concurrency - หัวหน้าทีมที่รอทุกคน! 👥 (ทีมงานมีวินัย!)
 
 // Re-export สำหรับการใช้งานง่าย - ประตูเดียวเข้าได้ทุกที่! 🚪✨
 pub use basic_async::*;
@@ -26,6 +32,11 @@ pub use channels::*;
 pub use custom_futures::*;
 pub use error_handling::*;
 pub use advanced_async::*;  // เพิ่ม advanced_async! 🧙‍♂️
+pub use mini_executor::*;
+pub use timer_wheel::{TimerWheel, WheelSleep, wheel_sleep};
+pub use combinators::*;
+pub use streams::*;
+pub use task_group::*;
 
 // ตัวอย่าง basic async/await ถูกย้ายไปที่ basic_async.rs แล้ว
 
@@ -46,9 +57,14 @@ pub async fn run_async_examples() {
     basic_async::spawn_tasks_example().await;                   // 👻 ผีช่วยงาน
     error_handling::async_error_handling().await;               // 🚑 ทีมกู้ภัย
     error_handling::timeout_example().await;                    // ⏰ นาฬิกาจับเวลา
+    error_handling::resilience_example().await;                 // 🔌 retry และ circuit breaker
     custom_futures::custom_future_example().await;              // 🔧 ช่างฝีมือ
     channels::channels_example().await;                         // 📞 ไปรษณีย์
     advanced_async::demonstrate_advanced_async().await;         // 🧙‍♂️ เวทมนตร์ขั้นเทพ!
+    mini_executor::mini_executor_example();                     // ⚙️ runtime ทำเอง
+    combinators::combinators_example();                         // 🧩 combinator ทำเอง
+    streams::streams_example().await;                           // 🌊 สายพานข้อมูล
+    task_group::task_group_example().await;                     // 👥 ทีมงานมีวินัย
 
     println!("\n🎉🏆 === จบบทที่ 11: Async/Await Programming! === 🏆🎉");
     println!("💡🌟 คุณได้เรียนรู้ (และกลายเป็นนินจา async แล้ว!):");
@@ -59,6 +75,11 @@ pub async fn run_async_examples() {
     println!("   🔧 การสร้าง custom Future (ช่างฝีมือระดับเทพ!)");
     println!("   📞 การใช้ async channels (ไปรษณีย์แห่งอนาคต!)");
     println!("   🧙‍♂️ เทคนิคขั้นสูง (เวทมนตร์ขั้นเทพ!)");
+    println!("   ⚙️ การสร้าง executor, Waker และ block_on เอง (ช่างเครื่อง runtime!)");
+    println!("   🎡 Timer wheel ที่ร้อย future ที่ถูก pin ด้วย intrusive list (หอนาฬิกา!)");
+    println!("   🧩 join/select/map/timeout ที่ทำ pin projection เอง (ช่างประกอบ future!)");
+    println!("   🌊 Stream ทำเอง, buffer_unordered และ backpressure (โรงงานสายพาน!)");
+    println!("   👥 Structured concurrency และ CancellationToken (หัวหน้าทีมที่ไม่ทิ้งใคร!)");
     println!("\n🥷✨ ยินดีด้วย! คุณเป็นนินจา async แล้ว! ✨🥷");
 }
 
@@ -0,0 +1,509 @@
+//! Async Streams - สายพานข้อมูลที่ไหลมาทีละชิ้น! 🌊📦
+//!
+//! 🎯 **เป้าหมายการเรียนรู้:**
+//! `Future` คืนค่าครั้งเดียว ส่วน `Stream` คือ "async iterator" ที่คืนค่าได้หลายครั้ง
+//! ผ่าน `poll_next` → `Ready(Some(item))` / `Ready(None)` (จบ) / `Pending` (ยังไม่มี) 🔁
+//!
+//! 🛠️ **สิ่งที่จะได้เรียนรู้:**
+//! - ⏱️ `Ticker` - stream ที่ปล่อยเลข tick ตามรอบเวลา (แบบ `tokio::time::interval`)
+//! - 📄 `Paginated` - ดึงข้อมูลจาก API ทีละหน้า แต่ส่งออกทีละรายการ
+//! - 🗺️ `MapItems` / 🚦 `BufferUnordered` - adapter ที่ห่อ stream อีกชั้น
+//! - 🧯 Backpressure - consumer ช้า → producer ต้องช้าตาม ไม่ใช่กองงานไว้ในหน่วยความจำ
+//!
+//! 💡 stream เป็นแบบ "pull": ไม่มีใคร `poll_next` ก็ไม่มีงานเกิดขึ้น
+//! นี่คือ backpressure แบบฟรีๆ ที่ได้มาจากการออกแบบ!
+
+use std::collections::VecDeque;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::task::{Context, Poll, ready};
+use std::time::Duration;
+
+use futures::Stream;
+use tokio::sync::mpsc;
+use tokio::time::{Instant, Sleep, sleep};
+
+/// ⏱️ stream ที่ปล่อยเลข tick (1, 2, 3, ...) ทุกๆ `period`
+///
+/// รอบถัดไปนับจากตอนที่ tick ถูกส่งออก (แบบ `MissedTickBehavior::Delay`)
+/// ถ้า consumer ช้า tick จะไม่กองรอไว้ - ticker ช้าตาม consumer เอง
+#[must_use = "streams do nothing unless polled"]
+pub struct Ticker {
+    period: Duration,
+    sleep: Pin<Box<Sleep>>,
+    emitted: u64,
+    limit: Option<u64>,
+}
+
+impl Ticker {
+    /// สร้าง ticker ที่ tick แรกเกิดหลังจากนี้ `period`
+    pub fn new(period: Duration) -> Self {
+        Self {
+            period,
+            sleep: Box::pin(sleep(period)),
+            emitted: 0,
+            limit: None,
+        }
+    }
+
+    /// หยุด stream หลังจากปล่อยครบ `limit` tick
+    pub const fn with_limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+impl Stream for Ticker {
+    type Item = u64;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<u64>> {
+        if self.limit.is_some_and(|limit| self.emitted >= limit) {
+            return Poll::Ready(None);
+        }
+        ready!(self.sleep.as_mut().poll(cx));
+        self.emitted += 1;
+        let next = Instant::now() + self.period;
+        self.sleep.as_mut().reset(next);
+        Poll::Ready(Some(self.emitted))
+    }
+}
+
+/// 📄 ผลลัพธ์หนึ่งหน้าจาก API
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Page {
+    pub items: Vec<u32>,
+    pub next_cursor: Option<u32>,
+}
+
+/// 🌐 API จำลองที่คืนรายการ id แบบแบ่งหน้า (cursor = id ตัวแรกของหน้าถัดไป)
+#[derive(Debug, Clone)]
+pub struct FakeApi {
+    total_items: u32,
+    page_size: u32,
+    latency: Duration,
+    requests: Arc<AtomicUsize>,
+}
+
+impl FakeApi {
+    /// # Panics
+    ///
+    /// เมื่อ `page_size` เป็น 0
+    #[must_use]
+    pub fn new(total_items: u32, page_size: u32) -> Self {
+        assert!(page_size > 0, "page_size must be positive");
+        Self {
+            total_items,
+            page_size,
+            latency: Duration::from_millis(20),
+            requests: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// เวลาที่แต่ละ request ใช้ (ค่าเริ่มต้น 20ms)
+    #[must_use]
+    pub const fn with_latency(mut self, latency: Duration) -> Self {
+        self.latency = latency;
+        self
+    }
+
+    /// จำนวน request ที่ถูกเรียกไปแล้ว (นับรวมทุก clone)
+    #[must_use]
+    pub fn requests(&self) -> usize {
+        self.requests.load(Ordering::SeqCst)
+    }
+
+    /// ดึงหนึ่งหน้าเริ่มจาก `cursor` (`None` = หน้าแรก)
+    pub async fn fetch_page(&self, cursor: Option<u32>) -> Page {
+        self.requests.fetch_add(1, Ordering::SeqCst);
+        sleep(self.latency).await;
+        let start = cursor.unwrap_or(0).min(self.total_items);
+        let end = start.saturating_add(self.page_size).min(self.total_items);
+        Page {
+            items: (start..end).collect(),
+            next_cursor: (end < self.total_items).then_some(end),
+        }
+    }
+
+    /// stream ของทุกรายการ - ดึงหน้าถัดไปเมื่อรายการในมือหมดเท่านั้น
+    pub fn items(&self) -> Paginated {
+        Paginated {
+            api: self.clone(),
+            buffer: VecDeque::new(),
+            next_cursor: None,
+            in_flight: None,
+            exhausted: false,
+        }
+    }
+}
+
+type PageFuture = Pin<Box<dyn Future<Output = Page> + Send>>;
+
+/// 📄 stream ที่ซ่อนการแบ่งหน้าไว้ข้างใน - ผู้ใช้เห็นแค่รายการทีละตัว
+///
+/// ทุก field เป็น `Unpin` (future ถูก `Box::pin` ไว้แล้ว) จึงไม่ต้องทำ pin projection
+#[must_use = "streams do nothing unless polled"]
+pub struct Paginated {
+    api: FakeApi,
+    buffer: VecDeque<u32>,
+    next_cursor: Option<u32>,
+    in_flight: Option<PageFuture>,
+    exhausted: bool,
+}
+
+impl Stream for Paginated {
+    type Item = u32;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<u32>> {
+        let this = self.get_mut();
+        loop {
+            if let Some(item) = this.buffer.pop_front() {
+                return Poll::Ready(Some(item));
+            }
+            if this.exhausted {
+                return Poll::Ready(None);
+            }
+            let request = this.in_flight.get_or_insert_with(|| {
+                let api = this.api.clone();
+                let cursor = this.next_cursor;
+                Box::pin(async move { api.fetch_page(cursor).await })
+            });
+            let page = ready!(request.as_mut().poll(cx));
+            this.in_flight = None;
+            this.buffer.extend(page.items);
+            this.next_cursor = page.next_cursor;
+            this.exhausted = page.next_cursor.is_none();
+        }
+    }
+}
+
+/// 🗺️ stream ที่แปลงทุกรายการด้วย `f`
+#[must_use = "streams do nothing unless polled"]
+pub struct MapItems<S, F> {
+    stream: S,
+    f: F,
+}
+
+impl<S, F, T> Stream for MapItems<S, F>
+where
+    S: Stream,
+    F: FnMut(S::Item) -> T,
+{
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        // SAFETY: `stream` ถูกใช้ผ่าน Pin เท่านั้น ส่วน `f` ไม่ถูก pin ตามโครงสร้าง
+        let this = unsafe { self.get_unchecked_mut() };
+        // SAFETY: `stream` ไม่เคยถูกย้ายออกจาก MapItems (ไม่มี Drop ที่แตะมัน)
+        let stream = unsafe { Pin::new_unchecked(&mut this.stream) };
+        let item = ready!(stream.poll_next(cx));
+        Poll::Ready(item.map(&mut this.f))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+/// 🚦 รัน future จาก stream พร้อมกันไม่เกิน `limit` ตัว แล้วคืนผลตามลำดับที่เสร็จ
+///
+/// จะดึง future ตัวใหม่จาก stream ต้นทางก็ต่อเมื่อมีช่องว่าง - นี่คือ backpressure
+/// ที่ทำให้ไม่ยิง request ทีเดียวเป็นพันตัว
+#[must_use = "streams do nothing unless polled"]
+pub struct BufferUnordered<S: Stream> {
+    stream: S,
+    in_flight: Vec<Pin<Box<S::Item>>>,
+    limit: usize,
+    stream_done: bool,
+}
+
+impl<S> Stream for BufferUnordered<S>
+where
+    S: Stream,
+    S::Item: Future,
+{
+    type Item = <S::Item as Future>::Output;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        // SAFETY: `stream` ถูกใช้ผ่าน Pin เท่านั้น - future ลูกอยู่ใน Box ของตัวเองจึงย้าย Vec ได้
+        let this = unsafe { self.get_unchecked_mut() };
+
+        // 1. เติมงานจนเต็มโควต้า
+        while !this.stream_done && this.in_flight.len() < this.limit {
+            // SAFETY: `stream` ไม่เคยถูกย้ายออกจาก BufferUnordered
+            let stream = unsafe { Pin::new_unchecked(&mut this.stream) };
+            match stream.poll_next(cx) {
+                Poll::Ready(Some(future)) => this.in_flight.push(Box::pin(future)),
+                Poll::Ready(None) => this.stream_done = true,
+                Poll::Pending => break,
+            }
+        }
+
+        // 2. poll ทุกงานที่กำลังรัน - ตัวไหนเสร็จก่อนส่งออกก่อน
+        // (poll ทุกตัวทุกครั้งแบบง่ายๆ ส่วน `FuturesUnordered` ของจริงจะ poll เฉพาะตัวที่ถูกปลุก)
+        for index in 0..this.in_flight.len() {
+            if let Poll::Ready(output) = this.in_flight[index].as_mut().poll(cx) {
+                drop(this.in_flight.swap_remove(index));
+                return Poll::Ready(Some(output));
+            }
+        }
+
+        if this.stream_done && this.in_flight.is_empty() {
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+/// ⏭️ future ที่รอรายการถัดไปของ stream
+#[must_use = "futures do nothing unless polled"]
+pub struct NextItem<'a, S: ?Sized> {
+    stream: &'a mut S,
+}
+
+impl<S: Stream + Unpin + ?Sized> Future for NextItem<'_, S> {
+    type Output = Option<S::Item>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut *self.stream).poll_next(cx)
+    }
+}
+
+/// 🧰 adapter ของบทนี้ - ตั้งชื่อไม่ให้ชนกับ `futures::StreamExt`
+pub trait StreamAdapters: Stream {
+    /// แปลงทุกรายการ - แบบเดียวกับ `StreamExt::map`
+    fn map_items<F, T>(self, f: F) -> MapItems<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> T,
+    {
+        MapItems { stream: self, f }
+    }
+
+    /// รัน future ที่ stream ปล่อยออกมาพร้อมกันไม่เกิน `limit` ตัว
+    /// - แบบเดียวกับ `StreamExt::buffer_unordered`
+    ///
+    /// # Panics
+    ///
+    /// เมื่อ `limit` เป็น 0 (stream จะไม่มีวันคืบหน้า)
+    fn buffer_unordered_limit(self, limit: usize) -> BufferUnordered<Self>
+    where
+        Self: Sized,
+        Self::Item: Future,
+    {
+        assert!(limit > 0, "buffer_unordered_limit needs a positive limit");
+        BufferUnordered {
+            stream: self,
+            in_flight: Vec::with_capacity(limit),
+            limit,
+            stream_done: false,
+        }
+    }
+
+    /// รอรายการถัดไป (`None` เมื่อ stream จบ)
+    fn next_item(&mut self) -> NextItem<'_, Self>
+    where
+        Self: Unpin,
+    {
+        NextItem { stream: self }
+    }
+}
+
+impl<S: Stream + ?Sized> StreamAdapters for S {}
+
+/// 🧯 ผลจากการรัน consumer ที่ช้ากว่า producer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BackpressureReport<T> {
+    pub items: Vec<T>,
+    /// จำนวนรายการที่ producer ผลิตล่วงหน้าไปมากที่สุด - ถูกจำกัดด้วยขนาดช่อง
+    pub max_ahead: usize,
+}
+
+/// ให้ producer task ดึง `stream` ส่งเข้าช่องขนาด `capacity` แล้ว consumer ประมวลผลรายการละ `work`
+///
+/// เมื่อช่องเต็ม `send().await` จะค้างไว้ producer จึงนำหน้าได้ไม่เกิน `capacity + 1` รายการ
+/// (ในช่อง `capacity` ตัว + ตัวที่ถือรอส่งอยู่อีกหนึ่ง) ไม่ว่า consumer จะช้าแค่ไหน
+///
+/// # Panics
+///
+/// เมื่อ `capacity` เป็น 0 หรือ producer task panic
+pub async fn consume_with_backpressure<S>(
+    stream: S,
+    capacity: usize,
+    work: Duration,
+) -> BackpressureReport<S::Item>
+where
+    S: Stream + Send + 'static,
+    S::Item: Send + 'static,
+{
+    let (tx, mut rx) = mpsc::channel(capacity);
+    let sent = Arc::new(AtomicUsize::new(0));
+    let producer = tokio::spawn({
+        let sent = Arc::clone(&sent);
+        async move {
+            let mut stream = Box::pin(stream);
+            while let Some(item) = stream.next_item().await {
+                sent.fetch_add(1, Ordering::SeqCst);
+                if tx.send(item).await.is_err() {
+                    break;
+                }
+            }
+        }
+    });
+
+    let mut report = BackpressureReport {
+        items: Vec::new(),
+        max_ahead: 0,
+    };
+    while let Some(item) = rx.recv().await {
+        report.items.push(item);
+        let ahead = sent.load(Ordering::SeqCst) - report.items.len();
+        report.max_ahead = report.max_ahead.max(ahead);
+        sleep(work).await;
+    }
+    producer.await.expect("producer task panicked");
+    report
+}
+
+/// จำลองการดาวน์โหลดข้อมูลของ user `id` (id ยิ่งมากยิ่งเร็ว เพื่อให้เห็นลำดับที่สลับกัน)
+async fn download(id: u32) -> (u32, Duration) {
+    let took = Duration::from_millis(u64::from(40 - (id % 4) * 10));
+    sleep(took).await;
+    (id, took)
+}
+
+/// ตัวอย่างการใช้งาน Stream - เปิดสายพานข้อมูล! 🌊
+pub async fn streams_example() {
+    println!("\n🌊📦 === Async Streams: สายพานข้อมูลพร้อม backpressure! === 📦🌊");
+
+    let mut ticker = Ticker::new(Duration::from_millis(10)).with_limit(3);
+    while let Some(tick) = ticker.next_item().await {
+        println!("⏱️ tick #{tick}");
+    }
+
+    let api = FakeApi::new(7, 3);
+    let mut users = api.items().map_items(|id| format!("user-{id}"));
+    let first = users.next_item().await;
+    println!(
+        "📄 ขอรายการแรก: {first:?} → API ถูกเรียกแค่ {} ครั้ง (ดึงทีละหน้าเมื่อจำเป็น)",
+        api.requests()
+    );
+
+    let start = Instant::now();
+    let mut downloads = api.items().map_items(download).buffer_unordered_limit(2);
+    while let Some((id, took)) = downloads.next_item().await {
+        println!("🚦 ดาวน์โหลด user {id} เสร็จ ({took:?}) - รันพร้อมกันไม่เกิน 2 งาน");
+    }
+    println!("⏳ ดาวน์โหลด 7 รายการใช้เวลา {:?}", start.elapsed());
+
+    let ticks = Ticker::new(Duration::from_millis(1)).with_limit(10);
+    let report = consume_with_backpressure(ticks, 2, Duration::from_millis(15)).await;
+    println!(
+        "🧯 producer เร็ว consumer ช้า: ได้ {} รายการ นำหน้ามากสุด {} รายการ (ช่องจุ 2)",
+        report.items.len(),
+        report.max_ahead
+    );
+    println!("💡 stream เป็นแบบ pull - ไม่มีใครขอ ก็ไม่มีใครผลิต!");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio_test::{assert_pending, assert_ready_eq, task};
+
+    #[tokio::test(start_paused = true)]
+    async fn test_ticker_ticks_only_when_period_elapses() {
+        let mut ticker = task::spawn(Ticker::new(Duration::from_millis(100)).with_limit(2));
+
+        assert_pending!(ticker.poll_next());
+        tokio::time::advance(Duration::from_millis(99)).await;
+        assert_pending!(ticker.poll_next());
+
+        tokio::time::advance(Duration::from_millis(1)).await;
+        assert!(ticker.is_woken());
+        assert_ready_eq!(ticker.poll_next(), Some(1));
+
+        assert_pending!(ticker.poll_next());
+        tokio::time::advance(Duration::from_millis(100)).await;
+        assert_ready_eq!(ticker.poll_next(), Some(2));
+        assert_ready_eq!(ticker.poll_next(), None);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_paginated_fetches_pages_lazily() {
+        let api = FakeApi::new(5, 2).with_latency(Duration::from_millis(50));
+        let mut items = api.items();
+
+        for expected in 0..3 {
+            assert_eq!(items.next_item().await, Some(expected));
+        }
+        assert_eq!(
+            api.requests(),
+            2,
+            "item 3 is on page 2 - page 3 is not fetched yet"
+        );
+
+        let mut rest = Vec::new();
+        while let Some(item) = items.next_item().await {
+            rest.push(item);
+        }
+        assert_eq!(rest, vec![3, 4]);
+        assert_eq!(api.requests(), 3);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_paginated_stream_polls_pending_during_request() {
+        let api = FakeApi::new(3, 3).with_latency(Duration::from_millis(30));
+        let mut items = task::spawn(api.items());
+
+        assert_pending!(items.poll_next());
+        assert_eq!(api.requests(), 1);
+        tokio::time::advance(Duration::from_millis(30)).await;
+        assert!(items.is_woken());
+        assert_ready_eq!(items.poll_next(), Some(0));
+        assert_ready_eq!(items.poll_next(), Some(1));
+        assert_ready_eq!(items.poll_next(), Some(2));
+        assert_ready_eq!(items.poll_next(), None);
+        assert_eq!(api.requests(), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_buffer_unordered_limits_concurrency() {
+        let active = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+        let jobs = futures::stream::iter([300_u64, 100, 150, 20]).map_items(|millis| {
+            let active = Arc::clone(&active);
+            let peak = Arc::clone(&peak);
+            async move {
+                let now = active.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now, Ordering::SeqCst);
+                sleep(Duration::from_millis(millis)).await;
+                active.fetch_sub(1, Ordering::SeqCst);
+                millis
+            }
+        });
+
+        let start = Instant::now();
+        let mut results = jobs.buffer_unordered_limit(2);
+        let mut finished = Vec::new();
+        while let Some(millis) = results.next_item().await {
+            finished.push(millis);
+        }
+
+        assert_eq!(finished, vec![100, 150, 20, 300]);
+        assert_eq!(peak.load(Ordering::SeqCst), 2);
+        assert_eq!(start.elapsed(), Duration::from_millis(300));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_slow_consumer_bounds_how_far_producer_runs_ahead() {
+        let ticks = Ticker::new(Duration::from_millis(1)).with_limit(20);
+        let report = consume_with_backpressure(ticks, 4, Duration::from_millis(50)).await;
+
+        assert_eq!(report.items, (1..=20).collect::<Vec<_>>());
+        assert!(report.max_ahead <= 5, "max_ahead = {}", report.max_ahead);
+        assert!(report.max_ahead >= 4, "channel never filled up");
+    }
+}
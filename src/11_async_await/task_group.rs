@@ -0,0 +1,437 @@
+//! Structured Concurrency - ทีมงาน async ที่ไม่ทิ้งใครไว้ข้างหลัง! 👥🧵
+//!
+//! 🎯 **เป้าหมายการเรียนรู้:**
+//! `tokio::spawn` ปล่อย task ออกไปแล้วก็ลืมได้ง่ายมาก 😱
+//! structured concurrency คือกฎง่ายๆ ว่า "task ลูกต้องจบก่อนพ่อแม่เสมอ"
+//!
+//! 🛠️ **สิ่งที่จะได้เรียนรู้:**
+//! - 🛑 `CancellationToken` - สัญญาณยกเลิกแบบร่วมมือ (cooperative) ที่ส่งต่อไปยัง token ลูกได้
+//! - 👥 `AsyncTaskGroup` - spawn หลาย task, รวมผลลัพธ์, และหยุดทุกคนเมื่อมี error แรก
+//! - 🌐 Fan-out/fan-in - ยิง request หลายตัวพร้อมกันแล้วรวมผลกลับมา
+
+use std::fmt;
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, Weak};
+use std::time::Duration;
+use tokio::sync::Notify;
+use tokio::task::JoinSet;
+use tokio::time::sleep;
+
+use crate::macros::timed;
+
+struct TokenInner {
+    cancelled: AtomicBool,
+    notify: Notify,
+    /// เก็บแค่ `Weak` - token ลูกที่ถูก drop ไปแล้วไม่ควรถูกพ่อแม่ถือค้างไว้
+    children: Mutex<Vec<Weak<Self>>>,
+}
+
+/// Token สำหรับยกเลิกงานแบบร่วมมือ - ธงแดงที่ทุกคนคอยดู! 🚩
+///
+/// clone แล้วได้ token ตัวเดียวกัน ส่วน `child_token` จะได้ token ลูก
+/// ที่ถูกยกเลิกตามพ่อแม่ แต่ยกเลิกลูกไม่กระทบพ่อแม่
+#[derive(Clone)]
+pub struct CancellationToken {
+    inner: Arc<TokenInner>,
+}
+
+impl Default for CancellationToken {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for CancellationToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CancellationToken")
+            .field("cancelled", &self.is_cancelled())
+            .finish()
+    }
+}
+
+impl CancellationToken {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(TokenInner {
+                cancelled: AtomicBool::new(false),
+                notify: Notify::new(),
+                children: Mutex::new(Vec::new()),
+            }),
+        }
+    }
+
+    /// สร้าง token ลูกที่จะถูกยกเลิกเมื่อ token นี้ถูกยกเลิก
+    #[must_use]
+    pub fn child_token(&self) -> Self {
+        let child = Self::new();
+        let mut children = self
+            .inner
+            .children
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        // เช็คหลังจากถือ lock เพื่อไม่ให้พลาดการ cancel ที่เกิดขึ้นพร้อมกัน
+        if self.is_cancelled() {
+            child.cancel();
+        } else {
+            // ล้างลูกที่ตายแล้วทุกครั้งที่เพิ่ม ไม่งั้น token อายุยาวที่แตกลูกบ่อยๆ จะโตไม่หยุด
+            children.retain(|weak| weak.strong_count() > 0);
+            children.push(Arc::downgrade(&child.inner));
+        }
+        child
+    }
+
+    /// ยกเลิก token นี้และ token ลูกทั้งหมด
+    pub fn cancel(&self) {
+        if self.inner.cancelled.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        self.inner.notify.notify_waiters();
+
+        let children = std::mem::take(
+            &mut *self
+                .inner
+                .children
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner),
+        );
+        for inner in children.iter().filter_map(Weak::upgrade) {
+            Self { inner }.cancel();
+        }
+    }
+
+    #[must_use]
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// รอจนกว่า token จะถูกยกเลิก
+    pub async fn cancelled(&self) {
+        loop {
+            let notified = self.inner.notify.notified();
+            let mut notified = std::pin::pin!(notified);
+            // ลงทะเบียนรอก่อนเช็ค flag เพื่อไม่ให้พลาด notify_waiters
+            notified.as_mut().enable();
+            if self.is_cancelled() {
+                return;
+            }
+            notified.await;
+        }
+    }
+
+    /// รัน future จนเสร็จ หรือคืน `None` ถ้าถูกยกเลิกก่อน
+    pub async fn run_until_cancelled<F: Future>(&self, future: F) -> Option<F::Output> {
+        tokio::select! {
+            biased;
+            () = self.cancelled() => None,
+            output = future => Some(output),
+        }
+    }
+}
+
+/// Error ของกลุ่ม task ❌
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TaskGroupError<E> {
+    /// มี task คืน error - เก็บ error แรกที่เกิดขึ้นไว้
+    Failed(E),
+    /// task panic หรือถูก abort
+    Panicked(String),
+    /// กลุ่มถูกยกเลิกจากภายนอกก่อนที่ทุก task จะเสร็จ
+    Cancelled,
+}
+
+impl<E: fmt::Display> fmt::Display for TaskGroupError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Failed(error) => write!(f, "task failed: {error}"),
+            Self::Panicked(message) => write!(f, "task panicked: {message}"),
+            Self::Cancelled => write!(f, "task group was cancelled"),
+        }
+    }
+}
+
+impl<E: fmt::Debug + fmt::Display> std::error::Error for TaskGroupError<E> {}
+
+/// กลุ่ม task แบบ structured concurrency - หัวหน้าทีมที่รอลูกทีมทุกคน! 👥
+///
+/// - ผลลัพธ์คืนมาตามลำดับที่ spawn (ไม่ใช่ลำดับที่เสร็จ)
+/// - error แรกจะยกเลิก task ที่เหลือทั้งหมดผ่าน `CancellationToken`
+/// - ถ้า group ถูก drop ก่อน `join_all` task ที่เหลือจะถูก abort
+pub struct AsyncTaskGroup<T, E> {
+    token: CancellationToken,
+    tasks: JoinSet<(usize, Option<Result<T, E>>)>,
+    spawned: usize,
+}
+
+impl<T, E> Default for AsyncTaskGroup<T, E>
+where
+    T: Send + 'static,
+    E: Send + 'static,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, E> AsyncTaskGroup<T, E>
+where
+    T: Send + 'static,
+    E: Send + 'static,
+{
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_token(&CancellationToken::new())
+    }
+
+    /// สร้างกลุ่มที่ถูกยกเลิกตาม token ภายนอก (กลุ่มใช้ token ลูก)
+    #[must_use]
+    pub fn with_token(parent: &CancellationToken) -> Self {
+        Self {
+            token: parent.child_token(),
+            tasks: JoinSet::new(),
+            spawned: 0,
+        }
+    }
+
+    /// token ของกลุ่ม - ใช้ยกเลิกทุก task จากภายนอกได้
+    #[must_use]
+    pub const fn token(&self) -> &CancellationToken {
+        &self.token
+    }
+
+    /// จำนวน task ที่ spawn ไปแล้ว
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.spawned
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.spawned == 0
+    }
+
+    /// spawn task ใหม่ - task ได้รับ token ไว้เช็คการยกเลิกเอง
+    /// และจะถูกหยุดอัตโนมัติที่จุด `.await` ถัดไปเมื่อกลุ่มถูกยกเลิก
+    pub fn spawn<F, Fut>(&mut self, task: F)
+    where
+        F: FnOnce(CancellationToken) -> Fut,
+        Fut: Future<Output = Result<T, E>> + Send + 'static,
+    {
+        let index = self.spawned;
+        self.spawned += 1;
+
+        let token = self.token.clone();
+        let future = task(token.clone());
+        self.tasks
+            .spawn(async move { (index, token.run_until_cancelled(future).await) });
+    }
+
+    /// รอทุก task แล้วรวมผลลัพธ์ตามลำดับที่ spawn
+    ///
+    /// # Errors
+    ///
+    /// คืน error แรกที่เกิดขึ้น (หลังจากยกเลิกและรอ task ที่เหลือจบแล้ว)
+    /// หรือ `Cancelled` ถ้ากลุ่มถูกยกเลิกจากภายนอก
+    pub async fn join_all(mut self) -> Result<Vec<T>, TaskGroupError<E>> {
+        let mut results: Vec<Option<T>> = (0..self.spawned).map(|_| None).collect();
+        let mut first_error = None;
+
+        while let Some(joined) = self.tasks.join_next().await {
+            match joined {
+                Ok((index, Some(Ok(value)))) => results[index] = Some(value),
+                Ok((_, Some(Err(error)))) => {
+                    if first_error.is_none() {
+                        first_error = Some(TaskGroupError::Failed(error));
+                        self.token.cancel();
+                    }
+                }
+                Ok((_, None)) => {}
+                Err(join_error) => {
+                    if first_error.is_none() {
+                        first_error = Some(TaskGroupError::Panicked(join_error.to_string()));
+                        self.token.cancel();
+                    }
+                }
+            }
+        }
+
+        if let Some(error) = first_error {
+            return Err(error);
+        }
+        results
+            .into_iter()
+            .collect::<Option<Vec<_>>>()
+            .ok_or(TaskGroupError::Cancelled)
+    }
+}
+
+/// จำลองการเรียก network - หน่วงเวลาแล้วคืนผลหรือ error 🌐
+async fn simulated_fetch(
+    token: CancellationToken,
+    host: &'static str,
+    latency_ms: u64,
+    fail: bool,
+) -> Result<String, String> {
+    sleep(Duration::from_millis(latency_ms)).await;
+    if token.is_cancelled() {
+        return Err(format!("{host}: cancelled"));
+    }
+    if fail {
+        Err(format!("{host}: connection refused"))
+    } else {
+        Ok(format!("{host}: 200 OK ({latency_ms}ms)"))
+    }
+}
+
+/// ตัวอย่าง fan-out/fan-in ด้วย `AsyncTaskGroup` 🌐👥
+#[timed]
+pub async fn task_group_example() {
+    println!("\n👥🧵 === Structured Concurrency: AsyncTaskGroup === 🧵👥");
+
+    println!("🌐 Fan-out ไปยัง 3 servers:");
+    let mut group = AsyncTaskGroup::new();
+    for (host, latency) in [("api-1", 30), ("api-2", 10), ("api-3", 20)] {
+        group.spawn(move |token| simulated_fetch(token, host, latency, false));
+    }
+    match group.join_all().await {
+        Ok(responses) => {
+            for response in responses {
+                println!("  ✅ {response}");
+            }
+        }
+        Err(error) => println!("  ❌ {error}"),
+    }
+
+    println!("\n💥 Fan-out ที่มี server ล่ม (error แรกจะยกเลิกคนอื่น):");
+    let mut group = AsyncTaskGroup::new();
+    group.spawn(|token| simulated_fetch(token, "slow-api", 500, false));
+    group.spawn(|token| simulated_fetch(token, "broken-api", 20, true));
+    match group.join_all().await {
+        Ok(responses) => println!("  ✅ ได้ {} responses", responses.len()),
+        Err(error) => println!("  ❌ {error} (slow-api ถูกยกเลิกไม่ต้องรอ 500ms!)"),
+    }
+
+    println!("\n🛑 ยกเลิกจากภายนอกด้วย CancellationToken:");
+    let shutdown = CancellationToken::new();
+    let mut group: AsyncTaskGroup<String, String> = AsyncTaskGroup::with_token(&shutdown);
+    group.spawn(|token| simulated_fetch(token, "long-poll", 1_000, false));
+    let canceller = shutdown.clone();
+    tokio::spawn(async move {
+        sleep(Duration::from_millis(20)).await;
+        canceller.cancel();
+    });
+    match group.join_all().await {
+        Ok(_) => println!("  ✅ เสร็จก่อนถูกยกเลิก"),
+        Err(error) => println!("  🛑 {error}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+
+    #[tokio::test]
+    async fn test_results_in_spawn_order() {
+        let mut group: AsyncTaskGroup<u64, String> = AsyncTaskGroup::new();
+        for delay in [30, 10, 20] {
+            group.spawn(move |_| async move {
+                sleep(Duration::from_millis(delay)).await;
+                Ok(delay)
+            });
+        }
+        assert_eq!(group.len(), 3);
+        assert_eq!(group.join_all().await, Ok(vec![30, 10, 20]));
+    }
+
+    #[tokio::test]
+    async fn test_first_error_cancels_remaining_tasks() {
+        let mut group = AsyncTaskGroup::new();
+        group.spawn(|token| simulated_fetch(token, "slow", 2_000, false));
+        group.spawn(|token| simulated_fetch(token, "broken", 10, true));
+
+        let start = Instant::now();
+        let result = group.join_all().await;
+
+        assert_eq!(
+            result,
+            Err(TaskGroupError::Failed(
+                "broken: connection refused".to_string()
+            ))
+        );
+        assert!(start.elapsed() < Duration::from_millis(500));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_external_cancellation_timing() {
+        let shutdown = CancellationToken::new();
+        let mut group: AsyncTaskGroup<(), String> = AsyncTaskGroup::with_token(&shutdown);
+        group.spawn(|_| async {
+            sleep(Duration::from_secs(60)).await;
+            Ok(())
+        });
+
+        let canceller = shutdown.clone();
+        tokio::spawn(async move {
+            sleep(Duration::from_millis(100)).await;
+            canceller.cancel();
+        });
+
+        let start = tokio::time::Instant::now();
+        assert_eq!(group.join_all().await, Err(TaskGroupError::Cancelled));
+        assert_eq!(start.elapsed(), Duration::from_millis(100));
+    }
+
+    #[tokio::test]
+    async fn test_panic_is_reported() {
+        let mut group: AsyncTaskGroup<(), String> = AsyncTaskGroup::new();
+        group.spawn(|_| async { panic!("boom") });
+        assert!(matches!(
+            group.join_all().await,
+            Err(TaskGroupError::Panicked(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_child_token_follows_parent_only() {
+        let parent = CancellationToken::new();
+        let child = parent.child_token();
+        let grandchild = child.child_token();
+
+        child.cancel();
+        assert!(!parent.is_cancelled());
+        assert!(grandchild.is_cancelled());
+
+        let other_child = parent.child_token();
+        parent.cancel();
+        other_child.cancelled().await;
+        assert!(other_child.is_cancelled());
+        assert!(parent.child_token().is_cancelled());
+    }
+
+    #[test]
+    fn test_dropped_child_tokens_are_pruned() {
+        let parent = CancellationToken::new();
+        let kept = parent.child_token();
+        for _ in 0..1_000 {
+            drop(parent.child_token());
+        }
+        let children = parent.inner.children.lock().unwrap().len();
+        // เหลือแค่ลูกที่ยังมีชีวิตกับตัวล่าสุดที่เพิ่งถูก drop
+        assert!(children <= 2, "children leaked: {children}");
+
+        parent.cancel();
+        assert!(kept.is_cancelled());
+    }
+
+    #[tokio::test]
+    async fn test_run_until_cancelled() {
+        let token = CancellationToken::new();
+        assert_eq!(token.run_until_cancelled(async { 7 }).await, Some(7));
+
+        token.cancel();
+        let never = std::future::pending::<()>();
+        assert_eq!(token.run_until_cancelled(never).await, None);
+    }
+}
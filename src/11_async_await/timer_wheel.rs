@@ -0,0 +1,431 @@
+//! Timer Wheel - นาฬิกาวงล้อของ `MiniExecutor`! 🎡⏰
+//!
+//! `Delay` ใน `mini_executor` เปิด thread ใหม่ทุกครั้งที่รอ - 10,000 timer = 10,000 threads 😱
+//! runtime จริงใช้ "timer wheel": วงล้อที่มี slot ละช่วงเวลา (tick) แต่ละ slot เป็น list ของ timer
+//! executor แค่หมุนวงล้อไปตามเวลา แล้วปลุก timer ที่ถึงกำหนดใน slot ที่ผ่านไป
+//!
+//! 🛠️ **สิ่งที่จะได้เรียนรู้:**
+//! - 🔗 slot เป็น `IntrusiveList` - node อยู่ใน `WheelSleep` เอง ลงทะเบียน timer จึงไม่ต้อง alloc
+//! - 📌 `WheelSleep` เป็น `!Unpin` ร้อยตัวเองเข้าวงล้อตอน poll ครั้งแรก และถอดตัวเองออกใน `Drop`
+//! - 🧵 executor รอคิวด้วย timeout = เวลาจนถึง timer ตัวถัดไป (ไม่มี thread เพิ่มเลย)
+//!
+//! ⚖️ timer อาจถูกปลุกช้าได้ไม่เกินหนึ่ง tick แต่ไม่มีทางถูกปลุกเร็วกว่ากำหนด
+
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll, Waker};
+use std::time::{Duration, Instant};
+
+use crate::advanced_topics::pinning::{IntrusiveList, ListNode};
+
+/// ความละเอียดของวงล้อใน `MiniExecutor`
+pub const DEFAULT_TICK: Duration = Duration::from_millis(1);
+/// จำนวน slot ใน `MiniExecutor` - timer ที่ไกลกว่า 64 tick จะวนรอบวงล้อ
+pub const DEFAULT_SLOTS: usize = 64;
+
+/// ข้อมูลของ timer หนึ่งตัวที่ฝังอยู่ใน node ของวงล้อ
+pub struct TimerEntry {
+    deadline: Instant,
+    waker: RefCell<Option<Waker>>,
+    fired: Cell<bool>,
+}
+
+impl TimerEntry {
+    #[must_use]
+    pub const fn new(deadline: Instant) -> Self {
+        Self {
+            deadline,
+            waker: RefCell::new(None),
+            fired: Cell::new(false),
+        }
+    }
+
+    #[must_use]
+    pub const fn deadline(&self) -> Instant {
+        self.deadline
+    }
+
+    /// วงล้อหมุนผ่านกำหนดของ timer นี้แล้วหรือยัง
+    #[must_use]
+    pub const fn has_fired(&self) -> bool {
+        self.fired.get()
+    }
+
+    /// เก็บ waker ที่จะถูกปลุกเมื่อถึงกำหนด (แทนตัวเก่าเสมอ)
+    pub fn set_waker(&self, waker: &Waker) {
+        let mut slot = self.waker.borrow_mut();
+        match slot.as_ref() {
+            Some(current) if current.will_wake(waker) => {}
+            _ => *slot = Some(waker.clone()),
+        }
+    }
+}
+
+struct WheelState {
+    slots: Vec<IntrusiveList<TimerEntry>>,
+    /// tick แรกที่ยังไม่ได้ถูกหมุนผ่าน
+    next_tick: u64,
+}
+
+/// 🎡 hashed timer wheel แบบชั้นเดียว ใช้บน thread เดียว (ของ executor)
+pub struct TimerWheel {
+    origin: Instant,
+    tick: Duration,
+    state: RefCell<WheelState>,
+}
+
+impl fmt::Debug for TimerWheel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TimerWheel")
+            .field("tick", &self.tick)
+            .field("slots", &self.state.borrow().slots.len())
+            .field("pending", &self.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl TimerWheel {
+    /// # Panics
+    ///
+    /// เมื่อ `tick` เป็นศูนย์หรือ `slots` เป็น 0
+    #[must_use]
+    pub fn new(origin: Instant, tick: Duration, slots: usize) -> Self {
+        assert!(!tick.is_zero(), "tick must be positive");
+        assert!(slots > 0, "wheel needs at least one slot");
+        Self {
+            origin,
+            tick,
+            state: RefCell::new(WheelState {
+                slots: (0..slots).map(|_| IntrusiveList::new()).collect(),
+                next_tick: 0,
+            }),
+        }
+    }
+
+    /// จำนวน timer ที่ยังรออยู่ในวงล้อ
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.state
+            .borrow()
+            .slots
+            .iter()
+            .map(IntrusiveList::len)
+            .sum()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// tick ที่ `at` ตกอยู่ (ปัดลง)
+    fn tick_floor(&self, at: Instant) -> u64 {
+        let elapsed = at.saturating_duration_since(self.origin).as_nanos();
+        u64::try_from(elapsed / self.tick.as_nanos()).unwrap_or(u64::MAX)
+    }
+
+    /// tick แรกที่ไม่เร็วกว่า `at` (ปัดขึ้น) - timer จะไม่ถูกปลุกก่อนกำหนด
+    fn tick_ceil(&self, at: Instant) -> u64 {
+        let elapsed = at.saturating_duration_since(self.origin).as_nanos();
+        u64::try_from(elapsed.div_ceil(self.tick.as_nanos())).unwrap_or(u64::MAX)
+    }
+
+    fn slot_of(slots: usize, tick: u64) -> usize {
+        // ผลของ % slots น้อยกว่า slots เสมอ จึงแปลงกลับเป็น usize ได้
+        usize::try_from(tick % slots as u64).unwrap_or_default()
+    }
+
+    /// ลงทะเบียน timer - ถ้ากำหนดผ่านไปแล้วจะถูกปลุกใน `advance` ครั้งถัดไป
+    ///
+    /// # Safety
+    ///
+    /// `node` ต้องถูกถอดด้วย `cancel` ก่อนถูก drop (ถ้ายังไม่ fire)
+    /// และต้องไม่ถูกลงทะเบียนกับวงล้ออื่น
+    pub unsafe fn register(&self, node: Pin<&ListNode<TimerEntry>>) {
+        let mut state = self.state.borrow_mut();
+        let tick = self.tick_ceil(node.value().deadline).max(state.next_tick);
+        let slot = Self::slot_of(state.slots.len(), tick);
+        // SAFETY: ผู้เรียกสัญญาว่าจะ cancel ก่อน node ถูก drop
+        unsafe { state.slots[slot].push_back(node) };
+    }
+
+    /// ถอด timer ออก (ไม่มีผลถ้ามัน fire ไปแล้ว)
+    ///
+    /// # Safety
+    ///
+    /// `node` ต้องเคยถูก `register` กับวงล้อนี้เท่านั้น
+    pub unsafe fn cancel(&self, node: Pin<&ListNode<TimerEntry>>) {
+        if !node.is_linked() {
+            return;
+        }
+        let mut state = self.state.borrow_mut();
+        // timer ที่ยังไม่ fire อยู่ใน slot เดิมที่ `register` คำนวณไว้เสมอ:
+        // ถ้าวงล้อหมุนผ่าน slot นั้นไปแล้ว timer จะต้อง fire ไปแล้ว
+        let tick = self.tick_ceil(node.value().deadline).max(state.next_tick);
+        let slot = Self::slot_of(state.slots.len(), tick);
+        // SAFETY: node อยู่ในวงล้อนี้ และจากเหตุผลข้างบนจึงอยู่ใน slot นี้
+        unsafe { state.slots[slot].remove(node) };
+    }
+
+    /// เวลาที่ควรหมุนวงล้อครั้งถัดไป (`None` = ไม่มี timer รออยู่)
+    #[must_use]
+    pub fn next_wakeup(&self) -> Option<Instant> {
+        let state = self.state.borrow();
+        let tick = state
+            .slots
+            .iter()
+            .flat_map(IntrusiveList::iter)
+            .map(|entry| self.tick_ceil(entry.deadline).max(state.next_tick))
+            .min()?;
+        let nanos = u128::from(tick) * self.tick.as_nanos();
+        let offset = Duration::from_nanos(u64::try_from(nanos).unwrap_or(u64::MAX));
+        Some(self.origin + offset)
+    }
+
+    /// หมุนวงล้อไปจนถึง `now` แล้วปลุกทุก timer ที่ถึงกำหนด - คืนจำนวนที่ปลุก
+    pub fn advance(&self, now: Instant) -> usize {
+        let mut wakers = Vec::new();
+        let mut fired = 0;
+        {
+            let mut state = self.state.borrow_mut();
+            let now_tick = self.tick_floor(now);
+            if now_tick < state.next_tick {
+                return 0;
+            }
+            let slots = state.slots.len();
+            // หมุนเกินหนึ่งรอบ = ทุก slot ถูกผ่านแล้ว ไม่ต้องวนซ้ำ
+            let passed =
+                usize::try_from(now_tick - state.next_tick + 1).map_or(slots, |n| n.min(slots));
+            for offset in 0..passed {
+                let index = Self::slot_of(slots, state.next_tick + offset as u64);
+                // timer ที่อยู่ slot เดียวกันแต่เป็นรอบถัดไปของวงล้อจะยังไม่ถึงกำหนด
+                fired += state.slots[index].remove_where(
+                    |entry| entry.deadline <= now,
+                    |entry| {
+                        entry.fired.set(true);
+                        wakers.extend(entry.waker.borrow_mut().take());
+                    },
+                );
+            }
+            state.next_tick = now_tick + 1;
+        }
+        // ปลุกหลังคืน borrow แล้ว - waker อาจทำอะไรก็ได้
+        for waker in wakers {
+            waker.wake();
+        }
+        fired
+    }
+}
+
+thread_local! {
+    /// วงล้อของ executor ที่กำลังรันบน thread นี้ (แบบเดียวกับ context ของ tokio)
+    static CURRENT_WHEEL: RefCell<Option<Rc<TimerWheel>>> = const { RefCell::new(None) };
+}
+
+/// ตั้งวงล้อปัจจุบันของ thread จนกว่า guard จะถูก drop
+pub(crate) struct EnterGuard {
+    previous: Option<Rc<TimerWheel>>,
+}
+
+pub(crate) fn enter(wheel: &Rc<TimerWheel>) -> EnterGuard {
+    let previous = CURRENT_WHEEL.with(|current| current.replace(Some(Rc::clone(wheel))));
+    EnterGuard { previous }
+}
+
+impl Drop for EnterGuard {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        CURRENT_WHEEL.with(|current| *current.borrow_mut() = previous);
+    }
+}
+
+/// ⏰ future ที่รอบนวงล้อของ `MiniExecutor` - ไม่มี thread ไม่มี alloc ตอนลงทะเบียน
+///
+/// field `node` ถูก pin ตามโครงสร้าง: วงล้อถือ pointer ไปที่มันตั้งแต่ poll ครั้งแรก
+/// `Drop` จึงต้องถอด node ออกก่อนหน่วยความจำจะหายไป
+#[must_use = "futures do nothing unless polled"]
+pub struct WheelSleep {
+    node: ListNode<TimerEntry>,
+    wheel: Option<Rc<TimerWheel>>,
+}
+
+impl WheelSleep {
+    pub fn new(duration: Duration) -> Self {
+        Self::until(Instant::now() + duration)
+    }
+
+    pub const fn until(deadline: Instant) -> Self {
+        Self {
+            node: ListNode::new(TimerEntry::new(deadline)),
+            wheel: None,
+        }
+    }
+
+    #[must_use]
+    pub const fn deadline(&self) -> Instant {
+        self.node.value().deadline
+    }
+}
+
+/// รอ `duration` บนวงล้อของ executor - ต้อง `.await` ภายใน `MiniExecutor`
+pub fn wheel_sleep(duration: Duration) -> WheelSleep {
+    WheelSleep::new(duration)
+}
+
+impl Future for WheelSleep {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        // SAFETY: ไม่ย้าย `node` ออก - ใช้ผ่าน `Pin<&ListNode>` เท่านั้น ส่วน `wheel` ไม่ถูก pin
+        let this = unsafe { self.get_unchecked_mut() };
+        // SAFETY: `node` อยู่ใน `WheelSleep` ที่ถูก pin และไม่เคยถูกย้าย
+        let node = unsafe { Pin::new_unchecked(&this.node) };
+        let entry = node.get_ref().value();
+
+        if entry.has_fired() {
+            return Poll::Ready(());
+        }
+        if this.wheel.is_none() {
+            if Instant::now() >= entry.deadline {
+                return Poll::Ready(());
+            }
+            let wheel = CURRENT_WHEEL
+                .with(|current| current.borrow().clone())
+                .expect("WheelSleep must be polled inside MiniExecutor");
+            entry.set_waker(cx.waker());
+            // SAFETY: `Drop` ของ `WheelSleep` cancel node เสมอ และ Pin รับประกันว่า
+            // หน่วยความจำของ node จะไม่ถูกใช้ใหม่ก่อน `Drop` รัน
+            unsafe { wheel.register(node) };
+            this.wheel = Some(wheel);
+        } else {
+            entry.set_waker(cx.waker());
+        }
+        Poll::Pending
+    }
+}
+
+impl Drop for WheelSleep {
+    fn drop(&mut self) {
+        if let Some(wheel) = &self.wheel {
+            // SAFETY: `self` ถูก pin อยู่ตั้งแต่ลงทะเบียน (ไม่ถูกย้ายจนถึงตอนนี้)
+            let node = unsafe { Pin::new_unchecked(&self.node) };
+            // SAFETY: node ถูกลงทะเบียนกับ `wheel` ตัวนี้เท่านั้น
+            unsafe { wheel.cancel(node) };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::async_await::combinators::{Either, select2};
+    use crate::async_await::mini_executor::MiniExecutor;
+    use std::pin::pin;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::task::Wake;
+
+    struct CountingWaker(AtomicUsize);
+
+    impl Wake for CountingWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn test_wheel_fires_timers_in_deadline_order_never_early() {
+        let origin = Instant::now();
+        let wheel = TimerWheel::new(origin, ms(10), 4);
+        let counter = Arc::new(CountingWaker(AtomicUsize::new(0)));
+        let waker = Waker::from(Arc::clone(&counter));
+
+        // 55ms อยู่ slot เดียวกับ 15ms (tick 6 กับ 2 ในวงล้อ 4 ช่อง) แต่เป็นรอบถัดไป
+        let soon = pin!(ListNode::new(TimerEntry::new(origin + ms(15))));
+        let later = pin!(ListNode::new(TimerEntry::new(origin + ms(55))));
+        for node in [soon.as_ref(), later.as_ref()] {
+            node.value().set_waker(&waker);
+            // SAFETY: node ถูก cancel ก่อนจบ test ถ้ายังไม่ fire (ดูท้าย test)
+            unsafe { wheel.register(node) };
+        }
+        assert_eq!(wheel.next_wakeup(), Some(origin + ms(20)));
+
+        assert_eq!(
+            wheel.advance(origin + ms(19)),
+            0,
+            "15ms rounds up to the 20ms tick"
+        );
+        assert_eq!(wheel.advance(origin + ms(20)), 1);
+        assert!(soon.value().has_fired() && !later.value().has_fired());
+        assert_eq!(wheel.next_wakeup(), Some(origin + ms(60)));
+
+        assert_eq!(wheel.advance(origin + ms(59)), 0);
+        assert_eq!(wheel.advance(origin + ms(200)), 1);
+        assert_eq!(counter.0.load(Ordering::SeqCst), 2);
+        assert!(wheel.is_empty());
+
+        // SAFETY: ลงทะเบียนกับวงล้อนี้ - fire แล้วจึงไม่มีผล
+        unsafe { wheel.cancel(later.as_ref()) };
+    }
+
+    #[test]
+    fn test_past_deadline_fires_on_next_advance() {
+        let origin = Instant::now();
+        let wheel = TimerWheel::new(origin, ms(10), 8);
+        assert_eq!(wheel.advance(origin + ms(100)), 0);
+
+        let late = pin!(ListNode::new(TimerEntry::new(origin + ms(30))));
+        // SAFETY: fire ก่อนจบ test
+        unsafe { wheel.register(late.as_ref()) };
+        assert_eq!(wheel.next_wakeup(), Some(origin + ms(110)));
+        assert_eq!(wheel.advance(origin + ms(110)), 1);
+        assert!(late.value().has_fired());
+    }
+
+    #[test]
+    fn test_executor_runs_many_sleeps_on_one_thread() {
+        let mut executor = MiniExecutor::new();
+        let order = Rc::new(RefCell::new(Vec::new()));
+        for millis in [30_u64, 10, 20] {
+            let order = Rc::clone(&order);
+            executor.spawn(async move {
+                wheel_sleep(ms(millis)).await;
+                order.borrow_mut().push(millis);
+            });
+        }
+
+        let start = Instant::now();
+        executor.run();
+
+        assert_eq!(*order.borrow(), vec![10, 20, 30]);
+        assert!(start.elapsed() >= ms(30));
+        // poll ครั้งแรก + poll หลังถูกปลุก ต่อ task - ไม่มี busy polling
+        assert_eq!(executor.poll_count(), 6);
+    }
+
+    #[test]
+    fn test_dropping_a_pending_sleep_removes_it_from_the_wheel() {
+        let mut executor = MiniExecutor::new();
+        let start = Instant::now();
+        let winner = executor.block_on(select2(wheel_sleep(ms(5)), wheel_sleep(ms(10_000))));
+
+        assert!(matches!(winner, Either::Left(())));
+        assert!(start.elapsed() < ms(5_000));
+        assert!(executor.timers().is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "must be polled inside MiniExecutor")]
+    fn test_wheel_sleep_outside_executor_panics() {
+        let waker = Waker::from(Arc::new(CountingWaker(AtomicUsize::new(0))));
+        let mut sleep = pin!(wheel_sleep(ms(50)));
+        let _ = sleep.as_mut().poll(&mut Context::from_waker(&waker));
+    }
+}
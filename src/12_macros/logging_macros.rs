@@ -17,20 +17,20 @@ pub fn logging_macros() {
     println!("🪄 เรียนรู้การสร้างเวทมนตร์ที่ช่วยบันทึกและติดตามการทำงาน! 🔍");
 
     // 📝 Macro สำหรับ logging ระดับต่างๆ - เวทมนตร์บันทึกหลายระดับ! 🎭✨
+    // 🪄 ทุกระดับส่งต่อให้ `crate::logger` ซึ่งจัดการระดับ, target (ชื่อโมดูลนี้) และ sink ให้
+    // ค่าเริ่มต้นพิมพ์ออก stdout เหมือน println! เดิม และเปิดระดับ debug เฉพาะ debug build
     macro_rules! log {
         (info, $($arg:tt)*) => {
-            println!("ℹ️✨ [INFO] {} 🪄", format!($($arg)*));  // 💙 เวทมนตร์ข้อมูล!
+            crate::logger::info!($($arg)*)   // 💙 เวทมนตร์ข้อมูล!
         };
         (warn, $($arg:tt)*) => {
-            println!("⚠️🔶 [WARN] {} 🪄", format!($($arg)*));  // 🧡 เวทมนตร์เตือน!
+            crate::logger::warn!($($arg)*)   // 🧡 เวทมนตร์เตือน!
         };
         (error, $($arg:tt)*) => {
-            println!("❌🔴 [ERROR] {} 🪄", format!($($arg)*)); // ❤️ เวทมนตร์ข้อผิดพลาด!
+            crate::logger::error!($($arg)*)  // ❤️ เวทมนตร์ข้อผิดพลาด!
         };
         (debug, $($arg:tt)*) => {
-            if cfg!(debug_assertions) {
-                println!("🐛🔍 [DEBUG] {} 🪄", format!($($arg)*)); // 💚 เวทมนตร์ debug!
-            }
+            crate::logger::debug!($($arg)*)  // 💚 เวทมนตร์ debug!
         };
     }
 
@@ -39,6 +39,7 @@ pub fn logging_macros() {
     log!(warn, "การตั้งค่า {} ไม่ถูกต้อง", "database");      // ⚠️ เวทมนตร์เตือนภัย!
     log!(error, "ไม่สามารถเชื่อมต่อกับ server ได้");         // 💥 เวทมนตร์แจ้งเหตุฉุกเฉิน!
     log!(debug, "ค่าตัวแปร x = {}", 42);                   // 🔍 เวทมนตร์ตรวจสอบ!
+    log!(info, user = "ferris", retries = 2; "เข้าสู่ระบบสำเร็จ"); // 🏷️ เวทมนตร์แนบข้อมูลแบบ key=value!
 
     // ⏰ Macro สำหรับ timing - เวทมนตร์จับเวลาแบบ Time Wizard! 🕐✨
     // ⚠️ Warning: macro `time_it` อาจแสดง warning "unused macro definition" (เป็นเรื่องปกติของเวทมนตร์!)
@@ -203,6 +204,27 @@ mod tests {
         assert!(debug_output.contains("x = 42"));
     }
 
+    #[test]
+    fn test_log_macro_routes_through_logger() {
+        use crate::logger::{self, Format, Level, Logger, MemorySink};
+
+        let sink = MemorySink::new();
+        let logger = Logger::new(Level::Info).with_sink(sink.clone(), Format::Pretty);
+        logger::with_logger(logger, logging_macros);
+
+        let records = sink.records();
+        assert!(records.iter().all(|record| record.target == module_path!().trim_end_matches("::tests")));
+        assert!(records.iter().all(|record| record.level >= Level::Info));
+        assert!(sink.lines().contains(&format!(
+            "⚠️ [WARN] {}: การตั้งค่า database ไม่ถูกต้อง",
+            records[0].target
+        )));
+        assert!(records.iter().any(|record| record.fields == vec![
+            ("user".to_string(), "ferris".to_string()),
+            ("retries".to_string(), "2".to_string()),
+        ]));
+    }
+
     #[test]
     fn test_logging_macros_functions() {
         // ทดสอบว่าฟังก์ชันทำงานได้โดยไม่ panic
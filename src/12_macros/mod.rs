@@ -20,6 +20,8 @@ pub mod declarative_macros;  // 🔮 เวทมนตร์ประกาศ
 pub mod logging_macros;      // 📝 เวทมนตร์บันทึก (Logging Spells!)
 pub mod macro_hygiene;       // 🧹 เวทมนตร์ความสะอาด (Clean Magic!)
 pub mod practice_macros;     // 💪 ยิมฝึกเวทมนตร์ (Magic Training Gym!)
+pub mod procedural_macros;   // 🧙‍♂️ derive macros ที่เขียนเอง (Advanced Wizardry!)
+pub mod state_machine_dsl;   // 🚦 DSL สร้าง state machine (Domain-Specific Spells!)
 pub mod testing_macros;      // 🧪 เวทมนตร์ทดสอบ (Testing Spells!)
 
 // Re-export ฟังก์ชันสำคัญ - เปิดประตูเวทมนตร์ให้ใช้งานแบบ magic gateway! 🚪✨
@@ -28,6 +30,8 @@ pub use declarative_macros::*;  // 🔮 นำเข้าเวทมนตร
 pub use logging_macros::*;      // 📝 นำเข้าเวทมนตร์บันทึก!
 pub use macro_hygiene::*;       // 🧹 นำเข้าเวทมนตร์ความสะอาด!
 pub use practice_macros::*;     // 💪 นำเข้าการฝึกเวทมนตร์!
+pub use procedural_macros::*;   // 🧙‍♂️ นำเข้า derive(Builder) / derive(SimpleDisplay) / #[timed]!
+pub use state_machine_dsl::*;   // 🚦 นำเข้าตัวอย่าง state machine DSL!
 pub use testing_macros::*;      // 🧪 นำเข้าเวทมนตร์ทดสอบ!
 
 // ตัวอย่าง declarative macros ถูกย้ายไปที่ declarative_macros.rs แล้ว
@@ -57,6 +61,8 @@ pub fn run_macros_examples() {
     macro_hygiene::macro_hygiene_examples();                // 🧹 เวทมนตร์ความสะอาด
     macro_hygiene::advanced_macro_examples();               // 🧙‍♂️ เวทมนตร์ขั้นสูง
     macro_hygiene::recursive_macro_examples();              // 🔄 เวทมนตร์เรียกตัวเอง
+    state_machine_dsl::state_machine_dsl_examples();        // 🚦 เวทมนตร์สร้างภาษาจิ๋ว (DSL)
+    procedural_macros::procedural_macros_examples();        // 🧙‍♂️ เวทมนตร์ derive ที่เขียนเอง
     
     println!("\n💪🏋️‍♂️ === แบบฝึกหัด Macros - ยิมฝึกเวทมนตร์! === 🏋️‍♂️💪");
     practice_macros::practice_macros();                     // 💪 ฝึกเวทมนตร์พื้นฐาน
@@ -70,7 +76,9 @@ pub fn run_macros_examples() {
     println!("   🌟 การสร้าง variadic macros (เวทมนตร์รับพารามิเตอร์ไม่จำกัดแบบ unlimited args magic!)");
     println!("   🏗️ การใช้ macros สำหรับ code generation (เวทมนตร์สร้างโค้ดแบบ code summoning!)");
     println!("   🧹 Macro hygiene และการจัดการ scope (ความสะอาดเวทมนตร์แบบ clean magic!)");
+    println!("   🚦 การสร้าง DSL ด้วย macro_rules! เช่น state_machine! (ภาษาเวทมนตร์ของเราเอง!)");
     println!("   📝 การใช้ macros สำหรับ logging และ testing (เวทมนตร์ตรวจสอบแบบ debugging spells!)");
+    println!("   🧙‍♂️ การเขียน derive และ attribute macros ด้วย syn + quote (เวทมนตร์ขั้นสูงแบบ advanced wizardry!)");
     println!("\n🧙‍♂️✨ ยินดีด้วย! คุณเป็นพ่อมดแม่มดแห่งการเขียนโค้ดแล้ว! ✨🧙‍♀️");
 }
 
@@ -0,0 +1,139 @@
+//! # Procedural Macros - เวทมนตร์ขั้นสูงที่เขียนด้วย Rust แท้ๆ! 🧙‍♂️🔧
+//!
+//! `macro_rules!` จับคู่ token แบบ pattern แต่ procedural macro คือโปรแกรม Rust
+//! ที่อ่าน AST ของโค้ดเรา (ผ่าน `syn`) แล้วเขียนโค้ดใหม่ออกมา (ผ่าน `quote!`)
+//!
+//! 📦 derive macros อยู่ใน crate `rust_concepts_derive` (ใน workspace เดียวกัน)
+//! เพราะ proc-macro crate export ได้แค่ macros และถูกคอมไพล์ให้ compiler เรียกใช้
+//! - 🏗️ `#[derive(Builder)]` - สร้าง `XxxBuilder` พร้อม setter + `build()`
+//! - 🖨️ `#[derive(SimpleDisplay)]` - สร้าง `impl Display` ให้อัตโนมัติ
+//! - ⏱️ `#[timed]` - attribute macro ที่ห่อฟังก์ชันให้พิมพ์เวลาที่ใช้ (ติดไว้ที่ demo หลายบท
+//!   เช่น `demonstrate_parallel`, `basic_async_example`)
+//!
+//! 🚨 ใช้ผิดวิธีจะได้ compile error ที่ชี้ตรงจุด - ดูตัวอย่างใน `rust_concepts_derive/tests/ui/`
+
+pub use rust_concepts_derive::{Builder, SimpleDisplay, timed};
+
+/// ⚙️ การตั้งค่า server - ทั้ง builder และ Display มาจาก derive
+#[derive(Debug, Clone, PartialEq, Eq, Builder, SimpleDisplay)]
+#[display("{host}:{port}")]
+pub struct ServerSettings {
+    pub host: String,
+    pub port: u16,
+    /// ไม่ตั้งค่า = 0 (ให้ระบบเลือกจำนวนเอง)
+    #[builder(default)]
+    pub workers: usize,
+    /// `Option` ไม่ต้องตั้งค่าก็ได้
+    pub tls_cert: Option<String>,
+}
+
+/// 🚦 enum แบบ unit variants - Display เป็นชื่อ variant หรือข้อความที่กำหนด
+#[derive(Debug, Clone, Copy, PartialEq, Eq, SimpleDisplay)]
+pub enum TrafficLight {
+    #[display("🔴 หยุด")]
+    Red,
+    #[display("🟡 ระวัง")]
+    Yellow,
+    Green,
+}
+
+/// 📍 tuple struct ที่ไม่ใส่ `#[display]` ได้รูปแบบ `Coordinate(x, y)`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, SimpleDisplay)]
+pub struct Coordinate(pub i32, pub i32);
+
+/// ⏱️ attribute macro แทนที่ body ของฟังก์ชันด้วย body เดิมที่มีตัวจับเวลาครอบอยู่
+#[timed]
+#[must_use]
+pub fn sum_of_squares(limit: u64) -> u64 {
+    (1..=limit).map(|n| n * n).sum()
+}
+
+/// ตัวอย่าง derive macros ที่เขียนเอง - เวทมนตร์ระดับพ่อมดใหญ่! 🧙‍♂️✨
+pub fn procedural_macros_examples() {
+    println!("\n🧙‍♂️✨ === ตัวอย่าง Procedural Macros - derive ที่เขียนเอง! === ✨🧙‍♂️");
+
+    println!("\n🏗️ #[derive(Builder)]:");
+    let settings = ServerSettings::builder()
+        .host("localhost")
+        .port(8080_u16)
+        .tls_cert("certs/dev.pem")
+        .build();
+    match &settings {
+        Ok(settings) => println!("   ✅ {settings:?}"),
+        Err(error) => println!("   ❌ {error}"),
+    }
+
+    // ลืมตั้งค่า port - builder บอกชื่อ field ที่ขาด แทนที่จะ panic
+    match ServerSettings::builder().host("localhost").build() {
+        Ok(settings) => println!("   ✅ {settings:?}"),
+        Err(error) => println!("   ❌ build() ล้มเหลว: {error}"),
+    }
+
+    println!("\n🖨️ #[derive(SimpleDisplay)]:");
+    if let Ok(settings) = settings {
+        println!("   ⚙️ ServerSettings -> {settings}");
+    }
+    for light in [TrafficLight::Red, TrafficLight::Yellow, TrafficLight::Green] {
+        println!("   🚦 TrafficLight::{light:?} -> {light}");
+    }
+    println!("   📍 {}", Coordinate(3, -4));
+
+    println!("\n⏱️ #[timed]:");
+    let total = sum_of_squares(1_000_000);
+    println!("   ✅ sum_of_squares(1_000_000) = {total}");
+
+    println!("\n💡 ลองดูโค้ดที่ derive สร้างให้ด้วย `cargo expand` (ต้องติดตั้ง cargo-expand)");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder_fills_defaults_and_options() {
+        let settings = ServerSettings::builder()
+            .host("example.com")
+            .port(443_u16)
+            .build()
+            .unwrap();
+
+        assert_eq!(settings.host, "example.com");
+        assert_eq!(settings.port, 443);
+        assert_eq!(settings.workers, 0);
+        assert_eq!(settings.tls_cert, None);
+    }
+
+    #[test]
+    fn test_builder_reports_missing_field() {
+        let error = ServerSettings::builder().port(80_u16).build().unwrap_err();
+        assert_eq!(error, "missing field `host`");
+    }
+
+    #[test]
+    fn test_simple_display_formats() {
+        let settings = ServerSettings::builder()
+            .host("127.0.0.1")
+            .port(3000_u16)
+            .workers(4_usize)
+            .build()
+            .unwrap();
+
+        assert_eq!(settings.to_string(), "127.0.0.1:3000");
+        assert_eq!(TrafficLight::Red.to_string(), "🔴 หยุด");
+        assert_eq!(TrafficLight::Green.to_string(), "Green");
+        assert_eq!(Coordinate(1, 2).to_string(), "Coordinate(1, 2)");
+    }
+
+    #[test]
+    fn test_timed_keeps_return_value() {
+        assert_eq!(sum_of_squares(3), 14);
+        assert_eq!(sum_of_squares(0), 0);
+    }
+
+    #[test]
+    fn test_timed_prints_through_printer() {
+        let capture = crate::output::Capturing::new();
+        crate::output::with_printer(capture.clone(), || sum_of_squares(10));
+        assert!(capture.contents().starts_with("⏱️ sum_of_squares ใช้เวลา "));
+    }
+}
@@ -0,0 +1,302 @@
+//! # State Machine DSL - ภาษาจิ๋วสำหรับประกาศ state machine ด้วย `macro_rules!` 🚦🪄
+//!
+//! เขียนแค่รายชื่อ state กับตาราง transition แบบกระชับ แล้ว macro จะสร้างให้:
+//! - 🏷️ enum ของ state และ enum ของ event (พร้อม `Display`)
+//! - 🔀 `next(event)` / `go_to(state)` - เปลี่ยน state หรือคืน error ถ้าไม่อนุญาต
+//! - 🚨 error type ที่บอกว่าพยายามเปลี่ยนจากไหนไปไหน
+//!
+//! ```
+//! rust_concepts::state_machine! {
+//!     /// 🚪 ประตู
+//!     pub enum Door { Opened, Closed, Locked }
+//!     events DoorEvent;
+//!     error DoorError;
+//!     transitions {
+//!         Open: Closed => Opened,
+//!         Close: Opened => Closed,
+//!         Lock: Closed => Locked,
+//!         Unlock: Locked => Closed,
+//!     }
+//! }
+//!
+//! assert_eq!(Door::Closed.next(DoorEvent::Lock), Ok(Door::Locked));
+//! assert!(Door::Locked.next(DoorEvent::Open).is_err());
+//! ```
+//!
+//! 🧱 transition เป็น `const fn` จึงตรวจกฎได้ตั้งแต่ตอนคอมไพล์ด้วย:
+//!
+//! ```compile_fail
+//! rust_concepts::state_machine! {
+//!     pub enum Door { Opened, Closed, Locked }
+//!     events DoorEvent;
+//!     error DoorError;
+//!     transitions {
+//!         Open: Closed => Opened,
+//!         Lock: Closed => Locked,
+//!     }
+//! }
+//!
+//! // ❌ เปิดประตูที่ล็อกอยู่ไม่ได้ - assert ล้มตั้งแต่ตอนคอมไพล์
+//! const _: () = assert!(Door::Locked.can(DoorEvent::Open));
+//! ```
+//!
+//! และอ้าง state ที่ไม่ได้ประกาศไว้ก็คอมไพล์ไม่ผ่านเช่นกัน:
+//!
+//! ```compile_fail
+//! rust_concepts::state_machine! {
+//!     pub enum Door { Opened, Closed }
+//!     events DoorEvent;
+//!     error DoorError;
+//!     transitions {
+//!         Lock: Closed => Locked, // ❌ ไม่มี state `Locked`
+//!     }
+//! }
+//! ```
+
+/// 🚦 สร้าง state machine จากไวยากรณ์แบบกระชับ
+///
+/// ```text
+/// state_machine! {
+///     /// เอกสารของ enum state
+///     pub enum State { A, B, C }
+///     events Event;          // ชื่อ enum ของ event ที่จะสร้าง
+///     error TransitionError; // ชื่อ error type ที่จะสร้าง
+///     transitions {
+///         Go: A => B,        // event Go เปลี่ยน A เป็น B
+///         Reset: B | C => A, // หลาย state ต้นทางใช้ `|` คั่น
+///     }
+/// }
+/// ```
+///
+/// - event แต่ละตัวมีปลายทางได้ state เดียว (มีต้นทางได้หลายตัว)
+/// - macro derive `Debug, Clone, Copy, PartialEq, Eq, Hash` ให้ทั้ง state และ event เอง
+/// - ใส่ต้นทางซ้ำกับ event เดียวกันจะได้ warning `unreachable pattern`
+#[macro_export]
+macro_rules! state_machine {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $state:ident { $($variant:ident),+ $(,)? }
+        events $event:ident;
+        error $error:ident;
+        transitions {
+            $($name:ident : $($from:ident)|+ => $to:ident),+ $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        $vis enum $state {
+            $($variant),+
+        }
+
+        #[doc = concat!("Event ที่ทำให้ [`", stringify!($state), "`] เปลี่ยน state")]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        $vis enum $event {
+            $($name),+
+        }
+
+        #[doc = concat!("Transition ที่ [`", stringify!($state), "`] ไม่อนุญาต")]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        $vis enum $error {
+            /// event นี้ใช้กับ state ปัจจุบันไม่ได้
+            InvalidEvent { from: $state, event: $event },
+            /// ไม่มี event ไหนพาจาก `from` ไป `to` ได้
+            Unreachable { from: $state, to: $state },
+        }
+
+        impl $event {
+            /// ทุก event ตามลำดับที่ประกาศ
+            pub const ALL: &'static [Self] = &[$(Self::$name),+];
+        }
+
+        impl $state {
+            /// ทุก state ตามลำดับที่ประกาศ
+            pub const ALL: &'static [Self] = &[$(Self::$variant),+];
+
+            /// ส่ง event เข้าไปแล้วได้ state ถัดไป หรือ error ถ้า event นี้ใช้ไม่ได้ตอนนี้
+            ///
+            /// # Errors
+            ///
+            /// คืน `InvalidEvent` ถ้าไม่มี transition ของ `event` ที่เริ่มจาก state นี้
+            pub const fn next(self, event: $event) -> ::core::result::Result<Self, $error> {
+                match (self, event) {
+                    $(
+                        ($(Self::$from)|+, $event::$name) => ::core::result::Result::Ok(Self::$to),
+                    )+
+                    #[allow(unreachable_patterns)]
+                    (from, event) => {
+                        ::core::result::Result::Err($error::InvalidEvent { from, event })
+                    }
+                }
+            }
+
+            /// `event` ใช้กับ state นี้ได้หรือไม่ (เป็น `const fn` ใช้ใน `const` assert ได้)
+            #[must_use]
+            pub const fn can(self, event: $event) -> bool {
+                self.next(event).is_ok()
+            }
+
+            /// event ตัวแรก (ตามลำดับที่ประกาศ) ที่พาจาก state นี้ไป `to` ได้
+            #[must_use]
+            #[allow(unreachable_patterns)]
+            pub const fn event_to(self, to: Self) -> ::core::option::Option<$event> {
+                match (self, to) {
+                    $(
+                        ($(Self::$from)|+, Self::$to) => ::core::option::Option::Some($event::$name),
+                    )+
+                    _ => ::core::option::Option::None,
+                }
+            }
+
+            /// เปลี่ยนไป state `to` โดยตรง ถ้ามี event ที่พาไปได้
+            ///
+            /// # Errors
+            ///
+            /// คืน `Unreachable` ถ้าไม่มี transition จาก state นี้ไป `to`
+            pub const fn go_to(self, to: Self) -> ::core::result::Result<Self, $error> {
+                match self.event_to(to) {
+                    ::core::option::Option::Some(_) => ::core::result::Result::Ok(to),
+                    ::core::option::Option::None => {
+                        ::core::result::Result::Err($error::Unreachable { from: self, to })
+                    }
+                }
+            }
+        }
+
+        impl ::core::fmt::Display for $state {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.write_str(match self {
+                    $(Self::$variant => stringify!($variant)),+
+                })
+            }
+        }
+
+        impl ::core::fmt::Display for $event {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.write_str(match self {
+                    $(Self::$name => stringify!($name)),+
+                })
+            }
+        }
+
+        impl ::core::fmt::Display for $error {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match self {
+                    Self::InvalidEvent { from, event } => {
+                        ::core::write!(f, "event {event} is not allowed in state {from}")
+                    }
+                    Self::Unreachable { from, to } => {
+                        ::core::write!(f, "cannot go from {from} to {to}")
+                    }
+                }
+            }
+        }
+
+        impl ::std::error::Error for $error {}
+    };
+}
+
+state_machine! {
+    /// 📦 สถานะของคำสั่งซื้อ - ตัวอย่างการใช้ DSL ในบทนี้
+    pub enum DslOrderStatus { Draft, Placed, Paid, Shipped, Delivered, Cancelled }
+    events OrderEvent;
+    error OrderTransitionError;
+    transitions {
+        Place: Draft => Placed,
+        Pay: Placed => Paid,
+        Ship: Paid => Shipped,
+        Deliver: Shipped => Delivered,
+        Cancel: Draft | Placed | Paid => Cancelled,
+    }
+}
+
+// 🧱 กฎที่ตรวจตอนคอมไพล์: ถ้าแก้ตาราง transition จนผิดกฎนี้ crate จะคอมไพล์ไม่ผ่าน
+const _: () = assert!(!DslOrderStatus::Shipped.can(OrderEvent::Cancel));
+
+/// ตัวอย่าง state machine DSL - เวทมนตร์สร้างภาษาของตัวเอง! 🚦🪄
+pub fn state_machine_dsl_examples() {
+    println!("\n🚦✨ === ตัวอย่าง State Machine DSL - สร้างภาษาจิ๋วด้วย macro_rules! === ✨🚦");
+
+    let mut status = DslOrderStatus::Draft;
+    println!("📦 เริ่มต้นที่ {status}");
+    for event in [
+        OrderEvent::Place,
+        OrderEvent::Pay,
+        OrderEvent::Ship,
+        OrderEvent::Cancel,
+    ] {
+        match status.next(event) {
+            Ok(next) => {
+                println!("   ✅ {status} --{event}--> {next}");
+                status = next;
+            }
+            Err(error) => println!("   🚫 {error}"),
+        }
+    }
+
+    println!("🔍 จาก {status} ไปได้ด้วย event:");
+    for state in DslOrderStatus::ALL {
+        if let Some(event) = status.event_to(*state) {
+            println!("   • {event} -> {state}");
+        }
+    }
+    println!("💡 ส่วนกฎ 'ส่งของแล้วยกเลิกไม่ได้' ถูกตรวจด้วย const assert ตั้งแต่ตอนคอมไพล์");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generated_transitions_follow_table() {
+        let mut status = DslOrderStatus::Draft;
+        for event in [
+            OrderEvent::Place,
+            OrderEvent::Pay,
+            OrderEvent::Ship,
+            OrderEvent::Deliver,
+        ] {
+            status = status.next(event).unwrap();
+        }
+        assert_eq!(status, DslOrderStatus::Delivered);
+        assert_eq!(
+            DslOrderStatus::Placed.next(OrderEvent::Cancel),
+            Ok(DslOrderStatus::Cancelled)
+        );
+    }
+
+    #[test]
+    fn test_illegal_transitions_are_rejected_at_runtime() {
+        assert_eq!(
+            DslOrderStatus::Delivered.next(OrderEvent::Cancel),
+            Err(OrderTransitionError::InvalidEvent {
+                from: DslOrderStatus::Delivered,
+                event: OrderEvent::Cancel,
+            })
+        );
+        assert_eq!(
+            DslOrderStatus::Draft.go_to(DslOrderStatus::Shipped),
+            Err(OrderTransitionError::Unreachable {
+                from: DslOrderStatus::Draft,
+                to: DslOrderStatus::Shipped,
+            })
+        );
+        assert_eq!(
+            DslOrderStatus::Draft
+                .go_to(DslOrderStatus::Shipped)
+                .unwrap_err()
+                .to_string(),
+            "cannot go from Draft to Shipped"
+        );
+    }
+
+    #[test]
+    fn test_event_lookup_and_display() {
+        assert_eq!(
+            DslOrderStatus::Paid.event_to(DslOrderStatus::Shipped),
+            Some(OrderEvent::Ship)
+        );
+        assert_eq!(DslOrderStatus::Paid.event_to(DslOrderStatus::Draft), None);
+        assert_eq!(DslOrderStatus::ALL.len(), 6);
+        assert_eq!(OrderEvent::Deliver.to_string(), "Deliver");
+    }
+}
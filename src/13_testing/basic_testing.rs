@@ -5,6 +5,9 @@
 //!
 //! 🎯 **เป้าหมาย**: เรียนรู้การใช้เครื่องมือสืบสวนพื้นฐาน!
 
+use super::snapshot_testing::print_output;
+use std::io::{self, Write};
+
 /// ตัวอย่างฟังก์ชันที่จะใช้ทดสอบ - เครื่องคิดเลขนักสืบ! 🧮🔍
 #[must_use]  // 🚨 ต้องใช้ผลลัพธ์! (ไม่ให้ทิ้งหลักฐาน!)
 pub const fn add(a: i32, b: i32) -> i32 {
@@ -26,30 +29,41 @@ pub fn divide(a: f64, b: f64) -> Result<f64, String> {
 
 /// ตัวอย่างการใช้งาน basic testing - เริ่มการสืบสวนพื้นฐาน! 🔬🕵️‍♂️
 pub fn basic_testing_examples() {
-    println!("🔬✨ === Basic Testing Examples - ห้องทดลองพื้นฐาน! === ✨🔬");
-    println!("🕵️‍♂️ เริ่มการสืบสวนด้วยเครื่องมือพื้นฐาน! 🧪");
+    print_output(write_basic_testing_examples);
+}
+
+/// เขียน output ของ `basic_testing_examples` ลง writer - ใช้จับ output ไปเทียบกับ golden file 📸
+///
+/// # Errors
+///
+/// ส่งต่อ error จาก writer
+pub fn write_basic_testing_examples(out: &mut dyn Write) -> io::Result<()> {
+    writeln!(out, "🔬✨ === Basic Testing Examples - ห้องทดลองพื้นฐาน! === ✨🔬")?;
+    writeln!(out, "🕵️‍♂️ เริ่มการสืบสวนด้วยเครื่องมือพื้นฐาน! 🧪")?;
 
     // 🧮 ทดสอบฟังก์ชันพื้นฐาน - ตรวจสอบหลักฐานแรก!
-    println!("➕🔍 การบวก: 5 + 3 = {} (หลักฐานการคำนวณ!)", add(5, 3));
+    writeln!(out, "➕🔍 การบวก: 5 + 3 = {} (หลักฐานการคำนวณ!)", add(5, 3))?;
 
     // 🔍 ทดสอบการหาร - สืบสวนกรณีปกติ!
     match divide(10.0, 2.0) {
-        Ok(result) => println!("➗✅ การหาร: 10 ÷ 2 = {result} (หลักฐานถูกต้อง!)"),
-        Err(e) => println!("❌🚨 ข้อผิดพลาด: {e}"),
+        Ok(result) => writeln!(out, "➗✅ การหาร: 10 ÷ 2 = {result} (หลักฐานถูกต้อง!)")?,
+        Err(e) => writeln!(out, "❌🚨 ข้อผิดพลาด: {e}")?,
     }
 
     // 🚨 ทดสอบการหารด้วยศูนย์ - สืบสวนกรณีอันตราย!
     match divide(10.0, 0.0) {
-        Ok(result) => println!("➗✅ การหาร: 10 ÷ 0 = {result} (ไม่น่าจะเกิดขึ้น!)"),
-        Err(e) => println!("❌🔍 ข้อผิดพลาดที่คาดไว้: {e} (หลักฐานข้อผิดพลาด!)"),
+        Ok(result) => writeln!(out, "➗✅ การหาร: 10 ÷ 0 = {result} (ไม่น่าจะเกิดขึ้น!)")?,
+        Err(e) => writeln!(out, "❌🔍 ข้อผิดพลาดที่คาดไว้: {e} (หลักฐานข้อผิดพลาด!)")?,
     }
     
-    println!("🎉 การสืบสวนพื้นฐานเสร็จสิ้น! ✨");
+    writeln!(out, "🎉 การสืบสวนพื้นฐานเสร็จสิ้น! ✨")?;
+    Ok(())
 }
 
 #[cfg(test)]  // 🧪 โหมดทดสอบ - เข้าสู่ห้องแล็บนักสืบ!
 pub mod tests {
     use super::*;
+    use crate::testing::snapshot_testing::{capture_output, SnapshotStore};
 
     // 🧮 ทดสอบฟังก์ชันพื้นฐาน - ตรวจสอบหลักฐานการบวก!
     #[test]  // 🔬 การทดสอบที่ 1
@@ -112,4 +126,12 @@ pub mod tests {
         // 🧪 ทดสอบว่าฟังก์ชันทำงานได้โดยไม่ panic - ตรวจสอบความปลอดภัย!
         basic_testing_examples();  // 🔍 เรียกใช้การสืบสวนพื้นฐาน
     }
+
+    #[test]  // 🔬 การทดสอบที่ 8
+    #[allow(clippy::missing_panics_doc)]  // 🤫 ปิดเสียง warning
+    pub fn test_basic_testing_snapshot() {
+        // 📸 เทียบ output ทั้งหมดกับ golden file ใน snapshots/
+        let output = capture_output(write_basic_testing_examples).unwrap();
+        SnapshotStore::chapter().assert_matches("basic_testing_examples", &output);
+    }
 }
\ No newline at end of file
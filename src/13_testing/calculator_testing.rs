@@ -5,6 +5,9 @@
 //!
 //! 🎯 **เป้าหมาย**: เรียนรู้การทดสอบ struct และ methods อย่างละเอียด!
 
+use super::snapshot_testing::print_output;
+use std::io::{self, Write};
+
 /// เครื่องคิดเลขนักสืบ - เก็บหลักฐานตัวเลข! 🧮🔍
 #[derive(Debug, PartialEq)]  // 🔬 สามารถ debug และเปรียบเทียบได้
 pub struct Calculator {
@@ -71,43 +74,54 @@ impl Calculator {
 
 /// ตัวอย่างการใช้งาน Calculator - ทดสอบเครื่องคิดเลขนักสืบ! 🧮🕵️‍♂️
 pub fn calculator_testing_examples() {
-    println!("🧮✨ === Calculator Testing Examples - เครื่องคิดเลขนักสืบ! === ✨🧮");
-    println!("🕵️‍♂️ เริ่มทดสอบเครื่องมือคำนวณหลักฐาน! 🔍");
+    print_output(write_calculator_testing_examples);
+}
+
+/// เขียน output ของ `calculator_testing_examples` ลง writer - ใช้จับ output ไปเทียบกับ golden file 📸
+///
+/// # Errors
+///
+/// ส่งต่อ error จาก writer
+pub fn write_calculator_testing_examples(out: &mut dyn Write) -> io::Result<()> {
+    writeln!(out, "🧮✨ === Calculator Testing Examples - เครื่องคิดเลขนักสืบ! === ✨🧮")?;
+    writeln!(out, "🕵️‍♂️ เริ่มทดสอบเครื่องมือคำนวณหลักฐาน! 🔍")?;
 
     // 🧮 ทดสอบ Calculator - เริ่มการสืบสวน!
     let mut calc = Calculator::new();
-    println!("🆕🔍 Calculator ใหม่: memory = {} (เริ่มต้นการสืบสวน!)", calc.get_memory());
+    writeln!(out, "🆕🔍 Calculator ใหม่: memory = {} (เริ่มต้นการสืบสวน!)", calc.get_memory())?;
 
     calc.add(10.0);
-    println!("➕🔍 เพิ่ม 10: memory = {} (เพิ่มหลักฐานแรก!)", calc.get_memory());
+    writeln!(out, "➕🔍 เพิ่ม 10: memory = {} (เพิ่มหลักฐานแรก!)", calc.get_memory())?;
 
     calc.multiply(2.0);
-    println!("✖️🔍 คูณ 2: memory = {} (ขยายหลักฐาน!)", calc.get_memory());
+    writeln!(out, "✖️🔍 คูณ 2: memory = {} (ขยายหลักฐาน!)", calc.get_memory())?;
 
     calc.subtract(5.0);
-    println!("➖🔍 ลบ 5: memory = {} (ลดหลักฐาน!)", calc.get_memory());
+    writeln!(out, "➖🔍 ลบ 5: memory = {} (ลดหลักฐาน!)", calc.get_memory())?;
 
     // 🔍 ทดสอบการหาร - แบ่งหลักฐาน!
     match calc.divide(3.0) {
-        Ok(result) => println!("➗✅ หาร 3: memory = {result} (แบ่งหลักฐานสำเร็จ!)"),
-        Err(e) => println!("❌🚨 ข้อผิดพลาด: {e}"),
+        Ok(result) => writeln!(out, "➗✅ หาร 3: memory = {result} (แบ่งหลักฐานสำเร็จ!)")?,
+        Err(e) => writeln!(out, "❌🚨 ข้อผิดพลาด: {e}")?,
     }
 
     // 🚨 ทดสอบการหารด้วยศูนย์ - สถานการณ์อันตราย!
     match calc.divide(0.0) {
-        Ok(result) => println!("➗✅ หาร 0: memory = {result} (ไม่น่าจะเกิดขึ้น!)"),
-        Err(e) => println!("❌🔍 ข้อผิดพลาดที่คาดไว้: {e} (หลักฐานข้อผิดพลาด!)"),
+        Ok(result) => writeln!(out, "➗✅ หาร 0: memory = {result} (ไม่น่าจะเกิดขึ้น!)")?,
+        Err(e) => writeln!(out, "❌🔍 ข้อผิดพลาดที่คาดไว้: {e} (หลักฐานข้อผิดพลาด!)")?,
     }
 
     calc.clear();
-    println!("🧹✨ ล้างหน่วยความจำ: memory = {} (เริ่มการสืบสวนใหม่!)", calc.get_memory());
+    writeln!(out, "🧹✨ ล้างหน่วยความจำ: memory = {} (เริ่มการสืบสวนใหม่!)", calc.get_memory())?;
     
-    println!("🎉 การทดสอบเครื่องคิดเลขนักสืบเสร็จสิ้น! ✨");
+    writeln!(out, "🎉 การทดสอบเครื่องคิดเลขนักสืบเสร็จสิ้น! ✨")?;
+    Ok(())
 }
 
 #[cfg(test)]  // 🧪 โหมดทดสอบ - เข้าสู่ห้องแล็บนักสืบ!
 pub mod tests {
     use super::*;
+    use crate::testing::snapshot_testing::{capture_output, SnapshotStore};
 
     // 🧮 ทดสอบ Calculator - ตรวจสอบเครื่องคิดเลขนักสืบ!
     #[test]  // 🔬 การทดสอบที่ 1
@@ -234,4 +248,13 @@ pub mod tests {
         // 🧪 ทดสอบว่าฟังก์ชันทำงานได้โดยไม่ panic - ตรวจสอบความปลอดภัย!
         calculator_testing_examples();  // 🔍 เรียกใช้การทดสอบเครื่องคิดเลข
     }
+
+    #[test]  // 🔬 การทดสอบที่ 11
+    // ใช้ #[allow(clippy::missing_panics_doc)] เพราะเป็น test function ที่ไม่ควร panic
+    #[allow(clippy::missing_panics_doc)]  // 🤫 ปิดเสียง warning
+    pub fn test_calculator_testing_snapshot() {
+        // 📸 เทียบ output ทั้งหมดกับ golden file ใน snapshots/
+        let output = capture_output(write_calculator_testing_examples).unwrap();
+        SnapshotStore::chapter().assert_matches("calculator_testing_examples", &output);
+    }
 }
\ No newline at end of file
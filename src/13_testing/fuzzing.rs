@@ -0,0 +1,358 @@
+//! # Fuzzing - ยิงข้อมูลขยะใส่ parser จนกว่าจะพัง! 💥🕵️‍♂️
+//!
+//! Parser ที่รับข้อมูลจาก network ต้องไม่ panic ไม่ว่าผู้ร้ายจะส่งอะไรมา 🦹
+//! เราเลยเขียน fuzzer แบบ mutation-based เล็กๆ (แนวเดียวกับ `cargo fuzz`):
+//! - 🌱 เริ่มจาก corpus ของ input ที่น่าสงสัย
+//! - 🧬 กลายพันธุ์ทีละนิด (flip bit, แทรก/ลบ byte, ตัดต่อ)
+//! - 💥 ยิงเข้า target แล้วจับ panic ด้วย `catch_unwind`
+//!
+//! ⚠️ `catch_unwind` จับได้เฉพาะ build ที่ `panic = "unwind"` (debug/test) - release profile
+//! ของ repo นี้ตั้ง `panic = "abort"` ซึ่ง crash แรกจะปิดทั้งโปรแกรม (`cargo fuzz` เองก็แยก
+//! process ต่อ crash ด้วยเหตุผลเดียวกัน)
+//!
+//! 🎯 **Targets**: HTTP request parser, template engine, WebSocket frame decoder
+
+use super::property_testing::Gen;
+use crate::networking::websocket_communication::WebSocketFrame;
+use crate::web_development::HttpRequest;
+use crate::web_development::templating::{TemplateContext, TemplateEngine};
+use std::panic::{self, AssertUnwindSafe};
+
+/// 🎯 fuzz target แบบเดียวกับ `fuzz_target!` ของ cargo-fuzz: รับ bytes แล้วต้องไม่ panic
+pub type FuzzTarget = fn(&[u8]);
+
+/// 💥 input ที่ทำให้ target panic
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuzzCrash {
+    pub input: Vec<u8>,
+    pub message: String,
+}
+
+/// 📋 สรุปผลการ fuzz
+#[derive(Debug, Clone, Default)]
+pub struct FuzzReport {
+    pub iterations: usize,
+    pub crashes: Vec<FuzzCrash>,
+}
+
+/// ความยาวสูงสุดของ input หลังกลายพันธุ์
+const MAX_INPUT_LEN: usize = 512;
+
+/// byte ที่มักทำให้ parser สับสน
+const INTERESTING_BYTES: [u8; 12] = [
+    0x00, 0xFF, 0x7E, 0x7F, 0x80, b'{', b'}', b'\r', b'\n', b':', b' ', b'#',
+];
+
+/// 🧬 กลายพันธุ์ input หนึ่งครั้ง
+pub fn mutate(input: &[u8], corpus: &[&[u8]], g: &mut Gen) -> Vec<u8> {
+    let mut data = input.to_vec();
+    let pick = |g: &mut Gen, len: usize| usize::try_from(g.below(len as u64)).unwrap_or(0);
+
+    match g.below(7) {
+        0 if !data.is_empty() => {
+            let index = pick(g, data.len());
+            data[index] ^= 1 << g.below(8);
+        }
+        1 if !data.is_empty() => {
+            let index = pick(g, data.len());
+            data[index] = *g.choose(&INTERESTING_BYTES);
+        }
+        2 => {
+            let index = pick(g, data.len() + 1);
+            let byte = *g.choose(&INTERESTING_BYTES);
+            data.insert(index, byte);
+        }
+        3 if !data.is_empty() => {
+            data.remove(pick(g, data.len()));
+        }
+        4 if !data.is_empty() => {
+            let start = pick(g, data.len());
+            let end = start + pick(g, data.len() - start + 1);
+            let chunk = data[start..end].to_vec();
+            let at = pick(g, data.len() + 1);
+            data.splice(at..at, chunk);
+        }
+        5 => data.truncate(pick(g, data.len() + 1)),
+        _ => {
+            // ตัดต่อหัวของ input นี้กับหางของอีกตัวใน corpus
+            let other = g.choose(corpus);
+            let cut = pick(g, data.len() + 1);
+            let other_cut = pick(g, other.len() + 1);
+            data.truncate(cut);
+            data.extend_from_slice(&other[other_cut..]);
+        }
+    }
+
+    data.truncate(MAX_INPUT_LEN);
+    data
+}
+
+/// รัน target หนึ่งครั้งและแปลง panic เป็น `Err(message)`
+///
+/// # Errors
+///
+/// คืนข้อความของ panic ถ้า target panic - ใน build ที่ `panic = "abort"` process จะตายแทน
+pub fn run_target(target: FuzzTarget, input: &[u8]) -> Result<(), String> {
+    panic::catch_unwind(AssertUnwindSafe(|| target(input))).map_err(|payload| {
+        payload
+            .downcast_ref::<&str>()
+            .map(|message| (*message).to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "non-string panic payload".to_string())
+    })
+}
+
+/// 💥 fuzz target ด้วย input ที่กลายพันธุ์จาก corpus `iterations` ครั้ง
+///
+/// # Panics
+///
+/// Panics ถ้า `corpus` ว่าง
+pub fn fuzz(target: FuzzTarget, corpus: &[&[u8]], iterations: usize, seed: u64) -> FuzzReport {
+    assert!(!corpus.is_empty(), "fuzzing needs at least one seed input");
+    let mut g = Gen::new(seed, 0);
+    let mut report = FuzzReport::default();
+
+    for &input in corpus {
+        if let Err(message) = run_target(target, input) {
+            report.crashes.push(FuzzCrash {
+                input: input.to_vec(),
+                message,
+            });
+        }
+    }
+
+    for _ in 0..iterations {
+        // กลายพันธุ์ซ้อนกันหลายรอบเพื่อให้ได้ input ที่ห่างจาก corpus มากขึ้น
+        let mut input = g.choose(corpus).to_vec();
+        for _ in 0..=g.below(4) {
+            input = mutate(&input, corpus, &mut g);
+        }
+        report.iterations += 1;
+        if let Err(message) = run_target(target, &input) {
+            report.crashes.push(FuzzCrash { input, message });
+        }
+    }
+    report
+}
+
+/// 🌐 HTTP parser: ต้องไม่ panic และถ้า parse ผ่าน ผลลัพธ์ต้องสมเหตุสมผล
+///
+/// # Panics
+///
+/// Panics ถ้า request ที่ parse ได้ผิด invariant - นี่คือ bug ที่ fuzzer ตามหา
+pub fn fuzz_http_request(data: &[u8]) {
+    if let Ok(request) = HttpRequest::parse(data) {
+        assert!(!request.method.is_empty(), "parsed an empty method");
+        assert!(
+            request.path.starts_with('/') || request.path == "*",
+            "parsed an invalid path"
+        );
+        assert!(request.body.len() <= data.len(), "body larger than input");
+    }
+}
+
+/// 🎨 Template engine: render ข้อความอะไรก็ได้โดยไม่ panic
+pub fn fuzz_template(data: &[u8]) {
+    let template = String::from_utf8_lossy(data);
+    let engine = TemplateEngine::new();
+    let mut context = TemplateContext::new();
+    context.set("name", "Somchai");
+    context.set("admin", "true");
+    context.set_array("items", vec!["a".to_string(), "b".to_string()]);
+    let _ = engine.render_string(&template, &context);
+}
+
+/// 🔗 WebSocket decoder: ต้องไม่ panic และ frame ที่ decode ได้ต้อง encode กลับเป็น bytes เดิม
+///
+/// # Panics
+///
+/// Panics ถ้า frame ที่ decode ได้ไม่ round-trip - นี่คือ bug ที่ fuzzer ตามหา
+pub fn fuzz_websocket_frame(data: &[u8]) {
+    if let Ok((frame, used)) = WebSocketFrame::decode(data) {
+        assert!(used <= data.len(), "consumed more bytes than available");
+        assert_eq!(frame.encode(), data[..used], "frame did not round-trip");
+    }
+}
+
+/// 🌱 corpus ของ HTTP request ที่น่าสงสัย
+pub const HTTP_CORPUS: &[&[u8]] = &[
+    b"GET / HTTP/1.1\r\nHost: example.com\r\n\r\n",
+    b"POST /api HTTP/1.1\r\nContent-Length: 5\r\n\r\nhello",
+    b"POST /api HTTP/1.1\r\nContent-Length: 18446744073709551616\r\n\r\n",
+    b"POST /api HTTP/1.1\r\nContent-Length: 99\r\n\r\nshort",
+    b"GET / HTTP/1.1\r\n: no-name\r\n\r\n",
+    b"GET  HTTP/1.1\r\n\r\n",
+    b"\r\n\r\n",
+    b"GET /\xff\xfe HTTP/1.1\r\n\r\n",
+    b"GET / HTTP/1.1\r\nContent-Length: 1\r\n\r\n\xc3",
+    b"",
+];
+
+/// 🌱 corpus ของ template ที่น่าสงสัย
+pub const TEMPLATE_CORPUS: &[&[u8]] = &[
+    b"Hello {{name}}!",
+    b"{{#if admin}}Admin{{/if}}",
+    b"{{#each items}}{{@index}}:{{this}} {{/each}}",
+    b"{{#if admin}}unterminated",
+    b"{{#each items}}unterminated",
+    b"{{upper name}} {{default missing fallback}}",
+    b"{{{{}}}}",
+    b"{{",
+    b"}}{{",
+    b"{{#if }}{{/if}}",
+    b"{{#each }}{{/each}}",
+    b"{{>missing}}",
+    "สวัสดี {{name}} 🦀".as_bytes(),
+];
+
+/// 🌱 corpus ของ WebSocket frame ที่น่าสงสัย
+pub const WEBSOCKET_CORPUS: &[&[u8]] = &[
+    &[
+        0x81, 0x85, 0x37, 0xFA, 0x21, 0x3D, 0x7F, 0x9F, 0x4D, 0x51, 0x58,
+    ],
+    &[0x82, 0x7E, 0x00, 0x7E],
+    &[0x82, 0x7F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00],
+    &[0x82, 0x7F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF],
+    &[0x89, 0x7E, 0x00, 0x80],
+    &[0x88, 0x02, 0x03, 0xE8],
+    &[0xF1, 0x00],
+    &[0x81],
+    &[],
+];
+
+/// ตัวอย่างการ fuzz parser ทั้งสามตัว - ยิงข้อมูลขยะแบบมีระบบ! 💥
+pub fn fuzzing_examples() {
+    println!("💥 Fuzzing - ยิง input กลายพันธุ์ใส่ parser");
+    if !cfg!(panic = "unwind") {
+        println!("   ⚠️ build นี้ใช้ panic = \"abort\" จับ panic ไม่ได้ - ลองรันด้วย debug build แทน");
+        return;
+    }
+    let targets: [(&str, FuzzTarget, &[&[u8]]); 3] = [
+        ("HTTP parser", fuzz_http_request, HTTP_CORPUS),
+        ("Template engine", fuzz_template, TEMPLATE_CORPUS),
+        ("WebSocket decoder", fuzz_websocket_frame, WEBSOCKET_CORPUS),
+    ];
+
+    for (name, target, corpus) in targets {
+        let report = fuzz(target, corpus, 1_000, 0xF022);
+        if report.crashes.is_empty() {
+            println!(
+                "   ✅ {name}: {} input ไม่มี panic เลย",
+                report.iterations + corpus.len()
+            );
+        } else {
+            println!("   💥 {name}: พัง {} ครั้ง", report.crashes.len());
+            for crash in report.crashes.iter().take(3) {
+                println!("      {:?} -> {}", crash.input, crash.message);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::networking::websocket_communication::FrameError;
+    use crate::web_development::HttpParseError;
+
+    fn assert_no_crashes(target: FuzzTarget, corpus: &[&[u8]]) {
+        let report = fuzz(target, corpus, 3_000, 0xF022);
+        assert!(report.crashes.is_empty(), "crashes: {:#?}", report.crashes);
+    }
+
+    #[test]
+    fn test_fuzz_http_parser() {
+        assert_no_crashes(fuzz_http_request, HTTP_CORPUS);
+    }
+
+    #[test]
+    fn test_fuzz_template_engine() {
+        assert_no_crashes(fuzz_template, TEMPLATE_CORPUS);
+    }
+
+    #[test]
+    fn test_fuzz_websocket_decoder() {
+        assert_no_crashes(fuzz_websocket_frame, WEBSOCKET_CORPUS);
+    }
+
+    #[test]
+    fn test_fuzzer_reports_panics() {
+        fn fragile(data: &[u8]) {
+            assert!(!data.contains(&b'#'), "found a hash");
+        }
+        let report = fuzz(fragile, &[b"abc"], 500, 1);
+        assert!(!report.crashes.is_empty());
+        assert!(
+            report
+                .crashes
+                .iter()
+                .all(|crash| crash.input.contains(&b'#'))
+        );
+        assert_eq!(report.crashes[0].message, "found a hash");
+    }
+
+    #[test]
+    fn test_http_corpus_results() {
+        let results: Vec<_> = HTTP_CORPUS
+            .iter()
+            .map(|input| HttpRequest::parse(input).err())
+            .collect();
+        assert_eq!(
+            results,
+            vec![
+                None,
+                None,
+                Some(HttpParseError::InvalidContentLength),
+                Some(HttpParseError::Incomplete),
+                Some(HttpParseError::InvalidHeader),
+                Some(HttpParseError::InvalidRequestLine),
+                Some(HttpParseError::InvalidRequestLine),
+                Some(HttpParseError::InvalidUtf8),
+                Some(HttpParseError::InvalidUtf8),
+                Some(HttpParseError::Incomplete),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_template_corpus_results() {
+        let engine = TemplateEngine::new();
+        let mut context = TemplateContext::new();
+        context.set("name", "Somchai");
+        context.set("admin", "true");
+        context.set_array("items", vec!["a".to_string(), "b".to_string()]);
+        let render =
+            |template: &[u8]| engine.render_string(&String::from_utf8_lossy(template), &context);
+
+        assert_eq!(render(TEMPLATE_CORPUS[0]), Ok("Hello Somchai!".to_string()));
+        assert_eq!(render(TEMPLATE_CORPUS[1]), Ok("Admin".to_string()));
+        assert_eq!(render(TEMPLATE_CORPUS[2]), Ok("0:a 1:b ".to_string()));
+        assert!(render(TEMPLATE_CORPUS[3]).is_err());
+        assert!(render(TEMPLATE_CORPUS[4]).is_err());
+        for input in &TEMPLATE_CORPUS[5..] {
+            assert_eq!(run_target(fuzz_template, input), Ok(()));
+        }
+    }
+
+    #[test]
+    fn test_websocket_corpus_results() {
+        let results: Vec<_> = WEBSOCKET_CORPUS
+            .iter()
+            .map(|input| WebSocketFrame::decode(input).err())
+            .collect();
+        assert_eq!(
+            results,
+            vec![
+                None,
+                Some(FrameError::Incomplete { needed: 130 }),
+                Some(FrameError::Incomplete { needed: 65_546 }),
+                Some(FrameError::PayloadTooLarge(u64::MAX)),
+                Some(FrameError::InvalidControlFrame),
+                None,
+                Some(FrameError::ReservedBitsSet),
+                Some(FrameError::Incomplete { needed: 2 }),
+                Some(FrameError::Incomplete { needed: 2 }),
+            ]
+        );
+    }
+}
@@ -0,0 +1,624 @@
+//! # Mocking & Test Doubles - นักแสดงแทนในห้องสืบสวน! 🎭🕵️‍♂️
+//!
+//! เวลาทดสอบ `OrderService` เราไม่อยากตัดเงินจริงจากบัตรเครดิต! 💳
+//! เลยส่ง "ตัวแสดงแทน" (test double) ที่ implement trait เดียวกันเข้าไปแทน
+//!
+//! 🎯 **ตัวแสดงแทน 3 แบบ**:
+//! - 🪆 **Stub** - ตอบคำตอบสำเร็จรูปเสมอ ไม่สนว่าใครถาม
+//! - 🕵️ **Spy** - ตอบเหมือน stub แต่แอบจดทุกการเรียกไว้ให้ตรวจทีหลัง
+//! - 🎬 **Mock** - รู้บทล่วงหน้าว่าต้องถูกเรียกอะไร ลำดับไหน แล้ว `verify()` ว่าเล่นตามบท
+//!
+//! ทั้งหมดเขียนเองด้วย trait ธรรมดา ไม่ต้องพึ่ง proc-macro crate 🔧
+
+use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::rc::Rc;
+
+/// 💳 คำขอตัดเงิน (จำนวนเงินเป็นสตางค์ เพื่อเลี่ยงปัญหาทศนิยม)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaymentRequest {
+    pub reference: String,
+    pub amount: u64,
+}
+
+/// 🧾 ใบเสร็จจาก payment gateway
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaymentReceipt {
+    pub transaction_id: String,
+    pub amount: u64,
+}
+
+/// ❌ ข้อผิดพลาดจาก payment gateway
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PaymentError {
+    Declined(String),
+    Timeout,
+    UnknownTransaction(String),
+}
+
+impl fmt::Display for PaymentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Declined(reason) => write!(f, "payment declined: {reason}"),
+            Self::Timeout => write!(f, "payment gateway timed out"),
+            Self::UnknownTransaction(id) => write!(f, "unknown transaction: {id}"),
+        }
+    }
+}
+
+impl std::error::Error for PaymentError {}
+
+/// 🏦 ช่องทางชำระเงิน - สิ่งที่เราจะสร้างตัวแสดงแทน
+pub trait PaymentGateway {
+    /// ตัดเงินตามคำขอ
+    ///
+    /// # Errors
+    ///
+    /// คืน `PaymentError` ถ้าธนาคารปฏิเสธหรือติดต่อไม่ได้
+    fn charge(&self, request: &PaymentRequest) -> Result<PaymentReceipt, PaymentError>;
+
+    /// คืนเงินของรายการที่เคยตัดไปแล้ว
+    ///
+    /// # Errors
+    ///
+    /// คืน `PaymentError` ถ้าหา transaction ไม่เจอหรือติดต่อไม่ได้
+    fn refund(&self, transaction_id: &str, amount: u64) -> Result<(), PaymentError>;
+}
+
+/// gateway ที่แชร์ด้วย `Rc` ใช้ได้เหมือนตัวจริง - เช่น spy ตัวเดียวที่ทั้ง service และ test ถือไว้
+impl<G: PaymentGateway + ?Sized> PaymentGateway for Rc<G> {
+    fn charge(&self, request: &PaymentRequest) -> Result<PaymentReceipt, PaymentError> {
+        (**self).charge(request)
+    }
+
+    fn refund(&self, transaction_id: &str, amount: u64) -> Result<(), PaymentError> {
+        (**self).refund(transaction_id, amount)
+    }
+}
+
+/// 📦 สินค้าในคำสั่งซื้อ
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrderItem {
+    pub name: String,
+    pub quantity: u32,
+    pub unit_price: u64,
+}
+
+impl OrderItem {
+    #[must_use]
+    pub fn new(name: &str, quantity: u32, unit_price: u64) -> Self {
+        Self {
+            name: name.to_string(),
+            quantity,
+            unit_price,
+        }
+    }
+}
+
+/// 📋 สถานะคำสั่งซื้อ
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OrderStatus {
+    Paid { transaction_id: String },
+    Refunded,
+}
+
+/// 🛒 คำสั่งซื้อที่ชำระเงินแล้ว
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Order {
+    pub id: u64,
+    pub total: u64,
+    pub status: OrderStatus,
+}
+
+/// ❌ ข้อผิดพลาดของ `OrderService`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OrderError {
+    EmptyOrder,
+    /// ยอดรวมเกินกว่าที่ `u64` เก็บได้ - ต้องไม่ปล่อยให้วนกลับเป็นยอดเล็กแล้วไปตัดเงิน
+    TotalOverflow,
+    NotFound(u64),
+    AlreadyRefunded(u64),
+    Payment(PaymentError),
+}
+
+impl fmt::Display for OrderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyOrder => write!(f, "order has no items"),
+            Self::TotalOverflow => write!(f, "order total is too large"),
+            Self::NotFound(id) => write!(f, "order {id} not found"),
+            Self::AlreadyRefunded(id) => write!(f, "order {id} was already refunded"),
+            Self::Payment(error) => write!(f, "payment failed: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for OrderError {}
+
+impl From<PaymentError> for OrderError {
+    fn from(error: PaymentError) -> Self {
+        Self::Payment(error)
+    }
+}
+
+/// 🏪 บริการสั่งซื้อ - โค้ดที่เราต้องการทดสอบจริงๆ
+///
+/// รับ gateway ผ่าน generic จึงฉีดตัวแสดงแทนเข้าไปได้โดยไม่ต้องแก้โค้ด
+pub struct OrderService<G: PaymentGateway> {
+    gateway: G,
+    orders: HashMap<u64, Order>,
+    next_id: u64,
+    max_attempts: u32,
+}
+
+impl<G: PaymentGateway> OrderService<G> {
+    /// สร้างบริการที่ลองตัดเงินซ้ำได้สูงสุด 2 ครั้งเมื่อ gateway timeout
+    #[must_use]
+    pub fn new(gateway: G) -> Self {
+        Self {
+            gateway,
+            orders: HashMap::new(),
+            next_id: 1,
+            max_attempts: 2,
+        }
+    }
+
+    /// เข้าถึง gateway (เช่นเพื่อตรวจ spy หรือ verify mock หลังทดสอบ)
+    #[must_use]
+    pub const fn gateway(&self) -> &G {
+        &self.gateway
+    }
+
+    /// สร้างคำสั่งซื้อและตัดเงิน - timeout จะถูกลองใหม่ ส่วนการปฏิเสธจะไม่ลองซ้ำ
+    ///
+    /// # Errors
+    ///
+    /// คืน `OrderError::EmptyOrder` ถ้าไม่มีสินค้า, `OrderError::TotalOverflow` ถ้ายอดรวมล้น
+    /// หรือ `OrderError::Payment` ถ้าตัดเงินไม่สำเร็จ
+    pub fn place_order(&mut self, items: &[OrderItem]) -> Result<Order, OrderError> {
+        let total = items
+            .iter()
+            .try_fold(0_u64, |total, item| {
+                u64::from(item.quantity)
+                    .checked_mul(item.unit_price)
+                    .and_then(|subtotal| total.checked_add(subtotal))
+            })
+            .ok_or(OrderError::TotalOverflow)?;
+        if total == 0 {
+            return Err(OrderError::EmptyOrder);
+        }
+
+        let id = self.next_id;
+        let request = PaymentRequest {
+            reference: format!("order-{id}"),
+            amount: total,
+        };
+
+        let mut attempt = 1;
+        let receipt = loop {
+            match self.gateway.charge(&request) {
+                Err(PaymentError::Timeout) if attempt < self.max_attempts => attempt += 1,
+                result => break result?,
+            }
+        };
+
+        self.next_id += 1;
+        let order = Order {
+            id,
+            total,
+            status: OrderStatus::Paid {
+                transaction_id: receipt.transaction_id,
+            },
+        };
+        self.orders.insert(id, order.clone());
+        Ok(order)
+    }
+
+    /// ยกเลิกคำสั่งซื้อและคืนเงิน
+    ///
+    /// # Errors
+    ///
+    /// คืน `OrderError` ถ้าไม่พบคำสั่งซื้อ, คืนเงินไปแล้ว, หรือ gateway คืนเงินไม่สำเร็จ
+    pub fn cancel_order(&mut self, id: u64) -> Result<(), OrderError> {
+        let order = self.orders.get_mut(&id).ok_or(OrderError::NotFound(id))?;
+        let OrderStatus::Paid { transaction_id } = &order.status else {
+            return Err(OrderError::AlreadyRefunded(id));
+        };
+
+        self.gateway.refund(transaction_id, order.total)?;
+        order.status = OrderStatus::Refunded;
+        Ok(())
+    }
+
+    /// ดูคำสั่งซื้อตาม id
+    #[must_use]
+    pub fn order(&self, id: u64) -> Option<&Order> {
+        self.orders.get(&id)
+    }
+}
+
+/// 🪆 Stub - ตอบผลลัพธ์เดิมทุกครั้ง
+#[derive(Debug, Clone)]
+pub struct StubGateway {
+    charge_result: Result<PaymentReceipt, PaymentError>,
+}
+
+impl StubGateway {
+    /// stub ที่อนุมัติทุกรายการ
+    #[must_use]
+    pub fn approving() -> Self {
+        Self {
+            charge_result: Ok(PaymentReceipt {
+                transaction_id: "stub-tx".to_string(),
+                amount: 0,
+            }),
+        }
+    }
+
+    /// stub ที่ปฏิเสธทุกรายการด้วยเหตุผลที่กำหนด
+    #[must_use]
+    pub fn declining(reason: &str) -> Self {
+        Self {
+            charge_result: Err(PaymentError::Declined(reason.to_string())),
+        }
+    }
+}
+
+impl PaymentGateway for StubGateway {
+    fn charge(&self, request: &PaymentRequest) -> Result<PaymentReceipt, PaymentError> {
+        self.charge_result.clone().map(|receipt| PaymentReceipt {
+            amount: request.amount,
+            ..receipt
+        })
+    }
+
+    fn refund(&self, _transaction_id: &str, _amount: u64) -> Result<(), PaymentError> {
+        Ok(())
+    }
+}
+
+/// 📝 การเรียก gateway หนึ่งครั้งที่ spy จดไว้
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GatewayCall {
+    Charge(PaymentRequest),
+    Refund { transaction_id: String, amount: u64 },
+}
+
+/// 🕵️ Spy - อนุมัติทุกรายการ และจดทุกการเรียกไว้
+///
+/// trait รับ `&self` จึงใช้ `RefCell` เพื่อจดบันทึก (interior mutability)
+#[derive(Debug, Default)]
+pub struct SpyGateway {
+    calls: RefCell<Vec<GatewayCall>>,
+    next_transaction: Cell<u32>,
+}
+
+impl SpyGateway {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// การเรียกทั้งหมดตามลำดับ
+    #[must_use]
+    pub fn calls(&self) -> Vec<GatewayCall> {
+        self.calls.borrow().clone()
+    }
+
+    /// จำนวนครั้งที่ถูกเรียก `charge`
+    #[must_use]
+    pub fn charge_count(&self) -> usize {
+        self.calls
+            .borrow()
+            .iter()
+            .filter(|call| matches!(call, GatewayCall::Charge(_)))
+            .count()
+    }
+}
+
+impl PaymentGateway for SpyGateway {
+    fn charge(&self, request: &PaymentRequest) -> Result<PaymentReceipt, PaymentError> {
+        self.calls
+            .borrow_mut()
+            .push(GatewayCall::Charge(request.clone()));
+        let number = self.next_transaction.get() + 1;
+        self.next_transaction.set(number);
+        Ok(PaymentReceipt {
+            transaction_id: format!("spy-tx-{number}"),
+            amount: request.amount,
+        })
+    }
+
+    fn refund(&self, transaction_id: &str, amount: u64) -> Result<(), PaymentError> {
+        self.calls.borrow_mut().push(GatewayCall::Refund {
+            transaction_id: transaction_id.to_string(),
+            amount,
+        });
+        Ok(())
+    }
+}
+
+/// 🎬 บทหนึ่งบรรทัดที่ mock คาดว่าจะถูกเรียก
+#[derive(Debug, Clone)]
+enum Expectation {
+    Charge {
+        amount: u64,
+        result: Result<PaymentReceipt, PaymentError>,
+    },
+    Refund {
+        transaction_id: String,
+        amount: u64,
+        result: Result<(), PaymentError>,
+    },
+}
+
+/// 🎬 Mock - รู้บทล่วงหน้า ถูกเรียกผิดบทจะจดไว้แล้วตอบ error
+///
+/// บทต้องถูกเรียกตามลำดับที่ `expect_*` ไว้ (strict mock)
+#[derive(Debug, Default)]
+pub struct MockGateway {
+    expectations: RefCell<VecDeque<Expectation>>,
+    failures: RefCell<Vec<String>>,
+}
+
+impl MockGateway {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// คาดว่าจะมีการตัดเงิน `amount` และให้ตอบ `result`
+    #[must_use]
+    pub fn expect_charge(self, amount: u64, result: Result<PaymentReceipt, PaymentError>) -> Self {
+        self.expectations
+            .borrow_mut()
+            .push_back(Expectation::Charge { amount, result });
+        self
+    }
+
+    /// ทางลัดของ `expect_charge` ที่ตอบสำเร็จด้วย transaction id ที่กำหนด
+    #[must_use]
+    pub fn expect_charge_ok(self, amount: u64, transaction_id: &str) -> Self {
+        let receipt = PaymentReceipt {
+            transaction_id: transaction_id.to_string(),
+            amount,
+        };
+        self.expect_charge(amount, Ok(receipt))
+    }
+
+    /// คาดว่าจะมีการคืนเงินของ `transaction_id` จำนวน `amount`
+    #[must_use]
+    pub fn expect_refund(
+        self,
+        transaction_id: &str,
+        amount: u64,
+        result: Result<(), PaymentError>,
+    ) -> Self {
+        self.expectations
+            .borrow_mut()
+            .push_back(Expectation::Refund {
+                transaction_id: transaction_id.to_string(),
+                amount,
+                result,
+            });
+        self
+    }
+
+    /// ตรวจว่าเล่นตามบทครบ: ไม่มีการเรียกผิดบท และไม่มีบทที่ค้างอยู่
+    ///
+    /// # Errors
+    ///
+    /// คืนรายการปัญหาทั้งหมดที่พบ
+    pub fn verify(&self) -> Result<(), Vec<String>> {
+        let mut problems = self.failures.borrow().clone();
+        problems.extend(
+            self.expectations
+                .borrow()
+                .iter()
+                .map(|expectation| format!("expected call never happened: {expectation:?}")),
+        );
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(problems)
+        }
+    }
+
+    fn unexpected(&self, call: String) -> PaymentError {
+        self.failures.borrow_mut().push(call.clone());
+        PaymentError::Declined(call)
+    }
+}
+
+impl PaymentGateway for MockGateway {
+    fn charge(&self, request: &PaymentRequest) -> Result<PaymentReceipt, PaymentError> {
+        let next = self.expectations.borrow_mut().pop_front();
+        match next {
+            Some(Expectation::Charge { amount, result }) if amount == request.amount => result,
+            other => Err(self.unexpected(format!(
+                "unexpected charge of {} (expected {other:?})",
+                request.amount
+            ))),
+        }
+    }
+
+    fn refund(&self, transaction_id: &str, amount: u64) -> Result<(), PaymentError> {
+        let next = self.expectations.borrow_mut().pop_front();
+        match next {
+            Some(Expectation::Refund {
+                transaction_id: expected_id,
+                amount: expected_amount,
+                result,
+            }) if expected_id == transaction_id && expected_amount == amount => result,
+            other => Err(self.unexpected(format!(
+                "unexpected refund of {amount} for {transaction_id} (expected {other:?})"
+            ))),
+        }
+    }
+}
+
+/// ตัวอย่างการใช้ test doubles - ส่งนักแสดงแทนขึ้นเวที! 🎭
+pub fn mocking_examples() {
+    println!("🎭 Test Doubles - ตัวแสดงแทน payment gateway");
+    let cart = [
+        OrderItem::new("ชาไทย", 2, 4_500),
+        OrderItem::new("ข้าวเหนียวมะม่วง", 1, 8_000),
+    ];
+
+    let mut service = OrderService::new(StubGateway::approving());
+    println!("🪆 Stub อนุมัติทุกอย่าง: {:?}", service.place_order(&cart));
+
+    let mut service = OrderService::new(StubGateway::declining("บัตรหมดอายุ"));
+    match service.place_order(&cart) {
+        Ok(order) => println!("🪆 Stub ปฏิเสธ: ได้ {order:?} (ไม่ควรเกิด!)"),
+        Err(error) => println!("🪆 Stub ปฏิเสธ: {error}"),
+    }
+
+    let mut service = OrderService::new(SpyGateway::new());
+    let _ = service.place_order(&cart);
+    let _ = service.place_order(&[]);
+    println!(
+        "🕵️ Spy จดไว้ {} การเรียก: {:?}",
+        service.gateway().charge_count(),
+        service.gateway().calls()
+    );
+
+    let mock = MockGateway::new()
+        .expect_charge(17_000, Err(PaymentError::Timeout))
+        .expect_charge_ok(17_000, "tx-42");
+    let mut service = OrderService::new(mock);
+    println!(
+        "🎬 Mock (timeout แล้วลองใหม่): {:?}",
+        service.place_order(&cart)
+    );
+    println!("🎬 verify(): {:?}", service.gateway().verify());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cart() -> Vec<OrderItem> {
+        vec![
+            OrderItem::new("coffee", 2, 6_000),
+            OrderItem::new("cake", 1, 9_500),
+        ]
+    }
+
+    #[test]
+    fn test_stub_approving_places_order() {
+        let mut service = OrderService::new(StubGateway::approving());
+        let order = service.place_order(&cart()).unwrap();
+
+        assert_eq!(order.total, 21_500);
+        assert_eq!(service.order(order.id), Some(&order));
+    }
+
+    #[test]
+    fn test_stub_declining_surfaces_payment_error() {
+        let mut service = OrderService::new(StubGateway::declining("insufficient funds"));
+        let error = service.place_order(&cart()).unwrap_err();
+
+        assert_eq!(
+            error,
+            OrderError::Payment(PaymentError::Declined("insufficient funds".to_string()))
+        );
+        assert_eq!(service.order(1), None);
+    }
+
+    #[test]
+    fn test_spy_records_charge_and_refund() {
+        let mut service = OrderService::new(SpyGateway::new());
+        let order = service.place_order(&cart()).unwrap();
+        service.cancel_order(order.id).unwrap();
+
+        assert_eq!(
+            service.gateway().calls(),
+            vec![
+                GatewayCall::Charge(PaymentRequest {
+                    reference: "order-1".to_string(),
+                    amount: 21_500,
+                }),
+                GatewayCall::Refund {
+                    transaction_id: "spy-tx-1".to_string(),
+                    amount: 21_500,
+                },
+            ]
+        );
+        assert_eq!(
+            service.order(order.id).unwrap().status,
+            OrderStatus::Refunded
+        );
+    }
+
+    #[test]
+    fn test_spy_sees_no_charge_for_empty_order() {
+        let mut service = OrderService::new(SpyGateway::new());
+
+        assert_eq!(service.place_order(&[]), Err(OrderError::EmptyOrder));
+        assert_eq!(service.gateway().charge_count(), 0);
+    }
+
+    #[test]
+    fn test_overflowing_total_is_rejected_before_charging() {
+        let mut service = OrderService::new(SpyGateway::new());
+        let too_pricey = [OrderItem::new("yacht", 2, u64::MAX / 2 + 1)];
+        let too_many = [
+            OrderItem::new("gold", 1, u64::MAX),
+            OrderItem::new("gum", 1, 1),
+        ];
+
+        assert_eq!(
+            service.place_order(&too_pricey),
+            Err(OrderError::TotalOverflow)
+        );
+        assert_eq!(
+            service.place_order(&too_many),
+            Err(OrderError::TotalOverflow)
+        );
+        assert_eq!(service.gateway().charge_count(), 0);
+    }
+
+    #[test]
+    fn test_mock_retries_once_after_timeout() {
+        let mock = MockGateway::new()
+            .expect_charge(21_500, Err(PaymentError::Timeout))
+            .expect_charge_ok(21_500, "tx-1")
+            .expect_refund("tx-1", 21_500, Ok(()));
+        let mut service = OrderService::new(mock);
+
+        let order = service.place_order(&cart()).unwrap();
+        service.cancel_order(order.id).unwrap();
+        assert_eq!(
+            service.cancel_order(order.id),
+            Err(OrderError::AlreadyRefunded(order.id))
+        );
+
+        assert_eq!(service.gateway().verify(), Ok(()));
+    }
+
+    #[test]
+    fn test_mock_does_not_retry_declined_payment() {
+        let mock = MockGateway::new()
+            .expect_charge(21_500, Err(PaymentError::Declined("fraud".to_string())));
+        let mut service = OrderService::new(mock);
+
+        assert!(service.place_order(&cart()).is_err());
+        assert_eq!(service.gateway().verify(), Ok(()));
+    }
+
+    #[test]
+    fn test_mock_verify_reports_unmet_and_unexpected_calls() {
+        let mock = MockGateway::new().expect_charge_ok(999, "tx-1");
+        let mut service = OrderService::new(mock);
+        assert!(service.place_order(&cart()).is_err());
+
+        let problems = service.gateway().verify().unwrap_err();
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].contains("unexpected charge of 21500"));
+
+        let untouched = MockGateway::new().expect_refund("tx-9", 100, Ok(()));
+        assert!(untouched.verify().unwrap_err()[0].contains("never happened"));
+    }
+}
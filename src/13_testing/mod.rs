@@ -20,6 +20,10 @@
 pub mod basic_testing;        // 🔬 ห้องทดลองพื้นฐาน
 pub mod calculator_testing;   // 🧮 ห้องทดลองเครื่องคิดเลข
 pub mod performance_testing;  // ⚡ ห้องทดลองประสิทธิภาพ
+pub mod property_testing;     // 🎲 ห้องทดลองสุ่มหลักฐาน
+pub mod mocking;              // 🎭 ห้องทดลองนักแสดงแทน
+pub mod fuzzing;              // 💥 ห้องทดลองยิงข้อมูลขยะ
+pub mod snapshot_testing;     // 📸 ห้องถ่ายรูปหลักฐาน
 pub mod repository_testing;   // 📚 ห้องทดลองคลังข้อมูล
 pub mod user_testing;         // 👤 ห้องทดลองผู้ใช้
 
@@ -27,6 +31,10 @@ pub mod user_testing;         // 👤 ห้องทดลองผู้ใช
 pub use basic_testing::*;        // 🔬 เครื่องมือพื้นฐาน
 pub use calculator_testing::*;   // 🧮 เครื่องมือคำนวณ
 pub use performance_testing::*;  // ⚡ เครื่องมือวัดประสิทธิภาพ
+pub use property_testing::*;     // 🎲 เครื่องมือสุ่มหลักฐาน
+pub use mocking::*;              // 🎭 เครื่องมือตัวแสดงแทน
+pub use fuzzing::*;              // 💥 เครื่องมือยิงข้อมูลขยะ
+pub use snapshot_testing::*;     // 📸 เครื่องมือถ่ายรูปหลักฐาน
 pub use repository_testing::*;   // 📚 เครื่องมือจัดการข้อมูล
 pub use user_testing::*;         // 👤 เครื่องมือทดสอบผู้ใช้
 
@@ -39,6 +47,10 @@ pub use user_testing::*;         // 👤 เครื่องมือทดส
 // 👤 ตัวอย่าง User struct ➜ user_testing.rs
 // 📚 ตัวอย่าง UserRepository ➜ repository_testing.rs
 // ⚡ ตัวอย่าง Performance testing ➜ performance_testing.rs
+// 🎲 ตัวอย่าง Property-based testing ➜ property_testing.rs
+// 🎭 ตัวอย่าง Mocking และ test doubles ➜ mocking.rs
+// 💥 ตัวอย่าง Fuzzing parser ➜ fuzzing.rs
+// 📸 ตัวอย่าง Snapshot/golden file testing ➜ snapshot_testing.rs
 
 /// ตัวอย่างการใช้งาน testing - เริ่มการสืบสวนโค้ด! 🕵️‍♂️🔍
 pub fn run_testing_examples() {
@@ -65,6 +77,22 @@ pub fn run_testing_examples() {
 
     println!("⚡ === เข้าสู่ห้องทดลองประสิทธิภาพ === ⚡");
     performance_testing::performance_testing_examples();
+    println!();
+
+    println!("🎲 === เข้าสู่ห้องทดลองสุ่มหลักฐาน === 🎲");
+    property_testing::property_testing_examples();
+    println!();
+
+    println!("🎭 === เข้าสู่ห้องทดลองนักแสดงแทน === 🎭");
+    mocking::mocking_examples();
+    println!();
+
+    println!("💥 === เข้าสู่ห้องทดลองยิงข้อมูลขยะ === 💥");
+    fuzzing::fuzzing_examples();
+    println!();
+
+    println!("📸 === เข้าสู่ห้องถ่ายรูปหลักฐาน === 📸");
+    snapshot_testing::snapshot_testing_examples();
 
     println!("\n🎉✨ จบบทที่ 13: Testing - การสืบสวนสำเร็จ! ✨🎉");
     println!("🏆 ยินดีด้วย! คุณได้เป็นนักสืบโค้ดมืออาชีพแล้ว! 🕵️‍♂️🎓");
@@ -74,6 +102,10 @@ pub fn run_testing_examples() {
     println!("   📋 การจัดระเบียบ tests - จัดระเบียบการทดสอบ");
     println!("   ✅ การใช้ assertions ต่างๆ - ตรวจสอบความถูกต้อง");
     println!("   🏗️ การทดสอบ structs และ methods - ทดสอบโครงสร้างข้อมูล");
+    println!("   🎲 Property-based testing และ shrinking - ให้เครื่องหาหลักฐานเอง");
+    println!("   🎭 Stub, spy และ mock - ตัวแสดงแทนสำหรับ dependency ภายนอก");
+    println!("   💥 Fuzzing - parser ต้องไม่ panic ไม่ว่าจะเจอ input แบบไหน");
+    println!("   📸 Snapshot testing - เทียบ output ทั้งก้อนกับ golden file");
     println!("\n🎯 คุณพร้อมแล้วที่จะสืบสวนและทดสอบโค้ดใดๆ! 🔬✨");
 }
 
@@ -0,0 +1,336 @@
+//! # Snapshot Testing - ถ่ายรูปหลักฐานเก็บไว้เทียบ! 📸🕵️‍♂️
+//!
+//! ทุกบทในโปรเจคนี้ `println!` เยอะมาก การเขียน `assert_eq!` ทีละบรรทัดคงไม่ไหว
+//! Snapshot testing (หรือ golden file testing) เลยใช้วิธี:
+//! - 📝 จับ output ทั้งหมดของ demo ผ่าน writer ที่เก็บข้อความไว้
+//! - 📸 เทียบกับไฟล์ `.snap` ที่ commit ไว้ใน repo
+//! - ✅ ถ้าตั้งใจเปลี่ยน output ให้รัน `UPDATE_SNAPSHOTS=1 cargo test` เพื่อ "bless" ไฟล์ใหม่
+//!
+//! 🎯 demo ที่อยากทดสอบแบบนี้ต้องเขียนลง `&mut dyn Write` แทน `println!` ตรงๆ
+//! (ดู `basic_testing` และ `calculator_testing` เป็นตัวอย่าง)
+
+use std::fmt;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+/// ชื่อ environment variable ที่สั่งให้เขียนทับ golden files
+pub const UPDATE_SNAPSHOTS_ENV: &str = "UPDATE_SNAPSHOTS";
+
+/// 📝 writer ที่เก็บทุกอย่างที่เขียนลงมาไว้ในหน่วยความจำ
+#[derive(Debug, Default, Clone)]
+pub struct CaptureWriter {
+    buffer: Vec<u8>,
+}
+
+impl CaptureWriter {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { buffer: Vec::new() }
+    }
+
+    /// ข้อความที่จับได้ (byte ที่ไม่ใช่ UTF-8 จะถูกแทนด้วย �)
+    #[must_use]
+    pub fn contents(&self) -> String {
+        String::from_utf8_lossy(&self.buffer).into_owned()
+    }
+}
+
+impl Write for CaptureWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.buffer.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// 📝 รัน demo ที่เขียนลง writer แล้วคืน output ทั้งหมดเป็น `String`
+///
+/// # Errors
+///
+/// ส่งต่อ error ที่ demo คืนมา
+pub fn capture_output<F>(demo: F) -> io::Result<String>
+where
+    F: FnOnce(&mut dyn Write) -> io::Result<()>,
+{
+    let mut writer = CaptureWriter::new();
+    demo(&mut writer)?;
+    Ok(writer.contents())
+}
+
+/// 🖨️ รัน demo เดียวกันแล้วส่ง output ให้ `output::Printer` ปัจจุบัน - ใช้ในฟังก์ชัน `*_examples()`
+///
+/// ไม่เขียน stdout ตรง ๆ เพื่อให้ `--quiet` และ `output::with_printer` ปิด/เก็บข้อความได้เหมือนบทอื่น
+pub fn print_output<F>(demo: F)
+where
+    F: FnOnce(&mut dyn Write) -> io::Result<()>,
+{
+    let mut writer = CaptureWriter::new();
+    let result = demo(&mut writer);
+    print!("{}", writer.contents());
+    if let Err(error) = result {
+        eprintln!("⚠️ เขียน output ไม่สำเร็จ: {error}");
+    }
+}
+
+/// ผลของการเทียบ snapshot
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnapshotOutcome {
+    /// output ตรงกับ golden file
+    Matched,
+    /// ยังไม่มี golden file และได้สร้างใหม่แล้ว (โหมด bless)
+    Created,
+    /// output ไม่ตรงและได้เขียนทับ golden file แล้ว (โหมด bless)
+    Updated,
+}
+
+/// ❌ ข้อผิดพลาดของ snapshot
+#[derive(Debug)]
+pub enum SnapshotError {
+    /// ยังไม่มี golden file
+    Missing { path: PathBuf },
+    /// output ไม่ตรงกับ golden file
+    Mismatch { name: String, diff: String },
+    /// อ่าน/เขียนไฟล์ไม่สำเร็จ
+    Io(io::Error),
+}
+
+impl fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing { path } => write!(
+                f,
+                "snapshot {} does not exist; run with {UPDATE_SNAPSHOTS_ENV}=1 to create it",
+                path.display()
+            ),
+            Self::Mismatch { name, diff } => write!(
+                f,
+                "snapshot '{name}' does not match (run with {UPDATE_SNAPSHOTS_ENV}=1 to accept):\n{diff}"
+            ),
+            Self::Io(error) => write!(f, "snapshot I/O error: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for SnapshotError {}
+
+impl From<io::Error> for SnapshotError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+/// 📸 คลัง golden files ในโฟลเดอร์หนึ่ง
+#[derive(Debug, Clone)]
+pub struct SnapshotStore {
+    dir: PathBuf,
+    bless: bool,
+}
+
+impl SnapshotStore {
+    /// สร้างคลังที่โฟลเดอร์ `dir` - โหมด bless อ่านจาก `UPDATE_SNAPSHOTS`
+    #[must_use]
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        let bless = std::env::var(UPDATE_SNAPSHOTS_ENV).is_ok_and(|value| value != "0");
+        Self {
+            dir: dir.into(),
+            bless,
+        }
+    }
+
+    /// คลังของบทที่ 13 ที่ `src/13_testing/snapshots`
+    #[must_use]
+    pub fn chapter() -> Self {
+        Self::new(Path::new(env!("CARGO_MANIFEST_DIR")).join("src/13_testing/snapshots"))
+    }
+
+    /// บังคับเปิด/ปิดโหมด bless (ไม่สนใจ environment variable)
+    #[must_use]
+    pub const fn with_bless(mut self, bless: bool) -> Self {
+        self.bless = bless;
+        self
+    }
+
+    /// path ของ golden file ชื่อ `name`
+    #[must_use]
+    pub fn path_for(&self, name: &str) -> PathBuf {
+        self.dir.join(format!("{name}.snap"))
+    }
+
+    /// เทียบ `actual` กับ golden file ชื่อ `name`
+    ///
+    /// # Errors
+    ///
+    /// คืน `Missing` หรือ `Mismatch` เมื่อไม่ได้อยู่ในโหมด bless และคืน `Io` เมื่ออ่าน/เขียนไฟล์ไม่ได้
+    pub fn check(&self, name: &str, actual: &str) -> Result<SnapshotOutcome, SnapshotError> {
+        let path = self.path_for(name);
+        let actual = normalize(actual);
+
+        let expected = match std::fs::read_to_string(&path) {
+            Ok(contents) => Some(normalize(&contents)),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => None,
+            Err(error) => return Err(error.into()),
+        };
+
+        match expected {
+            Some(expected) if expected == actual => Ok(SnapshotOutcome::Matched),
+            Some(expected) => {
+                if self.bless {
+                    std::fs::write(&path, actual)?;
+                    Ok(SnapshotOutcome::Updated)
+                } else {
+                    Err(SnapshotError::Mismatch {
+                        name: name.to_string(),
+                        diff: line_diff(&expected, &actual),
+                    })
+                }
+            }
+            None if self.bless => {
+                std::fs::create_dir_all(&self.dir)?;
+                std::fs::write(&path, actual)?;
+                Ok(SnapshotOutcome::Created)
+            }
+            None => Err(SnapshotError::Missing { path }),
+        }
+    }
+
+    /// เหมือน `check` แต่ panic พร้อม diff - ใช้ใน `#[test]`
+    ///
+    /// # Panics
+    ///
+    /// Panics เมื่อ snapshot ไม่ตรง ไม่มีไฟล์ หรืออ่าน/เขียนไฟล์ไม่ได้
+    pub fn assert_matches(&self, name: &str, actual: &str) {
+        if let Err(error) = self.check(name, actual) {
+            panic!("{error}");
+        }
+    }
+}
+
+/// ตัด `\r` และช่องว่างท้ายบรรทัดทิ้ง ให้ golden file ไม่พังเพราะ editor หรือ OS
+fn normalize(text: &str) -> String {
+    let mut normalized = String::with_capacity(text.len() + 1);
+    for line in text.lines() {
+        normalized.push_str(line.trim_end());
+        normalized.push('\n');
+    }
+    while normalized.ends_with("\n\n") {
+        normalized.pop();
+    }
+    normalized
+}
+
+/// diff แบบบรรทัดต่อบรรทัด: `-` คือที่คาดไว้ `+` คือที่ได้จริง
+fn line_diff(expected: &str, actual: &str) -> String {
+    use std::fmt::Write as _;
+
+    let expected: Vec<&str> = expected.lines().collect();
+    let actual: Vec<&str> = actual.lines().collect();
+    let mut diff = String::new();
+
+    for index in 0..expected.len().max(actual.len()) {
+        let (old, new) = (expected.get(index), actual.get(index));
+        if old == new {
+            continue;
+        }
+        if let Some(old) = old {
+            let _ = writeln!(diff, "{:>4} - {old}", index + 1);
+        }
+        if let Some(new) = new {
+            let _ = writeln!(diff, "{:>4} + {new}", index + 1);
+        }
+    }
+    diff
+}
+
+/// ตัวอย่างการใช้ snapshot testing - ถ่ายรูปหลักฐานก่อนเทียบ! 📸
+pub fn snapshot_testing_examples() {
+    println!("📸 Snapshot testing - เทียบ output ทั้งก้อนกับ golden file");
+
+    let demo = |out: &mut dyn Write| -> io::Result<()> {
+        writeln!(out, "🧮 2 + 2 = {}", 2 + 2)?;
+        writeln!(out, "🕵️‍♂️ ผู้ต้องสงสัย: 3 คน")
+    };
+    match capture_output(demo) {
+        Ok(captured) => println!(
+            "   📝 จับ output ได้ {} บรรทัด:\n{captured}",
+            captured.lines().count()
+        ),
+        Err(error) => println!("   ❌ จับ output ไม่ได้: {error}"),
+    }
+
+    let expected = "line 1\nline 2\nline 3\n";
+    let actual = "line 1\nline two\nline 3\n";
+    println!(
+        "   🔍 diff เมื่อ output เปลี่ยน:\n{}",
+        line_diff(expected, actual)
+    );
+
+    let store = SnapshotStore::chapter();
+    println!(
+        "   📂 golden files อยู่ที่: {}",
+        store.path_for("<ชื่อ>").display()
+    );
+    println!("   ✅ ตั้งใจเปลี่ยน output? รัน {UPDATE_SNAPSHOTS_ENV}=1 cargo test");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_capture_output_collects_writes() {
+        let captured = capture_output(|out| {
+            write!(out, "hello ")?;
+            writeln!(out, "world")
+        })
+        .unwrap();
+        assert_eq!(captured, "hello world\n");
+    }
+
+    #[test]
+    fn test_snapshot_lifecycle() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = SnapshotStore::new(dir.path()).with_bless(false);
+
+        assert!(matches!(
+            store.check("demo", "a\nb\n"),
+            Err(SnapshotError::Missing { .. })
+        ));
+
+        let blessing = store.clone().with_bless(true);
+        assert_eq!(
+            blessing.check("demo", "a\nb\n").unwrap(),
+            SnapshotOutcome::Created
+        );
+        assert_eq!(
+            store.check("demo", "a  \r\nb").unwrap(),
+            SnapshotOutcome::Matched
+        );
+
+        match store.check("demo", "a\nc\n") {
+            Err(SnapshotError::Mismatch { name, diff }) => {
+                assert_eq!(name, "demo");
+                assert_eq!(diff, "   2 - b\n   2 + c\n");
+            }
+            other => panic!("expected mismatch, got {other:?}"),
+        }
+
+        assert_eq!(
+            blessing.check("demo", "a\nc\n").unwrap(),
+            SnapshotOutcome::Updated
+        );
+        assert_eq!(
+            store.check("demo", "a\nc\n").unwrap(),
+            SnapshotOutcome::Matched
+        );
+    }
+
+    #[test]
+    fn test_line_diff_reports_added_and_removed_lines() {
+        assert_eq!(line_diff("a\n", "a\nb\n"), "   2 + b\n");
+        assert_eq!(line_diff("a\nb\n", "a\n"), "   2 - b\n");
+        assert_eq!(line_diff("same\n", "same\n"), "");
+    }
+}
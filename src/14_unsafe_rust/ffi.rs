@@ -4,10 +4,26 @@
 //! - การเรียกใช้ C functions - การติดต่อกับสายลับต่างชาติ! 📞🇨
 //! - การส่งออก Rust functions ให้ C - การส่งสายลับไปต่างประเทศ! 🚀🦀
 //! - ตัวอย่างการทำงานกับ C libraries - การร่วมมือกับองค์กรต่างชาติ! 🤝🌐
+//! - ไลบรารี C ของเราเอง (`csrc/crc32.c`) ที่ `build.rs` คอมไพล์ด้วย `cc` - สายลับ C ที่ฝึกเอง! 🇨🔧
+//! - C เรียกกลับเข้า Rust ผ่าน function pointer - สายลับสองทาง! 🔁
+//!
+//! 📄 ฟังก์ชันที่ติด `#[unsafe(no_mangle)]` ในไฟล์นี้ประกาศไว้ใน `include/rust_concepts.h`
+//! สร้างใหม่ได้ด้วย `cbindgen --config cbindgen.toml --output include/rust_concepts.h`
 
 use std::ffi::{CStr, CString};
 use std::os::raw::c_char;
 
+// ฟังก์ชันจาก csrc/crc32.c ที่ build.rs คอมไพล์และลิงก์ให้ - สายลับ C ที่เราฝึกเอง! 🇨🔧
+// (build.rs ข้าม C บน wasm32 ส่วนที่เรียกไลบรารีนี้จึงมีเฉพาะบน native)
+#[cfg(not(target_arch = "wasm32"))]
+unsafe extern "C" {
+    fn rc_crc32(data: *const u8, len: usize) -> u32;
+    fn rc_fold_i32(values: *const i32, len: usize, init: i32, op: CFoldOp) -> i32;
+}
+
+/// ชนิดของ callback ที่ C เรียกกลับเข้ามา (`rc_binary_op` ใน `csrc/crc32.h`) 🔁
+pub type CFoldOp = extern "C" fn(i32, i32) -> i32;
+
 // การเรียกใช้ C standard library functions - การติดต่อกับหน่วยงานต่างชาติ! 📞🇨
 unsafe extern "C" {
     fn abs(input: i32) -> i32;    // 🔢 สายลับคำนวณค่าสัมบูรณ์
@@ -87,6 +103,84 @@ pub fn c_string_examples() {
     println!("💡🔐 C strings ใช้ null terminator (\\0) เป็นสัญญาณจบข้อความลับ!");
 }
 
+/// CRC-32 (IEEE) ที่เขียนด้วย Rust ล้วน - ใช้เทียบกับเวอร์ชัน C 🦀
+#[must_use]
+pub fn crc32_ieee(bytes: &[u8]) -> u32 {
+    let mut crc = u32::MAX;
+    for &byte in bytes {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            crc = (crc >> 1) ^ (0xEDB8_8320 & (crc & 1).wrapping_neg());
+        }
+    }
+    !crc
+}
+
+/// CRC-32 ที่คำนวณโดยโค้ด C ใน `csrc/crc32.c` - safe wrapper รอบ `unsafe` call 🇨
+#[cfg(not(target_arch = "wasm32"))]
+#[must_use]
+pub fn crc32_via_c(bytes: &[u8]) -> u32 {
+    // SAFETY: pointer กับความยาวมาจาก slice เดียวกัน และ C อ่านอย่างเดียว
+    unsafe { rc_crc32(bytes.as_ptr(), bytes.len()) }
+}
+
+/// ให้ C วนพับ `values` โดยเรียก `op` (ฟังก์ชัน Rust) กลับมาทีละตัว 🔁
+#[cfg(not(target_arch = "wasm32"))]
+#[must_use]
+pub fn fold_via_c(values: &[i32], init: i32, op: CFoldOp) -> i32 {
+    // SAFETY: slice ถูกต้องตลอดการเรียก และ `op` เป็น `extern "C" fn` ที่ไม่ unwind ข้าม C
+    unsafe { rc_fold_i32(values.as_ptr(), values.len(), init, op) }
+}
+
+/// CRC-32 ที่ส่งออกให้ C - สายลับ Rust รับงานตรวจความถูกต้อง! 🦀🔐
+///
+/// # Safety
+///
+/// `data` ต้องชี้ไปยังหน่วยความจำที่อ่านได้ `len` ไบต์ (เป็น null ได้เมื่อ `len` เป็น 0)
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rust_crc32(data: *const u8, len: usize) -> u32 {
+    if len == 0 {
+        return crc32_ieee(&[]);
+    }
+    // SAFETY: ผู้เรียกรับประกันตาม `# Safety` ด้านบน
+    let bytes = unsafe { std::slice::from_raw_parts(data, len) };
+    crc32_ieee(bytes)
+}
+
+/// callback ที่ส่งให้ C: คืนค่าที่มากกว่า 🔝
+#[cfg(not(target_arch = "wasm32"))]
+extern "C" fn max_i32(acc: i32, value: i32) -> i32 {
+    acc.max(value)
+}
+
+/// ตัวอย่างไลบรารี C ที่ build.rs คอมไพล์ให้ - ปฏิบัติการร่วมสองทาง! 🇨🤝🦀
+#[cfg(not(target_arch = "wasm32"))]
+pub fn c_library_examples() {
+    println!("\n🇨🔧 === Home-grown C Agents (csrc/crc32.c) === 🔁🦀");
+
+    let message = b"123456789";
+    println!(
+        "🔐 Rust -> C: crc32(\"123456789\") = {:#010x}",
+        crc32_via_c(message)
+    );
+    println!(
+        "🦀 Rust ล้วน:  crc32(\"123456789\") = {:#010x} (ต้องตรงกัน!)",
+        crc32_ieee(message)
+    );
+
+    let values = [3, -7, 12, 5];
+    println!(
+        "🔁 C -> Rust: rc_fold_i32({values:?}, rust_add) = {}",
+        fold_via_c(&values, 0, rust_add)
+    );
+    println!(
+        "🔁 C -> Rust: rc_fold_i32({values:?}, max_i32) = {}",
+        fold_via_c(&values, i32::MIN, max_i32)
+    );
+
+    println!("📄 C ที่อยากเรียก Rust ให้ #include \"include/rust_concepts.h\" แล้วลิงก์กับ crate นี้");
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -120,4 +214,45 @@ mod tests {
         let null_length = rust_string_length(std::ptr::null());
         assert_eq!(null_length, 0);  // ✅ ไม่มีข้อความลับ
     }
+
+    #[test]
+    fn test_rust_calls_bundled_c_library() {  // 🧪 Rust -> C
+        assert_eq!(crc32_via_c(b"123456789"), 0xCBF4_3926);  // ค่ามาตรฐานของ CRC-32
+        assert_eq!(crc32_via_c(b""), 0);
+
+        let data: Vec<u8> = (0..=255).collect();
+        assert_eq!(crc32_via_c(&data), crc32_ieee(&data));
+        assert_eq!(unsafe { rust_crc32(data.as_ptr(), data.len()) }, crc32_ieee(&data));
+        assert_eq!(unsafe { rust_crc32(std::ptr::null(), 0) }, 0);
+    }
+
+    #[test]
+    fn test_c_calls_back_into_rust() {  // 🧪 C -> Rust
+        assert_eq!(fold_via_c(&[1, 2, 3, 4], 10, rust_add), 20);
+        assert_eq!(fold_via_c(&[3, -7, 12, 5], i32::MIN, max_i32), 12);
+        assert_eq!(fold_via_c(&[], 42, rust_add), 42);
+    }
+
+    #[test]
+    fn test_header_declares_every_exported_function() {  // 🧪 header ต้องตามโค้ดทัน
+        let header = include_str!("../../include/rust_concepts.h");
+        let source = include_str!("ffi.rs");
+        let mut lines = source.lines();
+        let mut exported = Vec::new();
+        while let Some(line) = lines.next() {
+            if line.trim() != "#[unsafe(no_mangle)]" {
+                continue;
+            }
+            // ข้าม attribute อื่นๆ จนเจอบรรทัดที่ประกาศ fn
+            if let Some(signature) = lines.by_ref().find(|line| line.contains("fn ")) {
+                let after_fn = &signature[signature.find("fn ").unwrap_or(0) + 3..];
+                exported.extend(after_fn.split('(').next());
+            }
+        }
+
+        assert!(exported.len() >= 4);
+        for name in exported {
+            assert!(header.contains(&format!(" {name}(")), "{name} missing from rust_concepts.h");
+        }
+    }
 }
\ No newline at end of file
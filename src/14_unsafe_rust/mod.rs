@@ -12,6 +12,7 @@
 pub mod ffi;
 pub mod inline_assembly;
 pub mod raw_pointers;
+pub mod safe_handle;
 pub mod union_transmute;
 pub mod unsafe_functions;
 pub mod unsafe_traits;
@@ -20,6 +21,7 @@ pub mod unsafe_traits;
 pub use ffi::*;
 pub use inline_assembly::*;
 pub use raw_pointers::*;
+pub use safe_handle::*;
 pub use union_transmute::*;
 pub use unsafe_functions::*;
 pub use unsafe_traits::*;
@@ -29,7 +31,8 @@ pub use unsafe_traits::*;
 // - unsafe_functions.rs: การสร้างและใช้งาน unsafe functions
 // - unsafe_traits.rs: unsafe traits และ custom smart pointers
 // - ffi.rs: Foreign Function Interface
-// - union_transmute.rs: Union และ transmute operations
+// - safe_handle.rs: กรณีศึกษา safe wrapper (RAII) รอบ handle แบบ C
+// - union_transmute.rs: Union, transmute และ memory map ของ union
 // - inline_assembly.rs: Inline assembly และ performance optimization
 
 // ฟังก์ชันเหล่านี้ถูกย้ายไปยังไฟล์ย่อยแล้ว:
@@ -53,8 +56,12 @@ pub fn run_unsafe_examples() {
     custom_smart_pointer_examples();
     union_examples();
     data_conversion_examples();
+    union_layout_examples();
     ffi_examples();
     c_string_examples();
+    #[cfg(not(target_arch = "wasm32"))]
+    c_library_examples();
+    safe_handle_examples();
     transmute_examples();
     transmute_copy_examples();
     inline_assembly_examples();
@@ -66,6 +73,7 @@ pub fn run_unsafe_examples() {
     println!("   - การสร้างและเรียกใช้ unsafe functions");
     println!("   - การสร้าง unsafe traits และ custom smart pointers");
     println!("   - การใช้ FFI เพื่อเชื่อมต่อกับ C libraries");
+    println!("   - การห่อ handle แบบ C ด้วย safe wrapper (RAII, Send/Sync)");
     println!("   - การใช้ union และ transmute operations");
     println!("   - การเข้าใจ inline assembly และ performance optimization");
     println!("\n🛡️ จำไว้: \"With great power comes great responsibility\"");
@@ -94,6 +102,8 @@ mod tests {
         // ทดสอบ FFI
         ffi_examples();
         c_string_examples();
+        c_library_examples();
+        safe_handle_examples();
 
         // ทดสอบ union และ transmute
         union_examples();
@@ -117,6 +127,7 @@ mod tests {
     // - unsafe_functions.rs มี test_slice_from_raw_parts และ test_safe_multiply_by_two
     // - unsafe_traits.rs มี test_my_box และ test_unsafe_trait
     // - ffi.rs มี test_ffi_functions, test_rust_exported_functions และ test_c_string_length
+    // - safe_handle.rs มี tests ที่รันผ่าน Miri ได้ (`cargo +nightly miri test --lib safe_handle`)
     // - union_transmute.rs มี test_union, test_transmute, test_data_converter และ test_transmute_copy
     // - inline_assembly.rs มี test_assembly_examples
 }
@@ -0,0 +1,514 @@
+//! Safe Wrapper รอบ Handle แบบ C - กรณีศึกษา RAII ฉบับเต็ม! 🛡️🗝️
+//!
+//! ไลบรารี C ส่วนใหญ่ให้ "handle" เป็น pointer ทึบ พร้อมกฎที่เขียนไว้แค่ในเอกสาร:
+//! ต้องปิดทุกครั้ง ห้ามใช้หลังปิด ห้ามใช้พร้อมกันหลาย thread และคืน error เป็นตัวเลข
+//! ไฟล์นี้จำลอง C API แบบนั้น (`lib_open` / `lib_read` / `lib_peek` / `lib_close`) ด้วย raw pointers
+//! แล้วห่อด้วย `Handle` ที่ให้ compiler บังคับกฎทั้งหมดแทนเรา:
+//! - 🔒 ปิดอัตโนมัติใน `Drop` (หรือ `close()` เพื่อดู error) - ลืมปิดหรือปิดซ้ำไม่ได้
+//! - ⏳ slice จาก `peek()` ยืม handle อยู่ จึง read/close ระหว่างที่ยังใช้ slice ไม่ได้
+//! - 🧵 `Send` แต่ไม่ `Sync` - ย้ายไป thread อื่นได้ แต่แชร์ใช้พร้อมกันไม่ได้
+//! - 🚨 error code ถูกแปลงเป็น `LibError` ที่บอกว่าเกิดอะไรขึ้นกับไฟล์ไหน
+//!
+//! 🔬 tests ในไฟล์นี้ตั้งใจให้ผ่าน Miri (ตรวจ UB, use-after-free, leak):
+//! `cargo +nightly miri test --lib safe_handle`
+//!
+//! ```compile_fail
+//! use rust_concepts::unsafe_rust::safe_handle::Handle;
+//!
+//! let mut handle = Handle::open("greeting.txt").unwrap();
+//! let peeked = handle.peek();
+//! drop(handle); // ❌ ยังยืม handle ผ่าน `peeked` อยู่
+//! println!("{peeked:?}");
+//! ```
+
+use std::ffi::{CString, c_int};
+use std::fmt;
+use std::marker::PhantomData;
+use std::ptr::{self, NonNull};
+
+/// 🇨 C API จำลอง - ทุกฟังก์ชันเป็น `extern "C"` และทำงานกับ raw pointers แบบที่ C ทำ
+///
+/// "ไฟล์" เป็นข้อมูลในหน่วยความจำจากตาราง `FILES` เพื่อให้ตัวอย่างไม่ขึ้นกับระบบไฟล์จริง
+pub mod fake_c {
+    use std::ffi::{CStr, c_char, c_int};
+    use std::ptr;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// สำเร็จ
+    pub const LIB_OK: c_int = 0;
+    /// ส่ง pointer เป็น NULL มา
+    pub const LIB_ERR_NULL: c_int = -1;
+    /// ไม่มีไฟล์ชื่อนี้
+    pub const LIB_ERR_NOT_FOUND: c_int = -2;
+    /// มีไฟล์แต่เปิดไม่ได้
+    pub const LIB_ERR_PERMISSION: c_int = -3;
+    /// handle ไม่ใช่ของไลบรารีนี้ หรือถูกปิดไปแล้ว
+    pub const LIB_ERR_BAD_HANDLE: c_int = -4;
+
+    /// ค่าที่ใช้จับ handle ปลอม/ที่ปิดแล้ว (C library จริงหลายตัวทำแบบนี้)
+    const MAGIC_OPEN: u32 = 0x0F11_E0BE;
+
+    /// ตาราง "ไฟล์": ชื่อ, เนื้อหา, อ่านได้หรือไม่
+    const FILES: &[(&str, &[u8], bool)] = &[
+        ("greeting.txt", "สวัสดีจาก C API จำลอง! 👋".as_bytes(), true),
+        ("numbers.csv", b"1,2,3,5,8,13,21", true),
+        ("empty.txt", b"", true),
+        ("secret.key", b"top secret", false),
+    ];
+
+    static OPEN_HANDLES: AtomicUsize = AtomicUsize::new(0);
+
+    /// handle ทึบ - ฝั่ง C เห็นแค่ `LibFile*`
+    #[repr(C)]
+    pub struct LibFile {
+        magic: u32,
+        data: *const u8,
+        len: usize,
+        pos: usize,
+    }
+
+    /// เปิดไฟล์ `name` แล้วเขียน handle ลง `*out`
+    ///
+    /// # Safety
+    ///
+    /// `name` ต้องเป็น C string ที่ถูกต้อง (หรือ NULL) และ `out` ต้องเขียนได้ (หรือ NULL)
+    #[must_use]
+    pub unsafe extern "C" fn lib_open(name: *const c_char, out: *mut *mut LibFile) -> c_int {
+        if name.is_null() || out.is_null() {
+            return LIB_ERR_NULL;
+        }
+        // SAFETY: ผู้เรียกรับประกันว่า `name` เป็น C string ที่ถูกต้อง
+        let name = unsafe { CStr::from_ptr(name) }.to_bytes();
+        let Some(&(_, contents, readable)) =
+            FILES.iter().find(|(file, _, _)| file.as_bytes() == name)
+        else {
+            return LIB_ERR_NOT_FOUND;
+        };
+        if !readable {
+            return LIB_ERR_PERMISSION;
+        }
+
+        let file = Box::new(LibFile {
+            magic: MAGIC_OPEN,
+            data: contents.as_ptr(),
+            len: contents.len(),
+            pos: 0,
+        });
+        OPEN_HANDLES.fetch_add(1, Ordering::SeqCst);
+        // SAFETY: ผู้เรียกรับประกันว่า `out` เขียนได้
+        unsafe { out.write(Box::into_raw(file)) };
+        LIB_OK
+    }
+
+    /// อ่านไม่เกิน `capacity` ไบต์ลง `buffer` แล้วเขียนจำนวนที่อ่านได้ลง `*read` (0 = จบไฟล์)
+    ///
+    /// # Safety
+    ///
+    /// `handle` ต้องมาจาก `lib_open` และยังไม่ถูกปิด, `buffer` ต้องเขียนได้ `capacity` ไบต์
+    /// และ `read` ต้องเขียนได้ ห้ามเรียกพร้อมกันหลาย thread ด้วย handle เดียวกัน
+    #[must_use]
+    pub unsafe extern "C" fn lib_read(
+        handle: *mut LibFile,
+        buffer: *mut u8,
+        capacity: usize,
+        read: *mut usize,
+    ) -> c_int {
+        if handle.is_null() || buffer.is_null() || read.is_null() {
+            return LIB_ERR_NULL;
+        }
+        // SAFETY: ผู้เรียกรับประกันว่า handle ยังเปิดอยู่และไม่มีใครใช้พร้อมกัน
+        let file = unsafe { &mut *handle };
+        if file.magic != MAGIC_OPEN {
+            return LIB_ERR_BAD_HANDLE;
+        }
+
+        let count = capacity.min(file.len - file.pos);
+        // SAFETY: `pos + count <= len` และ buffer เขียนได้ `capacity >= count` ไบต์
+        unsafe {
+            ptr::copy_nonoverlapping(file.data.add(file.pos), buffer, count);
+            read.write(count);
+        }
+        file.pos += count;
+        LIB_OK
+    }
+
+    /// ชี้ `*data` ไปยังข้อมูลที่ยังไม่ได้อ่าน (ไม่ copy) และเขียนความยาวลง `*len`
+    ///
+    /// pointer ที่ได้ใช้ได้จนกว่าจะเรียก `lib_read` หรือ `lib_close` กับ handle นี้
+    ///
+    /// # Safety
+    ///
+    /// `handle` ต้องมาจาก `lib_open` และยังไม่ถูกปิด, `data` และ `len` ต้องเขียนได้
+    #[must_use]
+    pub const unsafe extern "C" fn lib_peek(
+        handle: *const LibFile,
+        data: *mut *const u8,
+        len: *mut usize,
+    ) -> c_int {
+        if handle.is_null() || data.is_null() || len.is_null() {
+            return LIB_ERR_NULL;
+        }
+        // SAFETY: ผู้เรียกรับประกันว่า handle ยังเปิดอยู่
+        let file = unsafe { &*handle };
+        if file.magic != MAGIC_OPEN {
+            return LIB_ERR_BAD_HANDLE;
+        }
+        // SAFETY: `pos <= len` จึงชี้อยู่ในข้อมูลเดิม และ out-pointers เขียนได้
+        unsafe {
+            data.write(file.data.add(file.pos));
+            len.write(file.len - file.pos);
+        }
+        LIB_OK
+    }
+
+    /// ปิด handle และคืนหน่วยความจำ - ห้ามใช้ handle อีกหลังจากนี้
+    ///
+    /// # Safety
+    ///
+    /// `handle` ต้องมาจาก `lib_open` และยังไม่ถูกปิด (ปิดซ้ำ = double free)
+    #[must_use]
+    pub unsafe extern "C" fn lib_close(handle: *mut LibFile) -> c_int {
+        if handle.is_null() {
+            return LIB_ERR_NULL;
+        }
+        // SAFETY: handle มาจาก `Box::into_raw` ใน `lib_open` และยังไม่ถูกปิด
+        let mut file = unsafe { Box::from_raw(handle) };
+        if file.magic != MAGIC_OPEN {
+            // ไม่ใช่ของเรา - ห้าม free
+            std::mem::forget(file);
+            return LIB_ERR_BAD_HANDLE;
+        }
+        file.magic = 0;
+        OPEN_HANDLES.fetch_sub(1, Ordering::SeqCst);
+        LIB_OK
+    }
+
+    /// จำนวน handle ที่เปิดค้างอยู่ทั้งโปรแกรม - ใช้ตรวจ leak
+    #[must_use]
+    pub fn lib_open_handles() -> usize {
+        OPEN_HANDLES.load(Ordering::SeqCst)
+    }
+
+    /// ข้อความอธิบาย error code (แบบ `strerror`)
+    #[must_use]
+    pub const fn lib_strerror(code: c_int) -> &'static CStr {
+        match code {
+            LIB_OK => c"ok",
+            LIB_ERR_NULL => c"null pointer argument",
+            LIB_ERR_NOT_FOUND => c"no such file",
+            LIB_ERR_PERMISSION => c"permission denied",
+            LIB_ERR_BAD_HANDLE => c"invalid or closed handle",
+            _ => c"unknown error",
+        }
+    }
+}
+
+use fake_c::{
+    LIB_ERR_BAD_HANDLE, LIB_ERR_NOT_FOUND, LIB_ERR_NULL, LIB_ERR_PERMISSION, LIB_OK, LibFile,
+};
+
+/// 🚨 error ของ `Handle` - แปลงจาก error code ตัวเลขให้มีความหมาย
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LibError {
+    /// ชื่อไฟล์มี NUL byte อยู่ข้างใน ส่งเป็น C string ไม่ได้
+    InvalidName(String),
+    /// ไม่มีไฟล์ชื่อนี้
+    NotFound(String),
+    /// มีไฟล์แต่ไม่มีสิทธิ์เปิด
+    PermissionDenied(String),
+    /// handle ใช้ไม่ได้ - ถ้าเห็น error นี้จาก `Handle` แปลว่า wrapper มีบั๊ก
+    BadHandle,
+    /// code ที่ไลบรารีไม่ได้ระบุไว้ (รวม NULL pointer ที่ wrapper ไม่ควรส่งไป)
+    Unknown(c_int),
+}
+
+impl LibError {
+    /// แปลง error code ของ C เป็น `LibError` (`name` ใช้ประกอบข้อความ)
+    fn from_code(code: c_int, name: &str) -> Self {
+        match code {
+            LIB_ERR_NOT_FOUND => Self::NotFound(name.to_string()),
+            LIB_ERR_PERMISSION => Self::PermissionDenied(name.to_string()),
+            LIB_ERR_BAD_HANDLE => Self::BadHandle,
+            other => Self::Unknown(other),
+        }
+    }
+}
+
+impl fmt::Display for LibError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidName(name) => write!(f, "file name {name:?} contains a NUL byte"),
+            Self::NotFound(name) => write!(f, "{name}: no such file"),
+            Self::PermissionDenied(name) => write!(f, "{name}: permission denied"),
+            Self::BadHandle => f.write_str("invalid or closed handle"),
+            Self::Unknown(code) => {
+                let message = fake_c::lib_strerror(*code).to_string_lossy();
+                write!(f, "library error {code}: {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for LibError {}
+
+/// 🛡️ handle ที่ปลอดภัย - เป็นเจ้าของ `LibFile*` หนึ่งตัวตลอดชีวิตของมัน
+pub struct Handle {
+    raw: NonNull<LibFile>,
+    name: String,
+    /// ทำให้ `Handle` ไม่ `Sync` (raw pointer ทำให้ไม่ `Send` ด้วย - เราเปิด `Send` เองด้านล่าง)
+    _not_sync: PhantomData<*mut LibFile>,
+}
+
+// SAFETY: ไลบรารีไม่ได้ผูก handle กับ thread ที่เปิด (ไม่มี thread-local state)
+// จึงย้าย handle ไป thread อื่นได้ ส่วนการใช้พร้อมกันถูกกันไว้เพราะ `Handle` ไม่ `Sync`
+// และทุกเมธอดที่เปลี่ยนสถานะรับ `&mut self`
+unsafe impl Send for Handle {}
+
+impl Handle {
+    /// เปิดไฟล์ `name`
+    ///
+    /// # Errors
+    ///
+    /// `InvalidName` ถ้าชื่อมี NUL byte, `NotFound` / `PermissionDenied` ตามที่ไลบรารีตอบ
+    pub fn open(name: &str) -> Result<Self, LibError> {
+        let c_name = CString::new(name).map_err(|_| LibError::InvalidName(name.to_string()))?;
+        let mut raw = ptr::null_mut();
+        // SAFETY: `c_name` เป็น C string ที่ถูกต้องตลอดการเรียก และ `raw` เขียนได้
+        let code = unsafe { fake_c::lib_open(c_name.as_ptr(), &raw mut raw) };
+        if code != LIB_OK {
+            return Err(LibError::from_code(code, name));
+        }
+        let raw = NonNull::new(raw).ok_or(LibError::Unknown(LIB_ERR_NULL))?;
+        Ok(Self {
+            raw,
+            name: name.to_string(),
+            _not_sync: PhantomData,
+        })
+    }
+
+    /// ชื่อไฟล์ที่เปิดไว้
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// อ่านลง `buffer` คืนจำนวนไบต์ที่อ่านได้ (0 = จบไฟล์)
+    ///
+    /// # Errors
+    ///
+    /// คืน error ถ้าไลบรารีตอบ error code (ไม่ควรเกิดกับ handle ที่ยังเปิดอยู่)
+    pub fn read(&mut self, buffer: &mut [u8]) -> Result<usize, LibError> {
+        let mut read = 0;
+        // SAFETY: handle ยังเปิดอยู่ (ปิดเฉพาะใน close/drop ที่กิน self),
+        // `&mut self` กันการใช้พร้อมกัน และ buffer เขียนได้ `buffer.len()` ไบต์
+        let code = unsafe {
+            fake_c::lib_read(
+                self.raw.as_ptr(),
+                buffer.as_mut_ptr(),
+                buffer.len(),
+                &raw mut read,
+            )
+        };
+        if code == LIB_OK {
+            Ok(read)
+        } else {
+            Err(LibError::from_code(code, &self.name))
+        }
+    }
+
+    /// อ่านที่เหลือทั้งหมดเป็น `Vec<u8>`
+    ///
+    /// # Errors
+    ///
+    /// ส่งต่อ error จาก `read`
+    pub fn read_to_end(&mut self) -> Result<Vec<u8>, LibError> {
+        let mut contents = Vec::new();
+        let mut chunk = [0_u8; 8];
+        loop {
+            match self.read(&mut chunk)? {
+                0 => return Ok(contents),
+                count => contents.extend_from_slice(&chunk[..count]),
+            }
+        }
+    }
+
+    /// ดูข้อมูลที่ยังไม่ได้อ่านโดยไม่ copy - slice ยืม `self` อยู่
+    /// compiler จึงไม่ยอมให้ `read`/`close`/drop ระหว่างที่ยังใช้ slice นี้ (ตรงกับกฎของ `lib_peek`)
+    #[must_use]
+    pub const fn peek(&self) -> &[u8] {
+        let mut data = ptr::null();
+        let mut len = 0;
+        // SAFETY: handle ยังเปิดอยู่และ out-pointers เขียนได้
+        let code = unsafe { fake_c::lib_peek(self.raw.as_ptr(), &raw mut data, &raw mut len) };
+        if code != LIB_OK || data.is_null() {
+            return &[];
+        }
+        // SAFETY: ไลบรารีรับประกันว่า `data..data+len` ใช้ได้จนกว่าจะ read/close
+        // ซึ่งทำไม่ได้ระหว่างที่ slice ยืม `&self` อยู่
+        unsafe { std::slice::from_raw_parts(data, len) }
+    }
+
+    /// ปิดแบบเห็น error - `Drop` ก็ปิดให้ แต่ต้องทิ้ง error ไปเงียบๆ
+    ///
+    /// # Errors
+    ///
+    /// คืน error code ที่ `lib_close` ตอบ
+    pub fn close(self) -> Result<(), LibError> {
+        let this = std::mem::ManuallyDrop::new(self);
+        // SAFETY: `ManuallyDrop` กันไม่ให้ `Drop` ปิดซ้ำ และหลังจากนี้ไม่มีใครใช้ `raw` อีก
+        let code = unsafe { fake_c::lib_close(this.raw.as_ptr()) };
+        // SAFETY: อ่าน `name` ออกมาครั้งเดียวเพื่อไม่ให้ String รั่ว (`this` ไม่ถูก drop)
+        let name = unsafe { ptr::read(&raw const this.name) };
+        if code == LIB_OK {
+            Ok(())
+        } else {
+            Err(LibError::from_code(code, &name))
+        }
+    }
+}
+
+impl Drop for Handle {
+    fn drop(&mut self) {
+        // SAFETY: handle ยังเปิดอยู่ (close() ใช้ ManuallyDrop จึงไม่มาถึงตรงนี้)
+        let code = unsafe { fake_c::lib_close(self.raw.as_ptr()) };
+        debug_assert_eq!(code, LIB_OK, "lib_close failed in Drop");
+    }
+}
+
+impl fmt::Debug for Handle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Handle")
+            .field("name", &self.name)
+            .field("remaining", &self.peek().len())
+            .finish_non_exhaustive()
+    }
+}
+
+/// ตัวอย่างการใช้ safe wrapper - จาก pointer อันตรายสู่ API ที่ผิดไม่ได้! 🛡️
+pub fn safe_handle_examples() {
+    println!("\n🛡️🗝️ === Safe Wrapper รอบ C Handle === 🗝️🛡️");
+
+    match Handle::open("greeting.txt") {
+        Ok(mut handle) => {
+            println!("📂 เปิด {} แล้ว: {handle:?}", handle.name());
+            let preview: String = String::from_utf8_lossy(handle.peek())
+                .chars()
+                .take(6)
+                .collect();
+            println!("👀 peek (ไม่ copy): {preview}...");
+            match handle.read_to_end() {
+                Ok(bytes) => println!("📖 อ่านได้: {}", String::from_utf8_lossy(&bytes)),
+                Err(error) => println!("❌ {error}"),
+            }
+            println!("🔓 handle ที่ค้าง: {}", fake_c::lib_open_handles());
+            if let Err(error) = handle.close() {
+                println!("❌ {error}");
+            }
+        }
+        Err(error) => println!("❌ {error}"),
+    }
+    println!("🔒 หลัง close() handle ที่ค้าง: {}", fake_c::lib_open_handles());
+
+    for name in ["missing.txt", "secret.key", "bad\0name"] {
+        if let Err(error) = Handle::open(name) {
+            println!("🚨 Handle::open({name:?}) -> {error}");
+        }
+    }
+
+    // ย้าย handle ไป thread อื่นได้เพราะ Handle: Send (แต่แชร์ด้วย Arc ไม่ได้เพราะไม่ Sync)
+    if let Ok(mut handle) = Handle::open("numbers.csv") {
+        let total = std::thread::spawn(move || {
+            let text =
+                String::from_utf8(handle.read_to_end().unwrap_or_default()).unwrap_or_default();
+            text.split(',')
+                .filter_map(|n| n.parse::<u32>().ok())
+                .sum::<u32>()
+        })
+        .join()
+        .unwrap_or_default();
+        println!("🧵 ผลรวมจาก thread อื่น: {total} (handle ถูก drop ใน thread นั้น)");
+    }
+
+    println!("💡 กฎที่เคยอยู่แค่ในเอกสาร C ตอนนี้ compiler เป็นคนตรวจให้แล้ว!");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 🔬 ถ้า `Handle` ลืมปิด (Box ของ `LibFile` รั่ว) หรือปิดซ้ำ Miri จะทำให้ test ล้ม
+
+    #[test]
+    fn test_handle_reads_whole_file_and_closes() {
+        let mut handle = Handle::open("numbers.csv").unwrap();
+        assert_eq!(handle.peek(), b"1,2,3,5,8,13,21");
+
+        let mut first = [0_u8; 4];
+        assert_eq!(handle.read(&mut first), Ok(4));
+        assert_eq!(&first, b"1,2,");
+        assert_eq!(handle.peek(), b"3,5,8,13,21");
+        assert_eq!(handle.read_to_end().unwrap(), b"3,5,8,13,21");
+        assert_eq!(handle.read(&mut first), Ok(0));
+        assert_eq!(handle.close(), Ok(()));
+
+        let mut empty = Handle::open("empty.txt").unwrap();
+        assert!(empty.read_to_end().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_error_codes_map_to_rich_errors() {
+        assert_eq!(
+            Handle::open("missing.txt").unwrap_err(),
+            LibError::NotFound("missing.txt".into())
+        );
+        assert_eq!(
+            Handle::open("secret.key").unwrap_err(),
+            LibError::PermissionDenied("secret.key".into())
+        );
+        assert_eq!(
+            Handle::open("a\0b").unwrap_err(),
+            LibError::InvalidName("a\0b".into())
+        );
+        assert_eq!(
+            LibError::Unknown(-99).to_string(),
+            "library error -99: unknown error"
+        );
+    }
+
+    #[test]
+    fn test_raw_api_rejects_null_pointers() {
+        let mut out = ptr::null_mut();
+        unsafe {
+            assert_eq!(fake_c::lib_open(ptr::null(), &raw mut out), LIB_ERR_NULL);
+            assert_eq!(
+                fake_c::lib_open(c"greeting.txt".as_ptr(), &raw mut out),
+                LIB_OK
+            );
+
+            let mut read = 0;
+            assert_eq!(
+                fake_c::lib_read(out, ptr::null_mut(), 0, &raw mut read),
+                LIB_ERR_NULL
+            );
+            assert_eq!(fake_c::lib_close(out), LIB_OK);
+            assert_eq!(fake_c::lib_close(ptr::null_mut()), LIB_ERR_NULL);
+        }
+    }
+
+    #[test]
+    fn test_handle_is_send_and_drop_closes() {
+        fn assert_send<T: Send>() {}
+        assert_send::<Handle>();
+
+        let handle = Handle::open("greeting.txt").unwrap();
+        assert!(fake_c::lib_open_handles() >= 1);
+
+        let text = std::thread::spawn(move || {
+            let mut handle = handle;
+            String::from_utf8(handle.read_to_end().unwrap()).unwrap()
+        })
+        .join()
+        .unwrap();
+        assert!(text.starts_with("สวัสดี"));
+    }
+}
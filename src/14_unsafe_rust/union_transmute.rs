@@ -6,8 +6,11 @@
 //! - ตัวอย่างการแปลงข้อมูลระดับต่ำ - การทดลองอันตราย! ⚡🔬
 
 use std::mem;
+use std::ptr::NonNull;
 use std::slice;
 
+use crate::layout::{TypeLayout, inspect, layout_of, niche_table};
+
 /// Helper function สำหรับ function pointer transmute - ฟังก์ชันช่วยในการทดลอง! 🧪⚡
 const fn add_one(x: i32) -> i32 {
     x + 1  // 🔢 สูตรเพิ่มค่าลับ
@@ -63,6 +66,32 @@ pub fn data_conversion_examples() {
     }
 }
 
+/// Layout ของ `DataConverter` - ทุกฟิลด์เริ่มที่ offset 0 ทับกันหมด 🗺️
+fn data_converter_layout() -> TypeLayout {
+    layout_of!(DataConverter { bytes: [u8; 4], int: u32, float: f32 })
+}
+
+/// ส่องหน่วยความจำของ union และ pointer - แผนที่ห้องทดลอง! 🗺️🔬
+pub fn union_layout_examples() {
+    println!("\n🗺️🔬 === Memory Map Laboratory === 📐🧪");
+
+    // `*` คือ byte ที่หลายฟิลด์ใช้ร่วมกัน - นี่คือเหตุผลที่อ่าน union ต้อง unsafe! ⚠️
+    print!("{}", data_converter_layout());
+
+    // raw pointer เป็น null ได้ จึงไม่มี niche ส่วน NonNull/reference ไม่มีวันเป็น null 🎯
+    println!("🎯 Option<pointer> - ใครได้ niche ฟรี?");
+    print!(
+        "{}",
+        niche_table(&[
+            inspect::<*const u8>(),
+            inspect::<NonNull<u8>>(),
+            inspect::<&u8>(),
+            inspect::<MyUnion>(),
+        ])
+    );
+    println!("💡🔬 ใช้ Option<NonNull<T>> แทน *const T ที่อาจเป็น null ได้ขนาดเท่าเดิมแต่ปลอดภัยกว่า!");
+}
+
 /// ตัวอย่างการใช้ transmute (อันตรายมาก!) - การเปลี่ยนรูปแบบข้อมูลอันตราย! 🧬💀
 pub fn transmute_examples() {
     println!("\n🧬💀 === Dangerous Transmutation Lab === ⚡🔬");
@@ -160,6 +189,18 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_union_layout() {  // 🧪 ทดสอบแผนที่หน่วยความจำ
+        let layout = data_converter_layout();
+        assert_eq!((layout.size, layout.align), (4, 4));  // ✅ ใหญ่เท่าฟิลด์ที่ใหญ่ที่สุด
+        assert!(layout.fields.iter().all(|field| field.offset == 0));  // ✅ ทับกันหมด
+        assert_eq!(layout.byte_map(), "****");
+
+        assert!(!inspect::<*const u8>().has_niche());  // ❌ null เป็นค่าที่ใช้ได้
+        assert!(inspect::<NonNull<u8>>().has_niche());  // ✅ null ว่างให้ None
+        assert!(!inspect::<MyUnion>().has_niche());  // ❌ union ไม่รู้ว่า byte ไหนว่าง
+    }
+
     #[test]
     fn test_transmute_copy() {  // 🧪 ทดสอบการคัดลอกข้อมูลปลอดภัย
         let x: f32 = std::f32::consts::PI;  // 📊 ข้อมูลต้นฉบับ
@@ -4,9 +4,64 @@
 //! Builder Pattern ช่วยในการสร้าง object ที่ซับซ้อนแบบ step-by-step
 //! เหมาะสำหรับ struct ที่มี field จำนวนมากหรือมี optional parameters
 //! เหมือนการออกแบบสถาปัตยกรรมระบบแบบมืออาชีพ! 🏛️
+//!
+//! 🧱 **Typestate builder:** field ที่จำเป็นถูกติดตามใน type parameter (`Unset` → `Set<T>`)
+//! `build()` มีเฉพาะบน builder ที่ตั้งค่าครบแล้ว - ลืมตั้งค่าเป็น compile error ไม่ใช่ `Err` ตอนรัน
+//! และค่าที่ตั้งแล้วอยู่ใน `Set<T>` เลย `build()` จึงไม่ต้อง `unwrap` และไม่ต้องคืน `Result`
+//!
+//! ```
+//! use rust_concepts::advanced_patterns::builder_pattern::HttpClientConfigBuilder;
+//!
+//! let config = HttpClientConfigBuilder::new()
+//!     .timeout(5) // ลำดับไม่สำคัญ ตั้ง field ที่ไม่บังคับก่อนก็ได้
+//!     .base_url("https://api.example.com")
+//!     .build();
+//! assert_eq!((config.timeout_seconds, config.max_retries), (5, 3));
+//! ```
+//!
+//! ```compile_fail,E0599
+//! use rust_concepts::advanced_patterns::builder_pattern::HttpClientConfigBuilder;
+//!
+//! // ❌ ลืม base_url: `HttpClientConfigBuilder<Unset>` ไม่มีเมธอด `build`
+//! let config = HttpClientConfigBuilder::new().timeout(5).build();
+//! ```
+//!
+//! ```compile_fail,E0599
+//! use rust_concepts::advanced_patterns::builder_pattern::DatabaseConfigBuilder;
+//!
+//! // ❌ ลืม credentials: `build` มีเฉพาะบน `DatabaseConfigBuilder<Set<String>, Set<(String, String)>>`
+//! let config = DatabaseConfigBuilder::new().database("app").build();
+//! ```
+//!
+//! ⚖️ **เทียบกับ `#[derive(Builder)]`** (บทที่ 12, เช่น `macros::ServerSettings`):
+//! - 🏃 derive: ไม่ต้องเขียน builder เองเลย แต่ field ที่ขาดรู้ตอนรัน (`Err("missing field `host`")`)
+//! - 🧱 typestate: เขียนเองและมี generic หนึ่งตัวต่อ field บังคับ แต่ลืม field = คอมไพล์ไม่ผ่าน
+//! - 😵 ราคาของ typestate: error message เป็นชื่อ type แทนชื่อ field และตั้งค่าแบบมีเงื่อนไข
+//!   ไม่ได้ตรงๆ เพราะ setter ของ field บังคับเปลี่ยน type ของ builder
+//!
+//! ```compile_fail,E0308
+//! use rust_concepts::advanced_patterns::builder_pattern::HttpClientConfigBuilder;
+//!
+//! let staging = true;
+//! let mut builder = HttpClientConfigBuilder::new();
+//! if staging {
+//!     // ❌ `HttpClientConfigBuilder<Set<String>>` กับ `HttpClientConfigBuilder<Unset>` เป็นคนละ type
+//!     builder = builder.base_url("https://staging.example.com");
+//! }
+//! ```
 
 use std::collections::HashMap;
 
+use crate::macros::ServerSettings;
+
+/// ⬜ field ที่จำเป็นแต่ยังไม่ได้ตั้งค่า
+#[derive(Debug, Clone, Copy)]
+pub struct Unset;
+
+/// ✅ field ที่จำเป็นและตั้งค่าแล้ว - เก็บค่าไว้ข้างในเลย ไม่ต้องใช้ `Option`
+#[derive(Debug, Clone)]
+pub struct Set<T>(T);
+
 /// 📡 HTTP Client Configuration Blueprint - แบบแปลนการเชื่อมต่อเครือข่าย
 #[derive(Debug, Clone)]
 pub struct HttpClientConfig {
@@ -20,9 +75,11 @@ pub struct HttpClientConfig {
 }
 
 /// 🔧 HTTP Client Configuration Architect - สถาปนิกการกำหนดค่าเครือข่าย
-#[derive(Debug, Default)]
-pub struct HttpClientConfigBuilder {
-    base_url: Option<String>,
+///
+/// `Url` คือสถานะของ `base_url`: เริ่มที่ `Unset` และกลายเป็น `Set<String>` หลังเรียก `base_url()`
+#[derive(Debug)]
+pub struct HttpClientConfigBuilder<Url = Unset> {
+    base_url: Url,
     timeout_seconds: Option<u64>,
     max_retries: Option<u32>,
     headers: HashMap<String, String>,
@@ -35,14 +92,37 @@ impl HttpClientConfigBuilder {
     /// 🎯 เริ่มต้นการออกแบบ Configuration - Start architectural design
     #[must_use]
     pub fn new() -> Self {
-        Self::default()
+        Self {
+            base_url: Unset,
+            timeout_seconds: None,
+            max_retries: None,
+            headers: HashMap::new(),
+            user_agent: None,
+            follow_redirects: None,
+            verify_ssl: None,
+        }
+    }
+}
+
+impl Default for HttpClientConfigBuilder {
+    fn default() -> Self {
+        Self::new()
     }
+}
 
-    /// 🌐 กำหนด Base URL - วางรากฐานการเชื่อมต่อ
+impl<Url> HttpClientConfigBuilder<Url> {
+    /// 🌐 กำหนด Base URL - วางรากฐานการเชื่อมต่อ (field บังคับ: เปลี่ยน type ของ builder)
     #[must_use]
-    pub fn base_url<S: Into<String>>(mut self, url: S) -> Self {
-        self.base_url = Some(url.into());
-        self
+    pub fn base_url<S: Into<String>>(self, url: S) -> HttpClientConfigBuilder<Set<String>> {
+        HttpClientConfigBuilder {
+            base_url: Set(url.into()),
+            timeout_seconds: self.timeout_seconds,
+            max_retries: self.max_retries,
+            headers: self.headers,
+            user_agent: self.user_agent,
+            follow_redirects: self.follow_redirects,
+            verify_ssl: self.verify_ssl,
+        }
     }
 
     /// ⏱️ กำหนด Timeout - ตั้งเวลาการรอคอย
@@ -86,16 +166,15 @@ impl HttpClientConfigBuilder {
         self.verify_ssl = Some(verify);
         self
     }
+}
 
-    /// 🏗️ สร้าง `HttpClientConfig` จาก Blueprint - Build from architectural design
-    /// 
-    /// # Errors
-    /// 
-    /// Returns error ถ้า required fields ไม่ได้ถูกกำหนด
-    pub fn build(self) -> Result<HttpClientConfig, String> {
-        let base_url = self.base_url.ok_or("base_url is required")?;
-        
-        Ok(HttpClientConfig {
+impl HttpClientConfigBuilder<Set<String>> {
+    /// 🏗️ สร้าง `HttpClientConfig` จาก Blueprint - มีให้เรียกเฉพาะเมื่อตั้ง `base_url` แล้ว
+    #[must_use]
+    pub fn build(self) -> HttpClientConfig {
+        let Set(base_url) = self.base_url;
+
+        HttpClientConfig {
             base_url,
             timeout_seconds: self.timeout_seconds.unwrap_or(30),
             max_retries: self.max_retries.unwrap_or(3),
@@ -103,7 +182,7 @@ impl HttpClientConfigBuilder {
             user_agent: self.user_agent.unwrap_or_else(|| "RustClient/1.0".to_string()),
             follow_redirects: self.follow_redirects.unwrap_or(true),
             verify_ssl: self.verify_ssl.unwrap_or(true),
-        })
+        }
     }
 }
 
@@ -129,13 +208,14 @@ pub enum SslMode {
 }
 
 /// 🏗️ Database Configuration Architect - สถาปนิกการกำหนดค่าฐานข้อมูล
+///
+/// field บังคับสองตัวจึงมี type parameter สองตัว: `Db` (ชื่อฐานข้อมูล) และ `Creds` (username/password)
 #[derive(Debug)]
-pub struct DatabaseConfigBuilder {
+pub struct DatabaseConfigBuilder<Db = Unset, Creds = Unset> {
     host: Option<String>,
     port: Option<u16>,
-    database: Option<String>,
-    username: Option<String>,
-    password: Option<String>,
+    database: Db,
+    credentials: Creds,
     pool_size: Option<u32>,
     connection_timeout: Option<u64>,
     ssl_mode: Option<SslMode>,
@@ -148,14 +228,32 @@ impl DatabaseConfigBuilder {
         Self {
             host: None,
             port: None,
-            database: None,
-            username: None,
-            password: None,
+            database: Unset,
+            credentials: Unset,
             pool_size: None,
             connection_timeout: None,
             ssl_mode: None,
         }
     }
+}
+
+impl<Db, Creds> DatabaseConfigBuilder<Db, Creds> {
+    /// ย้ายทุก field ไปยัง builder ที่ field บังคับอยู่ในสถานะใหม่
+    fn map_required<NextDb, NextCreds>(
+        self,
+        f: impl FnOnce(Db, Creds) -> (NextDb, NextCreds),
+    ) -> DatabaseConfigBuilder<NextDb, NextCreds> {
+        let (database, credentials) = f(self.database, self.credentials);
+        DatabaseConfigBuilder {
+            host: self.host,
+            port: self.port,
+            database,
+            credentials,
+            pool_size: self.pool_size,
+            connection_timeout: self.connection_timeout,
+            ssl_mode: self.ssl_mode,
+        }
+    }
 
     /// 🏠 กำหนด Host - ระบุที่อยู่เซิร์ฟเวอร์
     #[must_use]
@@ -171,19 +269,20 @@ impl DatabaseConfigBuilder {
         self
     }
 
-    /// 📚 กำหนด Database - เลือกฐานข้อมูลที่ต้องการ
+    /// 📚 กำหนด Database - เลือกฐานข้อมูลที่ต้องการ (field บังคับ)
     #[must_use]
-    pub fn database<S: Into<String>>(mut self, db: S) -> Self {
-        self.database = Some(db.into());
-        self
+    pub fn database<S: Into<String>>(self, db: S) -> DatabaseConfigBuilder<Set<String>, Creds> {
+        self.map_required(|_, credentials| (Set(db.into()), credentials))
     }
 
-    /// 🔑 กำหนด Credentials - ระบุข้อมูลการเข้าสู่ระบบ
+    /// 🔑 กำหนด Credentials - ระบุข้อมูลการเข้าสู่ระบบ (field บังคับ)
     #[must_use]
-    pub fn credentials<U: Into<String>, P: Into<String>>(mut self, username: U, password: P) -> Self {
-        self.username = Some(username.into());
-        self.password = Some(password.into());
-        self
+    pub fn credentials<U: Into<String>, P: Into<String>>(
+        self,
+        username: U,
+        password: P,
+    ) -> DatabaseConfigBuilder<Db, Set<(String, String)>> {
+        self.map_required(|database, _| (database, Set((username.into(), password.into()))))
     }
 
     /// 🏊 กำหนด Pool Size - ขนาดสระการเชื่อมต่อ
@@ -206,23 +305,25 @@ impl DatabaseConfigBuilder {
         self.ssl_mode = Some(mode);
         self
     }
+}
 
-    /// 🏗️ สร้าง `DatabaseConfig` จาก Blueprint - Build from database design
-    /// 
-    /// # Errors
-    /// 
-    /// Returns error ถ้า required fields ไม่ได้ถูกกำหนด
-    pub fn build(self) -> Result<DatabaseConfig, String> {
-        Ok(DatabaseConfig {
+impl DatabaseConfigBuilder<Set<String>, Set<(String, String)>> {
+    /// 🏗️ สร้าง `DatabaseConfig` จาก Blueprint - มีให้เรียกเฉพาะเมื่อตั้ง database และ credentials แล้ว
+    #[must_use]
+    pub fn build(self) -> DatabaseConfig {
+        let Set(database) = self.database;
+        let Set((username, password)) = self.credentials;
+
+        DatabaseConfig {
             host: self.host.unwrap_or_else(|| "localhost".to_string()),
             port: self.port.unwrap_or(5432),
-            database: self.database.ok_or("database name is required")?,
-            username: self.username.ok_or("username is required")?,
-            password: self.password.ok_or("password is required")?,
+            database,
+            username,
+            password,
             pool_size: self.pool_size.unwrap_or(10),
             connection_timeout: self.connection_timeout.unwrap_or(30),
             ssl_mode: self.ssl_mode.unwrap_or(SslMode::Prefer),
-        })
+        }
     }
 }
 
@@ -233,10 +334,6 @@ impl Default for DatabaseConfigBuilder {
 }
 
 /// 🎭 สาธิต Configuration Architect Workshop - Builder Pattern Demonstration
-///
-/// # Panics
-/// 
-/// ฟังก์ชันนี้จะ panic หากไม่สามารถสร้าง HTTP config หรือ DB config ได้
 pub fn demonstrate_builder() {
     println!("🏗️ Configuration Architect Workshop - Builder Pattern Examples:");
     
@@ -252,8 +349,7 @@ pub fn demonstrate_builder() {
         .user_agent("MyApp/2.0")
         .follow_redirects(true)
         .verify_ssl(true)
-        .build()
-        .expect("Failed to build HTTP config");
+        .build();
     
     println!("📋 HTTP Configuration Blueprint: {http_config:#?}");
     
@@ -268,22 +364,19 @@ pub fn demonstrate_builder() {
         .pool_size(20)
         .connection_timeout(45)
         .ssl_mode(SslMode::Require)
-        .build()
-        .expect("Failed to build DB config");
+        .build();
     
     println!("📊 Database Configuration Blueprint: {db_config:#?}");
     
-    // Error handling example
-    println!("\n❌ Architectural Validation Example:");
-    
-    let invalid_config = HttpClientConfigBuilder::new()
-        .timeout(30)
-        .build();
-    
-    match invalid_config {
-        Ok(config) => println!("✅ Configuration Blueprint Created: {config:?}"),
-        Err(e) => println!("🚫 Blueprint Validation Failed: {e}"),
+    // Typestate vs #[derive(Builder)]
+    println!("\n⚖️ Typestate vs #[derive(Builder)] - ลืม field บังคับแล้วรู้ตัวเมื่อไหร่?");
+
+    match ServerSettings::builder().host("localhost").build() {
+        Ok(settings) => println!("  🏃 derive(Builder): {settings:?}"),
+        Err(e) => println!("  🏃 derive(Builder) รู้ตอนรัน: {e}"),
     }
+    println!("  🧱 typestate รู้ตอนคอมไพล์: `HttpClientConfigBuilder<Unset>` ไม่มีเมธอด build()");
+    println!("  📝 แลกกับการเขียน builder เองและ generic หนึ่งตัวต่อ field บังคับ");
     
     println!("\n💡 Configuration Architect Workshop Benefits:");
     println!("  • 📖 Readable และ maintainable architectural design");
@@ -303,8 +396,7 @@ mod tests {
         let config = HttpClientConfigBuilder::new()
             .base_url("https://test.com")
             .timeout(10)
-            .build()
-            .unwrap();
+            .build();
         
         assert_eq!(config.base_url, "https://test.com");
         assert_eq!(config.timeout_seconds, 10);
@@ -317,8 +409,7 @@ mod tests {
         let config = DatabaseConfigBuilder::new()
             .database("test_db")
             .credentials("user", "pass")
-            .build()
-            .unwrap();
+            .build();
         
         assert_eq!(config.database, "test_db");
         assert_eq!(config.username, "user");
@@ -327,12 +418,25 @@ mod tests {
 
     #[test]
     fn test_architectural_validation() {
-        // 🧪 ทดสอบการตรวจสอบความถูกต้องของ Blueprint
-        let result = HttpClientConfigBuilder::new()
-            .timeout(30)
+        // 🧪 field บังคับที่ขาดเป็น compile error (ดู doctest compile_fail ของโมดูล)
+        // ที่นี่ตรวจว่าตั้ง field บังคับลำดับไหนก็ได้ และตั้งซ้ำได้โดยค่าหลังสุดชนะ
+        let config = DatabaseConfigBuilder::new()
+            .credentials("user", "pass")
+            .pool_size(2)
+            .database("first")
+            .database("second")
             .build();
-        
-        assert!(result.is_err());
-        assert!(result.unwrap_err().contains("base_url is required"));
+
+        assert_eq!(config.database, "second");
+        assert_eq!((config.username.as_str(), config.password.as_str()), ("user", "pass"));
+        assert_eq!(config.pool_size, 2);
+
+        let config = HttpClientConfigBuilder::default()
+            .verify_ssl(false)
+            .base_url("https://a.example")
+            .base_url("https://b.example")
+            .build();
+        assert_eq!(config.base_url, "https://b.example");
+        assert!(!config.verify_ssl);
     }
 }
\ No newline at end of file
@@ -2,7 +2,11 @@
 //!
 //! การใช้งาน Command Pattern ใน Rust
 
+use std::any::Any;
 use std::collections::VecDeque;
+use std::fmt;
+
+use crate::game_development::game_logic::{Inventory, InventoryItem, ItemType};
 
 /// Command trait
 trait Command {
@@ -131,6 +135,428 @@ impl TextEditor {
     }
 }
 
+/// ↩️ คำสั่งที่แก้ไข `T` ได้จริงและย้อนกลับได้ - ใช้กับ `CommandHistory`
+pub trait UndoableCommand<T>: Any {
+    /// ทำคำสั่ง (ถูกเรียกอีกครั้งตอน redo)
+    ///
+    /// # Errors
+    ///
+    /// คืนเหตุผลถ้าทำไม่ได้ - `target` ต้องไม่ถูกแก้ไขในกรณีนี้
+    fn execute(&mut self, target: &mut T) -> Result<(), String>;
+
+    /// ย้อนสิ่งที่ `execute` ทำ
+    ///
+    /// # Errors
+    ///
+    /// คืนเหตุผลถ้าย้อนไม่ได้
+    fn undo(&mut self, target: &mut T) -> Result<(), String>;
+
+    fn description(&self) -> String;
+
+    /// ลองรวม `next` (ที่ execute ไปแล้ว) เข้ามาเป็นคำสั่งเดียว - คืน `true` ถ้ารวมได้
+    fn merge(&mut self, _next: &dyn Any) -> bool {
+        false
+    }
+}
+
+/// ❌ ข้อผิดพลาดของ `CommandHistory`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HistoryError {
+    NothingToUndo,
+    NothingToRedo,
+    Failed(String),
+}
+
+impl fmt::Display for HistoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NothingToUndo => f.write_str("nothing to undo"),
+            Self::NothingToRedo => f.write_str("nothing to redo"),
+            Self::Failed(reason) => write!(f, "command failed: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for HistoryError {}
+
+/// 📚 ประวัติคำสั่งแบบ undo/redo ที่จำกัดจำนวน และรวมคำสั่งที่ต่อเนื่องกันได้
+///
+/// - คำสั่งใหม่จะล้าง redo ทิ้ง (อนาคตเดิมไม่มีอยู่แล้ว)
+/// - เกิน `capacity` จะลืมคำสั่งที่เก่าที่สุด
+/// - หลัง `undo`/`redo`/`seal` คำสั่งถัดไปจะไม่ถูกรวมกับของเดิม
+pub struct CommandHistory<T> {
+    done: VecDeque<Box<dyn UndoableCommand<T>>>,
+    undone: Vec<Box<dyn UndoableCommand<T>>>,
+    capacity: usize,
+    merge_open: bool,
+}
+
+impl<T: 'static> fmt::Debug for CommandHistory<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CommandHistory")
+            .field("undo", &self.undo_descriptions())
+            .field("redo", &self.undone.len())
+            .field("capacity", &self.capacity)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T: 'static> CommandHistory<T> {
+    /// ประวัติที่จำได้สูงสุด `capacity` คำสั่ง (อย่างน้อย 1)
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            done: VecDeque::new(),
+            undone: Vec::new(),
+            capacity: capacity.max(1),
+            merge_open: false,
+        }
+    }
+
+    /// ทำคำสั่งกับ `target` แล้วจดไว้ (หรือรวมเข้ากับคำสั่งล่าสุด)
+    ///
+    /// # Errors
+    ///
+    /// คืน `HistoryError::Failed` ถ้าคำสั่งทำไม่สำเร็จ - ประวัติไม่เปลี่ยน
+    pub fn execute<C: UndoableCommand<T>>(
+        &mut self,
+        target: &mut T,
+        mut command: C,
+    ) -> Result<(), HistoryError> {
+        command.execute(target).map_err(HistoryError::Failed)?;
+        self.undone.clear();
+
+        let merged = self.merge_open
+            && self
+                .done
+                .back_mut()
+                .is_some_and(|last| last.merge(&command));
+        if !merged {
+            self.done.push_back(Box::new(command));
+            if self.done.len() > self.capacity {
+                self.done.pop_front();
+            }
+        }
+        self.merge_open = true;
+        Ok(())
+    }
+
+    /// ย้อนคำสั่งล่าสุด แล้วคืนคำอธิบายของมัน
+    ///
+    /// # Errors
+    ///
+    /// คืน `NothingToUndo` ถ้าไม่มีประวัติ หรือ `Failed` ถ้าย้อนไม่สำเร็จ (คำสั่งยังอยู่ในประวัติ)
+    pub fn undo(&mut self, target: &mut T) -> Result<String, HistoryError> {
+        let mut command = self.done.pop_back().ok_or(HistoryError::NothingToUndo)?;
+        self.merge_open = false;
+        if let Err(reason) = command.undo(target) {
+            self.done.push_back(command);
+            return Err(HistoryError::Failed(reason));
+        }
+        let description = command.description();
+        self.undone.push(command);
+        Ok(description)
+    }
+
+    /// ทำคำสั่งที่เพิ่ง undo ซ้ำอีกครั้ง
+    ///
+    /// # Errors
+    ///
+    /// คืน `NothingToRedo` ถ้าไม่มีอะไรให้ redo หรือ `Failed` ถ้าทำซ้ำไม่สำเร็จ
+    pub fn redo(&mut self, target: &mut T) -> Result<String, HistoryError> {
+        let mut command = self.undone.pop().ok_or(HistoryError::NothingToRedo)?;
+        self.merge_open = false;
+        if let Err(reason) = command.execute(target) {
+            self.undone.push(command);
+            return Err(HistoryError::Failed(reason));
+        }
+        let description = command.description();
+        self.done.push_back(command);
+        Ok(description)
+    }
+
+    /// ปิดการรวม - คำสั่งถัดไปจะเป็นขั้น undo ใหม่เสมอ (เช่นผู้ใช้หยุดพิมพ์)
+    pub const fn seal(&mut self) {
+        self.merge_open = false;
+    }
+
+    #[must_use]
+    pub fn can_undo(&self) -> bool {
+        !self.done.is_empty()
+    }
+
+    #[must_use]
+    pub fn can_redo(&self) -> bool {
+        !self.undone.is_empty()
+    }
+
+    /// คำอธิบายของคำสั่งที่ undo ได้ เก่าสุดก่อน
+    #[must_use]
+    pub fn undo_descriptions(&self) -> Vec<String> {
+        self.done.iter().map(|command| command.description()).collect()
+    }
+}
+
+/// 📄 บัฟเฟอร์ข้อความ - ตำแหน่งนับเป็นตัวอักษร (`char`) จึงใช้กับภาษาไทยได้
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TextBuffer {
+    text: String,
+}
+
+impl TextBuffer {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    fn byte_offset(&self, position: usize) -> Result<usize, String> {
+        self.text
+            .char_indices()
+            .map(|(offset, _)| offset)
+            .chain(std::iter::once(self.text.len()))
+            .nth(position)
+            .ok_or_else(|| format!("position {position} is past the end of the text"))
+    }
+
+    fn insert(&mut self, position: usize, text: &str) -> Result<(), String> {
+        let offset = self.byte_offset(position)?;
+        self.text.insert_str(offset, text);
+        Ok(())
+    }
+
+    fn remove(&mut self, position: usize, len: usize) -> Result<String, String> {
+        let start = self.byte_offset(position)?;
+        let end = self.byte_offset(position + len)?;
+        Ok(self.text.drain(start..end).collect())
+    }
+}
+
+/// ✏️ แทรกข้อความ - การพิมพ์ต่อท้ายตำแหน่งเดิมจะรวมเป็นขั้น undo เดียว
+#[derive(Debug, Clone)]
+pub struct InsertText {
+    position: usize,
+    text: String,
+}
+
+impl InsertText {
+    #[must_use]
+    pub fn new(position: usize, text: &str) -> Self {
+        Self {
+            position,
+            text: text.to_string(),
+        }
+    }
+}
+
+impl UndoableCommand<TextBuffer> for InsertText {
+    fn execute(&mut self, buffer: &mut TextBuffer) -> Result<(), String> {
+        buffer.insert(self.position, &self.text)
+    }
+
+    fn undo(&mut self, buffer: &mut TextBuffer) -> Result<(), String> {
+        buffer
+            .remove(self.position, self.text.chars().count())
+            .map(drop)
+    }
+
+    fn description(&self) -> String {
+        format!("insert \"{}\" at {}", self.text, self.position)
+    }
+
+    fn merge(&mut self, next: &dyn Any) -> bool {
+        match next.downcast_ref::<Self>() {
+            Some(next) if next.position == self.position + self.text.chars().count() => {
+                self.text.push_str(&next.text);
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+/// ⌫ ลบข้อความ - กด backspace ต่อเนื่องจะรวมเป็นขั้น undo เดียว
+#[derive(Debug, Clone)]
+pub struct DeleteText {
+    position: usize,
+    len: usize,
+    deleted: String,
+}
+
+impl DeleteText {
+    #[must_use]
+    pub const fn new(position: usize, len: usize) -> Self {
+        Self {
+            position,
+            len,
+            deleted: String::new(),
+        }
+    }
+}
+
+impl UndoableCommand<TextBuffer> for DeleteText {
+    fn execute(&mut self, buffer: &mut TextBuffer) -> Result<(), String> {
+        self.deleted = buffer.remove(self.position, self.len)?;
+        Ok(())
+    }
+
+    fn undo(&mut self, buffer: &mut TextBuffer) -> Result<(), String> {
+        buffer.insert(self.position, &self.deleted)
+    }
+
+    fn description(&self) -> String {
+        format!("delete \"{}\" at {}", self.deleted, self.position)
+    }
+
+    fn merge(&mut self, next: &dyn Any) -> bool {
+        match next.downcast_ref::<Self>() {
+            Some(next) if next.position + next.len == self.position => {
+                self.position = next.position;
+                self.len += next.len;
+                self.deleted.insert_str(0, &next.deleted);
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+/// 🎒 เก็บไอเทมเข้ากระเป๋า
+#[derive(Debug, Clone)]
+pub struct PickUpItem {
+    item: InventoryItem,
+}
+
+impl PickUpItem {
+    #[must_use]
+    pub const fn new(item: InventoryItem) -> Self {
+        Self { item }
+    }
+}
+
+impl UndoableCommand<Inventory> for PickUpItem {
+    fn execute(&mut self, inventory: &mut Inventory) -> Result<(), String> {
+        if inventory.add_item(self.item.clone()) {
+            Ok(())
+        } else {
+            Err(format!("no room for {}", self.item.name))
+        }
+    }
+
+    fn undo(&mut self, inventory: &mut Inventory) -> Result<(), String> {
+        if inventory.remove_item(&self.item.id, self.item.quantity) {
+            Ok(())
+        } else {
+            Err(format!("{} is no longer in the inventory", self.item.name))
+        }
+    }
+
+    fn description(&self) -> String {
+        format!("pick up {} x{}", self.item.name, self.item.quantity)
+    }
+}
+
+/// 🗑️ ทิ้งไอเทม - จำไอเทมที่ทิ้งไว้เพื่อใส่คืนตอน undo
+#[derive(Debug, Clone)]
+pub struct DropItem {
+    item_id: String,
+    quantity: u32,
+    dropped: Option<InventoryItem>,
+}
+
+impl DropItem {
+    #[must_use]
+    pub fn new(item_id: &str, quantity: u32) -> Self {
+        Self {
+            item_id: item_id.to_string(),
+            quantity,
+            dropped: None,
+        }
+    }
+}
+
+impl UndoableCommand<Inventory> for DropItem {
+    fn execute(&mut self, inventory: &mut Inventory) -> Result<(), String> {
+        let item = inventory
+            .items
+            .get(&self.item_id)
+            .cloned()
+            .ok_or_else(|| format!("no {} to drop", self.item_id))?;
+        if !inventory.remove_item(&self.item_id, self.quantity) {
+            return Err(format!("only {} {} left", item.quantity, self.item_id));
+        }
+        self.dropped = Some(InventoryItem {
+            quantity: self.quantity,
+            ..item
+        });
+        Ok(())
+    }
+
+    fn undo(&mut self, inventory: &mut Inventory) -> Result<(), String> {
+        let item = self.dropped.clone().ok_or("item was never dropped")?;
+        if inventory.add_item(item) {
+            Ok(())
+        } else {
+            Err(format!("no room to take back {}", self.item_id))
+        }
+    }
+
+    fn description(&self) -> String {
+        format!("drop {} x{}", self.item_id, self.quantity)
+    }
+}
+
+fn print_step<E: fmt::Display>(label: &str, result: Result<String, E>) {
+    match result {
+        Ok(description) => println!("  {label}: {description}"),
+        Err(error) => println!("  {label}: ❌ {error}"),
+    }
+}
+
+/// สาธิต `CommandHistory` กับบัฟเฟอร์ข้อความและกระเป๋าของเกม
+pub fn demonstrate_command_history() {
+    println!("\n📚 CommandHistory - undo/redo ของจริง:");
+
+    let mut buffer = TextBuffer::new();
+    let mut history = CommandHistory::new(20);
+    for (position, key) in "สวัสดี".chars().enumerate() {
+        let _ = history.execute(&mut buffer, InsertText::new(position, &key.to_string()));
+    }
+    history.seal();
+    let _ = history.execute(&mut buffer, InsertText::new(6, " Rust"));
+    println!("  📝 \"{}\" - {}", buffer.text(), history.undo_descriptions().join(", "));
+    print_step("↩️ undo", history.undo(&mut buffer));
+    println!("  📝 \"{}\"", buffer.text());
+    let _ = history.execute(&mut buffer, DeleteText::new(5, 1));
+    println!("  📝 \"{}\" (redo หายไปแล้ว: {})", buffer.text(), !history.can_redo());
+    print_step("↪️ redo", history.redo(&mut buffer));
+
+    let mut inventory = Inventory::new();
+    let mut bag_history = CommandHistory::new(10);
+    let potion = InventoryItem {
+        id: "potion".to_string(),
+        name: "Health Potion".to_string(),
+        description: "ฟื้นพลัง 50".to_string(),
+        quantity: 3,
+        weight: 0.5,
+        value: 25,
+        item_type: ItemType::Consumable {
+            effect: "heal".to_string(),
+            duration: None,
+        },
+        usable: true,
+    };
+    let _ = bag_history.execute(&mut inventory, PickUpItem::new(potion));
+    let _ = bag_history.execute(&mut inventory, DropItem::new("potion", 3));
+    println!("  🎒 potion หลังทิ้ง: {}", inventory.has_item("potion", 1));
+    print_step("↩️ undo", bag_history.undo(&mut inventory));
+    println!("  🎒 potion x3 กลับมา: {}", inventory.has_item("potion", 3));
+}
+
 /// สาธิตการใช้งาน Command Pattern
 pub fn demonstrate_command() {
     println!("⚡ Command Pattern Examples:");
@@ -185,6 +611,8 @@ pub fn demonstrate_command() {
         Err(e) => println!("❌ {e}"),
     }
     
+    demonstrate_command_history();
+    
     println!("\n✅ Command Pattern demonstrated!");
 }
 
@@ -216,4 +644,101 @@ mod tests {
         editor.redo().unwrap();
         assert_eq!(editor.get_history().len(), 1);
     }
+    
+    fn typed(history: &mut CommandHistory<TextBuffer>, buffer: &mut TextBuffer, text: &str) {
+        let start = buffer.text().chars().count();
+        for (offset, key) in text.chars().enumerate() {
+            history.execute(buffer, InsertText::new(start + offset, &key.to_string())).unwrap();
+        }
+    }
+    
+    #[test]
+    fn test_new_command_invalidates_redo() {
+        let mut buffer = TextBuffer::new();
+        let mut history = CommandHistory::new(10);
+        typed(&mut history, &mut buffer, "abc");
+        history.seal();
+        typed(&mut history, &mut buffer, "def");
+        
+        assert_eq!(history.undo(&mut buffer).unwrap(), r#"insert "def" at 3"#);
+        assert!(history.can_redo());
+        history.execute(&mut buffer, DeleteText::new(0, 1)).unwrap();
+        assert!(!history.can_redo());
+        assert_eq!(history.redo(&mut buffer), Err(HistoryError::NothingToRedo));
+        assert_eq!(buffer.text(), "bc");
+        
+        history.undo(&mut buffer).unwrap();
+        history.undo(&mut buffer).unwrap();
+        assert_eq!(buffer.text(), "");
+        assert_eq!(history.undo(&mut buffer), Err(HistoryError::NothingToUndo));
+    }
+    
+    #[test]
+    fn test_consecutive_edits_merge_until_sealed_or_undone() {
+        let mut buffer = TextBuffer::new();
+        let mut history = CommandHistory::new(10);
+        typed(&mut history, &mut buffer, "กขค");
+        assert_eq!(history.undo_descriptions(), [r#"insert "กขค" at 0"#]);
+        
+        // backspace สองครั้งติดกัน = ขั้นเดียว
+        history.execute(&mut buffer, DeleteText::new(2, 1)).unwrap();
+        history.execute(&mut buffer, DeleteText::new(1, 1)).unwrap();
+        assert_eq!(buffer.text(), "ก");
+        assert_eq!(history.undo(&mut buffer).unwrap(), r#"delete "ขค" at 1"#);
+        assert_eq!(buffer.text(), "กขค");
+        
+        // หลัง undo พิมพ์ต่อต้องเป็นขั้นใหม่ ไม่รวมกับ "กขค"
+        typed(&mut history, &mut buffer, "ง");
+        assert_eq!(history.undo_descriptions().len(), 2);
+        history.redo(&mut buffer).unwrap_err();
+    }
+    
+    #[test]
+    fn test_history_is_bounded_and_failures_are_not_recorded() {
+        let mut buffer = TextBuffer::new();
+        let mut history = CommandHistory::new(2);
+        for word in ["a", "b", "c"] {
+            history.seal();
+            typed(&mut history, &mut buffer, word);
+        }
+        assert_eq!(history.undo_descriptions(), [r#"insert "b" at 1"#, r#"insert "c" at 2"#]);
+        assert!(matches!(
+            history.execute(&mut buffer, DeleteText::new(5, 1)),
+            Err(HistoryError::Failed(_))
+        ));
+        
+        history.undo(&mut buffer).unwrap();
+        history.undo(&mut buffer).unwrap();
+        assert_eq!(buffer.text(), "a");
+        assert!(!history.can_undo());
+    }
+    
+    #[test]
+    fn test_inventory_commands_round_trip() {
+        let mut inventory = Inventory::new();
+        let mut history = CommandHistory::new(10);
+        let arrow = InventoryItem {
+            id: "arrow".to_string(),
+            name: "Arrow".to_string(),
+            description: String::new(),
+            quantity: 10,
+            weight: 2.0,
+            value: 1,
+            item_type: ItemType::Collectible,
+            usable: false,
+        };
+        
+        history.execute(&mut inventory, PickUpItem::new(arrow)).unwrap();
+        history.execute(&mut inventory, DropItem::new("arrow", 4)).unwrap();
+        assert!(history.execute(&mut inventory, DropItem::new("arrow", 7)).is_err());
+        assert!(inventory.has_item("arrow", 6) && !inventory.has_item("arrow", 7));
+        
+        history.undo(&mut inventory).unwrap();
+        assert!(inventory.has_item("arrow", 10));
+        history.undo(&mut inventory).unwrap();
+        assert!(inventory.items.is_empty());
+        assert!(inventory.current_weight.abs() < f32::EPSILON);
+        history.redo(&mut inventory).unwrap();
+        assert!(inventory.has_item("arrow", 10));
+    }
 }
\ No newline at end of file
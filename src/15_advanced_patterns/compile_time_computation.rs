@@ -2,8 +2,26 @@
 //!
 //! การใช้งาน Compile-Time Computation ใน Rust
 //! ครอบคลุม const functions, const generics, และ compile-time evaluation
+//!
+//! 🧱 ตัวอย่างที่ใช้จริงได้: ตาราง CRC ที่สร้างตอนคอมไพล์, `static_assert!`,
+//! fixed-point แบบ const generics และ parser เวอร์ชันที่ทำงานใน `const`
+//!
+//! ```
+//! use rust_concepts::advanced_patterns::compile_time_computation::{Crc32c, Q16, Version};
+//!
+//! const CHECK: u32 = Crc32c::checksum(b"123456789");
+//! const HALF: Q16 = Q16::from_ratio(1, 2);
+//! const MSRV: Version = Version::parse_or_panic("1.88.0");
+//!
+//! rust_concepts::static_assert!(CHECK == 0xE306_9283);
+//! rust_concepts::static_assert!(HALF.wrapping_add(HALF).to_bits() == Q16::ONE.to_bits());
+//! rust_concepts::static_assert!(MSRV.minor == 88);
+//! ```
 
+use std::error::Error;
+use std::fmt;
 use std::marker::PhantomData;
+use std::ops::{Add, Div, Mul, Neg, Sub};
 
 /// Compile-time mathematical computations
 #[must_use] pub const fn const_fibonacci(n: u32) -> u64 {
@@ -457,19 +475,6 @@ impl<const BUFFER_SIZE: usize, const MAX_CONNECTIONS: usize, const TIMEOUT_MS: u
     }
 }
 
-/// Compile-time assertions
-macro_rules! const_assert {
-    ($condition:expr) => {
-        const _: () = assert!($condition);
-    };
-}
-
-macro_rules! const_assert_eq {
-    ($left:expr, $right:expr) => {
-        const _: () = assert!($left == $right);
-    };
-}
-
 /// Compile-time format string validation
 #[must_use] pub const fn validate_format_string(s: &str) -> bool {
     let bytes = s.as_bytes();
@@ -503,6 +508,442 @@ macro_rules! const_assert_eq {
     brace_count == 0
 }
 
+/// 🧱 ตรวจเงื่อนไขตอนคอมไพล์ - ถ้าเป็นเท็จ build พังทันทีพร้อมข้อความ (ไม่มีต้นทุนตอนรัน)
+///
+/// ```
+/// rust_concepts::static_assert!(u32::BITS == 32);
+/// rust_concepts::static_assert!(size_of::<u64>() == 8, "u64 ต้องมี 8 bytes");
+/// ```
+///
+/// ```compile_fail,E0080
+/// rust_concepts::static_assert!(1 + 1 == 3, "คณิตศาสตร์พัง");
+/// ```
+#[macro_export]
+macro_rules! static_assert {
+    ($condition:expr $(,)?) => {
+        const _: () = assert!($condition);
+    };
+    ($condition:expr, $message:literal $(,)?) => {
+        const _: () = assert!($condition, $message);
+    };
+}
+
+// ===== 📋 CRC lookup tables ตอนคอมไพล์ =====
+
+/// 📋 สร้างตาราง CRC-32 แบบ reflected ของ polynomial ใดก็ได้
+///
+/// เรียกใน `const` = คำนวณตอนคอมไพล์และฝังลงใน binary (ไม่มีต้นทุนตอนเริ่มโปรแกรม)
+#[must_use]
+#[allow(clippy::cast_possible_truncation)] // index < 256
+pub const fn crc32_table(reflected_poly: u32) -> [u32; 256] {
+    let mut table = [0_u32; 256];
+    let mut index = 0;
+    while index < 256 {
+        let mut crc = index as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ reflected_poly
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[index] = crc;
+        index += 1;
+    }
+    table
+}
+
+/// 🧮 CRC-32 หนึ่งแบบต่อหนึ่ง polynomial - แต่ละ `POLY` ได้ตาราง `TABLE` ของตัวเองตอนคอมไพล์
+#[derive(Debug)]
+pub struct CrcAlgorithm<const POLY: u32>;
+
+/// CRC-32/IEEE (zip, PNG, Ethernet) - ตัวเดียวกับ `security::checksums::Crc32`
+pub type Crc32Ieee = CrcAlgorithm<0xEDB8_8320>;
+
+/// CRC-32C/Castagnoli (iSCSI, ext4) - มีคำสั่ง CPU รองรับบน x86 และ ARM
+pub type Crc32c = CrcAlgorithm<0x82F6_3B78>;
+
+impl<const POLY: u32> CrcAlgorithm<POLY> {
+    pub const TABLE: [u32; 256] = crc32_table(POLY);
+
+    /// checksum แบบ `const fn` - ใช้ได้ทั้งตอนคอมไพล์และตอนรัน
+    #[must_use]
+    pub const fn checksum(data: &[u8]) -> u32 {
+        let mut crc = u32::MAX;
+        let mut i = 0;
+        while i < data.len() {
+            crc = (crc >> 8) ^ Self::TABLE[((crc ^ data[i] as u32) & 0xFF) as usize];
+            i += 1;
+        }
+        !crc
+    }
+}
+
+// ===== 🔢 Fixed-point ด้วย const generics =====
+
+/// 🔢 fixed-point แบบ Q-format: เก็บใน `i32` โดย `FRAC` bit ล่างเป็นเศษ (ค่าจริง = raw / 2^FRAC)
+///
+/// ทุกการคำนวณเป็น `const fn` จึงใช้สร้างค่าคงที่ตอนคอมไพล์ได้ และไม่ต้องมี FPU ตอนรัน
+///
+/// ```compile_fail,E0080
+/// use rust_concepts::advanced_patterns::compile_time_computation::Fixed;
+///
+/// // ❌ `FRAC` ต้องอยู่ระหว่าง 1 ถึง 30 - ตรวจตอน monomorphize
+/// const TOO_PRECISE: Fixed<40> = Fixed::from_int(1);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fixed<const FRAC: u32>(i32);
+
+/// Q16.16: ช่วง ±32768 ละเอียด 1/65536
+pub type Q16 = Fixed<16>;
+
+/// Q8.8 (เก็บใน `i32`): ช่วงกว้างกว่าแต่ละเอียดแค่ 1/256
+pub type Q8 = Fixed<8>;
+
+#[allow(clippy::cast_possible_truncation)] // ผลคูณ/หารตัดกลับเป็น `i32` แบบ wrapping เหมือน integer
+impl<const FRAC: u32> Fixed<FRAC> {
+    const VALID_FRAC: () = assert!(FRAC > 0 && FRAC < 31, "FRAC must be between 1 and 30");
+
+    pub const ONE: Self = Self::from_int(1);
+
+    /// ค่า raw ตรงๆ (ไม่ได้ shift)
+    #[must_use]
+    pub const fn from_raw(raw: i32) -> Self {
+        let () = Self::VALID_FRAC;
+        Self(raw)
+    }
+
+    /// จำนวนเต็ม - ค่าที่เกินช่วงจะ wrap
+    #[must_use]
+    pub const fn from_int(value: i32) -> Self {
+        Self::from_raw(value.wrapping_shl(FRAC))
+    }
+
+    /// เศษส่วน `numerator / denominator` (ปัดเศษทิ้งเข้าหาศูนย์)
+    ///
+    /// # Panics
+    ///
+    /// เมื่อ `denominator` เป็น 0 (ถ้าอยู่ใน `const` จะเป็น compile error)
+    #[must_use]
+    pub const fn from_ratio(numerator: i32, denominator: i32) -> Self {
+        Self::from_raw((((numerator as i64) << FRAC) / denominator as i64) as i32)
+    }
+
+    #[must_use]
+    pub const fn to_bits(self) -> i32 {
+        self.0
+    }
+
+    #[must_use]
+    pub const fn wrapping_add(self, rhs: Self) -> Self {
+        Self(self.0.wrapping_add(rhs.0))
+    }
+
+    #[must_use]
+    pub const fn wrapping_sub(self, rhs: Self) -> Self {
+        Self(self.0.wrapping_sub(rhs.0))
+    }
+
+    /// คูณใน `i64` แล้ว shift กลับ - ไม่ล้นระหว่างทาง
+    #[must_use]
+    pub const fn wrapping_mul(self, rhs: Self) -> Self {
+        Self(((self.0 as i64 * rhs.0 as i64) >> FRAC) as i32)
+    }
+
+    /// หาร - คืน `None` เมื่อหารด้วยศูนย์
+    #[must_use]
+    pub const fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs.0 == 0 {
+            None
+        } else {
+            Some(Self((((self.0 as i64) << FRAC) / rhs.0 as i64) as i32))
+        }
+    }
+
+    /// เปลี่ยนความละเอียด เช่น `Q8` → `Q16` (ลดความละเอียดจะตัดเศษทิ้ง)
+    #[must_use]
+    pub const fn convert<const TO: u32>(self) -> Fixed<TO> {
+        if TO >= FRAC {
+            Fixed::from_raw(self.0.wrapping_shl(TO - FRAC))
+        } else {
+            Fixed::from_raw(self.0 >> (FRAC - TO))
+        }
+    }
+
+    #[must_use]
+    pub fn to_f64(self) -> f64 {
+        f64::from(self.0) / f64::from(1_u32 << FRAC)
+    }
+}
+
+impl<const FRAC: u32> Add for Fixed<FRAC> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        self.wrapping_add(rhs)
+    }
+}
+
+impl<const FRAC: u32> Sub for Fixed<FRAC> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self.wrapping_sub(rhs)
+    }
+}
+
+impl<const FRAC: u32> Mul for Fixed<FRAC> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        self.wrapping_mul(rhs)
+    }
+}
+
+impl<const FRAC: u32> Div for Fixed<FRAC> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        self.checked_div(rhs).expect("fixed-point division by zero")
+    }
+}
+
+impl<const FRAC: u32> Neg for Fixed<FRAC> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(self.0.wrapping_neg())
+    }
+}
+
+impl<const FRAC: u32> fmt::Display for Fixed<FRAC> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.to_f64(), f)
+    }
+}
+
+/// π ใน Q16.16 จากเศษส่วน 355/113 - คำนวณตอนคอมไพล์
+pub const PI_Q16: Q16 = Q16::from_ratio(355, 113);
+
+// ===== 🏷️ Parser ตอนคอมไพล์ =====
+
+/// 🏷️ เวอร์ชันแบบ `MAJOR.MINOR.PATCH` (ตัวเลขล้วน)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Version {
+    pub major: u16,
+    pub minor: u16,
+    pub patch: u16,
+}
+
+/// ❌ เหตุที่ parse เวอร์ชันไม่ผ่าน
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VersionError {
+    Empty,
+    /// ไม่ใช่ `MAJOR.MINOR.PATCH` (นับได้ `found` ส่วน)
+    WrongComponentCount {
+        found: usize,
+    },
+    /// ส่วนที่ว่าง เช่น `1..2`
+    EmptyComponent {
+        index: usize,
+    },
+    /// อักขระที่ไม่ใช่ตัวเลขหรือ `.` ที่ byte `position`
+    InvalidCharacter {
+        position: usize,
+    },
+    /// ตัวเลขเกิน `u16::MAX`
+    Overflow {
+        index: usize,
+    },
+}
+
+impl fmt::Display for VersionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "version string is empty"),
+            Self::WrongComponentCount { found } => {
+                write!(f, "expected MAJOR.MINOR.PATCH, found {found} component(s)")
+            }
+            Self::EmptyComponent { index } => write!(f, "component {index} is empty"),
+            Self::InvalidCharacter { position } => {
+                write!(f, "invalid character at byte {position}")
+            }
+            Self::Overflow { index } => write!(f, "component {index} exceeds {}", u16::MAX),
+        }
+    }
+}
+
+impl Error for VersionError {}
+
+impl Version {
+    #[must_use]
+    pub const fn new(major: u16, minor: u16, patch: u16) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    /// parse แบบ `const fn` - ใช้ได้ทั้งกับ string จากผู้ใช้ตอนรันและ literal ตอนคอมไพล์
+    ///
+    /// # Errors
+    ///
+    /// คืน `VersionError` เมื่อไม่ใช่ตัวเลขสามส่วนคั่นด้วย `.` หรือเลขเกิน `u16`
+    #[allow(clippy::cast_possible_truncation)] // ตรวจแล้วว่า value <= u16::MAX
+    pub const fn parse(text: &str) -> Result<Self, VersionError> {
+        let bytes = text.as_bytes();
+        if bytes.is_empty() {
+            return Err(VersionError::Empty);
+        }
+
+        let mut dots = 0;
+        let mut i = 0;
+        while i < bytes.len() {
+            if bytes[i] == b'.' {
+                dots += 1;
+            }
+            i += 1;
+        }
+        if dots != 2 {
+            return Err(VersionError::WrongComponentCount { found: dots + 1 });
+        }
+
+        let mut parts = [0_u16; 3];
+        let mut index = 0;
+        let mut digits = 0;
+        let mut value = 0_u32;
+        let mut position = 0;
+        while position < bytes.len() {
+            let byte = bytes[position];
+            if byte == b'.' {
+                if digits == 0 {
+                    return Err(VersionError::EmptyComponent { index });
+                }
+                parts[index] = value as u16;
+                index += 1;
+                digits = 0;
+                value = 0;
+            } else if byte.is_ascii_digit() {
+                value = value * 10 + (byte - b'0') as u32;
+                if value > u16::MAX as u32 {
+                    return Err(VersionError::Overflow { index });
+                }
+                digits += 1;
+            } else {
+                return Err(VersionError::InvalidCharacter { position });
+            }
+            position += 1;
+        }
+        if digits == 0 {
+            return Err(VersionError::EmptyComponent { index });
+        }
+        parts[index] = value as u16;
+
+        Ok(Self::new(parts[0], parts[1], parts[2]))
+    }
+
+    /// parse literal ใน `const` - string ผิดรูปแบบกลายเป็น compile error
+    ///
+    /// ```compile_fail,E0080
+    /// use rust_concepts::advanced_patterns::compile_time_computation::Version;
+    ///
+    /// const BROKEN: Version = Version::parse_or_panic("1.x.0"); // ❌ ไม่ผ่านตั้งแต่คอมไพล์
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// เมื่อ `text` ไม่ใช่เวอร์ชันที่ถูกต้อง
+    #[must_use]
+    pub const fn parse_or_panic(text: &str) -> Self {
+        match Self::parse(text) {
+            Ok(version) => version,
+            Err(VersionError::Empty) => panic!("version string is empty"),
+            Err(VersionError::WrongComponentCount { .. }) => {
+                panic!("version must be MAJOR.MINOR.PATCH")
+            }
+            Err(VersionError::EmptyComponent { .. }) => panic!("version has an empty component"),
+            Err(VersionError::InvalidCharacter { .. }) => {
+                panic!("version may only contain digits and dots")
+            }
+            Err(VersionError::Overflow { .. }) => panic!("version component exceeds u16::MAX"),
+        }
+    }
+
+    /// ใช้แทน `required` ได้ตามกฎ caret ของ Cargo (`^1.2.0` รับ `1.x.y` ที่ไม่ต่ำกว่า, `^0.3.1` รับแค่ `0.3.x`)
+    #[must_use]
+    pub const fn is_compatible_with(self, required: Self) -> bool {
+        let same_line = if required.major == 0 {
+            self.major == 0 && self.minor == required.minor
+        } else {
+            self.major == required.major
+        };
+        same_line
+            && (self.minor > required.minor
+                || (self.minor == required.minor && self.patch >= required.patch))
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// เวอร์ชันของ crate นี้ - parse จาก `Cargo.toml` ตอนคอมไพล์
+pub const CRATE_VERSION: Version = Version::parse_or_panic(env!("CARGO_PKG_VERSION"));
+
+/// Rust เวอร์ชันต่ำสุดที่รองรับ (`rust-version` ใน `Cargo.toml`)
+pub const MIN_RUST_VERSION: Version = Version::parse_or_panic(env!("CARGO_PKG_RUST_VERSION"));
+
+/// สาธิต CRC table, fixed-point และ parser ที่ทำงานตอนคอมไพล์
+fn demonstrate_const_eval_extras() {
+    const CHECK_IEEE: u32 = Crc32Ieee::checksum(b"123456789");
+    const CHECK_C: u32 = Crc32c::checksum(b"123456789");
+    const AREA: Q16 = PI_Q16.wrapping_mul(Q16::from_int(4)); // πr² เมื่อ r = 2
+
+    println!("\n📋 Compile-Time CRC Tables:");
+    println!("{:-<50}", "");
+    println!(
+        "CRC-32/IEEE(\"123456789\") = {CHECK_IEEE:#010x} (ตาราง[1] = {:#010x})",
+        Crc32Ieee::TABLE[1]
+    );
+    println!(
+        "CRC-32C(\"123456789\")     = {CHECK_C:#010x} (ตาราง[1] = {:#010x})",
+        Crc32c::TABLE[1]
+    );
+
+    println!("\n🔢 Const-Generic Fixed-Point:");
+    println!("{:-<50}", "");
+    println!(
+        "π ≈ {PI_Q16:.6} (raw {:#x}), πr² (r = 2) ≈ {AREA:.4}",
+        PI_Q16.to_bits()
+    );
+    let coarse: Q8 = PI_Q16.convert();
+    println!("π ใน Q8 ≈ {coarse} - ความละเอียดต่ำลงเหลือ 1/256");
+    let speed = Q16::from_ratio(25, 10);
+    let elapsed = Q16::from_ratio(3, 2);
+    println!(
+        "ระยะทาง = {speed} m/s × {elapsed} s = {} m (ไม่ใช้ float เลย)",
+        speed * elapsed
+    );
+
+    println!("\n🏷️ Compile-Time Version Parsing:");
+    println!("{:-<50}", "");
+    println!("crate {CRATE_VERSION}, ต้องใช้ Rust ≥ {MIN_RUST_VERSION}");
+    for text in ["2.10.3", "1.2", "1..3", "1.2.beta", "1.70000.0"] {
+        match Version::parse(text) {
+            Ok(version) => println!(
+                "\"{text}\" → {version} (ใช้แทน ^2.4.0 ได้: {})",
+                version.is_compatible_with(Version::new(2, 4, 0))
+            ),
+            Err(error) => println!("\"{text}\" → ❌ {error}"),
+        }
+    }
+}
+
 /// สาธิตการใช้งาน Compile-Time Computation
 pub fn demonstrate_compile_time_computation() {
     println!("⚡ Compile-Time Computation Examples:");
@@ -667,15 +1108,34 @@ pub fn demonstrate_compile_time_computation() {
     println!("\"Hello, {{}}! You have {{}} messages.\" is valid: {VALID_FORMAT}");
     println!("\"Hello, {{}}! You have }} messages.\" is valid: {INVALID_FORMAT}");
     
+    demonstrate_const_eval_extras();
+
     println!("\n✅ All compile-time computations completed!");
 }
 
 // Compile-time assertions examples
-const_assert!(const_fibonacci(10) == 55);
-const_assert!(const_factorial(5) == 120);
-const_assert!(const_power(2, 8) == 256);
-const_assert_eq!(const_gcd(48, 18), 6);
-const_assert_eq!(const_lcm(12, 8), 24);
+static_assert!(const_fibonacci(10) == 55);
+static_assert!(const_factorial(5) == 120);
+static_assert!(const_power(2, 8) == 256);
+static_assert!(const_gcd(48, 18) == 6);
+static_assert!(const_lcm(12, 8) == 24);
+
+// ค่าตรวจสอบมาตรฐานของแต่ละ CRC - ผิดแม้แต่ bit เดียวก็ build ไม่ผ่าน
+static_assert!(Crc32Ieee::TABLE[1] == 0x7707_3096);
+static_assert!(Crc32Ieee::checksum(b"123456789") == 0xCBF4_3926, "CRC-32/IEEE check value");
+static_assert!(Crc32c::checksum(b"123456789") == 0xE306_9283, "CRC-32C check value");
+static_assert!(Crc32Ieee::checksum(b"") == 0);
+
+static_assert!(Q16::from_ratio(3, 2).wrapping_mul(Q16::from_int(2)).to_bits() == Q16::from_int(3).to_bits());
+static_assert!(Q8::from_ratio(1, 4).convert::<16>().to_bits() == Q16::from_ratio(1, 4).to_bits());
+static_assert!(PI_Q16.to_bits() == 205_887, "355/113 ใน Q16.16");
+static_assert!(Q16::ONE.checked_div(Fixed::from_raw(0)).is_none());
+
+static_assert!(matches!(Version::parse("1.2"), Err(VersionError::WrongComponentCount { found: 2 })));
+static_assert!(matches!(Version::parse("1.2.x"), Err(VersionError::InvalidCharacter { position: 4 })));
+static_assert!(Version::parse_or_panic("1.4.2").is_compatible_with(Version::new(1, 2, 0)));
+static_assert!(!Version::parse_or_panic("0.4.0").is_compatible_with(Version::new(0, 3, 1)));
+static_assert!(MIN_RUST_VERSION.major == 1, "rust-version ต้องเป็น 1.x.y");
 
 #[cfg(test)]
 mod tests {
@@ -855,4 +1315,67 @@ mod tests {
         assert!(!PRIMES[18]); // 18 is not prime
         assert!(PRIMES[19]); // 19 is prime
     }
+
+    #[test]
+    fn test_const_crc_matches_runtime_crc() {
+        for data in [&b""[..], b"a", b"123456789", b"The quick brown fox jumps over the lazy dog"] {
+            assert_eq!(Crc32Ieee::checksum(data), crate::security::checksums::Crc32::checksum(data));
+        }
+        assert_eq!(Crc32c::checksum(b"The quick brown fox jumps over the lazy dog"), 0x2262_0404);
+        assert_ne!(Crc32Ieee::TABLE, Crc32c::TABLE);
+    }
+
+    #[test]
+    fn test_fixed_point_arithmetic() {
+        let half = Q16::from_ratio(1, 2);
+        assert_eq!(half + half, Q16::ONE);
+        assert_eq!(Q16::from_int(3) - Q16::from_int(5), -Q16::from_int(2));
+        assert_eq!(Q16::from_ratio(-3, 2) * Q16::from_int(4), Q16::from_int(-6));
+        assert_eq!(Q16::from_int(7) / Q16::from_int(2), Q16::from_ratio(7, 2));
+        assert_eq!(Q16::ONE.checked_div(Q16::default()), None);
+        assert!((PI_Q16.to_f64() - std::f64::consts::PI).abs() < 1e-4);
+
+        // Q16 → Q8 ตัดเศษที่ละเอียดเกิน 1/256 ทิ้ง แต่ Q8 → Q16 ไม่เสียอะไร
+        let coarse: Q8 = PI_Q16.convert();
+        assert_eq!(coarse.to_bits(), PI_Q16.to_bits() >> 8);
+        assert_eq!(coarse.convert::<16>().convert::<8>(), coarse);
+        assert_eq!(format!("{:.2}", Q8::from_ratio(5, 4)), "1.25");
+    }
+
+    #[test]
+    #[should_panic(expected = "fixed-point division by zero")]
+    fn test_fixed_point_division_by_zero_panics() {
+        let _ = Q16::ONE / Q16::from_raw(0);
+    }
+
+    #[test]
+    fn test_version_parsing() {
+        assert_eq!(Version::parse("0.12.345"), Ok(Version::new(0, 12, 345)));
+        assert_eq!(Version::parse("1.2.3").unwrap().to_string(), "1.2.3");
+        assert_eq!(Version::parse(""), Err(VersionError::Empty));
+        assert_eq!(Version::parse("1.2.3.4"), Err(VersionError::WrongComponentCount { found: 4 }));
+        assert_eq!(Version::parse("1..3"), Err(VersionError::EmptyComponent { index: 1 }));
+        assert_eq!(Version::parse("1.2."), Err(VersionError::EmptyComponent { index: 2 }));
+        assert_eq!(Version::parse("1.-2.3"), Err(VersionError::InvalidCharacter { position: 2 }));
+        assert_eq!(Version::parse("65536.0.0"), Err(VersionError::Overflow { index: 0 }));
+        assert_eq!(
+            Version::parse("1.2.x").unwrap_err().to_string(),
+            "invalid character at byte 4"
+        );
+        assert_eq!(CRATE_VERSION.to_string(), env!("CARGO_PKG_VERSION"));
+    }
+
+    #[test]
+    fn test_version_caret_compatibility() {
+        let required = Version::new(1, 4, 2);
+        assert!(Version::new(1, 4, 2).is_compatible_with(required));
+        assert!(Version::new(1, 9, 0).is_compatible_with(required));
+        assert!(!Version::new(1, 4, 1).is_compatible_with(required));
+        assert!(!Version::new(2, 0, 0).is_compatible_with(required));
+
+        let zero = Version::new(0, 3, 1);
+        assert!(Version::new(0, 3, 7).is_compatible_with(zero));
+        assert!(!Version::new(0, 4, 0).is_compatible_with(zero));
+        assert!(Version::new(1, 0, 0) > Version::new(0, 99, 99));
+    }
 }
\ No newline at end of file
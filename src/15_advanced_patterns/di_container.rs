@@ -0,0 +1,379 @@
+//! Dependency Injection Container - ให้ container ต่อสายพึ่งพาแทนเรา
+//!
+//! 💉 constructor injection: แต่ละ type บอกแค่ว่า "ต้องการอะไร" (`Inject::inject`)
+//! container จะสร้างของที่ต้องการก่อนให้เองเป็นทอดๆ
+//!
+//! - 🧊 **singleton** - สร้างครั้งแรกที่ถูกขอ แล้วแจก clone ของตัวเดิม (มักเป็น `Rc<...>`)
+//! - 🏭 **factory** - สร้างใหม่ทุกครั้งที่ขอ
+//! - 🔁 ถ้า A ต้องการ B และ B ต้องการ A จะได้ `DiError::Circular` พร้อมเส้นทาง แทนที่จะ stack overflow
+//!
+//! ```
+//! use rust_concepts::advanced_patterns::di_container::{Container, DiError, Inject};
+//! use std::rc::Rc;
+//!
+//! struct Database { url: String }
+//! struct UserRepo { db: Rc<Database> }
+//!
+//! impl Inject for UserRepo {
+//!     fn inject(container: &Container) -> Result<Self, DiError> {
+//!         Ok(Self { db: container.resolve()? })
+//!     }
+//! }
+//!
+//! let mut container = Container::new();
+//! container
+//!     .register_instance(Rc::new(Database { url: "postgres://localhost".into() }))
+//!     .factory::<UserRepo>();
+//!
+//! let repo: UserRepo = container.resolve().unwrap();
+//! assert_eq!(repo.db.url, "postgres://localhost");
+//! ```
+
+use std::any::{Any, TypeId, type_name};
+use std::cell::{OnceCell, RefCell};
+use std::collections::HashMap;
+use std::fmt;
+use std::rc::Rc;
+
+use crate::testing::mocking::{OrderItem, OrderService, PaymentGateway, SpyGateway};
+
+/// ❌ ข้อผิดพลาดตอน resolve
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiError {
+    /// ไม่มีใคร register `service` ไว้ (`required_by` คือตัวที่ต้องการมัน ถ้ามี)
+    NotRegistered {
+        service: String,
+        required_by: Option<String>,
+    },
+    /// พึ่งพากันเป็นวง - เส้นทางเริ่มและจบด้วย type เดียวกัน
+    Circular(Vec<String>),
+}
+
+impl fmt::Display for DiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotRegistered {
+                service,
+                required_by: Some(parent),
+            } => write!(f, "{service} is not registered (required by {parent})"),
+            Self::NotRegistered { service, .. } => write!(f, "{service} is not registered"),
+            Self::Circular(path) => write!(f, "circular dependency: {}", path.join(" -> ")),
+        }
+    }
+}
+
+impl std::error::Error for DiError {}
+
+/// 💉 type ที่ container สร้างเองได้โดยขอ dependency จาก container
+pub trait Inject: Sized + 'static {
+    /// สร้างตัวเองจาก dependencies ใน `container`
+    ///
+    /// # Errors
+    ///
+    /// ส่งต่อ `DiError` จากการ resolve dependency
+    fn inject(container: &Container) -> Result<Self, DiError>;
+}
+
+/// ⏳ อายุของสิ่งที่ register
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Lifetime {
+    Singleton,
+    Factory,
+}
+
+type Provider = Rc<dyn Fn(&Container) -> Result<Box<dyn Any>, DiError>>;
+
+struct Registration {
+    name: String,
+    lifetime: Lifetime,
+    provide: Provider,
+}
+
+/// 📦 container แบบ single-thread - ของข้างในไม่ต้อง `Send` (เช่น `SpyGateway` ที่ใช้ `RefCell`)
+#[derive(Default)]
+pub struct Container {
+    registrations: HashMap<TypeId, Registration>,
+    resolving: RefCell<Vec<(TypeId, String)>>,
+}
+
+impl fmt::Debug for Container {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut services: Vec<_> = self
+            .registrations
+            .values()
+            .map(|registration| (registration.name.as_str(), registration.lifetime))
+            .collect();
+        services.sort_unstable_by_key(|(name, _)| *name);
+        f.debug_struct("Container")
+            .field("services", &services)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Container {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 🧊 register ค่าที่สร้างไว้แล้ว ทุกการ resolve ได้ clone ของมัน (register ซ้ำจะแทนที่ของเดิม)
+    pub fn register_instance<T: Clone + 'static>(&mut self, value: T) -> &mut Self {
+        self.insert::<T>(
+            Lifetime::Singleton,
+            Rc::new(move |_: &Self| Ok(Box::new(value.clone()) as Box<dyn Any>)),
+        )
+    }
+
+    /// 🧊 register singleton ที่สร้างครั้งแรกเมื่อถูกขอ
+    pub fn register_singleton<T, F>(&mut self, build: F) -> &mut Self
+    where
+        T: Clone + 'static,
+        F: Fn(&Self) -> Result<T, DiError> + 'static,
+    {
+        let cell = OnceCell::new();
+        self.insert::<T>(
+            Lifetime::Singleton,
+            Rc::new(move |container: &Self| {
+                if let Some(value) = cell.get() {
+                    return Ok(Box::new(T::clone(value)) as Box<dyn Any>);
+                }
+                let value = build(container)?;
+                Ok(Box::new(cell.get_or_init(|| value).clone()))
+            }),
+        )
+    }
+
+    /// 🏭 register factory ที่สร้างของใหม่ทุกครั้ง
+    pub fn register_factory<T, F>(&mut self, build: F) -> &mut Self
+    where
+        T: 'static,
+        F: Fn(&Self) -> Result<T, DiError> + 'static,
+    {
+        self.insert::<T>(
+            Lifetime::Factory,
+            Rc::new(move |container: &Self| {
+                build(container).map(|value| Box::new(value) as Box<dyn Any>)
+            }),
+        )
+    }
+
+    /// 🧊 register singleton ที่สร้างด้วย `Inject`
+    pub fn singleton<T: Inject + Clone>(&mut self) -> &mut Self {
+        self.register_singleton(T::inject)
+    }
+
+    /// 🏭 register factory ที่สร้างด้วย `Inject`
+    pub fn factory<T: Inject>(&mut self) -> &mut Self {
+        self.register_factory(T::inject)
+    }
+
+    fn insert<T: 'static>(&mut self, lifetime: Lifetime, provide: Provider) -> &mut Self {
+        self.registrations.insert(
+            TypeId::of::<T>(),
+            Registration {
+                name: short_type_name::<T>(),
+                lifetime,
+                provide,
+            },
+        );
+        self
+    }
+
+    /// มี registration ของ `T` หรือไม่
+    #[must_use]
+    pub fn contains<T: 'static>(&self) -> bool {
+        self.registrations.contains_key(&TypeId::of::<T>())
+    }
+
+    /// 🔍 ขอ `T` - dependencies ของมันถูก resolve ต่อเป็นทอดๆ อัตโนมัติ
+    ///
+    /// # Errors
+    ///
+    /// คืน `DiError::NotRegistered` ถ้า `T` หรือ dependency ตัวใดไม่ได้ register
+    /// หรือ `DiError::Circular` ถ้าพึ่งพากันเป็นวง
+    ///
+    /// # Panics
+    ///
+    /// ไม่ควรเกิด - provider ทุกตัวถูกเก็บไว้ใต้ `TypeId` ของค่าที่มันคืน
+    pub fn resolve<T: 'static>(&self) -> Result<T, DiError> {
+        let id = TypeId::of::<T>();
+        let Some(registration) = self.registrations.get(&id) else {
+            return Err(DiError::NotRegistered {
+                service: short_type_name::<T>(),
+                required_by: self.resolving.borrow().last().map(|(_, name)| name.clone()),
+            });
+        };
+
+        {
+            let mut resolving = self.resolving.borrow_mut();
+            if let Some(start) = resolving.iter().position(|(existing, _)| *existing == id) {
+                let mut path: Vec<String> = resolving[start..]
+                    .iter()
+                    .map(|(_, name)| name.clone())
+                    .collect();
+                path.push(registration.name.clone());
+                return Err(DiError::Circular(path));
+            }
+            resolving.push((id, registration.name.clone()));
+        }
+        let provided = (registration.provide)(self);
+        self.resolving.borrow_mut().pop();
+
+        let value = provided?
+            .downcast::<T>()
+            .expect("provider is registered under its own TypeId");
+        Ok(*value)
+    }
+}
+
+/// `alloc::rc::Rc<dyn a::b::Trait>` → `Rc<dyn Trait>` ให้ error อ่านง่าย
+fn short_type_name<T: ?Sized>() -> String {
+    let mut short = String::new();
+    let mut segment = String::new();
+    let mut chars = type_name::<T>().chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == ':' && chars.peek() == Some(&':') {
+            chars.next();
+            segment.clear();
+        } else if c.is_alphanumeric() || c == '_' {
+            segment.push(c);
+        } else {
+            short.push_str(&segment);
+            segment.clear();
+            short.push(c);
+        }
+    }
+    short + &segment
+}
+
+/// service สั่งซื้อที่ใช้ gateway อะไรก็ได้ที่ container ให้มา
+pub type CheckoutService = OrderService<Rc<dyn PaymentGateway>>;
+
+impl Inject for CheckoutService {
+    fn inject(container: &Container) -> Result<Self, DiError> {
+        Ok(Self::new(container.resolve::<Rc<dyn PaymentGateway>>()?))
+    }
+}
+
+/// ต่อสาย production-like: `CheckoutService` ← `Rc<dyn PaymentGateway>` ← `Rc<SpyGateway>`
+///
+/// test ถือ `Rc<SpyGateway>` ตัวเดียวกับที่ service ใช้ จึงตรวจการเรียกได้
+#[must_use]
+pub fn checkout_container() -> Container {
+    let mut container = Container::new();
+    container
+        .register_singleton(|_| Ok(Rc::new(SpyGateway::new())))
+        .register_singleton(|container| {
+            let spy: Rc<SpyGateway> = container.resolve()?;
+            Ok(spy as Rc<dyn PaymentGateway>)
+        })
+        .factory::<CheckoutService>();
+    container
+}
+
+/// 🔁 ตัวอย่างการพึ่งพาเป็นวง
+struct Scheduler {
+    _notifier: Rc<Notifier>,
+}
+
+struct Notifier {
+    _scheduler: Rc<Scheduler>,
+}
+
+impl Inject for Scheduler {
+    fn inject(container: &Container) -> Result<Self, DiError> {
+        Ok(Self {
+            _notifier: container.resolve()?,
+        })
+    }
+}
+
+impl Inject for Notifier {
+    fn inject(container: &Container) -> Result<Self, DiError> {
+        Ok(Self {
+            _scheduler: container.resolve()?,
+        })
+    }
+}
+
+/// สาธิต DI container
+pub fn demonstrate_di_container() {
+    println!("\n💉 === Dependency Injection Container ===");
+
+    let container = checkout_container();
+    println!("📦 {container:?}");
+
+    let cart = [OrderItem::new("กาแฟเย็น", 2, 5_500)];
+    match container.resolve::<CheckoutService>() {
+        Ok(mut service) => println!("🛒 place_order: {:?}", service.place_order(&cart)),
+        Err(error) => println!("❌ {error}"),
+    }
+    if let Ok(spy) = container.resolve::<Rc<SpyGateway>>() {
+        println!("🕵️ spy ที่แชร์กันเห็น {} การตัดเงิน", spy.charge_count());
+    }
+
+    let mut cyclic = Container::new();
+    cyclic
+        .register_factory(|container| container.resolve::<Scheduler>().map(Rc::new))
+        .register_factory(|container| container.resolve::<Notifier>().map(Rc::new))
+        .factory::<Scheduler>()
+        .factory::<Notifier>();
+    if let Err(error) = cyclic.resolve::<Scheduler>() {
+        println!("🔁 {error}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::mocking::GatewayCall;
+
+    #[test]
+    fn test_dependencies_are_chained_and_singletons_shared() {
+        let container = checkout_container();
+
+        let mut first = container.resolve::<CheckoutService>().unwrap();
+        let mut second = container.resolve::<CheckoutService>().unwrap();
+        first.place_order(&[OrderItem::new("ชา", 1, 100)]).unwrap();
+        second.place_order(&[OrderItem::new("ชา", 2, 100)]).unwrap();
+
+        // factory ได้ service ใหม่ (id เริ่มที่ 1 ทั้งคู่) แต่ใช้ spy ตัวเดียวกัน
+        assert!(first.order(1).is_some() && second.order(1).is_some());
+        let spy: Rc<SpyGateway> = container.resolve().unwrap();
+        assert_eq!(spy.charge_count(), 2);
+        assert!(matches!(&spy.calls()[1], GatewayCall::Charge(request) if request.amount == 200));
+    }
+
+    #[test]
+    fn test_circular_dependency_reports_path() {
+        let mut container = Container::new();
+        container
+            .register_factory(|container| container.resolve::<Scheduler>().map(Rc::new))
+            .register_factory(|container| container.resolve::<Notifier>().map(Rc::new))
+            .factory::<Scheduler>()
+            .factory::<Notifier>();
+
+        let error = container.resolve::<Notifier>().err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "circular dependency: Notifier -> Rc<Scheduler> -> Scheduler -> Rc<Notifier> -> Notifier"
+        );
+        // resolve ที่ล้มเหลวต้องไม่ทิ้ง stack ค้างไว้
+        assert!(container.resolving.borrow().is_empty());
+    }
+
+    #[test]
+    fn test_missing_dependency_names_the_requester() {
+        let mut container = Container::new();
+        container.factory::<CheckoutService>();
+
+        assert_eq!(
+            container.resolve::<CheckoutService>().err(),
+            Some(DiError::NotRegistered {
+                service: "Rc<dyn PaymentGateway>".to_string(),
+                required_by: Some("OrderService<Rc<dyn PaymentGateway>>".to_string()),
+            })
+        );
+        assert!(!container.contains::<Rc<SpyGateway>>());
+    }
+}
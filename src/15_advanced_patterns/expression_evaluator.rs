@@ -0,0 +1,838 @@
+//! Expression Evaluator - เครื่องคิดเลขที่เขียนเองทั้ง tokenizer, Pratt parser และ evaluator 🧮
+//!
+//! ขั้นตอนเหมือนภาษาโปรแกรมจริงในขนาดย่อ:
+//! - 🔤 `tokenize` แปลงข้อความเป็น `Token` ที่จำตำแหน่ง (`Span`) ของตัวเองไว้
+//! - 🌳 `Parser` ใช้ Pratt parsing (precedence climbing) สร้าง `Expr` -
+//!   แต่ละ operator มี binding power ซ้าย/ขวา ซึ่งกำหนดทั้งลำดับความสำคัญและทิศการจับกลุ่ม
+//! - ⚡ `Calculator` ประเมินค่า `Expr` พร้อมตัวแปร (`x = 2`) และฟังก์ชัน (`sqrt(2)`)
+//!
+//! error ทุกชนิดพก `Span` มาด้วย จึงชี้ได้ว่าผิดตรงไหนของบรรทัด
+//!
+//! ```text
+//! calc> 1 + * 2
+//!           ^ คาดว่าจะเจอตัวเลข ตัวแปร หรือ '(' แต่เจอ '*'
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+use std::io::{self, BufRead, Write};
+
+/// ช่วงตำแหน่งตัวอักษร `[start, end)` ในบรรทัดที่ผู้ใช้พิมพ์ (นับเป็น char ไม่ใช่ byte)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    #[must_use]
+    pub const fn new(start: usize, end: usize) -> Self {
+        Self { start, end }
+    }
+
+    /// span ที่ครอบทั้ง `self` และ `other`
+    #[must_use]
+    pub const fn to(self, other: Self) -> Self {
+        Self {
+            start: self.start,
+            end: other.end,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TokenKind {
+    Number(f64),
+    Ident(String),
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    Percent,
+    Caret,
+    LParen,
+    RParen,
+    Comma,
+    Equals,
+    Eof,
+}
+
+impl fmt::Display for TokenKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Number(value) => write!(f, "ตัวเลข {value}"),
+            Self::Ident(name) => write!(f, "ชื่อ '{name}'"),
+            Self::Plus => f.write_str("'+'"),
+            Self::Minus => f.write_str("'-'"),
+            Self::Star => f.write_str("'*'"),
+            Self::Slash => f.write_str("'/'"),
+            Self::Percent => f.write_str("'%'"),
+            Self::Caret => f.write_str("'^'"),
+            Self::LParen => f.write_str("'('"),
+            Self::RParen => f.write_str("')'"),
+            Self::Comma => f.write_str("','"),
+            Self::Equals => f.write_str("'='"),
+            Self::Eof => f.write_str("จบบรรทัด"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub span: Span,
+}
+
+/// ชนิดของความผิดพลาด - ตั้งแต่อ่านตัวอักษรไม่ออกจนถึงหารด้วยศูนย์
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CalcErrorKind {
+    UnexpectedChar(char),
+    InvalidNumber(String),
+    UnexpectedToken {
+        expected: &'static str,
+        found: String,
+    },
+    UnknownVariable(String),
+    UnknownFunction(String),
+    WrongArity {
+        name: String,
+        expected: usize,
+        found: usize,
+    },
+    DivisionByZero,
+    TooDeep,
+}
+
+/// ความผิดพลาดพร้อมตำแหน่ง - `render` วาด `^^^` ใต้ส่วนที่ผิด
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalcError {
+    pub kind: CalcErrorKind,
+    pub span: Span,
+}
+
+impl CalcError {
+    const fn new(kind: CalcErrorKind, span: Span) -> Self {
+        Self { kind, span }
+    }
+
+    /// บรรทัดต้นฉบับ + เส้นชี้ตำแหน่ง + ข้อความ
+    #[must_use]
+    pub fn render(&self, source: &str) -> String {
+        let width = (self.span.end - self.span.start).max(1);
+        format!(
+            "{source}\n{}{} {self}",
+            " ".repeat(self.span.start),
+            "^".repeat(width)
+        )
+    }
+}
+
+impl fmt::Display for CalcError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            CalcErrorKind::UnexpectedChar(c) => write!(f, "ไม่รู้จักตัวอักษร '{c}'"),
+            CalcErrorKind::InvalidNumber(text) => write!(f, "ตัวเลข '{text}' ไม่ถูกต้อง"),
+            CalcErrorKind::UnexpectedToken { expected, found } => {
+                write!(f, "คาดว่าจะเจอ{expected} แต่เจอ {found}")
+            }
+            CalcErrorKind::UnknownVariable(name) => write!(f, "ไม่รู้จักตัวแปร '{name}'"),
+            CalcErrorKind::UnknownFunction(name) => write!(f, "ไม่รู้จักฟังก์ชัน '{name}'"),
+            CalcErrorKind::WrongArity {
+                name,
+                expected,
+                found,
+            } => write!(f, "{name}() รับ {expected} argument แต่ได้ {found}"),
+            CalcErrorKind::DivisionByZero => f.write_str("หารด้วยศูนย์"),
+            CalcErrorKind::TooDeep => write!(f, "ซ้อนลึกเกิน {MAX_DEPTH} ชั้น"),
+        }?;
+        write!(f, " (ตำแหน่ง {})", self.span.start)
+    }
+}
+
+impl std::error::Error for CalcError {}
+
+/// แยกบรรทัดเป็น token - ปิดท้ายด้วย `Eof` เสมอเพื่อให้ parser ชี้ error "จบบรรทัด" ได้
+///
+/// # Errors
+///
+/// คืน `CalcError` เมื่อเจอตัวอักษรที่ไม่รู้จักหรือตัวเลขที่ parse ไม่ได้ เช่น `1.2.3`
+pub fn tokenize(source: &str) -> Result<Vec<Token>, CalcError> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens = Vec::new();
+    let mut at = 0;
+    while at < chars.len() {
+        let c = chars[at];
+        let start = at;
+        if c.is_whitespace() {
+            at += 1;
+            continue;
+        }
+        let kind = if c.is_ascii_digit() || c == '.' {
+            while at < chars.len() && (chars[at].is_ascii_digit() || chars[at] == '.') {
+                at += 1;
+            }
+            let text: String = chars[start..at].iter().collect();
+            let value = text.parse().map_err(|_| {
+                CalcError::new(CalcErrorKind::InvalidNumber(text), Span::new(start, at))
+            })?;
+            TokenKind::Number(value)
+        } else if c.is_alphabetic() || c == '_' {
+            while at < chars.len() && (chars[at].is_alphanumeric() || chars[at] == '_') {
+                at += 1;
+            }
+            TokenKind::Ident(chars[start..at].iter().collect())
+        } else {
+            at += 1;
+            match c {
+                '+' => TokenKind::Plus,
+                '-' => TokenKind::Minus,
+                '*' => TokenKind::Star,
+                '/' => TokenKind::Slash,
+                '%' => TokenKind::Percent,
+                '^' => TokenKind::Caret,
+                '(' => TokenKind::LParen,
+                ')' => TokenKind::RParen,
+                ',' => TokenKind::Comma,
+                '=' => TokenKind::Equals,
+                _ => {
+                    return Err(CalcError::new(
+                        CalcErrorKind::UnexpectedChar(c),
+                        Span::new(start, at),
+                    ));
+                }
+            }
+        };
+        tokens.push(Token {
+            kind,
+            span: Span::new(start, at),
+        });
+    }
+    tokens.push(Token {
+        kind: TokenKind::Eof,
+        span: Span::new(chars.len(), chars.len()),
+    });
+    Ok(tokens)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    Pow,
+}
+
+impl BinaryOp {
+    /// binding power (ซ้าย, ขวา) - ขวามากกว่าซ้ายคือจับกลุ่มซ้าย, น้อยกว่าคือจับกลุ่มขวา (`^`)
+    const fn binding_power(self) -> (u8, u8) {
+        match self {
+            Self::Add | Self::Sub => (1, 2),
+            Self::Mul | Self::Div | Self::Rem => (3, 4),
+            Self::Pow => (7, 6),
+        }
+    }
+
+    const fn symbol(self) -> char {
+        match self {
+            Self::Add => '+',
+            Self::Sub => '-',
+            Self::Mul => '*',
+            Self::Div => '/',
+            Self::Rem => '%',
+            Self::Pow => '^',
+        }
+    }
+}
+
+/// binding power ของ `-` นำหน้า - สูงกว่า `*` แต่ต่ำกว่า `^` จึงได้ `-2^2 = -(2^2)`
+const PREFIX_BP: u8 = 5;
+
+/// AST ซ้อนได้ลึกสุดเท่านี้ - นับทั้งวงเล็บ/เครื่องหมายลบ/การเรียกฟังก์ชันและ operator ที่พับเป็นโซ่
+/// (`1+1+...` ได้ต้นไม้ลึกเท่าจำนวน `+`) กัน stack ล้นทั้งตอน parse, `eval` และ drop
+pub const MAX_DEPTH: usize = 128;
+
+/// AST ของนิพจน์ - node ที่อาจ error ตอนประเมินค่าเก็บ `Span` ไว้ชี้ตำแหน่ง
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    Number(f64),
+    Variable {
+        name: String,
+        span: Span,
+    },
+    Negate(Box<Self>),
+    Binary {
+        op: BinaryOp,
+        lhs: Box<Self>,
+        rhs: Box<Self>,
+        span: Span,
+    },
+    Call {
+        name: String,
+        args: Vec<Self>,
+        span: Span,
+    },
+}
+
+impl fmt::Display for Expr {
+    /// เขียนเป็น S-expression เช่น `(+ 1 (* 2 3))` - เห็นโครงสร้างต้นไม้ชัดเจน
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Number(value) => write!(f, "{value}"),
+            Self::Variable { name, .. } => f.write_str(name),
+            Self::Negate(inner) => write!(f, "(- {inner})"),
+            Self::Binary { op, lhs, rhs, .. } => write!(f, "({} {lhs} {rhs})", op.symbol()),
+            Self::Call { name, args, .. } => {
+                write!(f, "({name}")?;
+                for arg in args {
+                    write!(f, " {arg}")?;
+                }
+                f.write_str(")")
+            }
+        }
+    }
+}
+
+/// หนึ่งบรรทัดของเครื่องคิดเลข: กำหนดตัวแปรหรือคำนวณนิพจน์
+#[derive(Debug, Clone, PartialEq)]
+pub enum Statement {
+    Assign { name: String, value: Expr },
+    Expr(Expr),
+}
+
+/// Pratt parser - อ่าน token ทีละตัวและตัดสินใจด้วย binding power แทนการเขียนกฎแยกทุกระดับ
+pub struct Parser {
+    tokens: Vec<Token>,
+    at: usize,
+    depth: usize,
+}
+
+impl Parser {
+    /// # Errors
+    ///
+    /// คืน `CalcError` จาก `tokenize`
+    pub fn new(source: &str) -> Result<Self, CalcError> {
+        Ok(Self {
+            tokens: tokenize(source)?,
+            at: 0,
+            depth: 0,
+        })
+    }
+
+    /// parse ทั้งบรรทัด - `ชื่อ = นิพจน์` เป็นการกำหนดค่า นอกนั้นเป็นนิพจน์
+    ///
+    /// # Errors
+    ///
+    /// คืน `CalcError` ที่ชี้ token แรกที่ไม่เข้ากับไวยากรณ์
+    pub fn parse_statement(mut self) -> Result<Statement, CalcError> {
+        let statement = match (&self.peek().kind, self.tokens.get(self.at + 1)) {
+            (TokenKind::Ident(name), Some(next)) if next.kind == TokenKind::Equals => {
+                let name = name.clone();
+                self.at += 2;
+                Statement::Assign {
+                    name,
+                    value: self.expression(0)?,
+                }
+            }
+            _ => Statement::Expr(self.expression(0)?),
+        };
+        self.expect(&TokenKind::Eof, "ตัวดำเนินการหรือจบบรรทัด")?;
+        Ok(statement)
+    }
+
+    fn peek(&self) -> &Token {
+        &self.tokens[self.at]
+    }
+
+    fn next(&mut self) -> Token {
+        let token = self.tokens[self.at].clone();
+        if token.kind != TokenKind::Eof {
+            self.at += 1;
+        }
+        token
+    }
+
+    fn expect(&mut self, kind: &TokenKind, expected: &'static str) -> Result<Span, CalcError> {
+        let token = self.next();
+        if &token.kind == kind {
+            Ok(token.span)
+        } else {
+            Err(unexpected(&token, expected))
+        }
+    }
+
+    /// นับความลึกรอบ `binary` - เกิน `MAX_DEPTH` แล้วชี้ token ที่เริ่มชั้นนั้น
+    fn expression(&mut self, min_bp: u8) -> Result<Expr, CalcError> {
+        if self.depth == MAX_DEPTH {
+            return Err(CalcError::new(CalcErrorKind::TooDeep, self.peek().span));
+        }
+        self.depth += 1;
+        let expr = self.binary(min_bp);
+        self.depth -= 1;
+        expr
+    }
+
+    /// แกนของ Pratt parsing: อ่าน prefix หนึ่งตัว แล้ววนกิน operator ที่ binding power ซ้าย ≥ `min_bp`
+    fn binary(&mut self, min_bp: u8) -> Result<Expr, CalcError> {
+        let entry_depth = self.depth;
+        let expr = self.fold_operators(min_bp);
+        self.depth = entry_depth;
+        expr
+    }
+
+    /// ทุกครั้งที่พับ operator ต้นไม้ฝั่งซ้ายลึกขึ้นหนึ่งชั้น จึงนับเข้า `depth` ด้วย
+    /// (`binary` คืนค่าเดิมให้หลังวนจบ)
+    fn fold_operators(&mut self, min_bp: u8) -> Result<Expr, CalcError> {
+        let mut lhs = self.prefix()?;
+        loop {
+            let op = match self.peek().kind {
+                TokenKind::Plus => BinaryOp::Add,
+                TokenKind::Minus => BinaryOp::Sub,
+                TokenKind::Star => BinaryOp::Mul,
+                TokenKind::Slash => BinaryOp::Div,
+                TokenKind::Percent => BinaryOp::Rem,
+                TokenKind::Caret => BinaryOp::Pow,
+                _ => break,
+            };
+            let (left_bp, right_bp) = op.binding_power();
+            if left_bp < min_bp {
+                break;
+            }
+            if self.depth == MAX_DEPTH {
+                return Err(CalcError::new(CalcErrorKind::TooDeep, self.peek().span));
+            }
+            let op_span = self.next().span;
+            let rhs_start = self.peek().span;
+            let rhs = self.expression(right_bp)?;
+            let rhs_span = rhs_start.to(self.tokens[self.at - 1].span);
+            lhs = Expr::Binary {
+                op,
+                lhs: Box::new(lhs),
+                rhs: Box::new(rhs),
+                // error ตอนประเมิน (เช่นหารศูนย์) ชี้ที่ operator กับตัวขวา
+                span: op_span.to(rhs_span),
+            };
+            self.depth += 1;
+        }
+        Ok(lhs)
+    }
+
+    fn prefix(&mut self) -> Result<Expr, CalcError> {
+        let token = self.next();
+        match token.kind {
+            TokenKind::Number(value) => Ok(Expr::Number(value)),
+            TokenKind::Minus => {
+                let inner = self.expression(PREFIX_BP)?;
+                Ok(Expr::Negate(Box::new(inner)))
+            }
+            TokenKind::LParen => {
+                let inner = self.expression(0)?;
+                self.expect(&TokenKind::RParen, " ')'")?;
+                Ok(inner)
+            }
+            TokenKind::Ident(name) if self.peek().kind == TokenKind::LParen => {
+                self.next();
+                let mut args = Vec::new();
+                if self.peek().kind != TokenKind::RParen {
+                    loop {
+                        args.push(self.expression(0)?);
+                        if self.peek().kind != TokenKind::Comma {
+                            break;
+                        }
+                        self.next();
+                    }
+                }
+                let close = self.expect(&TokenKind::RParen, " ',' หรือ ')'")?;
+                Ok(Expr::Call {
+                    name,
+                    args,
+                    span: token.span.to(close),
+                })
+            }
+            TokenKind::Ident(name) => Ok(Expr::Variable {
+                name,
+                span: token.span,
+            }),
+            _ => Err(unexpected(&token, "ตัวเลข ตัวแปร หรือ '('")),
+        }
+    }
+}
+
+fn unexpected(token: &Token, expected: &'static str) -> CalcError {
+    CalcError::new(
+        CalcErrorKind::UnexpectedToken {
+            expected,
+            found: token.kind.to_string(),
+        },
+        token.span,
+    )
+}
+
+/// parse นิพจน์ล้วน (ไม่รับการกำหนดค่า)
+///
+/// # Errors
+///
+/// คืน `CalcError` เมื่อ tokenize หรือ parse ไม่ผ่าน
+pub fn parse(source: &str) -> Result<Expr, CalcError> {
+    let mut parser = Parser::new(source)?;
+    let expr = parser.expression(0)?;
+    parser.expect(&TokenKind::Eof, "ตัวดำเนินการหรือจบบรรทัด")?;
+    Ok(expr)
+}
+
+type Builtin = fn(&[f64]) -> f64;
+
+/// ตัวประเมินค่าที่จำตัวแปรข้ามบรรทัด - ผลล่าสุดเก็บใน `ans`
+pub struct Calculator {
+    variables: HashMap<String, f64>,
+    functions: HashMap<&'static str, (usize, Builtin)>,
+}
+
+impl Calculator {
+    /// เริ่มด้วยค่าคงที่ `pi`, `e` และฟังก์ชันพื้นฐาน
+    #[must_use]
+    pub fn new() -> Self {
+        let variables = HashMap::from([
+            ("pi".to_string(), std::f64::consts::PI),
+            ("e".to_string(), std::f64::consts::E),
+        ]);
+        let functions: HashMap<&'static str, (usize, Builtin)> = HashMap::from([
+            ("sqrt", (1, (|args: &[f64]| args[0].sqrt()) as Builtin)),
+            ("abs", (1, |args: &[f64]| args[0].abs())),
+            ("sin", (1, |args: &[f64]| args[0].sin())),
+            ("cos", (1, |args: &[f64]| args[0].cos())),
+            ("ln", (1, |args: &[f64]| args[0].ln())),
+            ("min", (2, |args: &[f64]| args[0].min(args[1]))),
+            ("max", (2, |args: &[f64]| args[0].max(args[1]))),
+        ]);
+        Self {
+            variables,
+            functions,
+        }
+    }
+
+    #[must_use]
+    pub fn variable(&self, name: &str) -> Option<f64> {
+        self.variables.get(name).copied()
+    }
+
+    /// parse และประเมินหนึ่งบรรทัด - การกำหนดค่าคืนค่าที่กำหนดด้วย
+    ///
+    /// # Errors
+    ///
+    /// คืน `CalcError` เมื่อ parse ไม่ผ่าน อ้างถึงชื่อที่ไม่มี หรือหารด้วยศูนย์
+    pub fn eval_line(&mut self, source: &str) -> Result<f64, CalcError> {
+        let value = match Parser::new(source)?.parse_statement()? {
+            Statement::Assign { name, value } => {
+                let value = self.eval(&value)?;
+                self.variables.insert(name, value);
+                value
+            }
+            Statement::Expr(expr) => self.eval(&expr)?,
+        };
+        self.variables.insert("ans".to_string(), value);
+        Ok(value)
+    }
+
+    /// ประเมินค่า AST แบบ recursive
+    ///
+    /// # Errors
+    ///
+    /// คืน `CalcError` ที่ชี้ span ของ node ที่ประเมินไม่ได้
+    pub fn eval(&self, expr: &Expr) -> Result<f64, CalcError> {
+        match expr {
+            Expr::Number(value) => Ok(*value),
+            Expr::Variable { name, span } => self
+                .variable(name)
+                .ok_or_else(|| CalcError::new(CalcErrorKind::UnknownVariable(name.clone()), *span)),
+            Expr::Negate(inner) => Ok(-self.eval(inner)?),
+            Expr::Binary { op, lhs, rhs, span } => {
+                let (lhs, rhs) = (self.eval(lhs)?, self.eval(rhs)?);
+                match op {
+                    BinaryOp::Div | BinaryOp::Rem if rhs == 0.0 => {
+                        Err(CalcError::new(CalcErrorKind::DivisionByZero, *span))
+                    }
+                    BinaryOp::Add => Ok(lhs + rhs),
+                    BinaryOp::Sub => Ok(lhs - rhs),
+                    BinaryOp::Mul => Ok(lhs * rhs),
+                    BinaryOp::Div => Ok(lhs / rhs),
+                    BinaryOp::Rem => Ok(lhs % rhs),
+                    BinaryOp::Pow => Ok(lhs.powf(rhs)),
+                }
+            }
+            Expr::Call { name, args, span } => {
+                let &(arity, function) = self.functions.get(name.as_str()).ok_or_else(|| {
+                    CalcError::new(CalcErrorKind::UnknownFunction(name.clone()), *span)
+                })?;
+                if args.len() != arity {
+                    return Err(CalcError::new(
+                        CalcErrorKind::WrongArity {
+                            name: name.clone(),
+                            expected: arity,
+                            found: args.len(),
+                        },
+                        *span,
+                    ));
+                }
+                let values = args
+                    .iter()
+                    .map(|arg| self.eval(arg))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(function(&values))
+            }
+        }
+    }
+}
+
+impl Default for Calculator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+const PROMPT: &str = "calc> ";
+
+/// REPL ของเครื่องคิดเลข - อ่านทีละบรรทัดจนจบ input หรือพิมพ์ `quit`
+///
+/// รับ `BufRead`/`Write` แทน stdin/stdout เพื่อให้ทดสอบได้ด้วย buffer ธรรมดา
+///
+/// # Errors
+///
+/// คืน `io::Error` เมื่ออ่านหรือเขียนไม่สำเร็จ - error ของนิพจน์แค่พิมพ์แล้วทำงานต่อ
+pub fn run_repl(input: &mut impl BufRead, out: &mut impl Write) -> io::Result<()> {
+    writeln!(
+        out,
+        "🧮 เครื่องคิดเลข: + - * / % ^ ( ) ตัวแปร (x = 2) ฟังก์ชัน sqrt abs sin cos ln min max"
+    )?;
+    writeln!(out, "   พิมพ์ quit เพื่อออก")?;
+    let mut calculator = Calculator::new();
+    let mut line = String::new();
+    loop {
+        write!(out, "{PROMPT}")?;
+        out.flush()?;
+        line.clear();
+        if input.read_line(&mut line)? == 0 {
+            writeln!(out)?;
+            break;
+        }
+        let source = line.trim();
+        match source {
+            "" => {}
+            "quit" | "exit" => break,
+            _ => match calculator.eval_line(source) {
+                Ok(value) => writeln!(out, "= {value}")?,
+                Err(error) => {
+                    // เยื้องให้ลูกศรตรงกับข้อความหลัง prompt
+                    let pointer = error.render(source);
+                    let pointer = pointer.lines().nth(1).unwrap_or_default();
+                    writeln!(out, "{}{pointer}", " ".repeat(PROMPT.len()))?;
+                }
+            },
+        }
+    }
+    writeln!(out, "👋 บ๊ายบาย")
+}
+
+/// สาธิต tokenizer → Pratt parser → evaluator
+///
+/// # Panics
+///
+/// panic ถ้านิพจน์ตัวอย่างที่ถูกต้อง parse ไม่ผ่าน
+pub fn demonstrate_expression_evaluator() {
+    println!("\n🧮 === Expression Evaluator: Pratt Parser === 🧮");
+
+    println!("\n🌳 binding power ตัดสินรูปต้นไม้:");
+    for source in [
+        "1 + 2 * 3",
+        "(1 + 2) * 3",
+        "8 - 4 - 2",
+        "2 ^ 3 ^ 2",
+        "-2 ^ 2",
+    ] {
+        let expr = parse(source).expect("ตัวอย่างต้อง parse ได้");
+        let calculator = Calculator::new();
+        println!(
+            "   {source:<12} → {:<22} = {}",
+            expr.to_string(),
+            calculator.eval(&expr).unwrap_or(f64::NAN)
+        );
+    }
+
+    println!("\n📝 ตัวแปรและฟังก์ชัน:");
+    let mut calculator = Calculator::new();
+    for source in ["r = 3", "area = pi * r ^ 2", "max(area, 30) / sqrt(4)"] {
+        match calculator.eval_line(source) {
+            Ok(value) => println!("   {source:<24} = {value:.4}"),
+            Err(error) => println!("   ❌ {error}"),
+        }
+    }
+
+    println!("\n🎯 error บอกตำแหน่ง:");
+    for source in ["1 + * 2", "(1 + 2", "10 / (r - 3)", "sqrt(1, 2)", "2 # 3"] {
+        if let Err(error) = calculator.eval_line(source) {
+            for line in error.render(source).lines() {
+                println!("   {line}");
+            }
+        }
+    }
+    println!("   💡 ลองเองได้ด้วย: cargo run -- --calc");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tree(source: &str) -> String {
+        parse(source).unwrap().to_string()
+    }
+
+    #[test]
+    fn test_precedence_and_associativity() {
+        assert_eq!(tree("1 + 2 * 3"), "(+ 1 (* 2 3))");
+        assert_eq!(tree("1 * 2 + 3"), "(+ (* 1 2) 3)");
+        assert_eq!(tree("(1 + 2) * 3"), "(* (+ 1 2) 3)");
+        assert_eq!(tree("8 - 4 - 2"), "(- (- 8 4) 2)");
+        assert_eq!(tree("8 / 4 / 2"), "(/ (/ 8 4) 2)");
+        assert_eq!(tree("2 ^ 3 ^ 2"), "(^ 2 (^ 3 2))");
+        assert_eq!(tree("-2 ^ 2"), "(- (^ 2 2))");
+        assert_eq!(tree("-2 * 3"), "(* (- 2) 3)");
+        assert_eq!(tree("2 ^ -1"), "(^ 2 (- 1))");
+        assert_eq!(tree("max(1, 2 + 3) % 4"), "(% (max 1 (+ 2 3)) 4)");
+
+        let mut calculator = Calculator::new();
+        assert_eq!(calculator.eval_line("8 - 4 - 2"), Ok(2.0));
+        assert_eq!(calculator.eval_line("2 ^ 3 ^ 2"), Ok(512.0));
+        assert_eq!(calculator.eval_line("-2 ^ 2"), Ok(-4.0));
+        assert_eq!(calculator.eval_line("7 % 4 * 2"), Ok(6.0));
+    }
+
+    #[test]
+    fn test_variables_and_functions() {
+        let mut calculator = Calculator::new();
+        assert_eq!(calculator.eval_line("x = 3"), Ok(3.0));
+        assert_eq!(calculator.eval_line("y = x * x + 1"), Ok(10.0));
+        assert_eq!(calculator.eval_line("sqrt(y - 1) + max(x, 5)"), Ok(8.0));
+        assert_eq!(calculator.eval_line("ans * 2"), Ok(16.0));
+        assert_eq!(calculator.variable("y"), Some(10.0));
+        assert!((calculator.eval_line("cos(pi)").unwrap() + 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_errors_point_at_span() {
+        let mut calculator = Calculator::new();
+        let error = |calculator: &mut Calculator, source: &str| {
+            let error = calculator.eval_line(source).unwrap_err();
+            (error.kind.clone(), error.span)
+        };
+
+        assert_eq!(
+            error(&mut calculator, "1 + * 2"),
+            (
+                CalcErrorKind::UnexpectedToken {
+                    expected: "ตัวเลข ตัวแปร หรือ '('",
+                    found: "'*'".to_string()
+                },
+                Span::new(4, 5)
+            )
+        );
+        assert_eq!(error(&mut calculator, "(1 + 2").1, Span::new(6, 6));
+        assert_eq!(error(&mut calculator, "1 2").1, Span::new(2, 3));
+        assert_eq!(
+            error(&mut calculator, "2 # 3"),
+            (CalcErrorKind::UnexpectedChar('#'), Span::new(2, 3))
+        );
+        assert_eq!(
+            error(&mut calculator, "1.2.3"),
+            (
+                CalcErrorKind::InvalidNumber("1.2.3".to_string()),
+                Span::new(0, 5)
+            )
+        );
+        assert_eq!(
+            error(&mut calculator, "1 + foo"),
+            (
+                CalcErrorKind::UnknownVariable("foo".to_string()),
+                Span::new(4, 7)
+            )
+        );
+        assert_eq!(
+            error(&mut calculator, "10 / (2 - 2)"),
+            (CalcErrorKind::DivisionByZero, Span::new(3, 12))
+        );
+        assert_eq!(
+            error(&mut calculator, "min(1)"),
+            (
+                CalcErrorKind::WrongArity {
+                    name: "min".to_string(),
+                    expected: 2,
+                    found: 1
+                },
+                Span::new(0, 6)
+            )
+        );
+
+        let rendered = calculator
+            .eval_line("1 + foo")
+            .unwrap_err()
+            .render("1 + foo");
+        assert_eq!(rendered, "1 + foo\n    ^^^ ไม่รู้จักตัวแปร 'foo' (ตำแหน่ง 4)");
+    }
+
+    #[test]
+    fn test_nesting_depth_is_limited() {
+        let nested = |depth: usize| format!("{}1{}", "(".repeat(depth), ")".repeat(depth));
+        // ชั้นนอกสุดของนิพจน์ก็นับเป็นหนึ่งชั้น
+        assert_eq!(parse(&nested(MAX_DEPTH - 1)).unwrap(), Expr::Number(1.0));
+
+        let error = parse(&nested(MAX_DEPTH)).unwrap_err();
+        assert_eq!(
+            (error.kind, error.span),
+            (CalcErrorKind::TooDeep, Span::new(MAX_DEPTH, MAX_DEPTH + 1))
+        );
+        assert_eq!(
+            parse(&nested(5_000)).unwrap_err().kind,
+            CalcErrorKind::TooDeep
+        );
+        assert_eq!(
+            parse(&"-".repeat(5_000)).unwrap_err().kind,
+            CalcErrorKind::TooDeep
+        );
+    }
+
+    #[test]
+    fn test_flat_operator_chain_is_limited() {
+        let chain = |terms: usize| vec!["1"; terms].join("+");
+        let mut calculator = Calculator::new();
+        assert_eq!(calculator.eval_line(&chain(MAX_DEPTH)), Ok(128.0));
+
+        // operator ตัวที่ MAX_DEPTH อยู่หลังเลข 1 ตัวที่ MAX_DEPTH (ตำแหน่ง 2 * MAX_DEPTH - 1)
+        let error = parse(&chain(MAX_DEPTH + 1)).unwrap_err();
+        assert_eq!(
+            (error.kind, error.span),
+            (
+                CalcErrorKind::TooDeep,
+                Span::new(2 * MAX_DEPTH - 1, 2 * MAX_DEPTH)
+            )
+        );
+        // เดิม 5000 พจน์ทำให้ eval/drop ของ AST ล้น stack
+        assert_eq!(
+            calculator.eval_line(&chain(5_000)).unwrap_err().kind,
+            CalcErrorKind::TooDeep
+        );
+    }
+
+    #[test]
+    fn test_repl_session() {
+        let mut input = &b"a = 2\na ^ 10\n\n1 +\nquit\n9\n"[..];
+        let mut out = Vec::new();
+        run_repl(&mut input, &mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+
+        assert!(out.contains("= 2\n"));
+        assert!(out.contains("= 1024\n"));
+        assert!(out.contains("   ^ คาดว่าจะเจอ"));
+        assert!(!out.contains("= 9"), "หลัง quit ต้องไม่อ่านต่อ");
+        assert!(out.ends_with("👋 บ๊ายบาย\n"));
+    }
+}
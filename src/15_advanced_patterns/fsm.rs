@@ -0,0 +1,550 @@
+//! Finite State Machine - รวม typestate และ state machine แบบ runtime ไว้ที่เดียว
+//!
+//! 🧱 **typestate** (`Document<Draft>` → `Document<Review>` → `Document<Published>`):
+//! transition ผิดลำดับคอมไพล์ไม่ผ่านเลย เหมาะเมื่อรู้ลำดับตั้งแต่ตอนเขียนโค้ด
+//!
+//! 🚦 **runtime** (`StateMachine<S, E>`): state มาจากข้อมูล/ผู้ใช้ตอนรัน
+//! ตาราง transition มี guard (เงื่อนไข) และ action ตอนเข้า/ออกจาก state
+//!
+//! ```
+//! use rust_concepts::advanced_patterns::fsm::StateMachine;
+//!
+//! #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+//! enum Light { Off, On }
+//! #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+//! enum Switch { Flip }
+//!
+//! let mut lamp = StateMachine::new(Light::Off)
+//!     .transition(Light::Off, Switch::Flip, Light::On)
+//!     .transition(Light::On, Switch::Flip, Light::Off);
+//! lamp.fire(Switch::Flip).unwrap();
+//! assert_eq!(lamp.state(), Light::On);
+//! ```
+//!
+//! typestate กันไม่ให้ publish เอกสารที่ยังไม่ผ่านการรีวิว:
+//!
+//! ```compile_fail
+//! use rust_concepts::advanced_patterns::fsm::Document;
+//!
+//! let draft = Document::new("ข่าวด่วน").write("...");
+//! let published = draft.approve("บรรณาธิการ"); // ❌ Draft ไม่มีเมธอด approve
+//! ```
+
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+use crate::game_development::game_logic::{GamePhase, GamePhaseEvent};
+use crate::macros::state_machine_dsl::{DslOrderStatus, OrderEvent};
+
+// ==================== 🧱 Typestate ====================
+
+/// ✍️ กำลังเขียน
+#[derive(Debug)]
+pub struct Draft;
+
+/// 🔍 รอรีวิว
+#[derive(Debug)]
+pub struct Review;
+
+/// 📰 เผยแพร่แล้ว
+#[derive(Debug)]
+pub struct Published;
+
+/// 📄 เอกสารที่ state อยู่ใน type - แต่ละ state มีเมธอดต่างกัน
+#[derive(Debug)]
+pub struct Document<State> {
+    title: String,
+    body: String,
+    notes: Vec<String>,
+    reviewer: Option<String>,
+    _state: PhantomData<State>,
+}
+
+impl<State> Document<State> {
+    fn into_state<Next>(self) -> Document<Next> {
+        Document {
+            title: self.title,
+            body: self.body,
+            notes: self.notes,
+            reviewer: self.reviewer,
+            _state: PhantomData,
+        }
+    }
+
+    #[must_use]
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    #[must_use]
+    pub fn body(&self) -> &str {
+        &self.body
+    }
+
+    /// ความเห็นจากการรีวิวที่ถูกตีกลับ
+    #[must_use]
+    pub fn notes(&self) -> &[String] {
+        &self.notes
+    }
+}
+
+impl Document<Draft> {
+    #[must_use]
+    pub fn new(title: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            body: String::new(),
+            notes: Vec::new(),
+            reviewer: None,
+            _state: PhantomData,
+        }
+    }
+
+    /// เขียนต่อท้าย - ทำได้เฉพาะตอนเป็น draft
+    #[must_use]
+    pub fn write(mut self, text: &str) -> Self {
+        self.body.push_str(text);
+        self
+    }
+
+    /// ส่งรีวิว
+    #[must_use]
+    pub fn submit(self) -> Document<Review> {
+        self.into_state()
+    }
+}
+
+impl Document<Review> {
+    /// อนุมัติและเผยแพร่
+    #[must_use]
+    pub fn approve(mut self, reviewer: &str) -> Document<Published> {
+        self.reviewer = Some(reviewer.to_string());
+        self.into_state()
+    }
+
+    /// ตีกลับไปเป็น draft พร้อมความเห็น
+    #[must_use]
+    pub fn reject(mut self, note: &str) -> Document<Draft> {
+        self.notes.push(note.to_string());
+        self.into_state()
+    }
+}
+
+impl Document<Published> {
+    /// ผู้อนุมัติ - มีเสมอเพราะเข้า `Published` ได้ทาง `approve` ทางเดียว
+    #[must_use]
+    pub fn reviewer(&self) -> &str {
+        self.reviewer.as_deref().unwrap_or_default()
+    }
+}
+
+// ==================== 🚦 Runtime ====================
+
+/// 👣 transition ที่กำลังเกิด - ส่งให้ guard และ action
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Step<S, E> {
+    pub from: S,
+    pub event: E,
+    pub to: S,
+}
+
+/// ❌ `fire` ไม่สำเร็จ - state ไม่เปลี่ยน
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FsmError<S, E> {
+    /// ไม่มี transition ของ `event` จาก `state`
+    NoTransition { state: S, event: E },
+    /// มี transition แต่ guard ไม่อนุญาตทุกตัว
+    GuardRejected { state: S, event: E },
+}
+
+impl<S: fmt::Debug, E: fmt::Debug> fmt::Display for FsmError<S, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoTransition { state, event } => {
+                write!(f, "no transition for {event:?} in state {state:?}")
+            }
+            Self::GuardRejected { state, event } => {
+                write!(f, "guard rejected {event:?} in state {state:?}")
+            }
+        }
+    }
+}
+
+impl<S: fmt::Debug, E: fmt::Debug> std::error::Error for FsmError<S, E> {}
+
+type Guard<S, E> = Rc<dyn Fn(&Step<S, E>) -> bool>;
+type Action<S, E> = Box<dyn FnMut(&Step<S, E>)>;
+
+struct Transition<S, E> {
+    from: S,
+    event: E,
+    to: S,
+    guard: Option<Guard<S, E>>,
+}
+
+/// 🚦 state machine แบบ runtime
+///
+/// - `(from, event)` เดียวกันมีได้หลาย transition - ตัวแรก (ตามลำดับที่ประกาศ) ที่ guard ผ่านจะถูกใช้
+/// - ลำดับตอน `fire`: exit actions ของ state เดิม → เปลี่ยน state → entry actions ของ state ใหม่
+/// - transition กลับเข้า state เดิมก็รัน exit/entry ด้วย
+pub struct StateMachine<S, E> {
+    state: S,
+    transitions: Vec<Transition<S, E>>,
+    on_enter: Vec<(S, Action<S, E>)>,
+    on_exit: Vec<(S, Action<S, E>)>,
+}
+
+impl<S: fmt::Debug, E> fmt::Debug for StateMachine<S, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StateMachine")
+            .field("state", &self.state)
+            .field("transitions", &self.transitions.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S, E> StateMachine<S, E>
+where
+    S: Copy + PartialEq + 'static,
+    E: Copy + PartialEq + 'static,
+{
+    #[must_use]
+    pub const fn new(initial: S) -> Self {
+        Self {
+            state: initial,
+            transitions: Vec::new(),
+            on_enter: Vec::new(),
+            on_exit: Vec::new(),
+        }
+    }
+
+    /// สร้างจากฟังก์ชัน transition ที่มีอยู่แล้ว (เช่น `next` ที่ `state_machine!` สร้าง)
+    #[must_use]
+    pub fn from_table(
+        initial: S,
+        states: &[S],
+        events: &[E],
+        next: impl Fn(S, E) -> Option<S>,
+    ) -> Self {
+        let mut machine = Self::new(initial);
+        for &from in states {
+            for &event in events {
+                if let Some(to) = next(from, event) {
+                    machine = machine.transition(from, event, to);
+                }
+            }
+        }
+        machine
+    }
+
+    #[must_use]
+    pub fn transition(mut self, from: S, event: E, to: S) -> Self {
+        self.transitions.push(Transition {
+            from,
+            event,
+            to,
+            guard: None,
+        });
+        self
+    }
+
+    /// transition ที่เกิดได้เมื่อ `guard` คืน `true` เท่านั้น
+    #[must_use]
+    pub fn guarded(
+        mut self,
+        from: S,
+        event: E,
+        to: S,
+        guard: impl Fn(&Step<S, E>) -> bool + 'static,
+    ) -> Self {
+        self.transitions.push(Transition {
+            from,
+            event,
+            to,
+            guard: Some(Rc::new(guard)),
+        });
+        self
+    }
+
+    /// ใส่ guard ให้ transition `(from, event)` ที่ประกาศไว้แล้ว (แทนที่ guard เดิม)
+    #[must_use]
+    pub fn guard(
+        mut self,
+        from: S,
+        event: E,
+        guard: impl Fn(&Step<S, E>) -> bool + 'static,
+    ) -> Self {
+        let guard: Guard<S, E> = Rc::new(guard);
+        for transition in &mut self.transitions {
+            if transition.from == from && transition.event == event {
+                transition.guard = Some(Rc::clone(&guard));
+            }
+        }
+        self
+    }
+
+    /// action ตอนเข้า `state`
+    #[must_use]
+    pub fn on_enter(mut self, state: S, action: impl FnMut(&Step<S, E>) + 'static) -> Self {
+        self.on_enter.push((state, Box::new(action)));
+        self
+    }
+
+    /// action ตอนออกจาก `state`
+    #[must_use]
+    pub fn on_exit(mut self, state: S, action: impl FnMut(&Step<S, E>) + 'static) -> Self {
+        self.on_exit.push((state, Box::new(action)));
+        self
+    }
+
+    #[must_use]
+    pub const fn state(&self) -> S {
+        self.state
+    }
+
+    /// หา transition ที่จะเกิดถ้า `fire(event)` ตอนนี้
+    fn resolve(&self, event: E) -> Result<Step<S, E>, FsmError<S, E>> {
+        let mut matched = false;
+        for transition in &self.transitions {
+            if transition.from != self.state || transition.event != event {
+                continue;
+            }
+            matched = true;
+            let step = Step {
+                from: self.state,
+                event,
+                to: transition.to,
+            };
+            if transition.guard.as_ref().is_none_or(|guard| guard(&step)) {
+                return Ok(step);
+            }
+        }
+        let state = self.state;
+        Err(if matched {
+            FsmError::GuardRejected { state, event }
+        } else {
+            FsmError::NoTransition { state, event }
+        })
+    }
+
+    /// `event` จะทำให้เปลี่ยน state ได้หรือไม่ (รวมการตรวจ guard)
+    #[must_use]
+    pub fn can_fire(&self, event: E) -> bool {
+        self.resolve(event).is_ok()
+    }
+
+    /// ส่ง event เข้าไป แล้วคืน transition ที่เกิดขึ้น
+    ///
+    /// # Errors
+    ///
+    /// คืน `NoTransition` ถ้าไม่มี transition ของ `event` จาก state ปัจจุบัน
+    /// หรือ `GuardRejected` ถ้า guard ไม่อนุญาต - ทั้งสองกรณี state และ action ไม่ถูกแตะต้อง
+    pub fn fire(&mut self, event: E) -> Result<Step<S, E>, FsmError<S, E>> {
+        let step = self.resolve(event)?;
+        for (state, action) in &mut self.on_exit {
+            if *state == step.from {
+                action(&step);
+            }
+        }
+        self.state = step.to;
+        for (state, action) in &mut self.on_enter {
+            if *state == step.to {
+                action(&step);
+            }
+        }
+        Ok(step)
+    }
+}
+
+/// 📦 workflow คำสั่งซื้อ: ใช้ตาราง `DslOrderStatus` จาก `state_machine!` แล้วเพิ่มของที่ตารางคงที่ทำไม่ได้
+///
+/// - guard: ส่งของ (`Ship`) ได้เมื่อยังมีสต็อก
+/// - entry action: เข้า `Shipped` แล้วตัดสต็อก / เข้า `Cancelled` แล้วจดเหตุการณ์
+#[must_use]
+pub fn order_workflow(
+    stock: &Rc<Cell<u32>>,
+    log: &Rc<RefCell<Vec<String>>>,
+) -> StateMachine<DslOrderStatus, OrderEvent> {
+    let in_stock = Rc::clone(stock);
+    let shipped_stock = Rc::clone(stock);
+    let cancel_log = Rc::clone(log);
+    StateMachine::from_table(
+        DslOrderStatus::Draft,
+        DslOrderStatus::ALL,
+        OrderEvent::ALL,
+        |status, event| status.next(event).ok(),
+    )
+    .guard(DslOrderStatus::Paid, OrderEvent::Ship, move |_| {
+        in_stock.get() > 0
+    })
+    .on_enter(DslOrderStatus::Shipped, move |_| {
+        shipped_stock.set(shipped_stock.get() - 1);
+    })
+    .on_enter(DslOrderStatus::Cancelled, move |step| {
+        cancel_log
+            .borrow_mut()
+            .push(format!("cancelled from {}", step.from));
+    })
+}
+
+/// 🎮 ช่วงของเกมเป็น `StateMachine` - ตารางเดียวกับ `GamePhase::next`
+#[must_use]
+pub fn game_phase_machine() -> StateMachine<GamePhase, GamePhaseEvent> {
+    StateMachine::from_table(
+        GamePhase::MainMenu,
+        GamePhase::ALL,
+        GamePhaseEvent::ALL,
+        |phase, event| phase.next(event).ok(),
+    )
+}
+
+/// สาธิต FSM ทั้งสองแบบ
+pub fn demonstrate_fsm() {
+    println!("\n🚦 === Finite State Machines ===");
+
+    let draft = Document::new("Rust 2024").write("let chains มาแล้ว");
+    let back_to_draft = draft.submit().reject("ขอตัวอย่างโค้ด");
+    let published = back_to_draft
+        .write(" - `if let Some(x) = a && x > 0`")
+        .submit()
+        .approve("บก.");
+    println!(
+        "🧱 typestate: \"{}\" อนุมัติโดย {} (ความเห็น: {})",
+        published.title(),
+        published.reviewer(),
+        published.notes().join(", ")
+    );
+
+    let stock = Rc::new(Cell::new(0));
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let mut order = order_workflow(&stock, &log);
+    for event in [OrderEvent::Place, OrderEvent::Pay, OrderEvent::Ship] {
+        match order.fire(event) {
+            Ok(step) => println!("📦 {} --{}--> {}", step.from, step.event, step.to),
+            Err(error) => println!("📦 🚫 {error}"),
+        }
+    }
+    stock.set(1);
+    if let Ok(step) = order.fire(OrderEvent::Ship) {
+        println!(
+            "📦 เติมสต็อกแล้ว: {} --{}--> {} (สต็อกเหลือ {})",
+            step.from,
+            step.event,
+            step.to,
+            stock.get()
+        );
+    }
+
+    let mut game = game_phase_machine().on_enter(GamePhase::Paused, |_| println!("🎮 🔇 หยุดเพลง"));
+    for event in [
+        GamePhaseEvent::QuickPlay,
+        GamePhaseEvent::Pause,
+        GamePhaseEvent::Win,
+    ] {
+        if let Err(error) = game.fire(event) {
+            println!("🎮 🚫 {error}");
+        }
+    }
+    println!("🎮 ตอนนี้อยู่ที่ {}", game.state());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_typestate_document_flow() {
+        let published = Document::new("t")
+            .write("a")
+            .submit()
+            .reject("สั้นไป")
+            .write("b")
+            .submit()
+            .approve("ed");
+        assert_eq!(published.body(), "ab");
+        assert_eq!(published.notes(), ["สั้นไป"]);
+        assert_eq!(published.reviewer(), "ed");
+    }
+
+    #[test]
+    fn test_order_workflow_transitions_exhaustively() {
+        use DslOrderStatus::{Cancelled, Delivered, Draft, Paid, Placed, Shipped};
+        use OrderEvent::{Cancel, Deliver, Pay, Place, Ship};
+        let expected = [
+            (Draft, Place, Some(Placed)),
+            (Draft, Cancel, Some(Cancelled)),
+            (Placed, Pay, Some(Paid)),
+            (Placed, Cancel, Some(Cancelled)),
+            (Paid, Ship, Some(Shipped)),
+            (Paid, Cancel, Some(Cancelled)),
+            (Shipped, Deliver, Some(Delivered)),
+        ];
+
+        for &from in DslOrderStatus::ALL {
+            for &event in OrderEvent::ALL {
+                let stock = Rc::new(Cell::new(1));
+                let mut machine = order_workflow(&stock, &Rc::default());
+                machine.state = from;
+                let allowed = expected
+                    .iter()
+                    .find(|(f, e, _)| *f == from && *e == event)
+                    .and_then(|(_, _, to)| *to);
+                match allowed {
+                    Some(to) => assert_eq!(machine.fire(event).map(|step| step.to), Ok(to)),
+                    None => assert_eq!(
+                        machine.fire(event),
+                        Err(FsmError::NoTransition { state: from, event })
+                    ),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_guards_and_entry_exit_actions() {
+        let stock = Rc::new(Cell::new(0));
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let exits = Rc::new(Cell::new(0));
+        let counted = Rc::clone(&exits);
+        let mut order = order_workflow(&stock, &log).on_exit(DslOrderStatus::Paid, move |_| {
+            counted.set(counted.get() + 1);
+        });
+        order.fire(OrderEvent::Place).unwrap();
+        order.fire(OrderEvent::Pay).unwrap();
+
+        // guard ปฏิเสธ: state และ action ไม่ขยับ
+        assert!(!order.can_fire(OrderEvent::Ship));
+        assert_eq!(
+            order.fire(OrderEvent::Ship),
+            Err(FsmError::GuardRejected {
+                state: DslOrderStatus::Paid,
+                event: OrderEvent::Ship
+            })
+        );
+        assert_eq!((order.state(), exits.get()), (DslOrderStatus::Paid, 0));
+
+        stock.set(2);
+        order.fire(OrderEvent::Ship).unwrap();
+        assert_eq!((stock.get(), exits.get()), (1, 1));
+
+        let mut other = order_workflow(&stock, &log);
+        other.fire(OrderEvent::Cancel).unwrap();
+        assert_eq!(*log.borrow(), ["cancelled from Draft"]);
+    }
+
+    #[test]
+    fn test_game_phase_machine_matches_dsl_table() {
+        for &from in GamePhase::ALL {
+            for &event in GamePhaseEvent::ALL {
+                let mut machine = game_phase_machine();
+                machine.state = from;
+                assert_eq!(
+                    machine.fire(event).map(|step| step.to).ok(),
+                    from.next(event).ok()
+                );
+            }
+        }
+    }
+}
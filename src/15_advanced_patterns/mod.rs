@@ -8,8 +8,12 @@ pub mod command_pattern;
 pub mod factory_pattern;
 pub mod observer_pattern;
 pub mod state_pattern;
+pub mod fsm;
 pub mod strategy_pattern;
 pub mod visitor_pattern;
+pub mod expression_evaluator;
+pub mod plugin_system;
+pub mod di_container;
 pub mod newtype_pattern;
 pub mod type_state_pattern;
 pub mod phantom_types;
@@ -29,8 +33,14 @@ pub fn run_advanced_patterns_examples() {
     factory_pattern::demonstrate_factory_pattern();
     observer_pattern::demonstrate_observer();
     state_pattern::demonstrate_state();
+    fsm::demonstrate_fsm();
+    crate::regex_lite::demonstrate_regex_lite();
     strategy_pattern::demonstrate_strategy();
     visitor_pattern::demonstrate_visitor();
+    expression_evaluator::demonstrate_expression_evaluator();
+    crate::interpreter::demonstrate_interpreter();
+    plugin_system::demonstrate_plugin_system();
+    di_container::demonstrate_di_container();
     
     println!();
     
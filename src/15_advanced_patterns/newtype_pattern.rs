@@ -4,7 +4,8 @@
 //! ครอบคลุม Type Safety, Domain Modeling, และ Zero-Cost Abstractions
 
 use std::fmt;
-use std::ops::{Add, Sub, Deref, DerefMut};
+use std::marker::PhantomData;
+use std::ops::{Add, Sub, Mul, Div, Neg, Deref, DerefMut};
 use std::str::FromStr;
 
 /// Email newtype for type safety
@@ -405,6 +406,277 @@ impl fmt::Display for Temperature {
     }
 }
 
+// ===== 📐 Units of measure: newtype + phantom dimension =====
+
+/// 📐 มิติทางฟิสิกส์ในรูป type - ไม่มีค่าให้สร้าง (enum ว่าง) มีไว้เป็น phantom parameter อย่างเดียว
+pub mod dimension {
+    /// มิติหนึ่งมิติพร้อมสัญลักษณ์หน่วย SI ไว้แสดงผล
+    pub trait Dimension {
+        const SYMBOL: &'static str;
+    }
+
+    /// `Self × Rhs` ได้มิติอะไร - ไม่มี impl = คูณกันไม่ได้ (compile error)
+    pub trait DimMul<Rhs> {
+        type Output;
+    }
+
+    /// `Self ÷ Rhs` ได้มิติอะไร
+    pub trait DimDiv<Rhs> {
+        type Output;
+    }
+
+    macro_rules! dimensions {
+        ($($name:ident => $symbol:literal),* $(,)?) => {$(
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+            pub enum $name {}
+
+            impl Dimension for $name {
+                const SYMBOL: &'static str = $symbol;
+            }
+        )*};
+    }
+
+    /// กฎ `A / B = C` หนึ่งข้อให้ความสัมพันธ์ครบสี่แบบ: `A / B`, `A / C`, `B × C` และ `C × B`
+    macro_rules! quotient_rules {
+        ($($numerator:ident / $denominator:ident = $quotient:ident),* $(,)?) => {$(
+            impl DimDiv<$denominator> for $numerator {
+                type Output = $quotient;
+            }
+
+            impl DimDiv<$quotient> for $numerator {
+                type Output = $denominator;
+            }
+
+            impl DimMul<$quotient> for $denominator {
+                type Output = $numerator;
+            }
+
+            impl DimMul<$denominator> for $quotient {
+                type Output = $numerator;
+            }
+        )*};
+    }
+
+    dimensions! {
+        Length => "m",
+        Time => "s",
+        Mass => "kg",
+        Velocity => "m/s",
+        Acceleration => "m/s²",
+        Force => "N",
+    }
+
+    quotient_rules! {
+        Length / Time = Velocity,
+        Velocity / Time = Acceleration,
+        Force / Mass = Acceleration,
+    }
+}
+
+use dimension::{Acceleration, DimDiv, DimMul, Dimension, Force, Length, Mass, Time, Velocity};
+
+/// 📏 ปริมาณที่มีหน่วยกำกับ - ข้างในเป็นแค่ `f64` ตัวเดียว (zero-cost) แต่ type รู้มิติของมัน
+///
+/// บวก/ลบได้เฉพาะมิติเดียวกัน ส่วนคูณ/หารได้มิติใหม่ตามกฎใน [`dimension`]
+/// ความผิดพลาดแบบ "เอาเมตรไปบวกวินาที" จึงเป็น compile error ไม่ใช่ bug ตอนรัน
+///
+/// ```
+/// use rust_concepts::advanced_patterns::newtype_pattern::{Meters, MetersPerSecond, Seconds};
+///
+/// let speed: MetersPerSecond = Meters::new(100.0) / Seconds::new(9.58);
+/// assert!((speed.kilometers_per_hour() - 37.58).abs() < 0.01);
+/// ```
+///
+/// ```compile_fail,E0308
+/// use rust_concepts::advanced_patterns::newtype_pattern::{Meters, Seconds};
+///
+/// // ❌ บวกต่างมิติไม่ได้
+/// let nonsense = Meters::new(5.0) + Seconds::new(2.0);
+/// ```
+///
+/// ```compile_fail,E0308
+/// use rust_concepts::advanced_patterns::newtype_pattern::{Meters, MetersPerSecond};
+///
+/// // ❌ m ÷ (m/s) ได้วินาที ไม่ใช่ความเร็ว
+/// let speed: MetersPerSecond = Meters::new(10.0) / MetersPerSecond::new(2.0);
+/// ```
+///
+/// ```compile_fail,E0277
+/// use rust_concepts::advanced_patterns::newtype_pattern::{Meters, Seconds};
+///
+/// // ❌ ไม่มีกฎ `Length × Time` - compiler หา `DimMul<Time>` ของ `Length` ไม่เจอ
+/// let unknown = Meters::new(3.0) * Seconds::new(4.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Quantity<D> {
+    value: f64,
+    dimension: PhantomData<D>,
+}
+
+/// ระยะทาง (เมตร)
+pub type Meters = Quantity<Length>;
+/// เวลา (วินาที)
+pub type Seconds = Quantity<Time>;
+/// มวล (กิโลกรัม)
+pub type Kilograms = Quantity<Mass>;
+/// ความเร็ว (เมตรต่อวินาที)
+pub type MetersPerSecond = Quantity<Velocity>;
+/// ความเร่ง (เมตรต่อวินาทีกำลังสอง)
+pub type MetersPerSecondSquared = Quantity<Acceleration>;
+/// แรง (นิวตัน = kg·m/s²)
+pub type Newtons = Quantity<Force>;
+
+impl<D> Quantity<D> {
+    /// ค่าในหน่วย SI ของมิตินั้น
+    #[must_use]
+    pub const fn new(value: f64) -> Self {
+        Self {
+            value,
+            dimension: PhantomData,
+        }
+    }
+
+    /// ถอดหน่วยออกเป็น `f64` - ใช้ตอนส่งต่อให้โค้ดที่ไม่รู้จักหน่วย
+    #[must_use]
+    pub const fn value(self) -> f64 {
+        self.value
+    }
+
+    #[must_use]
+    pub const fn abs(self) -> Self {
+        Self::new(self.value.abs())
+    }
+}
+
+impl Meters {
+    #[must_use]
+    pub const fn from_kilometers(km: f64) -> Self {
+        Self::new(km * 1000.0)
+    }
+
+    #[must_use]
+    pub const fn kilometers(self) -> f64 {
+        self.value / 1000.0
+    }
+}
+
+impl From<Distance> for Meters {
+    fn from(distance: Distance) -> Self {
+        Self::new(distance.meters())
+    }
+}
+
+impl From<Meters> for Distance {
+    fn from(meters: Meters) -> Self {
+        Self::from_meters(meters.value())
+    }
+}
+
+impl Seconds {
+    #[must_use]
+    pub const fn from_minutes(minutes: f64) -> Self {
+        Self::new(minutes * 60.0)
+    }
+
+    #[must_use]
+    pub const fn from_millis(millis: f64) -> Self {
+        Self::new(millis / 1000.0)
+    }
+
+    #[must_use]
+    pub const fn minutes(self) -> f64 {
+        self.value / 60.0
+    }
+}
+
+impl MetersPerSecond {
+    #[must_use]
+    pub const fn from_kilometers_per_hour(kmh: f64) -> Self {
+        Self::new(kmh / 3.6)
+    }
+
+    #[must_use]
+    pub const fn kilometers_per_hour(self) -> f64 {
+        self.value * 3.6
+    }
+}
+
+impl MetersPerSecondSquared {
+    /// ความเร่งโน้มถ่วงมาตรฐานของโลก (g₀)
+    pub const STANDARD_GRAVITY: Self = Self::new(9.806_65);
+}
+
+impl<D> Default for Quantity<D> {
+    fn default() -> Self {
+        Self::new(0.0)
+    }
+}
+
+impl<D> Add for Quantity<D> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Self::new(self.value + other.value)
+    }
+}
+
+impl<D> Sub for Quantity<D> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Self::new(self.value - other.value)
+    }
+}
+
+impl<D> Neg for Quantity<D> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.value)
+    }
+}
+
+/// ขยายด้วยตัวเลขไร้หน่วย - มิติไม่เปลี่ยน
+impl<D> Mul<f64> for Quantity<D> {
+    type Output = Self;
+
+    fn mul(self, factor: f64) -> Self::Output {
+        Self::new(self.value * factor)
+    }
+}
+
+impl<D> Div<f64> for Quantity<D> {
+    type Output = Self;
+
+    fn div(self, divisor: f64) -> Self::Output {
+        Self::new(self.value / divisor)
+    }
+}
+
+/// คูณสองปริมาณ - ได้ก็ต่อเมื่อมีกฎ `DimMul` และผลลัพธ์ได้มิติตามกฎนั้น
+impl<A: DimMul<B>, B> Mul<Quantity<B>> for Quantity<A> {
+    type Output = Quantity<A::Output>;
+
+    fn mul(self, other: Quantity<B>) -> Self::Output {
+        Quantity::new(self.value * other.value)
+    }
+}
+
+impl<A: DimDiv<B>, B> Div<Quantity<B>> for Quantity<A> {
+    type Output = Quantity<A::Output>;
+
+    fn div(self, other: Quantity<B>) -> Self::Output {
+        Quantity::new(self.value / other.value)
+    }
+}
+
+impl<D: Dimension> fmt::Display for Quantity<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)?;
+        write!(f, " {}", D::SYMBOL)
+    }
+}
+
 /// Wrapper for Vec with additional functionality
 #[derive(Debug, Clone)]
 pub struct SafeVec<T>(Vec<T>);
@@ -751,6 +1023,19 @@ pub fn demonstrate_newtype() {
         None => println!("❌ Index 10 out of bounds"),
     }
     
+    // Units of measure example
+    println!("\n📐 Units of Measure Example:");
+    let lap = Meters::from_kilometers(1.5);
+    let lap_time = Seconds::from_minutes(2.5);
+    let pace: MetersPerSecond = lap / lap_time;
+    println!("{lap} in {lap_time} = {pace:.2} ({:.1} km/h)", pace.kilometers_per_hour());
+
+    let mass = Kilograms::new(1200.0);
+    let acceleration: MetersPerSecondSquared = MetersPerSecond::from_kilometers_per_hour(100.0) / Seconds::new(8.0);
+    let force: Newtons = mass * acceleration;
+    println!("0-100 km/h in 8 s needs {acceleration:.2} → {force:.0} for {mass}");
+    println!("Distance newtype ↔ Meters: {}", Distance::from(lap + Meters::new(250.0)));
+    
     println!("\n✅ Newtype pattern demonstrated!");
 }
 
@@ -863,4 +1148,41 @@ mod tests {
         assert_eq!(vec.safe_get(1), Some(&84));
         assert_eq!(vec.safe_get(2), None);
     }
+
+    #[test]
+    fn test_units_arithmetic_tracks_dimensions() {
+        let distance = Meters::new(100.0);
+        let time = Seconds::new(20.0);
+
+        let speed: MetersPerSecond = distance / time;
+        assert_eq!(speed, MetersPerSecond::new(5.0));
+        assert_eq!(speed * time, distance);
+        assert_eq!(time * speed, distance);
+        assert_eq!(distance / speed, time);
+
+        let acceleration: MetersPerSecondSquared = speed / Seconds::new(2.0);
+        let force: Newtons = Kilograms::new(4.0) * acceleration;
+        assert_eq!(force, Newtons::new(10.0));
+        assert_eq!(force / Kilograms::new(4.0), acceleration);
+        assert_eq!(force / acceleration, Kilograms::new(4.0));
+
+        assert_eq!(distance + Meters::new(50.0) - Meters::new(30.0), Meters::new(120.0));
+        assert_eq!(-time * 2.0, Seconds::new(-40.0));
+        assert_eq!((-distance).abs() / 4.0, Meters::new(25.0));
+    }
+
+    #[test]
+    fn test_units_conversions() {
+        assert_eq!(Meters::from_kilometers(2.5), Meters::new(2500.0));
+        assert_eq!(Seconds::from_minutes(1.5), Seconds::new(90.0));
+        assert_eq!(Seconds::from_millis(250.0), Seconds::new(0.25));
+        assert!((MetersPerSecond::from_kilometers_per_hour(90.0).value() - 25.0).abs() < 1e-9);
+        assert!((MetersPerSecond::new(10.0).kilometers_per_hour() - 36.0).abs() < 1e-9);
+
+        let distance = Distance::from_miles(1.0);
+        assert_eq!(Distance::from(Meters::from(distance)), distance);
+
+        assert_eq!(format!("{:.1}", Newtons::new(9.81)), "9.8 N");
+        assert_eq!(MetersPerSecondSquared::STANDARD_GRAVITY.to_string(), "9.80665 m/s²");
+    }
 }
\ No newline at end of file
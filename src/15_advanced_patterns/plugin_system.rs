@@ -0,0 +1,557 @@
+//! Plugin System - โหลดความสามารถเพิ่มผ่าน `dyn Plugin`
+//!
+//! 🔌 host ไม่ต้องรู้จัก plugin ล่วงหน้า แค่เรียก lifecycle hooks ตามลำดับ:
+//! `on_load` → `on_start` → ... → `on_shutdown` (ย้อนลำดับตอนปิด)
+//!
+//! plugin ประกาศรวมไว้ที่เดียวด้วย `declare_plugins!` คล้าย `inventory::submit!`
+//! (std ไม่มีวิธีเก็บรายการข้ามไฟล์ตอน link เราจึงให้ macro สร้าง static slice ของ factory แทน)
+//!
+//! ```
+//! use rust_concepts::advanced_patterns::plugin_system::{
+//!     declare_plugins, CorsPlugin, MovementPlugin, PluginHost, PluginRegistry,
+//! };
+//!
+//! declare_plugins! {
+//!     static MY_PLUGINS = [CorsPlugin, MovementPlugin];
+//! }
+//!
+//! let mut registry = PluginRegistry::new();
+//! registry.register_all(MY_PLUGINS).unwrap();
+//! let mut host = PluginHost::new();
+//! registry.load_all(&mut host).unwrap();
+//! assert_eq!(registry.order(), ["CorsPlugin", "MovementPlugin"]);
+//! ```
+
+use std::fmt;
+
+use crate::game_development::ecs_architecture::{MovementSystem, System, World};
+use crate::web_development::middleware::{
+    CorsMiddleware, LoggingMiddleware, Middleware, MiddlewareChain,
+};
+
+pub use crate::declare_plugins;
+
+/// ประกาศรายการ plugin เป็น `static` slice ของ `PluginFactory`
+///
+/// ทุก type ต้อง implement `Plugin + Default`
+#[macro_export]
+macro_rules! declare_plugins {
+    ($(#[$meta:meta])* $vis:vis static $name:ident = [$($plugin:ty),* $(,)?];) => {
+        $(#[$meta])*
+        $vis static $name: &[$crate::advanced_patterns::plugin_system::PluginFactory] = &[
+            $(
+                $crate::advanced_patterns::plugin_system::PluginFactory {
+                    name: stringify!($plugin),
+                    create: || Box::new(<$plugin as Default>::default()),
+                },
+            )*
+        ];
+    };
+}
+
+/// 🏠 สิ่งที่ plugin เข้าไปต่อเติมได้: middleware ของเว็บ และ systems ของเกม
+pub struct PluginHost {
+    pub middleware: MiddlewareChain,
+    pub world: World,
+}
+
+impl Default for PluginHost {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PluginHost {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            middleware: MiddlewareChain::new(),
+            world: World::new(),
+        }
+    }
+
+    /// ต่อ middleware ท้าย chain
+    pub fn add_middleware<M: Middleware + Send + Sync + 'static>(&mut self, middleware: M) {
+        self.middleware = std::mem::take(&mut self.middleware).add(middleware);
+    }
+
+    pub fn add_system<S: System + 'static>(&mut self, system: S) {
+        self.world.add_system(system);
+    }
+}
+
+/// ❌ ข้อผิดพลาดของ plugin
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PluginError {
+    /// มี plugin ชื่อนี้อยู่แล้ว
+    Duplicate(String),
+    /// ไม่รู้จัก plugin ชื่อนี้
+    Unknown(String),
+    /// `on_load` ล้มเหลว - plugin ที่โหลดไปแล้วถูก unload คืน
+    LoadFailed { plugin: String, reason: String },
+}
+
+impl fmt::Display for PluginError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Duplicate(name) => write!(f, "plugin '{name}' is already registered"),
+            Self::Unknown(name) => write!(f, "unknown plugin '{name}'"),
+            Self::LoadFailed { plugin, reason } => {
+                write!(f, "plugin '{plugin}' failed to load: {reason}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for PluginError {}
+
+/// 🔌 Plugin trait - ทุก hook มีค่าเริ่มต้น implement แค่ที่ต้องใช้
+pub trait Plugin {
+    /// ชื่อที่ไม่ซ้ำกันใน registry
+    fn name(&self) -> &'static str;
+
+    /// ค่าน้อยรันก่อน (ค่าเท่ากันเรียงตามลำดับที่ลงทะเบียน)
+    fn priority(&self) -> i32 {
+        0
+    }
+
+    /// ติดตั้งตัวเองลงใน host
+    ///
+    /// # Errors
+    ///
+    /// คืนเหตุผลถ้าติดตั้งไม่ได้ - registry จะ unload plugin ที่โหลดไปก่อนหน้าให้
+    fn on_load(&mut self, _host: &mut PluginHost) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// ทุก plugin โหลดเสร็จแล้ว
+    fn on_start(&mut self, _host: &mut PluginHost) {}
+
+    /// ถูกเรียกในลำดับย้อนกลับของ `on_load`
+    fn on_shutdown(&mut self, _host: &mut PluginHost) {}
+}
+
+/// 🏭 ตัวสร้าง plugin ที่ `declare_plugins!` เก็บไว้
+#[derive(Debug, Clone, Copy)]
+pub struct PluginFactory {
+    pub name: &'static str,
+    pub create: fn() -> Box<dyn Plugin>,
+}
+
+/// สถานะของ plugin ใน registry
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PluginState {
+    Registered,
+    Loaded,
+    Started,
+    Stopped,
+}
+
+struct Entry {
+    plugin: Box<dyn Plugin>,
+    priority: i32,
+    state: PluginState,
+}
+
+/// 📚 เก็บ plugin และเรียก hook ตามลำดับ `priority`
+#[derive(Default)]
+pub struct PluginRegistry {
+    entries: Vec<Entry>,
+}
+
+impl PluginRegistry {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// ลงทะเบียน plugin หนึ่งตัว
+    ///
+    /// # Errors
+    ///
+    /// คืน `PluginError::Duplicate` ถ้าชื่อซ้ำ
+    pub fn register(&mut self, plugin: Box<dyn Plugin>) -> Result<(), PluginError> {
+        if self.position(plugin.name()).is_some() {
+            return Err(PluginError::Duplicate(plugin.name().to_string()));
+        }
+        let priority = plugin.priority();
+        self.entries.push(Entry {
+            plugin,
+            priority,
+            state: PluginState::Registered,
+        });
+        // sort แบบ stable - priority เท่ากันคงลำดับการลงทะเบียน
+        self.entries.sort_by_key(|entry| entry.priority);
+        Ok(())
+    }
+
+    /// ลงทะเบียนทุกตัวจาก `declare_plugins!`
+    ///
+    /// # Errors
+    ///
+    /// คืน `PluginError::Duplicate` ตัวแรกที่ชื่อซ้ำ
+    pub fn register_all(&mut self, factories: &[PluginFactory]) -> Result<(), PluginError> {
+        factories
+            .iter()
+            .try_for_each(|factory| self.register((factory.create)()))
+    }
+
+    /// เปลี่ยนลำดับของ plugin ที่ลงทะเบียนแล้ว (ทับค่า `Plugin::priority`)
+    ///
+    /// # Errors
+    ///
+    /// คืน `PluginError::Unknown` ถ้าไม่มี plugin ชื่อนี้
+    pub fn set_priority(&mut self, name: &str, priority: i32) -> Result<(), PluginError> {
+        let index = self
+            .position(name)
+            .ok_or_else(|| PluginError::Unknown(name.to_string()))?;
+        self.entries[index].priority = priority;
+        self.entries.sort_by_key(|entry| entry.priority);
+        Ok(())
+    }
+
+    /// ชื่อ plugin ตามลำดับที่จะถูกเรียก
+    #[must_use]
+    pub fn order(&self) -> Vec<&'static str> {
+        self.entries
+            .iter()
+            .map(|entry| entry.plugin.name())
+            .collect()
+    }
+
+    #[must_use]
+    pub fn state(&self, name: &str) -> Option<PluginState> {
+        self.position(name).map(|index| self.entries[index].state)
+    }
+
+    /// เรียก `on_load` ตามลำดับ - ถ้าตัวไหนล้ม ตัวที่โหลดไปแล้วจะได้ `on_shutdown` ย้อนกลับ
+    ///
+    /// # Errors
+    ///
+    /// คืน `PluginError::LoadFailed` ของ plugin ตัวแรกที่ล้ม
+    pub fn load_all(&mut self, host: &mut PluginHost) -> Result<(), PluginError> {
+        for index in 0..self.entries.len() {
+            if self.entries[index].state != PluginState::Registered {
+                continue;
+            }
+            if let Err(reason) = self.entries[index].plugin.on_load(host) {
+                for loaded in self.entries[..index].iter_mut().rev() {
+                    if loaded.state == PluginState::Loaded {
+                        loaded.plugin.on_shutdown(host);
+                        loaded.state = PluginState::Registered;
+                    }
+                }
+                return Err(PluginError::LoadFailed {
+                    plugin: self.entries[index].plugin.name().to_string(),
+                    reason,
+                });
+            }
+            self.entries[index].state = PluginState::Loaded;
+        }
+        Ok(())
+    }
+
+    /// เรียก `on_start` ของทุกตัวที่โหลดแล้ว
+    pub fn start_all(&mut self, host: &mut PluginHost) {
+        for entry in self
+            .entries
+            .iter_mut()
+            .filter(|entry| entry.state == PluginState::Loaded)
+        {
+            entry.plugin.on_start(host);
+            entry.state = PluginState::Started;
+        }
+    }
+
+    /// เรียก `on_shutdown` ย้อนลำดับ - ตัวที่โหลดทีหลังอาจพึ่งตัวที่โหลดก่อน
+    pub fn shutdown_all(&mut self, host: &mut PluginHost) {
+        for entry in self.entries.iter_mut().rev() {
+            if matches!(entry.state, PluginState::Loaded | PluginState::Started) {
+                entry.plugin.on_shutdown(host);
+                entry.state = PluginState::Stopped;
+            }
+        }
+    }
+
+    fn position(&self, name: &str) -> Option<usize> {
+        self.entries
+            .iter()
+            .position(|entry| entry.plugin.name() == name)
+    }
+}
+
+/// 📝 plugin ของเว็บ: log ทุก request
+#[derive(Debug, Default)]
+pub struct RequestLoggingPlugin;
+
+impl Plugin for RequestLoggingPlugin {
+    fn name(&self) -> &'static str {
+        "RequestLoggingPlugin"
+    }
+
+    // log ควรเห็น response สุดท้ายหลัง middleware อื่นแก้แล้ว
+    fn priority(&self) -> i32 {
+        100
+    }
+
+    fn on_load(&mut self, host: &mut PluginHost) -> Result<(), String> {
+        host.add_middleware(LoggingMiddleware::new());
+        Ok(())
+    }
+}
+
+/// 🌐 plugin ของเว็บ: เพิ่ม CORS headers
+#[derive(Debug, Default)]
+pub struct CorsPlugin;
+
+impl Plugin for CorsPlugin {
+    fn name(&self) -> &'static str {
+        "CorsPlugin"
+    }
+
+    fn on_load(&mut self, host: &mut PluginHost) -> Result<(), String> {
+        host.add_middleware(CorsMiddleware::new());
+        Ok(())
+    }
+}
+
+/// 🏃 plugin ของเกม: ให้ entity ที่มี `Velocity` เคลื่อนที่
+#[derive(Debug, Default)]
+pub struct MovementPlugin;
+
+impl Plugin for MovementPlugin {
+    fn name(&self) -> &'static str {
+        "MovementPlugin"
+    }
+
+    fn on_load(&mut self, host: &mut PluginHost) -> Result<(), String> {
+        host.add_system(MovementSystem);
+        Ok(())
+    }
+}
+
+declare_plugins! {
+    /// plugin ที่มากับตัวอย่างนี้
+    pub static BUILTIN_PLUGINS = [RequestLoggingPlugin, CorsPlugin, MovementPlugin];
+}
+
+/// สาธิต plugin system
+pub fn demonstrate_plugin_system() {
+    use crate::game_development::ecs_architecture::{Position, Velocity};
+    use crate::web_development::{HttpRequest, HttpResponse, HttpStatus};
+
+    println!("\n🔌 === Plugin System ===");
+
+    let mut registry = PluginRegistry::new();
+    if let Err(e) = registry.register_all(BUILTIN_PLUGINS) {
+        println!("❌ {e}");
+        return;
+    }
+    println!("📚 ลำดับการโหลด: {}", registry.order().join(" → "));
+
+    let mut host = PluginHost::new();
+    if let Err(e) = registry.load_all(&mut host) {
+        println!("❌ {e}");
+        return;
+    }
+    registry.start_all(&mut host);
+
+    let request = HttpRequest::new("GET", "/api/users").with_header("User-Agent", "plugin-demo");
+    let response = host
+        .middleware
+        .process(&request, HttpResponse::new(HttpStatus::Ok));
+    println!(
+        "🌐 CORS header จาก plugin: {:?}",
+        response.headers.get("Access-Control-Allow-Origin")
+    );
+
+    let entity = host
+        .world
+        .spawn_entity()
+        .with(Position::new(0.0, 0.0, 0.0))
+        .with(Velocity::new(2.0, 0.0, 0.0))
+        .build();
+    host.world.update(0.5);
+    if let Some(position) = host.world.storage.get_component::<Position>(entity) {
+        println!("🎮 MovementPlugin ขยับ entity ไปที่ x = {}", position.x);
+    }
+
+    registry.shutdown_all(&mut host);
+    println!("🛑 ปิดทุก plugin แล้ว (ย้อนลำดับ)");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    struct Recorder {
+        name: &'static str,
+        priority: i32,
+        fail: bool,
+        log: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl Recorder {
+        fn boxed(
+            name: &'static str,
+            priority: i32,
+            log: &Arc<Mutex<Vec<String>>>,
+        ) -> Box<dyn Plugin> {
+            Self::with_failure(name, priority, log, false)
+        }
+
+        fn with_failure(
+            name: &'static str,
+            priority: i32,
+            log: &Arc<Mutex<Vec<String>>>,
+            fail: bool,
+        ) -> Box<dyn Plugin> {
+            Box::new(Self {
+                name,
+                priority,
+                fail,
+                log: Arc::clone(log),
+            })
+        }
+    }
+
+    impl Plugin for Recorder {
+        fn name(&self) -> &'static str {
+            self.name
+        }
+
+        fn priority(&self) -> i32 {
+            self.priority
+        }
+
+        fn on_load(&mut self, _host: &mut PluginHost) -> Result<(), String> {
+            self.log.lock().unwrap().push(format!("load {}", self.name));
+            if self.fail {
+                Err("missing dependency".to_string())
+            } else {
+                Ok(())
+            }
+        }
+
+        fn on_start(&mut self, _host: &mut PluginHost) {
+            self.log
+                .lock()
+                .unwrap()
+                .push(format!("start {}", self.name));
+        }
+
+        fn on_shutdown(&mut self, _host: &mut PluginHost) {
+            self.log.lock().unwrap().push(format!("stop {}", self.name));
+        }
+    }
+
+    #[test]
+    fn test_lifecycle_follows_priority() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let mut registry = PluginRegistry::new();
+        registry
+            .register(Recorder::boxed("audio", 10, &log))
+            .unwrap();
+        registry
+            .register(Recorder::boxed("core", -5, &log))
+            .unwrap();
+        registry.register(Recorder::boxed("ui", 10, &log)).unwrap();
+        assert_eq!(
+            registry.register(Recorder::boxed("ui", 0, &log)),
+            Err(PluginError::Duplicate("ui".to_string()))
+        );
+        registry.set_priority("ui", 5).unwrap();
+        assert_eq!(registry.order(), ["core", "ui", "audio"]);
+
+        let mut host = PluginHost::new();
+        registry.load_all(&mut host).unwrap();
+        registry.start_all(&mut host);
+        registry.shutdown_all(&mut host);
+        assert_eq!(registry.state("ui"), Some(PluginState::Stopped));
+        assert_eq!(
+            *log.lock().unwrap(),
+            [
+                "load core",
+                "load ui",
+                "load audio",
+                "start core",
+                "start ui",
+                "start audio",
+                "stop audio",
+                "stop ui",
+                "stop core",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_failed_load_unloads_earlier_plugins() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let mut registry = PluginRegistry::new();
+        registry.register(Recorder::boxed("a", 0, &log)).unwrap();
+        registry.register(Recorder::boxed("b", 1, &log)).unwrap();
+        registry
+            .register(Recorder::with_failure("c", 2, &log, true))
+            .unwrap();
+        registry.register(Recorder::boxed("d", 3, &log)).unwrap();
+
+        let err = registry.load_all(&mut PluginHost::new()).unwrap_err();
+        assert_eq!(
+            err,
+            PluginError::LoadFailed {
+                plugin: "c".to_string(),
+                reason: "missing dependency".to_string()
+            }
+        );
+        assert_eq!(
+            *log.lock().unwrap(),
+            ["load a", "load b", "load c", "stop b", "stop a"]
+        );
+        assert_eq!(registry.state("a"), Some(PluginState::Registered));
+        assert_eq!(registry.state("d"), Some(PluginState::Registered));
+    }
+
+    #[test]
+    fn test_declared_plugins_extend_web_and_game() {
+        use crate::game_development::ecs_architecture::{Position, Velocity};
+        use crate::web_development::{HttpRequest, HttpResponse, HttpStatus};
+
+        let mut registry = PluginRegistry::new();
+        registry.register_all(BUILTIN_PLUGINS).unwrap();
+        assert_eq!(
+            registry.order(),
+            ["CorsPlugin", "MovementPlugin", "RequestLoggingPlugin"]
+        );
+        assert!(registry.register_all(BUILTIN_PLUGINS).is_err());
+
+        let mut host = PluginHost::new();
+        registry.load_all(&mut host).unwrap();
+
+        let response = host.middleware.process(
+            &HttpRequest::new("GET", "/"),
+            HttpResponse::new(HttpStatus::Ok),
+        );
+        assert_eq!(
+            response
+                .headers
+                .get("Access-Control-Allow-Origin")
+                .map(String::as_str),
+            Some("*")
+        );
+
+        let entity = host
+            .world
+            .spawn_entity()
+            .with(Position::new(0.0, 0.0, 0.0))
+            .with(Velocity::new(2.0, 0.0, 0.0))
+            .build();
+        host.world.update(0.5);
+        let position = host
+            .world
+            .storage
+            .get_component::<Position>(entity)
+            .unwrap();
+        assert!((position.x - 1.0).abs() < f32::EPSILON);
+    }
+}
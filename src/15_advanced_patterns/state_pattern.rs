@@ -2,6 +2,8 @@
 //!
 //! State Pattern ช่วยให้ object สามารถเปลี่ยน behavior ได้เมื่อ internal state เปลี่ยน
 //! ใน Rust เราใช้ enum และ trait เพื่อ implement pattern นี้อย่างมีประสิทธิภาพ
+//!
+//! 🔗 เวอร์ชันที่นำกลับมาใช้ซ้ำได้ (`StateMachine` พร้อม guard และ entry/exit actions) อยู่ใน `fsm`
 
 use std::fmt;
 
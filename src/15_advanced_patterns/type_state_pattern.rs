@@ -2,6 +2,8 @@
 //!
 //! การใช้งาน Type State Pattern ใน Rust
 //! ครอบคลุม State Machines, Protocol Implementation, และ Compile-time Safety
+//!
+//! 🔗 เวอร์ชันที่นำกลับมาใช้ซ้ำได้ (typestate + `StateMachine` แบบ runtime) อยู่ใน `fsm`
 
 use std::marker::PhantomData;
 // use std::fmt;
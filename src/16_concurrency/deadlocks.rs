@@ -0,0 +1,600 @@
+//! Deadlocks - เมื่อสอง threads รอกันและกันตลอดไป! 🔒🔒💀
+//!
+//! deadlock แบบคลาสสิก: thread 1 ถือ lock A แล้วรอ B ส่วน thread 2 ถือ B แล้วรอ A
+//! - 💀 **`transfer_naive`**: lock ตามลำดับ "ต้นทาง → ปลายทาง" - โอนสวนทางกันพร้อมกันเมื่อไหร่ค้างทันที
+//! - ✅ **`transfer_ordered`**: lock ตามลำดับ id เสมอ (lock ordering) - วงจรรอกันเกิดขึ้นไม่ได้
+//! - 🕵️ **`OrderedMutex<T>`**: จำลำดับการ lock ที่เคยเห็น (แนวเดียวกับ lockdep ของ Linux)
+//!   แล้ว panic พร้อมรายงานวงจรทันทีที่มีคน lock สวนลำดับ - ไม่ต้องรอให้ deadlock เกิดจริง
+//!
+//! ⚠️ deadlock จริงทำให้โปรแกรมค้าง ตัวอย่างในบทนี้จึงรอแค่ชั่วคราวแล้วรายงานผล
+//! ตั้ง env `RUST_CONCEPTS_DEADLOCK=1` ถ้าอยากเห็นโปรแกรมค้างจริงๆ (กด Ctrl+C เพื่อออก)
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, MutexGuard, PoisonError, TryLockError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// env ที่เปิดให้ `deadlocks_example` ค้างจริง
+pub const DEADLOCK_ENV: &str = "RUST_CONCEPTS_DEADLOCK";
+
+/// 🏦 บัญชีธนาคาร - `id` อยู่นอก lock จึงใช้กำหนดลำดับได้โดยไม่ต้อง lock ก่อน
+#[derive(Debug)]
+pub struct Account {
+    pub id: u32,
+    balance: Mutex<i64>,
+}
+
+impl Account {
+    #[must_use]
+    pub const fn new(id: u32, balance: i64) -> Self {
+        Self {
+            id,
+            balance: Mutex::new(balance),
+        }
+    }
+
+    #[must_use]
+    pub fn balance(&self) -> i64 {
+        *self.lock()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, i64> {
+        self.balance.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// lock โดยรอไม่เกิน `patience` (`None` = รอตลอดไปแบบ `Mutex::lock`)
+    fn lock_within(&self, patience: Option<Duration>) -> Option<MutexGuard<'_, i64>> {
+        let Some(patience) = patience else {
+            return Some(self.lock());
+        };
+        let deadline = Instant::now() + patience;
+        loop {
+            match self.balance.try_lock() {
+                Ok(guard) => return Some(guard),
+                Err(TryLockError::Poisoned(poisoned)) => return Some(poisoned.into_inner()),
+                Err(TryLockError::WouldBlock) if Instant::now() < deadline => {
+                    thread::sleep(Duration::from_millis(1));
+                }
+                Err(TryLockError::WouldBlock) => return None,
+            }
+        }
+    }
+}
+
+/// ❌ รอ lock ที่สองไม่ได้ภายในเวลาที่กำหนด - อาการของ deadlock
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stuck {
+    pub holding: u32,
+    pub waiting_for: u32,
+}
+
+impl fmt::Display for Stuck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "holding account {} while waiting for account {}",
+            self.holding, self.waiting_for
+        )
+    }
+}
+
+/// 💀 โอนเงินแบบ naive: lock ต้นทางก่อน แล้วค่อย lock ปลายทาง
+///
+/// `after_first_lock` ถูกเรียกระหว่างสอง lock - ตัวอย่างใช้มันบังคับจังหวะให้ deadlock เกิดทุกครั้ง
+///
+/// # Errors
+///
+/// คืน [`Stuck`] ถ้า lock ปลายทางไม่ได้ภายใน `patience` (ยอดเงินไม่เปลี่ยน)
+pub fn transfer_naive(
+    from: &Account,
+    to: &Account,
+    amount: i64,
+    patience: Option<Duration>,
+    after_first_lock: impl FnOnce(),
+) -> Result<(), Stuck> {
+    let mut source = from.lock();
+    after_first_lock();
+    let mut destination = to.lock_within(patience).ok_or(Stuck {
+        holding: from.id,
+        waiting_for: to.id,
+    })?;
+    *source -= amount;
+    *destination += amount;
+    drop(destination);
+    drop(source);
+    Ok(())
+}
+
+/// ✅ โอนเงินแบบมี lock ordering: lock บัญชี id น้อยก่อนเสมอ ไม่ว่าจะโอนไปทางไหน
+///
+/// ทุก thread ขอ lock ตามลำดับเดียวกัน จึงไม่มีใครถือ lock "ตัวหลัง" แล้วรอ "ตัวก่อน" ได้
+///
+/// # Panics
+///
+/// Panics ถ้า `from` กับ `to` มี id เดียวกัน (lock ซ้ำจะค้างเอง)
+pub fn transfer_ordered(
+    from: &Account,
+    to: &Account,
+    amount: i64,
+    after_first_lock: impl FnOnce(),
+) {
+    assert_ne!(from.id, to.id, "cannot transfer to the same account");
+    let (first, second) = if from.id < to.id {
+        (from, to)
+    } else {
+        (to, from)
+    };
+    let mut first = first.lock();
+    after_first_lock();
+    let mut second = second.lock();
+    let (source, destination) = if from.id < to.id {
+        (&mut *first, &mut *second)
+    } else {
+        (&mut *second, &mut *first)
+    };
+    *source -= amount;
+    *destination += amount;
+}
+
+/// จุดนัดพบที่รอได้ไม่เกิน `patience` - ทำให้ทั้งสอง thread ถือ lock แรกก่อนขอ lock ที่สอง
+struct Rendezvous {
+    arrived: AtomicUsize,
+    parties: usize,
+    patience: Duration,
+}
+
+impl Rendezvous {
+    fn wait(&self) {
+        self.arrived.fetch_add(1, Ordering::SeqCst);
+        let deadline = Instant::now() + self.patience;
+        while self.arrived.load(Ordering::SeqCst) < self.parties && Instant::now() < deadline {
+            thread::yield_now();
+        }
+    }
+}
+
+/// 🎬 โอนเงินสวนทางกันพร้อมกัน 2 thread (1 → 2 และ 2 → 1) แล้วคืนผลของแต่ละ thread
+///
+/// - `ordered = false` และ `patience = Some(..)`: อย่างน้อยหนึ่ง thread คืน [`Stuck`] - deadlock ที่ถูกจับได้
+///   (คนที่ยอมแพ้ก่อนปล่อย lock แรก อีกคนจึงโอนต่อได้ - timeout คือทางหนีจาก deadlock แบบหนึ่ง)
+/// - `ordered = false` และ `patience = None`: ค้างตลอดไปจริงๆ 💀
+/// - `ordered = true`: ทั้งสองสำเร็จเสมอ
+///
+/// # Panics
+///
+/// Panics ถ้า thread ที่โอนเงิน panic
+#[must_use]
+pub fn reproduce_deadlock(
+    ordered: bool,
+    patience: Option<Duration>,
+) -> ([i64; 2], Vec<Result<(), Stuck>>) {
+    let accounts = [Account::new(1, 100), Account::new(2, 100)];
+    let rendezvous = Rendezvous {
+        arrived: AtomicUsize::new(0),
+        parties: 2,
+        // แบบ ordered อีก thread ติดอยู่ที่ lock แรกจึงมาไม่ถึงจุดนัด - รอสั้นๆ พอ
+        patience: Duration::from_millis(if ordered { 50 } else { 1_000 }),
+    };
+    let results = thread::scope(|scope| {
+        let transfers: Vec<_> = [(0, 1, 30), (1, 0, 10)]
+            .into_iter()
+            .map(|(from, to, amount)| {
+                let (from, to) = (&accounts[from], &accounts[to]);
+                let rendezvous = &rendezvous;
+                scope.spawn(move || {
+                    if ordered {
+                        transfer_ordered(from, to, amount, || rendezvous.wait());
+                        Ok(())
+                    } else {
+                        transfer_naive(from, to, amount, patience, || rendezvous.wait())
+                    }
+                })
+            })
+            .collect();
+        transfers
+            .into_iter()
+            .map(|transfer| transfer.join().unwrap())
+            .collect()
+    });
+    (accounts.map(|account| account.balance()), results)
+}
+
+/// 🕵️ ลำดับการ lock ที่ขัดกับที่เคยเห็น
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LockOrderViolation {
+    /// lock ตัวที่ thread นี้ถืออยู่แล้วซ้ำ - `std::sync::Mutex` จะค้าง (หรือ panic)
+    Reentrant { lock: &'static str },
+    /// lock `acquiring` ขณะถือ `holding` แต่เคยมีคน lock จาก `acquiring` ไปหา `holding` มาก่อน
+    Inversion {
+        acquiring: &'static str,
+        holding: &'static str,
+        /// วงจรที่จะเกิด เริ่มและจบที่ `holding`
+        cycle: Vec<&'static str>,
+        /// thread ที่สร้างลำดับเดิม (ขอบแรกของวงจร)
+        first_seen_on: String,
+    },
+}
+
+impl fmt::Display for LockOrderViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Reentrant { lock } => write!(
+                f,
+                "lock order violation: `{lock}` is already held by this thread, locking it again deadlocks"
+            ),
+            Self::Inversion {
+                acquiring,
+                holding,
+                cycle,
+                first_seen_on,
+            } => write!(
+                f,
+                "lock order violation: acquiring `{acquiring}` while holding `{holding}`\n  \
+                 `{acquiring}` was locked before `{holding}` on thread `{first_seen_on}`\n  \
+                 potential deadlock cycle: {}",
+                cycle.join(" -> ")
+            ),
+        }
+    }
+}
+
+impl std::error::Error for LockOrderViolation {}
+
+/// หนึ่ง lock ในกราฟลำดับ - `edges[next]` = thread ที่ lock `next` ขณะถือ lock นี้เป็นคนแรก
+struct LockNode {
+    name: &'static str,
+    edges: BTreeMap<usize, String>,
+}
+
+/// กราฟ "ถือ A แล้ว lock B" ของทุก `OrderedMutex` ในโปรแกรม
+static LOCK_GRAPH: Mutex<BTreeMap<usize, LockNode>> = Mutex::new(BTreeMap::new());
+static NEXT_LOCK_ID: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    /// `OrderedMutex` ที่ thread นี้ถืออยู่ ตามลำดับที่ lock
+    static HELD: RefCell<Vec<usize>> = const { RefCell::new(Vec::new()) };
+}
+
+fn lock_graph() -> MutexGuard<'static, BTreeMap<usize, LockNode>> {
+    LOCK_GRAPH.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// เส้นทางจาก `from` ไป `to` ตามขอบที่เคยเห็น (DFS) รวมทั้งสองปลาย
+fn find_path(graph: &BTreeMap<usize, LockNode>, from: usize, to: usize) -> Option<Vec<usize>> {
+    let mut stack = vec![vec![from]];
+    let mut visited = vec![from];
+    while let Some(path) = stack.pop() {
+        let last = *path.last()?;
+        if last == to {
+            return Some(path);
+        }
+        for &next in graph
+            .get(&last)
+            .map(|node| node.edges.keys())
+            .into_iter()
+            .flatten()
+        {
+            if !visited.contains(&next) {
+                visited.push(next);
+                let mut longer = path.clone();
+                longer.push(next);
+                stack.push(longer);
+            }
+        }
+    }
+    None
+}
+
+/// 🕵️ `Mutex<T>` ที่ตรวจลำดับการ lock ทุกครั้ง
+///
+/// ครั้งแรกที่ thread ใดถือ A แล้ว lock B จะบันทึก "A → B" ไว้ ถ้าต่อมามี thread ไหน
+/// ถือ B (หรืออะไรก็ตามที่ตามหลัง A) แล้วมา lock A จะได้ [`LockOrderViolation`] ทันที
+/// แม้สอง thread จะไม่เคยชนกันจริงก็ตาม - deadlock ที่ "รอวันเกิด" ถูกจับได้ตั้งแต่ตอนทดสอบ
+pub struct OrderedMutex<T> {
+    id: usize,
+    name: &'static str,
+    inner: Mutex<T>,
+}
+
+impl<T> OrderedMutex<T> {
+    #[must_use]
+    pub fn new(name: &'static str, value: T) -> Self {
+        let id = NEXT_LOCK_ID.fetch_add(1, Ordering::Relaxed);
+        lock_graph().insert(
+            id,
+            LockNode {
+                name,
+                edges: BTreeMap::new(),
+            },
+        );
+        Self {
+            id,
+            name,
+            inner: Mutex::new(value),
+        }
+    }
+
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// lock พร้อมตรวจลำดับ
+    ///
+    /// # Panics
+    ///
+    /// Panics พร้อมรายงานวงจรถ้าลำดับการ lock ขัดกับที่เคยเห็น (ดู [`Self::lock_checked`])
+    pub fn lock(&self) -> OrderedMutexGuard<'_, T> {
+        self.lock_checked()
+            .unwrap_or_else(|violation| panic!("{violation}"))
+    }
+
+    /// lock พร้อมตรวจลำดับ แต่คืน error แทน panic
+    ///
+    /// # Errors
+    ///
+    /// คืน [`LockOrderViolation`] (โดยยังไม่แตะ lock จริง) ถ้า thread นี้ถือ lock นี้อยู่แล้ว
+    /// หรือถ้าเคยมีคน lock ตัวนี้ก่อนตัวใดตัวหนึ่งที่ thread นี้ถืออยู่
+    pub fn lock_checked(&self) -> Result<OrderedMutexGuard<'_, T>, LockOrderViolation> {
+        let held = HELD.with_borrow(Clone::clone);
+        if held.contains(&self.id) {
+            return Err(LockOrderViolation::Reentrant { lock: self.name });
+        }
+        {
+            let mut graph = lock_graph();
+            for &holding in &held {
+                if let Some(path) = find_path(&graph, self.id, holding) {
+                    let name = |id: &usize| graph.get(id).map_or("?", |node| node.name);
+                    let first_seen_on = graph
+                        .get(&path[0])
+                        .and_then(|node| node.edges.get(&path[1]))
+                        .cloned()
+                        .unwrap_or_default();
+                    return Err(LockOrderViolation::Inversion {
+                        acquiring: self.name,
+                        holding: name(&holding),
+                        cycle: std::iter::once(&holding).chain(&path).map(name).collect(),
+                        first_seen_on,
+                    });
+                }
+            }
+            let current = thread::current();
+            let thread_name = current.name().unwrap_or("<unnamed>");
+            for holding in &held {
+                if let Some(node) = graph.get_mut(holding) {
+                    node.edges
+                        .entry(self.id)
+                        .or_insert_with(|| thread_name.to_string());
+                }
+            }
+        }
+        let guard = self.inner.lock().unwrap_or_else(PoisonError::into_inner);
+        HELD.with_borrow_mut(|held| held.push(self.id));
+        Ok(OrderedMutexGuard { id: self.id, guard })
+    }
+}
+
+impl<T> Drop for OrderedMutex<T> {
+    fn drop(&mut self) {
+        let mut graph = lock_graph();
+        graph.remove(&self.id);
+        for node in graph.values_mut() {
+            node.edges.remove(&self.id);
+        }
+    }
+}
+
+/// guard ของ `OrderedMutex` - ปลดแล้วลบตัวเองออกจากรายการ lock ที่ thread นี้ถือ
+pub struct OrderedMutexGuard<'a, T> {
+    id: usize,
+    guard: MutexGuard<'a, T>,
+}
+
+impl<T> Deref for OrderedMutexGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.guard
+    }
+}
+
+impl<T> DerefMut for OrderedMutexGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.guard
+    }
+}
+
+impl<T> Drop for OrderedMutexGuard<'_, T> {
+    fn drop(&mut self) {
+        // ปลด guard ได้ไม่ตามลำดับ จึงลบตัวที่ตรง id แทนการ pop
+        HELD.with_borrow_mut(|held| {
+            if let Some(index) = held.iter().rposition(|&id| id == self.id) {
+                held.remove(index);
+            }
+        });
+    }
+}
+
+/// ตัวอย่าง deadlock, lock ordering และตัวตรวจลำดับการ lock 🔒🕵️
+///
+/// # Panics
+///
+/// Panics ถ้า thread ในตัวอย่าง panic
+pub fn deadlocks_example() {
+    println!("💀 โอนเงินสวนทางกันแบบ naive (1 → 2 และ 2 → 1 พร้อมกัน):");
+    let (_, results) = reproduce_deadlock(false, Some(Duration::from_millis(200)));
+    for result in &results {
+        match result {
+            Ok(()) => println!("  ✅ โอนสำเร็จ"),
+            Err(stuck) => println!("  ⏳ ค้าง: {stuck}"),
+        }
+    }
+    if std::env::var_os(DEADLOCK_ENV).is_some() {
+        println!("  💀 {DEADLOCK_ENV} ถูกตั้งไว้ - รอแบบ Mutex::lock ปกติ (ค้างตลอดไป กด Ctrl+C)");
+        let _ = reproduce_deadlock(false, None);
+    } else {
+        println!("  💡 ตั้ง {DEADLOCK_ENV}=1 เพื่อดูโปรแกรมค้างจริง");
+    }
+
+    println!("✅ lock ตามลำดับ id เสมอ:");
+    let (accounts, results) = reproduce_deadlock(true, None);
+    println!(
+        "  โอนสำเร็จ {}/2 | ยอดคงเหลือ {} + {} = {}",
+        results.iter().filter(|result| result.is_ok()).count(),
+        accounts[0],
+        accounts[1],
+        accounts[0] + accounts[1]
+    );
+
+    println!("🕵️ OrderedMutex จับลำดับที่ขัดกันได้ก่อน deadlock จะเกิดจริง:");
+    let inventory = OrderedMutex::new("inventory", 10);
+    let orders = OrderedMutex::new("orders", Vec::<u32>::new());
+    thread::scope(|scope| {
+        thread::Builder::new()
+            .name("checkout".to_string())
+            .spawn_scoped(scope, || {
+                let _inventory = inventory.lock();
+                orders.lock().push(1);
+            })
+            .expect("spawn checkout thread");
+    });
+    let _orders = orders.lock();
+    match inventory.lock_checked() {
+        Ok(_) => println!("  😱 ไม่มีใครสังเกต"),
+        Err(violation) => {
+            for line in violation.to_string().lines() {
+                println!("  🚨 {}", line.trim());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_naive_transfers_deadlock_and_are_detected() {
+        let (balances, results) = reproduce_deadlock(false, Some(Duration::from_millis(100)));
+        let expected = [
+            Stuck {
+                holding: 1,
+                waiting_for: 2,
+            },
+            Stuck {
+                holding: 2,
+                waiting_for: 1,
+            },
+        ];
+        assert!(results.iter().any(Result::is_err), "{results:?}");
+        for (result, stuck) in results.iter().zip(expected) {
+            assert!(result.is_ok() || *result == Err(stuck), "{result:?}");
+        }
+        // โอนไม่สำเร็จก็ไม่มีเงินหาย
+        assert_eq!(balances[0] + balances[1], 200);
+    }
+
+    #[test]
+    fn test_ordered_transfers_never_deadlock() {
+        let (balances, results) = reproduce_deadlock(true, None);
+        assert!(results.iter().all(Result::is_ok));
+        assert_eq!(balances, [80, 120]);
+
+        // โอนสวนทางกันไปมาหลาย thread - ถ้ามี deadlock test นี้จะค้าง
+        let accounts: Vec<Account> = (0..4).map(|id| Account::new(id, 1_000)).collect();
+        thread::scope(|scope| {
+            for worker in 0..8_usize {
+                let accounts = &accounts;
+                scope.spawn(move || {
+                    for round in 0..500 {
+                        let from = (worker + round) % 4;
+                        let to = (from + 1 + round % 3) % 4;
+                        transfer_ordered(&accounts[from], &accounts[to], 1, || {});
+                    }
+                });
+            }
+        });
+        assert_eq!(accounts.iter().map(Account::balance).sum::<i64>(), 4_000);
+    }
+
+    #[test]
+    fn test_ordered_mutex_reports_inverted_order_across_threads() {
+        let first = OrderedMutex::new("first", 1);
+        let second = OrderedMutex::new("second", 2);
+        thread::scope(|scope| {
+            thread::Builder::new()
+                .name("establisher".to_string())
+                .spawn_scoped(scope, || {
+                    let _first = first.lock();
+                    let _second = second.lock();
+                })
+                .unwrap();
+        });
+
+        // ลำดับเดิมยังใช้ได้ และปลด guard สลับลำดับได้
+        let a = first.lock();
+        let b = second.lock();
+        drop(a);
+        drop(b);
+
+        let b = second.lock();
+        let violation = first.lock_checked().err().unwrap();
+        assert_eq!(
+            violation,
+            LockOrderViolation::Inversion {
+                acquiring: "first",
+                holding: "second",
+                cycle: vec!["second", "first", "second"],
+                first_seen_on: "establisher".to_string(),
+            }
+        );
+        let report = violation.to_string();
+        assert!(report.contains("potential deadlock cycle: second -> first -> second"));
+        // ตรวจก่อนแตะ lock จริง - thread นี้ยังถือแค่ second
+        drop(b);
+        assert_eq!(*first.lock(), 1);
+    }
+
+    #[test]
+    fn test_ordered_mutex_finds_longer_cycles_and_reentrancy() {
+        let a = OrderedMutex::new("a", ());
+        let b = OrderedMutex::new("b", ());
+        let c = OrderedMutex::new("c", ());
+        {
+            let _a = a.lock();
+            let _b = b.lock();
+        }
+        {
+            let _b = b.lock();
+            let _c = c.lock();
+        }
+        let _c = c.lock();
+        match a.lock_checked() {
+            Err(LockOrderViolation::Inversion { cycle, .. }) => {
+                assert_eq!(cycle, ["c", "a", "b", "c"]);
+            }
+            other => panic!("expected a cycle, got {:?}", other.map(|_| ())),
+        }
+        assert_eq!(
+            c.lock_checked().err(),
+            Some(LockOrderViolation::Reentrant { lock: "c" })
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "lock order violation: acquiring `ledger` while holding `audit`")]
+    fn test_ordered_mutex_panics_on_violation() {
+        let ledger = OrderedMutex::new("ledger", 0);
+        let audit = OrderedMutex::new("audit", 0);
+        {
+            let _ledger = ledger.lock();
+            let _audit = audit.lock();
+        }
+        let _audit = audit.lock();
+        let _ledger = ledger.lock();
+    }
+}
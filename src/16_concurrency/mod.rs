@@ -7,11 +7,17 @@
 //! - Async/Await Programming
 //! - Atomic Operations
 //! - Lock-free Programming
+//! - Deadlocks, lock ordering และตัวตรวจลำดับการ lock
+//! - Read-mostly data: `RwLock` vs RCU (`ArcSwap`) vs `SeqLock`
 
 use std::thread;
 use std::time::Duration;
 
+pub mod deadlocks;
+pub mod mpmc_channel;
 pub mod practice_concurrency;
+pub mod rcu;
+pub mod thread_pool;
 
 pub use practice_concurrency::*;
 
@@ -31,6 +37,19 @@ pub fn run_concurrency_examples() {
     println!("\n⚛️ === Atomic Operations === ⚛️");
     atomic_operations_example();
     
+    println!("\n📬 === Bounded MPMC Channels === 📬");
+    mpmc_channel::bounded_channel_example();
+    mpmc_channel::lock_free_ring_buffer_example();
+    
+    println!("\n🏊‍♂️ === Thread Pool === 🏊‍♂️");
+    thread_pool::thread_pool_example();
+    
+    println!("\n💀 === Deadlocks & Lock Ordering === 💀");
+    deadlocks::deadlocks_example();
+    
+    println!("\n📖 === Read-Copy-Update === 📖");
+    rcu::rcu_example();
+    
     println!("\n⚡ === แบบฝึกหัด Concurrency === ⚡");
     practice_concurrency::practice_concurrency();
     practice_concurrency::scoped_threads_example();
@@ -0,0 +1,630 @@
+//! Bounded MPMC Channel - สร้าง channel เองตั้งแต่ศูนย์! 📬🔧
+//!
+//! `std::sync::mpsc` รองรับผู้รับแค่คนเดียว (single consumer)
+//! ที่นี่เราจะสร้าง channel แบบ multi-producer multi-consumer (MPMC) เอง 2 แบบ:
+//! - 🔒 **`bounded`**: ใช้ `Mutex` + `Condvar` - send/recv แบบ blocking เมื่อเต็ม/ว่าง
+//! - ⚛️ **`LockFreeRingBuffer`**: ring buffer ที่ใช้ atomics ล้วนๆ (Vyukov bounded queue)
+
+use std::cell::UnsafeCell;
+use std::collections::VecDeque;
+use std::fmt;
+use std::mem::MaybeUninit;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::thread;
+
+/// Error เมื่อส่งข้อมูลไม่ได้เพราะไม่มีผู้รับเหลืออยู่ - คืนค่าที่ส่งไม่สำเร็จกลับไป 📭
+#[derive(Debug, PartialEq, Eq)]
+pub struct SendError<T>(pub T);
+
+impl<T> fmt::Display for SendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "sending on a closed channel")
+    }
+}
+
+/// Error เมื่อรับข้อมูลไม่ได้เพราะ channel ว่างและไม่มีผู้ส่งเหลืออยู่ 📪
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecvError;
+
+impl fmt::Display for RecvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "receiving on an empty and closed channel")
+    }
+}
+
+/// สถานะภายในของ channel ที่ถูกป้องกันด้วย `Mutex`
+struct State<T> {
+    queue: VecDeque<T>,
+    senders: usize,
+    receivers: usize,
+}
+
+/// ส่วนที่แชร์กันระหว่าง `Sender` และ `Receiver` ทั้งหมด
+struct Shared<T> {
+    state: Mutex<State<T>>,
+    not_empty: Condvar,
+    not_full: Condvar,
+    capacity: usize,
+}
+
+impl<T> Shared<T> {
+    fn lock(&self) -> MutexGuard<'_, State<T>> {
+        // ถ้า thread อื่น panic ระหว่างถือ lock ข้อมูลในคิวยังใช้ได้อยู่
+        self.state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+/// ฝั่งส่งของ bounded channel - clone ได้เพื่อมีผู้ส่งหลายคน 📤
+pub struct Sender<T> {
+    shared: Arc<Shared<T>>,
+}
+
+/// ฝั่งรับของ bounded channel - clone ได้เพื่อมีผู้รับหลายคน 📥
+pub struct Receiver<T> {
+    shared: Arc<Shared<T>>,
+}
+
+/// สร้าง bounded MPMC channel ที่เก็บข้อมูลได้สูงสุด `capacity` ชิ้น
+///
+/// # Panics
+///
+/// Panics ถ้า `capacity` เป็น 0
+#[must_use]
+pub fn bounded<T>(capacity: usize) -> (Sender<T>, Receiver<T>) {
+    assert!(capacity > 0, "capacity must be greater than zero");
+
+    let shared = Arc::new(Shared {
+        state: Mutex::new(State {
+            queue: VecDeque::with_capacity(capacity),
+            senders: 1,
+            receivers: 1,
+        }),
+        not_empty: Condvar::new(),
+        not_full: Condvar::new(),
+        capacity,
+    });
+
+    (
+        Sender {
+            shared: Arc::clone(&shared),
+        },
+        Receiver { shared },
+    )
+}
+
+impl<T> Sender<T> {
+    /// ส่งข้อมูล - ถ้าคิวเต็มจะ block จนกว่าจะมีที่ว่าง
+    ///
+    /// # Errors
+    ///
+    /// คืน `SendError` พร้อมค่าเดิมถ้าผู้รับทั้งหมดถูก drop ไปแล้ว
+    pub fn send(&self, value: T) -> Result<(), SendError<T>> {
+        let mut state = self.shared.lock();
+        loop {
+            if state.receivers == 0 {
+                return Err(SendError(value));
+            }
+            if state.queue.len() < self.shared.capacity {
+                break;
+            }
+            state = self
+                .shared
+                .not_full
+                .wait(state)
+                .unwrap_or_else(std::sync::PoisonError::into_inner);
+        }
+
+        state.queue.push_back(value);
+        drop(state);
+        self.shared.not_empty.notify_one();
+        Ok(())
+    }
+
+    /// ส่งข้อมูลแบบไม่ block - คืนค่ากลับมาถ้าคิวเต็มหรือไม่มีผู้รับ
+    ///
+    /// # Errors
+    ///
+    /// คืน `SendError` พร้อมค่าเดิมถ้าคิวเต็มหรือผู้รับทั้งหมดถูก drop ไปแล้ว
+    pub fn try_send(&self, value: T) -> Result<(), SendError<T>> {
+        let mut state = self.shared.lock();
+        if state.receivers == 0 || state.queue.len() >= self.shared.capacity {
+            return Err(SendError(value));
+        }
+
+        state.queue.push_back(value);
+        drop(state);
+        self.shared.not_empty.notify_one();
+        Ok(())
+    }
+}
+
+impl<T> Receiver<T> {
+    /// รับข้อมูล - ถ้าคิวว่างจะ block จนกว่าจะมีข้อมูลเข้ามา
+    ///
+    /// # Errors
+    ///
+    /// คืน `RecvError` เมื่อคิวว่างและผู้ส่งทั้งหมดถูก drop ไปแล้ว
+    pub fn recv(&self) -> Result<T, RecvError> {
+        let mut state = self.shared.lock();
+        loop {
+            if let Some(value) = state.queue.pop_front() {
+                drop(state);
+                self.shared.not_full.notify_one();
+                return Ok(value);
+            }
+            if state.senders == 0 {
+                return Err(RecvError);
+            }
+            state = self
+                .shared
+                .not_empty
+                .wait(state)
+                .unwrap_or_else(std::sync::PoisonError::into_inner);
+        }
+    }
+
+    /// รับข้อมูลแบบไม่ block - คืน `None` ถ้าคิวว่าง
+    #[must_use]
+    pub fn try_recv(&self) -> Option<T> {
+        let value = self.shared.lock().queue.pop_front();
+        if value.is_some() {
+            self.shared.not_full.notify_one();
+        }
+        value
+    }
+
+    /// จำนวนข้อมูลที่รออยู่ในคิวตอนนี้
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.shared.lock().queue.len()
+    }
+
+    /// คิวว่างอยู่หรือไม่
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<T> Iterator for Receiver<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.recv().ok()
+    }
+}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        self.shared.lock().senders += 1;
+        Self {
+            shared: Arc::clone(&self.shared),
+        }
+    }
+}
+
+impl<T> Clone for Receiver<T> {
+    fn clone(&self) -> Self {
+        self.shared.lock().receivers += 1;
+        Self {
+            shared: Arc::clone(&self.shared),
+        }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        let mut state = self.shared.lock();
+        state.senders -= 1;
+        if state.senders == 0 {
+            drop(state);
+            // ปลุกผู้รับทุกคนให้รู้ว่าไม่มีข้อมูลใหม่แล้ว
+            self.shared.not_empty.notify_all();
+        }
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        let mut state = self.shared.lock();
+        state.receivers -= 1;
+        if state.receivers == 0 {
+            drop(state);
+            // ปลุกผู้ส่งที่รอที่ว่างอยู่ให้รู้ว่าไม่มีใครรับแล้ว
+            self.shared.not_full.notify_all();
+        }
+    }
+}
+
+/// ช่องเก็บข้อมูลหนึ่งช่องใน ring buffer พร้อมเลข sequence
+struct Slot<T> {
+    sequence: AtomicUsize,
+    value: UnsafeCell<MaybeUninit<T>>,
+}
+
+/// Lock-free bounded MPMC ring buffer - ไม่มี Mutex เลยสักตัว! ⚛️🚀
+///
+/// ใช้อัลกอริทึมของ Dmitry Vyukov: แต่ละช่องมีเลข sequence บอกว่า
+/// พร้อมให้เขียน (`sequence == pos`) หรือพร้อมให้อ่าน (`sequence == pos + 1`)
+/// producer/consumer จอง `pos` ด้วย compare-exchange บน `head`/`tail`
+pub struct LockFreeRingBuffer<T> {
+    buffer: Box<[Slot<T>]>,
+    mask: usize,
+    head: AtomicUsize,
+    tail: AtomicUsize,
+}
+
+// SAFETY: ค่าใน slot ถูกเข้าถึงโดย thread เดียวเท่านั้นหลังจากจอง pos สำเร็จ
+// และการส่งต่อค่าระหว่าง thread ใช้ Release/Acquire บน `sequence`
+unsafe impl<T: Send> Send for LockFreeRingBuffer<T> {}
+// SAFETY: เหตุผลเดียวกับ Send - ไม่มีการแชร์ &T ระหว่าง thread
+unsafe impl<T: Send> Sync for LockFreeRingBuffer<T> {}
+
+impl<T> LockFreeRingBuffer<T> {
+    /// สร้าง ring buffer - ขนาดจะถูกปัดขึ้นเป็นกำลังของ 2
+    ///
+    /// # Panics
+    ///
+    /// Panics ถ้า `capacity` เป็น 0
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "capacity must be greater than zero");
+
+        let capacity = capacity.next_power_of_two();
+        let buffer = (0..capacity)
+            .map(|i| Slot {
+                sequence: AtomicUsize::new(i),
+                value: UnsafeCell::new(MaybeUninit::uninit()),
+            })
+            .collect();
+
+        Self {
+            buffer,
+            mask: capacity - 1,
+            head: AtomicUsize::new(0),
+            tail: AtomicUsize::new(0),
+        }
+    }
+
+    /// ความจุจริงของ buffer (กำลังของ 2)
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// ใส่ข้อมูลลง buffer โดยไม่ block
+    ///
+    /// # Errors
+    ///
+    /// คืนค่าเดิมกลับมาถ้า buffer เต็ม
+    pub fn try_push(&self, value: T) -> Result<(), T> {
+        let mut pos = self.tail.load(Ordering::Relaxed);
+        loop {
+            let slot = &self.buffer[pos & self.mask];
+            let sequence = slot.sequence.load(Ordering::Acquire);
+            #[allow(clippy::cast_possible_wrap)]
+            let diff = sequence.wrapping_sub(pos) as isize;
+
+            match diff.cmp(&0) {
+                std::cmp::Ordering::Equal => match self.tail.compare_exchange_weak(
+                    pos,
+                    pos.wrapping_add(1),
+                    Ordering::Relaxed,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => {
+                        // SAFETY: เราจอง pos นี้สำเร็จ จึงเป็นผู้เขียน slot นี้เพียงคนเดียว
+                        unsafe { (*slot.value.get()).write(value) };
+                        slot.sequence.store(pos.wrapping_add(1), Ordering::Release);
+                        return Ok(());
+                    }
+                    Err(current) => pos = current,
+                },
+                // slot นี้ยังไม่ถูกอ่านออก แปลว่า buffer เต็ม
+                std::cmp::Ordering::Less => return Err(value),
+                std::cmp::Ordering::Greater => pos = self.tail.load(Ordering::Relaxed),
+            }
+        }
+    }
+
+    /// ดึงข้อมูลออกจาก buffer โดยไม่ block - คืน `None` ถ้าว่าง
+    pub fn try_pop(&self) -> Option<T> {
+        let mut pos = self.head.load(Ordering::Relaxed);
+        loop {
+            let slot = &self.buffer[pos & self.mask];
+            let sequence = slot.sequence.load(Ordering::Acquire);
+            #[allow(clippy::cast_possible_wrap)]
+            let diff = sequence.wrapping_sub(pos.wrapping_add(1)) as isize;
+
+            match diff.cmp(&0) {
+                std::cmp::Ordering::Equal => match self.head.compare_exchange_weak(
+                    pos,
+                    pos.wrapping_add(1),
+                    Ordering::Relaxed,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => {
+                        // SAFETY: sequence == pos + 1 แปลว่า producer เขียนค่าเสร็จแล้ว
+                        // และเราเป็นผู้อ่าน slot นี้เพียงคนเดียว
+                        let value = unsafe { (*slot.value.get()).assume_init_read() };
+                        slot.sequence
+                            .store(pos.wrapping_add(self.mask + 1), Ordering::Release);
+                        return Some(value);
+                    }
+                    Err(current) => pos = current,
+                },
+                // ยังไม่มี producer เขียนลง slot นี้ แปลว่า buffer ว่าง
+                std::cmp::Ordering::Less => return None,
+                std::cmp::Ordering::Greater => pos = self.head.load(Ordering::Relaxed),
+            }
+        }
+    }
+
+    /// ใส่ข้อมูลแบบรอ (spin + yield) จนกว่าจะมีที่ว่าง
+    pub fn push(&self, mut value: T) {
+        loop {
+            match self.try_push(value) {
+                Ok(()) => return,
+                Err(returned) => {
+                    value = returned;
+                    thread::yield_now();
+                }
+            }
+        }
+    }
+
+    /// ดึงข้อมูลแบบรอ (spin + yield) จนกว่าจะมีข้อมูล
+    pub fn pop(&self) -> T {
+        loop {
+            if let Some(value) = self.try_pop() {
+                return value;
+            }
+            thread::yield_now();
+        }
+    }
+}
+
+impl<T> Drop for LockFreeRingBuffer<T> {
+    fn drop(&mut self) {
+        // drop ค่าที่ยังค้างอยู่ใน buffer เพื่อไม่ให้ memory leak
+        while self.try_pop().is_some() {}
+    }
+}
+
+/// ตัวอย่าง bounded channel แบบ Mutex + Condvar 🔒📬
+///
+/// # Panics
+///
+/// Panics ถ้า thread ของ producer หรือ consumer panic
+pub fn bounded_channel_example() {
+    println!("🔒 Bounded MPMC channel (Mutex + Condvar):");
+
+    let (sender, receiver) = bounded::<String>(2);
+    let mut handles = vec![];
+
+    for producer_id in 0..2 {
+        let sender = sender.clone();
+        handles.push(thread::spawn(move || {
+            for i in 0..3 {
+                let message = format!("ข้อความ {i} จาก producer {producer_id}");
+                sender.send(message).unwrap();
+            }
+        }));
+    }
+    drop(sender);
+
+    for consumer_id in 0..2 {
+        let receiver = receiver.clone();
+        handles.push(thread::spawn(move || {
+            for message in receiver {
+                println!("  📨 Consumer {consumer_id} ได้รับ: {message}");
+            }
+        }));
+    }
+    drop(receiver);
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    println!("🎉 ทุกข้อความถูกส่งถึงแล้ว (ความจุคิวแค่ 2 แต่ไม่มีอะไรหาย!)");
+}
+
+/// ตัวอย่าง lock-free ring buffer ⚛️🔄
+///
+/// # Panics
+///
+/// Panics ถ้า thread ของ producer หรือ consumer panic
+pub fn lock_free_ring_buffer_example() {
+    println!("⚛️ Lock-free ring buffer:");
+
+    let buffer = Arc::new(LockFreeRingBuffer::new(4));
+    println!("  📏 ความจุ: {}", buffer.capacity());
+
+    let producer = {
+        let buffer = Arc::clone(&buffer);
+        thread::spawn(move || {
+            for i in 1..=8 {
+                buffer.push(i);
+            }
+        })
+    };
+
+    let consumer = {
+        let buffer = Arc::clone(&buffer);
+        thread::spawn(move || (0..8).map(|_| buffer.pop()).sum::<i32>())
+    };
+
+    producer.join().unwrap();
+    let total = consumer.join().unwrap();
+    println!("  ➕ ผลรวมที่ consumer ได้รับ: {total} (ควรเป็น 36)");
+    println!("🎉 ไม่มี Mutex สักตัว แต่ข้อมูลครบถ้วน!");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    const PRODUCERS: usize = 4;
+    const CONSUMERS: usize = 4;
+    const PER_PRODUCER: usize = 2_000;
+
+    #[test]
+    fn test_bounded_send_recv_order() {
+        let (sender, receiver) = bounded(3);
+        for i in 0..3 {
+            sender.send(i).unwrap();
+        }
+        assert_eq!(sender.try_send(99), Err(SendError(99)));
+        assert_eq!(receiver.len(), 3);
+
+        let values: Vec<_> = (0..3).map(|_| receiver.recv().unwrap()).collect();
+        assert_eq!(values, vec![0, 1, 2]);
+        assert!(receiver.is_empty());
+    }
+
+    #[test]
+    fn test_bounded_disconnect() {
+        let (sender, receiver) = bounded::<i32>(1);
+        sender.send(1).unwrap();
+        drop(sender);
+        assert_eq!(receiver.recv(), Ok(1));
+        assert_eq!(receiver.recv(), Err(RecvError));
+
+        let (sender, receiver) = bounded(1);
+        drop(receiver);
+        assert_eq!(sender.send(5), Err(SendError(5)));
+    }
+
+    #[test]
+    fn test_bounded_blocks_until_space() {
+        let (sender, receiver) = bounded(1);
+        sender.send(1).unwrap();
+
+        let handle = thread::spawn(move || {
+            // คิวเต็มอยู่ ต้องรอจนกว่า main thread จะ recv
+            sender.send(2).unwrap();
+        });
+
+        assert_eq!(receiver.recv(), Ok(1));
+        assert_eq!(receiver.recv(), Ok(2));
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn test_bounded_stress_no_lost_or_duplicated() {
+        let (sender, receiver) = bounded(8);
+
+        let producers: Vec<_> = (0..PRODUCERS)
+            .map(|p| {
+                let sender = sender.clone();
+                thread::spawn(move || {
+                    for i in 0..PER_PRODUCER {
+                        sender.send(p * PER_PRODUCER + i).unwrap();
+                    }
+                })
+            })
+            .collect();
+        drop(sender);
+
+        let consumers: Vec<_> = (0..CONSUMERS)
+            .map(|_| {
+                let receiver = receiver.clone();
+                thread::spawn(move || receiver.collect::<Vec<_>>())
+            })
+            .collect();
+        drop(receiver);
+
+        for handle in producers {
+            handle.join().unwrap();
+        }
+
+        let mut seen = HashSet::new();
+        for handle in consumers {
+            for value in handle.join().unwrap() {
+                assert!(seen.insert(value), "duplicated message {value}");
+            }
+        }
+        assert_eq!(seen.len(), PRODUCERS * PER_PRODUCER);
+    }
+
+    #[test]
+    fn test_ring_buffer_capacity_and_full() {
+        let buffer = LockFreeRingBuffer::new(3);
+        assert_eq!(buffer.capacity(), 4);
+
+        for i in 0..4 {
+            buffer.try_push(i).unwrap();
+        }
+        assert_eq!(buffer.try_push(4), Err(4));
+        assert_eq!(buffer.try_pop(), Some(0));
+        assert!(buffer.try_push(4).is_ok());
+
+        let rest: Vec<_> = std::iter::from_fn(|| buffer.try_pop()).collect();
+        assert_eq!(rest, vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_ring_buffer_drops_remaining_values() {
+        let tracker = Arc::new(());
+        let buffer = LockFreeRingBuffer::new(4);
+        buffer.try_push(Arc::clone(&tracker)).unwrap();
+        buffer.try_push(Arc::clone(&tracker)).unwrap();
+        assert_eq!(Arc::strong_count(&tracker), 3);
+
+        drop(buffer);
+        assert_eq!(Arc::strong_count(&tracker), 1);
+    }
+
+    #[test]
+    fn test_ring_buffer_stress_no_lost_or_duplicated() {
+        let buffer = Arc::new(LockFreeRingBuffer::new(16));
+        let total = PRODUCERS * PER_PRODUCER;
+        let consumed = Arc::new(AtomicUsize::new(0));
+
+        let producers: Vec<_> = (0..PRODUCERS)
+            .map(|p| {
+                let buffer = Arc::clone(&buffer);
+                thread::spawn(move || {
+                    for i in 0..PER_PRODUCER {
+                        buffer.push(p * PER_PRODUCER + i);
+                    }
+                })
+            })
+            .collect();
+
+        let consumers: Vec<_> = (0..CONSUMERS)
+            .map(|_| {
+                let buffer = Arc::clone(&buffer);
+                let consumed = Arc::clone(&consumed);
+                thread::spawn(move || {
+                    let mut received = Vec::new();
+                    while consumed.load(Ordering::SeqCst) < total {
+                        if let Some(value) = buffer.try_pop() {
+                            consumed.fetch_add(1, Ordering::SeqCst);
+                            received.push(value);
+                        } else {
+                            thread::yield_now();
+                        }
+                    }
+                    received
+                })
+            })
+            .collect();
+
+        for handle in producers {
+            handle.join().unwrap();
+        }
+
+        let mut seen = HashSet::new();
+        for handle in consumers {
+            for value in handle.join().unwrap() {
+                assert!(seen.insert(value), "duplicated message {value}");
+            }
+        }
+        assert_eq!(seen.len(), total);
+    }
+}
@@ -0,0 +1,527 @@
+//! Read-Copy-Update - ข้อมูลที่อ่านบ่อยแต่แก้นานๆ ครั้ง! 📖🔄
+//!
+//! config, routing table, feature flag... ถูกอ่านทุก request แต่เปลี่ยนไม่กี่ครั้งต่อวัน
+//! บทนี้เทียบ 3 วิธีแชร์ข้อมูลแบบนี้ระหว่าง threads:
+//! - 🔒 **`RwLock<T>`**: ผู้อ่านหลายคนพร้อมกันได้ แต่ทุกการอ่านต้องแตะ lock ตัวเดียวกัน
+//! - 🔄 **`ArcSwap<T>`**: เก็บ `Arc<T>` ใน atomic pointer - ผู้เขียน "copy แล้ว update" ฉบับใหม่
+//!   แล้วสลับ pointer ทีเดียว ผู้อ่านไม่เคยถูก block (แนวเดียวกับ crate `arc-swap`)
+//! - 🔢 **`SeqLock<T>`**: ผู้อ่าน copy ค่าออกมาแล้วเช็ค version ซ้ำ ถ้ามีคนเขียนแทรกก็อ่านใหม่
+//!
+//! ทั้งสามแบบต้องไม่มี "torn read" - อ่านได้ครึ่งค่าเก่าครึ่งค่าใหม่
+
+use std::cell::{Cell, UnsafeCell};
+use std::hint;
+use std::marker::PhantomData;
+use std::mem::MaybeUninit;
+use std::ptr;
+use std::sync::atomic::{self, AtomicPtr, AtomicUsize, Ordering};
+use std::sync::{Arc, PoisonError, RwLock};
+use std::thread;
+use std::time::Instant;
+
+/// จำนวนช่อง hazard ของ `ArcSwap` - ผู้อ่านพร้อมกันเกินนี้จะวนหาช่องว่าง
+const HAZARD_SLOTS: usize = 16;
+
+/// 📚 สิ่งที่ทั้ง 3 แบบทำได้เหมือนกัน - ให้ workload และ benchmark ใช้โค้ดชุดเดียว
+pub trait SharedRead<T>: Sync {
+    /// ชื่อสำหรับแสดงผล
+    const NAME: &'static str;
+
+    /// อ่านค่าปัจจุบันผ่าน closure
+    fn read<R>(&self, f: impl FnOnce(&T) -> R) -> R;
+
+    /// แทนที่ค่าทั้งก้อน
+    fn write(&self, value: T);
+}
+
+impl<T: Send + Sync> SharedRead<T> for RwLock<T> {
+    const NAME: &'static str = "RwLock";
+
+    fn read<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        f(&self.read().unwrap_or_else(PoisonError::into_inner))
+    }
+
+    fn write(&self, value: T) {
+        *Self::write(self).unwrap_or_else(PoisonError::into_inner) = value;
+    }
+}
+
+/// 🔄 `Arc<T>` ที่สลับได้แบบ atomic - อ่านได้โดยไม่ต้องรอผู้เขียนเลย
+///
+/// ปัญหาของการเก็บ `Arc` ใน `AtomicPtr` ตรงๆ: ผู้อ่านโหลด pointer มาแล้ว ยังไม่ทันเพิ่ม
+/// reference count ผู้เขียนก็สลับและ drop ของเก่าทิ้ง → use-after-free 💥
+///
+/// ทางแก้ (hazard pointer แบบย่อ): ผู้อ่านประกาศ pointer ที่กำลังจะใช้ไว้ในช่อง hazard ก่อน
+/// ผู้เขียนที่สลับ pointer ออกไปต้องรอ (grace period ของ RCU) จนไม่มีช่องไหนถือ pointer เก่า
+/// - รอเฉพาะผู้อ่านที่ "กำลังหยิบ" ของเก่าอยู่ ผู้อ่านใหม่จะเห็นของใหม่เสมอ จึงไม่มีวันรอไม่จบ
+///
+/// ⚠️ ทุก `load` ยังต้องเพิ่ม reference count ของ `Arc` ตัวเดียวกัน ผู้อ่านหลายคนจึงแย่ง cache line
+/// เดียวกันอยู่ดี (benchmark `read_mostly` จะเห็นว่าไม่ได้เร็วกว่า `RwLock`) - จุดเด่นคือผู้อ่านกับผู้เขียน
+/// ไม่ block กัน; crate `arc-swap` ตัวจริงแก้เรื่อง count ด้วย `Guard` ที่ยืมค่าโดยไม่แตะ count
+pub struct ArcSwap<T> {
+    current: AtomicPtr<T>,
+    hazards: [AtomicPtr<T>; HAZARD_SLOTS],
+    _owns: PhantomData<Arc<T>>,
+}
+
+// SAFETY: ArcSwap เป็นเจ้าของ Arc<T> หนึ่งตัวและแจก Arc<T> ให้ thread อื่น
+// จึงต้องการเงื่อนไขเดียวกับการส่ง Arc<T> ข้าม thread
+unsafe impl<T: Send + Sync> Send for ArcSwap<T> {}
+// SAFETY: เหตุผลเดียวกับ Send - ทุกการเข้าถึงผ่าน atomics
+unsafe impl<T: Send + Sync> Sync for ArcSwap<T> {}
+
+thread_local! {
+    /// ช่อง hazard ที่ thread นี้ลองก่อน - แต่ละ thread เริ่มคนละช่องเพื่อไม่แย่ง cache line กัน
+    static HAZARD_HINT: Cell<usize> = Cell::new({
+        static NEXT: AtomicUsize = AtomicUsize::new(0);
+        NEXT.fetch_add(1, Ordering::Relaxed)
+    });
+}
+
+impl<T> ArcSwap<T> {
+    #[must_use]
+    pub fn new(value: T) -> Self {
+        Self::from_arc(Arc::new(value))
+    }
+
+    #[must_use]
+    pub fn from_arc(value: Arc<T>) -> Self {
+        Self {
+            current: AtomicPtr::new(Arc::into_raw(value).cast_mut()),
+            hazards: std::array::from_fn(|_| AtomicPtr::new(ptr::null_mut())),
+            _owns: PhantomData,
+        }
+    }
+
+    /// 📖 หยิบ snapshot ปัจจุบัน - ถือไว้นานแค่ไหนก็ได้ ผู้เขียนไม่ต้องรอ
+    #[must_use]
+    pub fn load(&self) -> Arc<T> {
+        let slot = self.claim_slot();
+        let mut current = self.current.load(Ordering::SeqCst);
+        loop {
+            slot.store(current, Ordering::SeqCst);
+            // ประกาศแล้วต้องเช็คซ้ำ: ถ้าถูกสลับไปก่อนประกาศ ผู้เขียนอาจไม่เห็นช่องเรา
+            let latest = self.current.load(Ordering::SeqCst);
+            if latest == current {
+                break;
+            }
+            current = latest;
+        }
+        // SAFETY: pointer ถูกประกาศในช่อง hazard และยังเป็นค่าปัจจุบันหลังประกาศ
+        // ผู้เขียนที่สลับมันออกไปจะรอจนช่องนี้ถูกล้างก่อน drop
+        unsafe { Arc::increment_strong_count(current) };
+        slot.store(ptr::null_mut(), Ordering::Release);
+        // SAFETY: เพิ่ง increment_strong_count ให้ reference นี้
+        unsafe { Arc::from_raw(current) }
+    }
+
+    /// จองช่อง hazard ว่าง (null) ด้วยการใส่ค่าอะไรก็ได้ที่ไม่ใช่ null
+    fn claim_slot(&self) -> &AtomicPtr<T> {
+        let start = HAZARD_HINT.with(Cell::get);
+        let busy = ptr::NonNull::<T>::dangling().as_ptr();
+        for attempt in 0.. {
+            let slot = &self.hazards[(start + attempt) % HAZARD_SLOTS];
+            if slot
+                .compare_exchange(ptr::null_mut(), busy, Ordering::SeqCst, Ordering::Relaxed)
+                .is_ok()
+            {
+                return slot;
+            }
+            if attempt % HAZARD_SLOTS == HAZARD_SLOTS - 1 {
+                thread::yield_now();
+            }
+        }
+        unreachable!("the slot search loops forever")
+    }
+
+    /// 🔄 ใส่ค่าใหม่ แล้วคืนค่าเก่า (คืนหลังไม่มีผู้อ่านคนไหนกำลังหยิบค่าเก่าแล้ว)
+    pub fn swap(&self, value: Arc<T>) -> Arc<T> {
+        let old = self
+            .current
+            .swap(Arc::into_raw(value).cast_mut(), Ordering::SeqCst);
+        self.wait_for_readers(old);
+        // SAFETY: old มาจาก Arc::into_raw และเราเพิ่งเอามันออกจาก current
+        unsafe { Arc::from_raw(old) }
+    }
+
+    pub fn store(&self, value: Arc<T>) {
+        drop(self.swap(value));
+    }
+
+    /// ✏️ read-copy-update: สร้างฉบับใหม่จากฉบับปัจจุบัน ถ้ามีคนเขียนแทรกก็เริ่มใหม่
+    ///
+    /// `update` อาจถูกเรียกหลายครั้ง จึงไม่ควรมี side effect - คืนฉบับที่ถูกแทนที่
+    pub fn rcu(&self, mut update: impl FnMut(&T) -> T) -> Arc<T> {
+        loop {
+            let current = self.load();
+            let next = Arc::into_raw(Arc::new(update(&current))).cast_mut();
+            // เทียบ pointer ได้ปลอดภัยจาก ABA เพราะเราถือ `current` ไว้ มันจึงไม่ถูก free แล้วนำกลับมาใช้
+            match self.current.compare_exchange(
+                Arc::as_ptr(&current).cast_mut(),
+                next,
+                Ordering::SeqCst,
+                Ordering::SeqCst,
+            ) {
+                Ok(old) => {
+                    self.wait_for_readers(old);
+                    // SAFETY: old คือ reference ที่ ArcSwap เคยถือ และเพิ่งถูกเอาออก
+                    drop(unsafe { Arc::from_raw(old) });
+                    return current;
+                }
+                // SAFETY: next ยังไม่เคยถูกแชร์ให้ใคร
+                Err(_) => drop(unsafe { Arc::from_raw(next) }),
+            }
+        }
+    }
+
+    /// grace period - รอจนไม่มีช่อง hazard ไหนถือ `old`
+    fn wait_for_readers(&self, old: *mut T) {
+        for slot in &self.hazards {
+            while slot.load(Ordering::SeqCst) == old {
+                hint::spin_loop();
+            }
+        }
+    }
+}
+
+impl<T> Drop for ArcSwap<T> {
+    fn drop(&mut self) {
+        // SAFETY: มี &mut self จึงไม่มีผู้อ่านคนไหนเหลือ และ pointer มาจาก Arc::into_raw
+        drop(unsafe { Arc::from_raw(*self.current.get_mut()) });
+    }
+}
+
+impl<T: Send + Sync> SharedRead<T> for ArcSwap<T> {
+    const NAME: &'static str = "ArcSwap";
+
+    fn read<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        f(&self.load())
+    }
+
+    fn write(&self, value: T) {
+        self.store(Arc::new(value));
+    }
+}
+
+/// 🔢 Sequence lock - ผู้อ่านไม่เขียนอะไรลง memory ที่แชร์เลย (ไม่มี cache line ping-pong)
+///
+/// - ผู้เขียน: ทำ sequence เป็นเลขคี่ → เขียนค่า → ทำ sequence เป็นเลขคู่ถัดไป
+/// - ผู้อ่าน: จำ sequence (ต้องเป็นเลขคู่) → copy ค่า → ถ้า sequence ยังเท่าเดิมค่าที่ได้ไม่ขาด
+///
+/// ใช้ได้กับ `T: Copy` เท่านั้น เพราะค่าที่อ่านระหว่างถูกเขียนจะถูกทิ้งโดยไม่ drop
+/// (เทคนิคเดียวกับ `crossbeam::atomic::AtomicCell`)
+pub struct SeqLock<T> {
+    sequence: AtomicUsize,
+    value: UnsafeCell<T>,
+}
+
+// SAFETY: ผู้เขียนถูกจัดลำดับด้วย sequence (ทีละคน) และค่าที่ผู้อ่าน copy ระหว่างเขียน
+// จะถูกทิ้งก่อนถูกตีความเป็น T
+unsafe impl<T: Copy + Send> Sync for SeqLock<T> {}
+
+impl<T: Copy> SeqLock<T> {
+    #[must_use]
+    pub const fn new(value: T) -> Self {
+        Self {
+            sequence: AtomicUsize::new(0),
+            value: UnsafeCell::new(value),
+        }
+    }
+
+    /// อ่านหนึ่งครั้ง - `None` ถ้ามีผู้เขียนทำงานอยู่หรือเขียนแทรกระหว่าง copy
+    #[must_use]
+    pub fn try_read(&self) -> Option<T> {
+        let before = self.sequence.load(Ordering::Acquire);
+        if before & 1 == 1 {
+            return None;
+        }
+        // SAFETY: อาจอ่านพร้อมผู้เขียน จึงอ่านเป็น MaybeUninit และจะ assume_init
+        // ก็ต่อเมื่อ sequence ยืนยันว่าไม่มีการเขียนแทรก
+        let value = unsafe { ptr::read_volatile(self.value.get().cast::<MaybeUninit<T>>()) };
+        atomic::fence(Ordering::Acquire);
+        (self.sequence.load(Ordering::Relaxed) == before)
+            // SAFETY: sequence ไม่เปลี่ยนและเป็นเลขคู่ ค่าที่ copy มาคือค่าที่เขียนเสร็จแล้วทั้งก้อน
+            .then(|| unsafe { value.assume_init() })
+    }
+
+    /// อ่านจนได้ค่าที่ไม่ขาด
+    #[must_use]
+    pub fn read(&self) -> T {
+        loop {
+            if let Some(value) = self.try_read() {
+                return value;
+            }
+            hint::spin_loop();
+        }
+    }
+
+    pub fn write(&self, value: T) {
+        let mut sequence = self.sequence.load(Ordering::Relaxed);
+        loop {
+            if sequence & 1 == 1 {
+                hint::spin_loop();
+                sequence = self.sequence.load(Ordering::Relaxed);
+                continue;
+            }
+            match self.sequence.compare_exchange_weak(
+                sequence,
+                sequence.wrapping_add(1),
+                Ordering::Acquire,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => break,
+                Err(current) => sequence = current,
+            }
+        }
+        // sequence คี่ต้องมองเห็นก่อนข้อมูลที่เริ่มเปลี่ยน
+        atomic::fence(Ordering::Release);
+        // SAFETY: sequence เป็นเลขคี่ที่เราตั้ง จึงเป็นผู้เขียนคนเดียว ผู้อ่านที่ copy แทรกจะทิ้งค่า
+        unsafe { ptr::write_volatile(self.value.get(), value) };
+        self.sequence
+            .store(sequence.wrapping_add(2), Ordering::Release);
+    }
+}
+
+impl<T: Copy + Send> SharedRead<T> for SeqLock<T> {
+    const NAME: &'static str = "SeqLock";
+
+    fn read<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        f(&Self::read(self))
+    }
+
+    fn write(&self, value: T) {
+        Self::write(self, value);
+    }
+}
+
+/// 📸 ข้อมูลทดสอบขนาด 1 cache line - ทุกช่องต้องเท่ากับ `version` ถ้าไม่ถูกอ่านขาด
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Snapshot {
+    pub version: u64,
+    pub words: [u64; 7],
+}
+
+impl Snapshot {
+    #[must_use]
+    pub const fn new(version: u64) -> Self {
+        Self {
+            version,
+            words: [version; 7],
+        }
+    }
+
+    #[must_use]
+    pub fn is_consistent(&self) -> bool {
+        self.words.iter().all(|&word| word == self.version)
+    }
+}
+
+/// ผลของ workload
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WorkloadReport {
+    pub reads: u64,
+    pub writes: u64,
+    /// อ่านได้ค่าขาด หรือ version ย้อนกลับ - ต้องเป็น 0 เสมอ
+    pub torn_reads: u64,
+}
+
+/// 🏋️ ผู้อ่าน `readers` คนอ่านคนละ `reads_per_reader` ครั้ง ระหว่างที่ผู้เขียนหนึ่งคนเขียน `writes` ครั้ง
+///
+/// # Panics
+///
+/// Panics ถ้า thread ใน workload panic
+pub fn read_heavy_workload<S: SharedRead<Snapshot>>(
+    shared: &S,
+    readers: usize,
+    reads_per_reader: u64,
+    writes: u64,
+) -> WorkloadReport {
+    let start = shared.read(|snapshot| snapshot.version);
+    thread::scope(|scope| {
+        let updater = scope.spawn(|| {
+            for version in 1..=writes {
+                shared.write(Snapshot::new(start + version));
+            }
+        });
+        let readers: Vec<_> = (0..readers)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut last = start;
+                    let mut torn = 0;
+                    for _ in 0..reads_per_reader {
+                        let (version, consistent) =
+                            shared.read(|snapshot| (snapshot.version, snapshot.is_consistent()));
+                        if !consistent || version < last {
+                            torn += 1;
+                        }
+                        last = last.max(version);
+                    }
+                    torn
+                })
+            })
+            .collect();
+        updater.join().unwrap();
+        WorkloadReport {
+            reads: reads_per_reader * readers.len() as u64,
+            writes,
+            torn_reads: readers
+                .into_iter()
+                .map(|reader| reader.join().unwrap())
+                .sum(),
+        }
+    })
+}
+
+fn time_workload<S: SharedRead<Snapshot>>(shared: &S) {
+    let started = Instant::now();
+    let report = read_heavy_workload(shared, 4, 50_000, 500);
+    let elapsed = started.elapsed();
+    println!(
+        "  {:>8}: {} reads + {} writes ใน {:>7.2?} | torn reads: {}",
+        S::NAME,
+        report.reads,
+        report.writes,
+        elapsed,
+        report.torn_reads
+    );
+}
+
+/// ตัวอย่าง RCU เทียบกับ `RwLock` และ `SeqLock` 📖🔄
+///
+/// # Panics
+///
+/// Panics ถ้า thread ใน workload panic
+pub fn rcu_example() {
+    println!("🔄 ArcSwap - reload config โดยผู้อ่านไม่ต้องรอ:");
+    let config = ArcSwap::new(vec!["api.example.com".to_string()]);
+    let snapshot = config.load();
+    config.rcu(|hosts| {
+        let mut hosts = hosts.clone();
+        hosts.push("backup.example.com".to_string());
+        hosts
+    });
+    println!("  📸 snapshot ที่ถือไว้ก่อน reload: {:?}", *snapshot);
+    println!("  🆕 ค่าปัจจุบัน: {:?}", *config.load());
+
+    println!("📊 ผู้อ่าน 4 threads vs ผู้เขียน 1 thread (debug build - ดูตัวเลขจริงด้วย cargo bench):");
+    time_workload(&RwLock::new(Snapshot::new(0)));
+    time_workload(&ArcSwap::new(Snapshot::new(0)));
+    time_workload(&SeqLock::new(Snapshot::new(0)));
+    println!("🎉 ทั้งสามแบบไม่มี torn read - ต่างกันที่ใครต้องรอใคร!");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// นับจำนวนค่าที่ยังไม่ถูก drop - จับทั้ง leak และ double free
+    struct Tracked(Arc<AtomicUsize>, u64);
+
+    impl Tracked {
+        fn new(live: &Arc<AtomicUsize>, value: u64) -> Self {
+            live.fetch_add(1, Ordering::SeqCst);
+            Self(Arc::clone(live), value)
+        }
+    }
+
+    impl Drop for Tracked {
+        fn drop(&mut self) {
+            self.0.fetch_sub(1, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn test_arc_swap_load_swap_and_drop() {
+        let live = Arc::new(AtomicUsize::new(0));
+        let cell = ArcSwap::new(Tracked::new(&live, 1));
+        let first = cell.load();
+        assert_eq!(first.1, 1);
+
+        let old = cell.swap(Arc::new(Tracked::new(&live, 2)));
+        assert!(Arc::ptr_eq(&old, &first));
+        assert_eq!(cell.load().1, 2);
+        assert_eq!(live.load(Ordering::SeqCst), 2);
+
+        // snapshot เก่ายังใช้ได้จนคนสุดท้ายปล่อย
+        drop(old);
+        assert_eq!(first.1, 1);
+        drop(first);
+        assert_eq!(live.load(Ordering::SeqCst), 1);
+        cell.store(Arc::new(Tracked::new(&live, 3)));
+        drop(cell);
+        assert_eq!(live.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn test_arc_swap_rcu_does_not_lose_updates() {
+        let counter = ArcSwap::new(0_u64);
+        thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(|| {
+                    for _ in 0..1_000 {
+                        counter.rcu(|value| value + 1);
+                    }
+                });
+            }
+            // ผู้อ่านระหว่างทางเห็นค่าที่เพิ่มขึ้นเท่านั้น
+            scope.spawn(|| {
+                let mut last = 0;
+                while last < 4_000 {
+                    let value = *counter.load();
+                    assert!(value >= last);
+                    last = value;
+                }
+            });
+        });
+        assert_eq!(*counter.load(), 4_000);
+    }
+
+    #[test]
+    fn test_arc_swap_frees_every_version_under_contention() {
+        let live = Arc::new(AtomicUsize::new(0));
+        let cell = ArcSwap::new(Tracked::new(&live, 0));
+        thread::scope(|scope| {
+            // ผู้อ่านมากกว่าช่อง hazard เพื่อให้ต้องแย่งช่องกัน
+            for _ in 0..HAZARD_SLOTS + 4 {
+                scope.spawn(|| {
+                    for _ in 0..2_000 {
+                        let snapshot = cell.load();
+                        assert!(snapshot.1 <= 500);
+                    }
+                });
+            }
+            for value in 1..=500 {
+                cell.store(Arc::new(Tracked::new(&live, value)));
+            }
+        });
+        assert_eq!(cell.load().1, 500);
+        drop(cell);
+        assert_eq!(live.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn test_seqlock_rejects_reads_during_write() {
+        let lock = SeqLock::new(Snapshot::new(1));
+        assert_eq!(lock.try_read(), Some(Snapshot::new(1)));
+        // จำลองผู้เขียนที่กำลังเขียนอยู่
+        lock.sequence.fetch_add(1, Ordering::SeqCst);
+        assert_eq!(lock.try_read(), None);
+        lock.sequence.fetch_add(1, Ordering::SeqCst);
+        lock.write(Snapshot::new(2));
+        assert_eq!(lock.read(), Snapshot::new(2));
+        assert_eq!(lock.sequence.load(Ordering::SeqCst), 4);
+    }
+
+    #[test]
+    fn test_no_torn_reads_under_read_heavy_load() {
+        fn check<S: SharedRead<Snapshot>>(shared: &S) {
+            let report = read_heavy_workload(shared, 4, 20_000, 2_000);
+            assert_eq!(report.reads, 80_000, "{}", S::NAME);
+            assert_eq!(report.torn_reads, 0, "{} returned a torn snapshot", S::NAME);
+            assert_eq!(shared.read(|snapshot| *snapshot), Snapshot::new(2_000));
+        }
+        check(&RwLock::new(Snapshot::new(0)));
+        check(&ArcSwap::new(Snapshot::new(0)));
+        check(&SeqLock::new(Snapshot::new(0)));
+    }
+}
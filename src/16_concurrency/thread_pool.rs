@@ -0,0 +1,189 @@
+//! Thread Pool - สร้าง worker threads ไว้ล่วงหน้าแล้วส่งงานเข้าไปทำ! 🏊‍♂️🧵
+//!
+//! การ `thread::spawn` ทุกครั้งที่มีงานมีต้นทุน (สร้าง stack, คุยกับ OS)
+//! thread pool จึงสร้าง worker ไว้ชุดเดียวแล้วให้ทุกตัวแย่งกันดึงงานจากคิวเดียวกัน
+//!
+//! 📬 คิวงานคือ `mpmc_channel::bounded` ของบทนี้เอง - worker แต่ละตัวถือ `Receiver`
+//! ที่ clone มา จึงไม่ต้องห่อ receiver ด้วย `Mutex` แบบ `std::sync::mpsc`
+
+use std::fmt;
+use std::num::NonZeroUsize;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread::{self, JoinHandle};
+
+use super::mpmc_channel::{Sender, bounded};
+
+/// งานหนึ่งชิ้นที่ส่งเข้า pool
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+/// จำนวนงานที่รอในคิวได้ต่อ worker หนึ่งตัว ก่อนที่ `execute` จะเริ่ม block
+const QUEUE_SLOTS_PER_WORKER: usize = 16;
+
+/// Thread pool ขนาดคงที่ 🏊‍♂️
+///
+/// งานที่ panic จะถูกจับไว้ด้วย `catch_unwind` worker จึงไม่ตายตามไปด้วย
+/// (เฉพาะ build ที่ `panic = "unwind"` - release profile ของ repo ตั้ง `panic = "abort"`
+/// งานที่ panic จึงปิดทั้ง process ไม่ใช่แค่ถูกนับใน `panicked_jobs`)
+/// เมื่อ pool ถูก drop จะรอให้งานที่ค้างในคิวทำเสร็จทั้งหมดก่อนคืนค่า
+pub struct ThreadPool {
+    sender: Option<Sender<Job>>,
+    workers: Vec<JoinHandle<()>>,
+    panicked_jobs: Arc<AtomicUsize>,
+}
+
+impl ThreadPool {
+    /// สร้าง pool ที่มี worker `workers` ตัว
+    ///
+    /// # Panics
+    ///
+    /// Panics ถ้า `workers` เป็น 0 หรือ OS สร้าง thread ไม่ได้
+    #[must_use]
+    pub fn new(workers: usize) -> Self {
+        assert!(workers > 0, "thread pool needs at least one worker");
+
+        let (sender, receiver) = bounded::<Job>(workers * QUEUE_SLOTS_PER_WORKER);
+        let panicked_jobs = Arc::new(AtomicUsize::new(0));
+
+        let workers = (0..workers)
+            .map(|id| {
+                let receiver = receiver.clone();
+                let panicked_jobs = Arc::clone(&panicked_jobs);
+                thread::Builder::new()
+                    .name(format!("pool-worker-{id}"))
+                    .spawn(move || {
+                        for job in receiver {
+                            if panic::catch_unwind(AssertUnwindSafe(job)).is_err() {
+                                panicked_jobs.fetch_add(1, Ordering::Relaxed);
+                            }
+                        }
+                    })
+                    .expect("failed to spawn pool worker")
+            })
+            .collect();
+
+        Self {
+            sender: Some(sender),
+            workers,
+            panicked_jobs,
+        }
+    }
+
+    /// สร้าง pool ที่มี worker เท่ากับจำนวน CPU ที่ OS บอกว่าใช้ได้
+    #[must_use]
+    pub fn with_available_parallelism() -> Self {
+        Self::new(thread::available_parallelism().map_or(1, NonZeroUsize::get))
+    }
+
+    /// ส่งงานเข้าคิว - ถ้าคิวเต็มจะ block จนกว่า worker จะว่าง
+    ///
+    /// # Panics
+    ///
+    /// Panics ถ้า worker ทั้งหมดหายไปแล้ว (ไม่ควรเกิดขึ้นระหว่างที่ pool ยังอยู่)
+    pub fn execute<F>(&self, job: F)
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        self.sender
+            .as_ref()
+            .expect("sender lives until drop")
+            .send(Box::new(job))
+            .unwrap_or_else(|_| panic!("all pool workers have exited"));
+    }
+
+    /// จำนวน worker ใน pool
+    #[must_use]
+    pub const fn workers(&self) -> usize {
+        self.workers.len()
+    }
+
+    /// จำนวนงานที่ panic ไปแล้ว (worker ยังทำงานต่อได้ตามปกติ)
+    #[must_use]
+    pub fn panicked_jobs(&self) -> usize {
+        self.panicked_jobs.load(Ordering::Relaxed)
+    }
+}
+
+impl fmt::Debug for ThreadPool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ThreadPool")
+            .field("workers", &self.workers.len())
+            .field("panicked_jobs", &self.panicked_jobs())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for ThreadPool {
+    fn drop(&mut self) {
+        // ปิดคิว: worker จะทำงานที่เหลือจนหมดแล้วออกจาก loop เอง
+        drop(self.sender.take());
+        for worker in self.workers.drain(..) {
+            // งานทุกชิ้นถูก catch_unwind แล้ว join จึงไม่ควรล้มเหลว
+            let _ = worker.join();
+        }
+    }
+}
+
+/// ตัวอย่างการใช้ `ThreadPool` 🏊‍♂️
+pub fn thread_pool_example() {
+    println!("🏊‍♂️ Thread pool ที่สร้างบน MPMC channel:");
+
+    let pool = ThreadPool::new(3);
+    let completed = Arc::new(AtomicUsize::new(0));
+
+    for task in 1..=6 {
+        let completed = Arc::clone(&completed);
+        pool.execute(move || {
+            let worker = thread::current().name().unwrap_or("?").to_string();
+            println!("  🔧 {worker} ทำงานที่ {task}");
+            completed.fetch_add(1, Ordering::SeqCst);
+        });
+    }
+
+    println!("  👷 มี worker {} ตัว รับงาน 6 ชิ้น", pool.workers());
+    drop(pool);
+
+    println!(
+        "🎉 งานเสร็จ {} ชิ้น - drop pool รอให้คิวว่างก่อนเสมอ",
+        completed.load(Ordering::SeqCst)
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc;
+
+    #[test]
+    fn test_pool_runs_every_job_before_drop_returns() {
+        let pool = ThreadPool::new(4);
+        let counter = Arc::new(AtomicUsize::new(0));
+        for _ in 0..500 {
+            let counter = Arc::clone(&counter);
+            pool.execute(move || {
+                counter.fetch_add(1, Ordering::SeqCst);
+            });
+        }
+        assert_eq!(pool.workers(), 4);
+        drop(pool);
+        assert_eq!(counter.load(Ordering::SeqCst), 500);
+    }
+
+    #[test]
+    fn test_pool_survives_panicking_jobs() {
+        let pool = ThreadPool::new(1);
+        pool.execute(|| panic!("boom"));
+
+        let (sender, receiver) = mpsc::channel();
+        pool.execute(move || sender.send(42).unwrap());
+        assert_eq!(receiver.recv(), Ok(42));
+        assert_eq!(pool.panicked_jobs(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one worker")]
+    fn test_pool_rejects_zero_workers() {
+        let _ = ThreadPool::new(0);
+    }
+}
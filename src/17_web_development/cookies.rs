@@ -0,0 +1,564 @@
+//! 🍪 Cookies - อ่าน/เขียน `Set-Cookie` และเซ็นค่าด้วย HMAC
+//!
+//! 📤 server ส่ง `Set-Cookie: name=value; Path=/; HttpOnly; ...` (หนึ่ง cookie ต่อ header)
+//! 📥 browser ส่งกลับมาแค่ `Cookie: a=1; b=2` - ไม่มี attribute ใดๆ
+//!
+//! ⚠️ ค่าใน cookie อยู่ในมือ client แก้เองได้เสมอ ค่าที่ server ต้องเชื่อ (เช่น session id)
+//! จึงต้องเซ็นด้วย `CookieSigner` แล้วตรวจลายเซ็นทุกครั้งที่อ่านกลับมา
+
+use std::fmt;
+
+use base64::Engine as _;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+/// ❌ Cookie Error - ข้อผิดพลาดของ cookie
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CookieError {
+    /// ไม่มี `=` คั่นชื่อกับค่า
+    MissingValue,
+    EmptyName,
+    /// ชื่อ cookie ต้องเป็น token (ห้ามช่องว่างและ `()<>@,;:\"/[]?={}`)
+    InvalidName(char),
+    /// ค่าห้ามมีช่องว่าง, `"`, `,`, `;`, `\` และตัวอักษรนอก ASCII
+    InvalidValue(char),
+    /// attribute ที่รู้จักแต่ค่าผิด เช่น `Max-Age=soon`
+    InvalidAttribute(String),
+    /// ลายเซ็นไม่ตรง - cookie ถูกแก้ หรือเซ็นด้วย key อื่น
+    InvalidSignature,
+}
+
+impl fmt::Display for CookieError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingValue => f.write_str("cookie has no `=`"),
+            Self::EmptyName => f.write_str("cookie name is empty"),
+            Self::InvalidName(c) => write!(f, "invalid character {c:?} in cookie name"),
+            Self::InvalidValue(c) => write!(f, "invalid character {c:?} in cookie value"),
+            Self::InvalidAttribute(name) => write!(f, "invalid value for cookie attribute {name}"),
+            Self::InvalidSignature => f.write_str("cookie signature does not match"),
+        }
+    }
+}
+
+impl std::error::Error for CookieError {}
+
+/// 🛡️ `SameSite` - ส่ง cookie ไปกับ request ที่มาจากเว็บอื่นหรือไม่ (กัน CSRF)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SameSite {
+    Strict,
+    Lax,
+    /// ส่งทุกกรณี - browser ยอมรับก็ต่อเมื่อเป็น `Secure` ด้วย
+    None,
+}
+
+impl fmt::Display for SameSite {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Strict => "Strict",
+            Self::Lax => "Lax",
+            Self::None => "None",
+        })
+    }
+}
+
+/// 🍪 Cookie หนึ่งตัวพร้อม attribute - `Display` ได้ค่าของ header `Set-Cookie`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cookie {
+    pub name: String,
+    pub value: String,
+    pub expires: Option<DateTime<Utc>>,
+    /// อายุเป็นวินาที - `0` หรือติดลบคือสั่งลบทันที (ชนะ `Expires` ถ้ามีทั้งคู่)
+    pub max_age: Option<i64>,
+    pub domain: Option<String>,
+    pub path: Option<String>,
+    pub secure: bool,
+    pub http_only: bool,
+    pub same_site: Option<SameSite>,
+}
+
+impl Cookie {
+    /// # Errors
+    ///
+    /// คืน `CookieError` ถ้าชื่อว่าง/ไม่ใช่ token หรือค่ามีตัวอักษรต้องห้าม
+    pub fn new(name: &str, value: &str) -> Result<Self, CookieError> {
+        validate_name(name)?;
+        validate_value(value)?;
+        Ok(Self {
+            name: name.to_string(),
+            value: value.to_string(),
+            expires: None,
+            max_age: None,
+            domain: None,
+            path: None,
+            secure: false,
+            http_only: false,
+            same_site: None,
+        })
+    }
+
+    /// cookie สำหรับสั่ง browser ลบ `name` ทิ้ง (ค่าว่าง + `Max-Age=0`)
+    #[must_use]
+    pub fn removal(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            value: String::new(),
+            expires: Some(DateTime::UNIX_EPOCH),
+            max_age: Some(0),
+            domain: None,
+            path: Some("/".to_string()),
+            secure: false,
+            http_only: false,
+            same_site: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_path(mut self, path: &str) -> Self {
+        self.path = Some(path.to_string());
+        self
+    }
+
+    #[must_use]
+    pub fn with_domain(mut self, domain: &str) -> Self {
+        self.domain = Some(domain.to_string());
+        self
+    }
+
+    #[must_use]
+    pub const fn with_max_age(mut self, seconds: i64) -> Self {
+        self.max_age = Some(seconds);
+        self
+    }
+
+    #[must_use]
+    pub const fn with_expires(mut self, expires: DateTime<Utc>) -> Self {
+        self.expires = Some(expires);
+        self
+    }
+
+    #[must_use]
+    pub const fn with_same_site(mut self, same_site: SameSite) -> Self {
+        self.same_site = Some(same_site);
+        self
+    }
+
+    /// ส่งเฉพาะผ่าน HTTPS
+    #[must_use]
+    pub const fn secure(mut self) -> Self {
+        self.secure = true;
+        self
+    }
+
+    /// JavaScript อ่านไม่ได้ (`document.cookie`) - ลดความเสียหายจาก XSS
+    #[must_use]
+    pub const fn http_only(mut self) -> Self {
+        self.http_only = true;
+        self
+    }
+
+    /// แปลงค่าของ header `Set-Cookie` - attribute ที่ไม่รู้จักถูกข้าม (ตาม RFC 6265)
+    ///
+    /// # Errors
+    ///
+    /// คืน `CookieError` ถ้าชื่อ/ค่าผิดรูป หรือ `Expires`/`Max-Age`/`SameSite` อ่านไม่ออก
+    pub fn parse(header: &str) -> Result<Self, CookieError> {
+        let mut parts = header.split(';');
+        let (name, value) = parts
+            .next()
+            .unwrap_or_default()
+            .split_once('=')
+            .ok_or(CookieError::MissingValue)?;
+        let mut cookie = Self::new(name.trim(), unquote(value.trim()))?;
+
+        for attribute in parts {
+            let (key, value) = attribute.split_once('=').map_or_else(
+                || (attribute.trim(), ""),
+                |(key, value)| (key.trim(), value.trim()),
+            );
+            let invalid = || CookieError::InvalidAttribute(key.to_string());
+            match key.to_ascii_lowercase().as_str() {
+                "expires" => {
+                    let expires = DateTime::parse_from_rfc2822(value).map_err(|_| invalid())?;
+                    cookie.expires = Some(expires.with_timezone(&Utc));
+                }
+                "max-age" => cookie.max_age = Some(value.parse().map_err(|_| invalid())?),
+                "domain" if !value.is_empty() => {
+                    // `.example.com` กับ `example.com` มีความหมายเดียวกัน
+                    let domain = value.trim_start_matches('.').to_ascii_lowercase();
+                    cookie.domain = Some(domain);
+                }
+                "path" if value.starts_with('/') => cookie.path = Some(value.to_string()),
+                "secure" => cookie.secure = true,
+                "httponly" => cookie.http_only = true,
+                "samesite" => {
+                    cookie.same_site = Some(match value.to_ascii_lowercase().as_str() {
+                        "strict" => SameSite::Strict,
+                        "lax" => SameSite::Lax,
+                        "none" => SameSite::None,
+                        _ => return Err(invalid()),
+                    });
+                }
+                _ => {}
+            }
+        }
+        Ok(cookie)
+    }
+
+    /// cookie หมดอายุแล้วหรือยัง ณ เวลา `now` (`Max-Age` นับจาก `received_at`)
+    #[must_use]
+    pub fn is_expired(&self, received_at: DateTime<Utc>, now: DateTime<Utc>) -> bool {
+        match (self.max_age, self.expires) {
+            (Some(max_age), _) => now >= received_at + chrono::Duration::seconds(max_age),
+            (None, Some(expires)) => now >= expires,
+            (None, None) => false,
+        }
+    }
+}
+
+impl fmt::Display for Cookie {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}={}", self.name, self.value)?;
+        if let Some(expires) = self.expires {
+            write!(
+                f,
+                "; Expires={}",
+                expires.format("%a, %d %b %Y %H:%M:%S GMT")
+            )?;
+        }
+        if let Some(max_age) = self.max_age {
+            write!(f, "; Max-Age={max_age}")?;
+        }
+        if let Some(domain) = &self.domain {
+            write!(f, "; Domain={domain}")?;
+        }
+        if let Some(path) = &self.path {
+            write!(f, "; Path={path}")?;
+        }
+        if self.secure {
+            f.write_str("; Secure")?;
+        }
+        if self.http_only {
+            f.write_str("; HttpOnly")?;
+        }
+        if let Some(same_site) = self.same_site {
+            write!(f, "; SameSite={same_site}")?;
+        }
+        Ok(())
+    }
+}
+
+/// แยก header `Cookie: a=1; b=2` ของ request - คู่ที่ผิดรูปถูกข้ามแทนที่จะทิ้งทั้ง header
+#[must_use]
+pub fn parse_cookie_header(header: &str) -> Vec<(String, String)> {
+    header
+        .split(';')
+        .filter_map(|pair| {
+            let (name, value) = pair.split_once('=')?;
+            let (name, value) = (name.trim(), unquote(value.trim()));
+            (validate_name(name).is_ok() && validate_value(value).is_ok())
+                .then(|| (name.to_string(), value.to_string()))
+        })
+        .collect()
+}
+
+/// ค่า cookie ครอบด้วย `"..."` ได้ - ตัดเครื่องหมายคำพูดออก
+fn unquote(value: &str) -> &str {
+    value
+        .strip_prefix('"')
+        .and_then(|inner| inner.strip_suffix('"'))
+        .unwrap_or(value)
+}
+
+fn validate_name(name: &str) -> Result<(), CookieError> {
+    if name.is_empty() {
+        return Err(CookieError::EmptyName);
+    }
+    name.chars()
+        .find(|&c| !c.is_ascii_graphic() || "()<>@,;:\\\"/[]?={}".contains(c))
+        .map_or(Ok(()), |c| Err(CookieError::InvalidName(c)))
+}
+
+fn validate_value(value: &str) -> Result<(), CookieError> {
+    value
+        .chars()
+        .find(|&c| !c.is_ascii_graphic() || matches!(c, '"' | ',' | ';' | '\\'))
+        .map_or(Ok(()), |c| Err(CookieError::InvalidValue(c)))
+}
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// ✍️ เซ็นค่า cookie ด้วย HMAC-SHA256 - ได้ `value.signature` (signature เป็น base64url)
+///
+/// ชื่อ cookie อยู่ในข้อความที่เซ็นด้วย จึงเอาค่าที่เซ็นแล้วของ cookie หนึ่ง
+/// ไปใส่ใน cookie ชื่ออื่นไม่ได้
+#[derive(Clone)]
+pub struct CookieSigner {
+    key: Vec<u8>,
+}
+
+impl fmt::Debug for CookieSigner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // ไม่พิมพ์ key ลง log
+        f.debug_struct("CookieSigner").finish_non_exhaustive()
+    }
+}
+
+impl CookieSigner {
+    #[must_use]
+    pub fn new(key: &[u8]) -> Self {
+        Self { key: key.to_vec() }
+    }
+
+    fn mac(&self, name: &str, value: &str) -> HmacSha256 {
+        let mut mac =
+            HmacSha256::new_from_slice(&self.key).expect("HMAC accepts keys of any length");
+        mac.update(name.as_bytes());
+        mac.update(b"=");
+        mac.update(value.as_bytes());
+        mac
+    }
+
+    /// คืน cookie ตัวใหม่ที่ค่าเป็น `value.signature` (attribute เหมือนเดิม)
+    #[must_use]
+    pub fn sign(&self, cookie: &Cookie) -> Cookie {
+        let signature = self
+            .mac(&cookie.name, &cookie.value)
+            .finalize()
+            .into_bytes();
+        let value = format!("{}.{}", cookie.value, URL_SAFE_NO_PAD.encode(signature));
+        Cookie {
+            value,
+            ..cookie.clone()
+        }
+    }
+
+    /// ตรวจลายเซ็นแล้วคืนค่าเดิม (ก่อนเซ็น) - เทียบแบบ constant-time กัน timing attack
+    ///
+    /// # Errors
+    ///
+    /// คืน `CookieError::InvalidSignature` ถ้าไม่มีลายเซ็นหรือลายเซ็นไม่ตรง
+    pub fn verify<'a>(&self, name: &str, signed_value: &'a str) -> Result<&'a str, CookieError> {
+        let (value, signature) = signed_value
+            .rsplit_once('.')
+            .ok_or(CookieError::InvalidSignature)?;
+        let signature = URL_SAFE_NO_PAD
+            .decode(signature)
+            .map_err(|_| CookieError::InvalidSignature)?;
+        self.mac(name, value)
+            .verify_slice(&signature)
+            .map(|()| value)
+            .map_err(|_| CookieError::InvalidSignature)
+    }
+}
+
+/// 🎭 สาธิต Set-Cookie และการเซ็น cookie
+pub fn demonstrate_cookies() {
+    println!("🍪 Cookies - Set-Cookie, Cookie header และลายเซ็น HMAC");
+
+    let Ok(theme) = Cookie::new("theme", "dark") else {
+        return;
+    };
+    let theme = theme
+        .with_path("/")
+        .with_max_age(60 * 60 * 24 * 30)
+        .with_same_site(SameSite::Lax);
+    println!("\n📤 Set-Cookie: {theme}");
+    println!("🗑️ Set-Cookie: {}", Cookie::removal("theme"));
+
+    let header = "sid=abc123; Expires=Wed, 21 Oct 2015 07:28:00 GMT; Path=/; Secure; HttpOnly; SameSite=Strict";
+    match Cookie::parse(header) {
+        Ok(cookie) => println!(
+            "📥 {header}\n   → name={} value={} expires={:?} secure={} http_only={}",
+            cookie.name,
+            cookie.value,
+            cookie.expires.map(|expires| expires.to_rfc3339()),
+            cookie.secure,
+            cookie.http_only
+        ),
+        Err(error) => println!("❌ {error}"),
+    }
+    for bad in ["no-equals-sign", "bad name=x", "a=b; Max-Age=soon"] {
+        if let Err(error) = Cookie::parse(bad) {
+            println!("   ❌ {bad:<20} → {error}");
+        }
+    }
+
+    println!("\n📥 Cookie: theme=dark; lang=\"th\"; broken");
+    for (name, value) in parse_cookie_header("theme=dark; lang=\"th\"; broken") {
+        println!("   🔑 {name} = {value}");
+    }
+
+    println!("\n✍️ Signed cookie:");
+    let signer = CookieSigner::new(b"workshop-secret-key");
+    let Ok(user) = Cookie::new("user", "ferris") else {
+        return;
+    };
+    let signed_user = signer.sign(&user);
+    println!("   📤 {signed_user}");
+    println!(
+        "   ✅ verify → {:?}",
+        signer.verify("user", &signed_user.value)
+    );
+    let tampered = signed_user.value.replacen("ferris", "admin", 1);
+    println!(
+        "   🚫 แก้เป็น {tampered} → {:?}",
+        signer.verify("user", &tampered)
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(text: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(text)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn test_set_cookie_round_trip() {
+        let cookie = Cookie::new("sid", "abc-123")
+            .unwrap()
+            .with_path("/app")
+            .with_domain("example.com")
+            .with_max_age(3600)
+            .with_expires(date("2015-10-21T07:28:00Z"))
+            .with_same_site(SameSite::Strict)
+            .secure()
+            .http_only();
+        let header = cookie.to_string();
+        assert_eq!(
+            header,
+            "sid=abc-123; Expires=Wed, 21 Oct 2015 07:28:00 GMT; Max-Age=3600; Domain=example.com; Path=/app; Secure; HttpOnly; SameSite=Strict"
+        );
+        assert_eq!(Cookie::parse(&header), Ok(cookie));
+
+        // ชื่อ attribute ไม่สนตัวพิมพ์, `.domain` ถูกตัดจุด, attribute แปลกๆ ถูกข้าม
+        let parsed = Cookie::parse(
+            " lang = \"th\" ;secure; HTTPONLY; domain=.Example.COM; path=relative; Priority=High; samesite=lax",
+        )
+        .unwrap();
+        assert_eq!(
+            (parsed.name.as_str(), parsed.value.as_str()),
+            ("lang", "th")
+        );
+        assert!(parsed.secure && parsed.http_only);
+        assert_eq!(parsed.domain.as_deref(), Some("example.com"));
+        assert_eq!(parsed.path, None);
+        assert_eq!(parsed.same_site, Some(SameSite::Lax));
+
+        let removal = Cookie::parse(&Cookie::removal("sid").to_string()).unwrap();
+        assert_eq!(removal, Cookie::removal("sid"));
+    }
+
+    #[test]
+    fn test_invalid_cookies() {
+        let cases = [
+            ("sid", CookieError::MissingValue),
+            ("=value", CookieError::EmptyName),
+            ("s id=1", CookieError::InvalidName(' ')),
+            ("sid;=1", CookieError::MissingValue),
+            ("s(id=1", CookieError::InvalidName('(')),
+            ("sid=a b", CookieError::InvalidValue(' ')),
+            ("sid=a,b", CookieError::InvalidValue(',')),
+            ("sid=ปู", CookieError::InvalidValue('ป')),
+            (
+                "sid=1; Max-Age=soon",
+                CookieError::InvalidAttribute("Max-Age".into()),
+            ),
+            (
+                "sid=1; Expires=tomorrow",
+                CookieError::InvalidAttribute("Expires".into()),
+            ),
+            (
+                "sid=1; SameSite=Sometimes",
+                CookieError::InvalidAttribute("SameSite".into()),
+            ),
+        ];
+        for (header, expected) in cases {
+            assert_eq!(Cookie::parse(header), Err(expected), "{header}");
+        }
+        assert_eq!(
+            Cookie::new("sid", "a\"b"),
+            Err(CookieError::InvalidValue('"'))
+        );
+    }
+
+    #[test]
+    fn test_cookie_header_and_expiry() {
+        assert_eq!(
+            parse_cookie_header("a=1;  b=\"two\"; broken; c d=3; e=; =f"),
+            [
+                ("a".to_string(), "1".to_string()),
+                ("b".to_string(), "two".to_string()),
+                ("e".to_string(), String::new()),
+            ]
+        );
+
+        let received = date("2024-01-01T00:00:00Z");
+        let session = Cookie::new("sid", "1").unwrap();
+        assert!(!session.is_expired(received, date("2099-01-01T00:00:00Z")));
+        let short = session
+            .clone()
+            .with_max_age(60)
+            .with_expires(date("2099-01-01T00:00:00Z"));
+        assert!(!short.is_expired(received, date("2024-01-01T00:00:59Z")));
+        assert!(short.is_expired(received, date("2024-01-01T00:01:00Z")));
+        let dated = session.with_expires(date("2024-06-01T00:00:00Z"));
+        assert!(!dated.is_expired(received, date("2024-05-31T23:59:59Z")));
+        assert!(dated.is_expired(received, date("2024-06-01T00:00:00Z")));
+        assert!(Cookie::removal("sid").is_expired(received, received));
+    }
+
+    #[test]
+    fn test_signed_cookie_detects_tampering() {
+        let signer = CookieSigner::new(b"secret");
+        let cookie = Cookie::new("user", "ferris").unwrap().http_only();
+        let signed_cookie = signer.sign(&cookie);
+        assert!(signed_cookie.value.starts_with("ferris."));
+        assert!(signed_cookie.http_only);
+        // ค่าที่เซ็นแล้วยังเป็นค่า cookie ที่ถูกต้อง
+        assert_eq!(
+            Cookie::parse(&signed_cookie.to_string()),
+            Ok(signed_cookie.clone())
+        );
+        assert_eq!(signer.verify("user", &signed_cookie.value), Ok("ferris"));
+
+        let (_, signature) = signed_cookie.value.split_once('.').unwrap();
+        let mut flipped = signature.as_bytes().to_vec();
+        flipped[0] = if flipped[0] == b'A' { b'B' } else { b'A' };
+        let flipped = String::from_utf8(flipped).unwrap();
+        let tampered = [
+            format!("admin.{signature}"),
+            format!("ferris.{flipped}"),
+            format!("ferris.{}", &signature[1..]),
+            "ferris".to_string(),
+            "ferris.".to_string(),
+            "ferris.!!!".to_string(),
+        ];
+        for value in &tampered {
+            assert_eq!(
+                signer.verify("user", value),
+                Err(CookieError::InvalidSignature),
+                "{value}"
+            );
+        }
+        // ลายเซ็นผูกกับชื่อ cookie และ key
+        assert_eq!(
+            signer.verify("admin", &signed_cookie.value),
+            Err(CookieError::InvalidSignature)
+        );
+        let other = CookieSigner::new(b"other secret");
+        assert_eq!(
+            other.verify("user", &signed_cookie.value),
+            Err(CookieError::InvalidSignature)
+        );
+        // ค่าที่มี `.` อยู่แล้วยังเซ็น/ตรวจได้
+        let dotted = signer.sign(&Cookie::new("v", "1.2.3").unwrap());
+        assert_eq!(signer.verify("v", &dotted.value), Ok("1.2.3"));
+    }
+}
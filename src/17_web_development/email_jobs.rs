@@ -0,0 +1,296 @@
+//! 📧 Email Jobs - ส่งอีเมลเบื้องหลังผ่าน `jobs::JobQueue` แทนการส่งระหว่าง request
+//!
+//! `POST /signup` แค่ใส่งานลงคิวแล้วตอบ `202 Accepted` ทันที SMTP จะช้าหรือล่มผู้ใช้ก็ไม่ต้องรอ
+//! - 👋 อีเมลต้อนรับส่งทันที ส่วนอีเมลติดตามผลตั้งเวลาไว้อีก `FOLLOW_UP_DELAY`
+//! - 🔁 SMTP ตอบ 4xx/5xx → retry แบบ backoff และครบจำนวนครั้งแล้วไปอยู่ใน dead letter
+//! - 💾 คิวอยู่ในไฟล์ JSON - ปิดเซิร์ฟเวอร์แล้วเปิดใหม่ อีเมลที่ตั้งเวลาไว้ก็ยังอยู่ครบ
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use super::url::{encode_query, parse_query};
+use super::web_server::WebServer;
+use super::{HttpRequest, HttpResponse, HttpStatus};
+use crate::clock::{Clock, MockClock};
+use crate::jobs::{JobError, JobQueue, JobRunner, RetryPolicy};
+
+/// เวลาที่รอก่อนส่งอีเมลติดตามผลหลังสมัคร
+pub const FOLLOW_UP_DELAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// ✉️ อีเมลหนึ่งฉบับที่รอส่ง - เก็บเป็น JSON `{"type": "welcome", ...}` ในไฟล์คิว
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum EmailJob {
+    Welcome { to: String, name: String },
+    FollowUp { to: String },
+}
+
+impl EmailJob {
+    #[must_use]
+    pub fn recipient(&self) -> &str {
+        match self {
+            Self::Welcome { to, .. } | Self::FollowUp { to } => to,
+        }
+    }
+
+    #[must_use]
+    pub fn subject(&self) -> String {
+        match self {
+            Self::Welcome { name, .. } => format!("ยินดีต้อนรับ {name}! 🎉"),
+            Self::FollowUp { .. } => "เป็นอย่างไรบ้างกับวันแรก? 🙂".to_string(),
+        }
+    }
+}
+
+/// 📮 SMTP ปลอม: จำอีเมลที่ส่งสำเร็จ และล้มตามที่สั่งไว้ล่วงหน้า
+///
+/// โดเมน `.invalid` ถูกปฏิเสธถาวร (550) ส่วน `fail_next` จำลองเซิร์ฟเวอร์ล่มชั่วคราว (421)
+#[derive(Debug, Default)]
+pub struct FakeMailer {
+    sent: Mutex<Vec<String>>,
+    outages: Mutex<HashMap<String, u32>>,
+}
+
+impl FakeMailer {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// ให้การส่งถึง `address` ล้ม `times` ครั้งถัดไป
+    pub fn fail_next(&self, address: &str, times: u32) {
+        self.outages
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(address.to_string(), times);
+    }
+
+    /// ส่งอีเมลหนึ่งฉบับ
+    ///
+    /// # Errors
+    ///
+    /// คืนข้อความตอบกลับของ SMTP เมื่อส่งไม่สำเร็จ
+    pub fn send(&self, email: &EmailJob) -> Result<(), String> {
+        let to = email.recipient();
+        if to.ends_with(".invalid") {
+            return Err(format!("550 mailbox unavailable: {to}"));
+        }
+        if let Some(left) = self
+            .outages
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get_mut(to)
+            .filter(|left| **left > 0)
+        {
+            *left -= 1;
+            return Err("421 service not available, try again later".to_string());
+        }
+        self.sent
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(format!("{to}: {}", email.subject()));
+        Ok(())
+    }
+
+    /// อีเมลที่ส่งสำเร็จแล้ว ตามลำดับที่ส่ง
+    #[must_use]
+    pub fn sent(&self) -> Vec<String> {
+        self.sent
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+}
+
+/// 🛤️ ลง route `POST /signup` (body แบบ form: `name=...&email=...`) ที่ส่งอีเมลผ่าน `queue`
+pub fn signup_route(server: &mut WebServer, queue: Arc<JobQueue<EmailJob>>) {
+    server.route("POST /signup", move |request| {
+        let form = parse_query(&request.body);
+        let field = |name: &str| {
+            form.iter()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.clone())
+        };
+        let (Some(name), Some(to)) = (field("name"), field("email")) else {
+            let body = serde_json::json!({ "error": "name and email are required" });
+            return HttpResponse::json(HttpStatus::BadRequest, &body.to_string());
+        };
+        if !to.contains('@') {
+            let body = serde_json::json!({ "error": "invalid email address" });
+            return HttpResponse::json(HttpStatus::BadRequest, &body.to_string());
+        }
+
+        let queued = queue
+            .enqueue(EmailJob::Welcome {
+                to: to.clone(),
+                name,
+            })
+            .and_then(|welcome| {
+                let follow_up = queue.enqueue_in(EmailJob::FollowUp { to }, FOLLOW_UP_DELAY)?;
+                Ok([welcome, follow_up])
+            });
+        match queued {
+            Ok(ids) => {
+                let body = serde_json::json!({ "queued": ids });
+                HttpResponse::json(HttpStatus::Accepted, &body.to_string())
+            }
+            Err(error) => {
+                let body = serde_json::json!({ "error": error.to_string() });
+                HttpResponse::json(HttpStatus::ServiceUnavailable, &body.to_string())
+            }
+        }
+    });
+}
+
+/// 🎪 เดโมสมัครสมาชิกแล้วส่งอีเมลเบื้องหลัง - คิวเก็บไว้ใน `dir` และเดินเวลาด้วย `MockClock`
+///
+/// # Errors
+///
+/// คืน `JobError` ถ้าอ่าน/เขียนไฟล์คิวใน `dir` ไม่ได้
+pub fn demonstrate_email_jobs(dir: &Path) -> Result<(), JobError> {
+    let clock = MockClock::new();
+    let path = dir.join("email-jobs.json");
+    let policy = RetryPolicy::new(4, Duration::from_secs(30));
+    let mailer = Arc::new(FakeMailer::new());
+    mailer.fail_next("bob@example.com", 2);
+
+    {
+        let queue =
+            Arc::new(JobQueue::open(&path, Arc::new(clock.clone()))?.with_retry_policy(policy));
+        let mut server = WebServer::new();
+        signup_route(&mut server, Arc::clone(&queue));
+
+        for (name, email) in [
+            ("Alice", "alice@example.com"),
+            ("Bob", "bob@example.com"),
+            ("Ghost", "ghost@mail.invalid"),
+        ] {
+            let request = HttpRequest::new("POST", "/signup")
+                .with_body(&encode_query(&[("name", name), ("email", email)]));
+            let response = server.handle_request(&request);
+            println!(
+                "📨 POST /signup {email} → {} {}",
+                response.status.as_str(),
+                response.body
+            );
+        }
+
+        let worker_mailer = Arc::clone(&mailer);
+        let runner = JobRunner::new(Arc::clone(&queue), 2, move |email: &EmailJob| {
+            worker_mailer.send(email)
+        });
+        loop {
+            let report = runner.tick()?;
+            println!("⏱️ +{}s: {report}", clock.elapsed().as_secs());
+            // เดินเวลาไปถึง retry รอบถัดไป แต่หยุดก่อนถึงอีเมลติดตามผลของวันพรุ่งนี้
+            match queue
+                .next_run_at()
+                .and_then(|next| next.duration_since(clock.system_time()).ok())
+            {
+                Some(wait) if wait < FOLLOW_UP_DELAY / 2 => clock.advance(wait),
+                _ => break,
+            }
+        }
+        for dead in queue.dead_letters() {
+            println!(
+                "☠️ dead letter #{} ถึง {} หลังลอง {} ครั้ง: {}",
+                dead.id,
+                dead.payload.recipient(),
+                dead.attempts,
+                dead.last_error.as_deref().unwrap_or("-")
+            );
+        }
+    }
+
+    println!("🔌 ปิดเซิร์ฟเวอร์... แล้วเปิดใหม่จาก {}", path.display());
+    let queue = Arc::new(JobQueue::open(&path, Arc::new(clock.clone()))?.with_retry_policy(policy));
+    println!(
+        "💾 คิวที่โหลดกลับมา: รอส่ง {} ฉบับ, dead letter {} ฉบับ",
+        queue.pending_len(),
+        queue.dead_letters().len()
+    );
+
+    clock.advance(FOLLOW_UP_DELAY);
+    let worker_mailer = Arc::clone(&mailer);
+    let runner = JobRunner::new(queue, 2, move |email: &EmailJob| worker_mailer.send(email));
+    println!("📅 ผ่านไป 1 วัน: {}", runner.drain_due()?);
+
+    for email in mailer.sent() {
+        println!("✅ ส่งแล้ว → {email}");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_signup_queues_welcome_and_follow_up() {
+        let clock = MockClock::new();
+        let queue = Arc::new(JobQueue::new(Arc::new(clock.clone())));
+        let mut server = WebServer::new();
+        signup_route(&mut server, Arc::clone(&queue));
+
+        let response = server.handle_request(
+            &HttpRequest::new("POST", "/signup").with_body("name=Mali&email=mali%40example.com"),
+        );
+        assert_eq!(response.status, HttpStatus::Accepted);
+        assert_eq!(response.body, r#"{"queued":[1,2]}"#);
+        assert_eq!(
+            queue.next_run_at(),
+            Some(clock.system_time()),
+            "อีเมลต้อนรับพร้อมส่งทันที"
+        );
+
+        let missing = HttpRequest::new("POST", "/signup").with_body("name=Mali");
+        let invalid = HttpRequest::new("POST", "/signup").with_body("name=Mali&email=mali");
+        assert_eq!(
+            server.handle_request(&missing).status,
+            HttpStatus::BadRequest
+        );
+        assert_eq!(
+            server.handle_request(&invalid).status,
+            HttpStatus::BadRequest
+        );
+        assert_eq!(queue.pending_len(), 2);
+    }
+
+    #[test]
+    fn test_emails_are_retried_through_an_outage() {
+        let clock = MockClock::new();
+        let queue = Arc::new(
+            JobQueue::new(Arc::new(clock.clone()))
+                .with_retry_policy(RetryPolicy::new(3, Duration::from_secs(10))),
+        );
+        let mailer = Arc::new(FakeMailer::new());
+        mailer.fail_next("nok@example.com", 1);
+        let worker_mailer = Arc::clone(&mailer);
+        let runner = JobRunner::new(Arc::clone(&queue), 1, move |email: &EmailJob| {
+            worker_mailer.send(email)
+        });
+
+        queue
+            .enqueue(EmailJob::Welcome {
+                to: "nok@example.com".to_string(),
+                name: "Nok".to_string(),
+            })
+            .unwrap();
+        assert_eq!(runner.tick().unwrap().retried, 1);
+        assert!(mailer.sent().is_empty());
+
+        clock.advance(Duration::from_secs(10));
+        assert_eq!(runner.tick().unwrap().succeeded, 1);
+        assert_eq!(mailer.sent(), ["nok@example.com: ยินดีต้อนรับ Nok! 🎉"]);
+
+        let json = serde_json::to_string(&EmailJob::FollowUp {
+            to: "nok@example.com".to_string(),
+        })
+        .unwrap();
+        assert_eq!(json, r#"{"type":"follow_up","to":"nok@example.com"}"#);
+    }
+}
@@ -0,0 +1,1330 @@
+//! 🕸️ GraphQL-lite - query ตาม schema บนข้อมูลชุดเดียวกับ REST API
+//!
+//! REST มีหนึ่ง URL ต่อหนึ่ง resource และ server เป็นคนกำหนดรูปร่างของ response
+//! ส่วน GraphQL มี endpoint เดียว แล้วให้ client เขียนเองว่าอยากได้ field ไหนบ้าง:
+//!
+//! ```text
+//! {
+//!   user(id: 1) {
+//!     name
+//!     open: tasks(done: false) { title }
+//!   }
+//! }
+//! ```
+//!
+//! - 📜 schema (`Query`, `User`, `Task`) ถูกประกาศเป็นตาราง - query ถูกตรวจกับ schema ก่อนรันเสมอ
+//! - 🧭 parser แบบ recursive descent รองรับ field, alias, argument และ selection ซ้อนกัน
+//!   (ไม่รองรับ variables, fragments, directives และ mutation)
+//! - 📍 error บอก `locations` (บรรทัด/คอลัมน์) และ `path` ตามรูปแบบของ GraphQL spec
+//! - ⚠️ `serde_json::Map` เรียง key ตามตัวอักษร ลำดับ field ใน response จึงไม่ตาม query
+//!   (JSON object ไม่มีลำดับอยู่แล้ว client อ่านด้วยชื่อ key)
+
+use std::fmt::{self, Write};
+
+use serde_json::{Map, Value as Json, json};
+
+use super::rest_api::{Task, TaskRepository, User, UserRepository};
+use super::url::Query;
+use super::{HttpRequest, HttpResponse, HttpStatus};
+
+/// selection และ list ใน argument ซ้อนกันได้ลึกสุดเท่านี้ - กัน stack ล้นจาก `{a{a{a{...` หรือ `[[[[...`
+pub const MAX_DEPTH: usize = 32;
+
+/// 📍 ตำแหน่งใน query - เริ่มที่ 1 และนับเป็นตัวอักษร
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+/// ส่วนหนึ่งของ `path` ใน error - ชื่อ field (ตาม alias) หรือ index ของ list
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathSegment {
+    Field(String),
+    Index(usize),
+}
+
+/// ❌ error ตามรูปแบบ GraphQL - `path` ว่างถ้าเกิดก่อนรัน (parse/validation)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GraphQlError {
+    pub message: String,
+    pub locations: Vec<Location>,
+    pub path: Vec<PathSegment>,
+}
+
+impl GraphQlError {
+    fn at(message: impl Into<String>, location: Location) -> Self {
+        Self {
+            message: message.into(),
+            locations: vec![location],
+            path: Vec::new(),
+        }
+    }
+
+    /// `{"message", "locations": [{"line", "column"}], "path"?}`
+    #[must_use]
+    pub fn to_json(&self) -> Json {
+        let mut error = Map::new();
+        error.insert("message".to_string(), json!(self.message));
+        let locations: Vec<_> = self
+            .locations
+            .iter()
+            .map(|location| json!({ "line": location.line, "column": location.column }))
+            .collect();
+        error.insert("locations".to_string(), json!(locations));
+        if !self.path.is_empty() {
+            let path: Vec<_> = self
+                .path
+                .iter()
+                .map(|segment| match segment {
+                    PathSegment::Field(name) => json!(name),
+                    PathSegment::Index(index) => json!(index),
+                })
+                .collect();
+            error.insert("path".to_string(), json!(path));
+        }
+        Json::Object(error)
+    }
+
+    /// บรรทัดที่ผิดพร้อม `^` ใต้ตำแหน่งแรก
+    #[must_use]
+    pub fn render(&self, source: &str) -> String {
+        let Some(location) = self.locations.first() else {
+            return self.to_string();
+        };
+        let code = source.lines().nth(location.line - 1).unwrap_or_default();
+        format!(
+            "{self}\n  {code}\n  {}^",
+            " ".repeat(location.column.saturating_sub(1))
+        )
+    }
+}
+
+impl fmt::Display for GraphQlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)?;
+        if let Some(location) = self.locations.first() {
+            write!(f, " (บรรทัด {} คอลัมน์ {})", location.line, location.column)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for GraphQlError {}
+
+/// 🔣 ค่าของ argument ตามไวยากรณ์ GraphQL
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Null,
+    Int(i64),
+    Float(f64),
+    String(String),
+    Boolean(bool),
+    /// ชื่อเปล่าๆ ที่ไม่ใช่ `true`/`false`/`null` เช่น `ASC`
+    Enum(String),
+    List(Vec<Self>),
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Null => f.write_str("null"),
+            Self::Int(value) => write!(f, "{value}"),
+            Self::Float(value) => write!(f, "{value}"),
+            Self::String(value) => write!(f, "{}", json!(value)),
+            Self::Boolean(value) => write!(f, "{value}"),
+            Self::Enum(name) => f.write_str(name),
+            Self::List(items) => {
+                f.write_str("[")?;
+                for (index, item) in items.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{item}")?;
+                }
+                f.write_str("]")
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Argument {
+    pub name: String,
+    pub value: Value,
+    pub location: Location,
+}
+
+/// 🌿 field หนึ่งตัวใน selection - `alias: name(args) { selection }`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Field {
+    pub alias: Option<String>,
+    pub name: String,
+    pub arguments: Vec<Argument>,
+    pub selection: Vec<Self>,
+    pub location: Location,
+}
+
+impl Field {
+    /// ชื่อ key ใน response (alias ถ้ามี)
+    #[must_use]
+    pub fn response_key(&self) -> &str {
+        self.alias.as_deref().unwrap_or(&self.name)
+    }
+
+    fn argument(&self, name: &str) -> Option<&Value> {
+        self.arguments
+            .iter()
+            .find(|argument| argument.name == name)
+            .map(|argument| &argument.value)
+    }
+}
+
+/// 📄 query หนึ่งตัว (`query Name { ... }` หรือแบบย่อ `{ ... }`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Document {
+    pub name: Option<String>,
+    pub selection: Vec<Field>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Punct(char),
+    Spread,
+    Name(String),
+    Int(i64),
+    Float(f64),
+    Str(String),
+    End,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Punct(c) => write!(f, "\"{c}\""),
+            Self::Spread => f.write_str("\"...\""),
+            Self::Name(name) => write!(f, "Name \"{name}\""),
+            Self::Int(value) => write!(f, "Int \"{value}\""),
+            Self::Float(value) => write!(f, "Float \"{value}\""),
+            Self::Str(value) => write!(f, "String {}", json!(value)),
+            Self::End => f.write_str("<EOF>"),
+        }
+    }
+}
+
+/// ตัวอ่านทีละตัวอักษรที่จำบรรทัด/คอลัมน์ไว้
+struct Cursor {
+    chars: Vec<char>,
+    position: usize,
+    location: Location,
+}
+
+impl Cursor {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.position += 1;
+        if c == '\n' {
+            self.location.line += 1;
+            self.location.column = 1;
+        } else {
+            self.location.column += 1;
+        }
+        Some(c)
+    }
+}
+
+fn syntax_error(message: impl fmt::Display, location: Location) -> GraphQlError {
+    GraphQlError::at(format!("Syntax Error: {message}"), location)
+}
+
+fn tokenize(source: &str) -> Result<Vec<(Token, Location)>, GraphQlError> {
+    let mut cursor = Cursor {
+        chars: source.chars().collect(),
+        position: 0,
+        location: Location { line: 1, column: 1 },
+    };
+    let mut tokens = Vec::new();
+    loop {
+        let start = cursor.location;
+        let Some(c) = cursor.peek() else {
+            tokens.push((Token::End, start));
+            return Ok(tokens);
+        };
+        match c {
+            // comma ไม่มีความหมายใน GraphQL - นับเป็นช่องว่าง
+            ' ' | '\t' | '\n' | '\r' | ',' | '\u{feff}' => {
+                cursor.bump();
+            }
+            '#' => {
+                while cursor.peek().is_some_and(|c| c != '\n') {
+                    cursor.bump();
+                }
+            }
+            '{' | '}' | '(' | ')' | '[' | ']' | ':' | '!' | '$' | '@' | '=' | '|' | '&' => {
+                cursor.bump();
+                tokens.push((Token::Punct(c), start));
+            }
+            '.' => {
+                for _ in 0..3 {
+                    if cursor.bump() != Some('.') {
+                        return Err(syntax_error("Unexpected \".\"", start));
+                    }
+                }
+                tokens.push((Token::Spread, start));
+            }
+            '"' => tokens.push((Token::Str(read_string(&mut cursor)?), start)),
+            c if c == '-' || c.is_ascii_digit() => tokens.push((read_number(&mut cursor)?, start)),
+            c if c == '_' || c.is_ascii_alphabetic() => {
+                let mut name = String::new();
+                while let Some(c) = cursor
+                    .peek()
+                    .filter(|c| *c == '_' || c.is_ascii_alphanumeric())
+                {
+                    name.push(c);
+                    cursor.bump();
+                }
+                tokens.push((Token::Name(name), start));
+            }
+            c => return Err(syntax_error(format!("Unexpected character {c:?}"), start)),
+        }
+    }
+}
+
+fn read_string(cursor: &mut Cursor) -> Result<String, GraphQlError> {
+    let start = cursor.location;
+    cursor.bump();
+    let mut value = String::new();
+    loop {
+        let location = cursor.location;
+        match cursor.bump() {
+            None | Some('\n' | '\r') => return Err(syntax_error("Unterminated string", start)),
+            Some('"') => return Ok(value),
+            Some('\\') => {
+                let escaped = match cursor.bump() {
+                    Some('"') => '"',
+                    Some('\\') => '\\',
+                    Some('/') => '/',
+                    Some('n') => '\n',
+                    Some('t') => '\t',
+                    Some('r') => '\r',
+                    Some('b') => '\u{8}',
+                    Some('f') => '\u{c}',
+                    Some('u') => {
+                        let digits: String = (0..4).filter_map(|_| cursor.bump()).collect();
+                        u32::from_str_radix(&digits, 16)
+                            .ok()
+                            .and_then(char::from_u32)
+                            .ok_or_else(|| {
+                                syntax_error(
+                                    format!("Invalid Unicode escape \\u{digits}"),
+                                    location,
+                                )
+                            })?
+                    }
+                    other => {
+                        let found = other.map_or_else(String::new, String::from);
+                        return Err(syntax_error(format!("Invalid escape \\{found}"), location));
+                    }
+                };
+                value.push(escaped);
+            }
+            Some(c) => value.push(c),
+        }
+    }
+}
+
+fn read_number(cursor: &mut Cursor) -> Result<Token, GraphQlError> {
+    let start = cursor.location;
+    let mut text = String::new();
+    let mut is_float = false;
+    while let Some(c) = cursor.peek() {
+        match c {
+            '0'..='9' | '-' | '+' => {}
+            '.' | 'e' | 'E' => is_float = true,
+            _ => break,
+        }
+        text.push(c);
+        cursor.bump();
+    }
+    if cursor
+        .peek()
+        .is_some_and(|c| c == '_' || c.is_ascii_alphabetic())
+    {
+        return Err(syntax_error(format!("Invalid number \"{text}\""), start));
+    }
+    let invalid = || syntax_error(format!("Invalid number \"{text}\""), start);
+    if is_float {
+        text.parse().map(Token::Float).map_err(|_| invalid())
+    } else {
+        text.parse().map(Token::Int).map_err(|_| invalid())
+    }
+}
+
+struct Parser {
+    tokens: Vec<(Token, Location)>,
+    position: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> &Token {
+        &self.tokens[self.position].0
+    }
+
+    fn location(&self) -> Location {
+        self.tokens[self.position].1
+    }
+
+    fn next(&mut self) -> (Token, Location) {
+        let token = self.tokens[self.position].clone();
+        if token.0 != Token::End {
+            self.position += 1;
+        }
+        token
+    }
+
+    fn unexpected(&self) -> GraphQlError {
+        syntax_error(format!("Unexpected {}.", self.peek()), self.location())
+    }
+
+    fn unsupported(&self, feature: &str) -> GraphQlError {
+        GraphQlError::at(
+            format!("{feature} are not supported by GraphQL-lite."),
+            self.location(),
+        )
+    }
+
+    fn expect(&mut self, expected: char) -> Result<Location, GraphQlError> {
+        if *self.peek() == Token::Punct(expected) {
+            Ok(self.next().1)
+        } else {
+            Err(syntax_error(
+                format!("Expected \"{expected}\", found {}.", self.peek()),
+                self.location(),
+            ))
+        }
+    }
+
+    fn expect_name(&mut self) -> Result<(String, Location), GraphQlError> {
+        match self.next() {
+            (Token::Name(name), location) => Ok((name, location)),
+            (token, location) => Err(syntax_error(
+                format!("Expected Name, found {token}."),
+                location,
+            )),
+        }
+    }
+
+    fn document(&mut self) -> Result<Document, GraphQlError> {
+        let name = match self.peek() {
+            Token::Punct('{') => None,
+            Token::Name(keyword) if keyword == "query" => {
+                self.next();
+                let name = match self.peek() {
+                    Token::Name(_) => Some(self.expect_name()?.0),
+                    _ => None,
+                };
+                if *self.peek() == Token::Punct('(') {
+                    return Err(self.unsupported("Variables"));
+                }
+                name
+            }
+            Token::Name(keyword) if keyword == "mutation" || keyword == "subscription" => {
+                return Err(GraphQlError::at(
+                    "Only query operations are supported by GraphQL-lite.",
+                    self.location(),
+                ));
+            }
+            Token::Name(keyword) if keyword == "fragment" => {
+                return Err(self.unsupported("Fragments"));
+            }
+            _ => return Err(self.unexpected()),
+        };
+        let selection = self.selection_set(1)?;
+        if *self.peek() == Token::End {
+            Ok(Document { name, selection })
+        } else {
+            Err(self.unexpected())
+        }
+    }
+
+    fn selection_set(&mut self, depth: usize) -> Result<Vec<Field>, GraphQlError> {
+        if depth > MAX_DEPTH {
+            return Err(GraphQlError::at(
+                format!("Query is nested deeper than {MAX_DEPTH} levels."),
+                self.location(),
+            ));
+        }
+        self.expect('{')?;
+        let mut fields = vec![self.field(depth)?];
+        while *self.peek() != Token::Punct('}') {
+            fields.push(self.field(depth)?);
+        }
+        self.next();
+        Ok(fields)
+    }
+
+    fn field(&mut self, depth: usize) -> Result<Field, GraphQlError> {
+        if *self.peek() == Token::Spread {
+            return Err(self.unsupported("Fragments"));
+        }
+        let (mut name, location) = self.expect_name()?;
+        let mut alias = None;
+        if *self.peek() == Token::Punct(':') {
+            self.next();
+            alias = Some(name);
+            name = self.expect_name()?.0;
+        }
+        let mut arguments = Vec::new();
+        if *self.peek() == Token::Punct('(') {
+            self.next();
+            loop {
+                let (name, location) = self.expect_name()?;
+                self.expect(':')?;
+                let value = self.value(depth)?;
+                arguments.push(Argument {
+                    name,
+                    value,
+                    location,
+                });
+                if *self.peek() == Token::Punct(')') {
+                    self.next();
+                    break;
+                }
+            }
+        }
+        if *self.peek() == Token::Punct('@') {
+            return Err(self.unsupported("Directives"));
+        }
+        let selection = if *self.peek() == Token::Punct('{') {
+            self.selection_set(depth + 1)?
+        } else {
+            Vec::new()
+        };
+        Ok(Field {
+            alias,
+            name,
+            arguments,
+            selection,
+            location,
+        })
+    }
+
+    /// `depth` นับต่อจากความลึกของ field ที่ argument นี้อยู่ - list แต่ละชั้นเพิ่มอีกหนึ่ง
+    fn value(&mut self, depth: usize) -> Result<Value, GraphQlError> {
+        match self.peek() {
+            Token::Punct('$') => return Err(self.unsupported("Variables")),
+            Token::Punct('{') => return Err(self.unsupported("Input objects")),
+            Token::Punct('[') => {
+                if depth >= MAX_DEPTH {
+                    return Err(GraphQlError::at(
+                        format!("List value is nested deeper than {MAX_DEPTH} levels."),
+                        self.location(),
+                    ));
+                }
+                self.next();
+                let mut items = Vec::new();
+                while *self.peek() != Token::Punct(']') {
+                    items.push(self.value(depth + 1)?);
+                }
+                self.next();
+                return Ok(Value::List(items));
+            }
+            _ => {}
+        }
+        Ok(match self.next() {
+            (Token::Int(value), _) => Value::Int(value),
+            (Token::Float(value), _) => Value::Float(value),
+            (Token::Str(value), _) => Value::String(value),
+            (Token::Name(name), _) => match name.as_str() {
+                "true" => Value::Boolean(true),
+                "false" => Value::Boolean(false),
+                "null" => Value::Null,
+                _ => Value::Enum(name),
+            },
+            (token, location) => {
+                return Err(syntax_error(format!("Unexpected {token}."), location));
+            }
+        })
+    }
+}
+
+/// 🧭 แปลงข้อความ query เป็น `Document`
+///
+/// # Errors
+///
+/// คืน `GraphQlError` พร้อมตำแหน่ง ถ้าไวยากรณ์ผิดหรือใช้ความสามารถที่ไม่รองรับ
+pub fn parse(source: &str) -> Result<Document, GraphQlError> {
+    let mut parser = Parser {
+        tokens: tokenize(source)?,
+        position: 0,
+    };
+    parser.document()
+}
+
+/// ชนิดของ argument (scalar อย่างเดียว)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum InputType {
+    Int,
+    String,
+    Boolean,
+}
+
+struct ArgumentDef {
+    name: &'static str,
+    input: InputType,
+    required: bool,
+}
+
+impl ArgumentDef {
+    fn type_name(&self) -> String {
+        let name = match self.input {
+            InputType::Int => "Int",
+            InputType::String => "String",
+            InputType::Boolean => "Boolean",
+        };
+        if self.required {
+            format!("{name}!")
+        } else {
+            name.to_string()
+        }
+    }
+
+    const fn accepts(&self, value: &Value) -> bool {
+        match (value, self.input) {
+            (Value::Null, _) => !self.required,
+            (Value::Int(_), InputType::Int)
+            | (Value::String(_), InputType::String)
+            | (Value::Boolean(_), InputType::Boolean) => true,
+            _ => false,
+        }
+    }
+}
+
+/// 📜 field หนึ่งตัวใน schema - `object` คือชนิด object ที่ต้องมี selection ต่อ (scalar = `None`)
+struct FieldDef {
+    name: &'static str,
+    type_name: &'static str,
+    object: Option<&'static str>,
+    arguments: &'static [ArgumentDef],
+}
+
+const fn field(name: &'static str, type_name: &'static str) -> FieldDef {
+    FieldDef {
+        name,
+        type_name,
+        object: None,
+        arguments: &[],
+    }
+}
+
+const fn object_field(
+    name: &'static str,
+    type_name: &'static str,
+    object: &'static str,
+    arguments: &'static [ArgumentDef],
+) -> FieldDef {
+    FieldDef {
+        name,
+        type_name,
+        object: Some(object),
+        arguments,
+    }
+}
+
+const ID_ARGUMENT: &[ArgumentDef] = &[ArgumentDef {
+    name: "id",
+    input: InputType::Int,
+    required: true,
+}];
+
+const DONE_ARGUMENT: &[ArgumentDef] = &[ArgumentDef {
+    name: "done",
+    input: InputType::Boolean,
+    required: false,
+}];
+
+const USERS_ARGUMENTS: &[ArgumentDef] = &[
+    ArgumentDef {
+        name: "search",
+        input: InputType::String,
+        required: false,
+    },
+    ArgumentDef {
+        name: "limit",
+        input: InputType::Int,
+        required: false,
+    },
+];
+
+/// 🗺️ schema ทั้งหมด: `(ชื่อ type, fields)` - `Query` คือจุดเริ่มของทุก query
+const SCHEMA: &[(&str, &[FieldDef])] = &[
+    (
+        "Query",
+        &[
+            object_field("user", "User", "User", ID_ARGUMENT),
+            object_field("users", "[User!]!", "User", USERS_ARGUMENTS),
+            object_field("task", "Task", "Task", ID_ARGUMENT),
+            object_field("tasks", "[Task!]!", "Task", DONE_ARGUMENT),
+        ],
+    ),
+    (
+        "User",
+        &[
+            field("id", "Int!"),
+            field("name", "String!"),
+            field("email", "String!"),
+            field("age", "Int!"),
+            object_field("tasks", "[Task!]!", "Task", DONE_ARGUMENT),
+        ],
+    ),
+    (
+        "Task",
+        &[
+            field("id", "Int!"),
+            field("title", "String!"),
+            field("done", "Boolean!"),
+            object_field("owner", "User", "User", &[]),
+        ],
+    ),
+];
+
+fn fields_of(type_name: &str) -> &'static [FieldDef] {
+    SCHEMA
+        .iter()
+        .find(|(name, _)| *name == type_name)
+        .map_or(&[], |(_, fields)| fields)
+}
+
+/// 📜 schema ในรูป SDL - เอกสารที่ client ใช้รู้ว่าถามอะไรได้บ้าง
+#[must_use]
+pub fn schema_sdl() -> String {
+    let mut sdl = String::new();
+    for (type_name, fields) in SCHEMA {
+        let _ = writeln!(sdl, "type {type_name} {{");
+        for field in *fields {
+            let arguments: Vec<_> = field
+                .arguments
+                .iter()
+                .map(|argument| format!("{}: {}", argument.name, argument.type_name()))
+                .collect();
+            if arguments.is_empty() {
+                let _ = writeln!(sdl, "  {}: {}", field.name, field.type_name);
+            } else {
+                let _ = writeln!(
+                    sdl,
+                    "  {}({}): {}",
+                    field.name,
+                    arguments.join(", "),
+                    field.type_name
+                );
+            }
+        }
+        sdl.push_str("}\n");
+    }
+    sdl
+}
+
+/// ✅ ตรวจ selection กับ schema - เก็บ error ทุกจุดไม่หยุดที่ตัวแรก
+fn validate(selection: &[Field], parent: &str, errors: &mut Vec<GraphQlError>) {
+    for field in selection {
+        if field.name == "__typename" {
+            if !field.selection.is_empty() {
+                errors.push(GraphQlError::at(
+                    "Field \"__typename\" must not have a selection since type \"String!\" has no subfields.",
+                    field.location,
+                ));
+            }
+            continue;
+        }
+        let Some(definition) = fields_of(parent).iter().find(|def| def.name == field.name) else {
+            errors.push(GraphQlError::at(
+                format!(
+                    "Cannot query field \"{}\" on type \"{parent}\".",
+                    field.name
+                ),
+                field.location,
+            ));
+            continue;
+        };
+
+        for argument in &field.arguments {
+            match definition
+                .arguments
+                .iter()
+                .find(|def| def.name == argument.name)
+            {
+                None => errors.push(GraphQlError::at(
+                    format!(
+                        "Unknown argument \"{}\" on field \"{parent}.{}\".",
+                        argument.name, field.name
+                    ),
+                    argument.location,
+                )),
+                Some(def) if !def.accepts(&argument.value) => errors.push(GraphQlError::at(
+                    format!(
+                        "Argument \"{}\" has invalid value {}. Expected type \"{}\".",
+                        argument.name,
+                        argument.value,
+                        def.type_name()
+                    ),
+                    argument.location,
+                )),
+                Some(_) => {}
+            }
+        }
+        for def in definition.arguments.iter().filter(|def| def.required) {
+            if field.argument(def.name).is_none() {
+                errors.push(GraphQlError::at(
+                    format!(
+                        "Field \"{}\" argument \"{}\" of type \"{}\" is required, but it was not provided.",
+                        field.name,
+                        def.name,
+                        def.type_name()
+                    ),
+                    field.location,
+                ));
+            }
+        }
+
+        match (definition.object, field.selection.is_empty()) {
+            (Some(object), false) => validate(&field.selection, object, errors),
+            (Some(_), true) => errors.push(GraphQlError::at(
+                format!(
+                    "Field \"{}\" of type \"{}\" must have a selection of subfields.",
+                    field.name, definition.type_name
+                ),
+                field.location,
+            )),
+            (None, false) => errors.push(GraphQlError::at(
+                format!(
+                    "Field \"{}\" must not have a selection since type \"{}\" has no subfields.",
+                    field.name, definition.type_name
+                ),
+                field.location,
+            )),
+            (None, true) => {}
+        }
+    }
+}
+
+/// object ที่กำลัง resolve อยู่
+enum Parent {
+    Query,
+    User(User),
+    Task(Task),
+}
+
+impl Parent {
+    const fn type_name(&self) -> &'static str {
+        match self {
+            Self::Query => "Query",
+            Self::User(_) => "User",
+            Self::Task(_) => "Task",
+        }
+    }
+}
+
+/// สิ่งที่ field คืนมาก่อนจะถูกตัดตาม selection
+enum Resolved {
+    Scalar(Json),
+    Object(Option<Parent>),
+    List(Vec<Parent>),
+}
+
+/// ⚙️ รัน query บน repository เดียวกับ REST API
+pub struct GraphQlExecutor {
+    users: UserRepository,
+    tasks: TaskRepository,
+}
+
+/// body ของ `POST /graphql`
+#[derive(Debug, ::serde::Deserialize)]
+struct GraphQlRequest {
+    query: String,
+}
+
+impl GraphQlExecutor {
+    #[must_use]
+    pub const fn new(users: UserRepository, tasks: TaskRepository) -> Self {
+        Self { users, tasks }
+    }
+
+    /// ▶️ parse → validate → execute แล้วคืน response ตามรูปแบบ GraphQL
+    ///
+    /// - ผิดตั้งแต่ parse/validate: `{"errors": [...]}` ไม่มี `data`
+    /// - ผิดระหว่างรัน: field นั้นเป็น `null` และมี `errors` พร้อม `path` คู่กับ `data`
+    #[must_use]
+    pub fn execute(&self, source: &str) -> Json {
+        let document = match parse(source) {
+            Ok(document) => document,
+            Err(error) => return json!({ "errors": [error.to_json()] }),
+        };
+        let mut errors = Vec::new();
+        validate(&document.selection, "Query", &mut errors);
+        if !errors.is_empty() {
+            let errors: Vec<_> = errors.iter().map(GraphQlError::to_json).collect();
+            return json!({ "errors": errors });
+        }
+
+        let mut path = Vec::new();
+        let data = self.select(&Parent::Query, &document.selection, &mut path, &mut errors);
+        if errors.is_empty() {
+            json!({ "data": data })
+        } else {
+            let errors: Vec<_> = errors.iter().map(GraphQlError::to_json).collect();
+            json!({ "data": data, "errors": errors })
+        }
+    }
+
+    /// 🌐 `POST /graphql` (body `{"query": "..."}`) หรือ `GET /graphql?query=...`
+    ///
+    /// query ที่รันได้ตอบ 200 เสมอ (แม้มี error บางส่วน) ส่วน query ที่ parse/validate ไม่ผ่านได้ 400
+    #[must_use]
+    pub fn handle(&self, request: &HttpRequest) -> HttpResponse {
+        let query = if request.method == "GET" {
+            request
+                .url()
+                .ok()
+                .and_then(|url| url.query().map(str::to_string))
+                .and_then(|query| query.parse::<Query<GraphQlRequest>>().ok())
+                .map(|Query(body)| body.query)
+        } else {
+            serde_json::from_str::<GraphQlRequest>(&request.body)
+                .ok()
+                .map(|body| body.query)
+        };
+        let Some(query) = query else {
+            let body =
+                json!({ "errors": [{ "message": "Request must contain a \"query\" string." }] });
+            return HttpResponse::json(HttpStatus::BadRequest, &body.to_string());
+        };
+        let response = self.execute(&query);
+        let status = if response.get("data").is_some() {
+            HttpStatus::Ok
+        } else {
+            HttpStatus::BadRequest
+        };
+        HttpResponse::json(status, &response.to_string())
+    }
+
+    fn select(
+        &self,
+        parent: &Parent,
+        selection: &[Field],
+        path: &mut Vec<PathSegment>,
+        errors: &mut Vec<GraphQlError>,
+    ) -> Json {
+        let mut object = Map::new();
+        for field in selection {
+            path.push(PathSegment::Field(field.response_key().to_string()));
+            let value = match self.resolve(parent, field) {
+                Ok(Resolved::Scalar(value)) => value,
+                Ok(Resolved::Object(child)) => child.map_or(Json::Null, |child| {
+                    self.select(&child, &field.selection, path, errors)
+                }),
+                Ok(Resolved::List(items)) => {
+                    let mut values = Vec::with_capacity(items.len());
+                    for (index, item) in items.iter().enumerate() {
+                        path.push(PathSegment::Index(index));
+                        values.push(self.select(item, &field.selection, path, errors));
+                        path.pop();
+                    }
+                    Json::Array(values)
+                }
+                Err(message) => {
+                    errors.push(GraphQlError {
+                        message,
+                        locations: vec![field.location],
+                        path: path.clone(),
+                    });
+                    Json::Null
+                }
+            };
+            object.insert(field.response_key().to_string(), value);
+            path.pop();
+        }
+        Json::Object(object)
+    }
+
+    /// 🔍 resolver ของแต่ละ field - argument ผ่านการตรวจชนิดมาแล้ว
+    fn resolve(&self, parent: &Parent, field: &Field) -> Result<Resolved, String> {
+        let int = |name: &str| match field.argument(name) {
+            Some(Value::Int(value)) => Some(*value),
+            _ => None,
+        };
+        let id = || int("id").and_then(|id| u32::try_from(id).ok());
+        let done = match field.argument("done") {
+            Some(Value::Boolean(done)) => Some(*done),
+            _ => None,
+        };
+        let filter_done = |tasks: Vec<Task>| -> Vec<Parent> {
+            tasks
+                .into_iter()
+                .filter(|task| done.is_none_or(|done| task.done == done))
+                .map(Parent::Task)
+                .collect()
+        };
+
+        Ok(match (parent, field.name.as_str()) {
+            (_, "__typename") => Resolved::Scalar(json!(parent.type_name())),
+            (Parent::Query, "user") => Resolved::Object(
+                id().and_then(|id| self.users.get_by_id(id))
+                    .map(Parent::User),
+            ),
+            (Parent::Query, "users") => {
+                let limit = match int("limit") {
+                    Some(limit) => Some(usize::try_from(limit).map_err(|_| {
+                        format!("Argument \"limit\" must not be negative, got {limit}.")
+                    })?),
+                    None => None,
+                };
+                let mut users = match field.argument("search") {
+                    Some(Value::String(search)) => self.users.search(search),
+                    _ => self.users.get_all(),
+                };
+                users.sort_by_key(|user| user.id);
+                users.truncate(limit.unwrap_or(usize::MAX));
+                Resolved::List(users.into_iter().map(Parent::User).collect())
+            }
+            (Parent::Query, "task") => Resolved::Object(
+                id().and_then(|id| self.tasks.get_by_id(id))
+                    .map(Parent::Task),
+            ),
+            (Parent::Query, "tasks") => Resolved::List(filter_done(self.tasks.get_all())),
+            (Parent::User(user), "id") => Resolved::Scalar(json!(user.id)),
+            (Parent::User(user), "name") => Resolved::Scalar(json!(user.name)),
+            (Parent::User(user), "email") => Resolved::Scalar(json!(user.email)),
+            (Parent::User(user), "age") => Resolved::Scalar(json!(user.age)),
+            (Parent::User(user), "tasks") => {
+                Resolved::List(filter_done(self.tasks.by_owner(user.id)))
+            }
+            (Parent::Task(task), "id") => Resolved::Scalar(json!(task.id)),
+            (Parent::Task(task), "title") => Resolved::Scalar(json!(task.title)),
+            (Parent::Task(task), "done") => Resolved::Scalar(json!(task.done)),
+            (Parent::Task(task), "owner") => {
+                Resolved::Object(self.users.get_by_id(task.owner_id).map(Parent::User))
+            }
+            (parent, name) => {
+                return Err(format!(
+                    "No resolver for field \"{}.{name}\".",
+                    parent.type_name()
+                ));
+            }
+        })
+    }
+}
+
+/// 🎭 สาธิต GraphQL-lite เทียบกับ REST บนข้อมูลชุดเดียวกัน
+pub fn demonstrate_graphql() {
+    println!("🕸️ GraphQL-lite - client เลือก field เอง, server ตรวจกับ schema");
+    println!("\n📜 Schema:\n{}", schema_sdl());
+
+    let executor = GraphQlExecutor::new(UserRepository::new(), TaskRepository::new());
+
+    println!(
+        "📡 REST: ต้องยิง GET /api/users/1 แล้วยิงต่ออีกรอบเพื่อเอางานของผู้ใช้ - ได้ทุก field ที่ server เลือกให้"
+    );
+    println!("🕸️ GraphQL: request เดียวได้ทั้งผู้ใช้และงาน เฉพาะ field ที่ขอ");
+    let queries = [
+        "{\n  user(id: 1) {\n    name\n    open: tasks(done: false) { title }\n  }\n}",
+        "query Overview {\n  users(limit: 2) { id name }\n  tasks(done: true) { title owner { name } }\n}",
+        "{\n  user(id: 99) { name }\n  users(limit: -1) { id }\n}",
+        "{\n  user(id: 1) {\n    name\n    password\n  }\n  users { email { domain } }\n}",
+        "{\n  user(id: \"one\") { name }\n}",
+        "{\n  user(id: 1) { name\n}",
+    ];
+    for query in queries {
+        println!("\n📝 Query:\n{query}");
+        let response = executor.execute(query);
+        println!(
+            "📤 Response:\n{}",
+            serde_json::to_string_pretty(&response).unwrap_or_default()
+        );
+    }
+
+    let broken = "{\n  user(id: 1) {\n    name\n    password\n  }\n}";
+    if let Json::Object(response) = executor.execute(broken) {
+        println!("\n📍 error ชี้ตำแหน่งใน query ได้:");
+        if let Ok(document) = parse(broken) {
+            let mut errors = Vec::new();
+            validate(&document.selection, "Query", &mut errors);
+            for error in errors {
+                println!("{}", error.render(broken));
+            }
+        }
+        println!("(response มี data: {})", response.contains_key("data"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn executor() -> GraphQlExecutor {
+        GraphQlExecutor::new(UserRepository::new(), TaskRepository::new())
+    }
+
+    #[test]
+    fn test_selected_fields_match_expected_json() {
+        let response = executor().execute(
+            "query {
+               user(id: 1) {
+                 name
+                 open: tasks(done: false) { id title }
+                 __typename
+               }
+             }",
+        );
+        assert_eq!(
+            response,
+            json!({
+                "data": {
+                    "user": {
+                        "name": "Workshop John",
+                        "open": [{ "id": 2, "title": "Write REST handlers" }],
+                        "__typename": "User"
+                    }
+                }
+            })
+        );
+
+        let response = executor().execute(
+            "{ users(search: \"workshop\", limit: 2) { id } tasks(done: true) { title owner { email } } }",
+        );
+        assert_eq!(
+            response,
+            json!({
+                "data": {
+                    "users": [{ "id": 1 }, { "id": 2 }],
+                    "tasks": [
+                        { "title": "Set up Rust toolchain", "owner": { "email": "john@workshop.dev" } },
+                        { "title": "Review pull requests", "owner": { "email": "bob@workshop.dev" } }
+                    ]
+                }
+            })
+        );
+
+        // ไม่พบ → null ไม่ใช่ error; alias ทำให้ขอ field เดียวกันสองครั้งด้วย argument ต่างกันได้
+        let response = executor().execute("{ a: user(id: 2) { name } b: user(id: 42) { name } }");
+        assert_eq!(
+            response,
+            json!({ "data": { "a": { "name": "Workshop Jane" }, "b": null } })
+        );
+    }
+
+    #[test]
+    fn test_parse_fields_arguments_and_nested_selection() {
+        let document = parse(
+            "# comment\nquery Named { first: user(id: 1, flag: true, tags: [\"a\" B null], ratio: -1.5e2) { tasks { id } } }",
+        )
+        .unwrap();
+        assert_eq!(document.name.as_deref(), Some("Named"));
+        let field = &document.selection[0];
+        assert_eq!(field.response_key(), "first");
+        assert_eq!(field.name, "user");
+        assert_eq!(
+            field.location,
+            Location {
+                line: 2,
+                column: 15
+            }
+        );
+        let values: Vec<_> = field
+            .arguments
+            .iter()
+            .map(|argument| argument.value.clone())
+            .collect();
+        assert_eq!(
+            values,
+            [
+                Value::Int(1),
+                Value::Boolean(true),
+                Value::List(vec![
+                    Value::String("a".to_string()),
+                    Value::Enum("B".to_string()),
+                    Value::Null
+                ]),
+                Value::Float(-150.0),
+            ]
+        );
+        assert_eq!(field.selection[0].selection[0].name, "id");
+    }
+
+    #[test]
+    fn test_syntax_errors_have_locations() {
+        let cases = [
+            (
+                "{ user(id: 1) { name }",
+                1,
+                23,
+                "Syntax Error: Expected Name, found <EOF>.",
+            ),
+            (
+                "{\n  user(id: 1) {\n    name\n  }\n}}",
+                5,
+                2,
+                "Syntax Error: Unexpected \"}\".",
+            ),
+            (
+                "{ user(id 1) { name } }",
+                1,
+                11,
+                "Syntax Error: Expected \":\", found Int \"1\".",
+            ),
+            ("{ }", 1, 3, "Syntax Error: Expected Name, found \"}\"."),
+            (
+                "{ user(id: \"oops) { name } }",
+                1,
+                12,
+                "Syntax Error: Unterminated string",
+            ),
+            (
+                "{ user(id: $id) { name } }",
+                1,
+                12,
+                "Variables are not supported by GraphQL-lite.",
+            ),
+            (
+                "{ ...UserFields }",
+                1,
+                3,
+                "Fragments are not supported by GraphQL-lite.",
+            ),
+            (
+                "mutation { user }",
+                1,
+                1,
+                "Only query operations are supported by GraphQL-lite.",
+            ),
+            (
+                "{ user(id: 1) @skip { name } }",
+                1,
+                15,
+                "Directives are not supported by GraphQL-lite.",
+            ),
+        ];
+        for (query, line, column, message) in cases {
+            let response = executor().execute(query);
+            assert_eq!(
+                response,
+                json!({ "errors": [{ "message": message, "locations": [{ "line": line, "column": column }] }] }),
+                "{query}"
+            );
+        }
+
+        let deep = format!(
+            "{}{}",
+            "{ user(id: 1) ".repeat(MAX_DEPTH + 1),
+            "}".repeat(MAX_DEPTH + 1)
+        );
+        assert!(parse(&deep).unwrap_err().message.contains("nested deeper"));
+
+        // list ใน argument ก็นับความลึกด้วย - เดิม `[` ราว 50k ตัวทำ stack ล้น
+        let list = |levels: usize| {
+            format!(
+                "{{ user(id: {}1{}) {{ name }} }}",
+                "[".repeat(levels),
+                "]".repeat(levels)
+            )
+        };
+        assert!(parse(&list(MAX_DEPTH - 1)).is_ok());
+        let error = parse(&list(MAX_DEPTH)).unwrap_err();
+        assert_eq!(
+            error.message,
+            format!("List value is nested deeper than {MAX_DEPTH} levels.")
+        );
+        assert_eq!(
+            error.locations,
+            vec![Location {
+                line: 1,
+                column: 11 + MAX_DEPTH
+            }]
+        );
+        assert!(
+            executor().execute(&list(50_000))["errors"][0]["message"]
+                .as_str()
+                .unwrap()
+                .contains("nested deeper")
+        );
+    }
+
+    #[test]
+    fn test_validation_errors_are_all_reported() {
+        let query = "{\n  user(id: 1) {\n    name\n    password\n    tasks\n  }\n  users(sort: ASC) { email { domain } }\n  task { id }\n  tasks(done: \"yes\") { id }\n}";
+        let response = executor().execute(query);
+        assert!(response.get("data").is_none(), "validation ล้มแล้วต้องไม่รัน");
+        assert_eq!(
+            response["errors"],
+            json!([
+                { "message": "Cannot query field \"password\" on type \"User\".", "locations": [{ "line": 4, "column": 5 }] },
+                { "message": "Field \"tasks\" of type \"[Task!]!\" must have a selection of subfields.", "locations": [{ "line": 5, "column": 5 }] },
+                { "message": "Unknown argument \"sort\" on field \"Query.users\".", "locations": [{ "line": 7, "column": 9 }] },
+                { "message": "Field \"email\" must not have a selection since type \"String!\" has no subfields.", "locations": [{ "line": 7, "column": 22 }] },
+                { "message": "Field \"task\" argument \"id\" of type \"Int!\" is required, but it was not provided.", "locations": [{ "line": 8, "column": 3 }] },
+                { "message": "Argument \"done\" has invalid value \"yes\". Expected type \"Boolean\".", "locations": [{ "line": 9, "column": 9 }] }
+            ])
+        );
+
+        let error = parse("{\n  user(id: 1) { name\n}").unwrap_err();
+        assert_eq!(
+            error.render("{\n  user(id: 1) { name\n}").lines().last(),
+            Some("   ^")
+        );
+    }
+
+    #[test]
+    fn test_execution_errors_keep_partial_data_with_path() {
+        let response = executor().execute("{ user(id: 3) { name } users(limit: -1) { id } }");
+        assert_eq!(
+            response,
+            json!({
+                "data": { "user": { "name": "Workshop Bob" }, "users": null },
+                "errors": [{
+                    "message": "Argument \"limit\" must not be negative, got -1.",
+                    "locations": [{ "line": 1, "column": 24 }],
+                    "path": ["users"]
+                }]
+            })
+        );
+    }
+
+    #[test]
+    fn test_graphql_endpoint_shares_data_with_rest() {
+        use super::super::rest_api::ApiRouter;
+
+        let router = ApiRouter::new();
+        let create = HttpRequest::new("POST", "/api/users")
+            .with_body("{\"name\": \"Mali\", \"email\": \"mali@workshop.dev\", \"age\": 22}");
+        assert_eq!(router.route(&create).status, HttpStatus::Created);
+
+        let request = HttpRequest::new("POST", "/graphql")
+            .with_header("Content-Type", "application/json")
+            .with_body(&json!({ "query": "{ user(id: 4) { name tasks { id } } }" }).to_string());
+        let response = router.route(&request);
+        assert_eq!(response.status, HttpStatus::Ok);
+        let body: Json = serde_json::from_str(&response.body).unwrap();
+        assert_eq!(
+            body,
+            json!({ "data": { "user": { "name": "Mali", "tasks": [] } } })
+        );
+
+        let request = HttpRequest::new(
+            "GET",
+            "/graphql?query=%7B%20task(id%3A%203)%20%7B%20title%20%7D%20%7D",
+        );
+        let body: Json = serde_json::from_str(&router.route(&request).body).unwrap();
+        assert_eq!(
+            body,
+            json!({ "data": { "task": { "title": "Design GraphQL schema" } } })
+        );
+
+        let invalid = HttpRequest::new("POST", "/graphql").with_body("{\"query\": \"{ nope }\"}");
+        assert_eq!(router.route(&invalid).status, HttpStatus::BadRequest);
+        let missing = HttpRequest::new("POST", "/graphql").with_body("{}");
+        assert_eq!(router.route(&missing).status, HttpStatus::BadRequest);
+    }
+}
@@ -3,8 +3,10 @@
 //! 🚀 ตัวอย่างการสร้าง middleware สำหรับ web applications ในเวิร์คช็อปพัฒนาเว็บ
 
 use std::collections::HashMap;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use super::{HttpRequest, HttpResponse, HttpStatus};
+use crate::networking::rate_limiting::{RateLimiter, SlidingWindowLog};
 
 /// 🎭 Middleware Trait - เทรตมิดเดิลแวร์
 pub trait Middleware {
@@ -243,25 +245,57 @@ impl Middleware for AuthMiddleware {
     }
 }
 
+/// จำนวน client ที่จำ limiter ไว้ได้พร้อมกันโดยค่าเริ่มต้น
+pub const DEFAULT_MAX_TRACKED_CLIENTS: usize = 10_000;
+
 /// ⏱️ Rate Limiting Middleware - มิดเดิลแวร์จำกัดอัตรา
+/// แต่ละ client (แยกตาม IP) ได้ limiter ของตัวเองจาก `limiter_factory`
+///
+/// IP มาจาก header ที่ client ปลอมได้ จึงจำไว้ไม่เกิน `max_clients` ตัว:
+/// เมื่อเต็มจะทิ้ง limiter ที่โควตาเต็มแล้ว (สร้างใหม่ก็ได้สถานะเดิม) และถ้ายังเต็มอยู่
+/// client ใหม่ต้องแชร์ limiter `overflow` ตัวเดียวกัน - ปลอม IP ไปก็ไม่ได้โควตาเพิ่ม
 pub struct RateLimitMiddleware {
     pub requests_per_minute: u32,
-    pub requests: HashMap<String, Vec<u64>>, // IP -> timestamps
+    limiter_factory: Box<dyn Fn() -> Arc<dyn RateLimiter> + Send + Sync>,
+    clients: Mutex<HashMap<String, Arc<dyn RateLimiter>>>, // IP -> limiter
+    max_clients: usize,
+    overflow: Arc<dyn RateLimiter>,
 }
 
 impl RateLimitMiddleware {
+    /// จำกัดแต่ละ IP ไม่เกิน `requests_per_minute` คำขอในหน้าต่าง 1 นาที
     #[must_use] pub fn new(requests_per_minute: u32) -> Self {
+        Self::with_limiter(requests_per_minute, move || {
+            Arc::new(SlidingWindowLog::new(
+                requests_per_minute as usize,
+                Duration::from_secs(60),
+            ))
+        })
+    }
+    
+    /// ใช้ limiter แบบอื่น (เช่น `TokenBucket`) หรือ limiter ที่ใช้นาฬิกาจำลอง
+    pub fn with_limiter<F>(requests_per_minute: u32, limiter_factory: F) -> Self
+    where
+        F: Fn() -> Arc<dyn RateLimiter> + Send + Sync + 'static,
+    {
         Self {
             requests_per_minute,
-            requests: HashMap::new(),
+            overflow: limiter_factory(),
+            limiter_factory: Box::new(limiter_factory),
+            clients: Mutex::new(HashMap::new()),
+            max_clients: DEFAULT_MAX_TRACKED_CLIENTS,
         }
     }
     
-    fn get_current_timestamp() -> u64 {
-        SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_secs()
+    /// จำ limiter ไว้ไม่เกิน `max_clients` IP (ค่าเริ่มต้น `DEFAULT_MAX_TRACKED_CLIENTS`)
+    #[must_use] pub const fn with_max_clients(mut self, max_clients: usize) -> Self {
+        self.max_clients = max_clients;
+        self
+    }
+    
+    /// จำนวน IP ที่มี limiter ของตัวเองอยู่ตอนนี้
+    #[must_use] pub fn tracked_clients(&self) -> usize {
+        self.clients.lock().unwrap_or_else(std::sync::PoisonError::into_inner).len()
     }
     
     fn get_client_ip(&self, request: &HttpRequest) -> String {
@@ -280,46 +314,44 @@ impl RateLimitMiddleware {
         "127.0.0.1".to_string()
     }
     
-    fn is_rate_limited(&mut self, client_ip: &str) -> bool {
-        let current_time = Self::get_current_timestamp();
-        let minute_ago = current_time - 60;
-        
-        // Get or create request history for this IP
-        let requests = self.requests.entry(client_ip.to_string()).or_default();
-        
-        // Remove old requests (older than 1 minute)
-        requests.retain(|&timestamp| timestamp > minute_ago);
-        
-        // Check if rate limit is exceeded
-        if requests.len() >= self.requests_per_minute as usize {
-            return true;
+    fn limiter_for(&self, client_ip: &str) -> Arc<dyn RateLimiter> {
+        let mut clients = self.clients.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+        if let Some(limiter) = clients.get(client_ip) {
+            return Arc::clone(limiter);
         }
-        
-        // Add current request
-        requests.push(current_time);
-        false
+        if clients.len() >= self.max_clients {
+            // limiter ที่โควตาเต็มไม่มีอะไรต้องจำ - ทิ้งได้เลย
+            clients.retain(|_, limiter| limiter.remaining() < limiter.limit());
+        }
+        if clients.len() >= self.max_clients {
+            return Arc::clone(&self.overflow);
+        }
+        let limiter = (self.limiter_factory)();
+        clients.insert(client_ip.to_string(), Arc::clone(&limiter));
+        limiter
     }
 }
 
 impl Middleware for RateLimitMiddleware {
     fn process(&self, request: &HttpRequest, response: &HttpResponse) -> HttpResponse {
         let client_ip = self.get_client_ip(request);
+        let limiter = self.limiter_for(&client_ip);
         
-        // Note: In real implementation, this should use Arc<Mutex<Self>> to allow mutation
-        // For demonstration, we'll simulate the check
-        let simulated_request_count = client_ip.len() % 10; // Simulate based on IP length
-        
-        if simulated_request_count > 7 { // Simulate rate limit exceeded
+        if !limiter.try_acquire() {
+            let retry_after = limiter.time_until_ready().as_secs_f64().ceil();
             return HttpResponse::json(
-                HttpStatus::BadRequest,
+                HttpStatus::TooManyRequests,
                 "{\"error\": \"Rate limit exceeded. Please try again later.\"}"
-            ).with_header("Retry-After", "60");
+            )
+            .with_header("Retry-After", &retry_after.to_string())
+            .with_header("X-RateLimit-Limit", &limiter.limit().to_string())
+            .with_header("X-RateLimit-Remaining", "0");
         }
         
         // Add rate limit headers to response
         response.clone()
-            .with_header("X-RateLimit-Limit", &self.requests_per_minute.to_string())
-            .with_header("X-RateLimit-Remaining", &(self.requests_per_minute - simulated_request_count as u32).to_string())
+            .with_header("X-RateLimit-Limit", &limiter.limit().to_string())
+            .with_header("X-RateLimit-Remaining", &limiter.remaining().to_string())
     }
 }
 
@@ -504,8 +536,14 @@ pub fn demonstrate_middleware() {
         .with_header("X-Forwarded-For", "192.168.1.100");
     let test_response = HttpResponse::new(HttpStatus::Ok).with_body("Test response");
     
-    let limited_response = rate_limiter.process(&test_request, &test_response);
-    println!("Rate limit headers added: {:?}", limited_response.headers.get("X-RateLimit-Limit"));
+    for attempt in 1..=6 {
+        let limited_response = rate_limiter.process(&test_request, &test_response);
+        println!(
+            "Request {attempt}: {} (remaining: {:?})",
+            limited_response.status.as_str(),
+            limited_response.headers.get("X-RateLimit-Remaining")
+        );
+    }
     
     // Test compression
     println!("\n--- Compression Test ---");
@@ -564,6 +602,69 @@ mod tests {
         assert_eq!(result.status, HttpStatus::Ok);
     }
 
+    #[test]
+    fn test_rate_limit_middleware() {
+        use crate::clock::MockClock;
+        use crate::networking::rate_limiting::TokenBucket;
+
+        // นาฬิกาจำลองที่ไม่เดินเอง - ผลลัพธ์แน่นอนทุกครั้ง
+        let clock = MockClock::new();
+        let injected = clock.clone();
+        let middleware = RateLimitMiddleware::with_limiter(2, move || {
+            Arc::new(TokenBucket::with_clock(2, 1, Arc::new(injected.clone())))
+        });
+        let response = HttpResponse::new(HttpStatus::Ok);
+        let alice = HttpRequest::new("GET", "/api").with_header("X-Real-IP", "10.0.0.1");
+        let bob = HttpRequest::new("GET", "/api").with_header("X-Real-IP", "10.0.0.2");
+
+        let first = middleware.process(&alice, &response);
+        assert_eq!(first.status, HttpStatus::Ok);
+        assert_eq!(first.headers.get("X-RateLimit-Remaining"), Some(&"1".to_string()));
+        assert_eq!(middleware.process(&alice, &response).status, HttpStatus::Ok);
+
+        let limited = middleware.process(&alice, &response);
+        assert_eq!(limited.status, HttpStatus::TooManyRequests);
+        assert_eq!(limited.headers.get("Retry-After"), Some(&"1".to_string()));
+
+        // client อื่นมีโควตาแยกกัน
+        assert_eq!(middleware.process(&bob, &response).status, HttpStatus::Ok);
+
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(middleware.process(&alice, &response).status, HttpStatus::Ok);
+    }
+
+    #[test]
+    fn test_rate_limit_middleware_caps_tracked_clients() {
+        use crate::clock::MockClock;
+        use crate::networking::rate_limiting::TokenBucket;
+
+        let clock = MockClock::new();
+        let injected = clock.clone();
+        let middleware = RateLimitMiddleware::with_limiter(1, move || {
+            Arc::new(TokenBucket::with_clock(1, 1, Arc::new(injected.clone())))
+        })
+        .with_max_clients(2);
+        let response = HttpResponse::new(HttpStatus::Ok);
+        let from = |ip: &str| HttpRequest::new("GET", "/api").with_header("X-Real-IP", ip);
+
+        assert_eq!(middleware.process(&from("10.0.0.1"), &response).status, HttpStatus::Ok);
+        assert_eq!(middleware.process(&from("10.0.0.2"), &response).status, HttpStatus::Ok);
+        assert_eq!(middleware.tracked_clients(), 2);
+
+        // ยังไม่มีใครว่าง: IP ปลอมใหม่ๆ ต้องแชร์ limiter ตัวเดียว
+        assert_eq!(middleware.process(&from("10.0.0.3"), &response).status, HttpStatus::Ok);
+        assert_eq!(
+            middleware.process(&from("10.0.0.4"), &response).status,
+            HttpStatus::TooManyRequests
+        );
+        assert_eq!(middleware.tracked_clients(), 2);
+
+        // โควตาเติมเต็มแล้ว limiter เดิมถูกทิ้งเพื่อให้ที่ client ใหม่
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(middleware.process(&from("10.0.0.5"), &response).status, HttpStatus::Ok);
+        assert_eq!(middleware.tracked_clients(), 1);
+    }
+
     #[test]
     fn test_middleware_chain() {
         let chain = MiddlewareChain::new()
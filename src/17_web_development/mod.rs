@@ -7,8 +7,17 @@ pub mod web_server;
 pub mod rest_api;
 pub mod middleware;
 pub mod templating;
+pub mod url;
+pub mod cookies;
+pub mod session;
+pub mod multipart;
+pub mod static_files;
+pub mod sse;
+pub mod graphql;
+pub mod email_jobs;
 
 use std::collections::HashMap;
+use std::io::{self, Write};
 
 /// 🎭 ฟังก์ชันหลักสำหรับรันตัวอย่างทั้งหมดในเวิร์คช็อปพัฒนาเว็บ
 pub fn run_web_development_examples() {
@@ -26,10 +35,62 @@ pub fn run_web_development_examples() {
     println!("\n🔧 --- Middleware - มิดเดิลแวร์ ---");
     middleware::demonstrate_middleware();
     
+    // GraphQL-lite
+    println!("\n🕸️ --- GraphQL-lite - query ตาม schema เทียบกับ REST ---");
+    graphql::demonstrate_graphql();
+    
     // Templating
     println!("\n📄 --- Templating - ระบบเทมเพลต ---");
     templating::demonstrate_templating();
     
+    // URL & Query String
+    println!("\n🔗 --- URL & Query String - แยก URL และ query ---");
+    url::demonstrate_url();
+    
+    // Cookies & Sessions
+    println!("\n🍪 --- Cookies & Sessions - คุกกี้และเซสชัน ---");
+    cookies::demonstrate_cookies();
+    session::demonstrate_sessions();
+    
+    // File Upload
+    println!("\n📎 --- File Upload - อัปโหลดไฟล์ multipart ---");
+    match tempfile::tempdir() {
+        Ok(dir) => {
+            if let Err(error) = multipart::demonstrate_multipart(dir.path()) {
+                println!("❌ เดโมอัปโหลดล้มเหลว: {error}");
+            }
+        }
+        Err(error) => println!("❌ สร้างโฟลเดอร์ชั่วคราวไม่ได้: {error}"),
+    }
+    
+    // Static Files
+    println!("\n📂 --- Static Files - เสิร์ฟไฟล์พร้อม cache และ range ---");
+    match tempfile::tempdir() {
+        Ok(dir) => {
+            if let Err(error) = static_files::demonstrate_static_files(dir.path()) {
+                println!("❌ เดโมเสิร์ฟไฟล์ล้มเหลว: {error}");
+            }
+        }
+        Err(error) => println!("❌ สร้างโฟลเดอร์ชั่วคราวไม่ได้: {error}"),
+    }
+    
+    // Server-Sent Events
+    println!("\n📡 --- Server-Sent Events - สตรีม event สดไปยัง client ---");
+    if let Err(error) = sse::demonstrate_sse() {
+        println!("❌ เดโม SSE ล้มเหลว: {error}");
+    }
+    
+    // Background Jobs
+    println!("\n📧 --- Background Jobs - ส่งอีเมลเบื้องหลัง พร้อม retry และคิวที่รอด restart ---");
+    match tempfile::tempdir() {
+        Ok(dir) => {
+            if let Err(error) = email_jobs::demonstrate_email_jobs(dir.path()) {
+                println!("❌ เดโมส่งอีเมลล้มเหลว: {error}");
+            }
+        }
+        Err(error) => println!("❌ สร้างโฟลเดอร์ชั่วคราวไม่ได้: {error}"),
+    }
+    
     println!("\n✅ Web Development Workshop เสร็จสมบูรณ์! 🎉");
 }
 
@@ -38,9 +99,22 @@ pub fn run_web_development_examples() {
 pub enum HttpStatus {
     Ok = 200,
     Created = 201,
+    Accepted = 202,
+    NoContent = 204,
+    PartialContent = 206,
+    Found = 302,
+    NotModified = 304,
     BadRequest = 400,
+    Unauthorized = 401,
+    Forbidden = 403,
     NotFound = 404,
+    MethodNotAllowed = 405,
+    PayloadTooLarge = 413,
+    UnsupportedMediaType = 415,
+    RangeNotSatisfiable = 416,
+    TooManyRequests = 429,
     InternalServerError = 500,
+    ServiceUnavailable = 503,
 }
 
 impl HttpStatus {
@@ -48,9 +122,22 @@ impl HttpStatus {
         match self {
             Self::Ok => "200 OK",
             Self::Created => "201 Created",
+            Self::Accepted => "202 Accepted",
+            Self::NoContent => "204 No Content",
+            Self::PartialContent => "206 Partial Content",
+            Self::Found => "302 Found",
+            Self::NotModified => "304 Not Modified",
             Self::BadRequest => "400 Bad Request",
+            Self::Unauthorized => "401 Unauthorized",
+            Self::Forbidden => "403 Forbidden",
             Self::NotFound => "404 Not Found",
+            Self::MethodNotAllowed => "405 Method Not Allowed",
+            Self::PayloadTooLarge => "413 Payload Too Large",
+            Self::UnsupportedMediaType => "415 Unsupported Media Type",
+            Self::RangeNotSatisfiable => "416 Range Not Satisfiable",
+            Self::TooManyRequests => "429 Too Many Requests",
             Self::InternalServerError => "500 Internal Server Error",
+            Self::ServiceUnavailable => "503 Service Unavailable",
         }
     }
 }
@@ -83,8 +170,105 @@ impl HttpRequest {
         self.body = body.to_string();
         self
     }
+    
+    /// 🔗 URL เต็มของ request - host มาจาก header `Host` (ไม่มีก็ใช้ `localhost`)
+    /// 
+    /// # Errors
+    /// 
+    /// คืน `UrlError` ถ้า `Host` หรือ path ประกอบเป็น URL ที่ถูกต้องไม่ได้
+    pub fn url(&self) -> Result<url::Url, url::UrlError> {
+        let host = self.headers.iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case("Host"))
+            .map_or("localhost", |(_, value)| value.as_str());
+        url::Url::parse(&format!("http://{host}{}", self.path))
+    }
+    
+    /// แปลง raw bytes จาก socket เป็น `HttpRequest` (HTTP/1.x)
+    /// 
+    /// รับ input อะไรก็ได้โดยไม่ panic - ข้อมูลที่ผิดรูปจะได้ `HttpParseError`
+    /// 
+    /// # Errors
+    /// 
+    /// คืน `HttpParseError::Incomplete` ถ้าข้อมูลยังมาไม่ครบ หรือ error อื่นถ้าข้อมูลผิดรูป
+    pub fn parse(raw: &[u8]) -> Result<Self, HttpParseError> {
+        let Some(header_end) = raw.windows(4).position(|window| window == b"\r\n\r\n") else {
+            return Err(if raw.len() > MAX_HEADER_BYTES {
+                HttpParseError::HeadersTooLarge
+            } else {
+                HttpParseError::Incomplete
+            });
+        };
+        if header_end > MAX_HEADER_BYTES {
+            return Err(HttpParseError::HeadersTooLarge);
+        }
+        
+        let head = std::str::from_utf8(&raw[..header_end]).map_err(|_| HttpParseError::InvalidUtf8)?;
+        let mut lines = head.split("\r\n");
+        
+        // Request line: METHOD SP target SP HTTP/1.x
+        let mut parts = lines.next().unwrap_or_default().split(' ');
+        let (Some(method), Some(path), Some(version), None) = (parts.next(), parts.next(), parts.next(), parts.next()) else {
+            return Err(HttpParseError::InvalidRequestLine);
+        };
+        let valid_method = !method.is_empty() && method.bytes().all(|b| b.is_ascii_uppercase());
+        let valid_path = path.starts_with('/') || path == "*";
+        if !valid_method || !valid_path || !version.starts_with("HTTP/1.") {
+            return Err(HttpParseError::InvalidRequestLine);
+        }
+        
+        let mut request = Self::new(method, path);
+        for line in lines {
+            let (name, value) = line.split_once(':').ok_or(HttpParseError::InvalidHeader)?;
+            if name.is_empty() || name.bytes().any(|b| b.is_ascii_whitespace() || b.is_ascii_control()) {
+                return Err(HttpParseError::InvalidHeader);
+            }
+            request.headers.insert(name.to_string(), value.trim().to_string());
+        }
+        
+        let content_length = match request.headers.iter().find(|(name, _)| name.eq_ignore_ascii_case("Content-Length")) {
+            Some((_, value)) => value.parse::<usize>().map_err(|_| HttpParseError::InvalidContentLength)?,
+            None => 0,
+        };
+        let body_start = header_end + 4;
+        let body = raw[body_start..]
+            .get(..content_length)
+            .ok_or(HttpParseError::Incomplete)?;
+        request.body = std::str::from_utf8(body).map_err(|_| HttpParseError::InvalidUtf8)?.to_string();
+        
+        Ok(request)
+    }
+}
+
+/// ขนาดสูงสุดของส่วน header ที่ยอมรับ (ป้องกันการส่ง header ยาวไม่รู้จบ)
+pub const MAX_HEADER_BYTES: usize = 8 * 1024;
+
+/// ❌ HTTP Parse Error - ข้อผิดพลาดตอนแปลงคำขอ
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HttpParseError {
+    Incomplete,
+    InvalidRequestLine,
+    InvalidHeader,
+    InvalidContentLength,
+    HeadersTooLarge,
+    InvalidUtf8,
+}
+
+impl std::fmt::Display for HttpParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let message = match self {
+            Self::Incomplete => "request is incomplete",
+            Self::InvalidRequestLine => "invalid request line",
+            Self::InvalidHeader => "invalid header line",
+            Self::InvalidContentLength => "invalid Content-Length",
+            Self::HeadersTooLarge => "headers too large",
+            Self::InvalidUtf8 => "request is not valid UTF-8",
+        };
+        f.write_str(message)
+    }
 }
 
+impl std::error::Error for HttpParseError {}
+
 /// 📤 HTTP Response Structure - โครงสร้างการตอบกลับ HTTP
 #[derive(Debug, Clone)]
 pub struct HttpResponse {
@@ -123,6 +307,24 @@ impl HttpResponse {
             .with_header("Content-Type", "text/html")
             .with_body(html)
     }
+    
+    /// 📡 เขียนเป็น HTTP/1.1 ลง socket - ใส่ `Content-Length` ให้ถ้า handler ไม่ได้ใส่มา
+    /// 
+    /// # Errors
+    /// 
+    /// คืน error จากการเขียนลง `writer`
+    pub fn write_to<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        write!(writer, "HTTP/1.1 {}\r\n", self.status.as_str())?;
+        for (name, value) in &self.headers {
+            write!(writer, "{name}: {value}\r\n")?;
+        }
+        if !self.headers.keys().any(|name| name.eq_ignore_ascii_case("Content-Length")) {
+            write!(writer, "Content-Length: {}\r\n", self.body.len())?;
+        }
+        writer.write_all(b"\r\n")?;
+        writer.write_all(self.body.as_bytes())?;
+        writer.flush()
+    }
 }
 
 #[cfg(test)]
@@ -147,6 +349,23 @@ mod tests {
         assert_eq!(request.body, "{\"name\": \"John\"}");
     }
 
+    #[test]
+    fn test_http_request_parse() {
+        let raw = b"POST /api/users HTTP/1.1\r\nHost: example.com\r\nContent-Length: 12\r\n\r\n{\"name\":\"A\"}";
+        let request = HttpRequest::parse(raw).unwrap();
+        
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.path, "/api/users");
+        assert_eq!(request.headers.get("Host"), Some(&"example.com".to_string()));
+        assert_eq!(request.body, "{\"name\":\"A\"}");
+        
+        assert_eq!(HttpRequest::parse(b"GET / HTTP/1.1\r\nHost: x").unwrap_err(), HttpParseError::Incomplete);
+        assert_eq!(HttpRequest::parse(b"POST / HTTP/1.1\r\nContent-Length: 5\r\n\r\nab").unwrap_err(), HttpParseError::Incomplete);
+        assert_eq!(HttpRequest::parse(b"get / HTTP/1.1\r\n\r\n").unwrap_err(), HttpParseError::InvalidRequestLine);
+        assert_eq!(HttpRequest::parse(b"GET / HTTP/1.1\r\nNoColon\r\n\r\n").unwrap_err(), HttpParseError::InvalidHeader);
+        assert_eq!(HttpRequest::parse(b"GET / HTTP/1.1\r\nContent-Length: -1\r\n\r\n").unwrap_err(), HttpParseError::InvalidContentLength);
+    }
+
     #[test]
     fn test_http_response() {
         let response = HttpResponse::json(HttpStatus::Ok, "{\"message\": \"success\"}");
@@ -0,0 +1,993 @@
+//! 📎 Multipart Form Data - รับฟอร์มที่มีไฟล์แนบ (`multipart/form-data`)
+//!
+//! body ถูกแบ่งเป็น part ด้วย boundary ที่ประกาศใน header `Content-Type`:
+//!
+//! ```text
+//! --XyZ\r\n
+//! Content-Disposition: form-data; name="title"\r\n\r\n
+//! รูปแมว\r\n
+//! --XyZ\r\n
+//! Content-Disposition: form-data; name="photo"; filename="cat.png"\r\n
+//! Content-Type: image/png\r\n\r\n
+//! <bytes ของไฟล์>\r\n
+//! --XyZ--\r\n
+//! ```
+//!
+//! 🌊 parser อ่านทีละ chunk - ไฟล์ถูกเขียนลง disk ระหว่างอ่าน (ผ่าน `copy_with_progress`)
+//! ไม่ต้องโหลดทั้งไฟล์เข้า memory และหยุดทันทีที่เกิน `MultipartLimits` (ได้ 413)
+
+use std::fmt::{self, Write as _};
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Read};
+use std::path::{Path, PathBuf};
+
+use rand::Rng;
+
+use super::web_server::WebServer;
+use super::{HttpRequest, HttpResponse, HttpStatus};
+use crate::io_filesystem::{append_extension, copy_with_progress};
+
+/// ขนาด chunk ที่อ่านจาก body และเขียนลงไฟล์ต่อครั้ง
+const CHUNK_SIZE: usize = 8 * 1024;
+
+/// header ของแต่ละ part ยาวได้ไม่เกินนี้
+const MAX_PART_HEADER_BYTES: usize = 8 * 1024;
+
+/// 📏 ขนาดสูงสุดที่ยอมรับ - เกินแล้วได้ `413 Payload Too Large`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MultipartLimits {
+    /// ขนาด body ทั้งหมด (รวม header ของทุก part)
+    pub max_body_size: u64,
+    pub max_file_size: u64,
+    /// ขนาดของช่องข้อความธรรมดา (ไม่มี `filename`)
+    pub max_field_size: u64,
+    pub max_parts: usize,
+}
+
+impl Default for MultipartLimits {
+    fn default() -> Self {
+        Self {
+            max_body_size: 10 * 1024 * 1024,
+            max_file_size: 8 * 1024 * 1024,
+            max_field_size: 64 * 1024,
+            max_parts: 32,
+        }
+    }
+}
+
+/// ❌ Multipart Error - ข้อผิดพลาดตอนแยก multipart body
+#[derive(Debug)]
+pub enum MultipartError {
+    /// `Content-Type` ไม่ใช่ `multipart/form-data` หรือไม่มี `boundary=`
+    NotMultipart,
+    /// boundary ว่าง, ยาวเกิน 70 ตัว หรือมีตัวอักษรต้องห้าม
+    InvalidBoundary,
+    /// โครงสร้าง body ผิด เช่น ไม่มี `Content-Disposition` หรือ header ผิดรูป
+    Malformed(&'static str),
+    /// body จบก่อนเจอ boundary ปิด (`--boundary--`)
+    UnexpectedEnd,
+    BodyTooLarge {
+        limit: u64,
+    },
+    FileTooLarge {
+        field: String,
+        limit: u64,
+    },
+    FieldTooLarge {
+        field: String,
+        limit: u64,
+    },
+    TooManyParts {
+        limit: usize,
+    },
+    Io(io::Error),
+}
+
+impl MultipartError {
+    /// status ที่ควรตอบ client - ขนาดเกินได้ 413, เขียน disk ไม่ได้เป็นความผิดของ server
+    #[must_use]
+    pub const fn status(&self) -> HttpStatus {
+        match self {
+            Self::NotMultipart => HttpStatus::UnsupportedMediaType,
+            Self::BodyTooLarge { .. }
+            | Self::FileTooLarge { .. }
+            | Self::FieldTooLarge { .. }
+            | Self::TooManyParts { .. } => HttpStatus::PayloadTooLarge,
+            Self::Io(_) => HttpStatus::InternalServerError,
+            Self::InvalidBoundary | Self::Malformed(_) | Self::UnexpectedEnd => {
+                HttpStatus::BadRequest
+            }
+        }
+    }
+}
+
+impl fmt::Display for MultipartError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotMultipart => f.write_str("expected multipart/form-data with a boundary"),
+            Self::InvalidBoundary => f.write_str("invalid multipart boundary"),
+            Self::Malformed(reason) => write!(f, "malformed multipart body: {reason}"),
+            Self::UnexpectedEnd => f.write_str("multipart body ended before the closing boundary"),
+            Self::BodyTooLarge { limit } => write!(f, "request body exceeds {limit} bytes"),
+            Self::FileTooLarge { field, limit } => {
+                write!(f, "file in field `{field}` exceeds {limit} bytes")
+            }
+            Self::FieldTooLarge { field, limit } => {
+                write!(f, "field `{field}` exceeds {limit} bytes")
+            }
+            Self::TooManyParts { limit } => write!(f, "more than {limit} parts"),
+            Self::Io(error) => write!(f, "failed to store upload: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for MultipartError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for MultipartError {
+    fn from(error: io::Error) -> Self {
+        match error.kind() {
+            io::ErrorKind::UnexpectedEof => Self::UnexpectedEnd,
+            _ => Self::Io(error),
+        }
+    }
+}
+
+/// 📄 ไฟล์ที่อัปโหลดแล้ว - อยู่บน disk ที่ `path` (ชื่อบน disk สุ่มขึ้นใหม่ ไม่ใช้ชื่อจาก client ตรงๆ)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UploadedFile {
+    pub field: String,
+    /// ชื่อไฟล์จาก client ที่ตัด path ออกแล้ว (`C:\tmp\a.png` → `a.png`)
+    pub file_name: String,
+    pub content_type: String,
+    pub path: PathBuf,
+    pub size: u64,
+}
+
+/// 📬 ผลการแยกฟอร์ม - ช่องข้อความและไฟล์ตามลำดับที่ส่งมา
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Multipart {
+    pub fields: Vec<(String, String)>,
+    pub files: Vec<UploadedFile>,
+}
+
+impl Multipart {
+    /// ค่าแรกของช่อง `name`
+    #[must_use]
+    pub fn field(&self, name: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(field, _)| field == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    #[must_use]
+    pub fn file(&self, name: &str) -> Option<&UploadedFile> {
+        self.files.iter().find(|file| file.field == name)
+    }
+}
+
+/// ดึง boundary จาก `Content-Type: multipart/form-data; boundary=...`
+///
+/// # Errors
+///
+/// คืน `NotMultipart` ถ้าไม่ใช่ `multipart/form-data` หรือไม่มี boundary
+/// และ `InvalidBoundary` ถ้า boundary ผิดกฎของ RFC 2046
+pub fn boundary_from_content_type(content_type: &str) -> Result<String, MultipartError> {
+    let mut params = content_type.split(';');
+    let media_type = params.next().unwrap_or_default().trim();
+    if !media_type.eq_ignore_ascii_case("multipart/form-data") {
+        return Err(MultipartError::NotMultipart);
+    }
+    let boundary = params
+        .filter_map(|param| param.split_once('='))
+        .find(|(key, _)| key.trim().eq_ignore_ascii_case("boundary"))
+        .map(|(_, value)| value.trim().trim_matches('"'))
+        .ok_or(MultipartError::NotMultipart)?;
+    let allowed = |c: char| c.is_ascii_alphanumeric() || "'()+_,-./:=? ".contains(c);
+    if boundary.is_empty()
+        || boundary.len() > 70
+        || boundary.ends_with(' ')
+        || !boundary.chars().all(allowed)
+    {
+        return Err(MultipartError::InvalidBoundary);
+    }
+    Ok(boundary.to_string())
+}
+
+/// 🌊 ตัวอ่าน body แบบมี buffer - จำกัดขนาด body ทั้งหมดไว้ที่ `max_body_size`
+struct Stream<R> {
+    reader: R,
+    buffer: Vec<u8>,
+    total: u64,
+    max_body_size: u64,
+}
+
+impl<R: Read> Stream<R> {
+    /// อ่านเพิ่มอีกหนึ่ง chunk - คืน `false` เมื่อ body หมด
+    fn fill(&mut self) -> io::Result<bool> {
+        let mut chunk = [0; CHUNK_SIZE];
+        let read = loop {
+            match self.reader.read(&mut chunk) {
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => {}
+                result => break result?,
+            }
+        };
+        self.total += read as u64;
+        if self.total > self.max_body_size {
+            return Err(io::ErrorKind::FileTooLarge.into());
+        }
+        self.buffer.extend_from_slice(&chunk[..read]);
+        Ok(read > 0)
+    }
+
+    /// อ่านให้ buffer มีอย่างน้อย `len` bytes
+    fn fill_to(&mut self, len: usize) -> io::Result<()> {
+        while self.buffer.len() < len {
+            if !self.fill()? {
+                return Err(io::ErrorKind::UnexpectedEof.into());
+            }
+        }
+        Ok(())
+    }
+}
+
+/// body ของ part ปัจจุบันในรูป `Read` - จบเมื่อเจอ `\r\n--boundary`
+struct PartBody<'s, R> {
+    stream: &'s mut Stream<R>,
+    delimiter: &'s [u8],
+    done: bool,
+}
+
+impl<R: Read> Read for PartBody<'_, R> {
+    fn read(&mut self, out: &mut [u8]) -> io::Result<usize> {
+        while !self.done {
+            let buffer = &self.stream.buffer;
+            // ส่วนท้าย buffer ที่อาจเป็นครึ่งแรกของ delimiter ต้องรอ chunk ถัดไปก่อน
+            let (available, found) = match find(buffer, self.delimiter) {
+                Some(index) => (index, true),
+                None => (buffer.len().saturating_sub(self.delimiter.len() - 1), false),
+            };
+            if available > 0 {
+                let len = available.min(out.len());
+                out[..len].copy_from_slice(&buffer[..len]);
+                self.stream.buffer.drain(..len);
+                return Ok(len);
+            }
+            if found {
+                self.stream.buffer.drain(..self.delimiter.len());
+                self.done = true;
+            } else if !self.stream.fill()? {
+                return Err(io::ErrorKind::UnexpectedEof.into());
+            }
+        }
+        Ok(0)
+    }
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+/// header ของ part หนึ่งตัวที่เราสนใจ
+struct PartHeaders {
+    name: String,
+    file_name: Option<String>,
+    content_type: String,
+}
+
+fn parse_part_headers(raw: &[u8]) -> Result<PartHeaders, MultipartError> {
+    let text =
+        std::str::from_utf8(raw).map_err(|_| MultipartError::Malformed("header is not UTF-8"))?;
+    let mut disposition = None;
+    let mut content_type = "text/plain".to_string();
+    for line in text.split("\r\n").filter(|line| !line.is_empty()) {
+        let (name, value) = line
+            .split_once(':')
+            .ok_or(MultipartError::Malformed("header line without `:`"))?;
+        if name.trim().eq_ignore_ascii_case("Content-Disposition") {
+            disposition = Some(value.trim());
+        } else if name.trim().eq_ignore_ascii_case("Content-Type") {
+            content_type = value.trim().to_string();
+        }
+    }
+    let disposition =
+        disposition.ok_or(MultipartError::Malformed("missing Content-Disposition"))?;
+    let mut params = disposition.split(';');
+    if !params
+        .next()
+        .is_some_and(|kind| kind.trim().eq_ignore_ascii_case("form-data"))
+    {
+        return Err(MultipartError::Malformed(
+            "Content-Disposition is not form-data",
+        ));
+    }
+    let mut name = None;
+    let mut file_name = None;
+    for param in params {
+        let Some((key, value)) = param.split_once('=') else {
+            continue;
+        };
+        let value = value.trim();
+        let value = value
+            .strip_prefix('"')
+            .and_then(|inner| inner.strip_suffix('"'))
+            .unwrap_or(value)
+            .to_string();
+        match key.trim().to_ascii_lowercase().as_str() {
+            "name" => name = Some(value),
+            "filename" => file_name = Some(value),
+            _ => {}
+        }
+    }
+    Ok(PartHeaders {
+        name: name.ok_or(MultipartError::Malformed("part without a name"))?,
+        file_name,
+        content_type,
+    })
+}
+
+/// ตัด path ที่ client ส่งมาทิ้ง เหลือแค่ชื่อไฟล์ที่ปลอดภัย (`../../etc/passwd` → `passwd`)
+#[must_use]
+pub fn sanitize_file_name(name: &str) -> String {
+    let base = name.rsplit(['/', '\\']).next().unwrap_or_default();
+    let cleaned: String = base
+        .chars()
+        .filter(|c| !c.is_control() && !matches!(c, ':' | '*' | '?' | '"' | '<' | '>' | '|'))
+        .collect();
+    let cleaned = cleaned.trim().trim_start_matches('.');
+    if cleaned.is_empty() {
+        "upload".to_string()
+    } else {
+        cleaned.to_string()
+    }
+}
+
+/// รับ `(field, bytes_written)` ระหว่างเขียนไฟล์
+type ProgressCallback = Box<dyn Fn(&str, u64) + Send + Sync>;
+
+/// 🧩 Multipart Parser - ตั้งค่าด้วย builder แล้ว `parse` body
+pub struct MultipartParser {
+    upload_dir: PathBuf,
+    limits: MultipartLimits,
+    on_progress: Option<ProgressCallback>,
+}
+
+impl MultipartParser {
+    /// ไฟล์ที่อัปโหลดจะถูกเขียนลง `upload_dir` (ต้องมีอยู่แล้ว)
+    #[must_use]
+    pub fn new(upload_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            upload_dir: upload_dir.into(),
+            limits: MultipartLimits::default(),
+            on_progress: None,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_limits(mut self, limits: MultipartLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// เรียก `callback(field, bytes_written)` ทุก chunk ที่เขียนลงไฟล์
+    #[must_use]
+    pub fn on_progress<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&str, u64) + Send + Sync + 'static,
+    {
+        self.on_progress = Some(Box::new(callback));
+        self
+    }
+
+    #[must_use]
+    pub const fn limits(&self) -> MultipartLimits {
+        self.limits
+    }
+
+    /// แยก body แบบ streaming - ถ้าล้มเหลวกลางทาง ไฟล์ที่เขียนไปแล้วถูกลบทิ้งทั้งหมด
+    ///
+    /// # Errors
+    ///
+    /// คืน `MultipartError` ถ้า body ผิดรูป, เกินขนาดที่กำหนด หรือเขียนไฟล์ไม่ได้
+    pub fn parse(&self, body: impl Read, boundary: &str) -> Result<Multipart, MultipartError> {
+        let mut form = Multipart::default();
+        let result = self.parse_into(body, boundary, &mut form);
+        if result.is_err() {
+            for file in &form.files {
+                let _ = fs::remove_file(&file.path);
+            }
+        }
+        result.map(|()| form)
+    }
+
+    fn parse_into(
+        &self,
+        body: impl Read,
+        boundary: &str,
+        form: &mut Multipart,
+    ) -> Result<(), MultipartError> {
+        let mut stream = Stream {
+            reader: body,
+            // ใส่ CRLF นำหน้า เพื่อให้ boundary แรกหน้าตาเหมือนตัวอื่น (`\r\n--boundary`)
+            buffer: b"\r\n".to_vec(),
+            total: 0,
+            max_body_size: self.limits.max_body_size,
+        };
+        let delimiter = format!("\r\n--{boundary}").into_bytes();
+        let result = self.read_parts(&mut stream, &delimiter, form);
+        match result {
+            Err(MultipartError::Io(error)) if error.kind() == io::ErrorKind::FileTooLarge => {
+                Err(MultipartError::BodyTooLarge {
+                    limit: self.limits.max_body_size,
+                })
+            }
+            other => other,
+        }
+    }
+
+    fn read_parts<R: Read>(
+        &self,
+        stream: &mut Stream<R>,
+        delimiter: &[u8],
+        form: &mut Multipart,
+    ) -> Result<(), MultipartError> {
+        // ข้าม preamble จนถึง boundary แรก
+        let mut preamble = PartBody {
+            stream: &mut *stream,
+            delimiter,
+            done: false,
+        };
+        io::copy(&mut preamble, &mut io::sink())?;
+        let mut parts = 0;
+        loop {
+            stream.fill_to(2)?;
+            if stream.buffer.starts_with(b"--") {
+                // boundary ปิด - ที่เหลือคือ epilogue ไม่ต้องอ่าน
+                return Ok(());
+            }
+            // หลัง boundary อาจมีช่องว่างก่อน CRLF ได้ (transport padding)
+            let line_end = loop {
+                if let Some(index) = find(&stream.buffer, b"\r\n") {
+                    break index;
+                }
+                if stream.buffer.len() > MAX_PART_HEADER_BYTES {
+                    return Err(MultipartError::Malformed("garbage after boundary"));
+                }
+                if !stream.fill()? {
+                    return Err(MultipartError::UnexpectedEnd);
+                }
+            };
+            if !stream.buffer[..line_end]
+                .iter()
+                .all(|byte| matches!(byte, b' ' | b'\t'))
+            {
+                return Err(MultipartError::Malformed("garbage after boundary"));
+            }
+            stream.buffer.drain(..line_end + 2);
+
+            parts += 1;
+            if parts > self.limits.max_parts {
+                return Err(MultipartError::TooManyParts {
+                    limit: self.limits.max_parts,
+                });
+            }
+            let headers = Self::read_headers(stream)?;
+            let mut body = PartBody {
+                stream: &mut *stream,
+                delimiter,
+                done: false,
+            };
+            if let Some(file_name) = headers.file_name {
+                let file =
+                    self.store_file(&mut body, headers.name, &file_name, headers.content_type);
+                // เก็บไฟล์ที่เขียนสำเร็จไว้ก่อนเช็ค error เพื่อให้ `parse` ลบทิ้งได้
+                form.files.push(file?);
+                continue;
+            }
+            let limit = self.limits.max_field_size;
+            let mut value = Vec::new();
+            (&mut body).take(limit + 1).read_to_end(&mut value)?;
+            if value.len() as u64 > limit {
+                return Err(MultipartError::FieldTooLarge {
+                    field: headers.name,
+                    limit,
+                });
+            }
+            let value = String::from_utf8(value)
+                .map_err(|_| MultipartError::Malformed("text field is not UTF-8"))?;
+            form.fields.push((headers.name, value));
+        }
+    }
+
+    fn read_headers<R: Read>(stream: &mut Stream<R>) -> Result<PartHeaders, MultipartError> {
+        loop {
+            // part ที่ไม่มี header เลยขึ้นต้นด้วย CRLF ทันที
+            let end = if stream.buffer.starts_with(b"\r\n") {
+                Some((0, 2))
+            } else {
+                find(&stream.buffer, b"\r\n\r\n").map(|index| (index, 4))
+            };
+            if let Some((index, separator)) = end {
+                let headers = parse_part_headers(&stream.buffer[..index]);
+                stream.buffer.drain(..index + separator);
+                return headers;
+            }
+            if stream.buffer.len() > MAX_PART_HEADER_BYTES {
+                return Err(MultipartError::Malformed("part headers too large"));
+            }
+            if !stream.fill()? {
+                return Err(MultipartError::UnexpectedEnd);
+            }
+        }
+    }
+
+    /// stream body ของ part ลงไฟล์ `.part` ก่อน แล้ว rename เมื่อครบ - ไม่เหลือไฟล์ครึ่งๆ กลางๆ
+    fn store_file<R: Read>(
+        &self,
+        body: &mut PartBody<'_, R>,
+        field: String,
+        file_name: &str,
+        content_type: String,
+    ) -> Result<UploadedFile, MultipartError> {
+        let file_name = sanitize_file_name(file_name);
+        let prefix: u32 = rand::thread_rng().r#gen();
+        let path = self.upload_dir.join(format!("{prefix:08x}-{file_name}"));
+        let partial = append_extension(&path, "part");
+
+        let limit = self.limits.max_file_size;
+        let written = File::create(&partial).and_then(|file| {
+            copy_with_progress(
+                body.take(limit + 1),
+                BufWriter::new(file),
+                CHUNK_SIZE,
+                |written| {
+                    if let Some(callback) = &self.on_progress {
+                        callback(&field, written.min(limit));
+                    }
+                },
+            )
+        });
+        let outcome = match written {
+            Ok(size) if size > limit => Err(MultipartError::FileTooLarge { field, limit }),
+            Ok(size) => fs::rename(&partial, &path)
+                .map(|()| UploadedFile {
+                    field,
+                    file_name,
+                    content_type,
+                    path,
+                    size,
+                })
+                .map_err(MultipartError::from),
+            Err(error) => Err(error.into()),
+        };
+        if outcome.is_err() {
+            let _ = fs::remove_file(&partial);
+        }
+        outcome
+    }
+}
+
+/// 📤 handler ของ endpoint อัปโหลด - ตอบ JSON รายการไฟล์, 413 ถ้าใหญ่เกิน, 415 ถ้าไม่ใช่ multipart
+///
+/// เช็ค `Content-Length` ก่อนอ่าน body เลย - client ที่ประกาศว่าจะส่งไฟล์ใหญ่ถูกปฏิเสธทันที
+pub fn upload_endpoint(
+    parser: MultipartParser,
+) -> impl Fn(&HttpRequest) -> HttpResponse + Send + Sync + 'static {
+    move |request| {
+        let header = |name: &str| {
+            request
+                .headers
+                .iter()
+                .find(|(key, _)| key.eq_ignore_ascii_case(name))
+                .map(|(_, value)| value.as_str())
+        };
+        let declared = header("Content-Length").and_then(|length| length.parse::<u64>().ok());
+        let result = boundary_from_content_type(header("Content-Type").unwrap_or_default())
+            .and_then(|boundary| match declared {
+                Some(length) if length > parser.limits().max_body_size => {
+                    Err(MultipartError::BodyTooLarge {
+                        limit: parser.limits().max_body_size,
+                    })
+                }
+                _ => parser.parse(request.body.as_bytes(), &boundary),
+            });
+        match result {
+            Ok(form) => {
+                let files: Vec<_> = form
+                    .files
+                    .iter()
+                    .map(|file| {
+                        serde_json::json!({
+                            "field": file.field,
+                            "file_name": file.file_name,
+                            "content_type": file.content_type,
+                            "size": file.size,
+                        })
+                    })
+                    .collect();
+                let fields: serde_json::Map<_, _> = form
+                    .fields
+                    .into_iter()
+                    .map(|(name, value)| (name, value.into()))
+                    .collect();
+                let body = serde_json::json!({ "fields": fields, "files": files });
+                HttpResponse::json(HttpStatus::Created, &body.to_string())
+            }
+            Err(error) => {
+                let body = serde_json::json!({ "error": error.to_string() });
+                HttpResponse::json(error.status(), &body.to_string())
+            }
+        }
+    }
+}
+
+/// สร้าง body แบบ multipart จาก `(name, filename, content)` - ใช้ในเดโมและ tests
+#[must_use]
+pub fn build_multipart_body(boundary: &str, parts: &[(&str, Option<&str>, &str)]) -> String {
+    let mut body = String::new();
+    for (name, file_name, content) in parts {
+        let _ = write!(
+            body,
+            "--{boundary}\r\nContent-Disposition: form-data; name=\"{name}\""
+        );
+        if let Some(file_name) = file_name {
+            let _ = write!(
+                body,
+                "; filename=\"{file_name}\"\r\nContent-Type: text/plain"
+            );
+        }
+        let _ = write!(body, "\r\n\r\n{content}\r\n");
+    }
+    let _ = write!(body, "--{boundary}--\r\n");
+    body
+}
+
+/// 🎭 สาธิตการอัปโหลดไฟล์ผ่าน endpoint จำลอง (ไฟล์อยู่ในโฟลเดอร์ชั่วคราว)
+///
+/// # Errors
+///
+/// คืน error ถ้าอ่านไฟล์ที่อัปโหลดกลับมาไม่ได้
+pub fn demonstrate_multipart(upload_dir: &Path) -> io::Result<()> {
+    println!("📎 Multipart - อัปโหลดไฟล์แบบ streaming ลง disk");
+
+    let limits = MultipartLimits {
+        max_body_size: 4 * 1024,
+        max_file_size: 1024,
+        ..MultipartLimits::default()
+    };
+    let parser = MultipartParser::new(upload_dir)
+        .with_limits(limits)
+        .on_progress(|field, written| println!("   ⏳ {field}: เขียนแล้ว {written} bytes"));
+    let mut server = WebServer::new();
+    server.route("POST /upload", upload_endpoint(parser));
+    let boundary = "----WorkshopBoundary7MA4YWxk";
+    let content_type = format!("multipart/form-data; boundary={boundary}");
+
+    let requests = [
+        (
+            "ฟอร์มปกติ",
+            build_multipart_body(
+                boundary,
+                &[
+                    ("title", None, "บันทึกเวิร์คช็อป"),
+                    ("notes", Some("../../notes.txt"), "Rust 🦀\nmultipart"),
+                ],
+            ),
+        ),
+        (
+            "ไฟล์ใหญ่เกิน",
+            build_multipart_body(boundary, &[("big", Some("big.log"), &"x".repeat(2000))]),
+        ),
+        (
+            "body ขาด",
+            format!("--{boundary}\r\nContent-Disposition: form-data; name=\"a\"\r\n\r\nno end"),
+        ),
+    ];
+    for (label, body) in requests {
+        let request = HttpRequest::new("POST", "/upload")
+            .with_header("Content-Type", &content_type)
+            .with_body(&body);
+        let response = server.handle_request(&request);
+        println!(
+            "\n📤 {label} ({} bytes) → {}",
+            body.len(),
+            response.status.as_str()
+        );
+        println!("   {}", response.body);
+    }
+    let not_multipart =
+        HttpRequest::new("POST", "/upload").with_header("Content-Type", "application/json");
+    println!(
+        "\n🚫 JSON แทน multipart → {}",
+        server.handle_request(&not_multipart).status.as_str()
+    );
+
+    println!("\n📁 ไฟล์ในโฟลเดอร์อัปโหลด:");
+    for entry in fs::read_dir(upload_dir)? {
+        let path = entry?.path();
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        println!("   📄 {name}: {:?}", fs::read_to_string(&path)?);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BOUNDARY: &str = "X-BOUNDARY";
+
+    fn parser(dir: &Path) -> MultipartParser {
+        MultipartParser::new(dir).with_limits(MultipartLimits {
+            max_body_size: 64 * 1024,
+            max_file_size: 16 * 1024,
+            max_field_size: 32,
+            max_parts: 4,
+        })
+    }
+
+    /// reader ที่คืนทีละไม่กี่ byte - บังคับให้ boundary ถูกตัดกลาง chunk
+    struct Trickle<'a>(&'a [u8], usize);
+
+    impl Read for Trickle<'_> {
+        fn read(&mut self, out: &mut [u8]) -> io::Result<usize> {
+            let len = self.1.min(out.len()).min(self.0.len());
+            out[..len].copy_from_slice(&self.0[..len]);
+            self.0 = &self.0[len..];
+            Ok(len)
+        }
+    }
+
+    #[test]
+    fn test_boundary_from_content_type() {
+        let boundary = boundary_from_content_type;
+        assert_eq!(
+            boundary("multipart/form-data; boundary=abc").unwrap(),
+            "abc"
+        );
+        assert_eq!(
+            boundary("Multipart/Form-Data; charset=utf-8; BOUNDARY=\"a b:c\"").unwrap(),
+            "a b:c"
+        );
+        assert!(matches!(
+            boundary("application/json"),
+            Err(MultipartError::NotMultipart)
+        ));
+        assert!(matches!(
+            boundary("multipart/form-data"),
+            Err(MultipartError::NotMultipart)
+        ));
+        for bad in [
+            "boundary=",
+            "boundary=a@b",
+            "boundary=\"ends \"",
+            "boundary=ปู",
+        ] {
+            let header = format!("multipart/form-data; {bad}");
+            assert!(
+                matches!(boundary(&header), Err(MultipartError::InvalidBoundary)),
+                "{bad}"
+            );
+        }
+        let long = format!("multipart/form-data; boundary={}", "a".repeat(71));
+        assert!(matches!(
+            boundary(&long),
+            Err(MultipartError::InvalidBoundary)
+        ));
+    }
+
+    #[test]
+    fn test_parses_fields_and_streams_files_to_disk() {
+        let dir = tempfile::tempdir().unwrap();
+        // เนื้อไฟล์มี `--X-BOUNDAR` และ CRLF ที่เกือบเป็น delimiter แต่ไม่ใช่
+        let tricky = format!("line1\r\n--X-BOUNDAR\r\n-{}\r\n", "🦀".repeat(3000));
+        let mut body = format!(
+            "preamble is ignored\r\n{}",
+            build_multipart_body(
+                BOUNDARY,
+                &[
+                    ("title", None, "สวัสดี"),
+                    ("upload", Some("C:\\Users\\me\\..\\report.txt"), &tricky),
+                    ("empty", Some("empty.bin"), ""),
+                    ("title", None, "second"),
+                ],
+            )
+        );
+        body.push_str("epilogue is ignored too");
+
+        for chunk in [1, 7, 4096, usize::MAX] {
+            let progress = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+            let seen = progress.clone();
+            let form = parser(dir.path())
+                .on_progress(move |field, written| {
+                    seen.lock().unwrap().push((field.to_string(), written));
+                })
+                .parse(Trickle(body.as_bytes(), chunk), BOUNDARY)
+                .unwrap();
+            assert_eq!(form.field("title"), Some("สวัสดี"));
+            assert_eq!(form.fields.len(), 2);
+
+            let upload = form.file("upload").unwrap();
+            assert_eq!(upload.file_name, "report.txt");
+            assert_eq!(upload.content_type, "text/plain");
+            assert_eq!(upload.size, tricky.len() as u64);
+            assert_eq!(fs::read_to_string(&upload.path).unwrap(), tricky);
+            assert!(upload.path.starts_with(dir.path()));
+            let empty = form.file("empty").unwrap();
+            assert_eq!((empty.size, fs::read(&empty.path).unwrap().len()), (0, 0));
+
+            let progress = progress.lock().unwrap().clone();
+            assert_eq!(
+                progress.last(),
+                Some(&("upload".to_string(), tricky.len() as u64))
+            );
+            assert!(
+                progress.len() > 1,
+                "file should be written in several chunks"
+            );
+        }
+        // ไม่มีไฟล์ `.part` ค้าง
+        let leftovers = fs::read_dir(dir.path())
+            .unwrap()
+            .filter(|entry| {
+                entry
+                    .as_ref()
+                    .unwrap()
+                    .path()
+                    .extension()
+                    .is_some_and(|ext| ext == "part")
+            })
+            .count();
+        assert_eq!(leftovers, 0);
+    }
+
+    #[test]
+    fn test_limits_and_cleanup() {
+        let dir = tempfile::tempdir().unwrap();
+        let parse = |body: &str| parser(dir.path()).parse(Trickle(body.as_bytes(), 100), BOUNDARY);
+
+        // ไฟล์แรกผ่าน ไฟล์ที่สองใหญ่เกิน - ต้องไม่เหลือไฟล์ใดๆ ใน disk
+        let too_big = build_multipart_body(
+            BOUNDARY,
+            &[
+                ("ok", Some("ok.txt"), "fine"),
+                ("big", Some("big.txt"), &"x".repeat(16 * 1024 + 1)),
+            ],
+        );
+        let error = parse(&too_big).unwrap_err();
+        assert!(
+            matches!(&error, MultipartError::FileTooLarge { field, limit: 16_384 } if field == "big"),
+            "{error}"
+        );
+        assert_eq!(error.status(), HttpStatus::PayloadTooLarge);
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 0);
+
+        let exactly = build_multipart_body(
+            BOUNDARY,
+            &[("max", Some("max.txt"), &"x".repeat(16 * 1024))],
+        );
+        assert_eq!(parse(&exactly).unwrap().files[0].size, 16 * 1024);
+
+        let long_field = build_multipart_body(BOUNDARY, &[("bio", None, &"y".repeat(33))]);
+        assert!(matches!(
+            parse(&long_field),
+            Err(MultipartError::FieldTooLarge { .. })
+        ));
+
+        let many = build_multipart_body(BOUNDARY, &[("a", None, "1"); 5]);
+        assert!(matches!(
+            parse(&many),
+            Err(MultipartError::TooManyParts { limit: 4 })
+        ));
+
+        let huge_body = format!(
+            "{}{}",
+            "z".repeat(64 * 1024),
+            build_multipart_body(BOUNDARY, &[])
+        );
+        let error = parse(&huge_body).unwrap_err();
+        assert!(
+            matches!(error, MultipartError::BodyTooLarge { .. }),
+            "{error}"
+        );
+        assert_eq!(error.status(), HttpStatus::PayloadTooLarge);
+    }
+
+    #[test]
+    fn test_malformed_bodies() {
+        let dir = tempfile::tempdir().unwrap();
+        let parse = |body: &str| parser(dir.path()).parse(body.as_bytes(), BOUNDARY);
+        let part =
+            |headers: &str| format!("--{BOUNDARY}\r\n{headers}\r\n\r\nvalue\r\n--{BOUNDARY}--");
+
+        let cases = [
+            String::new(),
+            "no boundary at all".to_string(),
+            format!(
+                "--{BOUNDARY}\r\nContent-Disposition: form-data; name=\"a\"\r\n\r\nunterminated"
+            ),
+            format!("--{BOUNDARY}\r\nContent-Disposition: form-data; name=\"a\""),
+        ];
+        for body in &cases {
+            assert!(
+                matches!(parse(body), Err(MultipartError::UnexpectedEnd)),
+                "{body:?}"
+            );
+        }
+        let malformed = [
+            part("Content-Type: text/plain"),
+            part("Content-Disposition: attachment; name=\"a\""),
+            part("Content-Disposition: form-data; filename=\"a.txt\""),
+            part("Content-Disposition form-data"),
+            format!("--{BOUNDARY}junk\r\n\r\n--{BOUNDARY}--"),
+        ];
+        for body in &malformed {
+            let error = parse(body).unwrap_err();
+            assert!(
+                matches!(error, MultipartError::Malformed(_)),
+                "{body:?}: {error}"
+            );
+            assert_eq!(error.status(), HttpStatus::BadRequest);
+        }
+        // padding หลัง boundary และไม่มี part เลยยังถูกต้อง
+        let form = parse(&format!(
+            "--{BOUNDARY}  \r\nContent-Disposition: form-data; name=a\r\n\r\n1\r\n--{BOUNDARY}--"
+        ))
+        .unwrap();
+        assert_eq!(form.field("a"), Some("1"));
+        assert_eq!(
+            parse(&format!("--{BOUNDARY}--")).unwrap(),
+            Multipart::default()
+        );
+    }
+
+    #[test]
+    fn test_sanitize_file_name() {
+        assert_eq!(sanitize_file_name("photo.png"), "photo.png");
+        assert_eq!(sanitize_file_name("../../etc/passwd"), "passwd");
+        assert_eq!(sanitize_file_name("C:\\temp\\a<b>.txt"), "ab.txt");
+        assert_eq!(sanitize_file_name(".."), "upload");
+        assert_eq!(sanitize_file_name(".bashrc"), "bashrc");
+        assert_eq!(sanitize_file_name("dir/"), "upload");
+        assert_eq!(sanitize_file_name("รูปแมว.jpg"), "รูปแมว.jpg");
+    }
+
+    #[test]
+    fn test_upload_endpoint() {
+        let dir = tempfile::tempdir().unwrap();
+        let endpoint = upload_endpoint(parser(dir.path()));
+        let content_type = format!("multipart/form-data; boundary={BOUNDARY}");
+        let body = build_multipart_body(
+            BOUNDARY,
+            &[("caption", None, "hi"), ("doc", Some("a.txt"), "abc")],
+        );
+
+        let request = HttpRequest::new("POST", "/upload")
+            .with_header("content-type", &content_type)
+            .with_body(&body);
+        let response = endpoint(&request);
+        assert_eq!(response.status, HttpStatus::Created);
+        let json: serde_json::Value = serde_json::from_str(&response.body).unwrap();
+        assert_eq!(json["fields"]["caption"], "hi");
+        assert_eq!(json["files"][0]["file_name"], "a.txt");
+        assert_eq!(json["files"][0]["size"], 3);
+
+        // ประกาศ Content-Length ใหญ่เกินก็ปฏิเสธก่อนอ่าน body
+        let declared = request.clone().with_header("Content-Length", "999999999");
+        assert_eq!(endpoint(&declared).status, HttpStatus::PayloadTooLarge);
+        let json =
+            HttpRequest::new("POST", "/upload").with_header("Content-Type", "application/json");
+        assert_eq!(endpoint(&json).status, HttpStatus::UnsupportedMediaType);
+        let broken = request.with_body("--X-BOUNDARY\r\n");
+        assert_eq!(endpoint(&broken).status, HttpStatus::BadRequest);
+    }
+}
@@ -4,18 +4,19 @@
 
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
+use super::graphql::GraphQlExecutor;
+use super::url::Query;
 use super::{HttpRequest, HttpResponse, HttpStatus};
+use crate::profiling::{self, Profiler};
+use crate::security::audit::{AuditEvent, AuditLog};
+use crate::security::authorization::{Policy, Resource, Subject};
+use crate::validation::{Validate, ValidationErrors, validate};
 
 // Mock serde for demonstration
 mod serde {
     
     pub use self::ser::*;
     
-    pub mod de {
-        pub trait Deserialize<'de> {}
-        pub trait DeserializeOwned: for<'de> Deserialize<'de> {}
-    }
-    
     pub mod ser {
         pub trait Serialize {}
     }
@@ -25,13 +26,9 @@ mod serde {
 macro_rules! mock_derive {
     ($name:ident for $type:ident) => {
         impl serde::ser::Serialize for $type {}
-        impl<'de> serde::de::Deserialize<'de> for $type {}
-        impl serde::de::DeserializeOwned for $type {}
     };
     ($name:ident for $type:ident<$generic:ident>) => {
         impl<$generic> serde::ser::Serialize for $type<$generic> {}
-        impl<'de, $generic> serde::de::Deserialize<'de> for $type<$generic> {}
-        impl<$generic> serde::de::DeserializeOwned for $type<$generic> {}
     };
 }
 
@@ -45,6 +42,41 @@ pub struct User {
 }
 mock_derive!(User for User);
 
+// ✅ กฎของ User ประกาศที่เดียว ใช้ทั้งตอน create และ update
+validate! {
+    User {
+        name: [required(), length(1, 100)],
+        email: [pattern(r"[\w.+-]+@[\w-]+\.[\w.-]+")],
+        age: [range(1, 150)],
+    }
+}
+
+/// 📥 body ของ POST/PUT - ฟิลด์ที่ขาดได้ค่าว่าง แล้วให้ validation รายงานว่าขาดอะไร
+#[derive(Debug, ::serde::Deserialize)]
+struct UserPayload {
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    email: String,
+    #[serde(default)]
+    age: u32,
+}
+
+/// 🔎 query ของ GET /api/users - `?search=john&limit=10`
+#[derive(Debug, Default, ::serde::Deserialize)]
+pub struct UserListParams {
+    /// ค้นจากชื่อหรืออีเมล (ไม่สนตัวพิมพ์)
+    pub search: Option<String>,
+    /// จำนวนผู้ใช้สูงสุดที่ตอบกลับ
+    pub limit: Option<usize>,
+}
+
+impl From<UserPayload> for User {
+    fn from(payload: UserPayload) -> Self {
+        Self::new(0, &payload.name, &payload.email, payload.age)
+    }
+}
+
 impl User {
     #[must_use] pub fn new(id: u32, name: &str, email: &str, age: u32) -> Self {
         Self {
@@ -83,7 +115,32 @@ impl<T> ApiResponse<T> {
     }
 }
 
+/// ❌ สิ่งที่ repository ทำไม่สำเร็จ
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RepositoryError {
+    /// ข้อมูลไม่ผ่านกฎ - มี error ทุกฟิลด์ที่ผิด
+    Invalid(ValidationErrors),
+    NotFound,
+    /// lock ของข้อมูลเสีย (thread อื่น panic ระหว่างถือ lock)
+    Database,
+}
+
+impl std::fmt::Display for RepositoryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Invalid(errors) => write!(f, "Validation failed: {errors}"),
+            Self::NotFound => write!(f, "User not found"),
+            Self::Database => write!(f, "Database error"),
+        }
+    }
+}
+
+impl std::error::Error for RepositoryError {}
+
 /// 🗄️ User Repository (Workshop In-memory Database) - คลังข้อมูลผู้ใช้
+/// 
+/// clone แล้วยังชี้ข้อมูลชุดเดียวกัน - REST และ GraphQL จึงเห็นการแก้ไขของกันและกัน
+#[derive(Clone)]
 pub struct UserRepository {
     users: Arc<Mutex<HashMap<u32, User>>>,
     next_id: Arc<Mutex<u32>>,
@@ -126,65 +183,54 @@ impl UserRepository {
         }
     }
     
-    pub fn create(&self, mut user: User) -> Result<User, String> {
+    /// # Errors
+    ///
+    /// คืน `RepositoryError::Invalid` เมื่อข้อมูลไม่ผ่านกฎ (ไม่เสีย id) หรือ `Database` เมื่อ lock เสีย
+    pub fn create(&self, mut user: User) -> Result<User, RepositoryError> {
+        // ตรวจก่อนจอง id - request ที่ไม่ผ่านไม่ควรทำให้ id กระโดด
+        user.validate().map_err(RepositoryError::Invalid)?;
         if let (Ok(mut users), Ok(mut next_id)) = (self.users.lock(), self.next_id.lock()) {
             user.id = *next_id;
             *next_id += 1;
-            
-            // Validate user data
-            if user.name.is_empty() {
-                return Err("Name cannot be empty".to_string());
-            }
-            if user.email.is_empty() || !user.email.contains('@') {
-                return Err("Invalid email address".to_string());
-            }
-            if user.age == 0 || user.age > 150 {
-                return Err("Invalid age".to_string());
-            }
-            
             users.insert(user.id, user.clone());
             Ok(user)
         } else {
-            Err("Database error".to_string())
+            Err(RepositoryError::Database)
         }
     }
     
-    pub fn update(&self, id: u32, updated_user: User) -> Result<User, String> {
+    /// # Errors
+    ///
+    /// คืน `RepositoryError::NotFound` เมื่อไม่มี id นี้, `Invalid` เมื่อข้อมูลไม่ผ่านกฎ หรือ `Database`
+    pub fn update(&self, id: u32, updated_user: User) -> Result<User, RepositoryError> {
         if let Ok(mut users) = self.users.lock() {
             if let std::collections::hash_map::Entry::Occupied(mut e) = users.entry(id) {
                 let mut user = updated_user;
                 user.id = id; // Ensure ID doesn't change
-                
-                // Validate user data
-                if user.name.is_empty() {
-                    return Err("Name cannot be empty".to_string());
-                }
-                if user.email.is_empty() || !user.email.contains('@') {
-                    return Err("Invalid email address".to_string());
-                }
-                if user.age == 0 || user.age > 150 {
-                    return Err("Invalid age".to_string());
-                }
+                user.validate().map_err(RepositoryError::Invalid)?;
                 
                 e.insert(user.clone());
                 Ok(user)
             } else {
-                Err("User not found".to_string())
+                Err(RepositoryError::NotFound)
             }
         } else {
-            Err("Database error".to_string())
+            Err(RepositoryError::Database)
         }
     }
     
-    pub fn delete(&self, id: u32) -> Result<(), String> {
+    /// # Errors
+    ///
+    /// คืน `RepositoryError::NotFound` เมื่อไม่มี id นี้ หรือ `Database` เมื่อ lock เสีย
+    pub fn delete(&self, id: u32) -> Result<(), RepositoryError> {
         if let Ok(mut users) = self.users.lock() {
             if users.remove(&id).is_some() {
                 Ok(())
             } else {
-                Err("User not found".to_string())
+                Err(RepositoryError::NotFound)
             }
         } else {
-            Err("Database error".to_string())
+            Err(RepositoryError::Database)
         }
     }
     
@@ -203,6 +249,69 @@ impl UserRepository {
     }
 }
 
+/// ✅ Task Model - งานที่ผู้ใช้แต่ละคนถืออยู่
+#[derive(Debug, Clone)]
+pub struct Task {
+    pub id: u32,
+    pub owner_id: u32,
+    pub title: String,
+    pub done: bool,
+}
+
+impl Task {
+    #[must_use] pub fn new(id: u32, owner_id: u32, title: &str, done: bool) -> Self {
+        Self {
+            id,
+            owner_id,
+            title: title.to_string(),
+            done,
+        }
+    }
+}
+
+/// 🗂️ Task Repository (In-memory) - คลังงานของผู้ใช้ ยังไม่มี REST endpoint (อ่านผ่าน GraphQL)
+#[derive(Clone)]
+pub struct TaskRepository {
+    tasks: Arc<Mutex<Vec<Task>>>,
+}
+
+impl Default for TaskRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TaskRepository {
+    #[must_use] pub fn new() -> Self {
+        let tasks = vec![
+            Task::new(1, 1, "Set up Rust toolchain", true),
+            Task::new(2, 1, "Write REST handlers", false),
+            Task::new(3, 2, "Design GraphQL schema", false),
+            Task::new(4, 3, "Review pull requests", true),
+            Task::new(5, 3, "Deploy workshop server", false),
+        ];
+        Self {
+            tasks: Arc::new(Mutex::new(tasks)),
+        }
+    }
+    
+    /// งานทั้งหมดเรียงตาม id
+    #[must_use] pub fn get_all(&self) -> Vec<Task> {
+        self.tasks
+            .lock()
+            .map_or_else(|_| Vec::new(), |tasks| tasks.clone())
+    }
+    
+    #[must_use] pub fn get_by_id(&self, id: u32) -> Option<Task> {
+        self.get_all().into_iter().find(|task| task.id == id)
+    }
+    
+    /// งานของผู้ใช้ `owner_id`
+    #[must_use] pub fn by_owner(&self, owner_id: u32) -> Vec<Task> {
+        self.get_all().into_iter().filter(|task| task.owner_id == owner_id).collect()
+    }
+}
+
 /// 🎮 REST API Controller - ตัวควบคุม REST API
 pub struct UserController {
     repository: UserRepository,
@@ -216,27 +325,41 @@ impl Default for UserController {
 
 impl UserController {
     #[must_use] pub fn new() -> Self {
-        Self {
-            repository: UserRepository::new(),
-        }
+        Self::with_repository(UserRepository::new())
     }
     
-    /// GET /api/users
-    #[must_use] pub fn get_users(&self, request: &HttpRequest) -> HttpResponse {
-        // Check for search query parameter
-        if let Some(query) = self.extract_query_param(&request.path, "search") {
-            let users = self.repository.search(&query);
-            let response = ApiResponse::success(users);
-            return self.json_response(HttpStatus::Ok, &response);
+    /// ใช้ repository ที่มีอยู่แล้ว (ใช้ร่วมกับ API อื่นได้)
+    #[must_use] pub const fn with_repository(repository: UserRepository) -> Self {
+        Self { repository }
+    }
+    
+    /// GET /api/users?search=...&limit=... - query ผิดชนิด (เช่น `limit=many`) ได้ 400
+    #[must_use] pub fn get_users(&self, query: &str) -> HttpResponse {
+        profiling::span!("controller:get_users");
+        match query.parse::<Query<UserListParams>>() {
+            Ok(Query(params)) => {
+                let response = ApiResponse::success(self.list_users(&params));
+                self.json_response(HttpStatus::Ok, &response)
+            }
+            Err(error) => {
+                let body = serde_json::json!({ "success": false, "message": error.to_string() });
+                HttpResponse::json(HttpStatus::BadRequest, &body.to_string())
+            }
         }
-        
-        let users = self.repository.get_all();
-        let response = ApiResponse::success(users);
-        self.json_response(HttpStatus::Ok, &response)
+    }
+    
+    /// ผู้ใช้ที่ตรงกับ `params` เรียงตาม id
+    #[must_use] pub fn list_users(&self, params: &UserListParams) -> Vec<User> {
+        let mut users = params.search.as_deref()
+            .map_or_else(|| self.repository.get_all(), |query| self.repository.search(query));
+        users.sort_by_key(|user| user.id);
+        users.truncate(params.limit.unwrap_or(usize::MAX));
+        users
     }
     
     /// GET /api/users/{id}
     #[must_use] pub fn get_user(&self, id: u32) -> HttpResponse {
+        profiling::span!("controller:get_user");
         if let Some(user) = self.repository.get_by_id(id) {
             let response = ApiResponse::success(user);
             self.json_response(HttpStatus::Ok, &response)
@@ -248,17 +371,15 @@ impl UserController {
     
     /// POST /api/users
     #[must_use] pub fn create_user(&self, request: &HttpRequest) -> HttpResponse {
-        match self.parse_json::<User>(&request.body) {
+        profiling::span!("controller:create_user");
+        match Self::parse_user(&request.body) {
             Ok(user) => {
                 match self.repository.create(user) {
                     Ok(created_user) => {
                         let response = ApiResponse::success(created_user);
                         self.json_response(HttpStatus::Created, &response)
                     }
-                    Err(error) => {
-                        let response: ApiResponse<()> = ApiResponse::error(&error);
-                        self.json_response(HttpStatus::BadRequest, &response)
-                    }
+                    Err(error) => Self::error_response(&error),
                 }
             }
             Err(error) => {
@@ -270,22 +391,15 @@ impl UserController {
     
     /// PUT /api/users/{id}
     #[must_use] pub fn update_user(&self, id: u32, request: &HttpRequest) -> HttpResponse {
-        match self.parse_json::<User>(&request.body) {
+        profiling::span!("controller:update_user");
+        match Self::parse_user(&request.body) {
             Ok(user) => {
                 match self.repository.update(id, user) {
                     Ok(updated_user) => {
                         let response = ApiResponse::success(updated_user);
                         self.json_response(HttpStatus::Ok, &response)
                     }
-                    Err(error) => {
-                        let status = if error == "User not found" {
-                            HttpStatus::NotFound
-                        } else {
-                            HttpStatus::BadRequest
-                        };
-                        let response: ApiResponse<()> = ApiResponse::error(&error);
-                        self.json_response(status, &response)
-                    }
+                    Err(error) => Self::error_response(&error),
                 }
             }
             Err(error) => {
@@ -297,37 +411,51 @@ impl UserController {
     
     /// DELETE /api/users/{id}
     #[must_use] pub fn delete_user(&self, id: u32) -> HttpResponse {
+        profiling::span!("controller:delete_user");
         match self.repository.delete(id) {
             Ok(()) => {
                 let response: ApiResponse<()> = ApiResponse::success(());
                 self.json_response(HttpStatus::Ok, &response)
             }
-            Err(error) => {
-                let response: ApiResponse<()> = ApiResponse::error(&error);
-                self.json_response(HttpStatus::NotFound, &response)
-            }
+            Err(error) => Self::error_response(&error),
         }
     }
     
+    /// แปลง error ของ repository เป็น response - validation ได้ 400 พร้อมรายการ error ทุกฟิลด์
+    /// 
+    /// ```json
+    /// {"success": false, "message": "Validation failed",
+    ///  "errors": [{"field": "email", "code": "pattern", "message": "..."}]}
+    /// ```
+    fn error_response(error: &RepositoryError) -> HttpResponse {
+        let (status, body) = match error {
+            RepositoryError::Invalid(errors) => (
+                HttpStatus::BadRequest,
+                serde_json::json!({ "success": false, "message": "Validation failed", "errors": errors }),
+            ),
+            RepositoryError::NotFound => (
+                HttpStatus::NotFound,
+                serde_json::json!({ "success": false, "message": error.to_string() }),
+            ),
+            RepositoryError::Database => (
+                HttpStatus::InternalServerError,
+                serde_json::json!({ "success": false, "message": error.to_string() }),
+            ),
+        };
+        HttpResponse::json(status, &body.to_string())
+    }
+    
     // Helper methods
     fn json_response<T: serde::Serialize>(&self, status: HttpStatus, data: &T) -> HttpResponse {
         let json = self.serialize_json(data);
         HttpResponse::json(status, &json)
     }
     
-    fn parse_json<T: serde::de::DeserializeOwned>(&self, json_str: &str) -> Result<T, String> {
-        // Simplified JSON parsing for demonstration
-        // In real implementation, use serde_json
-        if json_str.contains("\"name\": \"John\"") {
-            let user = User::new(0, "John", "john@example.com", 30);
-            // This is a hack for demonstration - normally use serde_json::from_str
-            Ok(unsafe { std::mem::transmute_copy(&user) })
-        } else if json_str.contains("\"name\": \"Alice\"") {
-            let user = User::new(0, "Alice", "alice@example.com", 28);
-            Ok(unsafe { std::mem::transmute_copy(&user) })
-        } else {
-            Err("Invalid JSON format".to_string())
-        }
+    fn parse_user(json_str: &str) -> Result<User, String> {
+        // JSON ผิดรูป/ชนิดผิดตกที่นี่ ส่วนค่าที่ผิดกฎไปตกที่ validation
+        serde_json::from_str::<UserPayload>(json_str)
+            .map(User::from)
+            .map_err(|error| format!("Invalid JSON format: {error}"))
     }
     
     fn serialize_json<T: serde::Serialize>(&self, data: &T) -> String {
@@ -335,27 +463,14 @@ impl UserController {
         // In real implementation, use serde_json
         "{\"serialized\": \"data\"}".to_string() // Placeholder
     }
-    
-    fn extract_query_param(&self, path: &str, param: &str) -> Option<String> {
-        if let Some(query_start) = path.find('?') {
-            let query_string = &path[query_start + 1..];
-            for pair in query_string.split('&') {
-                if let Some(eq_pos) = pair.find('=') {
-                    let key = &pair[..eq_pos];
-                    let value = &pair[eq_pos + 1..];
-                    if key == param {
-                        return Some(value.to_string());
-                    }
-                }
-            }
-        }
-        None
-    }
 }
 
 /// 🛤️ API Router - ตัวจัดเส้นทาง API
 pub struct ApiRouter {
     controller: UserController,
+    graphql: GraphQlExecutor,
+    policy: Option<Policy>,
+    audit: Option<AuditLog>,
 }
 
 impl Default for ApiRouter {
@@ -366,17 +481,89 @@ impl Default for ApiRouter {
 
 impl ApiRouter {
     #[must_use] pub fn new() -> Self {
+        // REST และ GraphQL อ่าน/เขียนข้อมูลชุดเดียวกัน
+        let users = UserRepository::new();
         Self {
-            controller: UserController::new(),
+            controller: UserController::with_repository(users.clone()),
+            graphql: GraphQlExecutor::new(users, TaskRepository::new()),
+            policy: None,
+            audit: None,
         }
     }
     
+    /// 🛂 ตรวจสิทธิ์ทุก request ด้วย `policy` ก่อนเข้า handler (ไม่ตั้ง = ทุก endpoint เปิดแบบเดิม)
+    #[must_use] pub fn with_policy(mut self, policy: Policy) -> Self {
+        self.policy = Some(policy);
+        self
+    }
+    
+    /// 📒 บันทึกทุก request ที่ policy ปฏิเสธลง audit log
+    #[must_use] pub fn with_audit_log(mut self, audit: AuditLog) -> Self {
+        self.audit = Some(audit);
+        self
+    }
+    
+    /// action และ resource ของแต่ละ endpoint - ผู้ใช้แต่ละคนเป็นเจ้าของ record ของตัวเอง
+    fn access_for(method: &str, path_parts: &[&str]) -> (String, Resource) {
+        let user = |id: &str| Resource::new("users").with_id(id).with_attribute("owner_id", id);
+        match (method, path_parts) {
+            ("GET", ["api", "users"]) => ("read".to_string(), Resource::new("users")),
+            ("POST", ["api", "users"]) => ("create".to_string(), Resource::new("users")),
+            ("GET", ["api", "users", id]) => ("read".to_string(), user(id)),
+            ("PUT", ["api", "users", id]) => ("update".to_string(), user(id)),
+            ("DELETE", ["api", "users", id]) => ("delete".to_string(), user(id)),
+            (_, ["graphql"]) => ("query".to_string(), Resource::new("graphql")),
+            // endpoint ที่ไม่รู้จักก็ต้องผ่าน policy - ไม่บอกใครว่ามี path ไหนอยู่บ้าง
+            _ => (method.to_lowercase(), Resource::new(&path_parts.join("/"))),
+        }
+    }
+    
+    /// คืน response 401/403 ถ้า request ไม่ผ่าน policy
+    ///
+    /// ตัวตนอ่านจาก `X-User-Id` และ `X-User-Roles` (คั่นด้วย `,`) ที่ชั้น authentication
+    /// ด้านหน้า (เช่น gateway ที่ตรวจ token แล้ว) ใส่ให้ - ห้ามเชื่อ header นี้จาก client ตรงๆ
+    fn authorize(&self, request: &HttpRequest, path_parts: &[&str]) -> Option<HttpResponse> {
+        let policy = self.policy.as_ref()?;
+        let Some(user_id) = request.headers.get("X-User-Id") else {
+            return Some(HttpResponse::json(HttpStatus::Unauthorized, "{\"error\": \"Authentication required\"}"));
+        };
+        let subject = request.headers.get("X-User-Roles")
+            .map_or("", String::as_str)
+            .split(',')
+            .map(str::trim)
+            .filter(|role| !role.is_empty())
+            .fold(Subject::new(user_id), Subject::with_role);
+        let (action, resource) = Self::access_for(&request.method, path_parts);
+        let decision = policy.evaluate(&subject, &action, &resource);
+        if !decision.allowed && let Some(audit) = &self.audit {
+            audit.record(AuditEvent::PermissionDenied {
+                user: user_id.clone(),
+                action,
+                resource: resource.id.as_ref().map_or_else(|| resource.kind.clone(), |id| format!("{}/{id}", resource.kind)),
+                reason: decision.reason.clone(),
+            });
+        }
+        (!decision.allowed).then(|| {
+            let body = serde_json::json!({ "error": decision.reason });
+            HttpResponse::json(HttpStatus::Forbidden, &body.to_string())
+        })
+    }
+    
     #[must_use] pub fn route(&self, request: &HttpRequest) -> HttpResponse {
-        let path_parts: Vec<&str> = request.path.split('/').collect();
+        profiling::span!(format!("route {}", request.method));
+        // แยกด้วย Url - query (`?search=...`) ไม่ปนกับ path และ `%20` ถูกถอดแล้ว
+        let Ok(url) = request.url() else {
+            return HttpResponse::json(HttpStatus::BadRequest, "{\"error\": \"Invalid request URL\"}");
+        };
+        let segments = url.path_segments().unwrap_or_default();
+        let path_parts: Vec<&str> = segments.iter().map(String::as_str).collect();
+        if let Some(denied) = self.authorize(request, &path_parts) {
+            return denied;
+        }
         
         match (request.method.as_str(), path_parts.as_slice()) {
-            ("GET", ["", "api", "users"]) => self.controller.get_users(request),
-            ("GET", ["", "api", "users", id_str]) => {
+            ("GET", ["api", "users"]) => self.controller.get_users(url.query().unwrap_or_default()),
+            ("GET", ["api", "users", id_str]) => {
                 if let Ok(id) = id_str.parse::<u32>() {
                     self.controller.get_user(id)
                 } else {
@@ -384,8 +571,8 @@ impl ApiRouter {
                     HttpResponse::json(HttpStatus::BadRequest, "{\"error\": \"Invalid user ID\"}")
                 }
             }
-            ("POST", ["", "api", "users"]) => self.controller.create_user(request),
-            ("PUT", ["", "api", "users", id_str]) => {
+            ("POST", ["api", "users"]) => self.controller.create_user(request),
+            ("PUT", ["api", "users", id_str]) => {
                 if let Ok(id) = id_str.parse::<u32>() {
                     self.controller.update_user(id, request)
                 } else {
@@ -393,7 +580,8 @@ impl ApiRouter {
                     HttpResponse::json(HttpStatus::BadRequest, "{\"error\": \"Invalid user ID\"}")
                 }
             }
-            ("DELETE", ["", "api", "users", id_str]) => {
+            ("GET" | "POST", ["graphql"]) => self.graphql.handle(request),
+            ("DELETE", ["api", "users", id_str]) => {
                 if let Ok(id) = id_str.parse::<u32>() {
                     self.controller.delete_user(id)
                 } else {
@@ -425,8 +613,12 @@ pub fn demonstrate_rest_api() {
         // GET user by ID
         HttpRequest::new("GET", "/api/users/1"),
         
-        // Search users
+        // Search users - query แปลงเป็น UserListParams ด้วย Query extractor
         HttpRequest::new("GET", "/api/users?search=john"),
+        HttpRequest::new("GET", "/api/users?search=workshop&limit=1"),
+        
+        // ❌ query ผิดชนิด - ได้ 400 บอกชื่อ parameter
+        HttpRequest::new("GET", "/api/users?limit=many"),
         
         // 🔨 CREATE new workshop user
         HttpRequest::new("POST", "/api/users")
@@ -438,6 +630,11 @@ pub fn demonstrate_rest_api() {
             .with_header("Content-Type", "application/json")
             .with_body("{\"name\": \"Workshop Alice\", \"email\": \"alice@workshop.dev\", \"age\": 28}"),
         
+        // ❌ CREATE ที่ผิดหลายช่อง - ได้ 400 พร้อม error ครบทุกฟิลด์ในครั้งเดียว
+        HttpRequest::new("POST", "/api/users")
+            .with_header("Content-Type", "application/json")
+            .with_body("{\"name\": \" \", \"email\": \"not-an-email\", \"age\": 200}"),
+        
         // DELETE user
         HttpRequest::new("DELETE", "/api/users/2"),
         
@@ -445,6 +642,7 @@ pub fn demonstrate_rest_api() {
         HttpRequest::new("GET", "/api/invalid"),
     ];
     
+    let profiler = Profiler::start();
     for (i, request) in test_requests.iter().enumerate() {
         println!("\n--- Request {} ---", i + 1);
         println!("{} {}", request.method, request.path);
@@ -458,6 +656,13 @@ pub fn demonstrate_rest_api() {
             println!("Body: {}", response.body);
         }
     }
+    let profile = profiler.finish();
+    
+    // 🔥 route ไหนช้า? ดูจาก flamegraph หรือเปิด trace ใน chrome://tracing
+    println!("\n🔥 Flamegraph ของ router:");
+    print!("{profile}");
+    println!("🧭 Chrome trace JSON: {} bytes (ใช้ Profile::write_chrome_trace บันทึกเป็นไฟล์)",
+        profile.chrome_trace_json().len());
     
     // 🗄️ Demonstrate workshop repository operations directly
     println!("\n📊 Workshop Repository Operations");
@@ -517,6 +722,33 @@ mod tests {
         assert_eq!(response.status, HttpStatus::NotFound);
     }
 
+    #[test]
+    fn test_query_parameters_are_extracted() {
+        let router = ApiRouter::new();
+        let controller = &router.controller;
+        
+        let all = controller.list_users(&UserListParams::default());
+        assert_eq!(all.iter().map(|user| user.id).collect::<Vec<_>>(), [1, 2, 3]);
+        let Query(params) = "search=WORKSHOP&limit=2".parse::<Query<UserListParams>>().unwrap();
+        let found = controller.list_users(&params);
+        assert_eq!(found.len(), 2);
+        assert!(found.iter().all(|user| user.email.contains("workshop")));
+        
+        // ก่อนมี Url ตัว router เอา `?search=...` ไปเทียบกับ path แล้วได้ 404
+        let request = HttpRequest::new("GET", "/api/users?search=john&limit=1");
+        assert_eq!(router.route(&request).status, HttpStatus::Ok);
+        let request = HttpRequest::new("GET", "/api/users/1?verbose=true#top");
+        assert_eq!(router.route(&request).status, HttpStatus::Ok);
+        
+        let response = router.route(&HttpRequest::new("GET", "/api/users?limit=many"));
+        assert_eq!(response.status, HttpStatus::BadRequest);
+        let body: serde_json::Value = serde_json::from_str(&response.body).unwrap();
+        assert!(body["message"].as_str().unwrap().starts_with("query parameter `limit`"));
+        
+        let request = HttpRequest::new("GET", "/api/users").with_header("Host", "bad host");
+        assert_eq!(router.route(&request).status, HttpStatus::BadRequest);
+    }
+
     #[test]
     fn test_user_validation() {
         let repo = UserRepository::new();
@@ -527,6 +759,39 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_validation_errors_become_structured_400() {
+        let router = ApiRouter::new();
+        
+        let request = HttpRequest::new("POST", "/api/users")
+            .with_body("{\"name\": \"\", \"email\": \"john.workshop.dev\", \"age\": 0}");
+        let response = router.route(&request);
+        assert_eq!(response.status, HttpStatus::BadRequest);
+        let body: serde_json::Value = serde_json::from_str(&response.body).unwrap();
+        assert_eq!(body["message"], "Validation failed");
+        let fields: Vec<_> = body["errors"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|error| (error["field"].as_str().unwrap(), error["code"].as_str().unwrap()))
+            .collect();
+        assert_eq!(
+            fields,
+            [("name", "required"), ("name", "length"), ("email", "pattern"), ("age", "range")]
+        );
+        
+        // ผ่านกฎแล้วได้ id ถัดไปจริง (request ที่ไม่ผ่านไม่ได้จอง id)
+        let request = HttpRequest::new("POST", "/api/users")
+            .with_body("{\"name\": \"Mali\", \"email\": \"mali@workshop.dev\", \"age\": 22}");
+        assert_eq!(router.route(&request).status, HttpStatus::Created);
+        assert_eq!(router.controller.repository.get_by_id(4).unwrap().name, "Mali");
+        
+        let request = HttpRequest::new("PUT", "/api/users/9").with_body("{\"name\": \"Mali\", \"email\": \"mali@workshop.dev\", \"age\": 22}");
+        assert_eq!(router.route(&request).status, HttpStatus::NotFound);
+        let request = HttpRequest::new("PUT", "/api/users/1").with_body("{\"age\": \"thirty\"}");
+        assert_eq!(router.route(&request).status, HttpStatus::BadRequest);
+    }
+
     #[test]
     fn test_demonstrate_rest_api() {
         // Test that the function runs without panicking
@@ -0,0 +1,703 @@
+//! 🗝️ Sessions - จำว่า request ไหนมาจากผู้ใช้คนไหน
+//!
+//! HTTP ไม่มีสถานะ - server จึงเก็บข้อมูลไว้ใน `SessionStore` แล้วส่งแค่ session id
+//! (ที่เซ็นแล้ว) ไปใน cookie ให้ browser ส่งกลับมาทุก request
+//!
+//! 🔄 `SessionMiddleware` ทำทุกอย่างให้: อ่าน cookie → ตรวจลายเซ็น → โหลด session
+//! → ส่งให้ handler → บันทึก → ต่ออายุ cookie (sliding expiration)
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use super::cookies::{Cookie, CookieSigner, SameSite, parse_cookie_header};
+use super::middleware::Middleware;
+use super::{HttpRequest, HttpResponse, HttpStatus};
+use crate::clock::{self, Clock};
+
+/// ❌ Session Error - ข้อผิดพลาดของที่เก็บ session
+#[derive(Debug)]
+pub enum SessionError {
+    Io(io::Error),
+    /// ไฟล์ session อ่านได้แต่แปลงเป็น `Session` ไม่ได้
+    Corrupt {
+        id: String,
+        message: String,
+    },
+    /// id ไม่ใช่เลขฐาน 16 ยาว 32 ตัว - กันการใช้ id เป็น path (`../../etc/passwd`)
+    InvalidId(String),
+}
+
+impl fmt::Display for SessionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "session store I/O error: {error}"),
+            Self::Corrupt { id, message } => write!(f, "session {id} is corrupt: {message}"),
+            Self::InvalidId(id) => write!(f, "invalid session id {id:?}"),
+        }
+    }
+}
+
+impl std::error::Error for SessionError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for SessionError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+/// 🗝️ ข้อมูลของผู้ใช้หนึ่งคน - key/value แบบ string
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Session {
+    id: String,
+    data: BTreeMap<String, String>,
+    /// Unix timestamp (วินาที) ที่ session หมดอายุ
+    expires_at: u64,
+    #[serde(skip)]
+    destroyed: bool,
+}
+
+impl Session {
+    /// session ใหม่ที่ id สุ่มจาก 128 บิต (เดาไม่ได้)
+    #[must_use]
+    pub fn new(expires_at: u64) -> Self {
+        let bytes: [u8; 16] = rand::thread_rng().r#gen();
+        Self {
+            id: hex::encode(bytes),
+            data: BTreeMap::new(),
+            expires_at,
+            destroyed: false,
+        }
+    }
+
+    #[must_use]
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    #[must_use]
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.data.get(key).map(String::as_str)
+    }
+
+    pub fn insert(&mut self, key: &str, value: &str) {
+        self.data.insert(key.to_string(), value.to_string());
+    }
+
+    pub fn remove(&mut self, key: &str) -> Option<String> {
+        self.data.remove(key)
+    }
+
+    #[must_use]
+    pub const fn expires_at(&self) -> u64 {
+        self.expires_at
+    }
+
+    #[must_use]
+    pub const fn is_expired(&self, now: u64) -> bool {
+        now >= self.expires_at
+    }
+
+    /// ลบ session ทิ้งเมื่อ handler ทำงานเสร็จ (logout) - browser ได้ cookie สั่งลบ
+    pub const fn destroy(&mut self) {
+        self.destroyed = true;
+    }
+
+    #[must_use]
+    pub const fn is_destroyed(&self) -> bool {
+        self.destroyed
+    }
+}
+
+/// id ที่ `Session::new` สร้าง - เลขฐาน 16 ตัวเล็ก 32 ตัว
+fn is_valid_id(id: &str) -> bool {
+    id.len() == 32
+        && id
+            .bytes()
+            .all(|byte| matches!(byte, b'0'..=b'9' | b'a'..=b'f'))
+}
+
+/// 🗄️ ที่เก็บ session - สลับระหว่าง memory, ไฟล์ หรือฐานข้อมูลได้โดยไม่แก้ middleware
+pub trait SessionStore: Send + Sync {
+    /// # Errors
+    ///
+    /// คืน `SessionError` ถ้าอ่านที่เก็บไม่ได้หรือข้อมูลเสีย (ไม่เจอ id คือ `Ok(None)`)
+    fn load(&self, id: &str) -> Result<Option<Session>, SessionError>;
+
+    /// # Errors
+    ///
+    /// คืน `SessionError` ถ้าเขียนที่เก็บไม่ได้
+    fn save(&self, session: &Session) -> Result<(), SessionError>;
+
+    /// # Errors
+    ///
+    /// คืน `SessionError` ถ้าลบไม่ได้ (ลบ id ที่ไม่มีอยู่ถือว่าสำเร็จ)
+    fn destroy(&self, id: &str) -> Result<(), SessionError>;
+
+    /// ลบ session ที่หมดอายุ ณ `now` ทิ้ง แล้วคืนจำนวนที่ลบ
+    ///
+    /// # Errors
+    ///
+    /// คืน `SessionError` ถ้าอ่านหรือลบจากที่เก็บไม่ได้
+    fn purge_expired(&self, now: u64) -> Result<usize, SessionError>;
+}
+
+/// 🧠 เก็บ session ใน memory - หายเมื่อ restart server
+#[derive(Debug, Default)]
+pub struct MemorySessionStore {
+    sessions: Mutex<HashMap<String, Session>>,
+}
+
+impl MemorySessionStore {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn sessions(&self) -> std::sync::MutexGuard<'_, HashMap<String, Session>> {
+        self.sessions.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl SessionStore for MemorySessionStore {
+    fn load(&self, id: &str) -> Result<Option<Session>, SessionError> {
+        Ok(self.sessions().get(id).cloned())
+    }
+
+    fn save(&self, session: &Session) -> Result<(), SessionError> {
+        self.sessions().insert(session.id.clone(), session.clone());
+        Ok(())
+    }
+
+    fn destroy(&self, id: &str) -> Result<(), SessionError> {
+        self.sessions().remove(id);
+        Ok(())
+    }
+
+    fn purge_expired(&self, now: u64) -> Result<usize, SessionError> {
+        let mut sessions = self.sessions();
+        let before = sessions.len();
+        sessions.retain(|_, session| !session.is_expired(now));
+        Ok(before - sessions.len())
+    }
+}
+
+/// 📁 เก็บ session เป็นไฟล์ JSON หนึ่งไฟล์ต่อ session (`<dir>/<id>.json`) - อยู่รอดข้าม restart
+#[derive(Debug, Clone)]
+pub struct FileSessionStore {
+    dir: PathBuf,
+}
+
+impl FileSessionStore {
+    /// # Errors
+    ///
+    /// คืน `SessionError::Io` ถ้าสร้างโฟลเดอร์ `dir` ไม่ได้
+    pub fn new(dir: impl Into<PathBuf>) -> Result<Self, SessionError> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(Self { dir })
+    }
+
+    fn path_for(&self, id: &str) -> Result<PathBuf, SessionError> {
+        if !is_valid_id(id) {
+            return Err(SessionError::InvalidId(id.to_string()));
+        }
+        Ok(self.dir.join(format!("{id}.json")))
+    }
+
+    fn read(&self, id: &str) -> Result<Option<Session>, SessionError> {
+        let text = match fs::read_to_string(self.path_for(id)?) {
+            Ok(text) => text,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(error) => return Err(error.into()),
+        };
+        serde_json::from_str::<Session>(&text)
+            .ok()
+            .filter(|session| session.id == id)
+            .map(Some)
+            .ok_or_else(|| SessionError::Corrupt {
+                id: id.to_string(),
+                message: "not a session file for this id".to_string(),
+            })
+    }
+}
+
+impl SessionStore for FileSessionStore {
+    fn load(&self, id: &str) -> Result<Option<Session>, SessionError> {
+        // id มาจาก cookie ของ client - id รูปแบบแปลกก็แค่ "ไม่เจอ"
+        if !is_valid_id(id) {
+            return Ok(None);
+        }
+        self.read(id)
+    }
+
+    fn save(&self, session: &Session) -> Result<(), SessionError> {
+        let path = self.path_for(&session.id)?;
+        let json = serde_json::to_string(session).map_err(io::Error::from)?;
+        // เขียนไฟล์ชั่วคราวก่อนแล้ว rename - อ่านพร้อมกันก็ไม่เจอไฟล์ที่เขียนไม่ครบ
+        let temporary = path.with_extension("json.tmp");
+        fs::write(&temporary, json)?;
+        fs::rename(&temporary, &path)?;
+        Ok(())
+    }
+
+    fn destroy(&self, id: &str) -> Result<(), SessionError> {
+        match fs::remove_file(self.path_for(id)?) {
+            Err(error) if error.kind() != io::ErrorKind::NotFound => Err(error.into()),
+            _ => Ok(()),
+        }
+    }
+
+    fn purge_expired(&self, now: u64) -> Result<usize, SessionError> {
+        let mut purged = 0;
+        for entry in fs::read_dir(&self.dir)? {
+            let path = entry?.path();
+            let Some(id) = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .and_then(|name| name.strip_suffix(".json"))
+            else {
+                continue;
+            };
+            // ไฟล์เสียไม่ลบทิ้งเอง - ปล่อยไว้ให้คนตรวจสอบ
+            if let Ok(Some(session)) = self.read(id)
+                && session.is_expired(now)
+            {
+                self.destroy(id)?;
+                purged += 1;
+            }
+        }
+        Ok(purged)
+    }
+}
+
+/// 🔄 Session Middleware - ผูก session เข้ากับทุก request
+///
+/// cookie เก็บเฉพาะ session id ที่เซ็นด้วย HMAC - id ที่ถูกแก้, เซ็นด้วย key อื่น
+/// หรือหมดอายุแล้ว จะได้ session ใหม่ที่ว่างเปล่าแทน
+pub struct SessionMiddleware {
+    store: Arc<dyn SessionStore>,
+    signer: CookieSigner,
+    cookie_name: String,
+    ttl: Duration,
+    clock: Arc<dyn Clock>,
+}
+
+impl SessionMiddleware {
+    /// cookie ชื่อ `session_id` อายุ 30 นาทีนับจาก request ล่าสุด
+    #[must_use]
+    pub fn new(store: Arc<dyn SessionStore>, secret: &[u8]) -> Self {
+        Self {
+            store,
+            signer: CookieSigner::new(secret),
+            cookie_name: "session_id".to_string(),
+            ttl: Duration::from_secs(30 * 60),
+            clock: clock::system_clock(),
+        }
+    }
+
+    #[must_use]
+    pub const fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    #[must_use]
+    pub fn with_cookie_name(mut self, name: &str) -> Self {
+        self.cookie_name = name.to_string();
+        self
+    }
+
+    /// ใช้นาฬิกาจำลองใน tests เพื่อทดสอบการหมดอายุโดยไม่ต้องรอจริง
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// session ของ request นี้ - ได้ session ใหม่ถ้า cookie ไม่มี/ถูกแก้/หมดอายุ
+    ///
+    /// # Errors
+    ///
+    /// คืน `SessionError` ถ้าที่เก็บ session อ่านไม่ได้
+    pub fn load(&self, request: &HttpRequest) -> Result<Session, SessionError> {
+        let now = self.clock.unix_timestamp();
+        let signed_id = request
+            .headers
+            .iter()
+            .filter(|(name, _)| name.eq_ignore_ascii_case("Cookie"))
+            .flat_map(|(_, header)| parse_cookie_header(header))
+            .find(|(name, _)| *name == self.cookie_name)
+            .map(|(_, value)| value);
+        let id = signed_id
+            .as_deref()
+            .and_then(|signed| self.signer.verify(&self.cookie_name, signed).ok());
+
+        if let Some(id) = id
+            && let Some(session) = self.store.load(id)?
+        {
+            if !session.is_expired(now) {
+                return Ok(session);
+            }
+            self.store.destroy(id)?;
+        }
+        Ok(Session::new(now + self.ttl.as_secs()))
+    }
+
+    /// โหลด session → เรียก `handler` → บันทึก session แล้วแนบ `Set-Cookie` ไปกับ response
+    pub fn handle<F>(&self, request: &HttpRequest, handler: F) -> HttpResponse
+    where
+        F: FnOnce(&HttpRequest, &mut Session) -> HttpResponse,
+    {
+        let mut session = match self.load(request) {
+            Ok(session) => session,
+            Err(error) => return Self::store_error(&error),
+        };
+        let response = handler(request, &mut session);
+
+        if session.is_destroyed() {
+            return match self.store.destroy(session.id()) {
+                Ok(()) => response.with_header(
+                    "Set-Cookie",
+                    &Cookie::removal(&self.cookie_name).to_string(),
+                ),
+                Err(error) => Self::store_error(&error),
+            };
+        }
+
+        session.expires_at = self.clock.unix_timestamp() + self.ttl.as_secs();
+        if let Err(error) = self.store.save(&session) {
+            return Self::store_error(&error);
+        }
+        response.with_header("Set-Cookie", &self.session_cookie(session.id()).to_string())
+    }
+
+    /// cookie ที่เก็บ id ที่เซ็นแล้ว - JavaScript อ่านไม่ได้และไม่ถูกส่งไปกับ POST ข้ามเว็บ
+    fn session_cookie(&self, id: &str) -> Cookie {
+        let cookie = Cookie {
+            name: self.cookie_name.clone(),
+            value: id.to_string(),
+            expires: None,
+            max_age: Some(i64::try_from(self.ttl.as_secs()).unwrap_or(i64::MAX)),
+            domain: None,
+            path: Some("/".to_string()),
+            secure: false,
+            http_only: true,
+            same_site: Some(SameSite::Lax),
+        };
+        self.signer.sign(&cookie)
+    }
+
+    fn store_error(error: &SessionError) -> HttpResponse {
+        println!("⚠️ Session store error: {error}");
+        HttpResponse::json(
+            HttpStatus::InternalServerError,
+            "{\"error\": \"Session unavailable\"}",
+        )
+    }
+}
+
+/// ใส่ไว้ใน `MiddlewareChain` แล้วทุก response จะมี session cookie (สร้างใหม่หรือต่ออายุ)
+impl Middleware for SessionMiddleware {
+    fn process(&self, request: &HttpRequest, response: &HttpResponse) -> HttpResponse {
+        self.handle(request, |_, _| response.clone())
+    }
+}
+
+/// 🎭 สาธิต login → ใช้งาน → cookie ถูกแก้ → หมดอายุ → logout
+pub fn demonstrate_sessions() {
+    use crate::clock::MockClock;
+
+    println!("🗝️ Sessions - session id ที่เซ็นแล้วใน cookie + SessionStore");
+
+    let clock = MockClock::new();
+    let store = Arc::new(MemorySessionStore::new());
+    let sessions = SessionMiddleware::new(store.clone(), b"workshop-session-secret")
+        .with_ttl(Duration::from_secs(15 * 60))
+        .with_clock(Arc::new(clock.clone()));
+
+    // handler นับจำนวนครั้งที่เข้าชม และ logout เมื่อเรียก /logout
+    let handler = |request: &HttpRequest, session: &mut Session| {
+        if request.path == "/login" {
+            session.insert("user", "ferris");
+        }
+        if request.path == "/logout" {
+            session.destroy();
+        }
+        let visits = session
+            .get("visits")
+            .and_then(|v| v.parse::<u32>().ok())
+            .unwrap_or(0)
+            + 1;
+        session.insert("visits", &visits.to_string());
+        let user = session.get("user").unwrap_or("guest").to_string();
+        HttpResponse::new(HttpStatus::Ok).with_body(&format!("user={user} visits={visits}"))
+    };
+    let cookie_of = |response: &HttpResponse| {
+        response
+            .headers
+            .get("Set-Cookie")
+            .and_then(|header| header.split(';').next())
+            .unwrap_or_default()
+            .to_string()
+    };
+
+    let response = sessions.handle(&HttpRequest::new("POST", "/login"), handler);
+    println!("\n🔑 POST /login → {}", response.body);
+    println!(
+        "   📤 Set-Cookie: {}",
+        response
+            .headers
+            .get("Set-Cookie")
+            .map_or("-", String::as_str)
+    );
+    let cookie = cookie_of(&response);
+
+    let request = HttpRequest::new("GET", "/profile").with_header("Cookie", &cookie);
+    println!(
+        "👤 GET /profile (cookie เดิม) → {}",
+        sessions.handle(&request, handler).body
+    );
+
+    let tampered = cookie.replacen('=', "=0", 1);
+    let request = HttpRequest::new("GET", "/profile").with_header("Cookie", &tampered);
+    println!(
+        "🚫 GET /profile (cookie ถูกแก้) → {}",
+        sessions.handle(&request, handler).body
+    );
+
+    clock.advance(Duration::from_secs(16 * 60));
+    let request = HttpRequest::new("GET", "/profile").with_header("Cookie", &cookie);
+    println!(
+        "⌛ GET /profile (ผ่านไป 16 นาที) → {}",
+        sessions.handle(&request, handler).body
+    );
+
+    let response = sessions.handle(&HttpRequest::new("POST", "/login"), handler);
+    let request = HttpRequest::new("POST", "/logout").with_header("Cookie", &cookie_of(&response));
+    let response = sessions.handle(&request, handler);
+    println!(
+        "👋 POST /logout → Set-Cookie: {}",
+        response
+            .headers
+            .get("Set-Cookie")
+            .map_or("-", String::as_str)
+    );
+    println!(
+        "🧹 purge_expired ลบไป {} session",
+        store
+            .purge_expired(clock.unix_timestamp() + 3600)
+            .unwrap_or(0)
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::MockClock;
+
+    const SECRET: &[u8] = b"test secret";
+
+    fn counter(_: &HttpRequest, session: &mut Session) -> HttpResponse {
+        let count = session
+            .get("count")
+            .map_or(0, |count| count.parse::<u32>().unwrap())
+            + 1;
+        session.insert("count", &count.to_string());
+        HttpResponse::new(HttpStatus::Ok).with_body(&count.to_string())
+    }
+
+    /// `name=value` จาก `Set-Cookie` สำหรับส่งกลับใน header `Cookie`
+    fn cookie_of(response: &HttpResponse) -> String {
+        let header = response.headers.get("Set-Cookie").expect("Set-Cookie");
+        header.split(';').next().unwrap().to_string()
+    }
+
+    fn with_cookie(cookie: &str) -> HttpRequest {
+        HttpRequest::new("GET", "/").with_header("Cookie", &format!("theme=dark; {cookie}"))
+    }
+
+    #[test]
+    fn test_session_persists_across_requests() {
+        let store = Arc::new(MemorySessionStore::new());
+        let sessions = SessionMiddleware::new(store.clone(), SECRET);
+
+        let first = sessions.handle(&HttpRequest::new("GET", "/"), counter);
+        assert_eq!(first.body, "1");
+        let set_cookie = Cookie::parse(&first.headers["Set-Cookie"]).unwrap();
+        assert_eq!(set_cookie.name, "session_id");
+        assert!(set_cookie.http_only);
+        assert_eq!(set_cookie.same_site, Some(SameSite::Lax));
+        assert_eq!(set_cookie.max_age, Some(30 * 60));
+        // cookie มีแค่ id ที่เซ็นแล้ว ไม่มีข้อมูลใน session
+        let id = CookieSigner::new(SECRET)
+            .verify("session_id", &set_cookie.value)
+            .unwrap();
+        assert_eq!(store.load(id).unwrap().unwrap().get("count"), Some("1"));
+
+        let cookie = cookie_of(&first);
+        assert_eq!(sessions.handle(&with_cookie(&cookie), counter).body, "2");
+        assert_eq!(sessions.handle(&with_cookie(&cookie), counter).body, "3");
+        // ไม่มี cookie = ผู้ใช้ใหม่
+        assert_eq!(
+            sessions.handle(&HttpRequest::new("GET", "/"), counter).body,
+            "1"
+        );
+        assert_eq!(store.purge_expired(0).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_session_expiry_is_sliding() {
+        let clock = MockClock::new();
+        let store = Arc::new(MemorySessionStore::new());
+        let sessions = SessionMiddleware::new(store.clone(), SECRET)
+            .with_ttl(Duration::from_secs(60))
+            .with_clock(Arc::new(clock.clone()));
+
+        let cookie = cookie_of(&sessions.handle(&HttpRequest::new("GET", "/"), counter));
+        // ใช้งานทุก 50 วินาที - อายุถูกต่อทุกครั้งจึงไม่หมด
+        for expected in ["2", "3", "4"] {
+            clock.advance(Duration::from_secs(50));
+            assert_eq!(
+                sessions.handle(&with_cookie(&cookie), counter).body,
+                expected
+            );
+        }
+
+        clock.advance(Duration::from_secs(60));
+        let expired = sessions.handle(&with_cookie(&cookie), counter);
+        assert_eq!(expired.body, "1");
+        assert_ne!(cookie_of(&expired), cookie);
+        // session เก่าถูกลบออกจาก store ตอนเจอว่าหมดอายุ
+        assert_eq!(store.sessions().len(), 1);
+
+        clock.advance(Duration::from_secs(61));
+        assert_eq!(store.purge_expired(clock.unix_timestamp()).unwrap(), 1);
+        assert!(store.sessions().is_empty());
+    }
+
+    #[test]
+    fn test_tampered_cookie_starts_new_session() {
+        let store = Arc::new(MemorySessionStore::new());
+        let sessions = SessionMiddleware::new(store.clone(), SECRET);
+        let victim = cookie_of(&sessions.handle(&HttpRequest::new("GET", "/"), counter));
+        let (_, signed) = victim.split_once('=').unwrap();
+        let (id, signature) = signed.split_once('.').unwrap();
+
+        let other_id = Session::new(0).id().to_string();
+        let forged_elsewhere = SessionMiddleware::new(store.clone(), b"attacker secret");
+        let attacker = cookie_of(&forged_elsewhere.handle(&HttpRequest::new("GET", "/"), counter));
+        let tampered = [
+            format!("session_id={id}"),
+            format!("session_id={other_id}.{signature}"),
+            format!("session_id={id}.{}", &signature[2..]),
+            attacker,
+            format!("session_id=../../etc/passwd.{signature}"),
+        ];
+        for cookie in &tampered {
+            let response = sessions.handle(&with_cookie(cookie), counter);
+            assert_eq!(response.body, "1", "{cookie}");
+            assert_ne!(cookie_of(&response), victim);
+        }
+        // session ของเหยื่อไม่ถูกแตะ
+        assert_eq!(sessions.handle(&with_cookie(&victim), counter).body, "2");
+        // cookie ชื่ออื่นใช้ลายเซ็นเดียวกันไม่ได้
+        let renamed = SessionMiddleware::new(store, SECRET).with_cookie_name("sid");
+        let request = with_cookie(&victim.replacen("session_id", "sid", 1));
+        assert_eq!(renamed.handle(&request, counter).body, "1");
+    }
+
+    #[test]
+    fn test_logout_destroys_session() {
+        let store = Arc::new(MemorySessionStore::new());
+        let sessions = SessionMiddleware::new(store.clone(), SECRET);
+        let cookie = cookie_of(&sessions.handle(&HttpRequest::new("GET", "/"), counter));
+
+        let response = sessions.handle(&with_cookie(&cookie), |_, session| {
+            session.destroy();
+            HttpResponse::new(HttpStatus::Ok)
+        });
+        let removal = Cookie::parse(&response.headers["Set-Cookie"]).unwrap();
+        assert_eq!((removal.value.as_str(), removal.max_age), ("", Some(0)));
+        assert!(store.sessions().is_empty());
+        assert_eq!(sessions.handle(&with_cookie(&cookie), counter).body, "1");
+    }
+
+    #[test]
+    fn test_file_store() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = FileSessionStore::new(dir.path().join("sessions")).unwrap();
+
+        let mut session = Session::new(100);
+        session.insert("user", "ferris");
+        store.save(&session).unwrap();
+        assert_eq!(store.load(session.id()).unwrap(), Some(session.clone()));
+        // store ใหม่ที่ชี้โฟลเดอร์เดิม (เหมือน restart) ยังเห็น session
+        let reopened = FileSessionStore::new(dir.path().join("sessions")).unwrap();
+        assert_eq!(
+            reopened.load(session.id()).unwrap().unwrap().get("user"),
+            Some("ferris")
+        );
+
+        // id จาก client ที่ไม่ใช่รูปแบบของเราไม่ถูกใช้เป็น path
+        assert_eq!(store.load("../../etc/passwd").unwrap(), None);
+        assert!(matches!(
+            store.destroy("../x"),
+            Err(SessionError::InvalidId(_))
+        ));
+
+        let corrupt = Session::new(100);
+        fs::write(
+            dir.path().join(format!("sessions/{}.json", corrupt.id())),
+            "{not json",
+        )
+        .unwrap();
+        let error = store.load(corrupt.id()).unwrap_err();
+        assert!(matches!(error, SessionError::Corrupt { .. }), "{error}");
+
+        let fresh = Session::new(1_000);
+        store.save(&fresh).unwrap();
+        assert_eq!(store.purge_expired(500).unwrap(), 1);
+        assert_eq!(store.load(session.id()).unwrap(), None);
+        assert!(store.load(fresh.id()).unwrap().is_some());
+        store.destroy(fresh.id()).unwrap();
+        store.destroy(fresh.id()).unwrap();
+        assert_eq!(store.load(fresh.id()).unwrap(), None);
+    }
+
+    #[test]
+    fn test_session_middleware_in_chain() {
+        use super::super::middleware::MiddlewareChain;
+
+        let store = Arc::new(MemorySessionStore::new());
+        let chain = MiddlewareChain::new().add(SessionMiddleware::new(store.clone(), SECRET));
+        let response = chain.process(
+            &HttpRequest::new("GET", "/"),
+            HttpResponse::new(HttpStatus::Ok),
+        );
+        assert!(response.headers["Set-Cookie"].starts_with("session_id="));
+        assert_eq!(store.sessions().len(), 1);
+
+        // ที่เก็บใช้งานไม่ได้ → 500 แทนที่จะทำเหมือนไม่มี session
+        let broken_dir = tempfile::tempdir().unwrap();
+        let broken = Arc::new(FileSessionStore::new(broken_dir.path()).unwrap());
+        let sessions = SessionMiddleware::new(broken, SECRET);
+        drop(broken_dir);
+        let response = sessions.handle(&HttpRequest::new("GET", "/"), counter);
+        assert_eq!(response.status, HttpStatus::InternalServerError);
+    }
+}
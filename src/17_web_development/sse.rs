@@ -0,0 +1,981 @@
+//! 📡 Server-Sent Events - server ดัน event ไปหา browser ผ่าน response ที่เปิดค้างไว้
+//!
+//! response เป็น `text/event-stream` ส่งแบบ `Transfer-Encoding: chunked` ไม่มีวันจบ
+//! แต่ละ event คือกลุ่มบรรทัด `field: value` ที่ปิดท้ายด้วยบรรทัดว่าง:
+//!
+//! ```text
+//! id: 7
+//! event: enemy_defeat
+//! data: {"enemy_type":"goblin","score_reward":100}
+//!
+//! : keep-alive (บรรทัดที่ขึ้นต้นด้วย ":" คือ comment กัน proxy ตัด connection)
+//! ```
+//!
+//! - 📣 `SseHub` รับ event จาก `EventBus` เก็บประวัติไว้ให้ client ที่หลุดต่อจาก `Last-Event-ID` ได้
+//! - ⏳ `long_poll_endpoint` เป็นทางสำรองสำหรับ client ที่ใช้ SSE ไม่ได้:
+//!   request ค้างไว้จนมี event ใหม่ หรือหมดเวลาแล้วได้ `204 No Content`
+
+use std::any::Any;
+use std::collections::VecDeque;
+use std::fmt::{self, Write as _};
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, PoisonError};
+use std::thread;
+use std::time::Duration;
+
+use super::url::Query;
+use super::web_server::WebServer;
+use super::{HttpParseError, HttpRequest, HttpResponse, HttpStatus};
+use crate::event_bus::{EventBus, SubscriptionId};
+use crate::game_development::game_logic::{
+    EventData, EventType, GameEvent, GameLogicManager, GameState,
+};
+
+/// ✉️ event หนึ่งตัวของ SSE
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SseEvent {
+    pub id: Option<String>,
+    /// ชื่อ event - client ฟังด้วย `addEventListener(name)` (ไม่ใส่ = `message`)
+    pub event: Option<String>,
+    pub data: String,
+    /// บอก client ให้รอกี่ ms ก่อนต่อใหม่เมื่อหลุด
+    pub retry: Option<u64>,
+}
+
+impl SseEvent {
+    #[must_use]
+    pub fn new(data: impl Into<String>) -> Self {
+        Self {
+            id: None,
+            event: None,
+            data: data.into(),
+            retry: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_event(mut self, event: &str) -> Self {
+        self.event = Some(event.to_string());
+        self
+    }
+
+    #[must_use]
+    pub fn with_id(mut self, id: &str) -> Self {
+        self.id = Some(id.to_string());
+        self
+    }
+
+    #[must_use]
+    pub const fn with_retry(mut self, millis: u64) -> Self {
+        self.retry = Some(millis);
+        self
+    }
+}
+
+/// เขียนเป็นข้อความ `text/event-stream` (รวมบรรทัดว่างปิดท้าย)
+///
+/// data หลายบรรทัดถูกแยกเป็นหลาย `data:` - ส่วน `id` / `event` ตัดขึ้นบรรทัดใหม่ทิ้ง
+/// เพื่อไม่ให้ข้อมูลจากผู้ใช้แทรก field ปลอมเข้ามาได้
+impl fmt::Display for SseEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let single_line = |value: &str| value.replace(['\r', '\n'], "");
+        if let Some(id) = &self.id {
+            writeln!(f, "id: {}", single_line(id).replace('\0', ""))?;
+        }
+        if let Some(event) = &self.event {
+            writeln!(f, "event: {}", single_line(event))?;
+        }
+        if let Some(retry) = self.retry {
+            writeln!(f, "retry: {retry}")?;
+        }
+        for line in split_lines(&self.data) {
+            writeln!(f, "data: {line}")?;
+        }
+        writeln!(f)
+    }
+}
+
+/// แยกบรรทัดตาม spec ของ SSE (`\r\n`, `\n` หรือ `\r` เดี่ยว ๆ) - ข้อความว่างได้หนึ่งบรรทัดว่าง
+fn split_lines(text: &str) -> Vec<&str> {
+    let mut lines = Vec::new();
+    let mut rest = text;
+    while let Some(end) = rest.find(['\r', '\n']) {
+        lines.push(&rest[..end]);
+        let skip = if rest[end..].starts_with("\r\n") {
+            2
+        } else {
+            1
+        };
+        rest = &rest[end + skip..];
+    }
+    lines.push(rest);
+    lines
+}
+
+/// 🔍 แยก `text/event-stream` กลับเป็น events แบบเดียวกับ `EventSource` ของ browser
+///
+/// event สุดท้ายที่ยังไม่มีบรรทัดว่างปิดถือว่ายังมาไม่ครบและถูกทิ้ง
+#[must_use]
+pub fn parse_event_stream(text: &str) -> Vec<SseEvent> {
+    let mut events = Vec::new();
+    let mut current = SseEvent::new("");
+    let mut has_data = false;
+    let lines = split_lines(text);
+    // บรรทัดสุดท้ายหลังตัวขึ้นบรรทัดใหม่ตัวสุดท้ายยังไม่จบ
+    let complete = &lines[..lines.len() - 1];
+    for line in complete {
+        if line.is_empty() {
+            if has_data {
+                if current.data.ends_with('\n') {
+                    current.data.pop();
+                }
+                events.push(std::mem::replace(&mut current, SseEvent::new("")));
+            } else {
+                current = SseEvent::new("");
+            }
+            has_data = false;
+            continue;
+        }
+        if line.starts_with(':') {
+            continue;
+        }
+        let (field, value) = line.split_once(':').unwrap_or((line, ""));
+        let value = value.strip_prefix(' ').unwrap_or(value);
+        match field {
+            "data" => {
+                current.data.push_str(value);
+                current.data.push('\n');
+                has_data = true;
+            }
+            "event" => current.event = Some(value.to_string()),
+            "id" if !value.contains('\0') => current.id = Some(value.to_string()),
+            "retry" => {
+                if let Ok(retry) = value.parse() {
+                    current.retry = Some(retry);
+                }
+            }
+            _ => {}
+        }
+    }
+    events
+}
+
+/// 📦 เขียนแบบ `Transfer-Encoding: chunked` - ทุก `write` กลายเป็นหนึ่ง chunk
+#[derive(Debug)]
+pub struct ChunkedWriter<W: Write> {
+    inner: W,
+}
+
+impl<W: Write> ChunkedWriter<W> {
+    pub const fn new(inner: W) -> Self {
+        Self { inner }
+    }
+
+    /// ส่ง chunk ขนาด 0 ปิดท้าย body แล้วคืน writer ตัวใน
+    ///
+    /// # Errors
+    ///
+    /// คืน error จากการเขียนลง writer ตัวใน
+    pub fn finish(mut self) -> io::Result<W> {
+        self.inner.write_all(b"0\r\n\r\n")?;
+        self.inner.flush()?;
+        Ok(self.inner)
+    }
+}
+
+impl<W: Write> Write for ChunkedWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        // chunk ขนาด 0 แปลว่าจบ body - ห้ามส่งออกไประหว่างทาง
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        write!(self.inner, "{:x}\r\n", buf.len())?;
+        self.inner.write_all(buf)?;
+        self.inner.write_all(b"\r\n")?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// 📥 อ่าน body แบบ chunked - เรียก `on_chunk` ทันทีที่แต่ละ chunk มาถึง
+///
+/// # Errors
+///
+/// คืน `InvalidData` ถ้าขนาด chunk ผิดรูป หรือ `UnexpectedEof` ถ้า connection ปิดก่อน chunk สุดท้าย
+pub fn read_chunked<R: BufRead>(mut reader: R, mut on_chunk: impl FnMut(&[u8])) -> io::Result<u64> {
+    let invalid = |message: &str| io::Error::new(io::ErrorKind::InvalidData, message.to_string());
+    let mut total = 0_u64;
+    loop {
+        let mut size_line = String::new();
+        if reader.read_line(&mut size_line)? == 0 {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        // ตัด chunk extension (";name=value") ทิ้ง
+        let size = size_line.split(';').next().unwrap_or_default().trim();
+        let size = usize::from_str_radix(size, 16).map_err(|_| invalid("invalid chunk size"))?;
+        if size == 0 {
+            // trailer headers จบด้วยบรรทัดว่าง
+            loop {
+                let mut line = String::new();
+                if reader.read_line(&mut line)? == 0 || line.trim().is_empty() {
+                    return Ok(total);
+                }
+            }
+        }
+        let mut chunk = vec![0; size];
+        reader.read_exact(&mut chunk)?;
+        let mut crlf = [0; 2];
+        reader.read_exact(&mut crlf)?;
+        if &crlf != b"\r\n" {
+            return Err(invalid("chunk is not terminated by CRLF"));
+        }
+        total += size as u64;
+        on_chunk(&chunk);
+    }
+}
+
+/// 🚿 response SSE ที่เปิดค้างไว้ - ทุก event ถูก flush ออกไปทันที
+#[derive(Debug)]
+pub struct SseStream<W: Write> {
+    body: ChunkedWriter<W>,
+}
+
+impl<W: Write> SseStream<W> {
+    /// เขียน status line และ headers ของ `text/event-stream` แล้วเริ่ม body แบบ chunked
+    ///
+    /// # Errors
+    ///
+    /// คืน error จากการเขียนลง `writer`
+    pub fn start(mut writer: W) -> io::Result<Self> {
+        write!(
+            writer,
+            "HTTP/1.1 {}\r\nContent-Type: text/event-stream\r\nCache-Control: no-cache\r\n\
+             Connection: keep-alive\r\nTransfer-Encoding: chunked\r\n\r\n",
+            HttpStatus::Ok.as_str()
+        )?;
+        writer.flush()?;
+        Ok(Self {
+            body: ChunkedWriter::new(writer),
+        })
+    }
+
+    /// # Errors
+    ///
+    /// คืน error ถ้า client ปิด connection ไปแล้ว
+    pub fn send(&mut self, event: &SseEvent) -> io::Result<()> {
+        self.body.write_all(event.to_string().as_bytes())?;
+        self.body.flush()
+    }
+
+    /// 💓 ส่ง comment ให้ connection ไม่เงียบจน proxy ตัดทิ้ง
+    ///
+    /// # Errors
+    ///
+    /// คืน error ถ้า client ปิด connection ไปแล้ว
+    pub fn comment(&mut self, text: &str) -> io::Result<()> {
+        let mut comment = String::new();
+        for line in split_lines(text) {
+            let _ = writeln!(comment, ": {line}");
+        }
+        comment.push('\n');
+        self.body.write_all(comment.as_bytes())?;
+        self.body.flush()
+    }
+
+    /// ปิด stream อย่างถูกต้อง (client จะต่อใหม่เองตาม `retry`)
+    ///
+    /// # Errors
+    ///
+    /// คืน error จากการเขียนลง writer
+    pub fn finish(self) -> io::Result<W> {
+        self.body.finish()
+    }
+}
+
+/// ⚙️ ตั้งค่าการสตรีมของแต่ละ connection
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SseOptions {
+    /// เงียบนานเท่านี้แล้วส่ง comment `keep-alive`
+    pub keep_alive: Duration,
+    /// ส่งครบจำนวนนี้แล้วปิด stream (`None` = จนกว่า hub จะปิด หรือ client หลุด)
+    pub max_events: Option<usize>,
+}
+
+impl Default for SseOptions {
+    fn default() -> Self {
+        Self {
+            keep_alive: Duration::from_secs(15),
+            max_events: None,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct HubState {
+    last_id: u64,
+    history: VecDeque<(u64, SseEvent)>,
+    clients: Vec<Sender<SseEvent>>,
+    closed: bool,
+}
+
+#[derive(Debug)]
+struct HubInner {
+    state: Mutex<HubState>,
+    published: Condvar,
+    history_limit: usize,
+}
+
+/// 📣 ศูนย์กลางกระจาย event ไปยังทุก client - clone ได้ ทุก clone ใช้ข้อมูลชุดเดียวกัน
+#[derive(Debug, Clone)]
+pub struct SseHub {
+    inner: Arc<HubInner>,
+}
+
+impl SseHub {
+    /// เก็บประวัติล่าสุดไว้ `history_limit` ตัวสำหรับ `Last-Event-ID` และ long-polling
+    #[must_use]
+    pub fn new(history_limit: usize) -> Self {
+        Self {
+            inner: Arc::new(HubInner {
+                state: Mutex::new(HubState::default()),
+                published: Condvar::new(),
+                history_limit,
+            }),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HubState> {
+        self.inner
+            .state
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// ส่ง event ให้ทุก client พร้อมกำหนด `id` ต่อเนื่อง (ดูค่าล่าสุดได้จาก `last_id`)
+    pub fn publish(&self, mut event: SseEvent) {
+        let mut state = self.lock();
+        state.last_id += 1;
+        let id = state.last_id;
+        event.id = Some(id.to_string());
+        // client ที่ปิดไปแล้ว (receiver ถูก drop) ถูกลบออกตรงนี้
+        state
+            .clients
+            .retain(|client| client.send(event.clone()).is_ok());
+        state.history.push_back((id, event));
+        while state.history.len() > self.inner.history_limit {
+            state.history.pop_front();
+        }
+        drop(state);
+        self.inner.published.notify_all();
+    }
+
+    /// 🔌 ฟัง event ชนิด `T` จาก `bus` แล้วแปลงเป็น SSE (`convert` คืน `None` = ข้าม)
+    pub fn bridge<T, F>(&self, bus: &EventBus, convert: F) -> SubscriptionId
+    where
+        T: Any,
+        F: Fn(&T) -> Option<SseEvent> + Send + Sync + 'static,
+    {
+        let hub = self.clone();
+        bus.subscribe(move |event: &T| {
+            if let Some(event) = convert(event) {
+                hub.publish(event);
+            }
+        })
+    }
+
+    /// สมัครรับ event - คืน event ที่ตามไม่ทันหลัง `last_event_id` คู่กับช่องรับ event ใหม่
+    ///
+    /// ทำภายใต้ lock เดียวกัน จึงไม่มี event ตกหล่นหรือซ้ำระหว่างสองส่วน
+    #[must_use]
+    pub fn subscribe(&self, last_event_id: Option<u64>) -> (Vec<SseEvent>, Receiver<SseEvent>) {
+        let (sender, receiver) = mpsc::channel();
+        let mut state = self.lock();
+        let backlog = last_event_id.map_or_else(Vec::new, |last| Self::after(&state, last));
+        if !state.closed {
+            state.clients.push(sender);
+        }
+        drop(state);
+        (backlog, receiver)
+    }
+
+    fn after(state: &HubState, last_id: u64) -> Vec<SseEvent> {
+        state
+            .history
+            .iter()
+            .filter(|(id, _)| *id > last_id)
+            .map(|(_, event)| event.clone())
+            .collect()
+    }
+
+    /// event ทั้งหมดในประวัติที่ใหม่กว่า `last_id`
+    #[must_use]
+    pub fn events_since(&self, last_id: u64) -> Vec<SseEvent> {
+        Self::after(&self.lock(), last_id)
+    }
+
+    /// ⏳ รอจนมี event ใหม่กว่า `last_id` หรือครบ `timeout` (คืน `Vec` ว่าง)
+    #[must_use]
+    pub fn wait_for_events(&self, last_id: u64, timeout: Duration) -> Vec<SseEvent> {
+        let (state, _) = self
+            .inner
+            .published
+            .wait_timeout_while(self.lock(), timeout, |state| {
+                state.last_id <= last_id && !state.closed
+            })
+            .unwrap_or_else(PoisonError::into_inner);
+        Self::after(&state, last_id)
+    }
+
+    #[must_use]
+    pub fn last_id(&self) -> u64 {
+        self.lock().last_id
+    }
+
+    /// จำนวน client SSE ที่ยังเชื่อมต่ออยู่ (นับตอน publish ครั้งล่าสุด)
+    #[must_use]
+    pub fn client_count(&self) -> usize {
+        self.lock().clients.len()
+    }
+
+    /// 🛑 ปิด hub - stream ทุกตัวส่ง event ที่ค้างจนหมดแล้วจบ และ long-poll ที่รออยู่ตื่นทันที
+    pub fn close(&self) {
+        let mut state = self.lock();
+        state.closed = true;
+        state.clients.clear();
+        drop(state);
+        self.inner.published.notify_all();
+    }
+
+    /// 🚿 สตรีม event ลง `writer` จนกว่า hub ปิด ครบ `max_events` หรือ client หลุด
+    ///
+    /// คืนจำนวน event ที่ส่งไป - client ที่ปิด connection เองไม่นับเป็น error
+    ///
+    /// # Errors
+    ///
+    /// คืน error อื่นที่ไม่ใช่การหลุดของ client ระหว่างเขียนลง `writer`
+    pub fn stream<W: Write>(
+        &self,
+        writer: W,
+        last_event_id: Option<u64>,
+        options: SseOptions,
+    ) -> io::Result<usize> {
+        let (backlog, receiver) = self.subscribe(last_event_id);
+        let mut stream = SseStream::start(writer)?;
+        let mut sent = 0;
+        let result = (|| {
+            let limit = options.max_events.unwrap_or(usize::MAX);
+            for event in backlog.into_iter().take(limit) {
+                stream.send(&event)?;
+                sent += 1;
+            }
+            while sent < limit {
+                match receiver.recv_timeout(options.keep_alive) {
+                    Ok(event) => {
+                        stream.send(&event)?;
+                        sent += 1;
+                    }
+                    Err(RecvTimeoutError::Timeout) => stream.comment("keep-alive")?,
+                    Err(RecvTimeoutError::Disconnected) => break,
+                }
+            }
+            Ok(())
+        })();
+        match result {
+            Ok(()) => stream.finish().map(|_| sent),
+            Err(error) if is_disconnect(&error) => Ok(sent),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+fn is_disconnect(error: &io::Error) -> bool {
+    matches!(
+        error.kind(),
+        io::ErrorKind::BrokenPipe
+            | io::ErrorKind::ConnectionReset
+            | io::ErrorKind::ConnectionAborted
+    )
+}
+
+/// 🎮 แปลง `GameEvent` เป็น SSE - ชื่อ event มาจาก `EventType`, data เป็น JSON
+#[must_use]
+pub fn game_event(event: &GameEvent) -> SseEvent {
+    let name = match &event.event_type {
+        EventType::PlayerAction => "player_action",
+        EventType::GameStateChange => "game_state_change",
+        EventType::ScoreUpdate => "score_update",
+        EventType::HealthChange => "health_change",
+        EventType::ItemPickup => "item_pickup",
+        EventType::EnemySpawn => "enemy_spawn",
+        EventType::EnemyDefeat => "enemy_defeat",
+        EventType::LevelComplete => "level_complete",
+        EventType::Achievement => "achievement",
+        EventType::Custom(name) => name,
+    };
+    let data = match &event.data {
+        EventData::PlayerMoved { from, to } => {
+            serde_json::json!({ "from": [from.0, from.1], "to": [to.0, to.1] })
+        }
+        EventData::PlayerAttacked { target, damage } => {
+            serde_json::json!({ "target": target, "damage": damage })
+        }
+        EventData::ScoreChanged {
+            old_score,
+            new_score,
+        } => serde_json::json!({ "old_score": old_score, "new_score": new_score }),
+        EventData::HealthChanged {
+            old_health,
+            new_health,
+        } => serde_json::json!({ "old_health": old_health, "new_health": new_health }),
+        EventData::ItemPickedUp { item_type, value } => {
+            serde_json::json!({ "item_type": item_type, "value": value })
+        }
+        EventData::EnemySpawned {
+            enemy_type,
+            position,
+        } => serde_json::json!({ "enemy_type": enemy_type, "position": [position.0, position.1] }),
+        EventData::EnemyDefeated {
+            enemy_type,
+            score_reward,
+        } => serde_json::json!({ "enemy_type": enemy_type, "score_reward": score_reward }),
+        EventData::LevelCompleted { level, time } => {
+            serde_json::json!({ "level": level, "time_ms": time.as_millis() })
+        }
+        EventData::AchievementUnlocked { achievement_id } => {
+            serde_json::json!({ "achievement_id": achievement_id })
+        }
+        EventData::Custom(fields) => serde_json::json!(fields),
+    };
+    let data = serde_json::json!({ "source_id": event.id, "data": data });
+    SseEvent::new(data.to_string()).with_event(name)
+}
+
+/// ค่า `?since=` ของ long-polling
+#[derive(Debug, Default, ::serde::Deserialize)]
+pub struct PollParams {
+    pub since: Option<u64>,
+}
+
+/// ⏳ endpoint long-polling: `GET /poll?since=<id>` ค้างไว้จนมี event ใหม่กว่า `since`
+///
+/// มี event → `200` พร้อม JSON `{"last_id", "events"}` / หมดเวลา → `204` (client ยิงซ้ำได้เลย)
+/// ไม่ใส่ `since` จะใช้ header `Last-Event-ID` แทน
+pub fn long_poll_endpoint(
+    hub: SseHub,
+    timeout: Duration,
+) -> impl Fn(&HttpRequest) -> HttpResponse + Send + Sync + 'static {
+    move |request| {
+        let query = request
+            .url()
+            .ok()
+            .and_then(|url| url.query().map(str::to_string))
+            .unwrap_or_default();
+        let params = match query.parse::<Query<PollParams>>() {
+            Ok(Query(params)) => params,
+            Err(error) => {
+                let body = serde_json::json!({ "error": error.to_string() });
+                return HttpResponse::json(HttpStatus::BadRequest, &body.to_string());
+            }
+        };
+        let since = params.since.or_else(|| last_event_id(request)).unwrap_or(0);
+        let events = hub.wait_for_events(since, timeout);
+        if events.is_empty() {
+            return HttpResponse::new(HttpStatus::NoContent);
+        }
+        let last_id = events
+            .last()
+            .and_then(|event| event.id.as_deref()?.parse::<u64>().ok())
+            .unwrap_or(since);
+        let events: Vec<_> = events
+            .iter()
+            .map(|event| {
+                serde_json::json!({ "id": event.id, "event": event.event, "data": event.data })
+            })
+            .collect();
+        let body = serde_json::json!({ "last_id": last_id, "events": events });
+        HttpResponse::json(HttpStatus::Ok, &body.to_string())
+    }
+}
+
+/// ค่า header `Last-Event-ID` ที่ browser ส่งมาเองตอนต่อใหม่
+#[must_use]
+pub fn last_event_id(request: &HttpRequest) -> Option<u64> {
+    request
+        .headers
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case("Last-Event-ID"))
+        .and_then(|(_, value)| value.trim().parse().ok())
+}
+
+/// อ่าน request หนึ่งตัวจาก socket (อ่านต่อจน `HttpRequest::parse` ได้ครบ)
+fn read_request(stream: &mut TcpStream) -> io::Result<HttpRequest> {
+    let mut raw = Vec::new();
+    let mut buffer = [0; 1024];
+    loop {
+        let read = stream.read(&mut buffer)?;
+        if read == 0 {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        raw.extend_from_slice(&buffer[..read]);
+        match HttpRequest::parse(&raw) {
+            Ok(request) => return Ok(request),
+            Err(HttpParseError::Incomplete) => {}
+            Err(error) => return Err(io::Error::new(io::ErrorKind::InvalidData, error)),
+        }
+    }
+}
+
+/// 🔌 รับ connection หนึ่งตัว: `GET <events_path>` ได้ SSE ที่เปิดค้าง นอกนั้นส่งให้ `server`
+///
+/// `WebServer` คืน `HttpResponse` ที่จบในตัว จึงต้องแยก SSE ออกมาเขียนลง socket โดยตรง
+///
+/// # Errors
+///
+/// คืน error ถ้าอ่าน request หรือเขียน response ไม่ได้
+pub fn serve_connection(
+    mut stream: TcpStream,
+    hub: &SseHub,
+    server: &WebServer,
+    events_path: &str,
+    options: SseOptions,
+) -> io::Result<()> {
+    let request = match read_request(&mut stream) {
+        Ok(request) => request,
+        Err(error) if error.kind() == io::ErrorKind::InvalidData => {
+            return HttpResponse::new(HttpStatus::BadRequest).write_to(&stream);
+        }
+        Err(error) => return Err(error),
+    };
+    let path = request.path.split('?').next().unwrap_or_default();
+    if request.method == "GET" && path == events_path {
+        hub.stream(&stream, last_event_id(&request), options)?;
+        Ok(())
+    } else {
+        server.handle_request(&request).write_to(&stream)
+    }
+}
+
+/// 🧪 client จำลองแบบ `curl -N` - ส่ง request แล้วพิมพ์แต่ละ chunk ทันทีที่มาถึง
+fn curl(address: &str, request: &str) -> io::Result<String> {
+    let mut stream = TcpStream::connect(address)?;
+    stream.set_read_timeout(Some(Duration::from_secs(5)))?;
+    stream.write_all(request.as_bytes())?;
+    let mut reader = BufReader::new(stream);
+    let mut status = String::new();
+    reader.read_line(&mut status)?;
+    println!("   < {}", status.trim_end());
+    let mut chunked = false;
+    let mut content_length = 0;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 || line.trim().is_empty() {
+            break;
+        }
+        let (name, value) = line.split_once(':').unwrap_or((&line, ""));
+        chunked |= name.eq_ignore_ascii_case("Transfer-Encoding") && value.trim() == "chunked";
+        if name.eq_ignore_ascii_case("Content-Length") {
+            content_length = value.trim().parse().unwrap_or(0);
+        }
+    }
+    if chunked {
+        let mut body = String::new();
+        read_chunked(reader, |chunk| {
+            let chunk = String::from_utf8_lossy(chunk);
+            for line in chunk.lines().filter(|line| !line.is_empty()) {
+                println!("   | {line}");
+            }
+            body.push_str(&chunk);
+        })?;
+        Ok(body)
+    } else {
+        let mut body = vec![0; content_length];
+        reader.read_exact(&mut body)?;
+        let body = String::from_utf8_lossy(&body).into_owned();
+        if !body.is_empty() {
+            println!("   | {body}");
+        }
+        Ok(body)
+    }
+}
+
+/// 🎭 สาธิต SSE: สตรีม event จาก `GameLogicManager` ไปยัง client แบบ curl ผ่าน TCP จริง
+///
+/// # Errors
+///
+/// คืน error ถ้าเปิด socket หรือรับส่งข้อมูลไม่ได้
+pub fn demonstrate_sse() -> io::Result<()> {
+    println!("📡 SSE - สตรีม game events ผ่าน HTTP แบบเปิดค้าง");
+
+    let hub = SseHub::new(100);
+    let bus = EventBus::new();
+    let _ = hub.bridge(&bus, |event: &GameEvent| Some(game_event(event)));
+
+    let mut server = WebServer::new();
+    server.route(
+        "GET /poll",
+        long_poll_endpoint(hub.clone(), Duration::from_millis(200)),
+    );
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let address = listener.local_addr()?.to_string();
+    println!("💻 ลองเองระหว่างเดโมรัน: curl -N http://{address}/events");
+
+    let server_hub = hub.clone();
+    let server_thread = thread::spawn(move || {
+        let options = SseOptions {
+            keep_alive: Duration::from_millis(100),
+            max_events: None,
+        };
+        // SSE หนึ่ง connection + long-poll สอง connection
+        for stream in listener.incoming().take(3) {
+            serve_connection(stream?, &server_hub, &server, "/events", options)?;
+        }
+        Ok::<_, io::Error>(())
+    });
+
+    // ส่ง Last-Event-ID: 0 → ได้ทุก event ตั้งแต่ต้นแม้ต่อเข้ามาช้ากว่าเกม
+    let events_address = address.clone();
+    let client = thread::spawn(move || {
+        curl(
+            &events_address,
+            "GET /events HTTP/1.1\r\nHost: localhost\r\nAccept: text/event-stream\r\nLast-Event-ID: 0\r\n\r\n",
+        )
+    });
+
+    let mut game = GameLogicManager::new();
+    game.attach_event_bus(bus);
+    if let Err(error) = game.change_state(&GameState::Playing { level: 1, score: 0 }) {
+        println!("🚫 {error}");
+    }
+    for (index, enemy_type) in ["goblin", "orc"].into_iter().enumerate() {
+        game.add_event(GameEvent {
+            id: format!("enemy_killed_{index}"),
+            event_type: EventType::EnemyDefeat,
+            timestamp: game.clock.now(),
+            data: EventData::EnemyDefeated {
+                enemy_type: enemy_type.to_string(),
+                score_reward: 100,
+            },
+        });
+    }
+    hub.close();
+    let body = client
+        .join()
+        .map_err(|_| io::Error::other("client thread panicked"))??;
+    println!("🎯 client แยกได้ {} events", parse_event_stream(&body).len());
+
+    println!("\n⏳ Long-polling (สำหรับ client ที่ใช้ SSE ไม่ได้):");
+    let last_id = hub.last_id();
+    curl(
+        &address,
+        &format!(
+            "GET /poll?since={} HTTP/1.1\r\nHost: localhost\r\n\r\n",
+            last_id.saturating_sub(1)
+        ),
+    )?;
+    curl(
+        &address,
+        &format!("GET /poll?since={last_id} HTTP/1.1\r\nHost: localhost\r\n\r\n"),
+    )?;
+
+    server_thread
+        .join()
+        .map_err(|_| io::Error::other("server thread panicked"))?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_framing() {
+        let event = SseEvent::new("line one\nline two\r\nline three")
+            .with_event("score_update")
+            .with_id("42")
+            .with_retry(3000);
+        assert_eq!(
+            event.to_string(),
+            "id: 42\nevent: score_update\nretry: 3000\n\
+             data: line one\ndata: line two\ndata: line three\n\n"
+        );
+        assert_eq!(SseEvent::new("").to_string(), "data: \n\n");
+
+        // ขึ้นบรรทัดใหม่ใน id/event ต้องไม่กลายเป็น field ใหม่
+        let injected = SseEvent::new("x")
+            .with_event("chat\ndata: fake")
+            .with_id("1\nretry: 1");
+        assert_eq!(
+            injected.to_string(),
+            "id: 1retry: 1\nevent: chatdata: fake\ndata: x\n\n"
+        );
+        assert_eq!(parse_event_stream(&injected.to_string()).len(), 1);
+    }
+
+    #[test]
+    fn test_parse_event_stream_round_trip() {
+        let events = vec![
+            SseEvent::new("{\"a\":1}").with_event("tick").with_id("1"),
+            SseEvent::new("multi\nline").with_id("2").with_retry(500),
+            SseEvent::new(""),
+        ];
+        let text: String = events.iter().map(ToString::to_string).collect();
+        assert_eq!(parse_event_stream(&text), events);
+
+        let raw = ": comment\r\n\r\ndata:no space\rdata:  two spaces\r\nevent\n\nid: 9\ndata: tail";
+        let parsed = parse_event_stream(raw);
+        assert_eq!(parsed.len(), 1, "comment-only block และ event ที่ไม่จบต้องไม่นับ");
+        assert_eq!(parsed[0].data, "no space\n two spaces");
+        assert_eq!(parsed[0].event.as_deref(), Some(""));
+    }
+
+    #[test]
+    fn test_chunked_writer_and_reader() {
+        let mut writer = ChunkedWriter::new(Vec::new());
+        writer.write_all(b"hello").unwrap();
+        writer.write_all(b"").unwrap();
+        writer.write_all(&[b'x'; 20]).unwrap();
+        let raw = writer.finish().unwrap();
+        assert_eq!(
+            raw,
+            format!("5\r\nhello\r\n14\r\n{}\r\n0\r\n\r\n", "x".repeat(20)).as_bytes()
+        );
+
+        let mut chunks = Vec::new();
+        let total = read_chunked(&raw[..], |chunk| chunks.push(chunk.to_vec())).unwrap();
+        assert_eq!(total, 25);
+        assert_eq!(chunks, [b"hello".to_vec(), vec![b'x'; 20]]);
+
+        let with_extension = b"3;name=v\r\nabc\r\n0\r\nX-Trailer: 1\r\n\r\n";
+        assert_eq!(read_chunked(&with_extension[..], |_| {}).unwrap(), 3);
+        let error = read_chunked(&b"zz\r\n"[..], |_| {}).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        let error = read_chunked(&b"5\r\nab"[..], |_| {}).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn test_hub_stream_replays_backlog_then_live_events() {
+        let hub = SseHub::new(2);
+        for data in ["a", "b", "c"] {
+            hub.publish(SseEvent::new(data));
+        }
+        assert_eq!(hub.events_since(0).len(), 2, "ประวัติถูกจำกัดไว้ 2 ตัว");
+
+        let publisher = hub.clone();
+        let live = thread::spawn(move || {
+            while publisher.client_count() == 0 {
+                thread::sleep(Duration::from_millis(1));
+            }
+            publisher.publish(SseEvent::new("d").with_event("live"));
+            publisher.close();
+        });
+        let options = SseOptions {
+            keep_alive: Duration::from_secs(5),
+            max_events: None,
+        };
+        let mut raw = Vec::new();
+        // ประวัติที่เหลือคือ 2, 3 - ต่อจาก id 2 จึงได้ 3 แล้วตามด้วย event สด
+        let sent = hub.stream(&mut raw, Some(2), options).unwrap();
+        live.join().unwrap();
+        assert_eq!(sent, 2);
+
+        let raw = String::from_utf8(raw).unwrap();
+        let (head, body) = raw.split_once("\r\n\r\n").unwrap();
+        assert!(head.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(head.contains("Content-Type: text/event-stream"));
+        assert!(head.contains("Transfer-Encoding: chunked"));
+        let mut text = String::new();
+        read_chunked(body.as_bytes(), |chunk| {
+            text.push_str(std::str::from_utf8(chunk).unwrap());
+        })
+        .unwrap();
+        let events = parse_event_stream(&text);
+        assert_eq!(events[0], SseEvent::new("c").with_id("3"));
+        assert_eq!(
+            events[1],
+            SseEvent::new("d").with_id("4").with_event("live")
+        );
+    }
+
+    #[test]
+    fn test_stream_sends_keep_alive_and_stops_at_max_events() {
+        let hub = SseHub::new(10);
+        hub.publish(SseEvent::new("first"));
+        hub.publish(SseEvent::new("second"));
+        let options = SseOptions {
+            keep_alive: Duration::from_millis(5),
+            max_events: Some(1),
+        };
+        let mut raw = Vec::new();
+        assert_eq!(hub.stream(&mut raw, Some(0), options).unwrap(), 1);
+        assert!(String::from_utf8(raw).unwrap().ends_with("0\r\n\r\n"));
+
+        let options = SseOptions {
+            keep_alive: Duration::from_millis(5),
+            max_events: None,
+        };
+        let closer = hub.clone();
+        let handle = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(40));
+            closer.close();
+        });
+        let mut raw = Vec::new();
+        assert_eq!(hub.stream(&mut raw, None, options).unwrap(), 0);
+        handle.join().unwrap();
+        assert!(String::from_utf8(raw).unwrap().contains(": keep-alive\n\n"));
+    }
+
+    #[test]
+    fn test_bridge_and_long_poll_endpoint() {
+        let hub = SseHub::new(10);
+        let bus = EventBus::new();
+        let _ = hub.bridge(&bus, |event: &GameEvent| Some(game_event(event)));
+        let endpoint = long_poll_endpoint(hub.clone(), Duration::from_millis(20));
+
+        let timed_out = endpoint(&HttpRequest::new("GET", "/poll?since=0"));
+        assert_eq!(timed_out.status, HttpStatus::NoContent);
+        let bad = endpoint(&HttpRequest::new("GET", "/poll?since=abc"));
+        assert_eq!(bad.status, HttpStatus::BadRequest);
+
+        let mut game = GameLogicManager::new();
+        game.attach_event_bus(bus);
+        game.add_event(GameEvent {
+            id: "kill".to_string(),
+            event_type: EventType::EnemyDefeat,
+            timestamp: game.clock.now(),
+            data: EventData::EnemyDefeated {
+                enemy_type: "goblin".to_string(),
+                score_reward: 100,
+            },
+        });
+        let events = hub.events_since(0);
+        assert_eq!(events[0].event.as_deref(), Some("enemy_defeat"));
+        let data: serde_json::Value = serde_json::from_str(&events[0].data).unwrap();
+        assert_eq!(data["source_id"], "kill");
+        assert_eq!(data["data"]["enemy_type"], "goblin");
+
+        let response =
+            endpoint(&HttpRequest::new("GET", "/poll").with_header("Last-Event-ID", "0"));
+        assert_eq!(response.status, HttpStatus::Ok);
+        let body: serde_json::Value = serde_json::from_str(&response.body).unwrap();
+        assert_eq!(body["last_id"], hub.last_id());
+        assert_eq!(body["events"][0]["event"], "enemy_defeat");
+
+        // รอข้าม thread: publish ระหว่างที่ poll ค้างอยู่ต้องปลุกให้ตอบทันที
+        let publisher = hub.clone();
+        let last = hub.last_id();
+        let handle = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(10));
+            publisher.publish(SseEvent::new("wake"));
+        });
+        let slow = long_poll_endpoint(hub, Duration::from_secs(5));
+        let response = slow(&HttpRequest::new("GET", &format!("/poll?since={last}")));
+        handle.join().unwrap();
+        assert_eq!(response.status, HttpStatus::Ok);
+        assert!(response.body.contains("wake"));
+    }
+}
@@ -0,0 +1,745 @@
+//! 📂 Static Files - เสิร์ฟไฟล์จากโฟลเดอร์อย่างปลอดภัย
+//!
+//! - 🛡️ กัน path traversal: decode `%2e%2e` ก่อนตรวจ ไม่รับ `..` / ไฟล์ซ่อน
+//!   และตรวจซ้ำหลัง `canonicalize` เพื่อกัน symlink ที่ชี้ออกนอก root
+//! - 🏷️ `Content-Type` เดาจากนามสกุลไฟล์
+//! - ♻️ `ETag` + `If-None-Match` → `304 Not Modified` (browser ใช้ cache เดิมได้)
+//! - ✂️ `Range: bytes=...` → `206 Partial Content` (ดาวน์โหลดต่อ / เลื่อนวิดีโอ)
+//! - 🌊 `FileResponse::write_to` ส่งไฟล์ทีละ chunk โดยไม่โหลดทั้งไฟล์เข้า memory
+
+use std::fs::{self, File, Metadata};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use chrono::{DateTime, Utc};
+
+use super::web_server::WebServer;
+use super::{HttpRequest, HttpResponse, HttpStatus};
+use crate::io_filesystem::copy_with_progress;
+use crate::security::sanitization::safe_relative_path;
+
+/// ขนาด chunk เริ่มต้นตอนส่งไฟล์
+const DEFAULT_CHUNK_SIZE: usize = 64 * 1024;
+
+/// 📁 Static Files - handler ที่เสิร์ฟไฟล์ใต้ `root`
+#[derive(Debug, Clone)]
+pub struct StaticFiles {
+    root: PathBuf,
+    index_file: Option<String>,
+    cache_control: String,
+    chunk_size: usize,
+}
+
+/// ✂️ ช่วง bytes ที่ขอมา (ปิดทั้งสองฝั่ง เหมือน `Content-Range`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ByteRange {
+    pub start: u64,
+    pub end: u64,
+}
+
+impl ByteRange {
+    #[must_use]
+    pub const fn len(&self) -> u64 {
+        (self.end + 1).saturating_sub(self.start)
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.end < self.start
+    }
+}
+
+/// ผลการแปลง header `Range` เทียบกับขนาดไฟล์
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RangeRequest {
+    /// ไม่มี `Range` หรือรูปแบบที่ไม่รองรับ - ส่งทั้งไฟล์ (RFC 9110 อนุญาตให้เพิกเฉยได้)
+    Full,
+    Partial(ByteRange),
+    /// ช่วงอยู่นอกไฟล์ทั้งหมด → `416`
+    Unsatisfiable,
+}
+
+/// 📤 คำตอบที่ยังไม่ได้อ่านไฟล์ - body จะถูกอ่านทีละ chunk ตอนส่ง
+#[derive(Debug)]
+pub struct FileResponse {
+    pub status: HttpStatus,
+    pub headers: Vec<(String, String)>,
+    body: Option<(File, ByteRange)>,
+    chunk_size: usize,
+}
+
+impl FileResponse {
+    const fn new(status: HttpStatus) -> Self {
+        Self {
+            status,
+            headers: Vec::new(),
+            body: None,
+            chunk_size: DEFAULT_CHUNK_SIZE,
+        }
+    }
+
+    fn with_header(mut self, name: &str, value: impl Into<String>) -> Self {
+        self.headers.push((name.to_string(), value.into()));
+        self
+    }
+
+    /// ค่าของ header (ไม่สนตัวพิมพ์เล็กใหญ่)
+    #[must_use]
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// จำนวน bytes ของ body ที่จะถูกส่ง
+    #[must_use]
+    pub fn body_len(&self) -> u64 {
+        self.body.as_ref().map_or(0, |(_, range)| range.len())
+    }
+
+    /// 🌊 เขียน body ลง `writer` ทีละ chunk - `on_chunk` ได้จำนวน bytes สะสม
+    ///
+    /// # Errors
+    ///
+    /// คืน error ถ้า seek/อ่านไฟล์หรือเขียนลง `writer` ไม่ได้
+    pub fn write_body<W: Write>(self, writer: W, on_chunk: impl FnMut(u64)) -> io::Result<u64> {
+        let Some((mut file, range)) = self.body else {
+            return Ok(0);
+        };
+        file.seek(SeekFrom::Start(range.start))?;
+        let written =
+            copy_with_progress(file.take(range.len()), writer, self.chunk_size, on_chunk)?;
+        if written == range.len() {
+            Ok(written)
+        } else {
+            Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "file shrank while it was being sent",
+            ))
+        }
+    }
+
+    /// 📡 เขียนคำตอบ HTTP/1.1 ทั้งหมด (status line, headers, body) ลง socket
+    ///
+    /// # Errors
+    ///
+    /// คืน error จากการเขียนลง `writer` หรือการอ่านไฟล์
+    pub fn write_to<W: Write>(self, mut writer: W) -> io::Result<u64> {
+        write!(writer, "HTTP/1.1 {}\r\n", self.status.as_str())?;
+        for (name, value) in &self.headers {
+            write!(writer, "{name}: {value}\r\n")?;
+        }
+        writer.write_all(b"\r\n")?;
+        self.write_body(writer, |_| {})
+    }
+
+    /// แปลงเป็น `HttpResponse` ของ mini framework
+    ///
+    /// ⚠️ `HttpResponse::body` เป็น `String` จึงต้องอ่านทั้งช่วงเข้า memory
+    /// และไฟล์ binary จะถูกแปลงแบบ lossy - ถ้าส่งลง socket เองให้ใช้ `write_to`
+    ///
+    /// # Errors
+    ///
+    /// คืน error ถ้าอ่านไฟล์ไม่ได้
+    pub fn into_http_response(self) -> io::Result<HttpResponse> {
+        let mut response = HttpResponse::new(self.status);
+        for (name, value) in &self.headers {
+            response = response.with_header(name, value);
+        }
+        let mut body = Vec::new();
+        self.write_body(&mut body, |_| {})?;
+        response.body = String::from_utf8_lossy(&body).into_owned();
+        Ok(response)
+    }
+}
+
+impl StaticFiles {
+    /// สร้าง handler สำหรับโฟลเดอร์ `root` (ต้องมีอยู่จริง)
+    ///
+    /// # Errors
+    ///
+    /// คืน error ถ้า `root` ไม่มีอยู่หรือไม่ใช่โฟลเดอร์
+    pub fn new(root: impl AsRef<Path>) -> io::Result<Self> {
+        let root = fs::canonicalize(root)?;
+        if !root.is_dir() {
+            return Err(io::Error::new(
+                io::ErrorKind::NotADirectory,
+                format!("{} is not a directory", root.display()),
+            ));
+        }
+        Ok(Self {
+            root,
+            index_file: Some("index.html".to_string()),
+            cache_control: "public, max-age=0, must-revalidate".to_string(),
+            chunk_size: DEFAULT_CHUNK_SIZE,
+        })
+    }
+
+    /// ไฟล์ที่ใช้แทนเมื่อขอ path ที่เป็นโฟลเดอร์ (`None` = ตอบ 404)
+    #[must_use]
+    pub fn with_index_file(mut self, index_file: Option<&str>) -> Self {
+        self.index_file = index_file.map(str::to_string);
+        self
+    }
+
+    #[must_use]
+    pub fn with_cache_control(mut self, cache_control: &str) -> Self {
+        self.cache_control = cache_control.to_string();
+        self
+    }
+
+    /// # Panics
+    ///
+    /// เมื่อ `chunk_size` เป็น 0
+    #[must_use]
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+        assert!(chunk_size > 0, "chunk_size must be positive");
+        self.chunk_size = chunk_size;
+        self
+    }
+
+    #[must_use]
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// 🛡️ แปลง URL path (ยัง percent-encoded) เป็นไฟล์จริงใต้ `root`
+    ///
+    /// คืน `None` ถ้า path พยายามออกนอก `root`, ชี้ไปที่ไฟล์ซ่อน หรือไม่มีไฟล์นั้น
+    #[must_use]
+    pub fn resolve(&self, url_path: &str) -> Option<PathBuf> {
+        // ".." และไฟล์ซ่อน (.env, .git) ถูกปฏิเสธ - "/" หรือ "\" ที่ encode มาก็เช่นกัน
+        let relative = safe_relative_path(url_path).ok()?;
+        let mut path = fs::canonicalize(self.root.join(relative)).ok()?;
+        if path.is_dir() {
+            path = fs::canonicalize(path.join(self.index_file.as_ref()?)).ok()?;
+        }
+        // symlink ภายใน root อาจชี้ออกไปข้างนอก - ตรวจหลัง canonicalize อีกรอบ
+        (path.starts_with(&self.root) && path.is_file()).then_some(path)
+    }
+
+    /// 📨 ตอบ request สำหรับ `url_path` (path หลังตัด mount prefix แล้ว)
+    #[must_use]
+    pub fn respond(&self, request: &HttpRequest, url_path: &str) -> FileResponse {
+        let head = match request.method.as_str() {
+            "GET" => false,
+            "HEAD" => true,
+            _ => {
+                return FileResponse::new(HttpStatus::MethodNotAllowed)
+                    .with_header("Allow", "GET, HEAD");
+            }
+        };
+        let Some(path) = self.resolve(url_path) else {
+            return FileResponse::new(HttpStatus::NotFound);
+        };
+        match self.open(request, &path, head) {
+            Ok(response) => response,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => {
+                FileResponse::new(HttpStatus::NotFound)
+            }
+            Err(_) => FileResponse::new(HttpStatus::InternalServerError),
+        }
+    }
+
+    fn open(&self, request: &HttpRequest, path: &Path, head: bool) -> io::Result<FileResponse> {
+        let file = File::open(path)?;
+        let metadata = file.metadata()?;
+        let size = metadata.len();
+        let etag = etag(&metadata);
+        let mut response = FileResponse {
+            chunk_size: self.chunk_size,
+            ..FileResponse::new(HttpStatus::Ok)
+        }
+        .with_header("ETag", etag.clone())
+        .with_header("Cache-Control", self.cache_control.clone())
+        .with_header("Accept-Ranges", "bytes");
+        if let Ok(modified) = metadata.modified() {
+            let modified = DateTime::<Utc>::from(modified);
+            response = response.with_header(
+                "Last-Modified",
+                modified.format("%a, %d %b %Y %H:%M:%S GMT").to_string(),
+            );
+        }
+
+        if header(request, "If-None-Match").is_some_and(|tags| etag_matches(tags, &etag)) {
+            response.status = HttpStatus::NotModified;
+            return Ok(response);
+        }
+
+        // If-Range: ถ้าไฟล์เปลี่ยนไปแล้ว (ETag ไม่ตรง) ต้องส่งทั้งไฟล์แทนช่วงที่ขอ
+        let range_header = header(request, "Range")
+            .filter(|_| header(request, "If-Range").is_none_or(|tag| tag.trim() == etag));
+        let range = match range_header.map_or(RangeRequest::Full, |value| parse_range(value, size))
+        {
+            RangeRequest::Full if size == 0 => None,
+            RangeRequest::Full => Some(ByteRange {
+                start: 0,
+                end: size - 1,
+            }),
+            RangeRequest::Partial(range) => {
+                response.status = HttpStatus::PartialContent;
+                response = response.with_header(
+                    "Content-Range",
+                    format!("bytes {}-{}/{size}", range.start, range.end),
+                );
+                Some(range)
+            }
+            RangeRequest::Unsatisfiable => {
+                response.status = HttpStatus::RangeNotSatisfiable;
+                return Ok(response
+                    .with_header("Content-Range", format!("bytes */{size}"))
+                    .with_header("Content-Length", "0"));
+            }
+        };
+
+        let length = range.map_or(0, |range| range.len());
+        response = response
+            .with_header("Content-Type", content_type(path))
+            .with_header("Content-Length", length.to_string());
+        if !head {
+            response.body = range.map(|range| (file, range));
+        }
+        Ok(response)
+    }
+
+    /// 🔌 handler สำหรับ `WebServer` - ตัด `mount` ออกจาก path แล้วเสิร์ฟไฟล์
+    ///
+    /// ใช้คู่กับ route แบบ wildcard เช่น `"GET /static/*"`
+    pub fn handler(
+        &self,
+        mount: &str,
+    ) -> impl Fn(&HttpRequest) -> HttpResponse + Send + Sync + 'static {
+        let files = self.clone();
+        let mount = mount.trim_end_matches('/').to_string();
+        move |request| {
+            let path = request.path.split(['?', '#']).next().unwrap_or_default();
+            let Some(rest) = path
+                .strip_prefix(&mount)
+                .filter(|rest| rest.is_empty() || rest.starts_with('/'))
+            else {
+                return HttpResponse::new(HttpStatus::NotFound).with_body("Not Found");
+            };
+            files
+                .respond(request, rest)
+                .into_http_response()
+                .unwrap_or_else(|_| HttpResponse::new(HttpStatus::InternalServerError))
+        }
+    }
+}
+
+fn header<'a>(request: &'a HttpRequest, name: &str) -> Option<&'a str> {
+    request
+        .headers
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case(name))
+        .map(|(_, value)| value.as_str())
+}
+
+/// 🏷️ `ETag` จากขนาดและเวลาแก้ไขล่าสุด - เปลี่ยนทันทีที่ไฟล์ถูกเขียนใหม่
+fn etag(metadata: &Metadata) -> String {
+    let modified = metadata
+        .modified()
+        .ok()
+        .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+        .map_or(0, |elapsed| elapsed.as_nanos());
+    format!("\"{:x}-{modified:x}\"", metadata.len())
+}
+
+/// `If-None-Match` ใช้ weak comparison - `W/"abc"` ถือว่าตรงกับ `"abc"`
+fn etag_matches(header: &str, etag: &str) -> bool {
+    header.split(',').map(str::trim).any(|candidate| {
+        candidate == "*" || candidate.strip_prefix("W/").unwrap_or(candidate) == etag
+    })
+}
+
+/// ✂️ แปลง header `Range` (รองรับช่วงเดียว: `a-b`, `a-`, `-n`)
+///
+/// หลายช่วงหรือรูปแบบแปลก ๆ ได้ `Full` - ส่งทั้งไฟล์ก็ยังถูกต้องตาม spec
+#[must_use]
+pub fn parse_range(header: &str, size: u64) -> RangeRequest {
+    let Some(spec) = header.trim().strip_prefix("bytes=") else {
+        return RangeRequest::Full;
+    };
+    let Some((start, end)) = spec.trim().split_once('-') else {
+        return RangeRequest::Full;
+    };
+    if spec.contains(',') {
+        return RangeRequest::Full;
+    }
+    let parse = |value: &str| {
+        let value = value.trim();
+        (!value.is_empty() && value.bytes().all(|b| b.is_ascii_digit()))
+            .then(|| value.parse::<u64>().ok())
+            .flatten()
+    };
+    let range = match (parse(start), parse(end)) {
+        // "-500" = 500 bytes สุดท้าย
+        (None, Some(suffix)) if start.trim().is_empty() => {
+            if suffix == 0 || size == 0 {
+                return RangeRequest::Unsatisfiable;
+            }
+            ByteRange {
+                start: size.saturating_sub(suffix),
+                end: size - 1,
+            }
+        }
+        (Some(start), None) if end.trim().is_empty() => ByteRange {
+            start,
+            end: size.saturating_sub(1),
+        },
+        (Some(start), Some(end)) if start <= end => ByteRange {
+            start,
+            end: end.min(size.saturating_sub(1)),
+        },
+        _ => return RangeRequest::Full,
+    };
+    if range.start >= size {
+        RangeRequest::Unsatisfiable
+    } else {
+        RangeRequest::Partial(range)
+    }
+}
+
+/// 🏷️ เดา `Content-Type` จากนามสกุลไฟล์
+#[must_use]
+pub fn content_type(path: &Path) -> &'static str {
+    let extension = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .map(str::to_ascii_lowercase)
+        .unwrap_or_default();
+    match extension.as_str() {
+        "html" | "htm" => "text/html; charset=utf-8",
+        "css" => "text/css; charset=utf-8",
+        "js" | "mjs" => "text/javascript; charset=utf-8",
+        "json" => "application/json",
+        "txt" | "md" => "text/plain; charset=utf-8",
+        "csv" => "text/csv; charset=utf-8",
+        "xml" => "application/xml",
+        "svg" => "image/svg+xml",
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "ico" => "image/x-icon",
+        "wasm" => "application/wasm",
+        "pdf" => "application/pdf",
+        "woff2" => "font/woff2",
+        "mp4" => "video/mp4",
+        _ => "application/octet-stream",
+    }
+}
+
+/// 🎭 สาธิตการเสิร์ฟไฟล์จากโฟลเดอร์ (ไฟล์อยู่ในโฟลเดอร์ชั่วคราว)
+///
+/// # Errors
+///
+/// คืน error ถ้าสร้างไฟล์ตัวอย่างไม่ได้
+pub fn demonstrate_static_files(dir: &Path) -> io::Result<()> {
+    println!("📂 Static Files - เสิร์ฟไฟล์พร้อม ETag และ Range");
+
+    let public = dir.join("public");
+    fs::create_dir_all(public.join("css"))?;
+    fs::write(
+        public.join("index.html"),
+        "<h1>Welcome to Rust Web Server!</h1>\n",
+    )?;
+    fs::write(public.join("css/style.css"), "body { color: #b7410e; }\n")?;
+    fs::write(public.join(".env"), "SECRET=hunter2\n")?;
+    fs::write(dir.join("secret.txt"), "นอก root - ห้ามเห็น\n")?;
+    fs::write(public.join("numbers.txt"), "0123456789".repeat(10))?;
+
+    let files = StaticFiles::new(&public)?.with_cache_control("public, max-age=60");
+    let mut server = WebServer::new();
+    server.route("GET /static/*", files.handler("/static"));
+    server.route("HEAD /static/*", files.handler("/static"));
+
+    let show = |label: &str, request: HttpRequest| {
+        let response = server.handle_request(&request);
+        println!("\n📤 {label}: {} {}", request.method, request.path);
+        println!("   → {}", response.status.as_str());
+        for name in ["Content-Type", "Content-Range", "Content-Length"] {
+            if let Some(value) = response.headers.get(name) {
+                println!("   {name}: {value}");
+            }
+        }
+        if !response.body.is_empty() {
+            println!("   body: {:?}", response.body);
+        }
+        response
+    };
+
+    let first = show("หน้าแรก", HttpRequest::new("GET", "/static/"));
+    let etag = first.headers.get("ETag").cloned().unwrap_or_default();
+    println!("   ETag: {etag}");
+    show(
+        "ขอซ้ำพร้อม ETag",
+        HttpRequest::new("GET", "/static/index.html").with_header("If-None-Match", &etag),
+    );
+    show("CSS", HttpRequest::new("GET", "/static/css/style.css?v=2"));
+    show(
+        "ช่วง bytes 10-19",
+        HttpRequest::new("GET", "/static/numbers.txt").with_header("Range", "bytes=10-19"),
+    );
+    show(
+        "ช่วงเกินไฟล์",
+        HttpRequest::new("GET", "/static/numbers.txt").with_header("Range", "bytes=500-"),
+    );
+    show("HEAD", HttpRequest::new("HEAD", "/static/numbers.txt"));
+    show(
+        "traversal",
+        HttpRequest::new("GET", "/static/..%2fsecret.txt"),
+    );
+    show("ไฟล์ซ่อน", HttpRequest::new("GET", "/static/.env"));
+
+    println!("\n🌊 ส่งไฟล์ลง socket จำลองทีละ 32 bytes:");
+    let mut socket = Vec::new();
+    let request = HttpRequest::new("GET", "/numbers.txt");
+    files
+        .with_chunk_size(32)
+        .respond(&request, "/numbers.txt")
+        .write_body(&mut socket, |sent| println!("   ⏳ ส่งแล้ว {sent} bytes"))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn site() -> (tempfile::TempDir, StaticFiles) {
+        let dir = tempfile::tempdir().unwrap();
+        let public = dir.path().join("public");
+        fs::create_dir_all(public.join("docs")).unwrap();
+        fs::write(public.join("index.html"), "<h1>home</h1>").unwrap();
+        fs::write(public.join("docs/guide.txt"), "abcdefghijklmnopqrstuvwxyz").unwrap();
+        fs::write(public.join(".env"), "SECRET=1").unwrap();
+        fs::write(dir.path().join("secret.txt"), "top secret").unwrap();
+        let files = StaticFiles::new(&public).unwrap();
+        (dir, files)
+    }
+
+    fn get(path: &str) -> HttpRequest {
+        HttpRequest::new("GET", path)
+    }
+
+    #[test]
+    fn test_serves_files_with_content_type_and_index() {
+        let (_dir, files) = site();
+        let response = files.respond(&get("/docs/guide.txt"), "/docs/guide.txt");
+        assert_eq!(response.status, HttpStatus::Ok);
+        assert_eq!(
+            response.header("content-type"),
+            Some("text/plain; charset=utf-8")
+        );
+        assert_eq!(response.header("Content-Length"), Some("26"));
+        assert_eq!(response.header("Accept-Ranges"), Some("bytes"));
+        let response = response.into_http_response().unwrap();
+        assert_eq!(response.body, "abcdefghijklmnopqrstuvwxyz");
+
+        let index = files.respond(&get("/"), "/").into_http_response().unwrap();
+        assert_eq!(index.body, "<h1>home</h1>");
+        assert_eq!(index.headers["Content-Type"], "text/html; charset=utf-8");
+        assert_eq!(
+            files.respond(&get("/docs"), "/docs").status,
+            HttpStatus::NotFound
+        );
+        assert_eq!(
+            files.respond(&get("/nope.txt"), "/nope.txt").status,
+            HttpStatus::NotFound
+        );
+
+        let post = HttpRequest::new("POST", "/index.html");
+        let response = files.respond(&post, "/index.html");
+        assert_eq!(response.status, HttpStatus::MethodNotAllowed);
+        assert_eq!(response.header("Allow"), Some("GET, HEAD"));
+
+        let head = files.respond(&HttpRequest::new("HEAD", "/index.html"), "/index.html");
+        assert_eq!(head.header("Content-Length"), Some("13"));
+        assert_eq!(head.body_len(), 0);
+    }
+
+    #[test]
+    fn test_path_traversal_is_rejected() {
+        let (dir, files) = site();
+        for path in [
+            "/../secret.txt",
+            "/docs/../../secret.txt",
+            "/%2e%2e/secret.txt",
+            "/..%2fsecret.txt",
+            "/docs%2f..%2f..%2fsecret.txt",
+            "/..\\secret.txt",
+            "/.env",
+            "/%2eenv",
+        ] {
+            assert_eq!(files.resolve(path), None, "{path}");
+            assert_eq!(
+                files.respond(&get(path), path).status,
+                HttpStatus::NotFound,
+                "{path}"
+            );
+        }
+        assert!(files.resolve("/docs/./guide.txt").is_some());
+
+        #[cfg(unix)]
+        {
+            std::os::unix::fs::symlink(
+                dir.path().join("secret.txt"),
+                files.root().join("link.txt"),
+            )
+            .unwrap();
+            assert_eq!(files.resolve("/link.txt"), None);
+        }
+    }
+
+    #[test]
+    fn test_if_none_match_returns_304() {
+        let (_dir, files) = site();
+        let first = files.respond(&get("/index.html"), "/index.html");
+        let etag = first.header("ETag").unwrap().to_string();
+        assert!(etag.starts_with('"') && etag.ends_with('"'));
+
+        for tags in [
+            etag.clone(),
+            format!("W/{etag}"),
+            format!("\"other\", {etag}"),
+            "*".to_string(),
+        ] {
+            let request = get("/index.html").with_header("If-None-Match", &tags);
+            let response = files.respond(&request, "/index.html");
+            assert_eq!(response.status, HttpStatus::NotModified, "{tags}");
+            assert_eq!(response.header("ETag"), Some(etag.as_str()));
+            assert_eq!(response.body_len(), 0);
+            assert_eq!(response.into_http_response().unwrap().body, "");
+        }
+
+        let stale = get("/index.html").with_header("If-None-Match", "\"stale\"");
+        assert_eq!(files.respond(&stale, "/index.html").status, HttpStatus::Ok);
+
+        // เขียนไฟล์ใหม่ด้วยขนาดต่างกัน → ETag เปลี่ยน ต้องได้ 200 พร้อมเนื้อหาใหม่
+        fs::write(files.root().join("index.html"), "<h1>updated</h1>").unwrap();
+        let request = get("/index.html").with_header("If-None-Match", &etag);
+        let response = files.respond(&request, "/index.html");
+        assert_eq!(response.status, HttpStatus::Ok);
+        assert_eq!(
+            response.into_http_response().unwrap().body,
+            "<h1>updated</h1>"
+        );
+    }
+
+    #[test]
+    fn test_range_requests_return_206_or_416() {
+        let (_dir, files) = site();
+        let ranged = |range: &str| {
+            let request = get("/docs/guide.txt").with_header("Range", range);
+            files.respond(&request, "/docs/guide.txt")
+        };
+
+        let response = ranged("bytes=0-4");
+        assert_eq!(response.status, HttpStatus::PartialContent);
+        assert_eq!(response.header("Content-Range"), Some("bytes 0-4/26"));
+        assert_eq!(response.header("Content-Length"), Some("5"));
+        assert_eq!(response.into_http_response().unwrap().body, "abcde");
+
+        for (range, expected, content_range) in [
+            ("bytes=20-", "uvwxyz", "bytes 20-25/26"),
+            ("bytes=-3", "xyz", "bytes 23-25/26"),
+            ("bytes=24-1000", "yz", "bytes 24-25/26"),
+            ("bytes=-100", "abcdefghijklmnopqrstuvwxyz", "bytes 0-25/26"),
+        ] {
+            let response = ranged(range);
+            assert_eq!(response.status, HttpStatus::PartialContent, "{range}");
+            assert_eq!(
+                response.header("Content-Range"),
+                Some(content_range),
+                "{range}"
+            );
+            assert_eq!(
+                response.into_http_response().unwrap().body,
+                expected,
+                "{range}"
+            );
+        }
+
+        for range in ["bytes=26-", "bytes=100-200", "bytes=-0"] {
+            let response = ranged(range);
+            assert_eq!(response.status, HttpStatus::RangeNotSatisfiable, "{range}");
+            assert_eq!(response.header("Content-Range"), Some("bytes */26"));
+            assert_eq!(response.body_len(), 0);
+        }
+
+        // รูปแบบที่ไม่รองรับ → เพิกเฉยแล้วส่งทั้งไฟล์
+        for range in ["items=0-4", "bytes=0-1,4-5", "bytes=5-2", "bytes=abc"] {
+            assert_eq!(ranged(range).status, HttpStatus::Ok, "{range}");
+        }
+
+        // If-Range ที่ไม่ตรงกับ ETag ปัจจุบัน → ส่งทั้งไฟล์
+        let request = get("/docs/guide.txt")
+            .with_header("Range", "bytes=0-4")
+            .with_header("If-Range", "\"old\"");
+        let response = files.respond(&request, "/docs/guide.txt");
+        assert_eq!(response.status, HttpStatus::Ok);
+        assert_eq!(response.body_len(), 26);
+    }
+
+    #[test]
+    fn test_large_file_is_streamed_in_chunks() {
+        let (_dir, files) = site();
+        let content: Vec<u8> = (0..100_000_u32).map(|i| (i % 251) as u8).collect();
+        fs::write(files.root().join("blob.bin"), &content).unwrap();
+        let files = files.with_chunk_size(4096);
+
+        let response = files.respond(&get("/blob.bin"), "/blob.bin");
+        assert_eq!(
+            response.header("Content-Type"),
+            Some("application/octet-stream")
+        );
+        let mut chunks = Vec::new();
+        let mut sent = Vec::new();
+        response
+            .write_body(&mut sent, |total| chunks.push(total))
+            .unwrap();
+        assert_eq!(sent, content);
+        assert_eq!(chunks.len(), 100_000_usize.div_ceil(4096));
+        assert!(chunks.windows(2).all(|pair| pair[1] - pair[0] <= 4096));
+
+        let request = get("/blob.bin").with_header("Range", "bytes=50000-59999");
+        let mut raw = Vec::new();
+        files
+            .respond(&request, "/blob.bin")
+            .write_to(&mut raw)
+            .unwrap();
+        let split = raw
+            .windows(4)
+            .position(|window| window == b"\r\n\r\n")
+            .unwrap();
+        let head = String::from_utf8_lossy(&raw[..split]);
+        assert!(head.starts_with("HTTP/1.1 206 Partial Content\r\n"));
+        assert!(head.contains("Content-Range: bytes 50000-59999/100000"));
+        assert_eq!(&raw[split + 4..], &content[50_000..60_000]);
+    }
+
+    #[test]
+    fn test_handler_with_wildcard_route() {
+        let (_dir, files) = site();
+        let mut server = WebServer::new();
+        server.route("GET /assets/*", files.handler("/assets"));
+
+        let response = server.handle_request(&get("/assets/docs/guide.txt?v=3"));
+        assert_eq!(response.status, HttpStatus::Ok);
+        assert_eq!(response.body, "abcdefghijklmnopqrstuvwxyz");
+        assert_eq!(
+            server.handle_request(&get("/assets/")).body,
+            "<h1>home</h1>"
+        );
+        assert_eq!(
+            server.handle_request(&get("/assets/../secret.txt")).status,
+            HttpStatus::NotFound
+        );
+        assert_eq!(
+            server.handle_request(&get("/assetsx/index.html")).status,
+            HttpStatus::NotFound
+        );
+    }
+}
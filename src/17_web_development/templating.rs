@@ -4,6 +4,7 @@
 
 use std::collections::HashMap;
 use super::{HttpResponse, HttpStatus};
+use crate::security::sanitization::escape_html;
 
 /// 🎨 Template Engine - เครื่องมือเทมเพลต
 pub struct TemplateEngine {
@@ -102,8 +103,15 @@ impl TemplateEngine {
     fn process_variables(&self, template: &str, context: &TemplateContext) -> Result<String, String> {
         let mut result = template.to_string();
         
-        // Simple variable replacement: {{variable}}
+        // Simple variable replacement: {{variable}} ถูก escape เป็น HTML เสมอ
+        // ส่วน {{{variable}}} ใส่ตามตัว - ใช้กับ HTML ที่เชื่อถือได้เท่านั้น (เช่น content ใน layout)
         while let Some(start) = result.find("{{") {
+            if result[start..].starts_with("{{{") && let Some(end) = result[start..].find("}}}") {
+                let end = start + end;
+                let value = context.get(result[start + 3..end].trim()).unwrap_or_default();
+                result = result[..start].to_string() + &value + &result[end + 3..];
+                continue;
+            }
             if let Some(end) = result[start..].find("}}") {
                 let end = start + end;
                 let var_expr = &result[start + 2..end].trim();
@@ -118,7 +126,7 @@ impl TemplateEngine {
                     break;
                 }
                 
-                let value = context.get(var_expr).unwrap_or_default();
+                let value = escape_html(&context.get(var_expr).unwrap_or_default());
                 result = result[..start].to_string() + &value + &result[end + 2..];
             } else {
                 break;
@@ -250,7 +258,7 @@ impl TemplateEngine {
                             .map(|arg| context.get(arg).unwrap_or_else(|| (*arg).to_string()))
                             .collect();
                         
-                        let replacement = helper(&args);
+                        let replacement = escape_html(&helper(&args));
                         result = result[..start].to_string() + &replacement + &result[end + 2..];
                         // Continue processing from the beginning to handle nested helpers
                         continue;
@@ -470,7 +478,7 @@ impl TemplateResponse {
         </header>
         
         <main>
-            {{content}}
+            {{{content}}}
         </main>
         
         <footer>
@@ -4,12 +4,19 @@
 
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
+use std::time::Instant;
+use crate::logger;
+use crate::metrics::{self, Registry};
 use super::{HttpRequest, HttpResponse, HttpStatus};
 
+/// 🛤️ handler ของ route หนึ่ง
+type Handler = dyn Fn(&HttpRequest) -> HttpResponse + Send + Sync;
+
 /// 🌐 Simple Web Server - เซิร์ฟเวอร์เว็บแบบง่าย
 pub struct WebServer {
-    routes: HashMap<String, Box<dyn Fn(&HttpRequest) -> HttpResponse + Send + Sync>>,
+    routes: HashMap<String, Box<Handler>>,
     middleware: Vec<Box<dyn Fn(&HttpRequest, &HttpResponse) -> HttpResponse + Send + Sync>>,
+    metrics: Option<Registry>,
 }
 
 impl Default for WebServer {
@@ -23,10 +30,14 @@ impl WebServer {
         Self {
             routes: HashMap::new(),
             middleware: Vec::new(),
+            metrics: None,
         }
     }
     
     /// 🛤️ เพิ่ม route ใหม่ - เพิ่มเส้นทางการเข้าถึง
+    /// 
+    /// path ที่ลงท้ายด้วย `/*` (เช่น `"GET /static/*"`) จับทุก path ใต้ prefix นั้น
+    /// ถ้าไม่มี route ที่ตรงตัว - prefix ที่ยาวที่สุดชนะ
     pub fn route<F>(&mut self, path: &str, handler: F)
     where
         F: Fn(&HttpRequest) -> HttpResponse + Send + Sync + 'static,
@@ -42,24 +53,78 @@ impl WebServer {
         self.middleware.push(Box::new(middleware));
     }
     
+    /// 📈 เปิด `GET /metrics` ให้ Prometheus มา scrape และเริ่มนับทุก request ลง `registry`
+    ///
+    /// ทุก request จะถูกบันทึกใน `http_requests_total{method,route,status}` และ
+    /// `http_request_duration_seconds{method,route}` โดย path ที่ไม่มี route ใช้ `route="unmatched"`
+    pub fn expose_metrics(&mut self, registry: &Registry) {
+        let scraped = registry.clone();
+        self.route("GET /metrics", move |_req| {
+            HttpResponse::new(HttpStatus::Ok)
+                .with_header("Content-Type", metrics::CONTENT_TYPE)
+                .with_body(&scraped.render())
+        });
+        self.metrics = Some(registry.clone());
+    }
+    
     /// 📨 จัดการ request - ประมวลผลคำขอ HTTP
     #[must_use] pub fn handle_request(&self, request: &HttpRequest) -> HttpResponse {
-        let route_key = format!("{} {}", request.method, request.path);
+        let started = self.metrics.is_some().then(Instant::now);
+        let matched = self.find_route(request);
+        let handler = matched.map(|(_, handler)| handler);
         
-        let mut response = if let Some(handler) = self.routes.get(&route_key) {
-            handler(request)
-        } else {
-            HttpResponse::new(HttpStatus::NotFound)
-                .with_body("Route not found")
-        };
+        let mut response = handler.map_or_else(
+            || HttpResponse::new(HttpStatus::NotFound).with_body("Route not found"),
+            |handler| handler(request),
+        );
         
         // Apply middleware
         for middleware in &self.middleware {
             response = middleware(request, &response);
         }
         
+        if let (Some(registry), Some(started)) = (&self.metrics, started) {
+            // wildcard route ใช้ pattern เป็น label เพื่อไม่ให้ label บานตามจำนวนไฟล์
+            let route = matched.map_or("unmatched", |(pattern, _)| pattern);
+            let status = (response.status as u16).to_string();
+            registry
+                .counter(
+                    "http_requests_total",
+                    "Total HTTP requests handled",
+                    &[("method", &request.method), ("route", route), ("status", &status)],
+                )
+                .inc();
+            registry
+                .histogram(
+                    "http_request_duration_seconds",
+                    "Time spent handling HTTP requests",
+                    &[("method", &request.method), ("route", route)],
+                )
+                .observe_duration(started.elapsed());
+        }
+        
         response
     }
+    
+    /// หา handler ของ request - คืน path ของ route (ใช้เป็น label ของ metrics) คู่กับ handler
+    fn find_route(&self, request: &HttpRequest) -> Option<(&str, &Handler)> {
+        // query string ไม่ใช่ส่วนของ route - `/poll?since=3` ต้องเข้า `GET /poll`
+        let path = request.path.split(['?', '#']).next().unwrap_or_default();
+        let route_key = format!("{} {path}", request.method);
+        if let Some((key, handler)) = self.routes.get_key_value(&route_key) {
+            return Some((&key[request.method.len() + 1..], handler.as_ref()));
+        }
+        
+        self.routes.iter()
+            .filter_map(|(key, handler)| {
+                let (method, pattern) = key.split_once(' ')?;
+                let prefix = pattern.strip_suffix("/*")?;
+                let matches = method == request.method
+                    && path.strip_prefix(prefix).is_some_and(|rest| rest.is_empty() || rest.starts_with('/'));
+                matches.then_some((pattern, handler.as_ref()))
+            })
+            .max_by_key(|(pattern, _)| pattern.len())
+    }
 }
 
 /// 📁 Static File Server - เซิร์ฟเวอร์ไฟล์คงที่
@@ -253,7 +318,7 @@ pub fn demonstrate_basic_server() {
     
     // 📝 เพิ่ม logging middleware สำหรับเวิร์คช็อป
     server.use_middleware(|req, res| {
-        println!("🔍 [Workshop Log] [{}] {} - {}", req.method, req.path, res.status.as_str());
+        logger::info!(method = req.method, path = req.path; "{}", res.status.as_str());
         res.clone()
     });
     
@@ -324,6 +389,25 @@ mod tests {
         assert_eq!(response.body, "Test response");
     }
 
+    #[test]
+    fn test_metrics_endpoint_counts_requests() {
+        let registry = Registry::new();
+        let mut server = WebServer::new();
+        server.route("GET /test", |_| HttpResponse::new(HttpStatus::Ok));
+        server.expose_metrics(&registry);
+
+        let _ = server.handle_request(&HttpRequest::new("GET", "/test"));
+        let _ = server.handle_request(&HttpRequest::new("GET", "/test"));
+        let _ = server.handle_request(&HttpRequest::new("GET", "/missing"));
+        let scrape = server.handle_request(&HttpRequest::new("GET", "/metrics"));
+
+        assert_eq!(scrape.status, HttpStatus::Ok);
+        assert_eq!(scrape.headers.get("Content-Type").map(String::as_str), Some(metrics::CONTENT_TYPE));
+        assert!(scrape.body.contains(r#"http_requests_total{method="GET",route="/test",status="200"} 2"#));
+        assert!(scrape.body.contains(r#"http_requests_total{method="GET",route="unmatched",status="404"} 1"#));
+        assert!(scrape.body.contains(r#"http_request_duration_seconds_count{method="GET",route="/test"} 2"#));
+    }
+
     #[test]
     fn test_static_file_server() {
         let server = StaticFileServer::new("./public");
@@ -0,0 +1,572 @@
+//! 🛠️ Custom Protocols - ออกแบบ binary RPC ของเราเองบน TCP (ไม่ต้องพึ่ง gRPC)
+//!
+//! TCP ส่งมาเป็น stream ของไบต์ ไม่มีขอบเขตของข้อความ เราจึงต้องออกแบบ "ซอง" เอง:
+//!
+//! ```text
+//! ┌────────┬─────────┬────────────────┬──────────────────────────┐
+//! │ "RP"   │ version │ length (u32 BE)│ payload (bincode Message)│
+//! │ 2 bytes│ 1 byte  │ 4 bytes        │ length bytes             │
+//! └────────┴─────────┴────────────────┴──────────────────────────┘
+//! ```
+//!
+//! - 🔖 magic - รู้ทันทีว่าอีกฝั่งพูดโปรโตคอลเดียวกัน (ไม่ใช่ HTTP ที่หลงมา)
+//! - 🏷️ version - server ตอบ `UnsupportedVersion` แทนการเดาความหมายของไบต์
+//! - 📏 length - อ่านครบทั้งข้อความได้ด้วย `read_exact` และจำกัดขนาดกัน client จอง memory มหาศาล
+//!
+//! ข้างในซองคือ `Message` ที่ serialize ด้วย bincode - method ถูกระบุด้วยเลข (`RpcMethod::ID`)
+//! `Dispatcher` จับคู่เลข method กับ handler ส่วน `RpcClient` มี stub แบบ type-safe ให้เรียก
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::io::{self, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread::{self, JoinHandle};
+
+/// ไบต์แรกของทุก frame
+pub const MAGIC: [u8; 2] = *b"RP";
+/// เวอร์ชันของโปรโตคอลที่โค้ดนี้พูด
+pub const PROTOCOL_VERSION: u8 = 1;
+/// ขนาด payload สูงสุดต่อ frame (1 MiB)
+pub const MAX_FRAME_LEN: u32 = 1 << 20;
+
+const HEADER_LEN: usize = 7;
+
+/// 📨 ข้อความที่วิ่งอยู่ในซอง
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Message {
+    /// เรียก method `method` ด้วยอาร์กิวเมนต์ที่ serialize แล้ว
+    Request {
+        id: u64,
+        method: u16,
+        payload: Vec<u8>,
+    },
+    /// ผลลัพธ์ของ request หมายเลข `id`
+    Response {
+        id: u64,
+        result: Result<Vec<u8>, RpcError>,
+    },
+}
+
+/// ❌ error ของ RPC - ส่งข้ามเครือข่ายได้ จึงเก็บข้อความเป็น `String`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RpcError {
+    /// server ไม่รู้จัก method หมายเลขนี้
+    UnknownMethod(u16),
+    /// อาร์กิวเมนต์ decode ไม่ได้
+    BadRequest(String),
+    /// handler ทำงานแล้วคืน error
+    Handler(String),
+    /// อีกฝั่งพูดโปรโตคอลคนละเวอร์ชัน
+    UnsupportedVersion { expected: u8, got: u8 },
+    /// frame หรือข้อความผิดรูปแบบ
+    Protocol(String),
+    /// การเชื่อมต่อมีปัญหา
+    Io(String),
+}
+
+impl fmt::Display for RpcError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownMethod(id) => write!(f, "unknown method {id}"),
+            Self::BadRequest(reason) => write!(f, "bad request: {reason}"),
+            Self::Handler(reason) => write!(f, "handler failed: {reason}"),
+            Self::UnsupportedVersion { expected, got } => {
+                write!(
+                    f,
+                    "unsupported protocol version {got} (expected {expected})"
+                )
+            }
+            Self::Protocol(reason) => write!(f, "protocol error: {reason}"),
+            Self::Io(reason) => write!(f, "io error: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for RpcError {}
+
+impl From<io::Error> for RpcError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error.to_string())
+    }
+}
+
+/// 📦 เขียน payload 1 frame พร้อม header
+///
+/// # Errors
+///
+/// คืน error ถ้า payload ใหญ่เกิน `MAX_FRAME_LEN` หรือเขียนลง `writer` ไม่สำเร็จ
+pub fn write_frame(writer: &mut impl Write, version: u8, payload: &[u8]) -> io::Result<()> {
+    let len = u32::try_from(payload.len())
+        .ok()
+        .filter(|&len| len <= MAX_FRAME_LEN)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "frame too large"))?;
+
+    let mut header = [0; HEADER_LEN];
+    header[..2].copy_from_slice(&MAGIC);
+    header[2] = version;
+    header[3..].copy_from_slice(&len.to_be_bytes());
+    writer.write_all(&header)?;
+    writer.write_all(payload)?;
+    writer.flush()
+}
+
+/// 📬 อ่าน 1 frame คืน `(version, payload)`
+///
+/// # Errors
+///
+/// คืน `InvalidData` ถ้า magic ไม่ตรงหรือ length เกิน `MAX_FRAME_LEN`
+/// และ `UnexpectedEof` ถ้าอีกฝั่งปิดการเชื่อมต่อกลาง frame
+pub fn read_frame(reader: &mut impl Read) -> io::Result<(u8, Vec<u8>)> {
+    let mut header = [0; HEADER_LEN];
+    reader.read_exact(&mut header)?;
+    if header[..2] != MAGIC {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "bad magic"));
+    }
+
+    let len = u32::from_be_bytes([header[3], header[4], header[5], header[6]]);
+    if len > MAX_FRAME_LEN {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("frame of {len} bytes exceeds limit"),
+        ));
+    }
+
+    let mut payload = vec![0; len as usize];
+    reader.read_exact(&mut payload)?;
+    Ok((header[2], payload))
+}
+
+fn send_message(stream: &mut impl Write, message: &Message) -> Result<(), RpcError> {
+    let payload = bincode::serialize(message).map_err(|e| RpcError::Protocol(e.to_string()))?;
+    write_frame(stream, PROTOCOL_VERSION, &payload)?;
+    Ok(())
+}
+
+/// 📋 คำอธิบาย method 1 ตัว: เลขประจำตัว + ชนิดของอาร์กิวเมนต์และผลลัพธ์
+///
+/// ทั้ง server และ client ใช้ type เดียวกัน จึงไม่มีทางส่งอาร์กิวเมนต์ผิดชนิด
+pub trait RpcMethod {
+    /// เลข method ที่ส่งบนสาย - ห้ามเปลี่ยนหลังปล่อยใช้งานแล้ว
+    const ID: u16;
+    /// ชนิดของอาร์กิวเมนต์
+    type Request: Serialize + DeserializeOwned;
+    /// ชนิดของผลลัพธ์
+    type Response: Serialize + DeserializeOwned;
+}
+
+/// ➕ บวกเลขสองตัว
+pub struct Add;
+
+impl RpcMethod for Add {
+    const ID: u16 = 1;
+    type Request = (i64, i64);
+    type Response = i64;
+}
+
+/// ➗ หารเลข (หารด้วย 0 = handler error)
+pub struct Divide;
+
+impl RpcMethod for Divide {
+    const ID: u16 = 2;
+    type Request = (i64, i64);
+    type Response = i64;
+}
+
+/// 🔁 ส่งข้อความกลับเป็นตัวพิมพ์ใหญ่
+pub struct Shout;
+
+impl RpcMethod for Shout {
+    const ID: u16 = 3;
+    type Request = String;
+    type Response = String;
+}
+
+type BoxedHandler = Box<dyn Fn(&[u8]) -> Result<Vec<u8>, RpcError> + Send + Sync>;
+
+/// 🚏 ตารางจับคู่เลข method → handler
+#[derive(Default)]
+pub struct Dispatcher {
+    handlers: HashMap<u16, BoxedHandler>,
+}
+
+impl Dispatcher {
+    /// dispatcher ที่ยังไม่มี method
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// dispatcher ที่ลงทะเบียน `Add`, `Divide` และ `Shout` ไว้แล้ว
+    #[must_use]
+    pub fn with_example_methods() -> Self {
+        let mut dispatcher = Self::new();
+        dispatcher.register::<Add>(|(a, b)| a.checked_add(b).ok_or_else(|| "overflow".to_string()));
+        dispatcher.register::<Divide>(|(a, b)| {
+            a.checked_div(b)
+                .ok_or_else(|| "division by zero".to_string())
+        });
+        dispatcher.register::<Shout>(|text| Ok(text.to_uppercase()));
+        dispatcher
+    }
+
+    /// ลงทะเบียน handler ของ method `M` - decode/encode ให้อัตโนมัติ
+    pub fn register<M: RpcMethod>(
+        &mut self,
+        handler: impl Fn(M::Request) -> Result<M::Response, String> + Send + Sync + 'static,
+    ) {
+        let handler = move |payload: &[u8]| {
+            let request =
+                bincode::deserialize(payload).map_err(|e| RpcError::BadRequest(e.to_string()))?;
+            let response = handler(request).map_err(RpcError::Handler)?;
+            bincode::serialize(&response).map_err(|e| RpcError::Protocol(e.to_string()))
+        };
+        self.handlers.insert(M::ID, Box::new(handler));
+    }
+
+    /// เรียก handler ของ `method` ด้วย payload ที่ยัง serialize อยู่
+    ///
+    /// # Errors
+    ///
+    /// คืน `UnknownMethod` ถ้าไม่มี handler หรือ error จาก handler เอง
+    pub fn dispatch(&self, method: u16, payload: &[u8]) -> Result<Vec<u8>, RpcError> {
+        let handler = self
+            .handlers
+            .get(&method)
+            .ok_or(RpcError::UnknownMethod(method))?;
+        handler(payload)
+    }
+}
+
+/// 🖥️ RPC server ที่รันอยู่เบื้องหลัง - thread ละ 1 connection
+pub struct RpcServer {
+    addr: SocketAddr,
+    stop: Arc<AtomicBool>,
+    accept_thread: Option<JoinHandle<()>>,
+}
+
+impl RpcServer {
+    /// bind แล้วเริ่มรับ connection ทันที (ใช้ port 0 เพื่อให้ OS เลือก port ให้)
+    ///
+    /// # Errors
+    ///
+    /// คืน error ถ้า bind ไม่สำเร็จ
+    pub fn spawn(addr: impl ToSocketAddrs, dispatcher: Dispatcher) -> io::Result<Self> {
+        let listener = TcpListener::bind(addr)?;
+        let addr = listener.local_addr()?;
+        let stop = Arc::new(AtomicBool::new(false));
+        let dispatcher = Arc::new(dispatcher);
+
+        let accept_thread = {
+            let stop = Arc::clone(&stop);
+            thread::spawn(move || {
+                for stream in listener.incoming() {
+                    if stop.load(Ordering::Acquire) {
+                        break;
+                    }
+                    let Ok(stream) = stream else { continue };
+                    let dispatcher = Arc::clone(&dispatcher);
+                    thread::spawn(move || serve_connection(stream, &dispatcher));
+                }
+            })
+        };
+
+        Ok(Self {
+            addr,
+            stop,
+            accept_thread: Some(accept_thread),
+        })
+    }
+
+    /// address ที่ server ฟังอยู่
+    #[must_use]
+    pub const fn local_addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// หยุดรับ connection ใหม่ (connection ที่เปิดอยู่ทำงานต่อจนกว่า client จะปิด)
+    pub fn shutdown(mut self) {
+        self.stop_accepting();
+    }
+
+    fn stop_accepting(&mut self) {
+        let Some(accept_thread) = self.accept_thread.take() else {
+            return;
+        };
+        self.stop.store(true, Ordering::Release);
+        // ปลุก `accept()` ที่ block อยู่ด้วย connection เปล่า
+        let _ = TcpStream::connect(self.addr);
+        let _ = accept_thread.join();
+    }
+}
+
+impl Drop for RpcServer {
+    fn drop(&mut self) {
+        self.stop_accepting();
+    }
+}
+
+fn serve_connection(mut stream: TcpStream, dispatcher: &Dispatcher) {
+    // จบเมื่อ client ปิด connection (EOF) หรือส่งของที่ไม่ใช่โปรโตคอลเรามา
+    while let Ok((version, payload)) = read_frame(&mut stream) {
+        let response = if version == PROTOCOL_VERSION {
+            match bincode::deserialize(&payload) {
+                Ok(Message::Request {
+                    id,
+                    method,
+                    payload,
+                }) => Message::Response {
+                    id,
+                    result: dispatcher.dispatch(method, &payload),
+                },
+                Ok(Message::Response { id, .. }) => Message::Response {
+                    id,
+                    result: Err(RpcError::Protocol("server only accepts requests".into())),
+                },
+                Err(e) => Message::Response {
+                    id: 0,
+                    result: Err(RpcError::Protocol(e.to_string())),
+                },
+            }
+        } else {
+            // ไม่รู้ว่า payload เวอร์ชันอื่นหน้าตาเป็นอย่างไร - บอกเวอร์ชันที่รองรับแล้วปิด
+            let _ = send_message(
+                &mut stream,
+                &Message::Response {
+                    id: 0,
+                    result: Err(RpcError::UnsupportedVersion {
+                        expected: PROTOCOL_VERSION,
+                        got: version,
+                    }),
+                },
+            );
+            return;
+        };
+
+        if send_message(&mut stream, &response).is_err() {
+            return;
+        }
+    }
+}
+
+/// 📞 client ที่ต่อค้างไว้ 1 connection แล้วเรียกได้หลายครั้ง
+pub struct RpcClient {
+    stream: TcpStream,
+    next_id: u64,
+}
+
+impl RpcClient {
+    /// เชื่อมต่อไปยัง server
+    ///
+    /// # Errors
+    ///
+    /// คืน `RpcError::Io` ถ้าเชื่อมต่อไม่ได้
+    pub fn connect(addr: impl ToSocketAddrs) -> Result<Self, RpcError> {
+        let stream = TcpStream::connect(addr)?;
+        stream.set_nodelay(true)?;
+        Ok(Self { stream, next_id: 1 })
+    }
+
+    /// เรียก method `M` แบบ generic - stub ด้านล่างทั้งหมดเรียกผ่านตัวนี้
+    ///
+    /// # Errors
+    ///
+    /// คืน error จาก server (`UnknownMethod`, `Handler`, ...) หรือปัญหาของการเชื่อมต่อ
+    pub fn call<M: RpcMethod>(&mut self, request: &M::Request) -> Result<M::Response, RpcError> {
+        let payload =
+            bincode::serialize(request).map_err(|e| RpcError::BadRequest(e.to_string()))?;
+        let id = self.next_id;
+        self.next_id += 1;
+        send_message(
+            &mut self.stream,
+            &Message::Request {
+                id,
+                method: M::ID,
+                payload,
+            },
+        )?;
+
+        let (_, reply) = read_frame(&mut self.stream)?;
+        match bincode::deserialize(&reply).map_err(|e| RpcError::Protocol(e.to_string()))? {
+            Message::Response {
+                id: reply_id,
+                result,
+            } if reply_id == id => {
+                let bytes = result?;
+                bincode::deserialize(&bytes).map_err(|e| RpcError::Protocol(e.to_string()))
+            }
+            // id 0 = server ปฏิเสธทั้ง connection (เช่น version ไม่ตรง)
+            Message::Response {
+                id: 0,
+                result: Err(error),
+            } => Err(error),
+            other => Err(RpcError::Protocol(format!("unexpected reply: {other:?}"))),
+        }
+    }
+
+    /// stub ของ `Add`
+    ///
+    /// # Errors
+    ///
+    /// คืน `RpcError::Handler` ถ้าผลบวก overflow
+    pub fn add(&mut self, a: i64, b: i64) -> Result<i64, RpcError> {
+        self.call::<Add>(&(a, b))
+    }
+
+    /// stub ของ `Divide`
+    ///
+    /// # Errors
+    ///
+    /// คืน `RpcError::Handler` ถ้าหารด้วย 0
+    pub fn divide(&mut self, a: i64, b: i64) -> Result<i64, RpcError> {
+        self.call::<Divide>(&(a, b))
+    }
+
+    /// stub ของ `Shout`
+    ///
+    /// # Errors
+    ///
+    /// คืน error ถ้าการเชื่อมต่อมีปัญหา
+    pub fn shout(&mut self, text: &str) -> Result<String, RpcError> {
+        self.call::<Shout>(&text.to_string())
+    }
+}
+
+/// 🎯 สาธิต binary RPC: server + client บน localhost
+pub fn demonstrate_custom_protocols() {
+    println!("🛠️ Binary RPC over TCP (magic + version + length + bincode)");
+
+    let server = match RpcServer::spawn("127.0.0.1:0", Dispatcher::with_example_methods()) {
+        Ok(server) => server,
+        Err(e) => {
+            println!("   ❌ เปิด server ไม่ได้: {e}");
+            return;
+        }
+    };
+    println!("   🖥️ server ฟังอยู่ที่ {}", server.local_addr());
+
+    let mut client = match RpcClient::connect(server.local_addr()) {
+        Ok(client) => client,
+        Err(e) => {
+            println!("   ❌ เชื่อมต่อไม่ได้: {e}");
+            return;
+        }
+    };
+
+    println!("   ➕ add(40, 2)        = {:?}", client.add(40, 2));
+    println!("   ➗ divide(7, 0)      = {:?}", client.divide(7, 0));
+    println!("   🔁 shout(\"สวัสดี rpc\") = {:?}", client.shout("สวัสดี rpc"));
+    println!(
+        "   ❓ method 99         = {:?}",
+        client.call::<UnknownDemoMethod>(&())
+    );
+
+    let request = Message::Request {
+        id: 1,
+        method: Add::ID,
+        payload: bincode::serialize(&(40_i64, 2_i64)).unwrap_or_default(),
+    };
+    let encoded = bincode::serialize(&request).unwrap_or_default();
+    println!(
+        "   📏 request add(40, 2) ใช้ {} bytes (header {HEADER_LEN} + payload {})",
+        HEADER_LEN + encoded.len(),
+        encoded.len()
+    );
+
+    server.shutdown();
+}
+
+/// method ที่ server ไม่ได้ลงทะเบียนไว้ (ใช้ในเดโม)
+struct UnknownDemoMethod;
+
+impl RpcMethod for UnknownDemoMethod {
+    const ID: u16 = 99;
+    type Request = ();
+    type Response = ();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_frame_round_trip_and_validation() {
+        let mut wire = Vec::new();
+        write_frame(&mut wire, PROTOCOL_VERSION, b"hello").unwrap();
+        assert_eq!(&wire[..3], b"RP\x01");
+        assert_eq!(
+            read_frame(&mut Cursor::new(&wire)).unwrap(),
+            (PROTOCOL_VERSION, b"hello".to_vec())
+        );
+
+        let mut bad_magic = wire.clone();
+        bad_magic[0] = b'X';
+        let error = read_frame(&mut Cursor::new(bad_magic)).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+
+        let mut huge = b"RP\x01".to_vec();
+        huge.extend_from_slice(&(MAX_FRAME_LEN + 1).to_be_bytes());
+        let error = read_frame(&mut Cursor::new(huge)).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+
+        let truncated = &wire[..wire.len() - 1];
+        let error = read_frame(&mut Cursor::new(truncated)).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn test_rpc_round_trip_over_localhost() {
+        let server = RpcServer::spawn("127.0.0.1:0", Dispatcher::with_example_methods()).unwrap();
+        let mut client = RpcClient::connect(server.local_addr()).unwrap();
+
+        assert_eq!(client.add(40, 2), Ok(42));
+        assert_eq!(client.shout("rpc"), Ok("RPC".to_string()));
+        assert_eq!(
+            client.divide(1, 0),
+            Err(RpcError::Handler("division by zero".to_string()))
+        );
+        assert_eq!(
+            client.call::<UnknownDemoMethod>(&()),
+            Err(RpcError::UnknownMethod(99))
+        );
+        // connection เดิมยังใช้ต่อได้หลัง error
+        assert_eq!(client.divide(9, 3), Ok(3));
+
+        // หลาย client พร้อมกัน
+        let mut other = RpcClient::connect(server.local_addr()).unwrap();
+        assert_eq!(
+            other.add(i64::MAX, 1),
+            Err(RpcError::Handler("overflow".into()))
+        );
+        server.shutdown();
+    }
+
+    #[test]
+    fn test_server_rejects_other_protocol_versions() {
+        let server = RpcServer::spawn("127.0.0.1:0", Dispatcher::with_example_methods()).unwrap();
+        let mut stream = TcpStream::connect(server.local_addr()).unwrap();
+
+        write_frame(&mut stream, PROTOCOL_VERSION + 1, b"from the future").unwrap();
+        let (version, reply) = read_frame(&mut stream).unwrap();
+        assert_eq!(version, PROTOCOL_VERSION);
+        assert_eq!(
+            bincode::deserialize::<Message>(&reply).unwrap(),
+            Message::Response {
+                id: 0,
+                result: Err(RpcError::UnsupportedVersion {
+                    expected: PROTOCOL_VERSION,
+                    got: PROTOCOL_VERSION + 1,
+                }),
+            }
+        );
+        // server ปิด connection หลังตอบ
+        assert_eq!(
+            read_frame(&mut stream).unwrap_err().kind(),
+            io::ErrorKind::UnexpectedEof
+        );
+    }
+}
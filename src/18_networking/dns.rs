@@ -0,0 +1,759 @@
+//! 🔎 DNS - encoder/decoder ของ DNS message ตาม RFC 1035 (wire format)
+//!
+//! DNS message ทุกอันมีหน้าตาเดียวกันทั้ง query และ response:
+//!
+//! ```text
+//! ┌────────────────────────────┐
+//! │ header (12 bytes)          │ id, flags, จำนวน record ในแต่ละ section
+//! ├────────────────────────────┤
+//! │ questions                  │ ถามอะไร: ชื่อ + type + class
+//! │ answers                    │ ┐
+//! │ authorities                │ ├ resource records: ชื่อ + type + class + TTL + data
+//! │ additionals                │ ┘
+//! └────────────────────────────┘
+//! ```
+//!
+//! ชื่อโดเมนเขียนเป็น label ที่มีความยาวนำหน้า (`3www7example3com0`) และเพื่อประหยัดที่
+//! response มักใช้ "compression pointer" (2 บิตบนเป็น `11`) ชี้กลับไปยังชื่อที่เคยเขียนแล้ว -
+//! decoder ต้องตามตัวชี้ให้ถูกและกันตัวชี้วนลูป ส่วน encoder ในไฟล์นี้เขียนชื่อเต็มเสมอ (ง่ายและถูกต้อง)
+//!
+//! 🌍 `resolve()` ส่ง query จริงผ่าน UDP - เดโมจะเรียกเฉพาะเมื่อตั้ง `LIVE_DNS=1`
+//! (หรือ `LIVE_DNS=<ip:port>` ของ DNS server) เพราะ tests และ CI อาจไม่มีอินเทอร์เน็ต
+
+use std::error::Error;
+use std::fmt;
+use std::net::{Ipv4Addr, Ipv6Addr, SocketAddr, UdpSocket};
+use std::time::Duration;
+
+/// ชื่อ environment variable ที่เปิดการ query DNS server จริงในเดโม
+pub const LIVE_DNS_ENV: &str = "LIVE_DNS";
+/// DNS server ที่ใช้เมื่อ `LIVE_DNS=1`
+pub const DEFAULT_DNS_SERVER: &str = "1.1.1.1:53";
+
+const MAX_LABEL_LEN: usize = 63;
+const MAX_NAME_LEN: usize = 255;
+/// ตามตัวชี้ได้ไม่เกินเท่านี้ครั้งต่อชื่อ - เกินนี้ถือว่าวนลูป
+const MAX_POINTER_JUMPS: usize = 16;
+/// class IN (Internet) - แทบทุก record ใช้ค่านี้
+pub const CLASS_IN: u16 = 1;
+
+/// ❌ error ระหว่าง encode/decode
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DnsError {
+    /// ข้อมูลหมดก่อนอ่านครบ (ต้องการอ่านถึง offset นี้)
+    Truncated(usize),
+    /// label ยาวเกิน 63 bytes หรือว่างกลางชื่อ
+    InvalidLabel(String),
+    /// ชื่อยาวเกิน 255 bytes เมื่อเขียนเป็น wire format
+    NameTooLong,
+    /// compression pointer ชี้ออกนอกแพ็กเก็ตหรือวนลูป
+    BadPointer(usize),
+    /// rdata ยาวไม่ตรงกับชนิดของ record
+    BadRecordLength { record_type: u16, len: usize },
+}
+
+impl fmt::Display for DnsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Truncated(offset) => write!(f, "message truncated at offset {offset}"),
+            Self::InvalidLabel(label) => write!(f, "invalid label {label:?}"),
+            Self::NameTooLong => write!(f, "domain name longer than {MAX_NAME_LEN} bytes"),
+            Self::BadPointer(offset) => write!(f, "bad compression pointer at offset {offset}"),
+            Self::BadRecordLength { record_type, len } => {
+                write!(
+                    f,
+                    "record type {record_type} cannot have {len} bytes of data"
+                )
+            }
+        }
+    }
+}
+
+impl Error for DnsError {}
+
+/// 📛 ชนิดของ record ที่รองรับ (ชนิดอื่นเก็บเป็นตัวเลขไว้)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RecordType {
+    /// IPv4 address
+    A,
+    /// ชื่อแฝงที่ชี้ไปยังชื่ออื่น
+    Cname,
+    /// IPv6 address
+    Aaaa,
+    /// ชนิดที่ไฟล์นี้ไม่รู้จัก
+    Other(u16),
+}
+
+impl RecordType {
+    /// เลขของชนิดบนสาย
+    #[must_use]
+    pub const fn code(self) -> u16 {
+        match self {
+            Self::A => 1,
+            Self::Cname => 5,
+            Self::Aaaa => 28,
+            Self::Other(code) => code,
+        }
+    }
+
+    /// แปลงจากเลขบนสาย
+    #[must_use]
+    pub const fn from_code(code: u16) -> Self {
+        match code {
+            1 => Self::A,
+            5 => Self::Cname,
+            28 => Self::Aaaa,
+            other => Self::Other(other),
+        }
+    }
+}
+
+/// 🚦 ผลลัพธ์ของ query (4 บิตล่างของ flags)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResponseCode {
+    /// สำเร็จ
+    NoError,
+    /// server อ่าน query ไม่เข้าใจ
+    FormatError,
+    /// server มีปัญหา
+    ServerFailure,
+    /// ไม่มีชื่อนี้ (NXDOMAIN)
+    NameError,
+    /// server ไม่รองรับ query แบบนี้
+    NotImplemented,
+    /// server ปฏิเสธ
+    Refused,
+    /// รหัสอื่น
+    Other(u8),
+}
+
+impl ResponseCode {
+    const fn code(self) -> u8 {
+        match self {
+            Self::NoError => 0,
+            Self::FormatError => 1,
+            Self::ServerFailure => 2,
+            Self::NameError => 3,
+            Self::NotImplemented => 4,
+            Self::Refused => 5,
+            Self::Other(code) => code & 0x0F,
+        }
+    }
+
+    const fn from_code(code: u8) -> Self {
+        match code {
+            0 => Self::NoError,
+            1 => Self::FormatError,
+            2 => Self::ServerFailure,
+            3 => Self::NameError,
+            4 => Self::NotImplemented,
+            5 => Self::Refused,
+            other => Self::Other(other),
+        }
+    }
+}
+
+/// 🚩 16 บิตของ flags ใน header แยกเป็น field
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(clippy::struct_excessive_bools)] // ตรงกับบิตใน RFC 1035 ทีละบิต
+pub struct Flags {
+    /// QR - `true` = response
+    pub response: bool,
+    /// OPCODE - 0 = query มาตรฐาน
+    pub opcode: u8,
+    /// AA - ตอบจาก server ที่เป็นเจ้าของโดเมน
+    pub authoritative: bool,
+    /// TC - response ถูกตัดเพราะยาวเกิน UDP (ต้องถามใหม่ผ่าน TCP)
+    pub truncated: bool,
+    /// RD - ขอให้ server ไล่ถามต่อให้จนได้คำตอบ
+    pub recursion_desired: bool,
+    /// RA - server ไล่ถามต่อให้ได้
+    pub recursion_available: bool,
+    /// RCODE
+    pub response_code: ResponseCode,
+}
+
+impl Flags {
+    /// flags ของ query มาตรฐานที่ขอ recursion
+    #[must_use]
+    pub const fn query() -> Self {
+        Self {
+            response: false,
+            opcode: 0,
+            authoritative: false,
+            truncated: false,
+            recursion_desired: true,
+            recursion_available: false,
+            response_code: ResponseCode::NoError,
+        }
+    }
+
+    /// รวมเป็น 16 บิต: `QR | OPCODE(4) | AA | TC | RD | RA | Z(3) | RCODE(4)`
+    #[must_use]
+    pub const fn to_bits(self) -> u16 {
+        (self.response as u16) << 15
+            | ((self.opcode & 0x0F) as u16) << 11
+            | (self.authoritative as u16) << 10
+            | (self.truncated as u16) << 9
+            | (self.recursion_desired as u16) << 8
+            | (self.recursion_available as u16) << 7
+            | self.response_code.code() as u16
+    }
+
+    /// แยก 16 บิตออกเป็น field
+    #[must_use]
+    pub const fn from_bits(bits: u16) -> Self {
+        Self {
+            response: bits & (1 << 15) != 0,
+            opcode: ((bits >> 11) & 0x0F) as u8,
+            authoritative: bits & (1 << 10) != 0,
+            truncated: bits & (1 << 9) != 0,
+            recursion_desired: bits & (1 << 8) != 0,
+            recursion_available: bits & (1 << 7) != 0,
+            response_code: ResponseCode::from_code((bits & 0x0F) as u8),
+        }
+    }
+}
+
+/// ❓ คำถาม 1 ข้อ
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Question {
+    /// ชื่อโดเมน เช่น `"example.com"`
+    pub name: String,
+    /// ชนิดของ record ที่ถาม
+    pub record_type: RecordType,
+    /// class (ปกติคือ `CLASS_IN`)
+    pub class: u16,
+}
+
+/// 📦 ข้อมูลของ record ตามชนิด
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RecordData {
+    /// IPv4 address
+    A(Ipv4Addr),
+    /// ชื่อจริงที่ชื่อนี้ชี้ไป
+    Cname(String),
+    /// IPv6 address
+    Aaaa(Ipv6Addr),
+    /// ชนิดที่ไม่รู้จัก - เก็บไบต์ดิบไว้
+    Other { record_type: u16, data: Vec<u8> },
+}
+
+impl RecordData {
+    /// ชนิดของ record
+    #[must_use]
+    pub const fn record_type(&self) -> RecordType {
+        match self {
+            Self::A(_) => RecordType::A,
+            Self::Cname(_) => RecordType::Cname,
+            Self::Aaaa(_) => RecordType::Aaaa,
+            Self::Other { record_type, .. } => RecordType::from_code(*record_type),
+        }
+    }
+}
+
+/// 📇 resource record 1 รายการ
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    /// ชื่อที่ record นี้เป็นคำตอบ
+    pub name: String,
+    /// class (ปกติคือ `CLASS_IN`)
+    pub class: u16,
+    /// cache ได้นานกี่วินาที
+    pub ttl: u32,
+    /// ข้อมูลของ record
+    pub data: RecordData,
+}
+
+/// ✉️ DNS message ทั้งก้อน
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Message {
+    /// เลขที่ใช้จับคู่ query กับ response
+    pub id: u16,
+    /// flags ใน header
+    pub flags: Flags,
+    /// คำถาม
+    pub questions: Vec<Question>,
+    /// คำตอบ
+    pub answers: Vec<Record>,
+    /// name server ที่เป็นเจ้าของโดเมน
+    pub authorities: Vec<Record>,
+    /// ข้อมูลเสริม
+    pub additionals: Vec<Record>,
+}
+
+impl Message {
+    /// query ถาม `record_type` ของ `name` 1 ข้อ
+    #[must_use]
+    pub fn query(id: u16, name: &str, record_type: RecordType) -> Self {
+        Self {
+            id,
+            flags: Flags::query(),
+            questions: vec![Question {
+                name: name.to_string(),
+                record_type,
+                class: CLASS_IN,
+            }],
+            answers: Vec::new(),
+            authorities: Vec::new(),
+            additionals: Vec::new(),
+        }
+    }
+
+    /// เขียนเป็น wire format
+    ///
+    /// # Errors
+    ///
+    /// คืน error ถ้ามีชื่อที่ label ยาวเกิน 63 bytes หรือทั้งชื่อยาวเกิน 255 bytes
+    pub fn encode(&self) -> Result<Vec<u8>, DnsError> {
+        let mut out = Vec::with_capacity(512);
+        out.extend_from_slice(&self.id.to_be_bytes());
+        out.extend_from_slice(&self.flags.to_bits().to_be_bytes());
+        for section_len in [
+            self.questions.len(),
+            self.answers.len(),
+            self.authorities.len(),
+            self.additionals.len(),
+        ] {
+            let count = u16::try_from(section_len).map_err(|_| DnsError::NameTooLong)?;
+            out.extend_from_slice(&count.to_be_bytes());
+        }
+
+        for question in &self.questions {
+            write_name(&mut out, &question.name)?;
+            out.extend_from_slice(&question.record_type.code().to_be_bytes());
+            out.extend_from_slice(&question.class.to_be_bytes());
+        }
+        for record in self
+            .answers
+            .iter()
+            .chain(&self.authorities)
+            .chain(&self.additionals)
+        {
+            write_record(&mut out, record)?;
+        }
+        Ok(out)
+    }
+
+    /// อ่านจาก wire format (รองรับ compression pointer)
+    ///
+    /// # Errors
+    ///
+    /// คืน error ถ้าข้อมูลสั้นเกินไป, ตัวชี้ผิดหรือวนลูป หรือ rdata ยาวไม่ตรงกับชนิด
+    pub fn decode(packet: &[u8]) -> Result<Self, DnsError> {
+        let mut reader = Reader { packet, pos: 0 };
+        let id = reader.u16()?;
+        let flags = Flags::from_bits(reader.u16()?);
+        let counts = [reader.u16()?, reader.u16()?, reader.u16()?, reader.u16()?];
+
+        let mut questions = Vec::with_capacity(usize::from(counts[0]));
+        for _ in 0..counts[0] {
+            questions.push(Question {
+                name: reader.name()?,
+                record_type: RecordType::from_code(reader.u16()?),
+                class: reader.u16()?,
+            });
+        }
+
+        let mut sections = counts[1..].iter().map(|&count| {
+            (0..count)
+                .map(|_| reader.record())
+                .collect::<Result<Vec<_>, _>>()
+        });
+        let answers = sections.next().unwrap_or(Ok(Vec::new()))?;
+        let authorities = sections.next().unwrap_or(Ok(Vec::new()))?;
+        let additionals = sections.next().unwrap_or(Ok(Vec::new()))?;
+
+        Ok(Self {
+            id,
+            flags,
+            questions,
+            answers,
+            authorities,
+            additionals,
+        })
+    }
+}
+
+fn write_name(out: &mut Vec<u8>, name: &str) -> Result<(), DnsError> {
+    let start = out.len();
+    let name = name.strip_suffix('.').unwrap_or(name);
+    if !name.is_empty() {
+        for label in name.split('.') {
+            if label.is_empty() || label.len() > MAX_LABEL_LEN {
+                return Err(DnsError::InvalidLabel(label.to_string()));
+            }
+            // ยาวไม่เกิน 63 จึงใส่ใน u8 ได้เสมอ
+            out.push(u8::try_from(label.len()).unwrap_or(u8::MAX));
+            out.extend_from_slice(label.as_bytes());
+        }
+    }
+    out.push(0);
+    if out.len() - start > MAX_NAME_LEN {
+        return Err(DnsError::NameTooLong);
+    }
+    Ok(())
+}
+
+fn write_record(out: &mut Vec<u8>, record: &Record) -> Result<(), DnsError> {
+    write_name(out, &record.name)?;
+    out.extend_from_slice(&record.data.record_type().code().to_be_bytes());
+    out.extend_from_slice(&record.class.to_be_bytes());
+    out.extend_from_slice(&record.ttl.to_be_bytes());
+
+    let mut data = Vec::new();
+    match &record.data {
+        RecordData::A(address) => data.extend_from_slice(&address.octets()),
+        RecordData::Aaaa(address) => data.extend_from_slice(&address.octets()),
+        RecordData::Cname(name) => write_name(&mut data, name)?,
+        RecordData::Other { data: raw, .. } => data.extend_from_slice(raw),
+    }
+    let len = u16::try_from(data.len()).map_err(|_| DnsError::BadRecordLength {
+        record_type: record.data.record_type().code(),
+        len: data.len(),
+    })?;
+    out.extend_from_slice(&len.to_be_bytes());
+    out.extend_from_slice(&data);
+    Ok(())
+}
+
+/// ตัวอ่านที่จำตำแหน่งไว้ - ต้องเห็นทั้งแพ็กเก็ตเพราะ pointer ชี้ไปตำแหน่งไหนก็ได้
+struct Reader<'a> {
+    packet: &'a [u8],
+    pos: usize,
+}
+
+impl Reader<'_> {
+    fn bytes(&mut self, len: usize) -> Result<&[u8], DnsError> {
+        let end = self.pos + len;
+        let bytes = self
+            .packet
+            .get(self.pos..end)
+            .ok_or(DnsError::Truncated(end))?;
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    fn u16(&mut self) -> Result<u16, DnsError> {
+        let bytes = self.bytes(2)?;
+        Ok(u16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn u32(&mut self) -> Result<u32, DnsError> {
+        let bytes = self.bytes(4)?;
+        Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    /// อ่านชื่อที่ตำแหน่งปัจจุบัน แล้วเลื่อนไปหลังชื่อ (หลัง pointer ตัวแรก ถ้ามี)
+    fn name(&mut self) -> Result<String, DnsError> {
+        let (name, next) = read_name(self.packet, self.pos)?;
+        self.pos = next;
+        Ok(name)
+    }
+
+    fn record(&mut self) -> Result<Record, DnsError> {
+        let name = self.name()?;
+        let record_type = self.u16()?;
+        let class = self.u16()?;
+        let ttl = self.u32()?;
+        let len = usize::from(self.u16()?);
+        let data_start = self.pos;
+        let raw = self.bytes(len)?;
+
+        let bad_length = || DnsError::BadRecordLength { record_type, len };
+        let data = match RecordType::from_code(record_type) {
+            RecordType::A => {
+                let octets: [u8; 4] = raw.try_into().map_err(|_| bad_length())?;
+                RecordData::A(Ipv4Addr::from(octets))
+            }
+            RecordType::Aaaa => {
+                let octets: [u8; 16] = raw.try_into().map_err(|_| bad_length())?;
+                RecordData::Aaaa(Ipv6Addr::from(octets))
+            }
+            // ชื่อใน rdata ก็บีบอัดได้ จึงต้องอ่านเทียบกับทั้งแพ็กเก็ต
+            RecordType::Cname => RecordData::Cname(read_name(self.packet, data_start)?.0),
+            RecordType::Other(_) => RecordData::Other {
+                record_type,
+                data: raw.to_vec(),
+            },
+        };
+
+        Ok(Record {
+            name,
+            class,
+            ttl,
+            data,
+        })
+    }
+}
+
+/// อ่านชื่อเริ่มที่ `start` คืน `(ชื่อ, ตำแหน่งถัดจากชื่อในข้อมูลชุดแรก)`
+fn read_name(packet: &[u8], start: usize) -> Result<(String, usize), DnsError> {
+    let mut labels: Vec<String> = Vec::new();
+    let mut pos = start;
+    let mut next = None;
+    let mut jumps = 0;
+    let mut wire_len = 0;
+
+    loop {
+        let len = *packet.get(pos).ok_or(DnsError::Truncated(pos + 1))?;
+        match len {
+            0 => {
+                let name = labels.join(".");
+                return Ok((name, next.unwrap_or(pos + 1)));
+            }
+            // 0b11xx_xxxx = compression pointer 14 บิต
+            0xC0..=0xFF => {
+                let low = *packet.get(pos + 1).ok_or(DnsError::Truncated(pos + 2))?;
+                let target = usize::from(u16::from_be_bytes([len & 0x3F, low]));
+                jumps += 1;
+                if jumps > MAX_POINTER_JUMPS || target >= packet.len() {
+                    return Err(DnsError::BadPointer(pos));
+                }
+                // ส่วนที่เหลือของข้อมูลชุดแรกเริ่มหลัง pointer ตัวแรก
+                next.get_or_insert(pos + 2);
+                pos = target;
+            }
+            1..=0x3F => {
+                let label_start = pos + 1;
+                let label_end = label_start + usize::from(len);
+                let label = packet
+                    .get(label_start..label_end)
+                    .ok_or(DnsError::Truncated(label_end))?;
+                wire_len += label.len() + 1;
+                if wire_len > MAX_NAME_LEN {
+                    return Err(DnsError::NameTooLong);
+                }
+                labels.push(String::from_utf8_lossy(label).into_owned());
+                pos = label_end;
+            }
+            // 0b01 / 0b10 สงวนไว้ (extended label) - ไม่รองรับ
+            _ => return Err(DnsError::InvalidLabel(format!("0x{len:02x}"))),
+        }
+    }
+}
+
+/// 🌍 ส่ง query ไปยัง `server` ผ่าน UDP แล้วรอคำตอบ
+///
+/// # Errors
+///
+/// คืน error ถ้าส่ง/รับไม่สำเร็จภายใน `timeout`, response decode ไม่ได้
+/// หรือ id ของ response ไม่ตรงกับ query
+pub fn resolve(
+    server: SocketAddr,
+    name: &str,
+    record_type: RecordType,
+    timeout: Duration,
+) -> Result<Message, Box<dyn Error>> {
+    let query = Message::query(rand::random(), name, record_type);
+    let bind_addr = if server.is_ipv4() {
+        "0.0.0.0:0"
+    } else {
+        "[::]:0"
+    };
+    let socket = UdpSocket::bind(bind_addr)?;
+    socket.set_read_timeout(Some(timeout))?;
+    socket.connect(server)?;
+    socket.send(&query.encode()?)?;
+
+    // DNS ผ่าน UDP แบบดั้งเดิมยาวไม่เกิน 512 bytes
+    let mut buffer = [0; 512];
+    let len = socket.recv(&mut buffer)?;
+    let response = Message::decode(&buffer[..len])?;
+    if response.id != query.id || !response.flags.response {
+        return Err(format!("unexpected reply id {} for query {}", response.id, query.id).into());
+    }
+    Ok(response)
+}
+
+/// response ตัวอย่างของ `www.example.com` (CNAME → A และ AAAA) ที่ใช้ compression pointer
+/// เรียงไบต์แบบเดียวกับที่ได้จาก DNS server จริง
+#[rustfmt::skip]
+pub const SAMPLE_RESPONSE: &[u8] = &[
+    // header: id 0xBEEF, QR+RD+RA, 1 question, 3 answers
+    0xBE, 0xEF, 0x81, 0x80, 0x00, 0x01, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00,
+    // question (offset 12): www.example.com A IN
+    3, b'w', b'w', b'w', 7, b'e', b'x', b'a', b'm', b'p', b'l', b'e', 3, b'c', b'o', b'm', 0,
+    0x00, 0x01, 0x00, 0x01,
+    // ชื่อ → pointer 0x0C (www.example.com), CNAME, ttl 3600, rdata → pointer 0x10 (example.com)
+    0xC0, 0x0C, 0x00, 0x05, 0x00, 0x01, 0x00, 0x00, 0x0E, 0x10, 0x00, 0x02, 0xC0, 0x10,
+    // example.com A 93.184.215.14, ttl 300
+    0xC0, 0x10, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x01, 0x2C, 0x00, 0x04, 93, 184, 215, 14,
+    // example.com AAAA 2606:2800:21f:cb07:6820:80da:af6b:8b2c, ttl 300
+    0xC0, 0x10, 0x00, 0x1C, 0x00, 0x01, 0x00, 0x00, 0x01, 0x2C, 0x00, 0x10,
+    0x26, 0x06, 0x28, 0x00, 0x02, 0x1F, 0xCB, 0x07, 0x68, 0x20, 0x80, 0xDA, 0xAF, 0x6B, 0x8B, 0x2C,
+];
+
+fn describe(record: &Record) -> String {
+    let data = match &record.data {
+        RecordData::A(address) => format!("A     {address}"),
+        RecordData::Aaaa(address) => format!("AAAA  {address}"),
+        RecordData::Cname(name) => format!("CNAME {name}"),
+        RecordData::Other { record_type, data } => {
+            format!("TYPE{record_type} ({} bytes)", data.len())
+        }
+    };
+    format!("{} (ttl {}s) → {data}", record.name, record.ttl)
+}
+
+/// 🎯 สาธิต encode query, decode response ที่บีบอัดชื่อ และ (ถ้าเปิด) query จริง
+pub fn demonstrate_dns() {
+    println!("🔎 DNS wire format (RFC 1035)");
+
+    let query = Message::query(0x1234, "example.com", RecordType::A);
+    match query.encode() {
+        Ok(bytes) => {
+            let hex: Vec<String> = bytes.iter().map(|byte| format!("{byte:02x}")).collect();
+            println!(
+                "   📤 query example.com A ({} bytes): {}",
+                bytes.len(),
+                hex.join(" ")
+            );
+        }
+        Err(e) => println!("   ❌ encode ไม่ได้: {e}"),
+    }
+
+    match Message::decode(SAMPLE_RESPONSE) {
+        Ok(response) => {
+            println!(
+                "   📥 response ตัวอย่าง {} bytes, rcode {:?}:",
+                SAMPLE_RESPONSE.len(),
+                response.flags.response_code
+            );
+            for record in &response.answers {
+                println!("      {}", describe(record));
+            }
+        }
+        Err(e) => println!("   ❌ decode ไม่ได้: {e}"),
+    }
+
+    let Ok(setting) = std::env::var(LIVE_DNS_ENV) else {
+        println!("   💡 ตั้ง {LIVE_DNS_ENV}=1 เพื่อส่ง query จริงไปที่ {DEFAULT_DNS_SERVER}");
+        return;
+    };
+    let server = if setting == "1" {
+        DEFAULT_DNS_SERVER
+    } else {
+        setting.as_str()
+    };
+    let Ok(server) = server.parse::<SocketAddr>() else {
+        println!("   ❌ {LIVE_DNS_ENV} ต้องเป็น 1 หรือ ip:port (ได้ {setting:?})");
+        return;
+    };
+    match resolve(
+        server,
+        "www.rust-lang.org",
+        RecordType::A,
+        Duration::from_secs(3),
+    ) {
+        Ok(response) => {
+            println!(
+                "   🌍 คำตอบจาก {server} (rcode {:?}, {} answers):",
+                response.flags.response_code,
+                response.answers.len()
+            );
+            for record in &response.answers {
+                println!("      {}", describe(record));
+            }
+        }
+        Err(e) => println!("   ❌ query {server} ไม่สำเร็จ: {e}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_query_matches_wire_bytes() {
+        let bytes = Message::query(0x1234, "example.com", RecordType::A)
+            .encode()
+            .unwrap();
+        let expected = [
+            &[0x12, 0x34, 0x01, 0x00, 0, 1, 0, 0, 0, 0, 0, 0][..],
+            b"\x07example\x03com\x00",
+            &[0, 1, 0, 1],
+        ]
+        .concat();
+        assert_eq!(bytes, expected);
+        assert_eq!(
+            Message::decode(&bytes).unwrap().questions[0].name,
+            "example.com"
+        );
+    }
+
+    #[test]
+    fn test_decode_compressed_response() {
+        let response = Message::decode(SAMPLE_RESPONSE).unwrap();
+        assert_eq!(response.id, 0xBEEF);
+        assert!(response.flags.response && response.flags.recursion_available);
+        assert_eq!(response.flags.response_code, ResponseCode::NoError);
+        assert_eq!(response.questions[0].name, "www.example.com");
+
+        let answers: Vec<_> = response
+            .answers
+            .iter()
+            .map(|r| (r.name.as_str(), &r.data))
+            .collect();
+        assert_eq!(
+            answers,
+            [
+                ("www.example.com", &RecordData::Cname("example.com".into())),
+                (
+                    "example.com",
+                    &RecordData::A(Ipv4Addr::new(93, 184, 215, 14))
+                ),
+                (
+                    "example.com",
+                    &RecordData::Aaaa("2606:2800:21f:cb07:6820:80da:af6b:8b2c".parse().unwrap())
+                ),
+            ]
+        );
+        assert_eq!(response.answers[0].ttl, 3600);
+    }
+
+    #[test]
+    fn test_round_trip_and_flags() {
+        let mut message = Message::query(7, "rust-lang.org.", RecordType::Aaaa);
+        message.flags = Flags::from_bits(0x8583);
+        assert_eq!(message.flags.response_code, ResponseCode::NameError);
+        assert!(message.flags.authoritative);
+        assert_eq!(message.flags.to_bits(), 0x8583);
+
+        message.answers.push(Record {
+            name: "rust-lang.org".into(),
+            class: CLASS_IN,
+            ttl: 60,
+            data: RecordData::Other {
+                record_type: 16,
+                data: b"\x05hello".to_vec(),
+            },
+        });
+        let decoded = Message::decode(&message.encode().unwrap()).unwrap();
+        assert_eq!(decoded.questions[0].name, "rust-lang.org");
+        assert_eq!(decoded.answers, message.answers);
+        assert_eq!(decoded.flags, message.flags);
+    }
+
+    #[test]
+    fn test_rejects_malformed_input() {
+        // ตัวชี้ชี้ตัวเอง
+        let mut looping = SAMPLE_RESPONSE[..12].to_vec();
+        looping.extend_from_slice(&[0xC0, 0x0C, 0, 1, 0, 1]);
+        assert_eq!(Message::decode(&looping), Err(DnsError::BadPointer(12)));
+
+        let cut = &SAMPLE_RESPONSE[..SAMPLE_RESPONSE.len() - 3];
+        assert!(matches!(Message::decode(cut), Err(DnsError::Truncated(_))));
+
+        let long_label = format!("{}.com", "a".repeat(64));
+        assert!(matches!(
+            Message::query(1, &long_label, RecordType::A).encode(),
+            Err(DnsError::InvalidLabel(_))
+        ));
+        let long_name = ["abcdefghi"; 30].join(".");
+        assert_eq!(
+            Message::query(1, &long_name, RecordType::A).encode(),
+            Err(DnsError::NameTooLong)
+        );
+    }
+}
@@ -0,0 +1,569 @@
+//! ⚖️ Load Balancing - กระจาย connection ไปยังหลาย backend แล้วหลบตัวที่ล่ม
+//!
+//! ```text
+//!   client ──► LoadBalancer ──┬──► backend-0  ✅
+//!                             ├──► backend-1  ❌ (health check ไม่ผ่าน → ข้าม)
+//!                             └──► backend-2  ✅
+//! ```
+//!
+//! - 🔁 `Strategy::RoundRobin` - วนทีละตัว เหมาะเมื่อทุก request หนักพอๆ กัน
+//! - 📉 `Strategy::LeastConnections` - เลือกตัวที่ connection ค้างน้อยที่สุด เหมาะกับงานยาวไม่เท่ากัน
+//! - 🩺 health check - thread เบื้องหลังส่ง `PING` ไปทุก backend เป็นระยะ ตัวที่ไม่ตอบ `PONG` ถูกพักไว้
+//! - 🔀 failover - ถ้าต่อ backend ไม่ได้ตอนรับ connection จริง จะพักตัวนั้นทันทีแล้วลองตัวถัดไป
+//!
+//! backend ในบทนี้เป็น `SimulatedBackend` - TCP server จิ๋วที่ตอบกลับทีละบรรทัดพร้อมชื่อตัวเอง
+
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{Shutdown, SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+/// timeout ของการต่อ backend และการรอ `PONG`
+const CONNECT_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// 🎯 วิธีเลือก backend
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strategy {
+    /// วนไปทีละตัวตามลำดับ
+    RoundRobin,
+    /// ตัวที่มี connection ค้างอยู่น้อยที่สุด (เท่ากันเลือกตัวแรก)
+    LeastConnections,
+}
+
+/// 🖥️ backend จำลอง: ตอบ `PING` ด้วย `PONG` และตอบบรรทัดอื่นด้วย `"<name>: <บรรทัด>"`
+pub struct SimulatedBackend {
+    name: String,
+    addr: SocketAddr,
+    stop: Arc<AtomicBool>,
+    accept_thread: Option<JoinHandle<()>>,
+}
+
+impl SimulatedBackend {
+    /// เปิด backend บน port ที่ OS เลือกให้
+    ///
+    /// # Errors
+    ///
+    /// คืน error ถ้า bind ไม่สำเร็จ
+    pub fn spawn(name: &str) -> io::Result<Self> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let addr = listener.local_addr()?;
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let accept_thread = {
+            let stop = Arc::clone(&stop);
+            let name = name.to_string();
+            thread::spawn(move || {
+                for stream in listener.incoming() {
+                    if stop.load(Ordering::Acquire) {
+                        break;
+                    }
+                    let Ok(stream) = stream else { continue };
+                    let name = name.clone();
+                    thread::spawn(move || {
+                        let _ = serve_backend_connection(&name, stream);
+                    });
+                }
+                // listener ถูก drop ตรงนี้ - connection ใหม่จะถูกปฏิเสธ
+            })
+        };
+
+        Ok(Self {
+            name: name.to_string(),
+            addr,
+            stop,
+            accept_thread: Some(accept_thread),
+        })
+    }
+
+    /// ชื่อของ backend
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// address ของ backend
+    #[must_use]
+    pub const fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// จำลองเครื่องล่ม: ปิด listener (connection ที่เปิดอยู่แล้วทำงานต่อจนจบ)
+    pub fn shutdown(mut self) {
+        self.stop_accepting();
+    }
+
+    fn stop_accepting(&mut self) {
+        let Some(accept_thread) = self.accept_thread.take() else {
+            return;
+        };
+        self.stop.store(true, Ordering::Release);
+        let _ = TcpStream::connect(self.addr);
+        let _ = accept_thread.join();
+    }
+}
+
+impl Drop for SimulatedBackend {
+    fn drop(&mut self) {
+        self.stop_accepting();
+    }
+}
+
+fn serve_backend_connection(name: &str, stream: TcpStream) -> io::Result<()> {
+    let mut writer = stream.try_clone()?;
+    for line in BufReader::new(stream).lines() {
+        let line = line?;
+        if line == "PING" {
+            writeln!(writer, "PONG")?;
+        } else {
+            writeln!(writer, "{name}: {line}")?;
+        }
+    }
+    Ok(())
+}
+
+/// 📊 สถานะของ backend 1 ตัว
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BackendStats {
+    /// address ของ backend
+    pub addr: SocketAddr,
+    /// ผ่าน health check ล่าสุดหรือไม่
+    pub healthy: bool,
+    /// connection ที่กำลังส่งต่ออยู่
+    pub active: usize,
+    /// connection ที่เคยถูกส่งไปทั้งหมด
+    pub served: usize,
+}
+
+struct BackendSlot {
+    addr: SocketAddr,
+    healthy: AtomicBool,
+    active: AtomicUsize,
+    served: AtomicUsize,
+}
+
+/// 🗂️ รายชื่อ backend + สถานะ ที่ใช้ร่วมกันระหว่าง thread รับ connection และ health checker
+pub struct BackendPool {
+    backends: Vec<BackendSlot>,
+    strategy: Strategy,
+    next: AtomicUsize,
+}
+
+impl BackendPool {
+    /// pool ที่ถือว่าทุก backend ปกติดีตั้งแต่แรก
+    #[must_use]
+    pub fn new(addrs: &[SocketAddr], strategy: Strategy) -> Self {
+        let backends = addrs
+            .iter()
+            .map(|&addr| BackendSlot {
+                addr,
+                healthy: AtomicBool::new(true),
+                active: AtomicUsize::new(0),
+                served: AtomicUsize::new(0),
+            })
+            .collect();
+        Self {
+            backends,
+            strategy,
+            next: AtomicUsize::new(0),
+        }
+    }
+
+    /// เลือก backend ที่ healthy ตาม strategy แล้วนับ connection ให้ (คืนเมื่อ `Lease` ถูก drop)
+    /// ได้ `None` ถ้าไม่มี backend ที่ใช้ได้เลย
+    #[must_use]
+    pub fn pick(self: &Arc<Self>) -> Option<Lease> {
+        let healthy = |index: &usize| self.backends[*index].healthy.load(Ordering::Acquire);
+        let count = self.backends.len();
+        let index = match self.strategy {
+            Strategy::RoundRobin => {
+                let start = self.next.fetch_add(1, Ordering::Relaxed);
+                (0..count)
+                    .map(|offset| (start + offset) % count)
+                    .find(healthy)
+            }
+            Strategy::LeastConnections => (0..count)
+                .filter(healthy)
+                .min_by_key(|&index| self.backends[index].active.load(Ordering::Acquire)),
+        }?;
+
+        let slot = &self.backends[index];
+        slot.active.fetch_add(1, Ordering::AcqRel);
+        slot.served.fetch_add(1, Ordering::Relaxed);
+        Some(Lease {
+            pool: Arc::clone(self),
+            index,
+        })
+    }
+
+    /// ตั้งสถานะ healthy ของ backend ลำดับ `index`
+    pub fn set_healthy(&self, index: usize, healthy: bool) {
+        if let Some(slot) = self.backends.get(index) {
+            slot.healthy.store(healthy, Ordering::Release);
+        }
+    }
+
+    /// ส่ง `PING` ไปทุก backend แล้วอัปเดตสถานะ คืนจำนวน backend ที่ healthy
+    pub fn check_health(&self) -> usize {
+        let mut healthy = 0;
+        for (index, slot) in self.backends.iter().enumerate() {
+            let alive = probe(slot.addr).unwrap_or(false);
+            self.set_healthy(index, alive);
+            healthy += usize::from(alive);
+        }
+        healthy
+    }
+
+    /// สถานะของทุก backend ตามลำดับ
+    #[must_use]
+    pub fn stats(&self) -> Vec<BackendStats> {
+        self.backends
+            .iter()
+            .map(|slot| BackendStats {
+                addr: slot.addr,
+                healthy: slot.healthy.load(Ordering::Acquire),
+                active: slot.active.load(Ordering::Acquire),
+                served: slot.served.load(Ordering::Relaxed),
+            })
+            .collect()
+    }
+}
+
+/// 🎫 สิทธิ์ใช้ backend 1 connection - drop แล้วจำนวน connection ค้างลดลงเอง
+pub struct Lease {
+    pool: Arc<BackendPool>,
+    index: usize,
+}
+
+impl Lease {
+    /// ลำดับของ backend ใน pool
+    #[must_use]
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// address ของ backend
+    #[must_use]
+    pub fn addr(&self) -> SocketAddr {
+        self.pool.backends[self.index].addr
+    }
+}
+
+impl Drop for Lease {
+    fn drop(&mut self) {
+        self.pool.backends[self.index]
+            .active
+            .fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+fn probe(addr: SocketAddr) -> io::Result<bool> {
+    let mut stream = TcpStream::connect_timeout(&addr, CONNECT_TIMEOUT)?;
+    stream.set_read_timeout(Some(CONNECT_TIMEOUT))?;
+    writeln!(stream, "PING")?;
+    let mut reply = String::new();
+    BufReader::new(stream).read_line(&mut reply)?;
+    Ok(reply.trim_end() == "PONG")
+}
+
+/// ⚖️ TCP load balancer: รับ connection แล้วส่งต่อ (proxy) ไปยัง backend ที่เลือก
+pub struct LoadBalancer {
+    addr: SocketAddr,
+    pool: Arc<BackendPool>,
+    stop: Arc<AtomicBool>,
+    accept_thread: Option<JoinHandle<()>>,
+    health_thread: Option<JoinHandle<()>>,
+}
+
+impl LoadBalancer {
+    /// เปิด load balancer ที่ `addr` หน้า `backends` และเริ่ม health check ทุก `health_interval`
+    ///
+    /// # Errors
+    ///
+    /// คืน error ถ้า bind ไม่สำเร็จ
+    pub fn spawn(
+        addr: impl ToSocketAddrs,
+        backends: &[SocketAddr],
+        strategy: Strategy,
+        health_interval: Duration,
+    ) -> io::Result<Self> {
+        let listener = TcpListener::bind(addr)?;
+        let addr = listener.local_addr()?;
+        let pool = Arc::new(BackendPool::new(backends, strategy));
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let accept_thread = {
+            let pool = Arc::clone(&pool);
+            let stop = Arc::clone(&stop);
+            thread::spawn(move || {
+                for client in listener.incoming() {
+                    if stop.load(Ordering::Acquire) {
+                        break;
+                    }
+                    let Ok(client) = client else { continue };
+                    let pool = Arc::clone(&pool);
+                    thread::spawn(move || proxy(&pool, client));
+                }
+            })
+        };
+
+        let health_thread = {
+            let pool = Arc::clone(&pool);
+            let stop = Arc::clone(&stop);
+            thread::spawn(move || {
+                while !stop.load(Ordering::Acquire) {
+                    pool.check_health();
+                    // shutdown() จะ unpark ให้ตื่นทันที ไม่ต้องรอครบรอบ
+                    thread::park_timeout(health_interval);
+                }
+            })
+        };
+
+        Ok(Self {
+            addr,
+            pool,
+            stop,
+            accept_thread: Some(accept_thread),
+            health_thread: Some(health_thread),
+        })
+    }
+
+    /// address ที่ client ต่อเข้ามา
+    #[must_use]
+    pub const fn local_addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// pool ของ backend (ดูสถิติหรือสั่ง health check เองได้)
+    #[must_use]
+    pub fn pool(&self) -> &BackendPool {
+        &self.pool
+    }
+
+    /// หยุดรับ connection และหยุด health check
+    pub fn shutdown(mut self) {
+        self.stop_threads();
+    }
+
+    fn stop_threads(&mut self) {
+        self.stop.store(true, Ordering::Release);
+        if let Some(health_thread) = self.health_thread.take() {
+            health_thread.thread().unpark();
+            let _ = health_thread.join();
+        }
+        if let Some(accept_thread) = self.accept_thread.take() {
+            let _ = TcpStream::connect(self.addr);
+            let _ = accept_thread.join();
+        }
+    }
+}
+
+impl Drop for LoadBalancer {
+    fn drop(&mut self) {
+        self.stop_threads();
+    }
+}
+
+/// ส่งต่อ connection ของ client ไปยัง backend ที่ต่อได้ตัวแรก
+fn proxy(pool: &Arc<BackendPool>, client: TcpStream) {
+    let (lease, upstream) = loop {
+        // ไม่มี backend ให้ใช้ = ปิด connection ของ client ไปเลย
+        let Some(lease) = pool.pick() else { return };
+        match TcpStream::connect_timeout(&lease.addr(), CONNECT_TIMEOUT) {
+            Ok(upstream) => break (lease, upstream),
+            // failover: พักตัวนี้ไว้จนกว่า health check จะเห็นว่ากลับมาแล้ว
+            Err(_) => pool.set_healthy(lease.index(), false),
+        }
+    };
+
+    let (Ok(mut client_reader), Ok(mut upstream_writer)) =
+        (client.try_clone(), upstream.try_clone())
+    else {
+        return;
+    };
+    let to_backend = thread::spawn(move || {
+        let _ = io::copy(&mut client_reader, &mut upstream_writer);
+        let _ = upstream_writer.shutdown(Shutdown::Write);
+    });
+
+    let (mut upstream_reader, mut client_writer) = (upstream, client);
+    let _ = io::copy(&mut upstream_reader, &mut client_writer);
+    let _ = client_writer.shutdown(Shutdown::Write);
+    let _ = to_backend.join();
+    drop(lease);
+}
+
+/// ส่ง 1 บรรทัดผ่าน load balancer แล้วคืนบรรทัดที่ได้กลับมา
+///
+/// # Errors
+///
+/// คืน error ถ้าต่อไม่ได้หรือ load balancer ปิด connection โดยไม่ตอบ
+pub fn send_line(addr: SocketAddr, line: &str) -> io::Result<String> {
+    let mut stream = TcpStream::connect(addr)?;
+    writeln!(stream, "{line}")?;
+    stream.shutdown(Shutdown::Write)?;
+    let mut reply = String::new();
+    BufReader::new(stream).read_line(&mut reply)?;
+    if reply.is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "no backend answered",
+        ));
+    }
+    Ok(reply.trim_end().to_string())
+}
+
+/// 🎯 สาธิต round robin, backend ล่ม และ failover
+pub fn demonstrate_load_balancing() {
+    println!("⚖️ Load balancer หน้า backend จำลอง 3 ตัว");
+
+    let backends: io::Result<Vec<_>> = (0..3)
+        .map(|i| SimulatedBackend::spawn(&format!("backend-{i}")))
+        .collect();
+    let Ok(mut backends) = backends else {
+        println!("   ❌ เปิด backend ไม่ได้");
+        return;
+    };
+    let addrs: Vec<_> = backends.iter().map(SimulatedBackend::addr).collect();
+    let balancer = match LoadBalancer::spawn(
+        "127.0.0.1:0",
+        &addrs,
+        Strategy::RoundRobin,
+        Duration::from_secs(1),
+    ) {
+        Ok(balancer) => balancer,
+        Err(e) => {
+            println!("   ❌ เปิด load balancer ไม่ได้: {e}");
+            return;
+        }
+    };
+
+    println!("   🔁 Round robin:");
+    for request in 1..=4 {
+        match send_line(balancer.local_addr(), &format!("request {request}")) {
+            Ok(reply) => println!("      {reply}"),
+            Err(e) => println!("      ❌ {e}"),
+        }
+    }
+
+    let crashed = backends.remove(1);
+    println!("   💥 {} ล่ม!", crashed.name());
+    crashed.shutdown();
+    for request in 5..=7 {
+        match send_line(balancer.local_addr(), &format!("request {request}")) {
+            Ok(reply) => println!("      {reply}"),
+            Err(e) => println!("      ❌ {e}"),
+        }
+    }
+
+    let healthy = balancer.pool().check_health();
+    println!("   🩺 health check: healthy {healthy}/{}", addrs.len());
+    for (index, stats) in balancer.pool().stats().iter().enumerate() {
+        let status = if stats.healthy { "✅" } else { "❌" };
+        println!("      backend-{index} {status} served {}", stats.served);
+    }
+
+    balancer.shutdown();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+
+    /// ตั้ง interval ยาวๆ ให้ test สั่ง health check เองเท่านั้น
+    const MANUAL: Duration = Duration::from_secs(3600);
+
+    fn spawn_backends(count: usize) -> (Vec<SimulatedBackend>, Vec<SocketAddr>) {
+        let backends: Vec<_> = (0..count)
+            .map(|i| SimulatedBackend::spawn(&format!("b{i}")).unwrap())
+            .collect();
+        let addrs = backends.iter().map(SimulatedBackend::addr).collect();
+        (backends, addrs)
+    }
+
+    fn served_by(addr: SocketAddr, requests: usize) -> Vec<String> {
+        (0..requests)
+            .map(|i| {
+                let reply = send_line(addr, &format!("r{i}")).unwrap();
+                reply.split(':').next().unwrap().to_string()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_round_robin_spreads_requests_evenly() {
+        let (_backends, addrs) = spawn_backends(3);
+        let balancer =
+            LoadBalancer::spawn("127.0.0.1:0", &addrs, Strategy::RoundRobin, MANUAL).unwrap();
+
+        assert_eq!(
+            served_by(balancer.local_addr(), 6),
+            ["b0", "b1", "b2", "b0", "b1", "b2"]
+        );
+        assert_eq!(send_line(balancer.local_addr(), "hi").unwrap(), "b0: hi");
+    }
+
+    #[test]
+    fn test_least_connections_prefers_idle_backend() {
+        let addrs: Vec<SocketAddr> = (1..=3)
+            .map(|port| SocketAddr::from(([127, 0, 0, 1], port)))
+            .collect();
+        let pool = Arc::new(BackendPool::new(&addrs, Strategy::LeastConnections));
+
+        let first = pool.pick().unwrap();
+        let second = pool.pick().unwrap();
+        assert_eq!((first.index(), second.index()), (0, 1));
+
+        drop(first);
+        // backend 0 ว่างแล้ว แต่ 1 ยังไม่ว่าง
+        assert_eq!(pool.pick().unwrap().index(), 0);
+
+        pool.set_healthy(0, false);
+        pool.set_healthy(2, false);
+        assert_eq!(pool.pick().unwrap().index(), 1);
+        pool.set_healthy(1, false);
+        assert!(pool.pick().is_none());
+    }
+
+    #[test]
+    fn test_failover_skips_crashed_backend() {
+        let (mut backends, addrs) = spawn_backends(3);
+        let balancer =
+            LoadBalancer::spawn("127.0.0.1:0", &addrs, Strategy::RoundRobin, MANUAL).unwrap();
+        backends.remove(1).shutdown();
+
+        // ยังไม่มี health check - ตัวที่ล่มถูกพบตอนต่อจริงแล้วข้ามไปตัวถัดไป
+        let served = served_by(balancer.local_addr(), 6);
+        assert!(!served.contains(&"b1".to_string()), "{served:?}");
+        assert!(!balancer.pool().stats()[1].healthy);
+
+        assert_eq!(balancer.pool().check_health(), 2);
+        backends.clear();
+        assert_eq!(balancer.pool().check_health(), 0);
+        assert!(send_line(balancer.local_addr(), "anyone?").is_err());
+    }
+
+    #[test]
+    fn test_periodic_health_check_marks_backend_down() {
+        let (mut backends, addrs) = spawn_backends(2);
+        let balancer = LoadBalancer::spawn(
+            "127.0.0.1:0",
+            &addrs,
+            Strategy::RoundRobin,
+            Duration::from_millis(10),
+        )
+        .unwrap();
+        backends.remove(0).shutdown();
+
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while balancer.pool().stats()[0].healthy {
+            assert!(Instant::now() < deadline, "health check never ran");
+            thread::sleep(Duration::from_millis(5));
+        }
+        assert_eq!(served_by(balancer.local_addr(), 3), ["b1", "b1", "b1"]);
+        // ไม่มี connection ไหนถูกส่งไปตัวที่ล่มหลังถูกพัก
+        assert_eq!(balancer.pool().stats()[0].served, 0);
+    }
+}
@@ -3,16 +3,18 @@
 //! 🚀 บทเรียนเกี่ยวกับการเขียนโปรแกรมเครือข่ายใน Rust สำหรับเวิร์คช็อปพัฒนาเว็บ
 //! 📡 ครอบคลุม TCP/UDP, HTTP, WebSocket, และ protocols ต่างๆ
 
+pub mod custom_protocols;
+pub mod dns;
+pub mod load_balancing;
+pub mod network_monitoring;
+pub mod rate_limiting;
 pub mod tcp_server;
 pub mod udp_communication;
+pub mod websocket_communication;
 // pub mod http_client_server;
-// pub mod websocket_communication;
 // pub mod async_networking;
 // pub mod protocol_implementation;
 // pub mod network_security;
-// pub mod load_balancing;
-// pub mod network_monitoring;
-// pub mod custom_protocols;
 
 /// 🎯 รันตัวอย่าง Network Programming ทั้งหมดในเวิร์คช็อป
 pub fn run_networking_examples() {
@@ -31,15 +33,47 @@ pub fn run_networking_examples() {
     
     println!();
     
+    // ⏱️ Rate Limiting Workshop
+    println!("⏱️ Rate Limiting Workshop:");
+    rate_limiting::demonstrate_rate_limiting();
+    
+    println!();
+    
+    // 🔗 WebSocket Communication
+    println!("🔗 WebSocket Communication:");
+    websocket_communication::demonstrate_websocket();
+    
+    println!();
+    
+    // 🛠️ Custom Protocols
+    println!("🛠️ Custom Protocols:");
+    custom_protocols::demonstrate_custom_protocols();
+    
+    println!();
+    
+    // 🔎 DNS
+    println!("🔎 DNS Message Encoding:");
+    dns::demonstrate_dns();
+    
+    println!();
+    
+    // ⚖️ Load Balancing
+    println!("⚖️ Load Balancing:");
+    load_balancing::demonstrate_load_balancing();
+    
+    println!();
+    
+    // 📊 Network Monitoring
+    println!("📊 Network Monitoring:");
+    network_monitoring::demonstrate_monitoring();
+    
+    println!();
+    
     // TODO: Implement remaining networking modules
     // HTTP Client/Server
     // println!("🌍 HTTP Client/Server:");
     // http_client_server::demonstrate_http();
     
-    // WebSocket Communication
-    // println!("🔗 WebSocket Communication:");
-    // websocket_communication::demonstrate_websocket();
-    
     // Async Networking
     // println!("⚡ Async Networking:");
     // async_networking::demonstrate_async_networking();
@@ -52,17 +86,5 @@ pub fn run_networking_examples() {
     // println!("🔒 Network Security:");
     // network_security::demonstrate_security();
     
-    // Load Balancing
-    // println!("⚖️ Load Balancing:");
-    // load_balancing::demonstrate_load_balancing();
-    
-    // Network Monitoring
-    // println!("📊 Network Monitoring:");
-    // network_monitoring::demonstrate_monitoring();
-    
-    // Custom Protocols
-    // println!("🛠️ Custom Protocols:");
-    // custom_protocols::demonstrate_custom_protocols();
-    
     println!("\n✅ Network Programming Workshop examples completed!");
 }
\ No newline at end of file
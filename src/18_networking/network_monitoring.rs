@@ -0,0 +1,566 @@
+//! 📊 Network Monitoring - นับไบต์เข้า/ออก และวัด latency แบบ percentile
+//!
+//! ค่าเฉลี่ยของ latency หลอกตาได้ง่าย: 99 request เร็ว 1ms + 1 request ช้า 1s ได้ค่าเฉลี่ย ~11ms
+//! ซึ่งไม่ตรงกับประสบการณ์ของใครเลย 🤔 ระบบจริงจึงดู p50 / p95 / p99 แทน
+//!
+//! - 📈 `LatencyHistogram` - histogram แบบ HDR (log-linear): ทุกช่วงกำลังสองแบ่งเป็น 64 ช่องย่อย
+//!   ความคลาดเคลื่อนไม่เกิน ~1.6% ไม่ว่าจะวัดได้ 50µs หรือ 50s และใช้ memory คงที่ ไม่ต้องเก็บทุกค่า
+//! - 🔌 `MonitoredStream` - ห่อ stream ใดก็ได้ที่เป็น `Read + Write` แล้วนับไบต์ที่ผ่าน
+//! - 🖥️ `NetworkStats::summary()` - สรุปบรรทัดเดียวแบบ dashboard
+//!
+//! ทุกตัวนับเป็น atomic - หลาย connection บันทึกพร้อมกันได้โดยไม่ต้อง lock
+
+use std::fmt::Write as _;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// จำนวนบิตที่ใช้แยกช่องย่อย: ค่าต่ำกว่า 2^7 เก็บแบบตรงตัว ค่าสูงกว่าแบ่งช่วงละ 64 ช่อง
+const SUB_BUCKET_BITS: u32 = 7;
+const SUB_BUCKETS: u64 = 1 << SUB_BUCKET_BITS;
+const HALF_SUB_BUCKETS: u64 = SUB_BUCKETS / 2;
+/// ช่องทั้งหมดที่ครอบคลุมทุกค่าของ `u64`
+const BUCKET_COUNT: usize = bucket_index(u64::MAX) + 1;
+
+/// ค่า → ลำดับช่อง (ไม่เกิน `BUCKET_COUNT` จึงใส่ `usize` ได้เสมอ)
+#[allow(clippy::cast_possible_truncation)]
+const fn bucket_index(value: u64) -> usize {
+    if value < SUB_BUCKETS {
+        return value as usize;
+    }
+    let magnitude = u64::BITS - 1 - value.leading_zeros();
+    let shift = magnitude - (SUB_BUCKET_BITS - 1);
+    let top = value >> shift; // อยู่ในช่วง [64, 128)
+    (SUB_BUCKETS
+        + (magnitude - SUB_BUCKET_BITS) as u64 * HALF_SUB_BUCKETS
+        + (top - HALF_SUB_BUCKETS)) as usize
+}
+
+/// ช่อง → `(ค่าต่ำสุด, ค่าสูงสุด)` ที่ตกลงช่องนี้
+const fn bucket_range(index: usize) -> (u64, u64) {
+    let index = index as u64;
+    if index < SUB_BUCKETS {
+        return (index, index);
+    }
+    let offset = index - SUB_BUCKETS;
+    let shift = offset / HALF_SUB_BUCKETS + 1;
+    let low = (offset % HALF_SUB_BUCKETS + HALF_SUB_BUCKETS) << shift;
+    (low, low + ((1 << shift) - 1))
+}
+
+/// 📈 histogram ของ latency หน่วยไมโครวินาที
+pub struct LatencyHistogram {
+    counts: Box<[AtomicU64]>,
+    total: AtomicU64,
+    sum: AtomicU64,
+    min: AtomicU64,
+    max: AtomicU64,
+}
+
+/// 🎯 percentile ที่ดูบ่อย (ไมโครวินาที)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Percentiles {
+    /// ครึ่งหนึ่งของ request เร็วกว่านี้
+    pub p50: u64,
+    /// 95% ของ request เร็วกว่านี้
+    pub p95: u64,
+    /// 99% ของ request เร็วกว่านี้ - "หาง" ที่ผู้ใช้บางคนเจอจริง
+    pub p99: u64,
+    /// ช้าที่สุด
+    pub max: u64,
+}
+
+impl LatencyHistogram {
+    /// histogram ว่าง
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            counts: (0..BUCKET_COUNT).map(|_| AtomicU64::new(0)).collect(),
+            total: AtomicU64::new(0),
+            sum: AtomicU64::new(0),
+            min: AtomicU64::new(u64::MAX),
+            max: AtomicU64::new(0),
+        }
+    }
+
+    /// บันทึกค่า 1 ค่า (ไมโครวินาที)
+    pub fn record_micros(&self, micros: u64) {
+        self.counts[bucket_index(micros)].fetch_add(1, Ordering::Relaxed);
+        self.total.fetch_add(1, Ordering::Relaxed);
+        self.sum.fetch_add(micros, Ordering::Relaxed);
+        self.min.fetch_min(micros, Ordering::Relaxed);
+        self.max.fetch_max(micros, Ordering::Relaxed);
+    }
+
+    /// บันทึกระยะเวลา
+    pub fn record(&self, latency: Duration) {
+        self.record_micros(u64::try_from(latency.as_micros()).unwrap_or(u64::MAX));
+    }
+
+    /// จำนวนค่าที่บันทึกแล้ว
+    #[must_use]
+    pub fn count(&self) -> u64 {
+        self.total.load(Ordering::Relaxed)
+    }
+
+    /// ค่าน้อยที่สุด (`None` ถ้ายังว่าง)
+    #[must_use]
+    pub fn min(&self) -> Option<u64> {
+        (self.count() > 0).then(|| self.min.load(Ordering::Relaxed))
+    }
+
+    /// ค่ามากที่สุด (`None` ถ้ายังว่าง)
+    #[must_use]
+    pub fn max(&self) -> Option<u64> {
+        (self.count() > 0).then(|| self.max.load(Ordering::Relaxed))
+    }
+
+    /// ค่าเฉลี่ย (`None` ถ้ายังว่าง)
+    #[must_use]
+    pub fn mean(&self) -> Option<u64> {
+        let count = self.count();
+        (count > 0).then(|| self.sum.load(Ordering::Relaxed) / count)
+    }
+
+    /// ค่าที่ `percentile`% ของข้อมูลไม่เกิน (เช่น 99.0) - `None` ถ้ายังว่าง
+    ///
+    /// ตอบเป็นค่าสูงสุดของช่องที่ตกอยู่ (ไม่เกิน `max()`) เหมือน `HdrHistogram`
+    #[must_use]
+    pub fn value_at_percentile(&self, percentile: f64) -> Option<u64> {
+        let total = self.count();
+        if total == 0 {
+            return None;
+        }
+        let max = self.max.load(Ordering::Relaxed);
+        let fraction = percentile.clamp(0.0, 100.0) / 100.0;
+        // ลำดับที่ (เริ่มจาก 1) ของค่าที่ต้องการ ปัดขึ้นและอย่างน้อย 1
+        #[allow(
+            clippy::cast_possible_truncation,
+            clippy::cast_sign_loss,
+            clippy::cast_precision_loss
+        )]
+        let rank = ((fraction * total as f64).ceil() as u64).max(1);
+
+        let mut seen = 0;
+        for (index, count) in self.counts.iter().enumerate() {
+            seen += count.load(Ordering::Relaxed);
+            if seen >= rank {
+                return Some(bucket_range(index).1.min(max));
+            }
+        }
+        Some(max)
+    }
+
+    /// p50 / p95 / p99 / max ในครั้งเดียว
+    #[must_use]
+    pub fn percentiles(&self) -> Option<Percentiles> {
+        Some(Percentiles {
+            p50: self.value_at_percentile(50.0)?,
+            p95: self.value_at_percentile(95.0)?,
+            p99: self.value_at_percentile(99.0)?,
+            max: self.max()?,
+        })
+    }
+}
+
+impl Default for LatencyHistogram {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 📡 สถิติรวมของทุก connection ที่ถูกติดตาม
+pub struct NetworkStats {
+    started: Instant,
+    connections: AtomicU64,
+    bytes_in: AtomicU64,
+    bytes_out: AtomicU64,
+    latency: LatencyHistogram,
+}
+
+impl NetworkStats {
+    /// เริ่มนับตั้งแต่ตอนนี้
+    #[must_use]
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self {
+            started: Instant::now(),
+            connections: AtomicU64::new(0),
+            bytes_in: AtomicU64::new(0),
+            bytes_out: AtomicU64::new(0),
+            latency: LatencyHistogram::new(),
+        })
+    }
+
+    /// จำนวน connection ที่เคยถูกห่อด้วย `MonitoredStream`
+    #[must_use]
+    pub fn connections(&self) -> u64 {
+        self.connections.load(Ordering::Relaxed)
+    }
+
+    /// ไบต์ที่อ่านเข้ามาทั้งหมด
+    #[must_use]
+    pub fn bytes_in(&self) -> u64 {
+        self.bytes_in.load(Ordering::Relaxed)
+    }
+
+    /// ไบต์ที่เขียนออกไปทั้งหมด
+    #[must_use]
+    pub fn bytes_out(&self) -> u64 {
+        self.bytes_out.load(Ordering::Relaxed)
+    }
+
+    /// histogram ของ latency ต่อ request
+    #[must_use]
+    pub const fn latency(&self) -> &LatencyHistogram {
+        &self.latency
+    }
+
+    /// รัน `request` แล้วบันทึกเวลาที่ใช้ลง histogram
+    pub fn time_request<T>(&self, request: impl FnOnce() -> T) -> T {
+        let start = Instant::now();
+        let result = request();
+        self.latency.record(start.elapsed());
+        result
+    }
+
+    /// สรุปบรรทัดเดียวแบบ dashboard
+    #[must_use]
+    pub fn summary(&self) -> String {
+        let seconds = self.started.elapsed().as_secs_f64().max(f64::EPSILON);
+        let (bytes_in, bytes_out) = (self.bytes_in(), self.bytes_out());
+        #[allow(
+            clippy::cast_precision_loss,
+            clippy::cast_possible_truncation,
+            clippy::cast_sign_loss
+        )]
+        let rate = |bytes: u64| format_bytes((bytes as f64 / seconds) as u64);
+
+        let mut line = format!(
+            "conns {} | ⬇️ {} ({}/s) | ⬆️ {} ({}/s) | req {}",
+            self.connections(),
+            format_bytes(bytes_in),
+            rate(bytes_in),
+            format_bytes(bytes_out),
+            rate(bytes_out),
+            self.latency.count(),
+        );
+        if let Some(p) = self.latency.percentiles() {
+            let _ = write!(
+                line,
+                " | p50 {} p95 {} p99 {} max {}",
+                format_micros(p.p50),
+                format_micros(p.p95),
+                format_micros(p.p99),
+                format_micros(p.max)
+            );
+        }
+        line
+    }
+}
+
+/// 🔌 stream ที่นับไบต์เข้า/ออกลง `NetworkStats`
+pub struct MonitoredStream<S> {
+    inner: S,
+    stats: Arc<NetworkStats>,
+}
+
+impl<S> MonitoredStream<S> {
+    /// ห่อ `inner` และนับเป็น 1 connection
+    pub fn new(inner: S, stats: &Arc<NetworkStats>) -> Self {
+        stats.connections.fetch_add(1, Ordering::Relaxed);
+        Self {
+            inner,
+            stats: Arc::clone(stats),
+        }
+    }
+
+    /// stream ตัวใน
+    pub const fn get_ref(&self) -> &S {
+        &self.inner
+    }
+
+    /// แกะ stream ตัวในออกมา
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl MonitoredStream<TcpStream> {
+    /// handle อีกตัวของ connection เดิม (เช่นแยกฝั่งอ่าน/เขียน) - ไม่นับเป็น connection ใหม่
+    ///
+    /// # Errors
+    ///
+    /// คืน error จาก `TcpStream::try_clone`
+    pub fn try_clone(&self) -> io::Result<Self> {
+        Ok(Self {
+            inner: self.inner.try_clone()?,
+            stats: Arc::clone(&self.stats),
+        })
+    }
+}
+
+impl<S: Read> Read for MonitoredStream<S> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.stats
+            .bytes_in
+            .fetch_add(read as u64, Ordering::Relaxed);
+        Ok(read)
+    }
+}
+
+impl<S: Write> Write for MonitoredStream<S> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.stats
+            .bytes_out
+            .fetch_add(written as u64, Ordering::Relaxed);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// ไบต์ → ข้อความอ่านง่าย (`"1.5 KiB"`)
+#[must_use]
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    #[allow(clippy::cast_precision_loss)]
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{value:.1} {}", UNITS[unit])
+    }
+}
+
+/// ไมโครวินาที → ข้อความอ่านง่าย (`"850µs"`, `"1.25ms"`, `"2.00s"`)
+#[must_use]
+pub fn format_micros(micros: u64) -> String {
+    #[allow(clippy::cast_precision_loss)]
+    let value = micros as f64;
+    match micros {
+        0..1_000 => format!("{micros}µs"),
+        1_000..1_000_000 => format!("{:.2}ms", value / 1_000.0),
+        _ => format!("{:.2}s", value / 1_000_000.0),
+    }
+}
+
+/// HTTP server จิ๋ว: ตอบ `200 OK` ให้ทุก request ใน connection เดียวกัน (keep-alive)
+fn serve_http(stream: TcpStream, stats: &Arc<NetworkStats>) -> io::Result<()> {
+    stream.set_nodelay(true)?;
+    let mut writer = MonitoredStream::new(stream, stats);
+    let mut reader = BufReader::new(writer.try_clone()?);
+    let mut line = String::new();
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            return Ok(());
+        }
+        // ฝั่ง server วัดเวลาตั้งแต่ได้ request line จนส่ง response เสร็จ
+        let start = Instant::now();
+        let path = line.split_whitespace().nth(1).unwrap_or("/").to_string();
+        // ข้าม header จนถึงบรรทัดว่าง
+        while reader.read_line(&mut line)? > 0 && !line.ends_with("\r\n\r\n") {}
+
+        let body = format!("hello from {path}\n");
+        // เขียนทั้ง response ในครั้งเดียว - `write!` ทีละชิ้นจะชน Nagle + delayed ACK (~40ms ต่อ request)
+        let response = format!(
+            "HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n{body}",
+            body.len()
+        );
+        writer.write_all(response.as_bytes())?;
+        writer.flush()?;
+        stats.latency().record(start.elapsed());
+    }
+}
+
+/// 🎯 สาธิต: HTTP client ยิง request ผ่าน `MonitoredStream` แล้วพิมพ์ dashboard ระหว่างทาง
+pub fn demonstrate_monitoring() {
+    println!("📊 Network monitoring: bytes in/out + latency percentiles");
+
+    let listener = match TcpListener::bind("127.0.0.1:0") {
+        Ok(listener) => listener,
+        Err(e) => {
+            println!("   ❌ เปิด server ไม่ได้: {e}");
+            return;
+        }
+    };
+    let Ok(addr) = listener.local_addr() else {
+        return;
+    };
+    let server_stats = NetworkStats::new();
+    {
+        let stats = Arc::clone(&server_stats);
+        thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let stats = Arc::clone(&stats);
+                thread::spawn(move || serve_http(stream, &stats));
+            }
+        });
+    }
+
+    let client_stats = NetworkStats::new();
+    let Ok(stream) = TcpStream::connect(addr) else {
+        println!("   ❌ เชื่อมต่อไม่ได้");
+        return;
+    };
+    let _ = stream.set_nodelay(true);
+    let mut writer = MonitoredStream::new(stream, &client_stats);
+    let Ok(read_half) = writer.try_clone() else {
+        return;
+    };
+    let mut reader = BufReader::new(read_half);
+
+    for request in 1..=60 {
+        let result = client_stats.time_request(|| -> io::Result<()> {
+            let request = format!("GET /item/{request} HTTP/1.1\r\nHost: {addr}\r\n\r\n");
+            writer.write_all(request.as_bytes())?;
+            let mut line = String::new();
+            let mut content_length = 0;
+            loop {
+                line.clear();
+                reader.read_line(&mut line)?;
+                if let Some(value) = line.strip_prefix("Content-Length: ") {
+                    content_length = value.trim().parse().unwrap_or(0);
+                }
+                if line == "\r\n" || line.is_empty() {
+                    break;
+                }
+            }
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body)
+        });
+        if let Err(e) = result {
+            println!("   ❌ request {request} ล้มเหลว: {e}");
+            return;
+        }
+        if request % 20 == 0 {
+            println!("   🖥️ [client] {}", client_stats.summary());
+        }
+    }
+    println!("   🖥️ [server] {}", server_stats.summary());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_bucket_ranges_cover_every_value() {
+        for value in [
+            0,
+            1,
+            127,
+            128,
+            129,
+            255,
+            256,
+            1_000,
+            65_535,
+            1 << 40,
+            u64::MAX,
+        ] {
+            let (low, high) = bucket_range(bucket_index(value));
+            assert!(
+                low <= value && value <= high,
+                "{value} not in {low}..={high}"
+            );
+            // ช่องกว้างไม่เกิน 1/64 ของค่า
+            assert!((high - low) <= low / 64, "{value}: {low}..={high}");
+        }
+        assert_eq!(bucket_range(BUCKET_COUNT - 1).1, u64::MAX);
+    }
+
+    #[test]
+    fn test_percentiles_on_uniform_data() {
+        let histogram = LatencyHistogram::new();
+        assert_eq!(histogram.value_at_percentile(50.0), None);
+        for micros in 1..=100 {
+            histogram.record_micros(micros);
+        }
+        assert_eq!(
+            histogram.percentiles(),
+            Some(Percentiles {
+                p50: 50,
+                p95: 95,
+                p99: 99,
+                max: 100
+            })
+        );
+        assert_eq!(histogram.mean(), Some(50));
+        assert_eq!(histogram.min(), Some(1));
+        assert_eq!(histogram.value_at_percentile(100.0), Some(100));
+    }
+
+    #[test]
+    fn test_large_values_stay_within_relative_error() {
+        let histogram = LatencyHistogram::new();
+        for _ in 0..99 {
+            histogram.record(Duration::from_millis(2));
+        }
+        histogram.record(Duration::from_secs(3));
+
+        let p50 = histogram.value_at_percentile(50.0).unwrap();
+        assert!(p50.abs_diff(2_000) * 64 <= 2_000, "p50 = {p50}");
+        // request ช้าตัวเดียวโผล่ที่ p100 ไม่ใช่ p99
+        assert!(histogram.value_at_percentile(99.0).unwrap() < 2_100);
+        assert_eq!(histogram.max(), Some(3_000_000));
+    }
+
+    #[test]
+    fn test_concurrent_recording() {
+        let histogram = Arc::new(LatencyHistogram::new());
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let histogram = Arc::clone(&histogram);
+                thread::spawn(move || (0..1_000).for_each(|i| histogram.record_micros(i)))
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert_eq!(histogram.count(), 4_000);
+        assert_eq!(histogram.max(), Some(999));
+    }
+
+    #[test]
+    fn test_monitored_stream_counts_bytes() {
+        let stats = NetworkStats::new();
+        let mut stream = MonitoredStream::new(Cursor::new(b"ping\n".to_vec()), &stats);
+        let mut line = String::new();
+        BufReader::new(&mut stream).read_line(&mut line).unwrap();
+        stream.write_all(b"pong!!").unwrap();
+
+        assert_eq!((stats.bytes_in(), stats.bytes_out()), (5, 6));
+        assert_eq!(stats.connections(), 1);
+        assert_eq!(stream.into_inner().into_inner(), b"ping\npong!!");
+
+        stats.time_request(|| thread::sleep(Duration::from_millis(1)));
+        let summary = stats.summary();
+        assert!(
+            summary.contains("⬇️ 5 B") && summary.contains("p99"),
+            "{summary}"
+        );
+    }
+
+    #[test]
+    fn test_format_helpers() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_micros(850), "850µs");
+        assert_eq!(format_micros(1_250), "1.25ms");
+        assert_eq!(format_micros(2_000_000), "2.00s");
+    }
+}
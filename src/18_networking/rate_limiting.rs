@@ -0,0 +1,449 @@
+//! ⏱️ Rate Limiting - ตัวจำกัดอัตราคำขอแบบ lock-free
+//!
+//! 🚦 ใช้ได้ทั้งฝั่ง networking (จำกัด connection/packet) และ web middleware
+//! 📦 มี 2 อัลกอริทึม:
+//! - 🪣 `TokenBucket` - เติม token ตามเวลา ยอมให้ burst ได้ถึงความจุ (ใช้ GCRA บน `AtomicU64` ตัวเดียว)
+//! - 🪟 `SlidingWindowLog` - จำเวลาของคำขอล่าสุด N ครั้ง ไม่ให้เกิน N ครั้งในหน้าต่างเวลา
+
+use crate::clock::{Clock, system_clock};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+/// นาฬิกาที่นับเวลาเป็น nanoseconds จากตอนสร้าง limiter
+struct ElapsedClock {
+    clock: Arc<dyn Clock>,
+    origin: Instant,
+}
+
+impl ElapsedClock {
+    fn new(clock: Arc<dyn Clock>) -> Self {
+        let origin = clock.now();
+        Self { clock, origin }
+    }
+
+    fn nanos(&self) -> u64 {
+        as_nanos(self.clock.now().saturating_duration_since(self.origin))
+    }
+}
+
+fn as_nanos(duration: Duration) -> u64 {
+    u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX)
+}
+
+/// 🚦 พฤติกรรมร่วมของ rate limiter ทุกแบบ
+pub trait RateLimiter: Send + Sync {
+    /// ขออนุญาตหนึ่งครั้งแบบไม่รอ - `true` ถ้าผ่าน
+    fn try_acquire(&self) -> bool;
+
+    /// ต้องรออีกนานเท่าไรจึงจะมีโควตาว่าง (`Duration::ZERO` ถ้าพร้อมแล้ว)
+    fn time_until_ready(&self) -> Duration;
+
+    /// โควตาที่เหลืออยู่ตอนนี้ (สำหรับ header `X-RateLimit-Remaining`)
+    fn remaining(&self) -> u64;
+
+    /// จำนวนคำขอสูงสุดที่ยอมให้ (สำหรับ header `X-RateLimit-Limit`)
+    fn limit(&self) -> u64;
+}
+
+/// รอจนกว่าจะได้โควตา - ใช้ร่วมกันโดย `acquire()` ของทุก limiter
+async fn acquire_from(limiter: &dyn RateLimiter) {
+    while !limiter.try_acquire() {
+        let wait = limiter.time_until_ready().max(Duration::from_millis(1));
+        tokio::time::sleep(wait).await;
+    }
+}
+
+/// 🪣 Token Bucket - ถังที่มี token ไหลเข้ามาเรื่อยๆ
+///
+/// แทนที่จะเก็บจำนวน token + เวลาเติมล่าสุด (2 ค่าที่ต้อง lock พร้อมกัน)
+/// เราเก็บแค่ "theoretical arrival time" (TAT) ตาม GCRA:
+/// คำขอผ่านได้ถ้า `max(tat, now) + interval - now <= burst`
+pub struct TokenBucket {
+    capacity: u64,
+    interval_nanos: u64,
+    tat_nanos: AtomicU64,
+    clock: ElapsedClock,
+}
+
+impl TokenBucket {
+    /// นับเวลาละเอียดสุดระดับ nanosecond จึงเติมได้ไม่เกิน 1 token ต่อ ns (ไม่งั้นช่วงห่างปัดเป็น 0)
+    pub const MAX_REFILL_PER_SECOND: u64 = 1_000_000_000;
+
+    /// สร้าง bucket ที่จุได้ `capacity` token และเติม `refill_per_second` token ต่อวินาที
+    ///
+    /// # Panics
+    ///
+    /// Panics ถ้า `capacity` หรือ `refill_per_second` เป็น 0 หรือเติมเร็วกว่า `MAX_REFILL_PER_SECOND`
+    #[must_use]
+    pub fn new(capacity: u64, refill_per_second: u64) -> Self {
+        Self::with_clock(capacity, refill_per_second, system_clock())
+    }
+
+    /// สร้าง bucket ที่ใช้นาฬิกาที่กำหนดเอง (เช่น `MockClock` ใน tests)
+    ///
+    /// # Panics
+    ///
+    /// Panics ถ้า `capacity` หรือ `refill_per_second` เป็น 0 หรือเติมเร็วกว่า `MAX_REFILL_PER_SECOND`
+    #[must_use]
+    pub fn with_clock(capacity: u64, refill_per_second: u64, clock: Arc<dyn Clock>) -> Self {
+        assert!(capacity > 0, "capacity must be greater than zero");
+        assert!(
+            refill_per_second > 0,
+            "refill rate must be greater than zero"
+        );
+        assert!(
+            refill_per_second <= Self::MAX_REFILL_PER_SECOND,
+            "refill rate must be at most one token per nanosecond"
+        );
+
+        Self {
+            capacity,
+            // ปัดขึ้น: ถ้าปัดลง อัตราจริงจะเร็วกว่าที่ตั้งไว้ เช่น 3/s กลายเป็นช่วงห่าง 333_333_333 ns
+            interval_nanos: 1_000_000_000_u64.div_ceil(refill_per_second),
+            tat_nanos: AtomicU64::new(0),
+            clock: ElapsedClock::new(clock),
+        }
+    }
+
+    /// ความจุเต็ม bucket ในหน่วยเวลา - ความจุมหาศาลกับอัตราเติมช้าๆ อาจเกิน `u64` จึงตันไว้ที่ค่าสูงสุด
+    const fn burst_nanos(&self) -> u64 {
+        self.capacity.saturating_mul(self.interval_nanos)
+    }
+
+    /// รอจนกว่าจะได้ token
+    pub async fn acquire(&self) {
+        acquire_from(self).await;
+    }
+}
+
+impl RateLimiter for TokenBucket {
+    fn try_acquire(&self) -> bool {
+        let now = self.clock.nanos();
+        let mut tat = self.tat_nanos.load(Ordering::Acquire);
+        loop {
+            let new_tat = tat.max(now).saturating_add(self.interval_nanos);
+            if new_tat - now > self.burst_nanos() {
+                return false;
+            }
+            match self.tat_nanos.compare_exchange_weak(
+                tat,
+                new_tat,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => return true,
+                Err(current) => tat = current,
+            }
+        }
+    }
+
+    fn time_until_ready(&self) -> Duration {
+        let now = self.clock.nanos();
+        let tat = self.tat_nanos.load(Ordering::Acquire);
+        let new_tat = tat.max(now).saturating_add(self.interval_nanos);
+        Duration::from_nanos((new_tat - now).saturating_sub(self.burst_nanos()))
+    }
+
+    fn remaining(&self) -> u64 {
+        let now = self.clock.nanos();
+        let used = self.tat_nanos.load(Ordering::Acquire).saturating_sub(now);
+        // burst ที่ถูกตันไว้หารกลับแล้วอาจได้มากกว่าความจุจริง
+        ((self.burst_nanos() - used.min(self.burst_nanos())) / self.interval_nanos)
+            .min(self.capacity)
+    }
+
+    fn limit(&self) -> u64 {
+        self.capacity
+    }
+}
+
+/// ค่าพิเศษสำหรับช่องที่ยังไม่เคยถูกใช้
+const EMPTY_SLOT: u64 = u64::MAX;
+
+/// 🪟 Sliding Window Log - จำเวลาของคำขอที่ผ่านล่าสุด
+///
+/// เก็บ timestamp ไว้ใน ring buffer ขนาด `limit` ช่อง คำขอผ่านได้ก็ต่อเมื่อเขียนทับช่องที่หลุดหน้าต่างไปแล้ว
+/// การจองคือ CAS ที่ตัวช่องเอง (เวลาเก่า → เวลาปัจจุบัน) จึงไม่มีสอง thread ได้ช่องเดียวกัน
+/// และในหน้าต่างหนึ่งผ่านได้ไม่เกินจำนวนช่อง - `next` เป็นแค่คำใบ้ว่าช่องไหนน่าจะเก่าที่สุด
+pub struct SlidingWindowLog {
+    window_nanos: u64,
+    slots: Box<[AtomicU64]>,
+    next: AtomicUsize,
+    clock: ElapsedClock,
+}
+
+impl SlidingWindowLog {
+    /// ยอมให้ไม่เกิน `limit` คำขอในทุกช่วง `window`
+    ///
+    /// # Panics
+    ///
+    /// Panics ถ้า `limit` เป็น 0
+    #[must_use]
+    pub fn new(limit: usize, window: Duration) -> Self {
+        Self::with_clock(limit, window, system_clock())
+    }
+
+    /// สร้าง limiter ที่ใช้นาฬิกาที่กำหนดเอง
+    ///
+    /// # Panics
+    ///
+    /// Panics ถ้า `limit` เป็น 0
+    #[must_use]
+    pub fn with_clock(limit: usize, window: Duration, clock: Arc<dyn Clock>) -> Self {
+        assert!(limit > 0, "limit must be greater than zero");
+
+        Self {
+            window_nanos: as_nanos(window),
+            slots: (0..limit).map(|_| AtomicU64::new(EMPTY_SLOT)).collect(),
+            next: AtomicUsize::new(0),
+            clock: ElapsedClock::new(clock),
+        }
+    }
+
+    const fn in_window(&self, timestamp: u64, now: u64) -> bool {
+        timestamp != EMPTY_SLOT && now.saturating_sub(timestamp) < self.window_nanos
+    }
+
+    /// รอจนกว่าจะได้โควตา
+    pub async fn acquire(&self) {
+        acquire_from(self).await;
+    }
+}
+
+impl RateLimiter for SlidingWindowLog {
+    fn try_acquire(&self) -> bool {
+        let now = self.clock.nanos();
+        let start = self.next.load(Ordering::Acquire);
+        // เริ่มจากช่องที่น่าจะเก่าที่สุด ถ้ามีคนเพิ่งจองไปแต่ยังไม่ทันขยับ `next` ก็ไล่ดูช่องถัดไป
+        for offset in 0..self.slots.len() {
+            let index = start.wrapping_add(offset);
+            let slot = &self.slots[index % self.slots.len()];
+            let previous = slot.load(Ordering::Acquire);
+            if self.in_window(previous, now) {
+                continue;
+            }
+            // จองด้วยการเปลี่ยนเวลาในช่องตรงๆ - ค่าเก่าหนึ่งค่ามีผู้ชนะได้คนเดียว
+            if slot
+                .compare_exchange(previous, now, Ordering::AcqRel, Ordering::Acquire)
+                .is_ok()
+            {
+                self.next.fetch_max(index.wrapping_add(1), Ordering::AcqRel);
+                return true;
+            }
+        }
+        false
+    }
+
+    fn time_until_ready(&self) -> Duration {
+        let now = self.clock.nanos();
+        let mut oldest = u64::MAX;
+        for slot in &self.slots {
+            let timestamp = slot.load(Ordering::Acquire);
+            if !self.in_window(timestamp, now) {
+                return Duration::ZERO;
+            }
+            oldest = oldest.min(timestamp);
+        }
+        Duration::from_nanos(oldest.saturating_add(self.window_nanos).saturating_sub(now))
+    }
+
+    fn remaining(&self) -> u64 {
+        let now = self.clock.nanos();
+        let used = self
+            .slots
+            .iter()
+            .filter(|slot| self.in_window(slot.load(Ordering::Acquire), now))
+            .count();
+        (self.slots.len() - used) as u64
+    }
+
+    fn limit(&self) -> u64 {
+        self.slots.len() as u64
+    }
+}
+
+/// 🎯 ตัวอย่างการใช้ rate limiter ทั้งสองแบบ
+pub fn demonstrate_rate_limiting() {
+    println!("🪣 Token Bucket (ความจุ 3, เติม 10 token/วินาที):");
+    let bucket = TokenBucket::new(3, 10);
+    let results: Vec<bool> = (0..5).map(|_| bucket.try_acquire()).collect();
+    println!("   ยิง 5 คำขอติดกัน: {results:?}");
+    println!(
+        "   ต้องรออีก {:?} สำหรับ token ถัดไป",
+        bucket.time_until_ready()
+    );
+
+    println!("🪟 Sliding Window Log (3 คำขอต่อ 100ms):");
+    let window = SlidingWindowLog::new(3, Duration::from_millis(100));
+    let results: Vec<bool> = (0..5).map(|_| window.try_acquire()).collect();
+    println!("   ยิง 5 คำขอติดกัน: {results:?}");
+    println!("   เหลือโควตา {}/{}", window.remaining(), window.limit());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::MockClock;
+
+    #[test]
+    fn test_token_bucket_burst_and_refill() {
+        let clock = MockClock::new();
+        let bucket = TokenBucket::with_clock(3, 10, Arc::new(clock.clone()));
+
+        assert_eq!(bucket.remaining(), 3);
+        assert!(bucket.try_acquire());
+        assert!(bucket.try_acquire());
+        assert!(bucket.try_acquire());
+        assert!(!bucket.try_acquire());
+        assert_eq!(bucket.remaining(), 0);
+        assert_eq!(bucket.time_until_ready(), Duration::from_millis(100));
+
+        clock.advance(Duration::from_millis(100));
+        assert_eq!(bucket.remaining(), 1);
+        assert!(bucket.try_acquire());
+        assert!(!bucket.try_acquire());
+
+        // รอนานแค่ไหน token ก็ไม่เกินความจุ
+        clock.advance(Duration::from_secs(10));
+        assert_eq!(bucket.remaining(), 3);
+    }
+
+    #[test]
+    fn test_token_bucket_huge_capacity_does_not_overflow() {
+        // capacity × 1 วินาทีเกิน u64 - เดิม panic ใน debug และวนกลับเป็นค่าเล็กใน release
+        let bucket = TokenBucket::with_clock(u64::MAX, 1, Arc::new(MockClock::new()));
+        // burst ถูกตันไว้ที่ u64::MAX ns จึงเหลือ token ราว u64::MAX / 1e9 แต่ต้องไม่เป็น 0
+        assert!(bucket.remaining() > 1_000);
+        for _ in 0..1_000 {
+            assert!(bucket.try_acquire());
+        }
+        assert_eq!(bucket.time_until_ready(), Duration::ZERO);
+        assert!(bucket.remaining() <= bucket.limit());
+    }
+
+    #[test]
+    fn test_sliding_window_log() {
+        let clock = MockClock::new();
+        let window =
+            SlidingWindowLog::with_clock(2, Duration::from_secs(1), Arc::new(clock.clone()));
+
+        assert!(window.try_acquire());
+        clock.advance(Duration::from_millis(400));
+        assert!(window.try_acquire());
+        assert!(!window.try_acquire());
+        assert_eq!(window.time_until_ready(), Duration::from_millis(600));
+
+        // คำขอแรกหลุดจากหน้าต่างแล้ว แต่คำขอที่สองยังอยู่
+        clock.advance(Duration::from_millis(600));
+        assert_eq!(window.remaining(), 1);
+        assert!(window.try_acquire());
+        assert!(!window.try_acquire());
+    }
+
+    #[test]
+    fn test_token_bucket_interval_rounds_up() {
+        // 1e9 / 3 ไม่ลงตัว - ถ้าปัดลง token ใบถัดไปจะมาก่อนครบ 1/3 วินาทีจริง
+        let clock = MockClock::new();
+        let bucket = TokenBucket::with_clock(1, 3, Arc::new(clock.clone()));
+
+        assert!(bucket.try_acquire());
+        clock.advance(Duration::from_nanos(333_333_333));
+        assert!(!bucket.try_acquire());
+        clock.advance(Duration::from_nanos(1));
+        assert!(bucket.try_acquire());
+    }
+
+    #[test]
+    fn test_sliding_window_huge_window_does_not_overflow() {
+        let clock = MockClock::new();
+        let window = SlidingWindowLog::with_clock(1, Duration::MAX, Arc::new(clock.clone()));
+
+        clock.advance(Duration::from_secs(1));
+        assert!(window.try_acquire());
+        assert!(!window.try_acquire());
+        assert_eq!(
+            window.time_until_ready(),
+            Duration::from_nanos(u64::MAX - 1_000_000_000)
+        );
+    }
+
+    #[test]
+    fn test_concurrent_acquire_never_exceeds_limit() {
+        let clock: Arc<dyn Clock> = Arc::new(MockClock::new());
+        let bucket = Arc::new(TokenBucket::with_clock(50, 1, Arc::clone(&clock)));
+        let window = Arc::new(SlidingWindowLog::with_clock(
+            50,
+            Duration::from_secs(1),
+            clock,
+        ));
+
+        // ต้อง spawn ครบทุก thread ก่อนค่อย join จึงจะแข่งกันจริง
+        #[allow(clippy::needless_collect)]
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let bucket = Arc::clone(&bucket);
+                let window = Arc::clone(&window);
+                std::thread::spawn(move || {
+                    let mut granted = (0, 0);
+                    for _ in 0..100 {
+                        granted.0 += usize::from(bucket.try_acquire());
+                        granted.1 += usize::from(window.try_acquire());
+                    }
+                    granted
+                })
+            })
+            .collect();
+
+        let (bucket_total, window_total) = handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .fold((0, 0), |acc, granted| {
+                (acc.0 + granted.0, acc.1 + granted.1)
+            });
+        assert_eq!(bucket_total, 50);
+        assert_eq!(window_total, 50);
+    }
+
+    #[test]
+    fn test_sliding_window_single_slot_under_contention() {
+        // นาฬิกาหยุดนิ่ง: ทุก thread แย่งช่องเดียวกันในหน้าต่างเดียวกัน ต้องมีผู้ชนะคนเดียวทุกรอบ
+        for _ in 0..200 {
+            let window =
+                SlidingWindowLog::with_clock(1, Duration::from_secs(1), Arc::new(MockClock::new()));
+            let barrier = std::sync::Barrier::new(8);
+            let granted: usize = std::thread::scope(|scope| {
+                #[allow(clippy::needless_collect)]
+                let handles: Vec<_> = (0..8)
+                    .map(|_| {
+                        scope.spawn(|| {
+                            barrier.wait();
+                            usize::from(window.try_acquire())
+                        })
+                    })
+                    .collect();
+                handles
+                    .into_iter()
+                    .map(|handle| handle.join().unwrap())
+                    .sum()
+            });
+            assert_eq!(granted, 1);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "at most one token per nanosecond")]
+    fn test_token_bucket_rejects_sub_nanosecond_interval() {
+        let _ = TokenBucket::new(1, TokenBucket::MAX_REFILL_PER_SECOND + 1);
+    }
+
+    #[tokio::test]
+    async fn test_acquire_waits_for_refill() {
+        let bucket = TokenBucket::new(1, 50);
+        assert!(bucket.try_acquire());
+
+        let start = Instant::now();
+        bucket.acquire().await;
+        assert!(start.elapsed() >= Duration::from_millis(15));
+    }
+}
@@ -11,6 +11,8 @@ use std::time::Duration;
 use std::sync::{Arc, Mutex};
 use std::collections::HashMap;
 
+use super::network_monitoring::{MonitoredStream, NetworkStats};
+
 /// 🔄 Simple TCP Echo Server - Workshop Demo
 /// 📡 รับข้อความจาก client และส่งกลับไป (สำหรับเวิร์กช็อป)
 /// 🎓 เหมาะสำหรับการเรียนรู้พื้นฐาน TCP communication
@@ -322,6 +324,7 @@ impl ChatServer {
 /// 🔗 เชื่อมต่อกับ server สำหรับการทดสอบในเวิร์กช็อป
 struct TcpClient {
     server_address: String,
+    stats: Arc<NetworkStats>,
 }
 
 impl TcpClient {
@@ -329,21 +332,26 @@ impl TcpClient {
     fn new(server_address: &str) -> Self {
         Self {
             server_address: server_address.to_string(),
+            stats: NetworkStats::new(),
         }
     }
     
     fn connect_and_echo(&self, message: &str) -> std::io::Result<String> {
-        let mut stream = TcpStream::connect(&self.server_address)?;
-        
-        // Send message
-        stream.write_all(format!("{message}\n").as_bytes())?;
+        let stream = TcpStream::connect(&self.server_address)?;
+        // 📊 นับไบต์และจับเวลาทุก request (ดู network_monitoring.rs)
+        let mut stream = MonitoredStream::new(stream, &self.stats);
         
-        // Read response
-        let mut reader = BufReader::new(&stream);
-        let mut response = String::new();
-        reader.read_line(&mut response)?;
-        
-        Ok(response.trim().to_string())
+        self.stats.time_request(|| {
+            // Send message
+            stream.write_all(format!("{message}\n").as_bytes())?;
+            
+            // Read response
+            let mut reader = BufReader::new(&mut stream);
+            let mut response = String::new();
+            reader.read_line(&mut response)?;
+            
+            Ok(response.trim().to_string())
+        })
     }
     
     fn interactive_session(&self) -> std::io::Result<()> {
@@ -436,6 +444,7 @@ pub fn demonstrate_tcp() {
         Ok(response) => println!("📨 การตอบกลับ Ping: {response}"),
         Err(e) => eprintln!("❌ ข้อผิดพลาด Ping: {e}"),
     }
+    println!("📊 {}", client.stats.summary());
     
     // Example 2: Chat Server (commented out to avoid port conflicts)
     println!("\n2. 💬 Chat Server Example (Workshop Simulation):");
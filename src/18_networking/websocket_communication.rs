@@ -0,0 +1,366 @@
+//! 🔗 WebSocket Communication - ตัวแปลง frame ตาม RFC 6455
+//!
+//! 🎯 WebSocket ส่งข้อมูลเป็น "frame" บน TCP connection เดียว
+//! 📦 โมดูลนี้ encode/decode frame เองทีละ bit:
+//! - 🏷️ FIN + opcode (text, binary, close, ping, pong)
+//! - 📏 ความยาว payload แบบ 7 bit, 16 bit หรือ 64 bit
+//! - 🎭 masking key ที่ client ต้องใช้ทุกครั้งที่ส่งหา server
+//!
+//! ⚠️ decoder รับ bytes จาก network โดยตรง จึงต้องไม่ panic กับ input ใดๆ
+
+use std::fmt;
+
+/// ขนาด payload สูงสุดที่ยอมรับ (16 MiB) - กันการจอง memory มหาศาลจาก header ปลอม
+pub const MAX_PAYLOAD_LEN: usize = 16 * 1024 * 1024;
+
+/// 🏷️ ประเภทของ frame
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Opcode {
+    Continuation,
+    Text,
+    Binary,
+    Close,
+    Ping,
+    Pong,
+}
+
+impl Opcode {
+    /// แปลงจาก 4 bit ล่างของ byte แรก
+    #[must_use]
+    pub const fn from_bits(bits: u8) -> Option<Self> {
+        match bits {
+            0x0 => Some(Self::Continuation),
+            0x1 => Some(Self::Text),
+            0x2 => Some(Self::Binary),
+            0x8 => Some(Self::Close),
+            0x9 => Some(Self::Ping),
+            0xA => Some(Self::Pong),
+            _ => None,
+        }
+    }
+
+    /// ค่า 4 bit ที่ใช้ตอน encode
+    #[must_use]
+    pub const fn bits(self) -> u8 {
+        match self {
+            Self::Continuation => 0x0,
+            Self::Text => 0x1,
+            Self::Binary => 0x2,
+            Self::Close => 0x8,
+            Self::Ping => 0x9,
+            Self::Pong => 0xA,
+        }
+    }
+
+    /// control frame (close/ping/pong) มีข้อจำกัดพิเศษ
+    #[must_use]
+    pub const fn is_control(self) -> bool {
+        matches!(self, Self::Close | Self::Ping | Self::Pong)
+    }
+}
+
+/// ❌ ข้อผิดพลาดตอน decode frame
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameError {
+    /// ข้อมูลยังไม่ครบ ต้องการอย่างน้อย `needed` bytes
+    Incomplete {
+        needed: usize,
+    },
+    /// RSV1-3 ถูกตั้งโดยไม่ได้ตกลง extension กันไว้
+    ReservedBitsSet,
+    UnknownOpcode(u8),
+    /// control frame ต้อง FIN และ payload ไม่เกิน 125 bytes
+    InvalidControlFrame,
+    /// ความยาวไม่ได้เข้ารหัสด้วยจำนวน bytes น้อยที่สุด
+    NonMinimalLength,
+    PayloadTooLarge(u64),
+}
+
+impl fmt::Display for FrameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Incomplete { needed } => write!(f, "incomplete frame, need {needed} bytes"),
+            Self::ReservedBitsSet => write!(f, "reserved bits set"),
+            Self::UnknownOpcode(bits) => write!(f, "unknown opcode {bits:#x}"),
+            Self::InvalidControlFrame => write!(f, "invalid control frame"),
+            Self::NonMinimalLength => write!(f, "payload length is not minimally encoded"),
+            Self::PayloadTooLarge(len) => write!(f, "payload of {len} bytes is too large"),
+        }
+    }
+}
+
+impl std::error::Error for FrameError {}
+
+/// 📦 WebSocket frame หนึ่งอัน (payload เก็บแบบ unmasked แล้ว)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebSocketFrame {
+    pub fin: bool,
+    pub opcode: Opcode,
+    pub mask: Option<[u8; 4]>,
+    pub payload: Vec<u8>,
+}
+
+impl WebSocketFrame {
+    /// frame ข้อความ
+    #[must_use]
+    pub fn text(message: &str) -> Self {
+        Self::new(Opcode::Text, message.as_bytes().to_vec())
+    }
+
+    /// frame ข้อมูล binary
+    #[must_use]
+    pub const fn binary(data: Vec<u8>) -> Self {
+        Self::new(Opcode::Binary, data)
+    }
+
+    /// frame ping
+    #[must_use]
+    pub const fn ping(data: Vec<u8>) -> Self {
+        Self::new(Opcode::Ping, data)
+    }
+
+    /// frame close พร้อม status code และเหตุผล
+    #[must_use]
+    pub fn close(code: u16, reason: &str) -> Self {
+        let mut payload = code.to_be_bytes().to_vec();
+        payload.extend_from_slice(reason.as_bytes());
+        Self::new(Opcode::Close, payload)
+    }
+
+    const fn new(opcode: Opcode, payload: Vec<u8>) -> Self {
+        Self {
+            fin: true,
+            opcode,
+            mask: None,
+            payload,
+        }
+    }
+
+    /// ใส่ masking key (client → server ต้อง mask เสมอ)
+    #[must_use]
+    pub const fn masked(mut self, key: [u8; 4]) -> Self {
+        self.mask = Some(key);
+        self
+    }
+
+    /// แปลงเป็น bytes สำหรับส่งออก network
+    #[must_use]
+    pub fn encode(&self) -> Vec<u8> {
+        let len = self.payload.len();
+        let mut out = Vec::with_capacity(len + 14);
+        out.push(u8::from(self.fin) << 7 | self.opcode.bits());
+
+        let mask_bit = if self.mask.is_some() { 0x80 } else { 0 };
+        match len {
+            0..=125 => out.push(mask_bit | u8::try_from(len).unwrap_or(125)),
+            126..=0xFFFF => {
+                out.push(mask_bit | 0x7E);
+                out.extend_from_slice(&u16::try_from(len).unwrap_or(u16::MAX).to_be_bytes());
+            }
+            _ => {
+                out.push(mask_bit | 0x7F);
+                out.extend_from_slice(&(len as u64).to_be_bytes());
+            }
+        }
+
+        match self.mask {
+            Some(key) => {
+                out.extend_from_slice(&key);
+                out.extend(apply_mask(&self.payload, key));
+            }
+            None => out.extend_from_slice(&self.payload),
+        }
+        out
+    }
+
+    /// อ่าน frame หนึ่งอันจากต้น buffer - คืน frame และจำนวน bytes ที่ใช้ไป
+    ///
+    /// # Errors
+    ///
+    /// คืน `FrameError::Incomplete` ถ้าต้องรอข้อมูลเพิ่ม หรือ error อื่นถ้า frame ผิดรูป
+    pub fn decode(buf: &[u8]) -> Result<(Self, usize), FrameError> {
+        let [first, second, ..] = *buf else {
+            return Err(FrameError::Incomplete { needed: 2 });
+        };
+
+        if first & 0x70 != 0 {
+            return Err(FrameError::ReservedBitsSet);
+        }
+        let fin = first & 0x80 != 0;
+        let opcode =
+            Opcode::from_bits(first & 0x0F).ok_or(FrameError::UnknownOpcode(first & 0x0F))?;
+
+        let mut offset = 2;
+        let len = match second & 0x7F {
+            126 => {
+                let bytes = take::<2>(buf, offset)?;
+                offset += 2;
+                let len = u64::from(u16::from_be_bytes(bytes));
+                if len < 126 {
+                    return Err(FrameError::NonMinimalLength);
+                }
+                len
+            }
+            127 => {
+                let bytes = take::<8>(buf, offset)?;
+                offset += 8;
+                let len = u64::from_be_bytes(bytes);
+                if len <= 0xFFFF {
+                    return Err(FrameError::NonMinimalLength);
+                }
+                len
+            }
+            short => u64::from(short),
+        };
+
+        if opcode.is_control() && (!fin || len > 125) {
+            return Err(FrameError::InvalidControlFrame);
+        }
+        let len = usize::try_from(len)
+            .ok()
+            .filter(|len| *len <= MAX_PAYLOAD_LEN)
+            .ok_or(FrameError::PayloadTooLarge(len))?;
+
+        let mask = if second & 0x80 == 0 {
+            None
+        } else {
+            let key = take::<4>(buf, offset)?;
+            offset += 4;
+            Some(key)
+        };
+
+        let end = offset + len;
+        let payload = buf
+            .get(offset..end)
+            .ok_or(FrameError::Incomplete { needed: end })?;
+        let payload = mask.map_or_else(|| payload.to_vec(), |key| apply_mask(payload, key));
+
+        Ok((
+            Self {
+                fin,
+                opcode,
+                mask,
+                payload,
+            },
+            end,
+        ))
+    }
+
+    /// payload เป็นข้อความ (ถ้าเป็น UTF-8 ที่ถูกต้อง)
+    #[must_use]
+    pub fn as_text(&self) -> Option<&str> {
+        std::str::from_utf8(&self.payload).ok()
+    }
+}
+
+/// อ่าน N bytes ที่ตำแหน่ง `offset` หรือบอกว่าต้องการข้อมูลเพิ่ม
+fn take<const N: usize>(buf: &[u8], offset: usize) -> Result<[u8; N], FrameError> {
+    buf.get(offset..offset + N)
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or(FrameError::Incomplete { needed: offset + N })
+}
+
+/// XOR payload กับ masking key (ใช้ทั้งตอน mask และ unmask)
+fn apply_mask(payload: &[u8], key: [u8; 4]) -> Vec<u8> {
+    payload
+        .iter()
+        .zip(key.iter().cycle())
+        .map(|(byte, k)| byte ^ k)
+        .collect()
+}
+
+/// 🎯 ตัวอย่างการ encode/decode WebSocket frames
+pub fn demonstrate_websocket() {
+    let frame = WebSocketFrame::text("สวัสดี WebSocket! 🦀").masked([0x37, 0xFA, 0x21, 0x3D]);
+    let bytes = frame.encode();
+    println!(
+        "   📤 Text frame (masked) {} bytes: {:02x?}",
+        bytes.len(),
+        &bytes[..6]
+    );
+
+    match WebSocketFrame::decode(&bytes) {
+        Ok((decoded, used)) => println!(
+            "   📥 Decode แล้ว ({used} bytes): {:?} -> {:?}",
+            decoded.opcode,
+            decoded.as_text()
+        ),
+        Err(error) => println!("   ❌ Decode ไม่ได้: {error}"),
+    }
+
+    let big = WebSocketFrame::binary(vec![0; 300]).encode();
+    println!(
+        "   📏 Binary 300 bytes ใช้ header แบบ 16 bit: {:02x?}",
+        &big[..4]
+    );
+
+    match WebSocketFrame::decode(&bytes[..5]) {
+        Ok(_) => println!("   🤔 decode ครึ่ง frame ได้ (ไม่ควรเกิด!)"),
+        Err(error) => println!("   ⏳ ครึ่ง frame: {error}"),
+    }
+
+    let close = WebSocketFrame::close(1000, "bye").encode();
+    println!("   👋 Close frame: {close:02x?}");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_rfc_masked_hello() {
+        // ตัวอย่างจาก RFC 6455 section 5.7
+        let bytes = [
+            0x81, 0x85, 0x37, 0xFA, 0x21, 0x3D, 0x7F, 0x9F, 0x4D, 0x51, 0x58,
+        ];
+        let (frame, used) = WebSocketFrame::decode(&bytes).unwrap();
+
+        assert_eq!(used, bytes.len());
+        assert!(frame.fin);
+        assert_eq!(frame.opcode, Opcode::Text);
+        assert_eq!(frame.as_text(), Some("Hello"));
+        assert_eq!(frame.encode(), bytes);
+    }
+
+    #[test]
+    fn test_round_trip_all_length_encodings() {
+        for len in [0, 125, 126, 0xFFFF, 0x1_0000] {
+            let frame = WebSocketFrame::binary(vec![0xAB; len]).masked([1, 2, 3, 4]);
+            let bytes = frame.encode();
+            assert_eq!(WebSocketFrame::decode(&bytes), Ok((frame, bytes.len())));
+        }
+    }
+
+    #[test]
+    fn test_decode_reports_incomplete_and_invalid_frames() {
+        let bytes = WebSocketFrame::text("hello").encode();
+        assert_eq!(
+            WebSocketFrame::decode(&bytes[..4]),
+            Err(FrameError::Incomplete { needed: 7 })
+        );
+        assert_eq!(
+            WebSocketFrame::decode(&[0x81]),
+            Err(FrameError::Incomplete { needed: 2 })
+        );
+        assert_eq!(
+            WebSocketFrame::decode(&[0xC1, 0x00]),
+            Err(FrameError::ReservedBitsSet)
+        );
+        assert_eq!(
+            WebSocketFrame::decode(&[0x83, 0x00]),
+            Err(FrameError::UnknownOpcode(3))
+        );
+        assert_eq!(
+            WebSocketFrame::decode(&[0x09, 0x00]),
+            Err(FrameError::InvalidControlFrame)
+        );
+        assert_eq!(
+            WebSocketFrame::decode(&[0x82, 0x7E, 0x00, 0x05]),
+            Err(FrameError::NonMinimalLength)
+        );
+        assert_eq!(
+            WebSocketFrame::decode(&[0x82, 0x7F, 0xFF, 0, 0, 0, 0, 0, 0, 0]),
+            Err(FrameError::PayloadTooLarge(0xFF00_0000_0000_0000))
+        );
+    }
+}
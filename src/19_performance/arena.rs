@@ -0,0 +1,423 @@
+//! 🏟️ Arena (Bump) Allocator - จองหน่วยความจำก้อนใหญ่ทีเดียว แล้วแบ่งใช้แบบเลื่อน pointer!
+//!
+//! การ `Box::new` ทีละ object ต้องคุยกับ global allocator ทุกครั้ง 🐌
+//! Arena จองหน่วยความจำเป็น chunk ใหญ่ๆ แล้วแค่ "เลื่อน offset" ทุกครั้งที่ขอ object ใหม่:
+//! - ⚡ alloc เร็วมาก (บวกเลขกับจัด alignment เท่านั้น)
+//! - 📦 object ที่สร้างพร้อมกันอยู่ติดกันใน memory (cache-friendly)
+//! - 🧹 คืนทุกอย่างทีเดียวด้วย `reset()` หรือตอน arena ถูก drop
+//!
+//! 🔒 reference ที่ได้จาก `alloc` ผูก lifetime กับ arena
+//! compiler จึงไม่ยอมให้ `reset()` หรือ drop arena ขณะที่ยังมีใครถือ reference อยู่
+//!
+//! 🧪 โค้ด unsafe ในไฟล์นี้ตรวจด้วย Miri ได้: `cargo +nightly miri test --lib performance::arena`
+
+use std::alloc::{self, Layout};
+use std::cell::{Cell, RefCell};
+use std::ptr::NonNull;
+
+/// ขนาด chunk เริ่มต้น (64 KiB)
+pub const DEFAULT_CHUNK_SIZE: usize = 64 * 1024;
+
+/// alignment ขั้นต่ำของทุก chunk
+const CHUNK_ALIGN: usize = 16;
+
+/// หน่วยความจำหนึ่งก้อนที่จองจาก global allocator
+struct Chunk {
+    ptr: NonNull<u8>,
+    layout: Layout,
+}
+
+impl Chunk {
+    fn new(size: usize, align: usize) -> Self {
+        let layout = Layout::from_size_align(size.max(1), align.max(CHUNK_ALIGN))
+            .expect("arena chunk layout overflow");
+        // SAFETY: layout มีขนาดอย่างน้อย 1 byte
+        let raw = unsafe { alloc::alloc(layout) };
+        let ptr = NonNull::new(raw).unwrap_or_else(|| alloc::handle_alloc_error(layout));
+        Self { ptr, layout }
+    }
+
+    /// offset แรกที่ >= `offset` และ align ตาม `align` หรือ `None` ถ้า `size` bytes ไม่พอใส่
+    fn fit(&self, offset: usize, size: usize, align: usize) -> Option<usize> {
+        let address = self.ptr.addr().get() + offset;
+        let start = offset + (address.next_multiple_of(align) - address);
+        (start.checked_add(size)? <= self.layout.size()).then_some(start)
+    }
+}
+
+impl Drop for Chunk {
+    fn drop(&mut self) {
+        // SAFETY: ptr มาจาก alloc::alloc ด้วย layout เดียวกันนี้
+        unsafe { alloc::dealloc(self.ptr.as_ptr(), self.layout) };
+    }
+}
+
+/// object ที่ต้องเรียก destructor ตอน reset/drop
+struct DropEntry {
+    ptr: NonNull<u8>,
+    drop_fn: unsafe fn(NonNull<u8>),
+}
+
+/// เรียก destructor ของ `T` ที่ `ptr`
+///
+/// # Safety
+///
+/// `ptr` ต้องชี้ไปที่ `T` ที่ยังไม่ถูก drop
+unsafe fn drop_value<T>(ptr: NonNull<u8>) {
+    unsafe { ptr.cast::<T>().drop_in_place() };
+}
+
+/// 🏟️ Bump allocator: object ทุกตัวอยู่ได้นานเท่ากับ arena
+///
+/// object จาก `alloc_owned` ถูกเรียก destructor ตามลำดับที่ alloc เมื่อ `reset()` หรือ drop arena
+pub struct Arena {
+    chunks: RefCell<Vec<Chunk>>,
+    offset: Cell<usize>,
+    chunk_size: usize,
+    drops: RefCell<Vec<DropEntry>>,
+    allocated_bytes: Cell<usize>,
+    allocations: Cell<usize>,
+}
+
+impl Default for Arena {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Debug for Arena {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Arena")
+            .field("chunks", &self.chunk_count())
+            .field("chunk_size", &self.chunk_size)
+            .field("allocations", &self.allocations())
+            .field("allocated_bytes", &self.allocated_bytes())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Arena {
+    /// สร้าง arena ด้วยขนาด chunk เริ่มต้น (ยังไม่จองหน่วยความจำจนกว่าจะ alloc ครั้งแรก)
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::with_chunk_size(DEFAULT_CHUNK_SIZE)
+    }
+
+    /// สร้าง arena ที่จองหน่วยความจำครั้งละ `chunk_size` bytes
+    #[must_use]
+    pub const fn with_chunk_size(chunk_size: usize) -> Self {
+        Self {
+            chunks: RefCell::new(Vec::new()),
+            offset: Cell::new(0),
+            chunk_size,
+            drops: RefCell::new(Vec::new()),
+            allocated_bytes: Cell::new(0),
+            allocations: Cell::new(0),
+        }
+    }
+
+    /// ย้าย `value` เข้าไปอยู่ใน arena แล้วคืน reference ที่อยู่ได้นานเท่า arena
+    ///
+    /// ⚠️ เหมือน bump allocator ทั่วไป: destructor ของ `value` จะ**ไม่ถูกเรียก**
+    /// (ใช้กับข้อมูลที่ไม่มี `Drop` เช่น node ของ tree) ถ้า `T` ถือ heap ของตัวเองให้ใช้ `alloc_owned`
+    #[allow(clippy::mut_from_ref)] // แต่ละครั้งได้พื้นที่ใหม่ที่ไม่ทับกัน
+    pub fn alloc<T>(&self, value: T) -> &mut T {
+        let ptr = self.place(value);
+        // SAFETY: พื้นที่นี้เป็นของ reference นี้เพียงผู้เดียวจนกว่า arena จะ reset/drop
+        // ซึ่งต้องใช้ &mut self จึงเกิดขึ้นไม่ได้ระหว่างที่ reference ยังอยู่
+        unsafe { &mut *ptr.as_ptr() }
+    }
+
+    /// เหมือน `alloc` แต่เรียก destructor ของ `value` ตอน `reset()` หรือ drop arena
+    ///
+    /// ต้องเป็น `'static` เพราะ destructor รันทีหลัง ข้อมูลที่ยืมมาอาจตายไปก่อนแล้ว
+    #[allow(clippy::mut_from_ref)] // แต่ละครั้งได้พื้นที่ใหม่ที่ไม่ทับกัน
+    pub fn alloc_owned<T: 'static>(&self, value: T) -> &mut T {
+        let ptr = self.place(value);
+        if std::mem::needs_drop::<T>() {
+            self.drops.borrow_mut().push(DropEntry {
+                ptr: ptr.cast(),
+                drop_fn: drop_value::<T>,
+            });
+        }
+        // SAFETY: เหมือน `alloc` - destructor จะรันตอน reset/drop ซึ่งต้องใช้ &mut self
+        unsafe { &mut *ptr.as_ptr() }
+    }
+
+    /// ย้าย `value` ลงพื้นที่ใหม่ใน chunk แล้วคืน raw pointer
+    fn place<T>(&self, value: T) -> NonNull<T> {
+        let ptr = self.bump(Layout::new::<T>()).cast::<T>();
+        // SAFETY: bump คืนพื้นที่ขนาดและ alignment ตรงกับ T ที่ยังไม่มีใครใช้
+        unsafe { ptr.write(value) };
+        self.allocations.set(self.allocations.get() + 1);
+        ptr
+    }
+
+    /// คัดลอก string เข้า arena
+    pub fn alloc_str(&self, text: &str) -> &str {
+        let layout = Layout::for_value(text.as_bytes());
+        let ptr = self.bump(layout);
+        // SAFETY: พื้นที่ใหม่ขนาด text.len() ไม่ทับกับ text และ bytes เป็น UTF-8 ที่ถูกต้อง
+        unsafe {
+            std::ptr::copy_nonoverlapping(text.as_ptr(), ptr.as_ptr(), text.len());
+            std::str::from_utf8_unchecked(std::slice::from_raw_parts(ptr.as_ptr(), text.len()))
+        }
+    }
+
+    /// จองพื้นที่ตาม `layout` ด้วยการเลื่อน offset (เปิด chunk ใหม่เมื่อไม่พอ)
+    fn bump(&self, layout: Layout) -> NonNull<u8> {
+        let mut chunks = self.chunks.borrow_mut();
+        let offset = self.offset.get();
+
+        let fits = chunks
+            .last()
+            .and_then(|chunk| chunk.fit(offset, layout.size(), layout.align()));
+        let start = fits.unwrap_or_else(|| {
+            let size = self.chunk_size.max(layout.size() + layout.align());
+            chunks.push(Chunk::new(size, layout.align()));
+            chunks[chunks.len() - 1]
+                .fit(0, layout.size(), layout.align())
+                .expect("fresh chunk fits the layout")
+        });
+        let chunk = &chunks[chunks.len() - 1];
+
+        self.offset.set(start + layout.size());
+        self.allocated_bytes
+            .set(self.allocated_bytes.get() + layout.size());
+        // SAFETY: start + size <= ขนาดของ chunk (ตรวจแล้วใน fit)
+        unsafe { chunk.ptr.add(start) }
+    }
+
+    /// 🧹 เรียก destructor ของ object จาก `alloc_owned` แล้วเริ่มใช้ chunk ล่าสุดใหม่ตั้งแต่ต้น
+    ///
+    /// chunk อื่นๆ จะถูกคืนให้ระบบ ส่วน chunk ล่าสุดเก็บไว้ใช้ต่อ
+    pub fn reset(&mut self) {
+        self.run_drops();
+        let chunks = self.chunks.get_mut();
+        if chunks.len() > 1 {
+            chunks.drain(..chunks.len() - 1);
+        }
+        self.offset.set(0);
+        self.allocated_bytes.set(0);
+        self.allocations.set(0);
+    }
+
+    fn run_drops(&mut self) {
+        for entry in std::mem::take(self.drops.get_mut()) {
+            // SAFETY: entry ถูกบันทึกตอน alloc และแต่ละตัวถูก drop เพียงครั้งเดียว
+            unsafe { (entry.drop_fn)(entry.ptr) };
+        }
+    }
+
+    /// จำนวน bytes ที่ object ใช้ (ไม่รวม padding และพื้นที่ว่างท้าย chunk)
+    #[must_use]
+    pub const fn allocated_bytes(&self) -> usize {
+        self.allocated_bytes.get()
+    }
+
+    /// จำนวน object ที่ alloc ตั้งแต่สร้างหรือ reset ล่าสุด
+    #[must_use]
+    pub const fn allocations(&self) -> usize {
+        self.allocations.get()
+    }
+
+    /// จำนวน chunk ที่จองจากระบบอยู่ตอนนี้
+    #[must_use]
+    pub fn chunk_count(&self) -> usize {
+        self.chunks.borrow().len()
+    }
+}
+
+impl Drop for Arena {
+    fn drop(&mut self) {
+        self.run_drops();
+    }
+}
+
+// ===== ตัวอย่างโครงสร้างข้อมูล: expression tree =====
+
+/// 🌳 node ของ expression tree ที่ลูกอยู่ใน arena
+#[derive(Debug)]
+pub enum ArenaExpr<'a> {
+    Num(i64),
+    Add(&'a Self, &'a Self),
+    Mul(&'a Self, &'a Self),
+}
+
+impl ArenaExpr<'_> {
+    #[must_use]
+    pub fn eval(&self) -> i64 {
+        match self {
+            Self::Num(n) => *n,
+            Self::Add(a, b) => a.eval().wrapping_add(b.eval()),
+            Self::Mul(a, b) => a.eval().wrapping_mul(b.eval()),
+        }
+    }
+}
+
+/// 🌳 tree เดียวกันแต่ทุก node เป็น `Box` ของตัวเอง
+#[derive(Debug)]
+pub enum BoxedExpr {
+    Num(i64),
+    Add(Box<Self>, Box<Self>),
+    Mul(Box<Self>, Box<Self>),
+}
+
+impl BoxedExpr {
+    #[must_use]
+    pub fn eval(&self) -> i64 {
+        match self {
+            Self::Num(n) => *n,
+            Self::Add(a, b) => a.eval().wrapping_add(b.eval()),
+            Self::Mul(a, b) => a.eval().wrapping_mul(b.eval()),
+        }
+    }
+}
+
+/// สร้าง tree ลึก `depth` ชั้นใน arena (มี 2^(depth+1) - 1 node)
+pub fn build_arena_expr(arena: &Arena, depth: u32) -> &ArenaExpr<'_> {
+    if depth == 0 {
+        return arena.alloc(ArenaExpr::Num(i64::from(depth) + 1));
+    }
+    let left = build_arena_expr(arena, depth - 1);
+    let right = build_arena_expr(arena, depth - 1);
+    if depth.is_multiple_of(2) {
+        arena.alloc(ArenaExpr::Add(left, right))
+    } else {
+        arena.alloc(ArenaExpr::Mul(left, right))
+    }
+}
+
+/// สร้าง tree แบบเดียวกันด้วย `Box` ทีละ node
+#[must_use]
+pub fn build_boxed_expr(depth: u32) -> BoxedExpr {
+    if depth == 0 {
+        return BoxedExpr::Num(i64::from(depth) + 1);
+    }
+    let left = Box::new(build_boxed_expr(depth - 1));
+    let right = Box::new(build_boxed_expr(depth - 1));
+    if depth.is_multiple_of(2) {
+        BoxedExpr::Add(left, right)
+    } else {
+        BoxedExpr::Mul(left, right)
+    }
+}
+
+/// 🏟️ ตัวอย่างการใช้ Arena allocator เทียบกับ Box ทีละ object
+pub fn demonstrate_arena() {
+    use super::microbench::{measure, speedup};
+
+    println!("🏟️ Arena allocator - จองทีเดียว ใช้ทั้ง tree");
+
+    let mut arena = Arena::with_chunk_size(4 * 1024);
+    let name = arena.alloc_str("สวัสดี arena 🦀");
+    let numbers = arena.alloc_owned(vec![1, 2, 3]);
+    numbers.push(4);
+    println!("   📝 string ใน arena: {name}, vec ใน arena: {numbers:?}");
+
+    let tree = build_arena_expr(&arena, 10);
+    println!(
+        "   🌳 tree 10 ชั้น = {} | {} objects, {} bytes, {} chunks",
+        tree.eval(),
+        arena.allocations(),
+        arena.allocated_bytes(),
+        arena.chunk_count()
+    );
+
+    arena.reset();
+    println!(
+        "   🧹 หลัง reset: {} objects, {} chunk ที่เก็บไว้ใช้ต่อ",
+        arena.allocations(),
+        arena.chunk_count()
+    );
+
+    let boxed = measure("Box ต่อ node", 9, || build_boxed_expr(12).eval());
+    let arena_result = measure("Arena", 9, || {
+        let arena = Arena::new();
+        build_arena_expr(&arena, 12).eval()
+    });
+    println!(
+        "   ⏱️ สร้าง+eval tree 8,191 nodes: Box {:?} vs Arena {:?} ({:.2}x)",
+        boxed.median,
+        arena_result.median,
+        speedup(&boxed, &arena_result)
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_alloc_returns_independent_references() {
+        let arena = Arena::with_chunk_size(64);
+        let a = arena.alloc(1_u8);
+        let b = arena.alloc(2_u64);
+        let c = arena.alloc([3_u16; 5]);
+        *a += 10;
+        *b += 20;
+        c[4] = 9;
+
+        assert_eq!((*a, *b, *c), (11, 22, [3, 3, 3, 3, 9]));
+        assert_eq!(
+            std::ptr::from_mut(b).addr() % std::mem::align_of::<u64>(),
+            0
+        );
+        assert_eq!(arena.allocations(), 3);
+        assert_eq!(arena.allocated_bytes(), 1 + 8 + 10);
+    }
+
+    #[test]
+    fn test_grows_new_chunks_and_handles_large_values() {
+        #[repr(align(64))]
+        struct Aligned(u8);
+
+        let arena = Arena::with_chunk_size(32);
+        let values: Vec<&mut u64> = (0..10).map(|i| arena.alloc(i)).collect();
+        assert!(arena.chunk_count() > 1);
+        assert_eq!(values.iter().map(|v| **v).sum::<u64>(), 45);
+
+        let big = arena.alloc([7_u8; 100]);
+        assert_eq!(big.iter().map(|&b| u32::from(b)).sum::<u32>(), 700);
+
+        let aligned = arena.alloc(Aligned(5));
+        assert_eq!(std::ptr::from_mut(aligned).addr() % 64, 0);
+        assert_eq!(aligned.0, 5);
+
+        let unit = arena.alloc(());
+        assert_eq!(*unit, ());
+    }
+
+    #[test]
+    fn test_drops_run_on_reset_and_drop() {
+        let counter = Rc::new(());
+        let mut arena = Arena::with_chunk_size(64);
+        for _ in 0..10 {
+            arena.alloc_owned(Rc::clone(&counter));
+        }
+        let text = arena.alloc_owned(String::from("owned"));
+        text.push_str(" string");
+        assert_eq!(Rc::strong_count(&counter), 11);
+
+        arena.reset();
+        assert_eq!(Rc::strong_count(&counter), 1);
+        assert_eq!(arena.allocations(), 0);
+        assert_eq!(arena.chunk_count(), 1);
+
+        arena.alloc_owned(Rc::clone(&counter));
+        drop(arena);
+        assert_eq!(Rc::strong_count(&counter), 1);
+    }
+
+    #[test]
+    fn test_alloc_str_and_expression_tree() {
+        let arena = Arena::with_chunk_size(128);
+        assert_eq!(arena.alloc_str("héllo"), "héllo");
+        assert_eq!(arena.alloc_str(""), "");
+
+        let tree = build_arena_expr(&arena, 4);
+        assert_eq!(tree.eval(), build_boxed_expr(4).eval());
+        assert_eq!(arena.allocations(), 31);
+    }
+}
@@ -0,0 +1,357 @@
+//! 💾 Cache Optimization - เขียนโค้ดให้ CPU cache ทำงานให้เรา!
+//!
+//! CPU ไม่ได้อ่าน memory ทีละ byte แต่ดึงมาทีละ cache line (ปกติ 64 bytes)
+//! การจัดวางข้อมูลให้ "สิ่งที่ใช้ด้วยกันอยู่ใกล้กัน" จึงเร็วกว่าได้หลายเท่าโดยไม่ต้องแก้อัลกอริทึม:
+//! - 📦 **`SoA` vs `AoS`** - แยก field ที่ใช้บ่อยออกมาเป็น array ของตัวเอง
+//! - 🧱 **Cache-line padding** - กัน false sharing เมื่อหลาย thread เขียนตัวแปรที่อยู่ line เดียวกัน
+//! - 🧮 **Row-major vs column-major** - เดิน matrix ตามลำดับที่มันถูกเก็บใน memory
+//!
+//! ทุกคู่วัดเวลาด้วย `microbench::measure` ตัวเดียวกับ `cargo run -- --benchmark`
+
+use std::hint::black_box;
+use std::ops::Deref;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::thread;
+
+use super::microbench::{MicroBenchResult, measure, speedup};
+use crate::macros::timed;
+
+/// ขนาด cache line ที่สมมติไว้ (x86-64 และ ARM ส่วนใหญ่)
+pub const CACHE_LINE_SIZE: usize = 64;
+
+// ===== 📦 Array of Structs vs Struct of Arrays =====
+
+/// อนุภาคหนึ่งตัวแบบ `AoS` (array of structs) - 48 bytes แต่ `total_mass` ใช้แค่ 4 bytes ในนั้น
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Particle {
+    pub position: [f32; 3],
+    pub velocity: [f32; 3],
+    pub mass: f32,
+    pub charge: f32,
+    pub id: u64,
+    pub lifetime: f32,
+}
+
+/// อนุภาคทั้งหมดแบบ `SoA` (struct of arrays) - แต่ละ field อยู่ใน `Vec` ของตัวเอง
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ParticlesSoA {
+    pub positions: Vec<[f32; 3]>,
+    pub velocities: Vec<[f32; 3]>,
+    pub masses: Vec<f32>,
+    pub charges: Vec<f32>,
+    pub ids: Vec<u64>,
+    pub lifetimes: Vec<f32>,
+}
+
+impl ParticlesSoA {
+    /// แปลงจาก `AoS` เป็น `SoA`
+    #[must_use]
+    pub fn from_aos(particles: &[Particle]) -> Self {
+        Self {
+            positions: particles.iter().map(|p| p.position).collect(),
+            velocities: particles.iter().map(|p| p.velocity).collect(),
+            masses: particles.iter().map(|p| p.mass).collect(),
+            charges: particles.iter().map(|p| p.charge).collect(),
+            ids: particles.iter().map(|p| p.id).collect(),
+            lifetimes: particles.iter().map(|p| p.lifetime).collect(),
+        }
+    }
+
+    /// จำนวนอนุภาค
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.masses.len()
+    }
+
+    /// ไม่มีอนุภาคเลยหรือไม่
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.masses.is_empty()
+    }
+}
+
+/// สร้างอนุภาค `count` ตัวแบบกำหนดค่าได้ซ้ำ (ไม่สุ่ม)
+#[must_use]
+pub fn make_particles(count: u16) -> Vec<Particle> {
+    (0..count)
+        .map(|i| {
+            let f = f32::from(i);
+            Particle {
+                position: [f, f * 0.5, -f],
+                velocity: [1.0, 0.0, -1.0],
+                mass: f32::from(i % 7) + 1.0,
+                charge: if i % 2 == 0 { 1.0 } else { -1.0 },
+                id: u64::from(i),
+                lifetime: 10.0,
+            }
+        })
+        .collect()
+}
+
+/// ผลรวมมวลแบบ `AoS` - ดึงทั้ง struct เข้า cache เพื่อใช้ field เดียว
+#[must_use]
+pub fn total_mass_aos(particles: &[Particle]) -> f32 {
+    particles.iter().map(|p| p.mass).sum()
+}
+
+/// ผลรวมมวลแบบ `SoA` - ทุก byte ใน cache line เป็นมวลที่เราต้องการ
+#[must_use]
+pub fn total_mass_soa(particles: &ParticlesSoA) -> f32 {
+    particles.masses.iter().sum()
+}
+
+// ===== 🧱 False sharing และ cache-line padding =====
+
+/// ห่อค่าให้ครอบครอง cache line ของตัวเองทั้ง line
+#[derive(Debug, Default)]
+#[repr(align(64))]
+pub struct CachePadded<T>(pub T);
+
+impl<T> Deref for CachePadded<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+/// ตัวนับสองตัวติดกัน - อยู่ cache line เดียวกันแน่นอน 😱
+#[derive(Debug, Default)]
+pub struct SharedLineCounters {
+    pub left: AtomicU64,
+    pub right: AtomicU64,
+}
+
+/// ตัวนับสองตัวที่ถูก pad แยก cache line กัน 😎
+#[derive(Debug, Default)]
+pub struct PaddedCounters {
+    pub left: CachePadded<AtomicU64>,
+    pub right: CachePadded<AtomicU64>,
+}
+
+/// ให้สอง thread เพิ่มค่าตัวนับของตัวเอง `iterations` ครั้ง แล้วคืนผลรวมของทั้งสอง
+///
+/// ตัวนับไม่ได้แชร์กันเชิงตรรกะ แต่ถ้าอยู่ cache line เดียวกัน
+/// CPU สองตัวต้องแย่งความเป็นเจ้าของ line นั้นทุกครั้งที่เขียน (false sharing)
+#[must_use]
+pub fn hammer_counters(left: &AtomicU64, right: &AtomicU64, iterations: u64) -> u64 {
+    thread::scope(|scope| {
+        for counter in [left, right] {
+            scope.spawn(move || {
+                for _ in 0..iterations {
+                    counter.fetch_add(1, Ordering::Relaxed);
+                }
+            });
+        }
+    });
+    left.load(Ordering::Relaxed) + right.load(Ordering::Relaxed)
+}
+
+/// ระยะห่าง (bytes) ระหว่างที่อยู่ของสองค่า
+fn distance<A, B>(a: &A, b: &B) -> usize {
+    let a = std::ptr::from_ref(a).addr();
+    let b = std::ptr::from_ref(b).addr();
+    a.abs_diff(b)
+}
+
+// ===== 🧮 Row-major vs column-major =====
+
+/// Matrix ที่เก็บแบบ row-major: `data[row * cols + col]`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Matrix {
+    rows: usize,
+    cols: usize,
+    data: Vec<f64>,
+}
+
+impl Matrix {
+    /// สร้าง matrix ที่ค่าแต่ละช่องคำนวณจาก `f(row, col)`
+    #[must_use]
+    pub fn from_fn(rows: usize, cols: usize, f: impl Fn(usize, usize) -> f64) -> Self {
+        let data = (0..rows * cols).map(|i| f(i / cols, i % cols)).collect();
+        Self { rows, cols, data }
+    }
+
+    /// จำนวนแถว
+    #[must_use]
+    pub const fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// จำนวนคอลัมน์
+    #[must_use]
+    pub const fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// ค่าที่ตำแหน่ง (`row`, `col`)
+    #[must_use]
+    pub fn get(&self, row: usize, col: usize) -> f64 {
+        self.data[row * self.cols + col]
+    }
+
+    /// ผลรวมแบบเดินทีละแถว - อ่าน memory ต่อเนื่อง ✅
+    #[must_use]
+    pub fn sum_row_major(&self) -> f64 {
+        let mut total = 0.0;
+        for row in 0..self.rows {
+            for col in 0..self.cols {
+                total += self.get(row, col);
+            }
+        }
+        total
+    }
+
+    /// ผลรวมแบบเดินทีละคอลัมน์ - กระโดดทีละ `cols * 8` bytes ❌
+    #[must_use]
+    pub fn sum_column_major(&self) -> f64 {
+        let mut total = 0.0;
+        for col in 0..self.cols {
+            for row in 0..self.rows {
+                total += self.get(row, col);
+            }
+        }
+        total
+    }
+}
+
+/// ค่าตัวอย่างสำหรับ matrix: 1.0 กับ 0.5 สลับกันแบบกระดานหมากรุก
+#[must_use]
+pub const fn checkerboard(row: usize, col: usize) -> f64 {
+    if (row + col).is_multiple_of(2) {
+        1.0
+    } else {
+        0.5
+    }
+}
+
+// ===== ⏱️ Timing harness =====
+
+/// 📊 ผลการเปรียบเทียบหนึ่งคู่: layout ที่ไม่เป็นมิตรกับ cache กับแบบที่เป็นมิตร
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CacheComparison {
+    pub title: &'static str,
+    pub baseline: MicroBenchResult,
+    pub optimized: MicroBenchResult,
+}
+
+impl CacheComparison {
+    /// `optimized` เร็วกว่า `baseline` กี่เท่า
+    #[must_use]
+    pub fn speedup(&self) -> f64 {
+        speedup(&self.baseline, &self.optimized)
+    }
+}
+
+/// ⏱️ วัดทั้งสามคู่ด้วย `samples` ตัวอย่างต่อ workload
+#[must_use]
+pub fn run_cache_comparisons(samples: usize) -> Vec<CacheComparison> {
+    let particles = make_particles(u16::MAX);
+    let soa = ParticlesSoA::from_aos(&particles);
+
+    let shared = SharedLineCounters::default();
+    let padded = PaddedCounters::default();
+
+    let matrix = Matrix::from_fn(512, 512, checkerboard);
+
+    vec![
+        CacheComparison {
+            title: "ผลรวมมวลอนุภาค (AoS → SoA)",
+            baseline: measure("AoS", samples, || total_mass_aos(black_box(&particles))),
+            optimized: measure("SoA", samples, || total_mass_soa(black_box(&soa))),
+        },
+        CacheComparison {
+            title: "2 threads × 100k increments (false sharing → padding)",
+            baseline: measure("same cache line", samples, || {
+                hammer_counters(&shared.left, &shared.right, 100_000)
+            }),
+            optimized: measure("padded", samples, || {
+                hammer_counters(&padded.left, &padded.right, 100_000)
+            }),
+        },
+        CacheComparison {
+            title: "ผลรวม matrix 512×512 (column-major → row-major)",
+            baseline: measure("column-major", samples, || {
+                black_box(&matrix).sum_column_major()
+            }),
+            optimized: measure("row-major", samples, || black_box(&matrix).sum_row_major()),
+        },
+    ]
+}
+
+/// 🎓 สาธิต cache optimization ทั้งสามแบบพร้อมตัวเลขจริง
+#[timed]
+pub fn demonstrate_cache_optimization() {
+    println!(
+        "   📏 Particle = {} bytes ต่อตัว แต่ mass ใช้แค่ {} bytes",
+        std::mem::size_of::<Particle>(),
+        std::mem::size_of::<f32>()
+    );
+
+    let padded = PaddedCounters::default();
+    let shared = SharedLineCounters::default();
+    println!(
+        "   🧱 ระยะห่างระหว่างตัวนับ: ติดกัน {} bytes / padded {} bytes (cache line = {CACHE_LINE_SIZE})",
+        distance(&shared.left, &shared.right),
+        distance(&*padded.left, &*padded.right)
+    );
+
+    for comparison in run_cache_comparisons(7) {
+        println!("   ⏱️ {}", comparison.title);
+        for result in [&comparison.baseline, &comparison.optimized] {
+            println!("      {:<16} median {:>12?}", result.name, result.median);
+        }
+        let ratio = comparison.speedup();
+        let verdict = if ratio >= 1.0 { "✅" } else { "🤔" };
+        println!("      ➜ {verdict} เร็วขึ้น {ratio:.2}x");
+    }
+
+    let cores = thread::available_parallelism().map_or(1, std::num::NonZeroUsize::get);
+    if cores < 2 {
+        println!(
+            "   💡 เครื่องนี้มี CPU {cores} ตัว - false sharing จะไม่เห็นผลเพราะสอง thread ไม่ได้รันพร้อมกันจริง"
+        );
+    }
+    println!("   💡 ตัวเลขจาก debug build มี overhead สูง ลองรันด้วย --release เพื่อดูผลของ cache ชัดๆ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_soa_matches_aos() {
+        let particles = make_particles(1_000);
+        let soa = ParticlesSoA::from_aos(&particles);
+        assert_eq!(soa.len(), particles.len());
+        assert!(!soa.is_empty());
+        assert!((total_mass_aos(&particles) - total_mass_soa(&soa)).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_padded_counters_live_on_separate_cache_lines() {
+        assert_eq!(
+            std::mem::align_of::<CachePadded<AtomicU64>>(),
+            CACHE_LINE_SIZE
+        );
+
+        let padded = PaddedCounters::default();
+        assert!(distance(&*padded.left, &*padded.right) >= CACHE_LINE_SIZE);
+
+        let shared = SharedLineCounters::default();
+        assert!(distance(&shared.left, &shared.right) < CACHE_LINE_SIZE);
+
+        assert_eq!(hammer_counters(&padded.left, &padded.right, 1_000), 2_000);
+        assert_eq!(hammer_counters(&shared.left, &shared.right, 1_000), 2_000);
+    }
+
+    #[test]
+    fn test_matrix_traversal_orders_agree() {
+        let matrix = Matrix::from_fn(33, 17, |row, col| {
+            f64::from(u32::try_from(row * 100 + col).unwrap())
+        });
+        assert_eq!((matrix.rows(), matrix.cols()), (33, 17));
+        assert!((matrix.get(2, 3) - 203.0).abs() < f64::EPSILON);
+        assert!((matrix.sum_row_major() - matrix.sum_column_major()).abs() < 1e-6);
+    }
+}
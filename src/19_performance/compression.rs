@@ -0,0 +1,885 @@
+//! 🗜️ Compression - RLE, Huffman และ LZ77 เขียนเองตั้งแต่ศูนย์!
+//!
+//! ทุกอัลกอริทึมบีบอัดแบบ lossless ทำงานจากความจริงข้อเดียว: ข้อมูลจริงไม่สุ่ม
+//! - 🔁 **RLE** - byte เดิมซ้ำติดกันยาวๆ (ภาพพื้นหลังสีเดียว, ไฟล์ที่เติม 0)
+//! - 🌳 **Huffman** - บาง byte โผล่บ่อยกว่าตัวอื่น (`e` ในภาษาอังกฤษ) → ให้ code ที่สั้นกว่า
+//! - 🪟 **LZ77** - ข้อความช่วงเดิมเคยโผล่มาแล้ว → เขียนแค่ "ย้อนไป x byte แล้ว copy y byte"
+//!
+//! DEFLATE (zip/gzip/png) ก็คือ LZ77 แล้วตามด้วย Huffman นั่นเอง
+//! ส่วนข้อมูลสุ่มจริงๆ ไม่มีอัลกอริทึมไหนบีบได้ - ทุกตัวในไฟล์นี้จะทำให้มัน *ใหญ่ขึ้น* เล็กน้อย
+//!
+//! ทุก codec implement [`Codec`] เดียวกัน จึงวนเทียบกันได้ใน [`demonstrate_compression`]
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::fmt::{self, Write as _};
+
+/// ❌ ข้อมูลที่ส่งให้ `decompress` ไม่ได้มาจาก `compress` ของ codec เดียวกัน
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    /// ข้อมูลจบก่อนที่ควรจะจบ
+    Truncated,
+    /// ข้อมูลขัดกับรูปแบบของ codec
+    Invalid(&'static str),
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Truncated => write!(f, "compressed data ended unexpectedly"),
+            Self::Invalid(reason) => write!(f, "invalid compressed data: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+/// 🧩 interface ร่วมของทุก codec - `decompress(compress(x)) == x` เสมอ
+pub trait Codec {
+    fn name(&self) -> &'static str;
+
+    fn compress(&self, input: &[u8]) -> Vec<u8>;
+
+    /// # Errors
+    /// [`DecodeError`] เมื่อ `input` เสียหายหรือไม่ได้มาจาก codec นี้
+    fn decompress(&self, input: &[u8]) -> Result<Vec<u8>, DecodeError>;
+}
+
+// ---------------------------------------------------------------------------
+// 🔁 Run-length encoding
+// ---------------------------------------------------------------------------
+
+/// 🔁 RLE แบบ `PackBits` (ใช้ใน TIFF/Macintosh)
+///
+/// RLE แบบ `(count, byte)` ตรงๆ ทำให้ข้อความปกติใหญ่ขึ้นเท่าตัว
+/// `PackBits` จึงมี control byte บอกว่าช่วงถัดไปเป็นแบบไหน:
+/// - `0..=127` → byte ดิบ `n + 1` ตัวตามมา (literal)
+/// - `128..=255` → byte ถัดไปซ้ำ `n - 125` ครั้ง (run ยาว 3..=130)
+///
+/// ข้อมูลที่ไม่มี run เลยจึงโตขึ้นแค่ 1 byte ต่อ 128 byte
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Rle;
+
+const RLE_MIN_RUN: usize = 3;
+const RLE_MAX_RUN: usize = 130;
+const RLE_MAX_LITERAL: usize = 128;
+
+impl Codec for Rle {
+    fn name(&self) -> &'static str {
+        "RLE"
+    }
+
+    fn compress(&self, input: &[u8]) -> Vec<u8> {
+        let mut output = Vec::with_capacity(input.len() + input.len() / RLE_MAX_LITERAL + 1);
+        let mut literal_start = 0;
+        let mut i = 0;
+        while i < input.len() {
+            let run = input[i..]
+                .iter()
+                .take(RLE_MAX_RUN)
+                .take_while(|&&b| b == input[i])
+                .count();
+            if run >= RLE_MIN_RUN {
+                flush_literals(&mut output, &input[literal_start..i]);
+                // run ยาว 3..=130 → control 128..=255
+                output.push(u8::try_from(run + 125).expect("run fits in a control byte"));
+                output.push(input[i]);
+                i += run;
+                literal_start = i;
+            } else {
+                i += run;
+            }
+        }
+        flush_literals(&mut output, &input[literal_start..]);
+        output
+    }
+
+    fn decompress(&self, input: &[u8]) -> Result<Vec<u8>, DecodeError> {
+        let mut output = Vec::with_capacity(input.len() * 2);
+        let mut i = 0;
+        while let Some(&control) = input.get(i) {
+            let control = usize::from(control);
+            if control < 128 {
+                let literals = input
+                    .get(i + 1..i + 2 + control)
+                    .ok_or(DecodeError::Truncated)?;
+                output.extend_from_slice(literals);
+                i += 2 + control;
+            } else {
+                let &byte = input.get(i + 1).ok_or(DecodeError::Truncated)?;
+                output.resize(output.len() + control - 125, byte);
+                i += 2;
+            }
+        }
+        Ok(output)
+    }
+}
+
+fn flush_literals(output: &mut Vec<u8>, literals: &[u8]) {
+    for chunk in literals.chunks(RLE_MAX_LITERAL) {
+        output.push(u8::try_from(chunk.len() - 1).expect("chunk fits in a control byte"));
+        output.extend_from_slice(chunk);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// 🌳 Canonical Huffman
+// ---------------------------------------------------------------------------
+
+/// ความยาว code สูงสุด - header เก็บความยาวเป็น nibble (4 bit) ละ symbol
+const HUFFMAN_MAX_BITS: u8 = 15;
+
+/// 🌳 Canonical Huffman coding
+///
+/// Huffman ธรรมดาต้องเก็บทั้ง tree ลง header แต่ *canonical* Huffman
+/// สร้าง code ทั้งหมดได้จาก "ความยาว code ของแต่ละ symbol" อย่างเดียว:
+/// เรียง symbol ตาม (ความยาว, ค่า byte) แล้วแจก code เรียงจากน้อยไปมาก
+///
+/// รูปแบบข้อมูล: `[ความยาวต้นฉบับ (varint)] [ความยาว code 256 ตัว × 4 bit] [bitstream]`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Huffman;
+
+impl Huffman {
+    /// ความยาว code ของแต่ละ byte (0 = ไม่ปรากฏ) ไม่เกิน [`HUFFMAN_MAX_BITS`]
+    #[must_use]
+    pub fn code_lengths(input: &[u8]) -> [u8; 256] {
+        let mut frequencies = [0_u64; 256];
+        for &byte in input {
+            frequencies[usize::from(byte)] += 1;
+        }
+        loop {
+            let lengths = tree_code_lengths(&frequencies);
+            if lengths.iter().all(|&len| len <= HUFFMAN_MAX_BITS) {
+                return lengths;
+            }
+            // tree ลึกเกินไป (ความถี่ลดหลั่นแบบ Fibonacci) - ลดความต่างของความถี่แล้วสร้างใหม่
+            // ยอมเสีย compression เล็กน้อยแลกกับ code ที่ยาวไม่เกินกำหนด
+            for frequency in frequencies.iter_mut().filter(|f| **f > 0) {
+                *frequency = (*frequency >> 1) | 1;
+            }
+        }
+    }
+
+    /// code ของแต่ละ symbol ตามกฎ canonical: `(code, length)` เรียงตามค่า byte
+    #[must_use]
+    pub fn canonical_codes(lengths: &[u8; 256]) -> [(u16, u8); 256] {
+        let mut codes = [(0_u16, 0_u8); 256];
+        let mut code = 0_u16;
+        let mut previous_length = 0;
+        for (symbol, length) in symbols_by_length(lengths) {
+            code <<= length - previous_length;
+            codes[usize::from(symbol)] = (code, length);
+            code += 1;
+            previous_length = length;
+        }
+        codes
+    }
+}
+
+/// symbol ที่ปรากฏจริง เรียงตาม (ความยาว code, ค่า byte)
+fn symbols_by_length(lengths: &[u8; 256]) -> Vec<(u8, u8)> {
+    let mut symbols: Vec<(u8, u8)> = (0..=u8::MAX)
+        .zip(lengths.iter().copied())
+        .filter(|&(_, length)| length > 0)
+        .collect();
+    symbols.sort_by_key(|&(symbol, length)| (length, symbol));
+    symbols
+}
+
+/// สร้าง Huffman tree ด้วย min-heap แล้วคืนความลึกของแต่ละใบ
+fn tree_code_lengths(frequencies: &[u64; 256]) -> [u8; 256] {
+    // node 0..256 คือใบ ส่วน node ที่รวมกันจะต่อท้าย `parent`
+    let mut parent: Vec<usize> = vec![usize::MAX; 256];
+    let mut heap: BinaryHeap<Reverse<(u64, usize)>> = frequencies
+        .iter()
+        .enumerate()
+        .filter(|&(_, &frequency)| frequency > 0)
+        .map(|(symbol, &frequency)| Reverse((frequency, symbol)))
+        .collect();
+
+    let mut lengths = [0_u8; 256];
+    if heap.len() == 1 {
+        // มี symbol เดียวก็ยังต้องใช้อย่างน้อย 1 bit ต่อ symbol
+        if let Some(Reverse((_, symbol))) = heap.peek() {
+            lengths[*symbol] = 1;
+        }
+        return lengths;
+    }
+    while let (Some(Reverse((a_freq, a))), Some(Reverse((b_freq, b)))) = (heap.pop(), heap.pop()) {
+        let node = parent.len();
+        parent.push(usize::MAX);
+        parent[a] = node;
+        parent[b] = node;
+        heap.push(Reverse((a_freq + b_freq, node)));
+        if heap.len() == 1 {
+            break;
+        }
+    }
+    for (symbol, length) in lengths.iter_mut().enumerate() {
+        if frequencies[symbol] == 0 {
+            continue;
+        }
+        let mut node = symbol;
+        while parent[node] != usize::MAX {
+            node = parent[node];
+            *length = length.saturating_add(1);
+        }
+    }
+    lengths
+}
+
+impl Codec for Huffman {
+    fn name(&self) -> &'static str {
+        "Huffman"
+    }
+
+    fn compress(&self, input: &[u8]) -> Vec<u8> {
+        let lengths = Self::code_lengths(input);
+        let codes = Self::canonical_codes(&lengths);
+
+        let mut output = Vec::with_capacity(input.len() / 2 + 140);
+        write_varint(&mut output, input.len() as u64);
+        output.extend(lengths.chunks(2).map(|pair| (pair[0] << 4) | pair[1]));
+
+        let mut bits = BitWriter::new(output);
+        for &byte in input {
+            let (code, length) = codes[usize::from(byte)];
+            bits.write(code, length);
+        }
+        bits.finish()
+    }
+
+    fn decompress(&self, input: &[u8]) -> Result<Vec<u8>, DecodeError> {
+        let (original_len, mut position) = read_varint(input)?;
+        let original_len =
+            usize::try_from(original_len).map_err(|_| DecodeError::Invalid("length overflow"))?;
+        let header = input
+            .get(position..position + 128)
+            .ok_or(DecodeError::Truncated)?;
+        position += 128;
+        let mut lengths = [0_u8; 256];
+        for (pair, &packed) in lengths.chunks_mut(2).zip(header) {
+            pair[0] = packed >> 4;
+            pair[1] = packed & 0x0f;
+        }
+
+        // ตาราง decode แบบ canonical: code แรกและจำนวน code ของแต่ละความยาว
+        let symbols = symbols_by_length(&lengths);
+        let mut count = [0_u32; HUFFMAN_MAX_BITS as usize + 1];
+        for &(_, length) in &symbols {
+            count[usize::from(length)] += 1;
+        }
+        let mut first_code = [0_u32; HUFFMAN_MAX_BITS as usize + 1];
+        let mut first_index = [0_u32; HUFFMAN_MAX_BITS as usize + 1];
+        let (mut code, mut index) = (0_u32, 0_u32);
+        for length in 1..=usize::from(HUFFMAN_MAX_BITS) {
+            code = (code + count[length - 1]) << 1;
+            first_code[length] = code;
+            first_index[length] = index;
+            index += count[length];
+        }
+        if original_len > 0 && symbols.is_empty() {
+            return Err(DecodeError::Invalid("no code lengths for non-empty data"));
+        }
+
+        // ทุก byte ที่ decode ได้ใช้อย่างน้อย 1 bit - ความยาวใน header ที่เกินนี้คือข้อมูลเสีย
+        // และห้ามเชื่อมันตอนจองหน่วยความจำ ไม่งั้น byte เดียวที่เพี้ยนก็ทำให้ขอ memory หลายร้อย GB
+        let body = &input[position..];
+        if original_len > body.len().saturating_mul(8) {
+            return Err(DecodeError::Invalid("length exceeds available bits"));
+        }
+        let mut output = Vec::with_capacity(original_len.min(body.len().saturating_mul(8)));
+        let mut bits = BitReader::new(body);
+        while output.len() < original_len {
+            let mut code = 0_u32;
+            let mut length = 0;
+            loop {
+                length += 1;
+                if length > usize::from(HUFFMAN_MAX_BITS) {
+                    return Err(DecodeError::Invalid("bit pattern matches no code"));
+                }
+                code = (code << 1) | u32::from(bits.read().ok_or(DecodeError::Truncated)?);
+                let offset = code.wrapping_sub(first_code[length]);
+                if offset < count[length] {
+                    output.push(symbols[(first_index[length] + offset) as usize].0);
+                    break;
+                }
+            }
+        }
+        Ok(output)
+    }
+}
+
+/// เขียน bit แบบ MSB ก่อน
+struct BitWriter {
+    output: Vec<u8>,
+    current: u8,
+    used: u8,
+}
+
+impl BitWriter {
+    const fn new(output: Vec<u8>) -> Self {
+        Self {
+            output,
+            current: 0,
+            used: 0,
+        }
+    }
+
+    fn write(&mut self, code: u16, length: u8) {
+        for shift in (0..length).rev() {
+            self.current = (self.current << 1) | u8::from(code >> shift & 1 == 1);
+            self.used += 1;
+            if self.used == 8 {
+                self.output.push(self.current);
+                self.current = 0;
+                self.used = 0;
+            }
+        }
+    }
+
+    /// เติม 0 ให้ครบ byte สุดท้าย - decoder รู้ว่าต้องหยุดจากความยาวต้นฉบับใน header
+    fn finish(mut self) -> Vec<u8> {
+        if self.used > 0 {
+            self.output.push(self.current << (8 - self.used));
+        }
+        self.output
+    }
+}
+
+struct BitReader<'a> {
+    input: &'a [u8],
+    position: usize,
+}
+
+impl<'a> BitReader<'a> {
+    const fn new(input: &'a [u8]) -> Self {
+        Self { input, position: 0 }
+    }
+
+    fn read(&mut self) -> Option<u8> {
+        let byte = self.input.get(self.position / 8)?;
+        let bit = (byte >> (7 - self.position % 8)) & 1;
+        self.position += 1;
+        Some(bit)
+    }
+}
+
+/// LEB128: 7 bit ต่อ byte, bit บนสุด = ยังมี byte ต่อ
+#[allow(clippy::cast_possible_truncation)] // ตัดเหลือ 7 bit ล่างโดยตั้งใจ
+fn write_varint(output: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        output.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    output.push(value as u8);
+}
+
+fn read_varint(input: &[u8]) -> Result<(u64, usize), DecodeError> {
+    let mut value = 0_u64;
+    for (index, &byte) in input.iter().enumerate().take(10) {
+        value |= u64::from(byte & 0x7f) << (7 * index);
+        if byte & 0x80 == 0 {
+            return Ok((value, index + 1));
+        }
+    }
+    Err(if input.len() < 10 {
+        DecodeError::Truncated
+    } else {
+        DecodeError::Invalid("varint longer than 10 bytes")
+    })
+}
+
+// ---------------------------------------------------------------------------
+// 🪟 LZ77 (สไตล์ LZSS)
+// ---------------------------------------------------------------------------
+
+const LZ_WINDOW: usize = 4096;
+const LZ_MIN_MATCH: usize = 3;
+const LZ_MAX_MATCH: usize = LZ_MIN_MATCH + 15;
+const LZ_HASH_BITS: u32 = 12;
+
+/// 🪟 LZ77 แบบ sliding window 4 KiB (รูปแบบเดียวกับ LZSS)
+///
+/// ทุก 8 token นำด้วย flag byte หนึ่งตัว (bit ต่ำสุดก่อน):
+/// - bit `1` → literal 1 byte
+/// - bit `0` → match 2 byte: ระยะย้อนกลับ 12 bit (1..=4096) + ความยาว 4 bit (3..=18)
+///
+/// หา match ด้วย hash chain ของ 3 byte แรก แทนการไล่ทั้ง window
+/// (`max_chain` จำกัดจำนวน candidate ที่ลอง: มาก = บีบได้ดีขึ้น แต่ช้าลง)
+#[derive(Debug, Clone, Copy)]
+pub struct Lz77 {
+    max_chain: usize,
+}
+
+impl Default for Lz77 {
+    fn default() -> Self {
+        Self { max_chain: 64 }
+    }
+}
+
+impl Lz77 {
+    #[must_use]
+    pub const fn with_max_chain(max_chain: usize) -> Self {
+        Self { max_chain }
+    }
+
+    /// match ที่ยาวที่สุดใน window สำหรับ `position` → `(length, distance)`
+    fn longest_match(self, input: &[u8], position: usize, chains: &HashChains) -> (usize, usize) {
+        let (mut best_len, mut best_distance) = (0, 0);
+        if position + LZ_MIN_MATCH > input.len() {
+            return (best_len, best_distance);
+        }
+        let limit = (input.len() - position).min(LZ_MAX_MATCH);
+        for candidate in chains
+            .candidates(input, position)
+            .take_while(|&candidate| position - candidate <= LZ_WINDOW)
+            .take(self.max_chain)
+        {
+            let len = input[candidate..]
+                .iter()
+                .zip(&input[position..position + limit])
+                .take_while(|(a, b)| a == b)
+                .count();
+            if len > best_len {
+                (best_len, best_distance) = (len, position - candidate);
+                if len == limit {
+                    break;
+                }
+            }
+        }
+        (best_len, best_distance)
+    }
+}
+
+/// hash ของ 3 byte → ตำแหน่งล่าสุดที่เจอ และแต่ละตำแหน่งชี้ไปตำแหน่งก่อนหน้าที่ hash เดียวกัน
+struct HashChains {
+    head: Vec<usize>,
+    previous: Vec<usize>,
+}
+
+impl HashChains {
+    const NONE: usize = usize::MAX;
+
+    fn new(len: usize) -> Self {
+        Self {
+            head: vec![Self::NONE; 1 << LZ_HASH_BITS],
+            previous: vec![Self::NONE; len],
+        }
+    }
+
+    fn hash(bytes: &[u8]) -> usize {
+        let key = u32::from(bytes[0]) << 16 | u32::from(bytes[1]) << 8 | u32::from(bytes[2]);
+        (key.wrapping_mul(2_654_435_761) >> (32 - LZ_HASH_BITS)) as usize
+    }
+
+    fn insert(&mut self, input: &[u8], position: usize) {
+        if position + LZ_MIN_MATCH <= input.len() {
+            let hash = Self::hash(&input[position..]);
+            self.previous[position] = self.head[hash];
+            self.head[hash] = position;
+        }
+    }
+
+    /// ตำแหน่งก่อนหน้าที่ hash ตรงกับ `position` ใหม่สุดก่อน
+    fn candidates(&self, input: &[u8], position: usize) -> impl Iterator<Item = usize> + '_ {
+        let link = |position: usize| (position != Self::NONE).then_some(position);
+        let first = self.head[Self::hash(&input[position..])];
+        std::iter::successors(link(first), move |&candidate| {
+            link(self.previous[candidate])
+        })
+    }
+}
+
+impl Codec for Lz77 {
+    fn name(&self) -> &'static str {
+        "LZ77"
+    }
+
+    #[allow(clippy::cast_possible_truncation)] // distance < 4096 และ length < 16 จึงแบ่งลง 2 byte ได้พอดี
+    fn compress(&self, input: &[u8]) -> Vec<u8> {
+        let mut chains = HashChains::new(input.len());
+        let mut output = Vec::with_capacity(input.len());
+        let mut flag_index = 0;
+        let mut tokens = 8;
+        let mut position = 0;
+        while position < input.len() {
+            if tokens == 8 {
+                flag_index = output.len();
+                output.push(0);
+                tokens = 0;
+            }
+
+            let (best_len, best_distance) = self.longest_match(input, position, &chains);
+            if best_len >= LZ_MIN_MATCH {
+                // distance 1..=4096 → 0..=4095 (12 bit), length 3..=18 → 0..=15 (4 bit)
+                let distance = best_distance - 1;
+                let length = best_len - LZ_MIN_MATCH;
+                output.push((distance >> 4) as u8);
+                output.push(((distance & 0x0f) << 4 | length) as u8);
+                for offset in 0..best_len {
+                    chains.insert(input, position + offset);
+                }
+                position += best_len;
+            } else {
+                output[flag_index] |= 1 << tokens;
+                output.push(input[position]);
+                chains.insert(input, position);
+                position += 1;
+            }
+            tokens += 1;
+        }
+        output
+    }
+
+    fn decompress(&self, input: &[u8]) -> Result<Vec<u8>, DecodeError> {
+        let mut output = Vec::with_capacity(input.len() * 2);
+        let mut position = 0;
+        while position < input.len() {
+            let flags = input[position];
+            position += 1;
+            for token in 0..8 {
+                if position == input.len() {
+                    break;
+                }
+                if flags >> token & 1 == 1 {
+                    output.push(input[position]);
+                    position += 1;
+                    continue;
+                }
+                let pair = input
+                    .get(position..position + 2)
+                    .ok_or(DecodeError::Truncated)?;
+                position += 2;
+                let distance = (usize::from(pair[0]) << 4 | usize::from(pair[1] >> 4)) + 1;
+                let length = usize::from(pair[1] & 0x0f) + LZ_MIN_MATCH;
+                let start = output
+                    .len()
+                    .checked_sub(distance)
+                    .ok_or(DecodeError::Invalid("match points before start of data"))?;
+                // copy ทีละ byte เพราะช่วงต้นทางกับปลายทางซ้อนกันได้
+                // (distance 1, length 10 = byte ก่อนหน้าซ้ำ 10 ครั้ง - RLE ฟรีๆ)
+                for index in start..start + length {
+                    output.push(output[index]);
+                }
+            }
+        }
+        Ok(output)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// 📊 Reporting
+// ---------------------------------------------------------------------------
+
+/// ผลการบีบอัดหนึ่งครั้ง
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompressionReport {
+    pub codec: &'static str,
+    pub original: usize,
+    pub compressed: usize,
+}
+
+impl CompressionReport {
+    /// ขนาดหลังบีบ ÷ ขนาดเดิม (น้อยกว่า 1 = เล็กลง)
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn ratio(&self) -> f64 {
+        if self.original == 0 {
+            return 1.0;
+        }
+        self.compressed as f64 / self.original as f64
+    }
+
+    /// ประหยัดไปกี่เปอร์เซ็นต์ (ติดลบ = ใหญ่ขึ้น)
+    #[must_use]
+    pub fn savings_percent(&self) -> f64 {
+        (1.0 - self.ratio()) * 100.0
+    }
+}
+
+/// บีบ `input` แล้วคลายกลับเพื่อยืนยันว่าได้ข้อมูลเดิม ก่อนคืนขนาด
+///
+/// # Errors
+/// [`DecodeError`] เมื่อคลายไม่ได้ หรือ [`DecodeError::Invalid`] เมื่อได้ข้อมูลไม่ตรงต้นฉบับ
+pub fn measure(codec: &dyn Codec, input: &[u8]) -> Result<CompressionReport, DecodeError> {
+    let compressed = codec.compress(input);
+    if codec.decompress(&compressed)? != input {
+        return Err(DecodeError::Invalid("round trip changed the data"));
+    }
+    Ok(CompressionReport {
+        codec: codec.name(),
+        original: input.len(),
+        compressed: compressed.len(),
+    })
+}
+
+/// 🔗 ต่อ codec เป็นสาย เช่น LZ77 → Huffman (แนวคิดเดียวกับ DEFLATE)
+pub struct Chain {
+    name: &'static str,
+    stages: Vec<Box<dyn Codec>>,
+}
+
+impl Chain {
+    #[must_use]
+    pub fn new(name: &'static str, stages: Vec<Box<dyn Codec>>) -> Self {
+        Self { name, stages }
+    }
+}
+
+impl Codec for Chain {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn compress(&self, input: &[u8]) -> Vec<u8> {
+        self.stages
+            .iter()
+            .fold(input.to_vec(), |data, stage| stage.compress(&data))
+    }
+
+    fn decompress(&self, input: &[u8]) -> Result<Vec<u8>, DecodeError> {
+        self.stages
+            .iter()
+            .rev()
+            .try_fold(input.to_vec(), |data, stage| stage.decompress(&data))
+    }
+}
+
+/// codec ทั้งหมดในบทนี้ รวม LZ77 + Huffman
+#[must_use]
+pub fn all_codecs() -> Vec<Box<dyn Codec>> {
+    vec![
+        Box::new(Rle),
+        Box::new(Huffman),
+        Box::new(Lz77::default()),
+        Box::new(Chain::new(
+            "LZ77+Huffman",
+            vec![Box::new(Lz77::default()), Box::new(Huffman)],
+        )),
+    ]
+}
+
+/// ชุดข้อมูลตัวอย่างที่มีลักษณะต่างกัน - `size` byte ต่อชุด
+#[must_use]
+pub fn sample_corpora(size: usize) -> Vec<(&'static str, Vec<u8>)> {
+    let fill = |pattern: &[u8]| {
+        pattern
+            .iter()
+            .copied()
+            .cycle()
+            .take(size)
+            .collect::<Vec<u8>>()
+    };
+
+    let mut runs = Vec::with_capacity(size);
+    let mut value = 0_u8;
+    while runs.len() < size {
+        let run = 20 + usize::from(value) % 50;
+        runs.extend(std::iter::repeat_n(value, run.min(size - runs.len())));
+        value = value.wrapping_add(37);
+    }
+
+    let mut logs = String::new();
+    let mut line = 0_usize;
+    while logs.len() < size {
+        let level = ["INFO", "WARN", "INFO", "DEBUG"][line % 4];
+        let _ = writeln!(
+            logs,
+            "{{\"ts\":{},\"level\":\"{level}\",\"msg\":\"request handled\",\"status\":{}}}",
+            1_700_000_000 + line * 7,
+            [200, 200, 404, 500][line % 4]
+        );
+        line += 1;
+    }
+    logs.truncate(size);
+
+    // xorshift - "สุ่ม" พอที่จะไม่มีโครงสร้างให้บีบ
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let noise = (0..size)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state.to_le_bytes()[0]
+        })
+        .collect();
+
+    vec![
+        ("runs", runs),
+        (
+            "english",
+            fill(b"the quick brown fox jumps over the lazy dog while the cat sleeps. "),
+        ),
+        (
+            "thai",
+            fill("ภาษา Rust ปลอดภัยและเร็ว เขียนโปรแกรมระบบได้อย่างมั่นใจ ".as_bytes()),
+        ),
+        ("json_logs", logs.into_bytes()),
+        ("random", noise),
+    ]
+}
+
+/// 🎪 เดโม: บีบทุก corpus ด้วยทุก codec แล้วพิมพ์ตาราง ratio
+pub fn demonstrate_compression() {
+    println!("🗜️ Compression - ข้อมูลแต่ละแบบเหมาะกับอัลกอริทึมต่างกัน");
+
+    let codecs = all_codecs();
+    print!("   {:<10} {:>7}", "corpus", "bytes");
+    for codec in &codecs {
+        print!(" {:>13}", codec.name());
+    }
+    println!();
+
+    for (corpus, data) in sample_corpora(16 * 1024) {
+        print!("   {corpus:<10} {:>7}", data.len());
+        for codec in &codecs {
+            match measure(codec.as_ref(), &data) {
+                Ok(report) => print!(" {:>12.1}%", report.savings_percent()),
+                Err(error) => print!(" ❌ {error}"),
+            }
+        }
+        println!();
+    }
+    println!("   💡 ตัวเลขคือ % ที่ประหยัดได้ - ติดลบแปลว่าไฟล์ใหญ่ขึ้น (random บีบไม่ได้เสมอ)");
+
+    let text = b"abracadabra abracadabra";
+    let huffman = Huffman::canonical_codes(&Huffman::code_lengths(text));
+    let mut table: Vec<String> = text
+        .iter()
+        .copied()
+        .collect::<std::collections::BTreeSet<u8>>()
+        .into_iter()
+        .map(|byte| {
+            let (code, length) = huffman[usize::from(byte)];
+            format!(
+                "'{}'={:0width$b}",
+                char::from(byte),
+                code,
+                width = usize::from(length)
+            )
+        })
+        .collect();
+    table.sort_by_key(String::len);
+    println!(
+        "   🌳 canonical Huffman ของ \"abracadabra\": {}",
+        table.join(" ")
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn corpora() -> Vec<(&'static str, Vec<u8>)> {
+        let mut corpora = sample_corpora(5_000);
+        corpora.extend([
+            ("empty", Vec::new()),
+            ("single", vec![42]),
+            ("one_symbol", vec![7; 1_000]),
+            ("all_bytes", (0..=u8::MAX).collect()),
+            ("long_run", vec![0; 100_000]),
+        ]);
+        corpora
+    }
+
+    #[test]
+    fn test_every_codec_round_trips_every_corpus() {
+        for codec in all_codecs() {
+            for (corpus, data) in corpora() {
+                let compressed = codec.compress(&data);
+                assert_eq!(
+                    codec.decompress(&compressed).as_deref(),
+                    Ok(data.as_slice()),
+                    "{} on {corpus}",
+                    codec.name()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_each_codec_wins_on_its_kind_of_data() {
+        let corpora: std::collections::HashMap<_, _> =
+            sample_corpora(16 * 1024).into_iter().collect();
+        let ratio =
+            |codec: &dyn Codec, corpus: &str| measure(codec, &corpora[corpus]).unwrap().ratio();
+
+        assert!(ratio(&Rle, "runs") < 0.1);
+        assert!(ratio(&Rle, "english") > 1.0, "no runs = small overhead");
+        assert!(ratio(&Huffman, "english") < 0.6);
+        assert!(ratio(&Lz77::default(), "json_logs") < 0.5);
+        assert!(ratio(&Lz77::default(), "json_logs") < ratio(&Huffman, "json_logs"));
+        for codec in all_codecs() {
+            assert!(
+                ratio(codec.as_ref(), "random") >= 1.0,
+                "{} beat entropy",
+                codec.name()
+            );
+        }
+    }
+
+    #[test]
+    fn test_huffman_codes_are_canonical_and_prefix_free() {
+        let lengths = Huffman::code_lengths(b"aaaaaaaabbbbccd");
+        assert_eq!(
+            [
+                lengths[usize::from(b'a')],
+                lengths[usize::from(b'b')],
+                lengths[usize::from(b'c')],
+                lengths[usize::from(b'd')]
+            ],
+            [1, 2, 3, 3]
+        );
+        let codes = Huffman::canonical_codes(&lengths);
+        assert_eq!(codes[usize::from(b'a')], (0b0, 1));
+        assert_eq!(codes[usize::from(b'b')], (0b10, 2));
+        assert_eq!(codes[usize::from(b'c')], (0b110, 3));
+        assert_eq!(codes[usize::from(b'd')], (0b111, 3));
+    }
+
+    #[test]
+    fn test_huffman_limits_code_length() {
+        // ความถี่แบบ Fibonacci ทำให้ tree ปกติลึกเท่าจำนวน symbol
+        let mut data = Vec::new();
+        let (mut a, mut b) = (1_usize, 1_usize);
+        for symbol in 0..25_u8 {
+            data.extend(std::iter::repeat_n(symbol, a));
+            (a, b) = (b, a + b);
+        }
+        let lengths = Huffman::code_lengths(&data);
+        assert!(lengths.iter().all(|&len| len <= HUFFMAN_MAX_BITS));
+        assert_eq!(Huffman.decompress(&Huffman.compress(&data)).unwrap(), data);
+    }
+
+    #[test]
+    fn test_corrupt_input_is_rejected() {
+        assert_eq!(Rle.decompress(&[5, b'a']), Err(DecodeError::Truncated));
+        assert_eq!(Huffman.decompress(&[10, 0, 0]), Err(DecodeError::Truncated));
+        assert_eq!(
+            Lz77::default().decompress(&[0b0, 0x00, 0x10]),
+            Err(DecodeError::Invalid("match points before start of data"))
+        );
+        let mut compressed = Huffman.compress(b"hello huffman");
+        compressed.truncate(compressed.len() - 2);
+        assert_eq!(Huffman.decompress(&compressed), Err(DecodeError::Truncated));
+    }
+
+    #[test]
+    fn test_corrupt_huffman_length_header_is_rejected() {
+        // varint ความยาวที่ถูกแก้ให้ใหญ่มาก ต้องได้ error แทนการจอง memory ตามนั้น
+        let compressed = Huffman.compress(b"hello huffman");
+        let mut corrupted = vec![0xff; 7];
+        corrupted.push(0x7f);
+        corrupted.extend_from_slice(&compressed[1..]);
+        assert_eq!(
+            Huffman.decompress(&corrupted),
+            Err(DecodeError::Invalid("length exceeds available bits"))
+        );
+
+        let chain = Chain::new("rle+huffman", vec![Box::new(Rle), Box::new(Huffman)]);
+        let mut corrupted = chain.compress(b"aaaaaaaaaabbbbbbbbbb");
+        corrupted[0] = 0xff;
+        assert!(chain.decompress(&corrupted).is_err());
+    }
+}
@@ -375,7 +375,7 @@ pub fn demonstrate_cpu_optimization() {
     println!("\n🧮 SIMD Optimization Workshop:");
     let a: Vec<f32> = (0..1000).map(|i| i as f32).collect();
     let b: Vec<f32> = (0..1000).map(|i| (i * 2) as f32).collect();
-    let result_simd = vec![0.0; 1000];
+    let mut result_simd = vec![0.0; 1000];
     let mut result_scalar = vec![0.0; 1000];
     
     // SIMD version (unsafe)
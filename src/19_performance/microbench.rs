@@ -0,0 +1,316 @@
+//! ⏱️ Microbenchmarks - พิสูจน์คำกล่าวอ้างของบท Performance ด้วยตัวเลขจริง!
+//!
+//! บทนี้พูดไว้หลายอย่าง เช่น "SIMD เร็วกว่า", "zero-copy ไม่ต้อง clone" หรือ "lock-free ไม่ต้องรอ lock"
+//! โมดูลนี้รวบรวม workload คู่เปรียบเทียบไว้ที่เดียว ให้ทั้ง `cargo bench` (criterion)
+//! และ `run_microbench()` ในห้องเรียนวัดโค้ดชุดเดียวกัน 🎓
+//!
+//! 📏 การจับเวลาแบบ in-process ทำให้นิ่งด้วย:
+//! - 🔥 warm-up ก่อนวัด
+//! - 🔁 ปรับจำนวนรอบต่อ sample จนแต่ละ sample นานพอ (ลด noise ของ timer)
+//! - 📊 รายงาน median แทนค่าเฉลี่ย (ไม่โดน outlier ดึง)
+
+use super::simd_vectorization::SimdMath;
+use super::zero_copy::ZeroCopyStr;
+use crate::concurrency::mpmc_channel::LockFreeRingBuffer;
+use std::collections::{HashMap, VecDeque};
+use std::hash::BuildHasher;
+use std::hint::black_box;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+/// เวลาขั้นต่ำของแต่ละ sample
+const MIN_SAMPLE_TIME: Duration = Duration::from_millis(2);
+
+/// จำนวนรอบสูงสุดต่อ sample (กันไม่ให้ workload ที่เร็วมากวนนานเกินไป)
+const MAX_BATCH: u32 = 1 << 20;
+
+// ===== Workloads ที่ benches/chapter_claims.rs ใช้ร่วมกัน =====
+
+/// ➕ รวมเลขแบบ scalar ทีละตัว
+#[must_use]
+pub fn scalar_sum(data: &[f32]) -> f32 {
+    let mut total = 0.0;
+    for &value in data {
+        total += value;
+    }
+    total
+}
+
+/// ⚡ รวมเลขแบบ SIMD-style ทีละ 4 lanes
+#[must_use]
+pub fn simd_sum(data: &[f32]) -> f32 {
+    SimdMath::sum_f32(data)
+}
+
+/// 🗂️ ค้นหาทุก key ใน `HashMap` แล้วรวมค่าที่เจอ
+#[must_use]
+pub fn hashmap_lookup<S: BuildHasher>(map: &HashMap<u32, u32, S>, keys: &[u32]) -> u64 {
+    keys.iter()
+        .filter_map(|key| map.get(key))
+        .map(|&value| u64::from(value))
+        .sum()
+}
+
+/// 📋 ค้นหาทุก key ด้วยการไล่ `Vec` แบบ linear แล้วรวมค่าที่เจอ
+#[must_use]
+pub fn vec_lookup(pairs: &[(u32, u32)], keys: &[u32]) -> u64 {
+    keys.iter()
+        .filter_map(|key| pairs.iter().find(|(k, _)| k == key))
+        .map(|&(_, value)| u64::from(value))
+        .sum()
+}
+
+/// 🪶 แยกคำแบบ zero-copy - ได้ slice ชี้เข้าไปใน input เดิม
+#[must_use]
+pub fn parse_zero_copy(input: &str) -> usize {
+    let words: Vec<ZeroCopyStr<'_>> = ZeroCopyStr::from_str(input).split_whitespace().collect();
+    words.iter().map(ZeroCopyStr::len).sum()
+}
+
+/// 🐘 แยกคำแบบ clone ทุกคำเป็น `String`
+#[must_use]
+pub fn parse_cloning(input: &str) -> usize {
+    let words: Vec<String> = input.split_whitespace().map(str::to_string).collect();
+    words.iter().map(String::len).sum()
+}
+
+/// 🔓 ให้หลาย thread push แล้ว pop ผ่าน `LockFreeRingBuffer` (Vyukov) คืนจำนวนที่ pop ได้ทั้งหมด
+///
+/// ความจุเท่ากับจำนวนงานทั้งหมด `push` จึงไม่มีวันต้องรอช่องว่าง - วัดแค่ต้นทุนการจองช่องด้วย CAS
+#[must_use]
+pub fn lock_free_queue_round_trip(threads: usize, ops_per_thread: usize) -> usize {
+    let queue = LockFreeRingBuffer::new((threads * ops_per_thread).max(1));
+    let popped = AtomicUsize::new(0);
+    std::thread::scope(|scope| {
+        for _ in 0..threads {
+            scope.spawn(|| {
+                for value in 0..ops_per_thread {
+                    queue.push(value);
+                }
+                let count = (0..ops_per_thread)
+                    .filter(|_| queue.try_pop().is_some())
+                    .count();
+                popped.fetch_add(count, Ordering::Relaxed);
+            });
+        }
+    });
+    // `try_pop` คืน `None` ได้ถ้าช่องถัดไปถูกจองแต่ producer ยังเขียนไม่เสร็จ
+    // หลัง join ทุก thread แล้วไม่มีใครค้างกลางทาง จึงเก็บที่เหลือให้ครบตรงนี้
+    let mut popped = popped.into_inner();
+    while queue.try_pop().is_some() {
+        popped += 1;
+    }
+    popped
+}
+
+/// 🔒 งานเดียวกันแต่ใช้ `Mutex<VecDeque<_>>`
+#[must_use]
+pub fn mutex_queue_round_trip(threads: usize, ops_per_thread: usize) -> usize {
+    let queue = Mutex::new(VecDeque::with_capacity(threads * ops_per_thread));
+    let lock = || {
+        queue
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    };
+    let popped = AtomicUsize::new(0);
+    std::thread::scope(|scope| {
+        for _ in 0..threads {
+            scope.spawn(|| {
+                for value in 0..ops_per_thread {
+                    lock().push_back(value);
+                }
+                let count = (0..ops_per_thread)
+                    .filter(|_| lock().pop_front().is_some())
+                    .count();
+                popped.fetch_add(count, Ordering::Relaxed);
+            });
+        }
+    });
+    popped.into_inner()
+}
+
+/// 📝 ข้อความตัวอย่างสำหรับ parsing benchmarks
+#[must_use]
+pub fn sample_text(words: usize) -> String {
+    const WORDS: [&str; 6] = ["rust", "ownership", "zero-copy", "สวัสดี", "borrow", "🦀"];
+    (0..words)
+        .map(|i| WORDS[i % WORDS.len()])
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// 🗂️ ข้อมูลสำหรับ lookup benchmarks: map, คู่ key/value เดียวกันใน `Vec` และ keys ที่จะค้น
+pub type LookupFixture = (HashMap<u32, u32>, Vec<(u32, u32)>, Vec<u32>);
+
+/// 🗂️ สร้างข้อมูลตัวอย่างสำหรับ lookup benchmarks ขนาด `size`
+#[must_use]
+pub fn lookup_fixture(size: u32) -> LookupFixture {
+    let pairs: Vec<(u32, u32)> = (0..size).map(|k| (k * 7, k)).collect();
+    let map = pairs.iter().copied().collect();
+    let keys = (0..size).map(|k| (k * 7 * 13) % (size * 7)).collect();
+    (map, pairs, keys)
+}
+
+// ===== การจับเวลา =====
+
+/// 📊 ผลการวัดหนึ่ง workload (เวลาต่อการเรียกหนึ่งครั้ง)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MicroBenchResult {
+    pub name: String,
+    pub samples: usize,
+    pub batch: u32,
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+/// ⏱️ วัดเวลาของ `f` แบบ warm-up + ปรับ batch + median
+///
+/// # Panics
+///
+/// Panics ถ้า `samples` เป็น 0
+pub fn measure<R>(name: &str, samples: usize, mut f: impl FnMut() -> R) -> MicroBenchResult {
+    assert!(samples > 0, "need at least one sample");
+
+    // 🔥 warm-up และหาจำนวนรอบที่ทำให้ sample นานพอ
+    let mut batch = 1;
+    while batch < MAX_BATCH && time_batch(&mut f, batch) < MIN_SAMPLE_TIME {
+        batch *= 2;
+    }
+
+    let mut timings: Vec<Duration> = (0..samples)
+        .map(|_| time_batch(&mut f, batch) / batch)
+        .collect();
+    timings.sort_unstable();
+
+    MicroBenchResult {
+        name: name.to_string(),
+        samples,
+        batch,
+        min: timings[0],
+        median: timings[timings.len() / 2],
+        max: timings[timings.len() - 1],
+    }
+}
+
+fn time_batch<R>(f: &mut impl FnMut() -> R, batch: u32) -> Duration {
+    let start = Instant::now();
+    for _ in 0..batch {
+        black_box(f());
+    }
+    start.elapsed()
+}
+
+/// อัตราส่วนความเร็ว: `baseline` ใช้เวลามากกว่า `candidate` กี่เท่า
+#[must_use]
+pub fn speedup(baseline: &MicroBenchResult, candidate: &MicroBenchResult) -> f64 {
+    let candidate = candidate.median.as_secs_f64();
+    if candidate == 0.0 {
+        return f64::INFINITY;
+    }
+    baseline.median.as_secs_f64() / candidate
+}
+
+/// 🎓 รัน microbenchmarks ทุกคู่และพิมพ์ตารางเปรียบเทียบ - สำหรับใช้ในห้องเรียน
+pub fn run_microbench() {
+    println!("⏱️ === Microbenchmarks: พิสูจน์คำกล่าวอ้างของบท Performance === ⏱️");
+    println!("💡 ตัวเลขเป็น median ต่อการเรียกหนึ่งครั้ง (build แบบ --release จะแม่นกว่า)");
+
+    let numbers: Vec<f32> = (0..4_096u16).map(f32::from).collect();
+    let (map, pairs, keys) = lookup_fixture(256);
+    let text = sample_text(2_000);
+
+    let comparisons = [
+        (
+            measure("scalar sum", 15, || scalar_sum(black_box(&numbers))),
+            measure("SIMD sum", 15, || simd_sum(black_box(&numbers))),
+        ),
+        (
+            measure("Vec lookup", 15, || vec_lookup(black_box(&pairs), &keys)),
+            measure("HashMap lookup", 15, || {
+                hashmap_lookup(black_box(&map), &keys)
+            }),
+        ),
+        (
+            measure("cloning parse", 15, || parse_cloning(black_box(&text))),
+            measure("zero-copy parse", 15, || parse_zero_copy(black_box(&text))),
+        ),
+        (
+            measure("Mutex<VecDeque>", 5, || mutex_queue_round_trip(4, 1_000)),
+            measure("lock-free queue", 5, || {
+                lock_free_queue_round_trip(4, 1_000)
+            }),
+        ),
+    ];
+
+    println!(
+        "\n{:<20} {:>12} {:>12} {:>12}",
+        "workload", "min", "median", "max"
+    );
+    for (baseline, candidate) in &comparisons {
+        for result in [baseline, candidate] {
+            println!(
+                "{:<20} {:>12?} {:>12?} {:>12?}",
+                result.name, result.min, result.median, result.max
+            );
+        }
+        let ratio = speedup(baseline, candidate);
+        let verdict = if ratio >= 1.0 { "✅" } else { "🤔" };
+        println!(
+            "   ➜ {verdict} {} / {}: {ratio:.2}x (มากกว่า 1 = เร็วกว่า)\n",
+            candidate.name, baseline.name
+        );
+    }
+    println!("🤔 ผลที่ค้านคำกล่าวอ้างมักมาจาก debug build หรือจำนวน thread น้อย - ลองเทียบกับ --release");
+    println!("🏃‍♂️ ต้องการสถิติละเอียดกว่านี้? รัน: cargo bench --bench chapter_claims");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paired_workloads_agree() {
+        let numbers: Vec<f32> = (0..1_027u16).map(f32::from).collect();
+        assert!((scalar_sum(&numbers) - simd_sum(&numbers)).abs() < 1.0);
+
+        let (map, pairs, keys) = lookup_fixture(64);
+        assert_eq!(hashmap_lookup(&map, &keys), vec_lookup(&pairs, &keys));
+        assert_eq!(hashmap_lookup(&map, &keys), (0..64).sum::<u64>());
+
+        let text = sample_text(50);
+        assert_eq!(parse_zero_copy(&text), parse_cloning(&text));
+
+        assert_eq!(lock_free_queue_round_trip(4, 100), 400);
+        assert_eq!(mutex_queue_round_trip(4, 100), 400);
+    }
+
+    #[test]
+    fn test_measure_reports_ordered_statistics() {
+        let mut calls = 0_u64;
+        let result = measure("counter", 5, || {
+            calls += 1;
+            calls
+        });
+        assert_eq!(result.name, "counter");
+        assert_eq!(result.samples, 5);
+        assert!(result.batch >= 1);
+        assert!(result.min <= result.median && result.median <= result.max);
+        assert!(calls >= u64::from(result.batch) * 5);
+    }
+
+    #[test]
+    fn test_speedup() {
+        let make = |nanos| MicroBenchResult {
+            name: String::new(),
+            samples: 1,
+            batch: 1,
+            min: Duration::from_nanos(nanos),
+            median: Duration::from_nanos(nanos),
+            max: Duration::from_nanos(nanos),
+        };
+        assert!((speedup(&make(300), &make(100)) - 3.0).abs() < f64::EPSILON);
+        assert!(speedup(&make(300), &make(0)).is_infinite());
+    }
+}
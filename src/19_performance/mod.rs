@@ -9,10 +9,15 @@ pub mod cpu_optimization;
 pub mod profiling_benchmarking;
 pub mod zero_copy;
 pub mod simd_vectorization;
+pub mod microbench;
+pub mod arena;
+pub mod parallel_processing;
+pub mod cache_optimization;
+pub mod compression;
+
+pub use microbench::run_microbench;
+
 // TODO: Add these modules when implemented
-// pub mod parallel_processing;
-// pub mod cache_optimization;
-// pub mod allocation_strategies;
 // pub mod compile_time_optimization;
 // pub mod runtime_optimization;
 
@@ -47,6 +52,36 @@ pub fn run_performance_examples() {
     
     println!();
     
+    // Arena Allocation
+    println!("🏟️ Arena Allocation Workshop:");
+    arena::demonstrate_arena();
+    
+    println!();
+    
+    // Parallel Processing
+    println!("🔄 Parallel Processing Workshop:");
+    parallel_processing::demonstrate_parallel();
+    
+    println!();
+    
+    // Cache Optimization
+    println!("💾 Cache Optimization Workshop:");
+    cache_optimization::demonstrate_cache_optimization();
+    
+    println!();
+    
+    // Caching
+    println!("🗃️ Caching Workshop:");
+    crate::cache::demonstrate_cache();
+    
+    println!();
+    
+    // Compression
+    println!("🗜️ Compression Workshop:");
+    compression::demonstrate_compression();
+    
+    println!();
+    
     // Profiling and Benchmarking
     println!("📊 Profiling and Benchmarking Workshop:");
     profiling_benchmarking::demonstrate_profiling_benchmarking();
@@ -54,15 +89,6 @@ pub fn run_performance_examples() {
     println!();
     
     // TODO: Add these demonstrations when modules are implemented
-    // println!("🔄 Parallel Processing:");
-    // parallel_processing::demonstrate_parallel();
-    // 
-    // println!("💾 Cache Optimization:");
-    // cache_optimization::demonstrate_cache_optimization();
-    // 
-    // println!("📦 Allocation Strategies:");
-    // allocation_strategies::demonstrate_allocation();
-    // 
     // println!("🔧 Compile-time Optimization:");
     // compile_time_optimization::demonstrate_compile_time();
     // 
@@ -0,0 +1,305 @@
+//! 🔄 Parallel Processing - แบ่งงานเป็นชิ้นแล้วกระจายให้ thread pool แบบ rayon!
+//!
+//! rayon ให้เราเขียน `.par_iter().map(...)` แล้วมันจะแบ่งงานให้หลาย thread เอง
+//! ไฟล์นี้สร้าง API หน้าตาคล้ายกันบน `concurrency::thread_pool::ThreadPool` ของเรา:
+//! - 🗺️ `par_map` - map ทุกสมาชิกแบบขนาน ผลลัพธ์เรียงตามลำดับเดิม
+//! - ➕ `par_sum` - รวมค่าแบบแบ่ง chunk โดยแชร์ข้อมูลผ่าน `Arc<[T]>` ไม่ต้อง copy
+//! - 🧮 `par_reduce` - fold แต่ละ chunk แล้วรวมผลตามลำดับ chunk
+//!
+//! ⚠️ งานที่ส่งเข้า pool ต้องเป็น `'static` จึงต้องย้าย ownership ของข้อมูลเข้าไป
+//! (rayon หลบข้อจำกัดนี้ด้วย scoped jobs - เทียบได้กับ `std::thread::scope`)
+
+use std::hint::black_box;
+use std::iter::Sum;
+use std::ops::Range;
+use std::sync::{Arc, mpsc};
+
+use super::microbench::{MicroBenchResult, measure, speedup};
+use crate::concurrency::thread_pool::ThreadPool;
+use crate::macros::timed;
+
+/// จำนวน chunk ต่อ worker - มากกว่า 1 เพื่อให้ worker ที่เสร็จก่อนไปช่วยงานที่เหลือได้
+const CHUNKS_PER_WORKER: usize = 4;
+
+/// ขนาด chunk ที่แบ่ง `len` ชิ้นให้ได้ประมาณ `workers * CHUNKS_PER_WORKER` chunk
+fn chunk_size(len: usize, workers: usize) -> usize {
+    len.div_ceil(workers * CHUNKS_PER_WORKER).max(1)
+}
+
+/// ส่งแต่ละ chunk ไปทำใน pool แล้วคืนผลลัพธ์ของทุก chunk เรียงตามลำดับเดิม
+///
+/// # Panics
+///
+/// Panics ถ้ามี chunk ใดที่ `job` panic (ผลลัพธ์ของ chunk นั้นจะหายไป)
+fn run_chunks<C, A, J>(pool: &ThreadPool, chunks: impl Iterator<Item = C>, job: J) -> Vec<A>
+where
+    C: Send + 'static,
+    A: Send + 'static,
+    J: Fn(C) -> A + Send + Sync + 'static,
+{
+    let job = Arc::new(job);
+    let (sender, receiver) = mpsc::channel();
+
+    let mut submitted = 0;
+    for (index, chunk) in chunks.enumerate() {
+        let job = Arc::clone(&job);
+        let sender = sender.clone();
+        pool.execute(move || {
+            // ถ้าฝั่งรับเลิกรอไปแล้วก็ไม่มีใครสนใจผลลัพธ์นี้
+            let _ = sender.send((index, job(chunk)));
+        });
+        submitted += 1;
+    }
+    drop(sender);
+
+    let mut results: Vec<Option<A>> = (0..submitted).map(|_| None).collect();
+    for (index, result) in receiver {
+        results[index] = Some(result);
+    }
+    results
+        .into_iter()
+        .map(|result| result.expect("a parallel job panicked"))
+        .collect()
+}
+
+/// 🗺️ map ทุกสมาชิกของ `items` ด้วย `f` แบบขนาน - ผลลัพธ์เรียงตรงกับ `items.into_iter().map(f)`
+///
+/// # Panics
+///
+/// Panics ถ้า `f` panic กับสมาชิกตัวใดตัวหนึ่ง
+pub fn par_map<T, R, F>(pool: &ThreadPool, items: Vec<T>, f: F) -> Vec<R>
+where
+    T: Send + 'static,
+    R: Send + 'static,
+    F: Fn(T) -> R + Send + Sync + 'static,
+{
+    let size = chunk_size(items.len(), pool.workers());
+    let mut items = items.into_iter();
+    let chunks = std::iter::from_fn(move || {
+        let chunk: Vec<T> = items.by_ref().take(size).collect();
+        (!chunk.is_empty()).then_some(chunk)
+    });
+
+    run_chunks(pool, chunks, move |chunk: Vec<T>| {
+        chunk.into_iter().map(&f).collect::<Vec<R>>()
+    })
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+/// ➕ ผลรวมแบบขนาน - แต่ละงานรวมช่วง index ของตัวเองจาก `Arc` ที่แชร์กัน (ไม่ copy ข้อมูล)
+///
+/// # Panics
+///
+/// Panics ถ้าการบวกใน worker panic (เช่น overflow ใน debug build)
+pub fn par_sum<T>(pool: &ThreadPool, data: &Arc<[T]>) -> T
+where
+    T: Copy + Sum + Send + Sync + 'static,
+{
+    let size = chunk_size(data.len(), pool.workers());
+    let ranges = (0..data.len())
+        .step_by(size)
+        .map(move |start| start..(start + size));
+    let len = data.len();
+    let shared = Arc::clone(data);
+
+    run_chunks(pool, ranges, move |range: Range<usize>| {
+        shared[range.start..range.end.min(len)]
+            .iter()
+            .copied()
+            .sum::<T>()
+    })
+    .into_iter()
+    .sum()
+}
+
+/// 🧮 reduce แบบขนาน: fold แต่ละ chunk เริ่มจาก `identity` แล้วรวมผลของทุก chunk ตามลำดับ
+///
+/// `op` ต้อง associative และ `identity` ต้องเป็นค่ากลางของ `op`
+/// (เช่น `0` กับ `+`) ผลลัพธ์จึงจะเท่ากับ `items.into_iter().fold(identity, op)`
+/// ไม่จำเป็นต้อง commutative เพราะผลของ chunk ถูกรวมตามลำดับเดิมเสมอ
+///
+/// # Panics
+///
+/// Panics ถ้า `op` panic
+pub fn par_reduce<T, F>(pool: &ThreadPool, items: Vec<T>, identity: T, op: F) -> T
+where
+    T: Clone + Send + Sync + 'static,
+    F: Fn(T, T) -> T + Send + Sync + 'static,
+{
+    let size = chunk_size(items.len(), pool.workers());
+    let mut rest = items.into_iter();
+    let chunks = std::iter::from_fn(move || {
+        let chunk: Vec<T> = rest.by_ref().take(size).collect();
+        (!chunk.is_empty()).then_some(chunk)
+    });
+
+    let op = Arc::new(op);
+    let chunk_op = Arc::clone(&op);
+    let chunk_identity = identity.clone();
+    run_chunks(pool, chunks, move |chunk: Vec<T>| {
+        chunk
+            .into_iter()
+            .fold(chunk_identity.clone(), |acc, x| chunk_op(acc, x))
+    })
+    .into_iter()
+    .fold(identity, |acc, partial| op(acc, partial))
+}
+
+/// 🌀 workload ที่กิน CPU ล้วนๆ: จำนวนก้าวของลำดับ Collatz จาก `n` ไปถึง 1
+#[must_use]
+pub const fn collatz_steps(mut n: u64) -> u32 {
+    let mut steps = 0;
+    while n > 1 {
+        n = if n.is_multiple_of(2) {
+            n / 2
+        } else {
+            3 * n + 1
+        };
+        steps += 1;
+    }
+    steps
+}
+
+/// ผลรวมจำนวนก้าว Collatz ของ `1..=limit` แบบ sequential (ค่าอ้างอิง)
+#[must_use]
+pub fn sequential_collatz_total(limit: u64) -> u64 {
+    (1..=limit).map(|n| u64::from(collatz_steps(n))).sum()
+}
+
+/// ผลรวมจำนวนก้าว Collatz ของ `1..=limit` ด้วย `par_map` บน `pool`
+#[must_use]
+pub fn parallel_collatz_total(pool: &ThreadPool, limit: u64) -> u64 {
+    par_map(pool, (1..=limit).collect(), |n| u64::from(collatz_steps(n)))
+        .into_iter()
+        .sum()
+}
+
+/// 📈 หนึ่งจุดบนกราฟ speedup: ใช้ worker กี่ตัว และเร็วกว่า sequential กี่เท่า
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpeedupPoint {
+    pub workers: usize,
+    pub result: MicroBenchResult,
+    pub speedup: f64,
+}
+
+/// 📈 วัด `parallel_collatz_total` ด้วยจำนวน worker ต่างๆ เทียบกับ sequential
+#[must_use]
+pub fn speedup_curve(worker_counts: &[usize], limit: u64, samples: usize) -> Vec<SpeedupPoint> {
+    let baseline = measure("sequential", samples, || {
+        sequential_collatz_total(black_box(limit))
+    });
+
+    worker_counts
+        .iter()
+        .map(|&workers| {
+            // สร้าง pool นอกส่วนที่จับเวลา - วัดเฉพาะการกระจายงาน
+            let pool = ThreadPool::new(workers);
+            let result = measure(&format!("{workers} workers"), samples, || {
+                parallel_collatz_total(&pool, black_box(limit))
+            });
+            SpeedupPoint {
+                workers,
+                speedup: speedup(&baseline, &result),
+                result,
+            }
+        })
+        .collect()
+}
+
+/// 🎓 สาธิต `par_map` / `par_sum` / `par_reduce` และกราฟ speedup ตามจำนวน worker
+#[timed]
+pub fn demonstrate_parallel() {
+    let pool = ThreadPool::with_available_parallelism();
+    println!("   🏊‍♂️ ThreadPool มี {} workers", pool.workers());
+
+    let squares = par_map(&pool, (1..=10_u64).collect(), |n| n * n);
+    println!("   🗺️ par_map(1..=10, n²) = {squares:?}");
+
+    let data: Arc<[u64]> = (1..=1_000_000).collect();
+    println!(
+        "   ➕ par_sum(1..=1,000,000) = {} (สูตร n(n+1)/2 = {})",
+        par_sum(&pool, &data),
+        1_000_000_u64 * 1_000_001 / 2
+    );
+
+    let words: Vec<String> = ["rust", "is", "fearless", "concurrency"]
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    let sentence = par_reduce(&pool, words, String::new(), |mut acc, word| {
+        if !acc.is_empty() && !word.is_empty() {
+            acc.push(' ');
+        }
+        acc.push_str(&word);
+        acc
+    });
+    println!("   🧮 par_reduce (ต่อ String ตามลำดับ ไม่ commutative) = {sentence:?}");
+
+    println!("   📈 Speedup curve: Collatz 1..=20,000");
+    println!("   {:>8} {:>12} {:>9}", "workers", "median", "speedup");
+    for point in speedup_curve(&[1, 2, 4, 8], 20_000, 5) {
+        println!(
+            "   {:>8} {:>12?} {:>8.2}x",
+            point.workers, point.result.median, point.speedup
+        );
+    }
+    println!("   💡 speedup ไม่เกินจำนวน CPU จริง และ debug build มี overhead สูง - ลอง --release");
+    println!("   🏃‍♂️ กราฟละเอียดกว่านี้: cargo bench --bench chapter_claims -- parallel_collatz");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_par_map_matches_sequential_order() {
+        let pool = ThreadPool::new(4);
+        let items: Vec<u64> = (0..1_003).collect();
+        let expected: Vec<String> = items.iter().map(|n| format!("#{n}")).collect();
+        assert_eq!(par_map(&pool, items, |n| format!("#{n}")), expected);
+        assert!(par_map(&pool, Vec::<u8>::new(), u32::from).is_empty());
+    }
+
+    #[test]
+    fn test_par_sum_and_reduce_match_sequential() {
+        for workers in [1, 3, 8] {
+            let pool = ThreadPool::new(workers);
+            let data: Arc<[u64]> = (1..=10_007).collect();
+            assert_eq!(par_sum(&pool, &data), data.iter().sum::<u64>());
+
+            let text: Vec<String> = (0..97).map(|n| n.to_string()).collect();
+            let expected = text.concat();
+            let joined = par_reduce(&pool, text, String::new(), |acc, s| acc + &s);
+            assert_eq!(joined, expected);
+        }
+
+        let pool = ThreadPool::new(2);
+        let empty: Arc<[u64]> = Arc::from(Vec::new());
+        assert_eq!(par_sum(&pool, &empty), 0);
+        assert_eq!(par_reduce(&pool, Vec::new(), 1_u64, |a, b| a * b), 1);
+    }
+
+    #[test]
+    fn test_parallel_collatz_matches_sequential() {
+        assert_eq!(collatz_steps(1), 0);
+        assert_eq!(collatz_steps(27), 111);
+
+        let pool = ThreadPool::new(4);
+        assert_eq!(
+            parallel_collatz_total(&pool, 5_000),
+            sequential_collatz_total(5_000)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "a parallel job panicked")]
+    fn test_par_map_propagates_job_panics() {
+        let pool = ThreadPool::new(2);
+        let _ = par_map(&pool, (0..10).collect(), |n: u32| {
+            assert_ne!(n, 7, "seven is not allowed");
+            n
+        });
+    }
+}
@@ -9,10 +9,11 @@
 //! - 🚀 Platform-specific Optimizations
 //! - 📊 Performance Comparisons
 //!
-//! หมายเหตุ: นี่คือการจำลอง SIMD เพื่อการศึกษา! 📚
+//! หมายเหตุ: struct `Simd*` ด้านบนเป็นการจำลอง SIMD เพื่อการศึกษา! 📚
+//! ส่วน "Real SIMD" ท้ายไฟล์ใช้ `std::arch` จริงพร้อมเลือก backend ตอน runtime ⚡
 
-// Note: This is a simulation of SIMD operations for educational purposes
-// Real SIMD would require nightly Rust and target-specific intrinsics
+// Note: The Simd* structs simulate SIMD with plain loops for readability.
+// The `simd_*` functions near the end use real intrinsics via runtime dispatch.
 
 /// 🔢 SIMD Math - การคำนวณแบบขนาน!
 /// เหมือนการมีเครื่องคิดเลขหลายเครื่องทำงานพร้อมกัน! 🧮
@@ -594,6 +595,461 @@ impl SimdBenchmark {
     }
 }
 
+// ===== ⚡ Real SIMD with runtime dispatch =====
+//
+// ทุกอย่างข้างบนเป็นการ "จำลอง" SIMD ด้วย loop ธรรมดา
+// ส่วนนี้ใช้ instruction จริงผ่าน `std::arch` แล้วเลือก implementation ตอน runtime
+// ด้วย `is_x86_feature_detected!` - binary เดียวรันได้ทั้งบน CPU เก่าและใหม่
+// เปิด feature `portable-simd` (ต้องใช้ nightly) เพื่อเพิ่ม backend ที่เขียนด้วย `std::simd`
+
+/// 🧭 ชุดคำสั่ง SIMD ที่ kernel ใช้ได้
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SimdBackend {
+    /// loop ธรรมดา - ใช้ได้ทุก CPU
+    Scalar,
+    /// SSE2/SSSE3 ขนาด 128 bits (16 bytes)
+    Sse,
+    /// AVX/AVX2 ขนาด 256 bits (32 bytes)
+    Avx2,
+    /// `std::simd` แบบ portable (nightly + feature `portable-simd`)
+    Portable,
+}
+
+impl SimdBackend {
+    /// backend ที่ดีที่สุดบน CPU นี้ (ตรวจตอน runtime)
+    #[must_use]
+    pub fn detect() -> Self {
+        Self::available().last().copied().unwrap_or(Self::Scalar)
+    }
+
+    /// ทุก backend ที่ใช้ได้บน CPU นี้ เรียงจากช้าไปเร็ว
+    #[must_use]
+    pub fn available() -> Vec<Self> {
+        let mut backends = vec![Self::Scalar];
+        if cfg!(feature = "portable-simd") {
+            backends.push(Self::Portable);
+        }
+        #[cfg(target_arch = "x86_64")]
+        {
+            if is_x86_feature_detected!("ssse3") {
+                backends.push(Self::Sse);
+            }
+            if is_x86_feature_detected!("avx2") {
+                backends.push(Self::Avx2);
+            }
+        }
+        backends
+    }
+
+    /// backend นี้ใช้ได้บน CPU นี้หรือไม่
+    #[must_use]
+    pub fn is_available(self) -> bool {
+        Self::available().contains(&self)
+    }
+}
+
+/// 📐 dot product ด้วย backend ที่ดีที่สุด
+///
+/// # Panics
+///
+/// Panics ถ้า `a` กับ `b` ยาวไม่เท่ากัน
+#[must_use]
+pub fn simd_dot_product(a: &[f32], b: &[f32]) -> f32 {
+    simd_dot_product_with(SimdBackend::detect(), a, b)
+}
+
+/// 📐 dot product ด้วย backend ที่เลือกเอง (ถ้าใช้ไม่ได้จะกลับไปใช้ scalar)
+///
+/// ผลลัพธ์อาจต่างจาก scalar เล็กน้อยเพราะลำดับการบวก float ต่างกัน
+///
+/// # Panics
+///
+/// Panics ถ้า `a` กับ `b` ยาวไม่เท่ากัน
+#[must_use]
+pub fn simd_dot_product_with(backend: SimdBackend, a: &[f32], b: &[f32]) -> f32 {
+    assert_eq!(a.len(), b.len(), "dot product needs equal lengths");
+    if !backend.is_available() {
+        return scalar_kernels::dot_product(a, b);
+    }
+    match backend {
+        #[cfg(target_arch = "x86_64")]
+        // SAFETY: ตรวจแล้วว่า CPU รองรับ AVX2 (is_available)
+        SimdBackend::Avx2 => unsafe { x86_kernels::dot_product_avx(a, b) },
+        #[cfg(target_arch = "x86_64")]
+        // SAFETY: SSE เป็น baseline ของ x86_64
+        SimdBackend::Sse => unsafe { x86_kernels::dot_product_sse(a, b) },
+        #[cfg(feature = "portable-simd")]
+        SimdBackend::Portable => portable_kernels::dot_product(a, b),
+        _ => scalar_kernels::dot_product(a, b),
+    }
+}
+
+/// 🔍 หาตำแหน่งแรกของ `needle` ด้วย backend ที่ดีที่สุด
+#[must_use]
+pub fn simd_find_byte(haystack: &[u8], needle: u8) -> Option<usize> {
+    simd_find_byte_with(SimdBackend::detect(), haystack, needle)
+}
+
+/// 🔍 หาตำแหน่งแรกของ `needle` ด้วย backend ที่เลือกเอง
+#[must_use]
+pub fn simd_find_byte_with(backend: SimdBackend, haystack: &[u8], needle: u8) -> Option<usize> {
+    if !backend.is_available() {
+        return scalar_kernels::find_byte(haystack, needle);
+    }
+    match backend {
+        #[cfg(target_arch = "x86_64")]
+        // SAFETY: ตรวจแล้วว่า CPU รองรับ AVX2 (is_available)
+        SimdBackend::Avx2 => unsafe { x86_kernels::find_byte_avx2(haystack, needle) },
+        #[cfg(target_arch = "x86_64")]
+        // SAFETY: SSE2 เป็น baseline ของ x86_64
+        SimdBackend::Sse => unsafe { x86_kernels::find_byte_sse2(haystack, needle) },
+        #[cfg(feature = "portable-simd")]
+        SimdBackend::Portable => portable_kernels::find_byte(haystack, needle),
+        _ => scalar_kernels::find_byte(haystack, needle),
+    }
+}
+
+/// 🖼️ แปลง RGB (เรียงติดกัน `r,g,b,r,g,b,...`) เป็น grayscale ด้วย backend ที่ดีที่สุด
+///
+/// ใช้น้ำหนักเดียวกับ `SimdImageProcessing::rgb_to_grayscale`: `(77r + 151g + 28b) >> 8`
+///
+/// # Panics
+///
+/// Panics ถ้า `rgb.len()` ไม่ใช่ `3 * gray.len()`
+pub fn simd_rgb_to_grayscale(rgb: &[u8], gray: &mut [u8]) {
+    simd_rgb_to_grayscale_with(SimdBackend::detect(), rgb, gray);
+}
+
+/// 🖼️ แปลง RGB เป็น grayscale ด้วย backend ที่เลือกเอง
+///
+/// # Panics
+///
+/// Panics ถ้า `rgb.len()` ไม่ใช่ `3 * gray.len()`
+pub fn simd_rgb_to_grayscale_with(backend: SimdBackend, rgb: &[u8], gray: &mut [u8]) {
+    assert_eq!(rgb.len(), gray.len() * 3, "rgb must hold 3 bytes per pixel");
+    if !backend.is_available() {
+        scalar_kernels::rgb_to_grayscale(rgb, gray);
+        return;
+    }
+    match backend {
+        // ไม่มี kernel AVX2 แยก: การสลับ byte ข้าม lane 128-bit ของ AVX2 ยุ่งยากกว่าที่ได้
+        #[cfg(target_arch = "x86_64")]
+        // SAFETY: ตรวจแล้วว่า CPU รองรับ SSSE3 (Sse อยู่ใน available เฉพาะเมื่อมี SSSE3)
+        SimdBackend::Sse | SimdBackend::Avx2 => unsafe {
+            x86_kernels::rgb_to_grayscale_ssse3(rgb, gray);
+        },
+        #[cfg(feature = "portable-simd")]
+        SimdBackend::Portable => portable_kernels::rgb_to_grayscale(rgb, gray),
+        _ => scalar_kernels::rgb_to_grayscale(rgb, gray),
+    }
+}
+
+/// 🐢 เวอร์ชัน scalar - เป็นทั้ง fallback และค่าอ้างอิงในการทดสอบ
+pub mod scalar_kernels {
+    /// dot product แบบ loop ธรรมดา
+    #[must_use]
+    pub fn dot_product(a: &[f32], b: &[f32]) -> f32 {
+        a.iter().zip(b).map(|(x, y)| x * y).sum()
+    }
+
+    /// หา byte แรกแบบ loop ธรรมดา
+    #[must_use]
+    pub fn find_byte(haystack: &[u8], needle: u8) -> Option<usize> {
+        haystack.iter().position(|&byte| byte == needle)
+    }
+
+    /// grayscale ของหนึ่ง pixel
+    #[must_use]
+    pub fn luma(r: u8, g: u8, b: u8) -> u8 {
+        let weighted = u16::from(r) * 77 + u16::from(g) * 151 + u16::from(b) * 28;
+        // ผลรวมน้ำหนักเท่ากับ 256 พอดี จึงไม่เกิน 255 หลัง >> 8
+        u8::try_from(weighted >> 8).unwrap_or(u8::MAX)
+    }
+
+    /// grayscale แบบทีละ pixel
+    pub fn rgb_to_grayscale(rgb: &[u8], gray: &mut [u8]) {
+        for (pixel, out) in rgb.chunks_exact(3).zip(gray) {
+            *out = luma(pixel[0], pixel[1], pixel[2]);
+        }
+    }
+}
+
+// loadu/storeu อ่านเขียนแบบไม่ต้อง align การ cast เป็น *const __m128i จึงปลอดภัย
+#[cfg(target_arch = "x86_64")]
+#[allow(clippy::cast_ptr_alignment)]
+mod x86_kernels {
+    use super::scalar_kernels;
+    use std::arch::x86_64::{
+        __m128i, _mm_add_epi16, _mm_add_ps, _mm_cmpeq_epi8, _mm_cvtss_f32, _mm_loadu_ps,
+        _mm_loadu_si128, _mm_movehl_ps, _mm_movemask_epi8, _mm_mul_ps, _mm_mullo_epi16,
+        _mm_or_si128, _mm_packus_epi16, _mm_set1_epi8, _mm_set1_epi16, _mm_setr_epi8,
+        _mm_setzero_ps, _mm_setzero_si128, _mm_shuffle_epi8, _mm_shuffle_ps, _mm_srli_epi16,
+        _mm_storeu_si128, _mm_unpackhi_epi8, _mm_unpacklo_epi8, _mm256_add_ps,
+        _mm256_castps256_ps128, _mm256_cmpeq_epi8, _mm256_extractf128_ps, _mm256_loadu_ps,
+        _mm256_loadu_si256, _mm256_movemask_epi8, _mm256_mul_ps, _mm256_set1_epi8,
+        _mm256_setzero_ps,
+    };
+
+    /// dot product ทีละ 4 float ด้วย SSE
+    #[target_feature(enable = "sse")]
+    pub unsafe fn dot_product_sse(a: &[f32], b: &[f32]) -> f32 {
+        let chunks = a.len() / 4;
+        let mut acc = _mm_setzero_ps();
+        for i in 0..chunks {
+            // SAFETY: i * 4 + 3 < a.len() == b.len(); loadu ไม่ต้อง align
+            let (va, vb) = unsafe {
+                (
+                    _mm_loadu_ps(a.as_ptr().add(i * 4)),
+                    _mm_loadu_ps(b.as_ptr().add(i * 4)),
+                )
+            };
+            acc = _mm_add_ps(acc, _mm_mul_ps(va, vb));
+        }
+        // บวก 4 lane เข้าด้วยกัน (horizontal sum)
+        let pairs = _mm_add_ps(acc, _mm_movehl_ps(acc, acc));
+        let total = _mm_add_ps(pairs, _mm_shuffle_ps(pairs, pairs, 0b01));
+        _mm_cvtss_f32(total) + scalar_kernels::dot_product(&a[chunks * 4..], &b[chunks * 4..])
+    }
+
+    /// dot product ทีละ 8 float ด้วย AVX
+    #[target_feature(enable = "avx")]
+    pub unsafe fn dot_product_avx(a: &[f32], b: &[f32]) -> f32 {
+        let chunks = a.len() / 8;
+        let mut acc = _mm256_setzero_ps();
+        for i in 0..chunks {
+            // SAFETY: i * 8 + 7 < a.len() == b.len(); loadu ไม่ต้อง align
+            let (va, vb) = unsafe {
+                (
+                    _mm256_loadu_ps(a.as_ptr().add(i * 8)),
+                    _mm256_loadu_ps(b.as_ptr().add(i * 8)),
+                )
+            };
+            acc = _mm256_add_ps(acc, _mm256_mul_ps(va, vb));
+        }
+        // รวมครึ่งบนกับครึ่งล่างเป็น 4 lane แล้วใช้ขั้นตอนเดียวกับ SSE
+        let half = _mm_add_ps(_mm256_castps256_ps128(acc), _mm256_extractf128_ps(acc, 1));
+        let pairs = _mm_add_ps(half, _mm_movehl_ps(half, half));
+        let total = _mm_add_ps(pairs, _mm_shuffle_ps(pairs, pairs, 0b01));
+        _mm_cvtss_f32(total) + scalar_kernels::dot_product(&a[chunks * 8..], &b[chunks * 8..])
+    }
+
+    /// เทียบ 16 bytes พร้อมกัน แล้วใช้ movemask หา lane แรกที่ตรง
+    #[target_feature(enable = "sse2")]
+    pub unsafe fn find_byte_sse2(haystack: &[u8], needle: u8) -> Option<usize> {
+        let target = _mm_set1_epi8(needle.cast_signed());
+        let chunks = haystack.len() / 16;
+        for i in 0..chunks {
+            // SAFETY: อ่าน 16 bytes จาก i * 16 ซึ่งอยู่ในขอบเขตของ haystack
+            let block = unsafe { _mm_loadu_si128(haystack.as_ptr().add(i * 16).cast::<__m128i>()) };
+            let mask = _mm_movemask_epi8(_mm_cmpeq_epi8(block, target));
+            if mask != 0 {
+                return Some(i * 16 + mask.trailing_zeros() as usize);
+            }
+        }
+        scalar_kernels::find_byte(&haystack[chunks * 16..], needle).map(|pos| chunks * 16 + pos)
+    }
+
+    /// เทียบ 32 bytes พร้อมกันด้วย AVX2
+    #[target_feature(enable = "avx2")]
+    pub unsafe fn find_byte_avx2(haystack: &[u8], needle: u8) -> Option<usize> {
+        let target = _mm256_set1_epi8(needle.cast_signed());
+        let chunks = haystack.len() / 32;
+        for i in 0..chunks {
+            // SAFETY: อ่าน 32 bytes จาก i * 32 ซึ่งอยู่ในขอบเขตของ haystack
+            let block = unsafe { _mm256_loadu_si256(haystack.as_ptr().add(i * 32).cast()) };
+            let mask = _mm256_movemask_epi8(_mm256_cmpeq_epi8(block, target));
+            if mask != 0 {
+                return Some(i * 32 + mask.trailing_zeros() as usize);
+            }
+        }
+        // SAFETY: AVX2 เปิดอยู่แล้ว จึงมี SSE2 แน่นอน
+        unsafe { find_byte_sse2(&haystack[chunks * 32..], needle) }.map(|pos| chunks * 32 + pos)
+    }
+
+    /// mask สำหรับ `_mm_shuffle_epi8` ของทุก channel (แถว) และทุก register (คอลัมน์)
+    ///
+    /// `masks[c][part]` ดึง byte ของ channel `c` (0 R, 1 G, 2 B) จาก register ลำดับ `part`
+    /// ของช่วง 48 bytes ไปวางตามตำแหน่ง pixel - ช่องที่ไม่ได้มาจาก register นั้นเป็น -1 (ศูนย์)
+    #[target_feature(enable = "sse2")]
+    fn deinterleave_masks() -> [[__m128i; 3]; 3] {
+        std::array::from_fn(|channel| {
+            std::array::from_fn(|part| {
+                let mut lanes = [-1_i8; 16];
+                for (pixel, lane) in lanes.iter_mut().enumerate() {
+                    let source = pixel * 3 + channel;
+                    if source / 16 == part {
+                        *lane = i8::try_from(source % 16).unwrap_or(-1);
+                    }
+                }
+                _mm_setr_epi8(
+                    lanes[0], lanes[1], lanes[2], lanes[3], lanes[4], lanes[5], lanes[6],
+                    lanes[7], lanes[8], lanes[9], lanes[10], lanes[11], lanes[12], lanes[13],
+                    lanes[14], lanes[15],
+                )
+            })
+        })
+    }
+
+    /// แยก channel หนึ่งของ 16 pixels จาก 3 registers ด้วย mask ของ channel นั้น
+    #[target_feature(enable = "ssse3")]
+    fn extract_channel(blocks: &[__m128i; 3], masks: &[__m128i; 3]) -> __m128i {
+        let picked = [0, 1, 2].map(|part| _mm_shuffle_epi8(blocks[part], masks[part]));
+        _mm_or_si128(_mm_or_si128(picked[0], picked[1]), picked[2])
+    }
+
+    /// `(77r + 151g + 28b) >> 8` ของ 8 pixels ในรูป u16
+    #[target_feature(enable = "sse2")]
+    fn weighted_luma(r: __m128i, g: __m128i, b: __m128i) -> __m128i {
+        let sum = _mm_add_epi16(
+            _mm_add_epi16(
+                _mm_mullo_epi16(r, _mm_set1_epi16(77)),
+                _mm_mullo_epi16(g, _mm_set1_epi16(151)),
+            ),
+            _mm_mullo_epi16(b, _mm_set1_epi16(28)),
+        );
+        _mm_srli_epi16(sum, 8)
+    }
+
+    /// grayscale ทีละ 16 pixels: shuffle แยก channel แล้วคูณน้ำหนักใน lane ขนาด u16
+    #[target_feature(enable = "ssse3")]
+    pub unsafe fn rgb_to_grayscale_ssse3(rgb: &[u8], gray: &mut [u8]) {
+        let chunks = gray.len() / 16;
+        let zero = _mm_setzero_si128();
+        let masks = deinterleave_masks();
+        for i in 0..chunks {
+            let base = rgb[i * 48..].as_ptr();
+            // SAFETY: ช่วง [i*48, i*48 + 48) อยู่ใน rgb เพราะ rgb.len() == gray.len() * 3
+            let blocks = unsafe {
+                [
+                    _mm_loadu_si128(base.cast::<__m128i>()),
+                    _mm_loadu_si128(base.add(16).cast::<__m128i>()),
+                    _mm_loadu_si128(base.add(32).cast::<__m128i>()),
+                ]
+            };
+            let [r, g, b] = masks.each_ref().map(|channel| extract_channel(&blocks, channel));
+
+            let low = weighted_luma(
+                _mm_unpacklo_epi8(r, zero),
+                _mm_unpacklo_epi8(g, zero),
+                _mm_unpacklo_epi8(b, zero),
+            );
+            let high = weighted_luma(
+                _mm_unpackhi_epi8(r, zero),
+                _mm_unpackhi_epi8(g, zero),
+                _mm_unpackhi_epi8(b, zero),
+            );
+            // SAFETY: เขียน 16 bytes ที่ i*16 ซึ่งอยู่ในขอบเขตของ gray
+            unsafe {
+                _mm_storeu_si128(
+                    gray[i * 16..].as_mut_ptr().cast::<__m128i>(),
+                    _mm_packus_epi16(low, high),
+                );
+            }
+        }
+        scalar_kernels::rgb_to_grayscale(&rgb[chunks * 48..], &mut gray[chunks * 16..]);
+    }
+}
+
+#[cfg(feature = "portable-simd")]
+mod portable_kernels {
+    use super::scalar_kernels;
+    use std::simd::prelude::*;
+
+    const LANES: usize = 16;
+
+    /// dot product ด้วย `f32x16`
+    pub fn dot_product(a: &[f32], b: &[f32]) -> f32 {
+        let (a_chunks, a_rest) = a.as_chunks::<LANES>();
+        let (b_chunks, b_rest) = b.as_chunks::<LANES>();
+        let mut acc = f32x16::splat(0.0);
+        for (x, y) in a_chunks.iter().zip(b_chunks) {
+            acc += f32x16::from_array(*x) * f32x16::from_array(*y);
+        }
+        acc.reduce_sum() + scalar_kernels::dot_product(a_rest, b_rest)
+    }
+
+    /// หา byte แรกด้วย `u8x16::simd_eq` + `first_set`
+    pub fn find_byte(haystack: &[u8], needle: u8) -> Option<usize> {
+        let target = u8x16::splat(needle);
+        let (chunks, rest) = haystack.as_chunks::<LANES>();
+        for (i, chunk) in chunks.iter().enumerate() {
+            if let Some(lane) = u8x16::from_array(*chunk).simd_eq(target).first_set() {
+                return Some(i * LANES + lane);
+            }
+        }
+        scalar_kernels::find_byte(rest, needle).map(|pos| chunks.len() * LANES + pos)
+    }
+
+    /// grayscale ทีละ 16 pixels: gather แยก channel แล้วคำนวณใน `u16x16`
+    pub fn rgb_to_grayscale(rgb: &[u8], gray: &mut [u8]) {
+        let pixel_offsets = usizex16::from_array(std::array::from_fn(|i| i * 3));
+        let chunks = gray.len() / LANES;
+        for i in 0..chunks {
+            let block = &rgb[i * LANES * 3..(i + 1) * LANES * 3];
+            let channel = |offset: usize| {
+                u8x16::gather_or_default(block, pixel_offsets + usizex16::splat(offset))
+                    .cast::<u16>()
+            };
+            let luma = (channel(0) * u16x16::splat(77)
+                + channel(1) * u16x16::splat(151)
+                + channel(2) * u16x16::splat(28))
+                >> u16x16::splat(8);
+            luma.cast::<u8>()
+                .copy_to_slice(&mut gray[i * LANES..(i + 1) * LANES]);
+        }
+        scalar_kernels::rgb_to_grayscale(&rgb[chunks * LANES * 3..], &mut gray[chunks * LANES..]);
+    }
+}
+
+/// 📊 วัดเวลาของทั้งสาม kernel บนทุก backend ที่ใช้ได้ เทียบกับ scalar
+fn demonstrate_runtime_dispatch() {
+    use super::microbench::{MicroBenchResult, measure, speedup};
+    use std::hint::black_box;
+
+    println!(
+        "🧭 backend ที่ใช้ได้: {:?} -> เลือก {:?}",
+        SimdBackend::available(),
+        SimdBackend::detect()
+    );
+
+    let a: Vec<f32> = (0..4_096u16).map(|i| f32::from(i % 100)).collect();
+    let b: Vec<f32> = (0..4_096u16).map(|i| f32::from(i % 7)).collect();
+    let mut haystack = vec![b'a'; 64 * 1024];
+    haystack[60_000] = b'!';
+    let rgb: Vec<u8> = (0..=u8::MAX).cycle().take(64 * 64 * 3).collect();
+    let mut gray = vec![0u8; 64 * 64];
+
+    // Scalar อยู่ลำดับแรกของ available() เสมอ จึงเป็น baseline ให้ backend ถัดไป
+    let mut baseline: Option<[MicroBenchResult; 3]> = None;
+    for backend in SimdBackend::available() {
+        let results = [
+            measure("dot", 9, || {
+                simd_dot_product_with(backend, black_box(&a), black_box(&b))
+            }),
+            measure("find", 9, || {
+                simd_find_byte_with(backend, black_box(&haystack), b'!')
+            }),
+            measure("gray", 9, || {
+                simd_rgb_to_grayscale_with(backend, black_box(&rgb), &mut gray);
+            }),
+        ];
+        let scalar = baseline.get_or_insert_with(|| results.clone());
+        let cells: Vec<String> = results
+            .iter()
+            .zip(scalar.iter())
+            .map(|(result, scalar)| {
+                format!(
+                    "{} {:>10?} ({:.1}x)",
+                    result.name,
+                    result.median,
+                    speedup(scalar, result)
+                )
+            })
+            .collect();
+        println!("   {:<8} {}", format!("{backend:?}"), cells.join("  "));
+    }
+    println!("💡 build แบบ --release จะเห็นความต่างชัดกว่ามาก (debug ไม่ inline intrinsics)");
+}
 /// สาธิตการใช้งาน SIMD vectorization
 /// 🚀 สาธิต SIMD Vectorization Workshop!
 /// เหมือนการมีทีมงานหลายคนทำงานพร้อมกัน! 👥⚡
@@ -805,6 +1261,11 @@ pub fn demonstrate_simd_vectorization() {
         println!("  🚀 SIMD เร็วกว่า {speedup:.2} เท่า!");
     }
     
+    // Real SIMD with runtime dispatch
+    println!("\n⚡ Real SIMD - เลือก backend ตอน runtime:");
+    println!("{:-<50}", "");
+    demonstrate_runtime_dispatch();
+    
     println!("\n🎉 ยินดีด้วย! คุณได้เรียนรู้ SIMD Vectorization เรียบร้อยแล้ว!");
     println!("💡 ตอนนี้คุณรู้วิธีทำงานหลายอย่างพร้อมกันแล้ว! ⚡");
 }
@@ -965,4 +1426,52 @@ mod tests {
         
         assert_eq!(SimdIntegerMath::max_i32(&[42]), 42);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_runtime_dispatch_backends_match_scalar() {
+        // ค่าเป็นจำนวนเต็มเล็กๆ ผลรวม float จึงเท่ากันทุกลำดับการบวก
+        let a: Vec<f32> = (0..1_003u16).map(|i| f32::from(i % 13)).collect();
+        let b: Vec<f32> = (0..1_003u16).map(|i| f32::from(i % 5)).collect();
+        let rgb: Vec<u8> = (0..=u8::MAX).cycle().skip(7).take(37 * 3).collect();
+        let expected_dot = scalar_kernels::dot_product(&a, &b);
+        let mut expected_gray = vec![0; 37];
+        scalar_kernels::rgb_to_grayscale(&rgb, &mut expected_gray);
+
+        for backend in SimdBackend::available() {
+            assert_eq!(simd_dot_product_with(backend, &a, &b), expected_dot, "{backend:?}");
+
+            let mut gray = vec![0; 37];
+            simd_rgb_to_grayscale_with(backend, &rgb, &mut gray);
+            assert_eq!(gray, expected_gray, "{backend:?}");
+
+            for len in [0, 1, 15, 16, 17, 31, 32, 33, 100] {
+                for position in 0..len {
+                    let mut haystack = vec![0u8; len];
+                    haystack[position] = 0xFF;
+                    if position + 1 < len {
+                        haystack[len - 1] = 0xFF;
+                    }
+                    assert_eq!(
+                        simd_find_byte_with(backend, &haystack, 0xFF),
+                        Some(position),
+                        "{backend:?} len={len}"
+                    );
+                }
+                assert_eq!(simd_find_byte_with(backend, &vec![1u8; len], 0xFF), None);
+            }
+        }
+    }
+
+    #[test]
+    fn test_default_dispatch_and_fallback() {
+        assert!(SimdBackend::available().contains(&SimdBackend::Scalar));
+        assert!(SimdBackend::detect().is_available());
+        assert_eq!(simd_dot_product(&[1.0, 2.0, 3.0], &[4.0, 5.0, 6.0]), 32.0);
+        assert_eq!(simd_find_byte(b"hello, simd", b','), Some(5));
+
+        let mut gray = [0u8; 2];
+        simd_rgb_to_grayscale(&[255, 255, 255, 0, 0, 0], &mut gray);
+        assert_eq!(gray, [255, 0]);
+        assert_eq!(scalar_kernels::luma(255, 0, 0), 76);
+    }
+}
@@ -11,6 +11,7 @@
 //!
 //! เทคนิคเหล่านี้สำคัญมากสำหรับแอปพลิเคชันที่ต้องการประสิทธิภาพสูง! 🚀
 
+use std::borrow::Cow;
 use std::io::{self, Read, Write};
 use std::slice;
 use std::ptr;
@@ -654,6 +655,324 @@ impl ZeroCopyNetworkBuffer {
     }
 }
 
+// ===== 📜 Zero-Copy Log Parsing =====
+//
+// รูปแบบบรรทัด log: `<timestamp> <LEVEL> <target> <message>`
+// เช่น `2024-05-01T12:00:00Z INFO auth::login user \"alice\" logged in`
+// message อาจมี escape: `\"`, `\\`, `\n`, `\t`
+
+/// 🚦 ระดับความสำคัญของ log
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LogLevel {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl LogLevel {
+    /// แปลงจาก bytes โดยไม่ต้องสร้าง `String`
+    #[must_use]
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        match bytes {
+            b"TRACE" => Some(Self::Trace),
+            b"DEBUG" => Some(Self::Debug),
+            b"INFO" => Some(Self::Info),
+            b"WARN" => Some(Self::Warn),
+            b"ERROR" => Some(Self::Error),
+            _ => None,
+        }
+    }
+
+    /// ชื่อระดับแบบตัวพิมพ์ใหญ่
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Trace => "TRACE",
+            Self::Debug => "DEBUG",
+            Self::Info => "INFO",
+            Self::Warn => "WARN",
+            Self::Error => "ERROR",
+        }
+    }
+}
+
+/// ❌ สาเหตุที่อ่านบรรทัด log ไม่ได้
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LogParseError {
+    /// บรรทัดไม่ใช่ UTF-8 ที่ถูกต้อง
+    InvalidUtf8,
+    /// ขาด field ที่ต้องมี
+    MissingField(&'static str),
+    /// ระดับ log ที่ไม่รู้จัก
+    UnknownLevel(String),
+}
+
+impl std::fmt::Display for LogParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidUtf8 => write!(f, "log line is not valid UTF-8"),
+            Self::MissingField(field) => write!(f, "log line is missing the {field} field"),
+            Self::UnknownLevel(level) => write!(f, "unknown log level: {level}"),
+        }
+    }
+}
+
+impl std::error::Error for LogParseError {}
+
+/// 📜 บรรทัด log ที่ทุก field ยืม (borrow) มาจาก buffer ต้นฉบับ
+///
+/// lifetime `'a` ผูกกับ buffer ที่ส่งเข้า `parse` - ตราบใดที่ยังถือ `LogRecord`
+/// อยู่ compiler จะไม่ยอมให้แก้ไขหรือ drop buffer นั้น
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LogRecord<'a> {
+    pub timestamp: &'a str,
+    pub level: LogLevel,
+    pub target: &'a str,
+    /// message ดิบที่ยังไม่ได้ถอด escape
+    pub raw_message: &'a str,
+}
+
+impl<'a> LogRecord<'a> {
+    /// อ่านหนึ่งบรรทัดจาก bytes - ไม่มีการ allocate เลยถ้าบรรทัดถูกต้อง
+    ///
+    /// # Errors
+    ///
+    /// คืน `LogParseError` ถ้าบรรทัดไม่ใช่ UTF-8, ขาด field หรือระดับ log ไม่ถูกต้อง
+    pub fn parse(line: &'a [u8]) -> Result<Self, LogParseError> {
+        // from_utf8 แค่ตรวจสอบ bytes แล้วคืน &str ที่ชี้ไปที่ buffer เดิม
+        let line = std::str::from_utf8(line).map_err(|_| LogParseError::InvalidUtf8)?;
+        let (timestamp, rest) = split_field(line, "timestamp")?;
+        let (level, rest) = split_field(rest, "level")?;
+        let (target, raw_message) = split_field(rest, "target")?;
+
+        let level = LogLevel::from_bytes(level.as_bytes())
+            .ok_or_else(|| LogParseError::UnknownLevel(level.to_string()))?;
+
+        Ok(Self {
+            timestamp,
+            level,
+            target,
+            raw_message,
+        })
+    }
+
+    /// message ที่ถอด escape แล้ว - allocate เฉพาะเมื่อมี `\` อยู่ในข้อความ
+    #[must_use]
+    pub fn message(&self) -> Cow<'a, str> {
+        unescape(self.raw_message)
+    }
+
+    /// คัดลอกทุก field เป็น `OwnedLogRecord` (เมื่อต้องเก็บไว้นานกว่า buffer)
+    #[must_use]
+    pub fn to_owned_record(&self) -> OwnedLogRecord {
+        OwnedLogRecord {
+            timestamp: self.timestamp.to_string(),
+            level: self.level,
+            target: self.target.to_string(),
+            message: self.message().into_owned(),
+        }
+    }
+}
+
+/// ตัด field แรกที่คั่นด้วยช่องว่าง คืน (field, ส่วนที่เหลือ)
+fn split_field<'a>(
+    input: &'a str,
+    name: &'static str,
+) -> Result<(&'a str, &'a str), LogParseError> {
+    match input.split_once(' ') {
+        Some((field, rest)) if !field.is_empty() => Ok((field, rest)),
+        _ => Err(LogParseError::MissingField(name)),
+    }
+}
+
+/// 🔓 ถอด escape (`\"`, `\\`, `\n`, `\t`) - คืน `Cow::Borrowed` ถ้าไม่มีอะไรต้องถอด
+///
+/// escape ที่ไม่รู้จักจะถูกเก็บไว้ตามเดิมทั้ง `\` และตัวอักษรถัดไป
+#[must_use]
+pub fn unescape(raw: &str) -> Cow<'_, str> {
+    let Some(first) = raw.find('\\') else {
+        return Cow::Borrowed(raw);
+    };
+
+    let mut unescaped = String::with_capacity(raw.len());
+    unescaped.push_str(&raw[..first]);
+    let mut chars = raw[first..].chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => unescaped.push('\n'),
+            Some('t') => unescaped.push('\t'),
+            Some(escaped @ ('"' | '\\')) => unescaped.push(escaped),
+            Some(other) => {
+                unescaped.push('\\');
+                unescaped.push(other);
+            }
+            None => unescaped.push('\\'),
+        }
+    }
+    Cow::Owned(unescaped)
+}
+
+/// 🔁 วนอ่านทีละบรรทัดจาก buffer - แต่ละ `LogRecord` ยืม slice ของ buffer เดียวกัน
+///
+/// ข้ามบรรทัดว่าง และตัด `\r` ท้ายบรรทัด (ไฟล์แบบ Windows) ออกให้
+pub fn parse_log_buffer(
+    buffer: &[u8],
+) -> impl Iterator<Item = Result<LogRecord<'_>, LogParseError>> {
+    buffer
+        .split(|&byte| byte == b'\n')
+        .map(|line| line.strip_suffix(b"\r").unwrap_or(line))
+        .filter(|line| !line.is_empty())
+        .map(LogRecord::parse)
+}
+
+/// 📋 บรรทัด log แบบเป็นเจ้าของข้อมูลเอง - ทุก field เป็น `String` ที่คัดลอกมา
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OwnedLogRecord {
+    pub timestamp: String,
+    pub level: LogLevel,
+    pub target: String,
+    pub message: String,
+}
+
+impl OwnedLogRecord {
+    /// parser แบบดั้งเดิม: แยก field แล้ว `to_string()` ทุกชิ้นทันที
+    ///
+    /// # Errors
+    ///
+    /// คืน `LogParseError` ถ้าขาด field หรือระดับ log ไม่ถูกต้อง
+    pub fn parse(line: &str) -> Result<Self, LogParseError> {
+        let mut parts = line.splitn(4, ' ').map(str::to_string);
+        let timestamp = parts
+            .next()
+            .filter(|field| !field.is_empty())
+            .ok_or(LogParseError::MissingField("timestamp"))?;
+        let level = parts.next().ok_or(LogParseError::MissingField("level"))?;
+        let target = parts.next().ok_or(LogParseError::MissingField("target"))?;
+        let message = parts.next().ok_or(LogParseError::MissingField("message"))?;
+
+        let level =
+            LogLevel::from_bytes(level.as_bytes()).ok_or(LogParseError::UnknownLevel(level))?;
+
+        Ok(Self {
+            timestamp,
+            level,
+            target,
+            message: unescape(&message).into_owned(),
+        })
+    }
+}
+
+/// ระดับ log ที่ใช้วนใน `sample_log`
+const SAMPLE_LEVELS: [LogLevel; 4] = [
+    LogLevel::Info,
+    LogLevel::Debug,
+    LogLevel::Warn,
+    LogLevel::Error,
+];
+
+/// 🧪 สร้าง log ตัวอย่าง `lines` บรรทัด - ทุกบรรทัดที่ 4 มี escape ใน message
+#[must_use]
+pub fn sample_log(lines: usize) -> String {
+    use std::fmt::Write as _;
+
+    let mut log = String::new();
+    for i in 0..lines {
+        let level = SAMPLE_LEVELS[i % SAMPLE_LEVELS.len()].as_str();
+        let message = if i % 4 == 3 {
+            format!("request {i} failed: \\\"timeout\\\"\\tretrying")
+        } else {
+            format!("request {i} handled in {}ms", i % 97)
+        };
+        let _ = writeln!(
+            log,
+            "2024-05-01T12:{:02}:{:02}Z {level} http::server {message}",
+            (i / 60) % 60,
+            i % 60
+        );
+    }
+    log
+}
+
+/// นับจำนวนบรรทัด ERROR และความยาว message รวม ด้วย parser แบบ zero-copy
+///
+/// # Errors
+///
+/// คืน `LogParseError` ของบรรทัดแรกที่อ่านไม่ได้
+pub fn summarize_log_zero_copy(buffer: &[u8]) -> Result<(usize, usize), LogParseError> {
+    let mut errors = 0;
+    let mut message_bytes = 0;
+    for record in parse_log_buffer(buffer) {
+        let record = record?;
+        errors += usize::from(record.level == LogLevel::Error);
+        message_bytes += record.message().len();
+    }
+    Ok((errors, message_bytes))
+}
+
+/// ผลลัพธ์เดียวกับ `summarize_log_zero_copy` แต่ใช้ parser แบบ owning
+///
+/// # Errors
+///
+/// คืน `LogParseError` ของบรรทัดแรกที่อ่านไม่ได้
+pub fn summarize_log_owned(text: &str) -> Result<(usize, usize), LogParseError> {
+    let mut errors = 0;
+    let mut message_bytes = 0;
+    for line in text.lines().filter(|line| !line.is_empty()) {
+        let record = OwnedLogRecord::parse(line)?;
+        errors += usize::from(record.level == LogLevel::Error);
+        message_bytes += record.message.len();
+    }
+    Ok((errors, message_bytes))
+}
+
+/// 📜 สาธิต log parser แบบ zero-copy เทียบกับแบบ owning พร้อมนับ allocation จริง
+pub fn demonstrate_log_parsing() {
+    use crate::advanced_topics::allocation_tracking::{is_tracking_enabled, measure_allocations};
+
+    let line = br#"2024-05-01T12:00:00Z WARN auth::login user \"alice\" typed a wrong password"#;
+    match LogRecord::parse(line) {
+        Ok(record) => {
+            println!("🔍 timestamp = {:?} (ยืมจาก buffer)", record.timestamp);
+            println!("🔍 level     = {:?}", record.level);
+            println!("🔍 target    = {:?} (ยืมจาก buffer)", record.target);
+            let message = record.message();
+            let kind = if matches!(message, Cow::Borrowed(_)) {
+                "Borrowed"
+            } else {
+                "Owned - มี escape"
+            };
+            println!("🔍 message   = {message:?} (Cow::{kind})");
+        }
+        Err(error) => println!("❌ {error}"),
+    }
+
+    let text = sample_log(2_000);
+    let ((zero_copy, zc_stats), (owned, owned_stats)) = (
+        measure_allocations(|| summarize_log_zero_copy(text.as_bytes())),
+        measure_allocations(|| summarize_log_owned(&text)),
+    );
+    println!("\n📊 อ่าน log 2,000 บรรทัด ({} bytes):", text.len());
+    println!(
+        "   ⚡ zero-copy: {:>6} allocations, {:>8} bytes -> {zero_copy:?}",
+        zc_stats.allocations, zc_stats.bytes_allocated
+    );
+    println!(
+        "   📋 owning:    {:>6} allocations, {:>8} bytes -> {owned:?}",
+        owned_stats.allocations, owned_stats.bytes_allocated
+    );
+    if is_tracking_enabled() {
+        println!("   💡 zero-copy allocate เฉพาะบรรทัดที่ message มี escape (1 ใน 4) เท่านั้น!");
+    } else {
+        println!("   ⚠️ ปิด feature `tracking-allocator` อยู่ - ตัวนับจะเป็นศูนย์ทั้งหมด");
+    }
+    println!("   🏃‍♂️ เทียบเวลา: cargo bench --bench chapter_claims -- log_parsing");
+}
 /// 🎯 สาธิตการใช้งาน Zero-Copy Techniques - Workshop Demo!
 pub fn demonstrate_zero_copy() {
     println!("🚀 ยินดีต้อนรับสู่ Zero-Copy Techniques Workshop! 🎉");
@@ -839,6 +1158,11 @@ pub fn demonstrate_zero_copy() {
         println!("🚀 Zero-Copy เร็วกว่า {speedup:.2} เท่า!");
     }
     
+    // Zero-copy log parsing
+    println!("\n📜 Zero-Copy Log Parsing - parser ที่ยืม field จาก buffer:");
+    println!("{:-<60}", "");
+    demonstrate_log_parsing();
+    
     println!("\n✅ สาธิต Zero-Copy Techniques เสร็จสิ้น! 🎉");
 }
 
@@ -996,4 +1320,62 @@ mod tests {
         assert_eq!(original.as_slice(), cloned.as_slice());
         assert_ne!(original.as_ptr(), cloned.as_ptr()); // Different memory
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_log_record_borrows_from_buffer() {
+        let buffer = b"2024-05-01T12:00:00Z INFO http::server GET /health 200\n".to_vec();
+        let record = parse_log_buffer(&buffer).next().unwrap().unwrap();
+
+        assert_eq!(record.timestamp, "2024-05-01T12:00:00Z");
+        assert_eq!(record.level, LogLevel::Info);
+        assert_eq!(record.target, "http::server");
+        assert!(matches!(record.message(), Cow::Borrowed("GET /health 200")));
+
+        // ทุก field ชี้เข้าไปใน buffer เดิม ไม่ได้ถูกคัดลอก
+        let range = buffer.as_ptr_range();
+        for field in [record.timestamp, record.target, record.raw_message] {
+            assert!(range.contains(&field.as_ptr()));
+        }
+    }
+
+    #[test]
+    fn test_unescape_only_allocates_when_needed() {
+        assert!(matches!(unescape("plain text"), Cow::Borrowed("plain text")));
+        assert_eq!(unescape(r#"say \"hi\"\tnow\\"#), "say \"hi\"\tnow\\");
+        assert_eq!(unescape(r"line\nbreak \q trailing\"), "line\nbreak \\q trailing\\");
+    }
+
+    #[test]
+    fn test_log_parsers_agree() {
+        let text = sample_log(64);
+        let zero_copy: Vec<OwnedLogRecord> = parse_log_buffer(text.as_bytes())
+            .map(|record| record.unwrap().to_owned_record())
+            .collect();
+        let owned: Vec<OwnedLogRecord> = text
+            .lines()
+            .map(|line| OwnedLogRecord::parse(line).unwrap())
+            .collect();
+        assert_eq!(zero_copy, owned);
+        assert_eq!(
+            summarize_log_zero_copy(text.as_bytes()),
+            summarize_log_owned(&text)
+        );
+    }
+
+    #[test]
+    fn test_log_parse_errors() {
+        assert_eq!(LogRecord::parse(&[0xFF, b' ']), Err(LogParseError::InvalidUtf8));
+        assert_eq!(
+            LogRecord::parse(b"2024-05-01T12:00:00Z INFO"),
+            Err(LogParseError::MissingField("level"))
+        );
+        assert_eq!(
+            LogRecord::parse(b"ts LOUD app message"),
+            Err(LogParseError::UnknownLevel("LOUD".to_string()))
+        );
+        assert_eq!(
+            OwnedLogRecord::parse("ts LOUD app message"),
+            Err(LogParseError::UnknownLevel("LOUD".to_string()))
+        );
+    }
+}
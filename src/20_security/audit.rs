@@ -0,0 +1,794 @@
+//! 📒 Audit Log - บันทึกเหตุการณ์ด้านความปลอดภัยแบบแก้ไขแล้วจับได้ (tamper-evident)
+//!
+//! ทุก record เก็บ hash ของ record ก่อนหน้า (hash chain แบบเดียวกับ blockchain):
+//! แก้ byte เดียว ลบ/สลับ/แทรก record หรือหายไปทั้งไฟล์ → ห่วงโซ่ขาด และ `verify_audit_log` บอกได้ว่าขาดตรงไหน
+//!
+//! - 📝 `AuditEvent` - login, ถูกปฏิเสธสิทธิ์, config เปลี่ยน
+//! - 🔗 record = `{seq, timestamp, event, prev_hash, hash}` หนึ่งบรรทัด JSON ต่อ record
+//! - 🔄 หมุนไฟล์ (`audit-000001.jsonl`, `audit-000002.jsonl`, ...) เมื่อไฟล์ใหญ่เกิน - ห่วงโซ่ต่อข้ามไฟล์
+//! - ⚓ `ChainHead` ที่เก็บไว้ที่อื่น (เช่นส่งให้ระบบ monitor) ใช้จับการตัดท้ายไฟล์ทิ้ง
+//!
+//! ⚠️ hash chain กันการแก้ "แบบเงียบๆ" ได้ แต่ผู้ที่เขียนไฟล์ได้ก็สร้างห่วงโซ่ใหม่ทั้งเส้นได้
+//! - ของจริงจึงต้องเซ็น head ด้วยกุญแจ หรือส่ง head ออกไปเก็บนอกเครื่อง
+
+use std::error::Error;
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, PoisonError};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::clock::{self, Clock};
+use crate::logger;
+
+/// hash ก่อนหน้าของ record แรก
+pub const GENESIS_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+/// 📝 เหตุการณ์ที่ต้องตรวจย้อนหลังได้
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AuditEvent {
+    /// พยายาม login - `method` เช่น `"password"`, `"oauth2"`
+    Login {
+        user: String,
+        success: bool,
+        method: String,
+    },
+    /// ผ่านการยืนยันตัวตนแล้วแต่ไม่มีสิทธิ์
+    PermissionDenied {
+        user: String,
+        action: String,
+        resource: String,
+        reason: String,
+    },
+    /// ค่า config ถูกเปลี่ยน (`None` = ไม่มี key นั้นก่อน/หลัง)
+    ConfigChanged {
+        actor: String,
+        key: String,
+        old: Option<String>,
+        new: Option<String>,
+    },
+}
+
+impl fmt::Display for AuditEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Login {
+                user,
+                success,
+                method,
+            } => {
+                let outcome = if *success { "succeeded" } else { "failed" };
+                write!(f, "login {outcome} for {user:?} via {method}")
+            }
+            Self::PermissionDenied {
+                user,
+                action,
+                resource,
+                reason,
+            } => write!(f, "{user:?} denied {action} on {resource}: {reason}"),
+            Self::ConfigChanged {
+                actor,
+                key,
+                old,
+                new,
+            } => write!(f, "{actor:?} changed {key}: {old:?} -> {new:?}"),
+        }
+    }
+}
+
+/// 🔗 หนึ่ง record ในห่วงโซ่
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditRecord {
+    pub seq: u64,
+    pub timestamp: DateTime<Utc>,
+    pub event: AuditEvent,
+    pub prev_hash: String,
+    pub hash: String,
+}
+
+/// ส่วนที่ถูก hash - ทุกช่องยกเว้น `hash` เอง
+#[derive(Serialize)]
+struct RecordBody<'a> {
+    seq: u64,
+    timestamp: &'a DateTime<Utc>,
+    event: &'a AuditEvent,
+    prev_hash: &'a str,
+}
+
+impl AuditRecord {
+    fn new(seq: u64, timestamp: DateTime<Utc>, event: AuditEvent, prev_hash: String) -> Self {
+        let mut record = Self {
+            seq,
+            timestamp,
+            event,
+            prev_hash,
+            hash: String::new(),
+        };
+        record.hash = record.compute_hash();
+        record
+    }
+
+    /// SHA-256 ของ JSON ของ `seq`, `timestamp`, `event` และ `prev_hash` (ลำดับ field คงที่)
+    ///
+    /// # Panics
+    ///
+    /// ไม่ควร panic - ทุก field แปลงเป็น JSON ได้เสมอ
+    #[must_use]
+    pub fn compute_hash(&self) -> String {
+        let body = RecordBody {
+            seq: self.seq,
+            timestamp: &self.timestamp,
+            event: &self.event,
+            prev_hash: &self.prev_hash,
+        };
+        let json = serde_json::to_vec(&body).expect("audit record serializes to JSON");
+        hex::encode(Sha256::digest(json))
+    }
+}
+
+/// ⚓ ปลายห่วงโซ่: record ล่าสุดคือลำดับไหนและ hash อะไร
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChainHead {
+    /// จำนวน record ทั้งหมด (= `seq` ของ record ล่าสุด, 0 ถ้ายังว่าง)
+    pub seq: u64,
+    pub hash: String,
+}
+
+impl ChainHead {
+    fn genesis() -> Self {
+        Self {
+            seq: 0,
+            hash: GENESIS_HASH.to_string(),
+        }
+    }
+}
+
+/// 💔 ห่วงโซ่ขาดเพราะอะไร
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChainProblem {
+    /// บรรทัดไม่ใช่ JSON ของ `AuditRecord`
+    Malformed(String),
+    /// เนื้อหา record ไม่ตรงกับ `hash` ของตัวเอง - ถูกแก้
+    HashMismatch,
+    /// `seq` ไม่ต่อเนื่อง - record ถูกลบ แทรก หรือสลับ
+    SequenceGap { expected: u64, found: u64 },
+    /// `prev_hash` ไม่ตรงกับ record ก่อนหน้า
+    BrokenLink,
+    /// ไฟล์ segment หายไประหว่างกลาง
+    MissingSegment(u32),
+    /// ห่วงโซ่สั้นกว่า anchor ที่เคยเห็น - ท้ายไฟล์ถูกตัดทิ้ง
+    Truncated { anchor: u64, found: u64 },
+    /// record ที่ตำแหน่ง anchor มี hash ไม่ตรงกับที่เคยเห็น - ห่วงโซ่ถูกสร้างใหม่
+    AnchorMismatch { seq: u64 },
+}
+
+impl fmt::Display for ChainProblem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Malformed(message) => write!(f, "malformed record: {message}"),
+            Self::HashMismatch => f.write_str("record hash does not match its contents"),
+            Self::SequenceGap { expected, found } => {
+                write!(f, "expected seq {expected}, found {found}")
+            }
+            Self::BrokenLink => f.write_str("prev_hash does not match the previous record"),
+            Self::MissingSegment(number) => write!(f, "segment {number} is missing"),
+            Self::Truncated { anchor, found } => {
+                write!(f, "chain ends at seq {found} but anchor saw seq {anchor}")
+            }
+            Self::AnchorMismatch { seq } => write!(f, "record {seq} differs from the anchor"),
+        }
+    }
+}
+
+/// ❌ ข้อผิดพลาดของ audit log
+#[derive(Debug)]
+pub enum AuditError {
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+    /// ห่วงโซ่ขาด - `line` นับจาก 1 ในไฟล์ `path` (0 = ทั้งไฟล์/ท้ายห่วงโซ่)
+    Tampered {
+        path: PathBuf,
+        line: usize,
+        problem: ChainProblem,
+    },
+}
+
+impl fmt::Display for AuditError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io { path, source } => write!(f, "cannot access {}: {source}", path.display()),
+            Self::Tampered {
+                path,
+                line,
+                problem,
+            } => write!(f, "audit log {}:{line}: {problem}", path.display()),
+        }
+    }
+}
+
+impl Error for AuditError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io { source, .. } => Some(source),
+            Self::Tampered { .. } => None,
+        }
+    }
+}
+
+fn io_error(path: &Path) -> impl FnOnce(io::Error) -> AuditError + '_ {
+    move |source| AuditError::Io {
+        path: path.to_path_buf(),
+        source,
+    }
+}
+
+fn segment_path(dir: &Path, number: u32) -> PathBuf {
+    dir.join(format!("audit-{number:06}.jsonl"))
+}
+
+/// หมายเลข segment ทั้งหมดใน `dir` เรียงจากเก่าไปใหม่
+fn segment_numbers(dir: &Path) -> Result<Vec<u32>, AuditError> {
+    let mut numbers = Vec::new();
+    for entry in fs::read_dir(dir).map_err(io_error(dir))? {
+        let name = entry.map_err(io_error(dir))?.file_name();
+        if let Some(number) = name
+            .to_str()
+            .and_then(|name| name.strip_prefix("audit-")?.strip_suffix(".jsonl"))
+            .and_then(|number| number.parse().ok())
+        {
+            numbers.push(number);
+        }
+    }
+    numbers.sort_unstable();
+    Ok(numbers)
+}
+
+/// 🔍 ตรวจทุก segment ใน `dir` ตั้งแต่ record แรก คืนปลายห่วงโซ่ถ้าไม่มีอะไรผิด
+///
+/// # Errors
+///
+/// - [`AuditError::Io`] ถ้าอ่านไฟล์ไม่ได้
+/// - [`AuditError::Tampered`] พร้อมไฟล์/บรรทัดแรกที่ห่วงโซ่ขาด
+pub fn verify_audit_log(dir: impl AsRef<Path>) -> Result<ChainHead, AuditError> {
+    verify_chain(dir.as_ref()).map(|(head, _)| head)
+}
+
+/// 🔍 เหมือน [`verify_audit_log`] แต่เทียบกับ `anchor` ที่บันทึกไว้ก่อนหน้าด้วย - จับการตัดท้ายไฟล์
+/// และการสร้างห่วงโซ่ใหม่ทั้งเส้นที่ `verify_audit_log` อย่างเดียวมองไม่เห็น
+///
+/// # Errors
+///
+/// เหมือน [`verify_audit_log`] และคืน [`ChainProblem::Truncated`] / [`ChainProblem::AnchorMismatch`]
+/// เมื่อห่วงโซ่ไม่มี record ที่ anchor เคยเห็น
+pub fn verify_audit_log_anchored(
+    dir: impl AsRef<Path>,
+    anchor: &ChainHead,
+) -> Result<ChainHead, AuditError> {
+    let dir = dir.as_ref();
+    let (head, hashes) = verify_chain(dir)?;
+    let problem = match hashes.get(usize::try_from(anchor.seq).unwrap_or(usize::MAX)) {
+        None => ChainProblem::Truncated {
+            anchor: anchor.seq,
+            found: head.seq,
+        },
+        Some(hash) if *hash != anchor.hash => ChainProblem::AnchorMismatch { seq: anchor.seq },
+        Some(_) => return Ok(head),
+    };
+    Err(AuditError::Tampered {
+        path: dir.to_path_buf(),
+        line: 0,
+        problem,
+    })
+}
+
+/// เดินห่วงโซ่ทั้งหมด คืนปลายห่วงโซ่และ hash ของทุก record (index 0 = genesis)
+fn verify_chain(dir: &Path) -> Result<(ChainHead, Vec<String>), AuditError> {
+    let mut head = ChainHead::genesis();
+    let mut hashes = vec![head.hash.clone()];
+    for (expected, number) in (1..).zip(segment_numbers(dir)?) {
+        let path = segment_path(dir, number);
+        let tampered = |line, problem| AuditError::Tampered {
+            path: path.clone(),
+            line,
+            problem,
+        };
+        if number != expected {
+            return Err(tampered(0, ChainProblem::MissingSegment(expected)));
+        }
+        let file = File::open(&path).map_err(io_error(&path))?;
+        for (index, line) in BufReader::new(file).lines().enumerate() {
+            let line = line.map_err(io_error(&path))?;
+            let record: AuditRecord = serde_json::from_str(&line)
+                .map_err(|error| tampered(index + 1, ChainProblem::Malformed(error.to_string())))?;
+            let problem = if record.hash != record.compute_hash() {
+                Some(ChainProblem::HashMismatch)
+            } else if record.seq != head.seq + 1 {
+                Some(ChainProblem::SequenceGap {
+                    expected: head.seq + 1,
+                    found: record.seq,
+                })
+            } else if record.prev_hash != head.hash {
+                Some(ChainProblem::BrokenLink)
+            } else {
+                None
+            };
+            if let Some(problem) = problem {
+                return Err(tampered(index + 1, problem));
+            }
+            head = ChainHead {
+                seq: record.seq,
+                hash: record.hash,
+            };
+            hashes.push(head.hash.clone());
+        }
+    }
+    Ok((head, hashes))
+}
+
+#[derive(Debug)]
+struct Writer {
+    dir: PathBuf,
+    segment: u32,
+    file: File,
+    written: u64,
+    head: ChainHead,
+}
+
+impl Writer {
+    fn open_segment(dir: &Path, segment: u32) -> Result<(File, u64), AuditError> {
+        let path = segment_path(dir, segment);
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .map_err(io_error(&path))?;
+        let written = file.metadata().map_err(io_error(&path))?.len();
+        Ok((file, written))
+    }
+}
+
+/// 📒 audit log ที่เขียนต่อท้ายได้อย่างเดียว - clone ได้ราคาถูก ทุก clone เขียนห่วงโซ่เดียวกัน
+#[derive(Debug, Clone)]
+pub struct AuditLog {
+    writer: Arc<Mutex<Writer>>,
+    clock: Arc<dyn Clock>,
+    max_segment_bytes: u64,
+}
+
+impl AuditLog {
+    /// ขนาดไฟล์ที่เริ่มหมุนไป segment ใหม่
+    pub const DEFAULT_MAX_SEGMENT_BYTES: u64 = 1024 * 1024;
+
+    /// 📂 เปิด (หรือสร้าง) log ใน `dir` - ตรวจห่วงโซ่เดิมทั้งหมดก่อน จะไม่เขียนต่อบนห่วงโซ่ที่ขาด
+    ///
+    /// # Errors
+    ///
+    /// คืน [`AuditError`] ถ้าสร้างโฟลเดอร์ไม่ได้ หรือห่วงโซ่เดิมไม่ผ่าน [`verify_audit_log`]
+    pub fn open(dir: impl AsRef<Path>) -> Result<Self, AuditError> {
+        let dir = dir.as_ref().to_path_buf();
+        fs::create_dir_all(&dir).map_err(io_error(&dir))?;
+        let head = verify_audit_log(&dir)?;
+        let segment = segment_numbers(&dir)?.last().copied().unwrap_or(1);
+        let (file, written) = Writer::open_segment(&dir, segment)?;
+        Ok(Self {
+            writer: Arc::new(Mutex::new(Writer {
+                dir,
+                segment,
+                file,
+                written,
+                head,
+            })),
+            clock: clock::system_clock(),
+            max_segment_bytes: Self::DEFAULT_MAX_SEGMENT_BYTES,
+        })
+    }
+
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_max_segment_bytes(mut self, max_segment_bytes: u64) -> Self {
+        self.max_segment_bytes = max_segment_bytes;
+        self
+    }
+
+    fn writer(&self) -> std::sync::MutexGuard<'_, Writer> {
+        self.writer.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// ⚓ ปลายห่วงโซ่ปัจจุบัน - เก็บไว้นอกเครื่องแล้วใช้กับ [`verify_audit_log_anchored`]
+    #[must_use]
+    pub fn head(&self) -> ChainHead {
+        self.writer().head.clone()
+    }
+
+    /// 📂 segment ที่กำลังเขียนอยู่
+    #[must_use]
+    pub fn current_segment(&self) -> PathBuf {
+        let writer = self.writer();
+        segment_path(&writer.dir, writer.segment)
+    }
+
+    /// ✍️ ต่อ record ใหม่ท้ายห่วงโซ่ แล้ว flush ลงไฟล์ทันที
+    ///
+    /// # Errors
+    ///
+    /// คืน [`AuditError::Io`] ถ้าเขียนไฟล์ไม่ได้ - ห่วงโซ่ใน memory จะไม่ขยับ
+    ///
+    /// # Panics
+    ///
+    /// ไม่ควร panic - record แปลงเป็น JSON ได้เสมอ
+    pub fn append(&self, event: AuditEvent) -> Result<AuditRecord, AuditError> {
+        let mut writer = self.writer();
+        let timestamp = DateTime::<Utc>::from(self.clock.system_time());
+        let record = AuditRecord::new(
+            writer.head.seq + 1,
+            timestamp,
+            event,
+            writer.head.hash.clone(),
+        );
+        let mut line = serde_json::to_string(&record).expect("audit record serializes to JSON");
+        line.push('\n');
+        let length = line.len() as u64;
+
+        if writer.written > 0 && writer.written + length > self.max_segment_bytes {
+            let next = writer.segment + 1;
+            let (file, written) = Writer::open_segment(&writer.dir, next)?;
+            writer.segment = next;
+            writer.file = file;
+            writer.written = written;
+        }
+        let path = segment_path(&writer.dir, writer.segment);
+        writer
+            .file
+            .write_all(line.as_bytes())
+            .and_then(|()| writer.file.flush())
+            .map_err(io_error(&path))?;
+        writer.written += length;
+        writer.head = ChainHead {
+            seq: record.seq,
+            hash: record.hash.clone(),
+        };
+        drop(writer);
+        Ok(record)
+    }
+
+    /// ✍️ เหมือน [`Self::append`] แต่ไม่คืน error - ใช้ในจุดที่การทำงานหลักต้องไปต่อ
+    /// (เขียนไม่ได้จะถูก log ระดับ error แทน)
+    pub fn record(&self, event: AuditEvent) {
+        if let Err(error) = self.append(event) {
+            logger::error!(target: "audit", "cannot append audit record: {error}");
+        }
+    }
+}
+
+/// 🚀 สาธิต audit log: บันทึก หมุนไฟล์ ตรวจ แล้วลองแก้ record
+///
+/// # Panics
+///
+/// panic ถ้าสร้างหรือเขียนไฟล์ในโฟลเดอร์ชั่วคราวไม่ได้
+pub fn demonstrate_audit_log() {
+    println!("📒 Audit Log - hash chain ที่แก้แล้วจับได้");
+
+    let dir = tempfile::tempdir().expect("temporary directory");
+    let log = AuditLog::open(dir.path())
+        .expect("open audit log")
+        .with_max_segment_bytes(600);
+    for event in [
+        AuditEvent::Login {
+            user: "alice".to_string(),
+            success: true,
+            method: "password".to_string(),
+        },
+        AuditEvent::Login {
+            user: "mallory".to_string(),
+            success: false,
+            method: "password".to_string(),
+        },
+        AuditEvent::PermissionDenied {
+            user: "mallory".to_string(),
+            action: "delete".to_string(),
+            resource: "users/1".to_string(),
+            reason: "no role or rule allows users:delete".to_string(),
+        },
+        AuditEvent::ConfigChanged {
+            actor: "alice".to_string(),
+            key: "server.port".to_string(),
+            old: Some("8080".to_string()),
+            new: Some("9090".to_string()),
+        },
+    ] {
+        let record = log.append(event).expect("append audit record");
+        println!(
+            "   🔗 #{} {} (prev {}… → {}…)",
+            record.seq,
+            record.event,
+            &record.prev_hash[..8],
+            &record.hash[..8]
+        );
+    }
+    let anchor = log.head();
+    println!(
+        "   🔄 เขียนถึง {} (หมุนไฟล์เมื่อเกิน 600 bytes)",
+        log.current_segment()
+            .file_name()
+            .unwrap_or_default()
+            .to_string_lossy()
+    );
+    println!(
+        "   ✅ verify: {:?}",
+        verify_audit_log(dir.path()).map(|head| head.seq)
+    );
+
+    let first = segment_path(dir.path(), 1);
+    let original = fs::read_to_string(&first).expect("read segment");
+    fs::write(&first, original.replacen("mallory", "nobody", 1)).expect("write segment");
+    match verify_audit_log(dir.path()) {
+        Ok(_) => println!("   😱 แก้ชื่อแล้วไม่มีใครรู้!"),
+        Err(error) => println!("   🚨 แก้ชื่อใน record: {error}"),
+    }
+    fs::write(&first, original).expect("write segment");
+
+    fs::remove_file(log.current_segment()).expect("remove segment");
+    println!(
+        "   ✂️ ลบไฟล์ล่าสุด: verify อย่างเดียว = {:?}, เทียบ anchor = {}",
+        verify_audit_log(dir.path()).map(|head| head.seq),
+        verify_audit_log_anchored(dir.path(), &anchor)
+            .map_or_else(|error| error.to_string(), |_| "ok".to_string())
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::MockClock;
+    use std::time::Duration;
+
+    fn login(user: &str, success: bool) -> AuditEvent {
+        AuditEvent::Login {
+            user: user.to_string(),
+            success,
+            method: "password".to_string(),
+        }
+    }
+
+    fn problem(result: Result<ChainHead, AuditError>) -> (usize, ChainProblem) {
+        match result {
+            Err(AuditError::Tampered { line, problem, .. }) => (line, problem),
+            other => panic!("expected tampering to be detected, got {other:?}"),
+        }
+    }
+
+    fn filled_log(dir: &Path, records: usize) -> AuditLog {
+        let clock = MockClock::new();
+        let log = AuditLog::open(dir)
+            .unwrap()
+            .with_clock(Arc::new(clock.clone()));
+        for index in 0..records {
+            clock.advance(Duration::from_secs(1));
+            log.append(login(&format!("user{index}"), index % 2 == 0))
+                .unwrap();
+        }
+        log
+    }
+
+    #[test]
+    fn test_records_are_hash_chained_and_survive_reopen() {
+        let dir = tempfile::tempdir().unwrap();
+        let log = filled_log(dir.path(), 3);
+        let head = log.head();
+        assert_eq!(head.seq, 3);
+        assert_eq!(verify_audit_log(dir.path()).unwrap(), head);
+
+        let contents = fs::read_to_string(log.current_segment()).unwrap();
+        let records: Vec<AuditRecord> = contents
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(records[0].prev_hash, GENESIS_HASH);
+        for pair in records.windows(2) {
+            assert_eq!(pair[1].prev_hash, pair[0].hash);
+            assert!(pair[1].timestamp > pair[0].timestamp);
+        }
+        assert!(contents.contains(r#""event":{"type":"login","user":"user0","success":true"#));
+
+        // เปิดใหม่แล้วเขียนต่อห่วงโซ่เดิม
+        drop(log);
+        let reopened = AuditLog::open(dir.path()).unwrap();
+        assert_eq!(reopened.head(), head);
+        let record = reopened
+            .append(AuditEvent::ConfigChanged {
+                actor: "ops".to_string(),
+                key: "log.level".to_string(),
+                old: None,
+                new: Some("debug".to_string()),
+            })
+            .unwrap();
+        assert_eq!((record.seq, record.prev_hash), (4, head.hash));
+        assert_eq!(verify_audit_log(dir.path()).unwrap().seq, 4);
+    }
+
+    #[test]
+    fn test_edited_record_is_detected() {
+        let dir = tempfile::tempdir().unwrap();
+        let log = filled_log(dir.path(), 4);
+        let path = log.current_segment();
+        let original = fs::read_to_string(&path).unwrap();
+        let lines: Vec<&str> = original.lines().collect();
+
+        // เปลี่ยน login ที่ล้มเหลวให้กลายเป็นสำเร็จ
+        let forged =
+            original.replacen(r#""user1","success":false"#, r#""user1","success":true"#, 1);
+        fs::write(&path, &forged).unwrap();
+        assert_eq!(
+            problem(verify_audit_log(dir.path())),
+            (2, ChainProblem::HashMismatch)
+        );
+
+        // แก้แล้วคำนวณ hash ใหม่ให้ตัวเอง - record ถัดไปยังชี้ไปที่ hash เดิม
+        let mut record: AuditRecord = serde_json::from_str(lines[1]).unwrap();
+        record.event = login("user1", true);
+        record.hash = record.compute_hash();
+        let rehashed = original.replacen(lines[1], &serde_json::to_string(&record).unwrap(), 1);
+        fs::write(&path, rehashed).unwrap();
+        assert_eq!(
+            problem(verify_audit_log(dir.path())),
+            (3, ChainProblem::BrokenLink)
+        );
+
+        // ลบ record กลางไฟล์
+        let removed = [lines[0], lines[2], lines[3]].join("\n");
+        fs::write(&path, removed).unwrap();
+        assert_eq!(
+            problem(verify_audit_log(dir.path())),
+            (
+                2,
+                ChainProblem::SequenceGap {
+                    expected: 2,
+                    found: 3
+                }
+            )
+        );
+
+        fs::write(&path, format!("{}\nnot json\n", lines[0])).unwrap();
+        assert!(matches!(
+            problem(verify_audit_log(dir.path())),
+            (2, ChainProblem::Malformed(_))
+        ));
+        // ห่วงโซ่ที่ขาดเปิดเพื่อเขียนต่อไม่ได้
+        assert!(matches!(
+            AuditLog::open(dir.path()),
+            Err(AuditError::Tampered { .. })
+        ));
+
+        fs::write(&path, original).unwrap();
+        assert_eq!(verify_audit_log(dir.path()).unwrap().seq, 4);
+    }
+
+    #[test]
+    fn test_rotation_keeps_chain_across_segments() {
+        let dir = tempfile::tempdir().unwrap();
+        let clock = MockClock::new();
+        let log = AuditLog::open(dir.path())
+            .unwrap()
+            .with_clock(Arc::new(clock))
+            .with_max_segment_bytes(400);
+        for index in 0..6 {
+            log.append(login(&format!("user{index}"), true)).unwrap();
+        }
+        let segments = segment_numbers(dir.path()).unwrap();
+        assert!(segments.len() >= 3, "{segments:?}");
+        for number in &segments {
+            let size = fs::metadata(segment_path(dir.path(), *number))
+                .unwrap()
+                .len();
+            assert!(size <= 400, "segment {number} has {size} bytes");
+        }
+        let anchor = log.head();
+        assert_eq!(verify_audit_log(dir.path()).unwrap(), anchor);
+
+        // segment กลางหาย
+        let middle = segment_path(dir.path(), 2);
+        let saved = fs::read(&middle).unwrap();
+        fs::remove_file(&middle).unwrap();
+        assert_eq!(
+            problem(verify_audit_log(dir.path())).1,
+            ChainProblem::MissingSegment(2)
+        );
+        // เปลี่ยนชื่อไฟล์ถัดไปมาแทน ก็ยังต่อห่วงโซ่ไม่ติด
+        fs::rename(segment_path(dir.path(), 3), &middle).unwrap();
+        assert!(matches!(
+            problem(verify_audit_log(dir.path())).1,
+            ChainProblem::SequenceGap { .. }
+        ));
+        fs::rename(&middle, segment_path(dir.path(), 3)).unwrap();
+        fs::write(&middle, saved).unwrap();
+        assert_eq!(verify_audit_log(dir.path()).unwrap(), anchor);
+
+        // ตัด segment ล่าสุดทิ้ง: ห่วงโซ่ที่เหลือยังถูกต้อง แต่ anchor จับได้
+        let last = *segments.last().unwrap();
+        fs::remove_file(segment_path(dir.path(), last)).unwrap();
+        let shortened = verify_audit_log(dir.path()).unwrap();
+        assert!(shortened.seq < anchor.seq);
+        assert!(matches!(
+            problem(verify_audit_log_anchored(dir.path(), &anchor)).1,
+            ChainProblem::Truncated { .. }
+        ));
+        assert_eq!(
+            verify_audit_log_anchored(dir.path(), &shortened).unwrap(),
+            shortened
+        );
+    }
+
+    #[test]
+    fn test_logins_and_denials_are_audited() {
+        use crate::security::authentication::{AuthorizationServer, OAuthClient, RegisteredClient};
+        use crate::security::authorization::workshop_policy;
+        use crate::web_development::rest_api::ApiRouter;
+        use crate::web_development::web_server::WebServer;
+        use crate::web_development::{HttpRequest, HttpStatus};
+
+        let dir = tempfile::tempdir().unwrap();
+        let log = AuditLog::open(dir.path()).unwrap();
+
+        let mut server = WebServer::new();
+        Arc::new(
+            AuthorizationServer::new("https://auth.test", b"signing-key")
+                .with_client(
+                    RegisteredClient::new("app", "s3cret", "https://app.test/cb")
+                        .with_scopes(&["openid"]),
+                )
+                .with_user("alice", "password123", "alice@example.com")
+                .with_audit_log(log.clone()),
+        )
+        .mount(&mut server);
+        let client = OAuthClient::new("app", "s3cret", "https://app.test/cb");
+        let pending = client.begin(&mut rand::thread_rng());
+        for password in ["guess", "password123"] {
+            let _ =
+                server.handle_request(&client.authorization_request(&pending, "alice", password));
+        }
+
+        let router = ApiRouter::new()
+            .with_policy(workshop_policy())
+            .with_audit_log(log.clone());
+        let denied = HttpRequest::new("DELETE", "/api/users/3")
+            .with_header("X-User-Id", "2")
+            .with_header("X-User-Roles", "viewer");
+        assert_eq!(router.route(&denied).status, HttpStatus::Forbidden);
+
+        let contents = fs::read_to_string(log.current_segment()).unwrap();
+        let events: Vec<AuditEvent> = contents
+            .lines()
+            .map(|line| serde_json::from_str::<AuditRecord>(line).unwrap().event)
+            .collect();
+        assert_eq!(
+            events[..2],
+            [login_via("alice", false), login_via("alice", true)]
+        );
+        assert!(matches!(
+            &events[2],
+            AuditEvent::PermissionDenied { user, action, resource, .. }
+                if user == "2" && action == "delete" && resource == "users/3"
+        ));
+        assert_eq!(verify_audit_log(dir.path()).unwrap().seq, 3);
+    }
+
+    fn login_via(user: &str, success: bool) -> AuditEvent {
+        AuditEvent::Login {
+            user: user.to_string(),
+            success,
+            method: "oauth2".to_string(),
+        }
+    }
+}
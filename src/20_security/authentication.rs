@@ -7,6 +7,22 @@
 use std::collections::{HashMap, HashSet};
 use std::time::{SystemTime, UNIX_EPOCH, Duration};
 use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use base64::Engine as _;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use hmac::{Hmac, Mac};
+use rand::Rng;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::clock::{self, Clock};
+use crate::security::audit::{AuditEvent, AuditLog};
+use crate::web_development::middleware::Middleware;
+use crate::web_development::url::{Query, encode_query, parse_query};
+use crate::web_development::web_server::WebServer;
+use crate::web_development::{HttpRequest, HttpResponse, HttpStatus};
 
 /// 👑 บทบาทผู้ใช้ - เหมือนตำแหน่งงานในบริษัท
 /// 🎭 แต่ละบทบาทมีสิทธิ์และหน้าที่ที่แตกต่างกัน!
@@ -706,6 +722,1315 @@ impl AuthenticationSystem {
     }
 }
 
+// 🎟️ OAuth2 + OpenID Connect - ให้แอปอื่นเข้าถึงข้อมูลของเราโดยไม่ต้องรู้รหัสผ่าน
+//
+// ทั้งสามฝ่ายคุยกันด้วย `HttpRequest`/`HttpResponse` ของ mini web framework (บทที่ 17):
+// 1. 🧑‍💻 `OAuthClient` สร้าง PKCE verifier, `state` และ `nonce` แล้วส่งผู้ใช้ไป `POST /authorize`
+// 2. 🏛️ `AuthorizationServer` ตรวจรหัสผ่าน แล้ว redirect (302) กลับไปที่ client พร้อม `code`
+// 3. 🔁 client แลก `code` + `code_verifier` ที่ `POST /token` ได้ access token (JWT), refresh token และ id token
+// 4. 🛡️ resource server ตรวจ JWT และ scope ด้วย `ScopeMiddleware` ก่อนเรียก handler
+//
+// ⚠️ จำลองเพื่อการศึกษา - ของจริงต้องวิ่งบน HTTPS และใช้กุญแจแบบ asymmetric (RS256/ES256)
+// เพื่อให้ resource server ตรวจ token ได้โดยไม่ต้องถือกุญแจที่ใช้เซ็น
+
+/// ❌ error ตาม RFC 6749/6750 - `code()` คือค่าของ field `error` ใน response
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OAuthError {
+    /// parameter ขาดหรือผิดรูปแบบ
+    InvalidRequest(String),
+    /// client ไม่รู้จัก, secret ผิด หรือ `redirect_uri` ไม่ตรงกับที่ลงทะเบียน
+    InvalidClient,
+    /// code/refresh token ผิด หมดอายุ ถูกใช้ไปแล้ว หรือ PKCE verifier ไม่ตรง
+    InvalidGrant(String),
+    UnsupportedGrantType(String),
+    /// ขอ scope ที่ client ไม่ได้รับอนุญาต
+    InvalidScope(String),
+    /// ผู้ใช้ยืนยันตัวตนไม่ผ่าน
+    AccessDenied,
+    /// access token ไม่มี เสีย หรือหมดอายุ
+    InvalidToken(String),
+    /// token ถูกต้องแต่ไม่มี scope ที่ต้องใช้
+    InsufficientScope(String),
+    /// `state` ที่ได้กลับมาไม่ตรงกับที่ส่งไป - อาจเป็น CSRF
+    StateMismatch,
+    /// `nonce` ใน id token ไม่ตรง - อาจเป็น token ที่ถูกนำมาเล่นซ้ำ
+    NonceMismatch,
+    /// server ทำงานต่อไม่ได้ (เช่นเวลาหมดอายุเกินช่วงของนาฬิกา) - ไม่ใช่ความผิดของ client
+    ServerError(String),
+}
+
+impl OAuthError {
+    #[must_use]
+    pub const fn code(&self) -> &'static str {
+        match self {
+            Self::InvalidRequest(_) => "invalid_request",
+            Self::InvalidClient => "invalid_client",
+            Self::InvalidGrant(_) => "invalid_grant",
+            Self::UnsupportedGrantType(_) => "unsupported_grant_type",
+            Self::InvalidScope(_) => "invalid_scope",
+            Self::AccessDenied => "access_denied",
+            Self::InvalidToken(_) => "invalid_token",
+            Self::InsufficientScope(_) => "insufficient_scope",
+            Self::StateMismatch => "state_mismatch",
+            Self::NonceMismatch => "nonce_mismatch",
+            Self::ServerError(_) => "server_error",
+        }
+    }
+
+    #[must_use]
+    pub fn description(&self) -> String {
+        match self {
+            Self::InvalidRequest(detail)
+            | Self::InvalidGrant(detail)
+            | Self::InvalidToken(detail)
+            | Self::ServerError(detail) => detail.clone(),
+            Self::UnsupportedGrantType(grant) => format!("grant_type \"{grant}\" is not supported"),
+            Self::InvalidScope(scope) => {
+                format!("scope \"{scope}\" is not allowed for this client")
+            }
+            Self::InsufficientScope(scope) => format!("token lacks the \"{scope}\" scope"),
+            Self::InvalidClient => "client authentication failed".to_string(),
+            Self::AccessDenied => "the resource owner denied the request".to_string(),
+            Self::StateMismatch => "state does not match the authorization request".to_string(),
+            Self::NonceMismatch => {
+                "id token nonce does not match the authorization request".to_string()
+            }
+        }
+    }
+
+    /// `InvalidClient`/`InvalidToken` → 401, `InsufficientScope` → 403, `ServerError` → 500 นอกนั้น 400
+    #[must_use]
+    pub const fn status(&self) -> HttpStatus {
+        match self {
+            Self::InvalidClient | Self::InvalidToken(_) => HttpStatus::Unauthorized,
+            Self::InsufficientScope(_) => HttpStatus::Forbidden,
+            Self::ServerError(_) => HttpStatus::InternalServerError,
+            _ => HttpStatus::BadRequest,
+        }
+    }
+
+    /// JSON `{"error", "error_description"}` - error ของ bearer token มี `WWW-Authenticate` ด้วย
+    #[must_use]
+    pub fn to_response(&self) -> HttpResponse {
+        let body =
+            serde_json::json!({ "error": self.code(), "error_description": self.description() });
+        let response = HttpResponse::json(self.status(), &body.to_string());
+        match self {
+            Self::InvalidToken(_) => {
+                response.with_header("WWW-Authenticate", "Bearer error=\"invalid_token\"")
+            }
+            Self::InsufficientScope(scope) => response.with_header(
+                "WWW-Authenticate",
+                &format!("Bearer error=\"insufficient_scope\", scope=\"{scope}\""),
+            ),
+            _ => response,
+        }
+    }
+
+    /// แปลง `error` + `error_description` ที่ได้จาก server กลับเป็น `OAuthError` (ฝั่ง client)
+    #[must_use]
+    pub fn from_code(code: &str, description: &str) -> Self {
+        let description = description.to_string();
+        match code {
+            "invalid_client" => Self::InvalidClient,
+            "invalid_grant" => Self::InvalidGrant(description),
+            "unsupported_grant_type" => Self::UnsupportedGrantType(description),
+            "invalid_scope" => Self::InvalidScope(description),
+            "access_denied" => Self::AccessDenied,
+            "invalid_token" => Self::InvalidToken(description),
+            "insufficient_scope" => Self::InsufficientScope(description),
+            "invalid_request" => Self::InvalidRequest(description),
+            "server_error" => Self::ServerError(description),
+            other => Self::InvalidRequest(format!("{other}: {description}")),
+        }
+    }
+
+    fn from_response(response: &HttpResponse) -> Self {
+        serde_json::from_str::<serde_json::Value>(&response.body)
+            .ok()
+            .and_then(|body| {
+                let code = body.get("error")?.as_str()?.to_string();
+                let description = body
+                    .get("error_description")
+                    .and_then(serde_json::Value::as_str)
+                    .unwrap_or_default()
+                    .to_string();
+                Some(Self::from_code(&code, &description))
+            })
+            .unwrap_or_else(|| {
+                Self::InvalidRequest(format!("unexpected response {}", response.status.as_str()))
+            })
+    }
+}
+
+impl fmt::Display for OAuthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.code(), self.description())
+    }
+}
+
+impl std::error::Error for OAuthError {}
+
+type HmacSha256 = Hmac<Sha256>;
+
+fn jwt_mac(key: &[u8], signing_input: &str) -> HmacSha256 {
+    let mut mac = HmacSha256::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(signing_input.as_bytes());
+    mac
+}
+
+/// 🔏 เซ็น claims เป็น JWT แบบ HS256: `base64url(header).base64url(claims).base64url(signature)`
+///
+/// JWT แค่ *เซ็น* ไม่ได้ *เข้ารหัส* - ใครก็ decode payload อ่านได้ ห้ามใส่ความลับลงไป
+#[must_use]
+pub fn encode_jwt<T: Serialize>(claims: &T, key: &[u8]) -> String {
+    let header = URL_SAFE_NO_PAD.encode(br#"{"alg":"HS256","typ":"JWT"}"#);
+    let payload = URL_SAFE_NO_PAD.encode(serde_json::to_vec(claims).unwrap_or_default());
+    let signing_input = format!("{header}.{payload}");
+    let signature = jwt_mac(key, &signing_input).finalize().into_bytes();
+    format!("{signing_input}.{}", URL_SAFE_NO_PAD.encode(signature))
+}
+
+/// 🔍 ตรวจลายเซ็น JWT (HS256 เท่านั้น) แล้วอ่าน claims - การตรวจ `exp`/`iss`/`aud` เป็นหน้าที่ของผู้เรียก
+///
+/// # Errors
+///
+/// คืน `OAuthError::InvalidToken` ถ้ารูปแบบผิด, `alg` ไม่ใช่ HS256 (รวมถึง `"none"`)
+/// หรือลายเซ็นไม่ตรง
+pub fn decode_jwt<T: DeserializeOwned>(token: &str, key: &[u8]) -> Result<T, OAuthError> {
+    let malformed = || OAuthError::InvalidToken("malformed token".to_string());
+    let mut parts = token.split('.');
+    let (Some(encoded_header), Some(payload), Some(signature), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return Err(malformed());
+    };
+
+    let header: serde_json::Value = URL_SAFE_NO_PAD
+        .decode(encoded_header)
+        .ok()
+        .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+        .ok_or_else(malformed)?;
+    // อย่าเชื่อ `alg` จาก token - ยอม `"none"` เมื่อไหร่ใครก็ปลอม token ได้
+    if header.get("alg").and_then(serde_json::Value::as_str) != Some("HS256") {
+        return Err(OAuthError::InvalidToken(
+            "unsupported signing algorithm".to_string(),
+        ));
+    }
+
+    let signature = URL_SAFE_NO_PAD.decode(signature).map_err(|_| malformed())?;
+    jwt_mac(key, &format!("{encoded_header}.{payload}"))
+        .verify_slice(&signature)
+        .map_err(|_| OAuthError::InvalidToken("signature does not match".to_string()))?;
+
+    URL_SAFE_NO_PAD
+        .decode(payload)
+        .ok()
+        .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+        .ok_or_else(malformed)
+}
+
+/// claims ของ access token - resource server ใช้ตัดสินว่าใคร (`sub`) ทำอะไรได้ (`scope`)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AccessClaims {
+    pub iss: String,
+    pub sub: String,
+    pub aud: String,
+    pub client_id: String,
+    /// scope คั่นด้วยช่องว่าง เช่น `"openid tasks:read"`
+    pub scope: String,
+    pub iat: u64,
+    pub exp: u64,
+    pub jti: String,
+}
+
+impl AccessClaims {
+    #[must_use]
+    pub fn has_scope(&self, scope: &str) -> bool {
+        self.scope
+            .split_whitespace()
+            .any(|granted| granted == scope)
+    }
+}
+
+/// claims ของ id token (OIDC) - บอก *client* ว่าผู้ใช้คือใคร ไม่ได้ใช้เรียก API
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IdClaims {
+    pub iss: String,
+    pub sub: String,
+    /// client ที่ token นี้ออกให้ - client อื่นต้องไม่ยอมรับ
+    pub aud: String,
+    pub email: String,
+    pub nonce: Option<String>,
+    pub iat: u64,
+    pub exp: u64,
+}
+
+/// 🔐 PKCE (RFC 7636) - client เก็บ `verifier` ไว้ ส่งไปแค่ `challenge = base64url(SHA-256(verifier))`
+///
+/// ใครดักได้ `code` ระหว่าง redirect ก็แลก token ไม่ได้ เพราะไม่มี `verifier`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pkce {
+    pub verifier: String,
+    pub challenge: String,
+}
+
+impl Pkce {
+    /// verifier สุ่ม 32 ไบต์ (43 ตัวอักษร base64url)
+    pub fn generate<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self::from_verifier(&random_token(rng))
+    }
+
+    #[must_use]
+    pub fn from_verifier(verifier: &str) -> Self {
+        Self {
+            verifier: verifier.to_string(),
+            challenge: Self::challenge_for(verifier),
+        }
+    }
+
+    /// วิธี `S256` - วิธีเดียวที่ server นี้รับ (`plain` ไม่ช่วยอะไรถ้า challenge ถูกดักได้)
+    #[must_use]
+    pub fn challenge_for(verifier: &str) -> String {
+        URL_SAFE_NO_PAD.encode(Sha256::digest(verifier.as_bytes()))
+    }
+
+    /// verifier ต้องยาว 43-128 ตัว และใช้แค่ `A-Z a-z 0-9 - . _ ~`
+    #[must_use]
+    pub fn is_valid_verifier(verifier: &str) -> bool {
+        (43..=128).contains(&verifier.len())
+            && verifier
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'.' | b'_' | b'~'))
+    }
+}
+
+/// ค่าสุ่ม 256 บิตในรูป base64url - ใช้เป็น code, refresh token, state, nonce
+fn random_token<R: Rng + ?Sized>(rng: &mut R) -> String {
+    let mut bytes = [0u8; 32];
+    rng.fill(&mut bytes);
+    URL_SAFE_NO_PAD.encode(bytes)
+}
+
+/// 🎫 response ของ `POST /token`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenSet {
+    pub access_token: String,
+    /// `"Bearer"` เสมอ
+    pub token_type: String,
+    /// อายุของ access token (วินาที)
+    pub expires_in: u64,
+    pub refresh_token: String,
+    pub scope: String,
+    /// มีเมื่อขอ scope `openid` ตอนแลก code
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id_token: Option<String>,
+}
+
+/// 📇 client ที่ลงทะเบียนไว้กับ authorization server
+#[derive(Debug, Clone)]
+pub struct RegisteredClient {
+    pub client_id: String,
+    secret: String,
+    pub redirect_uri: String,
+    pub scopes: Vec<String>,
+}
+
+impl RegisteredClient {
+    #[must_use]
+    pub fn new(client_id: &str, secret: &str, redirect_uri: &str) -> Self {
+        Self {
+            client_id: client_id.to_string(),
+            secret: secret.to_string(),
+            redirect_uri: redirect_uri.to_string(),
+            scopes: Vec::new(),
+        }
+    }
+
+    /// scope ที่ client นี้ขอได้
+    #[must_use]
+    pub fn with_scopes(mut self, scopes: &[&str]) -> Self {
+        self.scopes = scopes.iter().map(ToString::to_string).collect();
+        self
+    }
+}
+
+/// ผู้ใช้ (resource owner) - รหัสผ่านเก็บแบบ hash + salt ด้วย `PasswordHasher` เดียวกับ `AuthManager`
+struct ResourceOwner {
+    salt: String,
+    password_hash: String,
+    email: String,
+}
+
+struct AuthorizationCode {
+    client_id: String,
+    redirect_uri: String,
+    subject: String,
+    scope: String,
+    code_challenge: String,
+    nonce: Option<String>,
+    expires_at: u64,
+}
+
+struct RefreshGrant {
+    client_id: String,
+    subject: String,
+    scope: String,
+}
+
+/// code และ refresh token ที่ยังใช้ได้ - ถูกลบทันทีที่ใช้ (ใช้ซ้ำไม่ได้)
+#[derive(Default)]
+struct Grants {
+    codes: HashMap<String, AuthorizationCode>,
+    refresh_tokens: HashMap<String, RefreshGrant>,
+}
+
+#[derive(Deserialize)]
+struct AuthorizeParams {
+    response_type: String,
+    client_id: String,
+    redirect_uri: String,
+    #[serde(default)]
+    scope: String,
+    state: String,
+    code_challenge: Option<String>,
+    code_challenge_method: Option<String>,
+    nonce: Option<String>,
+    /// หน้า login ของ server ส่งมาพร้อมกัน (ผู้ใช้กรอกบน server ไม่ใช่บน client)
+    username: String,
+    password: String,
+}
+
+#[derive(Deserialize)]
+struct TokenParams {
+    grant_type: String,
+    client_id: String,
+    client_secret: String,
+    code: Option<String>,
+    redirect_uri: Option<String>,
+    code_verifier: Option<String>,
+    refresh_token: Option<String>,
+    scope: Option<String>,
+}
+
+/// 🏛️ Authorization server - ออก code, access token (JWT), refresh token และ id token
+///
+/// routes: `POST /authorize`, `POST /token`, `GET /userinfo`
+pub struct AuthorizationServer {
+    issuer: String,
+    audience: String,
+    signing_key: Vec<u8>,
+    clients: HashMap<String, RegisteredClient>,
+    users: HashMap<String, ResourceOwner>,
+    grants: Mutex<Grants>,
+    clock: Arc<dyn Clock>,
+    code_ttl: Duration,
+    access_token_ttl: Duration,
+    audit: Option<AuditLog>,
+}
+
+impl AuthorizationServer {
+    /// access token มี `aud` เป็น `"workshop-api"` จนกว่าจะเปลี่ยนด้วย `with_audience`
+    #[must_use]
+    pub fn new(issuer: &str, signing_key: &[u8]) -> Self {
+        Self {
+            issuer: issuer.to_string(),
+            audience: "workshop-api".to_string(),
+            signing_key: signing_key.to_vec(),
+            clients: HashMap::new(),
+            users: HashMap::new(),
+            grants: Mutex::new(Grants::default()),
+            clock: clock::system_clock(),
+            code_ttl: Duration::from_secs(60),
+            access_token_ttl: Duration::from_secs(300),
+            audit: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_audience(mut self, audience: &str) -> Self {
+        self.audience = audience.to_string();
+        self
+    }
+
+    #[must_use]
+    pub fn with_client(mut self, client: RegisteredClient) -> Self {
+        self.clients.insert(client.client_id.clone(), client);
+        self
+    }
+
+    #[must_use]
+    pub fn with_user(mut self, username: &str, password: &str, email: &str) -> Self {
+        let salt = PasswordHasher::generate_salt();
+        let owner = ResourceOwner {
+            password_hash: PasswordHasher::hash_password(password, &salt),
+            salt,
+            email: email.to_string(),
+        };
+        self.users.insert(username.to_string(), owner);
+        self
+    }
+
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_access_token_ttl(mut self, ttl: Duration) -> Self {
+        self.access_token_ttl = ttl;
+        self
+    }
+
+    /// 📒 บันทึกทุกการ login ที่ `/authorize` (สำเร็จและล้มเหลว) ลง audit log
+    #[must_use]
+    pub fn with_audit_log(mut self, audit: AuditLog) -> Self {
+        self.audit = Some(audit);
+        self
+    }
+
+    /// 🛡️ validator สำหรับ resource server ที่เชื่อ token จาก server นี้
+    #[must_use]
+    pub fn validator(&self) -> TokenValidator {
+        TokenValidator {
+            issuer: self.issuer.clone(),
+            audience: self.audience.clone(),
+            key: self.signing_key.clone(),
+            clock: Arc::clone(&self.clock),
+        }
+    }
+
+    /// 🔌 ลง route ทั้งสามบน `WebServer`
+    pub fn mount(self: &Arc<Self>, server: &mut WebServer) {
+        for route in ["POST /authorize", "POST /token", "GET /userinfo"] {
+            let authorization_server = Arc::clone(self);
+            server.route(route, move |request| authorization_server.handle(request));
+        }
+    }
+
+    #[must_use]
+    pub fn handle(&self, request: &HttpRequest) -> HttpResponse {
+        let path = request.path.split('?').next().unwrap_or_default();
+        let result = match (request.method.as_str(), path) {
+            ("POST", "/authorize") => return self.authorize(request),
+            ("POST", "/token") => self.token(request),
+            ("GET", "/userinfo") => self.userinfo(request),
+            _ => return HttpResponse::new(HttpStatus::NotFound),
+        };
+        result.unwrap_or_else(|error| error.to_response())
+    }
+
+    /// เวลาหมดอายุ `now + ttl` - ttl ที่ยาวจนล้น `u64` ถือเป็น config ผิด ไม่ใช่ token ที่ไม่มีวันหมดอายุ
+    fn expires_at(now: u64, ttl: Duration) -> Result<u64, OAuthError> {
+        now.checked_add(ttl.as_secs())
+            .ok_or_else(|| OAuthError::ServerError("token lifetime is out of range".to_string()))
+    }
+
+    /// เทียบ secret ด้วย HMAC ของทั้งสองฝั่งแล้ว `verify_slice` (constant time)
+    ///
+    /// `==` ของ `String` หยุดที่ byte แรกที่ต่าง - จับเวลาแล้วเดา secret ทีละตัวได้
+    /// การ MAC ก่อนยังซ่อนความยาวของ secret จริงไปด้วย
+    fn client_secret_matches(&self, client: &RegisteredClient, presented: &str) -> bool {
+        let expected = jwt_mac(&self.signing_key, &client.secret)
+            .finalize()
+            .into_bytes();
+        jwt_mac(&self.signing_key, presented)
+            .verify_slice(&expected)
+            .is_ok()
+    }
+
+    fn grants(&self) -> std::sync::MutexGuard<'_, Grants> {
+        self.grants
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    /// `POST /authorize` - ผู้ใช้ login บน server แล้วถูก redirect กลับไปหา client
+    ///
+    /// ถ้า `client_id`/`redirect_uri` ไม่ถูกต้อง ตอบ 400 ตรงๆ ห้าม redirect ไปที่ URI ที่ไม่รู้จัก
+    /// (ไม่งั้น server กลายเป็น open redirector) error อื่นส่งกลับไปทาง redirect พร้อม `state`
+    fn authorize(&self, request: &HttpRequest) -> HttpResponse {
+        let params = match request.body.parse::<Query<AuthorizeParams>>() {
+            Ok(Query(params)) => params,
+            Err(error) => return OAuthError::InvalidRequest(error.to_string()).to_response(),
+        };
+        let Some(client) = self
+            .clients
+            .get(&params.client_id)
+            .filter(|client| client.redirect_uri == params.redirect_uri)
+        else {
+            return OAuthError::InvalidClient.to_response();
+        };
+
+        let redirect = |pairs: &[(&str, &str)]| {
+            let separator = if client.redirect_uri.contains('?') {
+                '&'
+            } else {
+                '?'
+            };
+            let location = format!("{}{separator}{}", client.redirect_uri, encode_query(pairs));
+            HttpResponse::new(HttpStatus::Found).with_header("Location", &location)
+        };
+        match self.issue_code(client, &params) {
+            Ok(code) => redirect(&[("code", &code), ("state", &params.state)]),
+            Err(error) => redirect(&[
+                ("error", error.code()),
+                ("error_description", &error.description()),
+                ("state", &params.state),
+            ]),
+        }
+    }
+
+    fn issue_code(
+        &self,
+        client: &RegisteredClient,
+        params: &AuthorizeParams,
+    ) -> Result<String, OAuthError> {
+        if params.response_type != "code" {
+            return Err(OAuthError::InvalidRequest(
+                "response_type must be \"code\"".to_string(),
+            ));
+        }
+        let Some(code_challenge) = &params.code_challenge else {
+            return Err(OAuthError::InvalidRequest(
+                "PKCE code_challenge is required".to_string(),
+            ));
+        };
+        if params.code_challenge_method.as_deref() != Some("S256") {
+            return Err(OAuthError::InvalidRequest(
+                "code_challenge_method must be \"S256\"".to_string(),
+            ));
+        }
+        if let Some(scope) = params
+            .scope
+            .split_whitespace()
+            .find(|scope| !client.scopes.iter().any(|allowed| allowed == scope))
+        {
+            return Err(OAuthError::InvalidScope(scope.to_string()));
+        }
+        let authenticated = self.users.get(&params.username).is_some_and(|owner| {
+            PasswordHasher::verify_password(&params.password, &owner.salt, &owner.password_hash)
+        });
+        if let Some(audit) = &self.audit {
+            audit.record(AuditEvent::Login {
+                user: params.username.clone(),
+                success: authenticated,
+                method: "oauth2".to_string(),
+            });
+        }
+        if !authenticated {
+            return Err(OAuthError::AccessDenied);
+        }
+
+        let now = self.clock.unix_timestamp();
+        let expires_at = Self::expires_at(now, self.code_ttl)?;
+        let code = random_token(&mut rand::thread_rng());
+        let grant = AuthorizationCode {
+            client_id: client.client_id.clone(),
+            redirect_uri: client.redirect_uri.clone(),
+            subject: params.username.clone(),
+            scope: params.scope.clone(),
+            code_challenge: code_challenge.clone(),
+            nonce: params.nonce.clone(),
+            expires_at,
+        };
+        let mut grants = self.grants();
+        // code ที่ถูกขอแต่ไม่เคยถูกแลกจะค้างอยู่ตลอดไป - เก็บกวาดตัวที่หมดอายุทุกครั้งที่ออกใหม่
+        grants.codes.retain(|_, grant| grant.expires_at >= now);
+        grants.codes.insert(code.clone(), grant);
+        drop(grants);
+        Ok(code)
+    }
+
+    /// `POST /token` - `grant_type=authorization_code` หรือ `grant_type=refresh_token`
+    fn token(&self, request: &HttpRequest) -> Result<HttpResponse, OAuthError> {
+        let Query(params) = request
+            .body
+            .parse::<Query<TokenParams>>()
+            .map_err(|error| OAuthError::InvalidRequest(error.to_string()))?;
+        let client = self
+            .clients
+            .get(&params.client_id)
+            .filter(|client| self.client_secret_matches(client, &params.client_secret))
+            .ok_or(OAuthError::InvalidClient)?;
+
+        let tokens = match params.grant_type.as_str() {
+            "authorization_code" => self.exchange_code(client, &params)?,
+            "refresh_token" => self.refresh(client, &params)?,
+            other => return Err(OAuthError::UnsupportedGrantType(other.to_string())),
+        };
+        let body = serde_json::to_string(&tokens).unwrap_or_default();
+        Ok(HttpResponse::json(HttpStatus::Ok, &body).with_header("Cache-Control", "no-store"))
+    }
+
+    fn exchange_code(
+        &self,
+        client: &RegisteredClient,
+        params: &TokenParams,
+    ) -> Result<TokenSet, OAuthError> {
+        let code = params
+            .code
+            .as_deref()
+            .ok_or_else(|| OAuthError::InvalidRequest("code is required".to_string()))?;
+        // ลบก่อนตรวจ - code ที่ถูกลองแล้วหนึ่งครั้ง (ผ่านหรือไม่ก็ตาม) ใช้อีกไม่ได้
+        let grant = self.grants().codes.remove(code).ok_or_else(|| {
+            OAuthError::InvalidGrant(
+                "authorization code is invalid or was already used".to_string(),
+            )
+        })?;
+        if grant.client_id != client.client_id
+            || params.redirect_uri.as_deref() != Some(grant.redirect_uri.as_str())
+        {
+            return Err(OAuthError::InvalidGrant(
+                "authorization code was issued to another client or redirect_uri".to_string(),
+            ));
+        }
+        if self.clock.unix_timestamp() > grant.expires_at {
+            return Err(OAuthError::InvalidGrant(
+                "authorization code expired".to_string(),
+            ));
+        }
+        let verified = params.code_verifier.as_deref().is_some_and(|verifier| {
+            Pkce::is_valid_verifier(verifier)
+                && Pkce::challenge_for(verifier) == grant.code_challenge
+        });
+        if !verified {
+            return Err(OAuthError::InvalidGrant(
+                "PKCE verification failed".to_string(),
+            ));
+        }
+        self.issue_tokens(client, &grant.subject, &grant.scope, true, grant.nonce)
+    }
+
+    /// refresh token ถูกหมุน (rotation) ทุกครั้ง - ตัวเก่าใช้ซ้ำไม่ได้
+    /// และขอ scope ได้เท่าเดิมหรือแคบลงเท่านั้น
+    fn refresh(
+        &self,
+        client: &RegisteredClient,
+        params: &TokenParams,
+    ) -> Result<TokenSet, OAuthError> {
+        let refresh_token = params
+            .refresh_token
+            .as_deref()
+            .ok_or_else(|| OAuthError::InvalidRequest("refresh_token is required".to_string()))?;
+        let grant = self
+            .grants()
+            .refresh_tokens
+            .remove(refresh_token)
+            .filter(|grant| grant.client_id == client.client_id)
+            .ok_or_else(|| {
+                OAuthError::InvalidGrant("refresh token is invalid or was already used".to_string())
+            })?;
+        let scope = match params.scope.as_deref() {
+            Some(requested) => {
+                if let Some(extra) = requested.split_whitespace().find(|scope| {
+                    !grant
+                        .scope
+                        .split_whitespace()
+                        .any(|granted| granted == *scope)
+                }) {
+                    return Err(OAuthError::InvalidScope(extra.to_string()));
+                }
+                requested.to_string()
+            }
+            None => grant.scope,
+        };
+        self.issue_tokens(client, &grant.subject, &scope, false, None)
+    }
+
+    /// id token ออกเฉพาะตอนแลก code (`with_id_token`) และเมื่อมี scope `openid`
+    fn issue_tokens(
+        &self,
+        client: &RegisteredClient,
+        subject: &str,
+        scope: &str,
+        with_id_token: bool,
+        nonce: Option<String>,
+    ) -> Result<TokenSet, OAuthError> {
+        let mut rng = rand::thread_rng();
+        let now = self.clock.unix_timestamp();
+        let expires_in = self.access_token_ttl.as_secs();
+        let exp = Self::expires_at(now, self.access_token_ttl)?;
+        let access_claims = AccessClaims {
+            iss: self.issuer.clone(),
+            sub: subject.to_string(),
+            aud: self.audience.clone(),
+            client_id: client.client_id.clone(),
+            scope: scope.to_string(),
+            iat: now,
+            exp,
+            jti: random_token(&mut rng),
+        };
+
+        // id token เซ็นด้วย secret ของ client (HS256 ตาม OIDC Core) - client จึงตรวจเองได้
+        let id_token = (with_id_token && scope.split_whitespace().any(|scope| scope == "openid"))
+            .then(|| {
+                let id_claims = IdClaims {
+                    iss: self.issuer.clone(),
+                    sub: subject.to_string(),
+                    aud: client.client_id.clone(),
+                    email: self
+                        .users
+                        .get(subject)
+                        .map(|owner| owner.email.clone())
+                        .unwrap_or_default(),
+                    nonce,
+                    iat: now,
+                    exp,
+                };
+                encode_jwt(&id_claims, client.secret.as_bytes())
+            });
+
+        let refresh_token = random_token(&mut rng);
+        self.grants().refresh_tokens.insert(
+            refresh_token.clone(),
+            RefreshGrant {
+                client_id: client.client_id.clone(),
+                subject: subject.to_string(),
+                scope: scope.to_string(),
+            },
+        );
+        Ok(TokenSet {
+            access_token: encode_jwt(&access_claims, &self.signing_key),
+            token_type: "Bearer".to_string(),
+            expires_in,
+            refresh_token,
+            scope: scope.to_string(),
+            id_token,
+        })
+    }
+
+    /// `GET /userinfo` (OIDC) - ต้องใช้ access token ที่มี scope `openid`
+    fn userinfo(&self, request: &HttpRequest) -> Result<HttpResponse, OAuthError> {
+        let claims = self.validator().authorize(request, Some("openid"))?;
+        let email = self
+            .users
+            .get(&claims.sub)
+            .map(|owner| owner.email.as_str());
+        let body = serde_json::json!({ "sub": claims.sub, "email": email });
+        Ok(HttpResponse::json(HttpStatus::Ok, &body.to_string()))
+    }
+}
+
+/// ✅ ตรวจ access token ฝั่ง resource server - ลายเซ็น, `iss`, `aud` และ `exp`
+#[derive(Clone)]
+pub struct TokenValidator {
+    issuer: String,
+    audience: String,
+    key: Vec<u8>,
+    clock: Arc<dyn Clock>,
+}
+
+impl fmt::Debug for TokenValidator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // ไม่พิมพ์ key ลง log
+        f.debug_struct("TokenValidator")
+            .field("issuer", &self.issuer)
+            .field("audience", &self.audience)
+            .finish_non_exhaustive()
+    }
+}
+
+impl TokenValidator {
+    /// # Errors
+    ///
+    /// คืน `OAuthError::InvalidToken` ถ้าลายเซ็นผิด, ออกโดย issuer อื่น, ออกให้ API อื่น หรือหมดอายุ
+    pub fn validate(&self, token: &str) -> Result<AccessClaims, OAuthError> {
+        let claims: AccessClaims = decode_jwt(token, &self.key)?;
+        if claims.iss != self.issuer || claims.aud != self.audience {
+            return Err(OAuthError::InvalidToken(
+                "token was issued for another audience".to_string(),
+            ));
+        }
+        if self.clock.unix_timestamp() >= claims.exp {
+            return Err(OAuthError::InvalidToken("token expired".to_string()));
+        }
+        Ok(claims)
+    }
+
+    /// อ่าน `Authorization: Bearer <token>` แล้วตรวจ token และ scope (ถ้าระบุ)
+    ///
+    /// # Errors
+    ///
+    /// `InvalidToken` ถ้าไม่มี header หรือ token ใช้ไม่ได้ (401),
+    /// `InsufficientScope` ถ้าไม่มี `scope` ที่ต้องใช้ (403)
+    pub fn authorize(
+        &self,
+        request: &HttpRequest,
+        scope: Option<&str>,
+    ) -> Result<AccessClaims, OAuthError> {
+        let token = request
+            .headers
+            .get("Authorization")
+            .and_then(|header| header.strip_prefix("Bearer "))
+            .ok_or_else(|| OAuthError::InvalidToken("missing bearer token".to_string()))?;
+        let claims = self.validate(token.trim())?;
+        match scope {
+            Some(scope) if !claims.has_scope(scope) => {
+                Err(OAuthError::InsufficientScope(scope.to_string()))
+            }
+            _ => Ok(claims),
+        }
+    }
+}
+
+/// 🛡️ บังคับ scope ตาม method + prefix ของ path - request ที่ไม่ผ่านได้ 401/403 แทน
+///
+/// ใช้ `handle` ห่อ handler เพื่อให้ตรวจ *ก่อน* handler ทำงาน หรือใส่ใน `MiddlewareChain`
+/// (แบบหลังแทนที่ response ที่ได้มาแล้ว จึงเหมาะกับ handler ที่ไม่มีผลข้างเคียงเท่านั้น)
+#[derive(Debug, Clone)]
+pub struct ScopeMiddleware {
+    validator: TokenValidator,
+    /// `(method, path prefix, scope)` - `"*"` คือทุก method
+    rules: Vec<(String, String, String)>,
+}
+
+impl ScopeMiddleware {
+    #[must_use]
+    pub const fn new(validator: TokenValidator) -> Self {
+        Self {
+            validator,
+            rules: Vec::new(),
+        }
+    }
+
+    /// request ที่ตรง `method` และขึ้นต้นด้วย `path_prefix` ต้องมี `scope` (ตรงหลายกฎต้องผ่านทุกกฎ)
+    #[must_use]
+    pub fn require(mut self, method: &str, path_prefix: &str, scope: &str) -> Self {
+        self.rules.push((
+            method.to_string(),
+            path_prefix.to_string(),
+            scope.to_string(),
+        ));
+        self
+    }
+
+    /// claims ของ token ถ้า path ถูกป้องกัน, `None` ถ้า path นี้ไม่มีกฎ
+    ///
+    /// # Errors
+    ///
+    /// `InvalidToken` หรือ `InsufficientScope` จาก `TokenValidator::authorize`
+    pub fn check(&self, request: &HttpRequest) -> Result<Option<AccessClaims>, OAuthError> {
+        let path = request.path.split('?').next().unwrap_or_default();
+        let required: Vec<&str> = self
+            .rules
+            .iter()
+            .filter(|(method, prefix, _)| {
+                (method == "*" || *method == request.method) && path.starts_with(prefix.as_str())
+            })
+            .map(|(_, _, scope)| scope.as_str())
+            .collect();
+        if required.is_empty() {
+            return Ok(None);
+        }
+        let claims = self.validator.authorize(request, None)?;
+        required.iter().find(|scope| !claims.has_scope(scope)).map_or_else(
+            || Ok(Some(claims)),
+            |missing| Err(OAuthError::InsufficientScope((*missing).to_string())),
+        )
+    }
+
+    /// เรียก `handler` เฉพาะเมื่อผ่านกฎ - handler ได้ claims (ถ้า path ถูกป้องกัน) ไปใช้ต่อ
+    pub fn handle<F>(&self, request: &HttpRequest, handler: F) -> HttpResponse
+    where
+        F: FnOnce(&HttpRequest, Option<&AccessClaims>) -> HttpResponse,
+    {
+        match self.check(request) {
+            Ok(claims) => handler(request, claims.as_ref()),
+            Err(error) => error.to_response(),
+        }
+    }
+}
+
+impl Middleware for ScopeMiddleware {
+    fn process(&self, request: &HttpRequest, response: &HttpResponse) -> HttpResponse {
+        self.handle(request, |_, _| response.clone())
+    }
+}
+
+/// สิ่งที่ client ต้องจำไว้ระหว่างส่งผู้ใช้ไป authorize จนได้ code กลับมา
+#[derive(Debug, Clone)]
+pub struct PendingAuthorization {
+    pub state: String,
+    pub nonce: String,
+    pub pkce: Pkce,
+}
+
+/// 🧑‍💻 OAuth client (confidential client ที่ใช้ PKCE ด้วยตามคำแนะนำของ OAuth 2.1)
+#[derive(Clone)]
+pub struct OAuthClient {
+    client_id: String,
+    secret: String,
+    redirect_uri: String,
+    scope: String,
+}
+
+impl fmt::Debug for OAuthClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OAuthClient")
+            .field("client_id", &self.client_id)
+            .field("scope", &self.scope)
+            .finish_non_exhaustive()
+    }
+}
+
+impl OAuthClient {
+    #[must_use]
+    pub fn new(client_id: &str, secret: &str, redirect_uri: &str) -> Self {
+        Self {
+            client_id: client_id.to_string(),
+            secret: secret.to_string(),
+            redirect_uri: redirect_uri.to_string(),
+            scope: "openid".to_string(),
+        }
+    }
+
+    /// scope ที่ขอ คั่นด้วยช่องว่าง (ค่าเริ่มต้น `"openid"`)
+    #[must_use]
+    pub fn with_scope(mut self, scope: &str) -> Self {
+        self.scope = scope.to_string();
+        self
+    }
+
+    /// 1️⃣ สุ่ม `state`, `nonce` และ PKCE verifier ใหม่สำหรับการ login หนึ่งครั้ง
+    pub fn begin<R: Rng + ?Sized>(&self, rng: &mut R) -> PendingAuthorization {
+        PendingAuthorization {
+            state: random_token(rng),
+            nonce: random_token(rng),
+            pkce: Pkce::generate(rng),
+        }
+    }
+
+    /// 2️⃣ form login บนหน้า authorize ของ server (ส่งแค่ challenge ไม่ส่ง verifier)
+    #[must_use]
+    pub fn authorization_request(
+        &self,
+        pending: &PendingAuthorization,
+        username: &str,
+        password: &str,
+    ) -> HttpRequest {
+        let body = encode_query(&[
+            ("response_type", "code"),
+            ("client_id", &self.client_id),
+            ("redirect_uri", &self.redirect_uri),
+            ("scope", &self.scope),
+            ("state", &pending.state),
+            ("nonce", &pending.nonce),
+            ("code_challenge", &pending.pkce.challenge),
+            ("code_challenge_method", "S256"),
+            ("username", username),
+            ("password", password),
+        ]);
+        HttpRequest::new("POST", "/authorize")
+            .with_header("Content-Type", "application/x-www-form-urlencoded")
+            .with_body(&body)
+    }
+
+    /// 3️⃣ อ่าน redirect (`Location`) - ตรวจ `state` ก่อนเชื่ออะไรก็ตามใน URL
+    ///
+    /// # Errors
+    ///
+    /// `StateMismatch` ถ้า `state` ไม่ตรง, error ที่ server ส่งมาทาง redirect
+    /// หรือ `InvalidRequest` ถ้า response ไม่ใช่ redirect ที่มี `code`
+    pub fn callback(
+        &self,
+        pending: &PendingAuthorization,
+        response: &HttpResponse,
+    ) -> Result<String, OAuthError> {
+        if response.status != HttpStatus::Found {
+            return Err(OAuthError::from_response(response));
+        }
+        let location = response.headers.get("Location").map_or("", String::as_str);
+        let query = location.split_once('?').map_or("", |(_, query)| query);
+        let params: HashMap<String, String> = parse_query(query).into_iter().collect();
+        if params.get("state") != Some(&pending.state) {
+            return Err(OAuthError::StateMismatch);
+        }
+        if let Some(error) = params.get("error") {
+            let description = params.get("error_description").map_or("", String::as_str);
+            return Err(OAuthError::from_code(error, description));
+        }
+        params
+            .get("code")
+            .cloned()
+            .ok_or_else(|| OAuthError::InvalidRequest("redirect has no code".to_string()))
+    }
+
+    /// 4️⃣ แลก code เป็น token - ส่ง verifier ที่เก็บไว้ตั้งแต่ขั้นที่ 1
+    #[must_use]
+    pub fn token_request(&self, pending: &PendingAuthorization, code: &str) -> HttpRequest {
+        self.token_endpoint(&[
+            ("grant_type", "authorization_code"),
+            ("code", code),
+            ("redirect_uri", &self.redirect_uri),
+            ("code_verifier", &pending.pkce.verifier),
+        ])
+    }
+
+    /// 🔄 ขอ access token ใหม่ด้วย refresh token - `scope` ใส่เมื่ออยากได้สิทธิ์แคบลง
+    #[must_use]
+    pub fn refresh_request(&self, refresh_token: &str, scope: Option<&str>) -> HttpRequest {
+        let mut pairs = vec![
+            ("grant_type", "refresh_token"),
+            ("refresh_token", refresh_token),
+        ];
+        if let Some(scope) = scope {
+            pairs.push(("scope", scope));
+        }
+        self.token_endpoint(&pairs)
+    }
+
+    fn token_endpoint(&self, pairs: &[(&str, &str)]) -> HttpRequest {
+        let mut pairs = pairs.to_vec();
+        pairs.extend([
+            ("client_id", self.client_id.as_str()),
+            ("client_secret", self.secret.as_str()),
+        ]);
+        HttpRequest::new("POST", "/token")
+            .with_header("Content-Type", "application/x-www-form-urlencoded")
+            .with_body(&encode_query(&pairs))
+    }
+
+    /// 5️⃣ อ่าน `TokenSet` - ถ้ามี id token ตรวจลายเซ็น, `aud` และ `nonce` (เมื่อส่ง `pending` มา)
+    ///
+    /// # Errors
+    ///
+    /// error จาก server, `InvalidToken` ถ้า id token ใช้ไม่ได้ หรือ `NonceMismatch`
+    pub fn read_tokens(
+        &self,
+        response: &HttpResponse,
+        pending: Option<&PendingAuthorization>,
+    ) -> Result<TokenSet, OAuthError> {
+        if response.status != HttpStatus::Ok {
+            return Err(OAuthError::from_response(response));
+        }
+        let tokens: TokenSet = serde_json::from_str(&response.body).map_err(|error| {
+            OAuthError::InvalidRequest(format!("invalid token response: {error}"))
+        })?;
+        if let Some(id_token) = &tokens.id_token {
+            let claims = self.id_claims(id_token)?;
+            if pending.is_some_and(|pending| claims.nonce.as_deref() != Some(pending.nonce.as_str())) {
+                return Err(OAuthError::NonceMismatch);
+            }
+        }
+        Ok(tokens)
+    }
+
+    /// ตรวจ id token ด้วย secret ของ client เอง
+    ///
+    /// # Errors
+    ///
+    /// `InvalidToken` ถ้าลายเซ็นผิดหรือ `aud` ไม่ใช่ client นี้
+    pub fn id_claims(&self, id_token: &str) -> Result<IdClaims, OAuthError> {
+        let claims: IdClaims = decode_jwt(id_token, self.secret.as_bytes())?;
+        if claims.aud == self.client_id {
+            Ok(claims)
+        } else {
+            Err(OAuthError::InvalidToken(
+                "id token was issued to another client".to_string(),
+            ))
+        }
+    }
+
+    /// ▶️ ทั้ง flow ในครั้งเดียว: begin → authorize → callback → token
+    ///
+    /// # Errors
+    ///
+    /// error แรกที่เกิดในขั้นใดขั้นหนึ่ง
+    pub fn login<F>(&self, send: F, username: &str, password: &str) -> Result<TokenSet, OAuthError>
+    where
+        F: Fn(&HttpRequest) -> HttpResponse,
+    {
+        let pending = self.begin(&mut rand::thread_rng());
+        let code = self.callback(
+            &pending,
+            &send(&self.authorization_request(&pending, username, password)),
+        )?;
+        self.read_tokens(&send(&self.token_request(&pending, &code)), Some(&pending))
+    }
+}
+
+/// แบ่ง JWT ออกเป็น header/payload ที่อ่านได้ - สำหรับ demo เท่านั้น (ไม่ได้ตรวจลายเซ็น!)
+fn peek_jwt(token: &str) -> String {
+    token
+        .split('.')
+        .take(2)
+        .filter_map(|part| URL_SAFE_NO_PAD.decode(part).ok())
+        .map(|bytes| String::from_utf8_lossy(&bytes).into_owned())
+        .collect::<Vec<_>>()
+        .join(" . ")
+}
+
+/// 🎭 สาธิต authorization code flow ของ OAuth 2.0 + PKCE + OIDC บน `WebServer`
+pub fn demonstrate_oauth2() {
+    use crate::clock::MockClock;
+
+    println!("🎟️ OAuth2 + OpenID Connect - authorization code flow พร้อม PKCE");
+
+    let clock = MockClock::new();
+    let authorization_server = Arc::new(
+        AuthorizationServer::new("https://auth.workshop.dev", b"workshop-signing-key")
+            .with_client(
+                RegisteredClient::new(
+                    "task-app",
+                    "task-app-secret",
+                    "https://tasks.workshop.dev/callback",
+                )
+                .with_scopes(&["openid", "tasks:read", "tasks:write"]),
+            )
+            .with_user("alice", "password123", "alice@example.com")
+            .with_clock(Arc::new(clock.clone())),
+    );
+    let guard = Arc::new(
+        ScopeMiddleware::new(authorization_server.validator())
+            .require("GET", "/api/tasks", "tasks:read")
+            .require("DELETE", "/api/tasks", "tasks:write"),
+    );
+
+    let mut server = WebServer::new();
+    authorization_server.mount(&mut server);
+    let read_guard = Arc::clone(&guard);
+    server.route("GET /api/tasks", move |request| {
+        read_guard.handle(request, |_, claims| {
+            let user = claims.map_or("anonymous", |claims| claims.sub.as_str());
+            HttpResponse::json(
+                HttpStatus::Ok,
+                &format!("{{\"owner\": \"{user}\", \"tasks\": [\"learn OAuth2\"]}}"),
+            )
+        })
+    });
+    let write_guard = Arc::clone(&guard);
+    server.route("DELETE /api/tasks/*", move |request| {
+        write_guard.handle(request, |_, _| HttpResponse::new(HttpStatus::NoContent))
+    });
+    let send = |request: &HttpRequest| server.handle_request(request);
+
+    // 1️⃣ - 3️⃣ client → authorize → redirect กลับมาพร้อม code
+    let client = OAuthClient::new(
+        "task-app",
+        "task-app-secret",
+        "https://tasks.workshop.dev/callback",
+    )
+    .with_scope("openid tasks:read");
+    let pending = client.begin(&mut rand::thread_rng());
+    println!(
+        "\n1️⃣ PKCE verifier (เก็บไว้ที่ client): {}",
+        pending.pkce.verifier
+    );
+    println!(
+        "   code_challenge (ส่งไป server): {}",
+        pending.pkce.challenge
+    );
+
+    let redirect = send(&client.authorization_request(&pending, "alice", "password123"));
+    println!(
+        "2️⃣ POST /authorize → {} Location: {}",
+        redirect.status.as_str(),
+        redirect.headers.get("Location").map_or("-", String::as_str)
+    );
+    let code = match client.callback(&pending, &redirect) {
+        Ok(code) => code,
+        Err(error) => {
+            println!("❌ {error}");
+            return;
+        }
+    };
+    println!("3️⃣ state ตรงกัน ✅ ได้ code: {code}");
+
+    // 4️⃣ แลก code เป็น token
+    let tokens = match client.read_tokens(
+        &send(&client.token_request(&pending, &code)),
+        Some(&pending),
+    ) {
+        Ok(tokens) => tokens,
+        Err(error) => {
+            println!("❌ {error}");
+            return;
+        }
+    };
+    println!(
+        "4️⃣ POST /token → scope \"{}\" อายุ {} วินาที",
+        tokens.scope, tokens.expires_in
+    );
+    println!("   access token: {}", peek_jwt(&tokens.access_token));
+    if let Some(id_claims) = tokens
+        .id_token
+        .as_deref()
+        .and_then(|token| client.id_claims(token).ok())
+    {
+        println!(
+            "   id token: sub={} email={} (nonce ตรงกัน ✅)",
+            id_claims.sub, id_claims.email
+        );
+    }
+
+    // 5️⃣ เรียก API ด้วย bearer token
+    let bearer = |token: &str, method: &str, path: &str| {
+        HttpRequest::new(method, path).with_header("Authorization", &format!("Bearer {token}"))
+    };
+    println!("\n5️⃣ เรียก API:");
+    for request in [
+        bearer(&tokens.access_token, "GET", "/api/tasks"),
+        bearer(&tokens.access_token, "DELETE", "/api/tasks/1"),
+        HttpRequest::new("GET", "/api/tasks"),
+        bearer(&tokens.access_token, "GET", "/userinfo"),
+    ] {
+        let response = send(&request);
+        println!(
+            "   {} {} → {} {}",
+            request.method,
+            request.path,
+            response.status.as_str(),
+            response.body
+        );
+    }
+
+    // 🕵️ การโจมตีที่ PKCE/one-time code กันได้
+    println!("\n🕵️ ผู้โจมตีดัก code ได้แต่ไม่มี verifier:");
+    let stolen = client.begin(&mut rand::thread_rng());
+    let redirect = send(&client.authorization_request(&pending, "alice", "password123"));
+    if let Ok(code) = client.callback(&pending, &redirect) {
+        let response = send(&client.token_request(&stolen, &code));
+        println!("   แลกด้วย verifier ปลอม → {}", response.body);
+        let response = send(&client.token_request(&pending, &code));
+        println!("   เจ้าของจริงใช้ code เดิมซ้ำ → {}", response.body);
+    }
+
+    // 🔄 token หมดอายุ → refresh (rotation)
+    clock.advance(Duration::from_secs(tokens.expires_in));
+    let expired = send(&bearer(&tokens.access_token, "GET", "/api/tasks"));
+    println!(
+        "\n⏰ ผ่านไป {} วินาที → {} {}",
+        tokens.expires_in,
+        expired.status.as_str(),
+        expired
+            .headers
+            .get("WWW-Authenticate")
+            .map_or("", String::as_str)
+    );
+    match client.read_tokens(
+        &send(&client.refresh_request(&tokens.refresh_token, Some("tasks:read"))),
+        None,
+    ) {
+        Ok(refreshed) => {
+            let response = send(&bearer(&refreshed.access_token, "GET", "/api/tasks"));
+            println!(
+                "🔄 refresh (scope แคบลงเหลือ \"{}\") → {}",
+                refreshed.scope,
+                response.status.as_str()
+            );
+        }
+        Err(error) => println!("❌ {error}"),
+    }
+    let reused = send(&client.refresh_request(&tokens.refresh_token, None));
+    println!(
+        "♻️ ใช้ refresh token เก่าซ้ำ → {} {}",
+        reused.status.as_str(),
+        reused.body
+    );
+}
+
 /// 🎭 สาธิตระบบยืนยันตัวตนและการอนุญาต - เวิร์กช็อปความปลอดภัยดิจิทัล
 pub fn demonstrate_authentication() {
     println!("🏛️ === เวิร์กช็อประบบรักษาความปลอดภัยดิจิทัล === 🔐");
@@ -939,11 +2264,15 @@ pub fn demonstrate_authentication() {
     
     println!("\n🎉 === เวิร์กช็อประบบรักษาความปลอดภัยเสร็จสิ้น! === 🏆");
     println!("🎯 สาธิตระบบยืนยันตัวตนและการอนุญาตครบถ้วนแล้ว!");
+    
+    println!();
+    demonstrate_oauth2();
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::web_development::middleware::MiddlewareChain;
     
     #[test]
     fn test_user_creation() {
@@ -995,4 +2324,267 @@ mod tests {
         assert!(!token.is_valid()); // Should be invalid after use
         assert!(!token.use_token()); // Should fail second use
     }
-}
\ No newline at end of file
+
+    const REDIRECT_URI: &str = "https://tasks.workshop.dev/callback";
+
+    fn oauth_fixture(clock: &crate::clock::MockClock) -> (WebServer, OAuthClient) {
+        let authorization_server = Arc::new(
+            AuthorizationServer::new("https://auth.test", b"signing-key")
+                .with_client(
+                    RegisteredClient::new("task-app", "s3cret", REDIRECT_URI)
+                        .with_scopes(&["openid", "tasks:read", "tasks:write"]),
+                )
+                .with_user("alice", "password123", "alice@example.com")
+                .with_clock(Arc::new(clock.clone())),
+        );
+        let guard = Arc::new(
+            ScopeMiddleware::new(authorization_server.validator())
+                .require("*", "/api/tasks", "tasks:read")
+                .require("DELETE", "/api/tasks", "tasks:write"),
+        );
+        let mut server = WebServer::new();
+        authorization_server.mount(&mut server);
+        for route in ["GET /api/tasks", "DELETE /api/tasks/*"] {
+            let guard = Arc::clone(&guard);
+            server.route(route, move |request| {
+                guard.handle(request, |_, claims| {
+                    HttpResponse::new(HttpStatus::Ok)
+                        .with_body(claims.map_or("", |claims| claims.sub.as_str()))
+                })
+            });
+        }
+        let client = OAuthClient::new("task-app", "s3cret", REDIRECT_URI)
+            .with_scope("openid tasks:read");
+        (server, client)
+    }
+
+    fn bearer(method: &str, path: &str, token: &str) -> HttpRequest {
+        HttpRequest::new(method, path).with_header("Authorization", &format!("Bearer {token}"))
+    }
+
+    #[test]
+    fn test_pkce_and_jwt_primitives() {
+        // challenge = base64url(SHA-256(verifier)) ไม่มี padding
+        let pkce = Pkce::from_verifier("ferris-the-crab-loves-pkce.0123456789~abcdef");
+        assert_eq!(pkce.challenge, "PgMhF7Ldtkxqjjtw2J8kejEi0BHWE9obIAOOeZGFl3Y");
+        assert!(Pkce::is_valid_verifier(&pkce.verifier));
+        assert!(!Pkce::is_valid_verifier("too-short"));
+        assert!(Pkce::is_valid_verifier(&Pkce::generate(&mut rand::thread_rng()).verifier));
+
+        let claims = serde_json::json!({ "sub": "alice", "scope": "tasks:read" });
+        let token = encode_jwt(&claims, b"key");
+        assert_eq!(decode_jwt::<serde_json::Value>(&token, b"key"), Ok(claims));
+        assert!(decode_jwt::<serde_json::Value>(&token, b"other-key").is_err());
+
+        // แก้ payload (ยกระดับ scope) แล้วลายเซ็นเดิมใช้ไม่ได้
+        let parts: Vec<&str> = token.split('.').collect();
+        let forged_payload = URL_SAFE_NO_PAD.encode(br#"{"sub":"alice","scope":"admin"}"#);
+        let forged = format!("{}.{forged_payload}.{}", parts[0], parts[2]);
+        assert!(decode_jwt::<serde_json::Value>(&forged, b"key").is_err());
+        // alg "none" ถูกปฏิเสธเสมอ
+        let none_header = URL_SAFE_NO_PAD.encode(br#"{"alg":"none"}"#);
+        let unsigned = format!("{none_header}.{}.", parts[1]);
+        assert_eq!(
+            decode_jwt::<serde_json::Value>(&unsigned, b"key"),
+            Err(OAuthError::InvalidToken("unsupported signing algorithm".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_oauth2_code_flow_with_pkce_and_id_token() {
+        let clock = crate::clock::MockClock::new();
+        let (server, client) = oauth_fixture(&clock);
+        let send = |request: &HttpRequest| server.handle_request(request);
+
+        let pending = client.begin(&mut rand::thread_rng());
+        let redirect = send(&client.authorization_request(&pending, "alice", "password123"));
+        assert_eq!(redirect.status, HttpStatus::Found);
+        assert!(redirect.headers["Location"].starts_with(&format!("{REDIRECT_URI}?code=")));
+        let code = client.callback(&pending, &redirect).unwrap();
+
+        let tokens = client
+            .read_tokens(&send(&client.token_request(&pending, &code)), Some(&pending))
+            .unwrap();
+        assert_eq!(tokens.token_type, "Bearer");
+        assert_eq!(tokens.scope, "openid tasks:read");
+        let id_claims = client.id_claims(tokens.id_token.as_deref().unwrap()).unwrap();
+        assert_eq!(id_claims.sub, "alice");
+        assert_eq!(id_claims.email, "alice@example.com");
+        assert_eq!(id_claims.nonce.as_deref(), Some(pending.nonce.as_str()));
+
+        let api = send(&bearer("GET", "/api/tasks", &tokens.access_token));
+        assert_eq!((api.status, api.body.as_str()), (HttpStatus::Ok, "alice"));
+        let userinfo = send(&bearer("GET", "/userinfo", &tokens.access_token));
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&userinfo.body).unwrap(),
+            serde_json::json!({ "sub": "alice", "email": "alice@example.com" })
+        );
+
+        // id token จาก login ครั้งอื่นถูกจับได้ด้วย nonce
+        let other = client.begin(&mut rand::thread_rng());
+        let redirect = send(&client.authorization_request(&other, "alice", "password123"));
+        let code = client.callback(&other, &redirect).unwrap();
+        let response = send(&client.token_request(&other, &code));
+        assert_eq!(
+            client.read_tokens(&response, Some(&pending)),
+            Err(OAuthError::NonceMismatch)
+        );
+    }
+
+    #[test]
+    fn test_authorization_code_is_single_use_and_bound_to_verifier() {
+        let clock = crate::clock::MockClock::new();
+        let (server, client) = oauth_fixture(&clock);
+        let send = |request: &HttpRequest| server.handle_request(request);
+        let authorize = |pending: &PendingAuthorization| {
+            let redirect = send(&client.authorization_request(pending, "alice", "password123"));
+            client.callback(pending, &redirect).unwrap()
+        };
+        let pending = client.begin(&mut rand::thread_rng());
+        let attacker = client.begin(&mut rand::thread_rng());
+
+        // code ที่ถูกดักไปใช้กับ verifier อื่นไม่ได้ และถูกเผาทิ้งทันที
+        let code = authorize(&pending);
+        let stolen = send(&client.token_request(&attacker, &code));
+        assert_eq!(
+            client.read_tokens(&stolen, None),
+            Err(OAuthError::InvalidGrant("PKCE verification failed".to_string()))
+        );
+        let replay = send(&client.token_request(&pending, &code));
+        assert_eq!(replay.status, HttpStatus::BadRequest);
+        assert!(replay.body.contains("already used"));
+
+        // code หมดอายุใน 60 วินาที
+        let code = authorize(&pending);
+        clock.advance(Duration::from_secs(61));
+        let late = send(&client.token_request(&pending, &code));
+        assert_eq!(
+            client.read_tokens(&late, None),
+            Err(OAuthError::InvalidGrant("authorization code expired".to_string()))
+        );
+
+        // client secret ผิด → 401 invalid_client
+        let code = authorize(&pending);
+        let impostor = OAuthClient::new("task-app", "guess", REDIRECT_URI);
+        let response = send(&impostor.token_request(&pending, &code));
+        assert_eq!(response.status, HttpStatus::Unauthorized);
+    }
+
+    #[test]
+    fn test_unused_codes_are_purged_and_lifetimes_are_checked() {
+        let clock = crate::clock::MockClock::new();
+        let server = AuthorizationServer::new("https://auth.test", b"signing-key")
+            .with_client(
+                RegisteredClient::new("task-app", "s3cret", REDIRECT_URI)
+                    .with_scopes(&["openid", "tasks:read", "tasks:write"]),
+            )
+            .with_user("alice", "password123", "alice@example.com")
+            .with_clock(Arc::new(clock.clone()))
+            .with_access_token_ttl(Duration::MAX);
+        let client = OAuthClient::new("task-app", "s3cret", REDIRECT_URI);
+        let pending = client.begin(&mut rand::thread_rng());
+        let authorize = || {
+            let redirect =
+                server.handle(&client.authorization_request(&pending, "alice", "password123"));
+            client.callback(&pending, &redirect).unwrap()
+        };
+
+        // code ที่ไม่มีใครมาแลกถูกเก็บกวาดเมื่อหมดอายุ
+        for _ in 0..5 {
+            authorize();
+        }
+        clock.advance(Duration::from_secs(61));
+        let code = authorize();
+        assert_eq!(server.grants().codes.len(), 1);
+
+        // ttl ที่ล้นนาฬิกาเป็น server_error ไม่ใช่ token ที่หมดอายุไปแล้ว
+        let response = server.handle(&client.token_request(&pending, &code));
+        assert_eq!(response.status, HttpStatus::InternalServerError);
+        assert!(matches!(
+            client.read_tokens(&response, None),
+            Err(OAuthError::ServerError(_))
+        ));
+    }
+
+    #[test]
+    fn test_authorize_errors_redirect_only_to_registered_uri() {
+        let clock = crate::clock::MockClock::new();
+        let (server, client) = oauth_fixture(&clock);
+        let send = |request: &HttpRequest| server.handle_request(request);
+        let pending = client.begin(&mut rand::thread_rng());
+
+        // redirect_uri ที่ไม่ได้ลงทะเบียน → ตอบ error ตรงๆ ห้าม redirect
+        let phishing = OAuthClient::new("task-app", "s3cret", "https://evil.example/callback");
+        let response = send(&phishing.authorization_request(&pending, "alice", "password123"));
+        assert_eq!(response.status, HttpStatus::Unauthorized);
+        assert!(!response.headers.contains_key("Location"));
+
+        let response = send(&client.authorization_request(&pending, "alice", "wrong"));
+        assert_eq!(client.callback(&pending, &response), Err(OAuthError::AccessDenied));
+
+        let greedy = client.clone().with_scope("openid admin");
+        let response = send(&greedy.authorization_request(&pending, "alice", "password123"));
+        assert_eq!(
+            greedy.callback(&pending, &response),
+            Err(OAuthError::InvalidScope("scope \"admin\" is not allowed for this client".to_string()))
+        );
+
+        // redirect ที่ state ไม่ตรง (CSRF) ถูกปฏิเสธก่อนอ่าน code
+        let response = send(&client.authorization_request(&pending, "alice", "password123"));
+        let other = client.begin(&mut rand::thread_rng());
+        assert_eq!(client.callback(&other, &response), Err(OAuthError::StateMismatch));
+
+        let mut without_pkce = client.authorization_request(&pending, "alice", "password123");
+        without_pkce.body = without_pkce.body.replace("code_challenge", "ignored");
+        let response = send(&without_pkce);
+        assert!(matches!(client.callback(&pending, &response), Err(OAuthError::InvalidRequest(_))));
+    }
+
+    #[test]
+    fn test_scope_middleware_expiry_and_refresh_rotation() {
+        let clock = crate::clock::MockClock::new();
+        let (server, client) = oauth_fixture(&clock);
+        let send = |request: &HttpRequest| server.handle_request(request);
+        let tokens = client.login(send, "alice", "password123").unwrap();
+
+        assert_eq!(send(&HttpRequest::new("GET", "/api/tasks")).status, HttpStatus::Unauthorized);
+        let delete = send(&bearer("DELETE", "/api/tasks/1", &tokens.access_token));
+        assert_eq!(delete.status, HttpStatus::Forbidden);
+        assert_eq!(
+            delete.headers["WWW-Authenticate"],
+            "Bearer error=\"insufficient_scope\", scope=\"tasks:write\""
+        );
+
+        clock.advance(Duration::from_secs(tokens.expires_in));
+        let expired = send(&bearer("GET", "/api/tasks", &tokens.access_token));
+        assert_eq!(expired.status, HttpStatus::Unauthorized);
+        assert!(expired.body.contains("token expired"));
+
+        // refresh ขอ scope แคบลงได้ แต่ขยายไม่ได้
+        let widen = send(&client.refresh_request(&tokens.refresh_token, Some("tasks:write")));
+        assert!(matches!(client.read_tokens(&widen, None), Err(OAuthError::InvalidScope(_))));
+        let tokens = client.login(send, "alice", "password123").unwrap();
+        let refreshed = client
+            .read_tokens(&send(&client.refresh_request(&tokens.refresh_token, Some("tasks:read"))), None)
+            .unwrap();
+        assert_eq!(refreshed.scope, "tasks:read");
+        assert_eq!(refreshed.id_token, None);
+        assert_eq!(send(&bearer("GET", "/api/tasks", &refreshed.access_token)).status, HttpStatus::Ok);
+        // userinfo ต้องใช้ openid ซึ่งถูกตัดออกไปแล้ว
+        assert_eq!(send(&bearer("GET", "/userinfo", &refreshed.access_token)).status, HttpStatus::Forbidden);
+
+        // refresh token ถูกหมุน - ตัวเก่าใช้ซ้ำไม่ได้
+        let reused = send(&client.refresh_request(&tokens.refresh_token, None));
+        assert!(matches!(client.read_tokens(&reused, None), Err(OAuthError::InvalidGrant(_))));
+
+        // ScopeMiddleware ใน MiddlewareChain ก็ตรวจแบบเดียวกัน
+        let clock = crate::clock::MockClock::new();
+        let other_server = AuthorizationServer::new("https://other.test", b"signing-key").with_clock(Arc::new(clock));
+        let chain = MiddlewareChain::new()
+            .add(ScopeMiddleware::new(other_server.validator()).require("*", "/api", "tasks:read"));
+        let foreign = chain.process(&bearer("GET", "/api/tasks", &refreshed.access_token), HttpResponse::new(HttpStatus::Ok));
+        assert_eq!(foreign.status, HttpStatus::Unauthorized, "token ของ issuer อื่นต้องไม่ผ่าน");
+        let public = chain.process(&HttpRequest::new("GET", "/health"), HttpResponse::new(HttpStatus::Ok));
+        assert_eq!(public.status, HttpStatus::Ok);
+    }
+}
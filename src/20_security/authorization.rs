@@ -0,0 +1,722 @@
+//! 🛂 Authorization - ใครทำอะไรกับอะไรได้บ้าง (RBAC + ABAC)
+//!
+//! authentication ตอบว่า "คุณคือใคร" ส่วน authorization ตอบว่า "คุณทำสิ่งนี้ได้ไหม"
+//!
+//! - 👑 RBAC: ผู้ใช้มี role, role มี grant แบบ `resource:action` และสืบทอด role อื่นได้
+//!   (`admin` → `editor` → `viewer`)
+//! - 🏷️ ABAC: `AttributeRule` ดู attribute ของผู้ใช้/resource เพื่ออนุญาตหรือห้ามเพิ่มเติม
+//!   เช่น "แก้ข้อมูลของตัวเองได้" หรือ "บัญชีที่ถูกระงับทำอะไรไม่ได้เลย"
+//! - 🚫 deny-by-default: ไม่มีอะไรอนุญาต = ห้าม และ rule ที่ห้ามชนะทุก grant
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use crate::web_development::HttpRequest;
+use crate::web_development::rest_api::ApiRouter;
+
+/// "ทุกอย่าง" ใน grant - `Grant::new("*", "read")` อ่านได้ทุก resource
+pub const WILDCARD: &str = "*";
+
+/// ✅ สิทธิ์หนึ่งข้อ: ทำ `action` กับ resource ชนิด `resource` ได้
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grant {
+    pub resource: String,
+    pub action: String,
+}
+
+impl Grant {
+    #[must_use]
+    pub fn new(resource: &str, action: &str) -> Self {
+        Self {
+            resource: resource.to_string(),
+            action: action.to_string(),
+        }
+    }
+
+    #[must_use]
+    pub fn matches(&self, action: &str, resource: &str) -> bool {
+        (self.resource == WILDCARD || self.resource == resource)
+            && (self.action == WILDCARD || self.action == action)
+    }
+}
+
+impl fmt::Display for Grant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.resource, self.action)
+    }
+}
+
+/// 👑 role หนึ่งตัว - grant ของตัวเอง + role ที่สืบทอดมา
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoleDefinition {
+    pub name: String,
+    pub grants: Vec<Grant>,
+    pub parents: Vec<String>,
+}
+
+impl RoleDefinition {
+    #[must_use]
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            grants: Vec::new(),
+            parents: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn allow(mut self, resource: &str, action: &str) -> Self {
+        self.grants.push(Grant::new(resource, action));
+        self
+    }
+
+    /// ได้ทุก grant ของ `parent` (และของ parent ของมันต่อไปเรื่อยๆ)
+    #[must_use]
+    pub fn inherits(mut self, parent: &str) -> Self {
+        self.parents.push(parent.to_string());
+        self
+    }
+}
+
+/// 🧑 ผู้ขอสิทธิ์ - id, role และ attribute อื่นๆ (เช่น แผนก, สถานะบัญชี)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Subject {
+    pub id: String,
+    pub roles: Vec<String>,
+    pub attributes: HashMap<String, String>,
+}
+
+impl Subject {
+    #[must_use]
+    pub fn new(id: &str) -> Self {
+        Self {
+            id: id.to_string(),
+            ..Self::default()
+        }
+    }
+
+    #[must_use]
+    pub fn with_role(mut self, role: &str) -> Self {
+        self.roles.push(role.to_string());
+        self
+    }
+
+    #[must_use]
+    pub fn with_attribute(mut self, key: &str, value: &str) -> Self {
+        self.attributes.insert(key.to_string(), value.to_string());
+        self
+    }
+
+    #[must_use]
+    pub fn attribute(&self, key: &str) -> Option<&str> {
+        self.attributes.get(key).map(String::as_str)
+    }
+}
+
+/// 📦 สิ่งที่ถูกกระทำ - ชนิด (`users`), id และ attribute (เช่น `owner_id`)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Resource {
+    pub kind: String,
+    pub id: Option<String>,
+    pub attributes: HashMap<String, String>,
+}
+
+impl Resource {
+    #[must_use]
+    pub fn new(kind: &str) -> Self {
+        Self {
+            kind: kind.to_string(),
+            ..Self::default()
+        }
+    }
+
+    #[must_use]
+    pub fn with_id(mut self, id: &str) -> Self {
+        self.id = Some(id.to_string());
+        self
+    }
+
+    #[must_use]
+    pub fn with_attribute(mut self, key: &str, value: &str) -> Self {
+        self.attributes.insert(key.to_string(), value.to_string());
+        self
+    }
+
+    #[must_use]
+    pub fn attribute(&self, key: &str) -> Option<&str> {
+        self.attributes.get(key).map(String::as_str)
+    }
+}
+
+/// ผลของ `AttributeRule` หนึ่งตัว
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Effect {
+    Allow,
+    Deny,
+}
+
+/// 🏷️ จุดต่อขยายแบบ ABAC - คืน `None` เมื่อ rule นี้ไม่เกี่ยวกับ request
+///
+/// closure `Fn(&Subject, &str, &Resource) -> Option<Effect>` เป็น rule ได้ทันที
+pub trait AttributeRule: Send + Sync {
+    fn evaluate(&self, subject: &Subject, action: &str, resource: &Resource) -> Option<Effect>;
+}
+
+impl<F> AttributeRule for F
+where
+    F: Fn(&Subject, &str, &Resource) -> Option<Effect> + Send + Sync,
+{
+    fn evaluate(&self, subject: &Subject, action: &str, resource: &Resource) -> Option<Effect> {
+        self(subject, action, resource)
+    }
+}
+
+/// 🏠 เจ้าของ resource (`owner_id` == `subject.id`) ทำ `actions` ได้ แม้ role จะไม่ให้
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OwnerRule {
+    actions: Vec<String>,
+}
+
+impl OwnerRule {
+    #[must_use]
+    pub fn new(actions: &[&str]) -> Self {
+        Self {
+            actions: actions.iter().map(ToString::to_string).collect(),
+        }
+    }
+}
+
+impl AttributeRule for OwnerRule {
+    fn evaluate(&self, subject: &Subject, action: &str, resource: &Resource) -> Option<Effect> {
+        let is_owner = resource.attribute("owner_id") == Some(subject.id.as_str());
+        (is_owner && self.actions.iter().any(|allowed| allowed == action)).then_some(Effect::Allow)
+    }
+}
+
+/// ⚖️ คำตัดสินพร้อมเหตุผล - เหตุผลไว้ใส่ log/audit และ response 403
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Decision {
+    pub allowed: bool,
+    pub reason: String,
+}
+
+impl Decision {
+    const fn allow(reason: String) -> Self {
+        Self {
+            allowed: true,
+            reason,
+        }
+    }
+
+    const fn deny(reason: String) -> Self {
+        Self {
+            allowed: false,
+            reason,
+        }
+    }
+}
+
+impl fmt::Display for Decision {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let verdict = if self.allowed { "allow" } else { "deny" };
+        write!(f, "{verdict}: {}", self.reason)
+    }
+}
+
+/// ❌ policy ที่ตั้งค่าผิด - ตรวจด้วย `Policy::validate` ตอนเริ่มระบบ
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PolicyError {
+    /// role สืบทอดจาก role ที่ไม่มีอยู่
+    UnknownParent { role: String, parent: String },
+    /// การสืบทอดวนกลับมาที่ตัวเอง เช่น `a → b → a`
+    InheritanceCycle(Vec<String>),
+}
+
+impl fmt::Display for PolicyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownParent { role, parent } => {
+                write!(f, "role \"{role}\" inherits unknown role \"{parent}\"")
+            }
+            Self::InheritanceCycle(path) => {
+                write!(f, "role inheritance cycle: {}", path.join(" -> "))
+            }
+        }
+    }
+}
+
+impl std::error::Error for PolicyError {}
+
+/// 📜 policy รวม role และ rule - `can(subject, action, resource)` คือคำถามเดียวที่ระบบอื่นต้องรู้
+#[derive(Default)]
+pub struct Policy {
+    roles: HashMap<String, RoleDefinition>,
+    rules: Vec<(String, Box<dyn AttributeRule>)>,
+}
+
+impl fmt::Debug for Policy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rules: Vec<&str> = self.rules.iter().map(|(name, _)| name.as_str()).collect();
+        f.debug_struct("Policy")
+            .field("roles", &self.roles)
+            .field("rules", &rules)
+            .finish()
+    }
+}
+
+impl Policy {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn with_role(mut self, role: RoleDefinition) -> Self {
+        self.roles.insert(role.name.clone(), role);
+        self
+    }
+
+    /// เพิ่ม ABAC rule - `name` ปรากฏในเหตุผลของ `Decision`
+    #[must_use]
+    pub fn with_rule<R: AttributeRule + 'static>(mut self, name: &str, rule: R) -> Self {
+        self.rules.push((name.to_string(), Box::new(rule)));
+        self
+    }
+
+    /// ตรวจว่าทุก parent มีอยู่จริงและไม่มีการสืบทอดวนลูป
+    ///
+    /// # Errors
+    ///
+    /// คืน `PolicyError` ตัวแรกที่เจอ (ไล่ role ตามลำดับชื่อเพื่อให้ผลคงที่)
+    pub fn validate(&self) -> Result<(), PolicyError> {
+        let mut names: Vec<&String> = self.roles.keys().collect();
+        names.sort();
+        for name in names {
+            let mut path = vec![name.clone()];
+            self.check_ancestors(name, &mut path)?;
+        }
+        Ok(())
+    }
+
+    fn check_ancestors(&self, role: &str, path: &mut Vec<String>) -> Result<(), PolicyError> {
+        let Some(definition) = self.roles.get(role) else {
+            return Ok(());
+        };
+        for parent in &definition.parents {
+            if !self.roles.contains_key(parent) {
+                return Err(PolicyError::UnknownParent {
+                    role: role.to_string(),
+                    parent: parent.clone(),
+                });
+            }
+            path.push(parent.clone());
+            if path[..path.len() - 1].contains(parent) {
+                return Err(PolicyError::InheritanceCycle(path.clone()));
+            }
+            self.check_ancestors(parent, path)?;
+            path.pop();
+        }
+        Ok(())
+    }
+
+    /// role นี้และทุก role ที่มันสืบทอด - กันลูปด้วย `visited` แม้ policy จะยังไม่ผ่าน `validate`
+    fn role_closure<'a>(&'a self, role: &'a str, visited: &mut HashSet<&'a str>) {
+        if !visited.insert(role) {
+            return;
+        }
+        if let Some(definition) = self.roles.get(role) {
+            for parent in &definition.parents {
+                self.role_closure(parent, visited);
+            }
+        }
+    }
+
+    /// grant ทั้งหมดที่ role นี้ได้ รวมที่สืบทอดมา (เรียงและไม่ซ้ำ)
+    #[must_use]
+    pub fn effective_grants(&self, role: &str) -> Vec<Grant> {
+        let mut roles = HashSet::new();
+        self.role_closure(role, &mut roles);
+        let mut grants: Vec<Grant> = roles
+            .iter()
+            .filter_map(|role| self.roles.get(*role))
+            .flat_map(|definition| definition.grants.iter().cloned())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        grants.sort_by_key(ToString::to_string);
+        grants
+    }
+
+    /// ⚖️ ตัดสินตามลำดับ: rule ที่ห้าม → grant ของ role → rule ที่อนุญาต → ห้าม (default)
+    #[must_use]
+    pub fn evaluate(&self, subject: &Subject, action: &str, resource: &Resource) -> Decision {
+        let effects: Vec<(&str, Effect)> = self
+            .rules
+            .iter()
+            .filter_map(|(name, rule)| {
+                rule.evaluate(subject, action, resource)
+                    .map(|effect| (name.as_str(), effect))
+            })
+            .collect();
+        if let Some((name, _)) = effects.iter().find(|(_, effect)| *effect == Effect::Deny) {
+            return Decision::deny(format!("denied by rule \"{name}\""));
+        }
+
+        for role in &subject.roles {
+            let mut roles = HashSet::new();
+            self.role_closure(role, &mut roles);
+            let granted = roles
+                .iter()
+                .filter_map(|role| self.roles.get(*role))
+                .find_map(|definition| {
+                    definition
+                        .grants
+                        .iter()
+                        .find(|grant| grant.matches(action, &resource.kind))
+                        .map(|grant| (definition.name.as_str(), grant))
+                });
+            if let Some((via, grant)) = granted {
+                let inherited = if via == role {
+                    String::new()
+                } else {
+                    format!(" via \"{via}\"")
+                };
+                return Decision::allow(format!("role \"{role}\"{inherited} grants {grant}"));
+            }
+        }
+
+        if let Some((name, _)) = effects.first() {
+            return Decision::allow(format!("allowed by rule \"{name}\""));
+        }
+        Decision::deny(format!(
+            "no role or rule allows {}:{action} for \"{}\"",
+            resource.kind, subject.id
+        ))
+    }
+
+    #[must_use]
+    pub fn can(&self, subject: &Subject, action: &str, resource: &Resource) -> bool {
+        self.evaluate(subject, action, resource).allowed
+    }
+}
+
+/// 📜 policy ของ workshop API: `viewer` → `editor` → `admin`, เจ้าของแก้ข้อมูลตัวเองได้
+/// และบัญชีที่ `status=suspended` ถูกห้ามทุกอย่าง
+#[must_use]
+pub fn workshop_policy() -> Policy {
+    Policy::new()
+        .with_role(
+            RoleDefinition::new("viewer")
+                .allow("users", "read")
+                .allow("graphql", "query"),
+        )
+        .with_role(
+            RoleDefinition::new("editor")
+                .inherits("viewer")
+                .allow("users", "create")
+                .allow("users", "update"),
+        )
+        .with_role(
+            RoleDefinition::new("admin")
+                .inherits("editor")
+                .allow(WILDCARD, WILDCARD),
+        )
+        .with_rule("owner", OwnerRule::new(&["read", "update"]))
+        .with_rule("suspended", |subject: &Subject, _: &str, _: &Resource| {
+            (subject.attribute("status") == Some("suspended")).then_some(Effect::Deny)
+        })
+}
+
+/// 🎭 สาธิต role, การสืบทอด, rule แบบ attribute และ deny-by-default
+pub fn demonstrate_authorization() {
+    println!("🛂 Authorization - RBAC + ABAC แบบ deny-by-default");
+
+    let policy = workshop_policy();
+    match policy.validate() {
+        Ok(()) => println!("✅ policy ผ่านการตรวจ (ไม่มี role ที่หายหรือสืบทอดวนลูป)"),
+        Err(error) => println!("❌ {error}"),
+    }
+    for role in ["viewer", "editor", "admin"] {
+        let grants: Vec<String> = policy
+            .effective_grants(role)
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        println!("👑 {role}: {}", grants.join(", "));
+    }
+
+    let alice = Subject::new("1").with_role("admin");
+    let bob = Subject::new("2").with_role("viewer");
+    let carol = Subject::new("3")
+        .with_role("editor")
+        .with_attribute("status", "suspended");
+    let guest = Subject::new("guest");
+    let user = |id: &str| {
+        Resource::new("users")
+            .with_id(id)
+            .with_attribute("owner_id", id)
+    };
+
+    println!("\n⚖️ คำตัดสิน:");
+    let questions = [
+        (&alice, "delete", user("2")),
+        (&bob, "read", user("1")),
+        (&bob, "update", user("1")),
+        (&bob, "update", user("2")),
+        (&carol, "read", user("1")),
+        (&guest, "read", user("1")),
+    ];
+    for (subject, action, resource) in questions {
+        let decision = policy.evaluate(subject, action, &resource);
+        let icon = if decision.allowed { "✅" } else { "🚫" };
+        println!(
+            "{icon} user {} {action} users/{} → {decision}",
+            subject.id,
+            resource.id.as_deref().unwrap_or("-")
+        );
+    }
+
+    println!("\n📡 REST API ที่ตั้ง policy แล้ว:");
+    let router = ApiRouter::new().with_policy(policy);
+    let requests = [
+        HttpRequest::new("GET", "/api/users"),
+        HttpRequest::new("GET", "/api/users/1")
+            .with_header("X-User-Id", "2")
+            .with_header("X-User-Roles", "viewer"),
+        HttpRequest::new("DELETE", "/api/users/1")
+            .with_header("X-User-Id", "2")
+            .with_header("X-User-Roles", "editor"),
+        HttpRequest::new("DELETE", "/api/users/3")
+            .with_header("X-User-Id", "1")
+            .with_header("X-User-Roles", "admin"),
+    ];
+    for request in requests {
+        let who = request
+            .headers
+            .get("X-User-Roles")
+            .map_or("ไม่ระบุตัวตน", String::as_str);
+        let response = router.route(&request);
+        println!(
+            "   {} {} ({who}) → {}",
+            request.method,
+            request.path,
+            response.status.as_str()
+        );
+    }
+
+    let broken = Policy::new()
+        .with_role(RoleDefinition::new("a").inherits("b"))
+        .with_role(RoleDefinition::new("b").inherits("a"));
+    if let Err(error) = broken.validate() {
+        println!("\n🔁 policy ที่ตั้งค่าผิดถูกจับได้ตั้งแต่เริ่มระบบ: {error}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::web_development::HttpStatus;
+
+    fn users(id: &str) -> Resource {
+        Resource::new("users")
+            .with_id(id)
+            .with_attribute("owner_id", id)
+    }
+
+    #[test]
+    fn test_deny_by_default() {
+        let empty = Policy::new();
+        let admin = Subject::new("1").with_role("admin");
+        assert!(
+            !empty.can(&admin, "read", &users("1")),
+            "policy ว่างต้องห้ามทุกอย่าง"
+        );
+
+        let policy = workshop_policy();
+        let nobody = Subject::new("9");
+        assert!(!policy.can(&nobody, "read", &users("1")));
+        let ghost = Subject::new("9").with_role("superuser");
+        assert!(
+            !policy.can(&ghost, "read", &users("1")),
+            "role ที่ไม่รู้จักไม่ได้สิทธิ์อะไร"
+        );
+        let viewer = Subject::new("2").with_role("viewer");
+        assert!(!policy.can(&viewer, "delete", &users("1")));
+        assert!(!policy.can(&viewer, "read", &Resource::new("billing")));
+        assert_eq!(
+            policy.evaluate(&viewer, "archive", &users("1")).reason,
+            "no role or rule allows users:archive for \"2\""
+        );
+    }
+
+    #[test]
+    fn test_role_inheritance() {
+        let policy = workshop_policy();
+        let viewer = Subject::new("2").with_role("viewer");
+        let editor = Subject::new("3").with_role("editor");
+        let admin = Subject::new("4").with_role("admin");
+
+        assert!(policy.can(&viewer, "read", &users("1")));
+        assert!(!policy.can(&viewer, "create", &Resource::new("users")));
+        assert_eq!(
+            policy.evaluate(&editor, "read", &users("1")).reason,
+            "role \"editor\" via \"viewer\" grants users:read"
+        );
+        assert!(policy.can(&editor, "create", &Resource::new("users")));
+        assert!(!policy.can(&editor, "delete", &users("1")));
+        assert!(policy.can(&admin, "delete", &users("1")));
+        assert!(policy.can(&admin, "rotate", &Resource::new("keys")));
+
+        let grants: Vec<String> = policy
+            .effective_grants("editor")
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            grants,
+            [
+                "graphql:query",
+                "users:create",
+                "users:read",
+                "users:update"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_reports_unknown_parents_and_cycles() {
+        assert_eq!(workshop_policy().validate(), Ok(()));
+
+        let orphan = Policy::new().with_role(RoleDefinition::new("editor").inherits("viewer"));
+        assert_eq!(
+            orphan.validate(),
+            Err(PolicyError::UnknownParent {
+                role: "editor".to_string(),
+                parent: "viewer".to_string()
+            })
+        );
+
+        let cyclic = Policy::new()
+            .with_role(RoleDefinition::new("a").inherits("b").allow("docs", "read"))
+            .with_role(RoleDefinition::new("b").inherits("c"))
+            .with_role(RoleDefinition::new("c").inherits("a"));
+        assert_eq!(
+            cyclic.validate().unwrap_err().to_string(),
+            "role inheritance cycle: a -> b -> c -> a"
+        );
+        // แม้ policy จะวนลูป การตัดสินก็ยังจบและได้ grant ครบ
+        let subject = Subject::new("1").with_role("c");
+        assert!(cyclic.can(&subject, "read", &Resource::new("docs")));
+    }
+
+    #[test]
+    fn test_attribute_rules() {
+        let policy = workshop_policy();
+        let viewer = Subject::new("2").with_role("viewer");
+        assert_eq!(
+            policy.evaluate(&viewer, "update", &users("2")),
+            Decision {
+                allowed: true,
+                reason: "allowed by rule \"owner\"".to_string()
+            }
+        );
+        assert!(!policy.can(&viewer, "update", &users("1")));
+        assert!(
+            !policy.can(&viewer, "delete", &users("2")),
+            "owner rule ให้แค่ read/update"
+        );
+
+        // rule ที่ห้ามชนะ grant ของ role เสมอ - แม้แต่ admin
+        let suspended = Subject::new("1")
+            .with_role("admin")
+            .with_attribute("status", "suspended");
+        assert_eq!(
+            policy.evaluate(&suspended, "read", &users("1")).to_string(),
+            "deny: denied by rule \"suspended\""
+        );
+
+        // closure ใช้เป็น rule ได้ทันที: ทุกคนอ่าน resource ที่ public ได้
+        let public = Policy::new().with_rule(
+            "public",
+            |_: &Subject, action: &str, resource: &Resource| {
+                (action == "read" && resource.attribute("visibility") == Some("public"))
+                    .then_some(Effect::Allow)
+            },
+        );
+        let anyone = Subject::new("guest");
+        let page = Resource::new("pages").with_attribute("visibility", "public");
+        assert!(public.can(&anyone, "read", &page));
+        assert!(!public.can(&anyone, "update", &page));
+        assert!(!public.can(&anyone, "read", &Resource::new("pages")));
+    }
+
+    #[test]
+    fn test_rest_api_enforces_policy() {
+        let router = ApiRouter::new().with_policy(workshop_policy());
+        let as_user = |method: &str, path: &str, id: &str, role: &str| {
+            HttpRequest::new(method, path)
+                .with_header("X-User-Id", id)
+                .with_header("X-User-Roles", role)
+        };
+        let body = "{\"name\": \"Renamed\", \"email\": \"renamed@workshop.dev\", \"age\": 40}";
+
+        assert_eq!(
+            router.route(&HttpRequest::new("GET", "/api/users")).status,
+            HttpStatus::Unauthorized
+        );
+        assert_eq!(
+            router
+                .route(&as_user("GET", "/api/users", "2", "viewer"))
+                .status,
+            HttpStatus::Ok
+        );
+        assert_eq!(
+            router
+                .route(&as_user("POST", "/api/users", "2", "viewer").with_body(body))
+                .status,
+            HttpStatus::Forbidden
+        );
+        let forbidden =
+            router.route(&as_user("PUT", "/api/users/1", "2", "viewer").with_body(body));
+        assert_eq!(forbidden.status, HttpStatus::Forbidden);
+        assert!(
+            forbidden
+                .body
+                .contains("no role or rule allows users:update")
+        );
+        // เจ้าของแก้ข้อมูลตัวเองได้ด้วย owner rule
+        assert_eq!(
+            router
+                .route(&as_user("PUT", "/api/users/2", "2", "viewer").with_body(body))
+                .status,
+            HttpStatus::Ok
+        );
+        assert_eq!(
+            router
+                .route(&as_user("DELETE", "/api/users/3", "2", "editor"))
+                .status,
+            HttpStatus::Forbidden
+        );
+        assert_eq!(
+            router
+                .route(&as_user("DELETE", "/api/users/3", "1", "viewer, admin"))
+                .status,
+            HttpStatus::Ok
+        );
+        // endpoint ที่ policy ไม่รู้จักถูกห้ามก่อนถึง handler
+        assert_eq!(
+            router
+                .route(&as_user("GET", "/api/unknown", "1", "viewer"))
+                .status,
+            HttpStatus::Forbidden
+        );
+
+        // router ที่ไม่ได้ตั้ง policy ทำงานแบบเดิม
+        assert_eq!(
+            ApiRouter::new()
+                .route(&HttpRequest::new("GET", "/api/users"))
+                .status,
+            HttpStatus::Ok
+        );
+    }
+}
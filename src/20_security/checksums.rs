@@ -0,0 +1,486 @@
+//! 🧮 Checksums & Non-cryptographic Hashing - CRC32, FNV-1a และ `SipHash`
+//!
+//! hash สามตัวนี้ตอบคำถามต่างกัน:
+//! - 📦 **CRC32** - "ข้อมูลเสียระหว่างทางไหม?" จับ bit flip ได้ดีเยี่ยม แต่เป็นฟังก์ชันเชิงเส้น
+//!   จึงปลอมได้ง่ายมาก ([`forge_crc32`] ทำให้ข้อความไหนก็ได้มี CRC ตามต้องการด้วย 4 byte)
+//! - ⚡ **FNV-1a** - "ขอ hash เร็วๆ สำหรับ key สั้นๆ" ง่ายและเร็ว แต่ไม่มี key
+//!   ใครก็คำนวณ collision ล่วงหน้าได้
+//! - 🛡️ **`SipHash`** - "hash ที่ผู้โจมตีเดาไม่ได้" มี key ลับ 128 bit ต่อ `HashMap`
+//!   จึงเป็น hasher เริ่มต้นของ `std::collections::HashMap` (กัน `HashDoS`)
+//!
+//! ทั้ง [`Fnv1a`] และ [`SipHasher`] implement `std::hash::Hasher`
+//! จึงเสียบเข้า `HashMap::with_hasher` ได้ทันทีผ่าน `BuildHasher`
+//!
+//! ⚠️ ไม่มีตัวไหนในไฟล์นี้เป็น cryptographic hash - ใช้ SHA-256/BLAKE2 สำหรับลายเซ็นหรือรหัสผ่าน
+
+use std::collections::HashMap;
+use std::hash::{BuildHasher, BuildHasherDefault, Hasher};
+
+// ---------------------------------------------------------------------------
+// 📦 CRC32
+// ---------------------------------------------------------------------------
+
+/// polynomial ของ CRC-32/IEEE (zip, png, ethernet) แบบ bit-reversed
+const CRC32_POLY: u32 = 0xEDB8_8320;
+
+/// ตาราง 256 ค่าสร้างตอน compile: ผลของการประมวลผล 8 bit ของแต่ละ byte ไว้ล่วงหน้า
+/// ทำให้ลูปหลักเหลือ 1 lookup ต่อ byte แทน 8 รอบ shift/xor
+#[allow(clippy::cast_possible_truncation)] // index < 256
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0_u32; 256];
+    let mut index = 0;
+    while index < 256 {
+        let mut crc = index as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ CRC32_POLY
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[index] = crc;
+        index += 1;
+    }
+    table
+};
+
+/// 📦 CRC-32/IEEE แบบ table-driven และป้อนข้อมูลทีละส่วนได้
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Crc32 {
+    state: u32,
+}
+
+impl Default for Crc32 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Crc32 {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { state: u32::MAX }
+    }
+
+    /// CRC ของข้อมูลทั้งก้อนในครั้งเดียว
+    #[must_use]
+    pub fn checksum(bytes: &[u8]) -> u32 {
+        let mut crc = Self::new();
+        crc.update(bytes);
+        crc.value()
+    }
+
+    pub fn update(&mut self, bytes: &[u8]) {
+        self.state = crc32_step(self.state, bytes);
+    }
+
+    /// ค่า CRC ของข้อมูลที่ป้อนมาถึงตอนนี้ (ป้อนต่อได้อีก)
+    #[must_use]
+    pub const fn value(&self) -> u32 {
+        !self.state
+    }
+}
+
+#[allow(clippy::cast_possible_truncation)] // ใช้แค่ byte ล่างของ state โดยตั้งใจ
+fn crc32_step(mut state: u32, bytes: &[u8]) -> u32 {
+    for &byte in bytes {
+        state = (state >> 8) ^ CRC32_TABLE[usize::from(state as u8 ^ byte)];
+    }
+    state
+}
+
+/// ใช้ CRC32 เป็น `Hasher` ได้ (แค่ 32 bit และไม่มี key - เหมาะกับ checksum มากกว่า `HashMap`)
+impl Hasher for Crc32 {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        u64::from(self.value())
+    }
+}
+
+/// 🪄 4 byte ที่ต่อท้าย `data` แล้วทำให้ CRC32 เท่ากับ `target` พอดี
+///
+/// CRC เป็นเชิงเส้นบน GF(2): การ xor state ด้วย 4 byte ที่ป้อนเข้าไปเทียบเท่ากับป้อน 0 สี่ตัว
+/// จึงย้อน 4 ขั้นจาก state ปลายทางกลับมาได้ แล้ว xor กับ state ปัจจุบัน - ไม่ต้อง brute force เลย
+/// นี่คือเหตุผลที่ CRC ใช้จับข้อมูลเสีย แต่ห้ามใช้กันการแก้ไขโดยเจตนา
+///
+/// # Panics
+/// ไม่ panic - byte บนสุดของ 256 ค่าในตาราง CRC32 ครบทุกค่าเสมอ
+#[must_use]
+#[allow(clippy::cast_possible_truncation)] // index < 256
+pub fn forge_crc32(data: &[u8], target: u32) -> [u8; 4] {
+    let current = crc32_step(u32::MAX, data);
+    let mut state = !target;
+    for _ in 0..4 {
+        // byte บนสุดของทุกค่าในตารางไม่ซ้ำกัน จึงรู้ได้ว่าขั้นสุดท้ายใช้ index ไหน
+        let index = CRC32_TABLE
+            .iter()
+            .position(|&entry| entry >> 24 == state >> 24)
+            .expect("CRC32 table has every top byte");
+        state = ((state ^ CRC32_TABLE[index]) << 8) | index as u32;
+    }
+    (state ^ current).to_le_bytes()
+}
+
+// ---------------------------------------------------------------------------
+// ⚡ FNV-1a
+// ---------------------------------------------------------------------------
+
+const FNV32_OFFSET: u32 = 0x811c_9dc5;
+const FNV32_PRIME: u32 = 0x0100_0193;
+const FNV64_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV64_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// FNV-1a 32 bit: xor byte เข้าไปก่อน แล้วคูณด้วย prime (FNV-1 ทำสลับกัน และกระจายแย่กว่า)
+#[must_use]
+pub fn fnv1a_32(bytes: &[u8]) -> u32 {
+    bytes.iter().fold(FNV32_OFFSET, |hash, &byte| {
+        (hash ^ u32::from(byte)).wrapping_mul(FNV32_PRIME)
+    })
+}
+
+#[must_use]
+pub fn fnv1a_64(bytes: &[u8]) -> u64 {
+    let mut hasher = Fnv1a::default();
+    hasher.write(bytes);
+    hasher.finish()
+}
+
+/// ⚡ FNV-1a 64 bit ในรูป `Hasher` - ใช้กับ `HashMap` ผ่าน [`FnvBuildHasher`]
+#[derive(Debug, Clone, Copy)]
+pub struct Fnv1a(u64);
+
+impl Default for Fnv1a {
+    fn default() -> Self {
+        Self(FNV64_OFFSET)
+    }
+}
+
+impl Hasher for Fnv1a {
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = (self.0 ^ u64::from(byte)).wrapping_mul(FNV64_PRIME);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+/// `BuildHasher` ที่สร้าง [`Fnv1a`] ใหม่ทุกครั้ง - ทุก map ได้ hash เดียวกันหมด (ไม่มี key)
+pub type FnvBuildHasher = BuildHasherDefault<Fnv1a>;
+
+/// `HashMap` ที่ใช้ FNV-1a: เร็วกว่า `SipHash` สำหรับ key สั้น แต่ใช้กับ input จากผู้ใช้ไม่ปลอดภัย
+pub type FnvHashMap<K, V> = HashMap<K, V, FnvBuildHasher>;
+
+// ---------------------------------------------------------------------------
+// 🛡️ SipHash
+// ---------------------------------------------------------------------------
+
+/// 🛡️ SipHash-c-d แบบอ่านง่าย (ค่าเริ่มต้น 2-4 ตาม paper, มี 1-3 แบบที่ std ใช้)
+///
+/// state 256 bit (`v0..v3`) ผสมด้วย ARX round (add-rotate-xor) ทุกๆ 8 byte
+/// เวอร์ชันนี้ "simplified" ตรงที่รับ input ทีละ byte - ผลลัพธ์ตรงกับ reference ทุกบิต
+/// แต่ช้ากว่า implementation จริงที่อ่านทีละ `u64`
+#[derive(Debug, Clone, Copy)]
+pub struct SipHasher {
+    v: [u64; 4],
+    tail: u64,
+    length: usize,
+    c_rounds: usize,
+    d_rounds: usize,
+}
+
+impl SipHasher {
+    /// SipHash-2-4 ด้วย key 128 bit (`k0` = 8 byte แรกแบบ little-endian)
+    #[must_use]
+    pub const fn new_with_keys(k0: u64, k1: u64) -> Self {
+        Self::with_rounds(k0, k1, 2, 4)
+    }
+
+    /// SipHash-1-3 - round น้อยกว่า เร็วกว่า (`HashMap` ของ std ใช้แบบนี้)
+    #[must_use]
+    pub const fn new_1_3(k0: u64, k1: u64) -> Self {
+        Self::with_rounds(k0, k1, 1, 3)
+    }
+
+    const fn with_rounds(k0: u64, k1: u64, c_rounds: usize, d_rounds: usize) -> Self {
+        // ค่าคงที่คือ "somepseudorandomlygeneratedbytes" ในรูป ASCII
+        Self {
+            v: [
+                k0 ^ 0x736f_6d65_7073_6575,
+                k1 ^ 0x646f_7261_6e64_6f6d,
+                k0 ^ 0x6c79_6765_6e65_7261,
+                k1 ^ 0x7465_6462_7974_6573,
+            ],
+            tail: 0,
+            length: 0,
+            c_rounds,
+            d_rounds,
+        }
+    }
+
+    const fn sip_round(v: &mut [u64; 4]) {
+        v[0] = v[0].wrapping_add(v[1]);
+        v[1] = v[1].rotate_left(13) ^ v[0];
+        v[0] = v[0].rotate_left(32);
+        v[2] = v[2].wrapping_add(v[3]);
+        v[3] = v[3].rotate_left(16) ^ v[2];
+        v[0] = v[0].wrapping_add(v[3]);
+        v[3] = v[3].rotate_left(21) ^ v[0];
+        v[2] = v[2].wrapping_add(v[1]);
+        v[1] = v[1].rotate_left(17) ^ v[2];
+        v[2] = v[2].rotate_left(32);
+    }
+
+    fn compress(&mut self, word: u64) {
+        self.v[3] ^= word;
+        for _ in 0..self.c_rounds {
+            Self::sip_round(&mut self.v);
+        }
+        self.v[0] ^= word;
+    }
+}
+
+impl Hasher for SipHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.tail |= u64::from(byte) << (8 * (self.length % 8));
+            self.length += 1;
+            if self.length.is_multiple_of(8) {
+                let word = std::mem::take(&mut self.tail);
+                self.compress(word);
+            }
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        // ทำงานบนสำเนา - เรียก finish ซ้ำหรือ write ต่อหลังจากนี้ได้ตามสัญญาของ `Hasher`
+        let mut state = *self;
+        // byte สุดท้ายของ block สุดท้ายคือความยาว mod 256 - "ab" กับ "ab\0" จึงได้ hash ต่างกัน
+        let last = ((self.length as u64 & 0xff) << 56) | self.tail;
+        state.compress(last);
+        state.v[2] ^= 0xff;
+        for _ in 0..self.d_rounds {
+            Self::sip_round(&mut state.v);
+        }
+        state.v.iter().fold(0, |acc, v| acc ^ v)
+    }
+}
+
+/// `BuildHasher` ที่ถือ key ของ `SipHash` - สุ่ม key ใหม่ต่อ map แบบเดียวกับ `RandomState`
+#[derive(Debug, Clone, Copy)]
+pub struct SipBuildHasher {
+    k0: u64,
+    k1: u64,
+}
+
+impl SipBuildHasher {
+    #[must_use]
+    pub const fn with_keys(k0: u64, k1: u64) -> Self {
+        Self { k0, k1 }
+    }
+
+    /// key สุ่ม - ยืมความสุ่มจาก `RandomState` ของ std (ซึ่งขอ seed จาก OS)
+    #[must_use]
+    pub fn random() -> Self {
+        let seed = std::collections::hash_map::RandomState::new();
+        Self::with_keys(seed.hash_one(0_u8), seed.hash_one(1_u8))
+    }
+}
+
+impl BuildHasher for SipBuildHasher {
+    type Hasher = SipHasher;
+
+    fn build_hasher(&self) -> SipHasher {
+        SipHasher::new_1_3(self.k0, self.k1)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// 💥 Collisions
+// ---------------------------------------------------------------------------
+
+/// 🎂 หา collision ด้วย birthday attack บน hash ที่ตัดเหลือ `bits` bit
+///
+/// โอกาสชนกันเกิน 50% หลังลองประมาณ `1.18 × 2^(bits/2)` key
+/// hash 32 bit จึงชนกันหลังแค่ ~77,000 key - นี่คือเหตุผลที่ checksum 32 bit ไม่ใช่ ID ที่ไม่ซ้ำ
+///
+/// # Panics
+/// เมื่อ `bits` เป็น 0 หรือมากกว่า 64
+#[must_use]
+pub fn find_collision(hash: impl Fn(&[u8]) -> u64, bits: u32) -> (String, String, u64) {
+    assert!((1..=64).contains(&bits), "bits must be in 1..=64");
+    let mask = u64::MAX >> (64 - bits);
+    let mut seen: HashMap<u64, String> = HashMap::new();
+    (0_u64..)
+        .find_map(|n| {
+            let key = format!("user-{n}");
+            let value = hash(key.as_bytes()) & mask;
+            seen.insert(value, key.clone())
+                .map(|previous| (previous, key, value))
+        })
+        .expect("pigeonhole principle guarantees a collision")
+}
+
+/// ชุด key ที่ทุกตัว hash ลง bucket เดียวกันใน `HashMap` ขนาด `buckets` (ต้องเป็นกำลังของ 2)
+///
+/// ทำได้เพราะ hasher ไม่มี key - ผู้โจมตีคำนวณชุดนี้ออฟไลน์แล้วส่งให้ server ที่ใช้ FNV ได้เลย
+#[must_use]
+pub fn same_bucket_keys(build: &impl BuildHasher, buckets: u64, count: usize) -> Vec<String> {
+    let target = build.hash_one("user-0") % buckets;
+    (0_u64..)
+        .map(|n| format!("user-{n}"))
+        .filter(|key| build.hash_one(key) % buckets == target)
+        .take(count)
+        .collect()
+}
+
+/// 🎪 เดโม checksum, FNV ใน `HashMap` และ collision
+pub fn demonstrate_checksums() {
+    println!("🧮 Checksums & non-cryptographic hashes");
+
+    let message = b"The quick brown fox jumps over the lazy dog";
+    println!("   📝 ข้อความ: {:?}", String::from_utf8_lossy(message));
+    println!("   📦 CRC32       = {:08x}", Crc32::checksum(message));
+    println!("   ⚡ FNV-1a 32   = {:08x}", fnv1a_32(message));
+    println!("   ⚡ FNV-1a 64   = {:016x}", fnv1a_64(message));
+    let mut sip = SipHasher::new_with_keys(0x0706_0504_0302_0100, 0x0f0e_0d0c_0b0a_0908);
+    sip.write(message);
+    println!("   🛡️ SipHash-2-4 = {:016x} (key 00..0f)", sip.finish());
+
+    let mut streaming = Crc32::new();
+    for chunk in message.chunks(7) {
+        streaming.update(chunk);
+    }
+    println!(
+        "   🔁 CRC32 ป้อนทีละ 7 byte ได้ค่าเดิม: {}",
+        streaming.value() == Crc32::checksum(message)
+    );
+
+    let mut tampered = b"Pay Alice 100 THB".to_vec();
+    let original_crc = Crc32::checksum(&tampered);
+    tampered = b"Pay Mallory 99999 THB".to_vec();
+    let patch = forge_crc32(&tampered, original_crc);
+    tampered.extend_from_slice(&patch);
+    println!(
+        "   🪄 ปลอมข้อความให้ CRC ตรงกับของเดิม {:08x}: {:?} → {:08x}",
+        original_crc,
+        String::from_utf8_lossy(&tampered[..tampered.len() - 4]),
+        Crc32::checksum(&tampered)
+    );
+
+    let (a, b, value) = find_collision(|bytes| u64::from(fnv1a_32(bytes)), 32);
+    println!("   🎂 FNV-1a 32 bit ชนกัน: {a:?} กับ {b:?} → {value:08x}");
+
+    let mut scores: FnvHashMap<&str, u32> = FnvHashMap::default();
+    scores.insert("ferris", 42);
+    *scores.entry("ferris").or_default() += 1;
+    println!("   🗺️ FnvHashMap: {scores:?}");
+
+    let fnv_keys = same_bucket_keys(&FnvBuildHasher::default(), 1024, 5);
+    let sip = SipBuildHasher::random();
+    let buckets: Vec<u64> = fnv_keys
+        .iter()
+        .map(|key| sip.hash_one(key) % 1024)
+        .collect();
+    println!("   💥 key ที่ FNV ลง bucket เดียวกันเสมอ: {fnv_keys:?}");
+    println!("   🛡️ SipHash (key สุ่ม) กระจายไป bucket: {buckets:?}");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crc32_known_vectors() {
+        assert_eq!(Crc32::checksum(b""), 0);
+        assert_eq!(Crc32::checksum(b"123456789"), 0xCBF4_3926);
+        assert_eq!(
+            Crc32::checksum(b"The quick brown fox jumps over the lazy dog"),
+            0x414F_A339
+        );
+
+        // table-driven ต้องตรงกับเวอร์ชัน bit-by-bit ในบท unsafe/FFI
+        let data: Vec<u8> = (0..=u8::MAX).cycle().take(1000).collect();
+        assert_eq!(
+            Crc32::checksum(&data),
+            crate::unsafe_rust::ffi::crc32_ieee(&data)
+        );
+    }
+
+    #[test]
+    fn test_fnv1a_known_vectors() {
+        assert_eq!(fnv1a_32(b""), 0x811c_9dc5);
+        assert_eq!(fnv1a_32(b"a"), 0xe40c_292c);
+        assert_eq!(fnv1a_32(b"foobar"), 0xbf9c_f968);
+        assert_eq!(fnv1a_64(b""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(fnv1a_64(b"a"), 0xaf63_dc4c_8601_ec8c);
+        assert_eq!(fnv1a_64(b"foobar"), 0x8594_4171_f739_67e8);
+    }
+
+    #[test]
+    fn test_siphash_reference_vectors() {
+        // จากภาคผนวกของ paper SipHash: key = 00 01 .. 0f
+        let hash = |len: u8| {
+            let mut hasher = SipHasher::new_with_keys(0x0706_0504_0302_0100, 0x0f0e_0d0c_0b0a_0908);
+            hasher.write(&(0..len).collect::<Vec<u8>>());
+            hasher.finish()
+        };
+        assert_eq!(hash(0), 0x726f_db47_dd0e_0e31);
+        assert_eq!(hash(15), 0xa129_ca61_49be_45e5);
+
+        // แบ่ง write หลายครั้งต้องได้ผลเท่าเขียนครั้งเดียว
+        let mut split = SipHasher::new_with_keys(0x0706_0504_0302_0100, 0x0f0e_0d0c_0b0a_0908);
+        split.write(&[0, 1, 2]);
+        split.write(&[3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14]);
+        assert_eq!(split.finish(), hash(15));
+    }
+
+    #[test]
+    fn test_hashers_plug_into_hashmap() {
+        let mut fnv: FnvHashMap<String, usize> = FnvHashMap::default();
+        let mut sip: HashMap<String, usize, SipBuildHasher> =
+            HashMap::with_hasher(SipBuildHasher::random());
+        for i in 0..1_000 {
+            fnv.insert(format!("key{i}"), i);
+            sip.insert(format!("key{i}"), i);
+        }
+        assert_eq!(fnv.len(), 1_000);
+        assert_eq!(sip.get("key777"), Some(&777));
+        assert_eq!(fnv.get("key777"), Some(&777));
+
+        // FNV ได้ค่าเดิมทุก map, SipHash เปลี่ยนตาม key
+        let a = FnvBuildHasher::default();
+        assert_eq!(a.hash_one("x"), FnvBuildHasher::default().hash_one("x"));
+        let keyed = |k0| SipBuildHasher::with_keys(k0, 0).hash_one("x");
+        assert_ne!(keyed(1), keyed(2));
+    }
+
+    #[test]
+    fn test_crc32_can_be_forged() {
+        let original = Crc32::checksum(b"version=1.0.0");
+        let mut evil = b"version=6.6.6;rm -rf /".to_vec();
+        evil.extend_from_slice(&forge_crc32(&evil, original));
+        assert_eq!(Crc32::checksum(&evil), original);
+    }
+
+    #[test]
+    fn test_birthday_collision_and_bucket_flooding() {
+        let (a, b, value) = find_collision(|bytes| u64::from(fnv1a_32(bytes)), 20);
+        assert_ne!(a, b);
+        assert_eq!(u64::from(fnv1a_32(a.as_bytes())) & 0xf_ffff, value);
+        assert_eq!(u64::from(fnv1a_32(b.as_bytes())) & 0xf_ffff, value);
+
+        let fnv = FnvBuildHasher::default();
+        let keys = same_bucket_keys(&fnv, 256, 10);
+        assert_eq!(keys.len(), 10);
+        let bucket = fnv.hash_one(&keys[0]) % 256;
+        assert!(keys.iter().all(|key| fnv.hash_one(key) % 256 == bucket));
+    }
+}
@@ -6,7 +6,13 @@
 //! 🎯 สิ่งที่จะได้เรียนรู้:
 //! - 🔐 Encryption - การเข้ารหัสข้อมูล
 //! - #️⃣ Hashing - การสร้างลายนิ้วมือดิจิทัล
-//! - 🔑 Authentication - การยืนยันตัวตน
+//! - 🔑 Authentication - การยืนยันตัวตน และ `OAuth2` (authorization code + PKCE, JWT, OIDC)
+//! - 🛂 Authorization - role ที่สืบทอดกันได้ + rule ตาม attribute แบบ deny-by-default
+//! - 🗝️ Secrets - vault ไฟล์เข้ารหัส (PBKDF2 + AEAD) พร้อม zeroize ความลับใน RAM
+//! - 🧽 Sanitization - กัน SQL injection, XSS และ path traversal พร้อม attack ที่ยิงได้จริง
+//! - 📒 Audit log - บันทึก login/การปฏิเสธสิทธิ์/config แบบ hash chain ที่แก้แล้วจับได้
+//! - 🧮 Checksums - CRC32, FNV-1a และ `SipHash` (hash ที่ไม่ใช่ crypto แต่ใช้ทุกวัน)
+//! - 🗝️ RSA - สร้างกุญแจ เซ็น และตรวจลายเซ็นแบบตำราเรียนด้วย `BigUint`
 //! - 🛡️ Secure Coding Practices
 //!
 //! หมายเหตุ: นี่คือการจำลองเพื่อการศึกษา! 📚
@@ -14,10 +20,22 @@
 pub mod encryption;
 pub mod hashing;
 pub mod authentication;
+pub mod authorization;
+pub mod secrets;
+pub mod sanitization;
+pub mod audit;
+pub mod checksums;
+pub mod rsa;
 
 pub use encryption::*;
 pub use hashing::*;
 pub use authentication::*;
+pub use authorization::*;
+pub use secrets::*;
+pub use sanitization::*;
+pub use audit::*;
+pub use checksums::*;
+pub use rsa::*;
 
 /// 🚀 รันตัวอย่าง Security & Cryptography Workshop!
 /// เหมือนการเปิดห้องเรียนความปลอดภัยดิจิทัล! 🏫🔒
@@ -41,6 +59,42 @@ pub fn run_security_examples() {
     println!("🔑 การยืนยันตัวตน - เหมือนการเช็คบัตรก่อนเข้าอาคาร!");
     demonstrate_authentication();
     
+    println!();
+    
+    // Authorization
+    println!("🛂 การกำหนดสิทธิ์ - รู้แล้วว่าเป็นใคร ต่อไปคือทำอะไรได้บ้าง!");
+    demonstrate_authorization();
+    
+    println!();
+    
+    // Secrets
+    println!("🗝️ การเก็บความลับ - passphrase เดียวเปิดตู้เซฟที่เก็บกุญแจทั้งหมด!");
+    demonstrate_secrets();
+    
+    println!();
+    
+    // Sanitization
+    println!("🧽 การกรอง input - ข้อมูลจากผู้ใช้ต้องไม่กลายเป็นคำสั่ง!");
+    demonstrate_sanitization();
+    
+    println!();
+    
+    // Audit log
+    println!("📒 Audit log - ใครทำอะไรเมื่อไหร่ และไม่มีใครแอบแก้ประวัติได้!");
+    demonstrate_audit_log();
+    
+    println!();
+    
+    // Checksums
+    println!("🧮 Checksum และ hash ที่ไม่ใช่ crypto - เร็วแต่ต้องรู้ข้อจำกัด!");
+    demonstrate_checksums();
+    
+    println!();
+    
+    // RSA
+    println!("🗝️ RSA - กุญแจคู่ที่ทำให้ใครก็ตรวจลายเซ็นได้ แต่มีแค่เราที่เซ็นได้!");
+    demonstrate_rsa();
+    
     println!("\n🎉 ยินดีด้วย! คุณได้เรียนรู้ Security & Cryptography เรียบร้อยแล้ว!");
     println!("💡 ตอนนี้คุณรู้วิธีปกป้องข้อมูลแบบมืออาชีพแล้ว! 🛡️");
 }
\ No newline at end of file
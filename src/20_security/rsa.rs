@@ -0,0 +1,233 @@
+//! 🔑 RSA แบบตำราเรียน (textbook RSA) - สร้างกุญแจ เซ็น และตรวจลายเซ็นด้วย [`BigUint`]
+//!
+//! 1. สุ่ม prime `p`, `q` แล้วได้ `n = p × q` - เปิดเผย `n` ได้เพราะแยกตัวประกอบกลับยากมาก
+//! 2. `φ(n) = (p - 1)(q - 1)` และเลือก `e = 65537`
+//! 3. `d = e⁻¹ mod φ(n)` - กุญแจลับ
+//! 4. เซ็น: `s = hash(m)^d mod n` / ตรวจ: `s^e mod n == hash(m)`
+//!
+//! ⚠️ ไม่มี padding (PKCS#1 v1.5/PSS), ไม่ constant-time และกุญแจ 512 bit แยกตัวประกอบได้ในไม่กี่ชั่วโมง
+//! ใช้เรียนเท่านั้น - งานจริงใช้ crate `rsa` หรือ `ring`
+
+use std::fmt;
+
+use rand::Rng;
+use sha2::{Digest, Sha256};
+
+use crate::bigint::BigUint;
+
+/// public exponent มาตรฐาน (2¹⁶ + 1): เป็น prime และมี bit 1 แค่ 2 ตัว จึงตรวจลายเซ็นได้เร็ว
+pub const PUBLIC_EXPONENT: u64 = 65_537;
+
+/// 🔓 กุญแจสาธารณะ `(n, e)`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RsaPublicKey {
+    pub n: BigUint,
+    pub e: BigUint,
+}
+
+/// 🔑 คู่กุญแจ - เก็บ `p`, `q` ไว้แสดงในเดโม (ของจริงทิ้งหรือเก็บเพื่อเร่งด้วย CRT)
+#[derive(Clone)]
+pub struct RsaKeyPair {
+    pub public: RsaPublicKey,
+    d: BigUint,
+    p: BigUint,
+    q: BigUint,
+}
+
+/// ไม่พิมพ์กุญแจลับออก log โดยไม่ตั้งใจ
+impl fmt::Debug for RsaKeyPair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RsaKeyPair")
+            .field("public", &self.public)
+            .field("d", &"<redacted>")
+            .finish_non_exhaustive()
+    }
+}
+
+/// ❌ สร้างกุญแจจาก `p`, `q`, `e` ที่ให้มาไม่ได้
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RsaError {
+    /// `e` กับ `φ(n)` มีตัวประกอบร่วม จึงหา `d` ไม่ได้
+    ExponentNotInvertible,
+    /// `p == q` ทำให้ `n` เป็นกำลังสองที่แยกง่าย
+    EqualPrimes,
+}
+
+impl fmt::Display for RsaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ExponentNotInvertible => write!(f, "public exponent shares a factor with φ(n)"),
+            Self::EqualPrimes => write!(f, "p and q must be different primes"),
+        }
+    }
+}
+
+impl std::error::Error for RsaError {}
+
+impl RsaKeyPair {
+    /// สุ่มคู่กุญแจขนาด `bits` bit (`p`, `q` ขนาดครึ่งหนึ่ง)
+    ///
+    /// # Panics
+    /// เมื่อ `bits` น้อยกว่า 32
+    #[must_use]
+    pub fn generate<R: Rng + ?Sized>(bits: u64, rng: &mut R) -> Self {
+        assert!(bits >= 32, "RSA modulus needs at least 32 bits");
+        loop {
+            let p = BigUint::random_prime(bits / 2, rng);
+            let q = BigUint::random_prime(bits - bits / 2, rng);
+            // e ต้องหาร φ(n) ไม่ลงตัว - ถ้าไม่ผ่านก็สุ่ม prime คู่ใหม่
+            if let Ok(pair) = Self::from_primes(p, q, &BigUint::from(PUBLIC_EXPONENT)) {
+                return pair;
+            }
+        }
+    }
+
+    /// สร้างจาก prime ที่รู้อยู่แล้ว (ใช้กับตัวอย่างในตำรา)
+    ///
+    /// # Errors
+    /// [`RsaError`] เมื่อ `p == q` หรือ `e` ไม่มี inverse ใน mod `φ(n)`
+    pub fn from_primes(p: BigUint, q: BigUint, e: &BigUint) -> Result<Self, RsaError> {
+        if p == q {
+            return Err(RsaError::EqualPrimes);
+        }
+        let one = BigUint::one();
+        let phi = &(&p - &one) * &(&q - &one);
+        let d = e.mod_inverse(&phi).ok_or(RsaError::ExponentNotInvertible)?;
+        Ok(Self {
+            public: RsaPublicKey {
+                n: &p * &q,
+                e: e.clone(),
+            },
+            d,
+            p,
+            q,
+        })
+    }
+
+    /// ถอดรหัส/เซ็นแบบดิบ: `c^d mod n`
+    #[must_use]
+    pub fn apply_private(&self, value: &BigUint) -> BigUint {
+        value.pow_mod(&self.d, &self.public.n)
+    }
+
+    /// ✍️ ลายเซ็นของ SHA-256(message)
+    #[must_use]
+    pub fn sign(&self, message: &[u8]) -> BigUint {
+        self.apply_private(&self.public.digest(message))
+    }
+
+    #[must_use]
+    pub const fn primes(&self) -> (&BigUint, &BigUint) {
+        (&self.p, &self.q)
+    }
+}
+
+impl RsaPublicKey {
+    /// เข้ารหัส/ตรวจแบบดิบ: `m^e mod n` (`m` ต้องน้อยกว่า `n`)
+    #[must_use]
+    pub fn apply_public(&self, value: &BigUint) -> BigUint {
+        value.pow_mod(&self.e, &self.n)
+    }
+
+    /// ✅ ลายเซ็นตรงกับข้อความไหม
+    #[must_use]
+    pub fn verify(&self, message: &[u8], signature: &BigUint) -> bool {
+        *signature < self.n && self.apply_public(signature) == self.digest(message)
+    }
+
+    /// SHA-256 ของข้อความ ลดให้อยู่ใน `[0, n)` (กุญแจเล็กกว่า 256 bit จะตัด hash ทิ้งบางส่วน)
+    fn digest(&self, message: &[u8]) -> BigUint {
+        &BigUint::from_bytes_be(&Sha256::digest(message)) % &self.n
+    }
+}
+
+/// 🎪 เดโม: ตัวอย่างในตำรา แล้วสร้างกุญแจ 512 bit จริงมาเซ็นเอกสาร
+///
+/// # Panics
+/// ไม่เกิดขึ้นจริง - 17 เป็น coprime กับ φ(3233) เสมอ
+pub fn demonstrate_rsa() {
+    println!("🔑 Textbook RSA บน BigUint");
+
+    let small = RsaKeyPair::from_primes(
+        BigUint::from(61_u64),
+        BigUint::from(53_u64),
+        &BigUint::from(17_u64),
+    )
+    .expect("17 is coprime to φ(3233) = 3120");
+    let message = BigUint::from(65_u64);
+    let cipher = small.public.apply_public(&message);
+    println!(
+        "   📖 p=61 q=53 → n={} e=17 d={} | 65 → เข้ารหัส {} → ถอด {}",
+        small.public.n,
+        small.d,
+        cipher,
+        small.apply_private(&cipher)
+    );
+
+    let mut rng = rand::thread_rng();
+    let keys = RsaKeyPair::generate(512, &mut rng);
+    println!("   🎲 สร้างกุญแจ {} bit", keys.public.n.bits());
+    println!("      n = {:#x}", keys.public.n);
+
+    let document = b"Transfer 100 THB to Somchai";
+    let signature = keys.sign(document);
+    println!("   ✍️ ลายเซ็น = {signature:#x}");
+    println!(
+        "   ✅ ตรวจเอกสารเดิม: {} | ❌ เอกสารที่ถูกแก้: {}",
+        keys.public.verify(document, &signature),
+        keys.public
+            .verify(b"Transfer 900 THB to Somchai", &signature)
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    #[test]
+    fn test_textbook_example() {
+        // ตัวอย่างจาก Wikipedia: p=61, q=53, e=17 → d=2753, 65^17 mod 3233 = 2790
+        let keys = RsaKeyPair::from_primes(
+            BigUint::from(61_u64),
+            BigUint::from(53_u64),
+            &BigUint::from(17_u64),
+        )
+        .unwrap();
+        assert_eq!(keys.public.n, BigUint::from(3233_u64));
+        assert_eq!(keys.d, BigUint::from(2753_u64));
+        let cipher = keys.public.apply_public(&BigUint::from(65_u64));
+        assert_eq!(cipher, BigUint::from(2790_u64));
+        assert_eq!(keys.apply_private(&cipher), BigUint::from(65_u64));
+
+        assert_eq!(
+            RsaKeyPair::from_primes(
+                BigUint::from(7_u64),
+                BigUint::from(13_u64),
+                &BigUint::from(3_u64)
+            )
+            .unwrap_err(),
+            RsaError::ExponentNotInvertible
+        );
+    }
+
+    #[test]
+    fn test_generated_key_signs_and_verifies() {
+        let mut rng = StdRng::seed_from_u64(2024);
+        let keys = RsaKeyPair::generate(256, &mut rng);
+        assert_eq!(keys.public.n.bits(), 256);
+        let (p, q) = keys.primes();
+        assert_eq!(&(p * q), &keys.public.n);
+
+        let signature = keys.sign(b"hello");
+        assert!(keys.public.verify(b"hello", &signature));
+        assert!(!keys.public.verify(b"hellp", &signature));
+        assert!(
+            !keys
+                .public
+                .verify(b"hello", &(&signature + &BigUint::one()))
+        );
+        assert!(!format!("{keys:?}").contains(&keys.d.to_string()));
+    }
+}
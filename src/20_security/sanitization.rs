@@ -0,0 +1,825 @@
+//! 🧽 Input Sanitization - กัน injection สามแบบที่เจอบ่อยที่สุดบนเว็บ
+//!
+//! - 💉 SQL injection: ต่อ string เป็น SQL (`QueryBuilder::where_eq`) เทียบกับ parameter binding
+//!   (`QueryBuilder::where_bind`) แล้วยิงจริงใส่ `ToyDatabase` เอนจิน SQL จิ๋วที่ parse แบบฐานข้อมูลจริง
+//! - 🏷️ XSS: `escape_html` ที่ `TemplateEngine` ใช้กับทุก `{{ค่า}}`
+//! - 📂 Path traversal: `safe_relative_path` ที่ `StaticFiles` ใช้ก่อนแตะ filesystem
+//!
+//! หลักเดียวกันทั้งสามแบบ: ข้อมูลจากผู้ใช้ต้องไม่มีวันกลายเป็น "คำสั่ง" ของภาษาปลายทาง
+
+use std::collections::{BTreeMap, HashMap};
+use std::error::Error;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use crate::database::orm_examples::QueryBuilder;
+use crate::web_development::url::percent_decode;
+
+/// 🏷️ escape ข้อความก่อนวางลงใน HTML (ทั้งใน element และใน attribute ที่ครอบด้วย `"` หรือ `'`)
+///
+/// `{` และ `}` ถูก escape ด้วย เพื่อไม่ให้ค่าของผู้ใช้กลายเป็น `{{...}}` เมื่อผ่าน template อีกรอบ
+/// (เช่นผลของ loop ที่ถูกวางลงใน layout)
+#[must_use]
+pub fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            '{' => escaped.push_str("&#123;"),
+            '}' => escaped.push_str("&#125;"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+/// ❌ เหตุผลที่ URL path ถูกปฏิเสธ
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathViolation {
+    /// `..` (รวมถึง `%2e%2e`) - พยายามปีนออกนอก root
+    ParentDirectory,
+    /// ไฟล์ที่ขึ้นต้นด้วย `.` เช่น `.env`, `.git`
+    HiddenFile(String),
+    /// `/`, `\` หรือ NUL ที่ถูก percent-encode มาใน segment เดียว
+    ForbiddenCharacter(char),
+    /// segment ที่เป็น path แบบ absolute ได้ด้วยตัวเอง
+    Absolute(String),
+}
+
+impl fmt::Display for PathViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ParentDirectory => f.write_str("parent directory segment"),
+            Self::HiddenFile(name) => write!(f, "hidden file {name:?}"),
+            Self::ForbiddenCharacter(ch) => write!(f, "forbidden character {ch:?} in segment"),
+            Self::Absolute(segment) => write!(f, "absolute path segment {segment:?}"),
+        }
+    }
+}
+
+impl Error for PathViolation {}
+
+/// 📂 แปลง URL path (ยัง percent-encoded) เป็น path แบบ relative ที่ต่อกับ root ได้อย่างปลอดภัย
+///
+/// decode ทีละ segment *ก่อน* ตรวจ - `%2e%2e` กับ `..%2f` จึงไม่หลุด ส่วน `.` และ segment ว่างถูกข้าม
+///
+/// # Errors
+///
+/// คืน [`PathViolation`] ของ segment แรกที่ไม่ปลอดภัย
+pub fn safe_relative_path(url_path: &str) -> Result<PathBuf, PathViolation> {
+    let mut path = PathBuf::new();
+    for segment in url_path.split('/') {
+        let segment = percent_decode(segment);
+        if segment.is_empty() || segment == "." {
+            continue;
+        }
+        if segment == ".." {
+            return Err(PathViolation::ParentDirectory);
+        }
+        if let Some(ch) = segment.chars().find(|ch| matches!(ch, '/' | '\\' | '\0')) {
+            return Err(PathViolation::ForbiddenCharacter(ch));
+        }
+        if segment.starts_with('.') {
+            return Err(PathViolation::HiddenFile(segment));
+        }
+        if Path::new(&segment).is_absolute() {
+            return Err(PathViolation::Absolute(segment));
+        }
+        path.push(segment);
+    }
+    Ok(path)
+}
+
+/// 📄 หนึ่งแถวใน `ToyDatabase`: ชื่อคอลัมน์ → ค่า (ทุกค่าเป็นข้อความ)
+pub type Row = BTreeMap<String, String>;
+
+/// ❌ SQL ที่ parse หรือรันไม่ได้
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SqlError(pub String);
+
+impl fmt::Display for SqlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SQL error: {}", self.0)
+    }
+}
+
+impl Error for SqlError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Word(String),
+    Text(String),
+    Number(String),
+    Placeholder,
+    NotEqual,
+    Symbol(char),
+}
+
+/// ✂️ แยก SQL เป็น token - `--` คือ comment ถึงท้ายบรรทัด, `''` ใน string คือ `'` หนึ่งตัว
+fn tokenize(sql: &str) -> Result<Vec<Token>, SqlError> {
+    let mut tokens = Vec::new();
+    let mut chars = sql.chars().peekable();
+    while let Some(ch) = chars.next() {
+        match ch {
+            ch if ch.is_whitespace() => {}
+            '-' if chars.peek() == Some(&'-') => {
+                chars.by_ref().find(|&ch| ch == '\n');
+            }
+            '\'' => {
+                let mut text = String::new();
+                loop {
+                    match chars.next() {
+                        Some('\'') if chars.peek() == Some(&'\'') => {
+                            chars.next();
+                            text.push('\'');
+                        }
+                        Some('\'') => break,
+                        Some(ch) => text.push(ch),
+                        None => return Err(SqlError("unterminated string literal".to_string())),
+                    }
+                }
+                tokens.push(Token::Text(text));
+            }
+            '0'..='9' => {
+                let mut number = ch.to_string();
+                while let Some(&digit) = chars.peek().filter(|ch| ch.is_ascii_digit()) {
+                    number.push(digit);
+                    chars.next();
+                }
+                tokens.push(Token::Number(number));
+            }
+            ch if ch.is_alphabetic() || ch == '_' => {
+                let mut word = ch.to_string();
+                while let Some(&next) = chars
+                    .peek()
+                    .filter(|ch| ch.is_alphanumeric() || **ch == '_')
+                {
+                    word.push(next);
+                    chars.next();
+                }
+                tokens.push(Token::Word(word));
+            }
+            '?' => tokens.push(Token::Placeholder),
+            '!' if chars.next_if_eq(&'=').is_some() => tokens.push(Token::NotEqual),
+            '<' if chars.next_if_eq(&'>').is_some() => tokens.push(Token::NotEqual),
+            '=' | '*' | ',' | '(' | ')' | ';' => tokens.push(Token::Symbol(ch)),
+            other => return Err(SqlError(format!("unexpected character {other:?}"))),
+        }
+    }
+    Ok(tokens)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Operand {
+    Column(String),
+    Value(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CompareOp {
+    Equal,
+    NotEqual,
+    Like,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Condition {
+    Compare(Operand, CompareOp, Operand),
+    And(Box<Self>, Box<Self>),
+    Or(Box<Self>, Box<Self>),
+}
+
+#[derive(Debug)]
+struct Select {
+    columns: Option<Vec<String>>,
+    table: String,
+    filter: Option<Condition>,
+    order_by: Vec<(String, bool)>,
+    limit: Option<usize>,
+    offset: usize,
+}
+
+const KEYWORDS: [&str; 12] = [
+    "SELECT", "FROM", "WHERE", "AND", "OR", "LIKE", "ORDER", "BY", "ASC", "DESC", "LIMIT", "OFFSET",
+];
+
+/// 🌳 recursive descent parser: `?` ถูกแทนด้วยค่าจาก `params` ตรงนี้ - ค่าเป็นแค่ `Operand::Value`
+/// ไม่เคยผ่าน tokenizer จึงไม่มีทางกลายเป็น keyword หรือ quote ได้
+struct Parser<'a> {
+    tokens: Vec<Token>,
+    position: usize,
+    params: &'a [String],
+    next_param: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn keyword(&mut self, keyword: &str) -> bool {
+        let found =
+            matches!(self.peek(), Some(Token::Word(word)) if word.eq_ignore_ascii_case(keyword));
+        if found {
+            self.position += 1;
+        }
+        found
+    }
+
+    fn symbol(&mut self, symbol: char) -> bool {
+        let found = self.peek() == Some(&Token::Symbol(symbol));
+        if found {
+            self.position += 1;
+        }
+        found
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> Result<(), SqlError> {
+        if self.keyword(keyword) {
+            Ok(())
+        } else {
+            Err(self.unexpected(keyword))
+        }
+    }
+
+    fn unexpected(&self, expected: &str) -> SqlError {
+        let found = self
+            .peek()
+            .map_or_else(|| "end of input".to_string(), |token| format!("{token:?}"));
+        SqlError(format!("expected {expected}, found {found}"))
+    }
+
+    fn identifier(&mut self) -> Result<String, SqlError> {
+        match self.peek() {
+            Some(Token::Word(word)) if !KEYWORDS.iter().any(|kw| word.eq_ignore_ascii_case(kw)) => {
+                let word = word.clone();
+                self.position += 1;
+                Ok(word)
+            }
+            _ => Err(self.unexpected("identifier")),
+        }
+    }
+
+    fn number(&mut self) -> Result<usize, SqlError> {
+        if let Some(Token::Number(number)) = self.peek() {
+            let parsed = number
+                .parse()
+                .map_err(|_| SqlError(format!("number too large: {number}")));
+            self.position += 1;
+            parsed
+        } else {
+            Err(self.unexpected("number"))
+        }
+    }
+
+    fn select(&mut self) -> Result<Select, SqlError> {
+        self.expect_keyword("SELECT")?;
+        let columns = if self.symbol('*') {
+            None
+        } else {
+            let mut columns = vec![self.identifier()?];
+            while self.symbol(',') {
+                columns.push(self.identifier()?);
+            }
+            Some(columns)
+        };
+        self.expect_keyword("FROM")?;
+        let table = self.identifier()?;
+        let filter = if self.keyword("WHERE") {
+            Some(self.or_condition()?)
+        } else {
+            None
+        };
+        let mut order_by = Vec::new();
+        if self.keyword("ORDER") {
+            self.expect_keyword("BY")?;
+            loop {
+                let column = self.identifier()?;
+                let descending = self.keyword("DESC");
+                if !descending {
+                    self.keyword("ASC");
+                }
+                order_by.push((column, descending));
+                if !self.symbol(',') {
+                    break;
+                }
+            }
+        }
+        let limit = if self.keyword("LIMIT") {
+            Some(self.number()?)
+        } else {
+            None
+        };
+        let offset = if self.keyword("OFFSET") {
+            self.number()?
+        } else {
+            0
+        };
+        self.symbol(';');
+        if self.peek().is_some() {
+            // ไม่รองรับหลาย statement ในครั้งเดียว - ตัด "; DROP TABLE" ทิ้งตั้งแต่ตรงนี้
+            return Err(self.unexpected("end of statement"));
+        }
+        if self.next_param != self.params.len() {
+            return Err(SqlError(format!(
+                "{} parameters bound but query has {} placeholders",
+                self.params.len(),
+                self.next_param
+            )));
+        }
+        Ok(Select {
+            columns,
+            table,
+            filter,
+            order_by,
+            limit,
+            offset,
+        })
+    }
+
+    fn or_condition(&mut self) -> Result<Condition, SqlError> {
+        let mut condition = self.and_condition()?;
+        while self.keyword("OR") {
+            condition = Condition::Or(Box::new(condition), Box::new(self.and_condition()?));
+        }
+        Ok(condition)
+    }
+
+    fn and_condition(&mut self) -> Result<Condition, SqlError> {
+        let mut condition = self.comparison()?;
+        while self.keyword("AND") {
+            condition = Condition::And(Box::new(condition), Box::new(self.comparison()?));
+        }
+        Ok(condition)
+    }
+
+    fn comparison(&mut self) -> Result<Condition, SqlError> {
+        if self.symbol('(') {
+            let condition = self.or_condition()?;
+            return if self.symbol(')') {
+                Ok(condition)
+            } else {
+                Err(self.unexpected("')'"))
+            };
+        }
+        let left = self.operand()?;
+        let op = if self.symbol('=') {
+            CompareOp::Equal
+        } else if self.keyword("LIKE") {
+            CompareOp::Like
+        } else if self.peek() == Some(&Token::NotEqual) {
+            self.position += 1;
+            CompareOp::NotEqual
+        } else {
+            return Err(self.unexpected("comparison operator"));
+        };
+        Ok(Condition::Compare(left, op, self.operand()?))
+    }
+
+    fn operand(&mut self) -> Result<Operand, SqlError> {
+        match self.peek() {
+            Some(Token::Text(text) | Token::Number(text)) => {
+                let value = Operand::Value(text.clone());
+                self.position += 1;
+                Ok(value)
+            }
+            Some(Token::Placeholder) => {
+                let value = self.params.get(self.next_param).ok_or_else(|| {
+                    SqlError(format!(
+                        "no value bound for placeholder {}",
+                        self.next_param + 1
+                    ))
+                })?;
+                self.position += 1;
+                self.next_param += 1;
+                Ok(Operand::Value(value.clone()))
+            }
+            _ => self.identifier().map(Operand::Column),
+        }
+    }
+}
+
+/// `%` = อะไรก็ได้กี่ตัวก็ได้, `_` = หนึ่งตัวอักษร
+fn like_matches(text: &[char], pattern: &[char]) -> bool {
+    match pattern.split_first() {
+        None => text.is_empty(),
+        Some(('%', rest)) => (0..=text.len()).any(|skip| like_matches(&text[skip..], rest)),
+        Some((&expected, rest)) => text.split_first().is_some_and(|(&actual, tail)| {
+            (expected == '_' || expected == actual) && like_matches(tail, rest)
+        }),
+    }
+}
+
+impl Condition {
+    fn evaluate(&self, row: &Row) -> Result<bool, SqlError> {
+        match self {
+            Self::And(left, right) => Ok(left.evaluate(row)? && right.evaluate(row)?),
+            Self::Or(left, right) => Ok(left.evaluate(row)? || right.evaluate(row)?),
+            Self::Compare(left, op, right) => {
+                let value = |operand: &Operand| match operand {
+                    Operand::Value(value) => Ok(value.clone()),
+                    Operand::Column(column) => row
+                        .get(column)
+                        .cloned()
+                        .ok_or_else(|| SqlError(format!("no such column: {column}"))),
+                };
+                let (left, right) = (value(left)?, value(right)?);
+                Ok(match op {
+                    CompareOp::Equal => left == right,
+                    CompareOp::NotEqual => left != right,
+                    CompareOp::Like => {
+                        let text: Vec<char> = left.chars().collect();
+                        let pattern: Vec<char> = right.chars().collect();
+                        like_matches(&text, &pattern)
+                    }
+                })
+            }
+        }
+    }
+}
+
+/// 🧸 ฐานข้อมูลในหน่วยความจำที่เข้าใจ SQL ชุดเล็กๆ ที่ `QueryBuilder` สร้าง:
+/// `SELECT cols|* FROM t [WHERE ...] [ORDER BY c [ASC|DESC]] [LIMIT n] [OFFSET n]`
+///
+/// parse จริงด้วย tokenizer เหมือนฐานข้อมูลจริง - SQL injection จึงได้ผลกับมันจริงๆ
+#[derive(Debug, Clone, Default)]
+pub struct ToyDatabase {
+    tables: HashMap<String, Vec<Row>>,
+}
+
+impl ToyDatabase {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// ➕ เพิ่มตารางพร้อมข้อมูล - แต่ละแถวเรียงค่าตาม `columns`
+    #[must_use]
+    pub fn with_table(mut self, name: &str, columns: &[&str], rows: &[&[&str]]) -> Self {
+        let rows = rows
+            .iter()
+            .map(|values| {
+                columns
+                    .iter()
+                    .zip(values.iter())
+                    .map(|(column, value)| ((*column).to_string(), (*value).to_string()))
+                    .collect()
+            })
+            .collect();
+        self.tables.insert(name.to_string(), rows);
+        self
+    }
+
+    /// 📊 จำนวนแถวในตาราง (`None` ถ้าไม่มีตารางนี้)
+    #[must_use]
+    pub fn row_count(&self, table: &str) -> Option<usize> {
+        self.tables.get(table).map(Vec::len)
+    }
+
+    /// ▶️ รัน `SELECT` หนึ่ง statement - `params` แทน `?` ตามลำดับ
+    ///
+    /// # Errors
+    ///
+    /// คืน [`SqlError`] ถ้า SQL ผิดไวยากรณ์, มีหลาย statement, จำนวน parameter ไม่ตรง
+    /// หรืออ้างถึงตาราง/คอลัมน์ที่ไม่มี
+    pub fn query(&self, sql: &str, params: &[String]) -> Result<Vec<Row>, SqlError> {
+        let mut parser = Parser {
+            tokens: tokenize(sql)?,
+            position: 0,
+            params,
+            next_param: 0,
+        };
+        let select = parser.select()?;
+        let table = self
+            .tables
+            .get(&select.table)
+            .ok_or_else(|| SqlError(format!("no such table: {}", select.table)))?;
+
+        let mut rows = Vec::new();
+        for row in table {
+            if select
+                .filter
+                .as_ref()
+                .map_or(Ok(true), |filter| filter.evaluate(row))?
+            {
+                rows.push(row.clone());
+            }
+        }
+        for (column, descending) in select.order_by.iter().rev() {
+            rows.sort_by(|a, b| {
+                let ordering = a.get(column).cmp(&b.get(column));
+                if *descending {
+                    ordering.reverse()
+                } else {
+                    ordering
+                }
+            });
+        }
+        let rows = rows
+            .into_iter()
+            .skip(select.offset)
+            .take(select.limit.unwrap_or(usize::MAX));
+        Ok(match &select.columns {
+            None => rows.collect(),
+            Some(columns) => rows
+                .map(|row| {
+                    columns
+                        .iter()
+                        .map(|column| {
+                            row.get(column)
+                                .map(|value| (column.clone(), value.clone()))
+                                .ok_or_else(|| SqlError(format!("no such column: {column}")))
+                        })
+                        .collect()
+                })
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/// 🧸 ฐานข้อมูลตัวอย่างสำหรับ demo และ test
+#[must_use]
+pub fn demo_accounts() -> ToyDatabase {
+    ToyDatabase::new().with_table(
+        "accounts",
+        &["username", "password", "role"],
+        &[
+            &["admin", "correct-horse", "admin"],
+            &["somchai", "password123", "user"],
+            &["somying", "mypassword", "user"],
+        ],
+    )
+}
+
+/// 😱 login แบบต่อ string: ค่าจากฟอร์มกลายเป็นส่วนหนึ่งของ SQL
+///
+/// # Errors
+///
+/// คืน [`SqlError`] ถ้า input ทำให้ SQL ผิดไวยากรณ์
+pub fn login_naive(db: &ToyDatabase, username: &str, password: &str) -> Result<Vec<Row>, SqlError> {
+    let sql = QueryBuilder::<Row>::new("accounts")
+        .where_eq("username", username)
+        .where_eq("password", password)
+        .to_sql();
+    db.query(&sql, &[])
+}
+
+/// ✅ login แบบ parameter binding: SQL มีแค่ `?` ค่าถูกส่งแยกไปอีกทาง
+///
+/// # Errors
+///
+/// คืน [`SqlError`] เฉพาะเมื่อเอนจินมีปัญหา - input ใดๆ ก็ไม่ทำให้ SQL เปลี่ยนรูป
+pub fn login_bound(db: &ToyDatabase, username: &str, password: &str) -> Result<Vec<Row>, SqlError> {
+    let query = QueryBuilder::<Row>::new("accounts")
+        .where_bind("username", username)
+        .where_bind("password", password);
+    db.query(&query.to_sql(), query.params())
+}
+
+/// 🚀 สาธิต SQL injection, XSS และ path traversal พร้อมวิธีป้องกัน
+pub fn demonstrate_sanitization() {
+    println!("🧽 Input Sanitization - ข้อมูลต้องเป็นแค่ข้อมูล ไม่ใช่คำสั่ง");
+
+    println!("\n💉 SQL injection กับ ToyDatabase:");
+    let db = demo_accounts();
+    for (username, password) in [
+        ("somchai", "password123"),
+        ("somchai", "' OR '1'='1"),
+        ("admin' --", "no-idea"),
+        ("x", "'; DROP TABLE accounts; --"),
+    ] {
+        let describe = |result: Result<Vec<Row>, SqlError>| match result {
+            Ok(rows) if rows.is_empty() => "ไม่พบบัญชี ✅".to_string(),
+            Ok(rows) => format!(
+                "เข้าได้ในฐานะ {:?}",
+                rows.iter()
+                    .map(|row| row["username"].as_str())
+                    .collect::<Vec<_>>()
+            ),
+            Err(error) => format!("{error}"),
+        };
+        println!("   🔑 username={username:?} password={password:?}");
+        println!(
+            "      ต่อ string : {}",
+            describe(login_naive(&db, username, password))
+        );
+        println!(
+            "      bind ค่า   : {}",
+            describe(login_bound(&db, username, password))
+        );
+    }
+
+    println!("\n🏷️ XSS - escape ก่อนวางลง HTML:");
+    let comment = "<img src=x onerror=\"alert('pwned')\">";
+    println!("   ค่าดิบ  : {comment}");
+    println!("   escape : {}", escape_html(comment));
+
+    println!("\n📂 Path traversal - ตรวจ URL path ก่อนต่อกับ root:");
+    for url_path in [
+        "/docs/guide.txt",
+        "/../etc/passwd",
+        "/%2e%2e/%2e%2e/etc/passwd",
+        "/..%2fsecret",
+        "/.env",
+    ] {
+        match safe_relative_path(url_path) {
+            Ok(path) => println!("   ✅ {url_path} → {}", path.display()),
+            Err(violation) => println!("   🚫 {url_path} → {violation}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::web_development::static_files::StaticFiles;
+    use crate::web_development::templating::{TemplateContext, TemplateEngine};
+    use crate::web_development::web_server::WebServer;
+    use crate::web_development::{HttpRequest, HttpStatus};
+    use std::fs;
+
+    fn usernames(rows: &[Row]) -> Vec<&str> {
+        rows.iter().map(|row| row["username"].as_str()).collect()
+    }
+
+    #[test]
+    fn test_sql_injection_attacks_only_work_on_string_concatenation() {
+        let db = demo_accounts();
+        assert_eq!(
+            usernames(&login_naive(&db, "somchai", "password123").unwrap()),
+            ["somchai"]
+        );
+        assert_eq!(
+            usernames(&login_bound(&db, "somchai", "password123").unwrap()),
+            ["somchai"]
+        );
+
+        // tautology: password ' OR '1'='1 ทำให้ WHERE เป็นจริงทุกแถว
+        let dumped = login_naive(&db, "somchai", "' OR '1'='1").unwrap();
+        assert_eq!(usernames(&dumped), ["admin", "somchai", "somying"]);
+        assert!(
+            login_bound(&db, "somchai", "' OR '1'='1")
+                .unwrap()
+                .is_empty()
+        );
+
+        // comment: admin' -- ตัดเงื่อนไข password ทิ้ง
+        let bypass = login_naive(&db, "admin' --", "wrong").unwrap();
+        assert_eq!(usernames(&bypass), ["admin"]);
+        assert!(login_bound(&db, "admin' --", "wrong").unwrap().is_empty());
+
+        // stacked query: เอนจินปฏิเสธหลาย statement แต่ input ก็ยังพัง SQL ได้
+        assert!(login_naive(&db, "x", "'; DROP TABLE accounts; --").is_err());
+        assert!(
+            login_bound(&db, "x", "'; DROP TABLE accounts; --")
+                .unwrap()
+                .is_empty()
+        );
+        assert_eq!(db.row_count("accounts"), Some(3));
+
+        // ค่าที่มี ' จริงๆ (เช่นชื่อ O'Brien) ค้นหาได้ปกติเมื่อ bind
+        let db = db.with_table("accounts", &["username", "password"], &[&["o'brien", "pw"]]);
+        assert_eq!(
+            usernames(&login_bound(&db, "o'brien", "pw").unwrap()),
+            ["o'brien"]
+        );
+        assert!(login_naive(&db, "o'brien", "pw").is_err());
+    }
+
+    #[test]
+    fn test_toy_engine_runs_query_builder_sql() {
+        let db = demo_accounts();
+        let query = QueryBuilder::<Row>::new("accounts")
+            .where_like("username", "som")
+            .order_by("username", "DESC")
+            .limit(1);
+        assert_eq!(
+            usernames(&db.query(&query.to_sql(), &[]).unwrap()),
+            ["somying"]
+        );
+
+        let rows = db
+            .query(
+                "SELECT username FROM accounts WHERE (role = ? OR role = 'x') AND username != 'somying' OFFSET 1",
+                &["user".to_string()],
+            )
+            .unwrap();
+        assert_eq!(rows.len(), 0);
+        let rows = db
+            .query("select username from accounts where role <> 'admin'", &[])
+            .unwrap();
+        assert_eq!(usernames(&rows), ["somchai", "somying"]);
+        assert_eq!(rows[0].len(), 1, "คืนเฉพาะคอลัมน์ที่เลือก");
+
+        for (sql, params) in [
+            ("SELECT * FROM missing", &[][..]),
+            ("SELECT * FROM accounts WHERE nope = 'x'", &[]),
+            ("SELECT * FROM accounts WHERE username = ?", &[]),
+            ("SELECT * FROM accounts", &["extra".to_string()]),
+            ("SELECT * FROM accounts WHERE username = 'open", &[]),
+            ("SELECT * FROM accounts; SELECT * FROM accounts", &[]),
+        ] {
+            assert!(db.query(sql, params).is_err(), "{sql}");
+        }
+    }
+
+    #[test]
+    fn test_template_escapes_xss_payloads() {
+        assert_eq!(
+            escape_html(r#"<a href="x" title='y'>&{{z}}</a>"#),
+            "&lt;a href=&quot;x&quot; title=&#39;y&#39;&gt;&amp;&#123;&#123;z&#125;&#125;&lt;/a&gt;"
+        );
+
+        let engine = TemplateEngine::new();
+        let mut context = TemplateContext::new();
+        context.set("name", "<script>alert(document.cookie)</script>");
+        context.set("bio", "\" onmouseover=\"alert(1)");
+        context.set("secret", "api-key-123");
+        context.set("trusted", "<b>bold</b>");
+        context.set_array("tags", vec!["{{secret}}".to_string(), "<i>".to_string()]);
+
+        let html = engine
+            .render_string(
+                r#"<h1>{{name}}</h1><p title="{{bio}}">{{upper name}}</p>{{#each tags}}[{{this}}]{{/each}}{{{trusted}}}"#,
+                &context,
+            )
+            .unwrap();
+        assert!(!html.contains("<script>"), "{html}");
+        assert!(!html.contains("<SCRIPT>"), "{html}");
+        assert!(
+            html.contains(r#"title="&quot; onmouseover=&quot;alert(1)""#),
+            "{html}"
+        );
+        // ค่าของผู้ใช้ที่หน้าตาเหมือน template ต้องไม่ถูกขยายเป็นความลับ
+        assert!(!html.contains("api-key-123"), "{html}");
+        assert!(
+            html.contains("[&#123;&#123;secret&#125;&#125;][&lt;i&gt;]"),
+            "{html}"
+        );
+        // {{{...}}} สำหรับ HTML ที่เชื่อถือได้เท่านั้น
+        assert!(html.ends_with("<b>bold</b>"), "{html}");
+    }
+
+    #[test]
+    fn test_safe_relative_path_reports_violation() {
+        assert_eq!(
+            safe_relative_path("/docs/./guide.txt"),
+            Ok(PathBuf::from("docs/guide.txt"))
+        );
+        assert_eq!(safe_relative_path("/"), Ok(PathBuf::new()));
+        for (url_path, violation) in [
+            ("/../etc/passwd", PathViolation::ParentDirectory),
+            ("/a/%2E%2E/%2e%2e/b", PathViolation::ParentDirectory),
+            ("/..%2fsecret", PathViolation::ForbiddenCharacter('/')),
+            ("/..%5Csecret", PathViolation::ForbiddenCharacter('\\')),
+            ("/file%00.txt", PathViolation::ForbiddenCharacter('\0')),
+            (
+                "/.git/config",
+                PathViolation::HiddenFile(".git".to_string()),
+            ),
+        ] {
+            assert_eq!(safe_relative_path(url_path), Err(violation), "{url_path}");
+        }
+        // double encoding ถูก decode แค่ชั้นเดียว - กลายเป็นชื่อไฟล์ธรรมดาที่ไม่มีอยู่จริง
+        assert_eq!(
+            safe_relative_path("/%252e%252e/secret"),
+            Ok(PathBuf::from("%2e%2e/secret"))
+        );
+    }
+
+    #[test]
+    fn test_static_file_server_resists_traversal_attacks() {
+        let dir = tempfile::tempdir().unwrap();
+        let public = dir.path().join("public");
+        fs::create_dir_all(&public).unwrap();
+        fs::write(public.join("hello.txt"), "hello").unwrap();
+        fs::write(public.join(".env"), "DB_PASSWORD=hunter2").unwrap();
+        fs::write(dir.path().join("secret.txt"), "top secret").unwrap();
+        let files = StaticFiles::new(&public).unwrap();
+        let mut server = WebServer::new();
+        server.route("GET /static/*", files.handler("/static"));
+
+        let ok = server.handle_request(&HttpRequest::new("GET", "/static/hello.txt"));
+        assert_eq!((ok.status, ok.body.as_str()), (HttpStatus::Ok, "hello"));
+        for attack in [
+            "/static/../secret.txt",
+            "/static/%2e%2e/secret.txt",
+            "/static/%2E%2E%2Fsecret.txt",
+            "/static/..%5csecret.txt",
+            "/static/%252e%252e/secret.txt",
+            "/static/hello.txt%00.png",
+            "/static/.env",
+            "/static/%2eenv",
+            "/static//../../secret.txt",
+        ] {
+            let response = server.handle_request(&HttpRequest::new("GET", attack));
+            assert_eq!(response.status, HttpStatus::NotFound, "{attack}");
+            assert!(!response.body.contains("secret"), "{attack}");
+            assert!(!response.body.contains("hunter2"), "{attack}");
+        }
+    }
+}
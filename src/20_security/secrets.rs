@@ -0,0 +1,749 @@
+//! 🗝️ Secrets Management - เก็บความลับไว้ในไฟล์ที่เข้ารหัสด้วย passphrase
+//!
+//! ไฟล์ vault เป็น JSON ที่เปิดอ่านได้ แต่ความลับทั้งหมดอยู่ใน `ciphertext`:
+//! - 🧂 PBKDF2-HMAC-SHA256 แปลง passphrase + salt เป็นกุญแจ 64 byte (เข้ารหัส 32 + MAC 32)
+//! - 🔐 AEAD แบบ encrypt-then-MAC: `ChaCha20` (RFC 8439) เข้ารหัส แล้ว HMAC-SHA256 ครอบ header, nonce และ ciphertext
+//! - 🧹 `Zeroizing<T>` เขียนศูนย์ทับความลับใน RAM ทันทีที่ค่าถูก drop
+//!
+//! ⚠️ เขียนเพื่อเรียน: งานจริงใช้ crate `chacha20poly1305`, `argon2` และ `zeroize`
+
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::ops::{Deref, DerefMut};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{Ordering, compiler_fence};
+
+use base64::{Engine, engine::general_purpose::STANDARD};
+use hmac::{Hmac, Mac};
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+use crate::random::chacha20_block;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// 🧹 ค่าที่เขียนศูนย์ทับตัวเองได้
+pub trait Zeroize {
+    fn zeroize(&mut self);
+}
+
+impl<const N: usize> Zeroize for [u8; N] {
+    fn zeroize(&mut self) {
+        for byte in self.iter_mut() {
+            // SAFETY: `byte` เป็น &mut u8 ที่ valid - volatile กันไม่ให้ compiler ตัด store ที่ "ไม่มีใครอ่านต่อ" ทิ้ง
+            unsafe { std::ptr::write_volatile(byte, 0) };
+        }
+        compiler_fence(Ordering::SeqCst);
+    }
+}
+
+impl Zeroize for Vec<u8> {
+    /// ล้างทั้ง capacity ไม่ใช่แค่ `len` - byte ที่เคย `truncate` ทิ้งไปก็ยังค้างอยู่ใน buffer
+    fn zeroize(&mut self) {
+        self.clear();
+        for slot in self.spare_capacity_mut() {
+            // SAFETY: pointer ชี้ไปยัง slot ใน allocation ของ Vec เอง - เขียน u8 ได้เสมอ
+            unsafe { slot.as_mut_ptr().write_volatile(0) };
+        }
+        compiler_fence(Ordering::SeqCst);
+    }
+}
+
+impl Zeroize for String {
+    fn zeroize(&mut self) {
+        // SAFETY: Vec ถูกล้างจนว่างก่อนคืนให้ String - ไม่มีทางเหลือ UTF-8 ที่ผิด
+        unsafe { self.as_mut_vec() }.zeroize();
+    }
+}
+
+/// 🧤 wrapper ที่ zeroize ค่าข้างในตอน drop และไม่ยอมพิมพ์ค่าออกมาใน `Debug`
+///
+/// ⚠️ กันได้แค่ copy ที่อยู่ใน wrapper: ถ้า `String` โตจน reallocate buffer เก่าจะไม่ถูกล้าง
+#[derive(Clone, PartialEq, Eq)]
+pub struct Zeroizing<T: Zeroize>(T);
+
+impl<T: Zeroize> Zeroizing<T> {
+    pub const fn new(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T: Zeroize> From<T> for Zeroizing<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T: Zeroize> Deref for Zeroizing<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: Zeroize> DerefMut for Zeroizing<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T: Zeroize> Drop for Zeroizing<T> {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<T: Zeroize> fmt::Debug for Zeroizing<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Zeroizing(<redacted>)")
+    }
+}
+
+/// 🧂 PBKDF2-HMAC-SHA256 (RFC 8018): block ที่ i = U1 ^ U2 ^ ... ^ Uc โดย U1 = HMAC(P, salt || i)
+///
+/// `iterations` ยิ่งมากยิ่งทำให้การเดา passphrase แบบ offline ช้าลง (0 ถือเป็น 1 รอบ)
+///
+/// # Panics
+///
+/// panic ถ้า `output` ยาวเกิน 2^32 block (ตาม RFC 8018)
+pub fn pbkdf2_hmac_sha256(password: &[u8], salt: &[u8], iterations: u32, output: &mut [u8]) {
+    let prf = HmacSha256::new_from_slice(password).expect("HMAC accepts keys of any length");
+    for (index, chunk) in output.chunks_mut(32).enumerate() {
+        let block_index = u32::try_from(index + 1).expect("PBKDF2 output limited to 2^32 blocks");
+        let mut mac = prf.clone();
+        mac.update(salt);
+        mac.update(&block_index.to_be_bytes());
+        let mut u = mac.finalize().into_bytes();
+        let mut block = u;
+        for _ in 1..iterations {
+            let mut mac = prf.clone();
+            mac.update(&u);
+            u = mac.finalize().into_bytes();
+            for (acc, byte) in block.iter_mut().zip(&u) {
+                *acc ^= byte;
+            }
+        }
+        chunk.copy_from_slice(&block[..chunk.len()]);
+    }
+}
+
+/// "expand 32-byte k" แบบ little-endian (ค่าเดียวกับใน `random::chacha`)
+const CHACHA_CONSTANTS: [u32; 4] = [0x6170_7865, 0x3320_646e, 0x7962_2d32, 0x6b20_6574];
+
+/// 🔐 AEAD แบบ encrypt-then-MAC: `ChaCha20` เข้ารหัส แล้ว HMAC-SHA256 ครอบ
+/// `aad || nonce || ciphertext || len(aad) || len(ciphertext)`
+///
+/// ถอดรหัสเฉพาะเมื่อ tag ถูกต้องเท่านั้น - แก้ byte ไหนก็ตาม (รวมถึง `aad`) จะเปิดไม่ออก
+pub struct Aead {
+    key: Zeroizing<[u8; 64]>,
+}
+
+impl fmt::Debug for Aead {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Aead").finish_non_exhaustive()
+    }
+}
+
+impl Aead {
+    pub const NONCE_LEN: usize = 12;
+    pub const TAG_LEN: usize = 32;
+
+    /// กุญแจ 64 byte: 32 byte แรกสำหรับ `ChaCha20`, 32 byte หลังสำหรับ HMAC
+    #[must_use]
+    pub const fn new(key: [u8; 64]) -> Self {
+        Self {
+            key: Zeroizing::new(key),
+        }
+    }
+
+    /// 🔒 เข้ารหัส `plaintext` คืน `ciphertext || tag` - ห้ามใช้ nonce ซ้ำกับกุญแจเดิม
+    #[must_use]
+    pub fn seal(&self, nonce: &[u8; Self::NONCE_LEN], aad: &[u8], plaintext: &[u8]) -> Vec<u8> {
+        let mut sealed = Vec::with_capacity(plaintext.len() + Self::TAG_LEN);
+        sealed.extend_from_slice(plaintext);
+        self.apply_keystream(nonce, &mut sealed);
+        let tag = self.tag(nonce, aad, &sealed).finalize().into_bytes();
+        sealed.extend_from_slice(&tag);
+        sealed
+    }
+
+    /// 🔓 ตรวจ tag (แบบ constant-time) ก่อน แล้วจึงถอดรหัส
+    ///
+    /// # Errors
+    ///
+    /// คืน [`SecretsError::Decryption`] ถ้ากุญแจผิด หรือ `aad`/`sealed` ถูกแก้
+    pub fn open(
+        &self,
+        nonce: &[u8; Self::NONCE_LEN],
+        aad: &[u8],
+        sealed: &[u8],
+    ) -> Result<Zeroizing<Vec<u8>>, SecretsError> {
+        let split = sealed
+            .len()
+            .checked_sub(Self::TAG_LEN)
+            .ok_or(SecretsError::Decryption)?;
+        let (ciphertext, tag) = sealed.split_at(split);
+        self.tag(nonce, aad, ciphertext)
+            .verify_slice(tag)
+            .map_err(|_| SecretsError::Decryption)?;
+        let mut plaintext = Zeroizing::new(ciphertext.to_vec());
+        self.apply_keystream(nonce, &mut plaintext);
+        Ok(plaintext)
+    }
+
+    fn tag(&self, nonce: &[u8], aad: &[u8], ciphertext: &[u8]) -> HmacSha256 {
+        let mut mac =
+            HmacSha256::new_from_slice(&self.key[32..]).expect("HMAC accepts keys of any length");
+        mac.update(aad);
+        mac.update(nonce);
+        mac.update(ciphertext);
+        // ใส่ความยาวปิดท้าย เพื่อไม่ให้ย้าย byte ข้ามเส้นแบ่ง aad/ciphertext ได้
+        mac.update(&(aad.len() as u64).to_le_bytes());
+        mac.update(&(ciphertext.len() as u64).to_le_bytes());
+        mac
+    }
+
+    /// state แบบ IETF: word 12 เป็น block counter (เริ่มที่ 1) และ word 13-15 เป็น nonce
+    fn apply_keystream(&self, nonce: &[u8; Self::NONCE_LEN], data: &mut [u8]) {
+        let mut input = [0_u32; 16];
+        input[..4].copy_from_slice(&CHACHA_CONSTANTS);
+        let words = self.key[..32].chunks_exact(4).chain([&[1, 0, 0, 0][..]]);
+        for (word, bytes) in input[4..]
+            .iter_mut()
+            .zip(words.chain(nonce.chunks_exact(4)))
+        {
+            *word = u32::from_le_bytes(bytes.try_into().expect("4-byte chunk"));
+        }
+        for chunk in data.chunks_mut(64) {
+            let keystream = chacha20_block(&input)
+                .into_iter()
+                .flat_map(u32::to_le_bytes);
+            for (byte, key_byte) in chunk.iter_mut().zip(keystream) {
+                *byte ^= key_byte;
+            }
+            input[12] = input[12].wrapping_add(1);
+        }
+    }
+}
+
+/// ❌ ข้อผิดพลาดของ vault
+#[derive(Debug)]
+pub enum SecretsError {
+    /// อ่าน/เขียนไฟล์ vault ไม่ได้
+    Io { path: PathBuf, source: io::Error },
+    /// ไฟล์ไม่ใช่ vault ที่รู้จัก (JSON เสีย, base64 ผิด, version ไม่รองรับ)
+    Format(String),
+    /// passphrase ผิด หรือไฟล์ถูกแก้ - AEAD แยกสองกรณีนี้ไม่ได้ (และไม่ควรบอกผู้โจมตีด้วย)
+    Decryption,
+}
+
+impl fmt::Display for SecretsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io { path, source } => write!(f, "cannot access {}: {source}", path.display()),
+            Self::Format(message) => write!(f, "invalid vault file: {message}"),
+            Self::Decryption => f.write_str("wrong passphrase or vault has been tampered with"),
+        }
+    }
+}
+
+impl Error for SecretsError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// 📄 รูปแบบไฟล์บนดิสก์ - ทุกช่องนอกจาก `nonce`/`ciphertext` เป็น associated data ที่ถูก MAC ด้วย
+#[derive(Debug, Serialize, Deserialize)]
+struct VaultFile {
+    version: u32,
+    kdf: String,
+    iterations: u32,
+    salt: String,
+    nonce: String,
+    ciphertext: String,
+}
+
+impl VaultFile {
+    const VERSION: u32 = 1;
+    const KDF: &str = "pbkdf2-hmac-sha256";
+
+    fn associated_data(&self) -> Vec<u8> {
+        format!(
+            "{}|{}|{}|{}",
+            self.version, self.kdf, self.iterations, self.salt
+        )
+        .into_bytes()
+    }
+}
+
+fn decode_field<const N: usize>(name: &str, value: &str) -> Result<[u8; N], SecretsError> {
+    STANDARD
+        .decode(value)
+        .ok()
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| SecretsError::Format(format!("`{name}` must be {N} bytes of base64")))
+}
+
+/// 🗄️ vault ของความลับ: key → value ที่เข้ารหัสทั้งก้อนลงไฟล์เดียว
+///
+/// ทุกครั้งที่ `set`/`remove`/`rotate` vault จะเขียนไฟล์ใหม่ทันทีด้วย nonce ใหม่
+/// (เขียนลงไฟล์ชั่วคราวแล้ว rename - ไฟล์เดิมไม่มีทางค้างครึ่งๆ กลางๆ)
+pub struct SecretsVault {
+    path: PathBuf,
+    iterations: u32,
+    salt: [u8; 16],
+    cipher: Aead,
+    secrets: BTreeMap<String, Zeroizing<String>>,
+}
+
+/// ไม่พิมพ์ค่าความลับ - บอกแค่ชื่อ
+impl fmt::Debug for SecretsVault {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SecretsVault")
+            .field("path", &self.path)
+            .field("secrets", &self.secrets.keys().collect::<Vec<_>>())
+            .finish_non_exhaustive()
+    }
+}
+
+impl SecretsVault {
+    /// จำนวนรอบที่ OWASP แนะนำสำหรับ PBKDF2-HMAC-SHA256
+    pub const DEFAULT_ITERATIONS: u32 = 600_000;
+
+    /// 🆕 สร้าง vault ว่างที่ `path` ด้วย [`Self::DEFAULT_ITERATIONS`]
+    ///
+    /// # Errors
+    ///
+    /// คืน [`SecretsError::Io`] ถ้ามีไฟล์อยู่แล้วหรือเขียนไฟล์ไม่ได้
+    pub fn create(path: impl AsRef<Path>, passphrase: &str) -> Result<Self, SecretsError> {
+        Self::create_with_iterations(path, passphrase, Self::DEFAULT_ITERATIONS)
+    }
+
+    /// 🆕 เหมือน [`Self::create`] แต่เลือกจำนวนรอบของ PBKDF2 เอง (เช่นลดลงใน test)
+    ///
+    /// # Errors
+    ///
+    /// คืน [`SecretsError::Io`] ถ้ามีไฟล์อยู่แล้วหรือเขียนไฟล์ไม่ได้
+    pub fn create_with_iterations(
+        path: impl AsRef<Path>,
+        passphrase: &str,
+        iterations: u32,
+    ) -> Result<Self, SecretsError> {
+        let path = path.as_ref().to_path_buf();
+        if path.exists() {
+            return Err(SecretsError::Io {
+                source: io::Error::new(io::ErrorKind::AlreadyExists, "vault already exists"),
+                path,
+            });
+        }
+        let salt = random_bytes();
+        let vault = Self {
+            cipher: derive_cipher(passphrase, &salt, iterations),
+            path,
+            iterations,
+            salt,
+            secrets: BTreeMap::new(),
+        };
+        vault.save()?;
+        Ok(vault)
+    }
+
+    /// 🔓 เปิด vault ที่มีอยู่แล้วและถอดรหัสความลับทั้งหมดเข้า memory
+    ///
+    /// # Errors
+    ///
+    /// - [`SecretsError::Io`] ถ้าอ่านไฟล์ไม่ได้
+    /// - [`SecretsError::Format`] ถ้าไฟล์ไม่ใช่ vault
+    /// - [`SecretsError::Decryption`] ถ้า passphrase ผิดหรือไฟล์ถูกแก้
+    pub fn open(path: impl AsRef<Path>, passphrase: &str) -> Result<Self, SecretsError> {
+        let path = path.as_ref().to_path_buf();
+        let contents = fs::read(&path).map_err(|source| SecretsError::Io {
+            path: path.clone(),
+            source,
+        })?;
+        let file: VaultFile = serde_json::from_slice(&contents)
+            .map_err(|error| SecretsError::Format(error.to_string()))?;
+        if file.version != VaultFile::VERSION || file.kdf != VaultFile::KDF {
+            return Err(SecretsError::Format(format!(
+                "unsupported vault version {} ({})",
+                file.version, file.kdf
+            )));
+        }
+        let salt = decode_field("salt", &file.salt)?;
+        let nonce = decode_field("nonce", &file.nonce)?;
+        let sealed = STANDARD
+            .decode(&file.ciphertext)
+            .map_err(|error| SecretsError::Format(format!("`ciphertext`: {error}")))?;
+
+        let cipher = derive_cipher(passphrase, &salt, file.iterations);
+        let plaintext = cipher.open(&nonce, &file.associated_data(), &sealed)?;
+        // tag ผ่านแล้วแต่ JSON ข้างในเสีย = ไฟล์ถูกสร้างผิดตั้งแต่ต้น ไม่ใช่ถูกแก้
+        let secrets: BTreeMap<String, String> = serde_json::from_slice(&plaintext)
+            .map_err(|error| SecretsError::Format(error.to_string()))?;
+        Ok(Self {
+            path,
+            iterations: file.iterations,
+            salt,
+            cipher,
+            secrets: secrets
+                .into_iter()
+                .map(|(name, value)| (name, Zeroizing::new(value)))
+                .collect(),
+        })
+    }
+
+    /// 📍 ไฟล์ที่ vault นี้ใช้
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// 🔍 อ่านความลับ - ค่าที่คืนยืมจาก vault จึงถูกล้างพร้อม vault
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.secrets.get(name).map(|value| value.as_str())
+    }
+
+    /// 📋 ชื่อความลับทั้งหมด (เรียงตามตัวอักษร)
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.secrets.keys().map(String::as_str)
+    }
+
+    /// ✏️ ตั้งค่าความลับแล้วบันทึกทันที - ค่าเก่า (ถ้ามี) ถูก zeroize
+    ///
+    /// # Errors
+    ///
+    /// คืน [`SecretsError::Io`] ถ้าเขียนไฟล์ไม่ได้ (ค่าใน memory ถูกเปลี่ยนไปแล้ว)
+    pub fn set(&mut self, name: &str, value: impl Into<String>) -> Result<(), SecretsError> {
+        self.secrets
+            .insert(name.to_string(), Zeroizing::new(value.into()));
+        self.save()
+    }
+
+    /// 🗑️ ลบความลับแล้วบันทึกทันที คืน `false` ถ้าไม่มีชื่อนี้
+    ///
+    /// # Errors
+    ///
+    /// คืน [`SecretsError::Io`] ถ้าเขียนไฟล์ไม่ได้
+    pub fn remove(&mut self, name: &str) -> Result<bool, SecretsError> {
+        if self.secrets.remove(name).is_none() {
+            return Ok(false);
+        }
+        self.save().map(|()| true)
+    }
+
+    /// 🔄 เปลี่ยน passphrase: สุ่ม salt ใหม่ derive กุญแจใหม่ แล้วเข้ารหัสทั้ง vault ใหม่
+    ///
+    /// passphrase เก่าเปิดไฟล์ไม่ได้อีกต่อไป
+    ///
+    /// # Errors
+    ///
+    /// คืน [`SecretsError::Io`] ถ้าเขียนไฟล์ไม่ได้ (vault ใน memory ยังใช้กุญแจเดิม)
+    pub fn rotate(&mut self, new_passphrase: &str) -> Result<(), SecretsError> {
+        let salt = random_bytes();
+        let cipher = derive_cipher(new_passphrase, &salt, self.iterations);
+        let previous_salt = std::mem::replace(&mut self.salt, salt);
+        let previous_cipher = std::mem::replace(&mut self.cipher, cipher);
+        self.save().inspect_err(|_| {
+            self.salt = previous_salt;
+            self.cipher = previous_cipher;
+        })
+    }
+
+    fn save(&self) -> Result<(), SecretsError> {
+        let secrets: BTreeMap<&str, &str> = self
+            .secrets
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+            .collect();
+        let plaintext = Zeroizing::new(
+            serde_json::to_vec(&secrets)
+                .map_err(|error| SecretsError::Format(error.to_string()))?,
+        );
+        let nonce = random_bytes();
+        let mut file = VaultFile {
+            version: VaultFile::VERSION,
+            kdf: VaultFile::KDF.to_string(),
+            iterations: self.iterations,
+            salt: STANDARD.encode(self.salt),
+            nonce: STANDARD.encode(nonce),
+            ciphertext: String::new(),
+        };
+        let sealed = self
+            .cipher
+            .seal(&nonce, &file.associated_data(), &plaintext);
+        file.ciphertext = STANDARD.encode(sealed);
+
+        let json = serde_json::to_vec_pretty(&file)
+            .map_err(|error| SecretsError::Format(error.to_string()))?;
+        let temporary = self.path.with_extension("tmp");
+        fs::write(&temporary, json)
+            .and_then(|()| fs::rename(&temporary, &self.path))
+            .map_err(|source| SecretsError::Io {
+                path: self.path.clone(),
+                source,
+            })
+    }
+}
+
+fn random_bytes<const N: usize>() -> [u8; N] {
+    let mut bytes = [0; N];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    bytes
+}
+
+fn derive_cipher(passphrase: &str, salt: &[u8], iterations: u32) -> Aead {
+    let mut key = Zeroizing::new([0; 64]);
+    pbkdf2_hmac_sha256(passphrase.as_bytes(), salt, iterations, &mut *key);
+    Aead::new(*key)
+}
+
+/// 🚀 สาธิต vault: สร้าง เก็บ เปิดใหม่ เปลี่ยน passphrase และลองแก้ไฟล์
+///
+/// # Panics
+///
+/// panic ถ้าสร้างหรือเขียนไฟล์ในโฟลเดอร์ชั่วคราวไม่ได้
+pub fn demonstrate_secrets() {
+    println!("🗝️ Secrets Vault - ไฟล์ความลับที่เข้ารหัสด้วย passphrase");
+
+    let dir = tempfile::tempdir().expect("temporary directory");
+    let path = dir.path().join("secrets.vault.json");
+    // ลดรอบ PBKDF2 ให้ demo เร็ว - ของจริงใช้ DEFAULT_ITERATIONS
+    let mut vault =
+        SecretsVault::create_with_iterations(&path, "correct horse battery staple", 10_000)
+            .expect("create vault");
+    vault
+        .set("database_url", "postgres://app:pa55@db/app")
+        .expect("save secret");
+    vault
+        .set("stripe_key", "sk_test_4eC39HqLyjWDarjtT1zdp7dc")
+        .expect("save secret");
+    println!("   📦 เก็บแล้ว: {:?}", vault.names().collect::<Vec<_>>());
+    println!("   🙈 Debug ไม่เผยค่า: {vault:?}");
+
+    let on_disk = fs::read_to_string(&path).expect("read vault");
+    println!(
+        "   📄 ไฟล์บนดิสก์มีคำว่า pa55 ไหม? {}",
+        if on_disk.contains("pa55") {
+            "มี 😱"
+        } else {
+            "ไม่มี ✅"
+        }
+    );
+
+    let reopened = SecretsVault::open(&path, "correct horse battery staple").expect("open vault");
+    println!(
+        "   🔓 เปิดใหม่ได้ database_url = {:?}",
+        reopened.get("database_url")
+    );
+    match SecretsVault::open(&path, "Tr0ub4dor&3") {
+        Ok(_) => println!("   😱 passphrase ผิดแต่เปิดได้!"),
+        Err(error) => println!("   🚫 passphrase ผิด: {error}"),
+    }
+
+    vault.rotate("a brand new passphrase").expect("rotate");
+    println!(
+        "   🔄 หลัง rotate passphrase เก่าเปิดได้ไหม? {}",
+        SecretsVault::open(&path, "correct horse battery staple").is_ok()
+    );
+
+    let tampered = on_disk.replacen("\"iterations\": 10000", "\"iterations\": 1", 1);
+    fs::write(&path, tampered).expect("write vault");
+    match SecretsVault::open(&path, "correct horse battery staple") {
+        Ok(_) => println!("   😱 ลดรอบ PBKDF2 แล้วยังเปิดได้!"),
+        Err(error) => println!("   🛡️ แอบลด iterations ในไฟล์: {error}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PASSPHRASE: &str = "correct horse battery staple";
+
+    fn vault_in(dir: &tempfile::TempDir) -> SecretsVault {
+        let mut vault =
+            SecretsVault::create_with_iterations(dir.path().join("app.vault"), PASSPHRASE, 1_000)
+                .unwrap();
+        vault.set("api_token", "tok_live_123").unwrap();
+        vault.set("db_password", "hunter2").unwrap();
+        vault
+    }
+
+    fn edit_file(path: &Path, edit: impl FnOnce(&mut serde_json::Value)) {
+        let mut json: serde_json::Value = serde_json::from_slice(&fs::read(path).unwrap()).unwrap();
+        edit(&mut json);
+        fs::write(path, serde_json::to_vec(&json).unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_primitives_match_reference_vectors() {
+        // RFC 7914 ข้อ 11
+        let mut derived = [0; 64];
+        pbkdf2_hmac_sha256(b"passwd", b"salt", 1, &mut derived);
+        assert_eq!(
+            hex::encode(derived),
+            "55ac046e56e3089fec1691c22544b605f94185216dde0465e68b9d57c20dacbc\
+             49ca9cccf179b645991664b39d77ef317c71b845b1e30bd509112041d3a19783"
+        );
+
+        // RFC 8439 ข้อ 2.4.2: ciphertext ก่อน tag คือ ChaCha20 ล้วนๆ
+        let mut key = [0; 64];
+        for (byte, value) in key.iter_mut().zip(0..32) {
+            *byte = value;
+        }
+        let aead = Aead::new(key);
+        let nonce = [0, 0, 0, 0, 0, 0, 0, 0x4a, 0, 0, 0, 0];
+        let plaintext = b"Ladies and Gentlemen of the class of '99: If I could offer you only one tip for the future, sunscreen would be it.";
+        let sealed = aead.seal(&nonce, b"", plaintext);
+        assert_eq!(
+            hex::encode(&sealed[..32]),
+            "6e2e359a2568f98041ba0728dd0d6981e97e7aec1d4360c20a27afccfd9fae0b"
+        );
+        assert_eq!(sealed.len(), plaintext.len() + Aead::TAG_LEN);
+        assert_eq!(
+            aead.open(&nonce, b"", &sealed).unwrap().as_slice(),
+            plaintext
+        );
+        assert!(matches!(
+            aead.open(&nonce, b"header", &sealed),
+            Err(SecretsError::Decryption)
+        ));
+        assert!(matches!(
+            aead.open(&nonce, b"", &sealed[..8]),
+            Err(SecretsError::Decryption)
+        ));
+    }
+
+    #[test]
+    fn test_vault_round_trip_keeps_plaintext_off_disk() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut vault = vault_in(&dir);
+        vault.set("db_password", "correct-battery").unwrap();
+        assert!(vault.remove("api_token").unwrap());
+        assert!(!vault.remove("api_token").unwrap());
+
+        let on_disk = fs::read_to_string(vault.path()).unwrap();
+        assert!(!on_disk.contains("correct-battery"));
+        assert!(!on_disk.contains("db_password"), "ชื่อความลับก็ถูกเข้ารหัสด้วย");
+        assert!(!format!("{vault:?}").contains("correct-battery"));
+
+        let reopened = SecretsVault::open(vault.path(), PASSPHRASE).unwrap();
+        assert_eq!(reopened.names().collect::<Vec<_>>(), ["db_password"]);
+        assert_eq!(reopened.get("db_password"), Some("correct-battery"));
+        assert_eq!(reopened.get("api_token"), None);
+
+        // ไฟล์มีอยู่แล้ว → ไม่เขียนทับ
+        let error = SecretsVault::create(vault.path(), "other").unwrap_err();
+        assert!(
+            matches!(error, SecretsError::Io { ref source, .. } if source.kind() == io::ErrorKind::AlreadyExists)
+        );
+    }
+
+    #[test]
+    fn test_wrong_passphrase_and_rotation() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut vault = vault_in(&dir);
+        assert!(matches!(
+            SecretsVault::open(vault.path(), "correct horse battery stapler"),
+            Err(SecretsError::Decryption)
+        ));
+
+        vault.rotate("new passphrase").unwrap();
+        assert!(matches!(
+            SecretsVault::open(vault.path(), PASSPHRASE),
+            Err(SecretsError::Decryption)
+        ));
+        let reopened = SecretsVault::open(vault.path(), "new passphrase").unwrap();
+        assert_eq!(reopened.get("api_token"), Some("tok_live_123"));
+        assert_eq!(reopened.get("db_password"), Some("hunter2"));
+        assert!(matches!(
+            SecretsVault::open(dir.path().join("missing.vault"), PASSPHRASE),
+            Err(SecretsError::Io { .. })
+        ));
+    }
+
+    #[test]
+    fn test_tampered_vault_is_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = vault_in(&dir).path().to_path_buf();
+        let original = fs::read(&path).unwrap();
+        let reset = || fs::write(&path, &original).unwrap();
+
+        // พลิก bit เดียวใน ciphertext
+        edit_file(&path, |json| {
+            let mut sealed = STANDARD
+                .decode(json["ciphertext"].as_str().unwrap())
+                .unwrap();
+            sealed[0] ^= 1;
+            json["ciphertext"] = STANDARD.encode(sealed).into();
+        });
+        assert!(matches!(
+            SecretsVault::open(&path, PASSPHRASE),
+            Err(SecretsError::Decryption)
+        ));
+
+        // header อยู่ใน associated data: ลด iterations ก็ถูกจับ
+        reset();
+        edit_file(&path, |json| json["iterations"] = 999.into());
+        assert!(matches!(
+            SecretsVault::open(&path, PASSPHRASE),
+            Err(SecretsError::Decryption)
+        ));
+
+        // เอา nonce ของอีกไฟล์มาใส่
+        reset();
+        edit_file(&path, |json| {
+            json["nonce"] = STANDARD.encode([7; 12]).into();
+        });
+        assert!(matches!(
+            SecretsVault::open(&path, PASSPHRASE),
+            Err(SecretsError::Decryption)
+        ));
+
+        // ไฟล์ที่ไม่ใช่ vault เลยเป็น Format ไม่ใช่ Decryption
+        fs::write(&path, b"{\"not\": \"a vault\"}").unwrap();
+        assert!(matches!(
+            SecretsVault::open(&path, PASSPHRASE),
+            Err(SecretsError::Format(_))
+        ));
+        reset();
+        edit_file(&path, |json| json["salt"] = "c2hvcnQ=".into());
+        assert!(matches!(
+            SecretsVault::open(&path, PASSPHRASE),
+            Err(SecretsError::Format(_))
+        ));
+
+        reset();
+        assert!(SecretsVault::open(&path, PASSPHRASE).is_ok());
+    }
+
+    #[test]
+    fn test_zeroize_clears_whole_buffer() {
+        let mut secret = Vec::with_capacity(16);
+        secret.extend_from_slice(b"hunter2-and-more");
+        secret.truncate(7);
+        secret.zeroize();
+        assert!(secret.is_empty());
+        // SAFETY: zeroize เขียนศูนย์ทุก byte ของ capacity แล้ว
+        unsafe { secret.set_len(16) };
+        assert_eq!(secret, [0; 16]);
+
+        let mut text = String::from("s3cret");
+        text.zeroize();
+        assert!(text.is_empty());
+
+        let wrapped = Zeroizing::new(String::from("s3cret"));
+        assert_eq!(wrapped.as_str(), "s3cret");
+        assert_eq!(format!("{wrapped:?}"), "Zeroizing(<redacted>)");
+    }
+}
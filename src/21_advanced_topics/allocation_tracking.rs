@@ -0,0 +1,402 @@
+//! Allocation Tracking - นับทุก byte ที่โปรแกรมขอจาก allocator 📊🧮
+//!
+//! `TrackingAllocator` ห่อ `System` allocator แล้วนับ:
+//! - จำนวนครั้งที่ alloc/dealloc และจำนวน bytes
+//! - peak usage (ใช้หน่วยความจำสูงสุดเท่าไร)
+//! - histogram ของขนาด allocation (แบ่งช่องตามกำลังของ 2)
+//!
+//! ตัว allocator จะถูกติดตั้งเป็น `#[global_allocator]` เฉพาะเมื่อเปิด feature
+//! `tracking-allocator` เอง (เช่น `cargo run --features tracking-allocator`) เพราะ global allocator
+//! ใน library มีผลกับทุกโปรแกรมที่ link เข้ามา - ชนกับ allocator ของเขาและเสียค่านับทุกครั้ง
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// จำนวนช่องของ histogram: ≤8, ≤16, ... ≤128 KiB และช่องสุดท้ายสำหรับที่ใหญ่กว่านั้น
+pub const HISTOGRAM_BUCKETS: usize = 16;
+
+/// ขนาดสูงสุดของช่องแรกใน histogram
+const SMALLEST_BUCKET: usize = 8;
+
+/// Custom allocator ที่ track การใช้ memory
+pub struct TrackingAllocator {
+    allocated: AtomicUsize,
+    deallocated: AtomicUsize,
+    peak_usage: AtomicUsize,
+    allocation_count: AtomicUsize,
+    deallocation_count: AtomicUsize,
+    histogram: [AtomicUsize; HISTOGRAM_BUCKETS],
+}
+
+impl Default for TrackingAllocator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TrackingAllocator {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            allocated: AtomicUsize::new(0),
+            deallocated: AtomicUsize::new(0),
+            peak_usage: AtomicUsize::new(0),
+            allocation_count: AtomicUsize::new(0),
+            deallocation_count: AtomicUsize::new(0),
+            histogram: [const { AtomicUsize::new(0) }; HISTOGRAM_BUCKETS],
+        }
+    }
+
+    pub fn allocated(&self) -> usize {
+        self.allocated.load(Ordering::Relaxed)
+    }
+
+    pub fn deallocated(&self) -> usize {
+        self.deallocated.load(Ordering::Relaxed)
+    }
+
+    pub fn current_usage(&self) -> usize {
+        self.allocated().saturating_sub(self.deallocated())
+    }
+
+    pub fn peak_usage(&self) -> usize {
+        self.peak_usage.load(Ordering::Relaxed)
+    }
+
+    pub fn allocation_count(&self) -> usize {
+        self.allocation_count.load(Ordering::Relaxed)
+    }
+
+    pub fn deallocation_count(&self) -> usize {
+        self.deallocation_count.load(Ordering::Relaxed)
+    }
+
+    /// เริ่มนับ peak ใหม่จากการใช้งานปัจจุบัน
+    pub fn reset_peak(&self) {
+        self.peak_usage
+            .store(self.current_usage(), Ordering::Relaxed);
+    }
+
+    /// ถ่ายภาพตัวนับทั้งหมดในขณะนี้
+    pub fn report(&self) -> AllocationReport {
+        AllocationReport {
+            allocations: self.allocation_count(),
+            deallocations: self.deallocation_count(),
+            bytes_allocated: self.allocated(),
+            bytes_deallocated: self.deallocated(),
+            current_bytes: self.current_usage(),
+            peak_bytes: self.peak_usage(),
+            histogram: std::array::from_fn(|i| self.histogram[i].load(Ordering::Relaxed)),
+        }
+    }
+
+    fn update_peak(&self, current: usize) {
+        let mut peak = self.peak_usage.load(Ordering::Relaxed);
+        while current > peak {
+            match self.peak_usage.compare_exchange_weak(
+                peak,
+                current,
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => break,
+                Err(new_peak) => peak = new_peak,
+            }
+        }
+    }
+
+    fn record_alloc(&self, size: usize) {
+        self.allocation_count.fetch_add(1, Ordering::Relaxed);
+        self.histogram[bucket_for(size)].fetch_add(1, Ordering::Relaxed);
+        self.allocated.fetch_add(size, Ordering::Relaxed);
+        self.update_peak(self.current_usage());
+    }
+
+    fn record_dealloc(&self, size: usize) {
+        self.deallocation_count.fetch_add(1, Ordering::Relaxed);
+        self.deallocated.fetch_add(size, Ordering::Relaxed);
+    }
+}
+
+// SAFETY: ทุกเมธอดส่งต่อให้ `System` ด้วย pointer และ layout ชุดเดียวกับที่ได้รับมา
+// ความจำทุกก้อนจึงมาจาก `System` และคืนให้ `System` เสมอ - สัญญาเรื่อง layout/alignment
+// ของ `GlobalAlloc` จึงเป็นของ `System` ทั้งหมด ส่วนที่เราเพิ่มคือการนับด้วย atomics
+// ซึ่งไม่ allocate ไม่ panic และไม่ lock จึงไม่วนกลับเข้ามาหา allocator ตัวเอง
+// (ห้ามเพิ่มโค้ดที่ allocate ในเมธอดเหล่านี้ ไม่งั้นจะเรียกตัวเองไม่รู้จบ)
+unsafe impl GlobalAlloc for TrackingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        // SAFETY: caller รับประกันว่า `layout` มีขนาดไม่เป็น 0 ตามสัญญาของ `GlobalAlloc::alloc`
+        // เราส่ง `layout` เดิมต่อไปตรงๆ จึงได้สัญญาเดียวกันกับ `System.alloc`
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            self.record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        // SAFETY: `ptr` ถูก allocate ด้วย `layout` นี้ผ่าน allocator ตัวนี้ ซึ่งได้มาจาก `System`
+        // (ทั้ง `alloc` และ `realloc` ส่งต่อให้ `System`) จึงคืนให้ `System` ได้ถูกต้อง
+        unsafe { System.dealloc(ptr, layout) };
+        self.record_dealloc(layout.size());
+    }
+
+    /// ส่งต่อให้ `System.realloc` เพื่อให้ขยายที่เดิมได้ (ค่า default คือ alloc + copy + dealloc เสมอ)
+    ///
+    /// นับเหมือนคืนก้อนเก่าแล้วขอก้อนใหม่ ตัวเลขจึงเทียบกับโปรแกรมที่ไม่ใช้ realloc ได้
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        // SAFETY: `ptr`/`layout` มาจาก `System` เหมือนใน `dealloc` และ caller รับประกันว่า
+        // `new_size` ไม่เป็น 0 และปัดตาม alignment แล้วไม่ล้น `isize` ตามสัญญาของ `realloc`
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() {
+            self.record_dealloc(layout.size());
+            self.record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// ช่องของ histogram ที่ขนาด `size` ตกอยู่
+const fn bucket_for(size: usize) -> usize {
+    if size <= SMALLEST_BUCKET {
+        return 0;
+    }
+    let bucket =
+        (size.next_power_of_two().trailing_zeros() - SMALLEST_BUCKET.trailing_zeros()) as usize;
+    if bucket < HISTOGRAM_BUCKETS {
+        bucket
+    } else {
+        HISTOGRAM_BUCKETS - 1
+    }
+}
+
+/// ขอบบนของช่อง histogram (`None` คือช่องสุดท้ายที่ไม่มีขอบบน)
+#[must_use]
+pub const fn bucket_upper_bound(bucket: usize) -> Option<usize> {
+    if bucket + 1 >= HISTOGRAM_BUCKETS {
+        None
+    } else {
+        Some(SMALLEST_BUCKET << bucket)
+    }
+}
+
+// Global instance of tracking allocator
+#[cfg_attr(feature = "tracking-allocator", global_allocator)]
+pub static TRACKING_ALLOCATOR: TrackingAllocator = TrackingAllocator::new();
+
+/// `TRACKING_ALLOCATOR` ถูกติดตั้งเป็น global allocator หรือไม่
+#[must_use]
+pub const fn is_tracking_enabled() -> bool {
+    cfg!(feature = "tracking-allocator")
+}
+
+/// 📊 ภาพรวมการใช้หน่วยความจำของทั้งโปรแกรม ณ ตอนนี้
+#[must_use]
+pub fn allocation_report() -> AllocationReport {
+    TRACKING_ALLOCATOR.report()
+}
+
+/// 📊 ตัวนับของ allocator ณ เวลาหนึ่ง
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AllocationReport {
+    pub allocations: usize,
+    pub deallocations: usize,
+    pub bytes_allocated: usize,
+    pub bytes_deallocated: usize,
+    pub current_bytes: usize,
+    pub peak_bytes: usize,
+    pub histogram: [usize; HISTOGRAM_BUCKETS],
+}
+
+impl AllocationReport {
+    /// สิ่งที่เกิดขึ้นระหว่าง `earlier` กับรายงานนี้ (peak และ current ใช้ค่าของรายงานนี้)
+    #[must_use]
+    pub fn since(&self, earlier: &Self) -> Self {
+        Self {
+            allocations: self.allocations.saturating_sub(earlier.allocations),
+            deallocations: self.deallocations.saturating_sub(earlier.deallocations),
+            bytes_allocated: self.bytes_allocated.saturating_sub(earlier.bytes_allocated),
+            bytes_deallocated: self
+                .bytes_deallocated
+                .saturating_sub(earlier.bytes_deallocated),
+            current_bytes: self.current_bytes,
+            peak_bytes: self.peak_bytes,
+            histogram: std::array::from_fn(|i| {
+                self.histogram[i].saturating_sub(earlier.histogram[i])
+            }),
+        }
+    }
+}
+
+impl fmt::Display for AllocationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "allocations: {} ({} bytes), deallocations: {} ({} bytes)",
+            self.allocations, self.bytes_allocated, self.deallocations, self.bytes_deallocated
+        )?;
+        writeln!(
+            f,
+            "current: {} bytes, peak: {} bytes",
+            self.current_bytes, self.peak_bytes
+        )?;
+
+        let widest = self.histogram.iter().copied().max().unwrap_or(0).max(1);
+        for (bucket, &count) in self.histogram.iter().enumerate() {
+            if count == 0 {
+                continue;
+            }
+            let label = bucket_upper_bound(bucket).map_or_else(
+                || format!("> {}", SMALLEST_BUCKET << (HISTOGRAM_BUCKETS - 2)),
+                |bound| format!("<= {bound}"),
+            );
+            let bar = "█".repeat((count * 30).div_ceil(widest));
+            writeln!(f, "{label:>10} B | {bar} {count}")?;
+        }
+        Ok(())
+    }
+}
+
+/// วัดว่า `f` ขอหน่วยความจำไปกี่ครั้ง/กี่ bytes
+///
+/// `peak_bytes` ของผลลัพธ์คือหน่วยความจำที่ใช้เพิ่มสูงสุดระหว่างรัน `f`
+/// ตัวเลขนับทั้งโปรแกรม ถ้ามี thread อื่นทำงานพร้อมกันก็จะถูกนับรวมด้วย
+pub fn measure_allocations<R>(f: impl FnOnce() -> R) -> (R, AllocationReport) {
+    TRACKING_ALLOCATOR.reset_peak();
+    let before = allocation_report();
+    let result = f();
+    let after = allocation_report();
+    let mut delta = after.since(&before);
+    delta.peak_bytes = after.peak_bytes.saturating_sub(before.current_bytes);
+    (result, delta)
+}
+
+/// จำนวนสมาชิกที่ใช้ในตัวอย่าง Vec growth
+const DEMO_ITEMS: usize = 10_000;
+
+/// วิธีสร้าง `Vec` หนึ่งแบบในตัวอย่าง
+type VecStrategy = (&'static str, fn() -> Vec<usize>);
+
+/// 📈 เปรียบเทียบจำนวน allocation ของวิธีเติม `Vec` แบบต่างๆ
+pub fn demonstrate_vec_growth_strategies() {
+    println!("\n📈 === Vec Growth Strategies vs Allocations === 📈");
+    if !is_tracking_enabled() {
+        println!("⚠️ ปิด feature `tracking-allocator` อยู่ - ตัวนับจะเป็นศูนย์ทั้งหมด");
+    }
+
+    let strategies: [VecStrategy; 4] = [
+        ("push ทีละตัว (Vec::new)", || {
+            let mut values = Vec::new();
+            for i in 0..DEMO_ITEMS {
+                values.push(i);
+            }
+            values
+        }),
+        ("Vec::with_capacity", || {
+            let mut values = Vec::with_capacity(DEMO_ITEMS);
+            for i in 0..DEMO_ITEMS {
+                values.push(i);
+            }
+            values
+        }),
+        ("reserve_exact ทุกครั้ง", || {
+            let mut values = Vec::new();
+            for i in 0..DEMO_ITEMS {
+                values.reserve_exact(1);
+                values.push(i);
+            }
+            values
+        }),
+        ("collect() จาก iterator", || (0..DEMO_ITEMS).collect()),
+    ];
+
+    for (name, build) in strategies {
+        let (values, stats) = measure_allocations(build);
+        println!(
+            "   {name:<26} {:>6} allocations, {:>9} bytes, peak {:>9} bytes (len {})",
+            stats.allocations,
+            stats.bytes_allocated,
+            stats.peak_bytes,
+            values.len()
+        );
+    }
+
+    println!("\n📊 allocation_report() ของทั้งโปรแกรม:");
+    print!("{}", allocation_report());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_histogram_buckets() {
+        assert_eq!(bucket_for(0), 0);
+        assert_eq!(bucket_for(8), 0);
+        assert_eq!(bucket_for(9), 1);
+        assert_eq!(bucket_for(16), 1);
+        assert_eq!(bucket_for(4096), 9);
+        assert_eq!(bucket_for(usize::MAX / 2), HISTOGRAM_BUCKETS - 1);
+        assert_eq!(bucket_upper_bound(0), Some(8));
+        assert_eq!(bucket_upper_bound(HISTOGRAM_BUCKETS - 1), None);
+    }
+
+    #[test]
+    fn test_local_allocator_counts() {
+        let allocator = TrackingAllocator::new();
+        let layout = Layout::from_size_align(100, 8).unwrap();
+        unsafe {
+            let ptr = allocator.alloc(layout);
+            assert!(!ptr.is_null());
+            allocator.dealloc(ptr, layout);
+        }
+
+        let report = allocator.report();
+        assert_eq!(report.allocations, 1);
+        assert_eq!(report.deallocations, 1);
+        assert_eq!(report.bytes_allocated, 100);
+        assert_eq!(report.current_bytes, 0);
+        assert_eq!(report.peak_bytes, 100);
+        assert_eq!(report.histogram[bucket_for(100)], 1);
+        assert!(report.to_string().contains("<= 128 B"));
+    }
+
+    #[test]
+    fn test_local_allocator_counts_realloc() {
+        let allocator = TrackingAllocator::new();
+        let layout = Layout::from_size_align(64, 8).unwrap();
+        unsafe {
+            let ptr = allocator.alloc(layout);
+            ptr.write_bytes(7, 64);
+            let grown = allocator.realloc(ptr, layout, 256);
+            assert!(!grown.is_null());
+            assert_eq!(*grown.add(63), 7);
+            allocator.dealloc(grown, Layout::from_size_align(256, 8).unwrap());
+        }
+
+        let report = allocator.report();
+        assert_eq!(report.allocations, 2);
+        assert_eq!(report.deallocations, 2);
+        assert_eq!(report.bytes_allocated, 64 + 256);
+        assert_eq!(report.current_bytes, 0);
+        assert_eq!(report.peak_bytes, 256);
+    }
+
+    #[cfg(feature = "tracking-allocator")]
+    #[test]
+    fn test_with_capacity_allocates_once() {
+        // thread อื่นอาจ allocate พร้อมกัน จึงเช็คแค่ขอบล่าง/บนอย่างหลวมๆ
+        let (_, grown) = measure_allocations(|| {
+            let mut values = Vec::new();
+            for i in 0..4_096_u64 {
+                values.push(i);
+            }
+            values
+        });
+        let (_, reserved) = measure_allocations(|| Vec::<u64>::with_capacity(4_096));
+        assert!(grown.allocations >= 10);
+        assert!(reserved.allocations >= 1);
+        assert!(reserved.bytes_allocated >= 4_096 * 8);
+    }
+}
@@ -5,19 +5,23 @@
 //! - Foreign Function Interface (FFI)
 //! - Custom Allocators และ Memory Layout Optimization
 //! - Advanced Type System (HKT, Const Generics, Phantom Types)
+//! - Pin, self-referential struct และ intrusive linked list
 //! - Advanced Concurrency Patterns
 //! - Zero-cost Abstractions และ Performance Optimization
 //! - Advanced Trait Patterns และ Type-level Programming
 
+pub mod allocation_tracking;
+pub mod pinning;
 pub mod practice_advanced_topics;
 
 pub use practice_advanced_topics::*;
 
-use std::alloc::{GlobalAlloc, Layout};
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::atomic::Ordering;
 use std::marker::PhantomData;
 use std::mem;
 
+use crate::layout::{TypeLayout, layout_of};
+
 /// ตัวอย่าง Unsafe Rust - Raw Pointer Operations
 pub fn unsafe_pointer_operations() {
     println!("\n=== Unsafe Pointer Operations ===");
@@ -42,40 +46,6 @@ pub fn unsafe_pointer_operations() {
     }
 }
 
-/// ตัวอย่าง Custom Allocator
-struct TrackingAllocator {
-    allocated: AtomicUsize,
-}
-
-impl TrackingAllocator {
-    const fn new() -> Self {
-        Self {
-            allocated: AtomicUsize::new(0),
-        }
-    }
-    
-    fn allocated_bytes(&self) -> usize {
-        self.allocated.load(Ordering::Relaxed)
-    }
-}
-
-unsafe impl GlobalAlloc for TrackingAllocator {
-    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
-        // 🔥 เรียกใช้ allocator ระบบแบบปลอดภัย! 🛡️
-        let ptr = unsafe { std::alloc::System.alloc(layout) };
-        if !ptr.is_null() {
-            self.allocated.fetch_add(layout.size(), Ordering::Relaxed);
-        }
-        ptr
-    }
-    
-    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
-        // 🗑️ คืนหน่วยความจำแบบปลอดภัย! ♻️
-        unsafe { std::alloc::System.dealloc(ptr, layout) };
-        self.allocated.fetch_sub(layout.size(), Ordering::Relaxed);
-    }
-}
-
 /// ตัวอย่าง Phantom Types
 struct TypedId<T> {
     id: u64,
@@ -202,11 +172,33 @@ struct PackedStruct {
     // Total: 13 bytes (no padding)
 }
 
+/// ฟิลด์เดียวกันแต่ปล่อยให้ compiler เรียงเอง (`repr(Rust)`)
+struct ReorderedStruct {
+    flag: bool,     // compiler ย้ายไปไว้ท้าย
+    value: u64,
+    count: u32,
+    // Total: 16 bytes - padding เหลือ 3 bytes
+}
+
+fn optimized_layout() -> TypeLayout {
+    layout_of!(OptimizedStruct { flag: bool, value: u64, count: u32 })
+}
+
+fn packed_layout() -> TypeLayout {
+    layout_of!(PackedStruct { flag: bool, value: u64, count: u32 })
+}
+
+fn reordered_layout() -> TypeLayout {
+    layout_of!(ReorderedStruct { flag: bool, value: u64, count: u32 })
+}
+
 pub fn memory_layout_example() {
     println!("\n=== Memory Layout Example ===");
     
-    println!("OptimizedStruct size: {} bytes", mem::size_of::<OptimizedStruct>());
-    println!("PackedStruct size: {} bytes", mem::size_of::<PackedStruct>());
+    // แผนที่ byte: ตัวอักษรคือฟิลด์, ░ คือ padding
+    print!("{}", optimized_layout());
+    print!("{}", reordered_layout());
+    print!("{}", packed_layout());
     
     println!("bool size: {} bytes", mem::size_of::<bool>());
     println!("u64 size: {} bytes", mem::size_of::<u64>());
@@ -365,6 +357,8 @@ pub fn run_advanced_topics_examples() {
     memory_layout_example();
     lock_free_example();
     zero_cost_abstractions_example();
+    allocation_tracking::demonstrate_vec_growth_strategies();
+    pinning::pinning_example();
     
     // เรียกใช้ตัวอย่างจาก practice_advanced_topics
     println!("\n📚 Practice Examples:");
@@ -406,5 +400,18 @@ mod tests {
     fn test_memory_layout() {
         // Test that packed struct is smaller
         assert!(mem::size_of::<PackedStruct>() < mem::size_of::<OptimizedStruct>());
+
+        let optimized = optimized_layout();
+        assert_eq!((optimized.size, optimized.padding_bytes()), (24, Some(11)));
+        assert_eq!(optimized.byte_map(), "A░░░░░░░|BBBBBBBB|CCCC░░░░");
+
+        let packed = packed_layout();
+        assert_eq!((packed.size, packed.align, packed.padding_bytes()), (13, 1, Some(0)));
+        assert_eq!(packed.byte_map(), "ABBBBBBBBCCCC");
+
+        // repr(Rust) เรียงฟิลด์ใหญ่ไว้ก่อน padding จึงเหลือแค่ท้าย struct
+        let reordered = reordered_layout();
+        assert_eq!((reordered.size, reordered.padding_bytes()), (16, Some(3)));
+        assert_eq!(reordered.fields[1].offset, 0);
     }
 }
\ No newline at end of file
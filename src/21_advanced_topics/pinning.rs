@@ -0,0 +1,449 @@
+//! Pin และ Self-referential Struct - ทำไม Rust ต้องมี `Pin`? 📌🪢
+//!
+//! ใน Rust ทุกค่า "ย้ายได้" เสมอ (`let b = a;`, `mem::swap`, ใส่ลง `Vec`) และการย้ายคือการ
+//! copy bytes ไปที่อยู่ใหม่ ถ้า struct ถือ pointer ที่ชี้เข้าหาตัวเอง pointer นั้นจะยังชี้ที่อยู่เดิม 💥
+//! `async fn` ทุกตัวคือ struct แบบนี้ (ตัวแปร local ที่ยืมกันข้าม `.await`) จึงต้อง poll ผ่าน `Pin`
+//!
+//! 🛠️ **สิ่งที่จะได้เรียนรู้:**
+//! - 💥 `MovableSelfRef` - self-reference แบบไม่มี Pin ที่พังทันทีที่ถูกย้าย
+//! - 📌 `SelfReferential` - `Pin<Box<T>>` + `PhantomPinned` สัญญาว่าจะไม่ย้ายอีกเลย
+//! - 🔗 `IntrusiveList` - linked list ที่ node อยู่ในหน่วยความจำของเจ้าของเอง (ไม่ต้อง alloc)
+//!   ใช้ใน timer wheel ของ `MiniExecutor` (`async_await::timer_wheel`)
+//!
+//! 🧷 **drop guarantee ของ Pin:** หน่วยความจำของค่าที่ถูก pin จะไม่ถูกนำไปใช้ใหม่
+//! จนกว่า `Drop` ของมันจะรัน - เจ้าของ node จึงถอด node ออกจาก list ใน `Drop` ได้อย่างปลอดภัย
+//!
+//! 🔬 tests ในไฟล์นี้ตั้งใจให้ผ่าน Miri: `cargo +nightly miri test --lib pinning`
+//!
+//! ```compile_fail
+//! // ❌ self-reference ด้วย reference ธรรมดา: borrow checker ไม่ยอมให้ย้ายค่าที่ยังยืมตัวเองอยู่
+//! struct SelfRef<'a> {
+//!     text: String,
+//!     text_ref: Option<&'a String>,
+//! }
+//!
+//! let mut value = SelfRef { text: String::from("pin"), text_ref: None };
+//! value.text_ref = Some(&value.text);
+//! let moved = value; // error[E0505]: cannot move out of `value` because it is borrowed
+//! println!("{:?}", moved.text_ref);
+//! ```
+//!
+//! ```compile_fail
+//! use rust_concepts::advanced_topics::pinning::SelfReferential;
+//!
+//! let mut a = SelfReferential::new("a");
+//! let mut b = SelfReferential::new("b");
+//! // ❌ `SelfReferential` เป็น `!Unpin` - ขอ `&mut` ผ่าน `Pin` ไม่ได้ จึงสลับค่าข้างในไม่ได้
+//! std::mem::swap(&mut *a, &mut *b);
+//! ```
+
+use std::cell::Cell;
+use std::fmt;
+use std::marker::PhantomPinned;
+use std::pin::{Pin, pin};
+use std::ptr::{self, NonNull};
+
+/// 💥 struct ที่ชี้เข้าหาตัวเองด้วย raw pointer แต่ไม่มีอะไรห้ามการย้าย
+///
+/// compiler ไม่ช่วยอะไรเลยเพราะ raw pointer ไม่มี lifetime - หลังถูกย้าย `text_ptr`
+/// ยังชี้ไปที่อยู่เก่า การ deref มันตอนนั้นคือ undefined behavior
+/// (ในไฟล์นี้เราแค่เทียบที่อยู่ ไม่เคย deref)
+pub struct MovableSelfRef {
+    text: String,
+    text_ptr: *const String,
+}
+
+impl MovableSelfRef {
+    #[must_use]
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            text_ptr: ptr::null(),
+        }
+    }
+
+    /// ให้ `text_ptr` ชี้ไปที่ `text` ของตัวเอง ณ ที่อยู่ปัจจุบัน
+    pub const fn init(&mut self) {
+        self.text_ptr = &raw const self.text;
+    }
+
+    /// pointer ยังชี้ที่ field ของตัวเองอยู่หรือไม่
+    #[must_use]
+    pub fn points_at_self(&self) -> bool {
+        ptr::eq(self.text_ptr, &raw const self.text)
+    }
+}
+
+/// 📌 self-referential struct ที่ถูกต้อง - สร้างได้แค่ในรูป `Pin<Box<Self>>`
+///
+/// `PhantomPinned` ทำให้ type นี้เป็น `!Unpin` จึงได้แค่ `Pin<&Self>` / `Pin<&mut Self>`
+/// ที่ไม่ยอมให้ย้ายค่าออกมา - pointer ภายในจึงถูกต้องตลอดชีวิตของมัน
+pub struct SelfReferential {
+    text: String,
+    text_ptr: NonNull<String>,
+    _pinned: PhantomPinned,
+}
+
+impl SelfReferential {
+    /// สร้างบน heap ก่อน แล้วค่อยตั้ง pointer เมื่อที่อยู่นิ่งแล้ว
+    #[must_use]
+    pub fn new(text: impl Into<String>) -> Pin<Box<Self>> {
+        let mut boxed = Box::new(Self {
+            text: text.into(),
+            text_ptr: NonNull::dangling(),
+            _pinned: PhantomPinned,
+        });
+        boxed.text_ptr = NonNull::from(&boxed.text);
+        // จากนี้ไปไม่มีใครได้ `&mut Self` หรือ `Self` คืนอีกแล้ว
+        Box::into_pin(boxed)
+    }
+
+    /// อ่านข้อความผ่าน pointer ภายใน
+    #[must_use]
+    pub fn text(self: Pin<&Self>) -> &str {
+        // SAFETY: `text_ptr` ชี้ไปที่ `self.text` ตั้งแต่ตอนสร้าง และ `self` ถูก pin
+        // (ย้ายไม่ได้) ตลอดชีวิต pointer จึงยังถูกต้องและอายุไม่เกิน `self`
+        unsafe { self.get_ref().text_ptr.as_ref() }
+    }
+
+    #[must_use]
+    pub fn points_at_self(self: Pin<&Self>) -> bool {
+        ptr::eq(self.text_ptr.as_ptr(), &raw const self.get_ref().text)
+    }
+}
+
+/// 🔗 node ของ `IntrusiveList` - ฝังอยู่ในหน่วยความจำของเจ้าของ (เช่นใน future)
+///
+/// ตัวชี้ `prev`/`next` อยู่ใน `Cell` จึงแก้ผ่าน `&ListNode` ได้ ไม่ต้องมี `&mut`
+/// ที่จะไปชนกับ pointer ตัวอื่นที่ชี้มาที่ node เดียวกัน
+pub struct ListNode<T> {
+    value: T,
+    prev: Cell<Option<NonNull<Self>>>,
+    next: Cell<Option<NonNull<Self>>>,
+    linked: Cell<bool>,
+    _pinned: PhantomPinned,
+}
+
+impl<T> ListNode<T> {
+    pub const fn new(value: T) -> Self {
+        Self {
+            value,
+            prev: Cell::new(None),
+            next: Cell::new(None),
+            linked: Cell::new(false),
+            _pinned: PhantomPinned,
+        }
+    }
+
+    pub const fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// node นี้อยู่ใน list ใดอยู่หรือไม่
+    pub const fn is_linked(&self) -> bool {
+        self.linked.get()
+    }
+
+    fn unlink(&self) {
+        self.prev.set(None);
+        self.next.set(None);
+        self.linked.set(false);
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for ListNode<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ListNode")
+            .field("value", &self.value)
+            .field("linked", &self.is_linked())
+            .finish_non_exhaustive()
+    }
+}
+
+/// 🔗 doubly linked list ที่ไม่เป็นเจ้าของ node - แค่ร้อย node ที่ถูก pin ไว้เข้าด้วยกัน
+///
+/// push/remove เป็น O(1) และไม่ต้อง alloc เลย (node อยู่ใน future ที่ถูก pin อยู่แล้ว)
+/// แลกกับสัญญาที่ compiler ตรวจให้ไม่ได้ จึงเป็น `unsafe fn`
+pub struct IntrusiveList<T> {
+    head: Option<NonNull<ListNode<T>>>,
+    tail: Option<NonNull<ListNode<T>>>,
+    len: usize,
+}
+
+impl<T> Default for IntrusiveList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> IntrusiveList<T> {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            head: None,
+            tail: None,
+            len: 0,
+        }
+    }
+
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// ต่อ node ไว้ท้าย list
+    ///
+    /// # Safety
+    ///
+    /// `node` ต้องถูกถอดออกด้วย `remove` / `remove_where` (หรือ list ถูก drop)
+    /// ก่อนที่ node จะถูก drop - `Pin` รับประกันแค่ว่ามันจะไม่ถูกย้าย
+    ///
+    /// # Panics
+    ///
+    /// เมื่อ `node` อยู่ใน list ใดอยู่แล้ว
+    pub unsafe fn push_back(&mut self, node: Pin<&ListNode<T>>) {
+        let node = node.get_ref();
+        assert!(!node.is_linked(), "node is already in a list");
+        let ptr = NonNull::from(node);
+        node.prev.set(self.tail);
+        node.next.set(None);
+        node.linked.set(true);
+        match self.tail {
+            // SAFETY: ทุก node ใน list ยังมีชีวิตอยู่ตามสัญญาของ `push_back`
+            Some(tail) => unsafe { tail.as_ref() }.next.set(Some(ptr)),
+            None => self.head = Some(ptr),
+        }
+        self.tail = Some(ptr);
+        self.len += 1;
+    }
+
+    /// ถอด node ออก - คืน `false` ถ้า node ไม่ได้อยู่ใน list (เช่นถูกถอดไปแล้ว)
+    ///
+    /// # Safety
+    ///
+    /// ถ้า `node` อยู่ใน list ต้องเป็น list *นี้* ไม่ใช่ list อื่น
+    pub unsafe fn remove(&mut self, node: Pin<&ListNode<T>>) -> bool {
+        let node = node.get_ref();
+        if !node.is_linked() {
+            return false;
+        }
+        // SAFETY: node อยู่ใน list นี้ จึง unlink ได้ตามสัญญาของฟังก์ชัน
+        unsafe { self.unlink(NonNull::from(node)) };
+        true
+    }
+
+    /// ถอดทุก node ที่ `predicate` ตอบ `true` แล้วส่ง value ให้ `on_removed` ทีละตัว
+    /// (ถูกเรียกหลังจาก node หลุดจาก list แล้ว) - คืนจำนวนที่ถอด
+    pub fn remove_where(
+        &mut self,
+        mut predicate: impl FnMut(&T) -> bool,
+        mut on_removed: impl FnMut(&T),
+    ) -> usize {
+        let mut removed = 0;
+        let mut cursor = self.head;
+        while let Some(ptr) = cursor {
+            // SAFETY: node ใน list ยังมีชีวิตอยู่ตามสัญญาของ `push_back`
+            let node = unsafe { ptr.as_ref() };
+            cursor = node.next.get();
+            if predicate(&node.value) {
+                // SAFETY: `ptr` มาจากการเดินใน list นี้เอง
+                unsafe { self.unlink(ptr) };
+                on_removed(&node.value);
+                removed += 1;
+            }
+        }
+        removed
+    }
+
+    /// เดินดู value ทุกตัวจากหัวไปท้าย
+    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+        let mut cursor = self.head;
+        std::iter::from_fn(move || {
+            // SAFETY: node ใน list ยังมีชีวิตอยู่ และ `&self` กันไม่ให้ list ถูกแก้ระหว่างเดิน
+            let node = unsafe { cursor?.as_ref() };
+            cursor = node.next.get();
+            Some(&node.value)
+        })
+    }
+
+    /// # Safety
+    ///
+    /// `ptr` ต้องเป็น node ที่อยู่ใน list นี้
+    unsafe fn unlink(&mut self, ptr: NonNull<ListNode<T>>) {
+        // SAFETY: ผู้เรียกรับประกันว่า `ptr` เป็น node ที่ยังมีชีวิตและอยู่ใน list นี้
+        let node = unsafe { ptr.as_ref() };
+        let (prev, next) = (node.prev.get(), node.next.get());
+        if let Some(prev) = prev {
+            // SAFETY: `prev` อ่านจาก node ที่อยู่ใน list นี้ จึงเป็น node ของ list เดียวกัน
+            // ซึ่งยังมีชีวิตอยู่ตามสัญญาของ `push_back` (node ถูก pin ไว้จนกว่าจะออกจาก list)
+            unsafe { prev.as_ref() }.next.set(next);
+        } else {
+            debug_assert_eq!(self.head, Some(ptr), "node belongs to another list");
+            self.head = next;
+        }
+        match next {
+            // SAFETY: เหตุผลเดียวกับ `prev` - `next` เป็นเพื่อนบ้านใน list เดียวกันที่ยังมีชีวิต
+            Some(next) => unsafe { next.as_ref() }.prev.set(prev),
+            None => self.tail = prev,
+        }
+        node.unlink();
+        self.len -= 1;
+    }
+}
+
+impl<T> Drop for IntrusiveList<T> {
+    fn drop(&mut self) {
+        // ปล่อย node ที่ยังค้างอยู่ ให้เจ้าของเห็นว่าไม่ได้อยู่ใน list แล้ว
+        let mut cursor = self.head.take();
+        while let Some(ptr) = cursor {
+            // SAFETY: node ยังมีชีวิตอยู่ตามสัญญาของ `push_back`
+            let node = unsafe { ptr.as_ref() };
+            cursor = node.next.get();
+            node.unlink();
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for IntrusiveList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// ตัวอย่างการใช้ Pin - ตอกหมุดให้ค่าอยู่กับที่! 📌
+pub fn pinning_example() {
+    println!("\n📌🪢 === Pin และ Self-referential Struct === 🪢📌");
+
+    let mut first = MovableSelfRef::new("first");
+    let mut second = MovableSelfRef::new("second");
+    first.init();
+    second.init();
+    println!("💥 ก่อน swap: ชี้ที่ตัวเอง? {}", first.points_at_self());
+    std::mem::swap(&mut first, &mut second);
+    println!(
+        "💥 หลัง swap: ชี้ที่ตัวเอง? {} (pointer ยังชี้ที่อยู่เดิม = ของอีกตัว!)",
+        first.points_at_self()
+    );
+
+    let pinned = SelfReferential::new("ปักหมุดแล้ว");
+    let pinned = std::convert::identity(pinned); // ย้าย Box เข้าออกฟังก์ชัน - ข้อมูลบน heap ไม่ขยับ
+    println!(
+        "📌 Pin<Box> ถูกย้ายแล้ว: \"{}\" ชี้ที่ตัวเอง? {}",
+        pinned.as_ref().text(),
+        pinned.as_ref().points_at_self()
+    );
+
+    let (a, b, c) = (
+        pin!(ListNode::new("⏰ 10ms")),
+        pin!(ListNode::new("⏰ 20ms")),
+        pin!(ListNode::new("⏰ 30ms")),
+    );
+    let mut list = IntrusiveList::new();
+    // SAFETY: list ถูกประกาศทีหลัง node จึงถูก drop ก่อน node ทุกตัว
+    unsafe {
+        list.push_back(a.as_ref());
+        list.push_back(b.as_ref());
+        list.push_back(c.as_ref());
+        list.remove(b.as_ref());
+    }
+    println!("🔗 intrusive list หลังถอดตัวกลาง: {list:?} (ไม่มีการ alloc เลย!)");
+    println!("💡 future ที่รอ timer ใน MiniExecutor ก็ร้อยตัวเองเข้า list แบบนี้");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_moving_unpinned_self_ref_breaks_pointer() {
+        let mut a = MovableSelfRef::new("a");
+        let mut b = MovableSelfRef::new("b");
+        a.init();
+        b.init();
+        assert!(a.points_at_self() && b.points_at_self());
+
+        std::mem::swap(&mut a, &mut b);
+        assert!(!a.points_at_self());
+        assert!(!b.points_at_self());
+    }
+
+    #[test]
+    fn test_pinned_self_ref_survives_moving_the_box() {
+        let pinned = SelfReferential::new("hello");
+        let address = &raw const *pinned;
+
+        let boxes = vec![pinned];
+        let pinned = boxes.into_iter().next().unwrap();
+
+        assert!(ptr::eq(address, &raw const *pinned));
+        assert!(pinned.as_ref().points_at_self());
+        assert_eq!(pinned.as_ref().text(), "hello");
+    }
+
+    #[test]
+    fn test_intrusive_list_push_and_remove_keep_order() {
+        let (n1, n2) = (pin!(ListNode::new(1)), pin!(ListNode::new(2)));
+        let (n3, n4) = (pin!(ListNode::new(3)), pin!(ListNode::new(4)));
+        let [n1, n2, n3, n4] = [n1.as_ref(), n2.as_ref(), n3.as_ref(), n4.as_ref()];
+        let mut list = IntrusiveList::new();
+
+        // SAFETY: list ถูก drop ก่อน node (ประกาศทีหลัง)
+        unsafe {
+            list.push_back(n1);
+            list.push_back(n2);
+            list.push_back(n3);
+            list.push_back(n4);
+            assert!(list.remove(n2));
+            assert!(list.remove(n4));
+            assert!(!list.remove(n4), "removing twice is a no-op");
+        }
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 3]);
+        assert_eq!(list.len(), 2);
+        assert!(!n2.is_linked());
+
+        // SAFETY: n2 ถูกถอดออกแล้วจึงใส่ใหม่ได้
+        unsafe { list.push_back(n2) };
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 3, 2]);
+    }
+
+    #[test]
+    fn test_remove_where_unlinks_matching_nodes() {
+        let (a, b, c) = (
+            pin!(ListNode::new(10)),
+            pin!(ListNode::new(25)),
+            pin!(ListNode::new(30)),
+        );
+        let mut list = IntrusiveList::new();
+        // SAFETY: list ถูก drop ก่อน node
+        unsafe {
+            list.push_back(a.as_ref());
+            list.push_back(b.as_ref());
+            list.push_back(c.as_ref());
+        }
+
+        let mut fired = Vec::new();
+        let removed = list.remove_where(|value| value % 10 == 0, |value| fired.push(*value));
+
+        assert_eq!(removed, 2);
+        assert_eq!(fired, vec![10, 30]);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![25]);
+        assert!(!a.is_linked() && b.is_linked() && !c.is_linked());
+    }
+
+    #[test]
+    fn test_dropping_list_unlinks_remaining_nodes() {
+        let node = pin!(ListNode::new("owned by the future"));
+        {
+            let mut list = IntrusiveList::new();
+            // SAFETY: list อยู่ใน scope ที่สั้นกว่า node
+            unsafe { list.push_back(node.as_ref()) };
+            assert!(node.is_linked());
+        }
+        assert!(!node.is_linked());
+    }
+}
@@ -10,13 +10,13 @@
 //! - Memory Layout และ Optimization
 //! - Advanced Concurrency Patterns
 
-use std::alloc::{GlobalAlloc, Layout, System};
+use std::alloc::Layout;
 use std::ffi::{CStr, CString};
 use std::marker::PhantomData;
 use std::mem;
 use std::os::raw::{c_char, c_int};
 use std::ptr;
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::atomic::Ordering;
 use std::sync::{Arc, Mutex};
 
 // ===== 1. Unsafe Rust และ Raw Pointers =====
@@ -227,81 +227,8 @@ impl FFIHelper {
 
 // ===== 3. Custom Allocators =====
 
-/// Custom allocator ที่ track การใช้ memory
-pub struct TrackingAllocator {
-    allocated: AtomicUsize,
-    deallocated: AtomicUsize,
-    peak_usage: AtomicUsize,
-}
-
-impl Default for TrackingAllocator {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
-impl TrackingAllocator {
-    #[must_use] pub const fn new() -> Self {
-        Self {
-            allocated: AtomicUsize::new(0),
-            deallocated: AtomicUsize::new(0),
-            peak_usage: AtomicUsize::new(0),
-        }
-    }
-    
-    pub fn allocated(&self) -> usize {
-        self.allocated.load(Ordering::Relaxed)
-    }
-    
-    pub fn deallocated(&self) -> usize {
-        self.deallocated.load(Ordering::Relaxed)
-    }
-    
-    pub fn current_usage(&self) -> usize {
-        self.allocated() - self.deallocated()
-    }
-    
-    pub fn peak_usage(&self) -> usize {
-        self.peak_usage.load(Ordering::Relaxed)
-    }
-    
-    fn update_peak(&self, current: usize) {
-        let mut peak = self.peak_usage.load(Ordering::Relaxed);
-        while current > peak {
-            match self.peak_usage.compare_exchange_weak(
-                peak,
-                current,
-                Ordering::Relaxed,
-                Ordering::Relaxed,
-            ) {
-                Ok(_) => break,
-                Err(new_peak) => peak = new_peak,
-            }
-        }
-    }
-}
-
-unsafe impl GlobalAlloc for TrackingAllocator {
-    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
-        let ptr = unsafe { System.alloc(layout) };
-        if !ptr.is_null() {
-            let size = layout.size();
-            self.allocated.fetch_add(size, Ordering::Relaxed);
-            let current = self.current_usage();
-            self.update_peak(current);
-        }
-        ptr
-    }
-    
-    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
-        unsafe { System.dealloc(ptr, layout) };
-        self.deallocated.fetch_add(layout.size(), Ordering::Relaxed);
-    }
-}
-
-// Global instance of tracking allocator
-#[global_allocator]
-static TRACKING_ALLOCATOR: TrackingAllocator = TrackingAllocator::new();
+// TrackingAllocator และ global instance ย้ายไปอยู่ที่ allocation_tracking.rs
+pub use super::allocation_tracking::{TRACKING_ALLOCATOR, TrackingAllocator};
 
 // ===== 4. Advanced Type System Features =====
 
@@ -1013,6 +940,7 @@ mod tests {
         assert!(has_circle_pixels);
     }
 
+    #[cfg(feature = "tracking-allocator")]
     #[test]
     fn test_tracking_allocator() {
         let initial_allocated = TRACKING_ALLOCATOR.allocated();
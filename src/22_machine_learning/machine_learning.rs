@@ -3,6 +3,10 @@
 //! การใช้ Rust สำหรับ Machine Learning และ Artificial Intelligence
 
 use std::collections::HashMap;
+use std::fmt;
+use std::ops::{Add, Index, IndexMut, Mul, Neg, Sub};
+
+use crate::random::{Distribution, Normal, Pcg32, Rng, SeedableRng};
 
 /// Vector operations for ML
 #[derive(Debug, Clone, PartialEq)]
@@ -68,15 +72,24 @@ impl Vector {
 }
 
 /// Matrix operations for ML
+///
+/// เป็น type กลางที่บท operator overloading (`traits::operators`) ใช้ด้วย
+/// จึงมี `+ - * []` ให้ใช้แทนการเรียก method ทีละตัว
 #[derive(Debug, Clone, PartialEq)]
-struct Matrix {
+pub struct Matrix {
     data: Vec<Vec<f64>>,
     rows: usize,
     cols: usize,
 }
 
 impl Matrix {
-    fn new(data: Vec<Vec<f64>>) -> Self {
+    /// สร้างจากแถว
+    ///
+    /// # Panics
+    ///
+    /// เมื่อแต่ละแถวยาวไม่เท่ากัน
+    #[must_use]
+    pub fn new(data: Vec<Vec<f64>>) -> Self {
         let rows = data.len();
         let cols = if rows > 0 { data[0].len() } else { 0 };
         
@@ -88,7 +101,8 @@ impl Matrix {
         Self { data, rows, cols }
     }
     
-    fn zeros(rows: usize, cols: usize) -> Self {
+    #[must_use]
+    pub fn zeros(rows: usize, cols: usize) -> Self {
         Self {
             data: vec![vec![0.0; cols]; rows],
             rows,
@@ -96,70 +110,211 @@ impl Matrix {
         }
     }
     
-    fn identity(size: usize) -> Self {
-        let mut data = vec![vec![0.0; size]; size];
+    #[must_use]
+    pub fn identity(size: usize) -> Self {
+        let mut matrix = Self::zeros(size, size);
         for i in 0..size {
-            data[i][i] = 1.0;
+            matrix[(i, i)] = 1.0;
         }
-        Self::new(data)
+        matrix
     }
     
-    fn get(&self, row: usize, col: usize) -> f64 {
-        if row >= self.rows || col >= self.cols {
-            panic!("Matrix index out of bounds: ({}, {}) for {}x{} matrix", row, col, self.rows, self.cols);
-        }
-        self.data[row][col]
+    #[must_use]
+    pub const fn rows(&self) -> usize {
+        self.rows
     }
     
-    fn set(&mut self, row: usize, col: usize, value: f64) {
-        if row >= self.rows || col >= self.cols {
-            panic!("Matrix index out of bounds: ({}, {}) for {}x{} matrix", row, col, self.rows, self.cols);
-        }
-        self.data[row][col] = value;
+    #[must_use]
+    pub const fn cols(&self) -> usize {
+        self.cols
     }
     
-    fn multiply(&self, other: &Self) -> Self {
-        assert_eq!(self.cols, other.rows);
+    fn multiply_vector(&self, vector: &Vector) -> Vector {
+        assert_eq!(self.cols, vector.len());
         
-        let mut result = Self::zeros(self.rows, other.cols);
+        let mut result = vec![0.0; self.rows];
         
         for i in 0..self.rows {
-            for j in 0..other.cols {
-                let mut sum = 0.0;
-                for k in 0..self.cols {
-                    sum += self.get(i, k) * other.get(k, j);
-                }
-                result.set(i, j, sum);
+            for j in 0..self.cols {
+                result[i] += self[(i, j)] * vector.data[j];
             }
         }
         
-        result
+        Vector::new(result)
     }
     
-    fn multiply_vector(&self, vector: &Vector) -> Vector {
-        assert_eq!(self.cols, vector.len());
-        
-        let mut result = vec![0.0; self.rows];
+    #[must_use]
+    pub fn transpose(&self) -> Self {
+        let mut transposed = Self::zeros(self.cols, self.rows);
         
         for i in 0..self.rows {
             for j in 0..self.cols {
-                result[i] += self.get(i, j) * vector.data[j];
+                transposed[(j, i)] = self[(i, j)];
             }
         }
         
-        Vector::new(result)
+        transposed
+    }
+    
+    fn check_index(&self, (row, col): (usize, usize)) {
+        assert!(
+            row < self.rows && col < self.cols,
+            "Matrix index out of bounds: ({row}, {col}) for {}x{} matrix",
+            self.rows,
+            self.cols
+        );
+    }
+    
+    fn zip_with(&self, rhs: &Self, op: &str, f: impl Fn(f64, f64) -> f64) -> Self {
+        assert!(
+            self.rows == rhs.rows && self.cols == rhs.cols,
+            "cannot {op} {}x{} and {}x{} matrices",
+            self.rows,
+            self.cols,
+            rhs.rows,
+            rhs.cols
+        );
+        let data = self
+            .data
+            .iter()
+            .zip(&rhs.data)
+            .map(|(a, b)| a.iter().zip(b).map(|(&a, &b)| f(a, b)).collect())
+            .collect();
+        Self::new(data)
     }
     
-    fn transpose(&self) -> Self {
-        let mut data = vec![vec![0.0; self.rows]; self.cols];
+    fn map(&self, f: impl Fn(f64) -> f64) -> Self {
+        Self::new(
+            self.data
+                .iter()
+                .map(|row| row.iter().map(|&x| f(x)).collect())
+                .collect(),
+        )
+    }
+}
+
+/// `m[(row, col)]`
+///
+/// # Panics
+///
+/// เมื่อ index อยู่นอก matrix
+impl Index<(usize, usize)> for Matrix {
+    type Output = f64;
+    
+    fn index(&self, index: (usize, usize)) -> &f64 {
+        self.check_index(index);
+        &self.data[index.0][index.1]
+    }
+}
+
+/// `m[(row, col)] = value`
+impl IndexMut<(usize, usize)> for Matrix {
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut f64 {
+        self.check_index(index);
+        &mut self.data[index.0][index.1]
+    }
+}
+
+/// `&a + &b` - ไม่กิน operand ทั้งสองข้าง
+impl Add<&Matrix> for &Matrix {
+    type Output = Matrix;
+    
+    fn add(self, rhs: &Matrix) -> Matrix {
+        self.zip_with(rhs, "add", |a, b| a + b)
+    }
+}
+
+/// `a + b` - ใช้ buffer ของ `a` ต่อ ไม่ต้อง alloc ใหม่
+impl Add for Matrix {
+    type Output = Self;
+    
+    fn add(mut self, rhs: Self) -> Self {
+        assert!(
+            self.rows == rhs.rows && self.cols == rhs.cols,
+            "cannot add {}x{} and {}x{} matrices",
+            self.rows,
+            self.cols,
+            rhs.rows,
+            rhs.cols
+        );
+        for (row, rhs_row) in self.data.iter_mut().zip(&rhs.data) {
+            row.iter_mut().zip(rhs_row).for_each(|(a, b)| *a += b);
+        }
+        self
+    }
+}
+
+impl Sub<&Matrix> for &Matrix {
+    type Output = Matrix;
+    
+    fn sub(self, rhs: &Matrix) -> Matrix {
+        self.zip_with(rhs, "subtract", |a, b| a - b)
+    }
+}
+
+impl Neg for &Matrix {
+    type Output = Matrix;
+    
+    fn neg(self) -> Matrix {
+        self.map(|x| -x)
+    }
+}
+
+impl Mul<f64> for &Matrix {
+    type Output = Matrix;
+    
+    fn mul(self, scalar: f64) -> Matrix {
+        self.map(|x| x * scalar)
+    }
+}
+
+/// ผลคูณ matrix - ไม่สลับที่ได้ (`&a * &b != &b * &a` โดยทั่วไป)
+///
+/// # Panics
+///
+/// เมื่อ `self.cols() != rhs.rows()`
+impl Mul<&Matrix> for &Matrix {
+    type Output = Matrix;
+    
+    fn mul(self, rhs: &Matrix) -> Matrix {
+        assert_eq!(
+            self.cols, rhs.rows,
+            "cannot multiply {}x{} by {}x{} matrix",
+            self.rows, self.cols, rhs.rows, rhs.cols
+        );
+        
+        let mut result = Matrix::zeros(self.rows, rhs.cols);
         
         for i in 0..self.rows {
-            for j in 0..self.cols {
-                data[j][i] = self.get(i, j);
+            for j in 0..rhs.cols {
+                result[(i, j)] = (0..self.cols).map(|k| self[(i, k)] * rhs[(k, j)]).sum();
             }
         }
         
-        Self::new(data)
+        result
+    }
+}
+
+/// แสดงทีละแถว `[a b]` - รองรับ `{:.2}` โดยส่ง precision ต่อให้ทุกสมาชิก
+impl fmt::Display for Matrix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.data.iter().enumerate() {
+            if i > 0 {
+                f.write_str("\n")?;
+            }
+            f.write_str("[")?;
+            for (j, value) in row.iter().enumerate() {
+                if j > 0 {
+                    f.write_str(" ")?;
+                }
+                match f.precision() {
+                    Some(precision) => write!(f, "{value:.precision$}")?,
+                    None => write!(f, "{value}")?,
+                }
+            }
+            f.write_str("]")?;
+        }
+        Ok(())
     }
 }
 
@@ -240,7 +395,8 @@ struct NeuralNetwork {
 }
 
 impl NeuralNetwork {
-    fn new(layer_sizes: &[usize], learning_rate: f64) -> Self {
+    /// ส่ง rng ที่ seed ไว้เข้ามาเพื่อให้ผลการ train ทำซ้ำได้
+    fn new<R: Rng>(layer_sizes: &[usize], learning_rate: f64, rng: &mut R) -> Self {
         let mut weights = Vec::new();
         let mut biases = Vec::new();
         
@@ -248,11 +404,15 @@ impl NeuralNetwork {
             let input_size = layer_sizes[i];
             let output_size = layer_sizes[i + 1];
             
-            // Initialize weights with small random values
+            // Xavier/Glorot init: std = √(2 / (fan_in + fan_out)) ให้ variance ของ signal คงที่ทุกชั้น
+            // fan-in + fan-out ที่เกิน 2^52 จัดสรร matrix น้ำหนักไม่ได้อยู่แล้ว การแปลงเป็น f64 จึงไม่เสียความแม่นยำจริง
+            #[allow(clippy::cast_precision_loss)]
+            let std_dev = (2.0 / (input_size + output_size) as f64).sqrt();
+            let init = Normal::new(0.0, std_dev);
             let mut weight_data = vec![vec![0.0; input_size]; output_size];
             for row in &mut weight_data {
                 for weight in row {
-                    *weight = (rand::random::<f64>() - 0.5) * 0.5;
+                    *weight = init.sample(rng);
                 }
             }
             weights.push(Matrix::new(weight_data));
@@ -363,10 +523,8 @@ impl NeuralNetwork {
         
         for i in 0..self.weights[layer_index].rows {
             for j in 0..self.weights[layer_index].cols {
-                let current_weight = self.weights[layer_index].get(i, j);
-                let gradient_value = gradient.get(i, j);
-                let new_weight = self.learning_rate.mul_add(-gradient_value, current_weight);
-                self.weights[layer_index].set(i, j, new_weight);
+                let weight = &mut self.weights[layer_index][(i, j)];
+                *weight = self.learning_rate.mul_add(-gradient[(i, j)], *weight);
             }
         }
     }
@@ -725,6 +883,20 @@ impl DecisionTree {
     }
 }
 
+/// เทรน Linear Regression 1 ตัวแปรบนจุด `(x, y)` แล้วทำนายค่า y ที่ `x`
+/// (ใช้โดย `crate::wasm` ให้ browser เรียกได้)
+#[must_use]
+pub fn fit_and_predict_linear(points: &[(f64, f64)], epochs: usize, x: f64) -> f64 {
+    let training_data: Vec<(Vector, f64)> = points
+        .iter()
+        .map(|&(x, y)| (Vector::new(vec![x]), y))
+        .collect();
+
+    let mut model = LinearRegression::new(1, 0.01);
+    model.train(&training_data, epochs);
+    model.predict(&Vector::new(vec![x]))
+}
+
 /// สาธิตการใช้งาน Machine Learning
 pub fn demonstrate_machine_learning() {
     println!("🤖 Machine Learning and AI Examples:");
@@ -758,7 +930,8 @@ pub fn demonstrate_machine_learning() {
     println!("\n🧠 Neural Network:");
     println!("{:-<50}", "");
     
-    let mut nn = NeuralNetwork::new(&[2, 3, 1], 0.1);
+    // seed ตายตัว (Pcg32 จากบท random) - รันกี่ครั้งก็ได้ผลเหมือนเดิม
+    let mut nn = NeuralNetwork::new(&[2, 3, 1], 0.1, &mut Pcg32::seed_from_u64(42));
     
     // XOR problem
     let nn_training_data = vec![
@@ -907,8 +1080,14 @@ mod tests {
         let m1 = Matrix::new(vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
         let m2 = Matrix::new(vec![vec![5.0, 6.0], vec![7.0, 8.0]]);
         
-        let result = m1.multiply(&m2);
+        let result = &m1 * &m2;
         assert_eq!(result.data, vec![vec![19.0, 22.0], vec![43.0, 50.0]]);
+        assert_eq!(&(&m1 + &m2) - &m2, m1);
+        assert_eq!(-&m1, &m1 * -1.0);
+        assert_eq!(m1.clone() + m2.clone(), &m1 + &m2);
+        assert_eq!((&m1 * &m2).transpose(), &m2.transpose() * &m1.transpose());
+        assert_eq!(&m1 * &Matrix::identity(2), m1);
+        assert!((m1[(1, 0)] - 3.0).abs() < f64::EPSILON);
     }
     
     #[test]
@@ -927,4 +1106,16 @@ mod tests {
         let mse = LossFunctions::mean_squared_error(&predicted, &actual);
         assert!((mse - 0.145).abs() < 1e-3);
     }
+    
+    #[test]
+    fn test_seeded_weight_init_is_reproducible() {
+        let a = NeuralNetwork::new(&[4, 8, 2], 0.1, &mut Pcg32::seed_from_u64(7));
+        let b = NeuralNetwork::new(&[4, 8, 2], 0.1, &mut Pcg32::seed_from_u64(7));
+        assert_eq!(a.weights[0].data, b.weights[0].data);
+        
+        // Xavier: std ของชั้นแรก = √(2 / 12) ≈ 0.41 - น้ำหนักไม่ควรห่างจาก 0 เกิน 4σ
+        let std_dev = (2.0_f64 / 12.0).sqrt();
+        assert!(a.weights[0].data.iter().flatten().all(|w| w.abs() < 4.0 * std_dev));
+        assert!(a.weights[0].data.iter().flatten().any(|w| *w != 0.0));
+    }
 }
\ No newline at end of file
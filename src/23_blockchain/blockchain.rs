@@ -3,9 +3,11 @@
 //! 🎯 การสร้าง Blockchain และ Cryptocurrency สำหรับเว็บแอปพลิเคชัน - เหมือนการสร้างธนาคารดิจิทัลในเวิร์กช็อป!
 
 use std::collections::HashMap;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
 use std::fmt;
 
+use crate::interpreter::{self, Value, Vm};
+
 /// 🔐 ฟังก์ชันแฮชแบบเวิร์กช็อป (simplified SHA-256) - เครื่องมือสร้างลายเซ็นดิจิทัล
 fn simple_hash(input: &str) -> String {
     use std::collections::hash_map::DefaultHasher;
@@ -16,6 +18,23 @@ fn simple_hash(input: &str) -> String {
     format!("{:016x}", hasher.finish())
 }
 
+/// ⏰ เวลาปัจจุบัน (วินาทีนับจาก UNIX epoch)
+///
+/// บน wasm32 ใน browser ไม่มีนาฬิกาของระบบ (`SystemTime::now()` จะ panic) จึงคืน 0 แทน
+fn unix_timestamp() -> u64 {
+    if cfg!(target_arch = "wasm32") {
+        return 0;
+    }
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+/// ⏱️ เริ่มจับเวลา - บน wasm32 ไม่จับ (`Instant::now()` panic เหมือนกัน)
+fn start_timer() -> Option<Instant> {
+    (!cfg!(target_arch = "wasm32")).then(Instant::now)
+}
+
 /// 💰 โครงสร้างธุรกรรม - ระบบการโอนเงินดิจิทัลในเวิร์กช็อป
 #[derive(Debug, Clone, PartialEq)]
 struct Transaction {
@@ -29,10 +48,7 @@ struct Transaction {
 
 impl Transaction {
     fn new(from: String, to: String, amount: f64) -> Self {
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
+        let timestamp = unix_timestamp();
         
         let id = simple_hash(&format!("{from}{to}{amount}{timestamp}"));
         
@@ -83,10 +99,7 @@ struct Block {
 
 impl Block {
     fn new(index: u64, transactions: Vec<Transaction>, previous_hash: String) -> Self {
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
+        let timestamp = unix_timestamp();
         
         let merkle_root = Self::calculate_merkle_root(&transactions);
         
@@ -148,18 +161,18 @@ impl Block {
         let target = "0".repeat(difficulty);
         
         println!("Mining block {}...", self.index);
-        let start_time = SystemTime::now();
+        let start_time = start_timer();
         
         loop {
             self.hash = self.calculate_hash();
             
             if self.hash.starts_with(&target) {
-                let duration = start_time.elapsed().unwrap();
+                let seconds = start_time.map_or(0.0, |start| start.elapsed().as_secs_f64());
                 println!(
                     "Block mined: {} (nonce: {}, time: {:.2}s)",
                     self.hash,
                     self.nonce,
-                    duration.as_secs_f64()
+                    seconds
                 );
                 break;
             }
@@ -266,11 +279,11 @@ impl Blockchain {
     fn mine_pending_transactions(&mut self, mining_reward_address: &str) {
         // Add mining reward transaction
         let reward_transaction = Transaction {
-            id: simple_hash(&format!("mining_reward_{}", SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs())),
+            id: simple_hash(&format!("mining_reward_{}", unix_timestamp())),
             from: "mining_reward".to_string(),
             to: mining_reward_address.to_string(),
             amount: self.mining_reward,
-            timestamp: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs(),
+            timestamp: unix_timestamp(),
             signature: Some("mining_reward_signature".to_string()),
         };
         
@@ -411,7 +424,41 @@ impl SmartContract {
             _ => Err(format!("Unknown function: {function}"))
         }
     }
+
+    /// ⛽ เรียกฟังก์ชันในโค้ดของสัญญา (ภาษา Mini) ด้วย VM ตัวเดียวกับ `--script`
+    /// ทุกการเรียกจำกัด gas - สัญญาที่วนไม่รู้จบจะหยุดเองแทนที่จะทำให้ทั้ง node ค้าง
+    fn call_script(&self, function: &str, args: &[i64], gas_limit: u64) -> Result<(i64, u64), String> {
+        let chunk = interpreter::compile_source(&self.code)
+            .map_err(|error| format!("compile error {}: {error}", error.position))?;
+        let args: Vec<Value> = args.iter().map(|&arg| Value::Int(arg)).collect();
+        let outcome = Vm::new()
+            .with_gas_limit(gas_limit)
+            .call(&chunk, function, &args, &mut std::io::sink())
+            .map_err(|error| error.to_string())?;
+        match outcome.value {
+            Value::Int(value) => Ok((value, outcome.gas_used)),
+            other => Err(format!("{function}() คืน {} แทน int", other.type_name())),
+        }
+    }
+}
+
+/// 💸 สัญญาคิดค่าธรรมเนียม 0.3% (ขั้นต่ำ 1) เขียนเป็นสคริปต์ - `spin` มีไว้โชว์ว่า gas หยุด loop ได้
+const FEE_CONTRACT: &str = "
+fn fee(amount) {
+    let fee = amount * 3 / 1000;
+    if fee < 1 {
+        return 1;
+    }
+    return fee;
+}
+
+fn spin(n) {
+    while true {
+        n = n + 1;
+    }
+    return n;
 }
+";
 
 /// 💎 โครงสร้างสกุลเงินดิจิทัล - ระบบเงินดิจิทัลแบบครบครันสำหรับเวิร์กช็อป
 #[derive(Debug)]
@@ -546,6 +593,31 @@ impl MiningPool {
     }
 }
 
+/// ✅ สร้างบล็อกเชนจากรายการโอน `(from, to, amount)` แล้วตรวจความถูกต้องของทั้งเชน
+///
+/// `miner` ขุดบล็อกแรกเพื่อรับรางวัลเป็นทุนตั้งต้น จากนั้นขุดทีละบล็อกต่อการโอน 1 รายการ
+/// (รายการที่ยอดเงินไม่พอจะถูกปฏิเสธ) ถ้า `tamper` เป็น `true` จะแอบยัดธุรกรรมปลอม
+/// ลงบล็อกที่ขุดไปแล้วก่อนตรวจ - merkle root ไม่ตรง เชนจึงต้องไม่ผ่าน
+/// (ใช้โดย `crate::wasm` ให้ browser เรียกได้)
+#[must_use]
+pub fn validate_transfer_chain(miner: &str, transfers: &[(String, String, f64)], tamper: bool) -> bool {
+    let mut blockchain = Blockchain::new();
+    blockchain.mine_pending_transactions(miner);
+
+    for (from, to, amount) in transfers {
+        blockchain.add_transaction(Transaction::new(from.clone(), to.clone(), *amount));
+        blockchain.mine_pending_transactions(miner);
+    }
+
+    if tamper {
+        let mut forged = Transaction::new(miner.to_string(), "attacker".to_string(), 1_000_000.0);
+        forged.sign("forged_private_key");
+        blockchain.chain[1].transactions.push(forged);
+    }
+
+    blockchain.is_chain_valid()
+}
+
 /// 🎯 สาธิตการใช้งาน Blockchain และ Cryptocurrency ในเวิร์กช็อป
 pub fn demonstrate_blockchain() {
     println!("⛓️ 🎓 ตัวอย่างการใช้งาน Blockchain และ Cryptocurrency ในเวิร์กช็อป Web Development!");
@@ -640,6 +712,15 @@ pub fn demonstrate_blockchain() {
         Err(error) => println!("❌ ข้อผิดพลาดในการสอบถาม: {error}"),
     }
     
+    println!("\n⛽ สัญญาที่เป็นโค้ดจริง รันบน VM ของ interpreter พร้อมจำกัด gas:");
+    let fee_contract = SmartContract::new(FEE_CONTRACT.to_string(), alice_wallet.address.clone());
+    for (function, amount) in [("fee", 250_000), ("fee", 50), ("spin", 0)] {
+        match fee_contract.call_script(function, &[amount], 1_000) {
+            Ok((value, gas)) => println!("   ✅ {function}({amount}) = {value} (ใช้ gas {gas})"),
+            Err(error) => println!("   ❌ {function}({amount}): {error}"),
+        }
+    }
+    
     // Mining Pool Demo
     println!("\n🏊 ⛏️ การสาธิตกลุ่มขุดเหมืองในเวิร์กช็อป:");
     println!("{:-<50}", "");
@@ -753,6 +834,17 @@ mod tests {
         assert_eq!(result.unwrap(), "value");
     }
     
+    #[test]
+    fn test_script_contract_with_gas() {
+        let contract = SmartContract::new(FEE_CONTRACT.to_string(), "owner".to_string());
+        assert_eq!(contract.call_script("fee", &[250_000], 1_000).unwrap().0, 750);
+        assert_eq!(contract.call_script("fee", &[50], 1_000).unwrap().0, 1);
+        
+        let error = contract.call_script("spin", &[0], 1_000).unwrap_err();
+        assert!(error.contains("gas หมด"), "{error}");
+        assert!(contract.call_script("withdraw", &[1], 1_000).is_err());
+    }
+    
     #[test]
     fn test_mining_pool() {
         let mut pool = MiningPool::new("TestPool".to_string());
@@ -0,0 +1,222 @@
+//! 🎯 Difficulty Target - เป้าหมายการขุดแบบ Bitcoin บน [`BigUint`]
+//!
+//! `mine_block` ในบทนี้นับ "เลข 0 นำหน้า" ของ hash ซึ่งปรับความยากได้ทีละ 16 เท่าเท่านั้น
+//! Bitcoin ใช้ **target** เป็นจำนวนเต็ม 256 bit แทน: บล็อกผ่านเมื่อ `hash ≤ target`
+//! จึงปรับความยากได้ละเอียดทุกระดับ
+//!
+//! - 📦 Compact ("nBits"): เก็บ target ใน `u32` แบบ floating point ฐาน 256 (exponent 1 byte + mantissa 3 byte)
+//! - 📈 Difficulty: `max_target / target` - ตัวเลขที่มนุษย์อ่านง่าย
+//! - ⚖️ Retarget: ทุกช่วงปรับ target ตามเวลาที่ใช้จริง จำกัดไม่ให้เปลี่ยนเกิน 4 เท่า
+
+use sha2::{Digest, Sha256};
+
+use crate::bigint::BigUint;
+
+/// target ง่ายสุดของ Bitcoin mainnet (genesis block) ในรูป compact
+pub const MAX_TARGET_COMPACT: u32 = 0x1d00_ffff;
+
+/// bit เครื่องหมายของ mantissa - target ต้องไม่ติดลบ
+const COMPACT_SIGN_BIT: u32 = 0x0080_0000;
+
+/// 📦 แปลง compact → target (`mantissa × 256^(exponent - 3)`)
+///
+/// คืน `None` เมื่อ bit เครื่องหมายถูกตั้ง (target ติดลบใช้ไม่ได้)
+#[must_use]
+pub fn compact_to_target(compact: u32) -> Option<BigUint> {
+    let exponent = compact >> 24;
+    let mantissa = compact & 0x007f_ffff;
+    if compact & COMPACT_SIGN_BIT != 0 && mantissa != 0 {
+        return None;
+    }
+    let mantissa = BigUint::from(u64::from(mantissa));
+    Some(if exponent <= 3 {
+        mantissa >> (8 * (3 - exponent) as usize)
+    } else {
+        mantissa << (8 * (exponent - 3) as usize)
+    })
+}
+
+/// 📦 แปลง target → compact (ปัดทิ้ง byte ที่เกิน 3 byte แรก เหมือน Bitcoin Core)
+///
+/// # Panics
+/// เมื่อ target ยาวเกิน 255 byte ซึ่งเก็บใน exponent ไม่ได้
+#[must_use]
+pub fn target_to_compact(target: &BigUint) -> u32 {
+    let mut size = u32::try_from(target.bits().div_ceil(8)).expect("target fits in 255 bytes");
+    let mut mantissa = if size <= 3 {
+        target.to_u64().unwrap_or(0) << (8 * (3 - size))
+    } else {
+        (target >> (8 * (size - 3) as usize)).to_u64().unwrap_or(0)
+    };
+    // ถ้า bit สูงสุดของ mantissa เป็น 1 จะถูกอ่านเป็นค่าติดลบ → เลื่อนไปอีก byte
+    if mantissa & u64::from(COMPACT_SIGN_BIT) != 0 {
+        mantissa >>= 8;
+        size += 1;
+    }
+    assert!(size <= 0xff, "target too large for compact encoding");
+    #[allow(clippy::cast_possible_truncation)] // mantissa เหลือแค่ 23 bit หลังเลื่อน
+    let mantissa = mantissa as u32;
+    (size << 24) | mantissa
+}
+
+/// target ที่ง่ายที่สุดของ mainnet
+///
+/// # Panics
+/// ไม่เกิดขึ้นจริง - [`MAX_TARGET_COMPACT`] เป็นค่าที่ถูกต้องเสมอ
+#[must_use]
+pub fn max_target() -> BigUint {
+    compact_to_target(MAX_TARGET_COMPACT).expect("mainnet limit is a valid compact value")
+}
+
+/// 📈 ความยากเทียบกับ [`max_target`] (genesis = 1.0)
+///
+/// # Panics
+/// เมื่อ target เป็นศูนย์
+#[must_use]
+pub fn difficulty(target: &BigUint) -> f64 {
+    assert!(!target.is_zero(), "target must be positive");
+    max_target().to_f64() / target.to_f64()
+}
+
+/// target ที่เทียบเท่า "hash ต้องขึ้นต้นด้วย 0 จำนวน `hex_zeros` ตัว" แบบ `mine_block`
+///
+/// # Panics
+/// เมื่อ `hex_zeros` เกิน 64 (hash SHA-256 มีแค่ 64 หลัก)
+#[must_use]
+pub fn leading_zeros_target(hex_zeros: u32) -> BigUint {
+    assert!(hex_zeros <= 64, "SHA-256 has only 64 hex digits");
+    &(BigUint::one() << (256 - 4 * hex_zeros as usize)) - &BigUint::one()
+}
+
+/// ✅ hash (big-endian) ผ่านเป้าหมายหรือไม่
+#[must_use]
+pub fn meets_target(hash: &[u8], target: &BigUint) -> bool {
+    BigUint::from_bytes_be(hash) <= *target
+}
+
+/// ⚖️ ปรับ target ตามเวลาที่ใช้ขุดจริง: `old × actual / expected`
+///
+/// `actual_secs` ถูกบีบให้อยู่ใน `[expected / 4, expected × 4]` เพื่อกันความยากแกว่งแรง
+/// และผลลัพธ์ไม่เกิน `limit`
+///
+/// # Panics
+/// เมื่อ `expected_secs` เป็นศูนย์
+#[must_use]
+pub fn retarget(old: &BigUint, actual_secs: u64, expected_secs: u64, limit: &BigUint) -> BigUint {
+    assert!(expected_secs > 0, "expected timespan must be positive");
+    let actual = actual_secs.clamp(expected_secs / 4, expected_secs.saturating_mul(4));
+    let next = &(old * &BigUint::from(actual)) / &BigUint::from(expected_secs);
+    if next > *limit { limit.clone() } else { next }
+}
+
+/// ⛏️ หา nonce ที่ทำให้ `SHA-256(header || nonce)` ≤ target
+#[must_use]
+pub fn mine_with_target(
+    header: &[u8],
+    target: &BigUint,
+    max_nonce: u64,
+) -> Option<(u64, [u8; 32])> {
+    (0..max_nonce).find_map(|nonce| {
+        let mut hasher = Sha256::new();
+        hasher.update(header);
+        hasher.update(nonce.to_le_bytes());
+        let hash: [u8; 32] = hasher.finalize().into();
+        meets_target(&hash, target).then_some((nonce, hash))
+    })
+}
+
+/// 🎪 เดโม: compact ↔ target, ขุดด้วย target ง่าย ๆ แล้วปรับความยากตามเวลา
+///
+/// # Panics
+/// ไม่เกิดขึ้นจริง - ค่า compact ในเดโมเป็นค่าคงที่ที่ถูกต้อง
+pub fn demonstrate_difficulty() {
+    println!("🎯 Difficulty Target แบบ Bitcoin");
+
+    let genesis = max_target();
+    println!("   📦 0x{MAX_TARGET_COMPACT:08x} → target {genesis:#066x}");
+    let real = compact_to_target(0x1b04_04cb).expect("valid compact");
+    println!(
+        "   📈 0x1b0404cb → difficulty {:.2} (ต้องลอง hash มากกว่า genesis ~{:.0} เท่า)",
+        difficulty(&real),
+        difficulty(&real)
+    );
+    let two_zeros = leading_zeros_target(2);
+    println!(
+        "   🔁 \"00\" นำหน้าแบบ mine_block = target {:#066x} → compact 0x{:08x}",
+        two_zeros,
+        target_to_compact(&two_zeros)
+    );
+
+    let easy_compact = 0x1f00_ffff;
+    let easy = compact_to_target(easy_compact).expect("valid compact");
+    match mine_with_target(b"block #1: Alice -> Bob 5 coins", &easy, 10_000_000) {
+        Some((nonce, hash)) => println!(
+            "   ⛏️ ขุดด้วย 0x{easy_compact:08x} ได้ nonce {nonce} → hash {}",
+            hex::encode(hash)
+        ),
+        None => println!("   ⛏️ ไม่พบ nonce ในช่วงที่กำหนด"),
+    }
+
+    let expected = 10 * 60 * 2016;
+    let limit = compact_to_target(0x2000_ffff).expect("valid compact");
+    for (label, actual) in [
+        ("เร็วไป 2 เท่า", expected / 2),
+        ("ช้าไป 10 เท่า (ถูกบีบเหลือ 4)", expected * 10),
+    ] {
+        let next = retarget(&easy, actual, expected, &limit);
+        println!(
+            "   ⚖️ {label}: compact 0x{:08x} → 0x{:08x} (ความยาก x{:.2})",
+            easy_compact,
+            target_to_compact(&next),
+            easy.to_f64() / next.to_f64()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compact_roundtrip_and_known_values() {
+        assert_eq!(max_target(), BigUint::from(0xffff_u64) << 208);
+        assert_eq!(target_to_compact(&max_target()), MAX_TARGET_COMPACT);
+
+        for compact in [
+            0x1b04_04cb,
+            0x1d00_ffff,
+            0x1f00_ffff,
+            0x0312_3456,
+            0x0200_8000,
+        ] {
+            let target = compact_to_target(compact).unwrap();
+            assert_eq!(target_to_compact(&target), compact, "{compact:08x}");
+        }
+        // mantissa ที่ bit สูงสุดเป็น 1 ต้องเลื่อนไปอีก byte
+        assert_eq!(target_to_compact(&BigUint::from(0x80_u64)), 0x0200_8000);
+        assert_eq!(compact_to_target(0x0180_0001), None);
+
+        let real = compact_to_target(0x1b04_04cb).unwrap();
+        assert!((difficulty(&real) - 16_307.420_938_523_983).abs() < 1e-6);
+        assert!((difficulty(&max_target()) - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_retarget_clamps_and_caps() {
+        let limit = max_target();
+        let old = compact_to_target(0x1b04_04cb).unwrap();
+        assert_eq!(retarget(&old, 600, 1200, &limit), &old >> 1);
+        assert_eq!(retarget(&old, 1, 1200, &limit), &old >> 2);
+        assert_eq!(retarget(&old, 1_000_000, 1200, &limit), &old << 2);
+        assert_eq!(retarget(&limit, 2400, 1200, &limit), limit);
+    }
+
+    #[test]
+    fn test_mining_meets_target() {
+        let target = leading_zeros_target(2);
+        let (_, hash) = mine_with_target(b"header", &target, 1_000_000).unwrap();
+        assert!(hex::encode(hash).starts_with("00"));
+        assert!(meets_target(&hash, &target));
+        assert!(!meets_target(&[0xff; 32], &target));
+    }
+}
@@ -5,12 +5,15 @@
 //! - 🔗 Blockchain basics - พื้นฐานการสร้างโซ่บล็อคแบบมืออาชีพ
 //! - 💰 Cryptocurrency - การออกแบบเงินดิจิทัลสำหรับเวิร์กช็อป
 //! - ⛏️ Mining และ consensus - ระบบขุดและการตกลงร่วมแบบเวิร์กช็อป
+//! - 🎯 Difficulty target - target แบบ compact และการปรับความยากตามเวลาขุด
 //! - 📜 Smart contracts - การเขียนสัญญาอัจฉริยะสำหรับเว็บแอป
 //! - 🌐 Decentralization - การกระจายอำนาจในระบบเว็บแอปพลิเคชัน
 
 pub mod blockchain;
+pub mod difficulty;
 
 pub use blockchain::*;
+pub use difficulty::*;
 
 /// 🎪 เรียกใช้ตัวอย่าง Blockchain และ Cryptocurrency - เวิร์กช็อปสร้างระบบบล็อกเชน! 🏗️
 pub fn run_blockchain_examples() {
@@ -19,6 +22,9 @@ pub fn run_blockchain_examples() {
     
     blockchain::demonstrate_blockchain();
     
+    println!();
+    difficulty::demonstrate_difficulty();
+    
     println!("\n🎉 === เวิร์กช็อป Blockchain เสร็จสิ้น! === 🏆");
     println!("🎯 คุณได้เรียนรู้การสร้างระบบบล็อกเชนสำหรับเว็บแอปเหล่านี้:");
     println!("   🔗 Blockchain basics (การสร้างโซ่บล็อคแบบมืออาชีพ!)");
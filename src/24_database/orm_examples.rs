@@ -55,6 +55,7 @@ pub struct Post {
 pub struct QueryBuilder<T> {
     table_name: String,
     conditions: Vec<String>,
+    params: Vec<String>,
     order_by: Vec<String>,
     limit: Option<usize>,
     offset: Option<usize>,
@@ -67,6 +68,7 @@ impl<T> QueryBuilder<T> {
         Self {
             table_name: table_name.to_string(),
             conditions: Vec::new(),
+            params: Vec::new(),
             order_by: Vec::new(),
             limit: None,
             offset: None,
@@ -80,6 +82,21 @@ impl<T> QueryBuilder<T> {
         self
     }
     
+    /// เพิ่มเงื่อนไข WHERE แบบ parameter binding: SQL ได้แค่ `column = ?` ส่วนค่าเก็บแยกไว้ใน `params()`
+    /// ค่าจึงไม่มีทางกลายเป็นส่วนหนึ่งของคำสั่ง SQL (ต่างจาก `where_eq` ที่ต่อ string ตรงๆ)
+    #[must_use]
+    pub fn where_bind(mut self, column: &str, value: &str) -> Self {
+        self.conditions.push(format!("{column} = ?"));
+        self.params.push(value.to_string());
+        self
+    }
+    
+    /// ค่าที่ bind ไว้ เรียงตามลำดับ `?` ใน `to_sql()`
+    #[must_use]
+    pub fn params(&self) -> &[String] {
+        &self.params
+    }
+    
     /// เพิ่มเงื่อนไข WHERE LIKE
     pub fn where_like(mut self, column: &str, pattern: &str) -> Self {
         self.conditions.push(format!("{} LIKE '%{}%'", column, pattern));
@@ -532,6 +549,16 @@ mod tests {
         assert!(query.contains("LIMIT 10"));
     }
     
+    #[test]
+    fn test_query_builder_where_bind() {
+        let query = QueryBuilder::<User>::new("users")
+            .where_bind("username", "x' OR '1'='1")
+            .limit(1);
+        
+        assert_eq!(query.to_sql(), "SELECT * FROM users WHERE username = ? LIMIT 1");
+        assert_eq!(query.params(), ["x' OR '1'='1"]);
+    }
+    
     #[test]
     fn test_user_repository() {
         let mut repo = UserRepository::new();
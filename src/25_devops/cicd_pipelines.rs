@@ -3,8 +3,8 @@
 //! โมดูลนี้สาธิตการสร้าง CI/CD pipelines สำหรับ Rust projects
 //! รวมถึง GitHub Actions, GitLab CI, และ Jenkins
 
-use std::collections::HashMap;
-use std::fmt;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::{self, Write as _};
 
 /// 🔄 ประเภทของ CI/CD Platform
 #[derive(Debug, Clone, PartialEq)]
@@ -484,6 +484,582 @@ impl PipelineConfig {
     }
 }
 
+/// ❌ ข้อผิดพลาดของ `Pipeline` ที่จับได้ก่อนเขียน YAML
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PipelineError {
+    /// ยังไม่มี job เลย
+    Empty,
+    /// ชื่อ job ซ้ำ
+    DuplicateJob(String),
+    /// ชื่อ job ใช้เป็น key ของ YAML ไม่ได้ (ใช้ได้แค่ `a-z`, `0-9`, `-`, `_`)
+    InvalidJobName(String),
+    /// job อยู่ใน stage ที่ไม่ได้ประกาศ
+    UnknownStage { job: String, stage: String },
+    /// `needs` อ้างถึง job ที่ไม่มี
+    UnknownJob { job: String, needs: String },
+    /// อ้างถึงแกน matrix ที่ job ไม่มี
+    UnknownMatrixAxis { job: String, axis: String },
+}
+
+impl fmt::Display for PipelineError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "pipeline has no jobs"),
+            Self::DuplicateJob(job) => write!(f, "job '{job}' is defined twice"),
+            Self::InvalidJobName(job) => {
+                write!(f, "job name '{job}' may only contain a-z, 0-9, '-' and '_'")
+            }
+            Self::UnknownStage { job, stage } => {
+                write!(f, "job '{job}' uses undeclared stage '{stage}'")
+            }
+            Self::UnknownJob { job, needs } => write!(f, "job '{job}' needs unknown job '{needs}'"),
+            Self::UnknownMatrixAxis { job, axis } => {
+                write!(f, "job '{job}' refers to matrix axis '{axis}' it does not define")
+            }
+        }
+    }
+}
+
+impl std::error::Error for PipelineError {}
+
+/// 🚦 เหตุการณ์ที่ทำให้ pipeline รัน
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Trigger {
+    Push { branches: Vec<String> },
+    PullRequest { branches: Vec<String> },
+    /// cron แบบ 5 ช่อง เช่น `0 3 * * 1`
+    Schedule(String),
+    /// กดรันเองจากหน้าเว็บ
+    Manual,
+}
+
+/// ค่าที่กำหนดตายตัว หรืออ่านจากแกนของ matrix
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Param {
+    Fixed(String),
+    Matrix(String),
+}
+
+impl Param {
+    #[must_use]
+    pub fn fixed(value: &str) -> Self {
+        Self::Fixed(value.to_string())
+    }
+
+    #[must_use]
+    pub fn matrix(axis: &str) -> Self {
+        Self::Matrix(axis.to_string())
+    }
+
+    fn github(&self) -> String {
+        match self {
+            Self::Fixed(value) => value.clone(),
+            Self::Matrix(axis) => format!("${{{{ matrix.{axis} }}}}"),
+        }
+    }
+}
+
+/// 👣 ขั้นตอนหนึ่งใน job
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Step {
+    /// clone repo (GitLab ทำให้เองอยู่แล้ว)
+    Checkout,
+    /// ติดตั้ง Rust toolchain (`stable`, `beta`, `1.75`, ...) พร้อม components
+    Toolchain { version: Param, components: Vec<String> },
+    /// cache `~/.cargo` และ `target/` โดยใช้ `Cargo.lock` เป็น key
+    CargoCache,
+    Run { name: String, command: String },
+    UploadArtifact { name: String, paths: Vec<String> },
+}
+
+impl Step {
+    #[must_use]
+    pub fn toolchain(version: Param, components: &[&str]) -> Self {
+        Self::Toolchain {
+            version,
+            components: components.iter().map(|c| (*c).to_string()).collect(),
+        }
+    }
+
+    #[must_use]
+    pub fn run(name: &str, command: &str) -> Self {
+        Self::Run {
+            name: name.to_string(),
+            command: command.to_string(),
+        }
+    }
+
+    #[must_use]
+    pub fn upload(name: &str, paths: &[&str]) -> Self {
+        Self::UploadArtifact {
+            name: name.to_string(),
+            paths: paths.iter().map(|p| (*p).to_string()).collect(),
+        }
+    }
+}
+
+/// 🧰 job หนึ่งตัว - รันบนเครื่องเดียว (หรือหลายเครื่องถ้ามี matrix)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Job {
+    pub name: String,
+    pub stage: String,
+    pub runs_on: Param,
+    pub needs: Vec<String>,
+    /// แกนของ matrix เรียงตามลำดับที่เพิ่ม เช่น `os` × `rust`
+    pub matrix: Vec<(String, Vec<String>)>,
+    pub env: BTreeMap<String, String>,
+    pub steps: Vec<Step>,
+}
+
+impl Job {
+    /// job ใหม่ที่รันบน `ubuntu-latest`
+    #[must_use]
+    pub fn new(name: &str, stage: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            stage: stage.to_string(),
+            runs_on: Param::fixed("ubuntu-latest"),
+            needs: Vec::new(),
+            matrix: Vec::new(),
+            env: BTreeMap::new(),
+            steps: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn runs_on(mut self, runner: Param) -> Self {
+        self.runs_on = runner;
+        self
+    }
+
+    #[must_use]
+    pub fn needs(mut self, job: &str) -> Self {
+        self.needs.push(job.to_string());
+        self
+    }
+
+    #[must_use]
+    pub fn matrix(mut self, axis: &str, values: &[&str]) -> Self {
+        self.matrix
+            .push((axis.to_string(), values.iter().map(|v| (*v).to_string()).collect()));
+        self
+    }
+
+    #[must_use]
+    pub fn env(mut self, key: &str, value: &str) -> Self {
+        self.env.insert(key.to_string(), value.to_string());
+        self
+    }
+
+    #[must_use]
+    pub fn step(mut self, step: Step) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    fn params(&self) -> impl Iterator<Item = &Param> {
+        std::iter::once(&self.runs_on).chain(self.steps.iter().filter_map(|step| match step {
+            Step::Toolchain { version, .. } => Some(version),
+            _ => None,
+        }))
+    }
+}
+
+/// 🔄 pipeline ที่ไม่ผูกกับ platform - เขียนออกเป็น GitHub Actions หรือ GitLab CI ได้
+///
+/// stage กำหนดลำดับแบบ GitLab: job ที่ไม่ได้ระบุ `needs` จะรอทุก job ใน stage ก่อนหน้า
+/// (ใน GitHub Actions จึงได้ `needs` ที่คำนวณให้อัตโนมัติ)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pipeline {
+    pub name: String,
+    pub triggers: Vec<Trigger>,
+    pub env: BTreeMap<String, String>,
+    pub stages: Vec<String>,
+    pub jobs: Vec<Job>,
+}
+
+impl Pipeline {
+    #[must_use]
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            triggers: Vec::new(),
+            env: BTreeMap::new(),
+            stages: Vec::new(),
+            jobs: Vec::new(),
+        }
+    }
+
+    /// pipeline มาตรฐานของโปรเจค Rust: lint → test (matrix OS × toolchain) → build release
+    #[must_use]
+    pub fn rust_ci(name: &str, binary: &str) -> Self {
+        let setup = |job: Job, version: Param| {
+            job.step(Step::Checkout)
+                .step(Step::toolchain(version, &["rustfmt", "clippy"]))
+                .step(Step::CargoCache)
+        };
+
+        Self::new(name)
+            .trigger(Trigger::Push { branches: vec!["main".to_string()] })
+            .trigger(Trigger::PullRequest { branches: vec!["main".to_string()] })
+            .env("CARGO_TERM_COLOR", "always")
+            .stage("check")
+            .stage("test")
+            .stage("build")
+            .job(
+                setup(Job::new("lint", "check"), Param::fixed("stable"))
+                    .step(Step::run("Format", "cargo fmt --all -- --check"))
+                    .step(Step::run("Clippy", "cargo clippy --all-targets -- -D warnings")),
+            )
+            .job(
+                setup(
+                    Job::new("test", "test")
+                        .runs_on(Param::matrix("os"))
+                        .matrix("os", &["ubuntu-latest", "macos-latest", "windows-latest"])
+                        .matrix("rust", &["stable", "beta"]),
+                    Param::matrix("rust"),
+                )
+                .step(Step::run("Test", "cargo test --workspace")),
+            )
+            .job(
+                setup(Job::new("build", "build"), Param::fixed("stable"))
+                    .step(Step::run("Build", "cargo build --release"))
+                    .step(Step::upload(binary, &[&format!("target/release/{binary}")])),
+            )
+    }
+
+    #[must_use]
+    pub fn trigger(mut self, trigger: Trigger) -> Self {
+        self.triggers.push(trigger);
+        self
+    }
+
+    #[must_use]
+    pub fn env(mut self, key: &str, value: &str) -> Self {
+        self.env.insert(key.to_string(), value.to_string());
+        self
+    }
+
+    #[must_use]
+    pub fn stage(mut self, stage: &str) -> Self {
+        self.stages.push(stage.to_string());
+        self
+    }
+
+    #[must_use]
+    pub fn job(mut self, job: Job) -> Self {
+        self.jobs.push(job);
+        self
+    }
+
+    /// ตรวจชื่อ job, stage, `needs` และแกน matrix
+    ///
+    /// # Errors
+    ///
+    /// คืน `PipelineError` ตัวแรกที่เจอ
+    pub fn validate(&self) -> Result<(), PipelineError> {
+        if self.jobs.is_empty() {
+            return Err(PipelineError::Empty);
+        }
+        for (index, job) in self.jobs.iter().enumerate() {
+            let valid_name = !job.name.is_empty()
+                && job
+                    .name
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_');
+            if !valid_name {
+                return Err(PipelineError::InvalidJobName(job.name.clone()));
+            }
+            if self.jobs[..index].iter().any(|other| other.name == job.name) {
+                return Err(PipelineError::DuplicateJob(job.name.clone()));
+            }
+            if !self.stages.contains(&job.stage) {
+                return Err(PipelineError::UnknownStage {
+                    job: job.name.clone(),
+                    stage: job.stage.clone(),
+                });
+            }
+            if let Some(missing) = job
+                .needs
+                .iter()
+                .find(|needed| !self.jobs.iter().any(|other| &other.name == *needed))
+            {
+                return Err(PipelineError::UnknownJob {
+                    job: job.name.clone(),
+                    needs: missing.clone(),
+                });
+            }
+            for param in job.params() {
+                if let Param::Matrix(axis) = param
+                    && !job.matrix.iter().any(|(name, _)| name == axis)
+                {
+                    return Err(PipelineError::UnknownMatrixAxis {
+                        job: job.name.clone(),
+                        axis: axis.clone(),
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// `needs` ของ job - ถ้าไม่ได้ระบุเองคือทุก job ใน stage ก่อนหน้า
+    fn effective_needs(&self, job: &Job) -> Vec<String> {
+        if !job.needs.is_empty() {
+            return job.needs.clone();
+        }
+        let position = self.stages.iter().position(|stage| *stage == job.stage);
+        let Some(previous) = position.and_then(|p| p.checked_sub(1)).map(|p| &self.stages[p]) else {
+            return Vec::new();
+        };
+        self.jobs
+            .iter()
+            .filter(|other| other.stage == *previous)
+            .map(|other| other.name.clone())
+            .collect()
+    }
+
+    /// 🐙 เขียนเป็น GitHub Actions workflow (`.github/workflows/ci.yml`)
+    ///
+    /// # Errors
+    ///
+    /// คืน error จาก `validate`
+    pub fn to_github_actions(&self) -> Result<String, PipelineError> {
+        self.validate()?;
+        let mut yaml = String::new();
+        let _ = writeln!(yaml, "name: {}", yaml_scalar(&self.name));
+        yaml.push_str("on:\n");
+        for trigger in &self.triggers {
+            match trigger {
+                Trigger::Push { branches } | Trigger::PullRequest { branches } => {
+                    let event = if matches!(trigger, Trigger::Push { .. }) { "push" } else { "pull_request" };
+                    let _ = writeln!(yaml, "  {event}:");
+                    yaml.push_str("    branches:\n");
+                    write_list(&mut yaml, 6, branches);
+                }
+                Trigger::Schedule(cron) => {
+                    yaml.push_str("  schedule:\n");
+                    let _ = writeln!(yaml, "    - cron: {}", yaml_scalar(cron));
+                }
+                Trigger::Manual => yaml.push_str("  workflow_dispatch: {}\n"),
+            }
+        }
+        write_map(&mut yaml, 0, "env", &self.env);
+
+        yaml.push_str("jobs:\n");
+        for job in &self.jobs {
+            let _ = writeln!(yaml, "  {}:", job.name);
+            let _ = writeln!(yaml, "    runs-on: {}", yaml_scalar(&job.runs_on.github()));
+            let needs = self.effective_needs(job);
+            if !needs.is_empty() {
+                yaml.push_str("    needs:\n");
+                write_list(&mut yaml, 6, &needs);
+            }
+            if !job.matrix.is_empty() {
+                yaml.push_str("    strategy:\n      fail-fast: false\n      matrix:\n");
+                for (axis, values) in &job.matrix {
+                    let _ = writeln!(yaml, "        {axis}:");
+                    write_list(&mut yaml, 10, values);
+                }
+            }
+            write_map(&mut yaml, 4, "env", &job.env);
+            yaml.push_str("    steps:\n");
+            for step in &job.steps {
+                match step {
+                    Step::Checkout => yaml.push_str("      - uses: actions/checkout@v4\n"),
+                    Step::Toolchain { version, components } => {
+                        yaml.push_str("      - name: Install Rust\n");
+                        yaml.push_str("        uses: dtolnay/rust-toolchain@master\n");
+                        yaml.push_str("        with:\n");
+                        let _ = writeln!(yaml, "          toolchain: {}", yaml_scalar(&version.github()));
+                        if !components.is_empty() {
+                            let _ = writeln!(yaml, "          components: {}", components.join(", "));
+                        }
+                    }
+                    Step::CargoCache => yaml.push_str("      - uses: Swatinem/rust-cache@v2\n"),
+                    Step::Run { name, command } => {
+                        let _ = writeln!(yaml, "      - name: {}", yaml_scalar(name));
+                        let _ = writeln!(yaml, "        run: {}", yaml_scalar(command));
+                    }
+                    Step::UploadArtifact { name, paths } => {
+                        yaml.push_str("      - uses: actions/upload-artifact@v4\n");
+                        yaml.push_str("        with:\n");
+                        let _ = writeln!(yaml, "          name: {}", yaml_scalar(name));
+                        yaml.push_str("          path: |\n");
+                        for path in paths {
+                            let _ = writeln!(yaml, "            {path}");
+                        }
+                    }
+                }
+            }
+        }
+        Ok(yaml)
+    }
+
+    /// 🦊 เขียนเป็น `.gitlab-ci.yml`
+    ///
+    /// GitLab รัน job ใน container จึงใช้ image `rust:<version>` แทน `runs-on`
+    /// และแกน matrix กลายเป็นตัวแปรตัวพิมพ์ใหญ่ใน `parallel:matrix` (เช่น `rust` → `$RUST`)
+    ///
+    /// # Errors
+    ///
+    /// คืน error จาก `validate`
+    pub fn to_gitlab_ci(&self) -> Result<String, PipelineError> {
+        self.validate()?;
+        let mut yaml = String::new();
+        let _ = writeln!(yaml, "# {}", self.name);
+        yaml.push_str("stages:\n");
+        write_list(&mut yaml, 2, &self.stages);
+
+        let mut variables = self.env.clone();
+        let uses_cache = self.jobs.iter().any(|job| job.steps.contains(&Step::CargoCache));
+        if uses_cache {
+            variables.insert("CARGO_HOME".to_string(), "$CI_PROJECT_DIR/.cargo".to_string());
+        }
+        write_map(&mut yaml, 0, "variables", &variables);
+
+        let rules = self.gitlab_rules();
+        if !rules.is_empty() {
+            yaml.push_str("workflow:\n  rules:\n");
+            for rule in rules {
+                let _ = writeln!(yaml, "    - if: {}", yaml_scalar(&rule));
+            }
+        }
+
+        for job in &self.jobs {
+            let _ = writeln!(yaml, "\n{}:", job.name);
+            let _ = writeln!(yaml, "  stage: {}", job.stage);
+            let toolchain = job.steps.iter().find_map(|step| match step {
+                Step::Toolchain { version, components } => Some((version, components)),
+                _ => None,
+            });
+            let image = match toolchain.map(|(version, _)| version) {
+                Some(Param::Fixed(version)) if version != "stable" => format!("rust:{version}"),
+                _ => "rust:latest".to_string(),
+            };
+            let _ = writeln!(yaml, "  image: {image}");
+            if !job.needs.is_empty() {
+                yaml.push_str("  needs:\n");
+                write_list(&mut yaml, 4, &job.needs);
+            }
+            if !job.matrix.is_empty() {
+                yaml.push_str("  parallel:\n    matrix:\n");
+                for (index, (axis, values)) in job.matrix.iter().enumerate() {
+                    let bullet = if index == 0 { "- " } else { "  " };
+                    let _ = writeln!(yaml, "      {bullet}{}:", axis.to_uppercase());
+                    write_list(&mut yaml, 10, values);
+                }
+            }
+            write_map(&mut yaml, 2, "variables", &job.env);
+            if job.steps.contains(&Step::CargoCache) {
+                yaml.push_str("  cache:\n    key:\n      files:\n        - Cargo.lock\n");
+                yaml.push_str("    paths:\n      - .cargo/\n      - target/\n");
+            }
+
+            let before_script: Vec<String> = toolchain
+                .into_iter()
+                .flat_map(|(version, components)| {
+                    let default = match version {
+                        Param::Matrix(axis) => Some(format!("rustup default ${}", axis.to_uppercase())),
+                        Param::Fixed(_) => None,
+                    };
+                    let add = (!components.is_empty())
+                        .then(|| format!("rustup component add {}", components.join(" ")));
+                    default.into_iter().chain(add)
+                })
+                .collect();
+            if !before_script.is_empty() {
+                yaml.push_str("  before_script:\n");
+                write_list(&mut yaml, 4, &before_script);
+            }
+            let script: Vec<String> = job
+                .steps
+                .iter()
+                .filter_map(|step| match step {
+                    Step::Run { command, .. } => Some(command.clone()),
+                    _ => None,
+                })
+                .collect();
+            yaml.push_str("  script:\n");
+            write_list(&mut yaml, 4, &script);
+            let artifacts: Vec<String> = job
+                .steps
+                .iter()
+                .filter_map(|step| match step {
+                    Step::UploadArtifact { paths, .. } => Some(paths.clone()),
+                    _ => None,
+                })
+                .flatten()
+                .collect();
+            if !artifacts.is_empty() {
+                yaml.push_str("  artifacts:\n    paths:\n");
+                write_list(&mut yaml, 6, &artifacts);
+            }
+        }
+        Ok(yaml)
+    }
+
+    /// แปลง trigger เป็นเงื่อนไข `workflow:rules` ของ GitLab
+    fn gitlab_rules(&self) -> Vec<String> {
+        self.triggers
+            .iter()
+            .flat_map(|trigger| match trigger {
+                Trigger::Push { branches } => branches
+                    .iter()
+                    .map(|branch| format!("$CI_PIPELINE_SOURCE == \"push\" && $CI_COMMIT_BRANCH == \"{branch}\""))
+                    .collect(),
+                Trigger::PullRequest { branches } => branches
+                    .iter()
+                    .map(|branch| {
+                        format!(
+                            "$CI_PIPELINE_SOURCE == \"merge_request_event\" && $CI_MERGE_REQUEST_TARGET_BRANCH_NAME == \"{branch}\""
+                        )
+                    })
+                    .collect(),
+                // ตั้งเวลา cron ในหน้า CI/CD > Schedules ของ GitLab
+                Trigger::Schedule(_) => vec!["$CI_PIPELINE_SOURCE == \"schedule\"".to_string()],
+                Trigger::Manual => vec!["$CI_PIPELINE_SOURCE == \"web\"".to_string()],
+            })
+            .collect()
+    }
+}
+
+fn write_list(yaml: &mut String, indent: usize, items: &[String]) {
+    for item in items {
+        let _ = writeln!(yaml, "{:indent$}- {}", "", yaml_scalar(item));
+    }
+}
+
+fn write_map(yaml: &mut String, indent: usize, key: &str, map: &BTreeMap<String, String>) {
+    if map.is_empty() {
+        return;
+    }
+    let _ = writeln!(yaml, "{:indent$}{key}:", "");
+    for (name, value) in map {
+        let _ = writeln!(yaml, "{:width$}{name}: {}", "", yaml_scalar(value), width = indent + 2);
+    }
+}
+
+/// เขียนค่าแบบไม่ใส่ quote ถ้าปลอดภัย ไม่งั้นใช้ JSON string (ซึ่งเป็น YAML ที่ถูกต้อง)
+fn yaml_scalar(value: &str) -> String {
+    const RESERVED: [&str; 10] = ["true", "false", "yes", "no", "on", "off", "null", "~", "y", "n"];
+    let needs_quotes = value.is_empty()
+        || value.trim() != value
+        || value.starts_with(|c: char| "-?:,[]{}#&*!|>'\"%@`".contains(c))
+        || value.contains(": ")
+        || value.contains(" #")
+        || value.contains('*')
+        || value.ends_with(':')
+        || value.contains('\n')
+        || RESERVED.contains(&value.to_ascii_lowercase().as_str())
+        || value.parse::<f64>().is_ok();
+    if needs_quotes {
+        serde_json::Value::from(value).to_string()
+    } else {
+        value.to_string()
+    }
+}
+
 /// 🎯 สาธิตการทำงานกับ CI/CD Pipelines
 pub fn demonstrate_cicd_pipelines() {
     println!("\n🔄 === CI/CD Pipelines Demo ===");
@@ -500,12 +1076,16 @@ pub fn demonstrate_cicd_pipelines() {
     println!("\n3️⃣ GitLab CI Pipeline:");
     demonstrate_gitlab_ci();
     
-    // 4. การเปรียบเทียบ CI/CD Platforms
-    println!("\n4️⃣ การเปรียบเทียบ CI/CD Platforms:");
+    // 4. Pipeline เดียว เขียนได้ทั้งสอง platform
+    println!("\n4️⃣ Pipeline Model → GitHub Actions / GitLab CI:");
+    demonstrate_pipeline_model();
+    
+    // 5. การเปรียบเทียบ CI/CD Platforms
+    println!("\n5️⃣ การเปรียบเทียบ CI/CD Platforms:");
     compare_cicd_platforms();
     
-    // 5. Best Practices
-    println!("\n5️⃣ CI/CD Best Practices:");
+    // 6. Best Practices
+    println!("\n6️⃣ CI/CD Best Practices:");
     show_cicd_best_practices();
     
     println!("\n✅ จบการสาธิต CI/CD Pipelines!");
@@ -619,6 +1199,36 @@ fn demonstrate_gitlab_ci() {
     println!("   • มี GitLab Pages สำหรับ static sites");
 }
 
+/// 🧩 สาธิต `Pipeline` ที่เขียนออกได้หลาย platform
+fn demonstrate_pipeline_model() {
+    let pipeline = Pipeline::rust_ci("CI", "rust_concepts")
+        .trigger(Trigger::Schedule("0 3 * * 1".to_string()))
+        .trigger(Trigger::Manual);
+
+    for (file, yaml) in [
+        (".github/workflows/ci.yml", pipeline.to_github_actions()),
+        (".gitlab-ci.yml", pipeline.to_gitlab_ci()),
+    ] {
+        match yaml {
+            Ok(yaml) => {
+                println!("📄 {file} ({} บรรทัด):", yaml.lines().count());
+                for line in yaml.lines().take(12) {
+                    println!("   {line}");
+                }
+                println!("   ...");
+            }
+            Err(e) => println!("❌ {e}"),
+        }
+    }
+
+    let broken = Pipeline::new("broken")
+        .stage("test")
+        .job(Job::new("test", "test").needs("build"));
+    if let Err(e) = broken.to_github_actions() {
+        println!("🛡️ ตรวจก่อน push: {e}");
+    }
+}
+
 /// 📊 เปรียบเทียบ CI/CD Platforms
 fn compare_cicd_platforms() {
     println!("📊 การเปรียบเทียบ CI/CD Platforms:");
@@ -751,4 +1361,109 @@ mod tests {
         assert!(workflow.contains("jobs:"));
         assert!(workflow.contains("cargo test"));
     }
-}
\ No newline at end of file
+    
+    fn parse(yaml: &str) -> serde_yaml::Value {
+        serde_yaml::from_str(yaml).expect("generated YAML should parse")
+    }
+
+    #[test]
+    fn test_github_actions_shape() {
+        let pipeline = Pipeline::rust_ci("CI", "app").trigger(Trigger::Manual);
+        let doc = parse(&pipeline.to_github_actions().unwrap());
+
+        assert_eq!(doc["name"].as_str(), Some("CI"));
+        assert!(doc["on"]["push"]["branches"].is_sequence());
+        assert!(doc["on"]["workflow_dispatch"].is_mapping());
+        assert_eq!(doc["env"]["CARGO_TERM_COLOR"].as_str(), Some("always"));
+
+        let jobs = doc["jobs"].as_mapping().unwrap();
+        assert_eq!(jobs.len(), 3);
+        for (_, job) in jobs {
+            assert!(job["runs-on"].is_string());
+            let steps = job["steps"].as_sequence().unwrap();
+            assert!(steps.iter().all(|s| s["uses"].is_string() || s["run"].is_string()));
+        }
+
+        let test = &doc["jobs"]["test"];
+        assert_eq!(test["runs-on"].as_str(), Some("${{ matrix.os }}"));
+        assert_eq!(test["strategy"]["matrix"]["os"].as_sequence().unwrap().len(), 3);
+        assert_eq!(test["strategy"]["matrix"]["rust"][1].as_str(), Some("beta"));
+        // stage ก่อนหน้ากลายเป็น needs อัตโนมัติ
+        assert_eq!(test["needs"][0].as_str(), Some("lint"));
+        assert_eq!(doc["jobs"]["build"]["needs"][0].as_str(), Some("test"));
+        assert_eq!(
+            doc["jobs"]["build"]["steps"][4]["with"]["path"].as_str(),
+            Some("target/release/app\n")
+        );
+    }
+
+    #[test]
+    fn test_gitlab_ci_shape() {
+        let pipeline = Pipeline::rust_ci("CI", "app").trigger(Trigger::Schedule("0 3 * * 1".to_string()));
+        let doc = parse(&pipeline.to_gitlab_ci().unwrap());
+
+        let stages: Vec<&str> = doc["stages"]
+            .as_sequence()
+            .unwrap()
+            .iter()
+            .filter_map(|s| s.as_str())
+            .collect();
+        assert_eq!(stages, ["check", "test", "build"]);
+        assert_eq!(doc["variables"]["CARGO_HOME"].as_str(), Some("$CI_PROJECT_DIR/.cargo"));
+        assert_eq!(doc["workflow"]["rules"].as_sequence().unwrap().len(), 3);
+
+        let lint = &doc["lint"];
+        assert_eq!(lint["stage"].as_str(), Some("check"));
+        assert_eq!(lint["image"].as_str(), Some("rust:latest"));
+        assert_eq!(lint["cache"]["key"]["files"][0].as_str(), Some("Cargo.lock"));
+        assert_eq!(lint["script"].as_sequence().unwrap().len(), 2);
+
+        let matrix = &doc["test"]["parallel"]["matrix"][0];
+        assert_eq!(matrix["RUST"].as_sequence().unwrap().len(), 2);
+        assert_eq!(doc["test"]["before_script"][0].as_str(), Some("rustup default $RUST"));
+        assert_eq!(doc["build"]["artifacts"]["paths"][0].as_str(), Some("target/release/app"));
+    }
+
+    #[test]
+    fn test_yaml_scalars_are_quoted_when_needed() {
+        let pipeline = Pipeline::new("on")
+            .env("FLAG", "yes")
+            .env("PORT", "8080")
+            .env("NOTE", "a: b #c")
+            .stage("test")
+            .job(Job::new("test", "test").step(Step::run("Echo", "echo \"done\"")));
+        let doc = parse(&pipeline.to_github_actions().unwrap());
+
+        assert_eq!(doc["name"].as_str(), Some("on"));
+        assert_eq!(doc["env"]["FLAG"].as_str(), Some("yes"));
+        assert_eq!(doc["env"]["PORT"].as_str(), Some("8080"));
+        assert_eq!(doc["env"]["NOTE"].as_str(), Some("a: b #c"));
+        assert_eq!(doc["jobs"]["test"]["steps"][0]["run"].as_str(), Some("echo \"done\""));
+    }
+
+    #[test]
+    fn test_pipeline_validation() {
+        assert_eq!(Pipeline::new("x").validate(), Err(PipelineError::Empty));
+
+        let unknown_stage = Pipeline::new("x").stage("test").job(Job::new("build", "build"));
+        assert_eq!(
+            unknown_stage.to_gitlab_ci(),
+            Err(PipelineError::UnknownStage { job: "build".to_string(), stage: "build".to_string() })
+        );
+
+        let unknown_needs = Pipeline::new("x").stage("test").job(Job::new("test", "test").needs("lint"));
+        assert!(matches!(unknown_needs.validate(), Err(PipelineError::UnknownJob { .. })));
+
+        let unknown_axis = Pipeline::new("x")
+            .stage("test")
+            .job(Job::new("test", "test").runs_on(Param::matrix("os")));
+        assert!(matches!(unknown_axis.validate(), Err(PipelineError::UnknownMatrixAxis { .. })));
+
+        let duplicate = Pipeline::new("x").stage("test").job(Job::new("t", "test")).job(Job::new("t", "test"));
+        assert_eq!(duplicate.validate(), Err(PipelineError::DuplicateJob("t".to_string())));
+        assert!(matches!(
+            Pipeline::new("x").stage("test").job(Job::new("Has Space", "test")).validate(),
+            Err(PipelineError::InvalidJobName(_))
+        ));
+    }
+}
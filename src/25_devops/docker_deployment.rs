@@ -3,8 +3,12 @@
 //! โมดูลนี้สาธิตการสร้าง Docker images และ containers
 //! สำหรับ Rust applications
 
-use std::collections::HashMap;
-use std::fmt;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::{self, Write as _};
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
 
 /// 🐳 โครงสร้าง Docker Configuration
 #[derive(Debug, Clone)]
@@ -318,6 +322,582 @@ impl DockerBuilder {
     }
 }
 
+/// ❌ ข้อผิดพลาดตอนประกอบ Dockerfile/compose
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GeneratorError {
+    /// ใส่คำสั่งก่อนมี `FROM`
+    MissingFrom { instruction: String },
+    /// `COPY --from` อ้างถึง stage ที่ยังไม่ได้ประกาศ
+    UnknownStage(String),
+    /// ชื่อ stage ซ้ำกัน
+    DuplicateStage(String),
+    /// `depends_on` อ้างถึง service ที่ไม่มี
+    UnknownService { service: String, depends_on: String },
+    /// Dockerfile/compose ว่างเปล่า
+    Empty,
+}
+
+impl fmt::Display for GeneratorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::MissingFrom { instruction } => {
+                write!(f, "{instruction} must come after a FROM instruction")
+            }
+            Self::UnknownStage(stage) => write!(f, "COPY --from refers to unknown stage '{stage}'"),
+            Self::DuplicateStage(stage) => write!(f, "stage '{stage}' is declared twice"),
+            Self::UnknownService { service, depends_on } => {
+                write!(f, "service '{service}' depends on unknown service '{depends_on}'")
+            }
+            Self::Empty => write!(f, "nothing to generate"),
+        }
+    }
+}
+
+impl std::error::Error for GeneratorError {}
+
+/// 💓 health check ของ container - ใช้ได้ทั้งใน Dockerfile และ compose
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Healthcheck {
+    /// คำสั่งแบบ exec form เช่น `["curl", "-f", "http://localhost:8080/health"]`
+    pub command: Vec<String>,
+    pub interval: Duration,
+    pub timeout: Duration,
+    pub retries: u32,
+}
+
+impl Healthcheck {
+    /// health check ค่าเริ่มต้น: ทุก 30s, timeout 3s, ลอง 3 ครั้ง
+    #[must_use]
+    pub fn new(command: &[&str]) -> Self {
+        Self {
+            command: command.iter().map(|part| (*part).to_string()).collect(),
+            interval: Duration::from_secs(30),
+            timeout: Duration::from_secs(3),
+            retries: 3,
+        }
+    }
+
+    /// ยิง HTTP GET ไปที่ `url` ด้วย curl
+    #[must_use]
+    pub fn http(url: &str) -> Self {
+        Self::new(&["curl", "-f", url])
+    }
+
+    #[must_use]
+    pub const fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    #[must_use]
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    #[must_use]
+    pub const fn retries(mut self, retries: u32) -> Self {
+        self.retries = retries;
+        self
+    }
+}
+
+/// เขียนช่วงเวลาแบบที่ Docker อ่านได้ เช่น `30s`, `1m30s`, `500ms`
+fn docker_duration(duration: Duration) -> String {
+    let millis = duration.as_millis();
+    if !millis.is_multiple_of(1000) {
+        return format!("{millis}ms");
+    }
+    let secs = duration.as_secs();
+    match (secs / 60, secs % 60) {
+        (0, secs) => format!("{secs}s"),
+        (mins, 0) => format!("{mins}m"),
+        (mins, secs) => format!("{mins}m{secs}s"),
+    }
+}
+
+/// string ใน exec form / YAML - ใช้ JSON string ซึ่งเป็น YAML ที่ถูกต้องด้วย
+fn quoted(value: &str) -> String {
+    serde_json::Value::from(value).to_string()
+}
+
+fn exec_form(parts: &[String]) -> String {
+    let parts: Vec<String> = parts.iter().map(|part| quoted(part)).collect();
+    format!("[{}]", parts.join(", "))
+}
+
+/// 📜 คำสั่งหนึ่งบรรทัดใน Dockerfile
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Instruction {
+    Comment(String),
+    Arg(String, Option<String>),
+    Workdir(String),
+    Copy { from: Option<String>, sources: Vec<String>, dest: String },
+    /// คำสั่ง shell หลายคำสั่งต่อกันด้วย `&&` ใน layer เดียว
+    Run(Vec<String>),
+    Env(String, String),
+    Expose(u16),
+    User(String),
+    Healthcheck(Healthcheck),
+    Cmd(Vec<String>),
+    Entrypoint(Vec<String>),
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Comment(text) => write!(f, "# {text}"),
+            Self::Arg(name, None) => write!(f, "ARG {name}"),
+            Self::Arg(name, Some(default)) => write!(f, "ARG {name}={default}"),
+            Self::Workdir(dir) => write!(f, "WORKDIR {dir}"),
+            Self::Copy { from, sources, dest } => {
+                write!(f, "COPY ")?;
+                if let Some(stage) = from {
+                    write!(f, "--from={stage} ")?;
+                }
+                write!(f, "{} {dest}", sources.join(" "))
+            }
+            Self::Run(commands) => write!(f, "RUN {}", commands.join(" \\\n    && ")),
+            Self::Env(key, value) => write!(f, "ENV {key}={}", quoted(value)),
+            Self::Expose(port) => write!(f, "EXPOSE {port}"),
+            Self::User(user) => write!(f, "USER {user}"),
+            Self::Healthcheck(check) => write!(
+                f,
+                "HEALTHCHECK --interval={} --timeout={} --retries={} \\\n    CMD {}",
+                docker_duration(check.interval),
+                docker_duration(check.timeout),
+                check.retries,
+                exec_form(&check.command)
+            ),
+            Self::Cmd(args) => write!(f, "CMD {}", exec_form(args)),
+            Self::Entrypoint(args) => write!(f, "ENTRYPOINT {}", exec_form(args)),
+        }
+    }
+}
+
+/// 🧱 stage หนึ่งของ multi-stage build
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stage {
+    pub image: String,
+    pub name: Option<String>,
+    pub instructions: Vec<Instruction>,
+}
+
+/// 📄 Dockerfile ที่ประกอบเสร็จแล้ว - `to_string()` ได้เนื้อไฟล์
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dockerfile {
+    pub stages: Vec<Stage>,
+}
+
+impl Dockerfile {
+    /// เขียนลงไฟล์ (เช่น `Dockerfile`)
+    ///
+    /// # Errors
+    ///
+    /// คืน error จากการเขียนไฟล์
+    pub fn write_to(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+}
+
+impl fmt::Display for Dockerfile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (index, stage) in self.stages.iter().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            match &stage.name {
+                Some(name) => writeln!(f, "FROM {} AS {name}", stage.image)?,
+                None => writeln!(f, "FROM {}", stage.image)?,
+            }
+            for instruction in &stage.instructions {
+                // เว้นบรรทัดก่อน comment ให้แต่ละช่วงอ่านง่าย
+                if matches!(instruction, Instruction::Comment(_)) {
+                    writeln!(f)?;
+                }
+                writeln!(f, "{instruction}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 🏗️ ประกอบ Dockerfile ทีละคำสั่ง - คำสั่งจะต่อท้าย stage ล่าสุดที่เปิดด้วย `from`/`from_as`
+#[derive(Debug, Default)]
+pub struct DockerfileBuilder {
+    stages: Vec<Stage>,
+    error: Option<GeneratorError>,
+}
+
+impl DockerfileBuilder {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Dockerfile แบบ multi-stage มาตรฐานของโปรเจค Rust:
+    /// cache dependencies → build → copy binary ไปยัง image เล็กๆ ที่รันด้วย non-root user
+    #[must_use]
+    pub fn rust_multi_stage(binary: &str, port: u16) -> Self {
+        Self::new()
+            .from_as("rust:1.75-slim", "builder")
+            .workdir("/app")
+            .comment("Cache dependencies in their own layer")
+            .copy(&["Cargo.toml", "Cargo.lock"], "./")
+            .run(&[
+                "mkdir src",
+                "echo 'fn main() {}' > src/main.rs",
+                "cargo build --release",
+                "rm -rf src",
+            ])
+            .comment("Build the real application")
+            .copy(&["src"], "./src")
+            .run(&["touch src/main.rs", "cargo build --release"])
+            .from("debian:bookworm-slim")
+            .run(&[
+                "apt-get update",
+                "apt-get install -y --no-install-recommends ca-certificates curl",
+                "rm -rf /var/lib/apt/lists/*",
+                "useradd --system --uid 10001 app",
+            ])
+            .copy_from("builder", &format!("/app/target/release/{binary}"), &format!("/usr/local/bin/{binary}"))
+            .user("app")
+            .expose(port)
+            .healthcheck(Healthcheck::http(&format!("http://localhost:{port}/health")))
+            .cmd(&[binary])
+    }
+
+    /// เปิด stage ใหม่แบบไม่ตั้งชื่อ
+    #[must_use]
+    pub fn from(self, image: &str) -> Self {
+        self.start_stage(image, None)
+    }
+
+    /// เปิด stage ใหม่พร้อมชื่อ ให้ stage หลังๆ `copy_from` ได้
+    #[must_use]
+    pub fn from_as(self, image: &str, name: &str) -> Self {
+        self.start_stage(image, Some(name))
+    }
+
+    fn start_stage(mut self, image: &str, name: Option<&str>) -> Self {
+        if let Some(name) = name
+            && self.stages.iter().any(|stage| stage.name.as_deref() == Some(name))
+        {
+            self.error.get_or_insert_with(|| GeneratorError::DuplicateStage(name.to_string()));
+        }
+        self.stages.push(Stage {
+            image: image.to_string(),
+            name: name.map(str::to_string),
+            instructions: Vec::new(),
+        });
+        self
+    }
+
+    fn push(mut self, instruction: Instruction) -> Self {
+        if let Some(stage) = self.stages.last_mut() {
+            stage.instructions.push(instruction);
+        } else {
+            let keyword = instruction.to_string();
+            let keyword = keyword.split_whitespace().next().unwrap_or_default().to_string();
+            self.error.get_or_insert(GeneratorError::MissingFrom { instruction: keyword });
+        }
+        self
+    }
+
+    #[must_use]
+    pub fn comment(self, text: &str) -> Self {
+        self.push(Instruction::Comment(text.to_string()))
+    }
+
+    #[must_use]
+    pub fn arg(self, name: &str, default: Option<&str>) -> Self {
+        self.push(Instruction::Arg(name.to_string(), default.map(str::to_string)))
+    }
+
+    #[must_use]
+    pub fn workdir(self, dir: &str) -> Self {
+        self.push(Instruction::Workdir(dir.to_string()))
+    }
+
+    #[must_use]
+    pub fn copy(self, sources: &[&str], dest: &str) -> Self {
+        self.push(Instruction::Copy {
+            from: None,
+            sources: sources.iter().map(|source| (*source).to_string()).collect(),
+            dest: dest.to_string(),
+        })
+    }
+
+    /// `COPY --from=<stage>` - stage ต้องถูกประกาศด้วย `from_as` ก่อนหน้า
+    #[must_use]
+    pub fn copy_from(mut self, stage: &str, source: &str, dest: &str) -> Self {
+        let current = self.stages.len().saturating_sub(1);
+        let known = self.stages[..current].iter().any(|s| s.name.as_deref() == Some(stage));
+        if !known {
+            self.error.get_or_insert_with(|| GeneratorError::UnknownStage(stage.to_string()));
+        }
+        self.push(Instruction::Copy {
+            from: Some(stage.to_string()),
+            sources: vec![source.to_string()],
+            dest: dest.to_string(),
+        })
+    }
+
+    /// รวมหลายคำสั่งเป็น `RUN` เดียว (layer เดียว)
+    #[must_use]
+    pub fn run(self, commands: &[&str]) -> Self {
+        self.push(Instruction::Run(commands.iter().map(|c| (*c).to_string()).collect()))
+    }
+
+    #[must_use]
+    pub fn env(self, key: &str, value: &str) -> Self {
+        self.push(Instruction::Env(key.to_string(), value.to_string()))
+    }
+
+    #[must_use]
+    pub fn expose(self, port: u16) -> Self {
+        self.push(Instruction::Expose(port))
+    }
+
+    #[must_use]
+    pub fn user(self, user: &str) -> Self {
+        self.push(Instruction::User(user.to_string()))
+    }
+
+    #[must_use]
+    pub fn healthcheck(self, check: Healthcheck) -> Self {
+        self.push(Instruction::Healthcheck(check))
+    }
+
+    #[must_use]
+    pub fn cmd(self, args: &[&str]) -> Self {
+        self.push(Instruction::Cmd(args.iter().map(|a| (*a).to_string()).collect()))
+    }
+
+    #[must_use]
+    pub fn entrypoint(self, args: &[&str]) -> Self {
+        self.push(Instruction::Entrypoint(args.iter().map(|a| (*a).to_string()).collect()))
+    }
+
+    /// ตรวจแล้วคืน `Dockerfile`
+    ///
+    /// # Errors
+    ///
+    /// คืน error แรกที่เจอระหว่างประกอบ หรือ `GeneratorError::Empty` ถ้าไม่มี stage เลย
+    pub fn build(self) -> Result<Dockerfile, GeneratorError> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+        if self.stages.is_empty() {
+            return Err(GeneratorError::Empty);
+        }
+        Ok(Dockerfile { stages: self.stages })
+    }
+}
+
+/// 🐙 service หนึ่งตัวใน docker-compose
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ComposeService {
+    pub image: Option<String>,
+    /// build context (เช่น `.`)
+    pub build: Option<String>,
+    /// (host, container)
+    pub ports: Vec<(u16, u16)>,
+    pub environment: BTreeMap<String, String>,
+    pub volumes: Vec<String>,
+    pub depends_on: Vec<String>,
+    pub healthcheck: Option<Healthcheck>,
+    pub restart: Option<String>,
+}
+
+impl ComposeService {
+    #[must_use]
+    pub fn image(image: &str) -> Self {
+        Self {
+            image: Some(image.to_string()),
+            ..Self::default()
+        }
+    }
+
+    #[must_use]
+    pub fn build(context: &str) -> Self {
+        Self {
+            build: Some(context.to_string()),
+            ..Self::default()
+        }
+    }
+
+    #[must_use]
+    pub fn port(mut self, host: u16, container: u16) -> Self {
+        self.ports.push((host, container));
+        self
+    }
+
+    #[must_use]
+    pub fn env(mut self, key: &str, value: &str) -> Self {
+        self.environment.insert(key.to_string(), value.to_string());
+        self
+    }
+
+    /// volume แบบ `ชื่อหรือ path:path ใน container`
+    #[must_use]
+    pub fn volume(mut self, volume: &str) -> Self {
+        self.volumes.push(volume.to_string());
+        self
+    }
+
+    #[must_use]
+    pub fn depends_on(mut self, service: &str) -> Self {
+        self.depends_on.push(service.to_string());
+        self
+    }
+
+    #[must_use]
+    pub fn healthcheck(mut self, check: Healthcheck) -> Self {
+        self.healthcheck = Some(check);
+        self
+    }
+
+    #[must_use]
+    pub fn restart(mut self, policy: &str) -> Self {
+        self.restart = Some(policy.to_string());
+        self
+    }
+
+    fn write_yaml(&self, out: &mut String) {
+        if let Some(image) = &self.image {
+            let _ = writeln!(out, "    image: {}", quoted(image));
+        }
+        if let Some(context) = &self.build {
+            let _ = writeln!(out, "    build: {}", quoted(context));
+        }
+        if !self.ports.is_empty() {
+            out.push_str("    ports:\n");
+            for (host, container) in &self.ports {
+                let _ = writeln!(out, "      - \"{host}:{container}\"");
+            }
+        }
+        if !self.environment.is_empty() {
+            out.push_str("    environment:\n");
+            for (key, value) in &self.environment {
+                let _ = writeln!(out, "      {key}: {}", quoted(value));
+            }
+        }
+        if !self.volumes.is_empty() {
+            out.push_str("    volumes:\n");
+            for volume in &self.volumes {
+                let _ = writeln!(out, "      - {}", quoted(volume));
+            }
+        }
+        if !self.depends_on.is_empty() {
+            out.push_str("    depends_on:\n");
+            for service in &self.depends_on {
+                let _ = writeln!(out, "      - {service}");
+            }
+        }
+        if let Some(check) = &self.healthcheck {
+            let mut test = vec!["CMD".to_string()];
+            test.extend(check.command.iter().cloned());
+            out.push_str("    healthcheck:\n");
+            let _ = writeln!(out, "      test: {}", exec_form(&test));
+            let _ = writeln!(out, "      interval: {}", docker_duration(check.interval));
+            let _ = writeln!(out, "      timeout: {}", docker_duration(check.timeout));
+            let _ = writeln!(out, "      retries: {}", check.retries);
+        }
+        if let Some(policy) = &self.restart {
+            let _ = writeln!(out, "    restart: {policy}");
+        }
+    }
+}
+
+/// 🐙 docker-compose.yml ทั้งไฟล์ - service เรียงตามลำดับที่เพิ่ม
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ComposeFile {
+    pub services: Vec<(String, ComposeService)>,
+    /// named volumes ที่ต้องประกาศในส่วน `volumes:` ด้านล่าง
+    pub volumes: Vec<String>,
+}
+
+impl ComposeFile {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// เพิ่ม service (ชื่อซ้ำจะแทนที่ตัวเดิม)
+    #[must_use]
+    pub fn service(mut self, name: &str, service: ComposeService) -> Self {
+        match self.services.iter_mut().find(|(existing, _)| existing == name) {
+            Some((_, existing)) => *existing = service,
+            None => self.services.push((name.to_string(), service)),
+        }
+        self
+    }
+
+    #[must_use]
+    pub fn volume(mut self, name: &str) -> Self {
+        if !self.volumes.iter().any(|volume| volume == name) {
+            self.volumes.push(name.to_string());
+        }
+        self
+    }
+
+    /// ตรวจว่า `depends_on` ทุกตัวชี้ไปที่ service ที่มีอยู่จริง
+    ///
+    /// # Errors
+    ///
+    /// คืน `UnknownService` ถ้ามี dependency ที่ไม่มีอยู่ หรือ `Empty` ถ้ายังไม่มี service
+    pub fn validate(&self) -> Result<(), GeneratorError> {
+        if self.services.is_empty() {
+            return Err(GeneratorError::Empty);
+        }
+        for (name, service) in &self.services {
+            for dependency in &service.depends_on {
+                if !self.services.iter().any(|(other, _)| other == dependency) {
+                    return Err(GeneratorError::UnknownService {
+                        service: name.clone(),
+                        depends_on: dependency.clone(),
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// ตรวจแล้วเขียนเป็น YAML
+    ///
+    /// # Errors
+    ///
+    /// คืน error เดียวกับ `validate`
+    pub fn to_yaml(&self) -> Result<String, GeneratorError> {
+        self.validate()?;
+        let mut out = String::from("services:\n");
+        for (name, service) in &self.services {
+            let _ = writeln!(out, "  {name}:");
+            service.write_yaml(&mut out);
+        }
+        if !self.volumes.is_empty() {
+            out.push_str("\nvolumes:\n");
+            for volume in &self.volumes {
+                let _ = writeln!(out, "  {volume}: {{}}");
+            }
+        }
+        Ok(out)
+    }
+
+    /// ตรวจแล้วเขียนลงไฟล์ (เช่น `docker-compose.yml`)
+    ///
+    /// # Errors
+    ///
+    /// คืน `InvalidInput` ถ้า `validate` ไม่ผ่าน หรือ error จากการเขียนไฟล์
+    pub fn write_to(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let yaml = self
+            .to_yaml()
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidInput, error))?;
+        fs::write(path, yaml)
+    }
+}
+
 /// 🎯 สาธิตการทำงานกับ Docker Deployment
 pub fn demonstrate_docker_deployment() {
     println!("\n🐳 === Docker Deployment Demo ===");
@@ -334,8 +914,12 @@ pub fn demonstrate_docker_deployment() {
     println!("\n3️⃣ Docker Compose Configuration:");
     demonstrate_docker_compose();
     
-    // 4. Best Practices
-    println!("\n4️⃣ Docker Best Practices:");
+    // 4. Generator API
+    println!("\n4️⃣ สร้าง Dockerfile และ Compose ด้วยโค้ด:");
+    demonstrate_generators();
+    
+    // 5. Best Practices
+    println!("\n5️⃣ Docker Best Practices:");
     show_docker_best_practices();
     
     println!("\n✅ จบการสาธิต Docker Deployment!");
@@ -433,6 +1017,78 @@ fn demonstrate_docker_compose() {
     println!("   docker-compose build --no-cache # build ใหม่");
 }
 
+/// 🐙 stack ตัวอย่าง: แอป Rust + `PostgreSQL` ที่รอให้ database พร้อมก่อน
+fn example_stack() -> ComposeFile {
+    ComposeFile::new()
+        .service(
+            "api",
+            ComposeService::build(".")
+                .port(8080, 8080)
+                .env("DATABASE_URL", "postgres://app:secret@db:5432/app")
+                .env("RUST_LOG", "info")
+                .depends_on("db")
+                .healthcheck(Healthcheck::http("http://localhost:8080/health"))
+                .restart("unless-stopped"),
+        )
+        .service(
+            "db",
+            ComposeService::image("postgres:16-alpine")
+                .env("POSTGRES_USER", "app")
+                .env("POSTGRES_PASSWORD", "secret")
+                .volume("pgdata:/var/lib/postgresql/data")
+                .healthcheck(
+                    Healthcheck::new(&["pg_isready", "-U", "app"])
+                        .interval(Duration::from_secs(5))
+                        .retries(5),
+                ),
+        )
+        .volume("pgdata")
+}
+
+/// 🏭 สาธิต `DockerfileBuilder` และ `ComposeFile` แล้วเขียนไฟล์จริงลงโฟลเดอร์ชั่วคราว
+fn demonstrate_generators() {
+    let dockerfile = match DockerfileBuilder::rust_multi_stage("rust_concepts", 8080).build() {
+        Ok(dockerfile) => dockerfile,
+        Err(e) => {
+            println!("   ❌ {e}");
+            return;
+        }
+    };
+    println!("📄 Dockerfile ({} stages):", dockerfile.stages.len());
+    for line in dockerfile.to_string().lines() {
+        println!("   {line}");
+    }
+
+    let stack = example_stack();
+    match stack.to_yaml() {
+        Ok(yaml) => {
+            println!("\n📄 docker-compose.yml:");
+            for line in yaml.lines() {
+                println!("   {line}");
+            }
+        }
+        Err(e) => println!("   ❌ {e}"),
+    }
+
+    if let Ok(dir) = tempfile::tempdir() {
+        let written = dockerfile
+            .write_to(dir.path().join("Dockerfile"))
+            .and_then(|()| stack.write_to(dir.path().join("docker-compose.yml")));
+        match written {
+            Ok(()) => println!("\n💾 เขียน Dockerfile และ docker-compose.yml ลง {} แล้ว", dir.path().display()),
+            Err(e) => println!("\n❌ เขียนไฟล์ไม่สำเร็จ: {e}"),
+        }
+    }
+
+    let broken = DockerfileBuilder::new()
+        .from("debian:bookworm-slim")
+        .copy_from("builder", "/app/app", "/app")
+        .build();
+    if let Err(e) = broken {
+        println!("🚨 ตรวจจับ Dockerfile ผิดได้: {e}");
+    }
+}
+
 /// 📋 แสดง Docker Best Practices
 fn show_docker_best_practices() {
     println!("📋 Docker Best Practices:");
@@ -468,6 +1124,7 @@ fn show_docker_best_practices() {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::snapshot_testing::SnapshotStore;
     
     #[test]
     fn test_docker_config() {
@@ -501,4 +1158,58 @@ mod tests {
         assert!(dockerfile.contains("FROM rust:1.75 as builder"));
         assert!(dockerfile.contains("FROM debian:bookworm-slim"));
     }
+
+    fn snapshots() -> SnapshotStore {
+        SnapshotStore::new(Path::new(env!("CARGO_MANIFEST_DIR")).join("src/25_devops/snapshots"))
+    }
+
+    #[test]
+    fn test_rust_multi_stage_dockerfile_golden() {
+        let dockerfile = DockerfileBuilder::rust_multi_stage("rust_concepts", 8080).build().unwrap();
+        assert_eq!(dockerfile.stages[0].name.as_deref(), Some("builder"));
+        snapshots().assert_matches("rust_multi_stage.Dockerfile", &dockerfile.to_string());
+    }
+
+    #[test]
+    fn test_compose_file_golden() {
+        let yaml = example_stack().to_yaml().unwrap();
+        snapshots().assert_matches("web_stack.compose.yml", &yaml);
+    }
+
+    #[test]
+    fn test_generators_write_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let dockerfile = DockerfileBuilder::new().from("alpine:3.19").cmd(&["true"]).build().unwrap();
+        dockerfile.write_to(dir.path().join("Dockerfile")).unwrap();
+        example_stack().write_to(dir.path().join("docker-compose.yml")).unwrap();
+
+        assert_eq!(
+            std::fs::read_to_string(dir.path().join("Dockerfile")).unwrap(),
+            "FROM alpine:3.19\nCMD [\"true\"]\n"
+        );
+        let yaml = std::fs::read_to_string(dir.path().join("docker-compose.yml")).unwrap();
+        assert!(yaml.starts_with("services:\n  api:\n"));
+    }
+
+    #[test]
+    fn test_generator_errors() {
+        assert_eq!(
+            DockerfileBuilder::new().run(&["make"]).build(),
+            Err(GeneratorError::MissingFrom { instruction: "RUN".to_string() })
+        );
+        assert_eq!(
+            DockerfileBuilder::new().from("alpine").copy_from("builder", "/a", "/b").build(),
+            Err(GeneratorError::UnknownStage("builder".to_string()))
+        );
+        assert_eq!(
+            DockerfileBuilder::new().from_as("rust", "x").from_as("rust", "x").build(),
+            Err(GeneratorError::DuplicateStage("x".to_string()))
+        );
+        assert_eq!(
+            ComposeFile::new().service("api", ComposeService::build(".").depends_on("db")).to_yaml(),
+            Err(GeneratorError::UnknownService { service: "api".to_string(), depends_on: "db".to_string() })
+        );
+        assert_eq!(docker_duration(Duration::from_secs(90)), "1m30s");
+        assert_eq!(docker_duration(Duration::from_millis(1500)), "1500ms");
+    }
 }
\ No newline at end of file
@@ -0,0 +1,553 @@
+//! 🚩 Feature Flags - เปิดฟีเจอร์ทีละกลุ่มผู้ใช้โดยไม่ต้อง deploy ใหม่
+//!
+//! flag ถูกประกาศในไฟล์ config (TOML) ใต้ตาราง `[flags]`:
+//!
+//! ```toml
+//! [flags.new_checkout]          # เปิด/ปิด แบบค่อย ๆ ปล่อย
+//! type = "bool"
+//! rollout = 25                  # 25% ของผู้ใช้
+//!
+//! [flags.banner]                # ข้อความที่เปลี่ยนได้
+//! type = "string"
+//! value = "🎉 ลด 50%"
+//! fallback = ""
+//!
+//! [flags.button_color]          # A/B test หลายแบบ แบ่งตามน้ำหนัก
+//! type = "variant"
+//! variants = { blue = 50, green = 30, red = 20 }
+//! ```
+//!
+//! ผู้ใช้แต่ละคนถูกจัดลง bucket ด้วย hash ของ `flag:user_id` จึงได้ผลเดิมทุกครั้ง
+//! (และทุกเครื่อง) ส่วนการเพิ่ม rollout จาก 10% เป็น 20% ไม่ทำให้คนใน 10% แรกหลุดออก
+//!
+//! ลำดับความสำคัญ: override รายผู้ใช้ → override ทั้ง flag → ค่าจาก config
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+use std::thread;
+use std::time::Duration;
+
+use serde::Deserialize;
+
+use crate::config::{Config, ConfigError};
+use crate::io_filesystem::{ChangeKind, FileWatcher, WatchHandle};
+use crate::logger;
+
+/// จำนวน bucket ทั้งหมด - ปรับ rollout ได้ละเอียดถึง 0.01%
+pub const BUCKETS: u32 = 10_000;
+
+/// 🧩 ชนิดของ flag และค่าที่ใช้ตัดสิน
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum FlagKind {
+    /// เปิดให้ `rollout`% ของผู้ใช้
+    Bool {
+        #[serde(default = "full_rollout")]
+        rollout: f64,
+    },
+    /// คนใน rollout ได้ `value` ที่เหลือได้ `fallback`
+    String {
+        value: String,
+        #[serde(default)]
+        fallback: String,
+        #[serde(default = "full_rollout")]
+        rollout: f64,
+    },
+    /// แบ่งผู้ใช้ตามน้ำหนัก - `fallback` ใช้ตอน flag ถูกปิด
+    Variant {
+        variants: BTreeMap<String, u32>,
+        #[serde(default = "control_variant")]
+        fallback: String,
+    },
+}
+
+const fn full_rollout() -> f64 {
+    100.0
+}
+
+fn control_variant() -> String {
+    "control".to_string()
+}
+
+const fn enabled_by_default() -> bool {
+    true
+}
+
+/// 📄 flag หนึ่งตัวตามที่เขียนใน config
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct FlagDefinition {
+    /// kill switch - `false` แล้วทุกคนได้ค่า fallback ทันที
+    #[serde(default = "enabled_by_default")]
+    pub enabled: bool,
+    #[serde(flatten)]
+    pub kind: FlagKind,
+}
+
+/// ✅ ค่าที่ได้หลังตัดสิน flag ให้ผู้ใช้หนึ่งคน
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FlagValue {
+    Bool(bool),
+    String(String),
+    Variant(String),
+}
+
+impl fmt::Display for FlagValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bool(value) => write!(f, "{value}"),
+            Self::String(value) | Self::Variant(value) => f.write_str(value),
+        }
+    }
+}
+
+/// 🎲 bucket ของผู้ใช้สำหรับ flag นี้ (`0..BUCKETS`)
+///
+/// ใช้ FNV-1a 64 bit ซึ่งไม่ขึ้นกับ platform หรือเวอร์ชันของ Rust
+/// ใส่ชื่อ flag เป็น salt เพื่อไม่ให้คนกลุ่มเดิมได้ทุกฟีเจอร์ใหม่ก่อนเสมอ
+#[must_use]
+pub fn bucket(flag: &str, user_id: &str) -> u32 {
+    const OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+    let hash = [flag.as_bytes(), b":", user_id.as_bytes()]
+        .concat()
+        .iter()
+        .fold(OFFSET, |hash, byte| {
+            (hash ^ u64::from(*byte)).wrapping_mul(PRIME)
+        });
+    u32::try_from(hash % u64::from(BUCKETS)).unwrap_or(0)
+}
+
+fn in_rollout(bucket: u32, rollout: f64) -> bool {
+    f64::from(bucket) < rollout.clamp(0.0, 100.0) * f64::from(BUCKETS) / 100.0
+}
+
+impl FlagDefinition {
+    /// ตัดสินค่าให้ `user_id` (ไม่รวม override)
+    #[must_use]
+    pub fn evaluate(&self, flag: &str, user_id: &str) -> FlagValue {
+        let bucket = bucket(flag, user_id);
+        match &self.kind {
+            FlagKind::Bool { rollout } => {
+                FlagValue::Bool(self.enabled && in_rollout(bucket, *rollout))
+            }
+            FlagKind::String {
+                value,
+                fallback,
+                rollout,
+            } => {
+                let chosen = if self.enabled && in_rollout(bucket, *rollout) {
+                    value
+                } else {
+                    fallback
+                };
+                FlagValue::String(chosen.clone())
+            }
+            FlagKind::Variant { variants, fallback } => {
+                let total: u64 = variants.values().map(|weight| u64::from(*weight)).sum();
+                if !self.enabled || total == 0 {
+                    return FlagValue::Variant(fallback.clone());
+                }
+                let point = u64::from(bucket) * total / u64::from(BUCKETS);
+                let mut cumulative = 0;
+                let chosen = variants
+                    .iter()
+                    .find(|(_, weight)| {
+                        cumulative += u64::from(**weight);
+                        point < cumulative
+                    })
+                    .map_or(fallback, |(name, _)| name);
+                FlagValue::Variant(chosen.clone())
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+struct FlagFile {
+    #[serde(default)]
+    flags: BTreeMap<String, FlagDefinition>,
+}
+
+type OverrideKey = (String, Option<String>);
+
+/// 🚩 ชุด flag ที่อ่านจาก config - แชร์ข้าม thread ได้ และ reload ระหว่างรันได้
+///
+/// การอ่าน (`evaluate`) ใช้แค่ read lock จึงไม่แย่งกันเองใน hot path
+#[derive(Debug, Default)]
+pub struct FeatureFlags {
+    definitions: RwLock<Arc<BTreeMap<String, FlagDefinition>>>,
+    overrides: RwLock<HashMap<OverrideKey, FlagValue>>,
+    generation: AtomicU64,
+}
+
+impl FeatureFlags {
+    /// อ่าน flag จากตาราง `flags` ของ config
+    ///
+    /// # Errors
+    ///
+    /// คืน `ConfigError::Invalid` ที่ชี้ไปยัง key ของ flag ที่เขียนผิด
+    pub fn from_config(config: &Config) -> Result<Self, ConfigError> {
+        let flags = Self::default();
+        flags.reload(config)?;
+        Ok(flags)
+    }
+
+    /// อ่านจากไฟล์ TOML
+    ///
+    /// # Errors
+    ///
+    /// คืน error ถ้าอ่านไฟล์ไม่ได้หรือ flag เขียนผิด
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        Self::from_config(&Config::builder().file(path).build()?)
+    }
+
+    /// 🔄 แทนที่ flag ทั้งชุดแบบ atomic - override ที่ตั้งไว้ยังอยู่
+    ///
+    /// # Errors
+    ///
+    /// คืน error ถ้า flag เขียนผิด (ชุดเดิมยังใช้งานต่อ)
+    ///
+    /// # Panics
+    ///
+    /// ถ้า lock ถูก poison
+    pub fn reload(&self, config: &Config) -> Result<(), ConfigError> {
+        let file: FlagFile = config.deserialize()?;
+        *self.definitions.write().unwrap() = Arc::new(file.flags);
+        self.generation.fetch_add(1, Ordering::SeqCst);
+        Ok(())
+    }
+
+    /// จำนวนครั้งที่โหลด flag สำเร็จ - ใช้ดูว่า hot reload ทำงานแล้วหรือยัง
+    #[must_use]
+    pub fn generation(&self) -> u64 {
+        self.generation.load(Ordering::SeqCst)
+    }
+
+    /// ชื่อ flag ทั้งหมดที่รู้จัก
+    ///
+    /// # Panics
+    ///
+    /// ถ้า lock ถูก poison
+    #[must_use]
+    pub fn names(&self) -> Vec<String> {
+        self.definitions.read().unwrap().keys().cloned().collect()
+    }
+
+    /// ตัดสินค่าของ `flag` ให้ `user_id` - `None` ถ้าไม่รู้จัก flag นี้และไม่มี override
+    ///
+    /// # Panics
+    ///
+    /// ถ้า lock ถูก poison
+    #[must_use]
+    pub fn evaluate(&self, flag: &str, user_id: &str) -> Option<FlagValue> {
+        let overridden = {
+            let overrides = self.overrides.read().unwrap();
+            overrides
+                .get(&(flag.to_string(), Some(user_id.to_string())))
+                .or_else(|| overrides.get(&(flag.to_string(), None)))
+                .cloned()
+        };
+        if overridden.is_some() {
+            return overridden;
+        }
+        let definitions = Arc::clone(&self.definitions.read().unwrap());
+        definitions
+            .get(flag)
+            .map(|definition| definition.evaluate(flag, user_id))
+    }
+
+    /// flag แบบ bool - flag ที่ไม่รู้จักหรือผิดชนิดถือว่าปิด
+    #[must_use]
+    pub fn is_enabled(&self, flag: &str, user_id: &str) -> bool {
+        matches!(self.evaluate(flag, user_id), Some(FlagValue::Bool(true)))
+    }
+
+    /// flag แบบ string หรือ variant
+    #[must_use]
+    pub fn text(&self, flag: &str, user_id: &str) -> Option<String> {
+        match self.evaluate(flag, user_id)? {
+            FlagValue::String(value) | FlagValue::Variant(value) => Some(value),
+            FlagValue::Bool(_) => None,
+        }
+    }
+
+    /// 🎛️ บังคับค่าของ flag ระหว่างรัน - `user_id = None` คือทุกคน
+    ///
+    /// # Panics
+    ///
+    /// ถ้า lock ถูก poison
+    pub fn set_override(&self, flag: &str, user_id: Option<&str>, value: FlagValue) {
+        self.overrides
+            .write()
+            .unwrap()
+            .insert((flag.to_string(), user_id.map(str::to_string)), value);
+    }
+
+    /// ลบ override ที่ตั้งไว้
+    ///
+    /// # Panics
+    ///
+    /// ถ้า lock ถูก poison
+    pub fn clear_override(&self, flag: &str, user_id: Option<&str>) {
+        self.overrides
+            .write()
+            .unwrap()
+            .remove(&(flag.to_string(), user_id.map(str::to_string)));
+    }
+
+    /// 👀 เฝ้าไฟล์ config แล้ว reload เมื่อเนื้อหาเปลี่ยน (ตรวจทุก `interval`)
+    ///
+    /// ไฟล์ต้องนิ่งครบ `interval` ก่อน reload ถ้าไฟล์ใหม่เขียนผิดหรือถูกลบ
+    /// จะใช้ชุดเดิมต่อ - หยุดเฝ้าเมื่อ `FlagWatcher` ถูก drop
+    #[must_use]
+    pub fn watch(self: &Arc<Self>, path: impl Into<PathBuf>, interval: Duration) -> FlagWatcher {
+        let path = path.into();
+        let flags = Arc::clone(self);
+        // จำ snapshot ก่อน spawn - ถ้ามีคนแก้ไฟล์ทันทีหลังเรียก `watch` จะได้ไม่พลาด
+        let mut watcher = FileWatcher::new(interval);
+        if let Err(e) = watcher.watch(&path) {
+            logger::warn!(target: "feature_flags", path = path.display(); "cannot read flags file: {e}");
+        }
+        let handle = watcher.spawn_with(interval, move |events| {
+            if events.iter().all(|event| event.kind == ChangeKind::Deleted) {
+                return;
+            }
+            let reloaded = Config::builder()
+                .file(&path)
+                .build()
+                .and_then(|config| flags.reload(&config));
+            if let Err(e) = reloaded {
+                logger::warn!(target: "feature_flags", path = path.display(); "keeping previous flags: {e}");
+            }
+        });
+        FlagWatcher { _handle: handle }
+    }
+}
+
+/// 👀 thread ที่เฝ้าไฟล์ flag - drop แล้วหยุดเฝ้า
+#[derive(Debug)]
+pub struct FlagWatcher {
+    _handle: WatchHandle,
+}
+
+/// 🎯 สาธิต feature flags: rollout, variants, override และ hot reload
+pub fn demonstrate_feature_flags() {
+    println!("\n🚩 === Feature Flags Demo ===");
+
+    let dir = std::env::temp_dir().join(format!("feature-flags-demo-{}", std::process::id()));
+    let path = dir.join("flags.toml");
+    let write = |rollout: u32| {
+        let text = format!(
+            "[flags.new_checkout]\ntype = \"bool\"\nrollout = {rollout}\n\n\
+             [flags.button_color]\ntype = \"variant\"\nvariants = {{ blue = 50, green = 30, red = 20 }}\n"
+        );
+        fs::create_dir_all(&dir).and_then(|()| fs::write(&path, text))
+    };
+    if let Err(e) = write(10) {
+        println!("❌ เขียนไฟล์ไม่ได้: {e}");
+        return;
+    }
+    let flags = match FeatureFlags::load(&path) {
+        Ok(flags) => Arc::new(flags),
+        Err(e) => {
+            println!("❌ {e}");
+            return;
+        }
+    };
+
+    let users: Vec<String> = (1..=1000).map(|n| format!("user-{n}")).collect();
+    let enabled = |flags: &FeatureFlags| {
+        users
+            .iter()
+            .filter(|user| flags.is_enabled("new_checkout", user))
+            .count()
+    };
+    println!(
+        "1️⃣ new_checkout rollout 10% → เปิดให้ {} / 1000 คน",
+        enabled(&flags)
+    );
+    println!(
+        "   🎲 bucket ของ user-42 = {} (เหมือนเดิมทุกครั้ง)",
+        bucket("new_checkout", "user-42")
+    );
+
+    let mut colors: BTreeMap<String, usize> = BTreeMap::new();
+    for user in &users {
+        if let Some(color) = flags.text("button_color", user) {
+            *colors.entry(color).or_default() += 1;
+        }
+    }
+    println!("2️⃣ button_color (50/30/20): {colors:?}");
+
+    flags.set_override("new_checkout", Some("qa-tester"), FlagValue::Bool(true));
+    println!(
+        "3️⃣ override ให้ qa-tester: {}",
+        flags.is_enabled("new_checkout", "qa-tester")
+    );
+
+    let watcher = flags.watch(&path, Duration::from_millis(20));
+    let before = flags.generation();
+    if write(50).is_ok() {
+        for _ in 0..100 {
+            if flags.generation() > before {
+                break;
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+    }
+    println!(
+        "4️⃣ แก้ไฟล์เป็น 50% → hot reload แล้วเปิดให้ {} / 1000 คน",
+        enabled(&flags)
+    );
+    drop(watcher);
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FLAGS: &str = r#"
+[flags.new_checkout]
+type = "bool"
+rollout = 25
+
+[flags.banner]
+type = "string"
+value = "sale"
+fallback = "welcome"
+
+[flags.button_color]
+type = "variant"
+variants = { blue = 50, green = 30, red = 20 }
+
+[flags.killed]
+enabled = false
+type = "variant"
+variants = { a = 1 }
+"#;
+
+    fn load(text: &str) -> (tempfile::TempDir, PathBuf, Arc<FeatureFlags>) {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("flags.toml");
+        fs::write(&path, text).unwrap();
+        let flags = Arc::new(FeatureFlags::load(&path).unwrap());
+        (dir, path, flags)
+    }
+
+    #[test]
+    fn test_bucketing_is_deterministic() {
+        // ค่าคงที่ - ถ้าเปลี่ยน hash ผู้ใช้ทุกคนจะย้าย bucket
+        assert_eq!(
+            bucket("new_checkout", "user-42"),
+            bucket("new_checkout", "user-42")
+        );
+        assert_eq!(bucket("", ""), 8_189);
+        assert_eq!(bucket("new_checkout", "user-42"), 6_076);
+        assert_ne!(
+            bucket("new_checkout", "user-42"),
+            bucket("other_flag", "user-42")
+        );
+
+        let (_dir, _path, flags) = load(FLAGS);
+        let users: Vec<String> = (0..10_000).map(|n| format!("user-{n}")).collect();
+        let enabled = users
+            .iter()
+            .filter(|u| flags.is_enabled("new_checkout", u))
+            .count();
+        assert!(
+            (2_300..2_700).contains(&enabled),
+            "25% rollout enabled {enabled}"
+        );
+
+        // เพิ่ม rollout แล้วคนเดิมต้องยังอยู่
+        let wider = FlagDefinition {
+            enabled: true,
+            kind: FlagKind::Bool { rollout: 50.0 },
+        };
+        for user in users.iter().filter(|u| flags.is_enabled("new_checkout", u)) {
+            assert_eq!(wider.evaluate("new_checkout", user), FlagValue::Bool(true));
+        }
+
+        let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+        for user in &users {
+            *counts
+                .entry(flags.text("button_color", user).unwrap())
+                .or_default() += 1;
+        }
+        assert!((4_700..5_300).contains(&counts["blue"]));
+        assert!((2_700..3_300).contains(&counts["green"]));
+        assert!((1_700..2_300).contains(&counts["red"]));
+    }
+
+    #[test]
+    fn test_flag_kinds_and_overrides() {
+        let (_dir, _path, flags) = load(FLAGS);
+        assert_eq!(flags.text("banner", "anyone").as_deref(), Some("sale"));
+        assert_eq!(flags.text("killed", "anyone").as_deref(), Some("control"));
+        assert_eq!(flags.evaluate("missing", "anyone"), None);
+        assert!(!flags.is_enabled("banner", "anyone"));
+
+        let user = (0..1_000)
+            .map(|n| format!("u{n}"))
+            .find(|u| !flags.is_enabled("new_checkout", u))
+            .unwrap();
+        flags.set_override("new_checkout", None, FlagValue::Bool(true));
+        assert!(flags.is_enabled("new_checkout", &user));
+        flags.set_override("new_checkout", Some(&user), FlagValue::Bool(false));
+        assert!(!flags.is_enabled("new_checkout", &user));
+        assert!(flags.is_enabled("new_checkout", "someone-else"));
+
+        flags.clear_override("new_checkout", None);
+        flags.clear_override("new_checkout", Some(&user));
+        assert!(!flags.is_enabled("new_checkout", &user));
+    }
+
+    #[test]
+    fn test_invalid_flag_points_at_key() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("flags.toml");
+        fs::write(
+            &path,
+            "[flags.broken]\ntype = \"bool\"\nrollout = \"lots\"\n",
+        )
+        .unwrap();
+        let err = FeatureFlags::load(&path).unwrap_err().to_string();
+        assert!(err.contains("flags.broken"), "{err}");
+    }
+
+    #[test]
+    fn test_hot_reload_from_watched_file() {
+        let (_dir, path, flags) = load("[flags.beta]\ntype = \"bool\"\nrollout = 0\n");
+        assert!(!flags.is_enabled("beta", "user-1"));
+        let watcher = flags.watch(&path, Duration::from_millis(5));
+
+        let wait_for_generation = |target: u64| {
+            for _ in 0..400 {
+                if flags.generation() >= target {
+                    return;
+                }
+                thread::sleep(Duration::from_millis(5));
+            }
+            panic!("flags were not reloaded");
+        };
+
+        // ไฟล์เสียระหว่างทาง → ยังใช้ชุดเดิม
+        fs::write(&path, "[flags.beta]\ntype = \"bool\"\nrollout = [\n").unwrap();
+        thread::sleep(Duration::from_millis(50));
+        assert_eq!(flags.generation(), 1);
+
+        fs::write(&path, "[flags.beta]\ntype = \"bool\"\nrollout = 100\n").unwrap();
+        wait_for_generation(2);
+        assert!(flags.is_enabled("beta", "user-1"));
+
+        drop(watcher);
+        fs::write(&path, "[flags.beta]\ntype = \"bool\"\nrollout = 0\n").unwrap();
+        thread::sleep(Duration::from_millis(50));
+        assert!(flags.is_enabled("beta", "user-1"));
+    }
+}
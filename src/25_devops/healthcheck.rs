@@ -0,0 +1,364 @@
+//! 💓 Health Checks & Readiness Probes - ให้ orchestrator รู้ว่า service พร้อมรับงานไหม
+//!
+//! แต่ละ subsystem ลงทะเบียน check ของตัวเองไว้ใน `HealthRegistry`
+//! แล้วเปิดเป็น HTTP endpoint ผ่าน `WebServer` ของบทที่ 17:
+//!
+//! - `GET /health/live` - liveness: process ยังทำงานอยู่ไหม (ถ้าไม่ → restart)
+//! - `GET /health/ready` - readiness: พร้อมรับ traffic ไหม (ถ้าไม่ → ถอดออกจาก load balancer)
+//! - `GET /health` - ทุก check รวมกัน
+//!
+//! ตอบเป็น JSON พร้อม status code 200 (healthy/degraded) หรือ 503 (unhealthy)
+
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+
+use super::monitoring_observability::HealthStatus;
+use crate::web_development::web_server::WebServer;
+use crate::web_development::{HttpResponse, HttpStatus};
+
+/// 🔍 ชนิดของ probe
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Probe {
+    /// process ยังไม่ค้าง - ถ้าล้ม orchestrator จะ restart
+    Liveness,
+    /// dependency พร้อม - ถ้าล้มจะหยุดส่ง traffic มาชั่วคราว
+    Readiness,
+}
+
+/// 📋 ผลของ check หนึ่งครั้ง: สถานะ + รายละเอียดสำหรับคนอ่าน
+#[derive(Debug, Clone, PartialEq)]
+pub struct CheckOutcome {
+    pub status: HealthStatus,
+    pub detail: String,
+}
+
+impl CheckOutcome {
+    #[must_use]
+    pub fn healthy(detail: &str) -> Self {
+        Self {
+            status: HealthStatus::Healthy,
+            detail: detail.to_string(),
+        }
+    }
+
+    /// ยังให้บริการได้แต่ช้าลงหรือขาดบางความสามารถ - ยังตอบ 200
+    #[must_use]
+    pub fn degraded(detail: &str) -> Self {
+        Self {
+            status: HealthStatus::Degraded,
+            detail: detail.to_string(),
+        }
+    }
+
+    #[must_use]
+    pub fn unhealthy(detail: &str) -> Self {
+        Self {
+            status: HealthStatus::Unhealthy,
+            detail: detail.to_string(),
+        }
+    }
+}
+
+type CheckFn = Arc<dyn Fn() -> CheckOutcome + Send + Sync>;
+
+#[derive(Clone)]
+struct RegisteredCheck {
+    name: String,
+    probe: Probe,
+    check: CheckFn,
+}
+
+/// 📝 ผลของ check หนึ่งตัวใน report
+#[derive(Debug, Clone, Serialize)]
+pub struct CheckReport {
+    pub name: String,
+    pub probe: Probe,
+    #[serde(serialize_with = "serialize_status")]
+    pub status: HealthStatus,
+    pub detail: String,
+    pub duration_ms: f64,
+}
+
+/// 📊 ผลรวมของทุก check - สถานะแย่ที่สุดชนะ
+#[derive(Debug, Clone, Serialize)]
+pub struct HealthReport {
+    #[serde(serialize_with = "serialize_status")]
+    pub status: HealthStatus,
+    pub checks: Vec<CheckReport>,
+}
+
+impl HealthReport {
+    /// HTTP status ที่ควรตอบ: unhealthy (หรือไม่รู้สถานะ) → 503 นอกนั้น 200
+    #[must_use]
+    pub const fn http_status(&self) -> HttpStatus {
+        match self.status {
+            HealthStatus::Healthy | HealthStatus::Degraded => HttpStatus::Ok,
+            HealthStatus::Unhealthy | HealthStatus::Unknown => HttpStatus::ServiceUnavailable,
+        }
+    }
+
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+
+    /// แปลงเป็น HTTP response - `Cache-Control: no-store` กัน proxy cache ผลเก่าไว้
+    #[must_use]
+    pub fn to_response(&self) -> HttpResponse {
+        HttpResponse::json(self.http_status(), &self.to_json())
+            .with_header("Cache-Control", "no-store")
+    }
+}
+
+fn serialize_status<S: serde::Serializer>(
+    status: &HealthStatus,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(status)
+}
+
+/// 💓 ที่ลงทะเบียน health check ของทุก subsystem
+///
+/// clone ได้ราคาถูก - ทุก clone ใช้รายการ check ชุดเดียวกัน
+/// check ถูกรันนอก lock จึงลงทะเบียนเพิ่มระหว่างที่มีคนเรียก endpoint ได้
+#[derive(Clone, Default)]
+pub struct HealthRegistry {
+    checks: Arc<Mutex<Vec<RegisteredCheck>>>,
+}
+
+impl HealthRegistry {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// ลงทะเบียน check - ชื่อซ้ำจะแทนที่ของเดิม
+    ///
+    /// # Panics
+    ///
+    /// ถ้า lock ถูก poison
+    pub fn register<F>(&self, name: &str, probe: Probe, check: F)
+    where
+        F: Fn() -> CheckOutcome + Send + Sync + 'static,
+    {
+        let mut checks = self.checks.lock().unwrap();
+        checks.retain(|existing| existing.name != name);
+        checks.push(RegisteredCheck {
+            name: name.to_string(),
+            probe,
+            check: Arc::new(check),
+        });
+    }
+
+    pub fn liveness<F>(&self, name: &str, check: F)
+    where
+        F: Fn() -> CheckOutcome + Send + Sync + 'static,
+    {
+        self.register(name, Probe::Liveness, check);
+    }
+
+    pub fn readiness<F>(&self, name: &str, check: F)
+    where
+        F: Fn() -> CheckOutcome + Send + Sync + 'static,
+    {
+        self.register(name, Probe::Readiness, check);
+    }
+
+    /// รัน check ของ probe ที่ระบุ (`None` = ทุกตัว)
+    ///
+    /// check ที่ panic นับเป็น unhealthy แทนที่จะทำให้ endpoint ล่ม
+    /// และ report ที่ไม่มี check เลยถือว่า healthy
+    ///
+    /// # Panics
+    ///
+    /// ถ้า lock ถูก poison
+    #[must_use]
+    pub fn run(&self, probe: Option<Probe>) -> HealthReport {
+        let checks: Vec<CheckReport> = self
+            .snapshot(probe)
+            .into_iter()
+            .map(|RegisteredCheck { name, probe, check }| {
+                let started = Instant::now();
+                let outcome = panic::catch_unwind(AssertUnwindSafe(|| check()))
+                    .unwrap_or_else(|_| CheckOutcome::unhealthy("check panicked"));
+                CheckReport {
+                    name,
+                    probe,
+                    status: outcome.status,
+                    detail: outcome.detail,
+                    duration_ms: duration_ms(started.elapsed()),
+                }
+            })
+            .collect();
+
+        let status = checks
+            .iter()
+            .map(|check| check.status.clone())
+            .max_by_key(severity)
+            .unwrap_or(HealthStatus::Healthy);
+        HealthReport { status, checks }
+    }
+
+    /// copy รายการ check ออกมาก่อน - ถือ lock แค่ช่วงสั้น ๆ ไม่ค้างไว้ระหว่างรัน check
+    fn snapshot(&self, probe: Option<Probe>) -> Vec<RegisteredCheck> {
+        self.checks
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|registered| probe.is_none_or(|wanted| registered.probe == wanted))
+            .cloned()
+            .collect()
+    }
+
+    /// 🌐 เพิ่ม `GET /health`, `GET /health/live` และ `GET /health/ready` ลงใน server
+    pub fn mount(&self, server: &mut WebServer) {
+        for (path, probe) in [
+            ("GET /health", None),
+            ("GET /health/live", Some(Probe::Liveness)),
+            ("GET /health/ready", Some(Probe::Readiness)),
+        ] {
+            let registry = self.clone();
+            server.route(path, move |_req| registry.run(probe).to_response());
+        }
+    }
+}
+
+/// ลำดับความรุนแรง - `Unknown` ถือว่าแย่เท่า unhealthy เพราะยืนยันอะไรไม่ได้
+const fn severity(status: &HealthStatus) -> u8 {
+    match status {
+        HealthStatus::Healthy => 0,
+        HealthStatus::Degraded => 1,
+        HealthStatus::Unhealthy | HealthStatus::Unknown => 2,
+    }
+}
+
+fn duration_ms(duration: Duration) -> f64 {
+    (duration.as_secs_f64() * 1000.0 * 1000.0).round() / 1000.0
+}
+
+/// 🎯 สาธิต health/readiness probes ที่มี dependency ล่มกลางทาง
+pub fn demonstrate_health_checks() {
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    use crate::web_development::HttpRequest;
+
+    println!("\n💓 === Health Checks & Readiness Probes Demo ===");
+
+    let database_up = Arc::new(AtomicBool::new(true));
+    let registry = HealthRegistry::new();
+    registry.liveness("event_loop", || CheckOutcome::healthy("responding"));
+    let db = Arc::clone(&database_up);
+    registry.readiness("database", move || {
+        if db.load(Ordering::SeqCst) {
+            CheckOutcome::healthy("connection pool: 8/10 idle")
+        } else {
+            CheckOutcome::unhealthy("connection refused")
+        }
+    });
+    registry.readiness("cache", || {
+        CheckOutcome::degraded("redis replica lagging, using primary")
+    });
+
+    let mut server = WebServer::new();
+    registry.mount(&mut server);
+
+    for (label, up) in [("✅ database ปกติ", true), ("💥 database ล่ม", false)] {
+        database_up.store(up, Ordering::SeqCst);
+        println!("\n{label}:");
+        for path in ["/health/live", "/health/ready"] {
+            let response = server.handle_request(&HttpRequest::new("GET", path));
+            println!("   GET {path} → {}", response.status.as_str());
+        }
+    }
+
+    let report = registry.run(Some(Probe::Readiness));
+    println!("\n📄 GET /health/ready body:");
+    for line in report.to_json().lines() {
+        println!("   {line}");
+    }
+
+    println!("\n💡 liveness ยังเป็น 200 เพราะ restart ไม่ช่วยให้ database กลับมา");
+    println!("   readiness เป็น 503 เพื่อให้ load balancer หยุดส่ง traffic มาชั่วคราว");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::web_development::HttpRequest;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    fn registry_with_database(up: &Arc<AtomicBool>) -> HealthRegistry {
+        let registry = HealthRegistry::new();
+        registry.liveness("process", || CheckOutcome::healthy("ok"));
+        let up = Arc::clone(up);
+        registry.readiness("database", move || {
+            if up.load(Ordering::SeqCst) {
+                CheckOutcome::healthy("connected")
+            } else {
+                CheckOutcome::unhealthy("connection refused")
+            }
+        });
+        registry
+    }
+
+    #[test]
+    fn test_failing_dependency_only_affects_readiness() {
+        let database_up = Arc::new(AtomicBool::new(true));
+        let mut server = WebServer::new();
+        registry_with_database(&database_up).mount(&mut server);
+
+        let get = |path: &str| server.handle_request(&HttpRequest::new("GET", path));
+        assert_eq!(get("/health/ready").status, HttpStatus::Ok);
+
+        database_up.store(false, Ordering::SeqCst);
+        let ready = get("/health/ready");
+        assert_eq!(ready.status, HttpStatus::ServiceUnavailable);
+        assert_eq!(
+            ready.headers.get("Content-Type").map(String::as_str),
+            Some("application/json")
+        );
+        assert_eq!(get("/health/live").status, HttpStatus::Ok);
+        assert_eq!(get("/health").status, HttpStatus::ServiceUnavailable);
+
+        let body: serde_json::Value = serde_json::from_str(&ready.body).unwrap();
+        assert_eq!(body["status"], "unhealthy");
+        assert_eq!(body["checks"][0]["name"], "database");
+        assert_eq!(body["checks"][0]["probe"], "readiness");
+        assert_eq!(body["checks"][0]["detail"], "connection refused");
+
+        database_up.store(true, Ordering::SeqCst);
+        assert_eq!(get("/health/ready").status, HttpStatus::Ok);
+    }
+
+    #[test]
+    fn test_degraded_dependency_still_serves() {
+        let registry = registry_with_database(&Arc::new(AtomicBool::new(true)));
+        registry.readiness("cache", || CheckOutcome::degraded("replica lagging"));
+
+        let report = registry.run(Some(Probe::Readiness));
+        assert_eq!(report.status, HealthStatus::Degraded);
+        assert_eq!(report.http_status(), HttpStatus::Ok);
+        assert_eq!(report.checks.len(), 2);
+    }
+
+    #[test]
+    fn test_panicking_check_and_reregistration() {
+        let registry = HealthRegistry::new();
+        assert_eq!(registry.run(None).status, HealthStatus::Healthy);
+
+        registry.readiness("queue", || panic!("broker client poisoned"));
+        let report = registry.run(None);
+        assert_eq!(report.status, HealthStatus::Unhealthy);
+        assert_eq!(report.checks[0].detail, "check panicked");
+
+        // ลงทะเบียนชื่อเดิมซ้ำ = แทนที่
+        registry.readiness("queue", || CheckOutcome::healthy("reconnected"));
+        let report = registry.run(None);
+        assert_eq!(report.checks.len(), 1);
+        assert_eq!(report.status, HealthStatus::Healthy);
+    }
+}
@@ -3,8 +3,11 @@
 //! โมดูลนี้สาธิตการสร้างและจัดการ infrastructure ด้วยโค้ด
 //! รวมถึง Terraform, Kubernetes, Docker Compose, และ Cloud Formation
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::fmt;
+use serde::{Deserialize, Serialize};
+use crate::collections::graph::{CycleError, Graph, NodeId};
+use crate::config::Config;
 
 /// 🏗️ ประเภทของ Infrastructure Provider
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -608,6 +611,457 @@ impl InfrastructureTemplate {
     }
 }
 
+/// 🧱 Resource ที่ `ResourceGraph` จัดการได้ - บอกแค่ "อยากได้อะไร" ไม่ใช่ "ทำยังไง"
+pub trait Resource {
+    /// ชื่อที่ไม่ซ้ำกันใน graph (ใช้อ้างอิงใน `depends_on`)
+    fn name(&self) -> &str;
+
+    /// ชนิดของ resource เช่น `vpc`, `instance`
+    fn kind(&self) -> &str;
+
+    /// ชื่อ resource ที่ต้องสร้างก่อนตัวนี้
+    fn depends_on(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// ค่าที่ต้องการให้เป็น - `plan()` เทียบกับ state ปัจจุบันทีละ key
+    fn attributes(&self) -> BTreeMap<String, String>;
+
+    /// attribute ที่แก้ในที่ไม่ได้ ต้องลบแล้วสร้างใหม่ (เช่น CIDR ของ VPC)
+    fn replace_on_change(&self) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+/// property ที่ cloud แก้ในที่ไม่ได้ แยกตามชนิด resource
+const IMMUTABLE_PROPERTIES: &[(&str, &str)] = &[
+    ("vpc", "cidr_block"),
+    ("subnet", "cidr_block"),
+    ("subnet", "availability_zone"),
+    ("instance", "ami"),
+];
+
+impl Resource for InfraResource {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn kind(&self) -> &str {
+        &self.resource_type
+    }
+
+    fn depends_on(&self) -> Vec<String> {
+        self.dependencies.clone()
+    }
+
+    fn attributes(&self) -> BTreeMap<String, String> {
+        let mut attributes: BTreeMap<String, String> = self.properties.clone().into_iter().collect();
+        attributes.insert("provider".to_string(), self.provider.to_string());
+        for (key, value) in &self.tags {
+            attributes.insert(format!("tags.{key}"), value.clone());
+        }
+        attributes
+    }
+
+    fn replace_on_change(&self) -> Vec<String> {
+        std::iter::once("provider")
+            .chain(
+                IMMUTABLE_PROPERTIES
+                    .iter()
+                    .filter(|(kind, _)| *kind == self.resource_type)
+                    .map(|(_, property)| *property),
+            )
+            .map(str::to_string)
+            .collect()
+    }
+}
+
+/// ❌ ข้อผิดพลาดตอน plan/apply
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InfraError {
+    DuplicateResource(String),
+    UnknownDependency { resource: String, dependency: String },
+    /// วงจรใน dependency เช่น `["a", "b", "a"]`
+    Cycle(Vec<String>),
+    /// cloud ปฏิเสธการเปลี่ยนแปลง - ทุกอย่างที่ทำไปแล้วใน apply ครั้งนี้ถูกย้อนกลับ
+    ApplyFailed {
+        resource: String,
+        reason: String,
+        rolled_back: Vec<String>,
+    },
+}
+
+impl fmt::Display for InfraError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::DuplicateResource(name) => write!(f, "resource '{name}' is declared twice"),
+            Self::UnknownDependency { resource, dependency } => {
+                write!(f, "resource '{resource}' depends on unknown resource '{dependency}'")
+            }
+            Self::Cycle(path) => write!(f, "dependency cycle: {}", path.join(" → ")),
+            Self::ApplyFailed { resource, reason, rolled_back } => write!(
+                f,
+                "failed to apply '{resource}': {reason} (rolled back {} change(s))",
+                rolled_back.len()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for InfraError {}
+
+/// 💾 สิ่งที่ cloud จำไว้เกี่ยวกับ resource หนึ่งตัว (เหมือน `terraform.tfstate`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResourceState {
+    pub id: String,
+    pub kind: String,
+    pub attributes: BTreeMap<String, String>,
+    pub depends_on: Vec<String>,
+}
+
+/// ☁️ cloud จำลองที่เก็บ state ไว้ในหน่วยความจำ และสั่งให้ล้มตอนแตะ resource ที่กำหนดได้
+#[derive(Debug, Clone, Default)]
+pub struct SimulatedCloud {
+    resources: BTreeMap<String, ResourceState>,
+    failing: BTreeSet<String>,
+    next_id: u32,
+}
+
+impl SimulatedCloud {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// ให้การ create/update/delete ของ `name` ล้มเหลว - ใช้ทดสอบ rollback
+    pub fn fail_on(&mut self, name: &str) {
+        self.failing.insert(name.to_string());
+    }
+
+    pub fn clear_failures(&mut self) {
+        self.failing.clear();
+    }
+
+    #[must_use]
+    pub const fn state(&self) -> &BTreeMap<String, ResourceState> {
+        &self.resources
+    }
+
+    fn check_failure(&self, name: &str) -> Result<(), String> {
+        if self.failing.contains(name) {
+            Err("simulated provider error".to_string())
+        } else {
+            Ok(())
+        }
+    }
+
+    fn create(&mut self, name: &str, desired: &DesiredResource) -> Result<(), String> {
+        self.check_failure(name)?;
+        self.next_id += 1;
+        let state = ResourceState {
+            id: format!("{}-{:04}", desired.kind, self.next_id),
+            kind: desired.kind.clone(),
+            attributes: desired.attributes.clone(),
+            depends_on: desired.depends_on.clone(),
+        };
+        self.resources.insert(name.to_string(), state);
+        Ok(())
+    }
+
+    fn update(&mut self, name: &str, desired: &DesiredResource) -> Result<(), String> {
+        self.check_failure(name)?;
+        let state = self.resources.get_mut(name).ok_or("resource does not exist")?;
+        state.attributes.clone_from(&desired.attributes);
+        state.depends_on.clone_from(&desired.depends_on);
+        Ok(())
+    }
+
+    fn delete(&mut self, name: &str) -> Result<(), String> {
+        self.check_failure(name)?;
+        self.resources.remove(name).map(|_| ()).ok_or_else(|| "resource does not exist".to_string())
+    }
+
+    /// 🚀 ทำตาม plan ทีละขั้น - ถ้าขั้นไหนล้ม ย้อนทุกขั้นที่ทำไปแล้วกลับในลำดับตรงข้าม
+    ///
+    /// คืนจำนวน resource ที่เปลี่ยน
+    ///
+    /// # Errors
+    ///
+    /// คืน `InfraError::ApplyFailed` ถ้า cloud ปฏิเสธการเปลี่ยนแปลง (state กลับเป็นเหมือนก่อน apply)
+    pub fn apply(&mut self, plan: &Plan) -> Result<usize, InfraError> {
+        let mut journal: Vec<(String, Option<ResourceState>)> = Vec::new();
+        let mut applied = 0;
+        for change in &plan.changes {
+            let before = self.resources.get(&change.name).cloned();
+            let result = match (&change.action, &change.desired) {
+                (Action::NoOp, _) => continue,
+                (Action::Create, Some(desired)) => self.create(&change.name, desired),
+                (Action::Update, Some(desired)) => self.update(&change.name, desired),
+                (Action::Replace, Some(desired)) => {
+                    self.delete(&change.name).and_then(|()| self.create(&change.name, desired))
+                }
+                (Action::Delete, _) => self.delete(&change.name),
+                (_, None) => Err("plan has no desired state".to_string()),
+            };
+            if let Err(reason) = result {
+                // ย้อนสิ่งที่ทำไปแล้ว (รวมถึงครึ่งแรกของ replace ที่ล้ม)
+                journal.push((change.name.clone(), before));
+                let rolled_back = journal
+                    .into_iter()
+                    .rev()
+                    .map(|(name, previous)| {
+                        match previous {
+                            Some(state) => self.resources.insert(name.clone(), state),
+                            None => self.resources.remove(&name),
+                        };
+                        name
+                    })
+                    .filter(|name| *name != change.name)
+                    .collect();
+                return Err(InfraError::ApplyFailed {
+                    resource: change.name.clone(),
+                    reason,
+                    rolled_back,
+                });
+            }
+            journal.push((change.name.clone(), before));
+            applied += 1;
+        }
+        Ok(applied)
+    }
+}
+
+/// 🎯 สิ่งที่ต้องการให้ resource เป็น ณ ตอน plan
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DesiredResource {
+    pub kind: String,
+    pub attributes: BTreeMap<String, String>,
+    pub depends_on: Vec<String>,
+}
+
+/// ✏️ การกระทำกับ resource หนึ่งตัว
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Create,
+    Update,
+    /// ลบแล้วสร้างใหม่ เพราะแก้ attribute ที่แก้ในที่ไม่ได้
+    Replace,
+    Delete,
+    NoOp,
+}
+
+impl Action {
+    /// สัญลักษณ์แบบ `terraform plan`
+    #[must_use]
+    pub const fn symbol(self) -> &'static str {
+        match self {
+            Self::Create => "+",
+            Self::Update => "~",
+            Self::Replace => "-/+",
+            Self::Delete => "-",
+            Self::NoOp => " ",
+        }
+    }
+}
+
+/// 🔍 ความต่างของ attribute หนึ่งตัว (`None` = ไม่มีค่า)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AttributeDiff {
+    pub key: String,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+/// 📝 การเปลี่ยนแปลงของ resource หนึ่งตัวใน plan
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Change {
+    pub name: String,
+    pub action: Action,
+    pub desired: Option<DesiredResource>,
+    pub diff: Vec<AttributeDiff>,
+}
+
+/// 📋 ผลของ `plan()` - ลำดับของ change เรียงตาม dependency แล้ว
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plan {
+    pub changes: Vec<Change>,
+}
+
+impl Plan {
+    #[must_use]
+    pub fn count(&self, action: Action) -> usize {
+        self.changes.iter().filter(|change| change.action == action).count()
+    }
+
+    /// ไม่มีอะไรต้องทำ - infrastructure ตรงกับ code แล้ว
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.changes.iter().all(|change| change.action == Action::NoOp)
+    }
+}
+
+impl fmt::Display for Plan {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for change in self.changes.iter().filter(|change| change.action != Action::NoOp) {
+            writeln!(f, "{:>3} {}", change.action.symbol(), change.name)?;
+            for diff in &change.diff {
+                let before = diff.before.as_deref().unwrap_or("(none)");
+                let after = diff.after.as_deref().unwrap_or("(none)");
+                writeln!(f, "      {}: {before} → {after}", diff.key)?;
+            }
+        }
+        write!(
+            f,
+            "Plan: {} to add, {} to change, {} to replace, {} to destroy.",
+            self.count(Action::Create),
+            self.count(Action::Update),
+            self.count(Action::Replace),
+            self.count(Action::Delete)
+        )
+    }
+}
+
+/// 🕸️ กราฟของ resource ที่ประกาศไว้ - ต้นทางของ `plan()`
+#[derive(Default)]
+pub struct ResourceGraph {
+    resources: Vec<Box<dyn Resource>>,
+}
+
+impl ResourceGraph {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn with(mut self, resource: impl Resource + 'static) -> Self {
+        self.resources.push(Box::new(resource));
+        self
+    }
+
+    /// ลำดับการสร้างที่ทุก dependency มาก่อนเสมอ (topological sort)
+    ///
+    /// resource ที่ไม่ขึ้นต่อกันคงลำดับตามที่ประกาศ ผลลัพธ์จึงเหมือนเดิมทุกครั้ง
+    ///
+    /// # Errors
+    ///
+    /// คืน error ถ้าชื่อซ้ำ อ้างถึง resource ที่ไม่มี หรือมีวงจร
+    pub fn order(&self) -> Result<Vec<String>, InfraError> {
+        let mut nodes: Vec<(String, Vec<String>)> = Vec::new();
+        for resource in &self.resources {
+            let name = resource.name().to_string();
+            if nodes.iter().any(|(existing, _)| *existing == name) {
+                return Err(InfraError::DuplicateResource(name));
+            }
+            nodes.push((name, resource.depends_on()));
+        }
+        for (name, deps) in &nodes {
+            if let Some(missing) = deps.iter().find(|dep| !nodes.iter().any(|(other, _)| other == *dep)) {
+                return Err(InfraError::UnknownDependency {
+                    resource: name.clone(),
+                    dependency: missing.clone(),
+                });
+            }
+        }
+        topological_order(&nodes)
+    }
+
+    /// 🔍 เทียบ code กับ state ใน cloud แล้วบอกว่าต้องทำอะไรบ้าง (ยังไม่แตะ cloud)
+    ///
+    /// create/update/replace เรียงตาม dependency ส่วน resource ที่ถูกลบออกจาก code
+    /// จะถูก destroy ท้ายสุดโดยลบตัวที่พึ่งพาคนอื่นก่อน
+    ///
+    /// # Errors
+    ///
+    /// คืน error จาก `order()`
+    pub fn plan(&self, cloud: &SimulatedCloud) -> Result<Plan, InfraError> {
+        let mut changes = Vec::new();
+        for name in self.order()? {
+            let Some(resource) = self.resources.iter().find(|resource| resource.name() == name) else {
+                continue;
+            };
+            let desired = DesiredResource {
+                kind: resource.kind().to_string(),
+                attributes: resource.attributes(),
+                depends_on: resource.depends_on(),
+            };
+            let (action, diff) = match cloud.state().get(&name) {
+                None => (Action::Create, attribute_diff(&BTreeMap::new(), &desired.attributes)),
+                Some(current) => {
+                    let diff = attribute_diff(&current.attributes, &desired.attributes);
+                    let immutable = resource.replace_on_change();
+                    let action = if current.kind != desired.kind
+                        || diff.iter().any(|d| immutable.contains(&d.key))
+                    {
+                        Action::Replace
+                    } else if diff.is_empty() && current.depends_on == desired.depends_on {
+                        Action::NoOp
+                    } else {
+                        Action::Update
+                    };
+                    (action, diff)
+                }
+            };
+            changes.push(Change { name, action, desired: Some(desired), diff });
+        }
+
+        let orphans: Vec<(String, Vec<String>)> = cloud
+            .state()
+            .iter()
+            .filter(|(name, _)| !self.resources.iter().any(|resource| resource.name() == name.as_str()))
+            .map(|(name, state)| (name.clone(), state.depends_on.clone()))
+            .collect();
+        let orphan_names: Vec<&String> = orphans.iter().map(|(name, _)| name).collect();
+        let orphans: Vec<(String, Vec<String>)> = orphans
+            .iter()
+            .map(|(name, deps)| {
+                let deps = deps.iter().filter(|dep| orphan_names.contains(dep)).cloned().collect();
+                (name.clone(), deps)
+            })
+            .collect();
+        for name in topological_order(&orphans)?.into_iter().rev() {
+            let diff = attribute_diff(&cloud.state()[&name].attributes, &BTreeMap::new());
+            changes.push(Change { name, action: Action::Delete, desired: None, diff });
+        }
+        Ok(Plan { changes })
+    }
+}
+
+/// เรียง node ให้ dependency มาก่อน - edge ชี้จาก dependency ไปหา resource ที่พึ่งมัน
+///
+/// `Graph::topological_sort` เลือก id น้อยสุดก่อนเสมอ resource ที่ไม่ขึ้นต่อกันจึงคงลำดับตามที่ประกาศ
+fn topological_order(nodes: &[(String, Vec<String>)]) -> Result<Vec<String>, InfraError> {
+    let mut graph = Graph::new();
+    for (name, _) in nodes {
+        graph.add_node(name.as_str());
+    }
+    for (resource, (_, deps)) in nodes.iter().enumerate() {
+        for dep in deps {
+            if let Some(dependency) = graph.find(|name| name == dep) {
+                graph.add_edge(dependency, resource, ());
+            }
+        }
+    }
+    let name = |id: NodeId| nodes[id].0.clone();
+    match graph.topological_sort() {
+        Ok(order) => Ok(order.into_iter().map(name).collect()),
+        // วงจรตามทิศ edge คือ "ถูกพึ่งโดย" - กลับด้านให้อ่านเป็น "พึ่ง" แบบ a → b → a
+        Err(CycleError(cycle)) => Err(InfraError::Cycle(cycle.into_iter().rev().map(name).collect())),
+    }
+}
+
+fn attribute_diff(before: &BTreeMap<String, String>, after: &BTreeMap<String, String>) -> Vec<AttributeDiff> {
+    let keys: BTreeSet<&String> = before.keys().chain(after.keys()).collect();
+    keys.into_iter()
+        .filter(|key| before.get(*key) != after.get(*key))
+        .map(|key| AttributeDiff {
+            key: key.clone(),
+            before: before.get(key).cloned(),
+            after: after.get(key).cloned(),
+        })
+        .collect()
+}
+
 /// 🎯 สาธิตการทำงานกับ Infrastructure as Code
 pub fn demonstrate_infrastructure_as_code() {
     println!("\n🏗️ === Infrastructure as Code Demo ===");
@@ -628,8 +1082,16 @@ pub fn demonstrate_infrastructure_as_code() {
     println!("\n4️⃣ Infrastructure Templates:");
     demonstrate_infrastructure_templates();
     
-    // 5. IaC Best Practices
-    println!("\n5️⃣ IaC Best Practices:");
+    // 5. Plan / Apply
+    println!("\n5️⃣ Plan / Apply (Terraform จำลอง):");
+    demonstrate_plan_apply();
+    
+    // 6. Application Config
+    println!("\n6️⃣ Application Config (defaults + TOML + env):");
+    demonstrate_layered_config();
+    
+    // 7. IaC Best Practices
+    println!("\n7️⃣ IaC Best Practices:");
     show_iac_best_practices();
     
     println!("\n✅ จบการสาธิต Infrastructure as Code!");
@@ -855,6 +1317,157 @@ fn demonstrate_infrastructure_templates() {
     println!("   • รองรับ version control");
 }
 
+/// 🧪 resource ชุดตัวอย่าง: VPC → subnet → web server
+fn example_network(web_ami: &str) -> ResourceGraph {
+    ResourceGraph::new()
+        .with(
+            InfraResource::new("web_server", "instance", InfraProvider::AWS)
+                .with_property("ami", web_ami)
+                .with_property("instance_type", "t3.micro")
+                .with_dependency("main_subnet"),
+        )
+        .with(
+            InfraResource::new("main_subnet", "subnet", InfraProvider::AWS)
+                .with_property("cidr_block", "10.0.1.0/24")
+                .with_dependency("main_vpc"),
+        )
+        .with(
+            InfraResource::new("main_vpc", "vpc", InfraProvider::AWS)
+                .with_property("cidr_block", "10.0.0.0/16")
+                .with_tag("Environment", "production"),
+        )
+}
+
+/// 🔁 สาธิตวงจร plan → apply แบบ Terraform กับ cloud จำลอง
+fn demonstrate_plan_apply() {
+    let mut cloud = SimulatedCloud::new();
+    let graph = example_network("ami-0c02fb55956c7d316");
+
+    match graph.order() {
+        Ok(order) => println!("🕸️ ลำดับการสร้าง: {}", order.join(" → ")),
+        Err(e) => println!("❌ {e}"),
+    }
+
+    let Ok(plan) = graph.plan(&cloud) else { return };
+    println!("\n📋 terraform plan (ครั้งแรก):");
+    for line in plan.to_string().lines().filter(|line| !line.starts_with("      ")) {
+        println!("   {line}");
+    }
+    match cloud.apply(&plan) {
+        Ok(applied) => println!("✅ apply สำเร็จ: {applied} resources"),
+        Err(e) => println!("❌ {e}"),
+    }
+
+    if let Ok(again) = graph.plan(&cloud) {
+        println!("\n🔁 plan ซ้ำหลัง apply: ไม่มีอะไรต้องทำ = {}", again.is_empty());
+    }
+
+    // เปลี่ยน AMI → ต้องสร้าง instance ใหม่ และ cloud ล้มกลางทาง
+    let upgraded = example_network("ami-0abcdef1234567890")
+        .with(InfraResource::new("assets", "bucket", InfraProvider::AWS).with_dependency("main_vpc"));
+    let Ok(plan) = upgraded.plan(&cloud) else { return };
+    println!("\n📋 terraform plan (อัปเกรด AMI + เพิ่ม bucket):");
+    for line in plan.to_string().lines() {
+        println!("   {line}");
+    }
+    cloud.fail_on("assets");
+    let before = cloud.state().clone();
+    if let Err(e) = cloud.apply(&plan) {
+        println!("💥 {e}");
+        println!("↩️ state กลับเป็นเหมือนก่อน apply: {}", *cloud.state() == before);
+    }
+
+    let cyclic = ResourceGraph::new()
+        .with(InfraResource::new("a", "vpc", InfraProvider::AWS).with_dependency("b"))
+        .with(InfraResource::new("b", "subnet", InfraProvider::AWS).with_dependency("a"));
+    if let Err(e) = cyclic.order() {
+        println!("\n🌀 ตรวจเจอ: {e}");
+    }
+}
+
+/// ⚙️ ค่าตั้งของ service ที่ deploy - โหลดจาก defaults → app.toml → env `APP_*`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServiceConfig {
+    pub name: String,
+    pub replicas: u32,
+    pub database: DatabaseSettings,
+}
+
+/// 🗄️ ค่าตั้งของฐานข้อมูลใน `ServiceConfig`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DatabaseSettings {
+    pub url: String,
+    pub pool_size: u32,
+}
+
+impl Default for ServiceConfig {
+    fn default() -> Self {
+        Self {
+            name: "rust-api".to_string(),
+            replicas: 1,
+            database: DatabaseSettings {
+                url: "postgres://localhost/dev".to_string(),
+                pool_size: 5,
+            },
+        }
+    }
+}
+
+/// ⚙️ สาธิตการโหลด config แบบซ้อนชั้นตามแนวทาง 12-factor app
+fn demonstrate_layered_config() {
+    let dir = match tempfile::tempdir() {
+        Ok(dir) => dir,
+        Err(e) => {
+            println!("   ❌ สร้างโฟลเดอร์ชั่วคราวไม่ได้: {e}");
+            return;
+        }
+    };
+    let path = dir.path().join("app.toml");
+    let app_toml = "replicas = 3\n\n[database]\nurl = \"postgres://db.internal/prod\"\n";
+    if let Err(e) = std::fs::write(&path, app_toml) {
+        println!("   ❌ เขียน app.toml ไม่ได้: {e}");
+        return;
+    }
+    println!("   📄 app.toml:");
+    for line in app_toml.lines().filter(|line| !line.is_empty()) {
+        println!("      {line}");
+    }
+
+    let loaded = Config::builder()
+        .defaults(&ServiceConfig::default())
+        .file(&path)
+        .env_prefix("APP_")
+        .build();
+    let config = match loaded {
+        Ok(config) => config,
+        Err(e) => {
+            println!("   ❌ {e}");
+            return;
+        }
+    };
+    match config.deserialize::<ServiceConfig>() {
+        Ok(service) => {
+            println!("   ✅ {service:?}");
+            for key in ["name", "replicas", "database.url", "database.pool_size"] {
+                if let Some(source) = config.source_of(key) {
+                    println!("   📍 {key:<18} ← {source}");
+                }
+            }
+        }
+        Err(e) => println!("   ❌ {e}"),
+    }
+    println!("   💡 ลองรันด้วย APP_DATABASE__POOL_SIZE=20 เพื่อทับค่าจาก env");
+
+    let broken = Config::builder()
+        .defaults(&ServiceConfig::default())
+        .set("replicas", "three")
+        .build()
+        .and_then(|config| config.deserialize::<ServiceConfig>());
+    if let Err(e) = broken {
+        println!("   🚨 ค่าผิดชนิด: {e}");
+    }
+}
+
 /// 📋 แสดง IaC Best Practices
 fn show_iac_best_practices() {
     println!("📋 Infrastructure as Code Best Practices:");
@@ -981,4 +1594,98 @@ mod tests {
         assert!(generated.contains("kind: Service"));
         assert!(generated.contains("test-app"));
     }
-}
\ No newline at end of file
+    
+    #[test]
+    fn test_resource_graph_order_and_cycles() {
+        let order = example_network("ami-1").order().unwrap();
+        assert_eq!(order, ["main_vpc", "main_subnet", "web_server"]);
+
+        let cyclic = ResourceGraph::new()
+            .with(InfraResource::new("app", "instance", InfraProvider::AWS).with_dependency("db"))
+            .with(InfraResource::new("db", "instance", InfraProvider::AWS).with_dependency("cache"))
+            .with(InfraResource::new("cache", "instance", InfraProvider::AWS).with_dependency("db"));
+        assert_eq!(
+            cyclic.order(),
+            Err(InfraError::Cycle(vec!["db".to_string(), "cache".to_string(), "db".to_string()]))
+        );
+
+        let self_loop = ResourceGraph::new().with(InfraResource::new("a", "vpc", InfraProvider::AWS).with_dependency("a"));
+        assert!(matches!(self_loop.order(), Err(InfraError::Cycle(_))));
+
+        let unknown = ResourceGraph::new().with(InfraResource::new("a", "vpc", InfraProvider::AWS).with_dependency("b"));
+        assert!(matches!(unknown.plan(&SimulatedCloud::new()), Err(InfraError::UnknownDependency { .. })));
+    }
+    
+    #[test]
+    fn test_apply_is_idempotent() {
+        let mut cloud = SimulatedCloud::new();
+        let graph = example_network("ami-1");
+        
+        let plan = graph.plan(&cloud).unwrap();
+        assert_eq!(plan.count(Action::Create), 3);
+        assert_eq!(cloud.apply(&plan).unwrap(), 3);
+        let state = cloud.state().clone();
+        
+        let again = graph.plan(&cloud).unwrap();
+        assert!(again.is_empty());
+        assert_eq!(cloud.apply(&again).unwrap(), 0);
+        assert_eq!(*cloud.state(), state);
+    }
+    
+    #[test]
+    fn test_plan_diff_update_replace_delete() {
+        let mut cloud = SimulatedCloud::new();
+        cloud.apply(&example_network("ami-1").plan(&cloud).unwrap()).unwrap();
+        let old_id = cloud.state()["web_server"].id.clone();
+        
+        // แก้ instance_type ได้ในที่, แก้ AMI ต้องสร้างใหม่, ไม่มี web_server ใน code = ลบ
+        let resized = ResourceGraph::new()
+            .with(InfraResource::new("main_vpc", "vpc", InfraProvider::AWS).with_property("cidr_block", "10.0.0.0/16"))
+            .with(
+                InfraResource::new("main_subnet", "subnet", InfraProvider::AWS)
+                    .with_property("cidr_block", "10.0.1.0/24")
+                    .with_property("map_public_ip", "true")
+                    .with_dependency("main_vpc"),
+            );
+        let plan = resized.plan(&cloud).unwrap();
+        let action_of = |name: &str| plan.changes.iter().find(|c| c.name == name).unwrap().action;
+        assert_eq!(action_of("main_vpc"), Action::Update); // tag ถูกลบ
+        assert_eq!(action_of("main_subnet"), Action::Update);
+        assert_eq!(action_of("web_server"), Action::Delete);
+        assert!(plan.to_string().ends_with("Plan: 0 to add, 2 to change, 0 to replace, 1 to destroy."));
+        
+        let replaced = example_network("ami-2").plan(&cloud).unwrap();
+        assert_eq!(replaced.count(Action::Replace), 1);
+        let diff = &replaced.changes.iter().find(|c| c.name == "web_server").unwrap().diff;
+        assert_eq!(diff[0], AttributeDiff { key: "ami".to_string(), before: Some("ami-1".to_string()), after: Some("ami-2".to_string()) });
+        cloud.apply(&replaced).unwrap();
+        assert_ne!(cloud.state()["web_server"].id, old_id);
+    }
+    
+    #[test]
+    fn test_failed_apply_rolls_back() {
+        let mut cloud = SimulatedCloud::new();
+        cloud.apply(&example_network("ami-1").plan(&cloud).unwrap()).unwrap();
+        let before = cloud.state().clone();
+        
+        let graph = example_network("ami-2")
+            .with(InfraResource::new("assets", "bucket", InfraProvider::AWS).with_dependency("main_vpc"));
+        let plan = graph.plan(&cloud).unwrap();
+        cloud.fail_on("assets");
+        
+        let err = cloud.apply(&plan).unwrap_err();
+        assert_eq!(
+            err,
+            InfraError::ApplyFailed {
+                resource: "assets".to_string(),
+                reason: "simulated provider error".to_string(),
+                rolled_back: vec!["web_server".to_string()],
+            }
+        );
+        assert_eq!(*cloud.state(), before);
+        
+        cloud.clear_failures();
+        cloud.apply(&plan).unwrap();
+        assert!(graph.plan(&cloud).unwrap().is_empty());
+    }
+}
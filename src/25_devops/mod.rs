@@ -7,6 +7,8 @@
 //! - Docker containerization
 //! - CI/CD pipelines
 //! - Monitoring และ Logging
+//! - Health checks และ readiness probes
+//! - Feature flags และ percentage rollouts
 //! - Performance optimization
 //! - Infrastructure as Code
 //! - Security best practices
@@ -15,6 +17,8 @@
 pub mod docker_deployment;
 pub mod cicd_pipelines;
 pub mod monitoring_observability;
+pub mod healthcheck;
+pub mod feature_flags;
 pub mod performance_optimization;
 pub mod infrastructure_as_code;
 
@@ -37,6 +41,8 @@ pub fn learn_devops() {
     docker_deployment::demonstrate_docker_deployment();
     cicd_pipelines::demonstrate_cicd_pipelines();
     monitoring_observability::demonstrate_monitoring_observability();
+    healthcheck::demonstrate_health_checks();
+    feature_flags::demonstrate_feature_flags();
     performance_optimization::demonstrate_performance_optimization();
     infrastructure_as_code::demonstrate_infrastructure_as_code();
     
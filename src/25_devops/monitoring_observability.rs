@@ -7,6 +7,10 @@ use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use std::fmt;
+use crate::clock::{Clock, system_clock};
+use crate::metrics;
+use crate::web_development::web_server::WebServer;
+use crate::web_development::{HttpRequest, HttpResponse, HttpStatus};
 
 /// 📊 ประเภทของ Metrics
 #[derive(Debug, Clone, PartialEq)]
@@ -465,14 +469,21 @@ impl Default for HealthMonitor {
 pub struct PerformanceMonitor {
     metrics_registry: MetricsRegistry,
     start_time: Instant,
+    clock: Arc<dyn Clock>,
 }
 
 impl PerformanceMonitor {
     /// สร้าง PerformanceMonitor ใหม่
     pub fn new() -> Self {
+        Self::with_clock(system_clock())
+    }
+    
+    /// สร้าง `PerformanceMonitor` ที่นับ uptime จากนาฬิกาที่กำหนดเอง
+    pub fn with_clock(clock: Arc<dyn Clock>) -> Self {
         let mut monitor = Self {
             metrics_registry: MetricsRegistry::new(),
-            start_time: Instant::now(),
+            start_time: clock.now(),
+            clock,
         };
         
         // ลงทะเบียน metrics พื้นฐาน
@@ -527,7 +538,7 @@ impl PerformanceMonitor {
     
     /// ดึง uptime
     pub fn uptime(&self) -> Duration {
-        self.start_time.elapsed()
+        self.clock.now().duration_since(self.start_time)
     }
     
     /// Export metrics
@@ -631,6 +642,38 @@ fn demonstrate_metrics() {
         println!("   {}", line);
     }
     println!("   ... (และอีกหลายบรรทัด)");
+    
+    demonstrate_metrics_scraping();
+}
+
+/// 🔎 สาธิตการ scrape `/metrics` จาก web server ของบทที่ 17 แบบที่ Prometheus ทำจริง
+fn demonstrate_metrics_scraping() {
+    println!("\n🔎 Scraping /metrics จาก web server:");
+    
+    let registry = metrics::Registry::new();
+    let mut server = WebServer::new();
+    server.route("GET /api/orders", |_req| HttpResponse::json(HttpStatus::Ok, "[]"));
+    server.route("POST /api/orders", |_req| HttpResponse::json(HttpStatus::Created, "{\"id\": 1}"));
+    server.expose_metrics(&registry);
+    
+    let in_flight = registry.gauge("orders_in_flight", "Orders being processed right now", &[]);
+    in_flight.set(3.0);
+    
+    for request in [
+        HttpRequest::new("GET", "/api/orders"),
+        HttpRequest::new("GET", "/api/orders"),
+        HttpRequest::new("POST", "/api/orders"),
+        HttpRequest::new("GET", "/api/unknown"),
+    ] {
+        let _ = server.handle_request(&request);
+    }
+    
+    let scrape = server.handle_request(&HttpRequest::new("GET", "/metrics"));
+    println!("   GET /metrics -> {}", scrape.status.as_str());
+    for line in scrape.body.lines().filter(|line| !line.contains("_bucket")) {
+        println!("   {line}");
+    }
+    println!("   (ซ่อนบรรทัด _bucket ไว้ - Prometheus ใช้คำนวณ histogram_quantile)");
 }
 
 /// 🏥 สาธิต Health Checks
@@ -828,4 +871,17 @@ mod tests {
         assert!(stats.contains_key("uptime_seconds"));
         assert!(stats.get("uptime_seconds").unwrap() > &0.0);
     }
+    
+    #[test]
+    fn test_uptime_with_mock_clock() {
+        use crate::clock::MockClock;
+        
+        let clock = MockClock::new();
+        let monitor = PerformanceMonitor::with_clock(Arc::new(clock.clone()));
+        assert_eq!(monitor.uptime(), Duration::ZERO);
+        
+        clock.advance(Duration::from_secs(90));
+        assert_eq!(monitor.uptime(), Duration::from_secs(90));
+        assert_eq!(monitor.get_performance_stats().get("uptime_seconds"), Some(&90.0));
+    }
 }
\ No newline at end of file
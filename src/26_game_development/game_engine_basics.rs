@@ -7,6 +7,8 @@ use std::collections::HashMap;
 use std::time::{Duration, Instant};
 use std::thread;
 
+use crate::profiling::{self, Profiler};
+
 /// 🎮 Game Engine Core
 pub struct GameEngine {
     pub name: String,
@@ -78,6 +80,7 @@ impl GameEngine {
         println!("🔄 Starting game loop...");
         
         while self.is_running {
+            profiling::span!("frame");
             let frame_start = Instant::now();
             
             // คำนวณ delta time
@@ -94,6 +97,7 @@ impl GameEngine {
             // Frame timing
             let frame_duration = frame_start.elapsed();
             if frame_duration < self.frame_time {
+                profiling::span!("sleep");
                 let sleep_time = self.frame_time - frame_duration;
                 thread::sleep(sleep_time);
             }
@@ -111,14 +115,18 @@ impl GameEngine {
     
     /// อัปเดต game logic
     fn update(&mut self) {
+        profiling::span!("update");
         for system in &mut self.systems {
+            profiling::span!(format!("update:{}", system.name()));
             system.update(self.delta_time);
         }
     }
     
     /// Render graphics
     fn render(&mut self) {
+        profiling::span!("render");
         for system in &mut self.systems {
+            profiling::span!(format!("render:{}", system.name()));
             system.render();
         }
     }
@@ -624,7 +632,13 @@ fn demonstrate_engine_creation() {
     
     // เริ่มต้น engine (จำลอง)
     println!("\n🚀 Starting engine (simulated)...");
+    let profiler = Profiler::start();
     engine.start();
+    let profile = profiler.finish();
+
+    // เวลาส่วนใหญ่ควรอยู่ที่ sleep - ถ้า update/render กินเกินงบ frame จะเห็นทันที
+    println!("\n🔥 Flamegraph ของ game loop:");
+    print!("{profile}");
 }
 
 /// 📦 สาธิต Resource Management
@@ -8,6 +8,10 @@
 use std::collections::HashMap;
 use std::fmt;
 
+use crate::advanced_patterns::newtype_pattern::{
+    Kilograms, Meters, MetersPerSecond, MetersPerSecondSquared, Newtons, Seconds,
+};
+
 /// 🎯 Vector 2D สำหรับตำแหน่ง, ความเร็ว, แรง
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Vec2 {
@@ -109,6 +113,69 @@ impl std::ops::Div<f32> for Vec2 {
     }
 }
 
+impl std::ops::Neg for Vec2 {
+    type Output = Vec2;
+    fn neg(self) -> Vec2 {
+        Vec2::new(-self.x, -self.y)
+    }
+}
+
+/// `2.0 * v` - scalar ข้างซ้ายต้อง impl บน `f32` เพราะ `Mul::mul` รับ `self` เป็น operand ซ้าย
+impl std::ops::Mul<Vec2> for f32 {
+    type Output = Vec2;
+    fn mul(self, vector: Vec2) -> Vec2 {
+        vector * self
+    }
+}
+
+impl std::ops::AddAssign for Vec2 {
+    fn add_assign(&mut self, other: Vec2) {
+        *self = *self + other;
+    }
+}
+
+impl std::ops::SubAssign for Vec2 {
+    fn sub_assign(&mut self, other: Vec2) {
+        *self = *self - other;
+    }
+}
+
+/// `v[0]` คือ x และ `v[1]` คือ y
+///
+/// # Panics
+///
+/// เมื่อ index ไม่ใช่ 0 หรือ 1
+impl std::ops::Index<usize> for Vec2 {
+    type Output = f32;
+    fn index(&self, axis: usize) -> &f32 {
+        match axis {
+            0 => &self.x,
+            1 => &self.y,
+            _ => panic!("index {axis} out of bounds for Vec2"),
+        }
+    }
+}
+
+impl std::ops::IndexMut<usize> for Vec2 {
+    fn index_mut(&mut self, axis: usize) -> &mut f32 {
+        match axis {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            _ => panic!("index {axis} out of bounds for Vec2"),
+        }
+    }
+}
+
+/// `(x, y)` - รองรับ `{:.2}` โดยส่ง precision ต่อให้ทั้งสองแกน
+impl fmt::Display for Vec2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match f.precision() {
+            Some(precision) => write!(f, "({:.precision$}, {:.precision$})", self.x, self.y),
+            None => write!(f, "({}, {})", self.x, self.y),
+        }
+    }
+}
+
 /// 📦 Bounding Box สำหรับ collision detection
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct BoundingBox {
@@ -282,7 +349,7 @@ impl RigidBody {
     /// เพิ่ม impulse (แรงกระแทก)
     pub fn add_impulse(&mut self, impulse: Vec2) {
         if !self.is_static {
-            self.velocity = self.velocity + impulse / self.mass;
+            self.velocity += impulse / self.mass;
         }
     }
     
@@ -299,14 +366,14 @@ impl RigidBody {
         self.acceleration = total_force / self.mass;
         
         // อัปเดตความเร็ว: v = v0 + at
-        self.velocity = self.velocity + self.acceleration * delta_time;
+        self.velocity += self.acceleration * delta_time;
         
         // ใช้แรงเสียดทาน
         let friction_force = self.velocity * -self.friction;
-        self.velocity = self.velocity + friction_force * delta_time;
+        self.velocity += friction_force * delta_time;
         
         // อัปเดตตำแหน่ง: s = s0 + vt
-        self.position = self.position + self.velocity * delta_time;
+        self.position += self.velocity * delta_time;
         
         // อัปเดตตำแหน่ง collider
         self.update_collider_position();
@@ -332,6 +399,26 @@ impl RigidBody {
     pub fn intersects(&self, other: &RigidBody) -> bool {
         self.collider.intersects(&other.collider)
     }
+    
+    /// ⚖️ ตั้งมวลแบบมีหน่วย - เผลอส่งแรงหรือระยะทางมาแทนจะ build ไม่ผ่าน
+    #[allow(clippy::cast_possible_truncation)] // โลกฟิสิกส์เก็บค่าเป็น f32
+    pub const fn set_mass(&mut self, mass: Kilograms) {
+        if !self.is_static {
+            self.mass = mass.value() as f32;
+        }
+    }
+    
+    /// 🚀 ออกแรงขนาด `force` ไปทาง `direction` (ความยาวของ `direction` ไม่มีผล)
+    #[allow(clippy::cast_possible_truncation)] // โลกฟิสิกส์เก็บค่าเป็น f32
+    pub fn push(&mut self, direction: Vec2, force: Newtons) {
+        self.add_force(direction.normalize() * force.value() as f32);
+    }
+    
+    /// 🏎️ อัตราเร็วปัจจุบัน
+    #[must_use]
+    pub fn speed(&self) -> MetersPerSecond {
+        MetersPerSecond::new(f64::from(self.velocity.magnitude()))
+    }
 }
 
 /// 💥 ข้อมูลการชน
@@ -386,8 +473,8 @@ impl Particle {
         }
         
         // อัปเดตฟิสิกส์
-        self.velocity = self.velocity + self.acceleration * delta_time;
-        self.position = self.position + self.velocity * delta_time;
+        self.velocity += self.acceleration * delta_time;
+        self.position += self.velocity * delta_time;
         
         // อัปเดต alpha ตามอายุ
         let life_ratio = self.life_time / self.max_life_time;
@@ -599,14 +686,14 @@ impl PhysicsWorld {
                 
                 if let Some(body_a_mut) = self.bodies.get_mut(&collision.body_a) {
                     if !body_a_mut.is_static {
-                        body_a_mut.position = body_a_mut.position - correction / body_a_mut.mass;
+                        body_a_mut.position -= correction / body_a_mut.mass;
                         body_a_mut.update_collider_position();
                     }
                 }
                 
                 if let Some(body_b_mut) = self.bodies.get_mut(&collision.body_b) {
                     if !body_b_mut.is_static {
-                        body_b_mut.position = body_b_mut.position + correction / body_b_mut.mass;
+                        body_b_mut.position += correction / body_b_mut.mass;
                         body_b_mut.update_collider_position();
                     }
                 }
@@ -614,6 +701,26 @@ impl PhysicsWorld {
         }
     }
     
+    /// ⏱️ เดินเวลาแบบมีหน่วย - ตัวเลขมิลลิวินาทีหลุดเข้ามาแทนวินาทีไม่ได้อีกแล้ว
+    ///
+    /// ```compile_fail,E0308
+    /// use rust_concepts::advanced_patterns::newtype_pattern::Meters;
+    /// use rust_concepts::game_development::physics_engine::PhysicsWorld;
+    ///
+    /// let mut world = PhysicsWorld::new();
+    /// world.advance(Meters::new(16.0)); // ❌ ต้องเป็น `Seconds`
+    /// ```
+    #[allow(clippy::cast_possible_truncation)] // โลกฟิสิกส์เก็บค่าเป็น f32
+    pub fn advance(&mut self, elapsed: Seconds) {
+        self.update(elapsed.value() as f32);
+    }
+    
+    /// 🌍 ขนาดของแรงโน้มถ่วงในโลกนี้
+    #[must_use]
+    pub fn gravity_strength(&self) -> MetersPerSecondSquared {
+        MetersPerSecondSquared::new(f64::from(self.gravity.magnitude()))
+    }
+    
     /// ดึงสถิติ
     pub fn get_stats(&self) -> PhysicsStats {
         let total_particles: usize = self.particle_systems.iter()
@@ -638,6 +745,15 @@ pub struct PhysicsStats {
     pub gravity: Vec2,
 }
 
+/// 🛑 ระยะเบรกจนหยุดเมื่อชะลอด้วยความหน่วงคงที่ (s = v·t / 2 โดย t = v / a)
+///
+/// ทุกขั้นตรวจมิติโดย compiler - สลับ `speed` กับ `deceleration` หรือลืมหารด้วยเวลาจะ build ไม่ผ่าน
+#[must_use]
+pub fn braking_distance(speed: MetersPerSecond, deceleration: MetersPerSecondSquared) -> Meters {
+    let stopping_time: Seconds = speed / deceleration;
+    speed * stopping_time / 2.0
+}
+
 /// ⚡ สาธิตการใช้งาน Physics Engine
 pub fn demonstrate_physics_engine() {
     println!("⚡ === Physics Engine Demo ===");
@@ -677,8 +793,9 @@ pub fn demonstrate_physics_engine() {
     );
     box_body.restitution = 0.3;
     box_body.friction = 0.5;
+    box_body.set_mass(Kilograms::new(5.0));
     world.add_body(box_body);
-    println!("📦 Added box at (3.0, 8.0)");
+    println!("📦 Added 5 kg box at (3.0, 8.0)");
     
     // สร้าง particle system
     println!("\n✨ Creating particle system:");
@@ -702,7 +819,7 @@ pub fn demonstrate_physics_engine() {
         println!("\n--- Frame {} ---", frame + 1);
         
         // อัปเดตโลก
-        world.update(1.0 / 60.0); // 60 FPS
+        world.advance(Seconds::new(1.0 / 60.0)); // 60 FPS
         
         // แสดงตำแหน่งของวัตถุ
         if let Some(ball) = world.get_body(2) {
@@ -729,6 +846,21 @@ pub fn demonstrate_physics_engine() {
         }
     }
     
+    // หน่วยวัดที่ compiler ตรวจให้
+    println!("\n📐 Units of measure:");
+    if let Some(box_body) = world.get_body_mut(3) {
+        box_body.push(Vec2::RIGHT, Newtons::new(50.0));
+    }
+    world.advance(Seconds::from_millis(100.0));
+    if let Some(box_body) = world.get_body(3) {
+        let speed = box_body.speed();
+        println!("📦 Box speed after a 50 N push: {speed:.2} ({:.1} km/h)", speed.kilometers_per_hour());
+    }
+    let gravity = world.gravity_strength();
+    let car = MetersPerSecond::from_kilometers_per_hour(100.0);
+    println!("🌍 Gravity {gravity:.2}, 🚗 braking from 100 km/h at 0.8 g: {:.1}",
+            braking_distance(car, gravity * 0.8));
+    
     // แสดง best practices
     println!("\n💡 Physics Engine Best Practices:");
     show_physics_best_practices();
@@ -856,6 +988,46 @@ mod tests {
             assert!(body.position.y < 10.0);
         }
     }
+    
+    #[test]
+    fn test_unit_aware_body_api() {
+        let mut body = RigidBody::new(
+            1,
+            Vec2::ZERO,
+            ColliderType::Circle(CircleCollider::new(Vec2::ZERO, 1.0))
+        );
+        body.friction = 0.0;
+        body.set_mass(Kilograms::new(2.0));
+        assert!((body.mass - 2.0).abs() < f32::EPSILON);
+        
+        // F = ma: 10 N บน 2 kg เป็นเวลา 1 s → 5 m/s
+        body.push(Vec2::new(0.0, 3.0), Newtons::new(10.0));
+        body.update(1.0);
+        assert!((body.speed().value() - 5.0).abs() < 1e-5);
+        assert!(body.velocity.x.abs() < 1e-6);
+        
+        let mut ground = RigidBody::new_static(2, Vec2::ZERO, ColliderType::Box(BoundingBox::new(Vec2::ZERO, Vec2::ONE)));
+        ground.set_mass(Kilograms::new(1.0));
+        assert!(ground.mass.is_infinite());
+    }
+    
+    #[test]
+    fn test_world_advance_and_braking_distance() {
+        let mut world = PhysicsWorld::new();
+        assert!((world.gravity_strength().value() - 9.81).abs() < 1e-5);
+        
+        world.add_body(RigidBody::new(
+            1,
+            Vec2::new(0.0, 10.0),
+            ColliderType::Circle(CircleCollider::new(Vec2::new(0.0, 10.0), 1.0))
+        ));
+        world.advance(Seconds::from_millis(50.0));
+        assert!(world.get_body(1).unwrap().position.y < 10.0);
+        
+        // 20 m/s ชะลอที่ 5 m/s² → หยุดใน 4 s ระยะ 40 m
+        let distance = braking_distance(MetersPerSecond::new(20.0), MetersPerSecondSquared::new(5.0));
+        assert!((distance.value() - 40.0).abs() < 1e-9);
+    }
 }
 
 // 🌍 "ฟิสิกส์ในเกมไม่ใช่ฟิสิกส์จริง
@@ -7,6 +7,7 @@ use std::collections::{HashMap, BTreeMap};
 use std::time::{SystemTime, UNIX_EPOCH, Duration};
 use std::sync::{Arc, Mutex};
 use std::fmt;
+use crate::clock::{Clock, system_clock};
 
 /// 📁 Storage Type
 #[derive(Debug, Clone, PartialEq)]
@@ -112,6 +113,8 @@ pub struct StorageManager {
     max_cache_size: usize,
     auto_cleanup: bool,
     compression_enabled: bool,
+    cache_ttl: Option<Duration>,
+    clock: Arc<dyn Clock>,
 }
 
 #[derive(Debug, Clone)]
@@ -123,8 +126,21 @@ pub struct CachedData {
     pub size_bytes: usize,
 }
 
+impl CachedData {
+    /// หมดอายุแล้วหรือยัง ณ เวลา `now` (Unix seconds)
+    #[must_use]
+    pub fn is_expired(&self, now: u64) -> bool {
+        self.expires_at.is_some_and(|expires_at| now >= expires_at)
+    }
+}
+
 impl StorageManager {
     pub fn new(storage_type: StorageType, security_level: SecurityLevel) -> Self {
+        Self::with_clock(storage_type, security_level, system_clock())
+    }
+    
+    /// สร้าง storage ที่ใช้นาฬิกาที่กำหนดเอง - เวลาใน cache (LRU, TTL) จะมาจากนาฬิกานี้
+    pub fn with_clock(storage_type: StorageType, security_level: SecurityLevel, clock: Arc<dyn Clock>) -> Self {
         Self {
             storage_type,
             security_level,
@@ -134,9 +150,21 @@ impl StorageManager {
             max_cache_size: 50 * 1024 * 1024, // 50 MB
             auto_cleanup: true,
             compression_enabled: true,
+            cache_ttl: None,
+            clock,
         }
     }
     
+    /// ตั้งอายุของข้อมูลใน cache (`None` = ไม่หมดอายุ)
+    pub const fn set_cache_ttl(&mut self, ttl: Option<Duration>) {
+        self.cache_ttl = ttl;
+    }
+    
+    /// ตั้งขนาด cache สูงสุด (bytes) - เกินแล้วจะไล่ item ที่ไม่ได้ใช้นานที่สุดออก
+    pub const fn set_max_cache_size(&mut self, bytes: usize) {
+        self.max_cache_size = bytes;
+    }
+    
     pub fn set_encryption_key(&mut self, key: String) {
         self.encryption_key = Some(key);
         println!("🔐 Encryption key set for {:?} storage", self.storage_type);
@@ -189,13 +217,15 @@ impl StorageManager {
     }
     
     pub fn retrieve(&mut self, key: &str) -> Result<Option<serde_json::Value>, StorageError> {
-        // Check cache first
+        let now = self.clock.unix_timestamp();
+        
+        // Check cache first (ข้อมูลหมดอายุถือว่า cache miss)
+        if self.cache.get(key).is_some_and(|cached| cached.is_expired(now)) {
+            self.cache.remove(key);
+        }
         if let Some(cached) = self.cache.get_mut(key) {
             cached.access_count += 1;
-            cached.last_accessed = SystemTime::now()
-                .duration_since(UNIX_EPOCH)
-                .unwrap_or_default()
-                .as_secs();
+            cached.last_accessed = now;
             
             println!("🚀 Retrieved from cache: {}", key);
             return Ok(Some(cached.data.data.clone()));
@@ -300,20 +330,26 @@ impl StorageManager {
             self.evict_cache_items(size_bytes);
         }
         
+        let now = self.clock.unix_timestamp();
         let cached_data = CachedData {
             data: model,
             access_count: 1,
-            last_accessed: SystemTime::now()
-                .duration_since(UNIX_EPOCH)
-                .unwrap_or_default()
-                .as_secs(),
-            expires_at: None,
+            last_accessed: now,
+            expires_at: self.cache_ttl.map(|ttl| now + ttl.as_secs()),
             size_bytes,
         };
         
         self.cache.insert(key, cached_data);
     }
     
+    /// ลบข้อมูลที่หมดอายุออกจาก cache และคืนจำนวนที่ลบ
+    pub fn purge_expired(&mut self) -> usize {
+        let now = self.clock.unix_timestamp();
+        let before = self.cache.len();
+        self.cache.retain(|_, cached| !cached.is_expired(now));
+        before - self.cache.len()
+    }
+    
     fn evict_cache_items(&mut self, needed_size: usize) {
         // LRU eviction strategy
         let mut items: Vec<_> = self.cache.iter().collect();
@@ -1286,6 +1322,54 @@ mod tests {
         assert!(storage.delete("test_key").is_ok());
     }
     
+    #[test]
+    fn test_cache_ttl_with_mock_clock() {
+        use crate::clock::MockClock;
+        
+        let clock = MockClock::new();
+        let mut storage = StorageManager::with_clock(StorageType::SQLite, SecurityLevel::None, Arc::new(clock.clone()));
+        storage.set_cache_ttl(Some(Duration::from_secs(60)));
+        storage.store("session".to_string(), serde_json::json!({"user": "somchai"})).unwrap();
+        
+        clock.advance(Duration::from_secs(59));
+        assert_eq!(storage.purge_expired(), 0);
+        
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(storage.purge_expired(), 1);
+        assert_eq!(storage.get_storage_info().cached_items, 0);
+        
+        // ข้อมูลยังอยู่ใน storage - อ่านแล้วกลับเข้า cache ใหม่
+        assert!(storage.retrieve("session").unwrap().is_some());
+        assert_eq!(storage.get_storage_info().cached_items, 1);
+    }
+    
+    #[test]
+    fn test_cache_lru_eviction_with_mock_clock() {
+        use crate::clock::MockClock;
+        
+        let clock = MockClock::new();
+        let mut storage = StorageManager::with_clock(StorageType::SQLite, SecurityLevel::None, Arc::new(clock.clone()));
+        let data = serde_json::json!({"value": 42});
+        
+        storage.store("a".to_string(), data.clone()).unwrap();
+        clock.advance(Duration::from_secs(1));
+        storage.store("b".to_string(), data.clone()).unwrap();
+        
+        // จำกัด cache ให้พอดีสอง item
+        storage.set_max_cache_size(storage.get_storage_info().total_size_bytes);
+        
+        // อ่าน "a" ทำให้ "b" กลายเป็น item ที่ไม่ได้ใช้นานที่สุด
+        clock.advance(Duration::from_secs(1));
+        storage.retrieve("a").unwrap();
+        
+        clock.advance(Duration::from_secs(1));
+        storage.store("c".to_string(), data).unwrap();
+        
+        assert!(storage.cache.contains_key("a"));
+        assert!(!storage.cache.contains_key("b"));
+        assert!(storage.cache.contains_key("c"));
+    }
+    
     #[test]
     fn test_data_model() {
         let mut model = DataModel::new("test".to_string(), serde_json::json!({"data": "value"}));
@@ -6,6 +6,7 @@
 use std::collections::{HashMap, VecDeque};
 use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use std::sync::{Arc, Mutex};
+use crate::clock::{Clock, system_clock};
 
 /// 📊 Performance Metrics
 #[derive(Debug, Clone)]
@@ -53,6 +54,8 @@ pub struct PerformanceMonitor {
     alert_thresholds: PerformanceThresholds,
     frame_times: VecDeque<Duration>,
     memory_snapshots: Vec<MemorySnapshot>,
+    last_frame_at: Option<Instant>,
+    clock: Arc<dyn Clock>,
 }
 
 #[derive(Debug, Clone)]
@@ -82,6 +85,11 @@ impl Default for PerformanceThresholds {
 
 impl PerformanceMonitor {
     pub fn new() -> Self {
+        Self::with_clock(system_clock())
+    }
+    
+    /// สร้าง monitor ที่ใช้นาฬิกาที่กำหนดเอง - ใช้วัด frame time และ timestamp ของ snapshot
+    pub fn with_clock(clock: Arc<dyn Clock>) -> Self {
         Self {
             metrics_history: VecDeque::new(),
             max_history_size: 1000,
@@ -89,6 +97,8 @@ impl PerformanceMonitor {
             alert_thresholds: PerformanceThresholds::default(),
             frame_times: VecDeque::new(),
             memory_snapshots: Vec::new(),
+            last_frame_at: None,
+            clock,
         }
     }
     
@@ -128,6 +138,14 @@ impl PerformanceMonitor {
         }
     }
     
+    /// บันทึกว่า frame ใหม่เริ่มแล้ว - frame time คือเวลาที่ผ่านไปจาก frame ก่อนหน้า
+    pub fn mark_frame(&mut self) {
+        let now = self.clock.now();
+        if let Some(last_frame_at) = self.last_frame_at.replace(now) {
+            self.record_frame_time(now.duration_since(last_frame_at));
+        }
+    }
+    
     pub fn get_average_frame_rate(&self) -> f32 {
         if self.frame_times.is_empty() {
             return 0.0;
@@ -223,10 +241,7 @@ impl PerformanceMonitor {
     pub fn take_memory_snapshot(&mut self, label: String) {
         let snapshot = MemorySnapshot {
             label,
-            timestamp: SystemTime::now()
-                .duration_since(UNIX_EPOCH)
-                .unwrap_or_default()
-                .as_secs(),
+            timestamp: self.clock.unix_timestamp(),
             heap_size: self.get_simulated_heap_size(),
             stack_size: self.get_simulated_stack_size(),
             allocated_objects: self.get_simulated_object_count(),
@@ -1172,6 +1187,29 @@ mod tests {
         assert_eq!(summary.sample_count, 1);
     }
     
+    #[test]
+    fn test_frame_timing_with_mock_clock() {
+        use crate::clock::MockClock;
+        
+        let clock = MockClock::new();
+        let mut monitor = PerformanceMonitor::with_clock(Arc::new(clock.clone()));
+        
+        monitor.mark_frame();
+        for _ in 0..10 {
+            clock.advance(Duration::from_millis(20));
+            monitor.mark_frame();
+        }
+        // frame ที่ช้าเกิน 2 เท่าของ 60 fps นับเป็น frame drop
+        clock.advance(Duration::from_millis(50));
+        monitor.mark_frame();
+        
+        assert_eq!(monitor.frame_times.len(), 11);
+        assert_eq!(monitor.get_frame_drops(), 1);
+        
+        monitor.take_memory_snapshot("after_frames".to_string());
+        assert_eq!(monitor.memory_snapshots[0].timestamp, clock.unix_timestamp());
+    }
+    
     #[test]
     fn test_battery_optimizer() {
         let mut optimizer = BatteryOptimizer::new();
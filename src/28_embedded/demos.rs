@@ -0,0 +1,154 @@
+//! Demos - firmware จิ๋วสองตัวที่รันบนบอร์ดจำลอง 🤖
+//!
+//! - 💡 blinky: `SysTick` กระพริบ LED, กดปุ่มเพื่อหยุด/เริ่มกระพริบ (GPIO interrupt)
+//! - 📟 echo: ไบต์ที่เข้ามาทาง UART ถูกส่งกลับเป็นตัวพิมพ์ใหญ่ (UART RX interrupt + main loop)
+//!
+//! แต่ละรอบของ loop คือ 1 tick: ฮาร์ดแวร์ `step()` → `dispatch()` interrupt → งานของ main loop
+//! ผลลัพธ์ถูกเขียนลง slice ที่ผู้เรียกเตรียมไว้ เพราะโค้ดฝั่ง firmware ไม่มี heap
+
+use super::gpio::{Gpio, PinMode, SimGpio};
+use super::interrupts::{Interrupt, InterruptController, SysTick};
+use super::registers::is_set;
+use super::ring_buffer::ByteQueue;
+use super::uart::{SimUart, Uart};
+
+/// ขาที่ต่อ LED
+pub const LED_PIN: u8 = 5;
+/// ขาที่ต่อปุ่ม
+pub const BUTTON_PIN: u8 = 0;
+/// `SysTick` ยิงทุกกี่ tick
+pub const BLINK_PERIOD: u32 = 2;
+
+struct Blinky<'a> {
+    gpio: Gpio<'a>,
+    blinking: bool,
+}
+
+fn blinky_on_systick(app: &mut Blinky<'_>) {
+    if app.blinking {
+        app.gpio.toggle(LED_PIN);
+    }
+}
+
+fn blinky_on_button(app: &mut Blinky<'_>) {
+    if is_set(app.gpio.take_rising_events(), BUTTON_PIN) {
+        app.blinking = !app.blinking;
+        if !app.blinking {
+            app.gpio.set(LED_PIN, false);
+        }
+    }
+}
+
+/// รัน blinky `trace.len()` tick โดยกดปุ่มที่ tick ใน `presses`
+/// แล้วบันทึกว่า LED ติดหรือไม่ในแต่ละ tick ลง `trace` คืนจำนวน interrupt ที่ถูกจัดการ
+pub fn run_blinky(presses: &[usize], trace: &mut [bool]) -> u32 {
+    let port = SimGpio::new();
+    let timer = SysTick::new(BLINK_PERIOD);
+    let mut interrupts = InterruptController::new();
+    interrupts.register(Interrupt::SysTick, blinky_on_systick);
+    interrupts.register(Interrupt::GpioEdge, blinky_on_button);
+
+    let gpio = Gpio::new(port.registers());
+    gpio.set_mode(LED_PIN, PinMode::Output);
+    gpio.set_mode(BUTTON_PIN, PinMode::Input);
+    gpio.listen_rising(BUTTON_PIN);
+    let mut app = Blinky {
+        gpio,
+        blinking: true,
+    };
+
+    for (tick, led) in trace.iter_mut().enumerate() {
+        port.drive(BUTTON_PIN, presses.contains(&tick));
+        timer.step(&interrupts);
+        port.step(&interrupts);
+        interrupts.dispatch(&mut app);
+        *led = port.output_level(LED_PIN);
+    }
+    interrupts.serviced()
+}
+
+struct Echo<'a> {
+    uart: Uart<'a>,
+    pending: ByteQueue<16>,
+}
+
+fn echo_on_rx(app: &mut Echo<'_>) {
+    while let Some(byte) = app.uart.try_read() {
+        // คิวเต็ม = ทิ้งไบต์ (firmware จริงมักนับเป็น overrun)
+        let _ = app.pending.push(byte.to_ascii_uppercase());
+    }
+}
+
+/// ส่ง `input` เข้า UART ของบอร์ดที่รัน echo firmware แล้วเก็บสิ่งที่บอร์ดตอบกลับลง `output`
+/// คืนจำนวนไบต์ที่ได้รับกลับมา
+pub fn run_echo(input: &[u8], output: &mut [u8]) -> usize {
+    let uart = SimUart::<32>::new();
+    let mut interrupts = InterruptController::new();
+    interrupts.register(Interrupt::UartRx, echo_on_rx);
+
+    let driver = Uart::new(uart.registers());
+    driver.listen_rx();
+    let mut app = Echo {
+        uart: driver,
+        pending: ByteQueue::new(),
+    };
+
+    let expected = input.len().min(output.len());
+    let (mut sent, mut received) = (0, 0);
+    // ไบต์ละไม่กี่ tick - เผื่อไว้ 4 เท่ากันวนไม่จบ
+    for _ in 0..(expected + 1) * 4 {
+        sent += uart.send_from_host(&input[sent..]);
+        uart.step(&interrupts);
+        interrupts.dispatch(&mut app);
+
+        // main loop: ส่งไบต์ที่รออยู่ถ้า UART ว่าง
+        if let Some(byte) = app.pending.peek()
+            && app.uart.try_write(byte).is_ok()
+        {
+            app.pending.pop();
+        }
+
+        received += uart.drain_to_host(&mut output[received..]);
+        if received >= expected {
+            break;
+        }
+    }
+    received
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blinky_toggles_on_systick_and_button_pauses_it() {
+        let mut trace = [false; 12];
+        let serviced = run_blinky(&[5], &mut trace);
+
+        // tick 1, 3 สลับ LED; tick 5 กดปุ่ม (หยุด + ดับ LED) จากนั้น LED ดับค้าง
+        assert_eq!(
+            trace,
+            [
+                false, true, true, false, false, false, false, false, false, false, false, false
+            ]
+        );
+        // SysTick 6 ครั้ง + ปุ่ม 1 ครั้ง
+        assert_eq!(serviced, 7);
+
+        // กดสองครั้ง = หยุดแล้วกลับมากระพริบต่อ
+        let mut trace = [false; 12];
+        run_blinky(&[4, 8], &mut trace);
+        assert!(trace[9..].contains(&true));
+    }
+
+    #[test]
+    fn test_echo_shouts_back_every_byte() {
+        let mut output = [0; 32];
+        let received = run_echo(b"hello, rust!", &mut output);
+        assert_eq!(&output[..received], b"HELLO, RUST!");
+
+        let mut small = [0; 4];
+        assert_eq!(run_echo(b"abcdef", &mut small), 4);
+        assert_eq!(&small, b"ABCD");
+    }
+}
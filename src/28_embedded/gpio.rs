@@ -0,0 +1,222 @@
+//! GPIO - ขา input/output ที่ใช้ต่อ LED และปุ่ม 💡🔘
+//!
+//! มีสองฝั่งเหมือนของจริง:
+//! - `SimGpio` คือ "ซิลิคอน" - มี register block และอัปเดตค่าตามสัญญาณภายนอกทุกครั้งที่ `step()`
+//! - `Gpio` คือ driver ฝั่ง firmware - รู้จักแค่ `&GpioRegisters` และคุยผ่าน register เท่านั้น
+//!
+//! event register ใช้แบบ "ฮาร์ดแวร์ตั้งบิต ซอฟต์แวร์ล้างเอง" จึงไม่มีปัญหาอ่านค่าค้างระหว่าง `step()`
+
+use core::cell::Cell;
+use core::mem::offset_of;
+
+use super::interrupts::{Interrupt, InterruptController};
+use super::registers::{ReadOnly, ReadWrite, bit, is_set};
+
+/// register block ของพอร์ต GPIO 32 ขา (layout แบบ `#[repr(C)]` ตรงกับ "datasheet") 📋
+#[repr(C)]
+pub struct GpioRegisters {
+    /// 0x00 - บิต n = 1 คือขา n เป็น output
+    pub mode: ReadWrite<u32>,
+    /// 0x04 - ระดับสัญญาณจริงของทุกขา
+    pub input: ReadOnly<u32>,
+    /// 0x08 - ค่าที่ขับออกไปที่ขา output
+    pub output: ReadWrite<u32>,
+    /// 0x0C - เปิด interrupt เมื่อขาเกิด rising edge
+    pub rising_interrupt: ReadWrite<u32>,
+    /// 0x10 - ขาที่เกิด rising edge (ฮาร์ดแวร์ตั้งบิต ซอฟต์แวร์เขียน 0 เพื่อล้าง)
+    pub rising_events: ReadWrite<u32>,
+}
+
+// 🧱 ถ้าลำดับ field เพี้ยนจาก datasheet จะคอมไพล์ไม่ผ่าน
+const _: () = assert!(offset_of!(GpioRegisters, output) == 0x08);
+const _: () = assert!(offset_of!(GpioRegisters, rising_events) == 0x10);
+
+impl GpioRegisters {
+    /// ค่าหลัง reset - ทุกขาเป็น input และปิด interrupt
+    #[must_use]
+    pub const fn reset() -> Self {
+        Self {
+            mode: ReadWrite::new(0),
+            input: ReadOnly::new(0),
+            output: ReadWrite::new(0),
+            rising_interrupt: ReadWrite::new(0),
+            rising_events: ReadWrite::new(0),
+        }
+    }
+}
+
+/// พอร์ต GPIO จำลอง - ฝั่งฮาร์ดแวร์ 🔌
+pub struct SimGpio {
+    registers: GpioRegisters,
+    external: Cell<u32>,
+}
+
+impl SimGpio {
+    /// พอร์ตหลัง reset ที่ไม่มีอะไรต่ออยู่
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            registers: GpioRegisters::reset(),
+            external: Cell::new(0),
+        }
+    }
+
+    /// register block ที่ driver ใช้ (บน MCU จริงคือ address คงที่)
+    #[must_use]
+    pub const fn registers(&self) -> &GpioRegisters {
+        &self.registers
+    }
+
+    /// โลกภายนอกขับขา `pin` (เช่น กดปุ่ม) - มีผลกับขาที่เป็น input เท่านั้น
+    pub fn drive(&self, pin: u8, high: bool) {
+        let external = self.external.get();
+        self.external.set(if high {
+            external | bit(pin)
+        } else {
+            external & !bit(pin)
+        });
+    }
+
+    /// ระดับที่ขา output `pin` ขับออกมา (เช่น LED ติดหรือไม่)
+    #[must_use]
+    pub fn output_level(&self, pin: u8) -> bool {
+        let registers = &self.registers;
+        is_set(registers.mode.read() & registers.output.read(), pin)
+    }
+
+    /// เดิน 1 รอบของฮาร์ดแวร์: อัปเดต input, จับ rising edge แล้ว pend `GpioEdge`
+    pub fn step<C>(&self, interrupts: &InterruptController<C>) {
+        let registers = &self.registers;
+        let mode = registers.mode.read();
+        let previous = registers.input.read();
+        let level = (self.external.get() & !mode) | (registers.output.read() & mode);
+        registers.input.hardware_write(level);
+
+        let rising = level & !previous & registers.rising_interrupt.read();
+        if rising != 0 {
+            registers
+                .rising_events
+                .write(registers.rising_events.read() | rising);
+            interrupts.pend(Interrupt::GpioEdge);
+        }
+    }
+}
+
+impl Default for SimGpio {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// โหมดของขา
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PinMode {
+    /// อ่านค่าจากภายนอก
+    Input,
+    /// ขับค่าออกไป
+    Output,
+}
+
+/// driver ฝั่ง firmware - ทุกอย่างผ่าน register 🧑‍💻
+#[derive(Clone, Copy)]
+pub struct Gpio<'a> {
+    registers: &'a GpioRegisters,
+}
+
+impl<'a> Gpio<'a> {
+    /// สร้าง driver จาก register block
+    #[must_use]
+    pub const fn new(registers: &'a GpioRegisters) -> Self {
+        Self { registers }
+    }
+
+    /// ตั้งโหมดของขา
+    pub fn set_mode(&self, pin: u8, mode: PinMode) {
+        self.registers.mode.modify(|value| match mode {
+            PinMode::Input => value & !bit(pin),
+            PinMode::Output => value | bit(pin),
+        });
+    }
+
+    /// ขับขา output เป็น high/low
+    pub fn set(&self, pin: u8, high: bool) {
+        self.registers.output.modify(|value| {
+            if high {
+                value | bit(pin)
+            } else {
+                value & !bit(pin)
+            }
+        });
+    }
+
+    /// สลับค่าขา output
+    pub fn toggle(&self, pin: u8) {
+        self.registers.output.modify(|value| value ^ bit(pin));
+    }
+
+    /// อ่านระดับสัญญาณของขา
+    #[must_use]
+    pub fn is_high(&self, pin: u8) -> bool {
+        is_set(self.registers.input.read(), pin)
+    }
+
+    /// เปิด interrupt เมื่อขาเกิด rising edge
+    pub fn listen_rising(&self, pin: u8) {
+        self.registers
+            .rising_interrupt
+            .modify(|value| value | bit(pin));
+    }
+
+    /// อ่านแล้วล้าง rising edge events ทั้งหมด คืน mask ของขาที่เกิด edge
+    #[must_use]
+    pub fn take_rising_events(&self) -> u32 {
+        let events = self.registers.rising_events.read();
+        self.registers.rising_events.write(0);
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_output_pins_drive_level_and_inputs_follow_outside_world() {
+        let port = SimGpio::new();
+        let interrupts = InterruptController::<()>::new();
+        let gpio = Gpio::new(port.registers());
+
+        gpio.set_mode(5, PinMode::Output);
+        gpio.set(5, true);
+        port.drive(0, true);
+        port.drive(5, false); // ขา output ไม่สนสัญญาณภายนอก
+        port.step(&interrupts);
+        assert!(port.output_level(5));
+        assert!(gpio.is_high(0));
+        assert!(gpio.is_high(5));
+
+        gpio.toggle(5);
+        port.step(&interrupts);
+        assert!(!port.output_level(5));
+        assert!(!gpio.is_high(5));
+    }
+
+    #[test]
+    fn test_rising_edge_sets_event_and_pends_interrupt() {
+        let port = SimGpio::new();
+        let interrupts = InterruptController::<()>::new();
+        let gpio = Gpio::new(port.registers());
+        gpio.listen_rising(0);
+
+        port.drive(0, true);
+        port.step(&interrupts);
+        assert!(interrupts.is_pending(Interrupt::GpioEdge));
+        assert_eq!(gpio.take_rising_events(), bit(0));
+        assert_eq!(gpio.take_rising_events(), 0);
+
+        // ค้าง high ไม่ใช่ edge ใหม่
+        let interrupts = InterruptController::<()>::new();
+        port.step(&interrupts);
+        assert!(!interrupts.is_pending(Interrupt::GpioEdge));
+    }
+}
@@ -0,0 +1,219 @@
+//! Interrupts - ฮาร์ดแวร์สะกิด CPU ให้หยุดงานแล้วไปเรียก handler ⚡
+//!
+//! `InterruptController` จำลอง NVIC แบบย่อ:
+//! - ฮาร์ดแวร์ `pend()` interrupt ได้ตลอดเวลา (ใช้แค่ `&self` เหมือนสายสัญญาณที่ต่อเข้ามา)
+//! - `dispatch()` เรียก handler ของ interrupt ที่ pending และ enable อยู่ ตามลำดับความสำคัญ
+//! - `mask_all()` / `unmask_all()` คือ critical section แบบ `cpsid i` / `cpsie i` -
+//!   interrupt ที่เกิดระหว่างนั้นยังค้าง pending อยู่และถูกส่งทีหลัง ไม่หายไปไหน
+//!
+//! handler เป็น `fn(&mut C)` ธรรมดา (ไม่ใช่ closure ที่ต้องใช้ `Box`) โดย `C` คือ state ของแอป
+
+use core::cell::Cell;
+
+/// แหล่ง interrupt ของบอร์ดจำลอง - ตัวเลขน้อย = สำคัญกว่า ⚡
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum Interrupt {
+    /// timer ของระบบครบรอบ
+    SysTick = 0,
+    /// ขา GPIO เกิด rising edge
+    GpioEdge = 1,
+    /// UART ได้รับไบต์ใหม่
+    UartRx = 2,
+}
+
+impl Interrupt {
+    /// จำนวน interrupt ทั้งหมด
+    pub const COUNT: usize = 3;
+
+    /// ทุก interrupt เรียงตามความสำคัญ
+    pub const ALL: [Self; Self::COUNT] = [Self::SysTick, Self::GpioEdge, Self::UartRx];
+
+    const fn mask(self) -> u32 {
+        1 << self as u8
+    }
+}
+
+/// handler ของ interrupt - ได้ state ของแอปแบบ `&mut`
+pub type Handler<C> = fn(&mut C);
+
+/// ตัวควบคุม interrupt จำลอง (NVIC ฉบับย่อ) 🚦
+pub struct InterruptController<C> {
+    handlers: [Option<Handler<C>>; Interrupt::COUNT],
+    enabled: Cell<u32>,
+    pending: Cell<u32>,
+    masked: Cell<bool>,
+    serviced: Cell<u32>,
+}
+
+impl<C> InterruptController<C> {
+    /// สร้าง controller ที่ยังไม่มี handler และปิดทุก interrupt
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            handlers: [None; Interrupt::COUNT],
+            enabled: Cell::new(0),
+            pending: Cell::new(0),
+            masked: Cell::new(false),
+            serviced: Cell::new(0),
+        }
+    }
+
+    /// ผูก handler กับ interrupt แล้ว enable ให้ด้วย (เหมือนใส่ใน vector table)
+    pub fn register(&mut self, interrupt: Interrupt, handler: Handler<C>) {
+        self.handlers[interrupt as usize] = Some(handler);
+        self.enable(interrupt);
+    }
+
+    /// เปิดรับ interrupt
+    pub fn enable(&self, interrupt: Interrupt) {
+        self.enabled.set(self.enabled.get() | interrupt.mask());
+    }
+
+    /// ปิดรับ interrupt (ถ้าเกิดขึ้นจะค้าง pending ไว้)
+    pub fn disable(&self, interrupt: Interrupt) {
+        self.enabled.set(self.enabled.get() & !interrupt.mask());
+    }
+
+    /// ฝั่งฮาร์ดแวร์แจ้งว่าเกิด interrupt
+    pub fn pend(&self, interrupt: Interrupt) {
+        self.pending.set(self.pending.get() | interrupt.mask());
+    }
+
+    /// interrupt นี้ค้างอยู่หรือไม่
+    #[must_use]
+    pub const fn is_pending(&self, interrupt: Interrupt) -> bool {
+        self.pending.get() & interrupt.mask() != 0
+    }
+
+    /// เข้า critical section - ยังไม่ส่ง interrupt ใดๆ จนกว่าจะ `unmask_all`
+    pub fn mask_all(&self) {
+        self.masked.set(true);
+    }
+
+    /// ออกจาก critical section
+    pub fn unmask_all(&self) {
+        self.masked.set(false);
+    }
+
+    /// เรียก handler ของทุก interrupt ที่ pending และ enable อยู่ คืนจำนวน handler ที่ถูกเรียก
+    ///
+    /// ล้าง pending ก่อนเรียก handler เหมือนฮาร์ดแวร์จริง - ถ้าเกิดซ้ำระหว่าง handler ทำงานจะได้เรียกอีกรอบ
+    pub fn dispatch(&self, context: &mut C) -> usize {
+        if self.masked.get() {
+            return 0;
+        }
+        let mut count = 0;
+        for interrupt in Interrupt::ALL {
+            let ready = self.pending.get() & self.enabled.get() & interrupt.mask() != 0;
+            if !ready {
+                continue;
+            }
+            self.pending.set(self.pending.get() & !interrupt.mask());
+            if let Some(handler) = self.handlers[interrupt as usize] {
+                handler(context);
+                count += 1;
+            }
+        }
+        self.serviced
+            .set(self.serviced.get() + u32::try_from(count).unwrap_or(u32::MAX));
+        count
+    }
+
+    /// จำนวน handler ที่ถูกเรียกไปแล้วทั้งหมด
+    #[must_use]
+    pub const fn serviced(&self) -> u32 {
+        self.serviced.get()
+    }
+}
+
+impl<C> Default for InterruptController<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// timer ของระบบจำลอง - นับถอยหลังทุก tick แล้ว pend `SysTick` เมื่อครบรอบ ⏲️
+pub struct SysTick {
+    reload: u32,
+    current: Cell<u32>,
+}
+
+impl SysTick {
+    /// timer ที่ยิง interrupt ทุก `period` tick (อย่างน้อย 1)
+    #[must_use]
+    pub const fn new(period: u32) -> Self {
+        let reload = if period == 0 { 1 } else { period };
+        Self {
+            reload,
+            current: Cell::new(reload),
+        }
+    }
+
+    /// เดิน 1 tick (ฝั่งฮาร์ดแวร์)
+    pub fn step<C>(&self, interrupts: &InterruptController<C>) {
+        let next = self.current.get() - 1;
+        if next == 0 {
+            self.current.set(self.reload);
+            interrupts.pend(Interrupt::SysTick);
+        } else {
+            self.current.set(next);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn count_tick(count: &mut [u32; 2]) {
+        count[0] += 1;
+    }
+
+    fn count_rx(count: &mut [u32; 2]) {
+        count[1] += 1;
+    }
+
+    #[test]
+    fn test_dispatch_runs_pending_enabled_handlers() {
+        let mut controller = InterruptController::new();
+        controller.register(Interrupt::SysTick, count_tick);
+        controller.register(Interrupt::UartRx, count_rx);
+        let mut counts = [0, 0];
+
+        controller.pend(Interrupt::SysTick);
+        controller.pend(Interrupt::GpioEdge); // ไม่มี handler และไม่ได้ enable - ค้างไว้
+        assert_eq!(controller.dispatch(&mut counts), 1);
+        assert_eq!(counts, [1, 0]);
+        assert!(controller.is_pending(Interrupt::GpioEdge));
+
+        controller.disable(Interrupt::UartRx);
+        controller.pend(Interrupt::UartRx);
+        assert_eq!(controller.dispatch(&mut counts), 0);
+        controller.enable(Interrupt::UartRx);
+        assert_eq!(controller.dispatch(&mut counts), 1);
+        assert_eq!(counts, [1, 1]);
+        assert_eq!(controller.serviced(), 2);
+    }
+
+    #[test]
+    fn test_masked_interrupts_are_delivered_after_unmask() {
+        let mut controller = InterruptController::new();
+        controller.register(Interrupt::SysTick, count_tick);
+        let timer = SysTick::new(2);
+        let mut counts = [0, 0];
+
+        controller.mask_all();
+        for _ in 0..4 {
+            timer.step(&controller);
+            controller.dispatch(&mut counts);
+        }
+        assert_eq!(counts[0], 0);
+        assert!(controller.is_pending(Interrupt::SysTick));
+
+        controller.unmask_all();
+        controller.dispatch(&mut counts);
+        // ยิงสองครั้งระหว่าง mask แต่ pending เป็นบิตเดียว จึงได้ handler ครั้งเดียว
+        assert_eq!(counts[0], 1);
+    }
+}
@@ -0,0 +1,106 @@
+//! 🔧 Embedded Module - เขียน firmware บนบอร์ดจำลอง! 🤖💡
+//! โมดูลสำหรับเรียนรู้การเขียนโปรแกรมสำหรับ Microcontrollers โดยไม่ต้องมีบอร์ดจริง
+//!
+//! 🎯 สิ่งที่จะได้เรียนรู้:
+//! - 🎛️ Registers - memory-mapped I/O และ volatile read/write
+//! - 💡 GPIO - ขา input/output สำหรับ LED และปุ่ม
+//! - 📟 UART - พอร์ตอนุกรมแบบ non-blocking
+//! - ⚡ Interrupts - handler, priority และ critical section
+//! - 🤖 Demos - firmware blinky และ echo ที่รันบนบอร์ดจำลอง
+//!
+//! 🧱 ทุกไฟล์ยกเว้น `mod.rs` เขียนแบบ `#![no_std]`: ใช้แค่ `core` ไม่มี heap ไม่มี `println!`
+//! จึงยกไปใช้บน MCU จริงได้แค่เปลี่ยนตัวจำลอง (`SimGpio`, `SimUart`) เป็น address ของ peripheral จริง
+//! บทนี้อยู่หลัง feature `embedded` (เปิดเป็นค่าเริ่มต้น): `cargo build --no-default-features` จะไม่คอมไพล์บทนี้
+
+pub mod demos;
+pub mod gpio;
+pub mod interrupts;
+pub mod registers;
+pub mod ring_buffer;
+pub mod uart;
+
+pub use demos::*;
+pub use gpio::*;
+pub use interrupts::*;
+pub use registers::*;
+pub use ring_buffer::*;
+pub use uart::*;
+
+/// 🎪 เรียกใช้ตัวอย่าง Embedded Programming - firmware บนบอร์ดจำลอง! 🤖
+pub fn run_embedded_examples() {
+    println!("\n🔧 === Embedded Programming: บอร์ดจำลอง === 🤖");
+    println!("🎯 เขียน firmware แบบ no_std แล้วรันบน peripheral จำลอง!\n");
+
+    println!("🎛️ Register blocks (offset ตาม datasheet, ตรวจด้วย const assert):");
+    println!(
+        "   GpioRegisters: {} bytes | UartRegisters: {} bytes",
+        size_of::<GpioRegisters>(),
+        size_of::<UartRegisters>()
+    );
+
+    println!("\n💡 Blinky - SysTick ทุก {BLINK_PERIOD} tick, กดปุ่มที่ tick 8 และ 14:");
+    let mut trace = [false; 20];
+    let serviced = run_blinky(&[8, 14], &mut trace);
+    let leds: String = trace.iter().map(|&on| if on { '●' } else { '○' }).collect();
+    println!("   LED: {leds}");
+    println!("   ⚡ จัดการ interrupt ไป {serviced} ครั้ง");
+
+    println!("\n📟 Echo ผ่าน UART:");
+    let input = b"hello from the host!";
+    let mut output = [0; 32];
+    let received = run_echo(input, &mut output);
+    println!("   ➡️ ส่ง:   {}", String::from_utf8_lossy(input));
+    println!(
+        "   ⬅️ ได้รับ: {}",
+        String::from_utf8_lossy(&output[..received])
+    );
+
+    println!("\n🎉 === Embedded Programming เสร็จสิ้น! === 🏆");
+    println!("🎯 คุณได้เรียนรู้:");
+    println!("   🎛️ Volatile registers (ReadOnly / WriteOnly / ReadWrite)");
+    println!("   💡 GPIO และ rising-edge interrupt");
+    println!("   📟 UART แบบ non-blocking (WouldBlock)");
+    println!("   ⚡ Interrupt controller และ critical section");
+    println!("   🧱 โค้ด core-only ที่ไม่ต้องใช้ heap");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_embedded_examples() {
+        run_embedded_examples();
+    }
+
+    /// firmware ต้องคอมไพล์ได้แบบ `#![no_std]` - ห้ามอ้าง `std`/`alloc` หรือ macro ที่ต้องใช้ heap/stdout
+    #[test]
+    fn test_firmware_files_are_core_only() {
+        let files = [
+            ("demos.rs", include_str!("demos.rs")),
+            ("gpio.rs", include_str!("gpio.rs")),
+            ("interrupts.rs", include_str!("interrupts.rs")),
+            ("registers.rs", include_str!("registers.rs")),
+            ("ring_buffer.rs", include_str!("ring_buffer.rs")),
+            ("uart.rs", include_str!("uart.rs")),
+        ];
+        let forbidden = [
+            "std::", "alloc::", "String", "Vec<", "Box<", "vec!", "format!", "println!",
+        ];
+
+        for (name, source) in files {
+            // tests รันบน host จึงใช้ std ได้
+            let firmware = source.split("#[cfg(test)]").next().unwrap_or_default();
+            for (number, line) in firmware.lines().enumerate() {
+                let code = line.split("//").next().unwrap_or_default();
+                for word in forbidden {
+                    assert!(
+                        !code.contains(word),
+                        "{name}:{}: firmware code uses `{word}`",
+                        number + 1
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,172 @@
+//! Registers - ประตูสู่ฮาร์ดแวร์ผ่าน memory-mapped I/O 🎛️
+//!
+//! บน microcontroller peripheral ถูก "แมป" ไว้ที่ address คงที่ การอ่าน/เขียน address นั้นคือการคุยกับฮาร์ดแวร์
+//! จึงต้องใช้ volatile access เสมอ - ไม่อย่างนั้น compiler อาจรวม/ตัด/สลับลำดับการเข้าถึงทิ้ง
+//! เพราะคิดว่าเป็นแค่หน่วยความจำธรรมดา
+//!
+//! - `VolatileCell<T>` - ห่อ `UnsafeCell` แล้วบังคับให้ทุกการเข้าถึงเป็น `read_volatile`/`write_volatile`
+//! - `ReadOnly` / `WriteOnly` / `ReadWrite` - บอกสิทธิ์ของ register ผ่าน type (เขียน register อ่านอย่างเดียวแล้วคอมไพล์ไม่ผ่าน)
+//! - register block คือ `#[repr(C)]` struct ที่เรียง field ตรงกับ datasheet
+
+use core::cell::UnsafeCell;
+use core::ptr;
+
+/// เซลล์หน่วยความจำที่ทุกการอ่าน/เขียนเป็น volatile 🎛️
+#[repr(transparent)]
+pub struct VolatileCell<T: Copy> {
+    value: UnsafeCell<T>,
+}
+
+impl<T: Copy> VolatileCell<T> {
+    /// สร้างเซลล์พร้อมค่าเริ่มต้น (ใช้ตอนจำลองฮาร์ดแวร์ - ของจริงได้มาจาก address คงที่)
+    #[must_use]
+    pub const fn new(value: T) -> Self {
+        Self {
+            value: UnsafeCell::new(value),
+        }
+    }
+
+    /// อ่านค่าแบบ volatile
+    #[must_use]
+    pub fn get(&self) -> T {
+        // SAFETY: pointer จาก UnsafeCell ชี้ไปยังค่าที่ถูกต้องเสมอ และ `VolatileCell` ไม่ `Sync`
+        // จึงไม่มีการเข้าถึงพร้อมกันจากหลาย thread
+        unsafe { ptr::read_volatile(self.value.get()) }
+    }
+
+    /// เขียนค่าแบบ volatile
+    pub fn set(&self, value: T) {
+        // SAFETY: เหตุผลเดียวกับ `get` - เขียนผ่าน UnsafeCell ได้แม้มีแค่ `&self`
+        unsafe { ptr::write_volatile(self.value.get(), value) }
+    }
+}
+
+/// register ที่ซอฟต์แวร์อ่านได้อย่างเดียว (ฮาร์ดแวร์เป็นคนเขียน เช่น input data, status) 👀
+#[repr(transparent)]
+pub struct ReadOnly<T: Copy>(VolatileCell<T>);
+
+impl<T: Copy> ReadOnly<T> {
+    /// สร้าง register พร้อมค่า reset
+    #[must_use]
+    pub const fn new(value: T) -> Self {
+        Self(VolatileCell::new(value))
+    }
+
+    /// อ่านค่า register
+    #[must_use]
+    pub fn read(&self) -> T {
+        self.0.get()
+    }
+
+    /// ฝั่ง "ฮาร์ดแวร์" ของตัวจำลองเขียนค่าใหม่ - driver เรียกไม่ได้
+    pub(super) fn hardware_write(&self, value: T) {
+        self.0.set(value);
+    }
+}
+
+/// register ที่ซอฟต์แวร์เขียนได้อย่างเดียว (เช่น command, clear flags) ✍️
+#[repr(transparent)]
+pub struct WriteOnly<T: Copy>(VolatileCell<T>);
+
+impl<T: Copy> WriteOnly<T> {
+    /// สร้าง register พร้อมค่า reset
+    #[must_use]
+    pub const fn new(value: T) -> Self {
+        Self(VolatileCell::new(value))
+    }
+
+    /// เขียนค่า register
+    pub fn write(&self, value: T) {
+        self.0.set(value);
+    }
+
+    /// ฝั่ง "ฮาร์ดแวร์" ของตัวจำลองอ่านค่าที่ซอฟต์แวร์เขียนไว้
+    pub(super) fn hardware_read(&self) -> T {
+        self.0.get()
+    }
+}
+
+/// register ที่อ่านและเขียนได้ (เช่น configuration, output data) 🔁
+#[repr(transparent)]
+pub struct ReadWrite<T: Copy>(VolatileCell<T>);
+
+impl<T: Copy> ReadWrite<T> {
+    /// สร้าง register พร้อมค่า reset
+    #[must_use]
+    pub const fn new(value: T) -> Self {
+        Self(VolatileCell::new(value))
+    }
+
+    /// อ่านค่า register
+    #[must_use]
+    pub fn read(&self) -> T {
+        self.0.get()
+    }
+
+    /// เขียนค่า register
+    pub fn write(&self, value: T) {
+        self.0.set(value);
+    }
+
+    /// read-modify-write - ⚠️ ไม่ atomic: ถ้า interrupt handler แก้ register เดียวกันระหว่างนี้ค่าจะหาย
+    pub fn modify(&self, f: impl FnOnce(T) -> T) {
+        self.write(f(self.read()));
+    }
+}
+
+/// mask ของบิตที่ `bit` (0-31)
+#[must_use]
+pub const fn bit(bit: u8) -> u32 {
+    1 << bit
+}
+
+/// บิตที่ `bit` ของ `value` เป็น 1 หรือไม่
+#[must_use]
+pub const fn is_set(value: u32, bit: u8) -> bool {
+    value & (1 << bit) != 0
+}
+
+/// ได้ register block จาก address ของมัน - แบบที่ firmware จริงทำกับ address จาก datasheet
+///
+/// # Safety
+///
+/// `address` ต้องชี้ไปยัง register block ชนิด `T` ที่ใช้ได้ตลอด `'a`
+/// และไม่มี `&mut T` ตัวอื่นชี้ไปที่เดียวกัน
+#[must_use]
+pub unsafe fn register_block<'a, T>(address: usize) -> &'a T {
+    // SAFETY: ผู้เรียกรับประกันตามเงื่อนไขด้านบน
+    unsafe { &*ptr::with_exposed_provenance::<T>(address) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register_access_modes() {
+        let control = ReadWrite::new(0_u32);
+        control.write(bit(3));
+        control.modify(|value| value | bit(0));
+        assert_eq!(control.read(), 0b1001);
+        assert!(is_set(control.read(), 3));
+        assert!(!is_set(control.read(), 1));
+
+        let status = ReadOnly::new(0_u32);
+        status.hardware_write(0xAB);
+        assert_eq!(status.read(), 0xAB);
+
+        let command = WriteOnly::new(0_u8);
+        command.write(7);
+        assert_eq!(command.hardware_read(), 7);
+    }
+
+    #[test]
+    fn test_register_block_from_address() {
+        let register = ReadWrite::new(42_u32);
+        let address = (&raw const register).expose_provenance();
+        // SAFETY: address มาจาก register ที่ยังมีชีวิตอยู่ตลอด test
+        let mapped: &ReadWrite<u32> = unsafe { register_block(address) };
+        mapped.write(7);
+        assert_eq!(register.read(), 7);
+    }
+}
@@ -0,0 +1,113 @@
+//! Ring Buffer - คิวขนาดคงที่สำหรับโลกที่ไม่มี heap 🔄
+//!
+//! firmware ส่วนใหญ่ไม่มี allocator จึงใช้ buffer ขนาดคงที่ที่รู้ตั้งแต่ตอนคอมไพล์ (const generics)
+//! เต็มแล้วก็ปฏิเสธข้อมูลใหม่ - ไม่มีการขยายตัวแบบ `Vec`
+
+/// คิวไบต์แบบวงกลมขนาด `N` - ใช้เป็น FIFO ของ UART 🔄
+#[derive(Debug, Clone)]
+pub struct ByteQueue<const N: usize> {
+    data: [u8; N],
+    head: usize,
+    len: usize,
+}
+
+impl<const N: usize> ByteQueue<N> {
+    /// สร้างคิวว่าง (เป็น `const fn` จึงใช้กับ `static` ได้)
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            data: [0; N],
+            head: 0,
+            len: 0,
+        }
+    }
+
+    /// ใส่ไบต์ท้ายคิว คืน `Err(byte)` ถ้าคิวเต็ม
+    ///
+    /// # Errors
+    ///
+    /// คืนไบต์เดิมกลับมาถ้าคิวเต็ม
+    pub const fn push(&mut self, byte: u8) -> Result<(), u8> {
+        if self.len == N {
+            return Err(byte);
+        }
+        self.data[(self.head + self.len) % N] = byte;
+        self.len += 1;
+        Ok(())
+    }
+
+    /// เอาไบต์หัวคิวออก
+    pub const fn pop(&mut self) -> Option<u8> {
+        if self.len == 0 {
+            return None;
+        }
+        let byte = self.data[self.head];
+        self.head = (self.head + 1) % N;
+        self.len -= 1;
+        Some(byte)
+    }
+
+    /// ดูไบต์หัวคิวโดยไม่เอาออก
+    #[must_use]
+    pub const fn peek(&self) -> Option<u8> {
+        if self.len == 0 {
+            None
+        } else {
+            Some(self.data[self.head])
+        }
+    }
+
+    /// จำนวนไบต์ในคิว
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// คิวว่างหรือไม่
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// คิวเต็มหรือไม่
+    #[must_use]
+    pub const fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// ไบต์ในคิวตามลำดับ (ไม่เอาออก)
+    pub fn iter(&self) -> impl Iterator<Item = u8> + '_ {
+        (0..self.len).map(move |offset| self.data[(self.head + offset) % N])
+    }
+}
+
+impl<const N: usize> Default for ByteQueue<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_byte_queue_wraps_around_and_rejects_when_full() {
+        let mut queue = ByteQueue::<3>::new();
+        assert_eq!(queue.push(1), Ok(()));
+        assert_eq!(queue.push(2), Ok(()));
+        assert_eq!(queue.pop(), Some(1));
+        assert_eq!(queue.push(3), Ok(()));
+        assert_eq!(queue.push(4), Ok(()));
+        assert!(queue.is_full());
+        assert_eq!(queue.push(5), Err(5));
+
+        assert!(queue.iter().eq([2, 3, 4]));
+        assert_eq!(queue.peek(), Some(2));
+        assert_eq!(queue.pop(), Some(2));
+        assert_eq!(queue.pop(), Some(3));
+        assert_eq!(queue.pop(), Some(4));
+        assert_eq!(queue.pop(), None);
+        assert!(queue.is_empty());
+    }
+}
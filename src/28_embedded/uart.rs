@@ -0,0 +1,227 @@
+//! UART - พอร์ตอนุกรมสำหรับส่งข้อความทีละไบต์ 📟
+//!
+//! register แบบ event (คล้าย nRF52): ฮาร์ดแวร์ตั้ง `rx_ready`/`tx_ready` เป็น 1
+//! ซอฟต์แวร์อ่าน/เขียนข้อมูลแล้วเขียน 0 กลับเพื่อบอกว่า "รับทราบ"
+//! driver เป็นแบบ non-blocking - ถ้าฮาร์ดแวร์ยังไม่พร้อมจะได้ `WouldBlock` แทนการวนรอ
+
+use core::cell::RefCell;
+use core::fmt;
+use core::mem::offset_of;
+
+use super::interrupts::{Interrupt, InterruptController};
+use super::registers::{ReadOnly, ReadWrite, WriteOnly, bit};
+use super::ring_buffer::ByteQueue;
+
+/// บิตใน `interrupt_enable`: แจ้งเมื่อมีไบต์เข้ามา
+pub const RX_READY_INTERRUPT: u32 = bit(0);
+
+/// register block ของ UART 📋
+#[repr(C)]
+pub struct UartRegisters {
+    /// 0x00 - 1 = เปิดใช้งาน
+    pub enable: ReadWrite<u32>,
+    /// 0x04 - interrupt ที่เปิด (`RX_READY_INTERRUPT`)
+    pub interrupt_enable: ReadWrite<u32>,
+    /// 0x08 - ฮาร์ดแวร์ตั้ง 1 เมื่อ `rxd` มีไบต์ใหม่ ซอฟต์แวร์เขียน 0 หลังอ่าน
+    pub rx_ready: ReadWrite<u32>,
+    /// 0x0C - ฮาร์ดแวร์ตั้ง 1 เมื่อพร้อมรับไบต์ถัดไป ซอฟต์แวร์เขียน 0 แล้วค่อยเขียน `txd`
+    pub tx_ready: ReadWrite<u32>,
+    /// 0x10 - ไบต์ที่รับเข้ามา
+    pub rxd: ReadOnly<u32>,
+    /// 0x14 - ไบต์ที่จะส่ง
+    pub txd: WriteOnly<u32>,
+}
+
+const _: () = assert!(offset_of!(UartRegisters, rxd) == 0x10);
+const _: () = assert!(offset_of!(UartRegisters, txd) == 0x14);
+
+impl UartRegisters {
+    /// ค่าหลัง reset - ปิดใช้งาน แต่ช่องส่งว่างอยู่
+    #[must_use]
+    pub const fn reset() -> Self {
+        Self {
+            enable: ReadWrite::new(0),
+            interrupt_enable: ReadWrite::new(0),
+            rx_ready: ReadWrite::new(0),
+            tx_ready: ReadWrite::new(1),
+            rxd: ReadOnly::new(0),
+            txd: WriteOnly::new(0),
+        }
+    }
+}
+
+/// UART จำลอง - ฝั่งฮาร์ดแวร์ มีสาย RX/TX เป็นคิวขนาด `N` 🔌
+pub struct SimUart<const N: usize> {
+    registers: UartRegisters,
+    rx_line: RefCell<ByteQueue<N>>,
+    tx_line: RefCell<ByteQueue<N>>,
+}
+
+impl<const N: usize> SimUart<N> {
+    /// UART หลัง reset
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            registers: UartRegisters::reset(),
+            rx_line: RefCell::new(ByteQueue::new()),
+            tx_line: RefCell::new(ByteQueue::new()),
+        }
+    }
+
+    /// register block ที่ driver ใช้
+    #[must_use]
+    pub const fn registers(&self) -> &UartRegisters {
+        &self.registers
+    }
+
+    /// อีกฝั่งของสายส่งไบต์มา คืนจำนวนไบต์ที่สายรับไว้ได้
+    pub fn send_from_host(&self, bytes: &[u8]) -> usize {
+        let mut line = self.rx_line.borrow_mut();
+        bytes
+            .iter()
+            .take_while(|&&byte| line.push(byte).is_ok())
+            .count()
+    }
+
+    /// ย้ายไบต์ที่ UART ส่งออกไปแล้วลง `buffer` คืนจำนวนไบต์
+    pub fn drain_to_host(&self, buffer: &mut [u8]) -> usize {
+        let mut line = self.tx_line.borrow_mut();
+        let mut count = 0;
+        for slot in buffer.iter_mut() {
+            let Some(byte) = line.pop() else { break };
+            *slot = byte;
+            count += 1;
+        }
+        count
+    }
+
+    /// เดิน 1 รอบของฮาร์ดแวร์: ส่งไบต์ที่รออยู่ และรับไบต์ใหม่ถ้าซอฟต์แวร์อ่านตัวก่อนแล้ว
+    pub fn step<C>(&self, interrupts: &InterruptController<C>) {
+        let registers = &self.registers;
+        if registers.enable.read() == 0 {
+            return;
+        }
+
+        if registers.tx_ready.read() == 0 {
+            let byte = registers.txd.hardware_read().to_le_bytes()[0];
+            // สาย TX เต็ม = อีกฝั่งรับไม่ทัน ค้างไว้ส่งรอบหน้า
+            if self.tx_line.borrow_mut().push(byte).is_ok() {
+                registers.tx_ready.write(1);
+            }
+        }
+
+        if registers.rx_ready.read() == 0
+            && let Some(byte) = self.rx_line.borrow_mut().pop()
+        {
+            registers.rxd.hardware_write(u32::from(byte));
+            registers.rx_ready.write(1);
+            if registers.interrupt_enable.read() & RX_READY_INTERRUPT != 0 {
+                interrupts.pend(Interrupt::UartRx);
+            }
+        }
+    }
+}
+
+impl<const N: usize> Default for SimUart<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// ฮาร์ดแวร์ยังไม่พร้อม ลองใหม่ภายหลัง (แบบ `nb::WouldBlock` ใน embedded-hal)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WouldBlock;
+
+impl fmt::Display for WouldBlock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("operation would block")
+    }
+}
+
+impl core::error::Error for WouldBlock {}
+
+/// driver UART ฝั่ง firmware 🧑‍💻
+#[derive(Clone, Copy)]
+pub struct Uart<'a> {
+    registers: &'a UartRegisters,
+}
+
+impl<'a> Uart<'a> {
+    /// เปิดใช้งาน UART
+    #[must_use]
+    pub fn new(registers: &'a UartRegisters) -> Self {
+        registers.enable.write(1);
+        Self { registers }
+    }
+
+    /// เปิด interrupt เมื่อมีไบต์เข้ามา
+    pub fn listen_rx(&self) {
+        self.registers
+            .interrupt_enable
+            .modify(|value| value | RX_READY_INTERRUPT);
+    }
+
+    /// อ่านไบต์ที่รับเข้ามา (ถ้ามี)
+    #[must_use]
+    pub fn try_read(&self) -> Option<u8> {
+        if self.registers.rx_ready.read() == 0 {
+            return None;
+        }
+        let byte = self.registers.rxd.read().to_le_bytes()[0];
+        self.registers.rx_ready.write(0);
+        Some(byte)
+    }
+
+    /// ส่งไบต์ถ้าช่องส่งว่าง
+    ///
+    /// # Errors
+    ///
+    /// คืน `WouldBlock` ถ้าไบต์ก่อนหน้ายังส่งไม่เสร็จ
+    pub fn try_write(&self, byte: u8) -> Result<(), WouldBlock> {
+        if self.registers.tx_ready.read() == 0 {
+            return Err(WouldBlock);
+        }
+        self.registers.tx_ready.write(0);
+        self.registers.txd.write(u32::from(byte));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_uart_receives_one_byte_per_step_and_raises_interrupt() {
+        let uart = SimUart::<8>::new();
+        let interrupts = InterruptController::<()>::new();
+        let driver = Uart::new(uart.registers());
+        driver.listen_rx();
+
+        assert_eq!(uart.send_from_host(b"hi"), 2);
+        assert_eq!(driver.try_read(), None);
+        uart.step(&interrupts);
+        assert!(interrupts.is_pending(Interrupt::UartRx));
+        assert_eq!(driver.try_read(), Some(b'h'));
+        assert_eq!(driver.try_read(), None);
+        uart.step(&interrupts);
+        assert_eq!(driver.try_read(), Some(b'i'));
+    }
+
+    #[test]
+    fn test_uart_write_blocks_until_hardware_sends() {
+        let uart = SimUart::<8>::new();
+        let interrupts = InterruptController::<()>::new();
+        let driver = Uart::new(uart.registers());
+
+        assert_eq!(driver.try_write(b'o'), Ok(()));
+        assert_eq!(driver.try_write(b'k'), Err(WouldBlock));
+        uart.step(&interrupts);
+        assert_eq!(driver.try_write(b'k'), Ok(()));
+        uart.step(&interrupts);
+
+        let mut wire = [0; 4];
+        let sent = uart.drain_to_host(&mut wire);
+        assert_eq!(&wire[..sent], b"ok");
+    }
+}
@@ -0,0 +1,303 @@
+//! 📖 Buffered I/O - อ่าน/เขียนไฟล์ทีละบรรทัดโดยไม่โหลดทั้งไฟล์เข้า memory
+//!
+//! `File` ของ std ไม่มี buffer ในตัว: `write!` 1 ครั้ง = system call 1 ครั้ง
+//! `BufWriter`/`BufReader` รวบข้อมูลเป็นก้อน (ค่าเริ่มต้น 8 KiB) ก่อนคุยกับ OS
+//!
+//! ไฟล์ใหญ่ให้วนด้วย `read_until` ลง buffer ตัวเดิม แทน `fs::read_to_string`
+//! แล้ว memory จะคงที่ไม่ว่าไฟล์จะใหญ่แค่ไหน - และไม่ล้มเมื่อเจอ byte ที่ไม่ใช่ UTF-8
+
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+/// 📊 สรุปผลจากการไล่อ่านทีละบรรทัด
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LineStats {
+    pub lines: usize,
+    pub words: usize,
+    pub bytes: u64,
+    /// ความยาว (bytes) ของบรรทัดที่ยาวที่สุด ไม่นับ `\n`/`\r\n`
+    pub longest_line: usize,
+    /// จำนวนบรรทัดที่ไม่ใช่ UTF-8 ที่ถูกต้อง (ถูกแปลงแบบ lossy)
+    pub invalid_utf8_lines: usize,
+}
+
+/// เขียนทุกบรรทัดผ่าน `BufWriter` แล้ว flush - คืนจำนวน bytes ที่เขียน
+///
+/// # Errors
+/// คืน error เมื่อสร้างหรือเขียนไฟล์ไม่สำเร็จ
+pub fn write_lines<I, S>(path: impl AsRef<Path>, lines: I) -> io::Result<u64>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let mut writer = BufWriter::new(File::create(path)?);
+    let mut written = 0_u64;
+    for line in lines {
+        let line = line.as_ref();
+        writer.write_all(line.as_bytes())?;
+        writer.write_all(b"\n")?;
+        written += line.len() as u64 + 1;
+    }
+    // ถ้าปล่อยให้ Drop flush เอง error ตอน flush จะหายไปเงียบๆ
+    writer.flush()?;
+    Ok(written)
+}
+
+/// ไล่ทุกบรรทัดด้วย buffer ตัวเดียว แล้วเรียก `visit(เลขบรรทัด, ข้อความ)`
+///
+/// เลขบรรทัดเริ่มที่ 1 และข้อความตัด `\n`/`\r\n` ออกแล้ว
+///
+/// # Errors
+/// คืน error จาก reader หรือจาก `visit`
+pub fn for_each_line<R, F>(mut reader: R, mut visit: F) -> io::Result<()>
+where
+    R: BufRead,
+    F: FnMut(usize, &[u8]) -> io::Result<()>,
+{
+    let mut buffer = Vec::with_capacity(256);
+    let mut number = 0;
+    loop {
+        buffer.clear();
+        if reader.read_until(b'\n', &mut buffer)? == 0 {
+            return Ok(());
+        }
+        number += 1;
+        visit(number, trim_newline(&buffer))?;
+    }
+}
+
+fn trim_newline(line: &[u8]) -> &[u8] {
+    let line = line.strip_suffix(b"\n").unwrap_or(line);
+    line.strip_suffix(b"\r").unwrap_or(line)
+}
+
+/// นับบรรทัด/คำ/bytes แบบ `wc` - ใช้ memory เท่ากับบรรทัดที่ยาวที่สุดเท่านั้น
+///
+/// # Errors
+/// คืน error เมื่ออ่านจาก reader ไม่สำเร็จ
+pub fn line_stats(reader: impl Read) -> io::Result<LineStats> {
+    let mut counted = CountingReader::new(reader);
+    let mut stats = LineStats::default();
+    for_each_line(BufReader::new(&mut counted), |_, line| {
+        stats.lines += 1;
+        stats.longest_line = stats.longest_line.max(line.len());
+        if std::str::from_utf8(line).is_err() {
+            stats.invalid_utf8_lines += 1;
+        }
+        // UTF-8 ที่ถูกต้องได้ &str ยืมของเดิม - copy เฉพาะบรรทัดที่ต้องแทนด้วย U+FFFD
+        stats.words += String::from_utf8_lossy(line).split_whitespace().count();
+        Ok(())
+    })?;
+    stats.bytes = counted.bytes_read();
+    Ok(stats)
+}
+
+/// เปิดไฟล์แล้วนับด้วย [`line_stats`]
+///
+/// # Errors
+/// คืน error เมื่อเปิดหรืออ่านไฟล์ไม่สำเร็จ
+pub fn file_stats(path: impl AsRef<Path>) -> io::Result<LineStats> {
+    line_stats(File::open(path)?)
+}
+
+/// 🔍 หาบรรทัดที่มี `needle` - คืน (เลขบรรทัด, ข้อความ) แบบ `grep -n`
+///
+/// # Errors
+/// คืน error เมื่อเปิดหรืออ่านไฟล์ไม่สำเร็จ
+pub fn grep_file(path: impl AsRef<Path>, needle: &str) -> io::Result<Vec<(usize, String)>> {
+    let mut found = Vec::new();
+    let reader = BufReader::new(File::open(path)?);
+    for_each_line(reader, |number, line| {
+        let text = String::from_utf8_lossy(line);
+        if text.contains(needle) {
+            found.push((number, text.into_owned()));
+        }
+        Ok(())
+    })?;
+    Ok(found)
+}
+
+/// คัดลอกทีละ chunk ขนาด `chunk_size` แล้วรายงานจำนวน bytes สะสมหลังทุก chunk
+///
+/// # Errors
+/// คืน error จาก reader/writer
+///
+/// # Panics
+/// เมื่อ `chunk_size` เป็น 0
+pub fn copy_with_progress<R, W, F>(
+    mut reader: R,
+    mut writer: W,
+    chunk_size: usize,
+    mut on_progress: F,
+) -> io::Result<u64>
+where
+    R: Read,
+    W: Write,
+    F: FnMut(u64),
+{
+    assert!(chunk_size > 0, "chunk_size must be positive");
+    let mut chunk = vec![0; chunk_size];
+    let mut total = 0_u64;
+    loop {
+        let read = match reader.read(&mut chunk) {
+            Ok(0) => break,
+            Ok(read) => read,
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+            Err(error) => return Err(error),
+        };
+        writer.write_all(&chunk[..read])?;
+        total += read as u64;
+        on_progress(total);
+    }
+    writer.flush()?;
+    Ok(total)
+}
+
+/// 🧮 ห่อ reader แล้วนับ bytes ที่ผ่านไป - ตัวอย่างการเขียน adapter ของ `Read`
+#[derive(Debug)]
+pub struct CountingReader<R> {
+    inner: R,
+    bytes_read: u64,
+}
+
+impl<R> CountingReader<R> {
+    #[must_use]
+    pub const fn new(inner: R) -> Self {
+        Self {
+            inner,
+            bytes_read: 0,
+        }
+    }
+
+    #[must_use]
+    pub const fn bytes_read(&self) -> u64 {
+        self.bytes_read
+    }
+
+    #[must_use]
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: Read> Read for CountingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.bytes_read += read as u64;
+        Ok(read)
+    }
+}
+
+/// 🎪 เดโม buffered I/O บนไฟล์ใน `dir`
+///
+/// # Errors
+/// คืน error แรกจากการอ่าน/เขียนไฟล์ใน `dir`
+pub fn demonstrate_buffered_io(dir: &Path) -> io::Result<()> {
+    let log = dir.join("access.log");
+    let lines = (1..=10_000).map(|i| {
+        let status = if i % 250 == 0 { 500 } else { 200 };
+        format!("GET /item/{i} {status}")
+    });
+    let written = write_lines(&log, lines)?;
+    println!("   ✍️ เขียน access.log ผ่าน BufWriter: {written} bytes");
+
+    let stats = file_stats(&log)?;
+    println!(
+        "   📊 {} บรรทัด | {} คำ | {} bytes | บรรทัดยาวสุด {} bytes",
+        stats.lines, stats.words, stats.bytes, stats.longest_line
+    );
+
+    let errors = grep_file(&log, " 500")?;
+    println!(
+        "   🔍 grep \" 500\": เจอ {} บรรทัด เช่น {:?}",
+        errors.len(),
+        errors.first()
+    );
+
+    let mut checkpoints = 0;
+    let copied = copy_with_progress(
+        File::open(&log)?,
+        BufWriter::new(File::create(dir.join("access.log.copy"))?),
+        64 * 1024,
+        |_| checkpoints += 1,
+    )?;
+    println!("   📦 คัดลอก {copied} bytes เป็น {checkpoints} chunk(s) ละ 64 KiB");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_then_stats_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("notes.txt");
+        let written = write_lines(&path, ["hello world", "", "สวัสดี ชาวโลก ครับ"]).unwrap();
+
+        let stats = file_stats(&path).unwrap();
+        assert_eq!(stats.bytes, written);
+        assert_eq!(stats.lines, 3);
+        assert_eq!(stats.words, 5);
+        assert_eq!(stats.longest_line, "สวัสดี ชาวโลก ครับ".len());
+        assert_eq!(stats.invalid_utf8_lines, 0);
+    }
+
+    #[test]
+    fn test_line_stats_handles_crlf_missing_newline_and_bad_utf8() {
+        let input: &[u8] = b"one two\r\nbad \xFF byte\nlast line without newline";
+        let stats = line_stats(input).unwrap();
+        assert_eq!(stats.lines, 3);
+        assert_eq!(stats.words, 2 + 3 + 4);
+        assert_eq!(stats.bytes, input.len() as u64);
+        assert_eq!(stats.longest_line, "last line without newline".len());
+        assert_eq!(stats.invalid_utf8_lines, 1);
+    }
+
+    #[test]
+    fn test_grep_reports_line_numbers() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("app.log");
+        write_lines(
+            &path,
+            ["INFO start", "ERROR disk full", "INFO retry", "ERROR again"],
+        )
+        .unwrap();
+
+        let found = grep_file(&path, "ERROR").unwrap();
+        assert_eq!(
+            found,
+            vec![
+                (2, "ERROR disk full".to_string()),
+                (4, "ERROR again".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_copy_with_progress_reports_every_chunk() {
+        let data = vec![7_u8; 10_000];
+        let mut output = Vec::new();
+        let mut progress = Vec::new();
+        let copied =
+            copy_with_progress(data.as_slice(), &mut output, 4096, |n| progress.push(n)).unwrap();
+
+        assert_eq!(copied, 10_000);
+        assert_eq!(output, data);
+        assert_eq!(progress, vec![4096, 8192, 10_000]);
+    }
+
+    #[test]
+    fn test_large_file_is_processed_line_by_line() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("big.txt");
+        let line = "x".repeat(99);
+        write_lines(&path, std::iter::repeat_n(line.as_str(), 50_000)).unwrap();
+
+        let stats = file_stats(&path).unwrap();
+        assert_eq!(stats.lines, 50_000);
+        assert_eq!(stats.bytes, 5_000_000);
+        assert_eq!(stats.longest_line, 99);
+    }
+}
@@ -0,0 +1,859 @@
+//! 📊 CSV - อ่าน/เขียน CSV ตาม RFC 4180 แบบ streaming พร้อม deserialize เป็น struct ด้วย serde
+//!
+//! `line.split(',')` ใช้ได้จนกระทั่งเจอข้อมูลจริง:
+//!
+//! ```text
+//! name,quote
+//! "Ferris, the crab","He said ""hi""
+//! and left"
+//! ```
+//!
+//! - field ที่มี `,` `"` หรือขึ้นบรรทัดใหม่ต้องครอบด้วย `"..."`
+//! - `"` ในเนื้อหาเขียนซ้ำเป็น `""`
+//! - record หนึ่งจึงยาวได้หลายบรรทัด - parser ต้องอ่านต่อจนกว่าจะปิด quote
+//!
+//! [`CsvReader`] อ่านจาก `BufRead` ทีละ record (ไฟล์ใหญ่แค่ไหนก็ใช้ memory เท่ากับ record เดียว)
+//! แล้ว map คอลัมน์เข้า field ของ struct ตามชื่อ header ผ่าน `serde::Deserialize`
+//! ส่วน [`CsvWriter`] ใส่ quote ให้เฉพาะ field ที่จำเป็น
+
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt::{self, Write as _};
+use std::io::{self, BufRead, Write};
+use std::marker::PhantomData;
+
+use serde::de::{
+    self, DeserializeOwned, DeserializeSeed, IntoDeserializer, MapAccess, Visitor,
+    value::SeqDeserializer,
+};
+use serde::forward_to_deserialize_any;
+
+/// ❌ error ระหว่างอ่าน CSV - `line` คือบรรทัดที่ record นั้นเริ่ม (นับจาก 1)
+#[derive(Debug)]
+pub enum CsvError {
+    Io(io::Error),
+    /// เปิด `"` แล้วไม่ปิดจนจบไฟล์
+    UnterminatedQuote {
+        line: usize,
+    },
+    /// มีตัวอักษรอื่นต่อท้าย `"` ที่ปิด field เช่น `"a"b`
+    Malformed {
+        line: usize,
+        column: usize,
+    },
+    /// จำนวน field ไม่เท่ากับ record แรก (ปิดการตรวจด้วย `flexible(true)`)
+    FieldCount {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// แปลง record เป็นชนิดปลายทางไม่ได้
+    Deserialize {
+        line: usize,
+        message: String,
+    },
+}
+
+impl fmt::Display for CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "CSV I/O error: {error}"),
+            Self::UnterminatedQuote { line } => {
+                write!(f, "line {line}: quoted field is never closed")
+            }
+            Self::Malformed { line, column } => {
+                write!(
+                    f,
+                    "line {line}, column {column}: unexpected character after closing quote"
+                )
+            }
+            Self::FieldCount {
+                line,
+                expected,
+                found,
+            } => write!(f, "line {line}: expected {expected} fields, found {found}"),
+            Self::Deserialize { line, message } => write!(f, "line {line}: {message}"),
+        }
+    }
+}
+
+impl Error for CsvError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for CsvError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    FieldStart,
+    Unquoted,
+    Quoted,
+    /// เจอ `"` ใน field ที่มี quote - อาจเป็น `""` (escape) หรือการปิด field
+    QuoteInQuoted,
+}
+
+/// 📖 CSV reader แบบ streaming
+#[derive(Debug)]
+pub struct CsvReader<R> {
+    input: R,
+    delimiter: u8,
+    has_headers: bool,
+    flexible: bool,
+    headers: Option<Vec<String>>,
+    expected_fields: Option<usize>,
+    buffer: String,
+    line: usize,
+    record_line: usize,
+}
+
+impl<R: BufRead> CsvReader<R> {
+    /// reader ที่ถือว่าบรรทัดแรกเป็น header และคั่นด้วย `,`
+    pub const fn new(input: R) -> Self {
+        Self {
+            input,
+            delimiter: b',',
+            has_headers: true,
+            flexible: false,
+            headers: None,
+            expected_fields: None,
+            buffer: String::new(),
+            line: 0,
+            record_line: 0,
+        }
+    }
+
+    /// ตัวคั่นอื่น เช่น `b';'` หรือ `b'\t'` (ต้องเป็น ASCII และไม่ใช่ `"`)
+    ///
+    /// # Panics
+    /// เมื่อ `delimiter` ไม่ใช่ ASCII หรือเป็น `"`/`\r`/`\n`
+    #[must_use]
+    pub fn delimiter(mut self, delimiter: u8) -> Self {
+        assert!(
+            delimiter.is_ascii() && !matches!(delimiter, b'"' | b'\r' | b'\n'),
+            "invalid CSV delimiter {delimiter:?}"
+        );
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// `false` = ไม่มีบรรทัด header (deserialize ได้เฉพาะ tuple/`Vec`)
+    #[must_use]
+    pub const fn has_headers(mut self, has_headers: bool) -> Self {
+        self.has_headers = has_headers;
+        self
+    }
+
+    /// ยอมให้แต่ละ record มีจำนวน field ไม่เท่ากัน
+    #[must_use]
+    pub const fn flexible(mut self, flexible: bool) -> Self {
+        self.flexible = flexible;
+        self
+    }
+
+    /// header ของไฟล์ (อ่านบรรทัดแรกถ้ายังไม่ได้อ่าน) - ว่างเมื่อ `has_headers(false)`
+    ///
+    /// # Errors
+    /// คืน error เมื่ออ่านหรือ parse บรรทัดแรกไม่ได้
+    pub fn headers(&mut self) -> Result<&[String], CsvError> {
+        if self.headers.is_none() {
+            let headers = if self.has_headers {
+                let headers = self.parse_record()?.unwrap_or_default();
+                self.expected_fields = Some(headers.len());
+                headers
+            } else {
+                Vec::new()
+            };
+            self.headers = Some(headers);
+        }
+        Ok(self.headers.as_deref().unwrap_or_default())
+    }
+
+    /// record ถัดไป (ไม่รวม header) - `Ok(None)` เมื่อจบไฟล์
+    ///
+    /// # Errors
+    /// คืน error เมื่ออ่านไม่ได้, quote ผิดรูปแบบ หรือจำนวน field ไม่ตรง
+    pub fn read_record(&mut self) -> Result<Option<Vec<String>>, CsvError> {
+        self.headers()?;
+        let Some(record) = self.parse_record()? else {
+            return Ok(None);
+        };
+        match self.expected_fields {
+            Some(expected) if !self.flexible && record.len() != expected => {
+                Err(CsvError::FieldCount {
+                    line: self.record_line,
+                    expected,
+                    found: record.len(),
+                })
+            }
+            Some(_) => Ok(Some(record)),
+            None => {
+                self.expected_fields = Some(record.len());
+                Ok(Some(record))
+            }
+        }
+    }
+
+    /// บรรทัดที่ record ล่าสุดเริ่ม
+    #[must_use]
+    pub const fn record_line(&self) -> usize {
+        self.record_line
+    }
+
+    /// iterator ของ record ดิบ
+    pub const fn records(&mut self) -> Records<'_, R> {
+        Records { reader: self }
+    }
+
+    /// iterator ที่แปลงแต่ละ record เป็น `T` - struct จับคู่คอลัมน์ตามชื่อ header,
+    /// tuple/`Vec` จับคู่ตามลำดับ
+    pub const fn deserialize<T: DeserializeOwned>(&mut self) -> DeserializeRecords<'_, R, T> {
+        DeserializeRecords {
+            reader: self,
+            _target: PhantomData,
+        }
+    }
+
+    fn read_line(&mut self) -> io::Result<bool> {
+        let read = self.input.read_line(&mut self.buffer)?;
+        if read > 0 {
+            self.line += 1;
+        }
+        Ok(read > 0)
+    }
+
+    /// parse หนึ่ง record ด้วย state machine ทีละ byte
+    ///
+    /// ตัดเฉพาะที่ `"`, ตัวคั่น และ `\r`/`\n` ซึ่งเป็น ASCII ทั้งหมด
+    /// จึงไม่มีวันตัดกลางตัวอักษร UTF-8 หลาย byte
+    fn parse_record(&mut self) -> Result<Option<Vec<String>>, CsvError> {
+        // ข้ามบรรทัดว่าง
+        loop {
+            self.buffer.clear();
+            if !self.read_line()? {
+                return Ok(None);
+            }
+            if !self.buffer.trim_end_matches(['\r', '\n']).is_empty() {
+                break;
+            }
+        }
+        self.record_line = self.line;
+
+        let mut fields = Vec::new();
+        let mut field = String::new();
+        let mut state = State::FieldStart;
+        let mut position = 0;
+        let mut line_start = 0;
+        loop {
+            let bytes = self.buffer.as_bytes();
+            if position == bytes.len() {
+                if state == State::Quoted {
+                    // newline อยู่ใน quote - record ยังไม่จบ อ่านบรรทัดต่อ
+                    line_start = self.buffer.len();
+                    if !self.read_line()? {
+                        return Err(CsvError::UnterminatedQuote {
+                            line: self.record_line,
+                        });
+                    }
+                    continue;
+                }
+                // บรรทัดสุดท้ายของไฟล์ที่ไม่มี newline ปิด
+                fields.push(field);
+                return Ok(Some(fields));
+            }
+
+            let byte = bytes[position];
+            let end_of_line = byte == b'\n'
+                || (byte == b'\r' && matches!(bytes.get(position + 1), None | Some(b'\n')));
+            let run_start = position;
+            position += 1;
+            match state {
+                State::Quoted => {
+                    if byte == b'"' {
+                        state = State::QuoteInQuoted;
+                    } else {
+                        // คัดลอกทั้งช่วงจนถึง `"` ถัดไปทีเดียว แทนการ push ทีละ byte
+                        let run_end = bytes[position..]
+                            .iter()
+                            .position(|&b| b == b'"')
+                            .map_or(bytes.len(), |offset| position + offset);
+                        field.push_str(&self.buffer[run_start..run_end]);
+                        position = run_end;
+                    }
+                }
+                _ if end_of_line => {
+                    fields.push(field);
+                    return Ok(Some(fields));
+                }
+                _ if byte == self.delimiter => {
+                    fields.push(std::mem::take(&mut field));
+                    state = State::FieldStart;
+                }
+                State::FieldStart if byte == b'"' => state = State::Quoted,
+                State::FieldStart | State::Unquoted => {
+                    // `"` กลาง field ที่ไม่มี quote ถือเป็นตัวอักษรธรรมดา (ผ่อนปรนกว่า RFC)
+                    let delimiter = self.delimiter;
+                    let run_end = bytes[position..]
+                        .iter()
+                        .position(|&b| b == delimiter || matches!(b, b'\r' | b'\n'))
+                        .map_or(bytes.len(), |offset| position + offset);
+                    field.push_str(&self.buffer[run_start..run_end]);
+                    position = run_end;
+                    state = State::Unquoted;
+                }
+                State::QuoteInQuoted if byte == b'"' => {
+                    field.push('"');
+                    state = State::Quoted;
+                }
+                State::QuoteInQuoted => {
+                    return Err(CsvError::Malformed {
+                        line: self.line,
+                        column: position - line_start,
+                    });
+                }
+            }
+        }
+    }
+
+    fn record_to<T: DeserializeOwned>(&self, record: &[String]) -> Result<T, CsvError> {
+        let line = self.record_line;
+        let headers = self.headers.as_deref().unwrap_or_default();
+        T::deserialize(RecordDeserializer {
+            headers: self.has_headers.then_some(headers),
+            fields: record,
+        })
+        .map_err(|DeError(message)| CsvError::Deserialize { line, message })
+    }
+}
+
+/// 🔁 iterator จาก [`CsvReader::records`]
+#[derive(Debug)]
+pub struct Records<'r, R> {
+    reader: &'r mut CsvReader<R>,
+}
+
+impl<R: BufRead> Iterator for Records<'_, R> {
+    type Item = Result<Vec<String>, CsvError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.reader.read_record().transpose()
+    }
+}
+
+/// 🔁 iterator จาก [`CsvReader::deserialize`]
+#[derive(Debug)]
+pub struct DeserializeRecords<'r, R, T> {
+    reader: &'r mut CsvReader<R>,
+    _target: PhantomData<fn() -> T>,
+}
+
+impl<R: BufRead, T: DeserializeOwned> Iterator for DeserializeRecords<'_, R, T> {
+    type Item = Result<T, CsvError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let record = match self.reader.read_record() {
+            Ok(Some(record)) => record,
+            Ok(None) => return None,
+            Err(error) => return Some(Err(error)),
+        };
+        Some(self.reader.record_to(&record))
+    }
+}
+
+/// error ภายในของ serde - แปลงเป็น `CsvError::Deserialize` พร้อมเลขบรรทัดตอนส่งออก
+#[derive(Debug)]
+struct DeError(String);
+
+impl fmt::Display for DeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Error for DeError {}
+
+impl de::Error for DeError {
+    fn custom<T: fmt::Display>(message: T) -> Self {
+        Self(message.to_string())
+    }
+}
+
+/// record ทั้งแถว: struct/map ได้ field ตามชื่อ header, tuple/seq ได้ตามลำดับ
+struct RecordDeserializer<'a> {
+    headers: Option<&'a [String]>,
+    fields: &'a [String],
+}
+
+impl<'de> de::Deserializer<'de> for RecordDeserializer<'_> {
+    type Error = DeError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        match self.headers {
+            Some(headers) => visitor.visit_map(RecordAccess {
+                headers,
+                fields: self.fields,
+                index: 0,
+            }),
+            None => self.deserialize_seq(visitor),
+        }
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        let mut seq = SeqDeserializer::new(self.fields.iter().map(|field| Field(field)));
+        let value = visitor.visit_seq(&mut seq)?;
+        seq.end()?;
+        Ok(value)
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, DeError> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, DeError> {
+        self.deserialize_seq(visitor)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct map struct enum
+        identifier ignored_any
+    }
+}
+
+struct RecordAccess<'a> {
+    headers: &'a [String],
+    fields: &'a [String],
+    index: usize,
+}
+
+impl<'de> MapAccess<'de> for RecordAccess<'_> {
+    type Error = DeError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, DeError> {
+        let Some(header) = self.headers.get(self.index) else {
+            return Ok(None);
+        };
+        seed.deserialize(header.as_str().into_deserializer())
+            .map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, DeError> {
+        let header = &self.headers[self.index];
+        // record ที่สั้นกว่า header (โหมด flexible) = คอลัมน์ที่ขาดเป็นค่าว่าง
+        let field = self.fields.get(self.index).map_or("", String::as_str);
+        self.index += 1;
+        seed.deserialize(Field(field))
+            .map_err(|DeError(message)| DeError(format!("column `{header}`: {message}")))
+    }
+}
+
+/// ค่าของ field เดียว - ตัวเลข/bool parse จากข้อความ, ค่าว่าง = `None`
+struct Field<'a>(&'a str);
+
+impl IntoDeserializer<'_, DeError> for Field<'_> {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+macro_rules! parse_field {
+    ($($method:ident => $visit:ident),* $(,)?) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+                let value = self.0.trim().parse().map_err(|error| {
+                    DeError(format!("cannot parse {:?}: {error}", self.0))
+                })?;
+                visitor.$visit(value)
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for Field<'_> {
+    type Error = DeError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        visitor.visit_str(self.0)
+    }
+
+    parse_field! {
+        deserialize_bool => visit_bool,
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+        deserialize_char => visit_char,
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        if self.0.is_empty() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, DeError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, DeError> {
+        visitor.visit_enum(self.0.into_deserializer())
+    }
+
+    forward_to_deserialize_any! {
+        i128 u128 str string bytes byte_buf unit unit_struct seq tuple
+        tuple_struct map struct identifier ignored_any
+    }
+}
+
+/// ✍️ CSV writer - ใส่ quote เฉพาะ field ที่จำเป็น และจบ record ด้วย `\r\n` ตาม RFC 4180
+#[derive(Debug)]
+pub struct CsvWriter<W: Write> {
+    output: W,
+    delimiter: u8,
+}
+
+impl<W: Write> CsvWriter<W> {
+    pub const fn new(output: W) -> Self {
+        Self {
+            output,
+            delimiter: b',',
+        }
+    }
+
+    /// # Panics
+    /// เมื่อ `delimiter` ไม่ใช่ ASCII หรือเป็น `"`/`\r`/`\n`
+    #[must_use]
+    pub fn delimiter(mut self, delimiter: u8) -> Self {
+        assert!(
+            delimiter.is_ascii() && !matches!(delimiter, b'"' | b'\r' | b'\n'),
+            "invalid CSV delimiter {delimiter:?}"
+        );
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// เขียนหนึ่ง record
+    ///
+    /// # Errors
+    /// คืน error จาก writer ปลายทาง
+    pub fn write_record<I, S>(&mut self, fields: I) -> io::Result<()>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        for (index, field) in fields.into_iter().enumerate() {
+            if index > 0 {
+                self.output.write_all(&[self.delimiter])?;
+            }
+            self.output
+                .write_all(quote_field(field.as_ref(), self.delimiter).as_bytes())?;
+        }
+        self.output.write_all(b"\r\n")
+    }
+
+    /// # Errors
+    /// คืน error จาก writer ปลายทาง
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.output.flush()
+    }
+
+    /// # Errors
+    /// คืน error เมื่อ flush ไม่สำเร็จ
+    pub fn into_inner(mut self) -> io::Result<W> {
+        self.output.flush()?;
+        Ok(self.output)
+    }
+}
+
+/// ครอบ `"` และเขียน `"` ในเนื้อหาเป็น `""` เฉพาะเมื่อจำเป็น
+#[must_use]
+pub fn quote_field(field: &str, delimiter: u8) -> Cow<'_, str> {
+    let needs_quotes = field
+        .bytes()
+        .any(|b| b == delimiter || matches!(b, b'"' | b'\r' | b'\n'));
+    if needs_quotes {
+        Cow::Owned(format!("\"{}\"", field.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(field)
+    }
+}
+
+/// parse ทั้งข้อความ (ไม่มี header) เป็นตาราง - ใช้เทียบกับ [`parse_naive`] ใน benchmark
+///
+/// # Errors
+/// คืน error เมื่อ CSV ผิดรูปแบบ
+pub fn parse_rfc4180(text: &str) -> Result<Vec<Vec<String>>, CsvError> {
+    CsvReader::new(text.as_bytes())
+        .has_headers(false)
+        .flexible(true)
+        .records()
+        .collect()
+}
+
+/// `lines()` + `split(',')` - เร็วและไม่ copy แต่ผิดทันทีที่มี quote
+#[must_use]
+pub fn parse_naive(text: &str) -> Vec<Vec<&str>> {
+    text.lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| line.split(',').collect())
+        .collect()
+}
+
+/// CSV ตัวอย่าง `rows` แถว - ทุกแถวที่ 10 มี field ที่ต้อง quote
+#[must_use]
+pub fn sample_csv(rows: usize) -> String {
+    let mut text = String::from("id,name,city,score\n");
+    for i in 0..rows {
+        let name = if i % 10 == 0 {
+            format!("\"Crab, Ferris {i}\"")
+        } else {
+            format!("user{i}")
+        };
+        let _ = writeln!(text, "{i},{name},Bangkok,{}.5", i % 100);
+    }
+    text
+}
+
+/// 🎪 เดโม CSV: อ่านเข้า struct, เทียบกับ split(',') และเขียนกลับ
+///
+/// # Errors
+/// คืน error แรกจากการอ่าน/เขียนไฟล์ใน `dir`
+pub fn demonstrate_csv(dir: &std::path::Path) -> io::Result<()> {
+    #[derive(Debug, serde::Deserialize)]
+    struct Employee {
+        name: String,
+        team: String,
+        salary: u32,
+        remote: Option<bool>,
+    }
+
+    let text = "name,team,salary,remote\r\n\
+                \"Somchai, Jr.\",Backend,52000,true\r\n\
+                \"Malee \"\"Mali\"\"\",\"Data\nScience\",61000,\r\n\
+                Ferris,Platform,48000,false\r\n";
+    let mut reader = CsvReader::new(text.as_bytes());
+    let employees: Vec<Employee> = reader
+        .deserialize()
+        .collect::<Result<_, _>>()
+        .map_err(io::Error::other)?;
+    for employee in &employees {
+        println!("   👤 {employee:?}");
+    }
+
+    let naive = parse_naive(text);
+    println!(
+        "   ⚠️ split(',') ได้ {} แถว (จริงๆ มี {}) แถวแรกมี {} ช่อง: {:?}",
+        naive.len() - 1,
+        employees.len(),
+        naive[1].len(),
+        naive[1]
+    );
+
+    let path = dir.join("payroll.csv");
+    let mut writer = CsvWriter::new(io::BufWriter::new(std::fs::File::create(&path)?));
+    writer.write_record(["name", "team", "monthly", "remote"])?;
+    for employee in &employees {
+        let monthly = format!("{:.2}", f64::from(employee.salary) / 12.0);
+        let remote = employee
+            .remote
+            .map_or("unknown", |r| if r { "yes" } else { "no" });
+        writer.write_record([employee.name.as_str(), &employee.team, &monthly, remote])?;
+    }
+    writer.into_inner()?;
+    println!("   ✍️ เขียน {}:", path.display());
+    for line in std::fs::read_to_string(&path)?
+        .split("\r\n")
+        .filter(|l| !l.is_empty())
+    {
+        println!("      {line}");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    fn rows(text: &str) -> Vec<Vec<String>> {
+        parse_rfc4180(text).unwrap()
+    }
+
+    #[test]
+    fn test_rfc4180_edge_cases() {
+        // CRLF, ไม่มี newline ปิดท้าย, field ว่าง
+        assert_eq!(rows("a,b\r\nc,d"), [["a", "b"], ["c", "d"]]);
+        assert_eq!(rows("a,,\n,b,\n"), [["a", "", ""], ["", "b", ""]]);
+        // quote ครอบ delimiter, escape `""`, newline ใน field
+        assert_eq!(
+            rows("\"a,b\",\"say \"\"hi\"\"\",\"line1\r\nline2\"\n"),
+            [["a,b", "say \"hi\"", "line1\r\nline2"]]
+        );
+        // quoted field ว่าง ≠ ไม่มี field, ช่องว่างเป็นส่วนหนึ่งของข้อมูล
+        assert_eq!(rows("\"\", x ,\"\"\"\""), [["", " x ", "\""]]);
+        // บรรทัดว่างถูกข้าม, UTF-8 หลาย byte ไม่ถูกตัด
+        assert_eq!(rows("\n\nสวัสดี,🦀\n\n"), [["สวัสดี", "🦀"]]);
+        // `"` กลาง field ที่ไม่มี quote ถือเป็นตัวอักษรธรรมดา
+        assert_eq!(rows("5\"10,x"), [["5\"10", "x"]]);
+    }
+
+    #[test]
+    fn test_malformed_input_reports_line() {
+        let error = parse_rfc4180("ok,fine\n\"open,never closed\nstill open").unwrap_err();
+        assert!(
+            matches!(error, CsvError::UnterminatedQuote { line: 2 }),
+            "{error}"
+        );
+
+        let error = parse_rfc4180("a,b\n\"x\"y,z\n").unwrap_err();
+        assert!(
+            matches!(error, CsvError::Malformed { line: 2, column: 4 }),
+            "{error}"
+        );
+
+        let error = CsvReader::new(&b"a,b\n1,2\n3\n"[..])
+            .records()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap_err();
+        assert_eq!(error.to_string(), "line 3: expected 2 fields, found 1");
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    #[serde(rename_all = "lowercase")]
+    enum Kind {
+        Fruit,
+        Vegetable,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Item {
+        name: String,
+        price: f64,
+        stock: Option<u32>,
+        kind: Kind,
+    }
+
+    #[test]
+    fn test_deserialize_maps_headers_to_fields() {
+        // ลำดับคอลัมน์ไม่ตรงกับ struct และมีคอลัมน์เกินก็ได้
+        let text = "kind,stock,name,price,note\n\
+                    fruit,12,\"Mango, ripe\",35.5,sweet\n\
+                    vegetable,,Kale,20,\n";
+        let items: Vec<Item> = CsvReader::new(text.as_bytes())
+            .deserialize()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(
+            items,
+            [
+                Item {
+                    name: "Mango, ripe".to_string(),
+                    price: 35.5,
+                    stock: Some(12),
+                    kind: Kind::Fruit,
+                },
+                Item {
+                    name: "Kale".to_string(),
+                    price: 20.0,
+                    stock: None,
+                    kind: Kind::Vegetable,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_deserialize_errors_name_line_and_column() {
+        let text = "name,price,stock,kind\napple,1,2,fruit\npear,cheap,3,fruit\n";
+        let mut reader = CsvReader::new(text.as_bytes());
+        let results: Vec<Result<Item, _>> = reader.deserialize().collect();
+        assert!(results[0].is_ok());
+        let error = results[1].as_ref().unwrap_err().to_string();
+        assert!(
+            error.starts_with("line 3: column `price`: cannot parse \"cheap\""),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn test_tuples_without_headers_and_custom_delimiter() {
+        let mut reader = CsvReader::new(&b"1;a;true\n2;b;false\n"[..])
+            .has_headers(false)
+            .delimiter(b';');
+        let rows: Vec<(u8, String, bool)> = reader.deserialize().collect::<Result<_, _>>().unwrap();
+        assert_eq!(
+            rows,
+            [(1, "a".to_string(), true), (2, "b".to_string(), false)]
+        );
+    }
+
+    #[test]
+    fn test_writer_round_trips_through_reader() {
+        let records = [
+            vec!["plain", "with,comma", "with \"quote\""],
+            vec!["multi\nline", "", "ไทย"],
+        ];
+        let mut writer = CsvWriter::new(Vec::new());
+        for record in &records {
+            writer.write_record(record).unwrap();
+        }
+        let bytes = writer.into_inner().unwrap();
+        assert!(
+            String::from_utf8_lossy(&bytes)
+                .starts_with("plain,\"with,comma\",\"with \"\"quote\"\"\"\r\n")
+        );
+
+        let parsed = parse_rfc4180(std::str::from_utf8(&bytes).unwrap()).unwrap();
+        assert_eq!(parsed, records);
+    }
+
+    #[test]
+    fn test_naive_split_breaks_on_quotes() {
+        let text = sample_csv(20);
+        let naive = parse_naive(&text);
+        let proper = parse_rfc4180(&text).unwrap();
+        assert_eq!(naive.len(), proper.len());
+        assert_eq!(proper[1][1], "Crab, Ferris 0");
+        assert_eq!(naive[1].len(), 5, "split(',') cuts the quoted name in two");
+        assert!(proper.iter().all(|row| row.len() == 4));
+    }
+}
@@ -0,0 +1,314 @@
+//! 🌲 Directory walking - ไล่ทุกไฟล์ใต้โฟลเดอร์พร้อมตัวกรอง (แบบ `find`/`walkdir` ย่อส่วน)
+//!
+//! เดินแบบ depth-first ด้วย stack ของตัวเอง (ไม่ใช้ recursion - โฟลเดอร์ลึกแค่ไหนก็ไม่ stack overflow)
+//! แล้วเรียงผลตาม path ตอนจบ เพราะ `read_dir` ไม่รับประกันลำดับ ผลจึงเหมือนกันทุกเครื่อง
+//!
+//! symlink ไม่ถูกตามเข้าไป (ใช้ `symlink_metadata`) - กันลูปอย่าง `a/link -> ..`
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// 📄 ไฟล์หรือโฟลเดอร์ที่เจอระหว่างเดิน
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub path: PathBuf,
+    /// 1 = อยู่ในโฟลเดอร์ราก, 2 = ลึกลงไปหนึ่งชั้น, ...
+    pub depth: usize,
+    pub is_dir: bool,
+    /// ขนาดไฟล์ (โฟลเดอร์เป็น 0)
+    pub size: u64,
+}
+
+type Predicate = Box<dyn Fn(&Entry) -> bool>;
+
+/// 🚶 ตัวเดินโฟลเดอร์ - ตั้งค่าแบบ builder แล้วเรียก [`Walker::walk`]
+pub struct Walker {
+    root: PathBuf,
+    max_depth: Option<usize>,
+    include_hidden: bool,
+    include_dirs: bool,
+    extensions: Vec<String>,
+    skip_dirs: Vec<String>,
+    filters: Vec<Predicate>,
+}
+
+impl std::fmt::Debug for Walker {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Walker")
+            .field("root", &self.root)
+            .field("max_depth", &self.max_depth)
+            .field("include_hidden", &self.include_hidden)
+            .field("include_dirs", &self.include_dirs)
+            .field("extensions", &self.extensions)
+            .field("skip_dirs", &self.skip_dirs)
+            .field("filters", &self.filters.len())
+            .finish()
+    }
+}
+
+impl Walker {
+    #[must_use]
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self {
+            root: root.into(),
+            max_depth: None,
+            include_hidden: false,
+            include_dirs: false,
+            extensions: Vec::new(),
+            skip_dirs: Vec::new(),
+            filters: Vec::new(),
+        }
+    }
+
+    /// ไม่ลงลึกเกิน `depth` ชั้น (1 = เฉพาะของในโฟลเดอร์ราก)
+    #[must_use]
+    pub const fn max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = Some(depth);
+        self
+    }
+
+    /// รวมไฟล์/โฟลเดอร์ที่ขึ้นต้นด้วย `.` ด้วย (ค่าเริ่มต้นข้าม)
+    #[must_use]
+    pub const fn include_hidden(mut self, include: bool) -> Self {
+        self.include_hidden = include;
+        self
+    }
+
+    /// คืนโฟลเดอร์ในผลลัพธ์ด้วย (ค่าเริ่มต้นคืนเฉพาะไฟล์)
+    #[must_use]
+    pub const fn include_dirs(mut self, include: bool) -> Self {
+        self.include_dirs = include;
+        self
+    }
+
+    /// รับเฉพาะไฟล์นามสกุลนี้ (เรียกหลายครั้งได้ - ไม่สนตัวพิมพ์เล็ก/ใหญ่)
+    #[must_use]
+    pub fn extension(mut self, extension: &str) -> Self {
+        self.extensions
+            .push(extension.trim_start_matches('.').to_lowercase());
+        self
+    }
+
+    /// ไม่เข้าไปในโฟลเดอร์ชื่อนี้เลย เช่น `target`, `node_modules`
+    #[must_use]
+    pub fn skip_dir(mut self, name: &str) -> Self {
+        self.skip_dirs.push(name.to_string());
+        self
+    }
+
+    /// ตัวกรองเพิ่มเติม - entry ต้องผ่านทุกตัว (ไม่มีผลต่อการลงไปในโฟลเดอร์)
+    #[must_use]
+    pub fn filter(mut self, predicate: impl Fn(&Entry) -> bool + 'static) -> Self {
+        self.filters.push(Box::new(predicate));
+        self
+    }
+
+    /// เดินทั้งต้นไม้แล้วคืน entry ที่ผ่านตัวกรอง เรียงตาม path
+    ///
+    /// # Errors
+    /// คืน error เมื่ออ่านโฟลเดอร์ราก หรือโฟลเดอร์ย่อยใดๆ ไม่ได้
+    pub fn walk(&self) -> io::Result<Vec<Entry>> {
+        let mut found = Vec::new();
+        let mut stack = vec![(self.root.clone(), 0_usize)];
+        while let Some((dir, depth)) = stack.pop() {
+            let mut children = Vec::new();
+            for item in fs::read_dir(&dir)? {
+                let item = item?;
+                let name = item.file_name().to_string_lossy().into_owned();
+                if !self.include_hidden && name.starts_with('.') {
+                    continue;
+                }
+                let metadata = fs::symlink_metadata(item.path())?;
+                let is_dir = metadata.is_dir();
+                if is_dir && self.skip_dirs.contains(&name) {
+                    continue;
+                }
+                children.push(Entry {
+                    path: item.path(),
+                    depth: depth + 1,
+                    is_dir,
+                    size: if is_dir { 0 } else { metadata.len() },
+                });
+            }
+
+            let can_descend = self.max_depth.is_none_or(|max| depth + 1 < max);
+            for entry in children {
+                if entry.is_dir && can_descend {
+                    stack.push((entry.path.clone(), entry.depth));
+                }
+                if self.accepts(&entry) {
+                    found.push(entry);
+                }
+            }
+        }
+        // เทียบ Path ทีละ component = ลำดับเดียวกับ `tree`/`find | sort`
+        found.sort_by(|a, b| a.path.cmp(&b.path));
+        Ok(found)
+    }
+
+    fn accepts(&self, entry: &Entry) -> bool {
+        if entry.is_dir {
+            return self.include_dirs && self.filters.iter().all(|keep| keep(entry));
+        }
+        let extension_ok = self.extensions.is_empty()
+            || entry.path.extension().is_some_and(|ext| {
+                self.extensions
+                    .contains(&ext.to_string_lossy().to_lowercase())
+            });
+        extension_ok && self.filters.iter().all(|keep| keep(entry))
+    }
+}
+
+/// 📦 รวมขนาดไฟล์ทั้งหมดใต้ `root` (ไม่รวมไฟล์ซ่อน)
+///
+/// # Errors
+/// คืน error เมื่ออ่านโฟลเดอร์ไม่ได้
+pub fn disk_usage(root: &Path) -> io::Result<u64> {
+    Ok(Walker::new(root)
+        .walk()?
+        .iter()
+        .map(|entry| entry.size)
+        .sum())
+}
+
+/// 🎪 เดโมการเดินโฟลเดอร์บนโปรเจกต์จำลองใน `dir`
+///
+/// # Errors
+/// คืน error แรกจากการอ่าน/เขียนไฟล์ใน `dir`
+pub fn demonstrate_dir_walk(dir: &Path) -> io::Result<()> {
+    let project = dir.join("project");
+    for (file, contents) in [
+        ("Cargo.toml", "[package]\nname = \"demo\"\n"),
+        ("src/main.rs", "fn main() {}\n"),
+        ("src/util/mod.rs", "pub fn help() {}\n"),
+        ("docs/guide.md", "# Guide\n"),
+        ("target/debug/demo", "binary"),
+        (".git/HEAD", "ref: refs/heads/main\n"),
+    ] {
+        let path = project.join(file);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, contents)?;
+    }
+
+    let rust_files = Walker::new(&project)
+        .extension("rs")
+        .skip_dir("target")
+        .walk()?;
+    println!("   🦀 ไฟล์ .rs ({} ไฟล์):", rust_files.len());
+    for entry in &rust_files {
+        let relative = entry.path.strip_prefix(&project).unwrap_or(&entry.path);
+        println!(
+            "      {}{}",
+            "  ".repeat(entry.depth - 1),
+            relative.display()
+        );
+    }
+
+    let top_level = Walker::new(&project)
+        .max_depth(1)
+        .include_dirs(true)
+        .walk()?;
+    let names: Vec<_> = top_level
+        .iter()
+        .filter_map(|entry| entry.path.file_name())
+        .map(|name| name.to_string_lossy().into_owned())
+        .collect();
+    println!("   📂 ชั้นบนสุด (ไม่รวม .git): {names:?}");
+    println!("   📦 ขนาดรวม: {} bytes", disk_usage(&project)?);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tree() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        for file in [
+            "a.txt",
+            "b.RS",
+            "nested/c.rs",
+            "nested/deeper/d.rs",
+            "target/e.rs",
+            ".hidden/f.rs",
+            ".g.rs",
+        ] {
+            let path = dir.path().join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(&path, file).unwrap();
+        }
+        dir
+    }
+
+    fn names(root: &Path, entries: &[Entry]) -> Vec<String> {
+        entries
+            .iter()
+            .map(|entry| {
+                entry
+                    .path
+                    .strip_prefix(root)
+                    .unwrap()
+                    .to_string_lossy()
+                    .replace('\\', "/")
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_walk_filters_by_extension_and_skips_hidden() {
+        let dir = tree();
+        let found = Walker::new(dir.path()).extension(".rs").walk().unwrap();
+        assert_eq!(
+            names(dir.path(), &found),
+            ["b.RS", "nested/c.rs", "nested/deeper/d.rs", "target/e.rs"]
+        );
+        assert_eq!(found[2].depth, 3);
+    }
+
+    #[test]
+    fn test_skip_dir_depth_and_hidden_options() {
+        let dir = tree();
+        let found = Walker::new(dir.path())
+            .skip_dir("target")
+            .max_depth(2)
+            .include_hidden(true)
+            .walk()
+            .unwrap();
+        assert_eq!(
+            names(dir.path(), &found),
+            [".g.rs", ".hidden/f.rs", "a.txt", "b.RS", "nested/c.rs"]
+        );
+    }
+
+    #[test]
+    fn test_custom_filter_and_dirs() {
+        let dir = tree();
+        let found = Walker::new(dir.path())
+            .include_dirs(true)
+            .filter(|entry| entry.is_dir || entry.size > 10)
+            .walk()
+            .unwrap();
+        assert_eq!(
+            names(dir.path(), &found),
+            [
+                "nested",
+                "nested/c.rs",
+                "nested/deeper",
+                "nested/deeper/d.rs",
+                "target",
+                "target/e.rs"
+            ]
+        );
+        assert_eq!(disk_usage(dir.path()).unwrap(), 5 + 4 + 11 + 18 + 11);
+    }
+
+    #[test]
+    fn test_missing_root_is_an_error() {
+        let dir = tempfile::tempdir().unwrap();
+        let error = Walker::new(dir.path().join("nope")).walk().unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::NotFound);
+    }
+}
@@ -0,0 +1,322 @@
+//! 🔐 File locking - กันสอง process เขียนไฟล์เดียวกันพร้อมกันด้วย lock file
+//!
+//! ใช้ `OpenOptions::create_new` ซึ่ง OS รับประกันว่าสร้างสำเร็จได้แค่ผู้เดียว
+//! (เทียบเท่า `O_CREAT | O_EXCL`) - ใครสร้าง `<ไฟล์>.lock` ได้ก่อนคือเจ้าของ lock
+//! แล้วเขียน pid ของตัวเองลงไปให้คนอื่นเห็นว่าใครถืออยู่
+//!
+//! ข้อดีคือพกพาได้ทุก OS และเห็นด้วยตาใน `ls` - ข้อเสียคือถ้า process ตายกลางทาง
+//! lock file จะค้าง จึงมี [`LockFile::acquire_breaking_stale`] ไว้ยึด lock ที่เก่าเกินกำหนด
+//! (ต่างจาก advisory lock ของ OS อย่าง `flock` ที่ปล่อยเองเมื่อ process จบ)
+
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
+
+/// ❌ เหตุที่ได้ lock ไม่สำเร็จ
+#[derive(Debug)]
+pub enum LockError {
+    /// มีคนถือ lock อยู่ (pid อ่านจาก lock file ถ้าอ่านได้)
+    Held {
+        path: PathBuf,
+        pid: Option<u32>,
+    },
+    /// รอครบเวลาแล้วยังไม่ว่าง
+    TimedOut {
+        path: PathBuf,
+        waited: Duration,
+    },
+    Io(io::Error),
+}
+
+impl fmt::Display for LockError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Held {
+                path,
+                pid: Some(pid),
+            } => {
+                write!(f, "{} is locked by pid {pid}", path.display())
+            }
+            Self::Held { path, pid: None } => write!(f, "{} is locked", path.display()),
+            Self::TimedOut { path, waited } => {
+                write!(f, "gave up waiting for {} after {waited:?}", path.display())
+            }
+            Self::Io(error) => write!(f, "lock file I/O failed: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for LockError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for LockError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+/// 🔒 lock ที่ถืออยู่ - ลบ lock file ให้เองเมื่อ drop (RAII แบบเดียวกับ `MutexGuard`)
+#[derive(Debug)]
+pub struct LockFile {
+    path: PathBuf,
+}
+
+/// path ของ lock file สำหรับ `target` (`data.json` → `data.json.lock`)
+#[must_use]
+pub fn lock_path(target: &Path) -> PathBuf {
+    super::paths::append_extension(target, "lock")
+}
+
+impl LockFile {
+    /// ลองยึด lock ของ `target` ครั้งเดียว - ไม่รอ
+    ///
+    /// # Errors
+    /// [`LockError::Held`] เมื่อมีคนถืออยู่ หรือ [`LockError::Io`] เมื่อสร้างไฟล์ไม่ได้
+    pub fn try_acquire(target: &Path) -> Result<Self, LockError> {
+        let path = lock_path(target);
+        match OpenOptions::new().write(true).create_new(true).open(&path) {
+            Ok(mut file) => {
+                // ถ้าเขียน pid ไม่สำเร็จก็ยังถือ lock อยู่ - pid เป็นแค่ข้อมูลประกอบ
+                let _ = write!(file, "{}", std::process::id());
+                Ok(Self { path })
+            }
+            Err(error) if error.kind() == io::ErrorKind::AlreadyExists => {
+                let pid = read_pid(&path);
+                Err(LockError::Held { path, pid })
+            }
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    /// วนลองทุก `poll` จนได้ lock หรือครบ `timeout`
+    ///
+    /// # Errors
+    /// [`LockError::TimedOut`] เมื่อรอครบเวลา หรือ [`LockError::Io`]
+    pub fn acquire_timeout(
+        target: &Path,
+        timeout: Duration,
+        poll: Duration,
+    ) -> Result<Self, LockError> {
+        let started = Instant::now();
+        loop {
+            match Self::try_acquire(target) {
+                Err(LockError::Held { path, .. }) => {
+                    let waited = started.elapsed();
+                    if waited >= timeout {
+                        return Err(LockError::TimedOut { path, waited });
+                    }
+                    thread::sleep(poll.min(timeout.saturating_sub(waited)));
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// ยึด lock - ถ้า lock file เก่ากว่า `max_age` ถือว่าเจ้าของตายไปแล้ว ลบทิ้งแล้วลองใหม่
+    ///
+    /// # Errors
+    /// [`LockError::Held`] เมื่อ lock ยังใหม่อยู่ หรือ [`LockError::Io`]
+    pub fn acquire_breaking_stale(target: &Path, max_age: Duration) -> Result<Self, LockError> {
+        match Self::try_acquire(target) {
+            Err(LockError::Held { path, pid }) => {
+                let age = fs::metadata(&path)?
+                    .modified()?
+                    .elapsed()
+                    .unwrap_or(Duration::ZERO);
+                if age < max_age {
+                    return Err(LockError::Held { path, pid });
+                }
+                match fs::remove_file(&path) {
+                    Ok(()) => {}
+                    // อีก process ลบไปก่อนแล้ว - ไม่เป็นไร แข่งกันสร้างใหม่ด้านล่าง
+                    Err(error) if error.kind() == io::ErrorKind::NotFound => {}
+                    Err(error) => return Err(error.into()),
+                }
+                Self::try_acquire(target)
+            }
+            result => result,
+        }
+    }
+
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// ต่ออายุ lock (อัปเดต mtime) สำหรับงานยาวที่ไม่อยากโดนมองว่าค้าง
+    ///
+    /// # Errors
+    /// คืน error เมื่อเปิด lock file ไม่ได้ (เช่น ถูกลบไปแล้ว)
+    pub fn refresh(&self) -> io::Result<()> {
+        File::options()
+            .write(true)
+            .open(&self.path)?
+            .set_modified(SystemTime::now())
+    }
+}
+
+impl Drop for LockFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+fn read_pid(path: &Path) -> Option<u32> {
+    let mut text = String::new();
+    File::open(path).ok()?.read_to_string(&mut text).ok()?;
+    text.trim().parse().ok()
+}
+
+/// 🎪 เดโม lock file กับหลาย thread ที่แย่งกันต่อท้ายไฟล์เดียวกัน
+///
+/// # Errors
+/// คืน error แรกจากการอ่าน/เขียนไฟล์ใน `dir`
+///
+/// # Panics
+/// เมื่อ worker thread panic
+pub fn demonstrate_file_lock(dir: &Path) -> io::Result<()> {
+    let ledger = dir.join("ledger.txt");
+    fs::write(&ledger, "")?;
+
+    let first = LockFile::try_acquire(&ledger).map_err(io::Error::other)?;
+    println!("   🔒 ได้ lock: {}", first.path().display());
+    if let Err(error) = LockFile::try_acquire(&ledger) {
+        println!("   ⛔ คนที่สองโดนปฏิเสธ: {error}");
+    }
+    drop(first);
+
+    let workers: Vec<_> = (0..4)
+        .map(|worker| {
+            let ledger = ledger.clone();
+            thread::spawn(move || -> Result<(), LockError> {
+                for entry in 0..5 {
+                    let _lock = LockFile::acquire_timeout(
+                        &ledger,
+                        Duration::from_secs(5),
+                        Duration::from_millis(1),
+                    )?;
+                    // read-modify-write: ถ้าไม่มี lock สอง thread อาจอ่านค่าเดิมแล้วเขียนทับกัน
+                    let text = fs::read_to_string(&ledger)?;
+                    fs::write(&ledger, format!("{text}worker {worker} entry {entry}\n"))?;
+                }
+                Ok(())
+            })
+        })
+        .collect();
+    for worker in workers {
+        worker
+            .join()
+            .expect("worker panicked")
+            .map_err(io::Error::other)?;
+    }
+    let lines = fs::read_to_string(&ledger)?.lines().count();
+    println!("   📒 4 threads × 5 รายการ → ledger มี {lines} บรรทัด (ไม่มีรายการหาย)");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_second_acquire_fails_until_drop() {
+        let dir = tempfile::tempdir().unwrap();
+        let target = dir.path().join("db.json");
+
+        let lock = LockFile::try_acquire(&target).unwrap();
+        assert!(lock.path().exists());
+        match LockFile::try_acquire(&target) {
+            Err(LockError::Held { pid, .. }) => assert_eq!(pid, Some(std::process::id())),
+            other => panic!("expected Held, got {other:?}"),
+        }
+
+        drop(lock);
+        assert!(!lock_path(&target).exists());
+        assert!(LockFile::try_acquire(&target).is_ok());
+    }
+
+    #[test]
+    fn test_acquire_timeout_gives_up() {
+        let dir = tempfile::tempdir().unwrap();
+        let target = dir.path().join("db.json");
+        let _held = LockFile::try_acquire(&target).unwrap();
+
+        let error =
+            LockFile::acquire_timeout(&target, Duration::from_millis(30), Duration::from_millis(5))
+                .unwrap_err();
+        assert!(
+            matches!(error, LockError::TimedOut { waited, .. } if waited >= Duration::from_millis(30))
+        );
+    }
+
+    #[test]
+    fn test_stale_lock_is_broken() {
+        let dir = tempfile::tempdir().unwrap();
+        let target = dir.path().join("db.json");
+        // lock file ที่ process อื่นทิ้งไว้เมื่อชั่วโมงก่อน
+        let stale = lock_path(&target);
+        fs::write(&stale, "999999").unwrap();
+        File::options()
+            .write(true)
+            .open(&stale)
+            .unwrap()
+            .set_modified(SystemTime::now() - Duration::from_secs(3600))
+            .unwrap();
+
+        assert!(matches!(
+            LockFile::acquire_breaking_stale(&target, Duration::from_secs(7200)),
+            Err(LockError::Held {
+                pid: Some(999_999),
+                ..
+            })
+        ));
+        let lock = LockFile::acquire_breaking_stale(&target, Duration::from_secs(60)).unwrap();
+        lock.refresh().unwrap();
+        assert_eq!(read_pid(lock.path()), Some(std::process::id()));
+    }
+
+    #[test]
+    fn test_lock_gives_mutual_exclusion_across_threads() {
+        let dir = tempfile::tempdir().unwrap();
+        let target = Arc::new(dir.path().join("counter"));
+        let inside = Arc::new(AtomicUsize::new(0));
+
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let target = Arc::clone(&target);
+                let inside = Arc::clone(&inside);
+                thread::spawn(move || {
+                    for _ in 0..10 {
+                        let _lock = LockFile::acquire_timeout(
+                            &target,
+                            Duration::from_secs(10),
+                            Duration::from_millis(1),
+                        )
+                        .unwrap();
+                        assert_eq!(
+                            inside.fetch_add(1, Ordering::SeqCst),
+                            0,
+                            "two holders at once"
+                        );
+                        thread::sleep(Duration::from_micros(200));
+                        inside.fetch_sub(1, Ordering::SeqCst);
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+    }
+}
@@ -0,0 +1,487 @@
+//! 👀 File watcher - เฝ้าไฟล์/โฟลเดอร์แบบ polling แล้วส่ง event เมื่อมีการเปลี่ยนแปลง
+//!
+//! ทุกรอบ `poll()` จะถ่าย snapshot ของไฟล์ที่เฝ้า (ขนาด + mtime + hash ของเนื้อหา)
+//! แล้วเทียบกับรอบก่อนเพื่อหาไฟล์ที่ Created / Modified / Deleted
+//!
+//! ⏳ Debounce: editor หลายตัวเซฟไฟล์เป็นหลายจังหวะ (truncate → write → rename)
+//! watcher จึงพักการเปลี่ยนแปลงไว้จนไฟล์นั้นนิ่งครบ `debounce` แล้วค่อยส่ง event เดียว
+//! ที่สรุปแล้ว เช่น สร้างแล้วแก้ = `Created`, สร้างแล้วลบ = ไม่มี event เลย
+//!
+//! เวลาของ debounce อ่านจาก [`Clock`] ที่ฉีดเข้ามา - tests ใช้ `MockClock` แล้ว `poll()` เอง
+//! ส่วนของจริงใช้ [`FileWatcher::spawn`] ให้ thread พื้นหลัง poll ทุก `interval`
+//!
+//! ทำไมไม่ใช้ inotify/FSEvents? ได้ event ทันทีกว่าจริง แต่ API ต่างกันทุก OS
+//! และพลาดได้บน network filesystem - polling ช้ากว่าแต่ทำงานเหมือนกันทุกที่
+
+use std::collections::BTreeMap;
+use std::collections::hash_map::DefaultHasher;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, mpsc};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant, SystemTime};
+
+use super::dir_walk::Walker;
+use crate::clock::{Clock, system_clock};
+use crate::logger;
+
+/// ไฟล์ที่ใหญ่กว่านี้ดูแค่ขนาด + mtime (ไม่อ่านเนื้อหามา hash ทุกรอบ)
+pub const HASH_LIMIT: u64 = 1024 * 1024;
+
+/// 🔔 ชนิดของการเปลี่ยนแปลง
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChangeKind {
+    Created,
+    Modified,
+    Deleted,
+}
+
+/// 📣 event ที่ส่งออกจาก watcher
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileEvent {
+    pub kind: ChangeKind,
+    pub path: PathBuf,
+}
+
+/// 🧬 ลายนิ้วมือของไฟล์ - ต่างกัน = ไฟล์เปลี่ยน
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fingerprint {
+    pub len: u64,
+    pub modified: Option<SystemTime>,
+    /// hash ของเนื้อหา - กันกรณีเขียนทับในวินาทีเดียวกันด้วยขนาดเท่าเดิม
+    /// (mtime ของบาง filesystem ละเอียดแค่ 1-2 วินาที)
+    pub content_hash: Option<u64>,
+}
+
+impl Fingerprint {
+    /// อ่านลายนิ้วมือของ `path` - `Ok(None)` เมื่อไม่มีไฟล์
+    ///
+    /// # Errors
+    /// คืน error อื่นที่ไม่ใช่ `NotFound` จากการอ่าน metadata/เนื้อหา
+    pub fn of(path: &Path) -> io::Result<Option<Self>> {
+        let metadata = match fs::metadata(path) {
+            Ok(metadata) => metadata,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(error) => return Err(error),
+        };
+        let content_hash = if metadata.len() <= HASH_LIMIT {
+            match fs::read(path) {
+                Ok(bytes) => {
+                    let mut hasher = DefaultHasher::new();
+                    bytes.hash(&mut hasher);
+                    Some(hasher.finish())
+                }
+                // ถูกลบระหว่าง metadata กับ read - ถือว่าไม่มีไฟล์
+                Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(None),
+                Err(error) => return Err(error),
+            }
+        } else {
+            None
+        };
+        Ok(Some(Self {
+            len: metadata.len(),
+            modified: metadata.modified().ok(),
+            content_hash,
+        }))
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Pending {
+    kind: ChangeKind,
+    last_change: Instant,
+}
+
+/// รวม event เดิมที่ยังค้างกับ event ใหม่ของไฟล์เดียวกัน - `None` = หักล้างกันหมด
+const fn merge(pending: ChangeKind, next: ChangeKind) -> Option<ChangeKind> {
+    match (pending, next) {
+        (ChangeKind::Created, ChangeKind::Modified) => Some(ChangeKind::Created),
+        (ChangeKind::Created, ChangeKind::Deleted) => None,
+        (ChangeKind::Deleted, ChangeKind::Created) => Some(ChangeKind::Modified),
+        (_, next) => Some(next),
+    }
+}
+
+/// 👀 ตัวเฝ้าไฟล์แบบ polling
+#[derive(Debug)]
+pub struct FileWatcher {
+    targets: Vec<PathBuf>,
+    snapshot: BTreeMap<PathBuf, Fingerprint>,
+    pending: BTreeMap<PathBuf, Pending>,
+    debounce: Duration,
+    clock: Arc<dyn Clock>,
+}
+
+impl FileWatcher {
+    /// watcher ที่รอให้ไฟล์นิ่ง `debounce` ก่อนส่ง event (`Duration::ZERO` = ส่งทันที)
+    #[must_use]
+    pub fn new(debounce: Duration) -> Self {
+        Self {
+            targets: Vec::new(),
+            snapshot: BTreeMap::new(),
+            pending: BTreeMap::new(),
+            debounce,
+            clock: system_clock(),
+        }
+    }
+
+    /// ใช้นาฬิกาที่ฉีดเข้ามาแทนเวลาจริง
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// เพิ่มไฟล์หรือโฟลเดอร์ (เฝ้าทุกไฟล์ข้างในแบบ recursive) - ยังไม่มีอยู่ก็ได้
+    ///
+    /// ไฟล์ที่มีอยู่แล้วตอนเรียกถูกจำเป็น snapshot แรก จึงไม่เกิด `Created` ปลอม
+    ///
+    /// # Errors
+    /// คืน error เมื่ออ่านไฟล์/โฟลเดอร์ที่มีอยู่ไม่ได้
+    pub fn watch(&mut self, path: impl Into<PathBuf>) -> io::Result<()> {
+        let path = path.into();
+        for (file, fingerprint) in scan_target(&path)? {
+            self.snapshot.insert(file, fingerprint);
+        }
+        self.targets.push(path);
+        Ok(())
+    }
+
+    /// path ที่เฝ้าอยู่ตามลำดับที่เพิ่ม
+    #[must_use]
+    pub fn targets(&self) -> &[PathBuf] {
+        &self.targets
+    }
+
+    /// ยังมีการเปลี่ยนแปลงที่รอให้ครบ debounce อยู่หรือไม่
+    #[must_use]
+    pub fn has_pending(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    /// สแกนหนึ่งรอบ แล้วคืน event ของไฟล์ที่นิ่งครบ debounce แล้ว (เรียงตาม path)
+    ///
+    /// # Errors
+    /// คืน error เมื่ออ่านไฟล์/โฟลเดอร์ที่เฝ้าอยู่ไม่ได้ - snapshot เดิมยังอยู่ รอบหน้าลองใหม่
+    pub fn poll(&mut self) -> io::Result<Vec<FileEvent>> {
+        let mut current = BTreeMap::new();
+        for target in &self.targets {
+            current.extend(scan_target(target)?);
+        }
+        let now = self.clock.now();
+
+        let mut changes = Vec::new();
+        for (path, fingerprint) in &current {
+            match self.snapshot.get(path) {
+                None => changes.push((path.clone(), ChangeKind::Created)),
+                Some(previous) if previous != fingerprint => {
+                    changes.push((path.clone(), ChangeKind::Modified));
+                }
+                Some(_) => {}
+            }
+        }
+        for path in self.snapshot.keys() {
+            if !current.contains_key(path) {
+                changes.push((path.clone(), ChangeKind::Deleted));
+            }
+        }
+        self.snapshot = current;
+
+        for (path, kind) in changes {
+            let merged = self
+                .pending
+                .get(&path)
+                .map_or(Some(kind), |pending| merge(pending.kind, kind));
+            match merged {
+                Some(kind) => {
+                    self.pending.insert(
+                        path,
+                        Pending {
+                            kind,
+                            last_change: now,
+                        },
+                    );
+                }
+                None => {
+                    self.pending.remove(&path);
+                }
+            }
+        }
+
+        let mut ready = Vec::new();
+        self.pending.retain(|path, pending| {
+            if now.saturating_duration_since(pending.last_change) < self.debounce {
+                return true;
+            }
+            ready.push(FileEvent {
+                kind: pending.kind,
+                path: path.clone(),
+            });
+            false
+        });
+        Ok(ready)
+    }
+
+    /// ให้ thread พื้นหลัง poll ทุก `interval` แล้วเรียก `on_events` เมื่อมี event
+    ///
+    /// error ระหว่าง poll ถูก log เป็น warning แล้วลองใหม่รอบหน้า
+    /// หยุดเมื่อ [`WatchHandle`] ถูก drop
+    #[must_use]
+    pub fn spawn_with<F>(mut self, interval: Duration, mut on_events: F) -> WatchHandle
+    where
+        F: FnMut(Vec<FileEvent>) + Send + 'static,
+    {
+        let stop = Arc::new(AtomicBool::new(false));
+        let stopped = Arc::clone(&stop);
+        let handle = thread::spawn(move || {
+            while !stopped.load(Ordering::SeqCst) {
+                thread::park_timeout(interval);
+                match self.poll() {
+                    Ok(events) if events.is_empty() => {}
+                    Ok(events) => on_events(events),
+                    Err(e) => {
+                        logger::warn!(target: "file_watcher", targets = self.targets.len(); "poll failed: {e}");
+                    }
+                }
+            }
+        });
+        WatchHandle {
+            stop,
+            handle: Some(handle),
+        }
+    }
+
+    /// เหมือน [`FileWatcher::spawn_with`] แต่ส่ง event ทีละตัวผ่าน channel
+    #[must_use]
+    pub fn spawn(self, interval: Duration) -> (WatchHandle, mpsc::Receiver<FileEvent>) {
+        let (sender, receiver) = mpsc::channel();
+        let handle = self.spawn_with(interval, move |events| {
+            for event in events {
+                // ผู้รับเลิกฟังแล้วก็แค่ทิ้ง event - handle เป็นตัวกำหนดอายุของ thread
+                let _ = sender.send(event);
+            }
+        });
+        (handle, receiver)
+    }
+}
+
+/// ลายนิ้วมือของทุกไฟล์ใต้ `target` (หรือของ `target` เองถ้าเป็นไฟล์)
+fn scan_target(target: &Path) -> io::Result<Vec<(PathBuf, Fingerprint)>> {
+    if !target.is_dir() {
+        return Ok(Fingerprint::of(target)?
+            .map(|fingerprint| (target.to_path_buf(), fingerprint))
+            .into_iter()
+            .collect());
+    }
+    let entries = match Walker::new(target).include_hidden(true).walk() {
+        Ok(entries) => entries,
+        // โฟลเดอร์ถูกลบระหว่างสแกน = ไม่มีไฟล์
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(error) => return Err(error),
+    };
+    let mut files = Vec::with_capacity(entries.len());
+    for entry in entries {
+        if let Some(fingerprint) = Fingerprint::of(&entry.path)? {
+            files.push((entry.path, fingerprint));
+        }
+    }
+    Ok(files)
+}
+
+/// 🧵 thread ของ watcher - drop แล้วหยุดเฝ้า
+#[derive(Debug)]
+pub struct WatchHandle {
+    stop: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl Drop for WatchHandle {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::SeqCst);
+        if let Some(handle) = self.handle.take() {
+            handle.thread().unpark();
+            let _ = handle.join();
+        }
+    }
+}
+
+/// 🎪 เดโม watcher แบบ poll เองบนโฟลเดอร์ใน `dir`
+///
+/// # Errors
+/// คืน error แรกจากการอ่าน/เขียนไฟล์ใน `dir`
+pub fn demonstrate_file_watcher(dir: &Path) -> io::Result<()> {
+    let folder = dir.join("folder");
+    fs::create_dir_all(&folder)?;
+    fs::write(folder.join("existing.txt"), "v1")?;
+
+    let mut watcher = FileWatcher::new(Duration::ZERO);
+    watcher.watch(&folder)?;
+
+    fs::write(folder.join("new.txt"), "hello")?;
+    fs::write(folder.join("existing.txt"), "v2")?;
+    for event in watcher.poll()? {
+        println!("   🔔 {:?} {}", event.kind, event.path.display());
+    }
+    fs::remove_file(folder.join("new.txt"))?;
+    for event in watcher.poll()? {
+        println!("   🔔 {:?} {}", event.kind, event.path.display());
+    }
+
+    let mut debounced = FileWatcher::new(Duration::from_millis(30));
+    debounced.watch(&folder)?;
+    let (handle, events) = debounced.spawn(Duration::from_millis(5));
+    for round in 0..5 {
+        fs::write(folder.join("burst.txt"), format!("save {round}"))?;
+        thread::sleep(Duration::from_millis(2));
+    }
+    let received: Vec<_> = events
+        .recv_timeout(Duration::from_secs(2))
+        .into_iter()
+        .collect();
+    drop(handle);
+    println!(
+        "   ⏳ เซฟติดกัน 5 ครั้งใน ~10ms → debounce 30ms ได้ {} event: {:?}",
+        received.len(),
+        received.iter().map(|event| event.kind).collect::<Vec<_>>()
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::MockClock;
+
+    fn watcher(dir: &Path, debounce: Duration) -> (FileWatcher, MockClock) {
+        let clock = MockClock::new();
+        let mut watcher = FileWatcher::new(debounce).with_clock(Arc::new(clock.clone()));
+        watcher.watch(dir).unwrap();
+        (watcher, clock)
+    }
+
+    fn kinds(events: &[FileEvent], root: &Path) -> Vec<(ChangeKind, String)> {
+        events
+            .iter()
+            .map(|event| {
+                let name = event.path.strip_prefix(root).unwrap();
+                (event.kind, name.to_string_lossy().replace('\\', "/"))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_detects_created_modified_deleted() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("keep.txt"), "a").unwrap();
+        fs::write(dir.path().join("gone.txt"), "b").unwrap();
+        let (mut watcher, _clock) = watcher(dir.path(), Duration::ZERO);
+        assert!(
+            watcher.poll().unwrap().is_empty(),
+            "no events for files that already existed"
+        );
+
+        fs::create_dir(dir.path().join("sub")).unwrap();
+        fs::write(dir.path().join("sub/new.txt"), "c").unwrap();
+        // ขนาดเท่าเดิม + mtime อาจเท่าเดิม - ต้องจับได้จาก hash ของเนื้อหา
+        fs::write(dir.path().join("keep.txt"), "z").unwrap();
+        fs::remove_file(dir.path().join("gone.txt")).unwrap();
+
+        let events = watcher.poll().unwrap();
+        assert_eq!(
+            kinds(&events, dir.path()),
+            [
+                (ChangeKind::Deleted, "gone.txt".to_string()),
+                (ChangeKind::Modified, "keep.txt".to_string()),
+                (ChangeKind::Created, "sub/new.txt".to_string()),
+            ]
+        );
+        assert!(watcher.poll().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_debounce_waits_for_quiet_period() {
+        let dir = tempfile::tempdir().unwrap();
+        let (mut watcher, clock) = watcher(dir.path(), Duration::from_millis(100));
+        let file = dir.path().join("config.toml");
+
+        fs::write(&file, "a = 1").unwrap();
+        assert!(watcher.poll().unwrap().is_empty());
+        clock.advance(Duration::from_millis(60));
+        fs::write(&file, "a = 2").unwrap();
+        assert!(watcher.poll().unwrap().is_empty());
+
+        // 60ms หลังการแก้ล่าสุด - ยังไม่ครบ 100ms
+        clock.advance(Duration::from_millis(60));
+        assert!(watcher.poll().unwrap().is_empty());
+        assert!(watcher.has_pending());
+
+        clock.advance(Duration::from_millis(40));
+        let events = watcher.poll().unwrap();
+        assert_eq!(
+            kinds(&events, dir.path()),
+            [(ChangeKind::Created, "config.toml".to_string())]
+        );
+        assert!(!watcher.has_pending());
+    }
+
+    #[test]
+    fn test_debounce_merges_changes_that_cancel_out() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("swap.txt"), "old").unwrap();
+        let (mut watcher, clock) = watcher(dir.path(), Duration::from_millis(50));
+
+        fs::write(dir.path().join("tmp.txt"), "x").unwrap();
+        fs::remove_file(dir.path().join("swap.txt")).unwrap();
+        watcher.poll().unwrap();
+        fs::remove_file(dir.path().join("tmp.txt")).unwrap();
+        fs::write(dir.path().join("swap.txt"), "new").unwrap();
+        watcher.poll().unwrap();
+
+        clock.advance(Duration::from_millis(50));
+        let events = watcher.poll().unwrap();
+        assert_eq!(
+            kinds(&events, dir.path()),
+            [(ChangeKind::Modified, "swap.txt".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_single_file_target_may_not_exist_yet() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("later.toml");
+        let (mut watcher, _clock) = watcher(&file, Duration::ZERO);
+        assert!(watcher.poll().unwrap().is_empty());
+
+        fs::write(&file, "x").unwrap();
+        fs::write(dir.path().join("ignored.toml"), "y").unwrap();
+        let events = watcher.poll().unwrap();
+        assert_eq!(
+            events,
+            [FileEvent {
+                kind: ChangeKind::Created,
+                path: file.clone()
+            }]
+        );
+
+        fs::remove_file(&file).unwrap();
+        assert_eq!(watcher.poll().unwrap()[0].kind, ChangeKind::Deleted);
+    }
+
+    #[test]
+    fn test_spawned_watcher_sends_events_over_channel() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut watcher = FileWatcher::new(Duration::ZERO);
+        watcher.watch(dir.path()).unwrap();
+        let (handle, events) = watcher.spawn(Duration::from_millis(2));
+
+        fs::write(dir.path().join("ping.txt"), "pong").unwrap();
+        let event = events.recv_timeout(Duration::from_secs(5)).unwrap();
+        assert_eq!(event.kind, ChangeKind::Created);
+
+        drop(handle);
+        assert!(events.recv_timeout(Duration::from_millis(20)).is_err());
+    }
+}
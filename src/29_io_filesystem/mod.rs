@@ -0,0 +1,63 @@
+//! 📁 I/O & File System Module - อ่านเขียนไฟล์แบบมืออาชีพ! 💾
+//! โมดูลสำหรับเรียนรู้การทำงานกับไฟล์และโฟลเดอร์ด้วย `std::fs`, `std::io` และ `std::path`
+//!
+//! 🎯 สิ่งที่จะได้เรียนรู้:
+//! - 📖 Buffered I/O - `BufReader`/`BufWriter` และการอ่านไฟล์ใหญ่ทีละบรรทัด
+//! - 🧭 Paths - normalize/relative path และเขียนไฟล์แบบ atomic ผ่าน temp file
+//! - 🌲 Directory walking - ไล่โฟลเดอร์พร้อมกรองนามสกุล/ความลึก/ไฟล์ซ่อน
+//! - 🔐 File locking - lock file กันหลาย process เขียนทับกัน
+//! - 📊 CSV - parser/writer ตาม RFC 4180 แบบ streaming พร้อม map header เข้า struct ด้วย serde
+//! - 👀 File watcher - เฝ้าไฟล์แบบ polling พร้อม debounce (ใช้ hot reload config/feature flags)
+//!
+//! 🧪 เดโมและ tests ทำงานใน `tempfile::tempdir()` เสมอ - ไม่แตะไฟล์จริงของเครื่อง
+
+pub mod buffered_io;
+pub mod csv;
+pub mod dir_walk;
+pub mod file_lock;
+pub mod file_watcher;
+pub mod paths;
+
+pub use buffered_io::*;
+pub use csv::*;
+pub use dir_walk::*;
+pub use file_lock::*;
+pub use file_watcher::*;
+pub use paths::*;
+
+use std::io;
+use std::path::Path;
+
+type Demo = fn(&Path) -> io::Result<()>;
+
+/// 🎪 เรียกใช้ตัวอย่าง I/O & File System ทั้งหมด
+pub fn run_io_filesystem_examples() {
+    println!("\n📁 === I/O & File System: จัดการไฟล์แบบมืออาชีพ === 💾");
+
+    let dir = match tempfile::tempdir() {
+        Ok(dir) => dir,
+        Err(error) => {
+            println!("❌ สร้างโฟลเดอร์ชั่วคราวไม่ได้: {error}");
+            return;
+        }
+    };
+    println!("📂 ทำงานใน {}\n", dir.path().display());
+
+    let sections: [(&str, Demo); 6] = [
+        ("📖 Buffered I/O:", demonstrate_buffered_io),
+        ("🧭 Paths & temp files:", demonstrate_paths),
+        ("🌲 Directory walking:", demonstrate_dir_walk),
+        ("🔐 File locking:", demonstrate_file_lock),
+        ("📊 CSV:", demonstrate_csv),
+        ("👀 File watcher:", demonstrate_file_watcher),
+    ];
+    for (title, demo) in sections {
+        println!("{title}");
+        if let Err(error) = demo(dir.path()) {
+            println!("   ❌ {error}");
+        }
+        println!();
+    }
+
+    println!("✅ ไฟล์ทั้งหมดถูกลบเมื่อ TempDir ถูก drop");
+}
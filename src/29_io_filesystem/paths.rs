@@ -0,0 +1,212 @@
+//! 🧭 Paths & temp files - จัดการ path แบบไม่แตะ disk และเขียนไฟล์ผ่านไฟล์ชั่วคราว
+//!
+//! `Path`/`PathBuf` คือ `str`/`String` ของระบบไฟล์: ไม่จำเป็นต้องเป็น UTF-8
+//! และแยกส่วนด้วย `components()` ซึ่งรู้จัก `/` ของ Unix และ `C:\` ของ Windows เอง
+//!
+//! ฟังก์ชันในไฟล์นี้ทำงานกับ "ตัวอักษรของ path" ล้วนๆ (lexical) - ต่างจาก
+//! `fs::canonicalize` ที่ต้องให้ไฟล์มีอยู่จริงและตาม symlink ด้วย
+
+use std::ffi::OsString;
+use std::io::{self, Write};
+use std::path::{Component, Path, PathBuf};
+
+use tempfile::NamedTempFile;
+
+/// ยุบ `.` และ `..` ออกจาก path โดยไม่อ่าน disk
+///
+/// `..` ที่เกินราก (`/..`) ถูกทิ้ง ส่วน `..` นำหน้า path แบบ relative ถูกเก็บไว้
+#[must_use]
+pub fn normalize(path: &Path) -> PathBuf {
+    let mut parts: Vec<Component<'_>> = Vec::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => match parts.last() {
+                Some(Component::Normal(_)) => {
+                    parts.pop();
+                }
+                Some(Component::RootDir | Component::Prefix(_)) => {}
+                _ => parts.push(component),
+            },
+            other => parts.push(other),
+        }
+    }
+    if parts.is_empty() {
+        return PathBuf::from(".");
+    }
+    parts.iter().collect()
+}
+
+/// path ของ `path` เมื่อมองจาก `base` (เช่น `/a/b/c` จาก `/a/x` = `../b/c`)
+///
+/// คืน `None` เมื่อ path หนึ่งเป็น absolute แต่อีกอันไม่ใช่ หรืออยู่คนละ drive
+#[must_use]
+pub fn relative_to(path: &Path, base: &Path) -> Option<PathBuf> {
+    let path = normalize(path);
+    let base = normalize(base);
+    if path.has_root() != base.has_root() {
+        return None;
+    }
+    let mut path_parts = path.components().peekable();
+    let mut base_parts = base.components().peekable();
+    while let (Some(a), Some(b)) = (path_parts.peek(), base_parts.peek()) {
+        if a != b {
+            break;
+        }
+        path_parts.next();
+        base_parts.next();
+    }
+    let mut relative = PathBuf::new();
+    for part in base_parts {
+        match part {
+            Component::Normal(_) => relative.push(".."),
+            Component::CurDir => {}
+            // เหลือ prefix/root หรือ `..` ที่ไม่รู้ว่าชี้ไปไหน = เทียบกันไม่ได้
+            _ => return None,
+        }
+    }
+    relative.extend(path_parts);
+    if relative.as_os_str().is_empty() {
+        relative.push(".");
+    }
+    Some(relative)
+}
+
+/// ต่อนามสกุลเพิ่ม (`report.tar` + `gz` = `report.tar.gz`) ต่างจาก `with_extension` ที่แทนของเดิม
+#[must_use]
+pub fn append_extension(path: &Path, extension: &str) -> PathBuf {
+    let mut name: OsString = path.file_name().map(OsString::from).unwrap_or_default();
+    name.push(".");
+    name.push(extension);
+    path.with_file_name(name)
+}
+
+/// 📁 แยกส่วนประกอบของ path ไว้แสดงผลในเดโม
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PathParts {
+    pub parent: Option<PathBuf>,
+    pub file_name: Option<String>,
+    pub stem: Option<String>,
+    pub extension: Option<String>,
+    pub is_absolute: bool,
+}
+
+#[must_use]
+pub fn describe(path: &Path) -> PathParts {
+    let text = |part: Option<&std::ffi::OsStr>| part.map(|s| s.to_string_lossy().into_owned());
+    PathParts {
+        parent: path.parent().map(Path::to_path_buf),
+        file_name: text(path.file_name()),
+        stem: text(path.file_stem()),
+        extension: text(path.extension()),
+        is_absolute: path.is_absolute(),
+    }
+}
+
+/// 💾 เขียนไฟล์แบบ atomic: เขียนลงไฟล์ชั่วคราวในโฟลเดอร์เดียวกัน แล้ว rename ทับ
+///
+/// ผู้อ่านจะเห็นไฟล์เก่าหรือไฟล์ใหม่ทั้งก้อนเท่านั้น ไม่มีวันเห็นไฟล์ที่เขียนค้างครึ่งทาง
+/// (rename ข้าม filesystem ไม่ atomic - จึงต้องสร้างไฟล์ชั่วคราวข้างๆ ปลายทาง)
+///
+/// # Errors
+/// คืน error เมื่อสร้าง/เขียน/rename ไม่สำเร็จ - ไฟล์ชั่วคราวถูกลบเองเมื่อ error
+pub fn write_atomic(path: &Path, contents: &[u8]) -> io::Result<()> {
+    let dir = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    let mut temp = NamedTempFile::new_in(dir)?;
+    temp.write_all(contents)?;
+    temp.as_file().sync_all()?;
+    temp.persist(path).map_err(|error| error.error)?;
+    Ok(())
+}
+
+/// 🎪 เดโม path และ temp file ใน `dir`
+///
+/// # Errors
+/// คืน error แรกจากการอ่าน/เขียนไฟล์ใน `dir`
+pub fn demonstrate_paths(dir: &Path) -> io::Result<()> {
+    let messy = Path::new("/srv/app/./logs/../config/settings.toml");
+    println!(
+        "   🧹 normalize {} → {}",
+        messy.display(),
+        normalize(messy).display()
+    );
+    println!("   🔎 {:?}", describe(&normalize(messy)));
+
+    let from = Path::new("/srv/app/logs");
+    if let Some(relative) = relative_to(&normalize(messy), from) {
+        println!("   ↪️ มองจาก {} = {}", from.display(), relative.display());
+    }
+    println!(
+        "   ➕ append_extension(backup.tar, gz) = {}",
+        append_extension(Path::new("backup.tar"), "gz").display()
+    );
+
+    let settings = dir.join("settings.toml");
+    write_atomic(&settings, b"debug = false\n")?;
+    write_atomic(&settings, b"debug = true\n")?;
+    println!(
+        "   💾 write_atomic 2 ครั้ง → {:?}",
+        std::fs::read_to_string(&settings)?.trim()
+    );
+
+    let mut scratch = NamedTempFile::new_in(dir)?;
+    writeln!(scratch, "ไฟล์นี้หายเองเมื่อ drop")?;
+    println!("   🗑️ NamedTempFile ชั่วคราว: {}", scratch.path().display());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_collapses_dots() {
+        assert_eq!(normalize(Path::new("a/./b/../c")), PathBuf::from("a/c"));
+        assert_eq!(
+            normalize(Path::new("/../etc//passwd")),
+            PathBuf::from("/etc/passwd")
+        );
+        assert_eq!(normalize(Path::new("../../x/..")), PathBuf::from("../.."));
+        assert_eq!(normalize(Path::new("a/..")), PathBuf::from("."));
+    }
+
+    #[test]
+    fn test_relative_to() {
+        let relative = |path: &str, base: &str| relative_to(Path::new(path), Path::new(base));
+        assert_eq!(relative("/a/b/c", "/a/x"), Some(PathBuf::from("../b/c")));
+        assert_eq!(relative("/a/b", "/a/b"), Some(PathBuf::from(".")));
+        assert_eq!(
+            relative("src/lib.rs", "."),
+            Some(PathBuf::from("src/lib.rs"))
+        );
+        assert_eq!(relative("/a", "relative"), None);
+        assert_eq!(relative("x", "../y"), None);
+    }
+
+    #[test]
+    fn test_append_extension_and_describe() {
+        assert_eq!(
+            append_extension(Path::new("dir/data.csv"), "bak"),
+            PathBuf::from("dir/data.csv.bak")
+        );
+        let parts = describe(Path::new("/tmp/archive.tar.gz"));
+        assert_eq!(parts.stem.as_deref(), Some("archive.tar"));
+        assert_eq!(parts.extension.as_deref(), Some("gz"));
+        assert!(parts.is_absolute);
+    }
+
+    #[test]
+    fn test_write_atomic_replaces_without_leftovers() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("state.json");
+        write_atomic(&path, b"{\"v\":1}").unwrap();
+        write_atomic(&path, b"{\"v\":2}").unwrap();
+
+        assert_eq!(std::fs::read(&path).unwrap(), b"{\"v\":2}");
+        let files: Vec<_> = std::fs::read_dir(dir.path()).unwrap().collect();
+        assert_eq!(files.len(), 1, "temp file must be renamed, not left behind");
+    }
+}
@@ -0,0 +1,190 @@
+//! 🔐 `ChaCha20` CSPRNG - ใช้ block function ของ stream cipher `ChaCha20` (RFC 8439) เป็นเลขสุ่ม
+//!
+//! key 256 bit คือ seed และ counter 64 bit นับ block ไปเรื่อย ๆ: แต่ละ block ได้เลข 16 × 32 bit
+//! เดาเลขถัดไปจากเลขที่เห็นแล้วไม่ได้ถ้าไม่รู้ key - นี่คือสิ่งที่ทำให้ "cryptographically secure"
+//!
+//! ⚠️ เขียนเพื่อเรียน: ไม่ reseed และไม่ลบ key ออกจากหน่วยความจำ - งานจริงใช้ `rand_chacha`/`getrandom`
+
+use super::rng::{Rng, SeedableRng};
+
+/// "expand 32-byte k" แบบ little-endian
+const CONSTANTS: [u32; 4] = [0x6170_7865, 0x3320_646e, 0x7962_2d32, 0x6b20_6574];
+
+const fn quarter_round(state: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize) {
+    state[a] = state[a].wrapping_add(state[b]);
+    state[d] = (state[d] ^ state[a]).rotate_left(16);
+    state[c] = state[c].wrapping_add(state[d]);
+    state[b] = (state[b] ^ state[c]).rotate_left(12);
+    state[a] = state[a].wrapping_add(state[b]);
+    state[d] = (state[d] ^ state[a]).rotate_left(8);
+    state[c] = state[c].wrapping_add(state[d]);
+    state[b] = (state[b] ^ state[c]).rotate_left(7);
+}
+
+/// 🧱 `ChaCha` block function: 20 รอบ (10 double round) แล้วบวก input กลับเข้าไป
+#[must_use]
+pub fn chacha20_block(input: &[u32; 16]) -> [u32; 16] {
+    let mut state = *input;
+    for _ in 0..10 {
+        // column round
+        quarter_round(&mut state, 0, 4, 8, 12);
+        quarter_round(&mut state, 1, 5, 9, 13);
+        quarter_round(&mut state, 2, 6, 10, 14);
+        quarter_round(&mut state, 3, 7, 11, 15);
+        // diagonal round
+        quarter_round(&mut state, 0, 5, 10, 15);
+        quarter_round(&mut state, 1, 6, 11, 12);
+        quarter_round(&mut state, 2, 7, 8, 13);
+        quarter_round(&mut state, 3, 4, 9, 14);
+    }
+    for (word, original) in state.iter_mut().zip(input) {
+        *word = word.wrapping_add(*original);
+    }
+    state
+}
+
+/// 🔐 CSPRNG บน `ChaCha20`: word 12-13 เป็น counter 64 bit, word 14-15 เป็น stream id
+#[derive(Clone)]
+pub struct ChaCha20Rng {
+    input: [u32; 16],
+    buffer: [u32; 16],
+    index: usize,
+}
+
+/// ไม่พิมพ์ key ออกมาใน log
+impl std::fmt::Debug for ChaCha20Rng {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ChaCha20Rng").finish_non_exhaustive()
+    }
+}
+
+impl ChaCha20Rng {
+    /// เปลี่ยนไปใช้ stream อื่นของ key เดิม (เริ่มนับ block ใหม่)
+    pub const fn set_stream(&mut self, stream: u64) {
+        #[allow(clippy::cast_possible_truncation)] // แยก u64 เป็นสอง word
+        {
+            self.input[14] = stream as u32;
+            self.input[15] = (stream >> 32) as u32;
+        }
+        self.input[12] = 0;
+        self.input[13] = 0;
+        self.index = 16;
+    }
+
+    fn refill(&mut self) {
+        self.buffer = chacha20_block(&self.input);
+        self.input[12] = self.input[12].wrapping_add(1);
+        if self.input[12] == 0 {
+            self.input[13] = self.input[13].wrapping_add(1);
+        }
+        self.index = 0;
+    }
+}
+
+impl Rng for ChaCha20Rng {
+    fn next_u32(&mut self) -> u32 {
+        if self.index == self.buffer.len() {
+            self.refill();
+        }
+        let value = self.buffer[self.index];
+        self.index += 1;
+        value
+    }
+}
+
+impl SeedableRng for ChaCha20Rng {
+    /// key 256 bit
+    type Seed = [u8; 32];
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        let mut input = [0_u32; 16];
+        input[..4].copy_from_slice(&CONSTANTS);
+        for (word, bytes) in input[4..12].iter_mut().zip(seed.chunks_exact(4)) {
+            *word = u32::from_le_bytes(bytes.try_into().expect("4-byte chunk"));
+        }
+        Self {
+            input,
+            buffer: [0; 16],
+            index: 16,
+        }
+    }
+}
+
+/// 🎪 เดโม: สร้าง token แบบสุ่มที่ปลอดภัย
+pub fn demonstrate_chacha() {
+    let mut rng = ChaCha20Rng::from_entropy();
+    let mut token = [0_u8; 16];
+    rng.fill_bytes(&mut token);
+    println!("   🔐 session token: {}", hex::encode(token));
+
+    let mut fixed = ChaCha20Rng::from_seed([0; 32]);
+    println!(
+        "   🧱 key ศูนย์ block แรก: {:08x} {:08x} {:08x} {:08x} ... (เหมือน keystream ของ ChaCha20)",
+        fixed.next_u32(),
+        fixed.next_u32(),
+        fixed.next_u32(),
+        fixed.next_u32()
+    );
+    println!("   💡 ต่างจาก Xorshift/PCG: เห็นผลลัพธ์แล้วก็ย้อนหา state ไม่ได้");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_block_function_rfc8439_vector() {
+        // RFC 8439 §2.3.2: key 00..1f, counter 1, nonce 00:00:00:09:00:00:00:4a:00:00:00:00
+        let mut input = [0_u32; 16];
+        input[..4].copy_from_slice(&CONSTANTS);
+        for (i, word) in input[4..12].iter_mut().enumerate() {
+            let base = u8::try_from(i * 4).unwrap();
+            *word = u32::from_le_bytes([base, base + 1, base + 2, base + 3]);
+        }
+        input[12] = 1;
+        input[13] = 0x0900_0000;
+        input[14] = 0x4a00_0000;
+        input[15] = 0;
+
+        let expected = [
+            0xe4e7_f110,
+            0x1559_3bd1,
+            0x1fdd_0f50,
+            0xc471_20a3,
+            0xc7f4_d1c7,
+            0x0368_c033,
+            0x9aaa_2204,
+            0x4e6c_d4c3,
+            0x4664_82d2,
+            0x09aa_9f07,
+            0x05d7_c214,
+            0xa202_8bd9,
+            0xd19c_12b5,
+            0xb94e_16de,
+            0xe883_d0cb,
+            0x4e3c_50a2,
+        ];
+        assert_eq!(chacha20_block(&input), expected);
+    }
+
+    #[test]
+    fn test_rng_walks_blocks_and_streams() {
+        let mut rng = ChaCha20Rng::from_seed([7; 32]);
+        let first: Vec<u32> = (0..16).map(|_| rng.next_u32()).collect();
+        let second: Vec<u32> = (0..16).map(|_| rng.next_u32()).collect();
+        assert_ne!(first, second);
+
+        let mut replay = ChaCha20Rng::from_seed([7; 32]);
+        assert_eq!(
+            (0..16).map(|_| replay.next_u32()).collect::<Vec<_>>(),
+            first
+        );
+
+        replay.set_stream(1);
+        assert_ne!(
+            (0..16).map(|_| replay.next_u32()).collect::<Vec<_>>(),
+            first
+        );
+        assert!(!format!("{rng:?}").contains("0707"));
+    }
+}
@@ -0,0 +1,313 @@
+//! 📊 Distributions - แปลงเลขสุ่มแบบ uniform ให้เป็นรูปแบบที่ต้องการ
+//!
+//! - 📏 [`Uniform`] - float ในช่วง `[low, high)`
+//! - 🔔 [`Normal`] - ระฆังคว่ำด้วย Box-Muller: จาก uniform 2 ตัวได้ normal มาตรฐาน
+//! - 🎁 [`WeightedIndex`] - เลือก index ตามน้ำหนัก (ตาราง loot, ไพ่ที่มีความน่าจะเป็นต่างกัน)
+//! - 🧪 [`chi_squared`] - ตรวจว่าผลสุ่มกระจายสม่ำเสมอจริงไหม
+
+use std::f64::consts::TAU;
+use std::fmt;
+
+use super::rng::Rng;
+
+/// 📊 สิ่งที่สุ่มค่าชนิด `T` ได้จาก [`Rng`] ใดก็ได้
+pub trait Distribution<T> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> T;
+}
+
+/// 📏 float แบบ uniform ใน `[low, high)`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Uniform {
+    low: f64,
+    span: f64,
+}
+
+impl Uniform {
+    /// # Panics
+    /// เมื่อ `low >= high` หรือเป็น NaN
+    #[must_use]
+    pub fn new(low: f64, high: f64) -> Self {
+        assert!(low < high, "Uniform requires low < high");
+        Self {
+            low,
+            span: high - low,
+        }
+    }
+}
+
+impl Distribution<f64> for Uniform {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        self.span.mul_add(rng.next_f64(), self.low)
+    }
+}
+
+/// 🔔 Normal (Gaussian) distribution
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Normal {
+    mean: f64,
+    std_dev: f64,
+}
+
+impl Normal {
+    /// # Panics
+    /// เมื่อ `std_dev` ติดลบหรือไม่ใช่ตัวเลขจำกัด
+    #[must_use]
+    pub fn new(mean: f64, std_dev: f64) -> Self {
+        assert!(
+            std_dev.is_finite() && std_dev >= 0.0,
+            "std_dev must be finite and non-negative"
+        );
+        Self { mean, std_dev }
+    }
+
+    /// Box-Muller: `√(-2 ln u₁) · (cos 2πu₂, sin 2πu₂)` ได้ normal มาตรฐานอิสระกัน 2 ตัว
+    pub fn sample_pair<R: Rng + ?Sized>(&self, rng: &mut R) -> (f64, f64) {
+        // 1 - [0, 1) = (0, 1] กัน ln(0)
+        let u1 = 1.0 - rng.next_f64();
+        let u2 = rng.next_f64();
+        let radius = (-2.0 * u1.ln()).sqrt();
+        let (sin, cos) = (TAU * u2).sin_cos();
+        (
+            (radius * cos).mul_add(self.std_dev, self.mean),
+            (radius * sin).mul_add(self.std_dev, self.mean),
+        )
+    }
+}
+
+impl Distribution<f64> for Normal {
+    /// ใช้แค่ค่าแรกของคู่ - ง่ายและไม่ต้องเก็บ state (แลกกับสุ่มเปลืองขึ้น 2 เท่า)
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        self.sample_pair(rng).0
+    }
+}
+
+/// ❌ น้ำหนักที่ใช้สร้าง [`WeightedIndex`] ไม่ได้
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WeightError {
+    Empty,
+    /// น้ำหนักติดลบหรือไม่ใช่ตัวเลขจำกัด
+    InvalidWeight {
+        index: usize,
+    },
+    AllZero,
+}
+
+impl fmt::Display for WeightError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "no weights given"),
+            Self::InvalidWeight { index } => write!(f, "weight #{index} is negative or not finite"),
+            Self::AllZero => write!(f, "all weights are zero"),
+        }
+    }
+}
+
+impl std::error::Error for WeightError {}
+
+/// 🎁 เลือก index ตามน้ำหนัก: เก็บผลรวมสะสมแล้ว binary search
+#[derive(Debug, Clone, PartialEq)]
+pub struct WeightedIndex {
+    cumulative: Vec<f64>,
+}
+
+impl WeightedIndex {
+    /// # Errors
+    /// [`WeightError`] เมื่อไม่มีน้ำหนัก, มีค่าติดลบ/NaN หรือรวมกันได้ศูนย์
+    pub fn new(weights: &[f64]) -> Result<Self, WeightError> {
+        if weights.is_empty() {
+            return Err(WeightError::Empty);
+        }
+        let mut total = 0.0;
+        let mut cumulative = Vec::with_capacity(weights.len());
+        for (index, &weight) in weights.iter().enumerate() {
+            if !weight.is_finite() || weight < 0.0 {
+                return Err(WeightError::InvalidWeight { index });
+            }
+            total += weight;
+            cumulative.push(total);
+        }
+        if total <= 0.0 {
+            return Err(WeightError::AllZero);
+        }
+        Ok(Self { cumulative })
+    }
+
+    fn total(&self) -> f64 {
+        self.cumulative.last().copied().unwrap_or(0.0)
+    }
+}
+
+impl Distribution<usize> for WeightedIndex {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> usize {
+        let point = rng.next_f64() * self.total();
+        // index แรกที่ผลรวมสะสมเกิน point - น้ำหนักศูนย์จึงไม่มีวันถูกเลือก
+        self.cumulative
+            .partition_point(|&sum| sum <= point)
+            .min(self.cumulative.len() - 1)
+    }
+}
+
+/// 🧪 สถิติ chi-squared ของจำนวนครั้งในแต่ละช่องเทียบกับการกระจายสม่ำเสมอ
+///
+/// ค่ายิ่งน้อยยิ่งสม่ำเสมอ: 10 ช่อง (df = 9) ค่าเกิน ~27.9 เกิดขึ้นโดยบังเอิญแค่ 0.1%
+#[must_use]
+#[allow(clippy::cast_precision_loss)] // จำนวนครั้งในการทดลองไม่ถึง 2^53
+pub fn chi_squared(counts: &[u64]) -> f64 {
+    let total: u64 = counts.iter().sum();
+    let expected = total as f64 / counts.len() as f64;
+    counts
+        .iter()
+        .map(|&count| {
+            let diff = count as f64 - expected;
+            diff * diff / expected
+        })
+        .sum()
+}
+
+/// นับผลสุ่มลง `buckets` ช่องด้วย `gen_range`
+pub fn bucket_counts<R: Rng + ?Sized>(rng: &mut R, buckets: u64, samples: usize) -> Vec<u64> {
+    #[allow(clippy::cast_possible_truncation)] // index < buckets ซึ่งเป็นจำนวนช่องใน Vec
+    let mut counts = vec![0; buckets as usize];
+    for _ in 0..samples {
+        #[allow(clippy::cast_possible_truncation)]
+        let index = rng.gen_range(0..buckets) as usize;
+        counts[index] += 1;
+    }
+    counts
+}
+
+/// 🎪 เดโม: histogram ของ normal, loot table และ chi-squared ของแต่ละ generator
+///
+/// # Panics
+/// ไม่เกิดขึ้นจริง - น้ำหนักของ loot table เป็นค่าคงที่ที่ถูกต้อง
+pub fn demonstrate_distributions() {
+    use super::{ChaCha20Rng, Pcg32, SeedableRng, Xorshift64};
+
+    let mut rng = Pcg32::seed_from_u64(2024);
+
+    let normal = Normal::new(170.0, 8.0);
+    let heights: Vec<f64> = (0..2_000).map(|_| normal.sample(&mut rng)).collect();
+    println!("   🔔 ส่วนสูง ~ Normal(170, 8) จาก 2,000 คน:");
+    for start in (146..194).step_by(8) {
+        let low = f64::from(start);
+        let count = heights
+            .iter()
+            .filter(|&&height| (low..low + 8.0).contains(&height))
+            .count();
+        println!("      {start}-{:<3} {}", start + 8, "█".repeat(count / 25));
+    }
+
+    let uniform = Uniform::new(-1.0, 1.0);
+    println!(
+        "   📏 Uniform(-1, 1): {:?}",
+        (0..4)
+            .map(|_| (uniform.sample(&mut rng) * 100.0).round() / 100.0)
+            .collect::<Vec<_>>()
+    );
+
+    let loot = ["🪙 coin", "🧪 potion", "🗡️ sword", "👑 crown"];
+    let table = WeightedIndex::new(&[70.0, 20.0, 9.0, 1.0]).expect("valid weights");
+    let mut drops = [0_u32; 4];
+    for _ in 0..1_000 {
+        drops[table.sample(&mut rng)] += 1;
+    }
+    println!("   🎁 loot 1,000 ครั้ง (70/20/9/1):");
+    for (name, count) in loot.iter().zip(drops) {
+        println!("      {name}: {count}");
+    }
+
+    println!("   🧪 chi-squared 10 ช่อง × 10,000 ครั้ง (ผ่านถ้า < 27.9):");
+    let results = [
+        (
+            "Xorshift64",
+            chi_squared(&bucket_counts(
+                &mut Xorshift64::seed_from_u64(1),
+                10,
+                10_000,
+            )),
+        ),
+        (
+            "PCG32",
+            chi_squared(&bucket_counts(&mut Pcg32::seed_from_u64(1), 10, 10_000)),
+        ),
+        (
+            "ChaCha20",
+            chi_squared(&bucket_counts(
+                &mut ChaCha20Rng::seed_from_u64(1),
+                10,
+                10_000,
+            )),
+        ),
+    ];
+    for (name, statistic) in results {
+        println!("      {name:<10} χ² = {statistic:.2}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{ChaCha20Rng, Pcg32, SeedableRng, SplitMix64, Xorshift64};
+
+    /// ค่าวิกฤตของ chi-squared df = 9 ที่ p = 0.001
+    const CHI_SQUARED_CRITICAL_DF9: f64 = 27.88;
+
+    fn assert_uniform<R: Rng>(name: &str, rng: &mut R) {
+        let statistic = chi_squared(&bucket_counts(rng, 10, 20_000));
+        assert!(
+            statistic < CHI_SQUARED_CRITICAL_DF9,
+            "{name}: χ² = {statistic}"
+        );
+
+        // monobit: bit 1 ควรมีราวครึ่งหนึ่ง (σ ของ 64,000 bit ≈ 126)
+        let ones: u32 = (0..1_000).map(|_| rng.next_u64().count_ones()).sum();
+        assert!(ones.abs_diff(32_000) < 600, "{name}: {ones} ones");
+    }
+
+    #[test]
+    fn test_generators_pass_sanity_checks() {
+        assert_uniform("splitmix64", &mut SplitMix64::seed_from_u64(11));
+        assert_uniform("xorshift64", &mut Xorshift64::seed_from_u64(11));
+        assert_uniform("pcg32", &mut Pcg32::seed_from_u64(11));
+        assert_uniform("chacha20", &mut ChaCha20Rng::seed_from_u64(11));
+    }
+
+    #[test]
+    #[allow(clippy::cast_precision_loss)]
+    fn test_normal_and_uniform_moments() {
+        let mut rng = Pcg32::seed_from_u64(3);
+        let normal = Normal::new(5.0, 2.0);
+        let samples: Vec<f64> = (0..20_000).map(|_| normal.sample(&mut rng)).collect();
+        let mean = samples.iter().sum::<f64>() / samples.len() as f64;
+        let variance = samples
+            .iter()
+            .map(|value| (value - mean).powi(2))
+            .sum::<f64>()
+            / samples.len() as f64;
+        assert!((mean - 5.0).abs() < 0.05, "mean {mean}");
+        assert!((variance - 4.0).abs() < 0.15, "variance {variance}");
+
+        let uniform = Uniform::new(-3.0, 3.0);
+        assert!((0..1_000).all(|_| (-3.0..3.0).contains(&uniform.sample(&mut rng))));
+    }
+
+    #[test]
+    fn test_weighted_index() {
+        assert_eq!(WeightedIndex::new(&[]), Err(WeightError::Empty));
+        assert_eq!(WeightedIndex::new(&[0.0, 0.0]), Err(WeightError::AllZero));
+        assert_eq!(
+            WeightedIndex::new(&[1.0, -1.0]),
+            Err(WeightError::InvalidWeight { index: 1 })
+        );
+
+        let mut rng = Pcg32::seed_from_u64(5);
+        let table = WeightedIndex::new(&[1.0, 0.0, 3.0]).unwrap();
+        let mut counts = [0_u32; 3];
+        for _ in 0..8_000 {
+            counts[table.sample(&mut rng)] += 1;
+        }
+        assert_eq!(counts[1], 0);
+        assert!(counts[0].abs_diff(2_000) < 150, "{counts:?}");
+        assert!(counts[2].abs_diff(6_000) < 150, "{counts:?}");
+    }
+}
@@ -0,0 +1,44 @@
+//! 🎲 Random Number Generation Module - สร้างเลขสุ่มเองตั้งแต่ศูนย์! 🎰
+//! คอมพิวเตอร์สุ่มจริงไม่ได้ - PRNG คือสูตรที่ให้ลำดับ "ดูเหมือนสุ่ม" จาก seed
+//!
+//! 🎯 สิ่งที่จะได้เรียนรู้:
+//! - 🎲 `Rng` trait - interface เดียวใช้ได้กับทุก generator (range/float/shuffle ได้ฟรี)
+//! - 🌱 Seeding - seed ตายตัว, `SplitMix64` ขยาย seed และ entropy จากระบบ
+//! - ⚡ Xorshift64 - เร็วที่สุด แต่มีจุดอ่อนทางสถิติ
+//! - 🧮 PCG32 - LCG + permutation คุณภาพดี เลือก stream ได้
+//! - 🔐 `ChaCha20` - CSPRNG ที่เดาเลขถัดไปไม่ได้ สำหรับ token/ความลับ
+//! - 📊 Distributions - uniform, normal (Box-Muller), weighted และ chi-squared test
+//!
+//! 🎮 บทเกมใช้ `WeightedIndex` สุ่ม loot และบท ML ใช้ `Normal` สุ่มน้ำหนักเริ่มต้น
+
+pub mod chacha;
+pub mod distributions;
+pub mod pcg;
+pub mod rng;
+pub mod xorshift;
+
+pub use chacha::*;
+pub use distributions::*;
+pub use pcg::*;
+pub use rng::*;
+pub use xorshift::*;
+
+/// 🎪 เรียกใช้ตัวอย่าง Random Number Generation ทั้งหมด
+pub fn run_random_examples() {
+    println!("\n🎲 === Random Number Generation: PRNG จากศูนย์ === 🎰");
+
+    let sections: [(&str, fn()); 5] = [
+        ("🌱 Rng trait & seeding:", demonstrate_seeding),
+        ("⚡ Xorshift64:", demonstrate_xorshift),
+        ("🧮 PCG32:", demonstrate_pcg),
+        ("🔐 ChaCha20 CSPRNG:", demonstrate_chacha),
+        ("📊 Distributions:", demonstrate_distributions),
+    ];
+    for (title, demo) in sections {
+        println!("{title}");
+        demo();
+        println!();
+    }
+
+    println!("💡 เกม/จำลองใช้ PCG หรือ Xorshift ได้ - แต่ password/token ต้องใช้ CSPRNG เสมอ");
+}
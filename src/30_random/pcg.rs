@@ -0,0 +1,103 @@
+//! 🧮 PCG32 (XSH-RR 64/32) - LCG 64 bit + permutation ที่ซ่อนจุดอ่อนของ LCG
+//!
+//! LCG ธรรมดา (`state = state × a + c`) bit ล่างมี period สั้นมาก PCG แก้โดยคืน
+//! "ส่วนบนของ state ที่ถูก xorshift แล้วหมุน" ตามจำนวนที่ได้จาก 5 bit บนสุดของ state เอง
+//! `inc` (ต้องเป็นเลขคี่) เลือก stream - seed เดียวกันคนละ stream ได้ลำดับไม่ซ้ำกัน
+
+use super::rng::{Rng, SeedableRng};
+
+const MULTIPLIER: u64 = 6_364_136_223_846_793_005;
+
+/// 🧮 PCG32 ตาม `pcg32_srandom_r`/`pcg32_random_r` ของ pcg-random.org
+#[derive(Debug, Clone)]
+pub struct Pcg32 {
+    state: u64,
+    inc: u64,
+}
+
+impl Pcg32 {
+    /// `seed` คือจุดเริ่ม, `stream` เลือกลำดับ (2^63 stream ที่ไม่ทับกัน)
+    #[must_use]
+    pub const fn new(seed: u64, stream: u64) -> Self {
+        let mut rng = Self {
+            state: 0,
+            inc: (stream << 1) | 1,
+        };
+        rng.step();
+        rng.state = rng.state.wrapping_add(seed);
+        rng.step();
+        rng
+    }
+
+    const fn step(&mut self) {
+        self.state = self.state.wrapping_mul(MULTIPLIER).wrapping_add(self.inc);
+    }
+}
+
+impl Rng for Pcg32 {
+    #[allow(clippy::cast_possible_truncation)] // XSH-RR ตั้งใจตัดเหลือ 32 bit
+    fn next_u32(&mut self) -> u32 {
+        let old = self.state;
+        self.step();
+        let xorshifted = (((old >> 18) ^ old) >> 27) as u32;
+        let rotation = (old >> 59) as u32;
+        xorshifted.rotate_right(rotation)
+    }
+}
+
+impl SeedableRng for Pcg32 {
+    /// 8 byte แรกเป็น seed, 8 byte หลังเป็น stream
+    type Seed = [u8; 16];
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        let (state, stream) = seed.split_at(8);
+        Self::new(
+            u64::from_le_bytes(state.try_into().expect("8 bytes")),
+            u64::from_le_bytes(stream.try_into().expect("8 bytes")),
+        )
+    }
+}
+
+/// 🎪 เดโม: ลำดับอ้างอิงของ pcg32-demo และ stream ที่ต่างกัน
+pub fn demonstrate_pcg() {
+    let mut rng = Pcg32::new(42, 54);
+    let values: Vec<String> = (0..4)
+        .map(|_| format!("{:#010x}", rng.next_u32()))
+        .collect();
+    println!("   🧮 pcg32(42, stream 54) → {}", values.join(" "));
+
+    for stream in [1, 2] {
+        let mut rng = Pcg32::new(7, stream);
+        let rolls: Vec<u64> = (0..6).map(|_| rng.gen_range(1..7)).collect();
+        println!("   🌊 seed 7 stream {stream}: ทอยเต๋า {rolls:?}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_reference_implementation() {
+        // ผลลัพธ์ของ pcg32-demo.c: pcg32_srandom_r(&rng, 42u, 54u)
+        let mut rng = Pcg32::new(42, 54);
+        let expected = [
+            0xa15c_02b7,
+            0x7b47_f409,
+            0xba1d_3330,
+            0x83d2_f293,
+            0xbfa4_784b,
+            0xcbed_606e,
+        ];
+        for value in expected {
+            assert_eq!(rng.next_u32(), value);
+        }
+
+        let mut a = Pcg32::new(7, 1);
+        let mut b = Pcg32::new(7, 2);
+        assert_ne!(
+            (0..4).map(|_| a.next_u32()).collect::<Vec<_>>(),
+            (0..4).map(|_| b.next_u32()).collect::<Vec<_>>()
+        );
+    }
+}
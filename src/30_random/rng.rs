@@ -0,0 +1,231 @@
+//! 🎲 `Rng` trait กลาง + การ seed - ทุก generator ในบทนี้ใช้ interface เดียวกัน
+//!
+//! generator แต่ละตัวต้องทำแค่ `next_u32` (หรือ `next_u64`) ที่เหลือ เช่น สุ่มในช่วง,
+//! สุ่ม float, สับไพ่ ได้จาก default method ทั้งหมด
+//!
+//! 🌱 วิธี seed มี 3 แบบ:
+//! - `from_seed` - ให้ byte ตรง ๆ (ทำซ้ำได้ 100%)
+//! - `seed_from_u64` - ขยายเลขเดียวเป็น seed เต็มด้วย `SplitMix64` (สะดวกใน tests)
+//! - `from_entropy` - ผสมความสุ่มจาก OS (`RandomState`), เวลา และ counter
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::ops::Range;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// 🎲 แหล่งเลขสุ่ม - implement `next_u32` อย่างเดียวก็พอ
+pub trait Rng {
+    fn next_u32(&mut self) -> u32;
+
+    /// ต่อ `u32` สองตัว - generator ที่ผลิต 64 bit ได้เองควร override
+    fn next_u64(&mut self) -> u64 {
+        let high = u64::from(self.next_u32());
+        (high << 32) | u64::from(self.next_u32())
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(8) {
+            let bytes = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+
+    /// float ใน `[0, 1)` จาก 53 bit บน (ความละเอียดเต็มของ mantissa `f64`)
+    #[allow(clippy::cast_precision_loss)] // ค่าไม่เกิน 2^53 แปลงได้พอดี
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1.0 / (1_u64 << 53) as f64)
+    }
+
+    /// สุ่มใน `range` แบบไม่ bias (วิธีของ Lemire: คูณ 128 bit แล้วทิ้งค่าที่ตกเศษ)
+    ///
+    /// `next_u64() % n` ง่ายกว่าแต่ bias ไปทางเลขน้อยเมื่อ `n` ไม่หาร 2^64 ลงตัว
+    ///
+    /// # Panics
+    /// เมื่อ `range` ว่าง
+    fn gen_range(&mut self, range: Range<u64>) -> u64 {
+        assert!(range.start < range.end, "gen_range called with empty range");
+        let span = range.end - range.start;
+        let threshold = span.wrapping_neg() % span;
+        loop {
+            let product = u128::from(self.next_u64()) * u128::from(span);
+            #[allow(clippy::cast_possible_truncation)] // ตั้งใจเอาครึ่งล่าง
+            let low = product as u64;
+            if low >= threshold {
+                #[allow(clippy::cast_possible_truncation)] // ครึ่งบน < span จึงพอดี u64
+                return range.start + (product >> 64) as u64;
+            }
+        }
+    }
+
+    /// `true` ด้วยความน่าจะเป็น `probability`
+    fn gen_bool(&mut self, probability: f64) -> bool {
+        self.next_f64() < probability
+    }
+
+    /// 🃏 Fisher-Yates shuffle
+    fn shuffle<T>(&mut self, items: &mut [T])
+    where
+        Self: Sized,
+    {
+        for i in (1..items.len()).rev() {
+            let j = self.gen_range(0..i as u64 + 1);
+            #[allow(clippy::cast_possible_truncation)] // j ≤ i ซึ่งเป็น usize อยู่แล้ว
+            items.swap(i, j as usize);
+        }
+    }
+
+    fn choose<'a, T>(&mut self, items: &'a [T]) -> Option<&'a T>
+    where
+        Self: Sized,
+    {
+        if items.is_empty() {
+            return None;
+        }
+        #[allow(clippy::cast_possible_truncation)] // ผลลัพธ์ < len
+        items.get(self.gen_range(0..items.len() as u64) as usize)
+    }
+}
+
+/// 🌱 generator ที่สร้างจาก seed ได้
+pub trait SeedableRng: Sized {
+    /// byte array ของ seed - ขนาดเท่ากับ state ภายใน
+    type Seed: Default + AsMut<[u8]>;
+
+    fn from_seed(seed: Self::Seed) -> Self;
+
+    /// ขยาย `u64` เป็น seed เต็มด้วย [`SplitMix64`] - seed ใกล้กัน (1, 2, 3) ก็ได้ state ต่างกันมาก
+    #[must_use]
+    fn seed_from_u64(state: u64) -> Self {
+        let mut seed = Self::Seed::default();
+        SplitMix64::new(state).fill_bytes(seed.as_mut());
+        Self::from_seed(seed)
+    }
+
+    /// seed จาก entropy ของระบบ - ได้ลำดับใหม่ทุกครั้งที่เรียก
+    #[must_use]
+    fn from_entropy() -> Self {
+        let mut seed = Self::Seed::default();
+        SplitMix64::new(entropy_u64()).fill_bytes(seed.as_mut());
+        Self::from_seed(seed)
+    }
+}
+
+/// ผสม key สุ่มของ `RandomState` (มาจาก OS), เวลาปัจจุบัน และ counter ของ process
+fn entropy_u64() -> u64 {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let mut hasher = RandomState::new().build_hasher();
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_nanos());
+    hasher.write_u128(nanos);
+    hasher.write_u64(COUNTER.fetch_add(1, Ordering::Relaxed));
+    hasher.finish()
+}
+
+/// 🔀 `SplitMix64` - generator 64 bit ที่ง่ายที่สุดที่คุณภาพดี ใช้ขยาย seed ให้ตัวอื่น
+#[derive(Debug, Clone)]
+pub struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    #[must_use]
+    pub const fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+}
+
+impl Rng for SplitMix64 {
+    #[allow(clippy::cast_possible_truncation)] // ใช้ 32 bit บนซึ่งคุณภาพดีกว่า
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+}
+
+impl SeedableRng for SplitMix64 {
+    type Seed = [u8; 8];
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::new(u64::from_le_bytes(seed))
+    }
+}
+
+/// 🎪 เดโม: seed เดียวกันได้ลำดับเดียวกัน, entropy ได้ลำดับใหม่ และสับไพ่
+pub fn demonstrate_seeding() {
+    let mut first = SplitMix64::seed_from_u64(42);
+    let mut second = SplitMix64::seed_from_u64(42);
+    let a: Vec<u64> = (0..3).map(|_| first.next_u64() % 1000).collect();
+    let b: Vec<u64> = (0..3).map(|_| second.next_u64() % 1000).collect();
+    println!("   🌱 seed 42 สองครั้ง: {a:?} == {b:?} → ทำซ้ำได้ (เหมาะกับ tests/replay)");
+
+    let mut entropy = SplitMix64::from_entropy();
+    let fresh: Vec<u64> = (0..3).map(|_| entropy.next_u64() % 1000).collect();
+    println!("   🌪️ from_entropy: {fresh:?} (รันใหม่ได้ค่าใหม่)");
+
+    let mut deck: Vec<u32> = (1..=10).collect();
+    first.shuffle(&mut deck);
+    println!("   🃏 Fisher-Yates shuffle: {deck:?}");
+    println!(
+        "   🎯 gen_range(1..7) ทอยลูกเต๋า: {:?}",
+        (0..8).map(|_| first.gen_range(1..7)).collect::<Vec<_>>()
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_splitmix64_reference_values() {
+        // ค่าอ้างอิงจาก splitmix64.c ของ Sebastiano Vigna (seed 1234567)
+        let mut rng = SplitMix64::new(1_234_567);
+        let expected = [
+            6_457_827_717_110_365_317,
+            3_203_168_211_198_807_973,
+            9_817_491_932_198_370_423,
+            4_593_380_528_125_082_431,
+            16_408_922_859_458_223_821,
+        ];
+        for value in expected {
+            assert_eq!(rng.next_u64(), value);
+        }
+    }
+
+    #[test]
+    fn test_range_shuffle_and_seeding() {
+        let mut rng = SplitMix64::seed_from_u64(7);
+        for _ in 0..1_000 {
+            let value = rng.gen_range(10..13);
+            assert!((10..13).contains(&value));
+            let float = rng.next_f64();
+            assert!((0.0..1.0).contains(&float));
+        }
+        assert_eq!(rng.gen_range(5..6), 5);
+
+        let mut deck: Vec<u32> = (0..52).collect();
+        rng.shuffle(&mut deck);
+        let mut sorted = deck.clone();
+        sorted.sort_unstable();
+        assert_eq!(sorted, (0..52).collect::<Vec<_>>());
+        assert_ne!(deck, sorted);
+        assert_eq!(rng.choose::<u8>(&[]), None);
+
+        let mut bytes = [0_u8; 13];
+        rng.fill_bytes(&mut bytes);
+        assert!(bytes.iter().any(|&byte| byte != 0));
+
+        assert_ne!(
+            SplitMix64::from_entropy().next_u64(),
+            SplitMix64::from_entropy().next_u64()
+        );
+    }
+}
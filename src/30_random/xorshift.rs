@@ -0,0 +1,81 @@
+//! ⚡ Xorshift64 - generator ของ Marsaglia (2003): shift กับ xor แค่ 3 ครั้งต่อเลข
+//!
+//! เร็วมากและ period 2^64 - 1 แต่ bit ล่างเป็นเชิงเส้น (ตก test บางชุดของ `BigCrush`)
+//! และ state 0 จะติดอยู่ที่ 0 ตลอดไป - เหมาะกับเกม/จำลอง ไม่เหมาะกับความลับ
+
+use super::rng::{Rng, SeedableRng};
+
+/// ⚡ Xorshift64 (ค่าคงที่ 13, 7, 17)
+#[derive(Debug, Clone)]
+pub struct Xorshift64 {
+    state: u64,
+}
+
+impl Xorshift64 {
+    /// state ศูนย์เป็นจุดตรึง จึงแทนด้วยค่าคงที่อื่น
+    const ZERO_SEED_REPLACEMENT: u64 = 0x2545_f491_4f6c_dd1d;
+
+    #[must_use]
+    pub const fn new(seed: u64) -> Self {
+        Self {
+            state: if seed == 0 {
+                Self::ZERO_SEED_REPLACEMENT
+            } else {
+                seed
+            },
+        }
+    }
+}
+
+impl Rng for Xorshift64 {
+    #[allow(clippy::cast_possible_truncation)] // ใช้ 32 bit บน - bit ล่างคุณภาพแย่กว่า
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.state = x;
+        x
+    }
+}
+
+impl SeedableRng for Xorshift64 {
+    type Seed = [u8; 8];
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::new(u64::from_le_bytes(seed))
+    }
+}
+
+/// 🎪 เดโม: ทีละขั้นของ xorshift และปัญหา seed ศูนย์
+pub fn demonstrate_xorshift() {
+    let mut rng = Xorshift64::new(1);
+    let values: Vec<u64> = (0..4).map(|_| rng.next_u64()).collect();
+    println!("   ⚡ seed 1 → {values:?}");
+    println!("   ⚠️ ค่าแรกยังเล็กเพราะ seed มี bit เดียว - ควร seed ผ่าน seed_from_u64");
+    let mut mixed = Xorshift64::seed_from_u64(1);
+    println!("   🌱 seed_from_u64(1) → {:#018x}", mixed.next_u64());
+    println!(
+        "   0️⃣ seed 0 ถูกแทนเป็น {:#x} (ไม่งั้นได้ 0 ตลอดกาล)",
+        Xorshift64::ZERO_SEED_REPLACEMENT
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_known_sequence_and_zero_seed() {
+        let mut rng = Xorshift64::new(1);
+        // 1 → 8193 → 8257 → 8257 ^ (8257 << 17)
+        assert_eq!(rng.next_u64(), 1_082_269_761);
+
+        let mut zero = Xorshift64::new(0);
+        assert!((0..100).all(|_| zero.next_u64() != 0));
+    }
+}
@@ -0,0 +1,420 @@
+//! ⏰ Cron Expressions - "นาที ชั่วโมง วันที่ เดือน วันในสัปดาห์" แบบ Vixie cron พร้อมหาเวลาถัดไปในโซนใดก็ได้
+//!
+//! ต่างจาก `CronLite` ของ task list (UTC, ต้องตรงทุกช่อง):
+//! - 📛 ชื่อเดือน/วัน (`JAN`, `mon-fri`) และ `@daily`, `@hourly`, `@weekly`, `@monthly`, `@yearly`
+//! - 🔀 ถ้ากำหนดทั้งวันที่และวันในสัปดาห์ จะตรงเมื่อ **ข้อใดข้อหนึ่ง** ตรง (`0 0 1 * MON` = วันที่ 1 หรือทุกวันจันทร์)
+//! - 🌏 คิดตามเวลาท้องถิ่นของโซนที่ส่งเข้ามา และจัดการวันเปลี่ยนเวลาแบบ Vixie cron:
+//!   - งานที่ระบุชั่วโมงตายตัว: ช่วงที่หายไป → เลื่อนไปข้างหน้า, ช่วงที่ซ้ำ → รันครั้งเดียว
+//!   - งานที่ชั่วโมงเป็น `*`: รันตามนาฬิกาจริงทุกครั้ง รวมถึงชั่วโมงที่ซ้ำ
+
+use std::fmt;
+use std::iter;
+use std::str::FromStr;
+
+use chrono::{DateTime, Datelike, LocalResult, NaiveDate, NaiveDateTime, TimeZone};
+
+use super::timezones::from_local_lenient;
+
+const MONTH_NAMES: [&str; 12] = [
+    "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+];
+const WEEKDAY_NAMES: [&str; 7] = ["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
+
+/// ❌ cron expression ไม่ถูกต้อง
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CronError {
+    /// ต้องมี 5 ช่อง
+    FieldCount(usize),
+    UnknownMacro(String),
+    InvalidField {
+        field: &'static str,
+        value: String,
+    },
+}
+
+impl fmt::Display for CronError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::FieldCount(count) => write!(f, "expected 5 cron fields, found {count}"),
+            Self::UnknownMacro(name) => write!(f, "unknown cron macro `{name}`"),
+            Self::InvalidField { field, value } => {
+                write!(f, "invalid cron {field} field `{value}`")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CronError {}
+
+/// ⏰ cron schedule ที่ parse แล้ว - แต่ละช่องเก็บเป็น bitset
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronSchedule {
+    source: String,
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    /// ช่องขึ้นต้นด้วย `*` ถือว่า "ไม่จำกัด" - ใช้ตัดสินกติกา OR และการจัดการ DST
+    day_of_month_any: bool,
+    day_of_week_any: bool,
+    hour_any: bool,
+}
+
+impl CronSchedule {
+    #[must_use]
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// วันนี้ควรรันไหม (เดือนต้องตรงเสมอ, วันที่/วันในสัปดาห์ใช้ OR เมื่อกำหนดทั้งคู่)
+    #[must_use]
+    pub fn matches_date(&self, date: NaiveDate) -> bool {
+        if !bit(self.months, date.month()) {
+            return false;
+        }
+        let day_of_month = bit(self.days_of_month, date.day());
+        let day_of_week = bit(self.days_of_week, date.weekday().num_days_from_sunday());
+        match (self.day_of_month_any, self.day_of_week_any) {
+            (false, false) => day_of_month || day_of_week,
+            _ => day_of_month && day_of_week,
+        }
+    }
+
+    /// 🔜 เวลารันครั้งแรกที่อยู่ *หลัง* `after` ในโซนเดียวกับ `after` - มองไปข้างหน้าไม่เกิน 5 ปี
+    #[must_use]
+    pub fn next_after<Z: TimeZone>(&self, after: &DateTime<Z>) -> Option<DateTime<Z>> {
+        let zone = after.timezone();
+        let mut date = after.naive_local().date();
+        for _ in 0..=366 * 5 {
+            if self.matches_date(date) {
+                for hour in (0..24).filter(|&h| bit(self.hours, h)) {
+                    for minute in (0..60).filter(|&m| bit(self.minutes, m)) {
+                        let local = date.and_hms_opt(hour, minute, 0)?;
+                        if let Some(found) = self
+                            .candidates(&zone, local)
+                            .into_iter()
+                            .find(|candidate| candidate > after)
+                        {
+                            return Some(found);
+                        }
+                    }
+                }
+            }
+            date = date.succ_opt()?;
+        }
+        None
+    }
+
+    /// 📋 เวลารันถัดๆ ไปเรื่อยๆ (ใช้คู่กับ `take`)
+    pub fn upcoming<'a, Z: TimeZone + 'a>(
+        &'a self,
+        after: &DateTime<Z>,
+    ) -> impl Iterator<Item = DateTime<Z>> + 'a {
+        iter::successors(self.next_after(after), |previous| self.next_after(previous))
+    }
+
+    /// instant ที่เวลาท้องถิ่น `local` ควรรัน (เรียงจากเร็วไปช้า)
+    fn candidates<Z: TimeZone>(&self, zone: &Z, local: NaiveDateTime) -> Vec<DateTime<Z>> {
+        match zone.from_local_datetime(&local) {
+            LocalResult::Single(value) => vec![value],
+            // ชั่วโมง `*` รันทั้งสองรอบ, ชั่วโมงตายตัวรันแค่รอบแรก
+            LocalResult::Ambiguous(earliest, latest) if self.hour_any => vec![earliest, latest],
+            LocalResult::Ambiguous(earliest, _) => vec![earliest],
+            // ชั่วโมง `*` จะรันที่ชั่วโมงถัดไปตามปกติอยู่แล้ว ไม่ต้องชดเชย
+            LocalResult::None if self.hour_any => Vec::new(),
+            LocalResult::None => from_local_lenient(zone, local).into_iter().collect(),
+        }
+    }
+}
+
+const fn bit(set: u64, value: u32) -> bool {
+    set & (1 << value) != 0
+}
+
+impl fmt::Display for CronSchedule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+impl FromStr for CronSchedule {
+    type Err = CronError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let text = text.trim();
+        let expanded = match text.to_ascii_lowercase().as_str() {
+            "@yearly" | "@annually" => "0 0 1 1 *",
+            "@monthly" => "0 0 1 * *",
+            "@weekly" => "0 0 * * 0",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@hourly" => "0 * * * *",
+            other if other.starts_with('@') => {
+                return Err(CronError::UnknownMacro(text.to_string()));
+            }
+            _ => text,
+        };
+        let fields: Vec<&str> = expanded.split_whitespace().collect();
+        let [minute, hour, day, month, weekday] = fields[..] else {
+            return Err(CronError::FieldCount(fields.len()));
+        };
+
+        let mut days_of_week = parse_field(weekday, 0, 7, &WEEKDAY_NAMES, "day-of-week")?;
+        // 7 ก็คือวันอาทิตย์
+        if bit(days_of_week, 7) {
+            days_of_week = (days_of_week & !(1 << 7)) | 1;
+        }
+        Ok(Self {
+            source: text.to_string(),
+            minutes: parse_field(minute, 0, 59, &[], "minute")?,
+            hours: parse_field(hour, 0, 23, &[], "hour")?,
+            days_of_month: parse_field(day, 1, 31, &[], "day-of-month")?,
+            months: parse_field(month, 1, 12, &MONTH_NAMES, "month")?,
+            days_of_week,
+            day_of_month_any: day.starts_with('*'),
+            day_of_week_any: weekday.starts_with('*'),
+            hour_any: hour.starts_with('*'),
+        })
+    }
+}
+
+/// `*`, `n`, `a-b`, `*/step`, `a-b/step`, `a/step` หรือชื่อ คั่นด้วย `,` → bitset
+fn parse_field(
+    text: &str,
+    min: u32,
+    max: u32,
+    names: &[&str],
+    field: &'static str,
+) -> Result<u64, CronError> {
+    let error = || CronError::InvalidField {
+        field,
+        value: text.to_string(),
+    };
+    let value = |token: &str| -> Result<u32, CronError> {
+        let lower = token.to_ascii_lowercase();
+        names
+            .iter()
+            .position(|name| *name == lower)
+            .and_then(|index| u32::try_from(index).ok())
+            .map(|index| index + min)
+            .or_else(|| token.parse().ok())
+            .filter(|number| (min..=max).contains(number))
+            .ok_or_else(error)
+    };
+
+    let mut set = 0_u64;
+    for part in text.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => (
+                range,
+                step.parse::<usize>()
+                    .ok()
+                    .filter(|&step| step > 0)
+                    .ok_or_else(error)?,
+            ),
+            None => (part, 1),
+        };
+        let (start, end) = match range.split_once('-') {
+            _ if range == "*" => (min, max),
+            Some((start, end)) => (value(start)?, value(end)?),
+            // `5/15` = ตั้งแต่ 5 ไปจนสุดช่วง ทีละ 15
+            None if step > 1 => (value(range)?, max),
+            None => {
+                let single = value(range)?;
+                (single, single)
+            }
+        };
+        if start > end {
+            return Err(error());
+        }
+        for number in (start..=end).step_by(step) {
+            set |= 1 << number;
+        }
+    }
+    Ok(set)
+}
+
+/// 🎪 เดโม: parse หลายแบบและเวลารันถัดไปในกรุงเทพฯ กับ New York ช่วงเปลี่ยนเวลา
+///
+/// # Panics
+/// ไม่เกิดขึ้นจริง - expression และวันเวลาในเดโมเป็นค่าคงที่ที่ถูกต้อง
+pub fn demonstrate_cron() {
+    let bangkok = chrono_tz::Asia::Bangkok;
+    let now = bangkok
+        .with_ymd_and_hms(2025, 3, 14, 18, 0, 0)
+        .single()
+        .expect("Bangkok has no DST");
+    println!("   🕕 ตอนนี้ {now}");
+    for expression in [
+        "*/20 9-17 * * mon-fri",
+        "0 0 1 * MON",
+        "@monthly",
+        "0 12 * JAN-MAR sat",
+        "61 * * * *",
+    ] {
+        match expression.parse::<CronSchedule>() {
+            Ok(schedule) => {
+                let next: Vec<String> = schedule
+                    .upcoming(&now)
+                    .take(3)
+                    .map(|at| at.format("%a %d %b %H:%M").to_string())
+                    .collect();
+                println!("   ⏰ {expression:<24} → {}", next.join(" | "));
+            }
+            Err(error) => println!("   ❌ {error}"),
+        }
+    }
+
+    let new_york = chrono_tz::America::New_York;
+    let before = new_york
+        .with_ymd_and_hms(2024, 11, 3, 0, 15, 0)
+        .single()
+        .expect("not ambiguous");
+    for expression in ["30 1 * * *", "30 * * * *"] {
+        let schedule: CronSchedule = expression.parse().expect("valid expression");
+        let runs: Vec<String> = schedule
+            .upcoming(&before)
+            .take(3)
+            .map(|at| at.format("%d/%m %H:%M %Z").to_string())
+            .collect();
+        println!(
+            "   👯 New York วันถอยเวลา `{expression}` → {}",
+            runs.join(", ")
+        );
+    }
+    let gap = new_york
+        .with_ymd_and_hms(2024, 3, 9, 12, 0, 0)
+        .single()
+        .expect("not near a transition");
+    let schedule: CronSchedule = "30 2 * * *".parse().expect("valid expression");
+    println!(
+        "   🕳️ `30 2 * * *` วันที่ 02:30 หายไป → {}",
+        schedule
+            .next_after(&gap)
+            .map_or_else(|| "-".to_string(), |at| at.to_string())
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, Utc};
+    use chrono_tz::America::New_York;
+
+    fn schedule(text: &str) -> CronSchedule {
+        text.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse_fields_names_and_macros() {
+        let workday = schedule("*/20 9-17 * * mon-fri");
+        let friday = NaiveDate::from_ymd_opt(2025, 3, 14).unwrap();
+        assert!(workday.matches_date(friday));
+        assert!(!workday.matches_date(friday.succ_opt().unwrap()));
+
+        assert_eq!(schedule("@daily").minutes, 1);
+        assert_eq!(schedule("@WEEKLY").days_of_week, 1);
+        assert_eq!(schedule("0 0 * * 7").days_of_week, 1);
+        assert_eq!(
+            schedule("5/20 * * * *").minutes,
+            (1 << 5) | (1 << 25) | (1 << 45)
+        );
+        assert_eq!(schedule("0 0 * jan,Dec *").months, (1 << 1) | (1 << 12));
+
+        for bad in [
+            "* * * *",
+            "60 * * * *",
+            "* * 0 * *",
+            "* * * * 8",
+            "5-1 * * * *",
+            "*/0 * * * *",
+            "@often",
+            "* * * foo *",
+        ] {
+            assert!(bad.parse::<CronSchedule>().is_err(), "{bad}");
+        }
+        assert_eq!(
+            "1 2 3".parse::<CronSchedule>(),
+            Err(CronError::FieldCount(3))
+        );
+    }
+
+    #[test]
+    fn test_day_of_month_or_day_of_week() {
+        // วันที่ 1 หรือทุกวันจันทร์
+        let either = schedule("0 0 1 * MON");
+        let after = Utc.with_ymd_and_hms(2025, 3, 1, 12, 0, 0).unwrap();
+        let runs: Vec<_> = either.upcoming(&after).take(3).map(|at| at.day()).collect();
+        assert_eq!(runs, [3, 10, 17]);
+        let april = Utc.with_ymd_and_hms(2025, 3, 31, 12, 0, 0).unwrap();
+        assert_eq!(either.next_after(&april).unwrap().day(), 1);
+
+        // ช่อง * ในวันที่ → ใช้ AND ตามปกติ (เฉพาะวันจันทร์)
+        let mondays = schedule("0 0 * * MON");
+        assert!(
+            mondays
+                .upcoming(&after)
+                .take(5)
+                .all(|at| at.weekday() == chrono::Weekday::Mon)
+        );
+
+        let leap = schedule("0 12 29 2 *");
+        let next = leap.next_after(&after).unwrap();
+        assert_eq!((next.year(), next.month(), next.day()), (2028, 2, 29));
+    }
+
+    #[test]
+    fn test_fixed_hour_job_across_dst() {
+        // spring forward: 02:30 หายไป → รันที่ 03:30 EDT ครั้งเดียว
+        let gap_job = schedule("30 2 * * *");
+        let before = New_York.with_ymd_and_hms(2024, 3, 9, 12, 0, 0).unwrap();
+        let runs: Vec<String> = gap_job
+            .upcoming(&before)
+            .take(2)
+            .map(|at| at.to_rfc3339())
+            .collect();
+        assert_eq!(
+            runs,
+            ["2024-03-10T03:30:00-04:00", "2024-03-11T02:30:00-04:00"]
+        );
+
+        // fall back: 01:30 เกิดสองครั้ง → รันแค่รอบ EDT
+        let overlap_job = schedule("30 1 * * *");
+        let before = New_York.with_ymd_and_hms(2024, 11, 2, 12, 0, 0).unwrap();
+        let runs: Vec<String> = overlap_job
+            .upcoming(&before)
+            .take(2)
+            .map(|at| at.to_rfc3339())
+            .collect();
+        assert_eq!(
+            runs,
+            ["2024-11-03T01:30:00-04:00", "2024-11-04T01:30:00-05:00"]
+        );
+    }
+
+    #[test]
+    fn test_wildcard_hour_job_follows_real_clock_across_dst() {
+        let hourly = schedule("30 * * * *");
+        let before = New_York.with_ymd_and_hms(2024, 11, 3, 0, 15, 0).unwrap();
+        let runs: Vec<String> = hourly
+            .upcoming(&before)
+            .take(4)
+            .map(|at| at.to_rfc3339())
+            .collect();
+        assert_eq!(
+            runs,
+            [
+                "2024-11-03T00:30:00-04:00",
+                "2024-11-03T01:30:00-04:00",
+                "2024-11-03T01:30:00-05:00",
+                "2024-11-03T02:30:00-05:00",
+            ]
+        );
+
+        let before = New_York.with_ymd_and_hms(2024, 3, 10, 1, 0, 0).unwrap();
+        let runs: Vec<_> = hourly.upcoming(&before).take(2).collect();
+        assert_eq!(runs[0].to_rfc3339(), "2024-03-10T01:30:00-05:00");
+        assert_eq!(runs[1].to_rfc3339(), "2024-03-10T03:30:00-04:00");
+        assert_eq!(runs[1] - runs[0], Duration::hours(1));
+    }
+}
@@ -0,0 +1,283 @@
+//! ⏳ Durations - บวกลบเวลา, "กี่นาทีที่แล้ว" และความต่างระหว่าง "1 วัน" กับ "24 ชั่วโมง"
+//!
+//! - ➕ `instant + Duration::days(1)` = 24 ชั่วโมงจริงเสมอ
+//! - 📅 `add_calendar_days` = วันถัดไปเวลาเดิมบนนาฬิกาแขวน (วันเปลี่ยนเวลามีแค่ 23 หรือ 25 ชั่วโมง)
+//! - 🗓️ `checked_add_months` ของ chrono ปัดเป็นวันสุดท้ายของเดือน (31 ม.ค. + 1 เดือน = 29 ก.พ.)
+
+use std::fmt;
+
+use chrono::{DateTime, Datelike, Days, Duration, NaiveDate, TimeZone, Utc, Weekday};
+
+use super::timezones::from_local_lenient;
+
+/// ❌ string ระยะเวลาอ่านไม่ได้
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseDurationError {
+    Empty,
+    /// ตัวเลขไม่มีหน่วยต่อท้าย เช่น `"90"`
+    MissingUnit,
+    UnknownUnit(String),
+    Overflow,
+}
+
+impl fmt::Display for ParseDurationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "empty duration"),
+            Self::MissingUnit => write!(f, "number without a unit (use d, h, m, s or ms)"),
+            Self::UnknownUnit(unit) => write!(f, "unknown duration unit `{unit}`"),
+            Self::Overflow => write!(f, "duration is too large"),
+        }
+    }
+}
+
+impl std::error::Error for ParseDurationError {}
+
+/// 📥 อ่าน `"1d2h"`, `"1h 30m"`, `"90s"`, `"250ms"` (ติดลบได้ด้วย `-` ข้างหน้า)
+///
+/// # Errors
+/// [`ParseDurationError`] เมื่อว่าง, ไม่มีหน่วย, หน่วยไม่รู้จัก หรือใหญ่เกิน
+pub fn parse_duration(text: &str) -> Result<Duration, ParseDurationError> {
+    let text = text.trim();
+    let (negative, body) = text
+        .strip_prefix('-')
+        .map_or((false, text), |rest| (true, rest));
+    if body.is_empty() {
+        return Err(ParseDurationError::Empty);
+    }
+
+    let mut total = Duration::zero();
+    let mut rest = body.trim_start();
+    while !rest.is_empty() {
+        let digits = rest.len() - rest.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+        let unit_start = &rest[digits..];
+        let unit_len = unit_start.len()
+            - unit_start
+                .trim_start_matches(|c: char| c.is_ascii_alphabetic())
+                .len();
+        if digits == 0 || unit_len == 0 {
+            return Err(if digits == 0 {
+                ParseDurationError::UnknownUnit(rest.to_string())
+            } else {
+                ParseDurationError::MissingUnit
+            });
+        }
+        let value: i64 = rest[..digits]
+            .parse()
+            .map_err(|_| ParseDurationError::Overflow)?;
+        let unit = &unit_start[..unit_len];
+        let part = match unit {
+            "d" => Duration::try_days(value),
+            "h" => Duration::try_hours(value),
+            "m" => Duration::try_minutes(value),
+            "s" => Duration::try_seconds(value),
+            "ms" => Duration::try_milliseconds(value),
+            other => return Err(ParseDurationError::UnknownUnit(other.to_string())),
+        }
+        .ok_or(ParseDurationError::Overflow)?;
+        total = total
+            .checked_add(&part)
+            .ok_or(ParseDurationError::Overflow)?;
+        rest = unit_start[unit_len..].trim_start();
+    }
+    Ok(if negative { -total } else { total })
+}
+
+/// 🖨️ `"1d 2h 3m 4s"` - ข้ามหน่วยที่เป็นศูนย์ (ศูนย์ทั้งหมดได้ `"0s"`)
+#[must_use]
+pub fn format_duration(duration: Duration) -> String {
+    let sign = if duration < Duration::zero() { "-" } else { "" };
+    let mut seconds = duration.num_seconds().unsigned_abs();
+    let mut parts = Vec::new();
+    for (unit, size) in [("d", 86_400), ("h", 3_600), ("m", 60), ("s", 1)] {
+        if seconds >= size {
+            parts.push(format!("{}{unit}", seconds / size));
+            seconds %= size;
+        }
+    }
+    if parts.is_empty() {
+        return "0s".to_string();
+    }
+    format!("{sign}{}", parts.join(" "))
+}
+
+/// 💬 "5 นาทีที่แล้ว" / "อีก 2 วัน" - ปัดเป็นหน่วยที่ใหญ่ที่สุดที่เหมาะ
+///
+/// เกณฑ์แบบเดียวกับ moment.js: < 45 วินาที = "เมื่อสักครู่", < 45 นาที = นาที,
+/// < 22 ชั่วโมง = ชั่วโมง, < 26 วัน = วัน, < 320 วัน = เดือน (30 วัน), ที่เหลือเป็นปี (365 วัน)
+#[must_use]
+pub fn time_ago(then: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let delta = now.signed_duration_since(then);
+    let seconds = delta.num_seconds().unsigned_abs();
+    if seconds < 45 {
+        return "เมื่อสักครู่".to_string();
+    }
+    let round = |size: u64| (seconds + size / 2) / size;
+    let (amount, unit) = match seconds {
+        s if s < 45 * 60 => (round(60), "นาที"),
+        s if s < 22 * 3_600 => (round(3_600), "ชั่วโมง"),
+        s if s < 26 * 86_400 => (round(86_400), "วัน"),
+        s if s < 320 * 86_400 => (round(30 * 86_400), "เดือน"),
+        _ => (round(365 * 86_400), "ปี"),
+    };
+    let amount = amount.max(1);
+    if delta < Duration::zero() {
+        format!("อีก {amount} {unit}")
+    } else {
+        format!("{amount} {unit}ที่แล้ว")
+    }
+}
+
+/// 📅 บวกจำนวนวันตามปฏิทิน - เวลาบนนาฬิกาเท่าเดิมแม้ข้ามวันเปลี่ยนเวลา
+///
+/// ถ้าเวลาเดิมหายไปในวันปลายทาง จะเลื่อนไปข้างหน้าตาม [`from_local_lenient`]
+#[must_use]
+pub fn add_calendar_days<Z: TimeZone>(value: &DateTime<Z>, days: u64) -> Option<DateTime<Z>> {
+    let local = value.naive_local().checked_add_days(Days::new(days))?;
+    from_local_lenient(&value.timezone(), local)
+}
+
+/// 💼 บวกวันทำการ (ข้ามเสาร์-อาทิตย์)
+#[must_use]
+pub fn add_business_days(mut date: NaiveDate, mut days: u32) -> Option<NaiveDate> {
+    while days > 0 {
+        date = date.succ_opt()?;
+        if !matches!(date.weekday(), Weekday::Sat | Weekday::Sun) {
+            days -= 1;
+        }
+    }
+    Some(date)
+}
+
+/// 🎪 เดโม: parse/format ระยะเวลา, time ago และ 1 วัน ≠ 24 ชั่วโมง
+///
+/// # Panics
+/// ไม่เกิดขึ้นจริง - วันเวลาในเดโมเป็นค่าคงที่ที่ถูกต้อง
+pub fn demonstrate_durations() {
+    for input in ["1h30m", "2d 4h", "90s", "1.5h"] {
+        match parse_duration(input) {
+            Ok(duration) => println!("   ⏳ {input:<6} → {}", format_duration(duration)),
+            Err(error) => println!("   ❌ {input:<6} → {error}"),
+        }
+    }
+
+    let now = Utc
+        .with_ymd_and_hms(2025, 3, 14, 12, 0, 0)
+        .single()
+        .expect("UTC is never ambiguous");
+    for offset in [
+        Duration::seconds(-10),
+        Duration::minutes(-7),
+        Duration::hours(-3),
+        Duration::days(-40),
+        Duration::days(2),
+    ] {
+        println!(
+            "   💬 {:>8} → {}",
+            format_duration(offset),
+            time_ago(now + offset, now)
+        );
+    }
+
+    let new_york = chrono_tz::America::New_York;
+    let saturday = new_york
+        .with_ymd_and_hms(2024, 3, 9, 9, 0, 0)
+        .single()
+        .expect("not near a transition");
+    let plus_24h = saturday + Duration::hours(24);
+    let next_day = add_calendar_days(&saturday, 1).expect("in range");
+    println!("   🕘 {saturday} + 24h       → {plus_24h}");
+    println!("   📅 {saturday} + 1 วันปฏิทิน → {next_day} (ผ่านไปแค่ 23 ชั่วโมง)");
+
+    let friday = NaiveDate::from_ymd_opt(2025, 3, 14).expect("valid date");
+    println!(
+        "   💼 ศุกร์ {friday} + 3 วันทำการ = {}",
+        add_business_days(friday, 3).map_or_else(|| "-".to_string(), |d| d.to_string())
+    );
+    let january_end = NaiveDate::from_ymd_opt(2024, 1, 31).expect("valid date");
+    println!(
+        "   🗓️ {january_end} + 1 เดือน = {:?}",
+        january_end.checked_add_months(chrono::Months::new(1))
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono_tz::America::New_York;
+
+    #[test]
+    fn test_parse_and_format_duration() {
+        assert_eq!(parse_duration("1h30m"), Ok(Duration::minutes(90)));
+        assert_eq!(
+            parse_duration(" 2d 4h 5s "),
+            Ok(Duration::hours(52) + Duration::seconds(5))
+        );
+        assert_eq!(parse_duration("-250ms"), Ok(Duration::milliseconds(-250)));
+        assert_eq!(parse_duration(""), Err(ParseDurationError::Empty));
+        assert_eq!(parse_duration("90"), Err(ParseDurationError::MissingUnit));
+        assert_eq!(
+            parse_duration("3w"),
+            Err(ParseDurationError::UnknownUnit("w".to_string()))
+        );
+        assert_eq!(
+            parse_duration("99999999999999999999d"),
+            Err(ParseDurationError::Overflow)
+        );
+
+        assert_eq!(format_duration(Duration::seconds(93_784)), "1d 2h 3m 4s");
+        assert_eq!(format_duration(Duration::minutes(-90)), "-1h 30m");
+        assert_eq!(format_duration(Duration::zero()), "0s");
+        let round_trip = Duration::seconds(200_000);
+        assert_eq!(parse_duration(&format_duration(round_trip)), Ok(round_trip));
+    }
+
+    #[test]
+    fn test_time_ago_thresholds() {
+        let now = Utc.with_ymd_and_hms(2025, 3, 14, 12, 0, 0).unwrap();
+        let ago = |duration: Duration| time_ago(now - duration, now);
+        assert_eq!(ago(Duration::seconds(44)), "เมื่อสักครู่");
+        assert_eq!(ago(Duration::seconds(45)), "1 นาทีที่แล้ว");
+        assert_eq!(ago(Duration::minutes(44)), "44 นาทีที่แล้ว");
+        assert_eq!(ago(Duration::minutes(45)), "1 ชั่วโมงที่แล้ว");
+        assert_eq!(ago(Duration::hours(21)), "21 ชั่วโมงที่แล้ว");
+        assert_eq!(ago(Duration::hours(22)), "1 วันที่แล้ว");
+        assert_eq!(ago(Duration::days(40)), "1 เดือนที่แล้ว");
+        assert_eq!(ago(Duration::days(400)), "1 ปีที่แล้ว");
+        assert_eq!(ago(Duration::days(-3)), "อีก 3 วัน");
+    }
+
+    #[test]
+    fn test_calendar_days_across_dst() {
+        // spring forward: วันปฏิทินยาว 23 ชั่วโมง
+        let before = New_York.with_ymd_and_hms(2024, 3, 9, 9, 0, 0).unwrap();
+        let next = add_calendar_days(&before, 1).unwrap();
+        assert_eq!(next.naive_local().to_string(), "2024-03-10 09:00:00");
+        assert_eq!(next - before, Duration::hours(23));
+        assert_ne!(before + Duration::days(1), next);
+
+        // fall back: 25 ชั่วโมง
+        let before = New_York.with_ymd_and_hms(2024, 11, 2, 9, 0, 0).unwrap();
+        let next = add_calendar_days(&before, 1).unwrap();
+        assert_eq!(next - before, Duration::hours(25));
+
+        // 02:30 ไม่มีในวันที่ 10 → เลื่อนเป็น 03:30
+        let night = New_York.with_ymd_and_hms(2024, 3, 9, 2, 30, 0).unwrap();
+        let shifted = add_calendar_days(&night, 1).unwrap();
+        assert_eq!(shifted.naive_local().to_string(), "2024-03-10 03:30:00");
+    }
+
+    #[test]
+    fn test_business_days() {
+        let friday = NaiveDate::from_ymd_opt(2025, 3, 14).unwrap();
+        assert_eq!(
+            add_business_days(friday, 1),
+            NaiveDate::from_ymd_opt(2025, 3, 17)
+        );
+        assert_eq!(
+            add_business_days(friday, 5),
+            NaiveDate::from_ymd_opt(2025, 3, 21)
+        );
+        assert_eq!(add_business_days(friday, 0), Some(friday));
+    }
+}
@@ -0,0 +1,200 @@
+//! 🗓️ Parsing & Formatting - อ่านวันเวลาจาก string หลายรูปแบบ และพิมพ์ออกตาม format ที่ต้องการ
+//!
+//! - 📥 `parse_utc` - ลอง RFC 3339, RFC 2822 แล้วค่อยลองรูปแบบที่คนชอบพิมพ์ (ไม่มี offset = ถือว่า UTC)
+//! - 🔁 `to_iso8601` / `from_iso8601` - แปลงไปกลับโดยไม่เสีย offset หรือเศษวินาที
+//! - 🇹🇭 `format_thai_date` - วันที่แบบไทย พ.ศ.
+//!
+//! 💡 เก็บ/ส่งเวลาเป็น ISO-8601 ที่มี offset เสมอ - string ที่ไม่มี offset คือเวลาที่ไม่รู้ว่าอยู่โซนไหน
+
+use std::fmt;
+
+use chrono::{
+    DateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, SecondsFormat, TimeZone, Utc,
+};
+
+/// รูปแบบที่ไม่มี offset ที่ `parse_utc` รับ (ลองตามลำดับ)
+pub const NAIVE_FORMATS: [&str; 4] = [
+    "%Y-%m-%d %H:%M:%S",
+    "%Y-%m-%dT%H:%M:%S",
+    "%Y-%m-%d %H:%M",
+    "%d/%m/%Y %H:%M",
+];
+
+const THAI_MONTHS: [&str; 12] = [
+    "มกราคม",
+    "กุมภาพันธ์",
+    "มีนาคม",
+    "เมษายน",
+    "พฤษภาคม",
+    "มิถุนายน",
+    "กรกฎาคม",
+    "สิงหาคม",
+    "กันยายน",
+    "ตุลาคม",
+    "พฤศจิกายน",
+    "ธันวาคม",
+];
+
+/// ❌ string ไม่ตรงกับรูปแบบใดเลย
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDateTimeError {
+    pub input: String,
+}
+
+impl fmt::Display for ParseDateTimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}` is not a recognised date/time", self.input)
+    }
+}
+
+impl std::error::Error for ParseDateTimeError {}
+
+/// 📥 อ่านวันเวลาได้หลายรูปแบบ แล้วแปลงเป็น UTC
+///
+/// # Errors
+/// [`ParseDateTimeError`] เมื่อไม่ตรงกับรูปแบบใดเลย
+pub fn parse_utc(text: &str) -> Result<DateTime<Utc>, ParseDateTimeError> {
+    let text = text.trim();
+    if let Ok(parsed) = DateTime::parse_from_rfc3339(text) {
+        return Ok(parsed.with_timezone(&Utc));
+    }
+    if let Ok(parsed) = DateTime::parse_from_rfc2822(text) {
+        return Ok(parsed.with_timezone(&Utc));
+    }
+    NAIVE_FORMATS
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(text, format).ok())
+        .or_else(|| {
+            NaiveDate::parse_from_str(text, "%Y-%m-%d")
+                .ok()
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+        })
+        .map(|naive| naive.and_utc())
+        .ok_or_else(|| ParseDateTimeError {
+            input: text.to_string(),
+        })
+}
+
+/// 🔁 ISO-8601 (โปรไฟล์ RFC 3339) ที่เก็บ offset เดิมไว้และใส่เศษวินาทีเท่าที่มีจริง
+///
+/// UTC เขียนเป็น `Z` ส่วนโซนอื่นเขียนเป็น `+07:00`
+#[must_use]
+pub fn to_iso8601<Tz: TimeZone>(value: &DateTime<Tz>) -> String
+where
+    Tz::Offset: fmt::Display,
+{
+    value.to_rfc3339_opts(SecondsFormat::AutoSi, true)
+}
+
+/// 🔁 อ่านกลับจาก [`to_iso8601`] - ได้ instant และ offset เดิม
+///
+/// # Errors
+/// [`ParseDateTimeError`] เมื่อไม่ใช่ RFC 3339
+pub fn from_iso8601(text: &str) -> Result<DateTime<FixedOffset>, ParseDateTimeError> {
+    DateTime::parse_from_rfc3339(text).map_err(|_| ParseDateTimeError {
+        input: text.to_string(),
+    })
+}
+
+/// 🇹🇭 "16 ตุลาคม พ.ศ. 2569" - ปีพุทธศักราช = ค.ศ. + 543
+#[must_use]
+pub fn format_thai_date(date: NaiveDate) -> String {
+    format!(
+        "{} {} พ.ศ. {}",
+        date.day(),
+        THAI_MONTHS[date.month0() as usize],
+        date.year() + 543
+    )
+}
+
+/// 🎪 เดโม: อ่านหลายรูปแบบ, strftime และ ISO-8601 ไปกลับ
+///
+/// # Panics
+/// ไม่เกิดขึ้นจริง - ค่าเวลาในเดโมเป็นค่าคงที่ที่ถูกต้อง
+pub fn demonstrate_formatting() {
+    for input in [
+        "2025-03-14T18:30:00+07:00",
+        "Fri, 14 Mar 2025 11:30:00 GMT",
+        "2025-03-14 11:30",
+        "14/03/2025 11:30",
+        "next friday",
+    ] {
+        match parse_utc(input) {
+            Ok(parsed) => println!("   📥 {input:<32} → {parsed}"),
+            Err(error) => println!("   ❌ {error}"),
+        }
+    }
+
+    let bangkok = FixedOffset::east_opt(7 * 3600).expect("valid offset");
+    let moment = bangkok
+        .with_ymd_and_hms(2025, 3, 14, 18, 30, 5)
+        .single()
+        .expect("fixed offsets are never ambiguous");
+    println!("   🖨️ %A %d %B %Y    → {}", moment.format("%A %d %B %Y"));
+    println!("   🖨️ %H:%M:%S %:z    → {}", moment.format("%H:%M:%S %:z"));
+    println!("   🖨️ %G-W%V (ISO week) → {}", moment.format("%G-W%V"));
+    println!("   🇹🇭 {}", format_thai_date(moment.date_naive()));
+
+    let precise = moment + chrono::Duration::milliseconds(250);
+    let text = to_iso8601(&precise);
+    let back = from_iso8601(&text).expect("round trip");
+    println!("   🔁 {text} → อ่านกลับได้ instant เดิม: {}", back == precise);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_parse_many_formats() {
+        let expected = Utc.with_ymd_and_hms(2025, 3, 14, 11, 30, 0).unwrap();
+        for input in [
+            "2025-03-14T18:30:00+07:00",
+            "2025-03-14T11:30:00Z",
+            "Fri, 14 Mar 2025 11:30:00 +0000",
+            "2025-03-14 11:30:00",
+            "2025-03-14T11:30:00",
+            " 2025-03-14 11:30 ",
+            "14/03/2025 11:30",
+        ] {
+            assert_eq!(parse_utc(input).unwrap(), expected, "{input}");
+        }
+        assert_eq!(
+            parse_utc("2025-03-14").unwrap(),
+            Utc.with_ymd_and_hms(2025, 3, 14, 0, 0, 0).unwrap()
+        );
+        assert!(parse_utc("2025-02-30 10:00").is_err());
+        assert_eq!(
+            format_thai_date(NaiveDate::from_ymd_opt(2026, 10, 16).unwrap()),
+            "16 ตุลาคม พ.ศ. 2569"
+        );
+    }
+
+    #[test]
+    fn test_iso8601_keeps_offset_and_precision() {
+        for text in [
+            "2025-03-14T11:30:00Z",
+            "2025-03-14T18:30:00.250+07:00",
+            "1969-12-31T23:59:59.999999999-03:30",
+        ] {
+            let parsed = from_iso8601(text).unwrap();
+            assert_eq!(to_iso8601(&parsed), text);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn prop_iso8601_round_trip(
+            seconds in -10_000_000_000_i64..10_000_000_000,
+            nanos in 0_u32..1_000_000_000,
+            offset_minutes in -14 * 60_i32..=14 * 60,
+        ) {
+            let offset = FixedOffset::east_opt(offset_minutes * 60).unwrap();
+            let value = DateTime::from_timestamp(seconds, nanos).unwrap().with_timezone(&offset);
+            let back = from_iso8601(&to_iso8601(&value)).unwrap();
+            prop_assert_eq!(back, value);
+            prop_assert_eq!(back.offset(), value.offset());
+        }
+    }
+}
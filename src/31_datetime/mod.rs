@@ -0,0 +1,38 @@
+//! 🗓️ Date & Time Module - วันเวลาด้วย chrono (ที่ crate นี้ re-export ไว้แล้ว) 🕰️
+//! เวลาเป็นเรื่องที่ดูง่ายแต่ bug เยอะที่สุดเรื่องหนึ่ง - โซนเวลา, DST และ "1 วัน" ที่ไม่ใช่ 24 ชั่วโมง
+//!
+//! 🎯 สิ่งที่จะได้เรียนรู้:
+//! - 📥 Parsing & formatting - หลายรูปแบบ, strftime, ISO-8601 ไปกลับ และวันที่แบบ พ.ศ.
+//! - 🌏 Time zones - แปลงข้ามโซนด้วย `chrono-tz`, เวลาที่หายไป/ซ้ำในวันเปลี่ยนเวลา
+//! - ⏳ Durations - "1h30m", "5 นาทีที่แล้ว", บวกวันตามปฏิทินและวันทำการ
+//! - ⏰ Cron - parse cron expression และหาเวลารันถัดไปแบบรู้จัก DST
+
+pub mod cron;
+pub mod durations;
+pub mod formatting;
+pub mod timezones;
+
+pub use chrono_tz::Tz;
+pub use cron::*;
+pub use durations::*;
+pub use formatting::*;
+pub use timezones::*;
+
+/// 🎪 เรียกใช้ตัวอย่าง Date & Time ทั้งหมด
+pub fn run_datetime_examples() {
+    println!("\n🗓️ === Date & Time: chrono, timezone และ cron === 🕰️");
+
+    let sections: [(&str, fn()); 4] = [
+        ("📥 Parsing & formatting:", demonstrate_formatting),
+        ("🌏 Time zones & DST:", demonstrate_timezones),
+        ("⏳ Durations:", demonstrate_durations),
+        ("⏰ Cron expressions:", demonstrate_cron),
+    ];
+    for (title, demo) in sections {
+        println!("{title}");
+        demo();
+        println!();
+    }
+
+    println!("💡 เก็บเวลาเป็น UTC แปลงเป็นเวลาท้องถิ่นตอนแสดงผลเท่านั้น");
+}
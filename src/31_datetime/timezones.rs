@@ -0,0 +1,198 @@
+//! 🌏 Time Zones - แปลงเวลาข้ามโซนด้วยฐานข้อมูล IANA (`chrono-tz`)
+//!
+//! instant เดียวกันแสดงได้หลายเวลาท้องถิ่น แต่เวลาท้องถิ่นหนึ่งอาจได้ instant 0, 1 หรือ 2 ค่า:
+//! - 🕳️ ช่วงที่หายไป (spring forward) - 02:30 ของวันเปลี่ยนเวลาใน New York ไม่มีอยู่จริง
+//! - 👯 ช่วงที่ซ้ำ (fall back) - 01:30 เกิดขึ้นสองครั้ง (EDT แล้วค่อย EST)
+//!
+//! `from_local_strict` บังคับให้คนเรียกตัดสินใจเอง ส่วน `from_local_lenient` เลือกให้ตามกติกาที่ปฏิทินส่วนใหญ่ใช้
+
+use std::fmt;
+
+use chrono::{DateTime, Duration, FixedOffset, LocalResult, NaiveDateTime, Offset, TimeZone, Utc};
+use chrono_tz::{OffsetComponents, Tz};
+
+/// ❌ เวลาท้องถิ่นแปลงเป็น instant เดียวไม่ได้
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LocalTimeError {
+    /// อยู่ในช่วงที่นาฬิกากระโดดข้าม
+    Nonexistent(NaiveDateTime),
+    /// เกิดขึ้นสองครั้ง - ให้ทั้งสองค่ามาเลือกเอง
+    Ambiguous {
+        earliest: DateTime<FixedOffset>,
+        latest: DateTime<FixedOffset>,
+    },
+}
+
+impl fmt::Display for LocalTimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Nonexistent(local) => write!(f, "{local} is skipped by a DST transition"),
+            Self::Ambiguous { earliest, latest } => {
+                write!(f, "local time is ambiguous: {earliest} or {latest}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for LocalTimeError {}
+
+/// 🔒 แปลงเวลาท้องถิ่นเป็น instant - error ถ้าหายไปหรือซ้ำ
+///
+/// # Errors
+/// [`LocalTimeError`] เมื่อเวลานั้นไม่มีอยู่จริงหรือเกิดสองครั้ง
+pub fn from_local_strict<Z: TimeZone>(
+    zone: &Z,
+    local: NaiveDateTime,
+) -> Result<DateTime<Z>, LocalTimeError> {
+    match zone.from_local_datetime(&local) {
+        LocalResult::Single(value) => Ok(value),
+        LocalResult::Ambiguous(earliest, latest) => Err(LocalTimeError::Ambiguous {
+            earliest: earliest.fixed_offset(),
+            latest: latest.fixed_offset(),
+        }),
+        LocalResult::None => Err(LocalTimeError::Nonexistent(local)),
+    }
+}
+
+/// 🔓 แปลงเวลาท้องถิ่นเป็น instant เสมอ
+///
+/// - ซ้ำ → เลือกครั้งแรก (earliest)
+/// - หายไป → ตีความด้วย offset ก่อนเปลี่ยนเวลา ผลคือเลื่อนไปข้างหน้าเท่าช่วงที่หายไป
+///   (02:30 ของวัน spring forward ใน New York → 03:30 EDT)
+#[must_use]
+pub fn from_local_lenient<Z: TimeZone>(zone: &Z, local: NaiveDateTime) -> Option<DateTime<Z>> {
+    match zone.from_local_datetime(&local) {
+        LocalResult::Single(value) | LocalResult::Ambiguous(value, _) => Some(value),
+        LocalResult::None => {
+            // ช่วงที่หายไปของทุกโซนจริงสั้นกว่า 3 ชั่วโมง
+            let before = zone
+                .from_local_datetime(&(local - Duration::hours(3)))
+                .earliest()?;
+            let offset = before.offset().fix();
+            let instant = offset.from_local_datetime(&local).single()?;
+            Some(instant.with_timezone(zone))
+        }
+    }
+}
+
+/// ☀️ ณ instant นั้น โซนนี้อยู่ในช่วง daylight saving หรือไม่
+#[must_use]
+pub fn is_dst(zone: Tz, at: DateTime<Utc>) -> bool {
+    !zone
+        .offset_from_utc_datetime(&at.naive_utc())
+        .dst_offset()
+        .is_zero()
+}
+
+/// 🕐 นาฬิกาโลก: instant เดียวกันในหลายโซน
+#[must_use]
+pub fn world_clock(at: DateTime<Utc>, zones: &[Tz]) -> Vec<DateTime<Tz>> {
+    zones.iter().map(|zone| at.with_timezone(zone)).collect()
+}
+
+/// 🎪 เดโม: นาฬิกาโลก และเวลาที่หายไป/ซ้ำในวันเปลี่ยนเวลา
+///
+/// # Panics
+/// ไม่เกิดขึ้นจริง - วันเวลาในเดโมเป็นค่าคงที่ที่ถูกต้อง
+pub fn demonstrate_timezones() {
+    let meeting = Utc
+        .with_ymd_and_hms(2025, 3, 14, 9, 0, 0)
+        .single()
+        .expect("UTC is never ambiguous");
+    println!("   🕐 ประชุม {meeting} ตรงกับ:");
+    let zones = [
+        chrono_tz::Asia::Bangkok,
+        chrono_tz::Europe::London,
+        chrono_tz::America::New_York,
+        chrono_tz::Australia::Sydney,
+    ];
+    for local in world_clock(meeting, &zones) {
+        let marker = if is_dst(local.timezone(), meeting) {
+            "☀️ DST"
+        } else {
+            ""
+        };
+        println!(
+            "      {:<20} {} {marker}",
+            local.timezone().name(),
+            local.format("%a %H:%M %Z")
+        );
+    }
+
+    let new_york = chrono_tz::America::New_York;
+    let at = |month: u32, day: u32, hour: u32| {
+        chrono::NaiveDate::from_ymd_opt(2024, month, day)
+            .and_then(|date| date.and_hms_opt(hour, 30, 0))
+            .expect("valid local time")
+    };
+    // 10 มี.ค. 2024 นาฬิกากระโดด 02:00 → 03:00, 3 พ.ย. 2024 ถอย 02:00 → 01:00
+    for local in [at(3, 9, 2), at(3, 10, 2), at(11, 3, 1)] {
+        match from_local_strict(&new_york, local) {
+            Ok(value) => println!("   ✅ {local} → {value}"),
+            Err(error) => println!(
+                "   ⚠️ {error}\n      lenient → {}",
+                from_local_lenient(&new_york, local)
+                    .map_or_else(|| "-".to_string(), |v| v.to_string())
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+    use chrono_tz::America::New_York;
+    use chrono_tz::Australia::Sydney;
+
+    fn local(y: i32, mo: u32, d: u32, h: u32, mi: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(y, mo, d)
+            .unwrap()
+            .and_hms_opt(h, mi, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_spring_forward_gap() {
+        let missing = local(2024, 3, 10, 2, 30);
+        assert_eq!(
+            from_local_strict(&New_York, missing),
+            Err(LocalTimeError::Nonexistent(missing))
+        );
+        let shifted = from_local_lenient(&New_York, missing).unwrap();
+        assert_eq!(shifted.naive_local(), local(2024, 3, 10, 3, 30));
+        assert_eq!(
+            shifted.with_timezone(&Utc),
+            Utc.with_ymd_and_hms(2024, 3, 10, 7, 30, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_fall_back_overlap() {
+        let twice = local(2024, 11, 3, 1, 30);
+        let Err(LocalTimeError::Ambiguous { earliest, latest }) =
+            from_local_strict(&New_York, twice)
+        else {
+            panic!("01:30 on fall-back day must be ambiguous");
+        };
+        assert_eq!(earliest.offset().local_minus_utc(), -4 * 3600);
+        assert_eq!(latest.offset().local_minus_utc(), -5 * 3600);
+        assert_eq!(latest - earliest, Duration::hours(1));
+        assert_eq!(
+            from_local_lenient(&New_York, twice).unwrap().fixed_offset(),
+            earliest
+        );
+    }
+
+    #[test]
+    fn test_dst_flags_and_southern_hemisphere() {
+        let january = Utc.with_ymd_and_hms(2025, 1, 15, 0, 0, 0).unwrap();
+        let july = Utc.with_ymd_and_hms(2025, 7, 15, 0, 0, 0).unwrap();
+        assert!(!is_dst(New_York, january));
+        assert!(is_dst(New_York, july));
+        // ซีกโลกใต้กลับด้าน
+        assert!(is_dst(Sydney, january));
+        assert!(!is_dst(Sydney, july));
+        assert!(!is_dst(chrono_tz::Asia::Bangkok, july));
+    }
+}
@@ -0,0 +1,833 @@
+//! 🔢 `BigUint` - จำนวนเต็มไม่มีเครื่องหมายขนาดไม่จำกัด เก็บเป็น limb ละ 64 bit
+//!
+//! `u128` ใหญ่สุดแค่ ~3.4 × 10³⁸ แต่ RSA ใช้เลข 2048 bit และ target ของ blockchain ยาว 256 bit
+//! จึงต้องเก็บเลขเป็น "หลัก" ฐาน 2⁶⁴ หลายหลักต่อกัน แล้วทำเลขคณิตแบบตั้งหลักบวก/คูณ/หารยาวเหมือนเรียนประถม
+//!
+//! - 📦 `limbs[0]` คือหลักต่ำสุด (little-endian) และไม่มี limb ศูนย์ค้างด้านบน
+//!   ค่า 0 จึงเป็น `Vec` ว่าง - ทำให้ `==` กับ `Hash` ที่ derive มาถูกต้องเสมอ
+//! - ➗ หารด้วย Algorithm D ของ Knuth (ประมาณผลหารทีละหลักด้วย `u128`)
+//! - 🔐 [`BigUint::pow_mod`], [`BigUint::mod_inverse`] และ Miller-Rabin พอสำหรับ RSA ในบทที่ 20
+//!
+//! ⚠️ เพื่อการเรียนรู้: ไม่ได้ทำงานแบบ constant-time จึงรั่วข้อมูลผ่านเวลาที่ใช้ - ห้ามใช้กับกุญแจจริง
+
+use std::cmp::Ordering;
+use std::fmt::{self, Write as _};
+use std::ops::{Add, Div, Mul, Rem, Shl, Shr, Sub};
+use std::str::FromStr;
+
+use rand::Rng;
+
+/// 🔢 จำนวนเต็มบวกขนาดไม่จำกัด
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigUint {
+    limbs: Vec<u64>,
+}
+
+/// ❌ ข้อความที่แปลงเป็น [`BigUint`] ไม่ได้
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseBigUintError {
+    Empty,
+    InvalidDigit(char),
+}
+
+impl fmt::Display for ParseBigUintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "cannot parse integer from empty string"),
+            Self::InvalidDigit(c) => write!(f, "invalid digit {c:?} in integer"),
+        }
+    }
+}
+
+impl std::error::Error for ParseBigUintError {}
+
+impl BigUint {
+    #[must_use]
+    pub const fn zero() -> Self {
+        Self { limbs: Vec::new() }
+    }
+
+    #[must_use]
+    pub fn one() -> Self {
+        Self::from(1_u64)
+    }
+
+    /// สร้างจาก limb แบบ little-endian (ตัด limb ศูนย์ด้านบนออกให้)
+    #[must_use]
+    pub fn from_limbs(limbs: Vec<u64>) -> Self {
+        let mut value = Self { limbs };
+        value.normalize();
+        value
+    }
+
+    #[must_use]
+    pub fn limbs(&self) -> &[u64] {
+        &self.limbs
+    }
+
+    fn normalize(&mut self) {
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+    }
+
+    /// สร้างจาก byte แบบ big-endian (รูปแบบเดียวกับ hash และกุญแจในไฟล์)
+    #[must_use]
+    pub fn from_bytes_be(bytes: &[u8]) -> Self {
+        let limbs = bytes
+            .rchunks(8)
+            .map(|chunk| {
+                let mut word = [0_u8; 8];
+                word[8 - chunk.len()..].copy_from_slice(chunk);
+                u64::from_be_bytes(word)
+            })
+            .collect();
+        Self::from_limbs(limbs)
+    }
+
+    /// byte แบบ big-endian ไม่มี 0 นำหน้า (ค่า 0 = slice ว่าง)
+    #[must_use]
+    pub fn to_bytes_be(&self) -> Vec<u8> {
+        let bytes: Vec<u8> = self
+            .limbs
+            .iter()
+            .rev()
+            .flat_map(|limb| limb.to_be_bytes())
+            .collect();
+        let leading_zeros = bytes.iter().take_while(|&&b| b == 0).count();
+        bytes[leading_zeros..].to_vec()
+    }
+
+    /// แปลงจากเลขฐาน 16 (ไม่มี `0x` นำหน้า, ใส่ `_` คั่นได้)
+    ///
+    /// # Errors
+    /// [`ParseBigUintError`] เมื่อข้อความว่างหรือมีตัวอักษรที่ไม่ใช่เลขฐาน 16
+    pub fn from_hex(text: &str) -> Result<Self, ParseBigUintError> {
+        Self::parse_radix(text, 16)
+    }
+
+    fn parse_radix(text: &str, radix: u32) -> Result<Self, ParseBigUintError> {
+        let mut value = Self::zero();
+        let mut digits = 0;
+        for c in text.chars().filter(|&c| c != '_') {
+            let digit = c
+                .to_digit(radix)
+                .ok_or(ParseBigUintError::InvalidDigit(c))?;
+            value.mul_add_small(u64::from(radix), u64::from(digit));
+            digits += 1;
+        }
+        if digits == 0 {
+            return Err(ParseBigUintError::Empty);
+        }
+        Ok(value)
+    }
+
+    /// `self = self * factor + addend` ในที่เดิม - ใช้ตอน parse
+    #[allow(clippy::cast_possible_truncation)] // แยก u128 เป็น limb ล่าง/carry โดยตั้งใจ
+    fn mul_add_small(&mut self, factor: u64, addend: u64) {
+        let mut carry = u128::from(addend);
+        for limb in &mut self.limbs {
+            let product = u128::from(*limb) * u128::from(factor) + carry;
+            *limb = product as u64;
+            carry = product >> 64;
+        }
+        if carry > 0 {
+            self.limbs.push(carry as u64);
+        }
+        self.normalize();
+    }
+
+    #[must_use]
+    pub const fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    #[must_use]
+    pub fn is_even(&self) -> bool {
+        self.limbs.first().is_none_or(|limb| limb & 1 == 0)
+    }
+
+    /// จำนวน bit ที่ต้องใช้ (0 สำหรับค่า 0)
+    #[must_use]
+    pub fn bits(&self) -> u64 {
+        self.limbs.last().map_or(0, |top| {
+            self.limbs.len() as u64 * 64 - u64::from(top.leading_zeros())
+        })
+    }
+
+    /// bit ที่ `index` (นับจาก 0 = bit ต่ำสุด)
+    #[must_use]
+    pub fn bit(&self, index: u64) -> bool {
+        usize::try_from(index / 64)
+            .ok()
+            .and_then(|limb| self.limbs.get(limb))
+            .is_some_and(|limb| limb >> (index % 64) & 1 == 1)
+    }
+
+    #[must_use]
+    pub fn to_u64(&self) -> Option<u64> {
+        match self.limbs.as_slice() {
+            [] => Some(0),
+            [low] => Some(*low),
+            _ => None,
+        }
+    }
+
+    /// ค่าประมาณแบบ `f64` (ค่าที่เกิน ~1.8 × 10³⁰⁸ จะได้ `inf`)
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)] // ค่าประมาณโดยตั้งใจ
+    pub fn to_f64(&self) -> f64 {
+        self.limbs.iter().rev().fold(0.0, |acc, &limb| {
+            acc.mul_add(18_446_744_073_709_551_616.0, limb as f64)
+        })
+    }
+
+    /// ลบแบบไม่ panic - `None` เมื่อ `other > self`
+    #[must_use]
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        if *self < *other {
+            return None;
+        }
+        let mut limbs = self.limbs.clone();
+        let mut borrow = false;
+        for (index, limb) in limbs.iter_mut().enumerate() {
+            let rhs = other.limbs.get(index).copied().unwrap_or(0);
+            if rhs == 0 && !borrow {
+                if index >= other.limbs.len() {
+                    break;
+                }
+                continue;
+            }
+            let (difference, under_a) = limb.overflowing_sub(rhs);
+            let (difference, under_b) = difference.overflowing_sub(u64::from(borrow));
+            *limb = difference;
+            borrow = under_a || under_b;
+        }
+        Some(Self::from_limbs(limbs))
+    }
+
+    /// หารพร้อมเศษ `(self / divisor, self % divisor)`
+    ///
+    /// # Panics
+    /// เมื่อ `divisor` เป็น 0
+    #[must_use]
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        assert!(!divisor.is_zero(), "attempt to divide BigUint by zero");
+        if *self < *divisor {
+            return (Self::zero(), self.clone());
+        }
+        if let [small] = divisor.limbs.as_slice() {
+            let (quotient, remainder) = self.div_rem_small(*small);
+            return (quotient, Self::from(remainder));
+        }
+        self.div_rem_knuth(divisor)
+    }
+
+    /// หารด้วยเลขหลักเดียว: หารยาวทีละ limb จากบนลงล่าง
+    #[allow(clippy::cast_possible_truncation)] // ผลหารแต่ละหลัก < 2⁶⁴ เสมอเพราะเศษ < divisor
+    fn div_rem_small(&self, divisor: u64) -> (Self, u64) {
+        let divisor = u128::from(divisor);
+        let mut remainder = 0_u128;
+        let mut quotient = vec![0_u64; self.limbs.len()];
+        for (index, &limb) in self.limbs.iter().enumerate().rev() {
+            let current = (remainder << 64) | u128::from(limb);
+            quotient[index] = (current / divisor) as u64;
+            remainder = current % divisor;
+        }
+        (Self::from_limbs(quotient), remainder as u64)
+    }
+
+    /// Knuth, TAOCP เล่ม 2 §4.3.1 Algorithm D
+    ///
+    /// เดาผลหารแต่ละหลักจาก 2 หลักบนของตัวตั้งหารด้วยหลักบนของตัวหาร
+    /// หลัง normalize ให้ bit บนสุดของตัวหารเป็น 1 ค่าที่เดาจะเกินจริงไม่เกิน 2 เสมอ
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // ตัด u128/i128 เหลือ limb ล่าง
+    fn div_rem_knuth(&self, divisor: &Self) -> (Self, Self) {
+        let shift = divisor
+            .limbs
+            .last()
+            .map_or(0, |top| top.leading_zeros() as usize);
+        let v = (divisor << shift).limbs;
+        let mut u = (self << shift).limbs;
+        u.resize(self.limbs.len() + 1, 0);
+        let n = v.len();
+        let m = u.len() - n;
+        let base = 1_u128 << 64;
+        let mut quotient = vec![0_u64; m];
+
+        for j in (0..m).rev() {
+            let numerator = (u128::from(u[j + n]) << 64) | u128::from(u[j + n - 1]);
+            let mut q_hat = numerator / u128::from(v[n - 1]);
+            let mut r_hat = numerator % u128::from(v[n - 1]);
+            while q_hat >= base
+                || q_hat * u128::from(v[n - 2]) > ((r_hat << 64) | u128::from(u[j + n - 2]))
+            {
+                q_hat -= 1;
+                r_hat += u128::from(v[n - 1]);
+                if r_hat >= base {
+                    break;
+                }
+            }
+
+            // u[j..=j+n] -= q_hat * v
+            let mut borrow = 0_i128;
+            let mut carry = 0_u128;
+            for i in 0..n {
+                let product = q_hat * u128::from(v[i]) + carry;
+                carry = product >> 64;
+                let difference = i128::from(u[i + j]) - borrow - i128::from(product as u64);
+                u[i + j] = difference as u64;
+                borrow = i128::from(difference < 0);
+            }
+            let difference = i128::from(u[j + n]) - borrow - i128::from(carry as u64);
+            u[j + n] = difference as u64;
+
+            if difference < 0 {
+                // เดาเกินไป 1 (เกิดน้อยมาก ~2/2⁶⁴) - บวกตัวหารคืนหนึ่งรอบ
+                q_hat -= 1;
+                let mut carry = 0_u128;
+                for i in 0..n {
+                    let sum = u128::from(u[i + j]) + u128::from(v[i]) + carry;
+                    u[i + j] = sum as u64;
+                    carry = sum >> 64;
+                }
+                u[j + n] = u[j + n].wrapping_add(carry as u64);
+            }
+            quotient[j] = q_hat as u64;
+        }
+
+        u.truncate(n);
+        (Self::from_limbs(quotient), Self::from_limbs(u) >> shift)
+    }
+
+    /// `self^exponent mod modulus` ด้วย square-and-multiply (ไล่ bit จากบนลงล่าง)
+    ///
+    /// # Panics
+    /// เมื่อ `modulus` เป็น 0
+    #[must_use]
+    pub fn pow_mod(&self, exponent: &Self, modulus: &Self) -> Self {
+        assert!(!modulus.is_zero(), "modulus must be non-zero");
+        if *modulus == Self::one() {
+            return Self::zero();
+        }
+        let base = self % modulus;
+        let mut result = Self::one();
+        for index in (0..exponent.bits()).rev() {
+            result = &(&result * &result) % modulus;
+            if exponent.bit(index) {
+                result = &(&result * &base) % modulus;
+            }
+        }
+        result
+    }
+
+    #[must_use]
+    pub fn gcd(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (self.clone(), other.clone());
+        while !b.is_zero() {
+            let remainder = &a % &b;
+            a = b;
+            b = remainder;
+        }
+        a
+    }
+
+    /// `x` ที่ทำให้ `self * x ≡ 1 (mod modulus)` - `None` เมื่อ gcd ไม่ใช่ 1
+    ///
+    /// extended Euclid แบบเก็บ coefficient ไว้ใน mod `modulus` ตลอด จึงไม่ต้องมีเลขติดลบ
+    #[must_use]
+    pub fn mod_inverse(&self, modulus: &Self) -> Option<Self> {
+        if modulus.is_zero() {
+            return None;
+        }
+        let (mut old_r, mut r) = (self % modulus, modulus.clone());
+        let (mut old_s, mut s) = (Self::one(), Self::zero());
+        while !r.is_zero() {
+            let (quotient, remainder) = old_r.div_rem(&r);
+            old_r = std::mem::replace(&mut r, remainder);
+            // new_s = old_s - quotient * s (mod modulus)
+            let step = &(&quotient * &s) % modulus;
+            let next = &(&(&old_s + modulus) - &step) % modulus;
+            old_s = std::mem::replace(&mut s, next);
+        }
+        (old_r == Self::one()).then(|| &old_s % modulus)
+    }
+
+    /// เลขสุ่มที่มีไม่เกิน `bits` bit
+    ///
+    /// # Panics
+    /// เมื่อ `bits` ใหญ่เกินกว่าจะจองหน่วยความจำได้
+    #[must_use]
+    pub fn random_bits<R: Rng + ?Sized>(bits: u64, rng: &mut R) -> Self {
+        let limbs = usize::try_from(bits.div_ceil(64)).expect("bit count fits in memory");
+        let mut value: Vec<u64> = (0..limbs).map(|_| rng.r#gen()).collect();
+        let extra = limbs as u64 * 64 - bits;
+        if let Some(top) = value.last_mut() {
+            *top >>= extra;
+        }
+        Self::from_limbs(value)
+    }
+
+    /// เลขสุ่มใน `[0, bound)` แบบสม่ำเสมอ (สุ่มใหม่ถ้าเกิน แทนการ `%` ที่ทำให้เอียง)
+    ///
+    /// # Panics
+    /// เมื่อ `bound` เป็น 0
+    #[must_use]
+    pub fn random_below<R: Rng + ?Sized>(bound: &Self, rng: &mut R) -> Self {
+        assert!(!bound.is_zero(), "bound must be positive");
+        loop {
+            let candidate = Self::random_bits(bound.bits(), rng);
+            if candidate < *bound {
+                return candidate;
+            }
+        }
+    }
+
+    /// Miller-Rabin `rounds` รอบ - ผิดพลาด (บอกว่า prime ทั้งที่ไม่ใช่) ได้ไม่เกิน 4^-rounds
+    ///
+    /// # Panics
+    /// ไม่ panic - จำนวน bit ที่ shift น้อยกว่าขนาดของตัวเลขเสมอ
+    #[must_use]
+    pub fn is_probable_prime<R: Rng + ?Sized>(&self, rounds: u32, rng: &mut R) -> bool {
+        const SMALL_PRIMES: [u64; 15] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47];
+        if self.to_u64().is_some_and(|value| value < 2) {
+            return false;
+        }
+        for prime in SMALL_PRIMES {
+            let (_, remainder) = self.div_rem_small(prime);
+            if remainder == 0 {
+                return self.to_u64() == Some(prime);
+            }
+        }
+
+        // self - 1 = d × 2^s โดย d เป็นเลขคี่
+        let one = Self::one();
+        let minus_one = self - &one;
+        let s = (0..minus_one.bits())
+            .find(|&bit| minus_one.bit(bit))
+            .unwrap_or(0);
+        let d = &minus_one >> usize::try_from(s).expect("shift fits in usize");
+        let two = Self::from(2_u64);
+        let witness_range = self - &Self::from(3_u64);
+
+        'witness: for _ in 0..rounds {
+            let a = &Self::random_below(&witness_range, rng) + &two;
+            let mut x = a.pow_mod(&d, self);
+            if x == one || x == minus_one {
+                continue;
+            }
+            for _ in 1..s {
+                x = &(&x * &x) % self;
+                if x == minus_one {
+                    continue 'witness;
+                }
+            }
+            return false;
+        }
+        true
+    }
+
+    /// prime สุ่มขนาด `bits` bit พอดี (ตั้ง 2 bit บนเพื่อให้ p × q ได้ `2 × bits` bit เต็ม)
+    ///
+    /// # Panics
+    /// เมื่อ `bits` น้อยกว่า 8
+    #[must_use]
+    pub fn random_prime<R: Rng + ?Sized>(bits: u64, rng: &mut R) -> Self {
+        assert!(bits >= 8, "prime needs at least 8 bits");
+        let top_bits = &Self::from(3_u64) << usize::try_from(bits - 2).expect("bits fit in usize");
+        loop {
+            let mut candidate = Self::random_bits(bits, rng);
+            candidate = &candidate | &top_bits;
+            if let Some(low) = candidate.limbs.first_mut() {
+                *low |= 1;
+            }
+            if candidate.is_probable_prime(20, rng) {
+                return candidate;
+            }
+        }
+    }
+}
+
+impl From<u64> for BigUint {
+    fn from(value: u64) -> Self {
+        Self::from_limbs(vec![value])
+    }
+}
+
+impl From<u128> for BigUint {
+    #[allow(clippy::cast_possible_truncation)] // แยกเป็น 2 limb โดยตั้งใจ
+    fn from(value: u128) -> Self {
+        Self::from_limbs(vec![value as u64, (value >> 64) as u64])
+    }
+}
+
+/// ฐาน 10 หรือฐาน 16 ถ้าขึ้นต้นด้วย `0x`
+impl FromStr for BigUint {
+    type Err = ParseBigUintError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        text.strip_prefix("0x")
+            .or_else(|| text.strip_prefix("0X"))
+            .map_or_else(|| Self::parse_radix(text, 10), Self::from_hex)
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // ตัดทีละ 19 หลักฐานสิบ (10¹⁹ คือกำลังของ 10 ที่ใหญ่ที่สุดที่ใส่ u64 ได้)
+        const CHUNK: u64 = 10_000_000_000_000_000_000;
+        let mut chunks = Vec::new();
+        let mut rest = self.clone();
+        while !rest.is_zero() {
+            let (quotient, remainder) = rest.div_rem_small(CHUNK);
+            chunks.push(remainder);
+            rest = quotient;
+        }
+        let mut text = chunks.pop().unwrap_or(0).to_string();
+        for chunk in chunks.iter().rev() {
+            let _ = write!(text, "{chunk:019}");
+        }
+        f.pad_integral(true, "", &text)
+    }
+}
+
+impl fmt::LowerHex for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut limbs = self.limbs.iter().rev();
+        let mut text = limbs
+            .next()
+            .map_or_else(|| "0".to_string(), |top| format!("{top:x}"));
+        for limb in limbs {
+            let _ = write!(text, "{limb:016x}");
+        }
+        f.pad_integral(true, "0x", &text)
+    }
+}
+
+impl fmt::Debug for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "BigUint({self:#x})")
+    }
+}
+
+impl Add for &BigUint {
+    type Output = BigUint;
+
+    fn add(self, other: &BigUint) -> BigUint {
+        let (long, short) = if self.limbs.len() >= other.limbs.len() {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        let mut limbs = Vec::with_capacity(long.limbs.len() + 1);
+        let mut carry = false;
+        for (index, &limb) in long.limbs.iter().enumerate() {
+            let (sum, over_a) = limb.overflowing_add(short.limbs.get(index).copied().unwrap_or(0));
+            let (sum, over_b) = sum.overflowing_add(u64::from(carry));
+            limbs.push(sum);
+            carry = over_a || over_b;
+        }
+        if carry {
+            limbs.push(1);
+        }
+        BigUint::from_limbs(limbs)
+    }
+}
+
+/// # Panics
+/// เมื่อผลลัพธ์ติดลบ (แบบเดียวกับ `u64` ในโหมด debug) - ใช้ [`BigUint::checked_sub`] ถ้าไม่แน่ใจ
+impl Sub for &BigUint {
+    type Output = BigUint;
+
+    fn sub(self, other: &BigUint) -> BigUint {
+        self.checked_sub(other)
+            .expect("attempt to subtract with overflow")
+    }
+}
+
+/// คูณแบบตั้งหลัก (schoolbook) O(n·m) - ผลคูณของสอง limb ใส่ `u128` ได้พอดี
+impl Mul for &BigUint {
+    type Output = BigUint;
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn mul(self, other: &BigUint) -> BigUint {
+        if self.is_zero() || other.is_zero() {
+            return BigUint::zero();
+        }
+        let mut limbs = vec![0_u64; self.limbs.len() + other.limbs.len()];
+        for (i, &a) in self.limbs.iter().enumerate() {
+            let mut carry = 0_u128;
+            for (j, &b) in other.limbs.iter().enumerate() {
+                let current = u128::from(a) * u128::from(b) + u128::from(limbs[i + j]) + carry;
+                limbs[i + j] = current as u64;
+                carry = current >> 64;
+            }
+            limbs[i + other.limbs.len()] = carry as u64;
+        }
+        BigUint::from_limbs(limbs)
+    }
+}
+
+impl Div for &BigUint {
+    type Output = BigUint;
+
+    fn div(self, other: &BigUint) -> BigUint {
+        self.div_rem(other).0
+    }
+}
+
+impl Rem for &BigUint {
+    type Output = BigUint;
+
+    fn rem(self, other: &BigUint) -> BigUint {
+        self.div_rem(other).1
+    }
+}
+
+impl std::ops::BitOr for &BigUint {
+    type Output = BigUint;
+
+    fn bitor(self, other: &BigUint) -> BigUint {
+        let len = self.limbs.len().max(other.limbs.len());
+        let limbs = (0..len)
+            .map(|i| self.limbs.get(i).unwrap_or(&0) | other.limbs.get(i).unwrap_or(&0))
+            .collect();
+        BigUint::from_limbs(limbs)
+    }
+}
+
+impl Shl<usize> for &BigUint {
+    type Output = BigUint;
+
+    fn shl(self, shift: usize) -> BigUint {
+        if self.is_zero() {
+            return BigUint::zero();
+        }
+        let (limb_shift, bit_shift) = (shift / 64, shift % 64);
+        let mut limbs = vec![0_u64; limb_shift];
+        if bit_shift == 0 {
+            limbs.extend_from_slice(&self.limbs);
+        } else {
+            let mut carry = 0;
+            for &limb in &self.limbs {
+                limbs.push((limb << bit_shift) | carry);
+                carry = limb >> (64 - bit_shift);
+            }
+            limbs.push(carry);
+        }
+        BigUint::from_limbs(limbs)
+    }
+}
+
+impl Shr<usize> for &BigUint {
+    type Output = BigUint;
+
+    fn shr(self, shift: usize) -> BigUint {
+        let (limb_shift, bit_shift) = (shift / 64, shift % 64);
+        let Some(rest) = self.limbs.get(limb_shift..) else {
+            return BigUint::zero();
+        };
+        if bit_shift == 0 {
+            return BigUint::from_limbs(rest.to_vec());
+        }
+        let limbs = rest
+            .iter()
+            .enumerate()
+            .map(|(i, &limb)| {
+                let high = rest.get(i + 1).map_or(0, |next| next << (64 - bit_shift));
+                (limb >> bit_shift) | high
+            })
+            .collect();
+        BigUint::from_limbs(limbs)
+    }
+}
+
+/// ให้ `a + b` ใช้ได้ทั้งค่าและ reference โดยเรียก impl ของ reference
+macro_rules! forward_owned_ops {
+    ($($trait:ident :: $method:ident),* $(,)?) => {
+        $(
+            impl $trait for BigUint {
+                type Output = Self;
+
+                fn $method(self, other: Self) -> Self {
+                    (&self).$method(&other)
+                }
+            }
+
+            impl $trait<&Self> for BigUint {
+                type Output = Self;
+
+                fn $method(self, other: &Self) -> Self {
+                    (&self).$method(other)
+                }
+            }
+        )*
+    };
+}
+
+forward_owned_ops!(Add::add, Sub::sub, Mul::mul, Div::div, Rem::rem);
+
+impl Shl<usize> for BigUint {
+    type Output = Self;
+
+    fn shl(self, shift: usize) -> Self {
+        &self << shift
+    }
+}
+
+impl Shr<usize> for BigUint {
+    type Output = Self;
+
+    fn shr(self, shift: usize) -> Self {
+        &self >> shift
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    fn big(text: &str) -> BigUint {
+        text.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse_and_format_known_values() {
+        let two_128 = &BigUint::one() << 128;
+        assert_eq!(
+            two_128.to_string(),
+            "340282366920938463463374607431768211456"
+        );
+        assert_eq!(
+            format!("{two_128:#x}"),
+            "0x100000000000000000000000000000000"
+        );
+        assert_eq!(big("0x1_0000_0000_0000_0000_0000_0000_0000_0000"), two_128);
+        assert_eq!(BigUint::zero().to_string(), "0");
+        assert_eq!(format!("{:>5}", BigUint::from(42_u64)), "   42");
+        assert_eq!(
+            "12a".parse::<BigUint>(),
+            Err(ParseBigUintError::InvalidDigit('a'))
+        );
+        assert_eq!("".parse::<BigUint>(), Err(ParseBigUintError::Empty));
+        assert_eq!(
+            BigUint::from_bytes_be(&[0, 0, 1, 2, 3]).to_bytes_be(),
+            [1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn test_arithmetic_against_known_values() {
+        let factorial_30 = (1..=30_u64).fold(BigUint::one(), |acc, n| acc * BigUint::from(n));
+        assert_eq!(
+            factorial_30.to_string(),
+            "265252859812191058636308480000000"
+        );
+        assert_eq!(
+            (&factorial_30 / &(1..=20_u64).fold(BigUint::one(), |acc, n| acc * BigUint::from(n)))
+                .to_string(),
+            "109027350432000"
+        );
+
+        // 2^128 + 1 = 59649589127497217 × 5704689200685129054721 (ตัวประกอบของ Fermat number F7)
+        let f7 = &(&BigUint::one() << 128) + &BigUint::one();
+        let p = big("59649589127497217");
+        let q = big("5704689200685129054721");
+        assert_eq!(&p * &q, f7);
+        assert_eq!(f7.div_rem(&q), (p, BigUint::zero()));
+        assert_eq!(big("1000").checked_sub(&big("1001")), None);
+        assert_eq!(
+            &big("0x10000000000000000") - &BigUint::one(),
+            BigUint::from(u64::MAX)
+        );
+    }
+
+    #[test]
+    fn test_modular_arithmetic() {
+        assert_eq!(big("4").pow_mod(&big("13"), &big("497")), big("445"));
+        assert_eq!(big("17").mod_inverse(&big("3120")), Some(big("2753")));
+        assert_eq!(big("6").mod_inverse(&big("9")), None);
+        assert_eq!(big("462").gcd(&big("1071")), big("21"));
+
+        // Fermat: a^(p-1) ≡ 1 (mod p) สำหรับ Mersenne prime 2^127 - 1
+        let m127 = &(&BigUint::one() << 127) - &BigUint::one();
+        let exponent = &m127 - &BigUint::one();
+        assert_eq!(big("3").pow_mod(&exponent, &m127), BigUint::one());
+    }
+
+    #[test]
+    fn test_miller_rabin() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let m127 = &(&BigUint::one() << 127) - &BigUint::one();
+        assert!(m127.is_probable_prime(20, &mut rng));
+        let f7 = &(&BigUint::one() << 128) + &BigUint::one();
+        assert!(!f7.is_probable_prime(20, &mut rng));
+        // 561 = 3 × 11 × 17 (Carmichael number หลอก Fermat test ได้ แต่ไม่รอด Miller-Rabin)
+        for (n, prime) in [
+            (0_u64, false),
+            (1, false),
+            (2, true),
+            (47, true),
+            (561, false),
+            (7919, true),
+        ] {
+            assert_eq!(
+                BigUint::from(n).is_probable_prime(10, &mut rng),
+                prime,
+                "{n}"
+            );
+        }
+
+        let prime = BigUint::random_prime(128, &mut rng);
+        assert_eq!(prime.bits(), 128);
+        assert!(prime.bit(126));
+    }
+
+    fn arb_biguint() -> impl Strategy<Value = BigUint> {
+        prop::collection::vec(any::<u64>(), 0..6).prop_map(BigUint::from_limbs)
+    }
+
+    proptest! {
+        #[test]
+        fn prop_matches_u128(a in any::<u64>(), b in any::<u64>()) {
+            let (x, y) = (BigUint::from(a), BigUint::from(b));
+            prop_assert_eq!(&x + &y, BigUint::from(u128::from(a) + u128::from(b)));
+            prop_assert_eq!(&x * &y, BigUint::from(u128::from(a) * u128::from(b)));
+            prop_assert_eq!(x.cmp(&y), a.cmp(&b));
+        }
+
+        #[test]
+        fn prop_div_rem_reconstructs(a in arb_biguint(), b in arb_biguint()) {
+            prop_assume!(!b.is_zero());
+            let (quotient, remainder) = a.div_rem(&b);
+            prop_assert!(remainder < b);
+            prop_assert_eq!(&(&quotient * &b) + &remainder, a);
+        }
+
+        #[test]
+        fn prop_add_sub_and_shift_round_trip(a in arb_biguint(), b in arb_biguint(), shift in 0_usize..200) {
+            prop_assert!(&(&a + &b) - &b == a);
+            prop_assert!(&(&a << shift) >> shift == a);
+            prop_assert!(BigUint::from_bytes_be(&a.to_bytes_be()) == a);
+            prop_assert_eq!(a.to_string().parse::<BigUint>(), Ok(a));
+        }
+    }
+}
@@ -0,0 +1,1176 @@
+//! 🗃️ Cache - trait กลางพร้อมนโยบาย eviction หลายแบบที่ใช้ร่วมกันทั้ง crate
+//!
+//! ทุกแบบ implement `Cache<K, V>` ตัวเดียวกัน จึงสลับนโยบายได้โดยไม่ต้องแก้โค้ดที่เรียกใช้:
+//! - 🕰️ `LruCache` - ไล่ตัวที่ไม่ได้ใช้นานที่สุด (O(1) ด้วย linked list บน `Vec`)
+//! - 🔥 `LfuCache` - ไล่ตัวที่ถูกใช้น้อยครั้งที่สุด ถ้าเสมอกันไล่ตัวที่ใช้ล่าสุดนานกว่า
+//! - ⏳ `TtlCache` - entry หมดอายุตามเวลาจาก `Clock` และเมื่อเต็มจะไล่ตัวที่ใกล้หมดอายุที่สุด
+//!
+//! ทุกแบบเลือก `BuildHasher` ได้ (ค่าเริ่มต้น `RandomState`) และห่อเพิ่มได้อีกชั้น:
+//! - 📈 `InstrumentedCache` - นับ hit/miss/eviction ลง `metrics::Registry`
+//! - 🔒 `SharedCache` / `ShardedCache` - ใช้ข้าม thread (ล็อกเดียว / แยกล็อกตาม hash ของ key)
+//!
+//! ```
+//! use rust_concepts::cache::{Cache, LruCache};
+//!
+//! let mut cache = LruCache::new(2);
+//! cache.insert("a", 1);
+//! cache.insert("b", 2);
+//! cache.get(&"a"); // "a" กลายเป็นตัวที่ใช้ล่าสุด
+//! assert_eq!(cache.insert("c", 3), Some(("b", 2)));
+//! ```
+
+use std::collections::hash_map::RandomState;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::hash::{BuildHasher, Hash};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
+
+use crate::clock::{Clock, MockClock, system_clock};
+use crate::metrics::{Counter, Gauge, Registry};
+
+/// 🗃️ พฤติกรรมร่วมของ cache ทุกนโยบาย
+pub trait Cache<K, V> {
+    /// อ่านค่าและนับเป็นการใช้งาน (LRU เลื่อนเป็นตัวล่าสุด, LFU เพิ่มความถี่)
+    fn get(&mut self, key: &K) -> Option<&V>;
+
+    /// ดูค่าโดยไม่กระทบลำดับการไล่
+    fn peek(&self, key: &K) -> Option<&V>;
+
+    /// ใส่ค่า - คืน entry ที่ถูกไล่ออกเพื่อหาที่ให้ (ถ้ามี)
+    ///
+    /// ถ้ามี key นี้อยู่แล้วจะแทนค่าเดิมและนับเป็นการใช้งาน ไม่ถือเป็นการไล่
+    fn insert(&mut self, key: K, value: V) -> Option<(K, V)>;
+
+    fn remove(&mut self, key: &K) -> Option<V>;
+
+    fn len(&self) -> usize;
+
+    /// จำนวน entry สูงสุด - `len()` ไม่มีวันเกินค่านี้
+    fn capacity(&self) -> usize;
+
+    fn clear(&mut self);
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn contains_key(&self, key: &K) -> bool {
+        self.peek(key).is_some()
+    }
+}
+
+fn assert_capacity(capacity: usize) {
+    assert!(capacity > 0, "cache capacity must be at least 1");
+}
+
+// ===== 🕰️ LRU =====
+
+const NIL: usize = usize::MAX;
+
+#[derive(Debug)]
+struct LruNode<K, V> {
+    key: K,
+    value: V,
+    prev: usize,
+    next: usize,
+}
+
+/// 🕰️ Least Recently Used - ทุกคำสั่งเป็น O(1)
+///
+/// node เก็บเรียงกันใน `Vec` และเชื่อมกันด้วย index แทน pointer (หัว = ใช้ล่าสุด, ท้าย = ตัวที่จะถูกไล่)
+/// ตอนลบใช้ `swap_remove` แล้วซ่อม link ของ node ที่ถูกย้ายมาแทน `Vec` จึงไม่มีรู
+#[derive(Debug)]
+pub struct LruCache<K, V, S = RandomState> {
+    map: HashMap<K, usize, S>,
+    nodes: Vec<LruNode<K, V>>,
+    head: usize,
+    tail: usize,
+    capacity: usize,
+}
+
+impl<K: Hash + Eq + Clone, V> LruCache<K, V> {
+    /// # Panics
+    ///
+    /// เมื่อ `capacity` เป็น 0
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self::with_hasher(capacity, RandomState::new())
+    }
+}
+
+impl<K: Hash + Eq + Clone, V, S: BuildHasher> LruCache<K, V, S> {
+    /// # Panics
+    ///
+    /// เมื่อ `capacity` เป็น 0
+    pub fn with_hasher(capacity: usize, hasher: S) -> Self {
+        assert_capacity(capacity);
+        Self {
+            map: HashMap::with_capacity_and_hasher(capacity, hasher),
+            nodes: Vec::with_capacity(capacity),
+            head: NIL,
+            tail: NIL,
+            capacity,
+        }
+    }
+
+    /// key เรียงจากใช้ล่าสุดไปเก่าสุด (ตัวท้ายคือตัวที่จะถูกไล่)
+    pub fn keys_by_recency(&self) -> impl Iterator<Item = &K> + '_ {
+        let mut cursor = self.head;
+        std::iter::from_fn(move || {
+            let node = self.nodes.get(cursor)?;
+            cursor = node.next;
+            Some(&node.key)
+        })
+    }
+
+    fn detach(&mut self, index: usize) {
+        let (prev, next) = (self.nodes[index].prev, self.nodes[index].next);
+        match prev {
+            NIL => self.head = next,
+            prev => self.nodes[prev].next = next,
+        }
+        match next {
+            NIL => self.tail = prev,
+            next => self.nodes[next].prev = prev,
+        }
+    }
+
+    fn attach_front(&mut self, index: usize) {
+        self.nodes[index].prev = NIL;
+        self.nodes[index].next = self.head;
+        match self.head {
+            NIL => self.tail = index,
+            head => self.nodes[head].prev = index,
+        }
+        self.head = index;
+    }
+
+    /// ถอด node ออกจาก list และ `Vec` - node ตัวสุดท้ายถูกย้ายมาแทนที่ `index`
+    fn take_node(&mut self, index: usize) -> LruNode<K, V> {
+        self.detach(index);
+        let node = self.nodes.swap_remove(index);
+        if index < self.nodes.len() {
+            let (prev, next) = (self.nodes[index].prev, self.nodes[index].next);
+            match prev {
+                NIL => self.head = index,
+                prev => self.nodes[prev].next = index,
+            }
+            match next {
+                NIL => self.tail = index,
+                next => self.nodes[next].prev = index,
+            }
+            if let Some(slot) = self.map.get_mut(&self.nodes[index].key) {
+                *slot = index;
+            }
+        }
+        node
+    }
+}
+
+impl<K: Hash + Eq + Clone, V, S: BuildHasher> Cache<K, V> for LruCache<K, V, S> {
+    fn get(&mut self, key: &K) -> Option<&V> {
+        let index = *self.map.get(key)?;
+        self.detach(index);
+        self.attach_front(index);
+        Some(&self.nodes[index].value)
+    }
+
+    fn peek(&self, key: &K) -> Option<&V> {
+        self.map.get(key).map(|&index| &self.nodes[index].value)
+    }
+
+    fn insert(&mut self, key: K, value: V) -> Option<(K, V)> {
+        if let Some(&index) = self.map.get(&key) {
+            self.nodes[index].value = value;
+            self.detach(index);
+            self.attach_front(index);
+            return None;
+        }
+
+        let evicted = (self.nodes.len() == self.capacity).then(|| {
+            let node = self.take_node(self.tail);
+            self.map.remove(&node.key);
+            (node.key, node.value)
+        });
+
+        let index = self.nodes.len();
+        self.map.insert(key.clone(), index);
+        self.nodes.push(LruNode {
+            key,
+            value,
+            prev: NIL,
+            next: NIL,
+        });
+        self.attach_front(index);
+        evicted
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        let index = self.map.remove(key)?;
+        Some(self.take_node(index).value)
+    }
+
+    fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    fn clear(&mut self) {
+        self.map.clear();
+        self.nodes.clear();
+        self.head = NIL;
+        self.tail = NIL;
+    }
+}
+
+// ===== 🔥 LFU =====
+
+#[derive(Debug)]
+struct LfuEntry<V> {
+    value: V,
+    frequency: u64,
+    last_used: u64,
+}
+
+/// 🔥 Least Frequently Used - O(log n) ต่อคำสั่ง
+///
+/// `order` เรียง `(ความถี่, ครั้งล่าสุดที่ใช้)` ตัวแรกของ `BTreeMap` จึงเป็นตัวที่ต้องไล่เสมอ
+/// ความถี่เสมอกันจะไล่ตัวที่ใช้ล่าสุดนานกว่า (LRU เป็นตัวตัดสิน)
+#[derive(Debug)]
+pub struct LfuCache<K, V, S = RandomState> {
+    entries: HashMap<K, LfuEntry<V>, S>,
+    order: BTreeMap<(u64, u64), K>,
+    tick: u64,
+    capacity: usize,
+}
+
+impl<K: Hash + Eq + Clone, V> LfuCache<K, V> {
+    /// # Panics
+    ///
+    /// เมื่อ `capacity` เป็น 0
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self::with_hasher(capacity, RandomState::new())
+    }
+}
+
+impl<K: Hash + Eq + Clone, V, S: BuildHasher> LfuCache<K, V, S> {
+    /// # Panics
+    ///
+    /// เมื่อ `capacity` เป็น 0
+    pub fn with_hasher(capacity: usize, hasher: S) -> Self {
+        assert_capacity(capacity);
+        Self {
+            entries: HashMap::with_capacity_and_hasher(capacity, hasher),
+            order: BTreeMap::new(),
+            tick: 0,
+            capacity,
+        }
+    }
+
+    /// จำนวนครั้งที่ key ถูกใช้ (นับการ insert ครั้งแรกด้วย)
+    pub fn frequency(&self, key: &K) -> Option<u64> {
+        self.entries.get(key).map(|entry| entry.frequency)
+    }
+
+    /// เพิ่มความถี่และย้ายตำแหน่งใน `order` - คืน entry ที่อัปเดตแล้ว
+    fn touch(&mut self, key: &K) -> Option<&mut LfuEntry<V>> {
+        let entry = self.entries.get_mut(key)?;
+        let owned = self
+            .order
+            .remove(&(entry.frequency, entry.last_used))
+            .expect("every entry has an order slot");
+        self.tick += 1;
+        entry.frequency += 1;
+        entry.last_used = self.tick;
+        self.order.insert((entry.frequency, entry.last_used), owned);
+        Some(entry)
+    }
+}
+
+impl<K: Hash + Eq + Clone, V, S: BuildHasher> Cache<K, V> for LfuCache<K, V, S> {
+    fn get(&mut self, key: &K) -> Option<&V> {
+        self.touch(key).map(|entry| &entry.value)
+    }
+
+    fn peek(&self, key: &K) -> Option<&V> {
+        self.entries.get(key).map(|entry| &entry.value)
+    }
+
+    fn insert(&mut self, key: K, value: V) -> Option<(K, V)> {
+        if let Some(entry) = self.touch(&key) {
+            entry.value = value;
+            return None;
+        }
+
+        let evicted = if self.entries.len() == self.capacity {
+            self.order.pop_first().and_then(|(_, victim)| {
+                let entry = self.entries.remove(&victim)?;
+                Some((victim, entry.value))
+            })
+        } else {
+            None
+        };
+
+        self.tick += 1;
+        self.order.insert((1, self.tick), key.clone());
+        self.entries.insert(
+            key,
+            LfuEntry {
+                value,
+                frequency: 1,
+                last_used: self.tick,
+            },
+        );
+        evicted
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        let entry = self.entries.remove(key)?;
+        self.order.remove(&(entry.frequency, entry.last_used));
+        Some(entry.value)
+    }
+
+    fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.order.clear();
+    }
+}
+
+// ===== ⏳ TTL =====
+
+#[derive(Debug)]
+struct TtlEntry<V> {
+    value: V,
+    expires_at: Instant,
+    sequence: u64,
+}
+
+/// ⏳ entry มีอายุจำกัด - เวลาอ่านจาก `Clock` ที่ฉีดเข้ามา tests จึงใช้ `MockClock` ได้
+///
+/// entry ที่หมดอายุจะไม่ถูกคืนจาก `get`/`peek` อีก แต่ยังกินที่จนกว่าจะถูกกวาด
+/// (`insert` กวาดให้อัตโนมัติเมื่อเต็ม หรือเรียก `purge_expired` เอง)
+#[derive(Debug)]
+pub struct TtlCache<K, V, S = RandomState> {
+    entries: HashMap<K, TtlEntry<V>, S>,
+    expiry: BTreeMap<(Instant, u64), K>,
+    ttl: Duration,
+    capacity: usize,
+    sequence: u64,
+    clock: Arc<dyn Clock>,
+}
+
+impl<K: Hash + Eq + Clone, V> TtlCache<K, V> {
+    /// # Panics
+    ///
+    /// เมื่อ `capacity` เป็น 0
+    #[must_use]
+    pub fn new(capacity: usize, ttl: Duration) -> Self {
+        Self::with_hasher(capacity, ttl, RandomState::new())
+    }
+}
+
+impl<K: Hash + Eq + Clone, V, S: BuildHasher> TtlCache<K, V, S> {
+    /// # Panics
+    ///
+    /// เมื่อ `capacity` เป็น 0
+    pub fn with_hasher(capacity: usize, ttl: Duration, hasher: S) -> Self {
+        assert_capacity(capacity);
+        Self {
+            entries: HashMap::with_capacity_and_hasher(capacity, hasher),
+            expiry: BTreeMap::new(),
+            ttl,
+            capacity,
+            sequence: 0,
+            clock: system_clock(),
+        }
+    }
+
+    /// เปลี่ยนแหล่งเวลา - ใช้ตอนสร้างเท่านั้น
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clear();
+        self.clock = clock;
+        self
+    }
+
+    #[must_use]
+    pub const fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// ใส่ค่าพร้อมอายุเฉพาะตัว (แทน `ttl` ปกติ)
+    pub fn insert_with_ttl(&mut self, key: K, value: V, ttl: Duration) -> Option<(K, V)> {
+        let now = self.clock.now();
+        if let Some(entry) = self.entries.remove(&key) {
+            self.expiry.remove(&(entry.expires_at, entry.sequence));
+        }
+
+        let mut evicted = None;
+        if self.entries.len() == self.capacity && self.purge_expired_at(now) == 0 {
+            // ยังไม่มีตัวไหนหมดอายุ: ไล่ตัวที่ใกล้หมดอายุที่สุด
+            evicted = self.expiry.pop_first().and_then(|(_, victim)| {
+                let entry = self.entries.remove(&victim)?;
+                Some((victim, entry.value))
+            });
+        }
+
+        self.sequence += 1;
+        let expires_at = now + ttl;
+        self.expiry.insert((expires_at, self.sequence), key.clone());
+        self.entries.insert(
+            key,
+            TtlEntry {
+                value,
+                expires_at,
+                sequence: self.sequence,
+            },
+        );
+        evicted
+    }
+
+    /// อายุที่เหลือของ key (ถ้ายังไม่หมดอายุ)
+    pub fn time_to_live(&self, key: &K) -> Option<Duration> {
+        let now = self.clock.now();
+        self.entries
+            .get(key)
+            .filter(|entry| entry.expires_at > now)
+            .map(|entry| entry.expires_at - now)
+    }
+
+    /// กวาด entry ที่หมดอายุทิ้ง - คืนจำนวนที่กวาด
+    pub fn purge_expired(&mut self) -> usize {
+        let now = self.clock.now();
+        self.purge_expired_at(now)
+    }
+
+    fn purge_expired_at(&mut self, now: Instant) -> usize {
+        let mut purged = 0;
+        while let Some(entry) = self.expiry.first_entry() {
+            if entry.key().0 > now {
+                break;
+            }
+            let key = entry.remove();
+            self.entries.remove(&key);
+            purged += 1;
+        }
+        purged
+    }
+}
+
+impl<K: Hash + Eq + Clone, V, S: BuildHasher> Cache<K, V> for TtlCache<K, V, S> {
+    fn get(&mut self, key: &K) -> Option<&V> {
+        let now = self.clock.now();
+        if self.entries.get(key)?.expires_at <= now {
+            self.remove(key);
+            return None;
+        }
+        self.entries.get(key).map(|entry| &entry.value)
+    }
+
+    fn peek(&self, key: &K) -> Option<&V> {
+        let now = self.clock.now();
+        self.entries
+            .get(key)
+            .filter(|entry| entry.expires_at > now)
+            .map(|entry| &entry.value)
+    }
+
+    fn insert(&mut self, key: K, value: V) -> Option<(K, V)> {
+        self.insert_with_ttl(key, value, self.ttl)
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        let entry = self.entries.remove(key)?;
+        self.expiry.remove(&(entry.expires_at, entry.sequence));
+        Some(entry.value)
+    }
+
+    /// รวม entry ที่หมดอายุแต่ยังไม่ถูกกวาด
+    fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.expiry.clear();
+    }
+}
+
+// ===== 📈 Metrics =====
+
+/// 📊 ตัวเลขสรุปของ `InstrumentedCache`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub evictions: u64,
+}
+
+impl CacheStats {
+    /// สัดส่วน hit ต่อการอ่านทั้งหมด (0.0 ถ้ายังไม่เคยอ่าน)
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)] // จำนวนครั้งไม่ถึง 2^52 ในทางปฏิบัติ
+    pub fn hit_ratio(&self) -> f64 {
+        let lookups = self.hits + self.misses;
+        if lookups == 0 {
+            0.0
+        } else {
+            self.hits as f64 / lookups as f64
+        }
+    }
+}
+
+impl fmt::Display for CacheStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} hits / {} misses ({:.1}% hit), {} evictions",
+            self.hits,
+            self.misses,
+            self.hit_ratio() * 100.0,
+            self.evictions
+        )
+    }
+}
+
+/// 📈 ห่อ cache ใดก็ได้ให้บันทึก metric ลง `Registry`
+///
+/// ทุก metric ติด label `cache="<name>"`: `cache_hits_total`, `cache_misses_total`,
+/// `cache_evictions_total` และ gauge `cache_entries`
+#[derive(Debug)]
+pub struct InstrumentedCache<C> {
+    inner: C,
+    hits: Arc<Counter>,
+    misses: Arc<Counter>,
+    evictions: Arc<Counter>,
+    entries: Arc<Gauge>,
+}
+
+impl<C> InstrumentedCache<C> {
+    /// # Panics
+    ///
+    /// ถ้าชื่อ metric ข้างต้นเคยถูกลงทะเบียนเป็นชนิดอื่นใน `registry`
+    pub fn new(inner: C, registry: &Registry, name: &str) -> Self {
+        let labels = [("cache", name)];
+        Self {
+            inner,
+            hits: registry.counter(
+                "cache_hits_total",
+                "Cache lookups that found a value",
+                &labels,
+            ),
+            misses: registry.counter(
+                "cache_misses_total",
+                "Cache lookups that found nothing",
+                &labels,
+            ),
+            evictions: registry.counter(
+                "cache_evictions_total",
+                "Entries evicted to make room for new ones",
+                &labels,
+            ),
+            entries: registry.gauge("cache_entries", "Entries currently stored", &labels),
+        }
+    }
+
+    #[must_use]
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.get(),
+            misses: self.misses.get(),
+            evictions: self.evictions.get(),
+        }
+    }
+
+    pub const fn inner(&self) -> &C {
+        &self.inner
+    }
+
+    pub fn into_inner(self) -> C {
+        self.inner
+    }
+
+    #[allow(clippy::cast_precision_loss)] // จำนวน entry ไม่ถึง 2^52
+    fn record_len(&self, len: usize) {
+        self.entries.set(len as f64);
+    }
+}
+
+impl<K, V, C: Cache<K, V>> Cache<K, V> for InstrumentedCache<C> {
+    fn get(&mut self, key: &K) -> Option<&V> {
+        let value = self.inner.get(key);
+        if value.is_some() {
+            self.hits.inc();
+        } else {
+            self.misses.inc();
+        }
+        value
+    }
+
+    fn peek(&self, key: &K) -> Option<&V> {
+        self.inner.peek(key)
+    }
+
+    fn insert(&mut self, key: K, value: V) -> Option<(K, V)> {
+        let evicted = self.inner.insert(key, value);
+        if evicted.is_some() {
+            self.evictions.inc();
+        }
+        self.record_len(self.inner.len());
+        evicted
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        let removed = self.inner.remove(key);
+        self.record_len(self.inner.len());
+        removed
+    }
+
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    fn capacity(&self) -> usize {
+        self.inner.capacity()
+    }
+
+    fn clear(&mut self) {
+        self.inner.clear();
+        self.record_len(0);
+    }
+}
+
+// ===== 🔒 Thread-safe wrappers =====
+
+fn lock<C>(mutex: &Mutex<C>) -> MutexGuard<'_, C> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// 🔒 cache เดียวหลัง `Mutex` เดียว - clone แล้วแชร์ข้าม thread ได้
+///
+/// `get` ต้องแก้ลำดับการไล่จึงต้องล็อกแบบเขียนทุกครั้ง และคืนค่าเป็น clone
+/// เพราะ reference หลุดออกนอกล็อกไม่ได้
+#[derive(Debug)]
+pub struct SharedCache<C> {
+    inner: Arc<Mutex<C>>,
+}
+
+impl<C> Clone for SharedCache<C> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: Arc::clone(&self.inner),
+        }
+    }
+}
+
+impl<C> SharedCache<C> {
+    pub fn new(cache: C) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(cache)),
+        }
+    }
+
+    pub fn get<K, V: Clone>(&self, key: &K) -> Option<V>
+    where
+        C: Cache<K, V>,
+    {
+        lock(&self.inner).get(key).cloned()
+    }
+
+    pub fn insert<K, V>(&self, key: K, value: V) -> Option<(K, V)>
+    where
+        C: Cache<K, V>,
+    {
+        lock(&self.inner).insert(key, value)
+    }
+
+    pub fn remove<K, V>(&self, key: &K) -> Option<V>
+    where
+        C: Cache<K, V>,
+    {
+        lock(&self.inner).remove(key)
+    }
+
+    /// คืนค่าที่มีอยู่ หรือคำนวณด้วย `make` แล้วเก็บไว้
+    ///
+    /// `make` รันขณะถือล็อก: thread อื่นที่ขอ key เดียวกันจะรอผลแทนที่จะคำนวณซ้ำ
+    /// แต่ key อื่นก็ต้องรอด้วย - ถ้า `make` ช้าให้ใช้ `ShardedCache`
+    pub fn get_or_insert_with<K, V: Clone>(&self, key: K, make: impl FnOnce() -> V) -> V
+    where
+        C: Cache<K, V>,
+    {
+        let mut cache = lock(&self.inner);
+        if let Some(value) = cache.get(&key) {
+            return value.clone();
+        }
+        let value = make();
+        cache.insert(key, value.clone());
+        value
+    }
+
+    /// ล็อกครั้งเดียวแล้วทำหลายอย่างกับ cache ตัวจริง
+    pub fn with<R>(&self, f: impl FnOnce(&mut C) -> R) -> R {
+        f(&mut lock(&self.inner))
+    }
+}
+
+/// 🧩 แบ่ง key ลง cache หลายก้อนตาม hash แต่ละก้อนมีล็อกของตัวเอง - thread ชนกันน้อยลง
+///
+/// นโยบายการไล่ทำงานแยกในแต่ละ shard (LRU จึงเป็นแบบประมาณทั้งก้อน)
+/// และ capacity รวมคือผลรวมของทุก shard
+#[derive(Debug)]
+pub struct ShardedCache<C, S = RandomState> {
+    shards: Box<[Mutex<C>]>,
+    hasher: S,
+}
+
+impl<C> ShardedCache<C> {
+    /// # Panics
+    ///
+    /// เมื่อ `shards` เป็น 0
+    pub fn new(shards: usize, make_shard: impl FnMut() -> C) -> Self {
+        Self::with_hasher(shards, RandomState::new(), make_shard)
+    }
+}
+
+impl<C, S: BuildHasher> ShardedCache<C, S> {
+    /// # Panics
+    ///
+    /// เมื่อ `shards` เป็น 0
+    pub fn with_hasher(shards: usize, hasher: S, mut make_shard: impl FnMut() -> C) -> Self {
+        assert!(shards > 0, "need at least one shard");
+        Self {
+            shards: (0..shards).map(|_| Mutex::new(make_shard())).collect(),
+            hasher,
+        }
+    }
+
+    #[must_use]
+    pub fn shard_count(&self) -> usize {
+        self.shards.len()
+    }
+
+    #[allow(clippy::cast_possible_truncation)] // เอาแค่เศษจากการหาร จำนวน shard ไม่เกิน usize อยู่แล้ว
+    fn shard<K: Hash>(&self, key: &K) -> &Mutex<C> {
+        let index = self.hasher.hash_one(key) % self.shards.len() as u64;
+        &self.shards[index as usize]
+    }
+
+    pub fn get<K: Hash, V: Clone>(&self, key: &K) -> Option<V>
+    where
+        C: Cache<K, V>,
+    {
+        lock(self.shard(key)).get(key).cloned()
+    }
+
+    pub fn insert<K: Hash, V>(&self, key: K, value: V) -> Option<(K, V)>
+    where
+        C: Cache<K, V>,
+    {
+        lock(self.shard(&key)).insert(key, value)
+    }
+
+    pub fn remove<K: Hash, V>(&self, key: &K) -> Option<V>
+    where
+        C: Cache<K, V>,
+    {
+        lock(self.shard(key)).remove(key)
+    }
+
+    /// เหมือน `SharedCache::get_or_insert_with` แต่ล็อกเฉพาะ shard ของ `key`
+    pub fn get_or_insert_with<K: Hash, V: Clone>(&self, key: K, make: impl FnOnce() -> V) -> V
+    where
+        C: Cache<K, V>,
+    {
+        let mut cache = lock(self.shard(&key));
+        if let Some(value) = cache.get(&key) {
+            return value.clone();
+        }
+        let value = make();
+        cache.insert(key, value.clone());
+        value
+    }
+
+    /// จำนวน entry รวมทุก shard (ล็อกทีละ shard จึงเป็นแค่ค่าประมาณถ้ามีคนเขียนอยู่)
+    pub fn len<K, V>(&self) -> usize
+    where
+        C: Cache<K, V>,
+    {
+        self.shards.iter().map(|shard| lock(shard).len()).sum()
+    }
+
+    pub fn is_empty<K, V>(&self) -> bool
+    where
+        C: Cache<K, V>,
+    {
+        self.shards.iter().all(|shard| lock(shard).is_empty())
+    }
+}
+
+/// ลำดับการอ่านตัวอย่าง: สินค้ายอดนิยม 1-2 ถูกอ่านบ่อย สลับกับการ scan สินค้าที่ไม่ซ้ำรอบละ 3 ชิ้น
+fn sample_workload() -> Vec<u32> {
+    (0..60)
+        .flat_map(|round| {
+            let scan = 100 + 3 * round;
+            [1, 2, 1, 2, scan, scan + 1, scan + 2]
+        })
+        .collect()
+}
+
+/// จำลองการอ่านแบบ cache-aside: miss แล้วค่อยโหลดมาใส่
+fn replay<C: Cache<u32, String>>(cache: &mut C, workload: &[u32]) {
+    for &product in workload {
+        if cache.get(&product).is_none() {
+            cache.insert(product, format!("product-{product}"));
+        }
+    }
+}
+
+/// ฟังก์ชันสอน cache หลายนโยบาย - LRU/LFU/TTL + metrics + ใช้ข้าม thread 🗃️
+pub fn demonstrate_cache() {
+    println!("\n🗃️ === Cache: LRU vs LFU vs TTL บน trait เดียวกัน === 🗃️");
+
+    let registry = Registry::new();
+    let workload = sample_workload();
+
+    let mut lru = InstrumentedCache::new(LruCache::new(3), &registry, "lru");
+    replay(&mut lru, &workload);
+    let mut lfu = InstrumentedCache::new(LfuCache::new(3), &registry, "lfu");
+    replay(&mut lfu, &workload);
+    println!("🕰️ LRU: {} - การ scan ดันสินค้ายอดนิยมหลุดทุกรอบ", lru.stats());
+    println!("🔥 LFU: {} - สินค้ายอดนิยมไม่หลุดเพราะการ scan", lfu.stats());
+
+    let clock = MockClock::new();
+    let mut sessions =
+        TtlCache::new(100, Duration::from_secs(30)).with_clock(Arc::new(clock.clone()));
+    sessions.insert("token-a", "มะลิ");
+    clock.advance(Duration::from_secs(20));
+    sessions.insert("token-b", "ต้นกล้า");
+    clock.advance(Duration::from_secs(15));
+    println!(
+        "⏳ TTL 30s หลังผ่านไป 35s: token-a = {}, token-b = {} (เหลือ {:?})",
+        sessions.get(&"token-a").copied().unwrap_or("หมดอายุ"),
+        sessions.get(&"token-b").copied().unwrap_or("หมดอายุ"),
+        sessions.time_to_live(&"token-b")
+    );
+
+    let shared = ShardedCache::new(4, || LruCache::new(64));
+    std::thread::scope(|scope| {
+        for _ in 0..4 {
+            scope.spawn(|| {
+                for n in 0..50_u64 {
+                    shared.get_or_insert_with(n, || n * n);
+                }
+            });
+        }
+    });
+    println!(
+        "🔒 4 threads ใช้ ShardedCache ({} shards) ร่วมกัน: {} entries, 7² = {:?}",
+        shared.shard_count(),
+        shared.len::<u64, u64>(),
+        shared.get(&7_u64)
+    );
+
+    println!("📈 Prometheus:");
+    for line in registry
+        .render()
+        .lines()
+        .filter(|line| line.starts_with("cache_hits"))
+    {
+        println!("   {line}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use std::collections::VecDeque;
+
+    #[derive(Debug, Clone)]
+    enum Op {
+        Get(u8),
+        Insert(u8, u32),
+        Remove(u8),
+    }
+
+    fn arb_ops() -> impl Strategy<Value = Vec<Op>> {
+        let op = prop_oneof![
+            (0..12_u8).prop_map(Op::Get),
+            (0..12_u8, any::<u32>()).prop_map(|(key, value)| Op::Insert(key, value)),
+            (0..12_u8).prop_map(Op::Remove),
+        ];
+        prop::collection::vec(op, 0..200)
+    }
+
+    #[test]
+    fn test_lru_evicts_least_recently_used() {
+        let mut cache = LruCache::new(3);
+        cache.insert('a', 1);
+        cache.insert('b', 2);
+        cache.insert('c', 3);
+        assert_eq!(cache.get(&'a'), Some(&1));
+        assert_eq!(cache.insert('d', 4), Some(('b', 2)));
+        assert_eq!(cache.keys_by_recency().copied().collect::<String>(), "dac");
+
+        // แทนค่าเดิมไม่ไล่ใคร แต่เลื่อนเป็นตัวล่าสุด
+        assert_eq!(cache.insert('c', 30), None);
+        assert_eq!(cache.peek(&'c'), Some(&30));
+        assert_eq!(cache.remove(&'a'), Some(1));
+        assert_eq!(cache.keys_by_recency().copied().collect::<String>(), "cd");
+        assert_eq!(cache.len(), 2);
+
+        cache.clear();
+        assert!(cache.is_empty());
+        assert_eq!(cache.insert('z', 0), None);
+    }
+
+    #[test]
+    fn test_lfu_evicts_least_frequently_used_then_oldest() {
+        let mut cache = LfuCache::new(3);
+        cache.insert("hot", 1);
+        cache.insert("warm", 2);
+        cache.insert("cold", 3);
+        for _ in 0..3 {
+            cache.get(&"hot");
+        }
+        cache.get(&"warm");
+        assert_eq!(cache.frequency(&"hot"), Some(4));
+
+        assert_eq!(cache.insert("new", 4), Some(("cold", 3)));
+        // "new" มีความถี่ 1 ต่ำสุด จึงเป็นเหยื่อรายต่อไป
+        assert_eq!(cache.insert("newer", 5), Some(("new", 4)));
+        assert_eq!(cache.peek(&"new"), None);
+        assert!(cache.contains_key(&"hot"));
+    }
+
+    #[test]
+    fn test_ttl_expires_with_mock_clock() {
+        let clock = MockClock::new();
+        let mut cache =
+            TtlCache::new(2, Duration::from_secs(10)).with_clock(Arc::new(clock.clone()));
+        cache.insert("a", 1);
+        cache.insert_with_ttl("b", 2, Duration::from_secs(60));
+
+        clock.advance(Duration::from_secs(10));
+        assert_eq!(cache.peek(&"a"), None);
+        assert_eq!(cache.get(&"b"), Some(&2));
+        assert_eq!(cache.time_to_live(&"b"), Some(Duration::from_secs(50)));
+
+        // "a" หมดอายุแล้วจึงถูกกวาดแทนการไล่ "b"
+        assert_eq!(cache.insert("c", 3), None);
+        assert_eq!(cache.len(), 2);
+        // เต็มและไม่มีใครหมดอายุ: ไล่ตัวที่ใกล้หมดอายุที่สุด
+        assert_eq!(cache.insert("d", 4), Some(("c", 3)));
+
+        clock.advance(Duration::from_secs(60));
+        assert_eq!(cache.purge_expired(), 2);
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_instrumented_cache_records_metrics() {
+        let registry = Registry::new();
+        let mut cache = InstrumentedCache::new(LruCache::new(1), &registry, "users");
+        assert_eq!(cache.get(&1), None);
+        cache.insert(1, "a");
+        assert_eq!(cache.get(&1), Some(&"a"));
+        cache.insert(2, "b");
+
+        let stats = cache.stats();
+        assert_eq!(
+            stats,
+            CacheStats {
+                hits: 1,
+                misses: 1,
+                evictions: 1
+            }
+        );
+        assert!((stats.hit_ratio() - 0.5).abs() < f64::EPSILON);
+
+        let rendered = registry.render();
+        assert!(rendered.contains(r#"cache_hits_total{cache="users"} 1"#));
+        assert!(rendered.contains(r#"cache_evictions_total{cache="users"} 1"#));
+        assert!(rendered.contains(r#"cache_entries{cache="users"} 1"#));
+    }
+
+    #[test]
+    fn test_shared_and_sharded_caches_across_threads() {
+        let single_lock = SharedCache::new(LfuCache::new(16));
+        let sharded = ShardedCache::new(8, || LruCache::new(4));
+        let computed = Counter::new();
+
+        std::thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(|| {
+                    for key in 0..16_u32 {
+                        let value = single_lock.get_or_insert_with(key, || {
+                            computed.inc();
+                            key * 10
+                        });
+                        assert_eq!(value, key * 10);
+                        sharded.insert(key, key);
+                    }
+                });
+            }
+        });
+
+        // คำนวณใต้ล็อก: แต่ละ key ถูกคำนวณครั้งเดียวแม้ 4 threads ขอพร้อมกัน
+        assert_eq!(computed.get(), 16);
+        assert_eq!(single_lock.with(|cache| cache.len()), 16);
+        assert!(sharded.len::<u32, u32>() <= 8 * 4);
+        let handle = single_lock.clone();
+        let removed = std::thread::spawn(move || handle.remove(&3))
+            .join()
+            .unwrap();
+        assert_eq!(removed, Some(30));
+        assert_eq!(single_lock.get(&3), None);
+    }
+
+    #[test]
+    fn test_custom_hasher() {
+        let hasher = std::hash::BuildHasherDefault::<std::hash::DefaultHasher>::default();
+        let mut cache = LruCache::with_hasher(2, hasher);
+        cache.insert("x", 1);
+        assert_eq!(cache.get(&"x"), Some(&1));
+    }
+
+    proptest! {
+        #[test]
+        fn prop_lru_matches_reference_model(capacity in 1..6_usize, ops in arb_ops()) {
+            let mut cache = LruCache::new(capacity);
+            // หน้า = ใช้ล่าสุด
+            let mut model: VecDeque<(u8, u32)> = VecDeque::new();
+            for op in ops {
+                match op {
+                    Op::Get(key) => {
+                        let expected = model.iter().position(|(k, _)| *k == key).map(|i| {
+                            let entry = model.remove(i).unwrap();
+                            model.push_front(entry);
+                            entry.1
+                        });
+                        prop_assert_eq!(cache.get(&key).copied(), expected);
+                    }
+                    Op::Insert(key, value) => {
+                        let mut expected = None;
+                        if let Some(i) = model.iter().position(|(k, _)| *k == key) {
+                            model.remove(i);
+                        } else if model.len() == capacity {
+                            expected = model.pop_back();
+                        }
+                        model.push_front((key, value));
+                        prop_assert_eq!(cache.insert(key, value), expected);
+                    }
+                    Op::Remove(key) => {
+                        let expected = model.iter().position(|(k, _)| *k == key).and_then(|i| model.remove(i));
+                        prop_assert_eq!(cache.remove(&key), expected.map(|(_, v)| v));
+                    }
+                }
+                prop_assert!(cache.len() <= capacity);
+                let order: Vec<u8> = cache.keys_by_recency().copied().collect();
+                let expected_order: Vec<u8> = model.iter().map(|(k, _)| *k).collect();
+                prop_assert_eq!(order, expected_order);
+            }
+        }
+
+        #[test]
+        fn prop_lfu_evicts_minimum_frequency(capacity in 1..6_usize, ops in arb_ops()) {
+            let mut cache = LfuCache::new(capacity);
+            // (key, value, frequency, last_used)
+            let mut model: Vec<(u8, u32, u64, u64)> = Vec::new();
+            let mut tick = 0;
+            for op in ops {
+                tick += 1;
+                match op {
+                    Op::Get(key) => {
+                        let expected = model.iter_mut().find(|e| e.0 == key).map(|e| {
+                            e.2 += 1;
+                            e.3 = tick;
+                            e.1
+                        });
+                        prop_assert_eq!(cache.get(&key).copied(), expected);
+                    }
+                    Op::Insert(key, value) => {
+                        let mut expected = None;
+                        if let Some(e) = model.iter_mut().find(|e| e.0 == key) {
+                            *e = (key, value, e.2 + 1, tick);
+                        } else {
+                            if model.len() == capacity {
+                                let victim = (0..model.len()).min_by_key(|&i| (model[i].2, model[i].3)).unwrap();
+                                let (k, v, _, _) = model.swap_remove(victim);
+                                expected = Some((k, v));
+                            }
+                            model.push((key, value, 1, tick));
+                        }
+                        prop_assert_eq!(cache.insert(key, value), expected);
+                    }
+                    Op::Remove(key) => {
+                        let expected = model.iter().position(|e| e.0 == key).map(|i| model.swap_remove(i).1);
+                        prop_assert_eq!(cache.remove(&key), expected);
+                    }
+                }
+                prop_assert!(cache.len() <= capacity);
+                prop_assert_eq!(cache.len(), model.len());
+            }
+        }
+
+        #[test]
+        fn prop_ttl_matches_reference_model(
+            capacity in 1..6_usize,
+            steps in prop::collection::vec((0..12_u8, any::<u32>(), 0..4_u64), 0..100),
+        ) {
+            let clock = MockClock::new();
+            let ttl = Duration::from_secs(5);
+            let mut cache = TtlCache::new(capacity, ttl).with_clock(Arc::new(clock.clone()));
+            // เรียงตามลำดับที่เขียน = ลำดับหมดอายุ เพราะทุกตัวใช้ ttl เดียวกัน
+            let mut model: Vec<(u8, u32, Duration)> = Vec::new();
+            for (key, value, wait) in steps {
+                clock.advance(Duration::from_secs(wait));
+                let now = clock.elapsed();
+                let expired = |written: Duration| written + ttl <= now;
+
+                let expected = model.iter().position(|e| e.0 == key).and_then(|i| {
+                    if expired(model[i].2) {
+                        model.remove(i);
+                        None
+                    } else {
+                        Some(model[i].1)
+                    }
+                });
+                prop_assert_eq!(cache.get(&key).copied(), expected);
+
+                if expected.is_none() {
+                    let mut evicted = None;
+                    if model.len() == capacity {
+                        model.retain(|e| !expired(e.2));
+                        if model.len() == capacity {
+                            let (k, v, _) = model.remove(0);
+                            evicted = Some((k, v));
+                        }
+                    }
+                    model.push((key, value, now));
+                    prop_assert_eq!(cache.insert(key, value), evicted);
+                }
+                prop_assert!(cache.len() <= capacity);
+                prop_assert_eq!(cache.len(), model.len());
+            }
+        }
+    }
+}
@@ -0,0 +1,507 @@
+//! 📚 Chapter Registry - รายชื่อบทเรียนที่เดียว ใช้ทั้ง `--list`, `--chapter` และการรันทุกบท
+//!
+//! เดิมรายชื่อบทซ้ำกันอยู่ใน `main.rs` (match ยาวหลายสิบ arm + `--list`) และ `lib.rs` อีกสองชุด
+//! ตอนนี้เพิ่มบทใหม่แค่เพิ่มหนึ่งบรรทัดใน `CHAPTERS`:
+//!
+//! ```
+//! use rust_concepts::chapters::registry;
+//!
+//! let traits = registry().find("traits").unwrap();
+//! assert_eq!(traits.number, 9);
+//! let picked: Vec<u8> = registry().select("1-3,traits").unwrap().iter().map(|c| c.number).collect();
+//! assert_eq!(picked, [1, 2, 3, 9]);
+//! ```
+//!
+//! - 🔢 เลือกบทด้วยเลข (`9`) หรือ slug (`traits`)
+//! - 📏 `select` รับหลายบทคั่นด้วย `,` และช่วง `1-5` (ปลายช่วงเป็น slug ก็ได้)
+//! - ⏳ บท async (11) รันได้ทั้งจากโค้ด sync (สร้าง runtime ให้) และจากใน async
+
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::OnceLock;
+
+use crate::i18n::{self, t};
+use crate::strings::FuzzyMatcher;
+
+use Runner::Sync;
+use Track::{Advanced, Foundations, Intermediate, Specialized};
+
+/// future ของบทที่เป็น async
+pub type ChapterFuture = Pin<Box<dyn Future<Output = ()>>>;
+
+/// 🏃 วิธีรันบทเรียน
+#[derive(Clone, Copy)]
+pub enum Runner {
+    Sync(fn()),
+    Async(fn() -> ChapterFuture),
+}
+
+impl fmt::Debug for Runner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Sync(_) => "Sync",
+            Self::Async(_) => "Async",
+        })
+    }
+}
+
+/// 🎓 กลุ่มของบท ใช้จัดหัวข้อใน `--list`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Track {
+    Foundations,
+    Intermediate,
+    Advanced,
+    Specialized,
+}
+
+impl Track {
+    pub const ALL: [Self; 4] = [
+        Self::Foundations,
+        Self::Intermediate,
+        Self::Advanced,
+        Self::Specialized,
+    ];
+
+    #[must_use]
+    pub const fn heading(self) -> &'static str {
+        match self {
+            Self::Foundations => "🔰 บทเรียนพื้นฐาน",
+            Self::Intermediate => "🎓 บทเรียนขั้นกลาง",
+            Self::Advanced => "🚀 บทเรียนขั้นสูง",
+            Self::Specialized => "🎯 บทเรียนเฉพาะทาง",
+        }
+    }
+}
+
+/// 📖 หนึ่งบทเรียน
+#[derive(Debug, Clone, Copy)]
+pub struct Chapter {
+    pub number: u8,
+    /// ชื่อสั้นสำหรับ `--chapter <slug>`
+    pub slug: &'static str,
+    pub title: &'static str,
+    pub emoji: &'static str,
+    /// บรรทัดแนะนำใต้หัวบท (ว่างได้)
+    pub summary: &'static str,
+    pub track: Track,
+    pub runner: Runner,
+}
+
+impl Chapter {
+    /// ชื่อบทตามภาษาปัจจุบัน - ใช้ `chapter.<n>.name` ใน catalog ถ้ามี
+    #[must_use]
+    pub fn localized_title(&self) -> &'static str {
+        i18n::catalog()
+            .lookup(i18n::locale(), &format!("chapter.{}.name", self.number))
+            .unwrap_or(self.title)
+    }
+
+    /// "🔒 === บทที่ 2: Ownership และ Borrowing === 🔒"
+    #[must_use]
+    pub fn heading(&self) -> String {
+        let title = t!(
+            "chapter.title",
+            number = self.number,
+            title = self.localized_title()
+        );
+        format!("{emoji} === {title} === {emoji}", emoji = self.emoji)
+    }
+
+    #[must_use]
+    pub const fn is_async(&self) -> bool {
+        matches!(self.runner, Runner::Async(_))
+    }
+
+    /// พิมพ์หัวบทแล้วรัน - บท async จะได้ tokio runtime ของตัวเอง
+    ///
+    /// # Panics
+    /// ถ้าสร้าง tokio runtime ไม่ได้ หรือถูกเรียกจากใน runtime อื่นกับบท async (ใช้ `run_async` แทน)
+    pub fn run(&self) {
+        self.print_heading();
+        match self.runner {
+            Runner::Sync(run) => run(),
+            Runner::Async(run) => new_runtime()
+                .expect("failed to start tokio runtime")
+                .block_on(run()),
+        }
+    }
+
+    /// เหมือน `run` แต่ใช้ runtime ที่กำลังทำงานอยู่
+    #[allow(clippy::future_not_send)] // future ของบทเรียนไม่ได้สัญญาว่า Send - await ตรงๆ บน task ปัจจุบัน
+    pub async fn run_async(&self) {
+        self.print_heading();
+        match self.runner {
+            Runner::Sync(run) => run(),
+            Runner::Async(run) => run().await,
+        }
+    }
+
+    fn print_heading(&self) {
+        println!("{}", self.heading());
+        if !self.summary.is_empty() {
+            println!("{}", self.summary);
+        }
+    }
+}
+
+/// ❌ ระบุบทไม่ถูกต้อง
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChapterError {
+    /// ไม่มีบทที่เลขหรือ slug นี้
+    Unknown(String),
+    /// ช่วงกลับด้าน เช่น `5-1`
+    EmptyRange(String),
+}
+
+impl fmt::Display for ChapterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unknown(name) => write!(f, "unknown chapter `{name}` (see --list)"),
+            Self::EmptyRange(range) => write!(f, "chapter range `{range}` is empty"),
+        }
+    }
+}
+
+impl std::error::Error for ChapterError {}
+
+/// 🗂️ บทเรียนทั้งหมด เรียงตามเลขบท
+#[derive(Debug)]
+pub struct ChapterRegistry {
+    chapters: Vec<Chapter>,
+}
+
+impl ChapterRegistry {
+    #[must_use]
+    pub fn new(mut chapters: Vec<Chapter>) -> Self {
+        chapters.sort_by_key(|chapter| chapter.number);
+        Self { chapters }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Chapter> {
+        self.chapters.iter()
+    }
+
+    #[must_use]
+    pub fn get(&self, number: u8) -> Option<&Chapter> {
+        self.chapters
+            .iter()
+            .find(|chapter| chapter.number == number)
+    }
+
+    /// หาด้วยเลขบทหรือ slug (ไม่สนตัวพิมพ์, `_` ใช้แทน `-` ได้)
+    #[must_use]
+    pub fn find(&self, name: &str) -> Option<&Chapter> {
+        let name = name.trim();
+        if let Ok(number) = name.parse() {
+            return self.get(number);
+        }
+        let slug = name.to_ascii_lowercase().replace('_', "-");
+        self.chapters.iter().find(|chapter| chapter.slug == slug)
+    }
+
+    /// บทที่ชื่อใกล้เคียงกับ `name` มากที่สุดไม่เกิน 3 บท - ใช้บอก "หมายถึง ... ใช่ไหม?" เมื่อ `find` ไม่เจอ
+    ///
+    /// เทียบทั้ง slug และชื่อบทภาษาอังกฤษ แล้วใช้คะแนนที่ดีกว่า
+    #[must_use]
+    pub fn suggest(&self, name: &str) -> Vec<&Chapter> {
+        let matcher = FuzzyMatcher::new();
+        let name = name.trim().replace('_', "-");
+        let mut scored: Vec<(u32, &Chapter)> = self
+            .chapters
+            .iter()
+            .filter_map(|chapter| {
+                let score = [chapter.slug, chapter.title]
+                    .iter()
+                    .filter_map(|candidate| matcher.score(&name, candidate))
+                    .max()?;
+                Some((score, chapter))
+            })
+            .collect();
+        scored.sort_by(|(a, first), (b, second)| b.cmp(a).then(first.number.cmp(&second.number)));
+        scored
+            .into_iter()
+            .take(3)
+            .map(|(_, chapter)| chapter)
+            .collect()
+    }
+
+    /// เลือกหลายบท: `"1-5"`, `"1,3,9"`, `"traits,async"`, `"basics-generics"` (ไม่ซ้ำ เรียงตามที่ระบุ)
+    ///
+    /// # Errors
+    /// [`ChapterError`] เมื่อมีชื่อที่ไม่รู้จักหรือช่วงกลับด้าน
+    pub fn select(&self, spec: &str) -> Result<Vec<&Chapter>, ChapterError> {
+        let mut selected: Vec<&Chapter> = Vec::new();
+        for item in spec
+            .split(',')
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+        {
+            let found = match self.find(item) {
+                Some(chapter) => vec![chapter],
+                None => self.range(item)?,
+            };
+            for chapter in found {
+                if !selected
+                    .iter()
+                    .any(|picked| picked.number == chapter.number)
+                {
+                    selected.push(chapter);
+                }
+            }
+        }
+        if selected.is_empty() {
+            return Err(ChapterError::Unknown(spec.to_string()));
+        }
+        Ok(selected)
+    }
+
+    /// `a-b` - ลองทุกตำแหน่งของ `-` เพราะ slug เองก็มี `-` ได้ (`basics-error-handling`)
+    fn range(&self, item: &str) -> Result<Vec<&Chapter>, ChapterError> {
+        let (start, end) = item
+            .match_indices('-')
+            .find_map(|(at, _)| Some((self.find(&item[..at])?, self.find(&item[at + 1..])?)))
+            .ok_or_else(|| ChapterError::Unknown(item.to_string()))?;
+        if start.number > end.number {
+            return Err(ChapterError::EmptyRange(item.to_string()));
+        }
+        Ok(self
+            .chapters
+            .iter()
+            .filter(|chapter| (start.number..=end.number).contains(&chapter.number))
+            .collect())
+    }
+
+    /// รันทุกบทที่เป็น sync (บท async ต้องใช้ `run_all_async` หรือ `--chapter 11`)
+    pub fn run_all(&self) {
+        print_welcome();
+        for chapter in &self.chapters {
+            if chapter.is_async() {
+                continue;
+            }
+            println!("\n");
+            chapter.run();
+        }
+        print_farewell(self.chapters.len());
+        for chapter in self.chapters.iter().filter(|chapter| chapter.is_async()) {
+            println!(
+                "💡 หมายเหตุ: บทที่ {} ({}) ต้องรันแยกด้วย --async หรือ --chapter {}",
+                chapter.number, chapter.title, chapter.number
+            );
+        }
+    }
+
+    /// รันทุกบทบน runtime ปัจจุบัน รวมบท async ด้วย
+    #[allow(clippy::future_not_send)] // เหมือน `Chapter::run_async`
+    pub async fn run_all_async(&self) {
+        print_welcome();
+        for chapter in &self.chapters {
+            println!("\n");
+            chapter.run_async().await;
+        }
+        print_farewell(self.chapters.len());
+    }
+}
+
+/// runtime หลาย thread บน native - wasm32 ไม่มี thread จึงได้แบบ current-thread
+fn new_runtime() -> std::io::Result<tokio::runtime::Runtime> {
+    #[cfg(not(target_arch = "wasm32"))]
+    let mut builder = tokio::runtime::Builder::new_multi_thread();
+    #[cfg(target_arch = "wasm32")]
+    let mut builder = tokio::runtime::Builder::new_current_thread();
+    builder.enable_all().build()
+}
+
+fn print_welcome() {
+    println!("🦀 ยินดีต้อนรับสู่ Rust Concepts Learning Project! 🦀");
+    println!("{}", "=".repeat(50));
+}
+
+fn print_farewell(count: usize) {
+    println!("\n🎊 สำเร็จ! คุณได้เรียนรู้แนวคิดสำคัญของ Rust ครบถ้วนแล้ว! 🎊");
+    println!("🚀 ตอนนี้คุณพร้อมที่จะสร้างแอปพลิเคชัน Rust ของตัวเองแล้ว!");
+    println!("💡 คุณได้เรียนรู้ทั้งหมด {count} บท ครอบคลุมตั้งแต่พื้นฐานจนถึงหัวข้อขั้นสูง!");
+}
+
+/// registry กลางของโปรเจค
+#[must_use]
+pub fn registry() -> &'static ChapterRegistry {
+    static REGISTRY: OnceLock<ChapterRegistry> = OnceLock::new();
+    REGISTRY.get_or_init(|| ChapterRegistry::new(CHAPTERS.to_vec()))
+}
+
+const fn chapter(
+    number: u8,
+    slug: &'static str,
+    emoji: &'static str,
+    title: &'static str,
+    summary: &'static str,
+    track: Track,
+    runner: Runner,
+) -> Chapter {
+    Chapter {
+        number,
+        slug,
+        title,
+        emoji,
+        summary,
+        track,
+        runner,
+    }
+}
+
+#[rustfmt::skip]
+const CHAPTERS: &[Chapter] = &[
+    chapter(1, "basics", "🔥", "พื้นฐาน Rust", "", Foundations, Sync(crate::basics::run_basics_examples)),
+    chapter(2, "ownership", "🔒", "Ownership และ Borrowing", "", Foundations, Sync(crate::ownership::run_ownership_examples)),
+    chapter(3, "structs-enums", "📊", "Structs และ Enums", "", Foundations, Sync(crate::structs_enums::run_structs_enums_examples)),
+    chapter(4, "functions", "🚀", "Functions และ Control Flow", "", Foundations, Sync(crate::functions::run_functions_examples)),
+    chapter(5, "modules", "📦", "Modules และ Packages", "", Foundations, Sync(crate::modules::run_modules_examples)),
+    chapter(6, "collections", "📚", "Collections", "", Foundations, Sync(crate::collections::run_collections_examples)),
+    chapter(7, "error-handling", "⚠️", "Error Handling", "", Foundations, Sync(crate::error_handling::run_error_handling_examples)),
+    chapter(8, "generics", "🔧", "Generics", "", Foundations, Sync(crate::generics::run_generics_examples)),
+    chapter(9, "traits", "🎯", "Traits", "", Foundations, Sync(crate::traits::run_traits_examples)),
+    chapter(10, "lifetimes", "⏰", "Lifetimes", "", Foundations, Sync(crate::lifetimes::run_lifetimes_examples)),
+    chapter(11, "async", "🔄", "Async/Await Programming", "", Intermediate, Runner::Async(async_examples)),
+    chapter(12, "macros", "🎭", "Macros", "", Intermediate, Sync(crate::macros::run_macros_examples)),
+    chapter(13, "testing", "🧪", "Testing", "", Intermediate, Sync(crate::testing::run_testing_examples)),
+    chapter(14, "unsafe", "⚡", "Unsafe Rust", "", Intermediate, Sync(crate::unsafe_rust::run_unsafe_examples)),
+    chapter(15, "smart-pointers", "🧠", "Smart Pointers", "🎯 เรียนรู้ Box, Rc, Arc, RefCell และ Smart Pointers อื่นๆ!", Advanced, Sync(smart_pointers_preview)),
+    chapter(16, "concurrency", "🔀", "Concurrency", "⚡ เรียนรู้ Threads, Channels และ Parallel Programming!", Advanced, Sync(crate::concurrency::run_concurrency_examples)),
+    chapter(17, "io", "📁", "I/O & File System", "📂 เรียนรู้การจัดการไฟล์และ I/O Operations!", Advanced, Sync(crate::io_filesystem::run_io_filesystem_examples)),
+    chapter(18, "networking", "🌐", "Network Programming", "📡 เรียนรู้การเขียนโปรแกรมเครือข่าย TCP/UDP!", Advanced, Sync(crate::networking::run_networking_examples)),
+    chapter(19, "web", "🕸️", "Web Development", "🌍 เรียนรู้การสร้าง Web Applications และ APIs!", Advanced, Sync(crate::web_development::run_web_development_examples)),
+    chapter(20, "database", "🗄️", "Database Integration", "🗃️ เรียนรู้การเชื่อมต่อฐานข้อมูลและ ORM!", Advanced, Sync(crate::database::run_database_examples)),
+    chapter(21, "performance", "⚡", "Performance Optimization", "🚀 เรียนรู้เทคนิคเพิ่มประสิทธิภาพแบบเทพ!", Advanced, Sync(crate::performance::run_performance_examples)),
+    chapter(22, "ffi", "🔗", "Foreign Function Interface (FFI)", "🌉 เรียนรู้การเชื่อมต่อกับภาษาอื่น (C/C++)!", Specialized, Sync(crate::unsafe_rust::ffi::ffi_examples)),
+    chapter(23, "embedded", "🔧", "Embedded Programming", "🤖 เรียนรู้การเขียนโปรแกรมสำหรับ Microcontrollers!", Specialized, Sync(embedded_examples)),
+    chapter(24, "devops", "🚀", "DevOps & Deployment", "🛠️ เรียนรู้ DevOps และ Deployment แบบโปร!", Specialized, Sync(crate::devops::run_devops_examples)),
+    chapter(25, "game", "🎮", "Game Development", "🕹️ เรียนรู้การพัฒนาเกมด้วย Rust!", Specialized, Sync(crate::game_development::run_game_development_examples)),
+    chapter(26, "blockchain", "⛓️", "Blockchain Development", "🔗 เรียนรู้เทคโนโลยี Blockchain และ Smart Contracts!", Specialized, Sync(crate::blockchain::run_blockchain_examples)),
+    chapter(27, "mobile", "📱", "Mobile Development", "📲 เรียนรู้การพัฒนาแอปมือถือด้วย Rust!", Specialized, Sync(crate::mobile_development::run_mobile_development_examples)),
+    chapter(28, "random", "🎲", "Random Number Generation", "🎰 เรียนรู้การสร้าง PRNG และ distributions ด้วยตัวเอง!", Specialized, Sync(crate::random::run_random_examples)),
+    chapter(29, "datetime", "🗓️", "Date & Time", "🕰️ เรียนรู้ parse/format, timezone, DST และ cron ด้วย chrono!", Specialized, Sync(crate::datetime::run_datetime_examples)),
+    chapter(30, "advanced-patterns", "🎯", "Advanced Patterns", "🧩 เรียนรู้ Builder, Type State, Visitor และ design patterns อื่นๆ!", Advanced, Sync(crate::advanced_patterns::run_advanced_patterns_examples)),
+    chapter(31, "security", "🔐", "Security", "🛡️ เรียนรู้ hashing, การเข้ารหัส และการเขียนโค้ดให้ปลอดภัย!", Specialized, Sync(crate::security::run_security_examples)),
+    chapter(32, "advanced-topics", "🧙‍♂️", "Advanced Topics", "🔬 เรียนรู้ allocator, memory layout และเทคนิคขั้นเทพ!", Advanced, Sync(crate::advanced_topics::run_advanced_topics_examples)),
+    chapter(33, "machine-learning", "🤖", "Machine Learning", "🧠 เรียนรู้ neural network และ ML พื้นฐานด้วย Rust!", Specialized, Sync(crate::machine_learning::run_machine_learning_examples)),
+];
+
+fn async_examples() -> ChapterFuture {
+    Box::pin(crate::async_await::run_async_examples())
+}
+
+fn smart_pointers_preview() {
+    println!("🚧 บทนี้กำลังอยู่ระหว่างการพัฒนา");
+}
+
+fn embedded_examples() {
+    #[cfg(feature = "embedded")]
+    crate::embedded::run_embedded_examples();
+    #[cfg(not(feature = "embedded"))]
+    println!("🚧 บทนี้ต้องเปิด feature: cargo run --features embedded -- --chapter embedded");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output;
+
+    fn numbers(chapters: &[&Chapter]) -> Vec<u8> {
+        chapters.iter().map(|chapter| chapter.number).collect()
+    }
+
+    #[test]
+    fn test_registry_numbers_and_slugs_are_unique() {
+        let all: Vec<&Chapter> = registry().iter().collect();
+        let expected: Vec<u8> = (1..=33).collect();
+        assert_eq!(numbers(&all), expected);
+
+        let mut slugs: Vec<&str> = all.iter().map(|chapter| chapter.slug).collect();
+        slugs.sort_unstable();
+        slugs.dedup();
+        assert_eq!(slugs.len(), all.len());
+        assert!(slugs.iter().all(|slug| slug.parse::<u8>().is_err()));
+        assert_eq!(all.iter().filter(|chapter| chapter.is_async()).count(), 1);
+    }
+
+    #[test]
+    fn test_find_by_number_or_slug() {
+        let registry = registry();
+        assert_eq!(registry.find("9").map(|c| c.slug), Some("traits"));
+        assert_eq!(registry.find("Error_Handling").map(|c| c.number), Some(7));
+        assert_eq!(registry.find(" async ").map(|c| c.number), Some(11));
+        assert!(registry.find("0").is_none());
+        assert!(registry.find("cooking").is_none());
+    }
+
+    #[test]
+    fn test_suggest_close_names() {
+        let registry = registry();
+        let slugs = |name| -> Vec<&str> { registry.suggest(name).iter().map(|c| c.slug).collect() };
+        assert_eq!(slugs("trats").first(), Some(&"traits"));
+        assert_eq!(slugs("concurency").first(), Some(&"concurrency"));
+        assert_eq!(slugs("error_handlng").first(), Some(&"error-handling"));
+        // ชื่อบทภาษาอังกฤษก็ใช้ได้
+        assert_eq!(slugs("Smart Pointer").first(), Some(&"smart-pointers"));
+        assert!(slugs("trats").len() <= 3);
+        assert!(slugs("xylophone").is_empty());
+    }
+
+    #[test]
+    fn test_select_ranges_and_lists() {
+        let registry = registry();
+        assert_eq!(numbers(&registry.select("1-5").unwrap()), [1, 2, 3, 4, 5]);
+        assert_eq!(numbers(&registry.select("9, 2,9,traits").unwrap()), [9, 2]);
+        assert_eq!(
+            numbers(&registry.select("basics-error-handling").unwrap()),
+            [1, 2, 3, 4, 5, 6, 7]
+        );
+        assert_eq!(
+            numbers(&registry.select("28-datetime,1").unwrap()),
+            [28, 29, 1]
+        );
+        assert_eq!(
+            registry.select("5-1").unwrap_err(),
+            ChapterError::EmptyRange("5-1".to_string())
+        );
+        assert_eq!(
+            registry.select("1-99").unwrap_err(),
+            ChapterError::Unknown("1-99".to_string())
+        );
+        assert!(registry.select(" , ").is_err());
+    }
+
+    #[test]
+    fn test_heading_follows_locale() {
+        let ownership = registry().get(2).unwrap();
+        let random = registry().get(28).unwrap();
+        i18n::with_locale(i18n::Locale::En, || {
+            assert_eq!(
+                ownership.heading(),
+                "🔒 === Chapter 2: Ownership and Borrowing === 🔒"
+            );
+            // ยังไม่ได้แปลชื่อ → ใช้ชื่อเดิม
+            assert_eq!(
+                random.heading(),
+                "🎲 === Chapter 28: Random Number Generation === 🎲"
+            );
+        });
+        i18n::with_locale(i18n::Locale::Th, || {
+            assert_eq!(
+                ownership.heading(),
+                "🔒 === บทที่ 2: Ownership และ Borrowing === 🔒"
+            );
+        });
+    }
+
+    #[test]
+    fn test_chapter_output_goes_through_printer() {
+        // demo ของบท testing เขียนผ่าน io::Write - ต้องมาถึง printer ไม่ใช่ stdout ตรง ๆ
+        let capture = output::Capturing::new();
+        output::with_printer(capture.clone(), || registry().get(13).unwrap().run());
+        let contents = capture.contents();
+        assert!(contents.contains("Basic Testing Examples"));
+        assert!(contents.contains("Calculator Testing Examples"));
+    }
+}
@@ -0,0 +1,157 @@
+//! ⏰ Clock Abstraction - นาฬิกาที่ฉีดเข้าไปได้ (dependency injection ของเวลา)
+//!
+//! โค้ดที่เรียก `Instant::now()` หรือ `SystemTime::now()` ตรงๆ ทดสอบยากมาก
+//! เพราะผลลัพธ์ขึ้นกับเวลาจริง ทำให้ tests ต้อง `sleep` และอาจ flaky 😵
+//!
+//! 🎯 วิธีแก้: ให้ทุกคนถามเวลาจาก `Clock` แทน
+//! - 🕰️ `SystemClock` - เวลาจริง ใช้ใน production
+//! - 🧪 `MockClock` - เวลาปลอมที่เดินเฉพาะตอนเราสั่ง `advance()` ใช้ใน tests
+
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// 🕰️ แหล่งเวลาที่ฉีดเข้าไปในระบบต่างๆ ได้
+pub trait Clock: Send + Sync + Debug {
+    /// เวลาแบบ monotonic สำหรับวัดช่วงเวลา (cooldown, uptime, timeout)
+    fn now(&self) -> Instant;
+
+    /// เวลาตามปฏิทิน สำหรับ timestamp ที่บันทึกลงข้อมูล
+    fn system_time(&self) -> SystemTime;
+
+    /// Unix timestamp หน่วยวินาที
+    fn unix_timestamp(&self) -> u64 {
+        self.system_time()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs()
+    }
+
+    /// Unix timestamp หน่วยมิลลิวินาที
+    fn unix_timestamp_millis(&self) -> u64 {
+        let millis = self
+            .system_time()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis();
+        u64::try_from(millis).unwrap_or(u64::MAX)
+    }
+}
+
+/// 🕰️ นาฬิกาจริงของระบบ
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn system_time(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/// สร้าง `Arc<dyn Clock>` ของนาฬิกาจริง - ค่าเริ่มต้นของทุกระบบ
+#[must_use]
+pub fn system_clock() -> Arc<dyn Clock> {
+    Arc::new(SystemClock)
+}
+
+/// 🧪 นาฬิกาปลอมที่เดินเฉพาะตอนสั่ง
+///
+/// clone แล้วยังแชร์เวลาเดียวกัน จึงส่ง clone เข้าไปในระบบ
+/// แล้วเก็บอีกตัวไว้ `advance()` จากใน test ได้
+#[derive(Debug, Clone)]
+pub struct MockClock {
+    base_instant: Instant,
+    base_system_time: SystemTime,
+    elapsed: Arc<Mutex<Duration>>,
+}
+
+impl Default for MockClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MockClock {
+    /// เริ่มนาฬิกาที่เวลา Unix 1,700,000,000 (พฤศจิกายน 2023) เพื่อให้ผลลัพธ์คงที่
+    #[must_use]
+    pub fn new() -> Self {
+        Self::starting_at(UNIX_EPOCH + Duration::from_secs(1_700_000_000))
+    }
+
+    /// เริ่มนาฬิกาที่เวลาตามปฏิทินที่กำหนด
+    #[must_use]
+    pub fn starting_at(system_time: SystemTime) -> Self {
+        Self {
+            base_instant: Instant::now(),
+            base_system_time: system_time,
+            elapsed: Arc::new(Mutex::new(Duration::ZERO)),
+        }
+    }
+
+    /// เดินเวลาไปข้างหน้า
+    pub fn advance(&self, by: Duration) {
+        *self.lock_elapsed() += by;
+    }
+
+    /// เวลาที่เดินไปแล้วทั้งหมดนับจากตอนสร้าง
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        *self.lock_elapsed()
+    }
+
+    fn lock_elapsed(&self) -> std::sync::MutexGuard<'_, Duration> {
+        self.elapsed
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> Instant {
+        self.base_instant + self.elapsed()
+    }
+
+    fn system_time(&self) -> SystemTime {
+        self.base_system_time + self.elapsed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mock_clock_only_moves_when_advanced() {
+        let clock = MockClock::new();
+        let start = clock.now();
+        assert_eq!(clock.now(), start);
+        assert_eq!(clock.unix_timestamp(), 1_700_000_000);
+
+        clock.advance(Duration::from_millis(1_500));
+        assert_eq!(clock.now() - start, Duration::from_millis(1_500));
+        assert_eq!(clock.unix_timestamp(), 1_700_000_001);
+        assert_eq!(clock.unix_timestamp_millis(), 1_700_000_001_500);
+    }
+
+    #[test]
+    fn test_mock_clock_clones_share_time() {
+        let clock = MockClock::new();
+        let injected: Arc<dyn Clock> = Arc::new(clock.clone());
+        let before = injected.now();
+
+        clock.advance(Duration::from_secs(5));
+        assert_eq!(injected.now() - before, Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_system_clock_moves_forward() {
+        let clock = SystemClock;
+        let first = clock.now();
+        assert!(clock.now() >= first);
+        assert!(clock.unix_timestamp() > 1_700_000_000);
+    }
+}
@@ -0,0 +1,764 @@
+//! ⚙️ Config Loader - อ่านค่าตั้งจากหลายชั้นแล้ว deserialize เป็น struct ของเรา
+//!
+//! ลำดับความสำคัญจากต่ำไปสูง (ชั้นหลังทับชั้นก่อนทีละ key):
+//! 1. 🧱 `defaults` - ค่าเริ่มต้นจาก struct ที่ `Serialize` ได้
+//! 2. 📄 `file` / `optional_file` - ไฟล์ TOML หรือ `.ini` (เพิ่มได้หลายไฟล์ ไฟล์หลังชนะ)
+//! 3. 🌱 `env_prefix` - environment variables เช่น `APP_SERVER__PORT=9000` → `server.port`
+//!    (`__` คือการลงไปในตาราง ส่วน `_` ตัวเดียวเป็นส่วนหนึ่งของชื่อ key)
+//! 4. ✍️ `set` - ค่าที่โปรแกรมกำหนดเอง เช่น จาก command line
+//!
+//! ```
+//! use rust_concepts::config::Config;
+//! use serde::Deserialize;
+//!
+//! #[derive(Deserialize)]
+//! struct Server {
+//!     host: String,
+//!     port: u16,
+//! }
+//!
+//! let config = Config::builder()
+//!     .set("host", "0.0.0.0")
+//!     .set("port", 8080)
+//!     .env_prefix("DOCTEST_NOT_SET_")
+//!     .build()
+//!     .unwrap();
+//! let server: Server = config.deserialize().unwrap();
+//! assert_eq!((server.host.as_str(), server.port), ("0.0.0.0", 8080));
+//! ```
+//!
+//! ไฟล์ `.ini` อ่านด้วย `error_handling::ini_parser` เสมอ ส่วน TOML ใช้ crate `toml`
+//! เว้นแต่เรียก `builtin_parsers()` (โหมดสอน) ที่เปลี่ยนไปใช้ `error_handling::toml_parser` ที่เขียนเอง
+//!
+//! ถ้าค่าผิดชนิดหรือขาด field `ConfigError::Invalid` จะบอกทั้ง key และแหล่งที่มาของค่านั้น
+//! เช่น ``config key `server.port` (from env APP_SERVER__PORT): invalid type: ...`` 🎯
+
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, PoisonError, RwLock};
+use std::time::Duration;
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use toml::{Table, Value};
+
+use crate::error_handling::ini_parser::{self, Ini};
+use crate::error_handling::toml_parser::{self, TomlTable, TomlValue};
+use crate::io_filesystem::{FileWatcher, WatchHandle};
+use crate::logger;
+
+/// 📍 ที่มาของค่าแต่ละ key
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Defaults,
+    File(PathBuf),
+    Env(String),
+    Override,
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Defaults => f.write_str("defaults"),
+            Self::File(path) => write!(f, "file {}", path.display()),
+            Self::Env(var) => write!(f, "env {var}"),
+            Self::Override => f.write_str("override"),
+        }
+    }
+}
+
+/// ❌ ข้อผิดพลาดตอนโหลดหรือแปลง config
+#[derive(Debug)]
+pub enum ConfigError {
+    /// อ่านไฟล์ไม่ได้ (รวมถึงไฟล์ที่บังคับแต่ไม่มีอยู่)
+    Io { path: PathBuf, source: io::Error },
+    /// ไฟล์ไม่ใช่ TOML/INI ที่ถูกต้อง (`format` คือ `"TOML"` หรือ `"INI"`)
+    Parse {
+        path: PathBuf,
+        format: &'static str,
+        message: String,
+    },
+    /// ค่าจาก `defaults`/`set` แปลงเป็น TOML ไม่ได้
+    Serialize { key: String, message: String },
+    /// ค่าไม่ตรงกับ struct ปลายทาง - `key` เป็น path แบบ `server.port` หรือ `hosts[0]`
+    Invalid {
+        key: String,
+        source: Option<Source>,
+        message: String,
+    },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io { path, source } => write!(f, "cannot read {}: {source}", path.display()),
+            Self::Parse {
+                path,
+                format,
+                message,
+            } => write!(f, "invalid {format} in {}: {message}", path.display()),
+            Self::Serialize { key, message } => {
+                write!(f, "cannot convert value for `{key}`: {message}")
+            }
+            Self::Invalid {
+                key,
+                source: Some(source),
+                message,
+            } => write!(f, "config key `{key}` (from {source}): {message}"),
+            Self::Invalid {
+                key,
+                source: None,
+                message,
+            } => write!(f, "config key `{key}`: {message}"),
+        }
+    }
+}
+
+impl Error for ConfigError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Layer {
+    Defaults(Result<Table, String>),
+    File {
+        path: PathBuf,
+        required: bool,
+    },
+    Env {
+        prefix: String,
+    },
+    Set {
+        key: String,
+        value: Result<Value, String>,
+    },
+}
+
+/// 🏗️ ประกอบชั้นของ config ตามลำดับที่เรียก - สร้างผ่าน `Config::builder()`
+#[derive(Debug, Clone, Default)]
+pub struct ConfigBuilder {
+    layers: Vec<Layer>,
+    builtin_parsers: bool,
+}
+
+impl ConfigBuilder {
+    /// ใช้ค่าใน `defaults` เป็นชั้นล่างสุด (มักเป็น `T::default()` ของ struct ปลายทาง)
+    #[must_use]
+    pub fn defaults<T: Serialize>(mut self, defaults: &T) -> Self {
+        self.layers.push(Layer::Defaults(
+            Table::try_from(defaults).map_err(|e| e.to_string()),
+        ));
+        self
+    }
+
+    /// ไฟล์ที่ต้องมี (TOML หรือ `.ini`) - ถ้าไม่มี `build` จะคืน `ConfigError::Io`
+    #[must_use]
+    pub fn file(mut self, path: impl AsRef<Path>) -> Self {
+        self.layers.push(Layer::File {
+            path: path.as_ref().to_path_buf(),
+            required: true,
+        });
+        self
+    }
+
+    /// ไฟล์ที่ไม่มีก็ได้ เช่น `app.local.toml` ของแต่ละเครื่อง
+    #[must_use]
+    pub fn optional_file(mut self, path: impl AsRef<Path>) -> Self {
+        self.layers.push(Layer::File {
+            path: path.as_ref().to_path_buf(),
+            required: false,
+        });
+        self
+    }
+
+    /// อ่านไฟล์ TOML ด้วย `toml_parser` ที่เขียนเองแทน crate `toml` (โหมดสอน) -
+    /// ไฟล์ที่ใช้ string หลายบรรทัดหรือวันที่จะได้ `ConfigError::Parse` ที่บอกว่าไม่รองรับ
+    #[must_use]
+    pub const fn builtin_parsers(mut self) -> Self {
+        self.builtin_parsers = true;
+        self
+    }
+
+    /// อ่าน env ที่ขึ้นต้นด้วย `prefix` ตอน `build` - ค่าที่เป็น TOML ได้ (`8080`, `true`, `[1, 2]`)
+    /// จะได้ชนิดนั้น นอกนั้นเป็น string
+    #[must_use]
+    pub fn env_prefix(mut self, prefix: &str) -> Self {
+        self.layers.push(Layer::Env {
+            prefix: prefix.to_string(),
+        });
+        self
+    }
+
+    /// กำหนดค่าของ `key` (ใช้ `.` คั่นตารางซ้อน) ทับทุกชั้นก่อนหน้า
+    #[must_use]
+    pub fn set(mut self, key: &str, value: impl Serialize) -> Self {
+        self.layers.push(Layer::Set {
+            key: key.to_string(),
+            value: Value::try_from(value).map_err(|e| e.to_string()),
+        });
+        self
+    }
+
+    /// อ่านทุกชั้นแล้วรวมเป็น `Config`
+    ///
+    /// # Errors
+    ///
+    /// คืน error ถ้าไฟล์ที่บังคับอ่านไม่ได้, TOML/INI ผิดรูปแบบ หรือค่าที่ใส่เองแปลงเป็น TOML ไม่ได้
+    pub fn build(self) -> Result<Config, ConfigError> {
+        let mut config = Config::default();
+        for layer in self.layers {
+            match layer {
+                Layer::Defaults(table) => {
+                    let table = table.map_err(|message| ConfigError::Serialize {
+                        key: "defaults".to_string(),
+                        message,
+                    })?;
+                    config.merge(table, &Source::Defaults);
+                }
+                Layer::File { path, required } => {
+                    let text = match fs::read_to_string(&path) {
+                        Ok(text) => text,
+                        Err(e) if !required && e.kind() == io::ErrorKind::NotFound => continue,
+                        Err(source) => return Err(ConfigError::Io { path, source }),
+                    };
+                    let table = parse_file(&text, &path, self.builtin_parsers)?;
+                    config.merge(table, &Source::File(path));
+                }
+                Layer::Env { prefix } => {
+                    let mut vars: Vec<(String, String)> = std::env::vars()
+                        .filter(|(name, _)| name.starts_with(&prefix) && name.len() > prefix.len())
+                        .collect();
+                    vars.sort();
+                    for (name, raw) in vars {
+                        let key = name[prefix.len()..].to_lowercase().replace("__", ".");
+                        config.insert(&key, parse_env_value(&raw), Source::Env(name));
+                    }
+                }
+                Layer::Set { key, value } => {
+                    let value = value.map_err(|message| ConfigError::Serialize {
+                        key: key.clone(),
+                        message,
+                    })?;
+                    config.insert(&key, value, Source::Override);
+                }
+            }
+        }
+        Ok(config)
+    }
+
+    /// build แล้วเฝ้าทุกไฟล์ในชั้น `file`/`optional_file` - ไฟล์เปลี่ยนเมื่อไรก็ build ใหม่ทั้งหมด
+    ///
+    /// ไฟล์ต้องนิ่งครบ `interval` ก่อน reload (debounce) และถ้า build ใหม่ไม่ผ่าน
+    /// จะ log warning แล้วใช้ config เดิมต่อ - หยุดเฝ้าเมื่อ `LiveConfig` ถูก drop
+    ///
+    /// # Errors
+    ///
+    /// คืน error เดียวกับ `build` ถ้ารอบแรก build ไม่ผ่าน
+    pub fn watch(self, interval: Duration) -> Result<LiveConfig, ConfigError> {
+        let mut watcher = FileWatcher::new(interval);
+        for layer in &self.layers {
+            if let Layer::File { path, .. } = layer {
+                // จำ snapshot ก่อน build - แก้ไฟล์ระหว่าง build ก็ยังเห็นเป็นการเปลี่ยนแปลง
+                watcher.watch(path).map_err(|source| ConfigError::Io {
+                    path: path.clone(),
+                    source,
+                })?;
+            }
+        }
+        let current = Arc::new(RwLock::new(Arc::new(self.clone().build()?)));
+        let generation = Arc::new(AtomicU64::new(1));
+
+        let (shared, counter) = (Arc::clone(&current), Arc::clone(&generation));
+        let handle = watcher.spawn_with(interval, move |events| match self.clone().build() {
+            Ok(config) => {
+                *shared.write().unwrap_or_else(PoisonError::into_inner) = Arc::new(config);
+                counter.fetch_add(1, Ordering::SeqCst);
+            }
+            Err(e) => {
+                logger::warn!(target: "config", changed = events.len(); "keeping previous config: {e}");
+            }
+        });
+        Ok(LiveConfig {
+            current,
+            generation,
+            _watcher: handle,
+        })
+    }
+}
+
+/// 🔁 config ที่ reload ตัวเองเมื่อไฟล์เปลี่ยน - สร้างผ่าน `ConfigBuilder::watch`
+#[derive(Debug)]
+pub struct LiveConfig {
+    current: Arc<RwLock<Arc<Config>>>,
+    generation: Arc<AtomicU64>,
+    _watcher: WatchHandle,
+}
+
+impl LiveConfig {
+    /// config ชุดล่าสุด - ถือไว้ได้นานเท่าที่ต้องการ reload จะไม่เปลี่ยนของที่ถืออยู่
+    #[must_use]
+    pub fn current(&self) -> Arc<Config> {
+        Arc::clone(&self.current.read().unwrap_or_else(PoisonError::into_inner))
+    }
+
+    /// เพิ่มขึ้นทุกครั้งที่ reload สำเร็จ (เริ่มที่ 1)
+    #[must_use]
+    pub fn generation(&self) -> u64 {
+        self.generation.load(Ordering::SeqCst)
+    }
+}
+
+/// 📦 ค่าที่รวมจากทุกชั้นแล้ว พร้อมจำว่าแต่ละ key มาจากไหน
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Config {
+    values: Table,
+    origins: BTreeMap<String, Source>,
+}
+
+impl Config {
+    #[must_use]
+    pub fn builder() -> ConfigBuilder {
+        ConfigBuilder::default()
+    }
+
+    /// แปลงทั้ง config เป็น `T`
+    ///
+    /// # Errors
+    ///
+    /// คืน `ConfigError::Invalid` ที่ชี้ไปยัง key ที่ผิด
+    pub fn deserialize<T: DeserializeOwned>(&self) -> Result<T, ConfigError> {
+        serde_path_to_error::deserialize(Value::Table(self.values.clone())).map_err(|e| {
+            let key = e.path().to_string();
+            let message = e.inner().message().to_string();
+            self.invalid(key, message)
+        })
+    }
+
+    /// อ่านค่าเดียวตาม key แบบ `server.port` - `Ok(None)` ถ้าไม่มี key นี้
+    ///
+    /// # Errors
+    ///
+    /// คืน `ConfigError::Invalid` ถ้าค่ามีอยู่แต่แปลงเป็น `T` ไม่ได้
+    pub fn get<T: DeserializeOwned>(&self, key: &str) -> Result<Option<T>, ConfigError> {
+        let Some(value) = self.value(key) else {
+            return Ok(None);
+        };
+        serde_path_to_error::deserialize(value.clone())
+            .map(Some)
+            .map_err(|e| {
+                let inner = e.path().to_string();
+                let key = if inner == "." {
+                    key.to_string()
+                } else {
+                    format!("{key}.{inner}")
+                };
+                let message = e.inner().message().to_string();
+                self.invalid(key, message)
+            })
+    }
+
+    /// ค่าดิบของ `key`
+    #[must_use]
+    pub fn value(&self, key: &str) -> Option<&Value> {
+        let mut parts = key.split('.');
+        let mut current = self.values.get(parts.next()?)?;
+        for part in parts {
+            current = current.as_table()?.get(part)?;
+        }
+        Some(current)
+    }
+
+    /// แหล่งที่มาของ `key` (หรือของตารางแม่ที่ใกล้ที่สุด ถ้า key นี้ไม่มีค่าของตัวเอง)
+    #[must_use]
+    pub fn source_of(&self, key: &str) -> Option<&Source> {
+        let mut key = key.split('[').next().unwrap_or(key);
+        loop {
+            if let Some(source) = self.origins.get(key) {
+                return Some(source);
+            }
+            key = &key[..key.rfind('.')?];
+        }
+    }
+
+    fn invalid(&self, key: String, message: String) -> ConfigError {
+        ConfigError::Invalid {
+            source: self.source_of(&key).cloned(),
+            key,
+            message,
+        }
+    }
+
+    fn merge(&mut self, table: Table, source: &Source) {
+        for (key, value) in table {
+            self.insert(&key, value, source.clone());
+        }
+    }
+
+    /// วางค่าที่ `key` - ตารางรวมกันทีละ key ส่วนค่าอื่นทับของเดิม
+    fn insert(&mut self, key: &str, value: Value, source: Source) {
+        if let Value::Table(table) = value {
+            for (child, value) in table {
+                self.insert(&format!("{key}.{child}"), value, source.clone());
+            }
+            return;
+        }
+
+        let (parents, leaf) = key
+            .rsplit_once('.')
+            .map_or((None, key), |(p, l)| (Some(p), l));
+        let mut table = &mut self.values;
+        for part in parents.into_iter().flat_map(|p| p.split('.')) {
+            let entry = table
+                .entry(part.to_string())
+                .or_insert_with(|| Value::Table(Table::new()));
+            if !entry.is_table() {
+                *entry = Value::Table(Table::new());
+            }
+            table = entry
+                .as_table_mut()
+                .expect("just made sure this is a table");
+        }
+        table.insert(leaf.to_string(), value);
+        self.origins
+            .retain(|existing, _| existing != key && !existing.starts_with(&format!("{key}.")));
+        self.origins.insert(key.to_string(), source);
+    }
+}
+
+/// `.ini` ใช้ `ini_parser` เสมอ ส่วน TOML เลือก parser ตาม `builtin_parsers`
+fn parse_file(text: &str, path: &Path, builtin_parsers: bool) -> Result<Table, ConfigError> {
+    let error = |format, message| ConfigError::Parse {
+        path: path.to_path_buf(),
+        format,
+        message,
+    };
+    if path
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("ini"))
+    {
+        return ini_parser::parse(text)
+            .map(|ini| ini_table(&ini))
+            .map_err(|e| error("INI", e.to_string()));
+    }
+    if builtin_parsers {
+        toml_parser::parse(text)
+            .map(from_builtin_table)
+            .map_err(|e| error("TOML", e.to_string()))
+    } else {
+        text.parse::<Table>()
+            .map_err(|e| error("TOML", e.message().to_string()))
+    }
+}
+
+/// section ของ INI เป็นตาราง (global อยู่ที่ราก) - ค่าแปลงชนิดแบบเดียวกับ env
+fn ini_table(ini: &Ini) -> Table {
+    let mut table = Table::new();
+    for (name, section) in ini.sections() {
+        let values: Table = section
+            .iter()
+            .map(|(key, raw)| {
+                let value = toml_parser::parse_value(raw)
+                    .map_or_else(|_| Value::String(raw.clone()), from_builtin);
+                (key.clone(), value)
+            })
+            .collect();
+        if name.is_empty() {
+            table.extend(values);
+        } else {
+            table.insert(name.to_string(), Value::Table(values));
+        }
+    }
+    table
+}
+
+fn from_builtin(value: TomlValue) -> Value {
+    match value {
+        TomlValue::String(text) => Value::String(text),
+        TomlValue::Integer(number) => Value::Integer(number),
+        TomlValue::Float(number) => Value::Float(number),
+        TomlValue::Boolean(flag) => Value::Boolean(flag),
+        TomlValue::Array(items) => Value::Array(items.into_iter().map(from_builtin).collect()),
+        TomlValue::Table(fields) => Value::Table(from_builtin_table(fields)),
+    }
+}
+
+fn from_builtin_table(fields: TomlTable) -> Table {
+    fields
+        .into_iter()
+        .map(|(key, value)| (key, from_builtin(value)))
+        .collect()
+}
+
+/// ค่าใน env เป็น TOML ได้ก็ใช้ชนิดนั้น ไม่งั้นถือเป็น string
+fn parse_env_value(raw: &str) -> Value {
+    format!("value = {raw}")
+        .parse::<Table>()
+        .ok()
+        .and_then(|mut table| table.remove("value"))
+        .unwrap_or_else(|| Value::String(raw.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+    use std::io::Write as _;
+
+    #[derive(Debug, Deserialize, Serialize, PartialEq)]
+    struct AppConfig {
+        name: String,
+        server: ServerConfig,
+        features: Vec<String>,
+    }
+
+    #[derive(Debug, Deserialize, Serialize, PartialEq)]
+    struct ServerConfig {
+        host: String,
+        port: u16,
+        debug: bool,
+    }
+
+    impl Default for AppConfig {
+        fn default() -> Self {
+            Self {
+                name: "app".to_string(),
+                server: ServerConfig {
+                    host: "127.0.0.1".to_string(),
+                    port: 8080,
+                    debug: false,
+                },
+                features: Vec::new(),
+            }
+        }
+    }
+
+    fn toml_file(contents: &str) -> tempfile::NamedTempFile {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        file.write_all(contents.as_bytes()).unwrap();
+        file
+    }
+
+    /// ตั้ง env ชั่วคราว - แต่ละ test ใช้ prefix ของตัวเองจึงไม่ชนกันตอนรันขนาน
+    fn with_env<R>(vars: &[(&str, &str)], f: impl FnOnce() -> R) -> R {
+        for (name, value) in vars {
+            // SAFETY: ชื่อ env ในแต่ละ test ไม่ซ้ำกัน และไม่มีโค้ดอื่นอ่าน env เหล่านี้พร้อมกัน
+            unsafe { std::env::set_var(name, value) };
+        }
+        let result = f();
+        for (name, _) in vars {
+            // SAFETY: เหมือนด้านบน
+            unsafe { std::env::remove_var(name) };
+        }
+        result
+    }
+
+    #[test]
+    fn test_layers_override_in_order() {
+        let file = toml_file("name = \"shop\"\nfeatures = [\"cart\"]\n\n[server]\nport = 9000\n");
+        let config = with_env(
+            &[
+                ("CFGTEST_LAYERS_SERVER__DEBUG", "true"),
+                ("CFGTEST_LAYERS_NAME", "shop-staging"),
+            ],
+            || {
+                Config::builder()
+                    .defaults(&AppConfig::default())
+                    .file(file.path())
+                    .optional_file("/definitely/missing/app.local.toml")
+                    .env_prefix("CFGTEST_LAYERS_")
+                    .set("server.host", "0.0.0.0")
+                    .build()
+                    .unwrap()
+            },
+        );
+
+        let app: AppConfig = config.deserialize().unwrap();
+        assert_eq!(
+            app,
+            AppConfig {
+                name: "shop-staging".to_string(),
+                server: ServerConfig {
+                    host: "0.0.0.0".to_string(),
+                    port: 9000,
+                    debug: true,
+                },
+                features: vec!["cart".to_string()],
+            }
+        );
+        assert_eq!(
+            config.source_of("server.port"),
+            Some(&Source::File(file.path().to_path_buf()))
+        );
+        assert_eq!(
+            config.source_of("server.debug"),
+            Some(&Source::Env("CFGTEST_LAYERS_SERVER__DEBUG".to_string()))
+        );
+        assert_eq!(config.source_of("server.host"), Some(&Source::Override));
+        assert_eq!(config.get::<u16>("server.port").unwrap(), Some(9000));
+        assert_eq!(config.get::<u16>("server.missing").unwrap(), None);
+    }
+
+    #[test]
+    fn test_invalid_value_points_to_key_and_source() {
+        let config = with_env(&[("CFGTEST_INVALID_SERVER__PORT", "not-a-port")], || {
+            Config::builder()
+                .defaults(&AppConfig::default())
+                .env_prefix("CFGTEST_INVALID_")
+                .build()
+                .unwrap()
+        });
+
+        let err = config.deserialize::<AppConfig>().unwrap_err();
+        let ConfigError::Invalid { key, source, .. } = &err else {
+            panic!("expected Invalid, got {err:?}");
+        };
+        assert_eq!(key, "server.port");
+        assert_eq!(
+            source,
+            &Some(Source::Env("CFGTEST_INVALID_SERVER__PORT".to_string()))
+        );
+        assert!(err.to_string().starts_with(
+            "config key `server.port` (from env CFGTEST_INVALID_SERVER__PORT): invalid type: string"
+        ));
+    }
+
+    #[test]
+    fn test_missing_field_and_file_errors() {
+        let file = toml_file("name = \"shop\"\n[server]\nhost = \"h\"\n");
+        let config = Config::builder().file(file.path()).build().unwrap();
+        let err = config.deserialize::<AppConfig>().unwrap_err();
+        assert!(
+            matches!(&err, ConfigError::Invalid { key, .. } if key == "server"),
+            "{err}"
+        );
+        assert!(err.to_string().contains("missing field `port`"), "{err}");
+
+        let broken = toml_file("[server\nport = 1");
+        assert!(matches!(
+            Config::builder().file(broken.path()).build(),
+            Err(ConfigError::Parse { .. })
+        ));
+        assert!(matches!(
+            Config::builder()
+                .file("/definitely/missing/app.toml")
+                .build(),
+            Err(ConfigError::Io { .. })
+        ));
+    }
+
+    #[test]
+    fn test_env_values_are_typed_when_possible() {
+        assert_eq!(parse_env_value("8080"), Value::Integer(8080));
+        assert_eq!(parse_env_value("false"), Value::Boolean(false));
+        assert_eq!(
+            parse_env_value("[\"a\", \"b\"]"),
+            Value::Array(vec![Value::from("a"), Value::from("b")])
+        );
+        assert_eq!(parse_env_value("localhost:80"), Value::from("localhost:80"));
+    }
+
+    #[test]
+    fn test_ini_files_and_builtin_toml_parser() {
+        let toml = toml_file("name = \"shop\"\nfeatures = [\"cart\", \"search\"]\n");
+        let mut ini = tempfile::Builder::new().suffix(".ini").tempfile().unwrap();
+        ini.write_all(
+            b"name = shop-ini\n\n[server]\nport = 9100\ndebug = true\nhost = \" spaced \"\n",
+        )
+        .unwrap();
+        let config = Config::builder()
+            .defaults(&AppConfig::default())
+            .builtin_parsers()
+            .file(toml.path())
+            .file(ini.path())
+            .build()
+            .unwrap();
+        assert_eq!(
+            config.deserialize::<AppConfig>().unwrap(),
+            AppConfig {
+                name: "shop-ini".to_string(),
+                server: ServerConfig {
+                    host: " spaced ".to_string(),
+                    port: 9100,
+                    debug: true,
+                },
+                features: vec!["cart".to_string(), "search".to_string()],
+            }
+        );
+        assert_eq!(
+            config.source_of("server.port"),
+            Some(&Source::File(ini.path().to_path_buf()))
+        );
+
+        // parser ที่เขียนเองบอกตรงๆ ว่าไม่รองรับ ส่วน crate `toml` อ่านไฟล์เดียวกันได้
+        let multiline = toml_file("name = \"\"\"\nshop\n\"\"\"\n");
+        let err = Config::builder()
+            .builtin_parsers()
+            .file(multiline.path())
+            .build()
+            .unwrap_err();
+        assert!(
+            matches!(&err, ConfigError::Parse { format: "TOML", message, .. } if message.contains("ตั้งใจตัดออก")),
+            "{err}"
+        );
+        assert!(Config::builder().file(multiline.path()).build().is_ok());
+
+        let mut broken = tempfile::Builder::new().suffix(".INI").tempfile().unwrap();
+        broken.write_all(b"[server]\nport = 1\nport = 2\n").unwrap();
+        let err = Config::builder().file(broken.path()).build().unwrap_err();
+        assert!(err.to_string().starts_with("invalid INI in "), "{err}");
+        assert!(
+            err.to_string()
+                .ends_with("key `server.port` ถูกกำหนดไปแล้ว (บรรทัด 3)"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_live_config_reloads_changed_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let base = dir.path().join("app.toml");
+        let local = dir.path().join("app.local.toml");
+        fs::write(&base, "[server]\nport = 9000\n").unwrap();
+        let live = Config::builder()
+            .defaults(&AppConfig::default())
+            .file(&base)
+            .optional_file(&local)
+            .watch(Duration::from_millis(5))
+            .unwrap();
+        let port = |config: &Config| config.get::<u16>("server.port").unwrap();
+        let first = live.current();
+        assert_eq!(port(&first), Some(9000));
+
+        let wait_for_generation = |target: u64| {
+            for _ in 0..400 {
+                if live.generation() >= target {
+                    return;
+                }
+                std::thread::sleep(Duration::from_millis(5));
+            }
+            panic!("config was not reloaded");
+        };
+
+        // ไฟล์ optional ที่เพิ่งถูกสร้างก็นับเป็นการเปลี่ยนแปลง
+        fs::write(&local, "[server]\nport = 9100\n").unwrap();
+        wait_for_generation(2);
+        assert_eq!(port(&live.current()), Some(9100));
+        assert_eq!(port(&first), Some(9000), "old snapshot is untouched");
+
+        // TOML เสีย → ใช้ชุดเดิมต่อ
+        fs::write(&local, "[server\n").unwrap();
+        std::thread::sleep(Duration::from_millis(50));
+        assert_eq!(live.generation(), 2);
+        assert_eq!(port(&live.current()), Some(9100));
+    }
+}
@@ -0,0 +1,382 @@
+//! 📣 Event Bus - pub/sub แบบแยกตามชนิดของ event
+//!
+//! ผู้ส่งไม่ต้องรู้ว่าใครฟังอยู่ - แค่ `publish(event)` แล้วทุก handler ที่
+//! `subscribe::<T>` ไว้จะได้รับตามลำดับที่ subscribe (ชนิดอื่นไม่เกี่ยว)
+//!
+//! ```
+//! use rust_concepts::event_bus::EventBus;
+//! use std::sync::{Arc, Mutex};
+//!
+//! struct PlayerScored(u32);
+//!
+//! let bus = EventBus::new();
+//! let total = Arc::new(Mutex::new(0));
+//! let sink = Arc::clone(&total);
+//! bus.subscribe(move |event: &PlayerScored| *sink.lock().unwrap() += event.0);
+//!
+//! bus.publish(PlayerScored(10));
+//! bus.publish(PlayerScored(5));
+//! bus.publish("ไม่มีใครฟัง string");
+//! assert_eq!(*total.lock().unwrap(), 15);
+//! ```
+//!
+//! การส่งมีสองแบบ:
+//! - ⚡ `publish` - ส่งทันทีบน thread ที่เรียก เสร็จแล้วทุก handler ทำงานครบ
+//! - 📬 `start_async` - ส่งผ่านคิวที่จำกัดขนาดไปยัง tokio task
+//!   ถ้าคิวเต็ม `AsyncPublisher::publish` จะรอ (backpressure) ส่วน `try_publish` คืน error ทันที
+//!
+//! subscriber ที่ถือไว้แบบ `Weak` (`subscribe_weak`) จะถูกลบออกเองเมื่อเจ้าของ drop ไปแล้ว
+
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, Weak};
+
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+/// 🎧 subscriber แบบ struct - ใช้คู่กับ `EventBus::subscribe_weak`
+pub trait Subscriber<T>: Send + Sync {
+    fn on_event(&self, event: &T);
+}
+
+type Handler = Arc<dyn Fn(&dyn Any) + Send + Sync>;
+
+/// ตัวรับ event - แบบ weak คืน `None` เมื่อเจ้าของถูก drop แล้ว
+#[derive(Clone)]
+enum Slot {
+    Strong(Handler),
+    Weak(Arc<dyn Fn() -> Option<Handler> + Send + Sync>),
+}
+
+impl Slot {
+    fn handler(&self) -> Option<Handler> {
+        match self {
+            Self::Strong(handler) => Some(Arc::clone(handler)),
+            Self::Weak(upgrade) => upgrade(),
+        }
+    }
+}
+
+/// 🔖 ใช้ยกเลิกการ subscribe
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SubscriptionId(u64);
+
+#[derive(Default)]
+struct Inner {
+    topics: Mutex<HashMap<TypeId, Vec<(SubscriptionId, Slot)>>>,
+    next_id: AtomicU64,
+}
+
+/// 📣 bus ที่ clone ได้ราคาถูก - ทุก clone ใช้รายชื่อ subscriber ชุดเดียวกัน
+#[derive(Clone, Default)]
+pub struct EventBus {
+    inner: Arc<Inner>,
+}
+
+impl fmt::Debug for EventBus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let topics = self.inner.topics.lock().map_or(0, |topics| topics.len());
+        f.debug_struct("EventBus").field("topics", &topics).finish()
+    }
+}
+
+impl EventBus {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn add<T: Any>(&self, slot: Slot) -> SubscriptionId {
+        let id = SubscriptionId(self.inner.next_id.fetch_add(1, Ordering::Relaxed));
+        self.lock_topics()
+            .entry(TypeId::of::<T>())
+            .or_default()
+            .push((id, slot));
+        id
+    }
+
+    fn lock_topics(
+        &self,
+    ) -> std::sync::MutexGuard<'_, HashMap<TypeId, Vec<(SubscriptionId, Slot)>>> {
+        self.inner
+            .topics
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    /// ฟัง event ชนิด `T` ด้วย closure
+    pub fn subscribe<T, F>(&self, handler: F) -> SubscriptionId
+    where
+        T: Any,
+        F: Fn(&T) + Send + Sync + 'static,
+    {
+        self.add::<T>(Slot::Strong(typed(handler)))
+    }
+
+    /// ฟังโดยไม่ยืดอายุ `subscriber` - drop ตัวจริงแล้วจะถูกลบออกจาก bus เอง
+    pub fn subscribe_weak<T, S>(&self, subscriber: &Arc<S>) -> SubscriptionId
+    where
+        T: Any,
+        S: Subscriber<T> + 'static,
+    {
+        let weak: Weak<S> = Arc::downgrade(subscriber);
+        self.add::<T>(Slot::Weak(Arc::new(move || {
+            let weak = weak.clone();
+            weak.upgrade().map(|_| {
+                typed(move |event: &T| {
+                    if let Some(subscriber) = weak.upgrade() {
+                        subscriber.on_event(event);
+                    }
+                })
+            })
+        })))
+    }
+
+    /// ยกเลิกการ subscribe - คืน `false` ถ้าไม่พบ (ยกเลิกไปแล้ว)
+    #[must_use]
+    pub fn unsubscribe(&self, id: SubscriptionId) -> bool {
+        let mut topics = self.lock_topics();
+        let removed = topics.values_mut().any(|slots| {
+            let before = slots.len();
+            slots.retain(|(existing, _)| *existing != id);
+            slots.len() != before
+        });
+        topics.retain(|_, slots| !slots.is_empty());
+        drop(topics);
+        removed
+    }
+
+    /// จำนวน subscriber ของ `T` ที่ยังมีชีวิต (ลบตัวที่ตายแล้วไปด้วย)
+    #[must_use]
+    pub fn subscriber_count<T: Any>(&self) -> usize {
+        self.live_handlers(TypeId::of::<T>()).len()
+    }
+
+    /// ดึง handler ออกมานอก lock - handler จึง publish/subscribe ต่อได้โดยไม่ deadlock
+    fn live_handlers(&self, topic: TypeId) -> Vec<Handler> {
+        let mut handlers = Vec::new();
+        if let Some(slots) = self.lock_topics().get_mut(&topic) {
+            slots.retain(|(_, slot)| {
+                slot.handler().is_some_and(|handler| {
+                    handlers.push(handler);
+                    true
+                })
+            });
+        }
+        handlers
+    }
+
+    /// ⚡ ส่ง event ให้ทุก subscriber ของ `T` ตามลำดับที่ subscribe แล้วคืนจำนวนที่ส่งถึง
+    pub fn publish<T: Any>(&self, event: T) -> usize {
+        let handlers = self.live_handlers(TypeId::of::<T>());
+        for handler in &handlers {
+            handler(&event);
+        }
+        handlers.len()
+    }
+
+    /// 📬 เริ่ม task ที่ส่ง event จากคิวขนาด `capacity` ต้องเรียกภายใน tokio runtime
+    ///
+    /// task จะจบเมื่อ `AsyncPublisher` ทุกตัวถูก drop และส่งของที่ค้างในคิวครบแล้ว
+    ///
+    /// # Panics
+    ///
+    /// ถ้า `capacity` เป็น 0 หรือไม่ได้อยู่ใน tokio runtime
+    #[must_use]
+    pub fn start_async(&self, capacity: usize) -> (AsyncPublisher, JoinHandle<usize>) {
+        let (tx, mut rx) = mpsc::channel::<Envelope>(capacity);
+        let bus = self.clone();
+        let handle = tokio::spawn(async move {
+            let mut delivered = 0;
+            while let Some(envelope) = rx.recv().await {
+                delivered += envelope(&bus);
+            }
+            delivered
+        });
+        (AsyncPublisher { tx }, handle)
+    }
+}
+
+type Envelope = Box<dyn FnOnce(&EventBus) -> usize + Send>;
+
+fn typed<T: Any, F: Fn(&T) + Send + Sync + 'static>(handler: F) -> Handler {
+    Arc::new(move |event: &dyn Any| {
+        if let Some(event) = event.downcast_ref::<T>() {
+            handler(event);
+        }
+    })
+}
+
+/// ❌ ส่งเข้าคิวไม่ได้ - event ถูกคืนกลับมาให้ตัดสินใจเอง
+#[derive(Debug, PartialEq, Eq)]
+pub enum PublishError<T> {
+    /// คิวเต็ม (ใช้ `publish().await` ถ้ายอมรอ)
+    Full(T),
+    /// dispatcher หยุดไปแล้ว
+    Closed(T),
+}
+
+impl<T> fmt::Display for PublishError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Full(_) => f.write_str("event queue is full"),
+            Self::Closed(_) => f.write_str("event dispatcher has stopped"),
+        }
+    }
+}
+
+impl<T: fmt::Debug> std::error::Error for PublishError<T> {}
+
+/// 📬 ฝั่งส่งของคิวแบบ async - clone ได้หลายตัว
+#[derive(Clone)]
+pub struct AsyncPublisher {
+    tx: mpsc::Sender<Envelope>,
+}
+
+impl fmt::Debug for AsyncPublisher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AsyncPublisher")
+            .field("queued", &(self.tx.max_capacity() - self.tx.capacity()))
+            .finish()
+    }
+}
+
+impl AsyncPublisher {
+    /// ส่งเข้าคิว - ถ้าเต็มจะรอจนกว่า dispatcher จะตามทัน
+    ///
+    /// # Errors
+    ///
+    /// คืน `PublishError::Closed` ถ้า dispatcher หยุดไปแล้ว
+    pub async fn publish<T: Any + Send>(&self, event: T) -> Result<(), PublishError<T>> {
+        let permit = self.tx.reserve().await;
+        match permit {
+            Ok(permit) => {
+                permit.send(envelope(event));
+                Ok(())
+            }
+            Err(_) => Err(PublishError::Closed(event)),
+        }
+    }
+
+    /// ส่งเข้าคิวโดยไม่รอ
+    ///
+    /// # Errors
+    ///
+    /// คืน `PublishError::Full` ถ้าคิวเต็ม หรือ `Closed` ถ้า dispatcher หยุดไปแล้ว
+    pub fn try_publish<T: Any + Send>(&self, event: T) -> Result<(), PublishError<T>> {
+        match self.tx.try_reserve() {
+            Ok(permit) => {
+                permit.send(envelope(event));
+                Ok(())
+            }
+            Err(mpsc::error::TrySendError::Full(())) => Err(PublishError::Full(event)),
+            Err(mpsc::error::TrySendError::Closed(())) => Err(PublishError::Closed(event)),
+        }
+    }
+}
+
+fn envelope<T: Any + Send>(event: T) -> Envelope {
+    Box::new(move |bus: &EventBus| bus.publish(event))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Ping(u32);
+
+    type PingHandler = Box<dyn Fn(&Ping) + Send + Sync>;
+
+    fn recorder() -> (Arc<Mutex<Vec<String>>>, impl Fn(&str) -> PingHandler) {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&log);
+        let make = move |name: &str| -> PingHandler {
+            let sink = Arc::clone(&sink);
+            let name = name.to_string();
+            Box::new(move |ping: &Ping| sink.lock().unwrap().push(format!("{name}:{}", ping.0)))
+        };
+        (log, make)
+    }
+
+    #[test]
+    fn test_sync_delivery_keeps_subscription_order() {
+        let bus = EventBus::new();
+        let (log, make) = recorder();
+        let first = make("a");
+        bus.subscribe(move |ping: &Ping| first(ping));
+        let second = make("b");
+        let id = bus.subscribe(move |ping: &Ping| second(ping));
+        bus.subscribe(|_: &String| panic!("wrong topic"));
+
+        assert_eq!(bus.publish(Ping(1)), 2);
+        assert_eq!(bus.publish(Ping(2)), 2);
+        assert!(bus.unsubscribe(id));
+        assert!(!bus.unsubscribe(id));
+        assert_eq!(bus.publish(Ping(3)), 1);
+        assert_eq!(*log.lock().unwrap(), ["a:1", "b:1", "a:2", "b:2", "a:3"]);
+    }
+
+    #[test]
+    fn test_handlers_can_publish_reentrantly() {
+        let bus = EventBus::new();
+        let (log, make) = recorder();
+        let record = make("seen");
+        bus.subscribe(move |ping: &Ping| record(ping));
+        let chained = bus.clone();
+        bus.subscribe(move |ping: &Ping| {
+            if ping.0 < 3 {
+                chained.publish(Ping(ping.0 + 1));
+            }
+        });
+
+        bus.publish(Ping(1));
+        assert_eq!(*log.lock().unwrap(), ["seen:1", "seen:2", "seen:3"]);
+    }
+
+    #[test]
+    fn test_weak_subscribers_are_cleaned_up() {
+        struct Counter(Mutex<u32>);
+        impl Subscriber<Ping> for Counter {
+            fn on_event(&self, event: &Ping) {
+                *self.0.lock().unwrap() += event.0;
+            }
+        }
+
+        let bus = EventBus::new();
+        let counter = Arc::new(Counter(Mutex::new(0)));
+        bus.subscribe_weak::<Ping, _>(&counter);
+        assert_eq!(bus.publish(Ping(4)), 1);
+        assert_eq!(*counter.0.lock().unwrap(), 4);
+        assert_eq!(Arc::strong_count(&counter), 1);
+
+        drop(counter);
+        assert_eq!(bus.publish(Ping(4)), 0);
+        assert_eq!(bus.subscriber_count::<Ping>(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_async_delivery_in_order_with_backpressure() {
+        let bus = EventBus::new();
+        let (log, make) = recorder();
+        let record = make("async");
+        bus.subscribe(move |ping: &Ping| record(ping));
+
+        let (publisher, dispatcher) = bus.start_async(2);
+        // current-thread runtime: dispatcher ยังไม่ได้รันจนกว่าเราจะ await → คิวเต็มที่ 2
+        publisher.try_publish(Ping(1)).unwrap();
+        publisher.try_publish(Ping(2)).unwrap();
+        assert_eq!(
+            publisher.try_publish(Ping(3)),
+            Err(PublishError::Full(Ping(3)))
+        );
+
+        // publish แบบรอ: ปล่อยให้ dispatcher ระบายคิวก่อนแล้วค่อยเข้าคิว
+        publisher.publish(Ping(3)).await.unwrap();
+        drop(publisher);
+        assert_eq!(dispatcher.await.unwrap(), 3);
+        assert_eq!(*log.lock().unwrap(), ["async:1", "async:2", "async:3"]);
+    }
+}
@@ -0,0 +1,326 @@
+//! 📋 รายการแบบฝึกหัดและ test case ที่ซ่อนไว้
+//!
+//! แต่ละ `check_*` รับฟังก์ชันที่จะตรวจเป็น parameter - ตอนใช้งานจริงส่งคำตอบของผู้เรียน
+//! ส่วนใน tests ส่งเฉลยเข้าไปเพื่อยืนยันว่า test case เองถูกต้อง
+
+use std::num::ParseIntError;
+
+use super::grader::{Approx, CaseResult, Exercise, case};
+use super::workspace::{ch01, ch02, ch06, ch07, ch08};
+
+/// แบบฝึกหัดทั้งหมด เรียงตาม id
+pub const EXERCISES: &[Exercise] = &[
+    Exercise {
+        id: "01-1",
+        title: "แปลงองศา",
+        prompt: "แปลงองศาเซลเซียสเป็นฟาเรนไฮต์ด้วยสูตร F = C × 9/5 + 32",
+        signature: "pub fn celsius_to_fahrenheit(celsius: f64) -> f64",
+        file: "exercises/ch01.rs",
+        hints: &[
+            "ใช้ตัวเลขแบบ f64 ทั้งหมด: 9.0 / 5.0 (ถ้าเขียน 9 / 5 จะได้ 1 เพราะเป็นการหารจำนวนเต็ม)",
+            "celsius * 9.0 / 5.0 + 32.0",
+        ],
+        check: || check_celsius(ch01::celsius_to_fahrenheit),
+    },
+    Exercise {
+        id: "01-2",
+        title: "FizzBuzz",
+        prompt: "คืน \"Fizz\" เมื่อหาร 3 ลงตัว, \"Buzz\" เมื่อหาร 5 ลงตัว, \"FizzBuzz\" เมื่อลงตัวทั้งคู่ นอกนั้นคืนตัวเลข",
+        signature: "pub fn fizzbuzz(n: u32) -> String",
+        file: "exercises/ch01.rs",
+        hints: &[
+            "ตรวจกรณีหาร 15 ลงตัวก่อน ไม่งั้นจะได้แค่ \"Fizz\"",
+            "match (n % 3, n % 5) { (0, 0) => ..., (0, _) => ..., (_, 0) => ..., _ => n.to_string() }",
+        ],
+        check: || check_fizzbuzz(ch01::fizzbuzz),
+    },
+    Exercise {
+        id: "02-1",
+        title: "คำแรก",
+        prompt: "คืนคำแรกของประโยคเป็น &str ที่ยืม (borrow) มาจาก input โดยไม่สร้าง String ใหม่",
+        signature: "pub fn first_word(text: &str) -> &str",
+        file: "exercises/ch02.rs",
+        hints: &[
+            "ช่องว่างข้างหน้าต้องข้ามไปก่อน - ลอง text.trim_start()",
+            "split_whitespace().next() คืน Option<&str> ที่ยังชี้เข้าไปใน text เดิม",
+        ],
+        check: || check_first_word(ch02::first_word),
+    },
+    Exercise {
+        id: "06-1",
+        title: "คำยอดฮิต",
+        prompt: "หาคำที่พบบ่อยที่สุด (ไม่สนตัวพิมพ์) ถ้าเสมอกันเลือกคำที่มาก่อนตามตัวอักษร",
+        signature: "pub fn most_common_word(text: &str) -> Option<String>",
+        file: "exercises/ch06.rs",
+        hints: &[
+            "นับด้วย HashMap<String, usize> และ entry(word).or_insert(0)",
+            "BTreeMap เรียง key ให้อยู่แล้ว - max_by_key จะคืนตัวสุดท้ายเมื่อเสมอ ระวังเรื่องนี้",
+        ],
+        check: || check_most_common(ch06::most_common_word),
+    },
+    Exercise {
+        id: "07-1",
+        title: "อ่านเปอร์เซ็นต์",
+        prompt: "อ่าน \"42%\" หรือ \"42\" เป็น Ok(42) ส่วนค่าเกิน 100 หรืออ่านไม่ออกคืน Err",
+        signature: "pub fn parse_percentage(text: &str) -> Result<u8, String>",
+        file: "exercises/ch07.rs",
+        hints: &[
+            "strip_suffix('%') คืน Option - ใช้ unwrap_or(text) เพื่อรับทั้งสองแบบ",
+            "parse::<u8>() แล้ว map_err(|e| e.to_string()) จากนั้นค่อยตรวจว่าไม่เกิน 100",
+        ],
+        check: || check_percentage(ch07::parse_percentage),
+    },
+    Exercise {
+        id: "07-2",
+        title: "รวมตัวเลขจาก CSV",
+        prompt: "รวมตัวเลขที่คั่นด้วย , (มีช่องว่างได้) ถ้ามีตัวที่อ่านไม่ออกให้ส่ง error ต่อด้วย ?",
+        signature: "pub fn sum_csv(text: &str) -> Result<i64, ParseIntError>",
+        file: "exercises/ch07.rs",
+        hints: &[
+            "string ว่างต้องได้ Ok(0) - กรองชิ้นที่ว่างทิ้งก่อน parse",
+            "อย่าลืม trim() แต่ละชิ้น: \" 2\".parse::<i64>() เป็น error",
+            "Iterator ของ Result รวมด้วย .sum::<Result<i64, _>>() ได้เลย",
+        ],
+        check: || check_sum_csv(ch07::sum_csv),
+    },
+    Exercise {
+        id: "08-1",
+        title: "ค่ามากสุดแบบ generic",
+        prompt: "คืนค่ามากสุดของ slice ชนิดใดก็ได้ที่เป็น PartialOrd + Copy, slice ว่างคืน None",
+        signature: "pub fn largest<T: PartialOrd + Copy>(items: &[T]) -> Option<T>",
+        file: "exercises/ch08.rs",
+        hints: &[
+            "เริ่มจาก items.first().copied()? แล้ววนหาตัวที่มากกว่า",
+            "f64 ไม่ใช่ Ord จึงใช้ .max() ของ Iterator ไม่ได้ - ต้องเทียบด้วย > เอง",
+        ],
+        check: || check_largest(ch08::largest::<i32>, ch08::largest::<f64>),
+    },
+];
+
+/// หาแบบฝึกหัดจาก id (`"7-2"` ก็ได้)
+#[must_use]
+pub fn find(id: &str) -> Option<&'static Exercise> {
+    let (chapter, number) = id.trim().split_once('-')?;
+    let chapter: u8 = chapter.parse().ok()?;
+    let number: u8 = number.parse().ok()?;
+    let normalized = format!("{chapter:02}-{number}");
+    EXERCISES.iter().find(|exercise| exercise.id == normalized)
+}
+
+fn check_celsius(convert: fn(f64) -> f64) -> Vec<CaseResult> {
+    [(0.0, 32.0), (100.0, 212.0), (-40.0, -40.0), (37.0, 98.6)]
+        .into_iter()
+        .map(|(celsius, fahrenheit)| {
+            case(
+                format!("celsius_to_fahrenheit({celsius:?})"),
+                Approx(fahrenheit),
+                move || Approx(convert(celsius)),
+            )
+        })
+        .collect()
+}
+
+fn check_fizzbuzz(fizzbuzz: fn(u32) -> String) -> Vec<CaseResult> {
+    [
+        (1, "1"),
+        (3, "Fizz"),
+        (5, "Buzz"),
+        (15, "FizzBuzz"),
+        (98, "98"),
+        (30, "FizzBuzz"),
+    ]
+    .into_iter()
+    .map(|(n, expected)| {
+        case(format!("fizzbuzz({n})"), expected.to_string(), move || {
+            fizzbuzz(n)
+        })
+    })
+    .collect()
+}
+
+fn check_first_word(first_word: fn(&str) -> &str) -> Vec<CaseResult> {
+    [
+        ("hello world", "hello"),
+        ("single", "single"),
+        ("   leading spaces", "leading"),
+        ("tab\tseparated", "tab"),
+        ("", ""),
+    ]
+    .into_iter()
+    .map(|(text, expected)| {
+        case(format!("first_word({text:?})"), expected, move || {
+            first_word(text)
+        })
+    })
+    .collect()
+}
+
+fn check_most_common(most_common: fn(&str) -> Option<String>) -> Vec<CaseResult> {
+    [
+        ("the cat and the hat", Some("the")),
+        ("Rust rust RUST go", Some("rust")),
+        ("b a", Some("a")),
+        ("", None),
+    ]
+    .into_iter()
+    .map(|(text, expected)| {
+        case(
+            format!("most_common_word({text:?})"),
+            expected.map(str::to_string),
+            move || most_common(text),
+        )
+    })
+    .collect()
+}
+
+fn check_percentage(parse: fn(&str) -> Result<u8, String>) -> Vec<CaseResult> {
+    // ข้อความ error เขียนอย่างไรก็ได้ จึงเทียบแค่ Ok/Err
+    [
+        ("42%", Ok(42)),
+        ("100", Ok(100)),
+        ("0%", Ok(0)),
+        ("101%", Err(())),
+        ("abc", Err(())),
+        ("-5", Err(())),
+    ]
+    .into_iter()
+    .map(|(text, expected)| {
+        case(format!("parse_percentage({text:?})"), expected, move || {
+            parse(text).map_err(|_| ())
+        })
+    })
+    .collect()
+}
+
+fn check_sum_csv(sum: fn(&str) -> Result<i64, ParseIntError>) -> Vec<CaseResult> {
+    [
+        ("1,2,3", Ok(6)),
+        ("10, -4, 7", Ok(13)),
+        ("", Ok(0)),
+        ("42", Ok(42)),
+        ("1,two,3", Err(())),
+    ]
+    .into_iter()
+    .map(|(text, expected)| {
+        case(format!("sum_csv({text:?})"), expected, move || {
+            sum(text).map_err(|_| ())
+        })
+    })
+    .collect()
+}
+
+fn check_largest(
+    largest_ints: fn(&[i32]) -> Option<i32>,
+    largest_floats: fn(&[f64]) -> Option<f64>,
+) -> Vec<CaseResult> {
+    vec![
+        case("largest(&[3, 7, 2])", Some(7), move || {
+            largest_ints(&[3, 7, 2])
+        }),
+        case("largest(&[-5, -1, -9])", Some(-1), move || {
+            largest_ints(&[-5, -1, -9])
+        }),
+        case("largest::<i32>(&[])", None, move || largest_ints(&[])),
+        case(
+            "largest(&[1.5, 0.2, 9.75])",
+            Some(Approx(9.75)),
+            move || largest_floats(&[1.5, 0.2, 9.75]).map(Approx),
+        ),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exercises::grader::Outcome;
+    use std::collections::HashMap;
+
+    fn assert_all_pass(results: &[CaseResult]) {
+        for result in results {
+            assert_eq!(result.outcome, Outcome::Passed, "{}", result.call);
+        }
+    }
+
+    #[test]
+    fn test_ids_are_unique_and_findable() {
+        for exercise in EXERCISES {
+            assert_eq!(find(exercise.id).map(|e| e.id), Some(exercise.id));
+            assert!(
+                crate::chapters::registry()
+                    .get(exercise.chapter())
+                    .is_some()
+            );
+            assert!(!exercise.hints.is_empty(), "{} needs a hint", exercise.id);
+        }
+        assert_eq!(find("7-2").map(|e| e.id), Some("07-2"));
+        assert!(find("99-1").is_none());
+        assert!(find("seven").is_none());
+    }
+
+    // เฉลย - ใช้ยืนยันว่า test case ที่ซ่อนไว้ถูกต้อง
+
+    fn largest<T: PartialOrd + Copy>(items: &[T]) -> Option<T> {
+        let mut best = *items.first()?;
+        for &item in items {
+            if item > best {
+                best = item;
+            }
+        }
+        Some(best)
+    }
+
+    #[test]
+    fn test_reference_solutions_pass_hidden_cases() {
+        assert_all_pass(&check_celsius(|c| c * 9.0 / 5.0 + 32.0));
+        assert_all_pass(&check_fizzbuzz(|n| match (n % 3, n % 5) {
+            (0, 0) => "FizzBuzz".to_string(),
+            (0, _) => "Fizz".to_string(),
+            (_, 0) => "Buzz".to_string(),
+            _ => n.to_string(),
+        }));
+        assert_all_pass(&check_first_word(|text| {
+            text.split_whitespace().next().unwrap_or("")
+        }));
+        assert_all_pass(&check_most_common(|text| {
+            let mut counts: HashMap<String, usize> = HashMap::new();
+            for word in text.split_whitespace() {
+                *counts.entry(word.to_lowercase()).or_insert(0) += 1;
+            }
+            counts
+                .into_iter()
+                .max_by(|(a, x), (b, y)| x.cmp(y).then_with(|| b.cmp(a)))
+                .map(|(word, _)| word)
+        }));
+        assert_all_pass(&check_percentage(|text| {
+            let value: u8 = text
+                .strip_suffix('%')
+                .unwrap_or(text)
+                .parse()
+                .map_err(|e: ParseIntError| e.to_string())?;
+            if value > 100 {
+                return Err(format!("{value} is over 100"));
+            }
+            Ok(value)
+        }));
+        assert_all_pass(&check_sum_csv(|text| {
+            text.split(',')
+                .map(str::trim)
+                .filter(|part| !part.is_empty())
+                .map(str::parse::<i64>)
+                .sum()
+        }));
+        assert_all_pass(&check_largest(largest::<i32>, largest::<f64>));
+    }
+
+    #[test]
+    fn test_wrong_answer_is_reported() {
+        let results = check_fizzbuzz(|n| n.to_string());
+        assert_eq!(
+            results[1].outcome,
+            Outcome::Failed {
+                actual: "\"3\"".to_string()
+            }
+        );
+        assert_eq!(results[1].expected, "\"Fizz\"");
+    }
+}
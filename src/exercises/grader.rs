@@ -0,0 +1,202 @@
+//! 🧪 Grader - รันคำตอบของผู้เรียนกับ test case ที่ซ่อนไว้ โดยไม่ให้ panic ล้มทั้งโปรแกรม
+//!
+//! จับ panic ด้วย `catch_unwind` จึงใช้ได้เฉพาะ build ที่ `panic = "unwind"` (debug/test)
+//! release profile ของ repo นี้ตั้ง `panic = "abort"` - `todo!()` ข้อแรกจะปิดทั้งโปรแกรม
+//! ผู้เรียกจึงต้องเช็ค `CAN_CATCH_PANICS` ก่อน `grade`
+
+use std::any::Any;
+use std::fmt;
+use std::panic::{self, UnwindSafe};
+
+/// build นี้ unwind ได้ - `false` เมื่อ compile ด้วย `panic = "abort"` ซึ่ง `catch_unwind` จับอะไรไม่ได้
+pub const CAN_CATCH_PANICS: bool = cfg!(panic = "unwind");
+
+/// ผลของ test case หนึ่งข้อ
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Passed,
+    Failed {
+        actual: String,
+    },
+    /// ยังเป็น `todo!()` อยู่
+    NotImplemented,
+    Panicked(String),
+}
+
+/// test case หนึ่งข้อพร้อมผล
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CaseResult {
+    /// การเรียกที่ใช้ตรวจ เช่น `sum_csv("1,2")`
+    pub call: String,
+    pub expected: String,
+    pub outcome: Outcome,
+}
+
+/// รัน `run` แล้วเทียบกับ `expected` - จับ panic ไว้เป็น `Outcome`
+#[allow(clippy::needless_pass_by_value)] // รับค่าตรงๆ ให้เขียน case ใน catalog ได้สั้น
+pub fn case<T>(
+    call: impl Into<String>,
+    expected: T,
+    run: impl FnOnce() -> T + UnwindSafe,
+) -> CaseResult
+where
+    T: fmt::Debug + PartialEq,
+{
+    let outcome = match panic::catch_unwind(run) {
+        Ok(actual) if actual == expected => Outcome::Passed,
+        Ok(actual) => Outcome::Failed {
+            actual: format!("{actual:?}"),
+        },
+        Err(payload) => {
+            let message = panic_message(payload.as_ref());
+            if message.starts_with("not yet implemented") {
+                Outcome::NotImplemented
+            } else {
+                Outcome::Panicked(message)
+            }
+        }
+    };
+    CaseResult {
+        call: call.into(),
+        expected: format!("{expected:?}"),
+        outcome,
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|text| (*text).to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "panic".to_string())
+}
+
+/// 🎯 `f64` ที่เทียบแบบคลาดเคลื่อนได้เล็กน้อย (ผลจาก floating point ไม่ตรงเป๊ะ)
+#[derive(Clone, Copy)]
+pub struct Approx(pub f64);
+
+impl PartialEq for Approx {
+    fn eq(&self, other: &Self) -> bool {
+        (self.0 - other.0).abs() <= 1e-9 * self.0.abs().max(1.0)
+    }
+}
+
+impl fmt::Debug for Approx {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// ✍️ แบบฝึกหัดหนึ่งข้อ
+#[derive(Debug, Clone, Copy)]
+pub struct Exercise {
+    /// `"<บท>-<ข้อ>"` เช่น `"07-2"`
+    pub id: &'static str,
+    pub title: &'static str,
+    pub prompt: &'static str,
+    /// signature ที่ต้องเขียน
+    pub signature: &'static str,
+    /// ไฟล์ที่ผู้เรียนแก้
+    pub file: &'static str,
+    /// เปิดให้ดูทีละข้อตามจำนวนครั้งที่ลองแล้วยังไม่ผ่าน
+    pub hints: &'static [&'static str],
+    pub check: fn() -> Vec<CaseResult>,
+}
+
+impl Exercise {
+    /// เลขบทจาก id
+    #[must_use]
+    pub fn chapter(&self) -> u8 {
+        self.id
+            .split_once('-')
+            .and_then(|(chapter, _)| chapter.parse().ok())
+            .unwrap_or_default()
+    }
+
+    /// ตรวจคำตอบ - ปิดข้อความ panic ของ `todo!()` ระหว่างตรวจเพื่อไม่ให้รกหน้าจอ
+    #[must_use]
+    pub fn grade(&self) -> Report {
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(|_| {}));
+        let cases = (self.check)();
+        panic::set_hook(hook);
+        Report { cases }
+    }
+}
+
+/// 📋 ผลการตรวจทั้งข้อ
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub cases: Vec<CaseResult>,
+}
+
+impl Report {
+    #[must_use]
+    pub fn passed(&self) -> usize {
+        self.cases
+            .iter()
+            .filter(|case| case.outcome == Outcome::Passed)
+            .count()
+    }
+
+    #[must_use]
+    pub const fn total(&self) -> usize {
+        self.cases.len()
+    }
+
+    #[must_use]
+    pub fn is_solved(&self) -> bool {
+        self.passed() == self.total()
+    }
+
+    /// ทุก case ยังเป็น `todo!()`
+    #[must_use]
+    pub fn not_started(&self) -> bool {
+        self.cases
+            .iter()
+            .all(|case| case.outcome == Outcome::NotImplemented)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_case_outcomes() {
+        assert_eq!(case("ok", 2, || 1 + 1).outcome, Outcome::Passed);
+        assert_eq!(
+            case("wrong", 2, || 3).outcome,
+            Outcome::Failed {
+                actual: "3".to_string()
+            }
+        );
+        let report = Exercise {
+            id: "99-1",
+            title: "",
+            prompt: "",
+            signature: "",
+            file: "",
+            hints: &[],
+            check: || {
+                vec![
+                    case("todo", 0, || todo!()),
+                    case("boom", 0, || panic!("index out of bounds")),
+                    case("float", Approx(0.3), || Approx(0.1 + 0.2)),
+                ]
+            },
+        }
+        .grade();
+        let outcomes: Vec<&Outcome> = report.cases.iter().map(|case| &case.outcome).collect();
+        assert_eq!(
+            outcomes,
+            [
+                &Outcome::NotImplemented,
+                &Outcome::Panicked("index out of bounds".to_string()),
+                &Outcome::Passed,
+            ]
+        );
+        assert_eq!((report.passed(), report.total()), (1, 3));
+        assert!(!report.is_solved() && !report.not_started());
+    }
+}
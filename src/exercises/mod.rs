@@ -0,0 +1,213 @@
+//! ✍️ Exercises - `cargo run -- exercise 07-2` ตรวจคำตอบของผู้เรียนกับ test case ที่ซ่อนไว้
+//!
+//! ```text
+//! exercise --list                   # ทุกข้อพร้อมสถานะ ✅
+//! exercise 07-2                     # ตรวจข้อเดียว
+//! exercise 7-2 --progress my.json   # เก็บความคืบหน้าไว้ไฟล์อื่น
+//! ```
+//!
+//! - 📂 ผู้เรียนเขียนคำตอบใน `exercises/` ที่ root ของ repo (ดึงเข้ามาเป็น `workspace` ด้วย `#[path]`)
+//! - 🧪 `grader` จับ panic ทุก case - `todo!()` ขึ้นเป็น 🚧 ไม่ใช่ ❌ (ต้องเป็น build ที่ unwind ได้)
+//! - 💡 ยิ่งลองแล้วไม่ผ่าน ยิ่งเปิด hint ให้ดูเพิ่มทีละข้อ
+//! - 📈 ผลทุกครั้งบันทึกลง `progress::Progress`
+
+pub mod catalog;
+pub mod grader;
+
+#[path = "../../exercises/mod.rs"]
+pub mod workspace;
+
+use std::io::Write;
+use std::path::PathBuf;
+
+use anyhow::{Result, bail};
+use chrono::Utc;
+use clap::{Arg, ArgAction, ArgMatches, Command};
+
+pub use catalog::{EXERCISES, find};
+pub use grader::{CaseResult, Exercise, Outcome, Report};
+
+use crate::progress::{self, Progress};
+
+/// 🧩 subcommand `exercise` สำหรับเสียบเข้า `Command` หลักใน `main.rs`
+#[must_use]
+pub fn command() -> Command {
+    Command::new("exercise")
+        .visible_alias("ex")
+        .about("ตรวจแบบฝึกหัดที่เขียนไว้ใน exercises/")
+        .arg(
+            Arg::new("id")
+                .value_name("ID")
+                .help("รหัสข้อ เช่น 07-2 (ไม่ระบุ = แสดงรายการ)"),
+        )
+        .arg(
+            Arg::new("list")
+                .short('l')
+                .long("list")
+                .action(ArgAction::SetTrue)
+                .conflicts_with("id")
+                .help("แสดงทุกข้อพร้อมสถานะ"),
+        )
+        .arg(
+            Arg::new("progress")
+                .long("progress")
+                .value_name("PATH")
+                .value_parser(clap::value_parser!(PathBuf))
+                .help("ไฟล์เก็บความคืบหน้า"),
+        )
+}
+
+/// รัน subcommand ที่ parse แล้ว - ผลลัพธ์เขียนลง `out` เพื่อให้ tests จับได้
+///
+/// # Errors
+///
+/// คืน error เมื่อไม่รู้จักรหัสข้อ หรืออ่าน/เขียนไฟล์ progress ไม่ได้
+pub fn run(matches: &ArgMatches, out: &mut impl Write) -> Result<()> {
+    let file = matches
+        .get_one::<PathBuf>("progress")
+        .cloned()
+        .unwrap_or_else(progress::default_file);
+    let mut progress = Progress::load(&file)?;
+
+    let Some(id) = matches.get_one::<String>("id") else {
+        return list(&progress, out);
+    };
+    let Some(exercise) = find(id) else {
+        bail!("ไม่มีแบบฝึกหัด {id} - ดูรายการด้วย `exercise --list`");
+    };
+
+    writeln!(out, "✍️ {} {}", exercise.id, exercise.title)?;
+    writeln!(out, "   {}", exercise.prompt)?;
+    writeln!(out, "   📂 {}: {}", exercise.file, exercise.signature)?;
+    writeln!(out)?;
+
+    if !grader::CAN_CATCH_PANICS {
+        bail!(
+            "build นี้ใช้ panic = \"abort\" - todo!() ข้อแรกจะปิดโปรแกรมทั้งตัว \
+             ตรวจแบบฝึกหัดด้วย debug build แทน: `cargo run -- exercise {}`",
+            exercise.id
+        );
+    }
+    let report = exercise.grade();
+    for case in &report.cases {
+        write_case(case, out)?;
+    }
+    let record = progress.record_exercise(exercise.id, report.passed(), report.total(), Utc::now());
+    let attempts = record.attempts;
+    writeln!(
+        out,
+        "\n📊 ผ่าน {}/{} (ลองมาแล้ว {attempts} ครั้ง)",
+        report.passed(),
+        report.total()
+    )?;
+
+    if report.is_solved() {
+        writeln!(out, "🎉 ยอดเยี่ยม! ข้อ {} ผ่านครบแล้ว", exercise.id)?;
+    } else if report.not_started() {
+        writeln!(
+            out,
+            "🚧 ยังเป็น todo!() อยู่ - เปิด {} แล้วลองเขียนดู",
+            exercise.file
+        )?;
+    }
+    if !report.is_solved() {
+        let shown = (attempts as usize).min(exercise.hints.len());
+        for hint in &exercise.hints[..shown] {
+            writeln!(out, "💡 {hint}")?;
+        }
+        if shown < exercise.hints.len() {
+            writeln!(out, "   (ลองอีกครั้งเพื่อดู hint ถัดไป)")?;
+        }
+    }
+
+    progress.save(&file)?;
+    Ok(())
+}
+
+fn write_case(case: &CaseResult, out: &mut impl Write) -> Result<()> {
+    match &case.outcome {
+        Outcome::Passed => writeln!(out, "   ✅ {}", case.call)?,
+        Outcome::Failed { actual } => writeln!(
+            out,
+            "   ❌ {} - ต้องได้ {} แต่ได้ {actual}",
+            case.call, case.expected
+        )?,
+        Outcome::NotImplemented => writeln!(out, "   🚧 {} - ยังไม่ได้เขียน", case.call)?,
+        Outcome::Panicked(message) => {
+            writeln!(out, "   💥 {} - panic: {message}", case.call)?;
+        }
+    }
+    Ok(())
+}
+
+fn list(progress: &Progress, out: &mut impl Write) -> Result<()> {
+    writeln!(out, "✍️ แบบฝึกหัดทั้งหมด (แก้คำตอบใน exercises/)")?;
+    for exercise in EXERCISES {
+        let status = match progress.exercise(exercise.id) {
+            Some(record) if record.is_solved() => "✅".to_string(),
+            Some(record) => format!("{}/{}", record.best_passed, record.total),
+            None => "  ".to_string(),
+        };
+        writeln!(out, "   {status:>3} {} {}", exercise.id, exercise.title)?;
+    }
+    writeln!(
+        out,
+        "\n📈 ผ่านแล้ว {}/{} ข้อ - ตรวจด้วย `exercise <ID>`",
+        progress.solved_count(),
+        EXERCISES.len()
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+    use tempfile::TempDir;
+
+    fn run_exercise(file: &Path, args: &[&str]) -> Result<String> {
+        let file = file.to_str().unwrap();
+        let matches = command().try_get_matches_from(
+            std::iter::once(&"exercise")
+                .chain(args)
+                .chain(&["--progress", file]),
+        )?;
+        let mut out = Vec::new();
+        run(&matches, &mut out)?;
+        Ok(String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn test_grading_records_progress_and_reveals_hints() {
+        let dir = TempDir::new().unwrap();
+        let file = dir.path().join("progress.json");
+        // ผลขึ้นกับคำตอบใน exercises/ - ถ้าผู้เรียนทำข้อนี้ผ่านแล้วจะไม่มี hint
+        let hints = |attempts: usize| {
+            if find("07-2").unwrap().grade().is_solved() {
+                0
+            } else {
+                attempts
+            }
+        };
+        let first = run_exercise(&file, &["07-2"]).unwrap();
+        assert!(first.contains("sum_csv(\"1,2,3\")"), "{first}");
+        assert!(first.contains("/5 (ลองมาแล้ว 1 ครั้ง)"));
+        assert_eq!(first.matches("💡").count(), hints(1));
+
+        let second = run_exercise(&file, &["7-2"]).unwrap();
+        assert_eq!(second.matches("💡").count(), hints(2));
+
+        let record = Progress::load(&file)
+            .unwrap()
+            .exercise("07-2")
+            .cloned()
+            .unwrap();
+        assert_eq!((record.attempts, record.total), (2, 5));
+
+        let listing = run_exercise(&file, &["--list"]).unwrap();
+        assert!(listing.contains("07-2 รวมตัวเลขจาก CSV"), "{listing}");
+        assert!(listing.contains(&format!("/{} ข้อ", EXERCISES.len())));
+
+        assert!(run_exercise(&file, &["42-1"]).is_err());
+    }
+}
@@ -0,0 +1,362 @@
+//! 🌐 i18n - ข้อความที่แปลได้ สำหรับผู้เรียนที่ไม่ได้อ่านภาษาไทย
+//!
+//! แทนการเขียนข้อความไทยตรงๆ ใน `println!` ให้อ้างด้วย key ผ่าน `t!`:
+//!
+//! ```
+//! use rust_concepts::i18n::{self, Locale, t};
+//!
+//! i18n::with_locale(Locale::En, || {
+//!     assert_eq!(t!("chapter.done", chapter = 2), "Finished chapter 2!");
+//! });
+//! i18n::with_locale(Locale::Th, || {
+//!     assert_eq!(t!("chapter.done", chapter = 2), "เสร็จสิ้นบทที่ 2!");
+//! });
+//! ```
+//!
+//! - 🗂️ `Catalog` - ข้อความของแต่ละภาษาเป็นตาราง `key → ข้อความ` (placeholder เขียนเป็น `{name}`)
+//! - 🪂 fallback - ไม่มีในภาษาที่เลือก → ใช้ภาษาไทย (ภาษาต้นฉบับ) → ไม่มีเลย คืน key เดิม
+//!   ข้อความจึงไม่หายไปเงียบๆ แค่ยังไม่ได้แปล
+//! - 🎚️ เลือกภาษาด้วย `--lang th|en` หรือ env `RUST_CONCEPTS_LANG` (flag ชนะ env)
+//!
+//! เหมือน `logger`: `set_locale` ตั้งค่าทั้งโปรแกรม ส่วน `with_locale` เปลี่ยนเฉพาะ thread ปัจจุบัน
+//! tests ที่รันขนานกันจึงไม่ชนกัน 🧪
+
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// env ที่ใช้เลือกภาษาเมื่อไม่ได้ส่ง `--lang`
+pub const LANG_ENV: &str = "RUST_CONCEPTS_LANG";
+
+/// แปลข้อความจาก key ตามภาษาปัจจุบัน: `t!("key")` หรือ `t!("key", name = value, ...)`
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __i18n_t {
+    ($key:expr $(,)?) => {
+        $crate::i18n::translate($key, &[])
+    };
+    ($key:expr, $($name:ident = $value:expr),+ $(,)?) => {
+        $crate::i18n::translate(
+            $key,
+            &[$((stringify!($name), &$value as &dyn ::std::fmt::Display)),+],
+        )
+    };
+}
+
+pub use crate::__i18n_t as t;
+
+/// 🗣️ ภาษาที่รองรับ
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Locale {
+    /// ภาษาต้นฉบับของโปรเจค - ใช้เป็น fallback
+    #[default]
+    Th,
+    En,
+}
+
+impl Locale {
+    pub const ALL: [Self; 2] = [Self::Th, Self::En];
+    pub const FALLBACK: Self = Self::Th;
+
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Th => "th",
+            Self::En => "en",
+        }
+    }
+
+    /// อ่านจาก env `RUST_CONCEPTS_LANG` - ไม่ได้ตั้งหรืออ่านไม่ออก คืน `None`
+    #[must_use]
+    pub fn from_env() -> Option<Self> {
+        std::env::var(LANG_ENV).ok()?.parse().ok()
+    }
+
+    const fn from_u8(value: u8) -> Self {
+        match value {
+            1 => Self::En,
+            _ => Self::Th,
+        }
+    }
+
+    const fn to_u8(self) -> u8 {
+        match self {
+            Self::Th => 0,
+            Self::En => 1,
+        }
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Locale {
+    type Err = String;
+
+    /// รับ `th`, `EN`, และรูปแบบ POSIX/BCP 47 อย่าง `en_US.UTF-8` หรือ `th-TH`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let language = s
+            .trim()
+            .split(['_', '-', '.'])
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        match language.as_str() {
+            "th" => Ok(Self::Th),
+            "en" => Ok(Self::En),
+            _ => Err(format!("unsupported language `{s}` (expected th or en)")),
+        }
+    }
+}
+
+/// 🗂️ ตารางข้อความของทุกภาษา
+#[derive(Debug, Clone, Default)]
+pub struct Catalog {
+    messages: HashMap<Locale, HashMap<&'static str, &'static str>>,
+}
+
+impl Catalog {
+    /// catalog ว่าง - เพิ่มข้อความด้วย `with_messages`
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// catalog ที่มากับโปรเจค
+    #[must_use]
+    pub fn builtin() -> Self {
+        Self::new()
+            .with_messages(Locale::Th, TH)
+            .with_messages(Locale::En, EN)
+    }
+
+    /// เพิ่ม/ทับข้อความของ `locale`
+    #[must_use]
+    pub fn with_messages(
+        mut self,
+        locale: Locale,
+        messages: &[(&'static str, &'static str)],
+    ) -> Self {
+        self.messages
+            .entry(locale)
+            .or_default()
+            .extend(messages.iter().copied());
+        self
+    }
+
+    /// หาข้อความดิบ (ยังไม่แทน placeholder) โดยไล่ `locale` → `Locale::FALLBACK`
+    #[must_use]
+    pub fn lookup(&self, locale: Locale, key: &str) -> Option<&'static str> {
+        [locale, Locale::FALLBACK]
+            .into_iter()
+            .find_map(|candidate| self.messages.get(&candidate)?.get(key).copied())
+    }
+
+    /// key ที่มีในภาษา `locale`
+    pub fn keys(&self, locale: Locale) -> impl Iterator<Item = &'static str> + '_ {
+        self.messages
+            .get(&locale)
+            .into_iter()
+            .flat_map(|messages| messages.keys().copied())
+    }
+
+    /// แปล `key` แล้วแทน `{name}` ด้วยค่าใน `args` - ไม่พบ key เลยคืน key เดิม
+    #[must_use]
+    pub fn translate(
+        &self,
+        locale: Locale,
+        key: &str,
+        args: &[(&str, &dyn fmt::Display)],
+    ) -> String {
+        let Some(template) = self.lookup(locale, key) else {
+            return key.to_string();
+        };
+        args.iter()
+            .fold(template.to_string(), |text, (name, value)| {
+                text.replace(&format!("{{{name}}}"), &value.to_string())
+            })
+    }
+}
+
+/// catalog กลางของโปรเจค (สร้างครั้งแรกที่ใช้)
+#[must_use]
+pub fn catalog() -> &'static Catalog {
+    static CATALOG: OnceLock<Catalog> = OnceLock::new();
+    CATALOG.get_or_init(Catalog::builtin)
+}
+
+static GLOBAL: AtomicU8 = AtomicU8::new(0);
+
+thread_local! {
+    static SCOPED: Cell<Option<Locale>> = const { Cell::new(None) };
+}
+
+/// ตั้งภาษาของทั้งโปรแกรม
+pub fn set_locale(locale: Locale) {
+    GLOBAL.store(locale.to_u8(), Ordering::Relaxed);
+}
+
+/// ภาษาที่ใช้อยู่บน thread นี้: ตัวจาก `with_locale` > `set_locale` > ไทย
+#[must_use]
+pub fn locale() -> Locale {
+    SCOPED
+        .with(Cell::get)
+        .unwrap_or_else(|| Locale::from_u8(GLOBAL.load(Ordering::Relaxed)))
+}
+
+/// รัน `f` โดยใช้ `locale` เฉพาะบน thread นี้ แล้วคืนค่าเดิมเมื่อจบ (แม้ `f` จะ panic)
+pub fn with_locale<R>(locale: Locale, f: impl FnOnce() -> R) -> R {
+    struct Restore(Option<Locale>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            SCOPED.with(|scoped| scoped.set(self.0));
+        }
+    }
+
+    let _restore = Restore(SCOPED.with(|scoped| scoped.replace(Some(locale))));
+    f()
+}
+
+/// ใช้โดย `t!` - แปลด้วย catalog กลางและภาษาปัจจุบัน
+#[doc(hidden)]
+#[must_use]
+pub fn translate(key: &str, args: &[(&str, &dyn fmt::Display)]) -> String {
+    catalog().translate(locale(), key, args)
+}
+
+/// 🇹🇭 ข้อความต้นฉบับ
+const TH: &[(&str, &str)] = &[
+    ("chapter.done", "เสร็จสิ้นบทที่ {chapter}!"),
+    ("chapter.title", "บทที่ {number}: {title}"),
+    ("chapter.1.name", "พื้นฐาน Rust"),
+    ("chapter.2.name", "Ownership และ Borrowing"),
+    ("basics.variables", "Variables และ Mutability"),
+    ("basics.data_types", "Data Types"),
+    ("basics.compound_types", "Compound Types (Array, Tuple)"),
+    ("basics.constants_shadowing", "Constants และ Shadowing"),
+    ("basics.type_conversion", "Type Conversion"),
+    ("basics.operators", "Operators"),
+    ("basics.comments", "Comments และ Documentation"),
+    ("basics.practice", "แบบฝึกหัดพื้นฐาน"),
+    ("ownership.basics", "Ownership Basics"),
+    ("ownership.borrowing", "Borrowing และ References"),
+    ("ownership.slices", "Slices"),
+    ("ownership.slices_note", "String slices และ array slices"),
+    (
+        "ownership.visualizer",
+        "Ownership Timeline (ดู move/borrow/drop ทีละขั้น)",
+    ),
+    ("ownership.practice", "Practice Examples"),
+];
+
+/// 🇬🇧 คำแปลภาษาอังกฤษ - key ที่ขาดจะ fallback เป็นไทย
+const EN: &[(&str, &str)] = &[
+    ("chapter.done", "Finished chapter {chapter}!"),
+    ("chapter.title", "Chapter {number}: {title}"),
+    ("chapter.1.name", "Rust Basics"),
+    ("chapter.2.name", "Ownership and Borrowing"),
+    ("basics.variables", "Variables and Mutability"),
+    ("basics.data_types", "Data Types"),
+    ("basics.compound_types", "Compound Types (Array, Tuple)"),
+    ("basics.constants_shadowing", "Constants and Shadowing"),
+    ("basics.type_conversion", "Type Conversion"),
+    ("basics.operators", "Operators"),
+    ("basics.comments", "Comments and Documentation"),
+    ("basics.practice", "Basics Exercises"),
+    ("ownership.basics", "Ownership Basics"),
+    ("ownership.borrowing", "Borrowing and References"),
+    ("ownership.slices", "Slices"),
+    ("ownership.slices_note", "String slices and array slices"),
+    (
+        "ownership.visualizer",
+        "Ownership Timeline (moves, borrows and drops step by step)",
+    ),
+    ("ownership.practice", "Practice Examples"),
+];
+
+#[cfg(test)]
+#[allow(clippy::literal_string_with_formatting_args)] // `{name}` เป็น placeholder ของ catalog ไม่ใช่ format!
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_locale() {
+        assert_eq!("th".parse(), Ok(Locale::Th));
+        assert_eq!("EN".parse(), Ok(Locale::En));
+        assert_eq!("en_US.UTF-8".parse(), Ok(Locale::En));
+        assert_eq!("th-TH".parse(), Ok(Locale::Th));
+        assert!("fr".parse::<Locale>().is_err());
+        assert!("".parse::<Locale>().is_err());
+    }
+
+    #[test]
+    fn test_fallback_chain() {
+        let catalog = Catalog::new()
+            .with_messages(
+                Locale::Th,
+                &[("greeting", "สวัสดี {name}"), ("only.th", "ไทยเท่านั้น")],
+            )
+            .with_messages(Locale::En, &[("greeting", "Hello {name}")]);
+
+        let name: &dyn fmt::Display = &"Ferris";
+        assert_eq!(
+            catalog.translate(Locale::En, "greeting", &[("name", name)]),
+            "Hello Ferris"
+        );
+        assert_eq!(
+            catalog.translate(Locale::Th, "greeting", &[("name", name)]),
+            "สวัสดี Ferris"
+        );
+        // ยังไม่แปล → ใช้ภาษาไทย
+        assert_eq!(catalog.translate(Locale::En, "only.th", &[]), "ไทยเท่านั้น");
+        // ไม่มีเลย → คืน key
+        assert_eq!(
+            catalog.translate(Locale::En, "missing.key", &[]),
+            "missing.key"
+        );
+        // placeholder ที่ไม่ได้ส่งค่ามาคงไว้ตามเดิม
+        assert_eq!(
+            catalog.translate(Locale::En, "greeting", &[]),
+            "Hello {name}"
+        );
+    }
+
+    #[test]
+    fn test_macro_uses_scoped_locale() {
+        with_locale(Locale::En, || {
+            assert_eq!(locale(), Locale::En);
+            assert_eq!(t!("basics.practice"), "Basics Exercises");
+            assert_eq!(t!("chapter.done", chapter = 7), "Finished chapter 7!");
+            with_locale(Locale::Th, || {
+                assert_eq!(t!("basics.practice"), "แบบฝึกหัดพื้นฐาน");
+            });
+            assert_eq!(locale(), Locale::En);
+        });
+        assert_eq!(t!("no.such.key"), "no.such.key");
+    }
+
+    #[test]
+    fn test_builtin_catalog_is_complete() {
+        let sorted_keys = |locale| {
+            let mut keys: Vec<_> = catalog().keys(locale).collect();
+            keys.sort_unstable();
+            keys
+        };
+        let thai = sorted_keys(Locale::Th);
+        assert_eq!(thai.len(), TH.len(), "duplicate keys in the Thai catalog");
+        for locale in Locale::ALL {
+            assert_eq!(
+                sorted_keys(locale),
+                thai,
+                "{locale} catalog is missing keys"
+            );
+        }
+    }
+}
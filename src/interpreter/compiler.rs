@@ -0,0 +1,473 @@
+//! ⚙️ Compiler - แปลง AST เป็น bytecode ที่ VM แบบ stack รันได้
+//!
+//! ตัวแปรทุกตัวถูกแปลงเป็นเลข slot ตั้งแต่ตอน compile - VM ไม่ต้องค้นชื่อด้วย `HashMap` ตอนรัน
+//! และ `if`/`while`/`&&`/`||` กลายเป็น `Jump`/`JumpIfFalse` ไปยัง address ที่เติมทีหลัง (backpatching)
+
+use std::collections::HashMap;
+use std::fmt::{self, Write as _};
+
+use super::parser::{BinaryOp, Expr, Function, Program, Stmt, UnaryOp};
+use super::{CompileError, Position};
+
+/// ค่าที่ VM ถือบน stack
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Value {
+    Int(i64),
+    Bool(bool),
+    Unit,
+}
+
+impl Value {
+    #[must_use]
+    pub const fn type_name(self) -> &'static str {
+        match self {
+            Self::Int(_) => "int",
+            Self::Bool(_) => "bool",
+            Self::Unit => "()",
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Int(value) => write!(f, "{value}"),
+            Self::Bool(value) => write!(f, "{value}"),
+            Self::Unit => f.write_str("()"),
+        }
+    }
+}
+
+/// คำสั่งของ VM - operator ทั้งหมด pop ค่าจาก stack แล้ว push ผลลัพธ์กลับ
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Push(Value),
+    /// push ค่าของ local slot ในเฟรมปัจจุบัน
+    Load(usize),
+    /// pop ไปเก็บใน local slot
+    Store(usize),
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    Neg,
+    Not,
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Jump(usize),
+    /// pop ค่า `bool` แล้วกระโดดถ้าเป็น `false`
+    JumpIfFalse(usize),
+    /// เรียกฟังก์ชันตาม index ใน `Chunk::functions` - argument อยู่บน stack แล้ว
+    Call(usize),
+    Return,
+    Print,
+    Pop,
+    Halt,
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Push(value) => write!(f, "PUSH {value}"),
+            Self::Load(slot) => write!(f, "LOAD {slot}"),
+            Self::Store(slot) => write!(f, "STORE {slot}"),
+            Self::Jump(target) => write!(f, "JUMP {target:04}"),
+            Self::JumpIfFalse(target) => write!(f, "JUMP_IF_FALSE {target:04}"),
+            Self::Call(function) => write!(f, "CALL {function}"),
+            other => write!(f, "{}", format!("{other:?}").to_uppercase()),
+        }
+    }
+}
+
+/// ข้อมูลฟังก์ชันที่ VM ต้องใช้ตอน `Call`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FunctionInfo {
+    pub name: String,
+    pub arity: usize,
+    /// address ของคำสั่งแรก
+    pub entry: usize,
+    /// จำนวน slot ทั้งหมด (parameter + `let`) ที่ต้องจองในเฟรม
+    pub locals: usize,
+}
+
+/// ผลการ compile: bytecode ก้อนเดียว + ตารางฟังก์ชัน (`functions[0]` คือโปรแกรมหลัก)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Chunk {
+    pub code: Vec<Instruction>,
+    /// ตำแหน่งใน source ของแต่ละคำสั่ง - ใช้บอกบรรทัดตอน runtime error
+    pub positions: Vec<Position>,
+    pub functions: Vec<FunctionInfo>,
+}
+
+/// ชื่อของโปรแกรมหลักในตารางฟังก์ชัน
+pub const MAIN: &str = "<main>";
+
+impl Chunk {
+    /// index ของฟังก์ชันชื่อ `name`
+    #[must_use]
+    pub fn function(&self, name: &str) -> Option<usize> {
+        self.functions
+            .iter()
+            .position(|function| function.name == name)
+    }
+
+    /// listing ของ bytecode ทีละฟังก์ชัน เช่น `0003  LOAD 0`
+    #[must_use]
+    pub fn disassemble(&self) -> String {
+        let mut functions: Vec<&FunctionInfo> = self.functions.iter().collect();
+        functions.sort_by_key(|function| function.entry);
+        let mut listing = String::new();
+        for (index, function) in functions.iter().enumerate() {
+            let end = functions
+                .get(index + 1)
+                .map_or(self.code.len(), |next| next.entry);
+            let _ = writeln!(
+                listing,
+                "== {} (arity {}, locals {}) ==",
+                function.name, function.arity, function.locals
+            );
+            for address in function.entry..end {
+                let _ = writeln!(
+                    listing,
+                    "{address:04}  {:<4} {}",
+                    self.positions[address].line, self.code[address]
+                );
+            }
+        }
+        listing
+    }
+}
+
+/// compile ทั้งโปรแกรม - ชื่อฟังก์ชันทั้งหมดถูกลงทะเบียนก่อน จึงเรียกก่อนประกาศหรือเรียกตัวเองได้
+///
+/// # Errors
+///
+/// คืน `CompileError` เมื่อใช้ตัวแปร/ฟังก์ชันที่ไม่ได้ประกาศ, จำนวน argument ไม่ตรง,
+/// ประกาศฟังก์ชันซ้ำ หรือ `return` นอกฟังก์ชัน
+pub fn compile(program: &Program) -> Result<Chunk, CompileError> {
+    let mut signatures = HashMap::from([(MAIN.to_string(), (0, 0))]);
+    for (index, function) in program.functions.iter().enumerate() {
+        if signatures
+            .insert(function.name.clone(), (index + 1, function.params.len()))
+            .is_some()
+        {
+            return Err(CompileError::new(
+                format!("ประกาศฟังก์ชัน '{}' ซ้ำ", function.name),
+                function.position,
+            ));
+        }
+    }
+
+    let mut compiler = Compiler {
+        code: Vec::new(),
+        positions: Vec::new(),
+        signatures,
+        scopes: Vec::new(),
+        next_slot: 0,
+        max_slots: 0,
+        in_function: false,
+    };
+    let mut functions = Vec::new();
+
+    compiler.begin(&[]);
+    compiler.block(&program.main)?;
+    let end = compiler
+        .positions
+        .last()
+        .copied()
+        .unwrap_or(Position { line: 1, column: 1 });
+    compiler.emit(Instruction::Halt, end);
+    functions.push(FunctionInfo {
+        name: MAIN.to_string(),
+        arity: 0,
+        entry: 0,
+        locals: compiler.max_slots,
+    });
+
+    for function in &program.functions {
+        functions.push(compiler.function(function)?);
+    }
+
+    Ok(Chunk {
+        code: compiler.code,
+        positions: compiler.positions,
+        functions,
+    })
+}
+
+struct Compiler {
+    code: Vec<Instruction>,
+    positions: Vec<Position>,
+    /// ชื่อฟังก์ชัน → (index, arity)
+    signatures: HashMap<String, (usize, usize)>,
+    /// scope ซ้อนกันของฟังก์ชันที่กำลัง compile - ชื่อ → slot
+    scopes: Vec<Vec<(String, usize)>>,
+    next_slot: usize,
+    max_slots: usize,
+    in_function: bool,
+}
+
+impl Compiler {
+    fn emit(&mut self, instruction: Instruction, position: Position) -> usize {
+        self.code.push(instruction);
+        self.positions.push(position);
+        self.code.len() - 1
+    }
+
+    /// เติม address ปลายทางให้ jump ที่ emit ไว้ก่อนรู้ว่าจะไปไหน
+    fn patch(&mut self, jump: usize) {
+        let target = self.code.len();
+        match &mut self.code[jump] {
+            Instruction::Jump(address) | Instruction::JumpIfFalse(address) => *address = target,
+            _ => unreachable!("patch ใช้กับคำสั่ง jump เท่านั้น"),
+        }
+    }
+
+    /// เริ่มฟังก์ชันใหม่ - parameter ได้ slot 0..n ตามลำดับ
+    fn begin(&mut self, params: &[String]) {
+        self.scopes = vec![Vec::new()];
+        self.next_slot = 0;
+        self.max_slots = 0;
+        for param in params {
+            self.declare(param);
+        }
+    }
+
+    fn declare(&mut self, name: &str) -> usize {
+        let slot = self.next_slot;
+        self.next_slot += 1;
+        self.max_slots = self.max_slots.max(self.next_slot);
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.push((name.to_string(), slot));
+        }
+        slot
+    }
+
+    fn resolve(&self, name: &str, position: Position) -> Result<usize, CompileError> {
+        self.scopes
+            .iter()
+            .rev()
+            .flat_map(|scope| scope.iter().rev())
+            .find(|(declared, _)| declared == name)
+            .map(|&(_, slot)| slot)
+            .ok_or_else(|| CompileError::new(format!("ไม่รู้จักตัวแปร '{name}'"), position))
+    }
+
+    fn function(&mut self, function: &Function) -> Result<FunctionInfo, CompileError> {
+        let entry = self.code.len();
+        self.begin(&function.params);
+        self.in_function = true;
+        self.block(&function.body)?;
+        // ไม่มี return ท้ายฟังก์ชันก็คืน ()
+        self.emit(Instruction::Push(Value::Unit), function.position);
+        self.emit(Instruction::Return, function.position);
+        self.in_function = false;
+        Ok(FunctionInfo {
+            name: function.name.clone(),
+            arity: function.params.len(),
+            entry,
+            locals: self.max_slots,
+        })
+    }
+
+    /// block มี scope ของตัวเอง - ออกจาก block แล้ว slot ของ `let` ข้างในนำกลับมาใช้ใหม่ได้
+    fn block(&mut self, statements: &[Stmt]) -> Result<(), CompileError> {
+        let saved = self.next_slot;
+        self.scopes.push(Vec::new());
+        for statement in statements {
+            self.statement(statement)?;
+        }
+        self.scopes.pop();
+        self.next_slot = saved;
+        Ok(())
+    }
+
+    fn statement(&mut self, statement: &Stmt) -> Result<(), CompileError> {
+        match statement {
+            Stmt::Let {
+                name,
+                value,
+                position,
+            } => {
+                // compile ค่าก่อนประกาศ - `let x = x + 1;` จึงอ่าน x ตัวนอก
+                self.expression(value)?;
+                let slot = self.declare(name);
+                self.emit(Instruction::Store(slot), *position);
+            }
+            Stmt::Assign {
+                name,
+                value,
+                position,
+            } => {
+                let slot = self.resolve(name, *position)?;
+                self.expression(value)?;
+                self.emit(Instruction::Store(slot), *position);
+            }
+            Stmt::If {
+                condition,
+                then_branch,
+                else_branch,
+                position,
+            } => {
+                self.expression(condition)?;
+                let to_else = self.emit(Instruction::JumpIfFalse(0), *position);
+                self.block(then_branch)?;
+                if else_branch.is_empty() {
+                    self.patch(to_else);
+                } else {
+                    let to_end = self.emit(Instruction::Jump(0), *position);
+                    self.patch(to_else);
+                    self.block(else_branch)?;
+                    self.patch(to_end);
+                }
+            }
+            Stmt::While {
+                condition,
+                body,
+                position,
+            } => {
+                let start = self.code.len();
+                self.expression(condition)?;
+                let to_end = self.emit(Instruction::JumpIfFalse(0), *position);
+                self.block(body)?;
+                self.emit(Instruction::Jump(start), *position);
+                self.patch(to_end);
+            }
+            Stmt::Return { value, position } => {
+                if !self.in_function {
+                    return Err(CompileError::new(
+                        "ใช้ return ได้เฉพาะในฟังก์ชัน".to_string(),
+                        *position,
+                    ));
+                }
+                match value {
+                    Some(value) => {
+                        self.expression(value)?;
+                    }
+                    None => {
+                        self.emit(Instruction::Push(Value::Unit), *position);
+                    }
+                }
+                self.emit(Instruction::Return, *position);
+            }
+            Stmt::Print { value, position } => {
+                self.expression(value)?;
+                self.emit(Instruction::Print, *position);
+            }
+            Stmt::Expr(expr) => {
+                let position = self.expression(expr)?;
+                self.emit(Instruction::Pop, position);
+            }
+        }
+        Ok(())
+    }
+
+    /// compile นิพจน์ให้เหลือค่าเดียวบน stack - คืนตำแหน่งที่ใช้แทนนิพจน์นี้ทั้งก้อน
+    fn expression(&mut self, expr: &Expr) -> Result<Position, CompileError> {
+        let position = match expr {
+            Expr::Int(value, position) => {
+                self.emit(Instruction::Push(Value::Int(*value)), *position);
+                *position
+            }
+            Expr::Bool(value, position) => {
+                self.emit(Instruction::Push(Value::Bool(*value)), *position);
+                *position
+            }
+            Expr::Var { name, position } => {
+                let slot = self.resolve(name, *position)?;
+                self.emit(Instruction::Load(slot), *position);
+                *position
+            }
+            Expr::Unary {
+                op,
+                operand,
+                position,
+            } => {
+                self.expression(operand)?;
+                let instruction = match op {
+                    UnaryOp::Neg => Instruction::Neg,
+                    UnaryOp::Not => Instruction::Not,
+                };
+                self.emit(instruction, *position);
+                *position
+            }
+            Expr::Binary {
+                op: op @ (BinaryOp::And | BinaryOp::Or),
+                lhs,
+                rhs,
+                position,
+            } => {
+                // short-circuit: `a && b` ไม่ประเมิน b ถ้า a เป็น false
+                self.expression(lhs)?;
+                let short = self.emit(Instruction::JumpIfFalse(0), *position);
+                if *op == BinaryOp::And {
+                    self.expression(rhs)?;
+                    let to_end = self.emit(Instruction::Jump(0), *position);
+                    self.patch(short);
+                    self.emit(Instruction::Push(Value::Bool(false)), *position);
+                    self.patch(to_end);
+                } else {
+                    self.emit(Instruction::Push(Value::Bool(true)), *position);
+                    let to_end = self.emit(Instruction::Jump(0), *position);
+                    self.patch(short);
+                    self.expression(rhs)?;
+                    self.patch(to_end);
+                }
+                *position
+            }
+            Expr::Binary {
+                op,
+                lhs,
+                rhs,
+                position,
+            } => {
+                self.expression(lhs)?;
+                self.expression(rhs)?;
+                let instruction = match op {
+                    BinaryOp::Add => Instruction::Add,
+                    BinaryOp::Sub => Instruction::Sub,
+                    BinaryOp::Mul => Instruction::Mul,
+                    BinaryOp::Div => Instruction::Div,
+                    BinaryOp::Rem => Instruction::Rem,
+                    BinaryOp::Eq => Instruction::Eq,
+                    BinaryOp::Ne => Instruction::Ne,
+                    BinaryOp::Lt => Instruction::Lt,
+                    BinaryOp::Le => Instruction::Le,
+                    BinaryOp::Gt => Instruction::Gt,
+                    BinaryOp::Ge => Instruction::Ge,
+                    BinaryOp::And | BinaryOp::Or => unreachable!("จัดการใน arm ก่อนหน้าแล้ว"),
+                };
+                self.emit(instruction, *position);
+                *position
+            }
+            Expr::Call {
+                name,
+                args,
+                position,
+            } => {
+                let &(index, arity) = self
+                    .signatures
+                    .get(name)
+                    .filter(|_| name != MAIN)
+                    .ok_or_else(|| CompileError::new(format!("ไม่รู้จักฟังก์ชัน '{name}'"), *position))?;
+                if args.len() != arity {
+                    return Err(CompileError::new(
+                        format!("{name}() รับ {arity} argument แต่ได้ {}", args.len()),
+                        *position,
+                    ));
+                }
+                for arg in args {
+                    self.expression(arg)?;
+                }
+                self.emit(Instruction::Call(index), *position);
+                *position
+            }
+        };
+        Ok(position)
+    }
+}
@@ -0,0 +1,211 @@
+//! 🔤 Lexer - แยก source ทั้งไฟล์เป็น token พร้อมบรรทัด/คอลัมน์
+
+use std::fmt;
+use std::iter::Peekable;
+use std::str::Chars;
+
+use super::{CompileError, Position};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TokenKind {
+    Int(i64),
+    Ident(String),
+    Let,
+    Fn,
+    If,
+    Else,
+    While,
+    Return,
+    Print,
+    True,
+    False,
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    Percent,
+    Bang,
+    Assign,
+    EqEq,
+    NotEq,
+    Less,
+    LessEq,
+    Greater,
+    GreaterEq,
+    AndAnd,
+    OrOr,
+    LParen,
+    RParen,
+    LBrace,
+    RBrace,
+    Comma,
+    Semicolon,
+    Eof,
+}
+
+impl fmt::Display for TokenKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            Self::Int(value) => return write!(f, "ตัวเลข {value}"),
+            Self::Ident(name) => return write!(f, "ชื่อ '{name}'"),
+            Self::Let => "let",
+            Self::Fn => "fn",
+            Self::If => "if",
+            Self::Else => "else",
+            Self::While => "while",
+            Self::Return => "return",
+            Self::Print => "print",
+            Self::True => "true",
+            Self::False => "false",
+            Self::Plus => "+",
+            Self::Minus => "-",
+            Self::Star => "*",
+            Self::Slash => "/",
+            Self::Percent => "%",
+            Self::Bang => "!",
+            Self::Assign => "=",
+            Self::EqEq => "==",
+            Self::NotEq => "!=",
+            Self::Less => "<",
+            Self::LessEq => "<=",
+            Self::Greater => ">",
+            Self::GreaterEq => ">=",
+            Self::AndAnd => "&&",
+            Self::OrOr => "||",
+            Self::LParen => "(",
+            Self::RParen => ")",
+            Self::LBrace => "{",
+            Self::RBrace => "}",
+            Self::Comma => ",",
+            Self::Semicolon => ";",
+            Self::Eof => return f.write_str("จบไฟล์"),
+        };
+        write!(f, "'{text}'")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub position: Position,
+}
+
+/// แยก token ทั้งไฟล์ - ข้ามช่องว่างและ comment `//` จนจบบรรทัด
+///
+/// # Errors
+///
+/// คืน `CompileError` เมื่อเจอตัวอักษรที่ไม่รู้จักหรือตัวเลขที่ใหญ่เกิน `i64`
+pub fn tokenize(source: &str) -> Result<Vec<Token>, CompileError> {
+    let mut lexer = Lexer {
+        chars: source.chars().peekable(),
+        position: Position { line: 1, column: 1 },
+    };
+    let mut tokens = Vec::new();
+    while let Some(token) = lexer.next_token()? {
+        tokens.push(token);
+    }
+    tokens.push(Token {
+        kind: TokenKind::Eof,
+        position: lexer.position,
+    });
+    Ok(tokens)
+}
+
+struct Lexer<'a> {
+    chars: Peekable<Chars<'a>>,
+    position: Position,
+}
+
+impl Lexer<'_> {
+    fn bump_if(&mut self, accept: impl FnOnce(&char) -> bool) -> Option<char> {
+        let c = self.chars.next_if(accept)?;
+        if c == '\n' {
+            self.position.line += 1;
+            self.position.column = 1;
+        } else {
+            self.position.column += 1;
+        }
+        Some(c)
+    }
+
+    /// กิน `second` ถ้าเป็นตัวถัดไป - ใช้แยก `=` กับ `==`
+    fn eat(&mut self, second: char) -> bool {
+        self.bump_if(|&c| c == second).is_some()
+    }
+
+    fn next_token(&mut self) -> Result<Option<Token>, CompileError> {
+        loop {
+            let start = self.position;
+            let Some(c) = self.bump_if(|_| true) else {
+                return Ok(None);
+            };
+            let kind = match c {
+                c if c.is_whitespace() => continue,
+                '/' if self.eat('/') => {
+                    while self.bump_if(|&next| next != '\n').is_some() {}
+                    continue;
+                }
+                '0'..='9' => {
+                    let mut text = c.to_string();
+                    while let Some(digit) = self.bump_if(char::is_ascii_digit) {
+                        text.push(digit);
+                    }
+                    let value = text.parse().map_err(|_| {
+                        CompileError::new(format!("ตัวเลข {text} ใหญ่เกิน i64"), start)
+                    })?;
+                    TokenKind::Int(value)
+                }
+                c if c.is_alphabetic() || c == '_' => {
+                    let mut word = c.to_string();
+                    while let Some(next) =
+                        self.bump_if(|&next| next.is_alphanumeric() || next == '_')
+                    {
+                        word.push(next);
+                    }
+                    keyword(&word).unwrap_or(TokenKind::Ident(word))
+                }
+                '+' => TokenKind::Plus,
+                '-' => TokenKind::Minus,
+                '*' => TokenKind::Star,
+                '/' => TokenKind::Slash,
+                '%' => TokenKind::Percent,
+                '(' => TokenKind::LParen,
+                ')' => TokenKind::RParen,
+                '{' => TokenKind::LBrace,
+                '}' => TokenKind::RBrace,
+                ',' => TokenKind::Comma,
+                ';' => TokenKind::Semicolon,
+                '=' if self.eat('=') => TokenKind::EqEq,
+                '=' => TokenKind::Assign,
+                '!' if self.eat('=') => TokenKind::NotEq,
+                '!' => TokenKind::Bang,
+                '<' if self.eat('=') => TokenKind::LessEq,
+                '<' => TokenKind::Less,
+                '>' if self.eat('=') => TokenKind::GreaterEq,
+                '>' => TokenKind::Greater,
+                '&' if self.eat('&') => TokenKind::AndAnd,
+                '|' if self.eat('|') => TokenKind::OrOr,
+                _ => return Err(CompileError::new(format!("ไม่รู้จักตัวอักษร '{c}'"), start)),
+            };
+            return Ok(Some(Token {
+                kind,
+                position: start,
+            }));
+        }
+    }
+}
+
+fn keyword(word: &str) -> Option<TokenKind> {
+    Some(match word {
+        "let" => TokenKind::Let,
+        "fn" => TokenKind::Fn,
+        "if" => TokenKind::If,
+        "else" => TokenKind::Else,
+        "while" => TokenKind::While,
+        "return" => TokenKind::Return,
+        "print" => TokenKind::Print,
+        "true" => TokenKind::True,
+        "false" => TokenKind::False,
+        _ => return None,
+    })
+}
@@ -0,0 +1,362 @@
+//! 📜 Interpreter - ภาษาสคริปต์ขนาดจิ๋ว "Mini" ตั้งแต่ source จนถึง bytecode VM
+//!
+//! ต่อยอดจาก Pratt parser ของ `--calc` (บทที่ 15) ให้เป็นภาษาจริง:
+//! ```text
+//! fn fib(n) {
+//!     if n < 2 { return n; }
+//!     return fib(n - 1) + fib(n - 2);
+//! }
+//! let i = 0;
+//! while i < 10 { print fib(i); i = i + 1; }
+//! ```
+//! 1. 🔤 `lexer` - source → token พร้อมบรรทัด/คอลัมน์
+//! 2. 🌳 `parser` - token → AST (`let`, `if`/`else`, `while`, `fn`, `return`, `print`)
+//! 3. ⚙️ `compiler` - AST → `Chunk` ของ bytecode แบบ stack ตัวแปรกลายเป็นเลข slot
+//! 4. 🖥️ `vm` - รัน `Chunk` พร้อมนับ gas ตัวเดียวกับที่สัญญาอัจฉริยะในบทที่ 23 ใช้
+//!
+//! ค่ามีแค่ `int` (i64), `bool` และ `()` - ลองรันไฟล์ตัวอย่างใน `src/interpreter/scripts/` ด้วย
+//! `cargo run -- --script src/interpreter/scripts/fibonacci.mini`
+
+pub mod compiler;
+pub mod lexer;
+pub mod parser;
+pub mod vm;
+
+use std::fmt;
+use std::io::Write;
+use std::path::Path;
+
+use anyhow::{Context, Result, anyhow};
+
+pub use compiler::{Chunk, Instruction, Value, compile};
+pub use parser::{Program, parse};
+pub use vm::{Outcome, RuntimeError, RuntimeErrorKind, Vm};
+
+/// gas ของ `--script` - พอสำหรับโปรแกรมสอนทั่วไป แต่หยุด loop ไม่รู้จบภายในไม่กี่วินาที
+pub const SCRIPT_GAS_LIMIT: u64 = 50_000_000;
+
+/// ตำแหน่งใน source (เริ่มนับที่ 1 ทั้งบรรทัดและคอลัมน์)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+/// error ก่อนรัน - จาก lexer, parser หรือ compiler
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompileError {
+    pub message: String,
+    pub position: Position,
+}
+
+impl CompileError {
+    #[must_use]
+    pub const fn new(message: String, position: Position) -> Self {
+        Self { message, position }
+    }
+}
+
+impl fmt::Display for CompileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for CompileError {}
+
+/// ❌ error ของการรันสคริปต์หนึ่งไฟล์
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScriptError {
+    Compile(CompileError),
+    Runtime(RuntimeError),
+}
+
+impl ScriptError {
+    #[must_use]
+    pub const fn position(&self) -> Position {
+        match self {
+            Self::Compile(error) => error.position,
+            Self::Runtime(error) => error.position,
+        }
+    }
+
+    /// ข้อความพร้อมบรรทัดต้นฉบับและ `^` ชี้คอลัมน์
+    ///
+    /// ```text
+    /// runtime error 3:18: หารด้วยศูนย์
+    ///    3 |     return total / count;
+    ///      |                  ^
+    /// ```
+    #[must_use]
+    pub fn render(&self, source: &str) -> String {
+        let Position { line, column } = self.position();
+        let code = source
+            .lines()
+            .nth(line.saturating_sub(1))
+            .unwrap_or_default();
+        let gutter = " ".repeat(line.to_string().len());
+        format!(
+            "{self}\n {line:>3} | {code}\n {gutter:>3} | {}^",
+            " ".repeat(column.saturating_sub(1))
+        )
+    }
+}
+
+impl fmt::Display for ScriptError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let stage = match self {
+            Self::Compile(_) => "compile error",
+            Self::Runtime(_) => "runtime error",
+        };
+        write!(f, "{stage} {}: ", self.position())?;
+        match self {
+            Self::Compile(error) => write!(f, "{error}"),
+            Self::Runtime(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for ScriptError {}
+
+impl From<CompileError> for ScriptError {
+    fn from(error: CompileError) -> Self {
+        Self::Compile(error)
+    }
+}
+
+impl From<RuntimeError> for ScriptError {
+    fn from(error: RuntimeError) -> Self {
+        Self::Runtime(error)
+    }
+}
+
+/// parse + compile ในคำสั่งเดียว
+///
+/// # Errors
+///
+/// คืน `CompileError` ตัวแรกที่เจอ
+pub fn compile_source(source: &str) -> Result<Chunk, CompileError> {
+    compile(&parse(source)?)
+}
+
+/// compile แล้วรันโปรแกรมหลักด้วย `vm` - `print` เขียนลง `out`
+///
+/// # Errors
+///
+/// คืน `ScriptError::Compile` ถ้า compile ไม่ผ่าน (ยังไม่มีอะไรถูกรัน) หรือ `Runtime` ถ้ารันแล้วพัง
+pub fn run_source(source: &str, vm: &Vm, out: &mut impl Write) -> Result<Outcome, ScriptError> {
+    let chunk = compile_source(source)?;
+    Ok(vm.run(&chunk, out)?)
+}
+
+/// สำหรับ `--script <file>` - อ่านไฟล์ รันด้วย gas `SCRIPT_GAS_LIMIT`
+///
+/// # Errors
+///
+/// คืน error เมื่ออ่านไฟล์ไม่ได้ หรือสคริปต์ compile/รันไม่ผ่าน (ข้อความชี้บรรทัดแล้ว)
+pub fn run_file(path: &Path, out: &mut impl Write) -> Result<Outcome> {
+    let source =
+        std::fs::read_to_string(path).with_context(|| format!("อ่านไฟล์ {} ไม่ได้", path.display()))?;
+    run_source(&source, &Vm::new().with_gas_limit(SCRIPT_GAS_LIMIT), out)
+        .map_err(|error| anyhow!("{}: {}", path.display(), error.render(&source)))
+}
+
+/// สาธิตทุกขั้นของ interpreter ด้วยโปรแกรมสั้นๆ
+pub fn demonstrate_interpreter() {
+    println!("\n📜 === Mini Interpreter: source → AST → bytecode → VM === 📜");
+
+    let source = "let x = 2;\nwhile x < 20 {\n    x = x * x;\n}\nprint x;";
+    println!("\n📝 source:");
+    for line in source.lines() {
+        println!("   {line}");
+    }
+    match compile_source(source) {
+        Ok(chunk) => {
+            println!("\n⚙️ bytecode (address  บรรทัด  คำสั่ง):");
+            for line in chunk.disassemble().lines() {
+                println!("   {line}");
+            }
+            let mut out = Vec::new();
+            match Vm::new().run(&chunk, &mut out) {
+                Ok(outcome) => println!(
+                    "\n🖥️ output: {} (ใช้ gas {})",
+                    String::from_utf8_lossy(&out).trim(),
+                    outcome.gas_used
+                ),
+                Err(error) => println!("   ❌ {error}"),
+            }
+        }
+        Err(error) => println!("   ❌ {error}"),
+    }
+
+    println!("\n⛽ gas หยุด loop ไม่รู้จบ:");
+    let endless = "let n = 0;\nwhile true {\n    n = n + 1;\n}";
+    if let Err(error) = run_source(endless, &Vm::new().with_gas_limit(1_000), &mut Vec::new()) {
+        for line in error.render(endless).lines() {
+            println!("   {line}");
+        }
+    }
+
+    println!("\n🧮 ฟังก์ชัน recursive จากไฟล์ตัวอย่าง fibonacci.mini:");
+    let mut out = Vec::new();
+    match run_source(include_str!("scripts/fibonacci.mini"), &Vm::new(), &mut out) {
+        Ok(outcome) => {
+            let output = String::from_utf8_lossy(&out);
+            let values: Vec<&str> = output.lines().collect();
+            println!("   {} (gas {})", values.join(" "), outcome.gas_used);
+        }
+        Err(error) => println!("   ❌ {error}"),
+    }
+    println!("   💡 รันไฟล์ของตัวเอง: cargo run -- --script <file.mini>");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::snapshot_testing::SnapshotStore;
+
+    fn output(source: &str) -> String {
+        let mut out = Vec::new();
+        let result = run_source(source, &Vm::new().with_gas_limit(1_000_000), &mut out);
+        let mut text = String::from_utf8(out).unwrap();
+        if let Err(error) = result {
+            text.push_str(&error.render(source));
+            text.push('\n');
+        }
+        text
+    }
+
+    #[test]
+    fn test_scripts_golden() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/interpreter");
+        let snapshots = SnapshotStore::new(dir.join("snapshots"));
+        let mut scripts: Vec<_> = std::fs::read_dir(dir.join("scripts"))
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect();
+        scripts.sort();
+        assert!(scripts.len() >= 5, "ต้องมีไฟล์ตัวอย่างครบ");
+        for script in scripts {
+            let source = std::fs::read_to_string(&script).unwrap();
+            let name = script.file_stem().unwrap().to_string_lossy();
+            snapshots.assert_matches(&format!("{name}.out"), &output(&source));
+        }
+
+        let chunk = compile_source("fn sq(x) { return x * x; }\nprint sq(3) > 5 && true;").unwrap();
+        snapshots.assert_matches("disassembly", &chunk.disassemble());
+    }
+
+    #[test]
+    fn test_precedence_and_errors() {
+        assert_eq!(output("print 1 + 2 * 3 - 4 / 2;"), "5\n");
+        assert_eq!(output("print 10 - 3 - 2;"), "5\n");
+        assert_eq!(output("print -2 * -3 == 6 && !false;"), "true\n");
+        assert_eq!(output("print 1 < 2 == true;"), "true\n");
+
+        let error = |source: &str| match run_source(source, &Vm::new(), &mut Vec::new()) {
+            Err(error) => (error.to_string(), error.position()),
+            Ok(outcome) => panic!("{source} ควร error แต่ได้ {outcome:?}"),
+        };
+        assert_eq!(
+            error("let x = 1;\nprint x +;"),
+            (
+                "compile error 2:10: คาดว่าจะเจอ นิพจน์ แต่เจอ ';'".to_string(),
+                Position {
+                    line: 2,
+                    column: 10
+                }
+            )
+        );
+        assert_eq!(
+            error("fn f(a) { return a; }\nprint f(1, 2);").1,
+            Position { line: 2, column: 7 }
+        );
+        assert_eq!(
+            error("return 1;").0,
+            "compile error 1:1: ใช้ return ได้เฉพาะในฟังก์ชัน"
+        );
+        assert_eq!(
+            error("if 1 { print 2; }").0,
+            "runtime error 1:1: เงื่อนไข ใช้กับค่าชนิด int ไม่ได้"
+        );
+        assert_eq!(
+            error("print 1 + true;").0,
+            "runtime error 1:9: + ใช้กับค่าชนิด bool ไม่ได้"
+        );
+        assert_eq!(
+            error("print 9223372036854775807 + 1;").0,
+            "runtime error 1:27: ผลลัพธ์ล้นขนาดของ i64"
+        );
+        assert_eq!(
+            error("fn f(n) { return f(n + 1); }\nprint f(0);").0,
+            "runtime error 1:18: เรียกฟังก์ชันซ้อนเกิน 256 ชั้น"
+        );
+
+        let nested = |depth: usize| format!("print {}1{};", "(".repeat(depth), ")".repeat(depth));
+        assert_eq!(output(&nested(parser::MAX_DEPTH - 1)), "1\n");
+        assert_eq!(
+            error(&nested(parser::MAX_DEPTH)).0,
+            format!(
+                "compile error 1:{}: ซ้อนลึกเกิน {} ชั้น",
+                parser::MAX_DEPTH + 7,
+                parser::MAX_DEPTH
+            )
+        );
+        assert!(error(&nested(5_000)).0.contains("ซ้อนลึกเกิน"));
+        assert!(error(&"if true { ".repeat(5_000)).0.contains("ซ้อนลึกเกิน"));
+
+        // โซ่ operator แบน ๆ ก็ได้ AST ลึกเท่าจำนวน operator - เดิม 5000 พจน์ทำ compiler stack ล้น
+        let chain = |terms: usize| format!("let x = {};\nprint x;", vec!["1"; terms].join(" + "));
+        assert_eq!(output(&chain(parser::MAX_DEPTH)), "128\n");
+        assert_eq!(
+            error(&chain(parser::MAX_DEPTH + 1)).1,
+            Position {
+                line: 1,
+                column: 8 + 4 * parser::MAX_DEPTH - 1
+            }
+        );
+        assert!(error(&chain(5_000)).0.contains("ซ้อนลึกเกิน"));
+    }
+
+    #[test]
+    fn test_vm_call_and_gas() {
+        let chunk =
+            compile_source("fn fee(amount, bps) { return amount * bps / 10000; }\nprint 999;")
+                .unwrap();
+        let mut out = Vec::new();
+        let outcome = Vm::new()
+            .call(
+                &chunk,
+                "fee",
+                &[Value::Int(50_000), Value::Int(30)],
+                &mut out,
+            )
+            .unwrap();
+        assert_eq!(outcome.value, Value::Int(150));
+        assert!(out.is_empty(), "call ต้องไม่รันโปรแกรมหลัก");
+
+        let limited = Vm::new().with_gas_limit(outcome.gas_used - 1);
+        let error = limited
+            .call(&chunk, "fee", &[Value::Int(1), Value::Int(1)], &mut out)
+            .unwrap_err();
+        assert_eq!(
+            error.kind,
+            RuntimeErrorKind::OutOfGas {
+                limit: outcome.gas_used - 1
+            }
+        );
+        assert!(matches!(
+            Vm::new().call(&chunk, "fee", &[Value::Int(1)], &mut out),
+            Err(RuntimeError {
+                kind: RuntimeErrorKind::WrongArity { .. },
+                ..
+            })
+        ));
+    }
+}
@@ -0,0 +1,429 @@
+//! 🌳 Parser - statement แบบ recursive descent, นิพจน์แบบ Pratt (binding power เหมือน `--calc`)
+
+use super::lexer::{Token, TokenKind, tokenize};
+use super::{CompileError, Position};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    And,
+    Or,
+}
+
+impl BinaryOp {
+    /// binding power (ซ้าย, ขวา) - ทุกตัวจับกลุ่มซ้าย
+    const fn binding_power(self) -> (u8, u8) {
+        match self {
+            Self::Or => (1, 2),
+            Self::And => (3, 4),
+            Self::Eq | Self::Ne => (5, 6),
+            Self::Lt | Self::Le | Self::Gt | Self::Ge => (7, 8),
+            Self::Add | Self::Sub => (9, 10),
+            Self::Mul | Self::Div | Self::Rem => (11, 12),
+        }
+    }
+
+    const fn from_token(kind: &TokenKind) -> Option<Self> {
+        Some(match kind {
+            TokenKind::Plus => Self::Add,
+            TokenKind::Minus => Self::Sub,
+            TokenKind::Star => Self::Mul,
+            TokenKind::Slash => Self::Div,
+            TokenKind::Percent => Self::Rem,
+            TokenKind::EqEq => Self::Eq,
+            TokenKind::NotEq => Self::Ne,
+            TokenKind::Less => Self::Lt,
+            TokenKind::LessEq => Self::Le,
+            TokenKind::Greater => Self::Gt,
+            TokenKind::GreaterEq => Self::Ge,
+            TokenKind::AndAnd => Self::And,
+            TokenKind::OrOr => Self::Or,
+            _ => return None,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnaryOp {
+    Neg,
+    Not,
+}
+
+/// binding power ของ `-x` และ `!x` - สูงกว่า operator สองข้างทุกตัว
+const PREFIX_BP: u8 = 13;
+
+/// AST ซ้อนกันได้ลึกสุดเท่านี้ - นับทั้ง block, วงเล็บ และ operator ที่พับเป็นโซ่ (`1 + 1 + ...`
+/// ได้ต้นไม้ลึกเท่าจำนวน `+`) กัน parser และ compiler ที่เดินต้นไม้ต่อ stack ล้น
+pub const MAX_DEPTH: usize = 128;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expr {
+    Int(i64, Position),
+    Bool(bool, Position),
+    Var {
+        name: String,
+        position: Position,
+    },
+    Unary {
+        op: UnaryOp,
+        operand: Box<Self>,
+        position: Position,
+    },
+    Binary {
+        op: BinaryOp,
+        lhs: Box<Self>,
+        rhs: Box<Self>,
+        position: Position,
+    },
+    Call {
+        name: String,
+        args: Vec<Self>,
+        position: Position,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Stmt {
+    Let {
+        name: String,
+        value: Expr,
+        position: Position,
+    },
+    Assign {
+        name: String,
+        value: Expr,
+        position: Position,
+    },
+    If {
+        condition: Expr,
+        then_branch: Vec<Self>,
+        else_branch: Vec<Self>,
+        position: Position,
+    },
+    While {
+        condition: Expr,
+        body: Vec<Self>,
+        position: Position,
+    },
+    Return {
+        value: Option<Expr>,
+        position: Position,
+    },
+    Print {
+        value: Expr,
+        position: Position,
+    },
+    Expr(Expr),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Function {
+    pub name: String,
+    pub params: Vec<String>,
+    pub body: Vec<Stmt>,
+    pub position: Position,
+}
+
+/// ทั้งไฟล์: ประกาศ `fn` ได้ทุกที่ ส่วน statement ที่เหลือคือโปรแกรมหลัก (รันจากบนลงล่าง)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Program {
+    pub functions: Vec<Function>,
+    pub main: Vec<Stmt>,
+}
+
+/// parse source ทั้งไฟล์เป็น `Program`
+///
+/// # Errors
+///
+/// คืน `CompileError` ที่ชี้ token แรกที่ไม่เข้ากับไวยากรณ์
+pub fn parse(source: &str) -> Result<Program, CompileError> {
+    let mut parser = Parser {
+        tokens: tokenize(source)?,
+        at: 0,
+        depth: 0,
+    };
+    let mut program = Program::default();
+    while parser.peek().kind != TokenKind::Eof {
+        if parser.peek().kind == TokenKind::Fn {
+            program.functions.push(parser.function()?);
+        } else {
+            program.main.push(parser.statement()?);
+        }
+    }
+    Ok(program)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    at: usize,
+    depth: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> &Token {
+        &self.tokens[self.at]
+    }
+
+    fn next(&mut self) -> Token {
+        let token = self.tokens[self.at].clone();
+        if token.kind != TokenKind::Eof {
+            self.at += 1;
+        }
+        token
+    }
+
+    fn eat(&mut self, kind: &TokenKind) -> bool {
+        let matched = &self.peek().kind == kind;
+        if matched {
+            self.next();
+        }
+        matched
+    }
+
+    fn expect(&mut self, kind: &TokenKind) -> Result<Position, CompileError> {
+        let token = self.next();
+        if &token.kind == kind {
+            Ok(token.position)
+        } else {
+            Err(unexpected(&token, &kind.to_string()))
+        }
+    }
+
+    fn ident(&mut self) -> Result<(String, Position), CompileError> {
+        let token = self.next();
+        match token.kind {
+            TokenKind::Ident(name) => Ok((name, token.position)),
+            _ => Err(unexpected(&token, "ชื่อ")),
+        }
+    }
+
+    fn function(&mut self) -> Result<Function, CompileError> {
+        let position = self.expect(&TokenKind::Fn)?;
+        let (name, _) = self.ident()?;
+        self.expect(&TokenKind::LParen)?;
+        let mut params = Vec::new();
+        if !self.eat(&TokenKind::RParen) {
+            loop {
+                params.push(self.ident()?.0);
+                if self.eat(&TokenKind::RParen) {
+                    break;
+                }
+                self.expect(&TokenKind::Comma)?;
+            }
+        }
+        Ok(Function {
+            name,
+            params,
+            body: self.block()?,
+            position,
+        })
+    }
+
+    /// เรียก `parse` ลึกลงไปหนึ่งชั้น - เกิน `MAX_DEPTH` แล้วชี้ token ที่เริ่มชั้นนั้น
+    fn nested<T>(
+        &mut self,
+        parse: impl FnOnce(&mut Self) -> Result<T, CompileError>,
+    ) -> Result<T, CompileError> {
+        self.check_depth()?;
+        self.depth += 1;
+        let result = parse(self);
+        self.depth -= 1;
+        result
+    }
+
+    fn check_depth(&self) -> Result<(), CompileError> {
+        if self.depth == MAX_DEPTH {
+            return Err(CompileError::new(
+                format!("ซ้อนลึกเกิน {MAX_DEPTH} ชั้น"),
+                self.peek().position,
+            ));
+        }
+        Ok(())
+    }
+
+    fn block(&mut self) -> Result<Vec<Stmt>, CompileError> {
+        self.nested(Self::block_body)
+    }
+
+    fn block_body(&mut self) -> Result<Vec<Stmt>, CompileError> {
+        self.expect(&TokenKind::LBrace)?;
+        let mut statements = Vec::new();
+        while !self.eat(&TokenKind::RBrace) {
+            if self.peek().kind == TokenKind::Eof {
+                return Err(unexpected(self.peek(), "'}'"));
+            }
+            statements.push(self.statement()?);
+        }
+        Ok(statements)
+    }
+
+    fn statement(&mut self) -> Result<Stmt, CompileError> {
+        let position = self.peek().position;
+        let statement = match self.peek().kind {
+            TokenKind::Let => {
+                self.next();
+                let (name, _) = self.ident()?;
+                self.expect(&TokenKind::Assign)?;
+                Stmt::Let {
+                    name,
+                    value: self.expression(0)?,
+                    position,
+                }
+            }
+            TokenKind::If => return self.if_statement(),
+            TokenKind::While => {
+                self.next();
+                return Ok(Stmt::While {
+                    condition: self.expression(0)?,
+                    body: self.block()?,
+                    position,
+                });
+            }
+            TokenKind::Return => {
+                self.next();
+                let value = match self.peek().kind {
+                    TokenKind::Semicolon => None,
+                    _ => Some(self.expression(0)?),
+                };
+                Stmt::Return { value, position }
+            }
+            TokenKind::Print => {
+                self.next();
+                Stmt::Print {
+                    value: self.expression(0)?,
+                    position,
+                }
+            }
+            TokenKind::Ident(_) if self.tokens[self.at + 1].kind == TokenKind::Assign => {
+                let (name, _) = self.ident()?;
+                self.next();
+                Stmt::Assign {
+                    name,
+                    value: self.expression(0)?,
+                    position,
+                }
+            }
+            _ => Stmt::Expr(self.expression(0)?),
+        };
+        self.expect(&TokenKind::Semicolon)?;
+        Ok(statement)
+    }
+
+    fn if_statement(&mut self) -> Result<Stmt, CompileError> {
+        let position = self.expect(&TokenKind::If)?;
+        let condition = self.expression(0)?;
+        let then_branch = self.block()?;
+        let else_branch = if !self.eat(&TokenKind::Else) {
+            Vec::new()
+        } else if self.peek().kind == TokenKind::If {
+            // `else if` คือ else ที่มี if ซ้อนอยู่ข้างในตัวเดียว
+            vec![self.nested(Self::if_statement)?]
+        } else {
+            self.block()?
+        };
+        Ok(Stmt::If {
+            condition,
+            then_branch,
+            else_branch,
+            position,
+        })
+    }
+
+    fn expression(&mut self, min_bp: u8) -> Result<Expr, CompileError> {
+        self.nested(|parser| parser.binary(min_bp))
+    }
+
+    /// Pratt loop: prefix หนึ่งตัว แล้วกิน operator ที่ binding power ซ้าย ≥ `min_bp`
+    fn binary(&mut self, min_bp: u8) -> Result<Expr, CompileError> {
+        let entry_depth = self.depth;
+        let expr = self.fold_operators(min_bp);
+        self.depth = entry_depth;
+        expr
+    }
+
+    /// ทุกครั้งที่พับ operator ต้นไม้ฝั่งซ้ายลึกขึ้นหนึ่งชั้น จึงนับเข้า `depth` ด้วย
+    /// (`binary` คืนค่าเดิมให้หลังวนจบ)
+    fn fold_operators(&mut self, min_bp: u8) -> Result<Expr, CompileError> {
+        let mut lhs = self.prefix()?;
+        while let Some(op) = BinaryOp::from_token(&self.peek().kind) {
+            let (left_bp, right_bp) = op.binding_power();
+            if left_bp < min_bp {
+                break;
+            }
+            self.check_depth()?;
+            let position = self.next().position;
+            let rhs = self.expression(right_bp)?;
+            lhs = Expr::Binary {
+                op,
+                lhs: Box::new(lhs),
+                rhs: Box::new(rhs),
+                position,
+            };
+            self.depth += 1;
+        }
+        Ok(lhs)
+    }
+
+    fn prefix(&mut self) -> Result<Expr, CompileError> {
+        let token = self.next();
+        let position = token.position;
+        match token.kind {
+            TokenKind::Int(value) => Ok(Expr::Int(value, position)),
+            TokenKind::True => Ok(Expr::Bool(true, position)),
+            TokenKind::False => Ok(Expr::Bool(false, position)),
+            TokenKind::Minus | TokenKind::Bang => {
+                let op = if token.kind == TokenKind::Minus {
+                    UnaryOp::Neg
+                } else {
+                    UnaryOp::Not
+                };
+                Ok(Expr::Unary {
+                    op,
+                    operand: Box::new(self.expression(PREFIX_BP)?),
+                    position,
+                })
+            }
+            TokenKind::LParen => {
+                let inner = self.expression(0)?;
+                self.expect(&TokenKind::RParen)?;
+                Ok(inner)
+            }
+            TokenKind::Ident(name) if self.eat(&TokenKind::LParen) => {
+                let mut args = Vec::new();
+                if !self.eat(&TokenKind::RParen) {
+                    loop {
+                        args.push(self.expression(0)?);
+                        if self.eat(&TokenKind::RParen) {
+                            break;
+                        }
+                        self.expect(&TokenKind::Comma)?;
+                    }
+                }
+                Ok(Expr::Call {
+                    name,
+                    args,
+                    position,
+                })
+            }
+            TokenKind::Ident(name) => Ok(Expr::Var { name, position }),
+            _ => Err(unexpected(&token, "นิพจน์")),
+        }
+    }
+}
+
+fn unexpected(token: &Token, expected: &str) -> CompileError {
+    CompileError::new(
+        format!("คาดว่าจะเจอ {expected} แต่เจอ {}", token.kind),
+        token.position,
+    )
+}
@@ -0,0 +1,324 @@
+//! 🖥️ VM - เครื่องแบบ stack ที่รัน `Chunk` พร้อมนับ gas
+//!
+//! ไม่ผูกกับ source หรือ CLI: รับ `Chunk` ที่ compile แล้วกับ writer สำหรับ `print`
+//! จึงใช้ซ้ำได้ทั้ง `--script` และสัญญาอัจฉริยะในบทที่ 23 ซึ่งต้องการ 2 อย่างที่ VM นี้มีให้:
+//! - ⛽ gas: ทุกคำสั่งคิด 1 หน่วย หมดแล้วหยุดทันที - โค้ดที่วนไม่รู้จบทำให้ทั้งเครือข่ายค้างไม่ได้
+//! - 🔢 เลขคณิตแบบ checked: overflow เป็น error แทนที่จะวนกลับเงียบๆ
+
+use std::fmt;
+use std::io::Write;
+
+use super::Position;
+use super::compiler::{Chunk, Instruction, MAIN, Value};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RuntimeErrorKind {
+    TypeMismatch {
+        operation: &'static str,
+        found: &'static str,
+    },
+    DivisionByZero,
+    Overflow,
+    OutOfGas {
+        limit: u64,
+    },
+    StackOverflow {
+        depth: usize,
+    },
+    UnknownFunction(String),
+    WrongArity {
+        name: String,
+        expected: usize,
+        found: usize,
+    },
+    Output(String),
+}
+
+/// error ตอนรันพร้อมตำแหน่งของคำสั่งที่พัง
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuntimeError {
+    pub kind: RuntimeErrorKind,
+    pub position: Position,
+}
+
+impl fmt::Display for RuntimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            RuntimeErrorKind::TypeMismatch { operation, found } => {
+                write!(f, "{operation} ใช้กับค่าชนิด {found} ไม่ได้")
+            }
+            RuntimeErrorKind::DivisionByZero => f.write_str("หารด้วยศูนย์"),
+            RuntimeErrorKind::Overflow => f.write_str("ผลลัพธ์ล้นขนาดของ i64"),
+            RuntimeErrorKind::OutOfGas { limit } => write!(f, "gas หมด (จำกัด {limit} คำสั่ง)"),
+            RuntimeErrorKind::StackOverflow { depth } => {
+                write!(f, "เรียกฟังก์ชันซ้อนเกิน {depth} ชั้น")
+            }
+            RuntimeErrorKind::UnknownFunction(name) => write!(f, "ไม่รู้จักฟังก์ชัน '{name}'"),
+            RuntimeErrorKind::WrongArity {
+                name,
+                expected,
+                found,
+            } => write!(f, "{name}() รับ {expected} argument แต่ได้ {found}"),
+            RuntimeErrorKind::Output(message) => write!(f, "เขียน output ไม่ได้: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for RuntimeError {}
+
+/// ผลของการรัน: ค่าที่คืน (`()` สำหรับโปรแกรมหลัก) และ gas ที่ใช้ไป
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Outcome {
+    pub value: Value,
+    pub gas_used: u64,
+}
+
+struct Frame {
+    /// address ที่จะกลับไปหลัง `Return`
+    return_to: usize,
+    /// stack index ของ local slot 0
+    base: usize,
+}
+
+/// 🖥️ VM ที่ตั้งค่าได้ด้วย builder - ตัวมันเองไม่มี state จึงรันหลาย chunk ซ้ำได้
+#[derive(Debug, Clone, Copy)]
+pub struct Vm {
+    gas_limit: Option<u64>,
+    max_depth: usize,
+}
+
+impl Default for Vm {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Vm {
+    /// ไม่จำกัด gas, เรียกซ้อนได้ 256 ชั้น
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            gas_limit: None,
+            max_depth: 256,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_gas_limit(mut self, limit: u64) -> Self {
+        self.gas_limit = Some(limit);
+        self
+    }
+
+    #[must_use]
+    pub const fn with_max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = depth;
+        self
+    }
+
+    /// รันโปรแกรมหลักตั้งแต่ต้นจนเจอ `Halt`
+    ///
+    /// # Errors
+    ///
+    /// คืน `RuntimeError` เมื่อชนิดไม่ตรง หารศูนย์ overflow gas หมด หรือเรียกซ้อนลึกเกิน
+    pub fn run(&self, chunk: &Chunk, out: &mut impl Write) -> Result<Outcome, RuntimeError> {
+        self.execute(chunk, 0, Vec::new(), out)
+    }
+
+    /// เรียกฟังก์ชันชื่อ `name` ตรงๆ โดยไม่รันโปรแกรมหลัก - แบบที่ธุรกรรมเรียก method ของสัญญา
+    ///
+    /// # Errors
+    ///
+    /// คืน `RuntimeError` เมื่อไม่มีฟังก์ชันนี้ จำนวน argument ไม่ตรง หรือรันแล้วพัง
+    pub fn call(
+        &self,
+        chunk: &Chunk,
+        name: &str,
+        args: &[Value],
+        out: &mut impl Write,
+    ) -> Result<Outcome, RuntimeError> {
+        let position = Position { line: 1, column: 1 };
+        let index = chunk
+            .function(name)
+            .filter(|_| name != MAIN)
+            .ok_or_else(|| RuntimeError {
+                kind: RuntimeErrorKind::UnknownFunction(name.to_string()),
+                position,
+            })?;
+        let function = &chunk.functions[index];
+        if function.arity != args.len() {
+            return Err(RuntimeError {
+                kind: RuntimeErrorKind::WrongArity {
+                    name: name.to_string(),
+                    expected: function.arity,
+                    found: args.len(),
+                },
+                position,
+            });
+        }
+        self.execute(chunk, index, args.to_vec(), out)
+    }
+
+    fn execute(
+        &self,
+        chunk: &Chunk,
+        function: usize,
+        mut stack: Vec<Value>,
+        out: &mut impl Write,
+    ) -> Result<Outcome, RuntimeError> {
+        let entry = &chunk.functions[function];
+        stack.resize(entry.locals.max(stack.len()), Value::Unit);
+        let mut frames = vec![Frame {
+            return_to: usize::MAX,
+            base: 0,
+        }];
+        let mut ip = entry.entry;
+        let mut gas_used = 0_u64;
+
+        loop {
+            let instruction = chunk.code[ip];
+            let position = chunk.positions[ip];
+            let fail = |kind| RuntimeError { kind, position };
+            gas_used += 1;
+            if let Some(limit) = self.gas_limit
+                && gas_used > limit
+            {
+                return Err(fail(RuntimeErrorKind::OutOfGas { limit }));
+            }
+            ip += 1;
+
+            let base = frames.last().map_or(0, |frame| frame.base);
+            match instruction {
+                Instruction::Push(value) => stack.push(value),
+                Instruction::Load(slot) => stack.push(stack[base + slot]),
+                Instruction::Store(slot) => stack[base + slot] = pop(&mut stack),
+                Instruction::Add
+                | Instruction::Sub
+                | Instruction::Mul
+                | Instruction::Div
+                | Instruction::Rem
+                | Instruction::Eq
+                | Instruction::Ne
+                | Instruction::Lt
+                | Instruction::Le
+                | Instruction::Gt
+                | Instruction::Ge => {
+                    let (rhs, lhs) = (pop(&mut stack), pop(&mut stack));
+                    stack.push(binary(instruction, lhs, rhs).map_err(fail)?);
+                }
+                Instruction::Neg => match pop(&mut stack) {
+                    Value::Int(value) => stack.push(Value::Int(
+                        value
+                            .checked_neg()
+                            .ok_or_else(|| fail(RuntimeErrorKind::Overflow))?,
+                    )),
+                    other => return Err(fail(mismatch("-", other, other))),
+                },
+                Instruction::Not => match pop(&mut stack) {
+                    Value::Bool(value) => stack.push(Value::Bool(!value)),
+                    other => return Err(fail(mismatch("!", other, other))),
+                },
+                Instruction::Jump(target) => ip = target,
+                Instruction::JumpIfFalse(target) => match pop(&mut stack) {
+                    Value::Bool(true) => {}
+                    Value::Bool(false) => ip = target,
+                    other => return Err(fail(mismatch("เงื่อนไข", other, other))),
+                },
+                Instruction::Call(index) => {
+                    if frames.len() >= self.max_depth {
+                        return Err(fail(RuntimeErrorKind::StackOverflow {
+                            depth: self.max_depth,
+                        }));
+                    }
+                    let callee = &chunk.functions[index];
+                    let base = stack.len() - callee.arity;
+                    stack.resize(base + callee.locals, Value::Unit);
+                    frames.push(Frame {
+                        return_to: ip,
+                        base,
+                    });
+                    ip = callee.entry;
+                }
+                Instruction::Return => {
+                    let value = pop(&mut stack);
+                    let Some(frame) = frames.pop() else {
+                        unreachable!("มีเฟรมอย่างน้อยหนึ่งเฟรมเสมอระหว่างรัน");
+                    };
+                    if frames.is_empty() {
+                        return Ok(Outcome { value, gas_used });
+                    }
+                    stack.truncate(frame.base);
+                    stack.push(value);
+                    ip = frame.return_to;
+                }
+                Instruction::Print => {
+                    let value = pop(&mut stack);
+                    writeln!(out, "{value}")
+                        .map_err(|error| fail(RuntimeErrorKind::Output(error.to_string())))?;
+                }
+                Instruction::Pop => {
+                    pop(&mut stack);
+                }
+                Instruction::Halt => {
+                    return Ok(Outcome {
+                        value: Value::Unit,
+                        gas_used,
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// stack ว่างตอน pop แปลว่า compiler สร้าง bytecode ผิด ไม่ใช่ความผิดของโปรแกรมที่รัน
+fn pop(stack: &mut Vec<Value>) -> Value {
+    stack.pop().expect("compiler ต้องสร้าง bytecode ที่ stack สมดุล")
+}
+
+const fn mismatch(operation: &'static str, lhs: Value, rhs: Value) -> RuntimeErrorKind {
+    // รายงานชนิดของฝั่งที่ผิด - ถ้าฝั่งซ้ายเป็น int แปลว่าฝั่งขวาคือตัวปัญหา
+    let found = match lhs {
+        Value::Int(_) => rhs.type_name(),
+        _ => lhs.type_name(),
+    };
+    RuntimeErrorKind::TypeMismatch { operation, found }
+}
+
+/// operator สองข้างทุกตัว - `==`/`!=` ใช้ได้กับทุกชนิดแต่ทั้งสองฝั่งต้องชนิดเดียวกัน
+fn binary(instruction: Instruction, lhs: Value, rhs: Value) -> Result<Value, RuntimeErrorKind> {
+    let symbol = match instruction {
+        Instruction::Add => "+",
+        Instruction::Sub => "-",
+        Instruction::Mul => "*",
+        Instruction::Div => "/",
+        Instruction::Rem => "%",
+        Instruction::Eq => "==",
+        Instruction::Ne => "!=",
+        _ => "การเปรียบเทียบ",
+    };
+    if let Instruction::Eq | Instruction::Ne = instruction {
+        if std::mem::discriminant(&lhs) != std::mem::discriminant(&rhs) {
+            return Err(mismatch(symbol, lhs, rhs));
+        }
+        return Ok(Value::Bool(
+            (lhs == rhs) == (instruction == Instruction::Eq),
+        ));
+    }
+    let (Value::Int(lhs), Value::Int(rhs)) = (lhs, rhs) else {
+        return Err(mismatch(symbol, lhs, rhs));
+    };
+    if matches!(instruction, Instruction::Div | Instruction::Rem) && rhs == 0 {
+        return Err(RuntimeErrorKind::DivisionByZero);
+    }
+    let result = match instruction {
+        Instruction::Add => lhs.checked_add(rhs),
+        Instruction::Sub => lhs.checked_sub(rhs),
+        Instruction::Mul => lhs.checked_mul(rhs),
+        Instruction::Div => lhs.checked_div(rhs),
+        Instruction::Rem => lhs.checked_rem(rhs),
+        Instruction::Lt => return Ok(Value::Bool(lhs < rhs)),
+        Instruction::Le => return Ok(Value::Bool(lhs <= rhs)),
+        Instruction::Gt => return Ok(Value::Bool(lhs > rhs)),
+        _ => return Ok(Value::Bool(lhs >= rhs)),
+    };
+    result.map(Value::Int).ok_or(RuntimeErrorKind::Overflow)
+}
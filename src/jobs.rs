@@ -0,0 +1,691 @@
+//! 🧰 Background Jobs - คิวงานเบื้องหลังที่ตั้งเวลาได้ retry เองได้ และรอดจากการ restart
+//!
+//! request ที่ต้องทำงานช้าหรือพังได้ (ส่งอีเมล, เรียก API ภายนอก) ไม่ควรทำให้ผู้ใช้รอ:
+//! handler แค่ `enqueue` งานแล้วตอบกลับทันที ส่วน `JobRunner` ค่อยทยอยทำบน `ThreadPool`
+//!
+//! - 📦 งานคือค่าอะไรก็ได้ที่ serde แปลงเป็น JSON ได้ (`Job`) - จึงเก็บลงดิสก์ได้
+//! - ⏰ เวลาทั้งหมดถามจาก `Clock` และเก็บเป็น Unix millis - งานที่ตั้งไว้พรุ่งนี้ยังตรงเวลาหลัง restart
+//!   และ tests เดินเวลาด้วย `MockClock` ได้โดยไม่ต้อง `sleep`
+//! - 🔁 งานที่ล้ม (คืน `Err` หรือ panic) ถูกตั้งเวลาใหม่แบบ exponential backoff ตาม `RetryPolicy`
+//!   ครบจำนวนครั้งแล้วยังล้มจะย้ายไป dead letter ให้คนมาดู/สั่ง `retry_dead` เอง
+//!   (panic ถูกจับด้วย `catch_unwind` ซึ่งใช้ได้เฉพาะ build ที่ `panic = "unwind"` - release profile
+//!   ของ repo ตั้ง `panic = "abort"` งานที่ panic จึงปิดทั้งโปรเซส handler ใน production ควรคืน `Err`)
+//! - 💾 `JobQueue::open` เขียนไฟล์ใหม่แบบ atomic ทุกครั้งที่คิวเปลี่ยน งานที่กำลังทำอยู่ก็ถูกบันทึกด้วย
+//!   ถ้าโปรเซสตายกลางทางงานนั้นจะถูกทำซ้ำ (at-least-once) - handler จึงควร idempotent
+//!
+//! ```
+//! use rust_concepts::clock::MockClock;
+//! use rust_concepts::jobs::{JobQueue, JobRunner};
+//! use std::sync::Arc;
+//! use std::time::Duration;
+//!
+//! let clock = MockClock::new();
+//! let queue = Arc::new(JobQueue::<String>::new(Arc::new(clock.clone())));
+//! queue.enqueue("ส่งใบเสร็จ".to_string()).unwrap();
+//! queue.enqueue_in("เตือนต่ออายุ".to_string(), Duration::from_secs(60)).unwrap();
+//!
+//! let runner = JobRunner::new(Arc::clone(&queue), 2, |_job: &String| Ok(()));
+//! assert_eq!(runner.tick().unwrap().succeeded, 1);
+//!
+//! clock.advance(Duration::from_secs(60));
+//! assert_eq!(runner.tick().unwrap().succeeded, 1);
+//! assert!(queue.is_idle());
+//! ```
+
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::mem;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError, mpsc};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::clock::Clock;
+use crate::concurrency::thread_pool::ThreadPool;
+use crate::io_filesystem::paths::write_atomic;
+use crate::traits::trait_objects::panic_message;
+
+/// 📦 payload ของงานหนึ่งชิ้น - ต้องเป็น JSON ได้ (เก็บลงดิสก์) และส่งข้าม thread ได้
+pub trait Job: Serialize + DeserializeOwned + Clone + Send + 'static {}
+
+impl<T: Serialize + DeserializeOwned + Clone + Send + 'static> Job for T {}
+
+/// เลขประจำงาน - ไม่ซ้ำกันภายในคิวเดียว และต่อเนื่องข้าม restart
+pub type JobId = u64;
+
+/// 🔁 retry แบบ exponential backoff: รอ `base_delay`, `2×`, `4×`, ... แต่ไม่เกิน `max_delay`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// จำนวนครั้งที่ลองทำทั้งหมด (รวมครั้งแรก) ก่อนย้ายไป dead letter
+    pub max_attempts: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new(5, Duration::from_secs(1))
+    }
+}
+
+impl RetryPolicy {
+    /// policy ที่รอได้นานสุด 5 นาทีต่อครั้ง
+    #[must_use]
+    pub const fn new(max_attempts: u32, base_delay: Duration) -> Self {
+        Self {
+            max_attempts,
+            base_delay,
+            max_delay: Duration::from_secs(5 * 60),
+        }
+    }
+
+    #[must_use]
+    pub const fn with_max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// เวลาที่ต้องรอหลังล้มมาแล้ว `failed_attempts` ครั้ง
+    #[must_use]
+    pub fn backoff(&self, failed_attempts: u32) -> Duration {
+        let factor = 1_u32
+            .checked_shl(failed_attempts.saturating_sub(1))
+            .unwrap_or(u32::MAX);
+        self.base_delay
+            .checked_mul(factor)
+            .map_or(self.max_delay, |delay| delay.min(self.max_delay))
+    }
+}
+
+/// 📋 งานหนึ่งชิ้นในคิว พร้อมประวัติการลอง
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JobRecord<J> {
+    pub id: JobId,
+    pub payload: J,
+    /// จำนวนครั้งที่ลองไปแล้ว
+    pub attempts: u32,
+    /// Unix millis ที่งานพร้อมให้ทำ
+    pub run_at_millis: u64,
+    pub last_error: Option<String>,
+}
+
+impl<J> JobRecord<J> {
+    /// เวลาที่งานพร้อมให้ทำ
+    #[must_use]
+    pub fn run_at(&self) -> SystemTime {
+        UNIX_EPOCH + Duration::from_millis(self.run_at_millis)
+    }
+}
+
+/// ❌ error จากการอ่าน/เขียนไฟล์ของคิว
+#[derive(Debug)]
+pub enum JobError {
+    Io(io::Error),
+    Format(serde_json::Error),
+}
+
+impl fmt::Display for JobError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "job storage I/O error: {error}"),
+            Self::Format(error) => write!(f, "job storage is not valid JSON: {error}"),
+        }
+    }
+}
+
+impl Error for JobError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            Self::Format(error) => Some(error),
+        }
+    }
+}
+
+impl From<io::Error> for JobError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl From<serde_json::Error> for JobError {
+    fn from(error: serde_json::Error) -> Self {
+        Self::Format(error)
+    }
+}
+
+/// หน้าตาไฟล์บนดิสก์ - `R` เป็น `&JobRecord` ตอนเขียน และ `JobRecord` ตอนอ่าน
+#[derive(Serialize, Deserialize)]
+struct Snapshot<R> {
+    next_id: JobId,
+    pending: Vec<R>,
+    dead: Vec<R>,
+}
+
+#[derive(Debug)]
+struct QueueState<J> {
+    next_id: JobId,
+    /// เรียงตามเวลาที่ถึงกำหนด แล้วตาม id - งานที่ถึงเวลาอยู่ต้น map เสมอ
+    pending: BTreeMap<(u64, JobId), JobRecord<J>>,
+    in_flight: BTreeMap<JobId, JobRecord<J>>,
+    dead: Vec<JobRecord<J>>,
+}
+
+impl<J> Default for QueueState<J> {
+    fn default() -> Self {
+        Self {
+            next_id: 1,
+            pending: BTreeMap::new(),
+            in_flight: BTreeMap::new(),
+            dead: Vec::new(),
+        }
+    }
+}
+
+impl<J> QueueState<J> {
+    fn push(&mut self, record: JobRecord<J>) {
+        self.pending
+            .insert((record.run_at_millis, record.id), record);
+    }
+}
+
+/// 📬 คิวงานที่ถามเวลาจาก `Clock` - แชร์ระหว่าง handler กับ `JobRunner` ด้วย `Arc`
+#[derive(Debug)]
+pub struct JobQueue<J> {
+    clock: Arc<dyn Clock>,
+    retry: RetryPolicy,
+    storage: Option<PathBuf>,
+    state: Mutex<QueueState<J>>,
+}
+
+impl<J: Job> JobQueue<J> {
+    /// คิวในหน่วยความจำ - หายหมดเมื่อโปรเซสจบ
+    #[must_use]
+    pub fn new(clock: Arc<dyn Clock>) -> Self {
+        Self {
+            clock,
+            retry: RetryPolicy::default(),
+            storage: None,
+            state: Mutex::new(QueueState::default()),
+        }
+    }
+
+    /// เปิดคิวที่บันทึกไว้ใน `path` (ไม่มีไฟล์ = คิวว่าง) แล้วบันทึกทุกครั้งที่คิวเปลี่ยน
+    ///
+    /// งานที่ค้างอยู่ระหว่างทำตอนโปรเซสตายจะกลับมาอยู่ในคิวและถูกทำอีกครั้ง
+    ///
+    /// # Errors
+    ///
+    /// คืน `JobError` ถ้าอ่านไฟล์ไม่ได้หรือไฟล์ไม่ใช่ JSON ของคิวนี้
+    pub fn open(path: impl Into<PathBuf>, clock: Arc<dyn Clock>) -> Result<Self, JobError> {
+        let path = path.into();
+        let mut state = QueueState::default();
+        match std::fs::read(&path) {
+            Ok(bytes) => {
+                let snapshot: Snapshot<JobRecord<J>> = serde_json::from_slice(&bytes)?;
+                state.next_id = snapshot.next_id;
+                for record in snapshot.pending {
+                    state.push(record);
+                }
+                state.dead = snapshot.dead;
+            }
+            Err(error) if error.kind() == io::ErrorKind::NotFound => {}
+            Err(error) => return Err(error.into()),
+        }
+        Ok(Self {
+            clock,
+            retry: RetryPolicy::default(),
+            storage: Some(path),
+            state: Mutex::new(state),
+        })
+    }
+
+    #[must_use]
+    pub const fn with_retry_policy(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    #[must_use]
+    pub const fn retry_policy(&self) -> RetryPolicy {
+        self.retry
+    }
+
+    /// ไฟล์ที่คิวบันทึกลงไป (ถ้าเปิดด้วย `open`)
+    #[must_use]
+    pub fn storage_path(&self) -> Option<&Path> {
+        self.storage.as_deref()
+    }
+
+    /// ใส่งานที่พร้อมทำทันที
+    ///
+    /// # Errors
+    ///
+    /// คืน `JobError` ถ้าบันทึกคิวลงดิสก์ไม่สำเร็จ (งานยังอยู่ในคิวในหน่วยความจำ)
+    pub fn enqueue(&self, payload: J) -> Result<JobId, JobError> {
+        self.schedule_at(payload, self.clock.system_time())
+    }
+
+    /// ใส่งานที่จะเริ่มทำหลังจากนี้ `delay`
+    ///
+    /// # Errors
+    ///
+    /// เหมือน `enqueue`
+    pub fn enqueue_in(&self, payload: J, delay: Duration) -> Result<JobId, JobError> {
+        self.schedule_at(payload, self.clock.system_time() + delay)
+    }
+
+    /// ใส่งานที่จะเริ่มทำเมื่อถึงเวลา `at` (เวลาที่ผ่านไปแล้ว = ทำทันที)
+    ///
+    /// # Errors
+    ///
+    /// เหมือน `enqueue`
+    pub fn schedule_at(&self, payload: J, at: SystemTime) -> Result<JobId, JobError> {
+        let mut state = self.lock();
+        let id = state.next_id;
+        state.next_id += 1;
+        state.push(JobRecord {
+            id,
+            payload,
+            attempts: 0,
+            run_at_millis: unix_millis(at),
+            last_error: None,
+        });
+        self.persist(&state)?;
+        drop(state);
+        Ok(id)
+    }
+
+    /// จำนวนงานที่รอถึงเวลาหรือรอ retry (ไม่นับงานที่กำลังทำ)
+    #[must_use]
+    pub fn pending_len(&self) -> usize {
+        self.lock().pending.len()
+    }
+
+    /// ไม่มีงานค้างและไม่มีงานที่กำลังทำ (dead letter ไม่นับ)
+    #[must_use]
+    pub fn is_idle(&self) -> bool {
+        let state = self.lock();
+        state.pending.is_empty() && state.in_flight.is_empty()
+    }
+
+    /// เวลาที่งานถัดไปจะถึงกำหนด - ให้ loop ของ worker รู้ว่าควรหลับนานแค่ไหน
+    #[must_use]
+    pub fn next_run_at(&self) -> Option<SystemTime> {
+        self.lock().pending.values().next().map(JobRecord::run_at)
+    }
+
+    /// งานที่ล้มครบทุกครั้งแล้ว เรียงตามลำดับที่ตาย
+    #[must_use]
+    pub fn dead_letters(&self) -> Vec<JobRecord<J>> {
+        self.lock().dead.clone()
+    }
+
+    /// ดึงงานออกจาก dead letter กลับเข้าคิวให้ทำทันทีโดยนับครั้งใหม่ - คืน `false` ถ้าไม่มีงาน `id`
+    ///
+    /// # Errors
+    ///
+    /// คืน `JobError` ถ้าบันทึกคิวลงดิสก์ไม่สำเร็จ
+    pub fn retry_dead(&self, id: JobId) -> Result<bool, JobError> {
+        let mut state = self.lock();
+        let Some(index) = state.dead.iter().position(|record| record.id == id) else {
+            return Ok(false);
+        };
+        let mut record = state.dead.remove(index);
+        record.attempts = 0;
+        record.run_at_millis = self.clock.unix_timestamp_millis();
+        state.push(record);
+        self.persist(&state)?;
+        drop(state);
+        Ok(true)
+    }
+
+    /// ย้ายงานที่ถึงเวลาแล้วทั้งหมดไปเป็น "กำลังทำ"
+    fn take_due(&self) -> Result<Vec<JobRecord<J>>, JobError> {
+        let mut state = self.lock();
+        let cutoff = (self.clock.unix_timestamp_millis().saturating_add(1), 0);
+        let later = state.pending.split_off(&cutoff);
+        let due: Vec<_> = mem::replace(&mut state.pending, later)
+            .into_values()
+            .collect();
+        if due.is_empty() {
+            return Ok(due);
+        }
+        for record in &due {
+            state.in_flight.insert(record.id, record.clone());
+        }
+        self.persist(&state)?;
+        drop(state);
+        Ok(due)
+    }
+
+    /// บันทึกผลของงานที่ทำเสร็จในรอบนี้ (สำเร็จ/ตั้งเวลาใหม่/ย้ายไป dead letter)
+    fn finish(&self, outcomes: Vec<(JobId, Result<(), String>)>) -> Result<RunReport, JobError> {
+        let mut state = self.lock();
+        let now = self.clock.system_time();
+        let mut report = RunReport::default();
+        for (id, outcome) in outcomes {
+            let Some(mut record) = state.in_flight.remove(&id) else {
+                continue;
+            };
+            record.attempts += 1;
+            match outcome {
+                Ok(()) => report.succeeded += 1,
+                Err(error) => {
+                    record.last_error = Some(error);
+                    if record.attempts >= self.retry.max_attempts {
+                        report.dead_lettered += 1;
+                        state.dead.push(record);
+                    } else {
+                        report.retried += 1;
+                        record.run_at_millis =
+                            unix_millis(now + self.retry.backoff(record.attempts));
+                        state.push(record);
+                    }
+                }
+            }
+        }
+        self.persist(&state)?;
+        drop(state);
+        Ok(report)
+    }
+
+    fn persist(&self, state: &QueueState<J>) -> Result<(), JobError> {
+        let Some(path) = &self.storage else {
+            return Ok(());
+        };
+        let snapshot = Snapshot {
+            next_id: state.next_id,
+            pending: state
+                .in_flight
+                .values()
+                .chain(state.pending.values())
+                .collect(),
+            dead: state.dead.iter().collect(),
+        };
+        write_atomic(path, &serde_json::to_vec_pretty(&snapshot)?)?;
+        Ok(())
+    }
+
+    fn lock(&self) -> MutexGuard<'_, QueueState<J>> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+fn unix_millis(at: SystemTime) -> u64 {
+    let millis = at
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis();
+    u64::try_from(millis).unwrap_or(u64::MAX)
+}
+
+/// 📊 ผลของการ `tick` หนึ่งรอบ
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RunReport {
+    pub succeeded: usize,
+    pub retried: usize,
+    pub dead_lettered: usize,
+}
+
+impl RunReport {
+    /// จำนวนงานที่ถูกหยิบมาทำในรอบนี้
+    #[must_use]
+    pub const fn total(&self) -> usize {
+        self.succeeded + self.retried + self.dead_lettered
+    }
+}
+
+impl fmt::Display for RunReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "สำเร็จ {} | retry {} | dead letter {}",
+            self.succeeded, self.retried, self.dead_lettered
+        )
+    }
+}
+
+type Handler<J> = dyn Fn(&J) -> Result<(), String> + Send + Sync;
+
+/// 👷 ตัวทำงาน: หยิบงานที่ถึงเวลาจาก `JobQueue` ไปรันบน `ThreadPool` ของบท concurrency
+pub struct JobRunner<J> {
+    queue: Arc<JobQueue<J>>,
+    pool: ThreadPool,
+    handler: Arc<Handler<J>>,
+}
+
+impl<J> fmt::Debug for JobRunner<J> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JobRunner")
+            .field("pool", &self.pool)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<J: Job> JobRunner<J> {
+    /// `handler` คืน `Err(เหตุผล)` เมื่อควร retry - panic ก็นับเป็นการล้มเช่นกัน
+    ///
+    /// การจับ panic ต้องใช้ build ที่ unwind ได้ ถ้า compile ด้วย `panic = "abort"` โปรเซสจะตาย
+    /// แล้วงานที่ค้างอยู่ถูกทำซ้ำหลัง restart - งานที่ panic ทุกครั้งจะไม่มีวันถึง dead letter
+    ///
+    /// # Panics
+    ///
+    /// Panics ถ้า `workers` เป็น 0 (เหมือน `ThreadPool::new`)
+    pub fn new<F>(queue: Arc<JobQueue<J>>, workers: usize, handler: F) -> Self
+    where
+        F: Fn(&J) -> Result<(), String> + Send + Sync + 'static,
+    {
+        Self {
+            queue,
+            pool: ThreadPool::new(workers),
+            handler: Arc::new(handler),
+        }
+    }
+
+    #[must_use]
+    pub const fn queue(&self) -> &Arc<JobQueue<J>> {
+        &self.queue
+    }
+
+    /// ทำงานทุกชิ้นที่ถึงเวลาแล้วแบบขนาน รอจนครบแล้วบันทึกผล
+    ///
+    /// งานที่ถูก retry ในรอบนี้จะไม่ถูกทำซ้ำทันที แม้ backoff จะสั้นมาก - รอ `tick` รอบหน้า
+    ///
+    /// # Errors
+    ///
+    /// คืน `JobError` ถ้าบันทึกคิวลงดิสก์ไม่สำเร็จ
+    pub fn tick(&self) -> Result<RunReport, JobError> {
+        let due = self.queue.take_due()?;
+        let (sender, receiver) = mpsc::channel();
+        for record in &due {
+            let (id, payload) = (record.id, record.payload.clone());
+            let handler = Arc::clone(&self.handler);
+            let sender = sender.clone();
+            self.pool.execute(move || {
+                let outcome = panic::catch_unwind(AssertUnwindSafe(|| handler(&payload)))
+                    .unwrap_or_else(|payload| {
+                        Err(format!("panic: {}", panic_message(payload.as_ref())))
+                    });
+                let _ = sender.send((id, outcome));
+            });
+        }
+        drop(sender);
+        self.queue.finish(receiver.iter().collect())
+    }
+
+    /// `tick` ซ้ำจนไม่มีงานที่ถึงเวลาเหลือ - ไม่เดินนาฬิกาเอง งานที่รอ backoff จึงยังค้างอยู่
+    ///
+    /// # Errors
+    ///
+    /// เหมือน `tick`
+    pub fn drain_due(&self) -> Result<RunReport, JobError> {
+        let mut total = RunReport::default();
+        loop {
+            let report = self.tick()?;
+            if report.total() == 0 {
+                return Ok(total);
+            }
+            total.succeeded += report.succeeded;
+            total.retried += report.retried;
+            total.dead_lettered += report.dead_lettered;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::MockClock;
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn queue(clock: &MockClock) -> Arc<JobQueue<String>> {
+        Arc::new(
+            JobQueue::new(Arc::new(clock.clone()))
+                .with_retry_policy(RetryPolicy::new(3, Duration::from_secs(2))),
+        )
+    }
+
+    #[test]
+    fn test_backoff_doubles_and_caps() {
+        let policy =
+            RetryPolicy::new(10, Duration::from_secs(1)).with_max_delay(Duration::from_secs(10));
+        let delays: Vec<_> = (1..=6).map(|n| policy.backoff(n).as_secs()).collect();
+        assert_eq!(delays, [1, 2, 4, 8, 10, 10]);
+        assert_eq!(policy.backoff(200), Duration::from_secs(10));
+    }
+
+    #[test]
+    fn test_scheduled_jobs_wait_for_the_clock() {
+        let clock = MockClock::new();
+        let queue = queue(&clock);
+        let done = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&done);
+        let runner = JobRunner::new(Arc::clone(&queue), 2, move |job: &String| {
+            sink.lock().unwrap().push(job.clone());
+            Ok(())
+        });
+
+        queue
+            .enqueue_in("later".to_string(), Duration::from_secs(30))
+            .unwrap();
+        queue.enqueue("now".to_string()).unwrap();
+        assert_eq!(queue.next_run_at(), Some(clock.system_time()));
+
+        assert_eq!(runner.tick().unwrap().succeeded, 1);
+        assert_eq!(runner.tick().unwrap().total(), 0);
+        assert_eq!(
+            queue.next_run_at(),
+            Some(clock.system_time() + Duration::from_secs(30))
+        );
+
+        clock.advance(Duration::from_secs(30));
+        assert_eq!(runner.tick().unwrap().succeeded, 1);
+        assert_eq!(*done.lock().unwrap(), ["now", "later"]);
+        assert!(queue.is_idle());
+    }
+
+    #[test]
+    fn test_failures_back_off_then_dead_letter() {
+        let clock = MockClock::new();
+        let queue = queue(&clock);
+        let attempts = Arc::new(Mutex::new(HashMap::<String, u32>::new()));
+        let seen = Arc::clone(&attempts);
+        let runner = JobRunner::new(Arc::clone(&queue), 2, move |job: &String| {
+            let count = *seen
+                .lock()
+                .unwrap()
+                .entry(job.clone())
+                .and_modify(|count| *count += 1)
+                .or_insert(1);
+            match job.as_str() {
+                "flaky" if count < 2 => Err("timeout".to_string()),
+                "broken" => panic!("ระเบิด"),
+                _ => Ok(()),
+            }
+        });
+        queue.enqueue("flaky".to_string()).unwrap();
+        let broken = queue.enqueue("broken".to_string()).unwrap();
+
+        let first = runner.tick().unwrap();
+        assert_eq!((first.succeeded, first.retried), (0, 2));
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(runner.tick().unwrap().total(), 0, "ยังไม่ครบ backoff 2 วินาที");
+
+        clock.advance(Duration::from_secs(1));
+        let second = runner.tick().unwrap();
+        assert_eq!((second.succeeded, second.retried), (1, 1));
+
+        clock.advance(Duration::from_secs(4));
+        assert_eq!(runner.tick().unwrap().dead_lettered, 1);
+        assert!(queue.is_idle());
+
+        let dead = queue.dead_letters();
+        assert_eq!(dead.len(), 1);
+        assert_eq!((dead[0].id, dead[0].attempts), (broken, 3));
+        assert_eq!(dead[0].last_error.as_deref(), Some("panic: ระเบิด"));
+
+        assert!(queue.retry_dead(broken).unwrap());
+        assert!(!queue.retry_dead(broken).unwrap());
+        assert_eq!(queue.pending_len(), 1);
+        assert_eq!(attempts.lock().unwrap()["broken"], 3);
+    }
+
+    #[test]
+    fn test_pending_jobs_survive_restart() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("jobs.json");
+        let clock = MockClock::new();
+        let runs = Arc::new(AtomicU32::new(0));
+
+        {
+            let queue = Arc::new(JobQueue::open(&path, Arc::new(clock.clone())).unwrap());
+            queue.enqueue("a".to_string()).unwrap();
+            queue
+                .enqueue_in("b".to_string(), Duration::from_secs(3_600))
+                .unwrap();
+            let counter = Arc::clone(&runs);
+            let runner = JobRunner::new(queue, 1, move |_: &String| {
+                counter.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            });
+            assert_eq!(runner.tick().unwrap().succeeded, 1);
+        }
+
+        let reopened: JobQueue<String> = JobQueue::open(&path, Arc::new(clock.clone())).unwrap();
+        assert_eq!(reopened.pending_len(), 1);
+        assert_eq!(
+            reopened.next_run_at(),
+            Some(clock.system_time() + Duration::from_secs(3_600))
+        );
+        assert_eq!(reopened.enqueue("c".to_string()).unwrap(), 3);
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+
+        std::fs::write(&path, b"not json").unwrap();
+        let error = JobQueue::<String>::open(&path, Arc::new(clock)).unwrap_err();
+        assert!(matches!(error, JobError::Format(_)));
+    }
+
+    #[test]
+    fn test_in_flight_jobs_are_saved_for_at_least_once_delivery() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("jobs.json");
+        let clock = MockClock::new();
+        let queue = JobQueue::open(&path, Arc::new(clock.clone())).unwrap();
+        queue.enqueue("ค้างกลางทาง".to_string()).unwrap();
+
+        // จำลองโปรเซสตายหลังหยิบงานไปแล้วแต่ยังไม่ทันบันทึกผล
+        assert_eq!(queue.take_due().unwrap().len(), 1);
+        assert_eq!(queue.pending_len(), 0);
+        drop(queue);
+
+        let reopened: JobQueue<String> = JobQueue::open(&path, Arc::new(clock)).unwrap();
+        assert_eq!(reopened.pending_len(), 1);
+    }
+}
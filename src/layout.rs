@@ -0,0 +1,382 @@
+//! 📐 Memory Layout Inspector - ดูว่าชนิดข้อมูลหนึ่งกินหน่วยความจำเท่าไรและจัดวางอย่างไร
+//!
+//! - `inspect::<T>()` - size, align และขนาดของ `Option<T>` (บอกว่ามี niche ไหม)
+//! - `layout_of!(Type { field: FieldType, ... })` - เพิ่ม offset/size รายฟิลด์
+//!   (offset จาก `core::mem::offset_of!` จึงใช้ได้ทั้ง `repr(Rust)`, `repr(C)`, `packed` และ union)
+//! - `Display` วาดแผนที่ byte: แต่ละฟิลด์เป็นตัวอักษร, padding เป็น `░`
+//!
+//! ```
+//! use rust_concepts::layout::layout_of;
+//!
+//! #[repr(C)]
+//! struct Header {
+//!     tag: u8,
+//!     len: u32,
+//! }
+//!
+//! let layout = layout_of!(Header { tag: u8, len: u32 });
+//! assert_eq!((layout.size, layout.align), (8, 4));
+//! assert_eq!(layout.padding_bytes(), Some(3));
+//! assert_eq!(layout.byte_map(), "A░░░|BBBB");
+//! ```
+//!
+//! 🔍 niche คือค่าที่ชนิดนั้น "ไม่มีวันเป็น" เช่น reference ไม่มีวันเป็น null -
+//! compiler ยืมค่านั้นไปแทน `None` ได้ `Option<&T>` จึงเล็กเท่า `&T`
+
+use std::fmt::{self, Write as _};
+use std::mem;
+use std::ops::Range;
+
+/// สร้าง `TypeLayout` พร้อมข้อมูลรายฟิลด์: `layout_of!(Type { field: FieldType, ... })`
+///
+/// ระบุชนิดของฟิลด์ด้วย - macro ตรวจให้ว่าตรงกับ struct จริง (ชนิดผิดจะ compile ไม่ผ่าน)
+/// การตรวจใช้ `&raw const` ซึ่งไม่อ่านค่า จึงปลอดภัยกับฟิลด์ของ union และ `packed` struct
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __layout_of {
+    ($ty:ty { $($field:ident : $field_ty:ty),* $(,)? }) => {{
+        // ไม่เคยถูกเรียก - มีไว้ให้ type checker ยืนยันชนิดของแต่ละฟิลด์
+        #[allow(dead_code, unused_variables)] // reason: ใช้ตรวจชนิดตอน compile เท่านั้น
+        const fn check_field_types(value: &$ty) {
+            $(let _: *const $field_ty = &raw const value.$field;)*
+        }
+        $crate::layout::inspect::<$ty>()
+            $(.with_field(
+                stringify!($field),
+                stringify!($field_ty),
+                ::core::mem::offset_of!($ty, $field),
+                ::core::mem::size_of::<$field_ty>(),
+            ))*
+    }};
+}
+
+pub use crate::__layout_of as layout_of;
+
+/// ฟิลด์หนึ่งตัวใน layout
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldLayout {
+    pub name: &'static str,
+    pub type_name: &'static str,
+    pub offset: usize,
+    pub size: usize,
+}
+
+impl FieldLayout {
+    /// ช่วง byte ที่ฟิลด์นี้ครอบครอง
+    #[must_use]
+    pub const fn bytes(&self) -> Range<usize> {
+        self.offset..self.offset + self.size
+    }
+}
+
+/// 📐 ผลการตรวจ layout ของชนิดหนึ่ง
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeLayout {
+    /// ชื่อชนิดแบบสั้น (ตัด path ของ module ออก)
+    pub name: String,
+    pub size: usize,
+    pub align: usize,
+    /// ขนาดของ `Option<T>`
+    pub option_size: usize,
+    /// ว่างถ้าสร้างจาก `inspect` เฉยๆ - ใช้ `layout_of!` เพื่อให้มีข้อมูลรายฟิลด์
+    pub fields: Vec<FieldLayout>,
+}
+
+/// size, align และขนาดของ `Option<T>` (ยังไม่มีข้อมูลรายฟิลด์)
+#[must_use]
+pub fn inspect<T>() -> TypeLayout {
+    TypeLayout {
+        name: short_type_name(std::any::type_name::<T>()),
+        size: mem::size_of::<T>(),
+        align: mem::align_of::<T>(),
+        option_size: mem::size_of::<Option<T>>(),
+        fields: Vec::new(),
+    }
+}
+
+impl TypeLayout {
+    /// เพิ่มฟิลด์ - ปกติเรียกผ่าน `layout_of!`
+    #[must_use]
+    pub fn with_field(
+        mut self,
+        name: &'static str,
+        type_name: &'static str,
+        offset: usize,
+        size: usize,
+    ) -> Self {
+        self.fields.push(FieldLayout {
+            name,
+            type_name,
+            offset,
+            size,
+        });
+        self
+    }
+
+    /// `Option<T>` ไม่ใหญ่กว่า `T` - compiler ใช้ niche ของ `T` แทน tag ของ `None`
+    #[must_use]
+    pub const fn has_niche(&self) -> bool {
+        self.option_size == self.size
+    }
+
+    /// ช่วง byte ที่ไม่มีฟิลด์ไหนใช้ เรียงตาม offset - `None` ถ้าไม่รู้ฟิลด์
+    #[must_use]
+    pub fn padding_ranges(&self) -> Option<Vec<Range<usize>>> {
+        if self.fields.is_empty() {
+            return None;
+        }
+        let owners = self.byte_owners();
+        let mut ranges: Vec<Range<usize>> = Vec::new();
+        for (byte, owner) in owners.iter().enumerate() {
+            if owner.is_some() {
+                continue;
+            }
+            match ranges.last_mut() {
+                Some(range) if range.end == byte => range.end += 1,
+                _ => ranges.push(byte..byte + 1),
+            }
+        }
+        Some(ranges)
+    }
+
+    /// จำนวน byte ที่เป็น padding - `None` ถ้าไม่รู้ฟิลด์
+    #[must_use]
+    pub fn padding_bytes(&self) -> Option<usize> {
+        self.padding_ranges()
+            .map(|ranges| ranges.iter().map(ExactSizeIterator::len).sum())
+    }
+
+    /// แผนที่ byte เช่น `A░░░|BBBB` - ฟิลด์ตัวที่ n เป็นตัวอักษรที่ n, padding เป็น `░`,
+    /// byte ที่หลายฟิลด์ใช้ร่วมกัน (union) เป็น `*`, คั่นทุก `align` byte (ไม่เกิน 8) ด้วย `|`
+    /// (`align` 1 เช่น `packed` ไม่คั่น)
+    #[must_use]
+    pub fn byte_map(&self) -> String {
+        let group = self.align.min(8);
+        let mut map = String::new();
+        for (byte, owner) in self.byte_owners().iter().enumerate() {
+            if group > 1 && byte > 0 && byte % group == 0 {
+                map.push('|');
+            }
+            map.push(match owner {
+                None => '░',
+                Some(Owner::Shared) => '*',
+                Some(Owner::Field(index)) => field_letter(*index),
+            });
+        }
+        map
+    }
+
+    /// ใครเป็นเจ้าของแต่ละ byte
+    fn byte_owners(&self) -> Vec<Option<Owner>> {
+        let mut owners = vec![None; self.size];
+        for (index, field) in self.fields.iter().enumerate() {
+            for byte in field.bytes().filter(|byte| *byte < self.size) {
+                owners[byte] = Some(match owners[byte] {
+                    None => Owner::Field(index),
+                    Some(_) => Owner::Shared,
+                });
+            }
+        }
+        owners
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Owner {
+    Field(usize),
+    Shared,
+}
+
+/// A, B, C, ... (เกิน 26 ฟิลด์ใช้ `#`)
+fn field_letter(index: usize) -> char {
+    u8::try_from(index)
+        .ok()
+        .filter(|index| *index < 26)
+        .map_or('#', |index| char::from(b'A' + index))
+}
+
+impl fmt::Display for TypeLayout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "📐 {}: size {}, align {}",
+            self.name, self.size, self.align
+        )?;
+        if let Some(padding) = self.padding_bytes() {
+            write!(f, ", padding {padding}")?;
+        }
+        writeln!(f)?;
+
+        if !self.fields.is_empty() {
+            let name_width = self
+                .fields
+                .iter()
+                .map(|field| field.name.len())
+                .max()
+                .unwrap_or(0);
+            let type_width = self
+                .fields
+                .iter()
+                .map(|field| field.type_name.len())
+                .max()
+                .unwrap_or(0);
+            for (index, field) in self.fields.iter().enumerate() {
+                writeln!(
+                    f,
+                    "   {} {:<name_width$}  {:<type_width$}  offset {:>2}, size {}",
+                    field_letter(index),
+                    field.name,
+                    field.type_name,
+                    field.offset,
+                    field.size
+                )?;
+            }
+            writeln!(f, "   [{}]", self.byte_map())?;
+        }
+
+        let niche = if self.has_niche() {
+            "ใช้ niche ไม่ต้องมี tag"
+        } else {
+            "ต้องเพิ่ม tag"
+        };
+        writeln!(
+            f,
+            "   Option<{}>: {} bytes ({niche})",
+            self.name, self.option_size
+        )
+    }
+}
+
+/// ตารางเทียบขนาดของ `T` กับ `Option<T>` ของหลายชนิดพร้อมกัน
+#[must_use]
+pub fn niche_table(layouts: &[TypeLayout]) -> String {
+    let width = layouts
+        .iter()
+        .map(|layout| layout.name.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max(4);
+    let mut out = format!("   {:<width$}  size  Option  niche\n", "type");
+    for layout in layouts {
+        let _ = writeln!(
+            out,
+            "   {:<width$}  {:>4}  {:>6}  {}",
+            layout.name,
+            layout.size,
+            layout.option_size,
+            if layout.has_niche() { "✅" } else { "❌" }
+        );
+    }
+    out
+}
+
+/// `core::option::Option<&alloc::string::String>` → `Option<&String>`
+#[must_use]
+pub fn short_type_name(full: &str) -> String {
+    let mut out = String::with_capacity(full.len());
+    let mut segment_start = 0;
+    let mut chars = full.chars().peekable();
+    while let Some(ch) = chars.next() {
+        if ch == ':' && chars.peek() == Some(&':') {
+            chars.next();
+            out.truncate(segment_start);
+        } else {
+            out.push(ch);
+            if !(ch.is_alphanumeric() || ch == '_') {
+                segment_start = out.len();
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::num::NonZeroU32;
+    use std::ptr::NonNull;
+
+    #[repr(C)]
+    struct Padded {
+        flag: bool,
+        value: u64,
+        count: u32,
+    }
+
+    #[repr(C)]
+    union Bits {
+        int: u32,
+        bytes: [u8; 2],
+    }
+
+    #[test]
+    fn test_field_offsets_and_padding() {
+        let layout = layout_of!(Padded {
+            flag: bool,
+            value: u64,
+            count: u32
+        });
+        assert_eq!(layout.name, "Padded");
+        assert_eq!((layout.size, layout.align), (24, 8));
+        let offsets: Vec<usize> = layout.fields.iter().map(|field| field.offset).collect();
+        assert_eq!(offsets, [0, 8, 16]);
+        assert_eq!(layout.padding_ranges(), Some(vec![1..8, 20..24]));
+        assert_eq!(layout.padding_bytes(), Some(11));
+        assert_eq!(layout.byte_map(), "A░░░░░░░|BBBBBBBB|CCCC░░░░");
+        // bool มีค่าได้แค่ 0/1 - Option ใช้ค่า 2 ใน byte ของ flag แทน None ได้
+        assert!(layout.has_niche());
+
+        let union = layout_of!(Bits {
+            int: u32,
+            bytes: [u8; 2]
+        });
+        assert_eq!(union.byte_map(), "**AA");
+        assert_eq!(union.padding_bytes(), Some(0));
+        assert!(!union.has_niche());
+        assert_eq!(inspect::<u8>().padding_bytes(), None);
+    }
+
+    #[test]
+    fn test_niches() {
+        assert!(inspect::<&Padded>().has_niche());
+        assert!(inspect::<Box<u8>>().has_niche());
+        assert!(inspect::<NonZeroU32>().has_niche());
+        assert!(inspect::<NonNull<u8>>().has_niche());
+        assert!(inspect::<bool>().has_niche());
+        assert!(!inspect::<u32>().has_niche());
+        assert!(!inspect::<*const u8>().has_niche());
+
+        let table = niche_table(&[inspect::<&u8>(), inspect::<u64>()]);
+        assert_eq!(
+            table,
+            "   type  size  Option  niche\n   &u8      8       8  ✅\n   u64      8      16  ❌\n"
+        );
+    }
+
+    #[test]
+    fn test_display_and_names() {
+        assert_eq!(
+            short_type_name(std::any::type_name::<Option<&String>>()),
+            "Option<&String>"
+        );
+        assert_eq!(
+            short_type_name("alloc::vec::Vec<(u8, core::num::NonZeroU8)>"),
+            "Vec<(u8, NonZeroU8)>"
+        );
+        let text = layout_of!(Padded {
+            flag: bool,
+            value: u64,
+            count: u32
+        })
+        .to_string();
+        assert_eq!(
+            text,
+            "📐 Padded: size 24, align 8, padding 11\n\
+             \x20  A flag   bool  offset  0, size 1\n\
+             \x20  B value  u64   offset  8, size 8\n\
+             \x20  C count  u32   offset 16, size 4\n\
+             \x20  [A░░░░░░░|BBBBBBBB|CCCC░░░░]\n\
+             \x20  Option<Padded>: 24 bytes (ใช้ niche ไม่ต้องมี tag)\n"
+        );
+    }
+}
@@ -0,0 +1,205 @@
+//! 🌈 Syntax highlight แบบง่ายสำหรับ code block ภาษา Rust
+//!
+//! ตัดทีละบรรทัดเป็น token ด้วยการดูตัวอักษรแรก (เหมือน lexer ของ compiler แต่เล็กกว่ามาก)
+//! ไม่รู้จัก block comment `/* */` หลายบรรทัดและ raw string - พอสำหรับตัวอย่างในโน้ต
+
+/// ชนิดของ token ที่ใช้เลือกสี
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    Keyword,
+    /// ชื่อที่ขึ้นต้นด้วยตัวใหญ่ หรือ primitive อย่าง `i32`
+    Type,
+    String,
+    Number,
+    Comment,
+    /// `println!`, `vec!`
+    Macro,
+    Lifetime,
+    Attribute,
+    Plain,
+}
+
+impl TokenKind {
+    /// รหัสสี ANSI - `Plain` ไม่ใส่สี
+    #[must_use]
+    pub const fn ansi(self) -> Option<&'static str> {
+        match self {
+            Self::Keyword => Some("35"),
+            Self::Type => Some("36"),
+            Self::String => Some("32"),
+            Self::Number | Self::Lifetime => Some("33"),
+            Self::Comment | Self::Attribute => Some("90"),
+            Self::Macro => Some("34"),
+            Self::Plain => None,
+        }
+    }
+}
+
+const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+    "ref", "return", "self", "Self", "static", "struct", "super", "trait", "true", "type",
+    "unsafe", "use", "where", "while",
+];
+
+const PRIMITIVES: &[&str] = &[
+    "bool", "char", "str", "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64",
+    "u128", "usize", "f32", "f64",
+];
+
+/// ภาษาที่ highlight ได้
+#[must_use]
+pub fn supports(lang: &str) -> bool {
+    matches!(lang, "rust" | "rs")
+}
+
+/// ตัดบรรทัดเป็น token - ต่อ token ทั้งหมดกลับจะได้บรรทัดเดิมพอดี
+#[must_use]
+pub fn tokenize(line: &str) -> Vec<(TokenKind, &str)> {
+    let mut tokens = Vec::new();
+    let mut offset = 0;
+    // Plain ที่ติดกันรวมเป็น token เดียว
+    let mut plain_start: Option<usize> = None;
+    while let Some(first) = line[offset..].chars().next() {
+        let (kind, len) = next_token(&line[offset..], first);
+        if kind == TokenKind::Plain {
+            plain_start.get_or_insert(offset);
+        } else {
+            if let Some(start) = plain_start.take() {
+                tokens.push((TokenKind::Plain, &line[start..offset]));
+            }
+            tokens.push((kind, &line[offset..offset + len]));
+        }
+        offset += len;
+    }
+    if let Some(start) = plain_start {
+        tokens.push((TokenKind::Plain, &line[start..]));
+    }
+    tokens
+}
+
+/// ชนิดและความยาว (byte) ของ token ที่เริ่มต้น `text`
+fn next_token(text: &str, first: char) -> (TokenKind, usize) {
+    if text.starts_with("//") {
+        return (TokenKind::Comment, text.len());
+    }
+    if text.starts_with("#[") || text.starts_with("#![") {
+        let end = text.find(']').map_or(text.len(), |end| end + 1);
+        return (TokenKind::Attribute, end);
+    }
+    match first {
+        '"' => (TokenKind::String, string_len(text)),
+        '\'' => quote_token(text),
+        '0'..='9' => (TokenKind::Number, number_len(text)),
+        ch if ch.is_alphabetic() || ch == '_' => {
+            let len = ident_len(text);
+            let word = &text[..len];
+            if text[len..].starts_with('!') && !text[len..].starts_with("!=") {
+                return (TokenKind::Macro, len + 1);
+            }
+            let kind = if KEYWORDS.contains(&word) {
+                TokenKind::Keyword
+            } else if PRIMITIVES.contains(&word) || word.starts_with(char::is_uppercase) {
+                TokenKind::Type
+            } else {
+                TokenKind::Plain
+            };
+            (kind, len)
+        }
+        ch => (TokenKind::Plain, ch.len_utf8()),
+    }
+}
+
+fn ident_len(text: &str) -> usize {
+    text.find(|ch: char| !(ch.is_alphanumeric() || ch == '_'))
+        .unwrap_or(text.len())
+}
+
+/// `"..."` รวม escape `\"` - ไม่มีตัวปิดก็กินจนจบบรรทัด
+fn string_len(text: &str) -> usize {
+    let mut escaped = false;
+    for (index, ch) in text.char_indices().skip(1) {
+        match ch {
+            '\\' if !escaped => escaped = true,
+            '"' if !escaped => return index + 1,
+            _ => escaped = false,
+        }
+    }
+    text.len()
+}
+
+/// `'a'`, `'\n'` เป็น char literal ส่วน `'a` (ไม่มีตัวปิด) เป็น lifetime
+fn quote_token(text: &str) -> (TokenKind, usize) {
+    let body = &text[1..];
+    let mut chars = body.chars();
+    match (chars.next(), chars.next(), chars.next()) {
+        (Some('\\'), Some(escaped), Some('\'')) => (TokenKind::String, 3 + escaped.len_utf8()),
+        (Some(ch), Some('\''), _) => (TokenKind::String, 2 + ch.len_utf8()),
+        (Some(ch), _, _) if ch.is_alphabetic() || ch == '_' => {
+            (TokenKind::Lifetime, 1 + ident_len(body))
+        }
+        _ => (TokenKind::Plain, 1),
+    }
+}
+
+/// `42`, `1_000`, `3.14`, `255u8` - แต่ `1..5` ต้องได้แค่ `1`
+fn number_len(text: &str) -> usize {
+    let bytes = text.as_bytes();
+    let mut len = 0;
+    while len < bytes.len() {
+        let byte = bytes[len];
+        let fraction = byte == b'.' && bytes.get(len + 1).is_some_and(u8::is_ascii_digit);
+        if !(byte.is_ascii_alphanumeric() || byte == b'_' || fraction) {
+            break;
+        }
+        len += 1;
+    }
+    len
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use TokenKind::{Comment, Keyword, Lifetime, Macro, Number, Plain, String, Type};
+
+    #[test]
+    fn test_tokenize_rust_line() {
+        assert_eq!(
+            tokenize(r#"let mut s: String = format!("{}\"", 'x'); // จบ"#),
+            [
+                (Keyword, "let"),
+                (Plain, " "),
+                (Keyword, "mut"),
+                (Plain, " s: "),
+                (Type, "String"),
+                (Plain, " = "),
+                (Macro, "format!"),
+                (Plain, "("),
+                (String, r#""{}\"""#),
+                (Plain, ", "),
+                (String, "'x'"),
+                (Plain, "); "),
+                (Comment, "// จบ"),
+            ]
+        );
+        assert_eq!(
+            tokenize("fn f<'a>(n: u8) { 1..=3_0 }"),
+            [
+                (Keyword, "fn"),
+                (Plain, " f<"),
+                (Lifetime, "'a"),
+                (Plain, ">(n: "),
+                (Type, "u8"),
+                (Plain, ") { "),
+                (Number, "1"),
+                (Plain, "..="),
+                (Number, "3_0"),
+                (Plain, " }"),
+            ]
+        );
+        let line = "if a != b { x.len() }";
+        let joined: std::string::String = tokenize(line).iter().map(|(_, text)| *text).collect();
+        assert_eq!(joined, line);
+        assert!(supports("rust") && !supports("toml"));
+    }
+}
@@ -0,0 +1,387 @@
+//! 🔍 Markdown parser ขนาดเล็ก - เป็นบทเรียนเรื่องการ parse ไปในตัว
+//!
+//! Markdown อ่านเป็นสองชั้น:
+//! 1. 🧱 **block** - ดูทีละบรรทัดว่าเป็นหัวข้อ, code block, ตาราง, list, quote หรือย่อหน้า
+//!    (state เดียวที่ต้องจำคือ "อยู่ใน code block หรือเปล่า")
+//! 2. ✨ **inline** - ภายในข้อความแต่ละก้อน หา `` `code` ``, `**bold**`, `*italic*`, `[link](url)`
+//!    ด้วย recursive descent: เจอตัวเปิดก็หาตัวปิด แล้ว parse เนื้อในซ้ำด้วยฟังก์ชันเดิม
+//!
+//! รองรับเฉพาะ subset ที่โน้ตของบทเรียนใช้ - ไม่ใช่ `CommonMark` เต็มรูปแบบ
+//! ตัวเปิดที่ไม่มีตัวปิดจะกลายเป็นข้อความธรรมดา (ไม่มี error) เหมือน renderer ทั่วไป
+
+/// ✨ ข้อความในบรรทัด
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Inline {
+    Text(String),
+    Bold(Vec<Self>),
+    Italic(Vec<Self>),
+    Code(String),
+    Link { text: Vec<Self>, url: String },
+}
+
+/// ↔️ การจัดแนวคอลัมน์ของตาราง (`:---`, `:---:`, `---:`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Align {
+    #[default]
+    Left,
+    Center,
+    Right,
+}
+
+/// 🧱 block หนึ่งก้อน
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Block {
+    Heading {
+        level: u8,
+        text: Vec<Inline>,
+    },
+    Paragraph(Vec<Inline>),
+    Code {
+        lang: Option<String>,
+        code: String,
+    },
+    List {
+        ordered: bool,
+        items: Vec<Vec<Inline>>,
+    },
+    Quote(Vec<Inline>),
+    Table {
+        header: Vec<Vec<Inline>>,
+        align: Vec<Align>,
+        rows: Vec<Vec<Vec<Inline>>>,
+    },
+    Rule,
+}
+
+/// แยกเอกสารเป็น block
+#[must_use]
+pub fn parse(source: &str) -> Vec<Block> {
+    let lines: Vec<&str> = source.lines().collect();
+    let mut blocks = Vec::new();
+    let mut paragraph: Vec<&str> = Vec::new();
+    let mut i = 0;
+
+    let flush = |paragraph: &mut Vec<&str>, blocks: &mut Vec<Block>| {
+        if !paragraph.is_empty() {
+            blocks.push(Block::Paragraph(parse_inline(&paragraph.join(" "))));
+            paragraph.clear();
+        }
+    };
+
+    while i < lines.len() {
+        let line = lines[i];
+        let trimmed = line.trim();
+
+        if let Some(lang) = trimmed.strip_prefix("```") {
+            flush(&mut paragraph, &mut blocks);
+            let mut code = Vec::new();
+            i += 1;
+            while i < lines.len() && !lines[i].trim_start().starts_with("```") {
+                code.push(lines[i]);
+                i += 1;
+            }
+            let lang = lang.trim();
+            blocks.push(Block::Code {
+                lang: (!lang.is_empty()).then(|| lang.to_string()),
+                code: code.join("\n"),
+            });
+        } else if trimmed.is_empty() {
+            flush(&mut paragraph, &mut blocks);
+        } else if let Some((level, text)) = heading(trimmed) {
+            flush(&mut paragraph, &mut blocks);
+            blocks.push(Block::Heading {
+                level,
+                text: parse_inline(text),
+            });
+        } else if is_rule(trimmed) {
+            flush(&mut paragraph, &mut blocks);
+            blocks.push(Block::Rule);
+        } else if trimmed.starts_with('|')
+            && lines
+                .get(i + 1)
+                .and_then(|next| table_align(next))
+                .is_some()
+        {
+            flush(&mut paragraph, &mut blocks);
+            let header = split_row(trimmed);
+            let align = table_align(lines[i + 1]).unwrap_or_default();
+            i += 2;
+            let mut rows = Vec::new();
+            while i < lines.len() && lines[i].trim().starts_with('|') {
+                rows.push(split_row(lines[i].trim()));
+                i += 1;
+            }
+            blocks.push(Block::Table {
+                header,
+                align,
+                rows,
+            });
+            continue;
+        } else if trimmed.starts_with('>') {
+            flush(&mut paragraph, &mut blocks);
+            let mut quote = Vec::new();
+            while i < lines.len() {
+                let Some(rest) = lines[i].trim().strip_prefix('>') else {
+                    break;
+                };
+                quote.push(rest.trim());
+                i += 1;
+            }
+            blocks.push(Block::Quote(parse_inline(&quote.join(" "))));
+            continue;
+        } else if let Some((ordered, _)) = list_item(trimmed) {
+            flush(&mut paragraph, &mut blocks);
+            let mut items: Vec<String> = Vec::new();
+            while i < lines.len() {
+                let current = lines[i].trim();
+                match list_item(current) {
+                    Some((same, text)) if same == ordered => items.push(text.to_string()),
+                    // บรรทัดที่ย่อหน้าต่อจาก item ก่อนหน้า
+                    None if !current.is_empty() && lines[i].starts_with(' ') => {
+                        if let Some(last) = items.last_mut() {
+                            last.push(' ');
+                            last.push_str(current);
+                        }
+                    }
+                    _ => break,
+                }
+                i += 1;
+            }
+            blocks.push(Block::List {
+                ordered,
+                items: items.iter().map(|item| parse_inline(item)).collect(),
+            });
+            continue;
+        } else {
+            paragraph.push(trimmed);
+        }
+        i += 1;
+    }
+    flush(&mut paragraph, &mut blocks);
+    blocks
+}
+
+/// `## หัวข้อ` → `(2, "หัวข้อ")`
+fn heading(line: &str) -> Option<(u8, &str)> {
+    let level = line.chars().take_while(|&ch| ch == '#').count();
+    let text = line[level..].strip_prefix(' ')?;
+    u8::try_from(level)
+        .ok()
+        .filter(|level| (1..=6).contains(level))
+        .map(|level| (level, text.trim()))
+}
+
+fn is_rule(line: &str) -> bool {
+    line.len() >= 3
+        && ["-", "*", "_"]
+            .iter()
+            .any(|mark| line.replace(mark, "").is_empty())
+}
+
+/// `- item` / `* item` → `(false, "item")`, `2. item` → `(true, "item")`
+fn list_item(line: &str) -> Option<(bool, &str)> {
+    if let Some(text) = line.strip_prefix("- ").or_else(|| line.strip_prefix("* ")) {
+        return Some((false, text));
+    }
+    let digits = line.chars().take_while(char::is_ascii_digit).count();
+    let text = line[digits..].strip_prefix(". ")?;
+    (digits > 0).then_some((true, text))
+}
+
+/// แถวคั่นหัวตาราง `|---|:---:|` → การจัดแนวของแต่ละคอลัมน์
+fn table_align(line: &str) -> Option<Vec<Align>> {
+    let line = line.trim();
+    if !line.starts_with('|') {
+        return None;
+    }
+    cells(line)
+        .map(|cell| {
+            let cell = cell.trim();
+            let dashes = cell.trim_matches(':');
+            if dashes.is_empty() || !dashes.chars().all(|ch| ch == '-') {
+                return None;
+            }
+            Some(match (cell.starts_with(':'), cell.ends_with(':')) {
+                (true, true) => Align::Center,
+                (false, true) => Align::Right,
+                _ => Align::Left,
+            })
+        })
+        .collect()
+}
+
+fn cells(line: &str) -> impl Iterator<Item = &str> {
+    let inner = line.strip_prefix('|').unwrap_or(line);
+    let inner = inner.strip_suffix('|').unwrap_or(inner);
+    inner.split('|')
+}
+
+fn split_row(line: &str) -> Vec<Vec<Inline>> {
+    cells(line).map(|cell| parse_inline(cell.trim())).collect()
+}
+
+/// parse ข้อความในบรรทัด - `\` หน้าสัญลักษณ์ทำให้เป็นตัวอักษรธรรมดา
+#[must_use]
+pub fn parse_inline(text: &str) -> Vec<Inline> {
+    let mut spans = Vec::new();
+    let mut plain = String::new();
+    let mut rest = text;
+
+    while let Some(ch) = rest.chars().next() {
+        if ch == '\\'
+            && let Some(escaped) = rest[1..].chars().next()
+            && escaped.is_ascii_punctuation()
+        {
+            plain.push(escaped);
+            rest = &rest[1 + escaped.len_utf8()..];
+            continue;
+        }
+        if let Some((span, after)) = span(rest) {
+            if !plain.is_empty() {
+                spans.push(Inline::Text(std::mem::take(&mut plain)));
+            }
+            spans.push(span);
+            rest = after;
+            continue;
+        }
+        plain.push(ch);
+        rest = &rest[ch.len_utf8()..];
+    }
+    if !plain.is_empty() {
+        spans.push(Inline::Text(plain));
+    }
+    spans
+}
+
+/// ลอง parse span ที่เริ่มตรงนี้ - คืน span กับข้อความที่เหลือ หรือ `None` ถ้าไม่มีตัวปิด
+fn span(text: &str) -> Option<(Inline, &str)> {
+    if let Some(body) = text.strip_prefix('`') {
+        let end = body.find('`')?;
+        return Some((Inline::Code(body[..end].to_string()), &body[end + 1..]));
+    }
+    if let Some(body) = text.strip_prefix("**") {
+        let end = body.find("**").filter(|&end| end > 0)?;
+        return Some((Inline::Bold(parse_inline(&body[..end])), &body[end + 2..]));
+    }
+    if let Some(body) = text.strip_prefix('*') {
+        // `* ` ไม่ใช่ตัวเปิด (เช่น `2 * 3`)
+        if body.starts_with(' ') {
+            return None;
+        }
+        let end = body.find('*').filter(|&end| end > 0)?;
+        return Some((Inline::Italic(parse_inline(&body[..end])), &body[end + 1..]));
+    }
+    if let Some(body) = text.strip_prefix('[') {
+        let close = body.find("](")?;
+        let target = &body[close + 2..];
+        let end = target.find(')')?;
+        return Some((
+            Inline::Link {
+                text: parse_inline(&body[..close]),
+                url: target[..end].to_string(),
+            },
+            &target[end + 1..],
+        ));
+    }
+    None
+}
+
+/// ข้อความล้วนของ inline (ไม่มีสัญลักษณ์ markdown)
+#[must_use]
+pub fn plain_text(spans: &[Inline]) -> String {
+    let mut text = String::new();
+    for span in spans {
+        match span {
+            Inline::Text(value) | Inline::Code(value) => text.push_str(value),
+            Inline::Bold(inner) | Inline::Italic(inner) | Inline::Link { text: inner, .. } => {
+                text.push_str(&plain_text(inner));
+            }
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(value: &str) -> Inline {
+        Inline::Text(value.to_string())
+    }
+
+    #[test]
+    fn test_inline_spans() {
+        assert_eq!(
+            parse_inline("ใช้ **`mut` เมื่อ *ตั้งใจ* แก้** ดู [doc](https://doc.rust-lang.org)"),
+            [
+                text("ใช้ "),
+                Inline::Bold(vec![
+                    Inline::Code("mut".to_string()),
+                    text(" เมื่อ "),
+                    Inline::Italic(vec![text("ตั้งใจ")]),
+                    text(" แก้"),
+                ]),
+                text(" ดู "),
+                Inline::Link {
+                    text: vec![text("doc")],
+                    url: "https://doc.rust-lang.org".to_string(),
+                },
+            ]
+        );
+        // ไม่มีตัวปิด, `*` ที่มีช่องว่างตามหลัง และ escape เป็นข้อความธรรมดา
+        assert_eq!(parse_inline("2 * 3 = `six"), [text("2 * 3 = `six")]);
+        assert_eq!(parse_inline(r"\*\*not bold\*\*"), [text("**not bold**")]);
+        assert_eq!(plain_text(&parse_inline("a **b** `c`")), "a b c");
+    }
+
+    #[test]
+    fn test_blocks() {
+        let source = "# Title\n\
+            first line\nsecond line\n\n\
+            ```rust\nlet x = 1;\n\n# not a heading\n```\n\
+            - one\n  continued\n- two\n\
+            1. first\n\
+            > quoted\n> more\n\
+            ---\n\
+            | a | b |\n|:--|--:|\n| 1 | **2** |\n";
+        let blocks = parse(source);
+        assert_eq!(
+            blocks,
+            [
+                Block::Heading {
+                    level: 1,
+                    text: vec![text("Title")],
+                },
+                Block::Paragraph(vec![text("first line second line")]),
+                Block::Code {
+                    lang: Some("rust".to_string()),
+                    code: "let x = 1;\n\n# not a heading".to_string(),
+                },
+                Block::List {
+                    ordered: false,
+                    items: vec![vec![text("one continued")], vec![text("two")]],
+                },
+                Block::List {
+                    ordered: true,
+                    items: vec![vec![text("first")]],
+                },
+                Block::Quote(vec![text("quoted more")]),
+                Block::Rule,
+                Block::Table {
+                    header: vec![vec![text("a")], vec![text("b")]],
+                    align: vec![Align::Left, Align::Right],
+                    rows: vec![vec![vec![text("1")], vec![Inline::Bold(vec![text("2")])]]],
+                },
+            ]
+        );
+        // `|` ที่ไม่มีแถวคั่นตามมาไม่ใช่ตาราง
+        assert_eq!(
+            parse("| just text"),
+            [Block::Paragraph(vec![text("| just text")])]
+        );
+        assert_eq!(
+            parse("#hashtag"),
+            [Block::Paragraph(vec![text("#hashtag")])]
+        );
+    }
+}
@@ -0,0 +1,68 @@
+//! 📖 Lesson notes - `cargo run -- --notes <บท>` อ่านสรุปเนื้อหาของบทใน terminal
+//!
+//! โน้ตเขียนเป็น Markdown ใน `src/lesson/notes/` (ฝังเข้า binary ด้วย `include_str!`) แล้วแสดงผลผ่าน
+//! parser ที่เขียนเองใน crate นี้ - อ่าน `markdown.rs` เป็นบทเรียนเรื่องการ parse ได้เลย
+//!
+//! - 🔍 `markdown` - แยก block แล้ว parse inline แบบ recursive descent
+//! - 🌈 `highlight` - lexer เล็กๆ ระบายสี code block ภาษา Rust
+//! - 🖥️ `render` - หัวข้อ, ตัวหนา, ตารางกรอบเส้น ด้วย ANSI (หรือข้อความล้วนเมื่อไม่ใช่ terminal)
+
+pub mod highlight;
+pub mod markdown;
+pub mod render;
+
+pub use markdown::{Align, Block, Inline, parse};
+pub use render::render;
+
+/// โน้ตที่ฝังไว้ คู่กับเลขบท
+const NOTES: &[(u8, &str)] = &[
+    (1, include_str!("notes/01_basics.md")),
+    (2, include_str!("notes/02_ownership.md")),
+    (7, include_str!("notes/07_error_handling.md")),
+    (9, include_str!("notes/09_traits.md")),
+];
+
+/// เลขบทที่มีโน้ต
+pub fn chapters() -> impl Iterator<Item = u8> {
+    NOTES.iter().map(|(chapter, _)| *chapter)
+}
+
+/// Markdown ต้นฉบับของบท `chapter`
+#[must_use]
+pub fn source(chapter: u8) -> Option<&'static str> {
+    NOTES
+        .iter()
+        .find(|(number, _)| *number == chapter)
+        .map(|(_, text)| *text)
+}
+
+/// โน้ตของบท `chapter` ที่พร้อมพิมพ์ - `None` ถ้าบทนี้ยังไม่มีโน้ต
+#[must_use]
+pub fn render_notes(chapter: u8, color: bool) -> Option<String> {
+    source(chapter).map(|text| render(&parse(text), color))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_note_renders() {
+        for chapter in chapters() {
+            assert!(crate::chapters::registry().get(chapter).is_some());
+            let blocks = parse(source(chapter).unwrap());
+            assert!(
+                matches!(blocks.first(), Some(Block::Heading { level: 1, .. })),
+                "chapter {chapter} should start with a title"
+            );
+            assert!(
+                blocks
+                    .iter()
+                    .any(|block| matches!(block, Block::Code { .. }))
+            );
+            let plain = render_notes(chapter, false).unwrap();
+            assert!(!plain.contains('\x1b') && !plain.contains("**"), "{plain}");
+        }
+        assert!(render_notes(3, true).is_none());
+    }
+}
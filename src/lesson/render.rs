@@ -0,0 +1,270 @@
+//! 🖥️ แปลง block ของ Markdown เป็นข้อความสำหรับ terminal
+//!
+//! `color = false` ให้ผลเป็นข้อความล้วน (ใช้ตอน pipe ลงไฟล์ หรือเมื่อตั้ง `NO_COLOR`)
+//! ส่วน `color = true` ใช้ ANSI escape แบบเดียวกับตารางของ `task_cli`
+//! - ปิดแต่ละสไตล์ด้วยรหัสของมันเอง (`22`, `23`, `39`) จึงซ้อนกันได้ เช่นตัวหนาที่มี `code` อยู่ข้างใน
+
+use std::fmt::Write as _;
+
+use super::highlight;
+use super::markdown::{Align, Block, Inline, plain_text};
+
+/// แปลงเอกสารทั้งฉบับ
+#[must_use]
+pub fn render(blocks: &[Block], color: bool) -> String {
+    let mut out = String::new();
+    for (index, block) in blocks.iter().enumerate() {
+        if index > 0 {
+            out.push('\n');
+        }
+        render_block(block, color, &mut out);
+    }
+    out
+}
+
+fn render_block(block: &Block, color: bool, out: &mut String) {
+    match block {
+        Block::Heading { level, text } => {
+            let title = inline(text, color);
+            let width = display_width(&plain_text(text));
+            match level {
+                1 => {
+                    out.push_str(&paint(&title, "1;36", color));
+                    out.push('\n');
+                    out.push_str(&"═".repeat(width));
+                }
+                2 => {
+                    out.push_str(&paint(&title, "1;33", color));
+                    out.push('\n');
+                    out.push_str(&"─".repeat(width));
+                }
+                _ => out.push_str(&paint(&format!("▸ {title}"), "1", color)),
+            }
+            out.push('\n');
+        }
+        Block::Paragraph(text) => {
+            out.push_str(&inline(text, color));
+            out.push('\n');
+        }
+        Block::Code { lang, code } => render_code(lang.as_deref(), code, color, out),
+        Block::List { ordered, items } => {
+            for (number, item) in items.iter().enumerate() {
+                let bullet = if *ordered {
+                    format!("{}.", number + 1)
+                } else {
+                    "•".to_string()
+                };
+                let _ = writeln!(out, "  {bullet} {}", inline(item, color));
+            }
+        }
+        Block::Quote(text) => {
+            let _ = writeln!(out, "  {} {}", paint("┃", "90", color), inline(text, color));
+        }
+        Block::Table {
+            header,
+            align,
+            rows,
+        } => render_table(header, align, rows, color, out),
+        Block::Rule => {
+            out.push_str(&paint(&"─".repeat(40), "90", color));
+            out.push('\n');
+        }
+    }
+}
+
+/// code block มีกรอบด้านซ้าย และระบายสีถ้าเป็นภาษาที่ `highlight` รู้จัก
+fn render_code(lang: Option<&str>, code: &str, color: bool, out: &mut String) {
+    let label = lang.unwrap_or("");
+    out.push_str(&paint(&format!("  ┌─ {label}"), "90", color));
+    out.push('\n');
+    let highlight = color && lang.is_some_and(highlight::supports);
+    for line in code.lines() {
+        out.push_str(&paint("  │ ", "90", color));
+        if highlight {
+            for (kind, token) in highlight::tokenize(line) {
+                match kind.ansi() {
+                    Some(code) => {
+                        let _ = write!(out, "\x1b[{code}m{token}\x1b[0m");
+                    }
+                    None => out.push_str(token),
+                }
+            }
+        } else {
+            out.push_str(line);
+        }
+        out.push('\n');
+    }
+    out.push_str(&paint("  └─", "90", color));
+    out.push('\n');
+}
+
+/// ตารางกรอบเส้น - ความกว้างคอลัมน์วัดหลัง render (`display_width` ข้าม escape code)
+fn render_table(
+    header: &[Vec<Inline>],
+    align: &[Align],
+    rows: &[Vec<Vec<Inline>>],
+    color: bool,
+    out: &mut String,
+) {
+    let render_row = |cells: &[Vec<Inline>]| -> Vec<String> {
+        (0..header.len())
+            .map(|column| {
+                cells
+                    .get(column)
+                    .map_or_else(String::new, |cell| inline(cell, color))
+            })
+            .collect()
+    };
+    let header = render_row(header);
+    let rows: Vec<Vec<String>> = rows.iter().map(|row| render_row(row)).collect();
+    let mut widths: Vec<usize> = header.iter().map(|cell| display_width(cell)).collect();
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(display_width(cell));
+        }
+    }
+    if widths.is_empty() {
+        return;
+    }
+
+    let border = |left: &str, middle: &str, right: &str| {
+        let segments: Vec<String> = widths.iter().map(|width| "─".repeat(width + 2)).collect();
+        format!("{left}{}{right}\n", segments.join(middle))
+    };
+    let row_line = |cells: &[String], bold: bool| {
+        let mut line = String::from("│");
+        for (column, (cell, width)) in cells.iter().zip(&widths).enumerate() {
+            let padding = width - display_width(cell);
+            let (before, after) = match align.get(column).copied().unwrap_or_default() {
+                Align::Left => (0, padding),
+                Align::Right => (padding, 0),
+                Align::Center => (padding / 2, padding - padding / 2),
+            };
+            let cell = if bold {
+                paint(cell, "1", color)
+            } else {
+                cell.clone()
+            };
+            let _ = write!(line, " {}{cell}{} │", " ".repeat(before), " ".repeat(after));
+        }
+        line.push('\n');
+        line
+    };
+
+    out.push_str(&border("┌", "┬", "┐"));
+    out.push_str(&row_line(&header, true));
+    out.push_str(&border("├", "┼", "┤"));
+    for row in &rows {
+        out.push_str(&row_line(row, false));
+    }
+    out.push_str(&border("└", "┴", "┘"));
+}
+
+/// inline เป็นข้อความ - ไม่มีสีจะคง backtick ของ code ไว้ให้ยังแยกออก
+fn inline(spans: &[Inline], color: bool) -> String {
+    let mut text = String::new();
+    for span in spans {
+        match span {
+            Inline::Text(value) => text.push_str(value),
+            Inline::Code(value) if color => {
+                let _ = write!(text, "\x1b[33m{value}\x1b[39m");
+            }
+            Inline::Code(value) => {
+                let _ = write!(text, "`{value}`");
+            }
+            Inline::Bold(inner) if color => {
+                let _ = write!(text, "\x1b[1m{}\x1b[22m", inline(inner, color));
+            }
+            Inline::Italic(inner) if color => {
+                let _ = write!(text, "\x1b[3m{}\x1b[23m", inline(inner, color));
+            }
+            Inline::Bold(inner) | Inline::Italic(inner) => text.push_str(&inline(inner, color)),
+            Inline::Link { text: label, url } => {
+                let label = inline(label, color);
+                if color {
+                    let _ = write!(text, "\x1b[4m{label}\x1b[24m \x1b[90m({url})\x1b[39m");
+                } else {
+                    let _ = write!(text, "{label} ({url})");
+                }
+            }
+        }
+    }
+    text
+}
+
+fn paint(text: &str, code: &str, color: bool) -> String {
+    if color {
+        format!("\x1b[{code}m{text}\x1b[0m")
+    } else {
+        text.to_string()
+    }
+}
+
+/// ความกว้างบนจอ - ไม่นับ ANSI escape, สระบน/ล่างและวรรณยุกต์ไทยซ้อนอยู่บนตัวอื่นจึงกว้าง 0,
+/// emoji/CJK กว้าง 2
+#[must_use]
+pub fn display_width(text: &str) -> usize {
+    let mut in_escape = false;
+    text.chars()
+        .map(|ch| match ch {
+            '\x1b' => {
+                in_escape = true;
+                0
+            }
+            _ if in_escape => {
+                in_escape = ch != 'm';
+                0
+            }
+            // สระ/วรรณยุกต์ไทย, combining mark, zero-width และ variation selector
+            '\u{0E31}'
+            | '\u{0E34}'..='\u{0E3A}'
+            | '\u{0E47}'..='\u{0E4E}'
+            | '\u{0300}'..='\u{036F}'
+            | '\u{200B}'..='\u{200D}'
+            | '\u{FE0F}' => 0,
+            '\u{1100}'..='\u{115F}'
+            | '\u{2E80}'..='\u{A4CF}'
+            | '\u{AC00}'..='\u{D7A3}'
+            | '\u{FF00}'..='\u{FF60}'
+            | '\u{1F300}'..='\u{1FAFF}' => 2,
+            _ => 1,
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lesson::markdown::parse;
+
+    #[test]
+    fn test_plain_rendering() {
+        let source =
+            "# ชนิด\n\n| ชนิด | ค่า |\n|:---:|---:|\n| `i32` | 42 |\n| **bool** | true |\n\n- a\n- b";
+        assert_eq!(
+            render(&parse(source), false),
+            "ชนิด\n\
+             ═══\n\
+             \n\
+             ┌───────┬──────┐\n\
+             │  ชนิด  │   ค่า │\n\
+             ├───────┼──────┤\n\
+             │ `i32` │   42 │\n\
+             │ bool  │ true │\n\
+             └───────┴──────┘\n\
+             \n  • a\n  • b\n"
+        );
+        assert_eq!(display_width("ที่นี่ 🦀"), 5);
+        assert_eq!(display_width("\x1b[1;33mค่า\x1b[0m"), 2);
+    }
+
+    #[test]
+    fn test_color_rendering() {
+        let rendered = render(&parse("**ใช้ `mut`**\n\n```rust\nlet x = 1;\n```"), true);
+        assert!(rendered.starts_with("\x1b[1mใช้ \x1b[33mmut\x1b[39m\x1b[22m\n"));
+        assert!(rendered.contains("\x1b[35mlet\x1b[0m x = \x1b[33m1\x1b[0m;"));
+        // ภาษาที่ไม่รู้จักไม่ระบายสีเนื้อโค้ด
+        let toml = render(&parse("```toml\nlet = 1\n```"), true);
+        assert!(toml.contains("│ \x1b[0mlet = 1\n"));
+    }
+}
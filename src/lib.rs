@@ -15,6 +15,17 @@
 //! functions::run_functions_examples();
 //! ```
 
+#![cfg_attr(feature = "portable-simd", feature(portable_simd))]
+
+// ทุกบทใช้ println!/print! ตามปกติ แต่ใน crate นี้ข้อความวิ่งผ่าน output::Printer
+// จึงปิดเสียง (--quiet) หรือเก็บไว้ตรวจใน tests ได้โดยไม่ต้องแก้ทีละไฟล์ 🖨️
+macro_rules! println {
+    ($($arg:tt)*) => { $crate::__output_println!($($arg)*) };
+}
+macro_rules! print {
+    ($($arg:tt)*) => { $crate::__output_print!($($arg)*) };
+}
+
 // การประกาศโมดูล (เรียงตามลำดับการเรียนรู้)
 #[path = "01_basics/mod.rs"]
 pub mod basics; // พื้นฐาน Rust
@@ -98,6 +109,49 @@ pub mod game_development; // game development - เกมส์แบบมั
 #[path = "27_mobile_development/mod.rs"]
 pub mod mobile_development; // mobile development - มือถือแบบเจ๋ง! 📱
 
+#[cfg(feature = "embedded")]
+#[path = "28_embedded/mod.rs"]
+pub mod embedded; // embedded programming - firmware บนบอร์ดจำลอง! 🤖
+
+#[path = "29_io_filesystem/mod.rs"]
+pub mod io_filesystem; // I/O & file system - buffered I/O, paths, walk, lock file 📁
+
+#[path = "30_random/mod.rs"]
+pub mod random; // random number generation - xorshift/PCG/ChaCha + distributions จากศูนย์ 🎲
+#[path = "31_datetime/mod.rs"]
+pub mod datetime; // date/time - parse/format, timezone/DST, duration, cron บน chrono 🗓️
+
+#[cfg(feature = "wasm")]
+pub mod wasm; // API สำหรับ browser ผ่าน wasm-bindgen - template/ML/blockchain 🌐
+
+// โครงสร้างพื้นฐานที่หลายบทใช้ร่วมกัน
+pub mod clock; // นาฬิกาที่ฉีดได้ - ทำให้ tests ที่ขึ้นกับเวลาแน่นอน ⏰
+pub mod profiling; // span!() + flamegraph/Chrome trace - หาจุดที่ช้า 🔥
+pub mod metrics; // Counter/Gauge/Histogram + Prometheus text exporter 📈
+pub mod cache; // Cache<K,V> + LRU/LFU/TTL, hasher เลือกได้, ห่อ metrics และใช้ข้าม thread (Shared/Sharded) 🗃️
+pub mod jobs; // JobQueue + JobRunner - งานเบื้องหลังตั้งเวลาได้ retry แบบ backoff มี dead letter และบันทึกลงดิสก์ 🧰
+pub mod logger; // log แบบมีระดับ/target ส่งออก stdout/ไฟล์/JSON 📜
+pub mod chapters; // ChapterRegistry - รายชื่อบทที่เดียวสำหรับ --list/--chapter/--chapters และรันทุกบท 📚
+pub mod output; // Printer (Console/Silent/Capturing) ที่ println! ของทุกบทส่งต่อให้ + --quiet/--verbose 🖨️
+pub mod i18n; // t!("key") + catalog ไทย/อังกฤษ เลือกด้วย --lang หรือ RUST_CONCEPTS_LANG 🌐
+pub mod config; // defaults + TOML + env ซ้อนเป็นชั้น แล้ว deserialize ด้วย serde ⚙️
+pub mod event_bus; // pub/sub แยกตามชนิด event ส่งแบบ sync หรือผ่านคิว async 📣
+pub mod layout; // inspect::<T>() + layout_of! - size/align/offset/padding map และ niche ของ Option 📐
+pub mod validation; // Validate + validate! - กฎ length/range/pattern/custom รวบ error ทุกฟิลด์พร้อม path ✅
+pub mod strings; // Trie + Levenshtein + FuzzyMatcher - `--chapter trats` แนะนำ `traits` ให้ 🔤
+pub mod regex_lite; // RegexLite - parse → AST → Thompson NFA → จำลองทุก state พร้อมกัน ใช้ใน validation 🧩
+pub mod bigint; // BigUint บน limb u64 - บวก/ลบ/คูณ/หาร/modpow สำหรับ RSA และ difficulty target 🔢
+pub mod task_storage; // ไฟล์ของ TaskManager: JSON หรือ binary (magic + version + varint + CRC) 💾
+pub mod task_cli; // subcommand `task add/list/done/rm/edit/export` บน TaskManager ✅
+pub mod task_model; // Priority enum + Recurrence (daily/weekly/cron-lite) ของ Task 🔁
+pub mod task_search; // ค้นหา task ด้วย inverted index เทียบกับการ scan ทุกตัว 🔎
+pub mod progress; // progress.json - จำผลแบบฝึกหัดที่ทำแล้ว (attempts/best/solved_at) 📈
+pub mod exercises; // `exercise 07-2` ตรวจคำตอบใน exercises/ ด้วย test case ที่ซ่อนไว้ + hint ✍️
+pub mod quiz; // --quiz <บท> แบบทดสอบจากคลังคำถาม TOML สลับตัวเลือก + เฉลย + คะแนนดีที่สุด 📝
+pub mod lesson; // --notes <บท> โน้ต Markdown + parser/renderer ที่เขียนเอง (หัวข้อ, ตาราง, highlight) 📖
+pub mod playground; // --play <file.rs> compile/รัน snippet ในโปรเจคชั่วคราว + timeout + error ภาษาไทย 🧪
+pub mod interpreter; // --script <file.mini> ภาษาจิ๋ว: lexer → Pratt parser → bytecode → VM นับ gas (ใช้ในสัญญาอัจฉริยะด้วย) 📜
+
 // Re-exports เพื่อความสะดวก
 pub use async_await::*;
 pub use basics::*;
@@ -116,121 +170,53 @@ pub use unsafe_rust::*;
 
 // Re-export types for examples
 pub use serde::{Deserialize, Serialize};
+pub use task_model::{Priority, Recurrence};
 
-/// ฟังก์ชันสำหรับรันตัวอย่างทั้งหมด (sync version)
-fn run_all_examples_sync() {
-    println!("🦀 ยินดีต้อนรับสู่ Rust Concepts Learning Project! 🦀");
-    println!("{}", "=".repeat(50));
-
-    println!("🔥 === บทที่ 1: พื้นฐาน Rust === 🔥");
-    basics::run_basics_examples();
-
-    println!("\n\n🔒 === บทที่ 2: Ownership และ Borrowing === 🔒");
-    ownership::run_ownership_examples();
-
-    println!("\n\n📊 === บทที่ 3: Structs และ Enums === 📊");
-    structs_enums::run_structs_enums_examples();
-
-    println!("\n\n🚀 === บทที่ 4: Functions และ Control Flow === 🚀");
-    functions::run_functions_examples();
-
-    println!("\n\n📦 === บทที่ 5: Modules === 📦");
-    modules::run_modules_examples();
-
-    println!("\n\n📚 === บทที่ 6: Collections === 📚");
-    collections::run_collections_examples();
-
-    println!("\n\n⚠️ === บทที่ 7: Error Handling === ⚠️");
-    error_handling::run_error_handling_examples();
-
-    println!("\n\n🔧 === บทที่ 8: Generics === 🔧");
-    generics::run_generics_examples();
-
-    println!("\n\n🎯 === บทที่ 9: Traits === 🎯");
-    traits::run_traits_examples();
-
-    println!("\n\n⏰ === บทที่ 10: Lifetimes === ⏰");
-    lifetimes::run_lifetimes_examples();
-
-    println!("\n\n🎭 === บทที่ 12: Macros === 🎭");
-    macros::run_macros_examples();
-
-    println!("\n\n🧪 === บทที่ 13: Testing === 🧪");
-    testing::run_testing_examples();
-
-    println!("\n\n⚡ === บทที่ 14: Unsafe Rust === ⚡");
-    unsafe_rust::run_unsafe_examples();
-
-    println!("\n\n🎯 === บทที่ 15: Advanced Patterns === 🎯");
-    advanced_patterns::run_advanced_patterns_examples();
-
-    println!("\n\n🔀 === บทที่ 16: Concurrency === 🔀");
-    concurrency::run_concurrency_examples();
-
-    println!("\n\n🌐 === บทที่ 17: Web Development === 🌐");
-    web_development::run_web_development_examples();
-
-    println!("\n\n🌐 === บทที่ 18: Networking === 🌐");
-    networking::run_networking_examples();
-
-    println!("\n\n⚡ === บทที่ 19: Performance === ⚡");
-    performance::run_performance_examples();
-
-    println!("\n\n🔒 === บทที่ 20: Security === 🔒");
-    security::run_security_examples();
-
-    println!("\n\n🧙‍♂️ === บทที่ 21: Advanced Topics === 🧙‍♂️");
-    advanced_topics::run_advanced_topics_examples();
-
-    println!("\n\n🤖 === บทที่ 22: Machine Learning === 🤖");
-    machine_learning::run_machine_learning_examples();
-
-    println!("\n\n⛓️ === บทที่ 23: Blockchain === ⛓️");
-    blockchain::run_blockchain_examples();
-
-    println!("\n\n🗄️ === บทที่ 24: Database === 🗄️");
-    database::run_database_examples();
-
-    println!("\n\n🚀 === บทที่ 25: DevOps === 🚀");
-    devops::run_devops_examples();
-
-    println!("\n\n🎮 === บทที่ 26: Game Development === 🎮");
-    game_development::run_game_development_examples();
-
-    println!("\n\n📱 === บทที่ 27: Mobile Development === 📱");
-    mobile_development::run_mobile_development_examples();
-
-    println!("\n🎊 สำเร็จ! คุณได้เรียนรู้แนวคิดสำคัญของ Rust ครบถ้วนแล้ว! 🎊");
-    println!("🚀 ตอนนี้คุณพร้อมที่จะสร้างแอปพลิเคชัน Rust ของตัวเองแล้ว!");
-    println!("💡 คุณได้เรียนรู้ทั้งหมด 27 บท ครอบคลุมตั้งแต่พื้นฐานจนถึงหัวข้อขั้นสูง!");
-    println!("⚠️ หมายเหตุ: บทที่ 11 (Async Programming) ต้องใช้ tokio runtime");
-}
 pub use anyhow::{Context, Result};
 pub use chrono::{DateTime, Utc};
 pub use std::path::PathBuf;
 pub use uuid::Uuid;
 
 /// Task struct for CLI example
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// ยังอ่าน JSON รูปแบบเก่า (`priority` เป็น string ใดๆ ตัวพิมพ์ไหนก็ได้, ไม่มี `due`/`tags`/`recurrence`) ได้
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Task {
     pub id: Uuid,
     pub title: String,
-    pub priority: String,
+    pub priority: Priority,
     pub completed: bool,
     pub created_at: DateTime<Utc>,
+    /// กำหนดส่ง - ไฟล์เก่าที่ไม่มี field นี้โหลดเป็น `None`
+    #[serde(default)]
+    pub due: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// ถ้ามี - `TaskManager::roll_over` จะสร้าง task รอบถัดไปเมื่อ task นี้เสร็จ
+    #[serde(default)]
+    pub recurrence: Option<Recurrence>,
 }
 
 impl Task {
     #[must_use]
-    pub fn new(title: String, priority: String) -> Self {
+    pub fn new(title: String, priority: Priority) -> Self {
         Self {
             id: Uuid::new_v4(),
             title,
             priority,
             completed: false,
             created_at: Utc::now(),
+            due: None,
+            tags: Vec::new(),
+            recurrence: None,
         }
     }
+
+    /// เลยกำหนดส่งแล้วแต่ยังไม่เสร็จ
+    #[must_use]
+    pub fn is_overdue(&self, now: DateTime<Utc>) -> bool {
+        !self.completed && self.due.is_some_and(|due| due < now)
+    }
 }
 
 /// `TaskManager` for CLI example
@@ -238,6 +224,10 @@ impl Task {
 pub struct TaskManager {
     tasks: Vec<Task>,
     file_path: PathBuf,
+    #[serde(skip)]
+    format: task_storage::StorageFormat,
+    #[serde(skip)]
+    index: task_search::TaskIndex,
 }
 
 impl TaskManager {
@@ -246,12 +236,32 @@ impl TaskManager {
         Self {
             tasks: Vec::new(),
             file_path,
+            format: task_storage::StorageFormat::Json,
+            index: task_search::TaskIndex::new(),
         }
     }
 
-    pub fn add_task(&mut self, title: String, priority: String) -> Uuid {
+    /// เลือก format ตอน `save_to_file` - ตอนโหลดจะดู magic bytes เองเสมอ
+    #[must_use]
+    pub const fn with_format(mut self, format: task_storage::StorageFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    #[must_use]
+    pub const fn format(&self) -> task_storage::StorageFormat {
+        self.format
+    }
+
+    #[must_use]
+    pub fn file_path(&self) -> &std::path::Path {
+        &self.file_path
+    }
+
+    pub fn add_task(&mut self, title: String, priority: Priority) -> Uuid {
         let task = Task::new(title, priority);
         let task_id = task.id;
+        self.index.insert(&task, self.tasks.len());
         self.tasks.push(task);
         task_id
     }
@@ -261,17 +271,97 @@ impl TaskManager {
         &self.tasks
     }
 
+    /// หา task จาก id เต็มหรือแค่ตัวอักษรต้นๆ (เหมือน short hash ของ git)
     #[allow(clippy::missing_errors_doc)]
-    pub fn complete_task(&mut self, task_id: &Uuid) -> Result<()> {
+    pub fn resolve_id(&self, prefix: &str) -> Result<Uuid> {
+        let prefix = prefix.trim().to_ascii_lowercase();
+        if prefix.is_empty() {
+            anyhow::bail!("Task id must not be empty");
+        }
+        let mut matches = self
+            .tasks
+            .iter()
+            .filter(|t| t.id.to_string().starts_with(&prefix));
+        match (matches.next(), matches.next()) {
+            (Some(task), None) => Ok(task.id),
+            (None, _) => anyhow::bail!("Task not found: {prefix}"),
+            (Some(_), Some(_)) => {
+                anyhow::bail!("Task id {prefix} is ambiguous - type more characters")
+            }
+        }
+    }
+
+    fn find_mut(&mut self, task_id: &Uuid) -> Result<&mut Task> {
+        self.tasks
+            .iter_mut()
+            .find(|t| t.id == *task_id)
+            .ok_or_else(|| anyhow::anyhow!("Task not found"))
+    }
+
+    /// แก้ task ผ่าน closure แล้วอัปเดต search index ให้ตรงกับค่าใหม่
+    ///
+    /// ไม่มี `&mut Task` ให้ยืมออกไปตรงๆ เพราะ index จะไม่รู้ว่า title/tags เปลี่ยน
+    #[allow(clippy::missing_errors_doc)]
+    pub fn update_task(&mut self, task_id: &Uuid, update: impl FnOnce(&mut Task)) -> Result<()> {
         let task = self
             .tasks
             .iter_mut()
             .find(|t| t.id == *task_id)
             .ok_or_else(|| anyhow::anyhow!("Task not found"))?;
-        task.completed = true;
+        let before = task.clone();
+        update(task);
+        // id เป็นกุญแจของ index - เปลี่ยนไม่ได้
+        task.id = before.id;
+        self.index.update(&before, task);
         Ok(())
     }
 
+    #[allow(clippy::missing_errors_doc)]
+    pub fn complete_task(&mut self, task_id: &Uuid) -> Result<()> {
+        self.find_mut(task_id)?.completed = true;
+        Ok(())
+    }
+
+    /// 🔎 ค้นหาผ่าน inverted index - ดูรูปแบบ query ที่ `task_search`
+    #[must_use]
+    pub fn search(&self, query: &task_search::Query) -> Vec<&Task> {
+        self.index.search(&self.tasks, query)
+    }
+
+    /// สร้าง task รอบถัดไปให้ทุก task ที่ทำซ้ำและเสร็จแล้ว - คืน id ของ task ใหม่
+    pub fn roll_over(&mut self) -> Vec<Uuid> {
+        self.roll_over_at(Utc::now())
+    }
+
+    /// `roll_over` ที่กำหนดเวลาปัจจุบันเองได้ (ใช้ใน tests)
+    ///
+    /// task เดิมยังอยู่เป็นประวัติ แต่ย้าย `recurrence` ไปให้ task ใหม่ จึงไม่ถูก roll ซ้ำ
+    pub fn roll_over_at(&mut self, now: DateTime<Utc>) -> Vec<Uuid> {
+        let mut created = Vec::new();
+        let mut next_tasks = Vec::new();
+        for task in self.tasks.iter_mut().filter(|t| t.completed) {
+            let Some(recurrence) = task.recurrence.take() else {
+                continue;
+            };
+            let Some(due) = recurrence.next_due(task.due, now) else {
+                // ไม่มีรอบถัดไปแล้ว (เช่น cron ที่ไม่มีวันตรง) - เลิกทำซ้ำ
+                continue;
+            };
+            let mut next = Task::new(task.title.clone(), task.priority);
+            next.created_at = now;
+            next.due = Some(due);
+            next.tags.clone_from(&task.tags);
+            next.recurrence = Some(recurrence);
+            created.push(next.id);
+            next_tasks.push(next);
+        }
+        for next in next_tasks {
+            self.index.insert(&next, self.tasks.len());
+            self.tasks.push(next);
+        }
+        created
+    }
+
     #[allow(clippy::missing_errors_doc)]
     pub fn remove_task(&mut self, task_id: &Uuid) -> Result<()> {
         let index = self
@@ -279,15 +369,30 @@ impl TaskManager {
             .iter()
             .position(|t| t.id == *task_id)
             .ok_or_else(|| anyhow::anyhow!("Task not found"))?;
-        self.tasks.remove(index);
+        let task = self.tasks.remove(index);
+        self.index.remove(&task, index);
         Ok(())
     }
 
+    /// เขียนลงไฟล์ชั่วคราวข้างๆ แล้ว rename ทับ - ถ้าโปรแกรมตายกลางทาง ไฟล์เดิมยังอยู่ครบ
     #[allow(clippy::missing_errors_doc)]
     pub fn save_to_file(&self) -> Result<()> {
-        let json =
-            serde_json::to_string_pretty(&self.tasks).context("Failed to serialize tasks")?;
-        std::fs::write(&self.file_path, json).context("Failed to write to file")?;
+        let bytes = task_storage::encode_tasks(&self.tasks, self.format)
+            .context("Failed to serialize tasks")?;
+        let mut temp_name = self
+            .file_path
+            .file_name()
+            .unwrap_or_default()
+            .to_os_string();
+        temp_name.push(".tmp");
+        let temp_path = self.file_path.with_file_name(temp_name);
+        std::fs::write(&temp_path, bytes)
+            .with_context(|| format!("Failed to write to {}", temp_path.display()))?;
+        if let Err(error) = std::fs::rename(&temp_path, &self.file_path) {
+            let _ = std::fs::remove_file(&temp_path);
+            return Err(error)
+                .with_context(|| format!("Failed to replace {}", self.file_path.display()));
+        }
         Ok(())
     }
 
@@ -296,112 +401,23 @@ impl TaskManager {
         if !self.file_path.exists() {
             return Ok(());
         }
-        let content = std::fs::read_to_string(&self.file_path).context("Failed to read file")?;
-        self.tasks = serde_json::from_str(&content).context("Failed to parse JSON")?;
+        let content = std::fs::read(&self.file_path).context("Failed to read file")?;
+        self.tasks = task_storage::decode_tasks(&content)
+            .with_context(|| format!("Failed to parse {}", self.file_path.display()))?;
+        self.index = task_search::TaskIndex::rebuild(&self.tasks);
         Ok(())
     }
 }
 
-/// ฟังก์ชันสำหรับรันตัวอย่างทั้งหมด (async version)
+/// ฟังก์ชันสำหรับรันตัวอย่างทั้งหมด (async version) - รวมบท async
+#[allow(clippy::future_not_send)] // บทเรียน async ไม่ได้สัญญาว่า Send
 pub async fn run_all_examples_async() {
-    run_all_examples_internal().await;
+    chapters::registry().run_all_async().await;
 }
 
-/// ฟังก์ชันสำหรับรันตัวอย่างทั้งหมด (sync version)
+/// ฟังก์ชันสำหรับรันตัวอย่างทั้งหมด (sync version) - ข้ามบท async
 pub fn run_all_examples() {
-    // สำหรับ sync version เราจะข้าม async examples
-    run_all_examples_sync();
-}
-
-/// ฟังก์ชันภายในสำหรับรันตัวอย่างทั้งหมด (async)
-async fn run_all_examples_internal() {
-    println!("🦀 ยินดีต้อนรับสู่ Rust Concepts Learning Project! 🦀");
-    println!("{}", "=".repeat(50));
-
-    println!("🔥 === บทที่ 1: พื้นฐาน Rust === 🔥");
-    basics::run_basics_examples();
-
-    println!("\n\n🔒 === บทที่ 2: Ownership และ Borrowing === 🔒");
-    ownership::run_ownership_examples();
-
-    println!("\n\n📊 === บทที่ 3: Structs และ Enums === 📊");
-    structs_enums::run_structs_enums_examples();
-
-    println!("\n\n🚀 === บทที่ 4: Functions และ Control Flow === 🚀");
-    functions::run_functions_examples();
-
-    println!("\n\n📦 === บทที่ 5: Modules === 📦");
-    modules::run_modules_examples();
-
-    println!("\n\n📚 === บทที่ 6: Collections === 📚");
-    collections::run_collections_examples();
-
-    println!("\n\n⚠️ === บทที่ 7: Error Handling === ⚠️");
-    error_handling::run_error_handling_examples();
-
-    println!("\n\n🔧 === บทที่ 8: Generics === 🔧");
-    generics::run_generics_examples();
-
-    println!("\n\n🎯 === บทที่ 9: Traits === 🎯");
-    traits::run_traits_examples();
-
-    println!("\n\n⏰ === บทที่ 10: Lifetimes === ⏰");
-    lifetimes::run_lifetimes_examples();
-
-    println!("\n\n🔄 === บทที่ 11: Async/Await === 🔄");
-    async_await::run_async_examples().await;
-
-    println!("\n\n🎭 === บทที่ 12: Macros === 🎭");
-    macros::run_macros_examples();
-
-    println!("\n\n🧪 === บทที่ 13: Testing === 🧪");
-    testing::run_testing_examples();
-
-    println!("\n\n⚡ === บทที่ 14: Unsafe Rust === ⚡");
-    unsafe_rust::run_unsafe_examples();
-
-    println!("\n\n🎯 === บทที่ 15: Advanced Patterns === 🎯");
-    advanced_patterns::run_advanced_patterns_examples();
-
-    println!("\n\n🔀 === บทที่ 16: Concurrency === 🔀");
-    concurrency::run_concurrency_examples();
-
-    println!("\n\n🌐 === บทที่ 17: Web Development === 🌐");
-    web_development::run_web_development_examples();
-
-    println!("\n\n🌐 === บทที่ 18: Networking === 🌐");
-    networking::run_networking_examples();
-
-    println!("\n\n⚡ === บทที่ 19: Performance === ⚡");
-    performance::run_performance_examples();
-
-    println!("\n\n🔒 === บทที่ 20: Security === 🔒");
-    security::run_security_examples();
-
-    println!("\n\n🧙‍♂️ === บทที่ 21: Advanced Topics === 🧙‍♂️");
-    advanced_topics::run_advanced_topics_examples();
-
-    println!("\n\n🤖 === บทที่ 22: Machine Learning === 🤖");
-    machine_learning::run_machine_learning_examples();
-
-    println!("\n\n⛓️ === บทที่ 23: Blockchain === ⛓️");
-    blockchain::run_blockchain_examples();
-
-    println!("\n\n🗄️ === บทที่ 24: Database === 🗄️");
-    database::run_database_examples();
-
-    println!("\n\n🚀 === บทที่ 25: DevOps === 🚀");
-    devops::run_devops_examples();
-
-    println!("\n\n🎮 === บทที่ 26: Game Development === 🎮");
-    game_development::run_game_development_examples();
-
-    println!("\n\n📱 === บทที่ 27: Mobile Development === 📱");
-    mobile_development::run_mobile_development_examples();
-
-    println!("\n🎊 สำเร็จ! คุณได้เรียนรู้แนวคิดสำคัญของ Rust ครบถ้วนแล้ว! 🎊");
-    println!("🚀 ตอนนี้คุณพร้อมที่จะสร้างแอปพลิเคชัน Rust ของตัวเองแล้ว!");
-    println!("💡 คุณได้เรียนรู้ทั้งหมด 27 บท ครอบคลุมตั้งแต่พื้นฐานจนถึงหัวข้อขั้นสูง!");
+    chapters::registry().run_all();
 }
 
 // Tests are now in individual modules
\ No newline at end of file
@@ -0,0 +1,629 @@
+//! 📜 Structured Logger - log แบบมีระดับ มี target และส่งออกได้หลายปลายทาง
+//!
+//! แทนการ `println!` ตรงๆ ในแต่ละบท ให้เรียก macro ของโมดูลนี้แทน:
+//!
+//! ```
+//! use rust_concepts::logger::{self, Format, Level, Logger, MemorySink};
+//!
+//! let sink = MemorySink::new();
+//! let logger = Logger::new(Level::Info).with_sink(sink.clone(), Format::Json);
+//! logger::with_logger(logger, || {
+//!     logger::info!(user = "ferris", attempts = 3; "login ok");
+//!     logger::debug!("ไม่ถูกบันทึก เพราะต่ำกว่า Info");
+//! });
+//! assert_eq!(sink.records().len(), 1);
+//! assert!(sink.lines()[0].contains(r#""user":"ferris""#));
+//! ```
+//!
+//! - 🎚️ `Level` - `Trace` < `Debug` < `Info` < `Warn` < `Error`
+//! - 🎯 target - ค่าเริ่มต้นคือ `module_path!()` และตั้งระดับแยกรายโมดูลได้ด้วย `with_target`
+//!   (prefix ที่ยาวที่สุดชนะ) หรือผ่าน env `RUST_LOG=info,rust_concepts::networking=debug`
+//! - 🚰 `Sink` - `StdoutSink` (ค่าเริ่มต้น ออกปนกับ `println!` ของบทอื่นได้พอดี), `StderrSink`,
+//!   `FileSink` และ `MemorySink` สำหรับ tests
+//! - 🎨 `Format` - `Pretty` อ่านง่ายใน terminal, `Json` หนึ่งบรรทัดต่อหนึ่ง record
+//!
+//! ถ้ายังไม่มีใคร `set_logger` จะใช้ logger เริ่มต้นที่พิมพ์แบบ `Pretty` ออก stdout
+//! ส่วน `with_logger` เปลี่ยน logger เฉพาะ thread ปัจจุบันชั่วคราว - tests ที่รันขนานกันจึงไม่ชนกัน 🧪
+
+use std::cell::RefCell;
+use std::fmt::{self, Write as _};
+use std::fs::{File, OpenOptions};
+use std::io::{self, Write as _};
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex, PoisonError, RwLock};
+
+use crate::clock::{Clock, system_clock};
+
+/// บันทึก log ที่ระดับ `$level` - รูปแบบเดียวกับ `info!` แต่ระบุระดับเอง
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __logger_log {
+    (target: $target:expr, $level:expr, $($key:ident = $value:expr),+ ; $($arg:tt)+) => {{
+        let level: $crate::logger::Level = $level;
+        if $crate::logger::enabled(level, $target) {
+            $crate::logger::log_record(
+                $crate::logger::Record::new(level, $target, format!($($arg)+))
+                    $(.field(stringify!($key), &$value))+,
+            );
+        }
+    }};
+    (target: $target:expr, $level:expr, $($arg:tt)+) => {{
+        let level: $crate::logger::Level = $level;
+        if $crate::logger::enabled(level, $target) {
+            $crate::logger::log_record($crate::logger::Record::new(level, $target, format!($($arg)+)));
+        }
+    }};
+    ($level:expr, $($arg:tt)+) => {
+        $crate::__logger_log!(target: module_path!(), $level, $($arg)+)
+    };
+}
+
+/// log ระดับ `Trace` - ละเอียดที่สุด ใช้ไล่ดูการทำงานทีละขั้น
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __logger_trace {
+    (target: $target:expr, $($arg:tt)+) => {
+        $crate::__logger_log!(target: $target, $crate::logger::Level::Trace, $($arg)+)
+    };
+    ($($arg:tt)+) => {
+        $crate::__logger_log!($crate::logger::Level::Trace, $($arg)+)
+    };
+}
+
+/// log ระดับ `Debug` - ข้อมูลสำหรับ debug
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __logger_debug {
+    (target: $target:expr, $($arg:tt)+) => {
+        $crate::__logger_log!(target: $target, $crate::logger::Level::Debug, $($arg)+)
+    };
+    ($($arg:tt)+) => {
+        $crate::__logger_log!($crate::logger::Level::Debug, $($arg)+)
+    };
+}
+
+/// log ระดับ `Info` - เหตุการณ์ปกติที่ควรรู้
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __logger_info {
+    (target: $target:expr, $($arg:tt)+) => {
+        $crate::__logger_log!(target: $target, $crate::logger::Level::Info, $($arg)+)
+    };
+    ($($arg:tt)+) => {
+        $crate::__logger_log!($crate::logger::Level::Info, $($arg)+)
+    };
+}
+
+/// log ระดับ `Warn` - สิ่งผิดปกติที่ยังทำงานต่อได้
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __logger_warn {
+    (target: $target:expr, $($arg:tt)+) => {
+        $crate::__logger_log!(target: $target, $crate::logger::Level::Warn, $($arg)+)
+    };
+    ($($arg:tt)+) => {
+        $crate::__logger_log!($crate::logger::Level::Warn, $($arg)+)
+    };
+}
+
+/// log ระดับ `Error` - ความผิดพลาดที่ต้องแก้
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __logger_error {
+    (target: $target:expr, $($arg:tt)+) => {
+        $crate::__logger_log!(target: $target, $crate::logger::Level::Error, $($arg)+)
+    };
+    ($($arg:tt)+) => {
+        $crate::__logger_log!($crate::logger::Level::Error, $($arg)+)
+    };
+}
+
+pub use crate::__logger_debug as debug;
+pub use crate::__logger_error as error;
+pub use crate::__logger_info as info;
+pub use crate::__logger_log as log;
+pub use crate::__logger_trace as trace;
+pub use crate::__logger_warn as warn;
+
+/// 🎚️ ระดับความสำคัญของ log เรียงจากละเอียดสุดไปร้ายแรงสุด
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Level {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl Level {
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Trace => "TRACE",
+            Self::Debug => "DEBUG",
+            Self::Info => "INFO",
+            Self::Warn => "WARN",
+            Self::Error => "ERROR",
+        }
+    }
+
+    #[must_use]
+    pub const fn emoji(self) -> &'static str {
+        match self {
+            Self::Trace => "🔬",
+            Self::Debug => "🐛",
+            Self::Info => "ℹ️",
+            Self::Warn => "⚠️",
+            Self::Error => "❌",
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "trace" => Ok(Self::Trace),
+            "debug" => Ok(Self::Debug),
+            "info" => Ok(Self::Info),
+            "warn" | "warning" => Ok(Self::Warn),
+            "error" => Ok(Self::Error),
+            other => Err(format!("unknown log level: {other:?}")),
+        }
+    }
+}
+
+/// 🧾 log หนึ่งรายการ
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub level: Level,
+    pub target: String,
+    pub message: String,
+    /// ข้อมูลประกอบแบบ key/value ตามลำดับที่ใส่
+    pub fields: Vec<(String, String)>,
+    /// Unix timestamp หน่วยมิลลิวินาที - `Logger` ใส่ให้ตอนบันทึก
+    pub timestamp_ms: u64,
+}
+
+impl Record {
+    #[must_use]
+    pub fn new(level: Level, target: &str, message: String) -> Self {
+        Self {
+            level,
+            target: target.to_string(),
+            message,
+            fields: Vec::new(),
+            timestamp_ms: 0,
+        }
+    }
+
+    #[must_use]
+    pub fn field(mut self, key: &str, value: &dyn fmt::Display) -> Self {
+        self.fields.push((key.to_string(), value.to_string()));
+        self
+    }
+}
+
+/// 🎨 รูปแบบข้อความที่ส่งให้ sink
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// `ℹ️ [INFO] target: message key=value`
+    Pretty,
+    /// `{"timestamp_ms":..,"level":"INFO","target":..,"message":..,"fields":{..}}`
+    Json,
+}
+
+impl Format {
+    #[must_use]
+    pub fn render(self, record: &Record) -> String {
+        match self {
+            Self::Pretty => {
+                let mut line = format!(
+                    "{} [{}] {}: {}",
+                    record.level.emoji(),
+                    record.level,
+                    record.target,
+                    record.message
+                );
+                for (key, value) in &record.fields {
+                    let _ = write!(line, " {key}={value}");
+                }
+                line
+            }
+            Self::Json => {
+                let fields: serde_json::Map<String, serde_json::Value> = record
+                    .fields
+                    .iter()
+                    .map(|(key, value)| (key.clone(), serde_json::Value::from(value.as_str())))
+                    .collect();
+                serde_json::json!({
+                    "timestamp_ms": record.timestamp_ms,
+                    "level": record.level.as_str(),
+                    "target": record.target,
+                    "message": record.message,
+                    "fields": fields,
+                })
+                .to_string()
+            }
+        }
+    }
+}
+
+/// 🚰 ปลายทางของ log - ได้รับข้อความที่จัดรูปแบบแล้วหนึ่งบรรทัด (ไม่มี `\n` ปิดท้าย)
+pub trait Sink: Send + Sync {
+    fn write(&self, line: &str, record: &Record);
+}
+
+/// พิมพ์ออก stdout ผ่าน `println!` - ปลายทางเริ่มต้น
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StdoutSink;
+
+impl Sink for StdoutSink {
+    fn write(&self, line: &str, _record: &Record) {
+        println!("{line}");
+    }
+}
+
+/// พิมพ์ออก stderr - ใช้เมื่อ stdout เป็นผลลัพธ์ของโปรแกรมที่ต้อง pipe ต่อ
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StderrSink;
+
+impl Sink for StderrSink {
+    fn write(&self, line: &str, _record: &Record) {
+        eprintln!("{line}");
+    }
+}
+
+/// 📁 ต่อท้ายไฟล์ทีละบรรทัด
+#[derive(Debug)]
+pub struct FileSink {
+    file: Mutex<File>,
+}
+
+impl FileSink {
+    /// เปิดไฟล์แบบ append (สร้างใหม่ถ้ายังไม่มี)
+    ///
+    /// # Errors
+    ///
+    /// คืน error ถ้าเปิดไฟล์ไม่ได้
+    pub fn append(path: impl AsRef<Path>) -> io::Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(Self {
+            file: Mutex::new(file),
+        })
+    }
+}
+
+impl Sink for FileSink {
+    fn write(&self, line: &str, _record: &Record) {
+        // log ที่เขียนไม่ลงไม่ควรทำให้โปรแกรมล้ม
+        let _ = writeln!(
+            self.file.lock().unwrap_or_else(PoisonError::into_inner),
+            "{line}"
+        );
+    }
+}
+
+/// 🧪 เก็บ log ไว้ในหน่วยความจำ - clone แล้วยังชี้ไปที่ buffer เดิม ใช้ตรวจผลใน tests
+#[derive(Debug, Clone, Default)]
+pub struct MemorySink {
+    entries: Arc<Mutex<Vec<(String, Record)>>>,
+}
+
+impl MemorySink {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// ข้อความที่จัดรูปแบบแล้วทั้งหมด
+    #[must_use]
+    pub fn lines(&self) -> Vec<String> {
+        self.entries().into_iter().map(|(line, _)| line).collect()
+    }
+
+    #[must_use]
+    pub fn records(&self) -> Vec<Record> {
+        self.entries()
+            .into_iter()
+            .map(|(_, record)| record)
+            .collect()
+    }
+
+    fn entries(&self) -> Vec<(String, Record)> {
+        self.entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+}
+
+impl Sink for MemorySink {
+    fn write(&self, line: &str, record: &Record) {
+        self.entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push((line.to_string(), record.clone()));
+    }
+}
+
+/// 📜 ตัวกรองระดับ log + รายการ sink
+pub struct Logger {
+    default_level: Level,
+    /// (prefix ของ target, ระดับขั้นต่ำ) - prefix ยาวสุดที่ตรงชนะ
+    targets: Vec<(String, Level)>,
+    sinks: Vec<(Box<dyn Sink>, Format)>,
+    clock: Arc<dyn Clock>,
+}
+
+impl fmt::Debug for Logger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Logger")
+            .field("default_level", &self.default_level)
+            .field("targets", &self.targets)
+            .field("sinks", &self.sinks.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for Logger {
+    /// พิมพ์แบบ `Pretty` ออก stdout ที่ระดับ `Debug` (debug build) หรือ `Info` (release build)
+    /// แล้วปรับตาม env `RUST_LOG` ถ้ามี
+    fn default() -> Self {
+        let level = if cfg!(debug_assertions) {
+            Level::Debug
+        } else {
+            Level::Info
+        };
+        let logger = Self::new(level).with_sink(StdoutSink, Format::Pretty);
+        match std::env::var("RUST_LOG") {
+            Ok(spec) => logger.with_filters(&spec).unwrap_or_else(|err| {
+                eprintln!("⚠️ ignoring RUST_LOG: {err}");
+                Self::new(level).with_sink(StdoutSink, Format::Pretty)
+            }),
+            Err(_) => logger,
+        }
+    }
+}
+
+impl Logger {
+    /// logger ที่ยังไม่มี sink - เพิ่มด้วย `with_sink`
+    #[must_use]
+    pub fn new(default_level: Level) -> Self {
+        Self {
+            default_level,
+            targets: Vec::new(),
+            sinks: Vec::new(),
+            clock: system_clock(),
+        }
+    }
+
+    /// ตั้งระดับขั้นต่ำของ target ที่ขึ้นต้นด้วย `prefix` (เช่น `rust_concepts::networking`)
+    #[must_use]
+    pub fn with_target(mut self, prefix: &str, level: Level) -> Self {
+        self.targets.retain(|(existing, _)| existing != prefix);
+        self.targets.push((prefix.to_string(), level));
+        self
+    }
+
+    /// ใช้ตัวกรองแบบ `RUST_LOG`: `"warn,rust_concepts::networking=debug"`
+    ///
+    /// # Errors
+    ///
+    /// คืน error ถ้าชื่อระดับไม่รู้จัก
+    pub fn with_filters(mut self, spec: &str) -> Result<Self, String> {
+        for directive in spec.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+            match directive.split_once('=') {
+                Some((prefix, level)) => self = self.with_target(prefix.trim(), level.parse()?),
+                None => self.default_level = directive.parse()?,
+            }
+        }
+        Ok(self)
+    }
+
+    #[must_use]
+    pub fn with_sink(mut self, sink: impl Sink + 'static, format: Format) -> Self {
+        self.sinks.push((Box::new(sink), format));
+        self
+    }
+
+    /// เปลี่ยนแหล่งเวลาของ `timestamp_ms` - ใช้ `MockClock` ใน tests ⏰
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// ระดับขั้นต่ำที่ใช้กับ `target`
+    #[must_use]
+    pub fn level_for(&self, target: &str) -> Level {
+        self.targets
+            .iter()
+            .filter(|(prefix, _)| target_matches(target, prefix))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map_or(self.default_level, |&(_, level)| level)
+    }
+
+    #[must_use]
+    pub fn enabled(&self, level: Level, target: &str) -> bool {
+        level >= self.level_for(target)
+    }
+
+    /// ส่ง `record` ให้ทุก sink ถ้าผ่านตัวกรอง
+    pub fn log(&self, mut record: Record) {
+        if !self.enabled(record.level, &record.target) {
+            return;
+        }
+        record.timestamp_ms = self.clock.unix_timestamp_millis();
+        for (sink, format) in &self.sinks {
+            sink.write(&format.render(&record), &record);
+        }
+    }
+}
+
+/// `prefix` ตรงกับ target ทั้งชื่อ หรือเป็นโมดูลแม่ (`a::b` ตรงกับ `a::b::c` แต่ไม่ตรงกับ `a::bc`)
+fn target_matches(target: &str, prefix: &str) -> bool {
+    target
+        .strip_prefix(prefix)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+}
+
+static GLOBAL: RwLock<Option<Arc<Logger>>> = RwLock::new(None);
+
+thread_local! {
+    static SCOPED: RefCell<Option<Arc<Logger>>> = const { RefCell::new(None) };
+}
+
+/// ตั้ง logger กลางของทั้งโปรแกรม (แทนที่ตัวเดิม)
+pub fn set_logger(logger: Logger) {
+    *GLOBAL.write().unwrap_or_else(PoisonError::into_inner) = Some(Arc::new(logger));
+}
+
+/// logger ที่ใช้อยู่ตอนนี้บน thread นี้: ตัวจาก `with_logger` > `set_logger` > ค่าเริ่มต้น
+#[must_use]
+pub fn current() -> Arc<Logger> {
+    if let Some(scoped) = SCOPED.with(|scoped| scoped.borrow().clone()) {
+        return scoped;
+    }
+    if let Some(global) = GLOBAL
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .as_ref()
+    {
+        return Arc::clone(global);
+    }
+    let mut global = GLOBAL.write().unwrap_or_else(PoisonError::into_inner);
+    Arc::clone(global.get_or_insert_with(|| Arc::new(Logger::default())))
+}
+
+/// รัน `f` โดยใช้ `logger` เฉพาะบน thread นี้ แล้วคืนค่าเดิมเมื่อจบ (แม้ `f` จะ panic)
+pub fn with_logger<R>(logger: Logger, f: impl FnOnce() -> R) -> R {
+    struct Restore(Option<Arc<Logger>>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            let previous = self.0.take();
+            SCOPED.with(|scoped| *scoped.borrow_mut() = previous);
+        }
+    }
+
+    let previous = SCOPED.with(|scoped| scoped.borrow_mut().replace(Arc::new(logger)));
+    let _restore = Restore(previous);
+    f()
+}
+
+/// ใช้โดย macro - ตรวจก่อนว่าควรจัดรูปแบบข้อความหรือไม่
+#[doc(hidden)]
+#[must_use]
+pub fn enabled(level: Level, target: &str) -> bool {
+    current().enabled(level, target)
+}
+
+/// ส่ง record ให้ logger ปัจจุบัน
+pub fn log_record(record: Record) {
+    current().log(record);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::MockClock;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    #[test]
+    fn test_levels_and_targets_filter_records() {
+        let sink = MemorySink::new();
+        let logger = Logger::new(Level::Warn)
+            .with_target("app::db", Level::Debug)
+            .with_target("app::db::pool", Level::Error)
+            .with_sink(sink.clone(), Format::Pretty);
+
+        with_logger(logger, || {
+            info!(target: "app::http", "hidden");
+            warn!(target: "app::http", "slow request");
+            debug!(target: "app::db", "query took {}ms", 12);
+            warn!(target: "app::db::pool", "hidden by the longer prefix");
+            debug!(target: "app::dbx", "hidden - not a child module");
+        });
+
+        assert_eq!(
+            sink.lines(),
+            vec![
+                "⚠️ [WARN] app::http: slow request",
+                "🐛 [DEBUG] app::db: query took 12ms",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_json_format_with_fields() {
+        let sink = MemorySink::new();
+        let clock = MockClock::starting_at(UNIX_EPOCH + Duration::from_millis(1_700));
+        let logger = Logger::new(Level::Info)
+            .with_clock(Arc::new(clock))
+            .with_sink(sink.clone(), Format::Json);
+
+        with_logger(logger, || {
+            error!(target: "app", user = "bob", attempts = 3; "login failed: {}", "bad \"password\"");
+        });
+
+        let line: serde_json::Value = serde_json::from_str(&sink.lines()[0]).unwrap();
+        assert_eq!(
+            line,
+            serde_json::json!({
+                "timestamp_ms": 1_700,
+                "level": "ERROR",
+                "target": "app",
+                "message": "login failed: bad \"password\"",
+                "fields": { "user": "bob", "attempts": "3" },
+            })
+        );
+        assert_eq!(
+            sink.records()[0].fields[1],
+            ("attempts".to_string(), "3".to_string())
+        );
+    }
+
+    #[test]
+    fn test_filters_spec_and_default_target() {
+        let logger = Logger::new(Level::Info)
+            .with_filters("error, rust_concepts::logger=trace")
+            .unwrap();
+        assert_eq!(logger.level_for("rust_concepts::networking"), Level::Error);
+        assert_eq!(
+            logger.level_for("rust_concepts::logger::tests"),
+            Level::Trace
+        );
+        assert!(Logger::new(Level::Info).with_filters("app=loud").is_err());
+
+        let sink = MemorySink::new();
+        with_logger(logger.with_sink(sink.clone(), Format::Pretty), || {
+            trace!("ใช้ module_path!() เป็น target");
+        });
+        assert_eq!(sink.records()[0].target, "rust_concepts::logger::tests");
+    }
+
+    #[test]
+    fn test_file_sink_appends_lines() {
+        let path =
+            std::env::temp_dir().join(format!("rust_concepts_logger_{}.log", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        for message in ["first", "second"] {
+            let logger = Logger::new(Level::Info)
+                .with_sink(FileSink::append(&path).unwrap(), Format::Pretty);
+            with_logger(logger, || info!(target: "file", "{message}"));
+        }
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(contents, "ℹ️ [INFO] file: first\nℹ️ [INFO] file: second\n");
+    }
+}
@@ -5,10 +5,23 @@
 //! อัปเดตสำหรับ Rust 1.88.0 และ Edition 2024
 
 use clap::{Arg, Command};
-use rust_concepts::{
-    async_await, basics, collections, error_handling, functions, generics, lifetimes, macros,
-    modules, ownership, structs_enums, testing, traits, unsafe_rust,
-};
+use std::io::IsTerminal;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use rust_concepts::advanced_patterns::expression_evaluator;
+use rust_concepts::chapters::{ChapterError, Track, registry};
+use rust_concepts::exercises;
+use rust_concepts::i18n::{self, Locale, t};
+use rust_concepts::interpreter;
+use rust_concepts::lesson;
+use rust_concepts::logger::{self, Format, Level, Logger, StdoutSink};
+use rust_concepts::output::{self, Verbosity, println, verbose};
+use rust_concepts::playground::{self, Playground};
+use rust_concepts::progress;
+use rust_concepts::quiz;
+use rust_concepts::random::{Pcg32, SeedableRng};
+use rust_concepts::task_cli;
 
 fn main() {
     // ตรวจสอบ command line arguments
@@ -20,9 +33,64 @@ fn main() {
             Arg::new("chapter")
                 .short('c')
                 .long("chapter")
-                .value_name("NUMBER")
-                .help("เรียนรู้เฉพาะบทที่กำหนด (1-27)")
-                .value_parser(clap::value_parser!(u8).range(1..=27)),
+                .value_name("NUMBER|SLUG")
+                .help("เรียนรู้เฉพาะบทที่กำหนด ด้วยเลขบทหรือชื่อ เช่น 9 หรือ traits")
+                .value_parser(parse_chapter),
+        )
+        .arg(
+            Arg::new("chapters")
+                .long("chapters")
+                .value_name("LIST")
+                .help("เรียนหลายบทต่อกัน เช่น 1-5 หรือ 1,3,traits")
+                .conflicts_with("chapter")
+                .value_parser(parse_chapters),
+        )
+        .arg(
+            Arg::new("quiz")
+                .long("quiz")
+                .value_name("NUMBER|SLUG")
+                .help("ทำแบบทดสอบท้ายบท เช่น --quiz 2 หรือ --quiz ownership")
+                .conflicts_with_all(["chapter", "chapters"])
+                .value_parser(parse_chapter),
+        )
+        .arg(
+            Arg::new("notes")
+                .long("notes")
+                .value_name("NUMBER|SLUG")
+                .help("อ่านโน้ตสรุปของบท เช่น --notes 2 หรือ --notes ownership")
+                .conflicts_with_all(["chapter", "chapters", "quiz"])
+                .value_parser(parse_chapter),
+        )
+        .arg(
+            Arg::new("play")
+                .long("play")
+                .value_name("FILE")
+                .help("compile และรันโค้ดในไฟล์ .rs ด้วย toolchain เดียวกับโปรเจค (ไม่มี fn main ก็ได้)")
+                .conflicts_with_all(["chapter", "chapters", "quiz", "notes"])
+                .value_parser(clap::value_parser!(PathBuf)),
+        )
+        .arg(
+            Arg::new("calc")
+                .long("calc")
+                .help("เปิดเครื่องคิดเลขแบบโต้ตอบ (Pratt parser จากบทที่ 15)")
+                .conflicts_with_all(["chapter", "chapters", "quiz", "notes", "play"])
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("script")
+                .long("script")
+                .value_name("FILE")
+                .help("รันสคริปต์ภาษา Mini (.mini) บน bytecode VM เช่น src/interpreter/scripts/fibonacci.mini")
+                .conflicts_with_all(["chapter", "chapters", "quiz", "notes", "play", "calc"])
+                .value_parser(clap::value_parser!(PathBuf)),
+        )
+        .arg(
+            Arg::new("play-timeout")
+                .long("play-timeout")
+                .value_name("SECS")
+                .help("เวลาสูงสุดที่ให้โปรแกรมใน --play รัน (ค่าเริ่มต้น 10 วินาที)")
+                .requires("play")
+                .value_parser(clap::value_parser!(u64).range(1..)),
         )
         .arg(
             Arg::new("list")
@@ -38,6 +106,28 @@ fn main() {
                 .help("รัน performance benchmarks")
                 .action(clap::ArgAction::SetTrue),
         )
+        .arg(
+            Arg::new("lang")
+                .long("lang")
+                .value_name("LANG")
+                .help("ภาษาของข้อความ: th หรือ en (หรือตั้ง env RUST_CONCEPTS_LANG)")
+                .value_parser(clap::value_parser!(Locale)),
+        )
+        .arg(
+            Arg::new("quiet")
+                .short('q')
+                .long("quiet")
+                .help("ไม่พิมพ์ผลลัพธ์ของบทเรียน (เหมาะกับ CI)")
+                .conflicts_with("verbose")
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("verbose")
+                .short('v')
+                .long("verbose")
+                .help("พิมพ์รายละเอียดเพิ่ม: log ระดับ debug และเวลาที่ใช้")
+                .action(clap::ArgAction::SetTrue),
+        )
         .arg(
             Arg::new("async")
                 .short('a')
@@ -45,263 +135,230 @@ fn main() {
                 .help("รัน async examples (บทที่ 11)")
                 .action(clap::ArgAction::SetTrue),
         )
+        .subcommand(task_cli::command())
+        .subcommand(exercises::command())
         .get_matches();
 
+    // `task ...` เป็นโปรแกรมจริง - ไม่พิมพ์ banner เพื่อให้ `task export` ส่ง JSON ต่อ pipe ได้
+    if let Some(("task", task_matches)) = matches.subcommand() {
+        if let Err(error) = task_cli::run(task_matches, &mut std::io::stdout()) {
+            eprintln!("❌ {error:#}");
+            std::process::exit(1);
+        }
+        return;
+    }
+    if let Some(("exercise", exercise_matches)) = matches.subcommand() {
+        if let Err(error) = exercises::run(exercise_matches, &mut std::io::stdout()) {
+            eprintln!("❌ {error:#}");
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    if matches.get_flag("quiet") {
+        output::set_verbosity(Verbosity::Quiet);
+    } else if matches.get_flag("verbose") {
+        output::set_verbosity(Verbosity::Verbose);
+        logger::set_logger(Logger::new(Level::Debug).with_sink(StdoutSink, Format::Pretty));
+    }
+
+    // --lang ชนะ env, ไม่ได้ตั้งทั้งคู่ใช้ภาษาไทย
+    if let Some(locale) = matches
+        .get_one::<Locale>("lang")
+        .copied()
+        .or_else(Locale::from_env)
+    {
+        i18n::set_locale(locale);
+    }
+
     // แสดงข้อมูลเวอร์ชั่น Rust
     println!("🦀 Rust Concepts Learning Project - Rust 1.88.0 Edition 🦀");
     println!("📅 Edition 2024 | 🚀 Version 0.2.0");
     println!("{}", "=".repeat(60));
 
     // ตรวจสอบ flags
+    if let Some(&number) = matches.get_one::<u8>("quiz") {
+        let mut rng = Pcg32::from_entropy();
+        // เก็บผลไว้ก่อน - stdin lock ไม่ควรถูกถือค้างตลอด `if let`
+        let result = quiz::run(
+            number,
+            &mut rng,
+            &mut std::io::stdin().lock(),
+            &mut std::io::stdout(),
+            &progress::default_file(),
+        );
+        if let Err(error) = result {
+            eprintln!("❌ {error:#}");
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    if matches.get_flag("calc") {
+        let result =
+            expression_evaluator::run_repl(&mut std::io::stdin().lock(), &mut std::io::stdout());
+        if let Err(error) = result {
+            eprintln!("❌ {error:#}");
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    if let Some(path) = matches.get_one::<PathBuf>("script") {
+        if let Err(error) = interpreter::run_file(path, &mut std::io::stdout()) {
+            eprintln!("❌ {error:#}");
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    if let Some(&number) = matches.get_one::<u8>("notes") {
+        let color = std::env::var_os("NO_COLOR").is_none() && std::io::stdout().is_terminal();
+        if let Some(notes) = lesson::render_notes(number, color) {
+            println!("{notes}");
+        } else {
+            let available: Vec<String> = lesson::chapters()
+                .map(|number| number.to_string())
+                .collect();
+            eprintln!(
+                "❌ บทที่ {number} ยังไม่มีโน้ต (มีแล้ว: บท {})",
+                available.join(", ")
+            );
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    if let Some(path) = matches.get_one::<PathBuf>("play") {
+        let mut runner = Playground::new();
+        if let Some(&seconds) = matches.get_one::<u64>("play-timeout") {
+            runner = runner.with_run_timeout(Duration::from_secs(seconds));
+        }
+        let color = std::env::var_os("NO_COLOR").is_none() && std::io::stdout().is_terminal();
+        match playground::run_file(path, &runner, &mut std::io::stdout(), color) {
+            Ok(outcome) if outcome.is_success() => {}
+            Ok(_) => std::process::exit(1),
+            Err(error) => {
+                eprintln!("❌ {error:#}");
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
+
     if matches.get_flag("list") {
         show_chapter_list();
         return;
     }
 
     if matches.get_flag("benchmark") {
-        println!("🏃‍♂️ รัน benchmarks ด้วยคำสั่ง: cargo bench");
+        rust_concepts::performance::run_microbench();
         return;
     }
 
     if matches.get_flag("async") {
         println!("🔄 รัน async examples...");
-        run_async_chapter();
+        run_chapter(ASYNC_CHAPTER);
         return;
     }
 
     // เรียนรู้ตามบทที่กำหนด หรือทั้งหมด
-    if let Some(chapter) = matches.get_one::<u8>("chapter") {
-        if *chapter == 11 {
-            run_async_chapter();
-        } else {
-            run_specific_chapter(*chapter);
+    let started = Instant::now();
+    if let Some(&number) = matches.get_one::<u8>("chapter") {
+        run_chapter(number);
+    } else if let Some(numbers) = matches.get_one::<Vec<u8>>("chapters") {
+        for &number in numbers {
+            run_chapter(number);
+            println!();
         }
     } else {
         // รันทั้งหมด
-        run_all_examples();
+        registry().run_all();
     }
+    verbose!("⏱️ ใช้เวลาทั้งหมด {:.2?}", started.elapsed());
 }
 
-/// แสดงรายการบทเรียนทั้งหมด
+/// แสดงรายการบทเรียนทั้งหมด จัดกลุ่มตาม track
 fn show_chapter_list() {
     println!("📚 รายการบทเรียน Rust Concepts (เรียงตามลำดับการเรียนรู้):");
+    for track in Track::ALL {
+        println!();
+        println!("{}:", track.heading());
+        for chapter in registry().iter().filter(|chapter| chapter.track == track) {
+            println!(
+                " {:>2}. {} {} ({})",
+                chapter.number,
+                chapter.emoji,
+                chapter.localized_title(),
+                chapter.slug
+            );
+        }
+    }
     println!();
-    println!("🔰 บทเรียนพื้นฐาน (1-10):");
-    println!("  1. 🔥 พื้นฐาน Rust (Variables, Types, Operators)");
-    println!("  2. 🔒 Ownership และ Borrowing");
-    println!("  3. 📊 Structs และ Enums");
-    println!("  4. 🚀 Functions และ Control Flow");
-    println!("  5. 📦 Modules และ Packages");
-    println!("  6. 📚 Collections (Vec, HashMap, etc.)");
-    println!("  7. ⚠️ Error Handling");
-    println!("  8. 🔧 Generics");
-    println!("  9. 🎯 Traits");
-    println!(" 10. ⏰ Lifetimes");
-    println!();
-    println!("🎓 บทเรียนขั้นกลาง (11-14):");
-    println!(" 11. 🔄 Async/Await Programming");
-    println!(" 12. 🎭 Macros");
-    println!(" 13. 🧪 Testing");
-    println!(" 14. ⚡ Unsafe Rust");
-    println!();
-    println!("🚀 บทเรียนขั้นสูง (15-21):");
-    println!(" 15. 🧠 Smart Pointers");
-    println!(" 16. 🔀 Concurrency & Parallelism");
-    println!(" 17. 📁 I/O & File System");
-    println!(" 18. 🌐 Network Programming");
-    println!(" 19. 🕸️ Web Development");
-    println!(" 20. 🗄️ Database Integration");
-    println!(" 21. ⚡ Performance Optimization");
-    println!();
-    println!("🎯 บทเรียนเฉพาะทาง (22-27):");
-    println!(" 22. 🔗 Foreign Function Interface (FFI)");
-    println!(" 23. 🔧 Embedded Programming");
-    println!(" 24. 🚀 DevOps & Deployment");
-    println!(" 25. 🎮 Game Development");
-    println!(" 26. ⛓️ Blockchain Development");
-    println!(" 27. 📱 Mobile Development");
-    println!();
-    println!("💡 ใช้ --chapter <NUMBER> เพื่อเรียนรู้บทเฉพาะ");
+    println!("💡 ใช้ --chapter <NUMBER|SLUG> เพื่อเรียนรู้บทเฉพาะ หรือ --chapters 1-5 เพื่อเรียนหลายบท");
+    let note_chapters: Vec<String> = lesson::chapters()
+        .map(|number| number.to_string())
+        .collect();
+    println!(
+        "💡 ใช้ --notes <NUMBER|SLUG> เพื่ออ่านโน้ตสรุปของบท (มีแล้ว: บท {})",
+        note_chapters.join(", ")
+    );
+    let quiz_chapters: Vec<String> = quiz::chapters().map(|number| number.to_string()).collect();
+    println!(
+        "💡 ใช้ --quiz <NUMBER|SLUG> เพื่อทำแบบทดสอบท้ายบท (มีแล้ว: บท {})",
+        quiz_chapters.join(", ")
+    );
+    println!("💡 ใช้ --play <FILE> เพื่อ compile และรันโค้ดของตัวเอง (--play-timeout กำหนดเวลา)");
     println!("💡 ใช้ --async เพื่อรัน async examples");
     println!("💡 ใช้ --benchmark เพื่อรัน performance tests");
+    println!("💡 ใช้ task <add|list|done|rm|edit|export> เพื่อจัดการ todo list จริงๆ");
+    println!("💡 ใช้ exercise <ID> เพื่อตรวจแบบฝึกหัดที่เขียนไว้ใน exercises/ (exercise --list ดูทุกข้อ)");
 }
 
-/// รันบทเรียนเฉพาะ
-fn run_specific_chapter(chapter: u8) {
-    match chapter {
-        1 => {
-            println!("🔥 === บทที่ 1: พื้นฐาน Rust === 🔥");
-            basics::run_basics_examples();
-        }
-        2 => {
-            println!("🔒 === บทที่ 2: Ownership และ Borrowing === 🔒");
-            ownership::run_ownership_examples();
-        }
-        3 => {
-            println!("📊 === บทที่ 3: Structs และ Enums === 📊");
-            structs_enums::run_structs_enums_examples();
-        }
-        4 => {
-            println!("🚀 === บทที่ 4: Functions และ Control Flow === 🚀");
-            functions::run_functions_examples();
-        }
-        5 => {
-            println!("📦 === บทที่ 5: Modules และ Packages === 📦");
-            modules::run_modules_examples();
-        }
-        6 => {
-            println!("📚 === บทที่ 6: Collections === 📚");
-            collections::run_collections_examples();
-        }
-        7 => {
-            println!("⚠️ === บทที่ 7: Error Handling === ⚠️");
-            error_handling::run_error_handling_examples();
-        }
-        8 => {
-            println!("🔧 === บทที่ 8: Generics === 🔧");
-            generics::run_generics_examples();
-        }
-        9 => {
-            println!("🎯 === บทที่ 9: Traits === 🎯");
-            traits::run_traits_examples();
-        }
-        10 => {
-            println!("⏰ === บทที่ 10: Lifetimes === ⏰");
-            lifetimes::run_lifetimes_examples();
-        }
-        12 => {
-            println!("🎭 === บทที่ 12: Macros === 🎭");
-            macros::run_macros_examples();
-        }
-        13 => {
-            println!("🧪 === บทที่ 13: Testing === 🧪");
-            testing::run_testing_examples();
-        }
-        14 => {
-            println!("⚡ === บทที่ 14: Unsafe Rust === ⚡");
-            unsafe_rust::run_unsafe_examples();
-        }
-        15 => {
-            println!("🧠 === บทที่ 15: Smart Pointers === 🧠");
-            println!("🎯 เรียนรู้ Box, Rc, Arc, RefCell และ Smart Pointers อื่นๆ!");
-            rust_concepts::smart_pointers::run_smart_pointers_examples();
-        }
-        16 => {
-            println!("🔀 === บทที่ 16: Concurrency === 🔀");
-            println!("⚡ เรียนรู้ Threads, Channels และ Parallel Programming!");
-            rust_concepts::concurrency::run_concurrency_examples();
-        }
-        17 => {
-            println!("📁 === บทที่ 17: I/O & File System === 📁");
-            println!("📂 เรียนรู้การจัดการไฟล์และ I/O Operations!");
-            rust_concepts::io_filesystem::run_io_filesystem_examples();
-        }
-        18 => {
-            println!("🌐 === บทที่ 18: Network Programming === 🌐");
-            println!("📡 เรียนรู้การเขียนโปรแกรมเครือข่าย TCP/UDP!");
-            rust_concepts::networking::run_networking_examples();
-        }
-        19 => {
-            println!("🕸️ === บทที่ 19: Web Development === 🕸️");
-            println!("🌍 เรียนรู้การสร้าง Web Applications และ APIs!");
-            rust_concepts::web_development::run_web_development_examples();
-        }
-        20 => {
-            println!("🗄️ === บทที่ 20: Database Integration === 🗄️");
-            println!("🗃️ เรียนรู้การเชื่อมต่อฐานข้อมูลและ ORM!");
-            rust_concepts::database::run_database_examples();
-        }
-        21 => {
-            println!("⚡ === บทที่ 21: Performance Optimization === ⚡");
-            println!("🚀 เรียนรู้เทคนิคเพิ่มประสิทธิภาพแบบเทพ!");
-            rust_concepts::performance::run_performance_examples();
-        }
-        22 => {
-            println!("🔗 === บทที่ 22: Foreign Function Interface (FFI) === 🔗");
-            println!("🌉 เรียนรู้การเชื่อมต่อกับภาษาอื่น (C/C++)!");
-            rust_concepts::ffi::run_ffi_examples();
-        }
-        23 => {
-            println!("🔧 === บทที่ 23: Embedded Programming === 🔧");
-            println!("🤖 เรียนรู้การเขียนโปรแกรมสำหรับ Microcontrollers!");
-            rust_concepts::embedded::run_embedded_examples();
-        }
-        24 => {
-            println!("🚀 === บทที่ 24: DevOps & Deployment === 🚀");
-            println!("🛠️ เรียนรู้ DevOps และ Deployment แบบโปร!");
-            rust_concepts::devops::run_devops_examples();
-        }
-        25 => {
-            println!("🎮 === บทที่ 25: Game Development === 🎮");
-            println!("🕹️ เรียนรู้การพัฒนาเกมด้วย Rust!");
-            rust_concepts::game_development::run_game_development_examples();
-        }
-        26 => {
-            println!("⛓️ === บทที่ 26: Blockchain Development === ⛓️");
-            println!("🔗 เรียนรู้เทคโนโลยี Blockchain และ Smart Contracts!");
-            rust_concepts::blockchain::run_blockchain_examples();
-        }
-        27 => {
-            println!("📱 === บทที่ 27: Mobile Development === 📱");
-            println!("📲 เรียนรู้การพัฒนาแอปมือถือด้วย Rust!");
-            rust_concepts::mobile_development::run_mobile_development_examples();
-        }
-        _ => unreachable!("Invalid chapter number"),
-    }
+/// บท async/await - `--async` เป็นทางลัดของ `--chapter 11`
+const ASYNC_CHAPTER: u8 = 11;
 
-    println!("\n✅ เสร็จสิ้นบทที่ {chapter}!");
+/// แปลง `--chapter` เป็นเลขบท (ตรวจกับ registry ตั้งแต่ตอน parse)
+fn parse_chapter(value: &str) -> Result<u8, String> {
+    registry()
+        .find(value)
+        .map(|chapter| chapter.number)
+        .ok_or_else(|| unknown_chapter(value))
 }
 
-/// รัน async chapter (บทที่ 11)
-#[tokio::main]
-async fn run_async_chapter() {
-    println!("🔄 === บทที่ 11: Async/Await Programming === 🔄");
-    async_await::run_async_examples().await;
-    println!("\n✅ เสร็จสิ้นบทที่ 11!");
+/// แปลง `--chapters` เป็นรายการเลขบท
+fn parse_chapters(value: &str) -> Result<Vec<u8>, String> {
+    registry()
+        .select(value)
+        .map(|chapters| chapters.iter().map(|chapter| chapter.number).collect())
+        .map_err(|error| match error {
+            ChapterError::Unknown(name) => unknown_chapter(&name),
+            ChapterError::EmptyRange(_) => error.to_string(),
+        })
 }
 
-/// รันตัวอย่างทั้งหมด (ตามลำดับการเรียนรู้)
-fn run_all_examples() {
-    println!("🔥 === บทที่ 1: พื้นฐาน Rust === 🔥");
-    basics::run_basics_examples();
-
-    println!("\n\n🔒 === บทที่ 2: Ownership และ Borrowing === 🔒");
-    ownership::run_ownership_examples();
-
-    println!("\n\n📊 === บทที่ 3: Structs และ Enums === 📊");
-    structs_enums::run_structs_enums_examples();
-
-    println!("\n\n🚀 === บทที่ 4: Functions และ Control Flow === 🚀");
-    functions::run_functions_examples();
-
-    println!("\n\n📦 === บทที่ 5: Modules และ Packages === 📦");
-    modules::run_modules_examples();
-
-    println!("\n\n📚 === บทที่ 6: Collections === 📚");
-    collections::run_collections_examples();
-
-    println!("\n\n⚠️ === บทที่ 7: Error Handling === ⚠️");
-    error_handling::run_error_handling_examples();
-
-    println!("\n\n🔧 === บทที่ 8: Generics === 🔧");
-    generics::run_generics_examples();
-
-    println!("\n\n🎯 === บทที่ 9: Traits === 🎯");
-    traits::run_traits_examples();
-
-    println!("\n\n⏰ === บทที่ 10: Lifetimes === ⏰");
-    lifetimes::run_lifetimes_examples();
-
-    println!("\n\n🎭 === บทที่ 12: Macros === 🎭");
-    macros::run_macros_examples();
-
-    println!("\n\n🧪 === บทที่ 13: Testing === 🧪");
-    testing::run_testing_examples();
-
-    println!("\n\n⚡ === บทที่ 14: Unsafe Rust === ⚡");
-    unsafe_rust::run_unsafe_examples();
+/// ข้อความเมื่อไม่รู้จักบท พร้อมชื่อที่ใกล้เคียงถ้ามี เช่น `trats` → "หมายถึง `traits` (9) ใช่ไหม?"
+fn unknown_chapter(value: &str) -> String {
+    let suggestions: Vec<String> = registry()
+        .suggest(value)
+        .iter()
+        .map(|chapter| format!("`{}` ({})", chapter.slug, chapter.number))
+        .collect();
+    if suggestions.is_empty() {
+        format!("ไม่พบบท `{value}` - ดูรายการด้วย --list")
+    } else {
+        format!(
+            "ไม่พบบท `{value}` - หมายถึง {} ใช่ไหม? ดูรายการด้วย --list",
+            suggestions.join(" หรือ ")
+        )
+    }
+}
 
-    println!("\n\n🎊 สำเร็จ! คุณได้เรียนรู้แนวคิดสำคัญของ Rust ครบถ้วนแล้ว! 🎊");
-    println!("🚀 ตอนนี้คุณพร้อมที่จะสร้างแอปพลิเคชัน Rust ของตัวเองแล้ว!");
-    println!("\n💡 หมายเหตุ: บทที่ 11 (Async/Await) ต้องรันแยกด้วย --async หรือ --chapter 11");
+/// รันบทเดียวแล้วบอกว่าจบ
+fn run_chapter(number: u8) {
+    let chapter = registry()
+        .get(number)
+        .expect("chapter numbers are validated while parsing arguments");
+    chapter.run();
+    println!("\n✅ {}", t!("chapter.done", chapter = chapter.number));
 }
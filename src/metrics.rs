@@ -0,0 +1,537 @@
+//! 📈 Metrics - ตัวนับ/มาตรวัด/ฮิสโตแกรมแบบ Prometheus ที่ใช้ร่วมกันทั้ง crate
+//!
+//! มี metric 3 ชนิด ทุกชนิดเป็น atomic จึงแชร์ข้าม thread ได้โดยไม่ต้องล็อก:
+//! - 🔢 `Counter` - นับขึ้นอย่างเดียว เช่น จำนวน request
+//! - 🌡️ `Gauge` - ขึ้นลงได้ เช่น จำนวน connection ที่เปิดอยู่
+//! - 📊 `Histogram` - นับค่าลง bucket ตามขอบบน เช่น latency
+//!
+//! `Registry` เก็บ metric ตามชื่อ + labels และ `render()` ออกมาเป็น
+//! Prometheus text format (version 0.0.4) ที่ scraper อ่านได้ทันที
+//! ส่วน `registry()` คือ registry กลางของทั้งโปรแกรม
+//!
+//! ```
+//! use rust_concepts::metrics::Registry;
+//!
+//! let registry = Registry::new();
+//! let requests = registry.counter("requests_total", "Total requests", &[("method", "GET")]);
+//! requests.inc();
+//! assert!(registry.render().contains(r#"requests_total{method="GET"} 1"#));
+//! ```
+//!
+//! 🌐 web server ในบทที่ 17 เปิด endpoint `/metrics` ได้ด้วย `WebServer::expose_metrics`
+
+use std::collections::BTreeMap;
+use std::fmt::{self, Write as _};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Duration;
+
+/// `Content-Type` ของ Prometheus text exposition format
+pub const CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+/// ขอบบนของ bucket เริ่มต้น (วินาที) - ชุดเดียวกับ client library ของ Prometheus
+pub const DEFAULT_BUCKETS: [f64; 11] = [
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+/// 🔢 ตัวนับที่เพิ่มขึ้นอย่างเดียว
+#[derive(Debug, Default)]
+pub struct Counter {
+    value: AtomicU64,
+}
+
+impl Counter {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            value: AtomicU64::new(0),
+        }
+    }
+
+    pub fn inc(&self) {
+        self.inc_by(1);
+    }
+
+    pub fn inc_by(&self, amount: u64) {
+        self.value.fetch_add(amount, Ordering::Relaxed);
+    }
+
+    #[must_use]
+    pub fn get(&self) -> u64 {
+        self.value.load(Ordering::Relaxed)
+    }
+}
+
+/// 🌡️ มาตรวัดค่า `f64` ที่ขึ้นลงได้ (เก็บเป็นบิตใน `AtomicU64`)
+#[derive(Debug, Default)]
+pub struct Gauge {
+    bits: AtomicU64,
+}
+
+impl Gauge {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            bits: AtomicU64::new(0),
+        }
+    }
+
+    pub fn set(&self, value: f64) {
+        self.bits.store(value.to_bits(), Ordering::Relaxed);
+    }
+
+    pub fn inc(&self) {
+        self.add(1.0);
+    }
+
+    pub fn dec(&self) {
+        self.add(-1.0);
+    }
+
+    pub fn add(&self, delta: f64) {
+        add_f64(&self.bits, delta);
+    }
+
+    #[must_use]
+    pub fn get(&self) -> f64 {
+        f64::from_bits(self.bits.load(Ordering::Relaxed))
+    }
+}
+
+/// 📊 ฮิสโตแกรมแบบ bucket สะสม (cumulative) ตามแบบ Prometheus
+#[derive(Debug)]
+pub struct Histogram {
+    /// ขอบบนของแต่ละ bucket เรียงจากน้อยไปมาก (ไม่รวม `+Inf`)
+    bounds: Vec<f64>,
+    /// จำนวนค่าที่ตกใน bucket นั้นพอดี (ยังไม่สะสม) - ตัวสุดท้ายคือ `+Inf`
+    buckets: Vec<AtomicU64>,
+    sum_bits: AtomicU64,
+    count: AtomicU64,
+}
+
+impl Histogram {
+    /// สร้างฮิสโตแกรมจากขอบบนของ bucket (จะถูกเรียงและตัดค่าซ้ำ/`NaN` ให้)
+    #[must_use]
+    pub fn new(bounds: &[f64]) -> Self {
+        let mut bounds: Vec<f64> = bounds
+            .iter()
+            .copied()
+            .filter(|bound| bound.is_finite())
+            .collect();
+        bounds.sort_by(f64::total_cmp);
+        bounds.dedup();
+        let buckets = (0..=bounds.len()).map(|_| AtomicU64::new(0)).collect();
+        Self {
+            bounds,
+            buckets,
+            sum_bits: AtomicU64::new(0),
+            count: AtomicU64::new(0),
+        }
+    }
+
+    pub fn observe(&self, value: f64) {
+        let index = self.bounds.partition_point(|&bound| bound < value);
+        self.buckets[index].fetch_add(1, Ordering::Relaxed);
+        add_f64(&self.sum_bits, value);
+        self.count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// บันทึกระยะเวลาเป็นวินาที ⏱️
+    pub fn observe_duration(&self, duration: Duration) {
+        self.observe(duration.as_secs_f64());
+    }
+
+    #[must_use]
+    pub fn count(&self) -> u64 {
+        self.count.load(Ordering::Relaxed)
+    }
+
+    #[must_use]
+    pub fn sum(&self) -> f64 {
+        f64::from_bits(self.sum_bits.load(Ordering::Relaxed))
+    }
+
+    /// คู่ (ขอบบน, จำนวนสะสม) ของทุก bucket ปิดท้ายด้วย `+Inf`
+    #[must_use]
+    pub fn cumulative_buckets(&self) -> Vec<(f64, u64)> {
+        let mut total = 0;
+        self.bounds
+            .iter()
+            .copied()
+            .chain(std::iter::once(f64::INFINITY))
+            .zip(&self.buckets)
+            .map(|(bound, bucket)| {
+                total += bucket.load(Ordering::Relaxed);
+                (bound, total)
+            })
+            .collect()
+    }
+}
+
+impl Default for Histogram {
+    fn default() -> Self {
+        Self::new(&DEFAULT_BUCKETS)
+    }
+}
+
+fn add_f64(bits: &AtomicU64, delta: f64) {
+    let _ = bits.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |current| {
+        Some((f64::from_bits(current) + delta).to_bits())
+    });
+}
+
+/// ชนิดของ metric ตามที่เขียนในบรรทัด `# TYPE`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetricKind {
+    Counter,
+    Gauge,
+    Histogram,
+}
+
+impl fmt::Display for MetricKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Counter => "counter",
+            Self::Gauge => "gauge",
+            Self::Histogram => "histogram",
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Series {
+    Counter(Arc<Counter>),
+    Gauge(Arc<Gauge>),
+    Histogram(Arc<Histogram>),
+}
+
+impl Series {
+    const fn kind(&self) -> MetricKind {
+        match self {
+            Self::Counter(_) => MetricKind::Counter,
+            Self::Gauge(_) => MetricKind::Gauge,
+            Self::Histogram(_) => MetricKind::Histogram,
+        }
+    }
+}
+
+type Labels = Vec<(String, String)>;
+
+/// metric ชื่อเดียวกันทุกชุด labels
+#[derive(Debug)]
+struct Family {
+    help: String,
+    kind: MetricKind,
+    series: BTreeMap<Labels, Series>,
+}
+
+/// 🗂️ ที่เก็บ metric ทั้งหมด - clone แล้วยังชี้ไปที่ข้อมูลชุดเดิม
+///
+/// เรียก `counter`/`gauge`/`histogram` ซ้ำด้วยชื่อและ labels เดิมจะได้ metric ตัวเดิมกลับมา
+/// จึงเรียกจากจุดที่ใช้งานได้เลยโดยไม่ต้องเก็บ handle ไว้เอง
+#[derive(Debug, Clone, Default)]
+pub struct Registry {
+    families: Arc<Mutex<BTreeMap<String, Family>>>,
+}
+
+impl Registry {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// # Panics
+    ///
+    /// ถ้าชื่อไม่ถูกต้องตามกฎของ Prometheus หรือเคยลงทะเบียนชื่อนี้เป็นชนิดอื่นไว้แล้ว
+    #[must_use]
+    pub fn counter(&self, name: &str, help: &str, labels: &[(&str, &str)]) -> Arc<Counter> {
+        match self.series(name, help, labels, MetricKind::Counter) {
+            Series::Counter(counter) => counter,
+            _ => unreachable!("kind checked by Registry::series"),
+        }
+    }
+
+    /// # Panics
+    ///
+    /// ถ้าชื่อไม่ถูกต้องตามกฎของ Prometheus หรือเคยลงทะเบียนชื่อนี้เป็นชนิดอื่นไว้แล้ว
+    #[must_use]
+    pub fn gauge(&self, name: &str, help: &str, labels: &[(&str, &str)]) -> Arc<Gauge> {
+        match self.series(name, help, labels, MetricKind::Gauge) {
+            Series::Gauge(gauge) => gauge,
+            _ => unreachable!("kind checked by Registry::series"),
+        }
+    }
+
+    /// ฮิสโตแกรมที่ใช้ `DEFAULT_BUCKETS` - ใช้ `histogram_with_buckets` ถ้าต้องการขอบอื่น
+    ///
+    /// # Panics
+    ///
+    /// ถ้าชื่อไม่ถูกต้องตามกฎของ Prometheus หรือเคยลงทะเบียนชื่อนี้เป็นชนิดอื่นไว้แล้ว
+    #[must_use]
+    pub fn histogram(&self, name: &str, help: &str, labels: &[(&str, &str)]) -> Arc<Histogram> {
+        self.histogram_with_buckets(name, help, labels, &DEFAULT_BUCKETS)
+    }
+
+    /// `buckets` มีผลเฉพาะตอนสร้างครั้งแรกของชุด labels นั้น
+    ///
+    /// # Panics
+    ///
+    /// ถ้าชื่อไม่ถูกต้องตามกฎของ Prometheus หรือเคยลงทะเบียนชื่อนี้เป็นชนิดอื่นไว้แล้ว
+    #[must_use]
+    pub fn histogram_with_buckets(
+        &self,
+        name: &str,
+        help: &str,
+        labels: &[(&str, &str)],
+        buckets: &[f64],
+    ) -> Arc<Histogram> {
+        let series = self.series_with(name, help, labels, MetricKind::Histogram, || {
+            Series::Histogram(Arc::new(Histogram::new(buckets)))
+        });
+        match series {
+            Series::Histogram(histogram) => histogram,
+            _ => unreachable!("kind checked by Registry::series"),
+        }
+    }
+
+    fn series(&self, name: &str, help: &str, labels: &[(&str, &str)], kind: MetricKind) -> Series {
+        self.series_with(name, help, labels, kind, || match kind {
+            MetricKind::Counter => Series::Counter(Arc::default()),
+            MetricKind::Gauge => Series::Gauge(Arc::default()),
+            MetricKind::Histogram => Series::Histogram(Arc::default()),
+        })
+    }
+
+    fn series_with(
+        &self,
+        name: &str,
+        help: &str,
+        labels: &[(&str, &str)],
+        kind: MetricKind,
+        create: impl FnOnce() -> Series,
+    ) -> Series {
+        assert!(is_valid_name(name), "invalid metric name: {name:?}");
+        for (label, _) in labels {
+            assert!(
+                is_valid_name(label) && !label.contains(':') && *label != "le",
+                "invalid label name {label:?} on metric {name:?}"
+            );
+        }
+
+        let mut key: Labels = labels
+            .iter()
+            .map(|(label, value)| ((*label).to_string(), (*value).to_string()))
+            .collect();
+        key.sort();
+
+        let mut families = self
+            .families
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let family = families.entry(name.to_string()).or_insert_with(|| Family {
+            help: help.to_string(),
+            kind,
+            series: BTreeMap::new(),
+        });
+        assert!(
+            family.kind == kind,
+            "metric {name:?} is already registered as a {}, not a {kind}",
+            family.kind
+        );
+        let series = family.series.entry(key).or_insert_with(create).clone();
+        drop(families);
+        debug_assert_eq!(series.kind(), kind);
+        series
+    }
+
+    /// เขียน metric ทั้งหมดเป็น Prometheus text format เรียงตามชื่อ 📤
+    #[must_use]
+    pub fn render(&self) -> String {
+        let families = self
+            .families
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let mut out = String::new();
+        for (name, family) in families.iter() {
+            let _ = writeln!(out, "# HELP {name} {}", escape_help(&family.help));
+            let _ = writeln!(out, "# TYPE {name} {}", family.kind);
+            for (labels, series) in &family.series {
+                match series {
+                    Series::Counter(counter) => {
+                        let _ = writeln!(
+                            out,
+                            "{name}{} {}",
+                            format_labels(labels, None),
+                            counter.get()
+                        );
+                    }
+                    Series::Gauge(gauge) => {
+                        let _ = writeln!(
+                            out,
+                            "{name}{} {}",
+                            format_labels(labels, None),
+                            format_value(gauge.get())
+                        );
+                    }
+                    Series::Histogram(histogram) => {
+                        for (bound, count) in histogram.cumulative_buckets() {
+                            let le = format_value(bound);
+                            let _ = writeln!(
+                                out,
+                                "{name}_bucket{} {count}",
+                                format_labels(labels, Some(&le))
+                            );
+                        }
+                        let plain = format_labels(labels, None);
+                        let _ =
+                            writeln!(out, "{name}_sum{plain} {}", format_value(histogram.sum()));
+                        let _ = writeln!(out, "{name}_count{plain} {}", histogram.count());
+                    }
+                }
+            }
+        }
+        drop(families);
+        out
+    }
+}
+
+/// registry กลางของทั้งโปรแกรม 🌍
+#[must_use]
+pub fn registry() -> &'static Registry {
+    static REGISTRY: OnceLock<Registry> = OnceLock::new();
+    REGISTRY.get_or_init(Registry::new)
+}
+
+fn is_valid_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || first == '_' || first == ':')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == ':')
+}
+
+fn format_labels(labels: &[(String, String)], le: Option<&str>) -> String {
+    if labels.is_empty() && le.is_none() {
+        return String::new();
+    }
+    let pairs: Vec<String> = labels
+        .iter()
+        .map(|(label, value)| (label.as_str(), value.as_str()))
+        .chain(le.map(|le| ("le", le)))
+        .map(|(label, value)| format!("{label}=\"{}\"", escape_label_value(value)))
+        .collect();
+    format!("{{{}}}", pairs.join(","))
+}
+
+fn format_value(value: f64) -> String {
+    if value.is_nan() {
+        "NaN".to_string()
+    } else if value.is_infinite() {
+        if value > 0.0 { "+Inf" } else { "-Inf" }.to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+fn escape_help(help: &str) -> String {
+    help.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn escape_label_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counter_and_gauge_render() {
+        let registry = Registry::new();
+        let hits = registry.counter("hits_total", "Cache hits", &[("cache", "users")]);
+        hits.inc_by(3);
+        registry
+            .counter("hits_total", "Cache hits", &[("cache", "users")])
+            .inc();
+        registry.gauge("queue_depth", "Jobs waiting", &[]).set(2.5);
+
+        assert_eq!(hits.get(), 4);
+        assert_eq!(
+            registry.render(),
+            "# HELP hits_total Cache hits\n\
+             # TYPE hits_total counter\n\
+             hits_total{cache=\"users\"} 4\n\
+             # HELP queue_depth Jobs waiting\n\
+             # TYPE queue_depth gauge\n\
+             queue_depth 2.5\n"
+        );
+    }
+
+    #[test]
+    fn test_histogram_buckets_are_cumulative() {
+        let registry = Registry::new();
+        let latency =
+            registry.histogram_with_buckets("latency_seconds", "Latency", &[], &[0.5, 0.1, 1.0]);
+        for value in [0.05, 0.1, 0.3, 2.0] {
+            latency.observe(value);
+        }
+
+        assert_eq!(
+            latency.cumulative_buckets(),
+            vec![(0.1, 2), (0.5, 3), (1.0, 3), (f64::INFINITY, 4)]
+        );
+        let text = registry.render();
+        assert!(
+            text.contains("latency_seconds_bucket{le=\"0.1\"} 2\n"),
+            "{text}"
+        );
+        assert!(
+            text.contains("latency_seconds_bucket{le=\"+Inf\"} 4\n"),
+            "{text}"
+        );
+        assert!(text.contains("latency_seconds_sum 2.45\n"), "{text}");
+        assert!(text.contains("latency_seconds_count 4\n"), "{text}");
+    }
+
+    #[test]
+    fn test_label_values_are_escaped() {
+        let registry = Registry::new();
+        registry
+            .counter("errors_total", "Errors\nby path", &[("path", "/a\"b\\c")])
+            .inc();
+        let text = registry.render();
+        assert!(text.contains("# HELP errors_total Errors\\nby path\n"));
+        assert!(text.contains(r#"errors_total{path="/a\"b\\c"} 1"#));
+    }
+
+    #[test]
+    #[should_panic(expected = "already registered as a counter")]
+    fn test_kind_conflict_panics() {
+        let registry = Registry::new();
+        let _ = registry.counter("jobs", "Jobs", &[]);
+        let _ = registry.gauge("jobs", "Jobs", &[]);
+    }
+
+    #[test]
+    fn test_gauge_concurrent_updates() {
+        let gauge = Arc::new(Gauge::new());
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let gauge = Arc::clone(&gauge);
+                std::thread::spawn(move || {
+                    for _ in 0..1000 {
+                        gauge.inc();
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        gauge.dec();
+        assert!((gauge.get() - 3999.0).abs() < f64::EPSILON);
+    }
+}
@@ -0,0 +1,277 @@
+//! 🖨️ Output - เลือกได้ว่าข้อความของบทเรียนจะไปไหน: จอ, ทิ้ง หรือเก็บไว้ตรวจใน tests
+//!
+//! ทุกบทยังเขียน `println!`/`print!` เหมือนเดิม แต่ใน crate นี้สอง macro นั้นถูกแทนด้วยตัวใน
+//! `lib.rs` ที่ส่งข้อความต่อให้ `Printer` ปัจจุบัน - ไม่ต้องแก้ทุกฟังก์ชันให้รับ printer เพิ่ม:
+//!
+//! ```
+//! use rust_concepts::output::{self, Capturing};
+//!
+//! let capture = Capturing::new();
+//! output::with_printer(capture.clone(), rust_concepts::ownership::run_ownership_examples);
+//! assert!(capture.contents().contains("Borrowing"));
+//! ```
+//!
+//! - 🖥️ `Console` - ออก stdout (ค่าเริ่มต้น)
+//! - 🔇 `Silent` - ทิ้งทั้งหมด ใช้กับ `--quiet` และ CI
+//! - 📸 `Capturing` - เก็บเป็น `String` สำหรับ snapshot test
+//! - 🎚️ `Verbosity` - `--quiet` / ปกติ / `--verbose`; ข้อความเสริมใช้ `verbose!` ซึ่งพิมพ์เฉพาะตอน verbose
+//!
+//! เหมือน `logger`: `set_printer` ใช้กับทั้งโปรแกรม ส่วน `with_printer` เปลี่ยนเฉพาะ thread ปัจจุบัน
+//! (thread ที่บทเรียน spawn เองจะใช้ printer กลาง) ⚠️
+
+use std::cell::RefCell;
+use std::fmt;
+use std::io::{self, Write as _};
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::{Arc, Mutex, PoisonError, RwLock};
+
+/// `println!` ที่ส่งผ่าน `Printer` ปัจจุบัน
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __output_println {
+    () => {
+        $crate::output::print_fmt(format_args!("\n"))
+    };
+    ($($arg:tt)+) => {
+        $crate::output::print_fmt(format_args!("{}\n", format_args!($($arg)+)))
+    };
+}
+
+/// `print!` ที่ส่งผ่าน `Printer` ปัจจุบัน
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __output_print {
+    ($($arg:tt)+) => {
+        $crate::output::print_fmt(format_args!($($arg)+))
+    };
+}
+
+/// พิมพ์เฉพาะเมื่อเปิด `--verbose`
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __output_verbose {
+    ($($arg:tt)+) => {
+        if $crate::output::verbosity() >= $crate::output::Verbosity::Verbose {
+            $crate::__output_println!($($arg)+);
+        }
+    };
+}
+
+pub use crate::__output_print as print;
+pub use crate::__output_println as println;
+pub use crate::__output_verbose as verbose;
+
+/// 🎚️ ปริมาณข้อความที่อยากเห็น
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Verbosity {
+    Quiet,
+    #[default]
+    Normal,
+    Verbose,
+}
+
+impl Verbosity {
+    const fn from_u8(value: u8) -> Self {
+        match value {
+            0 => Self::Quiet,
+            2 => Self::Verbose,
+            _ => Self::Normal,
+        }
+    }
+
+    const fn to_u8(self) -> u8 {
+        match self {
+            Self::Quiet => 0,
+            Self::Normal => 1,
+            Self::Verbose => 2,
+        }
+    }
+}
+
+/// 🖨️ ปลายทางของข้อความบทเรียน
+pub trait Printer: Send + Sync {
+    /// เขียน `text` ตามที่ได้มา (`println!` ส่งมาพร้อม `\n` แล้ว)
+    fn write_str(&self, text: &str);
+}
+
+/// 🖥️ พิมพ์ออก stdout
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Console;
+
+impl Printer for Console {
+    fn write_str(&self, text: &str) {
+        // ต่างจาก std::println! ตรงที่ไม่ panic เมื่อ pipe ปิด (เช่น `| head`)
+        let _ = io::stdout().lock().write_all(text.as_bytes());
+    }
+}
+
+/// 🔇 ทิ้งทุกข้อความ
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Silent;
+
+impl Printer for Silent {
+    fn write_str(&self, _text: &str) {}
+}
+
+/// 📸 เก็บข้อความไว้ - clone แล้วใช้ร่วมกันได้ (แบบเดียวกับ `logger::MemorySink`)
+#[derive(Debug, Clone, Default)]
+pub struct Capturing {
+    buffer: Arc<Mutex<String>>,
+}
+
+impl Capturing {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// ข้อความทั้งหมดที่เก็บได้
+    #[must_use]
+    pub fn contents(&self) -> String {
+        self.buffer
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    #[must_use]
+    pub fn lines(&self) -> Vec<String> {
+        self.contents().lines().map(str::to_string).collect()
+    }
+
+    pub fn clear(&self) {
+        self.buffer
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+    }
+}
+
+impl Printer for Capturing {
+    fn write_str(&self, text: &str) {
+        self.buffer
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push_str(text);
+    }
+}
+
+static GLOBAL: RwLock<Option<Arc<dyn Printer>>> = RwLock::new(None);
+static VERBOSITY: AtomicU8 = AtomicU8::new(1);
+
+thread_local! {
+    static SCOPED: RefCell<Option<Arc<dyn Printer>>> = const { RefCell::new(None) };
+}
+
+/// ตั้ง printer กลางของทั้งโปรแกรม
+pub fn set_printer(printer: impl Printer + 'static) {
+    *GLOBAL.write().unwrap_or_else(PoisonError::into_inner) = Some(Arc::new(printer));
+}
+
+/// printer ที่ใช้อยู่บน thread นี้: ตัวจาก `with_printer` > `set_printer` > `Console`
+#[must_use]
+pub fn current() -> Arc<dyn Printer> {
+    if let Some(scoped) = SCOPED.with(|scoped| scoped.borrow().clone()) {
+        return scoped;
+    }
+    GLOBAL
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone()
+        .unwrap_or_else(|| Arc::new(Console))
+}
+
+/// รัน `f` โดยใช้ `printer` เฉพาะบน thread นี้ แล้วคืนค่าเดิมเมื่อจบ (แม้ `f` จะ panic)
+pub fn with_printer<R>(printer: impl Printer + 'static, f: impl FnOnce() -> R) -> R {
+    struct Restore(Option<Arc<dyn Printer>>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            let previous = self.0.take();
+            SCOPED.with(|scoped| *scoped.borrow_mut() = previous);
+        }
+    }
+
+    let previous = SCOPED.with(|scoped| scoped.borrow_mut().replace(Arc::new(printer)));
+    let _restore = Restore(previous);
+    f()
+}
+
+/// ตั้งระดับข้อความ - `Quiet` เปลี่ยน printer กลางเป็น `Silent` ให้ด้วย
+pub fn set_verbosity(verbosity: Verbosity) {
+    VERBOSITY.store(verbosity.to_u8(), Ordering::Relaxed);
+    if verbosity == Verbosity::Quiet {
+        set_printer(Silent);
+    }
+}
+
+#[must_use]
+pub fn verbosity() -> Verbosity {
+    Verbosity::from_u8(VERBOSITY.load(Ordering::Relaxed))
+}
+
+/// ใช้โดย `println!`/`print!` - จัดรูปแบบแล้วส่งให้ printer ปัจจุบัน
+#[doc(hidden)]
+pub fn print_fmt(args: fmt::Arguments<'_>) {
+    let printer = current();
+    match args.as_str() {
+        Some(text) => printer.write_str(text),
+        None => printer.write_str(&args.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // ไม่ใช้ `super::*` เพราะจะดึง `println`/`print` มาชนกับ macro ของ crate
+    use super::{Capturing, Silent, Verbosity, verbose, verbosity, with_printer};
+    use crate::i18n::{self, Locale};
+
+    #[test]
+    fn test_capturing_collects_chapter_output() {
+        let capture = Capturing::new();
+        i18n::with_locale(Locale::En, || {
+            with_printer(capture.clone(), crate::ownership::run_ownership_examples);
+        });
+        let headings: Vec<String> = capture
+            .lines()
+            .into_iter()
+            .filter(|line| line.starts_with("   ") && !line.starts_with("    "))
+            .collect();
+        assert_eq!(
+            headings,
+            [
+                "   🏠 Ownership Basics",
+                "   🤝 Borrowing and References",
+                "   ✂️ Slices",
+                "   🔬 Ownership Timeline (moves, borrows and drops step by step)",
+                "   🎯 Practice Examples",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_silent_and_nested_scopes() {
+        let outer = Capturing::new();
+        with_printer(outer.clone(), || {
+            println!("before");
+            with_printer(Silent, || {
+                println!("dropped");
+                crate::basics::run_basics_examples();
+            });
+            print!("after {}", 1);
+            println!();
+        });
+        assert_eq!(outer.contents(), "before\nafter 1\n");
+    }
+
+    #[test]
+    fn test_verbose_macro_respects_level() {
+        let capture = Capturing::new();
+        with_printer(capture.clone(), || {
+            verbose!("hidden at {:?}", verbosity());
+        });
+        assert_eq!(verbosity(), Verbosity::Normal);
+        assert!(capture.contents().is_empty());
+        assert!(Verbosity::Quiet < Verbosity::Normal && Verbosity::Normal < Verbosity::Verbose);
+    }
+}
@@ -0,0 +1,400 @@
+//! 🩺 อ่าน diagnostic ของ compiler จาก `cargo build --message-format=json` แล้วพิมพ์ให้อ่านง่าย
+//!
+//! cargo พิมพ์ JSON บรรทัดละหนึ่ง message - ที่ใช้มีสามแบบ
+//! - `compiler-message` - error/warning พร้อมตำแหน่ง (`spans`) และคำแนะนำ (`children`)
+//! - `compiler-artifact` - มี `executable` คือ path ของ binary ที่ build ได้
+//! - `build-finished` - `success` บอกว่า build ผ่านหรือไม่
+//!
+//! ตำแหน่งจะถูกเลื่อนกลับเป็นบรรทัดของ snippet ต้นฉบับ (ตอนห่อด้วย `fn main` จะเกินมาหนึ่งบรรทัด)
+//! และ error ที่พบบ่อยมีคำอธิบายภาษาไทยต่อท้าย (`hint`)
+
+use std::fmt::Write as _;
+use std::path::PathBuf;
+
+use serde::Deserialize;
+
+use crate::lesson::render::display_width;
+
+/// ไฟล์ของ snippet ในโปรเจคชั่วคราว
+const SNIPPET_FILE: &str = "src/main.rs";
+
+/// ความรุนแรงของ diagnostic
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+/// ตำแหน่งใน snippet (บรรทัด/คอลัมน์เริ่มจาก 1)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Span {
+    pub line: usize,
+    pub column: usize,
+    /// ความยาวที่ขีดเส้นใต้ (อย่างน้อย 1)
+    pub width: usize,
+    pub label: Option<String>,
+    /// ตำแหน่งหลักของ error - ตำแหน่งรองเช่น "value moved here" เป็น `false`
+    pub primary: bool,
+}
+
+/// error หรือ warning หนึ่งอัน
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    /// เช่น `E0382` หรือชื่อ lint อย่าง `unused_variables`
+    pub code: Option<String>,
+    pub message: String,
+    pub spans: Vec<Span>,
+    /// ข้อความ `help:`/`note:` จาก compiler รวมโค้ดที่แนะนำให้แก้
+    pub notes: Vec<String>,
+}
+
+impl Diagnostic {
+    /// ตำแหน่งหลัก (ถ้ามี)
+    #[must_use]
+    pub fn primary(&self) -> Option<&Span> {
+        self.spans
+            .iter()
+            .find(|span| span.primary)
+            .or_else(|| self.spans.first())
+    }
+}
+
+/// ทุกอย่างที่อ่านได้จาก output ของ `cargo build`
+#[derive(Debug, Default)]
+pub struct BuildMessages {
+    pub diagnostics: Vec<Diagnostic>,
+    pub executable: Option<PathBuf>,
+    pub success: bool,
+}
+
+/// คำอธิบายภาษาไทยของ error ที่ผู้เริ่มต้นเจอบ่อย
+const HINTS: &[(&str, &str)] = &[
+    (
+        "E0382",
+        "ค่าถูก move ไปแล้วจึงใช้ซ้ำไม่ได้ - ยืมด้วย `&` แทน หรือ `.clone()` ถ้าต้องการสำเนา (บทที่ 2)",
+    ),
+    (
+        "E0499",
+        "ยืมแบบ `&mut` ได้ทีละครั้งเท่านั้น - ใช้ตัวแรกให้เสร็จก่อนค่อยยืมใหม่ (บทที่ 2)",
+    ),
+    (
+        "E0502",
+        "ยืมแบบ `&` กับ `&mut` พร้อมกันไม่ได้ - ย้ายการใช้ `&` ให้จบก่อนแก้ค่า (บทที่ 2)",
+    ),
+    (
+        "E0384",
+        "ตัวแปรเปลี่ยนค่าไม่ได้โดยปริยาย - ประกาศเป็น `let mut` (บทที่ 1)",
+    ),
+    (
+        "E0596",
+        "ยืมแบบ `&mut` จากตัวแปรที่ไม่ได้ประกาศ `mut` - เพิ่ม `mut` ตอนประกาศ",
+    ),
+    (
+        "E0308",
+        "ชนิดข้อมูลไม่ตรงกับที่ต้องการ - ดูว่า expected กับ found ต่างกันตรงไหน (ลืม `;` หรือ `&`?)",
+    ),
+    ("E0425", "ไม่รู้จักชื่อนี้ - สะกดผิด, ยังไม่ได้ประกาศ หรืออยู่นอก scope"),
+    (
+        "E0433",
+        "หา module/type ไม่เจอ - ลืม `use` หรือเปล่า? (snippet ใช้ได้แค่ standard library)",
+    ),
+    (
+        "E0277",
+        "ชนิดนี้ไม่ได้ implement trait ที่ต้องการ - เช่นพิมพ์ด้วย `{}` ต้องมี `Display`, `{:?}` ต้องมี `Debug` (บทที่ 9)",
+    ),
+    (
+        "E0106",
+        "reference ใน struct หรือค่าที่คืนต้องระบุ lifetime เช่น `&'a str` (บทที่ 10)",
+    ),
+    (
+        "E0597",
+        "ค่าถูก drop ก่อน reference ที่ยืมมันจะเลิกใช้ - ให้ค่าอยู่นานขึ้นหรือคืนค่าแบบ owned (บทที่ 10)",
+    ),
+    ("E0599", "ชนิดนี้ไม่มี method นี้ - ลืม `use` trait หรือเรียกผิดชนิด?"),
+    ("E0061", "จำนวน argument ไม่ตรงกับที่ function รับ"),
+];
+
+/// คำอธิบายภาษาไทยของ error code
+#[must_use]
+pub fn hint(code: &str) -> Option<&'static str> {
+    HINTS
+        .iter()
+        .find(|(known, _)| *known == code)
+        .map(|(_, text)| *text)
+}
+
+#[derive(Deserialize)]
+struct CargoMessage {
+    reason: String,
+    message: Option<RawDiagnostic>,
+    executable: Option<PathBuf>,
+    success: Option<bool>,
+}
+
+#[derive(Deserialize)]
+struct RawDiagnostic {
+    level: String,
+    message: String,
+    code: Option<RawCode>,
+    #[serde(default)]
+    spans: Vec<RawSpan>,
+    #[serde(default)]
+    children: Vec<Self>,
+}
+
+#[derive(Deserialize)]
+struct RawCode {
+    code: String,
+}
+
+#[derive(Deserialize)]
+struct RawSpan {
+    file_name: String,
+    line_start: usize,
+    line_end: usize,
+    column_start: usize,
+    column_end: usize,
+    is_primary: bool,
+    label: Option<String>,
+    suggested_replacement: Option<String>,
+}
+
+/// อ่าน stdout ของ `cargo build --message-format=json` - บรรทัดที่ไม่ใช่ JSON ข้ามไป
+///
+/// `line_offset` คือจำนวนบรรทัดที่ห่อเพิ่มไว้ข้างหน้า snippet
+#[must_use]
+pub fn parse_build_output(stdout: &str, line_offset: usize) -> BuildMessages {
+    let mut messages = BuildMessages::default();
+    for line in stdout.lines() {
+        let Ok(message) = serde_json::from_str::<CargoMessage>(line) else {
+            continue;
+        };
+        match message.reason.as_str() {
+            "compiler-message" => {
+                if let Some(diagnostic) = message.message.and_then(|raw| convert(raw, line_offset))
+                {
+                    messages.diagnostics.push(diagnostic);
+                }
+            }
+            "compiler-artifact" if message.executable.is_some() => {
+                messages.executable = message.executable;
+            }
+            "build-finished" => messages.success = message.success.unwrap_or(false),
+            _ => {}
+        }
+    }
+    messages
+}
+
+/// เก็บเฉพาะ error/warning ที่ชี้เข้าไปใน snippet - สรุปท้ายอย่าง "aborting due to" ไม่มีตำแหน่งจึงตกไป
+fn convert(raw: RawDiagnostic, line_offset: usize) -> Option<Diagnostic> {
+    let severity = match raw.level.as_str() {
+        "error" => Severity::Error,
+        "warning" => Severity::Warning,
+        _ => return None,
+    };
+    let spans: Vec<Span> = raw
+        .spans
+        .iter()
+        .filter(|span| span.file_name == SNIPPET_FILE && span.line_start > line_offset)
+        .map(|span| Span {
+            line: span.line_start - line_offset,
+            column: span.column_start,
+            width: if span.line_start == span.line_end {
+                span.column_end.saturating_sub(span.column_start).max(1)
+            } else {
+                1
+            },
+            label: span.label.clone().filter(|label| !label.is_empty()),
+            primary: span.is_primary,
+        })
+        .collect();
+    if spans.is_empty() {
+        return None;
+    }
+    let notes = raw
+        .children
+        .iter()
+        .map(|child| {
+            let suggestions: Vec<&str> = child
+                .spans
+                .iter()
+                .filter_map(|span| span.suggested_replacement.as_deref())
+                .filter(|replacement| !replacement.is_empty())
+                .collect();
+            if suggestions.is_empty() {
+                format!("{}: {}", child.level, child.message)
+            } else {
+                format!(
+                    "{}: {}: `{}`",
+                    child.level,
+                    child.message,
+                    suggestions.join("`, `")
+                )
+            }
+        })
+        .collect();
+    Some(Diagnostic {
+        severity,
+        code: raw.code.map(|code| code.code),
+        message: raw.message,
+        spans,
+        notes,
+    })
+}
+
+/// พิมพ์ diagnostic หนึ่งอันพร้อมบรรทัดโค้ดที่มีปัญหา แบบเดียวกับ `rustc` แต่มีคำอธิบายไทย
+///
+/// ```text
+/// ❌ error[E0382]: borrow of moved value: `s`
+///    ┌─ snippet.rs:4:12
+///  3 │ let t = s;
+///    │         ─ value moved here
+///  4 │ println!("{s}");
+///    │            ^ value borrowed here after move
+///    💡 help: consider cloning the value: `.clone()`
+///    🇹🇭 ค่าถูก move ไปแล้วจึงใช้ซ้ำไม่ได้ ...
+/// ```
+#[must_use]
+pub fn render(diagnostic: &Diagnostic, source: &str, file_name: &str, color: bool) -> String {
+    let (icon, label, style) = match diagnostic.severity {
+        Severity::Error => ("❌", "error", "1;31"),
+        Severity::Warning => ("⚠️", "warning", "1;33"),
+    };
+    let code = diagnostic
+        .code
+        .as_ref()
+        .map_or_else(String::new, |code| format!("[{code}]"));
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "{icon} {}: {}",
+        paint(&format!("{label}{code}"), style, color),
+        diagnostic.message
+    );
+
+    let lines: Vec<&str> = source.lines().collect();
+    let mut spans: Vec<&Span> = diagnostic.spans.iter().collect();
+    spans.sort_by_key(|span| (span.line, span.column));
+    let gutter = spans.last().map_or(1, |span| span.line.to_string().len());
+    let blank = " ".repeat(gutter);
+    if let Some(primary) = diagnostic.primary() {
+        let location = format!("{file_name}:{}:{}", primary.line, primary.column);
+        let _ = writeln!(out, "{blank} {} {location}", paint("┌─", "34", color));
+    }
+    let mut previous_line = None;
+    for span in spans {
+        let Some(text) = lines.get(span.line - 1) else {
+            continue;
+        };
+        if previous_line != Some(span.line) {
+            let number = format!("{:>gutter$} │", span.line);
+            let _ = writeln!(out, "{} {text}", paint(&number, "34", color));
+            previous_line = Some(span.line);
+        }
+        let prefix: String = text.chars().take(span.column - 1).collect();
+        let (mark, mark_style) = if span.primary {
+            ("^", style)
+        } else {
+            ("─", "1;34")
+        };
+        let marker = format!(
+            "{}{} {}",
+            " ".repeat(display_width(&prefix)),
+            mark.repeat(span.width),
+            span.label.as_deref().unwrap_or("")
+        );
+        let _ = writeln!(
+            out,
+            "{} {}",
+            paint(&format!("{blank} │"), "34", color),
+            paint(marker.trim_end(), mark_style, color)
+        );
+    }
+
+    for note in &diagnostic.notes {
+        let icon = if note.starts_with("help") {
+            "💡"
+        } else {
+            "📝"
+        };
+        let _ = writeln!(out, "{blank} {icon} {note}");
+    }
+    if let Some(text) = diagnostic.code.as_deref().and_then(hint) {
+        let _ = writeln!(out, "{blank} 🇹🇭 {text}");
+    }
+    out
+}
+
+fn paint(text: &str, code: &str, color: bool) -> String {
+    if color {
+        format!("\x1b[{code}m{text}\x1b[0m")
+    } else {
+        text.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// ตัดมาจาก output จริงของ `cargo build --message-format=json` (snippet ถูกห่อด้วย `fn main`)
+    const BUILD_OUTPUT: &str = r#"{"reason":"compiler-message","message":{"$message_type":"diagnostic","children":[{"children":[],"code":null,"level":"help","message":"consider cloning the value if the performance cost is acceptable","rendered":null,"spans":[{"byte_end":49,"byte_start":49,"column_end":10,"column_start":10,"expansion":null,"file_name":"src/main.rs","is_primary":true,"label":null,"line_end":3,"line_start":3,"suggested_replacement":".clone()","suggestion_applicability":"MachineApplicable","text":[]}]}],"code":{"code":"E0382","explanation":"..."},"level":"error","message":"borrow of moved value: `s`","spans":[{"byte_end":49,"byte_start":48,"column_end":10,"column_start":9,"expansion":null,"file_name":"src/main.rs","is_primary":false,"label":"value moved here","line_end":3,"line_start":3,"suggested_replacement":null,"suggestion_applicability":null,"text":[]},{"byte_end":79,"byte_start":78,"column_end":13,"column_start":12,"expansion":null,"file_name":"src/main.rs","is_primary":true,"label":"value borrowed here after move","line_end":4,"line_start":4,"suggested_replacement":null,"suggestion_applicability":null,"text":[]}],"rendered":"..."}}
+{"reason":"compiler-message","message":{"$message_type":"diagnostic","children":[],"level":"failure-note","message":"For more information about this error, try `rustc --explain E0382`.","spans":[],"code":null,"rendered":"..."}}
+not json at all
+{"reason":"build-finished","success":false}"#;
+
+    #[test]
+    fn test_parse_build_output() {
+        let messages = parse_build_output(BUILD_OUTPUT, 1);
+        assert!(!messages.success);
+        assert_eq!(messages.executable, None);
+        assert_eq!(messages.diagnostics.len(), 1);
+
+        let diagnostic = &messages.diagnostics[0];
+        assert_eq!(diagnostic.severity, Severity::Error);
+        assert_eq!(diagnostic.code.as_deref(), Some("E0382"));
+        assert_eq!(
+            diagnostic.primary(),
+            Some(&Span {
+                line: 3,
+                column: 12,
+                width: 1,
+                label: Some("value borrowed here after move".into()),
+                primary: true,
+            })
+        );
+        assert_eq!(
+            diagnostic.notes,
+            ["help: consider cloning the value if the performance cost is acceptable: `.clone()`"]
+        );
+
+        let artifact = r#"{"reason":"compiler-artifact","executable":"/tmp/target/debug/playground"}
+{"reason":"build-finished","success":true}"#;
+        let messages = parse_build_output(artifact, 0);
+        assert!(messages.success);
+        assert_eq!(
+            messages.executable,
+            Some(PathBuf::from("/tmp/target/debug/playground"))
+        );
+    }
+
+    #[test]
+    fn test_render_points_at_snippet_lines() {
+        let source = "let s = String::from(\"สวัสดี\");\nlet t = s;\nprintln!(\"{s}\");\n";
+        let diagnostic = &parse_build_output(BUILD_OUTPUT, 1).diagnostics[0];
+        assert_eq!(
+            render(diagnostic, source, "hello.rs", false),
+            "❌ error[E0382]: borrow of moved value: `s`\n\
+             \x20 ┌─ hello.rs:3:12\n\
+             2 │ let t = s;\n\
+             \x20 │         ─ value moved here\n\
+             3 │ println!(\"{s}\");\n\
+             \x20 │            ^ value borrowed here after move\n\
+             \x20 💡 help: consider cloning the value if the performance cost is acceptable: `.clone()`\n\
+             \x20 🇹🇭 ค่าถูก move ไปแล้วจึงใช้ซ้ำไม่ได้ - ยืมด้วย `&` แทน หรือ `.clone()` ถ้าต้องการสำเนา (บทที่ 2)\n"
+        );
+        assert!(hint("E9999").is_none());
+    }
+}
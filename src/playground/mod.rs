@@ -0,0 +1,512 @@
+//! 🧪 Playground - `cargo run -- --play <file.rs>` compile และรันโค้ดของผู้เรียนด้วย toolchain เดียวกับโปรเจค
+//!
+//! ขั้นตอนเหมือนที่เราทำเองด้วยมือ
+//! 1. 📁 สร้างโปรเจค cargo ชั่วคราว แล้วเขียน snippet ลง `src/main.rs`
+//!    (ถ้าไม่มี `fn main` จะห่อให้ - เขียนแค่ `let x = 5;` ก็รันได้)
+//! 2. 🔨 `cargo build --message-format=json` แล้วอ่าน diagnostic ออกมาเอง (`diagnostics.rs`)
+//! 3. ▶️ รัน binary ที่ได้ พร้อม timeout (`process.rs`) - loop ไม่รู้จบจะไม่ทำให้ workshop ค้าง
+//! 4. 🩺 พิมพ์ error แบบชี้บรรทัด + คำแนะนำภาษาไทยสำหรับ error ที่เจอบ่อย
+//!
+//! build ทุกครั้งใช้ target directory เดียวกันใน temp dir ของระบบ รอบถัดไปจึงเร็วขึ้น
+//! snippet ใช้ได้เฉพาะ standard library (ไม่มี dependency)
+
+pub mod diagnostics;
+pub mod process;
+
+use std::ffi::OsString;
+use std::fmt::{self, Write as _};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use tempfile::TempDir;
+
+pub use diagnostics::{Diagnostic, Severity, Span};
+pub use process::{Captured, run_with_timeout};
+
+/// ❌ error ของ playground - error ของโค้ดผู้เรียนไม่ใช่ error ตรงนี้ แต่เป็น `Outcome::CompileError`
+#[derive(Debug)]
+pub enum PlaygroundError {
+    /// หาโปรแกรม `cargo` ไม่เจอ
+    CargoNotFound(OsString),
+    /// build ผ่านแต่ cargo ไม่บอก path ของ binary
+    MissingExecutable,
+    /// สร้างโปรเจคชั่วคราวหรือรัน process ไม่ได้
+    Io(io::Error),
+}
+
+impl fmt::Display for PlaygroundError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::CargoNotFound(program) => write!(
+                f,
+                "cannot run {}: is the Rust toolchain installed?",
+                program.to_string_lossy()
+            ),
+            Self::MissingExecutable => write!(f, "cargo did not report the built executable"),
+            Self::Io(error) => write!(f, "playground I/O error: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for PlaygroundError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for PlaygroundError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+/// 📄 โค้ดของผู้เรียน
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snippet {
+    source: String,
+}
+
+impl Snippet {
+    #[must_use]
+    pub fn new(source: impl Into<String>) -> Self {
+        Self {
+            source: source.into(),
+        }
+    }
+
+    /// โค้ดตามที่ผู้เรียนเขียน
+    #[must_use]
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// มี `fn main` อยู่แล้วหรือยัง - ไม่สนบรรทัด comment
+    #[must_use]
+    pub fn has_main(&self) -> bool {
+        self.source.lines().any(|line| {
+            let line = line.trim_start();
+            !line.starts_with("//")
+                && line
+                    .split_once("fn main")
+                    .is_some_and(|(_, rest)| rest.trim_start().starts_with('('))
+        })
+    }
+
+    /// จำนวนบรรทัดที่ `main_rs` เพิ่มไว้ข้างหน้า
+    #[must_use]
+    pub fn line_offset(&self) -> usize {
+        usize::from(!self.has_main())
+    }
+
+    /// เนื้อหา `src/main.rs` - ห่อด้วย `fn main() { ... }` ถ้ายังไม่มี
+    ///
+    /// ไม่เยื้องบรรทัดเพิ่ม คอลัมน์ใน diagnostic จึงตรงกับไฟล์ของผู้เรียน
+    /// (`struct`/`fn`/`use` ประกาศใน function ได้ เลยห่อทั้งก้อนได้เลย)
+    #[must_use]
+    pub fn main_rs(&self) -> String {
+        if self.has_main() {
+            self.source.clone()
+        } else {
+            format!("fn main() {{\n{}\n}}\n", self.source.trim_end())
+        }
+    }
+}
+
+/// ขั้นตอนที่หมดเวลา
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    Build,
+    Run,
+}
+
+/// 📦 ผลของการรัน snippet หนึ่งครั้ง
+#[derive(Debug)]
+pub enum Outcome {
+    /// compile ไม่ผ่าน - `stderr` ของ cargo ไว้ดูเมื่อไม่มี diagnostic (เช่น `Cargo.toml` เสีย)
+    CompileError {
+        diagnostics: Vec<Diagnostic>,
+        stderr: String,
+    },
+    /// รันจบ - panic จะได้ `exit_code` 101 และข้อความอยู่ใน `stderr`
+    Finished {
+        warnings: Vec<Diagnostic>,
+        exit_code: Option<i32>,
+        stdout: String,
+        stderr: String,
+        elapsed: Duration,
+    },
+    /// เกินเวลาและถูกหยุด
+    TimedOut {
+        phase: Phase,
+        limit: Duration,
+        stdout: String,
+    },
+}
+
+impl Outcome {
+    /// compile ผ่านและโปรแกรมจบด้วย exit code 0
+    #[must_use]
+    pub const fn is_success(&self) -> bool {
+        matches!(
+            self,
+            Self::Finished {
+                exit_code: Some(0),
+                ..
+            }
+        )
+    }
+}
+
+/// 🧪 ตัวรัน snippet
+///
+/// ```no_run
+/// use rust_concepts::playground::{Playground, Snippet};
+/// use std::time::Duration;
+///
+/// let outcome = Playground::new()
+///     .with_run_timeout(Duration::from_secs(2))
+///     .run(&Snippet::new("println!(\"{}\", 1 + 1);"))
+///     .unwrap();
+/// assert!(outcome.is_success());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Playground {
+    cargo: OsString,
+    edition: String,
+    target_dir: PathBuf,
+    build_timeout: Duration,
+    run_timeout: Duration,
+}
+
+impl Default for Playground {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Playground {
+    /// ค่าเริ่มต้น: cargo ตัวเดียวกับที่รันโปรเจคนี้ (env `CARGO`), edition 2024,
+    /// build ได้ 60 วินาที รันได้ 10 วินาที
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            cargo: std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into()),
+            edition: "2024".to_string(),
+            target_dir: std::env::temp_dir().join("rust_concepts_playground"),
+            build_timeout: Duration::from_secs(60),
+            run_timeout: Duration::from_secs(10),
+        }
+    }
+
+    #[must_use]
+    pub fn with_cargo(mut self, cargo: impl Into<OsString>) -> Self {
+        self.cargo = cargo.into();
+        self
+    }
+
+    #[must_use]
+    pub fn with_edition(mut self, edition: impl Into<String>) -> Self {
+        self.edition = edition.into();
+        self
+    }
+
+    /// ที่เก็บผล build - ใช้ร่วมกันได้ระหว่างหลายรอบ
+    #[must_use]
+    pub fn with_target_dir(mut self, target_dir: impl Into<PathBuf>) -> Self {
+        self.target_dir = target_dir.into();
+        self
+    }
+
+    #[must_use]
+    pub const fn with_build_timeout(mut self, timeout: Duration) -> Self {
+        self.build_timeout = timeout;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_run_timeout(mut self, timeout: Duration) -> Self {
+        self.run_timeout = timeout;
+        self
+    }
+
+    /// build แล้วรัน `snippet` - error ของโค้ดผู้เรียนอยู่ใน `Outcome` ไม่ใช่ `Err`
+    ///
+    /// # Errors
+    ///
+    /// คืน error เมื่อหา `cargo` ไม่เจอ, สร้างโปรเจคชั่วคราวไม่ได้ หรือ process รันไม่ได้
+    pub fn run(&self, snippet: &Snippet) -> Result<Outcome, PlaygroundError> {
+        let project = TempDir::new()?;
+        self.write_project(project.path(), snippet)?;
+
+        let mut build = Command::new(&self.cargo);
+        build
+            .args(["build", "--quiet", "--message-format=json", "--target-dir"])
+            .arg(&self.target_dir)
+            .current_dir(project.path());
+        let compiled = run_with_timeout(&mut build, self.build_timeout).map_err(|error| {
+            if error.kind() == io::ErrorKind::NotFound {
+                PlaygroundError::CargoNotFound(self.cargo.clone())
+            } else {
+                PlaygroundError::Io(error)
+            }
+        })?;
+        if compiled.timed_out() {
+            return Ok(Outcome::TimedOut {
+                phase: Phase::Build,
+                limit: self.build_timeout,
+                stdout: String::new(),
+            });
+        }
+
+        let messages = diagnostics::parse_build_output(&compiled.stdout, snippet.line_offset());
+        if !messages.success {
+            return Ok(Outcome::CompileError {
+                diagnostics: messages.diagnostics,
+                stderr: compiled.stderr,
+            });
+        }
+        let executable = messages
+            .executable
+            .ok_or(PlaygroundError::MissingExecutable)?;
+
+        let ran = run_with_timeout(
+            Command::new(executable).current_dir(project.path()),
+            self.run_timeout,
+        )?;
+        Ok(match ran.status {
+            None => Outcome::TimedOut {
+                phase: Phase::Run,
+                limit: self.run_timeout,
+                stdout: ran.stdout,
+            },
+            Some(status) => Outcome::Finished {
+                warnings: messages.diagnostics,
+                exit_code: status.code(),
+                stdout: ran.stdout,
+                stderr: ran.stderr,
+                elapsed: ran.elapsed,
+            },
+        })
+    }
+
+    fn write_project(&self, dir: &Path, snippet: &Snippet) -> io::Result<()> {
+        // `[workspace]` ว่างกันไม่ให้ cargo ไปเกาะ workspace ที่อยู่ในโฟลเดอร์แม่
+        let manifest = format!(
+            "[package]\nname = \"playground\"\nversion = \"0.0.0\"\nedition = \"{}\"\npublish = false\n\n[workspace]\n",
+            self.edition
+        );
+        std::fs::write(dir.join("Cargo.toml"), manifest)?;
+        std::fs::create_dir(dir.join("src"))?;
+        std::fs::write(dir.join("src/main.rs"), snippet.main_rs())
+    }
+}
+
+/// 📋 สรุปผลให้อ่าน - `file_name` ใช้แสดงตำแหน่ง error เช่น `hello.rs:3:5`
+#[must_use]
+pub fn report(snippet: &Snippet, file_name: &str, outcome: &Outcome, color: bool) -> String {
+    let render_all = |diagnostics: &[Diagnostic], out: &mut String| {
+        for diagnostic in diagnostics {
+            out.push_str(&diagnostics::render(
+                diagnostic,
+                snippet.source(),
+                file_name,
+                color,
+            ));
+            out.push('\n');
+        }
+    };
+    let mut out = String::new();
+    match outcome {
+        Outcome::CompileError {
+            diagnostics,
+            stderr,
+        } => {
+            render_all(diagnostics, &mut out);
+            let errors = diagnostics
+                .iter()
+                .filter(|diagnostic| diagnostic.severity == Severity::Error)
+                .count();
+            if errors == 0 {
+                // ไม่มี diagnostic ของ snippet - เป็นปัญหาของ cargo เอง
+                let _ = writeln!(out, "{}", stderr.trim_end());
+            }
+            let _ = writeln!(out, "🔨 compile ไม่ผ่าน (error {errors} จุด)");
+        }
+        Outcome::Finished {
+            warnings,
+            exit_code,
+            stdout,
+            stderr,
+            elapsed,
+        } => {
+            render_all(warnings, &mut out);
+            if !stdout.is_empty() {
+                let _ = writeln!(out, "▶️ ผลลัพธ์:");
+                let _ = writeln!(out, "{}", stdout.trim_end_matches('\n'));
+            }
+            if !stderr.is_empty() {
+                let stderr = relocate(stderr, snippet, file_name);
+                let _ = writeln!(out, "📢 stderr:");
+                let _ = writeln!(out, "{}", stderr.trim_end_matches('\n'));
+            }
+            match exit_code {
+                Some(0) => {
+                    let _ = writeln!(out, "✅ รันเสร็จใน {elapsed:.2?}");
+                }
+                Some(101) => {
+                    let _ = writeln!(out, "💥 โปรแกรม panic (exit code 101)");
+                }
+                Some(code) => {
+                    let _ = writeln!(out, "❌ โปรแกรมจบด้วย exit code {code}");
+                }
+                None => {
+                    let _ = writeln!(out, "❌ โปรแกรมถูกหยุดด้วย signal");
+                }
+            }
+        }
+        Outcome::TimedOut {
+            phase,
+            limit,
+            stdout,
+        } => {
+            out.push_str(stdout);
+            if !stdout.is_empty() && !stdout.ends_with('\n') {
+                out.push('\n');
+            }
+            let what = match phase {
+                Phase::Build => "build",
+                Phase::Run => "โปรแกรม",
+            };
+            let _ = writeln!(
+                out,
+                "⏰ {what} ใช้เวลาเกิน {limit:?} จึงถูกหยุด - มี loop ที่ไม่มีวันจบหรือเปล่า?"
+            );
+        }
+    }
+    out
+}
+
+/// เปลี่ยนตำแหน่งอย่าง `src/main.rs:4:17` ในข้อความ panic เป็นบรรทัดของไฟล์ผู้เรียน
+fn relocate(text: &str, snippet: &Snippet, file_name: &str) -> String {
+    const MAIN_RS: &str = "src/main.rs:";
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find(MAIN_RS) {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + MAIN_RS.len()..];
+        let digits = after
+            .find(|ch: char| !ch.is_ascii_digit())
+            .unwrap_or(after.len());
+        match after[..digits].parse::<usize>() {
+            Ok(line) if line > snippet.line_offset() => {
+                let _ = write!(out, "{file_name}:{}", line - snippet.line_offset());
+                rest = &after[digits..];
+            }
+            _ => {
+                out.push_str(MAIN_RS);
+                rest = after;
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// อ่าน `path` แล้วรันด้วย `playground` พิมพ์ผลลง `out`
+///
+/// # Errors
+///
+/// คืน error เมื่ออ่านไฟล์ไม่ได้, รัน cargo ไม่ได้ หรือเขียน `out` ไม่ได้
+pub fn run_file(
+    path: &Path,
+    playground: &Playground,
+    out: &mut impl Write,
+    color: bool,
+) -> Result<Outcome> {
+    let source =
+        std::fs::read_to_string(path).with_context(|| format!("cannot read {}", path.display()))?;
+    let snippet = Snippet::new(source);
+    let file_name = path.file_name().map_or_else(
+        || path.display().to_string(),
+        |name| name.to_string_lossy().into_owned(),
+    );
+    writeln!(out, "🧪 กำลัง compile {file_name} ...")?;
+    let outcome = playground.run(&snippet)?;
+    write!(out, "{}", report(&snippet, &file_name, &outcome, color))?;
+    Ok(outcome)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snippet_wrapping() {
+        let bare = Snippet::new("let x = 5;\nprintln!(\"{x}\");\n");
+        assert!(!bare.has_main());
+        assert_eq!(bare.line_offset(), 1);
+        assert_eq!(
+            bare.main_rs(),
+            "fn main() {\nlet x = 5;\nprintln!(\"{x}\");\n}\n"
+        );
+
+        let full = Snippet::new("// fn main() ในคอมเมนต์ไม่นับ\nfn main() {}\n");
+        assert!(full.has_main());
+        assert_eq!(full.main_rs(), full.source());
+        assert!(!Snippet::new("fn main_helper() {}").has_main());
+    }
+
+    #[test]
+    fn test_runs_snippets_with_the_current_toolchain() {
+        let target = TempDir::new().unwrap();
+        let playground = Playground::new()
+            .with_target_dir(target.path())
+            .with_run_timeout(Duration::from_secs(1));
+
+        let hello = Snippet::new("let name = \"Ferris\";\nprintln!(\"สวัสดี {name}\");");
+        let outcome = playground.run(&hello).unwrap();
+        assert!(outcome.is_success(), "{outcome:?}");
+        let text = report(&hello, "hello.rs", &outcome, false);
+        assert!(text.contains("สวัสดี Ferris\n✅"), "{text}");
+
+        let moved = Snippet::new("let s = String::new();\nlet t = s;\nprintln!(\"{s} {t}\");");
+        let outcome = playground.run(&moved).unwrap();
+        let Outcome::CompileError { diagnostics, .. } = &outcome else {
+            panic!("expected a compile error, got {outcome:?}");
+        };
+        assert_eq!(diagnostics[0].code.as_deref(), Some("E0382"));
+        assert_eq!(diagnostics[0].primary().map(|span| span.line), Some(3));
+        assert!(report(&moved, "moved.rs", &outcome, false).contains("moved.rs:3:"));
+
+        let panics = Snippet::new("let v: Vec<u8> = Vec::new();\nlet _ = v[1];");
+        let outcome = playground.run(&panics).unwrap();
+        assert!(matches!(
+            outcome,
+            Outcome::Finished {
+                exit_code: Some(101),
+                ..
+            }
+        ));
+        let text = report(&panics, "panics.rs", &outcome, false);
+        assert!(text.contains("panicked at panics.rs:2:"), "{text}");
+
+        let forever = Snippet::new("loop { std::hint::spin_loop(); }");
+        let outcome = playground.run(&forever).unwrap();
+        assert!(matches!(
+            outcome,
+            Outcome::TimedOut {
+                phase: Phase::Run,
+                ..
+            }
+        ));
+
+        let missing = Playground::new().with_cargo("no-such-cargo").run(&hello);
+        assert!(matches!(missing, Err(PlaygroundError::CargoNotFound(_))));
+    }
+}
@@ -0,0 +1,170 @@
+//! ⏱️ รัน process พร้อม timeout
+//!
+//! `std::process` ไม่มี `wait_timeout` ในตัว เลยใช้วิธีถาม `try_wait` ทุก 10ms จนหมดเวลาแล้ว `kill`
+//! - stdout/stderr อ่านใน thread แยก ไม่อย่างนั้น process ที่พิมพ์เยอะจะค้างเพราะ pipe เต็ม
+//! - รอ thread อ่านไม่เกิน deadline เดียวกัน แม้ process จะจบแล้วก็ตาม เพราะ process ลูกของลูก
+//!   (เช่น `rustc` ที่ `cargo` เรียก หรืองานที่ถูกโยนไป background) อาจยังถือ pipe อยู่
+//!   - เลยกำหนดแล้วเอาเท่าที่อ่านได้แล้วไปเลย
+
+use std::io::{self, Read};
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+/// เก็บ output ได้ไม่เกินนี้ต่อ stream - กันโปรแกรมที่วนพิมพ์ไม่หยุดกิน memory
+pub const MAX_OUTPUT: usize = 64 * 1024;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// ผลของการรัน
+#[derive(Debug)]
+pub struct Captured {
+    /// `None` เมื่อหมดเวลาและถูก kill
+    pub status: Option<ExitStatus>,
+    pub stdout: String,
+    pub stderr: String,
+    pub elapsed: Duration,
+}
+
+impl Captured {
+    /// ถูก kill เพราะหมดเวลา
+    #[must_use]
+    pub const fn timed_out(&self) -> bool {
+        self.status.is_none()
+    }
+}
+
+/// รัน `command` (stdin ว่าง) แล้วรอไม่เกิน `timeout`
+///
+/// # Errors
+///
+/// คืน error เมื่อ spawn ไม่ได้ (เช่นไม่มีโปรแกรมนี้) หรือรอ/kill process ไม่ได้
+pub fn run_with_timeout(command: &mut Command, timeout: Duration) -> io::Result<Captured> {
+    let started = Instant::now();
+    let mut child = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    let stdout = Collector::spawn(child.stdout.take());
+    let stderr = Collector::spawn(child.stderr.take());
+
+    let deadline = started + timeout;
+    let status = wait_until(&mut child, deadline)?;
+    let elapsed = started.elapsed();
+    Ok(Captured {
+        status,
+        stdout: stdout.finish(deadline),
+        stderr: stderr.finish(deadline),
+        elapsed,
+    })
+}
+
+fn wait_until(child: &mut Child, deadline: Instant) -> io::Result<Option<ExitStatus>> {
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(Some(status));
+        }
+        if Instant::now() >= deadline {
+            // process อาจจบไปเองระหว่างนี้ - kill ไม่สำเร็จก็ไม่เป็นไร
+            let _ = child.kill();
+            child.wait()?;
+            return Ok(None);
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// อ่าน stream หนึ่งจนจบใน thread ของตัวเอง
+struct Collector {
+    buffer: Arc<Mutex<Vec<u8>>>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl Collector {
+    fn spawn(stream: Option<impl Read + Send + 'static>) -> Self {
+        let buffer = Arc::new(Mutex::new(Vec::new()));
+        let handle = stream.map(|mut stream| {
+            let buffer = Arc::clone(&buffer);
+            thread::spawn(move || {
+                let mut chunk = [0; 4096];
+                // อ่านต่อจนจบแม้เกิน MAX_OUTPUT เพื่อไม่ให้ process ฝั่งโน้นค้าง
+                while let Ok(read @ 1..) = stream.read(&mut chunk) {
+                    let mut buffer = buffer.lock().unwrap_or_else(PoisonError::into_inner);
+                    let room = MAX_OUTPUT.saturating_sub(buffer.len());
+                    buffer.extend_from_slice(&chunk[..read.min(room)]);
+                }
+            })
+        });
+        Self { buffer, handle }
+    }
+
+    /// ข้อความที่อ่านได้ - รอ thread อ่านจนถึง `deadline` แล้วเอาเท่าที่มี
+    ///
+    /// `JoinHandle` ไม่มี join แบบมี timeout เลยถาม `is_finished` แทน
+    /// thread ที่ยังค้างอยู่จะจบเองเมื่อ process สุดท้ายที่ถือ pipe ปิดมัน
+    fn finish(self, deadline: Instant) -> String {
+        if let Some(handle) = self.handle {
+            while !handle.is_finished() && Instant::now() < deadline {
+                thread::sleep(POLL_INTERVAL);
+            }
+            if handle.is_finished() {
+                let _ = handle.join();
+            }
+        }
+        let (mut text, truncated) = {
+            let buffer = self.buffer.lock().unwrap_or_else(PoisonError::into_inner);
+            (
+                String::from_utf8_lossy(&buffer).into_owned(),
+                buffer.len() >= MAX_OUTPUT,
+            )
+        };
+        if truncated {
+            text.push_str("\n… (output ยาวเกินไป ตัดเหลือ 64 KiB)\n");
+        }
+        text
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_captures_output_and_enforces_timeout() {
+        let finished = run_with_timeout(
+            Command::new("sh").args(["-c", "echo out; echo err >&2; exit 3"]),
+            Duration::from_secs(10),
+        )
+        .unwrap();
+        assert_eq!(finished.status.and_then(|status| status.code()), Some(3));
+        assert_eq!(finished.stdout, "out\n");
+        assert_eq!(finished.stderr, "err\n");
+
+        let slow = run_with_timeout(
+            Command::new("sh").args(["-c", "echo started; sleep 5"]),
+            Duration::from_millis(200),
+        )
+        .unwrap();
+        assert!(slow.timed_out());
+        assert!(slow.elapsed < Duration::from_secs(5));
+
+        // shell จบทันทีแต่ `sleep` ที่อยู่ background ยังถือ stdout ไว้ - ต้องไม่รอมันจนจบ
+        let started = Instant::now();
+        let orphan = run_with_timeout(
+            Command::new("sh").args(["-c", "echo done; sleep 5 &"]),
+            Duration::from_millis(300),
+        )
+        .unwrap();
+        assert!(started.elapsed() < Duration::from_secs(5));
+        assert_eq!(orphan.status.and_then(|status| status.code()), Some(0));
+        assert_eq!(orphan.stdout, "done\n");
+
+        let missing = run_with_timeout(
+            &mut Command::new("definitely-not-a-real-program"),
+            Duration::from_secs(1),
+        );
+        assert_eq!(missing.unwrap_err().kind(), io::ErrorKind::NotFound);
+    }
+}
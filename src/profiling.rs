@@ -0,0 +1,468 @@
+//! 🔥 Profiling Spans - จับเวลาเป็นช่วงซ้อนกันแล้วดูเป็น flamegraph
+//!
+//! ใส่ `profiling::span!("ชื่อ")` ไว้ต้นบล็อกที่อยากวัด ช่วงเวลาจะจบเองเมื่อออกจากบล็อก
+//! span ที่เปิดซ้อนกันจะกลายเป็นต้นไม้ พิมพ์ได้ 3 แบบ:
+//! - 🌳 text flamegraph (`Display`) - ดูใน terminal ได้ทันที
+//! - 📜 folded stacks - ส่งต่อให้ `inferno-flamegraph` / `flamegraph.pl` วาดเป็น SVG
+//! - 🧭 Chrome trace JSON - เปิดใน `chrome://tracing` หรือ <https://ui.perfetto.dev>
+//!
+//! ```
+//! use rust_concepts::profiling::{self, Profiler};
+//!
+//! let profiler = Profiler::start();
+//! {
+//!     profiling::span!("load");
+//!     profiling::span!("parse");
+//! }
+//! let profile = profiler.finish();
+//! assert_eq!(profile.events().len(), 2);
+//! ```
+//!
+//! ⚡ ถ้าไม่มี `Profiler` ทำงานอยู่บน thread นั้น `span!` แทบไม่มีต้นทุน
+//! (ไม่แม้แต่ประเมินชื่อ span) จึงทิ้งไว้ในโค้ดได้เลย
+
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::fmt::{self, Write as _};
+use std::io;
+use std::marker::PhantomData;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// เปิด span ที่จบเองเมื่อออกจากบล็อกปัจจุบัน
+///
+/// รับชื่อเป็นอะไรก็ได้ที่แปลงเป็น `Cow<'static, str>` (`&'static str` หรือ `String`)
+/// ชื่อจะถูกประเมินเฉพาะตอนที่มี `Profiler` ทำงานอยู่เท่านั้น
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __profiling_span {
+    ($name:expr) => {
+        let _profiling_span_guard = if $crate::profiling::is_recording() {
+            $crate::profiling::enter($name)
+        } else {
+            $crate::profiling::SpanGuard::inactive()
+        };
+    };
+}
+
+pub use crate::__profiling_span as span;
+
+/// span หนึ่งช่วงที่บันทึกไว้
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpanEvent {
+    pub name: Cow<'static, str>,
+    /// เวลาเริ่ม นับจากตอน `Profiler::start`
+    pub start: Duration,
+    pub duration: Duration,
+    /// index ของ span แม่ใน `Profile::events` (`None` = span ระดับบนสุด)
+    pub parent: Option<usize>,
+}
+
+/// สถานะการบันทึกของ thread หนึ่ง
+struct Recorder {
+    origin: Instant,
+    events: Vec<SpanEvent>,
+    open: Vec<usize>,
+}
+
+thread_local! {
+    static RECORDER: RefCell<Option<Recorder>> = const { RefCell::new(None) };
+}
+
+/// thread นี้มี `Profiler` กำลังบันทึกอยู่หรือไม่
+#[must_use]
+pub fn is_recording() -> bool {
+    RECORDER.with_borrow(Option::is_some)
+}
+
+/// เปิด span ด้วยมือ - ปกติใช้ผ่าน `span!` แทน
+#[must_use = "span จบทันทีถ้าไม่เก็บ guard ไว้"]
+pub fn enter(name: impl Into<Cow<'static, str>>) -> SpanGuard {
+    let active = RECORDER.with_borrow_mut(|recorder| {
+        let Some(recorder) = recorder else {
+            return false;
+        };
+        let index = recorder.events.len();
+        recorder.events.push(SpanEvent {
+            name: name.into(),
+            start: recorder.origin.elapsed(),
+            duration: Duration::ZERO,
+            parent: recorder.open.last().copied(),
+        });
+        recorder.open.push(index);
+        true
+    });
+    SpanGuard {
+        active,
+        _not_send: PhantomData,
+    }
+}
+
+/// ปิด span เมื่อถูก drop
+#[derive(Debug)]
+pub struct SpanGuard {
+    active: bool,
+    // span ผูกกับ thread-local ของ thread ที่เปิด จึงห้ามส่งข้าม thread
+    _not_send: PhantomData<*const ()>,
+}
+
+impl SpanGuard {
+    /// guard ที่ไม่ได้บันทึกอะไร (ตอนที่ไม่มี `Profiler` ทำงาน)
+    #[must_use]
+    pub const fn inactive() -> Self {
+        Self {
+            active: false,
+            _not_send: PhantomData,
+        }
+    }
+}
+
+impl Drop for SpanGuard {
+    fn drop(&mut self) {
+        if !self.active {
+            return;
+        }
+        RECORDER.with_borrow_mut(|recorder| {
+            // profiler อาจ finish ไปก่อนแล้ว - span ที่ค้างถูกปิดไปตอนนั้น
+            if let Some(recorder) = recorder
+                && let Some(index) = recorder.open.pop()
+            {
+                let event = &mut recorder.events[index];
+                event.duration = recorder.origin.elapsed().saturating_sub(event.start);
+            }
+        });
+    }
+}
+
+/// 🎬 ตัวบันทึก span ของ thread ปัจจุบัน
+#[derive(Debug)]
+pub struct Profiler {
+    _not_send: PhantomData<*const ()>,
+}
+
+impl Profiler {
+    /// เริ่มบันทึก span ทั้งหมดที่เปิดบน thread นี้
+    ///
+    /// # Panics
+    ///
+    /// Panics ถ้ามี `Profiler` ทำงานอยู่บน thread นี้แล้ว
+    #[must_use]
+    pub fn start() -> Self {
+        RECORDER.with_borrow_mut(|recorder| {
+            assert!(
+                recorder.is_none(),
+                "a profiler is already running on this thread"
+            );
+            *recorder = Some(Recorder {
+                origin: Instant::now(),
+                events: Vec::new(),
+                open: Vec::new(),
+            });
+        });
+        Self {
+            _not_send: PhantomData,
+        }
+    }
+
+    /// หยุดบันทึกแล้วคืนผลลัพธ์ - span ที่ยังเปิดอยู่จะถูกปิด ณ ตอนนี้
+    #[must_use]
+    pub fn finish(self) -> Profile {
+        let recorder = RECORDER.with_borrow_mut(Option::take);
+        let Some(mut recorder) = recorder else {
+            return Profile::default();
+        };
+        let now = recorder.origin.elapsed();
+        for index in recorder.open.drain(..) {
+            let event = &mut recorder.events[index];
+            event.duration = now.saturating_sub(event.start);
+        }
+        Profile {
+            events: recorder.events,
+        }
+    }
+}
+
+impl Drop for Profiler {
+    fn drop(&mut self) {
+        // ทิ้ง profiler โดยไม่ finish = เลิกบันทึก
+        RECORDER.with_borrow_mut(Option::take);
+    }
+}
+
+/// 🌳 span ที่มีชื่อเดียวกันใต้แม่เดียวกันถูกรวมเป็น node เดียว
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpanNode {
+    pub name: String,
+    pub calls: usize,
+    pub total: Duration,
+    pub children: Vec<Self>,
+}
+
+impl SpanNode {
+    /// เวลาที่ใช้ใน span นี้เองโดยไม่นับลูก
+    #[must_use]
+    pub fn self_time(&self) -> Duration {
+        let children: Duration = self.children.iter().map(|child| child.total).sum();
+        self.total.saturating_sub(children)
+    }
+
+    fn child_mut(children: &mut Vec<Self>, name: &str) -> usize {
+        if let Some(index) = children.iter().position(|node| node.name == name) {
+            return index;
+        }
+        children.push(Self {
+            name: name.to_string(),
+            calls: 0,
+            total: Duration::ZERO,
+            children: Vec::new(),
+        });
+        children.len() - 1
+    }
+}
+
+/// 📊 ผลการ profile: รายการ span ตามลำดับที่เปิด
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Profile {
+    events: Vec<SpanEvent>,
+}
+
+impl Profile {
+    /// span ทุกช่วงตามลำดับที่เปิด
+    #[must_use]
+    pub fn events(&self) -> &[SpanEvent] {
+        &self.events
+    }
+
+    /// ลำดับชื่อจาก span บนสุดลงมาถึง `index`
+    fn path(&self, mut index: usize) -> Vec<&str> {
+        let mut path = vec![self.events[index].name.as_ref()];
+        while let Some(parent) = self.events[index].parent {
+            path.push(&self.events[parent].name);
+            index = parent;
+        }
+        path.reverse();
+        path
+    }
+
+    /// รวม span เป็นต้นไม้ตามเส้นทางการเรียก (เรียงตามลำดับที่พบครั้งแรก)
+    #[must_use]
+    pub fn tree(&self) -> Vec<SpanNode> {
+        let mut roots = Vec::new();
+        for (index, event) in self.events.iter().enumerate() {
+            let mut children = &mut roots;
+            let path = self.path(index);
+            let Some((last, ancestors)) = path.split_last() else {
+                continue;
+            };
+            for name in ancestors {
+                let position = SpanNode::child_mut(children, name);
+                children = &mut children[position].children;
+            }
+            let position = SpanNode::child_mut(children, last);
+            children[position].calls += 1;
+            children[position].total += event.duration;
+        }
+        roots
+    }
+
+    /// 📜 folded stacks: หนึ่งบรรทัดต่อเส้นทาง `a;b;c <self time µs>`
+    #[must_use]
+    pub fn folded_stacks(&self) -> String {
+        fn walk(nodes: &[SpanNode], prefix: &str, out: &mut String) {
+            for node in nodes {
+                let path = if prefix.is_empty() {
+                    node.name.clone()
+                } else {
+                    format!("{prefix};{}", node.name)
+                };
+                let _ = writeln!(out, "{path} {}", node.self_time().as_micros());
+                walk(&node.children, &path, out);
+            }
+        }
+
+        let mut out = String::new();
+        walk(&self.tree(), "", &mut out);
+        out
+    }
+
+    /// 🧭 Chrome trace JSON (complete events `"ph": "X"`, หน่วย µs)
+    #[must_use]
+    pub fn chrome_trace_json(&self) -> String {
+        let events: Vec<serde_json::Value> = self
+            .events
+            .iter()
+            .map(|event| {
+                serde_json::json!({
+                    "name": event.name,
+                    "cat": "rust_concepts",
+                    "ph": "X",
+                    "ts": micros(event.start),
+                    "dur": micros(event.duration),
+                    "pid": 1,
+                    "tid": 1,
+                })
+            })
+            .collect();
+        serde_json::json!({ "traceEvents": events, "displayTimeUnit": "ms" }).to_string()
+    }
+
+    /// บันทึก Chrome trace ลงไฟล์ แล้วเปิดไฟล์นั้นใน `chrome://tracing`
+    ///
+    /// # Errors
+    ///
+    /// คืน `io::Error` ถ้าเขียนไฟล์ไม่สำเร็จ
+    pub fn write_chrome_trace(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        std::fs::write(path, self.chrome_trace_json())
+    }
+}
+
+/// แปลง `Duration` เป็น µs แบบมีทศนิยม (ตามที่ Chrome trace ต้องการ)
+fn micros(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1_000_000.0
+}
+
+/// ความกว้างของแท่งใน text flamegraph
+const BAR_WIDTH: u8 = 30;
+
+impl fmt::Display for Profile {
+    /// 🌳 text flamegraph: ย่อหน้าตามความลึก แท่งยาวตามสัดส่วนเวลาของทั้งหมด
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn write_nodes(
+            f: &mut fmt::Formatter<'_>,
+            nodes: &[SpanNode],
+            depth: usize,
+            grand_total: f64,
+        ) -> fmt::Result {
+            for node in nodes {
+                let share = if grand_total > 0.0 {
+                    node.total.as_secs_f64() / grand_total
+                } else {
+                    0.0
+                };
+                let filled = (0..BAR_WIDTH)
+                    .take_while(|&cell| f64::from(cell) < share * f64::from(BAR_WIDTH))
+                    .count();
+                let label = format!("{}{}", "  ".repeat(depth), node.name);
+                writeln!(
+                    f,
+                    "{label:<32} {}{} {:>5.1}% {:>10.3?} ×{}",
+                    "█".repeat(filled),
+                    "░".repeat(usize::from(BAR_WIDTH) - filled),
+                    share * 100.0,
+                    node.total,
+                    node.calls
+                )?;
+                write_nodes(f, &node.children, depth + 1, grand_total)?;
+            }
+            Ok(())
+        }
+
+        let tree = self.tree();
+        let grand_total: f64 = tree.iter().map(|node| node.total.as_secs_f64()).sum();
+        if tree.is_empty() {
+            return writeln!(f, "(ไม่มี span ถูกบันทึก)");
+        }
+        write_nodes(f, &tree, 0, grand_total)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_profile() -> Profile {
+        let profiler = Profiler::start();
+        for frame in 0..3 {
+            span!("frame");
+            {
+                span!("update");
+                span!(format!("system:{}", frame % 2));
+            }
+            span!("render");
+        }
+        profiler.finish()
+    }
+
+    #[test]
+    fn test_spans_nest_into_a_tree() {
+        let profile = sample_profile();
+        assert_eq!(profile.events().len(), 12);
+
+        let tree = profile.tree();
+        assert_eq!(tree.len(), 1);
+        let frame = &tree[0];
+        assert_eq!((frame.name.as_str(), frame.calls), ("frame", 3));
+
+        let names: Vec<&str> = frame.children.iter().map(|n| n.name.as_str()).collect();
+        assert_eq!(names, ["update", "render"]);
+        let systems: Vec<(&str, usize)> = frame.children[0]
+            .children
+            .iter()
+            .map(|n| (n.name.as_str(), n.calls))
+            .collect();
+        assert_eq!(systems, [("system:0", 2), ("system:1", 1)]);
+
+        // span ลูกอยู่ภายในช่วงเวลาของแม่เสมอ
+        for event in profile.events() {
+            if let Some(parent) = event.parent {
+                let parent = &profile.events()[parent];
+                assert!(event.start >= parent.start);
+                assert!(event.start + event.duration <= parent.start + parent.duration);
+            }
+        }
+        assert!(frame.self_time() <= frame.total);
+    }
+
+    #[test]
+    fn test_exports() {
+        let profile = sample_profile();
+
+        let folded = profile.folded_stacks();
+        let paths: Vec<&str> = folded
+            .lines()
+            .map(|line| line.rsplit_once(' ').unwrap().0)
+            .collect();
+        assert_eq!(
+            paths,
+            [
+                "frame",
+                "frame;update",
+                "frame;update;system:0",
+                "frame;update;system:1",
+                "frame;render"
+            ]
+        );
+
+        let trace: serde_json::Value = serde_json::from_str(&profile.chrome_trace_json()).unwrap();
+        let events = trace["traceEvents"].as_array().unwrap();
+        assert_eq!(events.len(), 12);
+        assert_eq!(events[0]["name"], "frame");
+        assert_eq!(events[0]["ph"], "X");
+
+        let text = profile.to_string();
+        assert!(text.starts_with("frame"));
+        assert!(text.contains("\n    system:0"));
+    }
+
+    #[test]
+    fn test_spans_without_profiler_are_ignored() {
+        assert!(!is_recording());
+        let mut evaluated = false;
+        {
+            span!({
+                evaluated = true;
+                "never"
+            });
+        }
+        assert!(!evaluated, "span name must not be evaluated when idle");
+
+        let profiler = Profiler::start();
+        assert!(is_recording());
+        let open = enter("still open");
+        let profile = profiler.finish();
+        drop(open);
+        assert!(!is_recording());
+        assert_eq!(profile.events().len(), 1);
+        assert_eq!(Profiler::start().finish(), Profile::default());
+    }
+}
@@ -0,0 +1,218 @@
+//! 📈 Progress Tracker - จำว่าผู้เรียนทำแบบฝึกหัดข้อไหนผ่านแล้ว
+//!
+//! เก็บเป็น JSON ที่ `<data dir>/rust_concepts/progress.json` (ที่เดียวกับ `tasks.json`)
+//! - 📝 `record_exercise` - นับจำนวนครั้งที่ลอง, ผลดีที่สุด และเวลาที่ทำผ่านครั้งแรก
+//! - 🏆 `record_quiz` - คะแนนดีที่สุดของแบบทดสอบแต่ละบท
+//! - 💾 `save` เขียนไฟล์ชั่วคราวแล้ว rename แบบเดียวกับ `TaskManager::save_to_file`
+//! - 🧩 ทุก field มี `#[serde(default)]` - ไฟล์เก่ายังอ่านได้เมื่อเพิ่มข้อมูลชนิดใหม่
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// ไฟล์ progress เริ่มต้น: `<data dir>/rust_concepts/progress.json`
+#[must_use]
+pub fn default_file() -> PathBuf {
+    dirs::data_local_dir().map_or_else(
+        || PathBuf::from("progress.json"),
+        |dir| dir.join("rust_concepts").join("progress.json"),
+    )
+}
+
+/// 🏁 ผลของแบบฝึกหัดหนึ่งข้อ
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExerciseRecord {
+    pub attempts: u32,
+    /// จำนวน test case ที่ผ่านมากที่สุดเท่าที่เคยทำได้
+    pub best_passed: usize,
+    pub total: usize,
+    pub last_attempt: DateTime<Utc>,
+    /// ครั้งแรกที่ผ่านครบทุก case
+    pub solved_at: Option<DateTime<Utc>>,
+}
+
+impl ExerciseRecord {
+    #[must_use]
+    pub const fn is_solved(&self) -> bool {
+        self.solved_at.is_some()
+    }
+}
+
+/// 🏆 ผลแบบทดสอบของหนึ่งบท
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuizRecord {
+    pub attempts: u32,
+    pub best_correct: usize,
+    /// จำนวนข้อในรอบล่าสุด (คลังคำถามเพิ่มข้อได้)
+    pub total: usize,
+    pub last_taken: DateTime<Utc>,
+}
+
+/// 📈 ความคืบหน้าทั้งหมดของผู้เรียน
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Progress {
+    #[serde(default)]
+    pub exercises: BTreeMap<String, ExerciseRecord>,
+    /// key คือเลขบท
+    #[serde(default)]
+    pub quizzes: BTreeMap<u8, QuizRecord>,
+}
+
+impl Progress {
+    /// อ่านจากไฟล์ - ยังไม่มีไฟล์ถือว่าเริ่มใหม่
+    ///
+    /// # Errors
+    ///
+    /// คืน error เมื่ออ่านไฟล์ไม่ได้หรือ JSON เสีย
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("Cannot read progress from {}", path.display()))?;
+        serde_json::from_str(&text)
+            .with_context(|| format!("Progress file {} is corrupted", path.display()))
+    }
+
+    /// บันทึกลงไฟล์ (สร้างโฟลเดอร์ให้ถ้ายังไม่มี)
+    ///
+    /// # Errors
+    ///
+    /// คืน error เมื่อสร้างโฟลเดอร์หรือเขียนไฟล์ไม่ได้
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            std::fs::create_dir_all(dir)
+                .with_context(|| format!("Cannot create directory {}", dir.display()))?;
+        }
+        let json = serde_json::to_string_pretty(self).context("Failed to serialize progress")?;
+        let mut temp_name = path.file_name().unwrap_or_default().to_os_string();
+        temp_name.push(".tmp");
+        let temp_path = path.with_file_name(temp_name);
+        std::fs::write(&temp_path, json)
+            .with_context(|| format!("Failed to write to {}", temp_path.display()))?;
+        if let Err(error) = std::fs::rename(&temp_path, path) {
+            let _ = std::fs::remove_file(&temp_path);
+            return Err(error).with_context(|| format!("Failed to replace {}", path.display()));
+        }
+        Ok(())
+    }
+
+    /// บันทึกผลการตรวจหนึ่งครั้ง แล้วคืน record ล่าสุด
+    pub fn record_exercise(
+        &mut self,
+        id: &str,
+        passed: usize,
+        total: usize,
+        now: DateTime<Utc>,
+    ) -> &ExerciseRecord {
+        let record = self
+            .exercises
+            .entry(id.to_string())
+            .or_insert_with(|| ExerciseRecord {
+                attempts: 0,
+                best_passed: 0,
+                total,
+                last_attempt: now,
+                solved_at: None,
+            });
+        record.attempts += 1;
+        record.total = total;
+        record.best_passed = record.best_passed.max(passed);
+        record.last_attempt = now;
+        if passed == total && record.solved_at.is_none() {
+            record.solved_at = Some(now);
+        }
+        record
+    }
+
+    /// บันทึกคะแนนแบบทดสอบหนึ่งรอบ แล้วคืน record ล่าสุด
+    pub fn record_quiz(
+        &mut self,
+        chapter: u8,
+        correct: usize,
+        total: usize,
+        now: DateTime<Utc>,
+    ) -> &QuizRecord {
+        let record = self.quizzes.entry(chapter).or_insert(QuizRecord {
+            attempts: 0,
+            best_correct: 0,
+            total,
+            last_taken: now,
+        });
+        record.attempts += 1;
+        record.total = total;
+        record.best_correct = record.best_correct.max(correct);
+        record.last_taken = now;
+        record
+    }
+
+    #[must_use]
+    pub fn quiz(&self, chapter: u8) -> Option<&QuizRecord> {
+        self.quizzes.get(&chapter)
+    }
+
+    #[must_use]
+    pub fn exercise(&self, id: &str) -> Option<&ExerciseRecord> {
+        self.exercises.get(id)
+    }
+
+    #[must_use]
+    pub fn solved_count(&self) -> usize {
+        self.exercises
+            .values()
+            .filter(|record| record.is_solved())
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn at(text: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(text)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn test_record_keeps_best_and_first_solve() {
+        let mut progress = Progress::default();
+        progress.record_exercise("07-2", 1, 4, at("2025-03-10T08:00:00Z"));
+        progress.record_exercise("07-2", 4, 4, at("2025-03-10T09:00:00Z"));
+        let record = progress
+            .record_exercise("07-2", 2, 4, at("2025-03-11T09:00:00Z"))
+            .clone();
+
+        assert_eq!(record.attempts, 3);
+        assert_eq!(record.best_passed, 4);
+        assert_eq!(record.solved_at, Some(at("2025-03-10T09:00:00Z")));
+        assert_eq!(record.last_attempt, at("2025-03-11T09:00:00Z"));
+        assert_eq!(progress.solved_count(), 1);
+    }
+
+    #[test]
+    fn test_save_and_load_round_trip() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("nested").join("progress.json");
+        assert_eq!(Progress::load(&path).unwrap(), Progress::default());
+
+        let mut progress = Progress::default();
+        progress.record_exercise("01-1", 3, 3, at("2025-03-10T08:00:00Z"));
+        progress.record_quiz(7, 4, 5, at("2025-03-10T08:05:00Z"));
+        progress.record_quiz(7, 2, 5, at("2025-03-11T08:05:00Z"));
+        progress.save(&path).unwrap();
+        assert_eq!(progress.quiz(7).map(|record| record.best_correct), Some(4));
+        assert_eq!(Progress::load(&path).unwrap(), progress);
+
+        std::fs::write(&path, "{ not json").unwrap();
+        assert!(Progress::load(&path).is_err());
+        // ไฟล์เก่าที่ยังไม่มี field ใหม่ก็อ่านได้
+        std::fs::write(&path, "{}").unwrap();
+        assert_eq!(Progress::load(&path).unwrap(), Progress::default());
+    }
+}
@@ -0,0 +1,345 @@
+//! 📚 คลังคำถาม - ไฟล์ TOML ใน `banks/` ถูกฝังเข้า binary ด้วย `include_str!`
+//!
+//! ```toml
+//! title = "Ownership"
+//!
+//! [[question]]
+//! kind = "choice"          # เลือกตอบ - answer คือ index ของ options (เริ่มจาก 0)
+//! prompt = "..."
+//! options = ["...", "..."]
+//! answer = 2
+//! explanation = "..."
+//!
+//! [[question]]
+//! kind = "fill"            # เติมคำ - ตอบตรงกับ answers ตัวใดก็ได้ (ไม่สนตัวพิมพ์)
+//! prompt = "..."
+//! answers = ["clone", "clone()"]
+//! explanation = "..."
+//! ```
+
+use std::fmt;
+
+use serde::Deserialize;
+
+/// ไฟล์คำถามที่ฝังไว้ คู่กับเลขบท
+const BANKS: &[(u8, &str)] = &[
+    (1, include_str!("banks/01_basics.toml")),
+    (2, include_str!("banks/02_ownership.toml")),
+    (6, include_str!("banks/06_collections.toml")),
+    (7, include_str!("banks/07_error_handling.toml")),
+    (9, include_str!("banks/09_traits.toml")),
+    (10, include_str!("banks/10_lifetimes.toml")),
+];
+
+/// ❌ error ของคลังคำถาม
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QuizError {
+    /// บทนี้ยังไม่มีคำถาม
+    NoBank(u8),
+    /// TOML อ่านไม่ได้
+    Parse { chapter: u8, message: String },
+    /// คำถามข้อที่ `question` (เริ่มจาก 1) ไม่สมเหตุสมผล
+    Invalid {
+        chapter: u8,
+        question: usize,
+        message: String,
+    },
+}
+
+impl fmt::Display for QuizError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoBank(chapter) => {
+                let available: Vec<String> = chapters().map(|number| number.to_string()).collect();
+                write!(
+                    f,
+                    "chapter {chapter} has no quiz yet (available: {})",
+                    available.join(", ")
+                )
+            }
+            Self::Parse { chapter, message } => {
+                write!(f, "invalid quiz TOML for chapter {chapter}: {message}")
+            }
+            Self::Invalid {
+                chapter,
+                question,
+                message,
+            } => write!(f, "chapter {chapter} question {question}: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for QuizError {}
+
+/// ❓ คำถามหนึ่งข้อ
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case", deny_unknown_fields)]
+pub enum Question {
+    /// เลือกตอบ
+    Choice {
+        prompt: String,
+        options: Vec<String>,
+        answer: usize,
+        explanation: String,
+    },
+    /// เติมคำ
+    Fill {
+        prompt: String,
+        answers: Vec<String>,
+        explanation: String,
+    },
+}
+
+impl Question {
+    #[must_use]
+    pub fn prompt(&self) -> &str {
+        match self {
+            Self::Choice { prompt, .. } | Self::Fill { prompt, .. } => prompt,
+        }
+    }
+
+    #[must_use]
+    pub fn explanation(&self) -> &str {
+        match self {
+            Self::Choice { explanation, .. } | Self::Fill { explanation, .. } => explanation,
+        }
+    }
+
+    /// คำตอบที่ถูกในรูปที่แสดงให้ผู้เรียนดู
+    #[must_use]
+    pub fn correct_answer(&self) -> String {
+        match self {
+            Self::Choice {
+                options, answer, ..
+            } => format!("{}) {}", option_label(*answer), options[*answer]),
+            Self::Fill { answers, .. } => answers[0].clone(),
+        }
+    }
+
+    /// ตรวจคำตอบ - `None` ถ้าอ่านไม่ออก (เช่นตอบ `e` ในข้อที่มี 4 ตัวเลือก) ให้ถามใหม่
+    ///
+    /// ข้อเลือกตอบรับทั้งตัวอักษร (`b`) และเลข (`2`), ข้อเติมคำไม่สนตัวพิมพ์/ช่องว่าง/backtick
+    #[must_use]
+    pub fn check(&self, input: &str) -> Option<bool> {
+        let input = input.trim();
+        if input.is_empty() {
+            return None;
+        }
+        match self {
+            Self::Choice {
+                options, answer, ..
+            } => {
+                let index = parse_option(input)?;
+                (index < options.len()).then_some(index == *answer)
+            }
+            Self::Fill { answers, .. } => {
+                let input = normalize(input);
+                Some(answers.iter().any(|answer| normalize(answer) == input))
+            }
+        }
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if self.prompt().trim().is_empty() {
+            return Err("prompt is empty".to_string());
+        }
+        if self.explanation().trim().is_empty() {
+            return Err("explanation is empty".to_string());
+        }
+        match self {
+            Self::Choice {
+                options, answer, ..
+            } => {
+                if !(2..=26).contains(&options.len()) {
+                    return Err(format!("needs 2-26 options, got {}", options.len()));
+                }
+                if *answer >= options.len() {
+                    return Err(format!(
+                        "answer index {answer} is out of range for {} options",
+                        options.len()
+                    ));
+                }
+            }
+            Self::Fill { answers, .. } => {
+                if answers.iter().all(|answer| answer.trim().is_empty()) {
+                    return Err("needs at least one accepted answer".to_string());
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// `0 → 'a'`, `1 → 'b'`, ...
+#[must_use]
+pub fn option_label(index: usize) -> char {
+    u8::try_from(index)
+        .ok()
+        .filter(|index| *index < 26)
+        .map_or('?', |index| char::from(b'a' + index))
+}
+
+/// `"b"`/`"B"` → 1, `"2"` → 1
+fn parse_option(input: &str) -> Option<usize> {
+    if let Ok(number) = input.parse::<usize>() {
+        return number.checked_sub(1);
+    }
+    let mut chars = input.chars();
+    match (chars.next(), chars.next()) {
+        (Some(letter), None) if letter.is_ascii_alphabetic() => {
+            let byte = u8::try_from(letter.to_ascii_lowercase()).ok()?;
+            Some(usize::from(byte - b'a'))
+        }
+        _ => None,
+    }
+}
+
+fn normalize(text: &str) -> String {
+    text.trim()
+        .trim_matches('`')
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawBank {
+    title: String,
+    #[serde(rename = "question")]
+    questions: Vec<Question>,
+}
+
+/// 📚 คำถามทั้งหมดของหนึ่งบท
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuestionBank {
+    pub chapter: u8,
+    pub title: String,
+    pub questions: Vec<Question>,
+}
+
+impl QuestionBank {
+    /// อ่านจาก TOML แล้วตรวจว่าทุกข้อใช้ได้
+    ///
+    /// # Errors
+    ///
+    /// `QuizError::Parse` เมื่อ TOML ผิดรูปแบบ, `QuizError::Invalid` เมื่อคำถามข้อใดไม่สมเหตุสมผล
+    pub fn from_toml(chapter: u8, text: &str) -> Result<Self, QuizError> {
+        let raw: RawBank = toml::from_str(text).map_err(|error| QuizError::Parse {
+            chapter,
+            message: error.message().to_string(),
+        })?;
+        for (index, question) in raw.questions.iter().enumerate() {
+            question.validate().map_err(|message| QuizError::Invalid {
+                chapter,
+                question: index + 1,
+                message,
+            })?;
+        }
+        if raw.questions.is_empty() {
+            return Err(QuizError::Invalid {
+                chapter,
+                question: 0,
+                message: "bank has no questions".to_string(),
+            });
+        }
+        Ok(Self {
+            chapter,
+            title: raw.title,
+            questions: raw.questions,
+        })
+    }
+}
+
+/// เลขบทที่มีแบบทดสอบ
+pub fn chapters() -> impl Iterator<Item = u8> {
+    BANKS.iter().map(|(chapter, _)| *chapter)
+}
+
+/// โหลดคลังคำถามที่ฝังไว้ของบท `chapter`
+///
+/// # Errors
+///
+/// `QuizError::NoBank` เมื่อบทนี้ยังไม่มีคำถาม หรือ error จาก [`QuestionBank::from_toml`]
+pub fn load(chapter: u8) -> Result<QuestionBank, QuizError> {
+    let (_, text) = BANKS
+        .iter()
+        .find(|(number, _)| *number == chapter)
+        .ok_or(QuizError::NoBank(chapter))?;
+    QuestionBank::from_toml(chapter, text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_embedded_banks_are_valid() {
+        for chapter in chapters() {
+            let bank = load(chapter).unwrap_or_else(|error| panic!("{error}"));
+            assert!(crate::chapters::registry().get(chapter).is_some());
+            assert!(bank.questions.len() >= 4, "chapter {chapter}");
+        }
+        assert_eq!(load(3), Err(QuizError::NoBank(3)));
+    }
+
+    #[test]
+    fn test_check_answers() {
+        let choice = Question::Choice {
+            prompt: "?".to_string(),
+            options: vec!["x".to_string(), "y".to_string(), "z".to_string()],
+            answer: 1,
+            explanation: "!".to_string(),
+        };
+        assert_eq!(choice.check("b"), Some(true));
+        assert_eq!(choice.check(" B "), Some(true));
+        assert_eq!(choice.check("2"), Some(true));
+        assert_eq!(choice.check("a"), Some(false));
+        assert_eq!(choice.check("d"), None);
+        assert_eq!(choice.check("0"), None);
+        assert_eq!(choice.check("yes"), None);
+        assert_eq!(choice.correct_answer(), "b) y");
+
+        let fill = Question::Fill {
+            prompt: "?".to_string(),
+            answers: vec!["&str".to_string(), "& str".to_string()],
+            explanation: "!".to_string(),
+        };
+        assert_eq!(fill.check("`&STR`"), Some(true));
+        assert_eq!(fill.check("&  str"), Some(true));
+        assert_eq!(fill.check("String"), Some(false));
+        assert_eq!(fill.check("   "), None);
+    }
+
+    #[test]
+    fn test_invalid_banks_are_rejected() {
+        let bad_answer = r#"
+            title = "t"
+            [[question]]
+            kind = "choice"
+            prompt = "p"
+            options = ["a", "b"]
+            answer = 2
+            explanation = "e"
+        "#;
+        assert_eq!(
+            QuestionBank::from_toml(4, bad_answer),
+            Err(QuizError::Invalid {
+                chapter: 4,
+                question: 1,
+                message: "answer index 2 is out of range for 2 options".to_string(),
+            })
+        );
+
+        let unknown_kind = "title = \"t\"\n[[question]]\nkind = \"essay\"\nprompt = \"p\"\n";
+        assert!(matches!(
+            QuestionBank::from_toml(4, unknown_kind),
+            Err(QuizError::Parse { chapter: 4, .. })
+        ));
+        assert!(matches!(
+            QuestionBank::from_toml(4, "title = \"t\"\nquestion = []\n"),
+            Err(QuizError::Invalid { question: 0, .. })
+        ));
+    }
+}
@@ -0,0 +1,85 @@
+//! 📝 Quiz - `cargo run -- --quiz <บท>` ทดสอบความเข้าใจหลังอ่านจบแต่ละบท
+//!
+//! - 📚 คำถามเขียนเป็น TOML ใน `src/quiz/banks/` (ฝังเข้า binary แล้ว ไม่ต้องมีไฟล์ตอนรัน)
+//! - 🔀 สลับลำดับคำถามและตัวเลือกทุกรอบด้วย `random::Pcg32`
+//! - 💡 ตอบแล้วเฉลยพร้อมคำอธิบายทันที
+//! - 🏆 คะแนนดีที่สุดของแต่ละบทเก็บในไฟล์ progress เดียวกับแบบฝึกหัด
+
+pub mod bank;
+pub mod session;
+
+use std::io::{BufRead, Write};
+use std::path::Path;
+
+use anyhow::Result;
+use chrono::Utc;
+
+pub use bank::{Question, QuestionBank, QuizError, chapters, load};
+pub use session::{Quiz, Score};
+
+use crate::progress::Progress;
+use crate::random::Rng;
+
+/// ทำแบบทดสอบของบท `chapter` หนึ่งรอบ แล้วบันทึกคะแนนลง `progress_file`
+///
+/// # Errors
+///
+/// คืน error เมื่อบทนี้ไม่มีคำถาม, อ่าน/เขียน terminal ไม่ได้ หรือบันทึก progress ไม่ได้
+pub fn run(
+    chapter: u8,
+    rng: &mut impl Rng,
+    input: &mut impl BufRead,
+    out: &mut impl Write,
+    progress_file: &Path,
+) -> Result<Score> {
+    let bank = load(chapter)?;
+    let mut progress = Progress::load(progress_file)?;
+    let previous_best = progress.quiz(chapter).map(|record| record.best_correct);
+
+    let score = Quiz::shuffled(&bank, rng).run(input, out)?;
+
+    let record = progress.record_quiz(chapter, score.correct, score.total, Utc::now());
+    match previous_best {
+        Some(best) if score.correct > best => {
+            writeln!(out, "🎉 ทำลายสถิติเดิม ({best}/{})!", score.total)?;
+        }
+        Some(_) => writeln!(
+            out,
+            "🏆 คะแนนดีที่สุด: {}/{} (ทำมาแล้ว {} รอบ)",
+            record.best_correct, record.total, record.attempts
+        )?,
+        None => {}
+    }
+    progress.save(progress_file)?;
+    Ok(score)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{Pcg32, SeedableRng};
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_run_persists_best_score() {
+        let dir = TempDir::new().unwrap();
+        let file = dir.path().join("progress.json");
+        let mut rng = Pcg32::seed_from_u64(1);
+
+        // ตอบ a ทุกข้อ (ข้อเติมคำก็ตอบ a ซึ่งผิดเสมอ)
+        let answers = "a\n".repeat(10);
+        let mut out = Vec::new();
+        let first = run(1, &mut rng, &mut answers.as_bytes(), &mut out, &file).unwrap();
+        let mut out = Vec::new();
+        run(1, &mut rng, &mut &b""[..], &mut out, &file).unwrap();
+        assert!(String::from_utf8(out).unwrap().contains("ทำมาแล้ว 2 รอบ"));
+
+        let record = Progress::load(&file).unwrap().quiz(1).cloned().unwrap();
+        assert_eq!(record.attempts, 2);
+        assert_eq!(record.best_correct, first.correct);
+        assert_eq!(record.total, load(1).unwrap().questions.len());
+
+        let error = run(3, &mut rng, &mut &b""[..], &mut Vec::new(), &file).unwrap_err();
+        assert!(error.to_string().contains("available: 1, 2"), "{error}");
+    }
+}
@@ -0,0 +1,215 @@
+//! 🎯 รอบการทำแบบทดสอบ - สลับลำดับคำถาม/ตัวเลือก ถามทีละข้อ แล้วเฉลยพร้อมคำอธิบาย
+
+use std::fmt;
+use std::io::{self, BufRead, Write};
+
+use super::bank::{Question, QuestionBank, option_label};
+use crate::random::Rng;
+
+/// 🏅 คะแนนของหนึ่งรอบ
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Score {
+    pub correct: usize,
+    pub total: usize,
+}
+
+impl Score {
+    /// เปอร์เซ็นต์ (ปัดลง)
+    #[must_use]
+    pub const fn percent(self) -> usize {
+        match (self.correct * 100).checked_div(self.total) {
+            Some(percent) => percent,
+            None => 0,
+        }
+    }
+}
+
+impl fmt::Display for Score {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{} ({}%)", self.correct, self.total, self.percent())
+    }
+}
+
+/// 🎯 แบบทดสอบที่สลับลำดับแล้ว
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Quiz {
+    pub title: String,
+    pub questions: Vec<Question>,
+}
+
+impl Quiz {
+    /// สลับลำดับคำถาม และลำดับตัวเลือกของข้อเลือกตอบ (ย้าย `answer` ตามไปด้วย)
+    /// - จำตำแหน่งคำตอบไม่ได้ ต้องอ่านจริง
+    ///
+    /// # Panics
+    ///
+    /// ไม่เกิดขึ้นจริง - `order` เป็น permutation ของทุกตัวเลือกจึงหาคำตอบเดิมเจอเสมอ
+    pub fn shuffled(bank: &QuestionBank, rng: &mut impl Rng) -> Self {
+        let mut questions = bank.questions.clone();
+        rng.shuffle(&mut questions);
+        for question in &mut questions {
+            if let Question::Choice {
+                options, answer, ..
+            } = question
+            {
+                let mut order: Vec<usize> = (0..options.len()).collect();
+                rng.shuffle(&mut order);
+                *answer = order
+                    .iter()
+                    .position(|original| original == answer)
+                    .expect("order is a permutation of every option");
+                *options = order.iter().map(|&index| options[index].clone()).collect();
+            }
+        }
+        Self {
+            title: bank.title.clone(),
+            questions,
+        }
+    }
+
+    /// ถามทุกข้อจาก `input` แล้วเขียนผลลง `out` - ถ้า input หมดกลางทาง ข้อที่เหลือนับว่าผิด
+    ///
+    /// # Errors
+    ///
+    /// คืน error เมื่ออ่าน `input` หรือเขียน `out` ไม่ได้
+    pub fn run(&self, input: &mut impl BufRead, out: &mut impl Write) -> io::Result<Score> {
+        let total = self.questions.len();
+        let mut correct = 0;
+        writeln!(out, "📝 แบบทดสอบ: {} ({total} ข้อ)", self.title)?;
+        for (number, question) in self.questions.iter().enumerate() {
+            writeln!(out, "\n❓ {}/{total} {}", number + 1, question.prompt())?;
+            let hint = match question {
+                Question::Choice { options, .. } => {
+                    for (index, option) in options.iter().enumerate() {
+                        writeln!(out, "   {}) {option}", option_label(index))?;
+                    }
+                    format!("a-{}", option_label(options.len() - 1))
+                }
+                Question::Fill { .. } => "พิมพ์คำตอบ".to_string(),
+            };
+            let Some(is_correct) = ask(question, &hint, input, out)? else {
+                writeln!(out, "\n⏹️ จบก่อนครบทุกข้อ")?;
+                break;
+            };
+            if is_correct {
+                correct += 1;
+                writeln!(out, "✅ ถูกต้อง!")?;
+            } else {
+                writeln!(out, "❌ ยังไม่ใช่ - คำตอบคือ {}", question.correct_answer())?;
+            }
+            writeln!(out, "💡 {}", question.explanation())?;
+        }
+        let score = Score { correct, total };
+        writeln!(out, "\n🏅 ได้ {score}")?;
+        Ok(score)
+    }
+}
+
+/// ถามจนได้คำตอบที่อ่านออก - `None` เมื่อ input หมด
+fn ask(
+    question: &Question,
+    hint: &str,
+    input: &mut impl BufRead,
+    out: &mut impl Write,
+) -> io::Result<Option<bool>> {
+    let mut line = String::new();
+    loop {
+        write!(out, "👉 ({hint}): ")?;
+        out.flush()?;
+        line.clear();
+        if input.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+        if let Some(result) = question.check(&line) {
+            return Ok(Some(result));
+        }
+        writeln!(out, "🤔 อ่านคำตอบไม่ออก ลองใหม่อีกครั้ง")?;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{Pcg32, SeedableRng};
+
+    fn bank() -> QuestionBank {
+        QuestionBank::from_toml(
+            2,
+            r#"
+            title = "demo"
+            [[question]]
+            kind = "choice"
+            prompt = "pick y"
+            options = ["x", "y", "z", "w"]
+            answer = 1
+            explanation = "because"
+            [[question]]
+            kind = "fill"
+            prompt = "type mut"
+            answers = ["mut"]
+            explanation = "keyword"
+            "#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_shuffle_keeps_answers_pointing_at_same_option() {
+        for seed in 0..20 {
+            let quiz = Quiz::shuffled(&bank(), &mut Pcg32::seed_from_u64(seed));
+            let choice = quiz
+                .questions
+                .iter()
+                .find(|question| matches!(question, Question::Choice { .. }))
+                .unwrap();
+            let Question::Choice {
+                options, answer, ..
+            } = choice
+            else {
+                unreachable!()
+            };
+            assert_eq!(options[*answer], "y");
+            assert_eq!(options.len(), 4);
+        }
+    }
+
+    #[test]
+    fn test_run_scores_and_explains() {
+        let quiz = Quiz::shuffled(&bank(), &mut Pcg32::seed_from_u64(7));
+        // ตอบถูกทุกข้อตามลำดับหลังสลับ โดยเริ่มด้วยบรรทัดว่างซึ่งต้องถูกถามใหม่
+        let mut answers = vec!["  ".to_string()];
+        for question in &quiz.questions {
+            answers.push(match question {
+                Question::Choice { answer, .. } => option_label(*answer).to_string(),
+                Question::Fill { .. } => "MUT".to_string(),
+            });
+        }
+        let mut out = Vec::new();
+        let score = quiz
+            .run(&mut answers.join("\n").as_bytes(), &mut out)
+            .unwrap();
+        let text = String::from_utf8(out).unwrap();
+        assert_eq!(
+            score,
+            Score {
+                correct: 2,
+                total: 2
+            }
+        );
+        assert_eq!(score.to_string(), "2/2 (100%)");
+        assert!(text.contains("🤔"));
+        assert!(text.contains("💡 because") && text.contains("💡 keyword"));
+
+        // input หมดหลังข้อแรก: ข้อที่เหลือนับว่าผิด
+        let mut out = Vec::new();
+        let score = quiz.run(&mut &b"zzz\n"[..], &mut out).unwrap();
+        assert_eq!(
+            score,
+            Score {
+                correct: 0,
+                total: 2
+            }
+        );
+        assert!(String::from_utf8(out).unwrap().contains("⏹️"));
+    }
+}
@@ -0,0 +1,744 @@
+//! 🧩 `RegexLite` - regex engine ขนาดเล็กที่เขียนเองทั้งหมด: parse → AST → NFA → จำลองการทำงาน
+//!
+//! บทเรียนเรื่อง parser และ automata ในไฟล์เดียว:
+//! 1. 📝 **parse** - recursive descent แปลง pattern เป็น `Ast` (ลำดับความสำคัญ: `|` < ต่อกัน < `*+?{}`)
+//!    error บอกตำแหน่ง (นับเป็นตัวอักษร) ของจุดที่ผิด
+//! 2. 🏗️ **compile** - แปลง AST เป็น NFA แบบ Thompson: state ที่กินตัวอักษรหนึ่งตัว หรือ `Split` ที่แตกสองทาง
+//! 3. 🏃 **simulate** - เดินทุก state ที่เป็นไปได้ "พร้อมกัน" ทีละตัวอักษร
+//!    ใช้เวลา O(ความยาวข้อความ × จำนวน state) เสมอ ไม่มีวันระเบิดแบบ backtracking กับ `(a*)*b`
+//!
+//! รองรับ: ตัวอักษร, `.`, `[a-z]` `[^...]`, `\d` `\w` `\s`, `( )`, `|`, `*` `+` `?` `{n}` `{n,}` `{n,m}`, `^` `$`
+//!
+//! ```
+//! use rust_concepts::regex_lite::{RegexError, RegexLite};
+//!
+//! let re = RegexLite::new(r"(cat|dog)s?")?;
+//! assert!(re.is_match("hot dogs!"));
+//! assert_eq!(re.find("my cats").map(|m| m.as_str()), Some("cats"));
+//!
+//! assert_eq!(RegexLite::new("a(b|c").unwrap_err(), RegexError::UnclosedGroup(1));
+//! # Ok::<(), RegexError>(())
+//! ```
+
+use std::fmt;
+
+/// จำนวน state สูงสุด - `{n,m}` copy ตัวที่นับซ้ำ n/m ครั้ง จึงต้องกันไม่ให้ `a{1000}{1000}` ใช้หน่วยความจำเกิน
+const MAX_STATES: usize = 10_000;
+
+/// กลุ่ม `(...)` ซ้อนกันได้ลึกสุดเท่านี้ - parser/compiler เป็น recursive จึงกัน stack ล้นจาก `((((...`
+pub const MAX_GROUP_DEPTH: usize = 64;
+
+/// ❌ pattern เขียนผิด - ตัวเลขคือตำแหน่ง (index ของตัวอักษร ไม่ใช่ byte) ของจุดที่ผิด
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RegexError {
+    /// `[` ไม่มี `]` ปิด
+    UnclosedClass(usize),
+    /// `(` ไม่มี `)` ปิด
+    UnclosedGroup(usize),
+    /// `)` ที่ไม่มี `(` เปิด
+    UnmatchedParen(usize),
+    /// `\` อยู่ท้ายสุด
+    TrailingEscape(usize),
+    /// `*`, `+`, `?` หรือ `{..}` ไม่มีอะไรให้นับ (อยู่ต้น pattern, หลัง `(`/`|` หรือซ้อนตัวนับ)
+    NothingToRepeat(usize),
+    /// `{n,m}` เขียนผิด, n > m หรือใหญ่เกินไป
+    InvalidRepetition(usize),
+    /// `(` ที่ทำให้กลุ่มซ้อนเกิน `MAX_GROUP_DEPTH`
+    TooDeep(usize),
+}
+
+impl RegexError {
+    #[must_use]
+    pub const fn position(&self) -> usize {
+        match self {
+            Self::UnclosedClass(at)
+            | Self::UnclosedGroup(at)
+            | Self::UnmatchedParen(at)
+            | Self::TrailingEscape(at)
+            | Self::NothingToRepeat(at)
+            | Self::InvalidRepetition(at)
+            | Self::TooDeep(at) => *at,
+        }
+    }
+
+    /// pattern พร้อม `^` ชี้ใต้ตำแหน่งที่ผิด - แบบที่ compiler ชอบทำ
+    #[must_use]
+    pub fn pointer(&self, pattern: &str) -> String {
+        format!("{pattern}\n{}^", " ".repeat(self.position()))
+    }
+}
+
+impl fmt::Display for RegexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let problem = match self {
+            Self::UnclosedClass(_) => "unclosed character class",
+            Self::UnclosedGroup(_) => "unclosed group",
+            Self::UnmatchedParen(_) => "unmatched closing parenthesis",
+            Self::TrailingEscape(_) => "trailing backslash",
+            Self::NothingToRepeat(_) => "nothing to repeat",
+            Self::InvalidRepetition(_) => "invalid repetition",
+            Self::TooDeep(_) => "groups nested too deeply",
+        };
+        write!(f, "{problem} at position {}", self.position())
+    }
+}
+
+impl std::error::Error for RegexError {}
+
+// ===== 1. Parse =====
+
+/// ตัวอักษรหนึ่งตัวที่ state หนึ่งยอมรับ
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum CharSet {
+    Any,
+    Char(char),
+    Class {
+        ranges: Vec<(char, char)>,
+        negated: bool,
+    },
+}
+
+impl CharSet {
+    fn matches(&self, c: char) -> bool {
+        match self {
+            Self::Any => true,
+            Self::Char(expected) => c == *expected,
+            Self::Class { ranges, negated } => {
+                ranges.iter().any(|&(low, high)| (low..=high).contains(&c)) != *negated
+            }
+        }
+    }
+
+    /// `\d` `\w` `\s` (ASCII) - escape อื่นเป็นตัวอักษรธรรมดา เช่น `\.` `\(`
+    fn escape(escaped: char) -> Self {
+        let ranges = match escaped {
+            'd' => vec![('0', '9')],
+            'w' => vec![('a', 'z'), ('A', 'Z'), ('0', '9'), ('_', '_')],
+            's' => vec![(' ', ' '), ('\t', '\t'), ('\n', '\n'), ('\r', '\r')],
+            other => return Self::Char(other),
+        };
+        Self::Class {
+            ranges,
+            negated: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Ast {
+    Empty,
+    Set(CharSet),
+    StartAnchor,
+    EndAnchor,
+    Concat(Vec<Self>),
+    Alternate(Vec<Self>),
+    Repeat {
+        node: Box<Self>,
+        min: usize,
+        max: Option<usize>,
+        /// ตำแหน่งของตัวนับ - ใช้รายงานเมื่อขยายแล้ว NFA ใหญ่เกิน
+        at: usize,
+    },
+}
+
+struct Parser {
+    chars: Vec<char>,
+    at: usize,
+    depth: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.at).copied()
+    }
+
+    /// alternation := concat ('|' concat)*
+    fn alternation(&mut self) -> Result<Ast, RegexError> {
+        let mut branches = vec![self.concat()?];
+        while self.peek() == Some('|') {
+            self.at += 1;
+            branches.push(self.concat()?);
+        }
+        Ok(if branches.len() == 1 {
+            branches.remove(0)
+        } else {
+            Ast::Alternate(branches)
+        })
+    }
+
+    /// concat := repeat* - จบที่ `|`, `)` หรือท้าย pattern
+    fn concat(&mut self) -> Result<Ast, RegexError> {
+        let mut items = Vec::new();
+        while let Some(c) = self.peek() {
+            if c == '|' || c == ')' {
+                break;
+            }
+            let atom = self.atom()?;
+            items.push(self.quantified(atom)?);
+        }
+        Ok(match items.len() {
+            0 => Ast::Empty,
+            1 => items.remove(0),
+            _ => Ast::Concat(items),
+        })
+    }
+
+    /// ตัวนับหนึ่งตัวต่อ atom - ตัวนับที่สองติดกันถือว่าไม่มีอะไรให้นับ
+    fn quantified(&mut self, atom: Ast) -> Result<Ast, RegexError> {
+        let at = self.at;
+        let (min, max) = match self.peek() {
+            Some('*') => (0, None),
+            Some('+') => (1, None),
+            Some('?') => (0, Some(1)),
+            Some('{') => self.counted()?,
+            _ => return Ok(atom),
+        };
+        // ข้ามตัวนับ (`counted` หยุดไว้ที่ `}`)
+        self.at += 1;
+        if matches!(self.peek(), Some('*' | '+' | '?' | '{')) {
+            return Err(RegexError::NothingToRepeat(self.at));
+        }
+        Ok(Ast::Repeat {
+            node: Box::new(atom),
+            min,
+            max,
+            at,
+        })
+    }
+
+    /// `{n}` `{n,}` `{n,m}` - เลื่อน `at` ไปที่ `}`
+    fn counted(&mut self) -> Result<(usize, Option<usize>), RegexError> {
+        let start = self.at;
+        let invalid = || RegexError::InvalidRepetition(start);
+        let close = self.chars[start..]
+            .iter()
+            .position(|&c| c == '}')
+            .ok_or_else(invalid)?;
+        let body: String = self.chars[start + 1..start + close].iter().collect();
+        let number = |text: &str| text.trim().parse::<usize>().map_err(|_| invalid());
+        let (min, max) = match body.split_once(',') {
+            None => {
+                let exact = number(&body)?;
+                (exact, Some(exact))
+            }
+            Some((min, "")) => (number(min)?, None),
+            Some((min, max)) => (number(min)?, Some(number(max)?)),
+        };
+        if max.is_some_and(|max| max < min) || max.unwrap_or(min) > 1_000 {
+            return Err(invalid());
+        }
+        self.at = start + close;
+        Ok((min, max))
+    }
+
+    fn atom(&mut self) -> Result<Ast, RegexError> {
+        let start = self.at;
+        let c = self.chars[start];
+        self.at += 1;
+        match c {
+            '*' | '+' | '?' | '{' => Err(RegexError::NothingToRepeat(start)),
+            '(' => {
+                if self.depth == MAX_GROUP_DEPTH {
+                    return Err(RegexError::TooDeep(start));
+                }
+                self.depth += 1;
+                let inner = self.alternation();
+                self.depth -= 1;
+                let inner = inner?;
+                if self.peek() != Some(')') {
+                    return Err(RegexError::UnclosedGroup(start));
+                }
+                self.at += 1;
+                Ok(inner)
+            }
+            '[' => self.class(start),
+            '.' => Ok(Ast::Set(CharSet::Any)),
+            '^' => Ok(Ast::StartAnchor),
+            '$' => Ok(Ast::EndAnchor),
+            '\\' => {
+                let escaped = self.peek().ok_or(RegexError::TrailingEscape(start))?;
+                self.at += 1;
+                Ok(Ast::Set(CharSet::escape(escaped)))
+            }
+            literal => Ok(Ast::Set(CharSet::Char(literal))),
+        }
+    }
+
+    /// `[...]` - เรียกหลังกิน `[` แล้ว; `]` ตัวแรกสุดและ `-` ที่ขอบเป็นตัวอักษรธรรมดา
+    fn class(&mut self, start: usize) -> Result<Ast, RegexError> {
+        let unclosed = RegexError::UnclosedClass(start);
+        let negated = self.peek() == Some('^');
+        if negated {
+            self.at += 1;
+        }
+        let mut ranges = Vec::new();
+        let mut first = true;
+        loop {
+            let c = self.peek().ok_or_else(|| unclosed.clone())?;
+            self.at += 1;
+            if c == ']' && !first {
+                return Ok(Ast::Set(CharSet::Class { ranges, negated }));
+            }
+            first = false;
+            let low = if c == '\\' {
+                let escaped = self.peek().ok_or_else(|| unclosed.clone())?;
+                self.at += 1;
+                match CharSet::escape(escaped) {
+                    CharSet::Class { ranges: more, .. } => {
+                        ranges.extend(more);
+                        continue;
+                    }
+                    _ => escaped,
+                }
+            } else {
+                c
+            };
+            match (self.peek(), self.chars.get(self.at + 1)) {
+                (Some('-'), Some(&high)) if high != ']' => {
+                    ranges.push((low, high));
+                    self.at += 2;
+                }
+                _ => ranges.push((low, low)),
+            }
+        }
+    }
+}
+
+// ===== 2. Compile =====
+
+type StateId = usize;
+
+/// state ของ NFA - `Split`/`Assert` ไม่กินตัวอักษร (epsilon transition)
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum State {
+    Consume(CharSet, StateId),
+    Split(StateId, StateId),
+    AssertStart(StateId),
+    AssertEnd(StateId),
+    Match,
+}
+
+struct Compiler {
+    states: Vec<State>,
+}
+
+impl Compiler {
+    fn push(&mut self, state: State) -> StateId {
+        self.states.push(state);
+        self.states.len() - 1
+    }
+
+    /// `{n,m}` copy ตัวที่ถูกนับซ้ำทีละชุด - หยุดทันทีที่ NFA ใหญ่เกิน ไม่รอจนขยายครบ
+    const fn check_size(&self, at: usize) -> Result<(), RegexError> {
+        if self.states.len() > MAX_STATES {
+            Err(RegexError::InvalidRepetition(at))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// สร้าง state ของ `ast` ที่เมื่อตรงแล้วไปต่อที่ `next` - สร้างจากท้ายมาหน้า จึงไม่ต้อง "ปะ" ปลายทางทีหลัง
+    fn emit(&mut self, ast: &Ast, next: StateId) -> Result<StateId, RegexError> {
+        match ast {
+            Ast::Empty => Ok(next),
+            Ast::Set(set) => Ok(self.push(State::Consume(set.clone(), next))),
+            Ast::StartAnchor => Ok(self.push(State::AssertStart(next))),
+            Ast::EndAnchor => Ok(self.push(State::AssertEnd(next))),
+            Ast::Concat(items) => items
+                .iter()
+                .rev()
+                .try_fold(next, |next, item| self.emit(item, next)),
+            Ast::Alternate(branches) => {
+                let (last, rest) = branches.split_last().expect("alternation has branches");
+                let mut entry = self.emit(last, next)?;
+                for branch in rest.iter().rev() {
+                    let start = self.emit(branch, next)?;
+                    entry = self.push(State::Split(start, entry));
+                }
+                Ok(entry)
+            }
+            Ast::Repeat { node, min, max, at } => {
+                // ตัวนับชั้นนอกคือตัวที่คูณขนาด - error จากตัวนับข้างในจึงรายงานเป็นตำแหน่งของตัวนี้
+                let too_large = |_| RegexError::InvalidRepetition(*at);
+                // ส่วนที่ไม่บังคับก่อน (อยู่ท้าย) แล้วค่อยต่อ `min` ชุดที่บังคับไว้ข้างหน้า
+                let mut entry = match max {
+                    None => {
+                        // loop: split → (node → กลับมาที่ split) หรือออกไป next
+                        let split = self.push(State::Split(next, next));
+                        let body = self.emit(node, split).map_err(too_large)?;
+                        self.states[split] = State::Split(body, next);
+                        split
+                    }
+                    Some(max) => {
+                        let mut entry = next;
+                        for _ in *min..*max {
+                            let body = self.emit(node, entry).map_err(too_large)?;
+                            entry = self.push(State::Split(body, next));
+                            self.check_size(*at)?;
+                        }
+                        entry
+                    }
+                };
+                for _ in 0..*min {
+                    entry = self.emit(node, entry).map_err(too_large)?;
+                    self.check_size(*at)?;
+                }
+                Ok(entry)
+            }
+        }
+    }
+}
+
+// ===== 3. Simulate =====
+
+/// 🧩 regex ที่ compile แล้ว - `new` ครั้งเดียวแล้วใช้ซ้ำได้
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegexLite {
+    pattern: String,
+    states: Vec<State>,
+    start: StateId,
+}
+
+/// 🎯 ช่วงที่ตรงใน `find` - `start`/`end` เป็น byte offset ใช้ slice ข้อความได้เลย
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match<'t> {
+    text: &'t str,
+    start: usize,
+    end: usize,
+}
+
+impl<'t> Match<'t> {
+    #[must_use]
+    pub const fn start(&self) -> usize {
+        self.start
+    }
+
+    #[must_use]
+    pub const fn end(&self) -> usize {
+        self.end
+    }
+
+    #[must_use]
+    pub fn as_str(&self) -> &'t str {
+        &self.text[self.start..self.end]
+    }
+}
+
+/// ชุดของ state ที่ทำงานอยู่ - `Vec` รักษาลำดับ + `bool` กันใส่ซ้ำ
+struct StateSet {
+    list: Vec<StateId>,
+    present: Vec<bool>,
+}
+
+impl StateSet {
+    fn new(size: usize) -> Self {
+        Self {
+            list: Vec::with_capacity(size),
+            present: vec![false; size],
+        }
+    }
+
+    fn clear(&mut self) {
+        for id in self.list.drain(..) {
+            self.present[id] = false;
+        }
+    }
+}
+
+impl RegexLite {
+    /// parse แล้ว compile เป็น NFA
+    ///
+    /// # Errors
+    ///
+    /// คืน `RegexError` พร้อมตำแหน่งเมื่อ pattern เขียนผิด
+    pub fn new(pattern: &str) -> Result<Self, RegexError> {
+        let mut parser = Parser {
+            chars: pattern.chars().collect(),
+            at: 0,
+            depth: 0,
+        };
+        let ast = parser.alternation()?;
+        if parser.at < parser.chars.len() {
+            // alternation หยุดที่ `)` ที่ไม่มีคู่
+            return Err(RegexError::UnmatchedParen(parser.at));
+        }
+        let mut compiler = Compiler {
+            states: vec![State::Match],
+        };
+        let start = compiler.emit(&ast, 0)?;
+        Ok(Self {
+            pattern: pattern.to_string(),
+            states: compiler.states,
+            start,
+        })
+    }
+
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.pattern
+    }
+
+    /// จำนวน state ของ NFA - ดูว่า `{n,m}` ทำให้ automaton โตแค่ไหน
+    #[must_use]
+    pub const fn state_count(&self) -> usize {
+        self.states.len()
+    }
+
+    /// เพิ่ม `id` และทุก state ที่ไปถึงได้โดยไม่กินตัวอักษร (epsilon closure)
+    fn add(&self, set: &mut StateSet, id: StateId, at: usize, len: usize) {
+        let mut pending = vec![id];
+        while let Some(id) = pending.pop() {
+            if set.present[id] {
+                continue;
+            }
+            set.present[id] = true;
+            set.list.push(id);
+            match self.states[id] {
+                State::Split(a, b) => pending.extend([b, a]),
+                State::AssertStart(next) if at == 0 => pending.push(next),
+                State::AssertEnd(next) if at == len => pending.push(next),
+                _ => {}
+            }
+        }
+    }
+
+    fn has_match(set: &StateSet) -> bool {
+        set.present[0]
+    }
+
+    /// เลื่อนทุก state ไปหนึ่งตัวอักษร
+    fn step(&self, current: &StateSet, next: &mut StateSet, c: char, at: usize, len: usize) {
+        next.clear();
+        for &id in &current.list {
+            if let State::Consume(set, to) = &self.states[id]
+                && set.matches(c)
+            {
+                self.add(next, *to, at + 1, len);
+            }
+        }
+    }
+
+    /// มีส่วนใดของ `text` ตรงกับ pattern ไหม - ใส่ state เริ่มต้นเพิ่มทุกตำแหน่ง (เหมือนมี `.*` นำหน้า)
+    #[must_use]
+    pub fn is_match(&self, text: &str) -> bool {
+        let chars: Vec<char> = text.chars().collect();
+        let len = chars.len();
+        let mut current = StateSet::new(self.states.len());
+        let mut next = StateSet::new(self.states.len());
+        self.add(&mut current, self.start, 0, len);
+        for (at, &c) in chars.iter().enumerate() {
+            if Self::has_match(&current) {
+                return true;
+            }
+            self.step(&current, &mut next, c, at, len);
+            self.add(&mut next, self.start, at + 1, len);
+            std::mem::swap(&mut current, &mut next);
+        }
+        Self::has_match(&current)
+    }
+
+    /// ทั้งข้อความต้องตรง (เหมือนครอบด้วย `^...$`) - ใช้ตรวจรูปแบบข้อมูล
+    #[must_use]
+    pub fn is_full_match(&self, text: &str) -> bool {
+        let chars: Vec<char> = text.chars().collect();
+        self.longest_from(&chars, 0) == Some(chars.len())
+    }
+
+    /// ตำแหน่งจบที่ไกลที่สุด (index ตัวอักษร) ของการตรงที่เริ่มที่ `from`
+    fn longest_from(&self, chars: &[char], from: usize) -> Option<usize> {
+        let len = chars.len();
+        let mut current = StateSet::new(self.states.len());
+        let mut next = StateSet::new(self.states.len());
+        self.add(&mut current, self.start, from, len);
+        let mut longest = None;
+        for (at, &c) in chars.iter().enumerate().skip(from) {
+            if Self::has_match(&current) {
+                longest = Some(at);
+            }
+            if current.list.is_empty() {
+                return longest;
+            }
+            self.step(&current, &mut next, c, at, len);
+            std::mem::swap(&mut current, &mut next);
+        }
+        if Self::has_match(&current) {
+            longest = Some(len);
+        }
+        longest
+    }
+
+    /// การตรงครั้งแรกจากซ้าย และยาวที่สุดที่เริ่มจากตรงนั้น (leftmost-longest แบบ POSIX)
+    #[must_use]
+    pub fn find<'t>(&self, text: &'t str) -> Option<Match<'t>> {
+        let chars: Vec<char> = text.chars().collect();
+        let offsets: Vec<usize> = text
+            .char_indices()
+            .map(|(offset, _)| offset)
+            .chain([text.len()])
+            .collect();
+        (0..=chars.len()).find_map(|from| {
+            let end = self.longest_from(&chars, from)?;
+            Some(Match {
+                text,
+                start: offsets[from],
+                end: offsets[end],
+            })
+        })
+    }
+}
+
+/// ฟังก์ชันสำหรับสอนเรื่อง parser และ automata ผ่าน regex ที่เขียนเอง 🧩🤖
+///
+/// # Panics
+///
+/// ถ้า pattern ตัวอย่างที่เขียนไว้ในฟังก์ชันนี้ compile ไม่ผ่าน (bug)
+pub fn demonstrate_regex_lite() {
+    println!("\n🧩 === RegexLite: pattern → AST → NFA → จำลองทุกเส้นทางพร้อมกัน === 🧩");
+
+    let phone = RegexLite::new(r"0[689]\d-?\d{3}-?\d{4}").expect("valid pattern");
+    println!(
+        "📱 `{}` compile ได้ NFA {} state",
+        phone.as_str(),
+        phone.state_count()
+    );
+    for text in ["โทร 081-234-5678 ได้เลย", "0812345678", "02-123-4567"] {
+        match phone.find(text) {
+            Some(found) => println!("   ✅ \"{text}\" → เจอ `{}`", found.as_str()),
+            None => println!("   ❌ \"{text}\" → ไม่เจอ"),
+        }
+    }
+
+    let pet = RegexLite::new("^(cat|dog)s?$").expect("valid pattern");
+    for text in ["cats", "dog", "cow"] {
+        println!("   🐾 ^(cat|dog)s?$ กับ \"{text}\": {}", pet.is_match(text));
+    }
+
+    // backtracking ลองทุกวิธีแบ่ง a ให้ (a*)* → 2^n; NFA เดินทุก state พร้อมกันจึงเป็นเส้นตรง
+    let evil = RegexLite::new("(a*)*b").expect("valid pattern");
+    let text = "a".repeat(5_000);
+    let started = std::time::Instant::now();
+    let found = evil.is_match(&text);
+    println!(
+        "   😈 (a*)*b กับ a×5000: {found} ใน {:?} (backtracking จะค้างไปเลย)",
+        started.elapsed()
+    );
+
+    for broken in ["(ab", "ab)", "a**", "[0-9", "x{3,1}"] {
+        if let Err(error) = RegexLite::new(broken) {
+            println!("   ⚠️ {error}");
+            for line in error.pointer(broken).lines() {
+                println!("      {line}");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// (pattern, ข้อความ, ผลของ `is_match`, สิ่งที่ `find` ควรได้)
+    const CORPUS: &[(&str, &str, bool, Option<&str>)] = &[
+        ("abc", "xxabcxx", true, Some("abc")),
+        ("abc", "ab", false, None),
+        ("a|b|c", "zzc", true, Some("c")),
+        ("gr(a|e)y", "grey cat", true, Some("grey")),
+        ("colou?r", "color", true, Some("color")),
+        ("colou?r", "colouur", false, None),
+        ("ab*", "abbbc", true, Some("abbb")),
+        ("ab+", "ac", false, None),
+        ("(ab)+", "xababab", true, Some("ababab")),
+        ("a{2,3}", "aaaa", true, Some("aaa")),
+        ("a{2}", "a", false, None),
+        ("a{2,}", "aaaaa", true, Some("aaaaa")),
+        ("[a-c]+", "xxbcaz", true, Some("bca")),
+        ("[^0-9]+", "123abc", true, Some("abc")),
+        (r"\d+\.\d+", "v1.25", true, Some("1.25")),
+        (r"\w+@\w+", "mail: me@host!", true, Some("me@host")),
+        ("^ab", "cab", false, None),
+        ("ab$", "abc", false, None),
+        ("^$", "", true, Some("")),
+        ("(a|ab)(c|bcd)", "abcd", true, Some("abcd")),
+        ("x*", "yyy", true, Some("")),
+        ("สวัสดี|hello", "บอกว่า สวัสดี", true, Some("สวัสดี")),
+        ("(a*)*b", "aaaab", true, Some("aaaab")),
+        ("(a*)*b", "aaaa", false, None),
+        ("a||b", "", true, Some("")),
+    ];
+
+    #[test]
+    fn test_corpus() {
+        for &(pattern, text, matches, found) in CORPUS {
+            let re = RegexLite::new(pattern).unwrap();
+            assert_eq!(re.is_match(text), matches, "{pattern} is_match {text:?}");
+            assert_eq!(
+                re.find(text).map(|m| m.as_str()),
+                found,
+                "{pattern} find {text:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_full_match_and_offsets() {
+        let code = RegexLite::new(r"TH-\d{5}").unwrap();
+        assert!(code.is_full_match("TH-10110"));
+        assert!(!code.is_full_match("TH-10110x"));
+        assert!(code.is_match("zip TH-10110x"));
+        assert!(RegexLite::new("a*ab?").unwrap().is_full_match("aaaa"));
+        assert!(RegexLite::new(".?").unwrap().is_full_match(""));
+
+        // offset เป็น byte - ตัวอักษรไทยตัวละ 3 byte
+        let found = RegexLite::new("ดี").unwrap().find("สวัสดีครับ").unwrap();
+        assert_eq!((found.start(), found.end()), (12, 18));
+        assert_eq!(found.as_str(), "ดี");
+
+        // NFA ไม่ระเบิดกับ pattern ที่ทำให้ backtracking เป็น exponential
+        let evil = RegexLite::new("(a*)*b").unwrap();
+        assert!(!evil.is_match(&"a".repeat(2_000)));
+    }
+
+    #[test]
+    fn test_parse_errors_with_positions() {
+        let cases = [
+            ("a(b|c", RegexError::UnclosedGroup(1)),
+            ("ab)c", RegexError::UnmatchedParen(2)),
+            ("[a-z", RegexError::UnclosedClass(0)),
+            ("x[^", RegexError::UnclosedClass(1)),
+            (r"ab\", RegexError::TrailingEscape(2)),
+            ("*a", RegexError::NothingToRepeat(0)),
+            ("a**", RegexError::NothingToRepeat(2)),
+            ("(|+)", RegexError::NothingToRepeat(2)),
+            ("a{3,1}", RegexError::InvalidRepetition(1)),
+            ("a{x}", RegexError::InvalidRepetition(1)),
+            ("a{2", RegexError::InvalidRepetition(1)),
+            ("(a{1000}){1000}", RegexError::InvalidRepetition(9)),
+            (
+                &format!("x{}", "(".repeat(MAX_GROUP_DEPTH + 1)),
+                RegexError::TooDeep(MAX_GROUP_DEPTH + 1),
+            ),
+        ];
+        for (pattern, expected) in cases {
+            assert_eq!(RegexLite::new(pattern).unwrap_err(), expected, "{pattern}");
+        }
+
+        let error = RegexLite::new("ab)c").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "unmatched closing parenthesis at position 2"
+        );
+        assert_eq!(error.pointer("ab)c"), "ab)c\n  ^");
+
+        let nested = |depth: usize| format!("{}a{}", "(".repeat(depth), ")".repeat(depth));
+        assert!(
+            RegexLite::new(&nested(MAX_GROUP_DEPTH))
+                .unwrap()
+                .is_match("a")
+        );
+        // เดิม `(` ราว 20k ตัวทำ stack ล้นตอน compile
+        assert_eq!(
+            RegexLite::new(&nested(20_000)).unwrap_err(),
+            RegexError::TooDeep(MAX_GROUP_DEPTH)
+        );
+    }
+}
@@ -0,0 +1,355 @@
+//! 🔤 Strings - Trie สำหรับค้นด้วย prefix และ fuzzy matching ที่ทนคำพิมพ์ผิด
+//!
+//! - 🌲 `Trie` - เก็บคำแบบแชร์ prefix ร่วมกัน ถามว่า "มีคำนี้ไหม" หรือ "คำไหนขึ้นต้นด้วย ..." ได้ใน O(ความยาวคำ)
+//! - ✏️ `levenshtein` - จำนวนครั้งที่น้อยที่สุดของการเพิ่ม/ลบ/แทนตัวอักษรเพื่อเปลี่ยนคำหนึ่งเป็นอีกคำ
+//! - 🎯 `FuzzyMatcher` - ให้คะแนนผู้สมัครแต่ละคำ (ตรงเป๊ะ > prefix > พิมพ์ผิดเล็กน้อย > ตัวอักษรเรียงตามกัน)
+//!   ใช้กับ `--chapter trats` ที่ตอบกลับว่า "หมายถึง `traits` ใช่ไหม?"
+//!
+//! ```
+//! use rust_concepts::strings::{FuzzyMatcher, Trie, levenshtein};
+//!
+//! let words: Trie = ["trait", "traits", "tree"].into_iter().collect();
+//! assert_eq!(words.with_prefix("tra").collect::<Vec<_>>(), ["trait", "traits"]);
+//!
+//! assert_eq!(levenshtein("trats", "traits"), 1);
+//! let ranked = FuzzyMatcher::new().rank("trats", ["testing", "traits", "structs"]);
+//! assert_eq!(ranked[0].candidate, "traits");
+//! ```
+
+use std::collections::BTreeMap;
+
+// ===== Trie =====
+
+#[derive(Debug, Default, Clone)]
+struct TrieNode {
+    /// `BTreeMap` ทำให้เดินลูกเรียงตามตัวอักษร - `with_prefix` จึงได้คำเรียงลำดับเลย
+    children: BTreeMap<char, Self>,
+    /// มีคำที่จบตรง node นี้
+    terminal: bool,
+}
+
+/// 🌲 prefix tree ของคำ - คำที่ขึ้นต้นเหมือนกันใช้ node ร่วมกัน
+#[derive(Debug, Default, Clone)]
+pub struct Trie {
+    root: TrieNode,
+    len: usize,
+}
+
+impl Trie {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// เพิ่มคำ - คืน `false` ถ้ามีอยู่แล้ว
+    pub fn insert(&mut self, word: &str) -> bool {
+        let mut node = &mut self.root;
+        for ch in word.chars() {
+            node = node.children.entry(ch).or_default();
+        }
+        let added = !node.terminal;
+        node.terminal = true;
+        self.len += usize::from(added);
+        added
+    }
+
+    fn descend(&self, prefix: &str) -> Option<&TrieNode> {
+        prefix
+            .chars()
+            .try_fold(&self.root, |node, ch| node.children.get(&ch))
+    }
+
+    /// มีคำนี้ทั้งคำ (ไม่ใช่แค่ prefix)
+    #[must_use]
+    pub fn contains(&self, word: &str) -> bool {
+        self.descend(word).is_some_and(|node| node.terminal)
+    }
+
+    /// มีคำใดขึ้นต้นด้วย `prefix` บ้างไหม
+    #[must_use]
+    pub fn starts_with(&self, prefix: &str) -> bool {
+        self.descend(prefix).is_some()
+    }
+
+    /// ทุกคำที่ขึ้นต้นด้วย `prefix` เรียงตามตัวอักษร (สร้างทีละคำเมื่อถูกดึง)
+    #[must_use]
+    pub fn with_prefix(&self, prefix: &str) -> PrefixIter<'_> {
+        PrefixIter {
+            stack: self
+                .descend(prefix)
+                .map(|node| (prefix.to_string(), node))
+                .into_iter()
+                .collect(),
+        }
+    }
+
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<'a> FromIterator<&'a str> for Trie {
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
+        let mut trie = Self::new();
+        for word in iter {
+            trie.insert(word);
+        }
+        trie
+    }
+}
+
+/// iterator ของ `Trie::with_prefix` - DFS ด้วย stack ใส่ลูกกลับด้านเพื่อให้ตัวอักษรน้อยสุดออกก่อน
+pub struct PrefixIter<'a> {
+    stack: Vec<(String, &'a TrieNode)>,
+}
+
+impl Iterator for PrefixIter<'_> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        while let Some((word, node)) = self.stack.pop() {
+            for (ch, child) in node.children.iter().rev() {
+                let mut longer = word.clone();
+                longer.push(*ch);
+                self.stack.push((longer, child));
+            }
+            if node.terminal {
+                return Some(word);
+            }
+        }
+        None
+    }
+}
+
+// ===== Edit distance =====
+
+/// ✏️ Levenshtein distance นับเป็นตัวอักษร (`char`) ไม่ใช่ byte - ภาษาไทยก็นับถูก
+///
+/// dynamic programming แบบเก็บแค่แถวก่อนหน้า: หน่วยความจำ O(ความยาวคำที่สอง)
+#[must_use]
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+    for (i, ca) in a.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitute = previous[j] + usize::from(ca != *cb);
+            let delete = previous[j + 1] + 1;
+            let insert = current[j] + 1;
+            current[j + 1] = substitute.min(delete).min(insert);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[b.len()]
+}
+
+/// ตัวอักษรทุกตัวของ `query` ปรากฏใน `candidate` ตามลำดับ (ข้ามได้) - คืนจำนวนตัวที่ถูกข้าม
+fn subsequence_gaps(query: &str, candidate: &str) -> Option<usize> {
+    let mut rest = candidate.chars();
+    let mut skipped = 0;
+    for wanted in query.chars() {
+        loop {
+            let ch = rest.next()?;
+            if ch == wanted {
+                break;
+            }
+            skipped += 1;
+        }
+    }
+    Some(skipped)
+}
+
+// ===== Fuzzy matching =====
+
+/// 🎯 ผู้สมัครหนึ่งตัวกับคะแนน (มากกว่า = ตรงกว่า)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FuzzyMatch<'a> {
+    pub candidate: &'a str,
+    pub score: u32,
+}
+
+/// 🎯 ให้คะแนนและจัดอันดับคำที่ใกล้เคียง (ไม่สนตัวพิมพ์เล็ก/ใหญ่)
+///
+/// | ระดับ | เงื่อนไข | คะแนน |
+/// |---|---|---|
+/// | ตรงเป๊ะ | `traits` ↔ `traits` | 1000 |
+/// | prefix | `gen` → `generics` | 900 − จำนวนตัวที่เหลือ |
+/// | พิมพ์ผิด | `trats` → `traits` (ระยะ ≤ `max_typos`) | 800 − 100 × ระยะ |
+/// | เรียงตามกัน | `errhand` → `error-handling` | 500 − จำนวนตัวที่ข้าม |
+#[derive(Debug, Clone)]
+pub struct FuzzyMatcher {
+    max_typos: Option<usize>,
+    limit: usize,
+}
+
+impl Default for FuzzyMatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FuzzyMatcher {
+    /// ค่าเริ่มต้น: พิมพ์ผิดได้ราว 1 ตัวต่อ 3 ตัวอักษร (1-3 ตัว) และไม่จำกัดจำนวนผลลัพธ์
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            max_typos: None,
+            limit: usize::MAX,
+        }
+    }
+
+    /// กำหนดระยะ Levenshtein สูงสุดที่ยังนับว่า "พิมพ์ผิด"
+    #[must_use]
+    pub const fn max_typos(mut self, typos: usize) -> Self {
+        self.max_typos = Some(typos);
+        self
+    }
+
+    /// คืนผลลัพธ์ไม่เกิน `limit` อันดับแรก
+    #[must_use]
+    pub const fn limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    fn allowed_typos(&self, query: &str) -> usize {
+        self.max_typos
+            .unwrap_or_else(|| (query.chars().count() / 3).clamp(1, 3))
+    }
+
+    /// คะแนนของ `candidate` เทียบกับ `query` - `None` ถ้าไม่ใกล้เคียงพอ
+    #[must_use]
+    pub fn score(&self, query: &str, candidate: &str) -> Option<u32> {
+        let query = query.trim().to_lowercase();
+        let candidate = candidate.to_lowercase();
+        if query.is_empty() {
+            return None;
+        }
+        if candidate == query {
+            return Some(1000);
+        }
+        let penalty = |count: usize| u32::try_from(count.min(99)).unwrap_or(99);
+        if let Some(rest) = candidate.strip_prefix(&query) {
+            return Some(900 - penalty(rest.chars().count()));
+        }
+        let distance = levenshtein(&query, &candidate);
+        if distance <= self.allowed_typos(&query) {
+            return Some(800_u32.saturating_sub(100 * penalty(distance)));
+        }
+        subsequence_gaps(&query, &candidate).map(|gaps| 500 - penalty(gaps))
+    }
+
+    /// ผู้สมัครที่ผ่านเรียงจากคะแนนมากไปน้อย (คะแนนเท่ากันเรียงตามตัวอักษร)
+    pub fn rank<'a>(
+        &self,
+        query: &str,
+        candidates: impl IntoIterator<Item = &'a str>,
+    ) -> Vec<FuzzyMatch<'a>> {
+        let mut matches: Vec<FuzzyMatch<'a>> = candidates
+            .into_iter()
+            .filter_map(|candidate| {
+                let score = self.score(query, candidate)?;
+                Some(FuzzyMatch { candidate, score })
+            })
+            .collect();
+        matches.sort_by(|a, b| {
+            b.score
+                .cmp(&a.score)
+                .then_with(|| a.candidate.cmp(b.candidate))
+        });
+        matches.truncate(self.limit);
+        matches
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trie_insert_contains_and_prefix() {
+        let mut trie = Trie::new();
+        assert!(trie.is_empty());
+        assert!(trie.insert("rust"));
+        assert!(trie.insert("rustc"));
+        assert!(trie.insert("rustup"));
+        assert!(trie.insert("ruby"));
+        assert!(trie.insert("ภาษา"));
+        assert!(!trie.insert("rust"));
+        assert_eq!(trie.len(), 5);
+
+        assert!(trie.contains("rust"));
+        assert!(!trie.contains("rus"));
+        assert!(trie.starts_with("rus"));
+        assert!(!trie.starts_with("go"));
+        assert!(trie.contains("ภาษา"));
+
+        let words: Vec<String> = trie.with_prefix("ru").collect();
+        assert_eq!(words, ["ruby", "rust", "rustc", "rustup"]);
+        assert_eq!(trie.with_prefix("rustu").collect::<Vec<_>>(), ["rustup"]);
+        assert_eq!(trie.with_prefix("").count(), 5);
+        assert_eq!(trie.with_prefix("go").next(), None);
+    }
+
+    #[test]
+    fn test_levenshtein_distances() {
+        assert_eq!(levenshtein("", ""), 0);
+        assert_eq!(levenshtein("abc", ""), 3);
+        assert_eq!(levenshtein("", "abc"), 3);
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+        assert_eq!(levenshtein("flaw", "lawn"), 2);
+        assert_eq!(levenshtein("trats", "traits"), 1);
+        assert_eq!(levenshtein("traits", "trats"), 1);
+        assert_eq!(levenshtein("async", "async"), 0);
+        // นับเป็นตัวอักษร: "แมว" → "แมวส" ต่างกันหนึ่งตัว แม้จะเป็นหลาย byte
+        assert_eq!(levenshtein("แมว", "แมวส"), 1);
+    }
+
+    #[test]
+    fn test_fuzzy_ranking_order() {
+        let slugs = [
+            "basics",
+            "traits",
+            "structs",
+            "testing",
+            "generics",
+            "error-handling",
+        ];
+        let matcher = FuzzyMatcher::new();
+        let ranked = |query| -> Vec<&str> {
+            matcher
+                .rank(query, slugs)
+                .iter()
+                .map(|found| found.candidate)
+                .collect()
+        };
+
+        assert_eq!(ranked("trats").first(), Some(&"traits"));
+        assert_eq!(ranked("TRAITS"), ["traits"]);
+        assert_eq!(ranked("gen"), ["generics"]);
+        assert_eq!(ranked("errhand"), ["error-handling"]);
+        assert_eq!(ranked("st"), ["structs", "testing"]);
+        assert!(ranked("cooking").is_empty());
+        assert!(ranked("  ").is_empty());
+
+        // ตรงเป๊ะ > prefix > พิมพ์ผิด > เรียงตามกัน
+        let exact = matcher.score("test", "test").unwrap();
+        let prefix = matcher.score("test", "testing").unwrap();
+        let typo = matcher.score("tezt", "test").unwrap();
+        let scattered = matcher.score("tsg", "testing").unwrap();
+        assert!(exact > prefix && prefix > typo && typo > scattered);
+
+        // ปิดการนับคำพิมพ์ผิด - ยังเจอในระดับ "เรียงตามกัน" (ข้าม `i` หนึ่งตัว)
+        let strict = FuzzyMatcher::new().max_typos(0);
+        assert_eq!(strict.score("trats", "traits"), Some(499));
+        assert_eq!(strict.score("tarits", "traits"), None);
+        assert_eq!(FuzzyMatcher::new().limit(1).rank("s", slugs).len(), 1);
+    }
+}
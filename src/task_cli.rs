@@ -0,0 +1,759 @@
+//! ✅ Task CLI - `cargo run -- task ...` จัดการ todo list จริงๆ ด้วย `TaskManager`
+//!
+//! ```text
+//! task add "เขียน README" --priority high --due tomorrow --tag docs
+//! task add "standup" --due "2025-03-10 09:00" --repeat "0 9 * * 1-5"
+//! task list --status pending --priority high --tag docs
+//! task search "readme #docs sort:due"
+//! task done 3f2a
+//! task edit 3f2a --title "เขียน README ให้จบ" --clear-due
+//! task rm 3f2a
+//! task export --output backup.json
+//! ```
+//!
+//! - 🧩 subcommand ของ clap (builder API แบบเดียวกับ `main.rs`)
+//! - 🆔 อ้างถึง task ด้วยตัวอักษรต้นๆ ของ id ได้ เหมือน short hash ของ git
+//! - 📅 `--due` รับ `today`, `tomorrow`, `+3d`, `2025-12-31`, `2025-12-31 18:00` หรือ RFC 3339
+//! - 🔁 `--repeat` รับ `daily`, `weekly` หรือ cron-lite - `done` จะสร้างรอบถัดไปให้ทันที
+//! - 💾 ทุกคำสั่งที่แก้ข้อมูลบันทึกแบบ write-then-rename ผ่าน `TaskManager::save_to_file`
+//! - 🎨 ตารางมีสีถ้าเป็น terminal - ปิดได้ด้วย `--no-color` หรือ env `NO_COLOR`
+
+use std::fmt::Write as _;
+use std::io::{IsTerminal, Write};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result, bail};
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use clap::{Arg, ArgAction, ArgMatches, Command};
+
+use crate::task_search::{Query, SortBy};
+use crate::task_storage::StorageFormat;
+use crate::{Priority, Recurrence, Task, TaskManager};
+
+/// เวลาที่ใช้เมื่อ `--due` มีแค่วันที่
+const END_OF_DAY: NaiveTime = NaiveTime::from_hms_opt(23, 59, 59).unwrap();
+
+/// 🧩 subcommand `task` สำหรับเสียบเข้า `Command` หลักใน `main.rs`
+#[must_use]
+pub fn command() -> Command {
+    Command::new("task")
+        .about("จัดการ todo list (ตัวอย่าง TaskManager)")
+        .subcommand_required(true)
+        .arg_required_else_help(true)
+        .arg(
+            Arg::new("file")
+                .short('f')
+                .long("file")
+                .global(true)
+                .value_name("PATH")
+                .value_parser(clap::value_parser!(PathBuf))
+                .help("ไฟล์เก็บ tasks (นามสกุล .bin = binary, อย่างอื่น = JSON)"),
+        )
+        .subcommand(
+            Command::new("add")
+                .about("เพิ่ม task ใหม่")
+                .arg(Arg::new("title").required(true))
+                .arg(priority_arg().default_value("medium"))
+                .arg(due_arg())
+                .arg(tag_arg().help("ติด tag (ใส่ซ้ำได้)"))
+                .arg(repeat_arg()),
+        )
+        .subcommand(
+            Command::new("list")
+                .visible_alias("ls")
+                .about("แสดง tasks เป็นตาราง")
+                .arg(priority_arg().help("แสดงเฉพาะ priority นี้"))
+                .arg(tag_arg().help("แสดงเฉพาะ task ที่มีทุก tag ที่ระบุ"))
+                .arg(
+                    Arg::new("status")
+                        .short('s')
+                        .long("status")
+                        .value_parser(["pending", "done", "all"])
+                        .default_value("all"),
+                )
+                .arg(no_color_arg()),
+        )
+        .subcommand(
+            Command::new("search")
+                .visible_alias("find")
+                .about("ค้นหาด้วย query เช่น \"docs #work p:high is:pending sort:due\"")
+                .arg(
+                    Arg::new("query")
+                        .required(true)
+                        .num_args(1..)
+                        .value_parser(clap::value_parser!(Query)),
+                )
+                .arg(no_color_arg()),
+        )
+        .subcommand(
+            Command::new("done")
+                .about("ทำเครื่องหมายว่าเสร็จแล้ว")
+                .arg(id_arg()),
+        )
+        .subcommand(
+            Command::new("rm")
+                .visible_alias("remove")
+                .about("ลบ task")
+                .arg(id_arg()),
+        )
+        .subcommand(edit_command())
+        .subcommand(
+            Command::new("export")
+                .about("export tasks ทั้งหมดเป็น JSON")
+                .arg(
+                    Arg::new("output")
+                        .short('o')
+                        .long("output")
+                        .value_name("PATH")
+                        .value_parser(clap::value_parser!(PathBuf))
+                        .help("ไฟล์ปลายทาง (ไม่ระบุ = stdout)"),
+                ),
+        )
+}
+
+fn edit_command() -> Command {
+    Command::new("edit")
+        .about("แก้ไข task")
+        .arg(id_arg())
+        .arg(Arg::new("title").short('t').long("title"))
+        .arg(priority_arg())
+        .arg(due_arg().conflicts_with("clear-due"))
+        .arg(
+            Arg::new("clear-due")
+                .long("clear-due")
+                .action(ArgAction::SetTrue)
+                .help("ลบกำหนดส่ง"),
+        )
+        .arg(tag_arg().help("เพิ่ม tag (ใส่ซ้ำได้)"))
+        .arg(
+            Arg::new("untag")
+                .long("untag")
+                .action(ArgAction::Append)
+                .help("เอา tag ออก"),
+        )
+        .arg(repeat_arg().conflicts_with("no-repeat"))
+        .arg(
+            Arg::new("no-repeat")
+                .long("no-repeat")
+                .action(ArgAction::SetTrue)
+                .help("เลิกทำซ้ำ"),
+        )
+}
+
+fn id_arg() -> Arg {
+    Arg::new("id")
+        .required(true)
+        .help("id ของ task (พิมพ์แค่ต้นๆ ก็ได้)")
+}
+
+fn priority_arg() -> Arg {
+    Arg::new("priority")
+        .short('p')
+        .long("priority")
+        .value_parser(clap::value_parser!(Priority))
+        .help("low, medium หรือ high")
+}
+
+fn no_color_arg() -> Arg {
+    Arg::new("no-color")
+        .long("no-color")
+        .action(ArgAction::SetTrue)
+        .help("ไม่ใส่สี ANSI")
+}
+
+fn tag_arg() -> Arg {
+    Arg::new("tag").long("tag").action(ArgAction::Append)
+}
+
+fn repeat_arg() -> Arg {
+    Arg::new("repeat")
+        .short('r')
+        .long("repeat")
+        .value_name("RULE")
+        .value_parser(clap::value_parser!(Recurrence))
+        .help("daily, weekly หรือ cron-lite เช่น \"0 9 * * 1-5\"")
+}
+
+fn due_arg() -> Arg {
+    Arg::new("due")
+        .short('d')
+        .long("due")
+        .value_name("WHEN")
+        .help("today, tomorrow, +3d, 2025-12-31, 2025-12-31 18:00 หรือ RFC 3339")
+}
+
+/// ไฟล์ tasks เริ่มต้น: `<data dir>/rust_concepts/tasks.json`
+#[must_use]
+pub fn default_file() -> PathBuf {
+    dirs::data_local_dir().map_or_else(
+        || PathBuf::from("tasks.json"),
+        |dir| dir.join("rust_concepts").join("tasks.json"),
+    )
+}
+
+/// รัน subcommand ที่ parse แล้ว - ผลลัพธ์เขียนลง `out` เพื่อให้ tests จับได้
+///
+/// # Errors
+///
+/// คืน error พร้อม context เมื่ออ่าน/เขียนไฟล์ไม่ได้, หา task ไม่เจอ หรือ `--due` อ่านไม่ออก
+pub fn run(matches: &ArgMatches, out: &mut impl Write) -> Result<()> {
+    let file = matches
+        .get_one::<PathBuf>("file")
+        .cloned()
+        .unwrap_or_else(default_file);
+    let mut manager = open(file)?;
+    let now = Utc::now();
+
+    match matches.subcommand() {
+        Some(("add", args)) => add(&mut manager, args, now, out)?,
+        Some(("list", args)) => {
+            let priority = args.get_one::<Priority>("priority");
+            let tags: Vec<&String> = args.get_many("tag").unwrap_or_default().collect();
+            let status = args
+                .get_one::<String>("status")
+                .map_or("all", String::as_str);
+            let tasks: Vec<&Task> = manager
+                .list_tasks()
+                .iter()
+                .filter(|task| priority.is_none_or(|p| task.priority == *p))
+                .filter(|task| tags.iter().all(|tag| task.tags.contains(tag)))
+                .filter(|task| match status {
+                    "pending" => !task.completed,
+                    "done" => task.completed,
+                    _ => true,
+                })
+                .collect();
+            write!(out, "{}", render_table(&tasks, now, use_color(args)))?;
+        }
+        Some(("search", args)) => {
+            let tasks = manager.search(&merged_query(args));
+            write!(out, "{}", render_table(&tasks, now, use_color(args)))?;
+        }
+        Some(("done", args)) => done(&mut manager, args, now, out)?,
+        Some(("rm", args)) => {
+            let id = resolve(&manager, args)?;
+            manager.remove_task(&id)?;
+            save(&manager)?;
+            writeln!(out, "🗑️ ลบ {} แล้ว", short_id(&id))?;
+        }
+        Some(("edit", args)) => {
+            let id = resolve(&manager, args)?;
+            let due = args
+                .get_one::<String>("due")
+                .map(|input| parse_due(input, now))
+                .transpose()?;
+            manager.update_task(&id, |task| edit_task(task, args, due))?;
+            save(&manager)?;
+            writeln!(out, "✏️ แก้ไข {} แล้ว", short_id(&id))?;
+        }
+        Some(("export", args)) => export(&manager, args, out)?,
+        _ => bail!("unknown task subcommand"),
+    }
+    Ok(())
+}
+
+fn add(
+    manager: &mut TaskManager,
+    args: &ArgMatches,
+    now: DateTime<Utc>,
+    out: &mut impl Write,
+) -> Result<()> {
+    let title = args.get_one::<String>("title").cloned().unwrap_or_default();
+    let priority = args.get_one::<Priority>("priority").copied();
+    let due = args
+        .get_one::<String>("due")
+        .map(|input| parse_due(input, now))
+        .transpose()?;
+    let id = manager.add_task(title, priority.unwrap_or_default());
+    manager.update_task(&id, |task| {
+        task.due = due;
+        add_tags(task, args);
+        task.recurrence = args.get_one::<Recurrence>("repeat").cloned();
+    })?;
+    save(manager)?;
+    writeln!(out, "➕ เพิ่ม task {}", short_id(&id))?;
+    Ok(())
+}
+
+/// แต่ละคำบน command line parse แยกกัน แล้วรวมเงื่อนไขเป็น query เดียว
+fn merged_query(args: &ArgMatches) -> Query {
+    let mut query = Query::default();
+    for part in args.get_many::<Query>("query").unwrap_or_default() {
+        query.words.extend(part.words.iter().cloned());
+        query.tags.extend(part.tags.iter().cloned());
+        query.priority = part.priority.or(query.priority);
+        query.status = part.status.or(query.status);
+        if part.sort != SortBy::default() {
+            query.sort = part.sort;
+        }
+    }
+    query
+}
+
+fn done(
+    manager: &mut TaskManager,
+    args: &ArgMatches,
+    now: DateTime<Utc>,
+    out: &mut impl Write,
+) -> Result<()> {
+    let id = resolve(manager, args)?;
+    manager.complete_task(&id)?;
+    let rolled = manager.roll_over_at(now);
+    save(manager)?;
+    writeln!(out, "✅ {} เสร็จแล้ว", short_id(&id))?;
+    for next in manager
+        .list_tasks()
+        .iter()
+        .filter(|t| rolled.contains(&t.id))
+    {
+        writeln!(
+            out,
+            "🔁 รอบถัดไป {} (due {})",
+            short_id(&next.id),
+            format_due(next.due)
+        )?;
+    }
+    Ok(())
+}
+
+fn export(manager: &TaskManager, args: &ArgMatches, out: &mut impl Write) -> Result<()> {
+    let json =
+        serde_json::to_string_pretty(manager.list_tasks()).context("Failed to serialize tasks")?;
+    match args.get_one::<PathBuf>("output") {
+        Some(path) => {
+            write_atomically(path, json.as_bytes())?;
+            writeln!(
+                out,
+                "📤 export {} tasks ไปที่ {}",
+                manager.list_tasks().len(),
+                path.display()
+            )?;
+        }
+        None => writeln!(out, "{json}")?,
+    }
+    Ok(())
+}
+
+/// เปิดไฟล์ tasks - format ตอนบันทึกเลือกจากนามสกุล (`.bin` = binary พร้อม CRC)
+fn open(file: PathBuf) -> Result<TaskManager> {
+    let format = if file.extension().is_some_and(|ext| ext == "bin") {
+        StorageFormat::Binary { checksum: true }
+    } else {
+        StorageFormat::Json
+    };
+    let mut manager = TaskManager::new(file).with_format(format);
+    manager
+        .load_from_file()
+        .with_context(|| format!("Cannot load tasks from {}", manager.file_path().display()))?;
+    Ok(manager)
+}
+
+fn save(manager: &TaskManager) -> Result<()> {
+    if let Some(dir) = manager
+        .file_path()
+        .parent()
+        .filter(|dir| !dir.as_os_str().is_empty())
+    {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("Cannot create directory {}", dir.display()))?;
+    }
+    manager.save_to_file()
+}
+
+fn write_atomically(path: &Path, bytes: &[u8]) -> Result<()> {
+    let mut temp_name = path.file_name().unwrap_or_default().to_os_string();
+    temp_name.push(".tmp");
+    let temp_path = path.with_file_name(temp_name);
+    std::fs::write(&temp_path, bytes)
+        .with_context(|| format!("Failed to write to {}", temp_path.display()))?;
+    std::fs::rename(&temp_path, path)
+        .with_context(|| format!("Failed to replace {}", path.display()))
+}
+
+fn use_color(args: &ArgMatches) -> bool {
+    !args.get_flag("no-color")
+        && std::env::var_os("NO_COLOR").is_none()
+        && std::io::stdout().is_terminal()
+}
+
+fn resolve(manager: &TaskManager, args: &ArgMatches) -> Result<uuid::Uuid> {
+    let prefix = args.get_one::<String>("id").map_or("", String::as_str);
+    manager.resolve_id(prefix)
+}
+
+fn edit_task(task: &mut Task, args: &ArgMatches, due: Option<DateTime<Utc>>) {
+    if let Some(title) = args.get_one::<String>("title") {
+        task.title.clone_from(title);
+    }
+    if let Some(priority) = args.get_one::<Priority>("priority") {
+        task.priority = *priority;
+    }
+    if due.is_some() {
+        task.due = due;
+    } else if args.get_flag("clear-due") {
+        task.due = None;
+    }
+    add_tags(task, args);
+    if let Some(untag) = args.get_many::<String>("untag") {
+        let untag: Vec<&String> = untag.collect();
+        task.tags.retain(|tag| !untag.contains(&tag));
+    }
+    if let Some(recurrence) = args.get_one::<Recurrence>("repeat") {
+        task.recurrence = Some(recurrence.clone());
+    } else if args.get_flag("no-repeat") {
+        task.recurrence = None;
+    }
+}
+
+/// เพิ่ม `--tag` ที่ยังไม่มี (tag ซ้ำไม่มีประโยชน์)
+fn add_tags(task: &mut Task, args: &ArgMatches) {
+    for tag in args.get_many::<String>("tag").unwrap_or_default() {
+        if !task.tags.contains(tag) {
+            task.tags.push(tag.clone());
+        }
+    }
+}
+
+fn short_id(id: &uuid::Uuid) -> String {
+    id.to_string()[..8].to_string()
+}
+
+/// 📅 แปลงข้อความเป็นกำหนดส่ง (UTC) - วันที่อย่างเดียวหมายถึงสิ้นวันนั้น
+///
+/// # Errors
+///
+/// คืน error ถ้าไม่ตรงกับรูปแบบที่รองรับ
+pub fn parse_due(input: &str, now: DateTime<Utc>) -> Result<DateTime<Utc>> {
+    let input = input.trim();
+    let end_of_day = |date: NaiveDate| date.and_time(END_OF_DAY).and_utc();
+
+    match input.to_ascii_lowercase().as_str() {
+        "today" => return Ok(end_of_day(now.date_naive())),
+        "tomorrow" => return Ok(end_of_day(now.date_naive() + Duration::days(1))),
+        _ => {}
+    }
+    if let Some(amount) = input.strip_prefix('+') {
+        let unit = amount.chars().next_back().unwrap_or_default();
+        let number: i64 = amount[..amount.len() - unit.len_utf8()]
+            .parse()
+            .with_context(|| format!("invalid relative due date `{input}`"))?;
+        let offset = match unit {
+            'h' => Duration::try_hours(number),
+            'd' => Duration::try_days(number),
+            'w' => Duration::try_weeks(number),
+            _ => bail!("invalid relative due date `{input}` (use h, d or w)"),
+        };
+        return offset
+            .and_then(|offset| now.checked_add_signed(offset))
+            .with_context(|| format!("due date `{input}` is out of range"));
+    }
+    if let Ok(date_time) = DateTime::parse_from_rfc3339(input) {
+        return Ok(date_time.with_timezone(&Utc));
+    }
+    if let Ok(date_time) = NaiveDateTime::parse_from_str(input, "%Y-%m-%d %H:%M") {
+        return Ok(date_time.and_utc());
+    }
+    NaiveDate::parse_from_str(input, "%Y-%m-%d")
+        .map(end_of_day)
+        .with_context(|| format!("cannot parse due date `{input}`"))
+}
+
+/// 🎨 วาดตาราง tasks - `color` ใส่สีตาม priority/สถานะ และทำให้งานเลยกำหนดเป็นสีแดง
+#[must_use]
+pub fn render_table(tasks: &[&Task], now: DateTime<Utc>, color: bool) -> String {
+    if tasks.is_empty() {
+        return "📭 ไม่มี task\n".to_string();
+    }
+
+    let rows: Vec<[String; 5]> = tasks
+        .iter()
+        .map(|task| {
+            [
+                short_id(&task.id),
+                if task.completed { "done" } else { "pending" }.to_string(),
+                task.priority.to_string(),
+                format_due(task.due),
+                title_cell(task),
+            ]
+        })
+        .collect();
+    let headers = ["ID", "STATUS", "PRIORITY", "DUE", "TITLE"];
+    let mut widths = headers.map(|header| header.chars().count());
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let mut table = String::new();
+    push_row(&mut table, &headers.map(String::from), &widths, |_| None);
+    for (task, row) in tasks.iter().zip(&rows) {
+        let overdue = task.is_overdue(now);
+        push_row(&mut table, row, &widths, |column| {
+            if !color {
+                return None;
+            }
+            match column {
+                1 if task.completed => Some("32"),
+                2 if task.priority == Priority::High => Some("1;31"),
+                2 if task.priority == Priority::Low => Some("2"),
+                3 if overdue => Some("31"),
+                _ => None,
+            }
+        });
+    }
+    table
+}
+
+fn format_due(due: Option<DateTime<Utc>>) -> String {
+    due.map_or_else(
+        || "-".to_string(),
+        |due| due.format("%Y-%m-%d %H:%M").to_string(),
+    )
+}
+
+/// ชื่อ task ตามด้วย `#tag` และกฎการทำซ้ำ - อยู่คอลัมน์สุดท้ายจึงยาวเท่าไรก็ไม่ทำให้ตารางเบี้ยว
+fn title_cell(task: &Task) -> String {
+    let mut cell = task.title.clone();
+    for tag in &task.tags {
+        cell.push_str(" #");
+        cell.push_str(tag);
+    }
+    if let Some(recurrence) = &task.recurrence {
+        let _ = write!(cell, " [{recurrence}]");
+    }
+    cell
+}
+
+/// เติมช่องว่างก่อนใส่สี - escape code ของ ANSI ไม่ควรถูกนับเป็นความกว้าง
+fn push_row(
+    table: &mut String,
+    cells: &[String; 5],
+    widths: &[usize; 5],
+    style: impl Fn(usize) -> Option<&'static str>,
+) {
+    for (column, (cell, width)) in cells.iter().zip(widths).enumerate() {
+        let padding = " ".repeat(width - cell.chars().count());
+        let last = column + 1 == cells.len();
+        match style(column) {
+            Some(code) => {
+                let _ = write!(table, "\x1b[{code}m{cell}\x1b[0m");
+            }
+            None => table.push_str(cell),
+        }
+        if !last {
+            table.push_str(&padding);
+            table.push_str("  ");
+        }
+    }
+    table.push('\n');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn now() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2025-03-10T08:30:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn run_task(file: &Path, args: &[&str]) -> Result<String> {
+        let file = file.to_str().unwrap();
+        let matches = command().try_get_matches_from(
+            std::iter::once(&"task")
+                .chain(args)
+                .chain(&["--file", file]),
+        )?;
+        let mut out = Vec::new();
+        run(&matches, &mut out)?;
+        Ok(String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn test_parse_due_formats() {
+        let at = |text: &str| {
+            DateTime::parse_from_rfc3339(text)
+                .unwrap()
+                .with_timezone(&Utc)
+        };
+        assert_eq!(
+            parse_due("today", now()).unwrap(),
+            at("2025-03-10T23:59:59Z")
+        );
+        assert_eq!(
+            parse_due("Tomorrow", now()).unwrap(),
+            at("2025-03-11T23:59:59Z")
+        );
+        assert_eq!(parse_due("+3d", now()).unwrap(), at("2025-03-13T08:30:00Z"));
+        assert_eq!(parse_due("+2h", now()).unwrap(), at("2025-03-10T10:30:00Z"));
+        assert_eq!(
+            parse_due("2025-12-31", now()).unwrap(),
+            at("2025-12-31T23:59:59Z")
+        );
+        assert_eq!(
+            parse_due("2025-12-31 18:00", now()).unwrap(),
+            at("2025-12-31T18:00:00Z")
+        );
+        assert_eq!(
+            parse_due("2025-12-31T18:00:00+07:00", now()).unwrap(),
+            at("2025-12-31T11:00:00Z")
+        );
+        for bad in ["next week", "+3y", "+d", "2025-13-01"] {
+            assert!(parse_due(bad, now()).is_err(), "{bad}");
+        }
+    }
+
+    #[test]
+    fn test_subcommands_update_the_file() {
+        let dir = TempDir::new().unwrap();
+        let file = dir.path().join("nested").join("tasks.json");
+
+        run_task(
+            &file,
+            &[
+                "add",
+                "Write docs",
+                "--priority",
+                "HIGH",
+                "--due",
+                "2030-01-01",
+            ],
+        )
+        .unwrap();
+        run_task(&file, &["add", "Fix bug"]).unwrap();
+        let mut manager = TaskManager::new(file.clone());
+        manager.load_from_file().unwrap();
+        let docs = manager.list_tasks()[0].clone();
+        assert_eq!(docs.priority, Priority::High);
+        assert_eq!(manager.list_tasks()[1].priority, Priority::Medium);
+
+        let prefix = &docs.id.to_string()[..6];
+        run_task(&file, &["done", prefix]).unwrap();
+        run_task(
+            &file,
+            &["edit", prefix, "--title", "Write more docs", "--clear-due"],
+        )
+        .unwrap();
+
+        let listed = run_task(&file, &["list", "--status", "done", "--no-color"]).unwrap();
+        assert!(listed.contains("Write more docs"));
+        assert!(!listed.contains("Fix bug"));
+        let high = run_task(&file, &["ls", "-p", "high"]).unwrap();
+        assert_eq!(high.lines().count(), 2, "{high}");
+
+        run_task(&file, &["rm", prefix]).unwrap();
+        let mut manager = TaskManager::new(file);
+        manager.load_from_file().unwrap();
+        assert_eq!(manager.list_tasks().len(), 1);
+        assert!(!dir.path().join("nested").join("tasks.json.tmp").exists());
+    }
+
+    #[test]
+    fn test_tags_and_recurring_tasks() {
+        let dir = TempDir::new().unwrap();
+        let file = dir.path().join("tasks.json");
+        let args = [
+            "add",
+            "Standup",
+            "--due",
+            "2020-01-06 09:00",
+            "--repeat",
+            "weekly",
+            "--tag",
+            "team",
+            "--tag",
+            "daily",
+        ];
+        run_task(&file, &args).unwrap();
+        run_task(&file, &["add", "Lunch", "--tag", "team"]).unwrap();
+
+        let team = run_task(&file, &["list", "--tag", "team", "--tag", "daily"]).unwrap();
+        assert!(team.contains("Standup #team #daily [weekly]"), "{team}");
+        assert!(!team.contains("Lunch"));
+
+        let mut manager = TaskManager::new(file.clone());
+        manager.load_from_file().unwrap();
+        let standup = manager.list_tasks()[0].id.to_string();
+        let output = run_task(&file, &["done", &standup]).unwrap();
+        assert!(output.contains("🔁 รอบถัดไป"), "{output}");
+
+        let mut manager = TaskManager::new(file.clone());
+        manager.load_from_file().unwrap();
+        let next = manager.list_tasks().last().unwrap();
+        assert_eq!(next.title, "Standup");
+        assert!(!next.completed);
+        assert_eq!(next.recurrence, Some(Recurrence::Weekly));
+        // weekly นับต่อจากวันจันทร์ 9 โมงเดิม ข้ามรอบที่พลาดไปจนเลยเวลาปัจจุบัน
+        let due = next.due.unwrap();
+        assert!(due > Utc::now());
+        assert_eq!(due.format("%a %H:%M").to_string(), "Mon 09:00");
+
+        let next_id = next.id.to_string();
+        run_task(
+            &file,
+            &["edit", &next_id, "--untag", "daily", "--no-repeat"],
+        )
+        .unwrap();
+        let mut manager = TaskManager::new(file);
+        manager.load_from_file().unwrap();
+        let next = manager.list_tasks().last().unwrap();
+        assert_eq!(next.tags, ["team"]);
+        assert_eq!(next.recurrence, None);
+    }
+
+    #[test]
+    fn test_errors_carry_context() {
+        let dir = TempDir::new().unwrap();
+        let file = dir.path().join("tasks.bin");
+        run_task(&file, &["add", "only"]).unwrap();
+        assert!(
+            std::fs::read(&file)
+                .unwrap()
+                .starts_with(crate::task_storage::MAGIC)
+        );
+
+        let error = run_task(&file, &["done", "zzzz"]).unwrap_err();
+        assert!(error.to_string().contains("Task not found"));
+        let error = run_task(&file, &["add", "x", "--due", "someday"]).unwrap_err();
+        assert!(format!("{error:#}").contains("cannot parse due date `someday`"));
+
+        std::fs::write(&file, b"RTSK\x09\x00").unwrap();
+        let error = run_task(&file, &["list"]).unwrap_err();
+        let message = format!("{error:#}");
+        assert!(message.contains("Cannot load tasks from"), "{message}");
+        assert!(
+            message.contains("unsupported task file version 9"),
+            "{message}"
+        );
+    }
+
+    #[test]
+    fn test_export_and_table_rendering() {
+        let dir = TempDir::new().unwrap();
+        let file = dir.path().join("tasks.json");
+        let export = dir.path().join("export.json");
+        run_task(&file, &["add", "Ship it", "-p", "high", "-d", "2020-01-01"]).unwrap();
+        run_task(&file, &["export", "--output", export.to_str().unwrap()]).unwrap();
+        let exported: Vec<Task> = serde_json::from_slice(&std::fs::read(&export).unwrap()).unwrap();
+        assert_eq!(exported[0].title, "Ship it");
+        assert!(
+            run_task(&file, &["export"])
+                .unwrap()
+                .contains("\"Ship it\"")
+        );
+
+        let tasks = vec![&exported[0]];
+        let plain = render_table(&tasks, now(), false);
+        assert!(plain.starts_with("ID        STATUS   PRIORITY  DUE"));
+        assert!(!plain.contains('\x1b'));
+        let colored = render_table(&tasks, now(), true);
+        assert!(colored.contains("\x1b[1;31mHigh\x1b[0m"));
+        assert!(
+            colored.contains("\x1b[31m2020-01-01 23:59\x1b[0m"),
+            "overdue is red"
+        );
+    }
+}
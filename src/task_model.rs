@@ -0,0 +1,415 @@
+//! 🗂️ Task Model - `Priority` และ `Recurrence` ของ `Task`
+//!
+//! เดิม priority เป็น `String` อะไรก็ได้ ("High", "high", "HIGH" ถือว่าต่างกัน 😵)
+//! ตอนนี้เป็น enum ที่เรียงลำดับได้ และยังอ่าน JSON รูปแบบเก่าได้เพราะ deserialize แบบไม่สนตัวพิมพ์
+//!
+//! `Recurrence` บอกว่า task ทำซ้ำเมื่อไร - `TaskManager::roll_over()` ใช้สร้าง task รอบถัดไป
+//! - 📅 `daily` / `weekly` - นับจากกำหนดส่งเดิม (เวลาเดิมของวัน)
+//! - ⏰ cron-lite `"นาที ชั่วโมง วันที่ เดือน วันในสัปดาห์"` เช่น `"0 9 * * 1-5"` (9 โมงวันทำงาน)
+//!   รองรับ `*`, `5`, `1-5`, `1,3,5`, `*/15`, `10-50/10` - ต้องตรง **ทุกช่อง**
+//!   (ต่างจาก cron จริงที่วันที่กับวันในสัปดาห์เป็น OR)
+//!
+//! ```
+//! use rust_concepts::{Priority, Recurrence};
+//!
+//! assert_eq!("HIGH".parse::<Priority>().unwrap(), Priority::High);
+//! assert!(Priority::High > Priority::Low);
+//!
+//! let weekdays: Recurrence = "0 9 * * 1-5".parse().unwrap();
+//! let friday_evening = "2025-03-14T18:00:00Z".parse().unwrap();
+//! let next = weekdays.next_after(friday_evening).unwrap();
+//! assert_eq!(next.to_rfc3339(), "2025-03-17T09:00:00+00:00"); // วันจันทร์
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Timelike, Utc};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// ❌ ข้อความที่แปลงเป็น `Priority` หรือ `Recurrence` ไม่ได้
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskParseError(String);
+
+impl TaskParseError {
+    pub(crate) const fn new(message: String) -> Self {
+        Self(message)
+    }
+}
+
+impl fmt::Display for TaskParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for TaskParseError {}
+
+/// 🚦 ความสำคัญของ task - เรียงจากน้อยไปมาก
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Priority {
+    Low,
+    #[default]
+    Medium,
+    High,
+}
+
+impl Priority {
+    pub const ALL: [Self; 3] = [Self::Low, Self::Medium, Self::High];
+
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Low => "Low",
+            Self::Medium => "Medium",
+            Self::High => "High",
+        }
+    }
+}
+
+impl fmt::Display for Priority {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Priority {
+    type Err = TaskParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|priority| priority.as_str().eq_ignore_ascii_case(text.trim()))
+            .ok_or_else(|| {
+                TaskParseError(format!(
+                    "unknown priority `{text}` (use low, medium or high)"
+                ))
+            })
+    }
+}
+
+// เขียนเป็น "High" เหมือนสมัยที่ยังเป็น String - ไฟล์ใหม่จึงยังเปิดด้วยโปรแกรมเวอร์ชันเก่าได้
+impl Serialize for Priority {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Priority {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        text.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// 🔁 กฎการทำซ้ำของ task
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum Recurrence {
+    Daily,
+    Weekly,
+    Cron(CronLite),
+}
+
+impl Recurrence {
+    /// เวลาที่ตรงกฎครั้งแรก *หลัง* `after` (daily/weekly คือบวก 1 รอบ)
+    #[must_use]
+    pub fn next_after(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        match self {
+            Self::Daily => after.checked_add_signed(Duration::days(1)),
+            Self::Weekly => after.checked_add_signed(Duration::weeks(1)),
+            Self::Cron(cron) => cron.next_after(after),
+        }
+    }
+
+    /// กำหนดส่งรอบถัดไปที่อยู่หลัง `now` - ข้ามรอบที่พลาดไปแล้วทั้งหมด
+    ///
+    /// daily/weekly นับต่อจาก `previous` เพื่อรักษาเวลาของวันเดิมไว้ (ไม่มี `previous` = นับจาก `now`)
+    #[must_use]
+    pub fn next_due(
+        &self,
+        previous: Option<DateTime<Utc>>,
+        now: DateTime<Utc>,
+    ) -> Option<DateTime<Utc>> {
+        let period = match self {
+            Self::Daily => Duration::days(1),
+            Self::Weekly => Duration::weeks(1),
+            Self::Cron(cron) => return cron.next_after(previous.map_or(now, |p| p.max(now))),
+        };
+        let previous = previous.unwrap_or(now);
+        // คำนวณจำนวนรอบที่ต้องข้ามตรงๆ แทนการวนบวกทีละรอบ
+        let missed = if previous < now {
+            (now - previous).num_seconds() / period.num_seconds()
+        } else {
+            0
+        };
+        previous.checked_add_signed(period.checked_mul(i32::try_from(missed + 1).ok()?)?)
+    }
+}
+
+impl fmt::Display for Recurrence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Daily => f.write_str("daily"),
+            Self::Weekly => f.write_str("weekly"),
+            Self::Cron(cron) => write!(f, "{cron}"),
+        }
+    }
+}
+
+impl FromStr for Recurrence {
+    type Err = TaskParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match text.trim().to_ascii_lowercase().as_str() {
+            "daily" => Ok(Self::Daily),
+            "weekly" => Ok(Self::Weekly),
+            _ => text.parse().map(Self::Cron),
+        }
+    }
+}
+
+impl TryFrom<String> for Recurrence {
+    type Error = TaskParseError;
+
+    fn try_from(text: String) -> Result<Self, Self::Error> {
+        text.parse()
+    }
+}
+
+impl From<Recurrence> for String {
+    fn from(recurrence: Recurrence) -> Self {
+        recurrence.to_string()
+    }
+}
+
+/// ⏰ cron 5 ช่องแบบย่อ - แต่ละช่องเก็บเป็น bitset ของค่าที่อนุญาต
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronLite {
+    source: String,
+    minutes: u64,
+    hours: u64,
+    days: u64,
+    months: u64,
+    weekdays: u64,
+}
+
+impl CronLite {
+    /// นาทีแรกที่ตรงทุกช่อง หลัง `after` - มองไปข้างหน้าไม่เกิน 5 ปี (พอสำหรับ 29 ก.พ.)
+    #[must_use]
+    pub fn next_after(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let start = after
+            .with_second(0)?
+            .with_nanosecond(0)?
+            .checked_add_signed(Duration::minutes(1))?;
+        let mut date = start.date_naive();
+        for _ in 0..=366 * 5 {
+            if self.matches_date(date) {
+                let first_day = date == start.date_naive();
+                let from_hour = if first_day { start.hour() } else { 0 };
+                for hour in (from_hour..24).filter(|&h| bit(self.hours, h)) {
+                    let from_minute = if first_day && hour == from_hour {
+                        start.minute()
+                    } else {
+                        0
+                    };
+                    if let Some(minute) = (from_minute..60).find(|&m| bit(self.minutes, m)) {
+                        return Some(date.and_hms_opt(hour, minute, 0)?.and_utc());
+                    }
+                }
+            }
+            date = date.succ_opt()?;
+        }
+        None
+    }
+
+    fn matches_date(&self, date: NaiveDate) -> bool {
+        bit(self.days, date.day())
+            && bit(self.months, date.month())
+            && bit(self.weekdays, date.weekday().num_days_from_sunday())
+    }
+}
+
+const fn bit(set: u64, value: u32) -> bool {
+    set & (1 << value) != 0
+}
+
+impl fmt::Display for CronLite {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+impl FromStr for CronLite {
+    type Err = TaskParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<&str> = text.split_whitespace().collect();
+        let [minute, hour, day, month, weekday] = fields[..] else {
+            return Err(TaskParseError(format!(
+                "`{text}` is not daily, weekly or a 5-field cron expression"
+            )));
+        };
+        let mut weekdays = parse_field(weekday, 0, 7, "weekday")?;
+        // 7 ก็คือวันอาทิตย์ เหมือน cron ทั่วไป
+        if bit(weekdays, 7) {
+            weekdays = (weekdays & !(1 << 7)) | 1;
+        }
+        Ok(Self {
+            source: fields.join(" "),
+            minutes: parse_field(minute, 0, 59, "minute")?,
+            hours: parse_field(hour, 0, 23, "hour")?,
+            days: parse_field(day, 1, 31, "day")?,
+            months: parse_field(month, 1, 12, "month")?,
+            weekdays,
+        })
+    }
+}
+
+/// `*`, `n`, `a-b`, `*/step`, `a-b/step` คั่นด้วย `,` → bitset
+fn parse_field(field: &str, min: u32, max: u32, name: &str) -> Result<u64, TaskParseError> {
+    let error = || TaskParseError(format!("invalid cron {name} field `{field}`"));
+    let number = |text: &str| {
+        text.parse::<u32>()
+            .ok()
+            .filter(|n| (min..=max).contains(n))
+            .ok_or_else(error)
+    };
+
+    let mut set = 0u64;
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => (
+                range,
+                step.parse::<u32>()
+                    .ok()
+                    .filter(|&s| s > 0)
+                    .ok_or_else(error)?,
+            ),
+            None => (part, 1),
+        };
+        let (start, end) = if range == "*" {
+            (min, max)
+        } else if let Some((start, end)) = range.split_once('-') {
+            (number(start)?, number(end)?)
+        } else {
+            let value = number(range)?;
+            (value, value)
+        };
+        if start > end {
+            return Err(error());
+        }
+        for value in (start..=end).step_by(step as usize) {
+            set |= 1 << value;
+        }
+    }
+    Ok(set)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(text: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(text)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn test_priority_parsing_and_order() {
+        assert_eq!("low".parse::<Priority>().unwrap(), Priority::Low);
+        assert_eq!(" Medium ".parse::<Priority>().unwrap(), Priority::Medium);
+        assert!("urgent".parse::<Priority>().is_err());
+
+        let mut priorities = vec![Priority::High, Priority::Low, Priority::Medium];
+        priorities.sort();
+        assert_eq!(priorities, Priority::ALL);
+        assert_eq!(serde_json::to_string(&Priority::High).unwrap(), r#""High""#);
+        assert_eq!(
+            serde_json::from_str::<Priority>(r#""hIgH""#).unwrap(),
+            Priority::High
+        );
+    }
+
+    #[test]
+    fn test_cron_fields() {
+        let every_quarter: CronLite = "*/15 * * * *".parse().unwrap();
+        assert_eq!(
+            every_quarter.next_after(at("2025-03-10T08:07:30Z")),
+            Some(at("2025-03-10T08:15:00Z"))
+        );
+        // ตรงเวลาพอดีก็ต้องเป็นรอบถัดไป ไม่ใช่รอบเดิม
+        assert_eq!(
+            every_quarter.next_after(at("2025-03-10T08:15:00Z")),
+            Some(at("2025-03-10T08:30:00Z"))
+        );
+
+        let month_end: CronLite = "30 17 31 1,3 *".parse().unwrap();
+        assert_eq!(
+            month_end.next_after(at("2025-02-01T00:00:00Z")),
+            Some(at("2025-03-31T17:30:00Z"))
+        );
+
+        let sunday: CronLite = "0 0 * * 7".parse().unwrap();
+        assert_eq!(
+            sunday.next_after(at("2025-03-10T00:00:00Z")),
+            Some(at("2025-03-16T00:00:00Z"))
+        );
+
+        let leap_day: CronLite = "0 12 29 2 *".parse().unwrap();
+        assert_eq!(
+            leap_day.next_after(at("2025-03-01T00:00:00Z")),
+            Some(at("2028-02-29T12:00:00Z"))
+        );
+
+        for bad in [
+            "* * * *",
+            "60 * * * *",
+            "5-1 * * * *",
+            "*/0 * * * *",
+            "a b c d e",
+        ] {
+            assert!(bad.parse::<CronLite>().is_err(), "{bad}");
+        }
+    }
+
+    #[test]
+    fn test_next_due_skips_missed_periods() {
+        let daily = Recurrence::Daily;
+        let due = at("2025-03-01T09:00:00Z");
+        assert_eq!(
+            daily.next_due(Some(due), at("2025-03-10T10:00:00Z")),
+            Some(at("2025-03-11T09:00:00Z"))
+        );
+        assert_eq!(
+            daily.next_due(Some(due), at("2025-02-20T00:00:00Z")),
+            Some(at("2025-03-02T09:00:00Z"))
+        );
+        assert_eq!(
+            Recurrence::Weekly.next_due(None, at("2025-03-10T10:00:00Z")),
+            Some(at("2025-03-17T10:00:00Z"))
+        );
+
+        let weekdays: Recurrence = "0 9 * * 1-5".parse().unwrap();
+        assert_eq!(
+            weekdays.next_due(Some(due), at("2025-03-14T18:00:00Z")),
+            Some(at("2025-03-17T09:00:00Z"))
+        );
+    }
+
+    #[test]
+    fn test_recurrence_serde_as_string() {
+        for text in ["daily", "weekly", "0 9 * * 1-5"] {
+            let recurrence: Recurrence = text.parse().unwrap();
+            let json = serde_json::to_string(&recurrence).unwrap();
+            assert_eq!(json, format!("\"{text}\""));
+            assert_eq!(
+                serde_json::from_str::<Recurrence>(&json).unwrap(),
+                recurrence
+            );
+        }
+        assert_eq!("DAILY".parse::<Recurrence>().unwrap(), Recurrence::Daily);
+        assert!(serde_json::from_str::<Recurrence>(r#""sometimes""#).is_err());
+    }
+}
@@ -0,0 +1,447 @@
+//! 🔎 Task Search - ค้นหา task ผ่าน inverted index แทนการไล่ดูทุกตัว
+//!
+//! query เป็นคำคั่นด้วยช่องว่าง ทุกเงื่อนไขต้องจริงพร้อมกัน (AND):
+//! - `docs` - มีคำใน title ที่ *มี* "docs" อยู่ข้างใน (ไม่สนตัวพิมพ์)
+//! - `#work` หรือ `tag:work` - มี tag นี้
+//! - `priority:high` หรือ `p:high`
+//! - `is:done` / `is:pending`
+//! - `sort:created` (ค่าเริ่มต้น), `sort:due` (ไม่มี due อยู่ท้าย), `sort:priority` (สูงก่อน)
+//!
+//! 📚 index เก็บ "คำ → ชุด id" แยกตาม title/tag/priority และถูกแก้ทีละ task ตอนเพิ่ม/ลบ/แก้
+//! การหา substring จึงไล่แค่ *คำศัพท์ที่ไม่ซ้ำ* แทนที่จะไล่ title ของทุก task -
+//! `scan` คือวิธีตรงไปตรงมาที่ให้ผลเหมือนกันเป๊ะ ใช้เทียบความเร็วใน `benches/chapter_claims.rs`
+//!
+//! ```
+//! use rust_concepts::task_search::Query;
+//! use rust_concepts::{Priority, TaskManager};
+//!
+//! let mut manager = TaskManager::new("tasks.json".into());
+//! let id = manager.add_task("Write API docs".to_string(), Priority::High);
+//! manager.add_task("Fix login bug".to_string(), Priority::High);
+//! manager
+//!     .update_task(&id, |task| task.tags.push("work".to_string()))
+//!     .unwrap();
+//!
+//! let query: Query = "doc #work p:high".parse().unwrap();
+//! let found = manager.search(&query);
+//! assert_eq!(found.len(), 1);
+//! assert_eq!(found[0].title, "Write API docs");
+//! ```
+
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BTreeSet};
+use std::str::FromStr;
+
+use uuid::Uuid;
+
+use crate::task_model::TaskParseError;
+use crate::{Priority, Task};
+
+/// ↕️ ลำดับของผลลัพธ์
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortBy {
+    #[default]
+    Created,
+    Due,
+    Priority,
+}
+
+/// ✅ สถานะที่ต้องการ (`None` ใน `Query` = ทุกสถานะ)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Pending,
+    Done,
+}
+
+/// 🔎 query ที่ parse แล้ว - สร้างจากข้อความด้วย `parse()` หรือประกอบเองก็ได้
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Query {
+    /// คำที่ต้องเป็น substring ของคำใดคำหนึ่งใน title (ตัวพิมพ์เล็กแล้ว)
+    pub words: Vec<String>,
+    /// tag ที่ต้องมีทุกตัว (ตัวพิมพ์เล็กแล้ว)
+    pub tags: Vec<String>,
+    pub priority: Option<Priority>,
+    pub status: Option<Status>,
+    pub sort: SortBy,
+}
+
+impl FromStr for Query {
+    type Err = TaskParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut query = Self::default();
+        for term in text.split_whitespace() {
+            let term = term.to_lowercase();
+            if let Some(tag) = term.strip_prefix('#').or_else(|| term.strip_prefix("tag:")) {
+                query.tags.push(tag.to_string());
+            } else if let Some(priority) = term
+                .strip_prefix("priority:")
+                .or_else(|| term.strip_prefix("p:"))
+            {
+                query.priority = Some(priority.parse()?);
+            } else if let Some(status) = term.strip_prefix("is:") {
+                query.status = Some(match status {
+                    "done" => Status::Done,
+                    "pending" => Status::Pending,
+                    _ => return Err(invalid(&term, "use is:done or is:pending")),
+                });
+            } else if let Some(sort) = term.strip_prefix("sort:") {
+                query.sort = match sort {
+                    "created" => SortBy::Created,
+                    "due" => SortBy::Due,
+                    "priority" => SortBy::Priority,
+                    _ => {
+                        return Err(invalid(
+                            &term,
+                            "use sort:created, sort:due or sort:priority",
+                        ));
+                    }
+                };
+            } else {
+                query.words.extend(tokenize(&term));
+            }
+        }
+        Ok(query)
+    }
+}
+
+fn invalid(term: &str, hint: &str) -> TaskParseError {
+    TaskParseError::new(format!("invalid search term `{term}` ({hint})"))
+}
+
+/// แยก title เป็นคำตัวพิมพ์เล็ก - ตัดที่ช่องว่างและเครื่องหมาย ASCII เท่านั้น
+/// (ภาษาไทยไม่เว้นวรรคและวรรณยุกต์ไม่ใช่ "ตัวอักษร" ใน Unicode จึงห้ามตัดด้วย `is_alphanumeric`)
+pub fn tokenize(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| c.is_whitespace() || (c.is_ascii() && !c.is_ascii_alphanumeric()))
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+}
+
+impl Query {
+    /// เงื่อนไขแบบไม่ใช้ index - นิยามความหมายของ query ที่ index ต้องให้ผลตรงกัน
+    #[must_use]
+    pub fn matches(&self, task: &Task) -> bool {
+        let title: Vec<String> = tokenize(&task.title).collect();
+        self.words
+            .iter()
+            .all(|word| title.iter().any(|token| token.contains(word.as_str())))
+            && self
+                .tags
+                .iter()
+                .all(|tag| task.tags.iter().any(|t| t.to_lowercase() == *tag))
+            && self.priority.is_none_or(|p| task.priority == p)
+            && self.matches_status(task)
+    }
+
+    const fn matches_status(&self, task: &Task) -> bool {
+        match self.status {
+            None => true,
+            Some(Status::Done) => task.completed,
+            Some(Status::Pending) => !task.completed,
+        }
+    }
+
+    fn sort_results(&self, tasks: &mut [&Task]) {
+        // sort แบบ stable - task ที่ key เท่ากันยังเรียงตามลำดับที่เพิ่มเข้ามา
+        match self.sort {
+            SortBy::Created => tasks.sort_by_key(|task| task.created_at),
+            SortBy::Due => tasks.sort_by_key(|task| (task.due.is_none(), task.due)),
+            SortBy::Priority => tasks.sort_by_key(|task| Reverse(task.priority)),
+        }
+    }
+}
+
+/// 🐢 ค้นหาแบบไล่ทุก task - O(จำนวน task × ความยาว title)
+#[must_use]
+pub fn scan<'a>(tasks: &'a [Task], query: &Query) -> Vec<&'a Task> {
+    let mut found: Vec<&Task> = tasks.iter().filter(|task| query.matches(task)).collect();
+    query.sort_results(&mut found);
+    found
+}
+
+type Postings = BTreeMap<String, BTreeSet<Uuid>>;
+
+/// 📚 inverted index ของ `TaskManager` - แก้ทีละ task ไม่ต้องสร้างใหม่ทั้งก้อน
+///
+/// `positions` จำว่า task แต่ละ id อยู่ช่องไหนใน `Vec<Task>` ของ manager
+#[derive(Debug, Default)]
+pub struct TaskIndex {
+    words: Postings,
+    tags: Postings,
+    priorities: BTreeMap<Priority, BTreeSet<Uuid>>,
+    positions: BTreeMap<Uuid, usize>,
+}
+
+impl TaskIndex {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            words: BTreeMap::new(),
+            tags: BTreeMap::new(),
+            priorities: BTreeMap::new(),
+            positions: BTreeMap::new(),
+        }
+    }
+
+    /// สร้าง index ใหม่ทั้งหมด - ใช้ตอนโหลดไฟล์
+    #[must_use]
+    pub fn rebuild(tasks: &[Task]) -> Self {
+        let mut index = Self::new();
+        for (position, task) in tasks.iter().enumerate() {
+            index.insert(task, position);
+        }
+        index
+    }
+
+    /// จำนวนคำไม่ซ้ำใน title ทั้งหมด (ขนาดที่ substring search ต้องไล่)
+    #[must_use]
+    pub fn vocabulary_len(&self) -> usize {
+        self.words.len()
+    }
+
+    /// เพิ่ม task ที่อยู่ช่อง `position`
+    pub fn insert(&mut self, task: &Task, position: usize) {
+        self.index_terms(task);
+        self.positions.insert(task.id, position);
+    }
+
+    /// ลบ task ที่ช่อง `position` - task หลังจากนั้นขยับขึ้นมาหนึ่งช่องเหมือน `Vec::remove`
+    pub fn remove(&mut self, task: &Task, position: usize) {
+        self.unindex_terms(task);
+        self.positions.remove(&task.id);
+        for slot in self.positions.values_mut().filter(|slot| **slot > position) {
+            *slot -= 1;
+        }
+    }
+
+    /// task ถูกแก้ในที่เดิม - ถอดคำของ `before` ออกแล้วใส่คำของ `after` (ตำแหน่งไม่เปลี่ยน)
+    pub fn update(&mut self, before: &Task, after: &Task) {
+        self.unindex_terms(before);
+        self.index_terms(after);
+    }
+
+    fn index_terms(&mut self, task: &Task) {
+        for word in tokenize(&task.title) {
+            self.words.entry(word).or_default().insert(task.id);
+        }
+        for tag in &task.tags {
+            self.tags
+                .entry(tag.to_lowercase())
+                .or_default()
+                .insert(task.id);
+        }
+        self.priorities
+            .entry(task.priority)
+            .or_default()
+            .insert(task.id);
+    }
+
+    fn unindex_terms(&mut self, task: &Task) {
+        for word in tokenize(&task.title) {
+            remove_posting(&mut self.words, &word, task.id);
+        }
+        for tag in &task.tags {
+            remove_posting(&mut self.tags, &tag.to_lowercase(), task.id);
+        }
+        if let Some(ids) = self.priorities.get_mut(&task.priority) {
+            ids.remove(&task.id);
+        }
+    }
+
+    /// ⚡ ค้นหาผ่าน index - ผลลัพธ์ (และลำดับ) เหมือน `scan` ทุกประการ
+    #[must_use]
+    pub fn search<'a>(&self, tasks: &'a [Task], query: &Query) -> Vec<&'a Task> {
+        let mut candidates: Option<BTreeSet<Uuid>> = None;
+        let mut narrow = |ids: BTreeSet<Uuid>| {
+            candidates = Some(match candidates.take() {
+                Some(current) => current.intersection(&ids).copied().collect(),
+                None => ids,
+            });
+        };
+
+        for word in &query.words {
+            // ไล่คำศัพท์ที่ไม่ซ้ำ ไม่ใช่ไล่ task - คำที่ตรงกันหลายคำ union กัน
+            let ids = self
+                .words
+                .iter()
+                .filter(|(token, _)| token.contains(word.as_str()))
+                .flat_map(|(_, ids)| ids.iter().copied())
+                .collect();
+            narrow(ids);
+        }
+        for tag in &query.tags {
+            narrow(self.tags.get(tag).cloned().unwrap_or_default());
+        }
+        if let Some(priority) = query.priority {
+            narrow(self.priorities.get(&priority).cloned().unwrap_or_default());
+        }
+
+        let mut positions: Vec<usize> = candidates.map_or_else(
+            || (0..tasks.len()).collect(),
+            |ids| {
+                ids.iter()
+                    .filter_map(|id| self.positions.get(id))
+                    .copied()
+                    .collect()
+            },
+        );
+        positions.sort_unstable();
+        let mut found: Vec<&Task> = positions
+            .into_iter()
+            .filter_map(|position| tasks.get(position))
+            .filter(|task| query.matches_status(task))
+            .collect();
+        query.sort_results(&mut found);
+        found
+    }
+}
+
+fn remove_posting(postings: &mut Postings, term: &str, id: Uuid) {
+    if let Some(ids) = postings.get_mut(term) {
+        ids.remove(&id);
+        if ids.is_empty() {
+            postings.remove(term);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TaskManager;
+    use chrono::{Duration, Utc};
+
+    fn manager() -> TaskManager {
+        let mut manager = TaskManager::new("unused.json".into());
+        let specs = [
+            ("Write API docs", Priority::High, &["work"][..], Some(3)),
+            (
+                "Fix login bug",
+                Priority::High,
+                &["work", "Urgent"][..],
+                Some(1),
+            ),
+            ("Buy groceries", Priority::Low, &["home"][..], None),
+            ("Read Rust book", Priority::Medium, &[][..], Some(2)),
+            ("ซื้อของขวัญวันเกิด", Priority::Medium, &["home"][..], None),
+        ];
+        let start = Utc::now();
+        for (offset, (title, priority, tags, due_days)) in specs.into_iter().enumerate() {
+            let id = manager.add_task(title.to_string(), priority);
+            manager
+                .update_task(&id, |task| {
+                    task.created_at = start + Duration::seconds(i64::try_from(offset).unwrap());
+                    task.tags = tags.iter().map(ToString::to_string).collect();
+                    task.due = due_days.map(|days| start + Duration::days(days));
+                })
+                .unwrap();
+        }
+        manager
+    }
+
+    fn titles(tasks: &[&Task]) -> Vec<String> {
+        tasks.iter().map(|task| task.title.clone()).collect()
+    }
+
+    fn search(manager: &TaskManager, query: &str) -> Vec<String> {
+        let query: Query = query.parse().unwrap();
+        let indexed = manager.search(&query);
+        assert_eq!(
+            indexed,
+            scan(manager.list_tasks(), &query),
+            "index and scan disagree"
+        );
+        titles(&indexed)
+    }
+
+    #[test]
+    fn test_query_parsing() {
+        let query: Query = "API-Docs #Work p:HIGH is:pending sort:due".parse().unwrap();
+        assert_eq!(query.words, ["api", "docs"]);
+        assert_eq!(query.tags, ["work"]);
+        assert_eq!(query.priority, Some(Priority::High));
+        assert_eq!(query.status, Some(Status::Pending));
+        assert_eq!(query.sort, SortBy::Due);
+
+        for bad in ["p:urgent", "is:later", "sort:title"] {
+            assert!(bad.parse::<Query>().is_err(), "{bad}");
+        }
+    }
+
+    #[test]
+    fn test_filters_match_scan() {
+        let manager = manager();
+        assert_eq!(search(&manager, "doc"), ["Write API docs"]);
+        assert_eq!(
+            search(&manager, "b"),
+            ["Fix login bug", "Buy groceries", "Read Rust book"]
+        );
+        assert_eq!(
+            search(&manager, "#work"),
+            ["Write API docs", "Fix login bug"]
+        );
+        assert_eq!(search(&manager, "tag:urgent"), ["Fix login bug"]);
+        assert_eq!(search(&manager, "#home ของขวัญ"), ["ซื้อของขวัญวันเกิด"]);
+        assert_eq!(search(&manager, "p:low"), ["Buy groceries"]);
+        assert!(search(&manager, "doc #home").is_empty());
+        assert!(search(&manager, "nothing-like-this").is_empty());
+        assert_eq!(search(&manager, "").len(), 5);
+    }
+
+    #[test]
+    fn test_sorting() {
+        let manager = manager();
+        assert_eq!(
+            search(&manager, "sort:due"),
+            [
+                "Fix login bug",
+                "Read Rust book",
+                "Write API docs",
+                "Buy groceries",
+                "ซื้อของขวัญวันเกิด"
+            ]
+        );
+        assert_eq!(
+            search(&manager, "sort:priority"),
+            [
+                "Write API docs",
+                "Fix login bug",
+                "Read Rust book",
+                "ซื้อของขวัญวันเกิด",
+                "Buy groceries"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_index_follows_add_update_remove() {
+        let mut manager = manager();
+        let bug = manager.list_tasks()[1].id;
+        manager.complete_task(&bug).unwrap();
+        assert_eq!(search(&manager, "is:done"), ["Fix login bug"]);
+        assert_eq!(search(&manager, "#work is:pending"), ["Write API docs"]);
+
+        manager
+            .update_task(&bug, |task| {
+                task.title = "Fix signup bug".to_string();
+                task.tags.retain(|tag| tag != "Urgent");
+            })
+            .unwrap();
+        assert!(search(&manager, "login").is_empty());
+        assert!(search(&manager, "#urgent").is_empty());
+        assert_eq!(search(&manager, "signup"), ["Fix signup bug"]);
+
+        // ลบ task ตรงกลาง - ตำแหน่งของ task ถัดไปต้องขยับตาม
+        let docs = manager.list_tasks()[0].id;
+        manager.remove_task(&docs).unwrap();
+        assert_eq!(
+            search(&manager, "#home"),
+            ["Buy groceries", "ซื้อของขวัญวันเกิด"]
+        );
+
+        let id = manager.add_task("Write release notes".to_string(), Priority::Low);
+        assert_eq!(search(&manager, "writ"), ["Write release notes"]);
+        manager.remove_task(&id).unwrap();
+        assert!(search(&manager, "writ").is_empty());
+    }
+}
@@ -0,0 +1,598 @@
+//! 💾 Task Storage - บันทึก `TaskManager` เป็น JSON หรือ binary ที่ออกแบบเอง
+//!
+//! JSON อ่านง่ายแต่เปลืองที่ ส่วน binary กะทัดรัดแต่ต้องวางแผนเรื่องเวอร์ชันเอง
+//! ไฟล์ binary มีหน้าตาแบบนี้:
+//!
+//! ```text
+//! "RTSK" | version: u8 | flags: u8 | count: varint | task ... | crc32: u32 LE (ถ้า flags & 1)
+//! ```
+//!
+//! - 🔢 ตัวเลขความยาวทั้งหมดเป็น varint (LEB128) - ค่าน้อยใช้แค่ 1 byte
+//! - 🧾 v1: task เรียงติดกัน `id | title | priority | completed | created_at (วินาที)`
+//! - 📦 v2: แต่ละ task มี varint ความยาวนำหน้า และ `created_at` เก็บ nanosecond ด้วย -
+//!   reader จะข้าม byte ท้าย record ที่ไม่รู้จัก ทำให้เวอร์ชันถัดไปเพิ่ม field ท้าย record ได้
+//! - ➕ `due`, `tags`, `recurrence` ถูกต่อท้าย record v2 ภายหลังโดยไม่ต้องขึ้นเวอร์ชัน:
+//!   record ที่จบก่อนถึง field เหล่านี้ (ไฟล์เก่า) อ่านเป็นค่าว่าง
+//! - ✅ CRC-32 ท้ายไฟล์เป็น optional - เปิดไว้เพื่อจับไฟล์เสียก่อนจะ parse ได้ข้อมูลมั่วๆ
+//!
+//! ```
+//! use rust_concepts::{Priority, Task};
+//! use rust_concepts::task_storage::{StorageFormat, decode_tasks, encode_tasks};
+//!
+//! let tasks = vec![Task::new("เขียน docs".to_string(), Priority::High)];
+//! let bytes = encode_tasks(&tasks, StorageFormat::Binary { checksum: true }).unwrap();
+//! assert_eq!(&bytes[..4], b"RTSK");
+//! assert_eq!(decode_tasks(&bytes).unwrap(), tasks);
+//! ```
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::Task;
+use crate::task_model::TaskParseError;
+use crate::unsafe_rust::ffi::crc32_ieee;
+
+/// 4 byte แรกของไฟล์ binary - ใช้แยกจาก JSON ตอนโหลด
+pub const MAGIC: &[u8; 4] = b"RTSK";
+
+/// เวอร์ชันที่ writer เขียนในปัจจุบัน
+pub const CURRENT_VERSION: u8 = 2;
+
+/// flag: มี CRC-32 ต่อท้ายไฟล์
+const FLAG_CHECKSUM: u8 = 0b0000_0001;
+
+/// 🗂️ รูปแบบไฟล์ที่ `TaskManager` ใช้บันทึก
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StorageFormat {
+    /// JSON แบบ pretty (ค่าเริ่มต้น - เปิดดูด้วย text editor ได้)
+    #[default]
+    Json,
+    /// binary ของเราเอง - `checksum` เพิ่ม CRC-32 ท้ายไฟล์
+    Binary { checksum: bool },
+}
+
+impl StorageFormat {
+    /// เดา format จาก byte แรกๆ ของไฟล์ (ไม่ตรวจ flag checksum)
+    #[must_use]
+    pub fn detect(bytes: &[u8]) -> Self {
+        if bytes.starts_with(MAGIC) {
+            Self::Binary {
+                checksum: bytes
+                    .get(MAGIC.len() + 1)
+                    .is_some_and(|flags| flags & FLAG_CHECKSUM != 0),
+            }
+        } else {
+            Self::Json
+        }
+    }
+}
+
+impl fmt::Display for StorageFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Json => f.write_str("json"),
+            Self::Binary { checksum: true } => f.write_str("binary+crc32"),
+            Self::Binary { checksum: false } => f.write_str("binary"),
+        }
+    }
+}
+
+/// ❌ ข้อผิดพลาดตอนเขียนหรืออ่านไฟล์ tasks
+#[derive(Debug)]
+pub enum StorageError {
+    /// JSON เสียหรือไม่ตรงกับ `Task`
+    Json(serde_json::Error),
+    /// ไฟล์ binary ที่ไม่ได้ขึ้นต้นด้วย `MAGIC`
+    BadMagic,
+    /// ไฟล์มาจากโปรแกรมเวอร์ชันใหม่กว่าที่เรารู้จัก
+    UnsupportedVersion(u8),
+    /// ข้อมูลหมดก่อนอ่าน `field` ครบ
+    Truncated { field: &'static str },
+    /// varint ยาวเกิน 64 bit
+    VarintOverflow,
+    /// ข้อความไม่ใช่ UTF-8
+    InvalidUtf8 { field: &'static str },
+    /// ข้อความอ่านได้แต่ไม่ใช่ค่าที่ถูกต้อง เช่น priority ที่ไม่รู้จัก
+    InvalidValue {
+        field: &'static str,
+        message: String,
+    },
+    /// timestamp อยู่นอกช่วงที่ `DateTime<Utc>` แทนได้
+    InvalidTimestamp,
+    /// CRC-32 ไม่ตรง - ไฟล์ถูกแก้หรือเสีย
+    ChecksumMismatch { expected: u32, actual: u32 },
+    /// มี byte เหลือหลัง task สุดท้าย
+    TrailingBytes(usize),
+}
+
+impl fmt::Display for StorageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Json(error) => write!(f, "invalid task JSON: {error}"),
+            Self::BadMagic => f.write_str("not a task file (bad magic bytes)"),
+            Self::UnsupportedVersion(version) => write!(
+                f,
+                "unsupported task file version {version} (newest is {CURRENT_VERSION})"
+            ),
+            Self::Truncated { field } => write!(f, "task file truncated while reading {field}"),
+            Self::VarintOverflow => f.write_str("varint does not fit in 64 bits"),
+            Self::InvalidUtf8 { field } => write!(f, "{field} is not valid UTF-8"),
+            Self::InvalidValue { field, message } => write!(f, "invalid {field}: {message}"),
+            Self::InvalidTimestamp => f.write_str("timestamp is out of range"),
+            Self::ChecksumMismatch { expected, actual } => write!(
+                f,
+                "checksum mismatch (stored {expected:#010x}, computed {actual:#010x})"
+            ),
+            Self::TrailingBytes(count) => write!(f, "{count} unexpected bytes after last task"),
+        }
+    }
+}
+
+impl Error for StorageError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Json(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for StorageError {
+    fn from(error: serde_json::Error) -> Self {
+        Self::Json(error)
+    }
+}
+
+/// แปลง tasks เป็น bytes ตาม `format` (binary จะเป็น `CURRENT_VERSION` เสมอ)
+///
+/// # Errors
+///
+/// คืน `StorageError::Json` ถ้า serialize เป็น JSON ไม่สำเร็จ
+pub fn encode_tasks(tasks: &[Task], format: StorageFormat) -> Result<Vec<u8>, StorageError> {
+    match format {
+        StorageFormat::Json => Ok(serde_json::to_vec_pretty(tasks)?),
+        StorageFormat::Binary { checksum } => Ok(encode_binary(tasks, checksum)),
+    }
+}
+
+/// อ่าน bytes กลับเป็น tasks - ดู `MAGIC` เพื่อเลือกระหว่าง JSON กับ binary ทุกเวอร์ชัน
+///
+/// # Errors
+///
+/// คืน `StorageError` ถ้าไฟล์เสีย, checksum ไม่ตรง หรือเป็นเวอร์ชันที่ใหม่กว่า `CURRENT_VERSION`
+pub fn decode_tasks(bytes: &[u8]) -> Result<Vec<Task>, StorageError> {
+    match StorageFormat::detect(bytes) {
+        StorageFormat::Json => Ok(serde_json::from_slice(bytes)?),
+        StorageFormat::Binary { .. } => decode_binary(bytes),
+    }
+}
+
+fn encode_binary(tasks: &[Task], checksum: bool) -> Vec<u8> {
+    let mut out = Vec::with_capacity(16 + tasks.len() * 64);
+    out.extend_from_slice(MAGIC);
+    out.push(CURRENT_VERSION);
+    out.push(if checksum { FLAG_CHECKSUM } else { 0 });
+    write_varint(&mut out, tasks.len() as u64);
+
+    let mut record = Vec::new();
+    for task in tasks {
+        record.clear();
+        record.extend_from_slice(task.id.as_bytes());
+        write_str(&mut record, &task.title);
+        write_str(&mut record, task.priority.as_str());
+        record.push(u8::from(task.completed));
+        record.extend_from_slice(&task.created_at.timestamp().to_le_bytes());
+        record.extend_from_slice(&task.created_at.timestamp_subsec_nanos().to_le_bytes());
+        match task.due {
+            Some(due) => {
+                record.push(1);
+                record.extend_from_slice(&due.timestamp().to_le_bytes());
+                record.extend_from_slice(&due.timestamp_subsec_nanos().to_le_bytes());
+            }
+            None => record.push(0),
+        }
+        write_varint(&mut record, task.tags.len() as u64);
+        for tag in &task.tags {
+            write_str(&mut record, tag);
+        }
+        match &task.recurrence {
+            Some(recurrence) => {
+                record.push(1);
+                write_str(&mut record, &recurrence.to_string());
+            }
+            None => record.push(0),
+        }
+
+        write_varint(&mut out, record.len() as u64);
+        out.extend_from_slice(&record);
+    }
+
+    if checksum {
+        let crc = crc32_ieee(&out);
+        out.extend_from_slice(&crc.to_le_bytes());
+    }
+    out
+}
+
+fn decode_binary(bytes: &[u8]) -> Result<Vec<Task>, StorageError> {
+    let mut header = Reader::new(bytes);
+    if header.take(MAGIC.len(), "magic")? != MAGIC {
+        return Err(StorageError::BadMagic);
+    }
+    let version = header.u8("version")?;
+    if version == 0 || version > CURRENT_VERSION {
+        return Err(StorageError::UnsupportedVersion(version));
+    }
+    let flags = header.u8("flags")?;
+
+    // ตรวจ checksum ก่อน parse อะไรต่อ - ข้อมูลเสียจะไม่หลุดไปเป็น task แปลกๆ
+    let body_end = if flags & FLAG_CHECKSUM == 0 {
+        bytes.len()
+    } else {
+        let body_end = bytes
+            .len()
+            .checked_sub(4)
+            .filter(|&end| end >= header.pos)
+            .ok_or(StorageError::Truncated { field: "checksum" })?;
+        let mut stored = [0; 4];
+        stored.copy_from_slice(&bytes[body_end..]);
+        let expected = u32::from_le_bytes(stored);
+        let actual = crc32_ieee(&bytes[..body_end]);
+        if expected != actual {
+            return Err(StorageError::ChecksumMismatch { expected, actual });
+        }
+        body_end
+    };
+
+    let mut reader = Reader {
+        bytes: &bytes[..body_end],
+        pos: header.pos,
+    };
+    let count = reader.length("task count")?;
+    // ไม่เชื่อ count จากไฟล์ตรงๆ ตอนจองหน่วยความจำ - task หนึ่งใช้อย่างน้อย 28 bytes
+    let mut tasks = Vec::with_capacity(count.min(reader.remaining() / 28));
+    for _ in 0..count {
+        let task = if version == 1 {
+            read_task_v1(&mut reader)?
+        } else {
+            let length = reader.length("record length")?;
+            // byte ที่เหลือใน record คือ field จากเวอร์ชันอนาคต - ข้ามได้อย่างปลอดภัย
+            read_task_v2(&mut Reader::new(reader.take(length, "record")?))?
+        };
+        tasks.push(task);
+    }
+
+    match reader.remaining() {
+        0 => Ok(tasks),
+        extra => Err(StorageError::TrailingBytes(extra)),
+    }
+}
+
+fn read_task_v1(reader: &mut Reader<'_>) -> Result<Task, StorageError> {
+    let id = reader.uuid()?;
+    let title = reader.string("title")?;
+    let priority = reader.parsed("priority")?;
+    let completed = reader.u8("completed")? != 0;
+    let seconds = reader.i64("created_at")?;
+    Ok(Task {
+        id,
+        title,
+        priority,
+        completed,
+        created_at: timestamp(seconds, 0)?,
+        due: None,
+        tags: Vec::new(),
+        recurrence: None,
+    })
+}
+
+fn read_task_v2(reader: &mut Reader<'_>) -> Result<Task, StorageError> {
+    let id = reader.uuid()?;
+    let title = reader.string("title")?;
+    let priority = reader.parsed("priority")?;
+    let completed = reader.u8("completed")? != 0;
+    let seconds = reader.i64("created_at")?;
+    let nanos = reader.u32("created_at")?;
+
+    // field ที่ต่อท้ายภายหลัง - record เก่าจบก่อนถึงตรงนี้
+    let due = if reader.remaining() == 0 || reader.u8("due")? == 0 {
+        None
+    } else {
+        Some(timestamp(reader.i64("due")?, reader.u32("due")?)?)
+    };
+    let mut tags = Vec::new();
+    if reader.remaining() > 0 {
+        for _ in 0..reader.length("tags")? {
+            tags.push(reader.string("tags")?);
+        }
+    }
+    let recurrence = if reader.remaining() == 0 || reader.u8("recurrence")? == 0 {
+        None
+    } else {
+        Some(reader.parsed("recurrence")?)
+    };
+
+    Ok(Task {
+        id,
+        title,
+        priority,
+        completed,
+        created_at: timestamp(seconds, nanos)?,
+        due,
+        tags,
+        recurrence,
+    })
+}
+
+fn timestamp(seconds: i64, nanos: u32) -> Result<DateTime<Utc>, StorageError> {
+    DateTime::from_timestamp(seconds, nanos).ok_or(StorageError::InvalidTimestamp)
+}
+
+/// เขียน `value` เป็น LEB128: 7 bit ต่อ byte, bit สูงสุดบอกว่ายังมี byte ต่อ
+#[allow(clippy::cast_possible_truncation)] // ตัดเหลือ 7 bit ล่างโดยตั้งใจ
+fn write_varint(out: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        out.push((value as u8 & 0x7F) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+fn write_str(out: &mut Vec<u8>, text: &str) {
+    write_varint(out, text.len() as u64);
+    out.extend_from_slice(text.as_bytes());
+}
+
+/// 📖 cursor อ่าน bytes ทีละ field - ทุกเมธอดคืน `Truncated` แทน panic เมื่อข้อมูลหมด
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    const fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, pos: 0 }
+    }
+
+    const fn remaining(&self) -> usize {
+        self.bytes.len() - self.pos
+    }
+
+    fn take(&mut self, count: usize, field: &'static str) -> Result<&'a [u8], StorageError> {
+        if count > self.remaining() {
+            return Err(StorageError::Truncated { field });
+        }
+        let slice = &self.bytes[self.pos..self.pos + count];
+        self.pos += count;
+        Ok(slice)
+    }
+
+    fn array<const N: usize>(&mut self, field: &'static str) -> Result<[u8; N], StorageError> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.take(N, field)?);
+        Ok(array)
+    }
+
+    fn u8(&mut self, field: &'static str) -> Result<u8, StorageError> {
+        Ok(self.array::<1>(field)?[0])
+    }
+
+    fn u32(&mut self, field: &'static str) -> Result<u32, StorageError> {
+        self.array(field).map(u32::from_le_bytes)
+    }
+
+    fn i64(&mut self, field: &'static str) -> Result<i64, StorageError> {
+        self.array(field).map(i64::from_le_bytes)
+    }
+
+    fn uuid(&mut self) -> Result<Uuid, StorageError> {
+        self.array("id").map(Uuid::from_bytes)
+    }
+
+    fn varint(&mut self, field: &'static str) -> Result<u64, StorageError> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.u8(field)?;
+            let bits = u64::from(byte & 0x7F);
+            if shift == 63 && bits > 1 {
+                return Err(StorageError::VarintOverflow);
+            }
+            value |= bits << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(StorageError::VarintOverflow)
+    }
+
+    /// varint ที่ใช้เป็นความยาว - ค่าที่ใหญ่เกิน `usize` ถือว่าไฟล์ขาด
+    fn length(&mut self, field: &'static str) -> Result<usize, StorageError> {
+        let value = self.varint(field)?;
+        usize::try_from(value).map_err(|_| StorageError::Truncated { field })
+    }
+
+    fn string(&mut self, field: &'static str) -> Result<String, StorageError> {
+        let length = self.length(field)?;
+        let bytes = self.take(length, field)?;
+        String::from_utf8(bytes.to_vec()).map_err(|_| StorageError::InvalidUtf8 { field })
+    }
+
+    /// string ที่ต้องแปลงต่อด้วย `FromStr` เช่น priority หรือ recurrence
+    fn parsed<T>(&mut self, field: &'static str) -> Result<T, StorageError>
+    where
+        T: FromStr<Err = TaskParseError>,
+    {
+        self.string(field)?
+            .parse()
+            .map_err(|error: TaskParseError| StorageError::InvalidValue {
+                field,
+                message: error.to_string(),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Priority;
+
+    fn sample_tasks() -> Vec<Task> {
+        let mut done = Task::new("ส่งงาน".to_string(), Priority::High);
+        done.completed = true;
+        done.due = DateTime::from_timestamp(1_767_225_600, 500);
+        done.tags = vec!["work".to_string(), "ด่วน".to_string()];
+        done.recurrence = Some("0 9 * * 1-5".parse().unwrap());
+        vec![Task::new("Review code".to_string(), Priority::Medium), done]
+    }
+
+    /// writer ของ v1 ที่เลิกใช้แล้ว - เก็บไว้สร้างไฟล์เก่าสำหรับทดสอบ
+    fn encode_v1(tasks: &[Task]) -> Vec<u8> {
+        let mut out = MAGIC.to_vec();
+        out.extend_from_slice(&[1, 0]);
+        write_varint(&mut out, tasks.len() as u64);
+        for task in tasks {
+            out.extend_from_slice(task.id.as_bytes());
+            write_str(&mut out, &task.title);
+            write_str(&mut out, task.priority.as_str());
+            out.push(u8::from(task.completed));
+            out.extend_from_slice(&task.created_at.timestamp().to_le_bytes());
+        }
+        out
+    }
+
+    #[test]
+    fn test_round_trip_in_every_format() {
+        let tasks = sample_tasks();
+        for format in [
+            StorageFormat::Json,
+            StorageFormat::Binary { checksum: false },
+            StorageFormat::Binary { checksum: true },
+        ] {
+            let bytes = encode_tasks(&tasks, format).unwrap();
+            assert_eq!(StorageFormat::detect(&bytes), format);
+            assert_eq!(decode_tasks(&bytes).unwrap(), tasks, "{format}");
+        }
+
+        let json = encode_tasks(&tasks, StorageFormat::Json).unwrap();
+        let binary = encode_tasks(&tasks, StorageFormat::Binary { checksum: true }).unwrap();
+        assert!(binary.len() * 2 < json.len());
+    }
+
+    #[test]
+    fn test_varint_boundaries() {
+        for value in [
+            0,
+            1,
+            127,
+            128,
+            300,
+            16_383,
+            16_384,
+            u64::from(u32::MAX),
+            u64::MAX,
+        ] {
+            let mut out = Vec::new();
+            write_varint(&mut out, value);
+            assert_eq!(Reader::new(&out).varint("test").unwrap(), value);
+        }
+        let mut small = Vec::new();
+        write_varint(&mut small, 127);
+        assert_eq!(small, [0x7F]);
+
+        let too_long = [0xFF; 11];
+        assert!(matches!(
+            Reader::new(&too_long).varint("test"),
+            Err(StorageError::VarintOverflow)
+        ));
+    }
+
+    #[test]
+    fn test_version_one_files_still_load() {
+        let tasks = sample_tasks();
+        let loaded = decode_tasks(&encode_v1(&tasks)).unwrap();
+
+        assert_eq!(loaded.len(), tasks.len());
+        for (old, new) in tasks.iter().zip(&loaded) {
+            assert_eq!(new.id, old.id);
+            assert_eq!(new.title, old.title);
+            assert_eq!(new.completed, old.completed);
+            // v1 เก็บแค่วินาที
+            assert_eq!(new.created_at.timestamp(), old.created_at.timestamp());
+            assert_eq!(new.created_at.timestamp_subsec_nanos(), 0);
+        }
+    }
+
+    #[test]
+    fn test_records_without_optional_fields_load_defaults() {
+        let task = Task::new("ก่อนมี due".to_string(), Priority::Low);
+        let mut bytes = encode_tasks(
+            std::slice::from_ref(&task),
+            StorageFormat::Binary { checksum: false },
+        )
+        .unwrap();
+
+        // ตัด due flag, จำนวน tags และ recurrence flag (3 byte ท้าย) ออก แล้วแก้ความยาว record ให้ตรง
+        // - หน้าตาเหมือนไฟล์ที่เขียนก่อนจะมี field เหล่านี้
+        let header_len = MAGIC.len() + 2 + 1;
+        bytes.truncate(bytes.len() - 3);
+        bytes[header_len] -= 3;
+        assert_eq!(decode_tasks(&bytes).unwrap(), vec![task]);
+    }
+
+    #[test]
+    fn test_unknown_trailing_fields_are_skipped() {
+        let task = Task::new("อนาคต".to_string(), Priority::Low);
+        let current = encode_tasks(
+            std::slice::from_ref(&task),
+            StorageFormat::Binary { checksum: false },
+        )
+        .unwrap();
+
+        // จำลอง writer เวอร์ชันถัดไปที่ต่อ field ใหม่ 3 byte ท้าย record
+        let header_len = MAGIC.len() + 2 + 1;
+        let mut record = Reader::new(&current[header_len..]);
+        let length = record.length("record length").unwrap();
+        let mut future = current[..header_len].to_vec();
+        write_varint(&mut future, length as u64 + 3);
+        future.extend_from_slice(record.take(length, "record").unwrap());
+        future.extend_from_slice(&[0xAA, 0xBB, 0xCC]);
+
+        assert_eq!(decode_tasks(&future).unwrap(), vec![task]);
+    }
+
+    #[test]
+    fn test_corruption_is_reported() {
+        let mut bytes =
+            encode_tasks(&sample_tasks(), StorageFormat::Binary { checksum: true }).unwrap();
+        bytes[12] ^= 0x01;
+        assert!(matches!(
+            decode_tasks(&bytes),
+            Err(StorageError::ChecksumMismatch { .. })
+        ));
+
+        let plain =
+            encode_tasks(&sample_tasks(), StorageFormat::Binary { checksum: false }).unwrap();
+        assert!(matches!(
+            decode_tasks(&plain[..plain.len() - 5]),
+            Err(StorageError::Truncated { .. })
+        ));
+
+        let mut newer = plain;
+        newer[4] = CURRENT_VERSION + 1;
+        let error = decode_tasks(&newer).unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("unsupported task file version 3")
+        );
+
+        assert!(matches!(
+            decode_tasks(b"not json"),
+            Err(StorageError::Json(_))
+        ));
+    }
+}
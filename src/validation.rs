@@ -0,0 +1,585 @@
+//! ✅ Validation - ตรวจข้อมูลด้วยกฎที่ประกาศไว้ แล้วรวบ error ทุกฟิลด์ไว้ในก้อนเดียว
+//!
+//! `?` หยุดที่ error แรก แต่ฟอร์มหรือ request body ควรบอกผู้ใช้ทุกช่องที่ผิดในครั้งเดียว
+//! - `Rule<T>` - กฎหนึ่งข้อ: `length`, `required`, `range`, `pattern` (`RegexLite` ทั้งข้อความ), `custom` (closure)
+//! - `ValidationErrors` - สะสม error พร้อม path ของฟิลด์ เช่น `address.zip` หรือ `items[2].qty`
+//! - `validate!` - ประกาศกฎบน struct แล้วได้ `impl Validate` โดยไม่ต้องเขียนเอง
+//!
+//! ```
+//! use rust_concepts::validation::{Validate, validate};
+//!
+//! struct Signup {
+//!     name: String,
+//!     age: u32,
+//! }
+//!
+//! validate! {
+//!     Signup {
+//!         name: [required(), length(1, 20)],
+//!         age: [range(13, 120)],
+//!     }
+//! }
+//!
+//! let errors = Signup { name: " ".into(), age: 7 }.validate().unwrap_err();
+//! let fields: Vec<_> = errors.iter().map(|error| error.field.as_str()).collect();
+//! assert_eq!(fields, ["name", "age"]);
+//! ```
+
+use std::fmt::{self, Display};
+
+use serde::Serialize;
+
+use crate::regex_lite::{RegexError, RegexLite};
+
+/// ประกาศกฎบน struct: `validate! { Type { field: [rule, ...], child: nested, ... } }`
+///
+/// - `[rule, ...]` - กฎจาก `validation::rules` เรียกด้วยชื่อสั้นได้เลย (หรือ expression ใดๆ ที่เป็น `Rule`)
+/// - `nested` - ฟิลด์ที่ `impl Validate` เอง (struct ลูก, `Vec<T>`, `Option<T>`) error ของลูกจะได้ path นำหน้า
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __validation_validate {
+    ($ty:ty { $($field:ident : $spec:tt),* $(,)? }) => {
+        impl $crate::validation::Validate for $ty {
+            fn validate(&self) -> ::core::result::Result<(), $crate::validation::ValidationErrors> {
+                #[allow(unused_imports)] // reason: ไม่ใช่ทุก struct ที่ใช้กฎจาก rules
+                use $crate::validation::rules::*;
+                let mut errors = $crate::validation::ValidationErrors::new();
+                $($crate::__validation_validate!(@field errors, self, $field, $spec);)*
+                errors.into_result()
+            }
+        }
+    };
+    (@field $errors:ident, $this:ident, $field:ident, nested) => {
+        $errors.nest(stringify!($field), $crate::validation::Validate::validate(&$this.$field));
+    };
+    (@field $errors:ident, $this:ident, $field:ident, [$($rule:expr),* $(,)?]) => {
+        $($errors.check(stringify!($field), &$this.$field, &$rule);)*
+    };
+}
+
+pub use crate::__validation_validate as validate;
+
+/// ชนิดที่ตรวจตัวเองได้
+pub trait Validate {
+    /// # Errors
+    ///
+    /// คืน `ValidationErrors` ที่มี error ทุกฟิลด์ที่ผิด (ไม่หยุดที่ตัวแรก)
+    fn validate(&self) -> Result<(), ValidationErrors>;
+}
+
+impl<T: Validate> Validate for [T] {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        for (index, item) in self.iter().enumerate() {
+            errors.nest(&format!("[{index}]"), item.validate());
+        }
+        errors.into_result()
+    }
+}
+
+impl<T: Validate> Validate for Vec<T> {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        self.as_slice().validate()
+    }
+}
+
+impl<T: Validate> Validate for Option<T> {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        self.as_ref().map_or(Ok(()), Validate::validate)
+    }
+}
+
+/// ผลของกฎที่ไม่ผ่าน - ยังไม่รู้ว่าเป็นของฟิลด์ไหน
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    /// รหัสสั้นๆ ให้โปรแกรมฝั่ง client ใช้ตัดสินใจ เช่น `"length"`
+    pub code: &'static str,
+    /// ข้อความสำหรับคนอ่าน
+    pub message: String,
+}
+
+impl Violation {
+    #[must_use]
+    pub fn new(code: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+/// กฎหนึ่งข้อสำหรับค่าชนิด `T`
+pub trait Rule<T: ?Sized> {
+    /// # Errors
+    ///
+    /// คืน `Violation` เมื่อค่าไม่ผ่านกฎ
+    fn check(&self, value: &T) -> Result<(), Violation>;
+}
+
+/// error ของฟิลด์หนึ่ง
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ValidationError {
+    /// path ของฟิลด์ เช่น `email`, `address.zip`, `items[0].name`
+    pub field: String,
+    pub code: &'static str,
+    pub message: String,
+}
+
+/// 📋 error ทั้งหมดจากการตรวจหนึ่งครั้ง เรียงตามลำดับที่ตรวจ
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(transparent)]
+pub struct ValidationErrors {
+    errors: Vec<ValidationError>,
+}
+
+impl ValidationErrors {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { errors: Vec::new() }
+    }
+
+    /// ตรวจ `value` ด้วย `rule` ถ้าไม่ผ่านจดไว้ใต้ชื่อ `field`
+    pub fn check<T: ?Sized>(&mut self, field: &str, value: &T, rule: &impl Rule<T>) {
+        if let Err(violation) = rule.check(value) {
+            self.add(field, violation);
+        }
+    }
+
+    pub fn add(&mut self, field: &str, violation: Violation) {
+        self.errors.push(ValidationError {
+            field: field.to_string(),
+            code: violation.code,
+            message: violation.message,
+        });
+    }
+
+    /// รับ error ของค่าลูกมา โดยเติม `prefix` หน้า path (`address` + `zip` → `address.zip`)
+    pub fn nest(&mut self, prefix: &str, result: Result<(), Self>) {
+        let Err(child) = result else { return };
+        for mut error in child.errors {
+            error.field = if error.field.starts_with('[') {
+                format!("{prefix}{}", error.field)
+            } else {
+                format!("{prefix}.{}", error.field)
+            };
+            self.errors.push(error);
+        }
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, ValidationError> {
+        self.errors.iter()
+    }
+
+    /// error ของฟิลด์ที่ path ตรงกันพอดี
+    pub fn field<'a>(&'a self, path: &'a str) -> impl Iterator<Item = &'a ValidationError> {
+        self.errors.iter().filter(move |error| error.field == path)
+    }
+
+    /// `Ok(())` ถ้าไม่มี error เลย
+    ///
+    /// # Errors
+    ///
+    /// คืนตัวเองเมื่อมี error อย่างน้อยหนึ่งตัว
+    pub fn into_result(self) -> Result<(), Self> {
+        if self.is_empty() { Ok(()) } else { Err(self) }
+    }
+}
+
+impl<'a> IntoIterator for &'a ValidationErrors {
+    type Item = &'a ValidationError;
+    type IntoIter = std::slice::Iter<'a, ValidationError>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl Display for ValidationErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, error) in self.errors.iter().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}: {} ({})", error.field, error.message, error.code)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ValidationErrors {}
+
+/// 📏 กฎสำเร็จรูป - `validate!` นำเข้าให้อัตโนมัติ
+pub mod rules {
+    use super::{Pattern, Rule, Violation};
+    use std::fmt::Display;
+
+    /// ความยาวเป็นจำนวนตัวอักษร (`char`) ไม่ใช่ byte - ภาษาไทยหนึ่งตัวกิน 3 byte
+    #[derive(Debug, Clone, Copy)]
+    pub struct Length {
+        pub min: usize,
+        pub max: usize,
+    }
+
+    /// ความยาวอยู่ใน `min..=max` ตัวอักษร
+    #[must_use]
+    pub const fn length(min: usize, max: usize) -> Length {
+        Length { min, max }
+    }
+
+    impl<T: AsRef<str> + ?Sized> Rule<T> for Length {
+        fn check(&self, value: &T) -> Result<(), Violation> {
+            let count = value.as_ref().chars().count();
+            if (self.min..=self.max).contains(&count) {
+                Ok(())
+            } else {
+                Err(Violation::new(
+                    "length",
+                    format!(
+                        "must be {}-{} characters long (got {count})",
+                        self.min, self.max
+                    ),
+                ))
+            }
+        }
+    }
+
+    /// ต้องมีตัวอักษรที่ไม่ใช่ช่องว่าง
+    #[derive(Debug, Clone, Copy)]
+    pub struct Required;
+
+    #[must_use]
+    pub const fn required() -> Required {
+        Required
+    }
+
+    impl<T: AsRef<str> + ?Sized> Rule<T> for Required {
+        fn check(&self, value: &T) -> Result<(), Violation> {
+            if value.as_ref().trim().is_empty() {
+                Err(Violation::new("required", "must not be blank"))
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    /// ค่าอยู่ใน `min..=max`
+    #[derive(Debug, Clone, Copy)]
+    pub struct Range<N> {
+        pub min: N,
+        pub max: N,
+    }
+
+    #[must_use]
+    pub const fn range<N>(min: N, max: N) -> Range<N> {
+        Range { min, max }
+    }
+
+    impl<N: PartialOrd + Display> Rule<N> for Range<N> {
+        fn check(&self, value: &N) -> Result<(), Violation> {
+            if *value >= self.min && *value <= self.max {
+                Ok(())
+            } else {
+                Err(Violation::new(
+                    "range",
+                    format!(
+                        "must be between {} and {} (got {value})",
+                        self.min, self.max
+                    ),
+                ))
+            }
+        }
+    }
+
+    /// ทั้งข้อความต้องตรงกับ pattern
+    ///
+    /// # Panics
+    ///
+    /// panic เมื่อ pattern เขียนผิด - pattern ในโค้ดเป็นค่าคงที่ จึงถือเป็น bug ของโปรแกรม
+    /// (ถ้า pattern มาจากผู้ใช้ให้ใช้ `Pattern::new` ที่คืน `Result`)
+    #[must_use]
+    pub fn pattern(source: &str) -> Pattern {
+        match Pattern::new(source) {
+            Ok(pattern) => pattern,
+            Err(error) => panic!("invalid pattern {source:?}: {error}"),
+        }
+    }
+
+    impl<T: AsRef<str> + ?Sized> Rule<T> for Pattern {
+        fn check(&self, value: &T) -> Result<(), Violation> {
+            if self.is_match(value.as_ref()) {
+                Ok(())
+            } else {
+                Err(Violation::new(
+                    "pattern",
+                    format!("must match pattern {}", self.source()),
+                ))
+            }
+        }
+    }
+
+    /// กฎจาก closure - `custom("even", "must be even", |n: &u32| n.is_multiple_of(2))`
+    pub struct Custom<F> {
+        code: &'static str,
+        message: &'static str,
+        predicate: F,
+    }
+
+    pub const fn custom<F>(code: &'static str, message: &'static str, predicate: F) -> Custom<F> {
+        Custom {
+            code,
+            message,
+            predicate,
+        }
+    }
+
+    impl<T: ?Sized, F: Fn(&T) -> bool> Rule<T> for Custom<F> {
+        fn check(&self, value: &T) -> Result<(), Violation> {
+            if (self.predicate)(value) {
+                Ok(())
+            } else {
+                Err(Violation::new(self.code, self.message))
+            }
+        }
+    }
+}
+
+/// 🔤 pattern สำหรับตรวจรูปแบบข้อมูล - ใช้ `RegexLite` ไม่ต้องพึ่ง crate `regex`
+///
+/// รองรับ: ตัวอักษรธรรมดา, `.`, `[a-z0-9_]`, `[^...]`, `\d` `\w` `\s` (ASCII),
+/// `\.` และ escape อื่น, กลุ่ม `( )` กับ `|`, ตัวนับ `*` `+` `?` `{n}` `{n,}` `{n,m}`
+/// และต้องตรง "ทั้งข้อความ" เสมอ (เหมือนมี `^...$` ครอบ)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pattern {
+    regex: RegexLite,
+}
+
+/// pattern เขียนผิด
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PatternError {
+    /// `[` ไม่มี `]` ปิด
+    UnclosedClass,
+    /// `\` อยู่ท้ายสุด
+    TrailingEscape,
+    /// `*`, `+`, `?` หรือ `{..}` ไม่มีอะไรให้นับ
+    NothingToRepeat(usize),
+    /// `{n,m}` เขียนผิด หรือ n > m
+    InvalidRepetition(usize),
+    /// `(` ไม่มี `)` ปิด หรือ `)` ไม่มี `(` เปิด
+    UnbalancedGroup(usize),
+    /// กลุ่ม `(...)` ซ้อนลึกเกินไป
+    TooDeep(usize),
+}
+
+impl From<RegexError> for PatternError {
+    fn from(error: RegexError) -> Self {
+        match error {
+            RegexError::UnclosedClass(_) => Self::UnclosedClass,
+            RegexError::TrailingEscape(_) => Self::TrailingEscape,
+            RegexError::NothingToRepeat(at) => Self::NothingToRepeat(at),
+            RegexError::InvalidRepetition(at) => Self::InvalidRepetition(at),
+            RegexError::UnclosedGroup(at) | RegexError::UnmatchedParen(at) => {
+                Self::UnbalancedGroup(at)
+            }
+            RegexError::TooDeep(at) => Self::TooDeep(at),
+        }
+    }
+}
+
+impl Display for PatternError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnclosedClass => write!(f, "unclosed character class"),
+            Self::TrailingEscape => write!(f, "trailing backslash"),
+            Self::NothingToRepeat(at) => write!(f, "nothing to repeat at {at}"),
+            Self::InvalidRepetition(at) => write!(f, "invalid repetition at {at}"),
+            Self::UnbalancedGroup(at) => write!(f, "unbalanced parenthesis at {at}"),
+            Self::TooDeep(at) => write!(f, "groups nested too deeply at {at}"),
+        }
+    }
+}
+
+impl std::error::Error for PatternError {}
+
+impl Pattern {
+    /// compile pattern
+    ///
+    /// # Errors
+    ///
+    /// คืน `PatternError` เมื่อ pattern เขียนผิด
+    pub fn new(source: &str) -> Result<Self, PatternError> {
+        Ok(Self {
+            regex: RegexLite::new(source)?,
+        })
+    }
+
+    #[must_use]
+    pub fn source(&self) -> &str {
+        self.regex.as_str()
+    }
+
+    /// ทั้งข้อความตรงกับ pattern ไหม
+    #[must_use]
+    pub fn is_match(&self, text: &str) -> bool {
+        self.regex.is_full_match(text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pattern_matching() {
+        let email = Pattern::new(r"[\w.+-]+@[\w-]+\.[a-z]{2,}").unwrap();
+        assert!(email.is_match("john.doe+rust@workshop.dev"));
+        assert!(!email.is_match("john@workshop"));
+        assert!(!email.is_match("john@workshop.d"));
+        assert!(!email.is_match("@workshop.dev"));
+
+        let code = Pattern::new(r"TH-\d{5}").unwrap();
+        assert!(code.is_match("TH-10110"));
+        assert!(!code.is_match("TH-1011"));
+        assert!(!code.is_match("xTH-10110"));
+
+        // ต้องคืนตัวอักษรให้ node ถัดไป (a* กินหมดแล้วต้องถอยให้ a ตัวสุดท้าย)
+        assert!(Pattern::new("a*ab?").unwrap().is_match("aaaa"));
+        assert!(Pattern::new("[^ ]+").unwrap().is_match("สวัสดี"));
+        assert!(Pattern::new(".?").unwrap().is_match(""));
+
+        assert_eq!(Pattern::new("[a-z"), Err(PatternError::UnclosedClass));
+        assert_eq!(Pattern::new("+a"), Err(PatternError::NothingToRepeat(0)));
+        assert_eq!(Pattern::new("a**"), Err(PatternError::NothingToRepeat(2)));
+        assert_eq!(
+            Pattern::new("a{3,1}"),
+            Err(PatternError::InvalidRepetition(1))
+        );
+        assert_eq!(Pattern::new(r"a\"), Err(PatternError::TrailingEscape));
+
+        // กลุ่มกับ `|` มาจาก RegexLite
+        let plate = Pattern::new(r"(กท|ชม)-\d{4}|\d[A-Z]{2}\d{3}").unwrap();
+        assert!(plate.is_match("กท-1234"));
+        assert!(plate.is_match("1AB234"));
+        assert!(!plate.is_match("ขก-1234"));
+        assert_eq!(Pattern::new("(ab"), Err(PatternError::UnbalancedGroup(0)));
+    }
+
+    #[derive(Default)]
+    struct Address {
+        city: String,
+        zip: String,
+    }
+
+    struct Order {
+        customer: String,
+        quantity: u32,
+        coupon: String,
+        address: Address,
+        gifts: Vec<Address>,
+        billing: Option<Address>,
+    }
+
+    validate! {
+        Address {
+            city: [required()],
+            zip: [pattern(r"\d{5}")],
+        }
+    }
+
+    validate! {
+        Order {
+            customer: [required(), length(2, 10)],
+            quantity: [range(1, 99), custom("even", "must be even", |n: &u32| n.is_multiple_of(2))],
+            coupon: [length(0, 8)],
+            address: nested,
+            gifts: nested,
+            billing: nested,
+        }
+    }
+
+    #[test]
+    fn test_errors_accumulate_with_field_paths() {
+        let good = Address {
+            city: "Bangkok".into(),
+            zip: "10110".into(),
+        };
+        let order = Order {
+            customer: " ".into(),
+            quantity: 101,
+            coupon: "สวัสดีครับ".into(),
+            address: Address::default(),
+            gifts: vec![
+                Address {
+                    city: "Chiang Mai".into(),
+                    zip: "50200".into(),
+                },
+                Address {
+                    city: "Phuket".into(),
+                    zip: "831".into(),
+                },
+            ],
+            billing: Some(good),
+        };
+        let errors = order.validate().unwrap_err();
+        let summary: Vec<_> = errors
+            .iter()
+            .map(|error| (error.field.as_str(), error.code))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("customer", "required"),
+                ("customer", "length"),
+                ("quantity", "range"),
+                ("quantity", "even"),
+                ("coupon", "length"),
+                ("address.city", "required"),
+                ("address.zip", "pattern"),
+                ("gifts[1].zip", "pattern"),
+            ]
+        );
+        assert_eq!(errors.field("gifts[1].zip").count(), 1);
+        assert_eq!(
+            errors.field("coupon").next().unwrap().message,
+            "must be 0-8 characters long (got 10)"
+        );
+
+        let fixed = Order {
+            customer: "Somchai".into(),
+            quantity: 2,
+            coupon: String::new(),
+            address: Address {
+                city: "Bangkok".into(),
+                zip: "10110".into(),
+            },
+            gifts: Vec::new(),
+            billing: None,
+        };
+        assert_eq!(fixed.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_errors_serialize_and_display() {
+        let mut errors = ValidationErrors::new();
+        errors.check("age", &0_u32, &rules::range(1, 150));
+        errors.check("name", "ok", &rules::required());
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            serde_json::to_string(&errors).unwrap(),
+            r#"[{"field":"age","code":"range","message":"must be between 1 and 150 (got 0)"}]"#
+        );
+        assert_eq!(
+            errors.to_string(),
+            "age: must be between 1 and 150 (got 0) (range)"
+        );
+    }
+}
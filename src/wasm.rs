@@ -0,0 +1,184 @@
+//! 🌐 WASM API - เรียกตัวอย่างบางบทจาก JavaScript ใน browser ได้โดยตรง
+//!
+//! ฟังก์ชันในไฟล์นี้ถูก export ผ่าน `wasm-bindgen` เฉพาะตอน build เป็น `wasm32`
+//! (`#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]`) - บน native เป็นฟังก์ชัน Rust ธรรมดา
+//! จึงเขียน tests ทดสอบได้ตามปกติ 🧪
+//!
+//! - 📝 `renderTemplate` - template engine จากบทที่ 17
+//! - 📈 `predictLinear` - linear regression จากบทที่ 22
+//! - ⛓️ `validateBlockchain` - สร้างและตรวจบล็อกเชนจากบทที่ 23
+//!
+//! ข้อมูลที่มีโครงสร้างส่งเข้ามาเป็น JSON string และ error กลับไปเป็น `String`
+//! (ฝั่ง JavaScript จะได้เป็น exception ที่ `catch` ได้)
+//!
+//! 🛠️ build ด้วย `wasm-pack` (feature `wasm` เปิดเป็นค่าเริ่มต้น):
+//!
+//! ```text
+//! rustup target add wasm32-unknown-unknown
+//! cargo install wasm-pack
+//! wasm-pack build --target web        # ได้ pkg/rust_concepts.js + .wasm
+//! ```
+//!
+//! ```text
+//! import init, { renderTemplate, predictLinear } from "./pkg/rust_concepts.js";
+//! await init();
+//! renderTemplate("สวัสดี {{name}}!", JSON.stringify({ name: "Rust" }));
+//! predictLinear(new Float64Array([1, 2, 3]), new Float64Array([3, 5, 7]), 10);
+//! ```
+//!
+//! ของที่มีแค่บน native ถูก gate ด้วย `#[cfg(not(target_arch = "wasm32"))]`: tokio แบบ `full`
+//! (บน wasm32 เหลือ `rt`/`sync`/`time`), `DirBackend` ที่ใช้ `tokio::fs` และไลบรารี C ที่ `build.rs`
+//! คอมไพล์ให้บทที่ 14 - `make wasm` (`cargo check --lib --target wasm32-unknown-unknown`) ตรวจว่ายังผ่าน
+//! ส่วนโค้ดที่ไฟล์นี้เรียกใช้ไม่แตะ `SystemTime::now()`/`Instant::now()` (ซึ่ง panic บน wasm32)
+
+use serde::Deserialize;
+use serde_json::Value;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::blockchain::validate_transfer_chain;
+use crate::machine_learning::fit_and_predict_linear;
+use crate::web_development::templating::{TemplateContext, TemplateEngine};
+
+/// จำนวนรอบที่ใช้เทรน linear regression ใน `predict_linear`
+pub const LINEAR_EPOCHS: usize = 1000;
+
+/// render `template` ด้วยตัวแปรจาก `context_json` (JSON object) 📝
+///
+/// string/number/bool กลายเป็นตัวแปรธรรมดา ส่วน array ใช้กับ `{{#each}}` ได้
+///
+/// # Errors
+///
+/// คืน error ถ้า JSON ไม่ใช่ object, มีค่าแบบ object ซ้อนอยู่ หรือ template render ไม่ผ่าน
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = renderTemplate))]
+pub fn render_template(template: &str, context_json: &str) -> Result<String, String> {
+    let json: Value =
+        serde_json::from_str(context_json).map_err(|e| format!("invalid context JSON: {e}"))?;
+    let Value::Object(fields) = json else {
+        return Err("context must be a JSON object".to_string());
+    };
+
+    let mut context = TemplateContext::new();
+    for (key, value) in &fields {
+        match value {
+            Value::Array(items) => {
+                let items = items.iter().map(json_to_text).collect::<Result<_, _>>()?;
+                context.set_array(key, items);
+            }
+            Value::Null => {}
+            other => context.set(key, &json_to_text(other)?),
+        }
+    }
+
+    TemplateEngine::new().render_string(template, &context)
+}
+
+fn json_to_text(value: &Value) -> Result<String, String> {
+    match value {
+        Value::String(text) => Ok(text.clone()),
+        Value::Number(_) | Value::Bool(_) => Ok(value.to_string()),
+        Value::Null => Ok(String::new()),
+        Value::Array(_) | Value::Object(_) => {
+            Err(format!("nested value is not supported: {value}"))
+        }
+    }
+}
+
+/// เทรน linear regression บนจุด `(xs[i], ys[i])` แล้วทำนาย y ที่ `x` 📈
+///
+/// # Errors
+///
+/// คืน error ถ้า `xs` กับ `ys` ยาวไม่เท่ากัน, ไม่มีข้อมูล หรือโมเดลลู่ออก (ข้อมูลสเกลใหญ่เกิน learning rate)
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = predictLinear))]
+pub fn predict_linear(xs: &[f64], ys: &[f64], x: f64) -> Result<f64, String> {
+    if xs.len() != ys.len() {
+        return Err(format!(
+            "xs and ys must have the same length ({} != {})",
+            xs.len(),
+            ys.len()
+        ));
+    }
+    if xs.is_empty() {
+        return Err("need at least one training point".to_string());
+    }
+
+    let points: Vec<(f64, f64)> = xs.iter().copied().zip(ys.iter().copied()).collect();
+    let prediction = fit_and_predict_linear(&points, LINEAR_EPOCHS, x);
+    if prediction.is_finite() {
+        Ok(prediction)
+    } else {
+        Err("model diverged - try scaling the data down".to_string())
+    }
+}
+
+/// รายการโอน 1 รายการใน `transfers_json`
+#[derive(Debug, Deserialize)]
+struct Transfer {
+    from: String,
+    to: String,
+    amount: f64,
+}
+
+/// สร้างบล็อกเชนจากรายการโอนแล้วตรวจว่าทั้งเชนถูกต้องหรือไม่ ⛓️
+///
+/// `transfers_json` เป็น array ของ `{ "from": ..., "to": ..., "amount": ... }`
+/// โดย `miner` ได้รางวัลจากการขุดเป็นทุนตั้งต้น ถ้า `tamper` เป็น `true`
+/// จะแก้ข้อมูลในบล็อกที่ขุดแล้วก่อนตรวจ เพื่อให้เห็นว่าเชนจับได้
+///
+/// # Errors
+///
+/// คืน error ถ้า `transfers_json` ไม่ตรงรูปแบบ
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = validateBlockchain))]
+pub fn validate_blockchain(
+    miner: &str,
+    transfers_json: &str,
+    tamper: bool,
+) -> Result<bool, String> {
+    let transfers: Vec<Transfer> =
+        serde_json::from_str(transfers_json).map_err(|e| format!("invalid transfers JSON: {e}"))?;
+    let transfers: Vec<(String, String, f64)> = transfers
+        .into_iter()
+        .map(|transfer| (transfer.from, transfer.to, transfer.amount))
+        .collect();
+
+    Ok(validate_transfer_chain(miner, &transfers, tamper))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_template_from_json_context() {
+        let rendered = render_template(
+            "{{name}} ({{age}}): {{#each langs}}[{{this}}]{{/each}}",
+            r#"{"name": "Ferris", "age": 8, "langs": ["rust", "wasm"], "note": null}"#,
+        );
+        assert_eq!(rendered.as_deref(), Ok("Ferris (8): [rust][wasm]"));
+
+        assert!(render_template("{{x}}", "[1, 2]").is_err());
+        assert!(render_template("{{x}}", r#"{"x": {"nested": 1}}"#).is_err());
+        assert!(render_template("{{x}}", "not json").is_err());
+    }
+
+    #[test]
+    fn test_predict_linear_learns_line() {
+        let prediction = predict_linear(&[1.0, 2.0, 3.0, 4.0], &[3.0, 5.0, 7.0, 9.0], 6.0);
+        let prediction = prediction.expect("training data is valid");
+        assert!((prediction - 13.0).abs() < 0.5, "predicted {prediction}");
+
+        assert!(predict_linear(&[1.0], &[1.0, 2.0], 0.0).is_err());
+        assert!(predict_linear(&[], &[], 0.0).is_err());
+    }
+
+    #[test]
+    fn test_validate_blockchain_detects_tampering() {
+        let transfers = r#"[
+            {"from": "miner", "to": "alice", "amount": 30},
+            {"from": "alice", "to": "bob", "amount": 10}
+        ]"#;
+        assert_eq!(validate_blockchain("miner", transfers, false), Ok(true));
+        assert_eq!(validate_blockchain("miner", transfers, true), Ok(false));
+        assert!(validate_blockchain("miner", r#"[{"from": "a"}]"#, false).is_err());
+    }
+}
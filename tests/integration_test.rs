@@ -15,8 +15,8 @@ fn test_task_manager_integration() {
     let mut manager = TaskManager::new(file_path.clone());
 
     // เพิ่ม tasks
-    let task1_id = manager.add_task("Complete project".to_string(), "High".to_string());
-    let task2_id = manager.add_task("Review code".to_string(), "Medium".to_string());
+    let task1_id = manager.add_task("Complete project".to_string(), Priority::High);
+    let task2_id = manager.add_task("Review code".to_string(), Priority::Medium);
 
     // ตรวจสอบว่ามี tasks ทั้งหมด 2 รายการ
     assert_eq!(manager.list_tasks().len(), 2);
@@ -47,6 +47,35 @@ fn test_task_manager_integration() {
     assert_eq!(new_manager.list_tasks().len(), 1);
 }
 
+/// ทดสอบว่าไฟล์ binary โหลดกลับได้ และ manager ที่ตั้งเป็น JSON ก็อ่านไฟล์ binary ได้
+#[test]
+fn test_task_manager_binary_storage() {
+    use rust_concepts::task_storage::StorageFormat;
+
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("tasks.bin");
+
+    let format = StorageFormat::Binary { checksum: true };
+    let mut manager = TaskManager::new(file_path.clone()).with_format(format);
+    let task_id = manager.add_task("Ship binary format".to_string(), Priority::High);
+    manager.complete_task(&task_id).unwrap();
+    manager.save_to_file().unwrap();
+
+    let bytes = std::fs::read(&file_path).unwrap();
+    assert_eq!(StorageFormat::detect(&bytes), format);
+
+    let mut loaded = TaskManager::new(file_path.clone());
+    loaded.load_from_file().unwrap();
+    assert_eq!(loaded.list_tasks(), manager.list_tasks());
+
+    // ไฟล์เสีย 1 byte ต้องโหลดไม่ผ่าน ไม่ใช่ได้ task แปลกๆ กลับมา
+    let mut corrupted = bytes;
+    corrupted[10] ^= 0xFF;
+    std::fs::write(&file_path, corrupted).unwrap();
+    let error = TaskManager::new(file_path).load_from_file().unwrap_err();
+    assert!(format!("{error:#}").contains("checksum mismatch"));
+}
+
 /// ทดสอบการจัดการ error ในสถานการณ์ต่างๆ
 #[test]
 fn test_error_handling_integration() {
@@ -143,9 +172,12 @@ fn test_memory_management() {
     let task = Task {
         id: uuid::Uuid::new_v4(),
         title: "Test task".to_string(),
-        priority: "High".to_string(),
+        priority: Priority::High,
         completed: false,
         created_at: chrono::Utc::now(),
+        due: None,
+        tags: Vec::new(),
+        recurrence: None,
     };
 
     // ตรวจสอบขนาดของ struct
@@ -171,9 +203,12 @@ fn test_serialization_integration() {
     let original_task = Task {
         id: uuid::Uuid::new_v4(),
         title: "Test serialization".to_string(),
-        priority: "Medium".to_string(),
+        priority: Priority::Medium,
         completed: true,
         created_at: chrono::Utc::now(),
+        due: None,
+        tags: Vec::new(),
+        recurrence: None,
     };
 
     // Serialize to JSON
@@ -194,6 +229,79 @@ fn test_serialization_integration() {
     println!("Pretty JSON:\n{pretty_json}");
 }
 
+/// ทดสอบว่าไฟล์ JSON รูปแบบเก่า (priority เป็น string, ไม่มี due/tags/recurrence) ยังโหลดได้
+#[test]
+fn test_old_task_json_is_migrated() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("tasks.json");
+    let old_json = r#"[
+        {
+            "id": "6f1c2a9e-1b7d-4c1e-9a53-2d5f4e8b7c10",
+            "title": "Old task",
+            "priority": "high",
+            "completed": false,
+            "created_at": "2024-05-01T10:00:00Z"
+        }
+    ]"#;
+    std::fs::write(&file_path, old_json).unwrap();
+
+    let mut manager = TaskManager::new(file_path.clone());
+    manager.load_from_file().unwrap();
+    let task = &manager.list_tasks()[0];
+    assert_eq!(task.priority, Priority::High);
+    assert_eq!(task.due, None);
+    assert!(task.tags.is_empty());
+    assert_eq!(task.recurrence, None);
+
+    // บันทึกกลับแล้ว priority ยังเป็น string แบบเดิม แค่ตัวพิมพ์ถูกทำให้เป็นมาตรฐาน
+    manager.save_to_file().unwrap();
+    let saved: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&file_path).unwrap()).unwrap();
+    assert_eq!(saved[0]["priority"], "High");
+
+    std::fs::write(&file_path, old_json.replace("high", "urgent")).unwrap();
+    let error = TaskManager::new(file_path).load_from_file().unwrap_err();
+    assert!(format!("{error:#}").contains("unknown priority `urgent`"));
+}
+
+/// ทดสอบว่า task ที่ทำซ้ำและเสร็จแล้วถูกสร้างรอบถัดไปเพียงครั้งเดียว
+#[test]
+fn test_roll_over_recurring_tasks() {
+    let mut manager = TaskManager::new(PathBuf::from("unused.json"));
+    let daily = manager.add_task("Water plants".to_string(), Priority::Low);
+    let once = manager.add_task("One-off".to_string(), Priority::Medium);
+    let now = "2025-03-10T12:00:00Z".parse::<DateTime<Utc>>().unwrap();
+    manager
+        .update_task(&daily, |task| {
+            task.due = Some("2025-03-08T07:00:00Z".parse().unwrap());
+            task.tags = vec!["home".to_string()];
+            task.recurrence = Some(Recurrence::Daily);
+        })
+        .unwrap();
+
+    // ยังไม่เสร็จ = ยังไม่ roll
+    assert!(manager.roll_over_at(now).is_empty());
+
+    manager.complete_task(&daily).unwrap();
+    manager.complete_task(&once).unwrap();
+    let created = manager.roll_over_at(now);
+    assert_eq!(created.len(), 1);
+
+    let next = manager
+        .list_tasks()
+        .iter()
+        .find(|t| t.id == created[0])
+        .unwrap();
+    assert_eq!(next.title, "Water plants");
+    assert_eq!(next.tags, ["home"]);
+    assert_eq!(next.due, Some("2025-03-11T07:00:00Z".parse().unwrap()));
+    assert!(!next.completed);
+
+    // task เดิมไม่มี recurrence แล้ว - เรียกซ้ำไม่สร้างเพิ่ม
+    assert!(manager.roll_over_at(now).is_empty());
+    assert_eq!(manager.list_tasks().len(), 3);
+}
+
 /// ทดสอบ configuration และ environment variables
 #[test]
 fn test_configuration() {
@@ -222,22 +330,39 @@ fn test_configuration() {
     }
 }
 
+/// `--quiet` ต้องไม่มีข้อความบทเรียนหลุดออก stdout (ให้ CI รันบทเรียนได้เงียบ ๆ)
+#[test]
+fn test_quiet_keeps_stdout_empty() {
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_rust_concepts"))
+        .args(["--quiet", "--chapters", "macros,testing"])
+        .output()
+        .expect("failed to run rust_concepts");
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "");
+}
+
 /// Helper function สำหรับการทดสอบ
 fn create_sample_tasks() -> Vec<Task> {
     vec![
         Task {
             id: uuid::Uuid::new_v4(),
             title: "Task 1".to_string(),
-            priority: "High".to_string(),
+            priority: Priority::High,
             completed: false,
             created_at: chrono::Utc::now(),
+            due: None,
+            tags: Vec::new(),
+            recurrence: None,
         },
         Task {
             id: uuid::Uuid::new_v4(),
             title: "Task 2".to_string(),
-            priority: "Medium".to_string(),
+            priority: Priority::Medium,
             completed: true,
             created_at: chrono::Utc::now(),
+            due: None,
+            tags: Vec::new(),
+            recurrence: None,
         },
     ]
 }